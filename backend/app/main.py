@@ -1,9 +1,10 @@
-from fastapi import FastAPI, HTTPException, BackgroundTasks
+from fastapi import FastAPI, HTTPException, BackgroundTasks, Header
 from fastapi.middleware.cors import CORSMiddleware
-from fastapi.responses import JSONResponse
+from fastapi.responses import JSONResponse, HTMLResponse
 from pydantic import BaseModel
 import uvicorn
 from typing import Optional, List
+from datetime import datetime
 import logging
 from dotenv import load_dotenv
 from db import DatabaseManager
@@ -11,7 +12,9 @@ import json
 from threading import Lock
 from transcript_processor import TranscriptProcessor
 from sheets_exporter import SheetsExporter
+from sharing import SharingStore, owner_of
 import time
+import os
 
 # Load environment variables
 load_dotenv()
@@ -666,6 +669,166 @@ async def export_to_sheets(meeting_id: str):
         logger.error(f"Error exporting to sheets for {meeting_id}: {e}", exc_info=True)
         raise HTTPException(status_code=500, detail=str(e))
 
+sharing = SharingStore()
+
+def require_owner(authorization: Optional[str]) -> str:
+    """The caller's identity from its bearer token"""
+    if not authorization or not authorization.startswith("Bearer "):
+        raise HTTPException(status_code=401, detail="Sign in to share meetings")
+    token = authorization[len("Bearer "):].strip()
+    if not token:
+        raise HTTPException(status_code=401, detail="Sign in to share meetings")
+    return owner_of(token)
+
+async def require_meeting_owner(meeting_id: str, authorization: Optional[str]) -> str:
+    owner = require_owner(authorization)
+    if not await sharing.owns(meeting_id, owner):
+        raise HTTPException(status_code=403, detail="This meeting was shared by someone else")
+    return owner
+
+class ShareMeetingRequest(BaseModel):
+    recipients: List[str]
+    permission: str
+    meeting: dict
+
+@app.post("/meetings/{meeting_id}/shares")
+async def share_meeting(meeting_id: str, request: ShareMeetingRequest,
+                        authorization: Optional[str] = Header(None)):
+    """Upload a meeting and grant teammates read-only or edit access"""
+    owner = await require_meeting_owner(meeting_id, authorization)
+    if request.permission not in ("read_only", "edit"):
+        raise HTTPException(status_code=400, detail="Invalid permission")
+    if not request.recipients:
+        raise HTTPException(status_code=400, detail="No recipients")
+    try:
+        return await sharing.share(meeting_id, owner, request.meeting,
+                                   request.recipients, request.permission)
+    except Exception as e:
+        logger.error(f"Error sharing meeting {meeting_id}: {e}", exc_info=True)
+        raise HTTPException(status_code=500, detail=str(e))
+
+@app.get("/meetings/{meeting_id}/shares")
+async def list_meeting_shares(meeting_id: str, authorization: Optional[str] = Header(None)):
+    await require_meeting_owner(meeting_id, authorization)
+    return await sharing.list_shares(meeting_id)
+
+@app.delete("/meetings/{meeting_id}/shares/{share_id}")
+async def revoke_meeting_share(meeting_id: str, share_id: str,
+                               authorization: Optional[str] = Header(None)):
+    await require_meeting_owner(meeting_id, authorization)
+    if not await sharing.revoke(meeting_id, share_id):
+        raise HTTPException(status_code=404, detail="Share not found")
+    return {"message": "Share revoked", "id": share_id}
+
+class SyncCommentsRequest(BaseModel):
+    comments: List[dict]
+
+async def require_meeting_member(meeting_id: str, authorization: Optional[str],
+                                 email: Optional[str]) -> str:
+    """The caller, if it shared the meeting or the meeting is shared with `email`"""
+    caller = require_owner(authorization)
+    if not await sharing.is_shared(meeting_id):
+        raise HTTPException(status_code=404, detail="Share the meeting before syncing comments")
+    if not await sharing.can_access(meeting_id, caller, email):
+        raise HTTPException(status_code=403, detail="This meeting isn't shared with you")
+    return caller
+
+@app.post("/meetings/{meeting_id}/comments/sync")
+async def sync_meeting_comments(meeting_id: str, request: SyncCommentsRequest,
+                                authorization: Optional[str] = Header(None),
+                                x_user_email: Optional[str] = Header(None)):
+    """Exchange segment comments on a shared meeting; returns every comment the
+    team server has for it. Teammates send their email in X-User-Email."""
+    await require_meeting_member(meeting_id, authorization, x_user_email)
+    try:
+        return await sharing.sync_comments(meeting_id, request.comments)
+    except Exception as e:
+        logger.error(f"Error syncing comments for {meeting_id}: {e}", exc_info=True)
+        raise HTTPException(status_code=500, detail=str(e))
+
+# Where guests open share links; must be reachable from outside, so it is
+# configured rather than derived from the request
+SHARE_PUBLIC_BASE_URL = os.getenv("SHARE_PUBLIC_BASE_URL", "http://localhost:5167").rstrip("/")
+
+def share_link_response(link: dict) -> dict:
+    return {**link, "url": f"{SHARE_PUBLIC_BASE_URL}/s/{link['id']}"}
+
+class CreateShareLinkRequest(BaseModel):
+    meeting_id: str
+    title: str
+    html: str
+    expires_at: datetime
+
+@app.post("/share-links")
+async def create_share_link(request: CreateShareLinkRequest,
+                            authorization: Optional[str] = Header(None)):
+    """Publish a rendered read-only page of a meeting until `expires_at`"""
+    owner = require_owner(authorization)
+    if request.expires_at.tzinfo is None:
+        raise HTTPException(status_code=400, detail="expires_at needs a time zone")
+    if request.expires_at <= datetime.now(request.expires_at.tzinfo):
+        raise HTTPException(status_code=400, detail="expires_at is in the past")
+    try:
+        link = await sharing.create_link(request.meeting_id, owner, request.title,
+                                         request.html, request.expires_at)
+        return share_link_response(link)
+    except Exception as e:
+        logger.error(f"Error creating share link for {request.meeting_id}: {e}", exc_info=True)
+        raise HTTPException(status_code=500, detail=str(e))
+
+@app.get("/meetings/{meeting_id}/share-links")
+async def list_share_links(meeting_id: str, authorization: Optional[str] = Header(None)):
+    owner = require_owner(authorization)
+    return [share_link_response(link) for link in await sharing.list_links(meeting_id, owner)]
+
+@app.delete("/share-links/{link_id}")
+async def revoke_share_link(link_id: str, authorization: Optional[str] = Header(None)):
+    owner = require_owner(authorization)
+    if not await sharing.revoke_link(link_id, owner):
+        raise HTTPException(status_code=404, detail="Link not found")
+    return {"message": "Link revoked", "id": link_id}
+
+@app.get("/s/{link_id}", response_class=HTMLResponse)
+async def open_share_link(link_id: str):
+    """The public page behind a share link"""
+    html = await sharing.link_page(link_id)
+    if html is None:
+        raise HTTPException(status_code=404, detail="This link has expired or was revoked")
+    return HTMLResponse(content=html, headers={"X-Robots-Tag": "noindex"})
+
+class CrashReportRequest(BaseModel):
+    id: str
+    app_version: str
+    os: str
+    arch: str
+    message: str
+    location: Optional[str] = None
+    backtrace: str = ""
+    recent_log: List[str] = []
+    created_at: str
+
+CRASH_REPORTS_DIR = os.getenv("CRASH_REPORTS_DIR", "crash_reports")
+
+@app.post("/crash-reports")
+async def submit_crash_report(report: CrashReportRequest):
+    """Store an anonymized crash report sent by a desktop client that opted in."""
+    try:
+        os.makedirs(CRASH_REPORTS_DIR, exist_ok=True)
+        # The id is client-supplied; keep only characters safe for a file name
+        safe_id = "".join(c for c in report.id if c.isalnum() or c == "-")[:64]
+        if not safe_id:
+            raise HTTPException(status_code=400, detail="Invalid crash report id")
+        path = os.path.join(CRASH_REPORTS_DIR, f"{safe_id}.json")
+        with open(path, "w", encoding="utf-8") as f:
+            json.dump(report.dict(), f, indent=2)
+        logger.info(f"Stored crash report {safe_id} (app {report.app_version}, {report.os})")
+        return {"message": "Crash report received", "id": safe_id}
+    except HTTPException:
+        raise
+    except Exception as e:
+        logger.error(f"Error storing crash report: {e}", exc_info=True)
+        raise HTTPException(status_code=500, detail=str(e))
+
 
 @app.on_event("shutdown")
 async def shutdown_event():
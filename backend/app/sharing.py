@@ -0,0 +1,285 @@
+"""Team sharing: meetings uploaded by a desktop client for teammates.
+
+Callers are identified by the bearer token they send; a meeting belongs to
+the token that first shared it, and only that token may change its shares.
+Teammates name their email as well; an email belongs to the first token
+that names it. Tokens are stored hashed.
+"""
+import hashlib
+import json
+import logging
+import os
+import secrets
+import sqlite3
+import uuid
+from contextlib import asynccontextmanager
+from datetime import datetime, timezone
+from typing import Dict, List, Optional
+
+import aiosqlite
+
+logger = logging.getLogger(__name__)
+
+
+def owner_of(token: str) -> str:
+    """Stable identity for a bearer token, without storing the token"""
+    return hashlib.sha256(token.encode("utf-8")).hexdigest()
+
+
+def utc_now() -> str:
+    return datetime.now(timezone.utc).isoformat()
+
+
+class SharingStore:
+    def __init__(self, db_path: str = None):
+        if db_path is None:
+            db_path = os.getenv('DATABASE_PATH', 'meeting_minutes.db')
+        self.db_path = db_path
+        self._init_db()
+
+    def _init_db(self):
+        with sqlite3.connect(self.db_path) as conn:
+            cursor = conn.cursor()
+            cursor.execute("""
+                CREATE TABLE IF NOT EXISTS shared_meetings (
+                    meeting_id TEXT PRIMARY KEY,
+                    owner TEXT NOT NULL,
+                    meeting TEXT NOT NULL,
+                    updated_at TEXT NOT NULL
+                )
+            """)
+            cursor.execute("""
+                CREATE TABLE IF NOT EXISTS meeting_shares (
+                    id TEXT PRIMARY KEY,
+                    meeting_id TEXT NOT NULL,
+                    email TEXT NOT NULL,
+                    permission TEXT NOT NULL,
+                    shared_by TEXT,
+                    created_at TEXT NOT NULL,
+                    UNIQUE (meeting_id, email),
+                    FOREIGN KEY (meeting_id) REFERENCES shared_meetings(meeting_id)
+                )
+            """)
+            cursor.execute("""
+                CREATE TABLE IF NOT EXISTS share_links (
+                    id TEXT PRIMARY KEY,
+                    meeting_id TEXT NOT NULL,
+                    owner TEXT NOT NULL,
+                    title TEXT NOT NULL,
+                    html TEXT NOT NULL,
+                    expires_at TEXT NOT NULL,
+                    created_at TEXT NOT NULL
+                )
+            """)
+            cursor.execute("""
+                CREATE TABLE IF NOT EXISTS share_identities (
+                    email TEXT PRIMARY KEY,
+                    owner TEXT NOT NULL,
+                    claimed_at TEXT NOT NULL
+                )
+            """)
+            cursor.execute("""
+                CREATE TABLE IF NOT EXISTS shared_comments (
+                    id TEXT PRIMARY KEY,
+                    meeting_id TEXT NOT NULL,
+                    comment TEXT NOT NULL,
+                    created_at TEXT NOT NULL,
+                    FOREIGN KEY (meeting_id) REFERENCES shared_meetings(meeting_id)
+                )
+            """)
+            conn.commit()
+
+    @asynccontextmanager
+    async def _get_connection(self):
+        conn = await aiosqlite.connect(self.db_path)
+        conn.row_factory = aiosqlite.Row
+        try:
+            yield conn
+        finally:
+            await conn.close()
+
+    async def _owner(self, conn, meeting_id: str) -> Optional[str]:
+        cursor = await conn.execute(
+            "SELECT owner FROM shared_meetings WHERE meeting_id = ?", (meeting_id,)
+        )
+        row = await cursor.fetchone()
+        return row["owner"] if row else None
+
+    async def is_shared(self, meeting_id: str) -> bool:
+        async with self._get_connection() as conn:
+            return await self._owner(conn, meeting_id) is not None
+
+    async def owns(self, meeting_id: str, owner: str) -> bool:
+        """True when `owner` shared the meeting, or nobody has yet"""
+        async with self._get_connection() as conn:
+            current = await self._owner(conn, meeting_id)
+        return current is None or current == owner
+
+    async def can_access(self, meeting_id: str, owner: str, email: Optional[str]) -> bool:
+        """True for the token that shared the meeting and for the teammates
+        it is shared with. A teammate's email is claimed by the first token
+        that names it; other tokens naming it are refused."""
+        async with self._get_connection() as conn:
+            current = await self._owner(conn, meeting_id)
+            if current is None:
+                return False
+            if current == owner:
+                return True
+            email = (email or "").strip().lower()
+            if not email:
+                return False
+            cursor = await conn.execute(
+                "SELECT 1 FROM meeting_shares WHERE meeting_id = ? AND email = ?",
+                (meeting_id, email)
+            )
+            if await cursor.fetchone() is None:
+                return False
+            await conn.execute(
+                "INSERT OR IGNORE INTO share_identities (email, owner, claimed_at) VALUES (?, ?, ?)",
+                (email, owner, utc_now())
+            )
+            await conn.commit()
+            cursor = await conn.execute(
+                "SELECT owner FROM share_identities WHERE email = ?", (email,)
+            )
+            row = await cursor.fetchone()
+        return row is not None and row["owner"] == owner
+
+    async def share(self, meeting_id: str, owner: str, meeting: Dict,
+                    recipients: List[str], permission: str) -> List[Dict]:
+        """Stores the meeting snapshot and grants `permission` to each
+        recipient; sharing again with a recipient updates their permission."""
+        now = utc_now()
+        async with self._get_connection() as conn:
+            await conn.execute(
+                """
+                INSERT INTO shared_meetings (meeting_id, owner, meeting, updated_at)
+                VALUES (?, ?, ?, ?)
+                ON CONFLICT(meeting_id) DO UPDATE SET meeting = excluded.meeting,
+                                                      updated_at = excluded.updated_at
+                """,
+                (meeting_id, owner, json.dumps(meeting), now)
+            )
+            for email in recipients:
+                await conn.execute(
+                    """
+                    INSERT INTO meeting_shares (id, meeting_id, email, permission, shared_by, created_at)
+                    VALUES (?, ?, ?, ?, ?, ?)
+                    ON CONFLICT(meeting_id, email) DO UPDATE SET permission = excluded.permission
+                    """,
+                    (f"share-{uuid.uuid4()}", meeting_id, email, permission, owner[:12], now)
+                )
+            await conn.commit()
+        logger.info(f"Shared meeting {meeting_id} with {len(recipients)} teammates ({permission})")
+        return await self.list_shares(meeting_id)
+
+    async def list_shares(self, meeting_id: str) -> List[Dict]:
+        async with self._get_connection() as conn:
+            cursor = await conn.execute(
+                """
+                SELECT id, meeting_id, email, permission, shared_by, created_at
+                FROM meeting_shares WHERE meeting_id = ? ORDER BY created_at, email
+                """,
+                (meeting_id,)
+            )
+            return [dict(row) for row in await cursor.fetchall()]
+
+    async def revoke(self, meeting_id: str, share_id: str) -> bool:
+        """Removes a teammate's access. The meeting copy goes with the last share."""
+        async with self._get_connection() as conn:
+            cursor = await conn.execute(
+                "DELETE FROM meeting_shares WHERE id = ? AND meeting_id = ?",
+                (share_id, meeting_id)
+            )
+            removed = cursor.rowcount > 0
+            await conn.execute(
+                """
+                DELETE FROM shared_comments WHERE meeting_id = ?
+                AND NOT EXISTS (SELECT 1 FROM meeting_shares WHERE meeting_id = ?)
+                """,
+                (meeting_id, meeting_id)
+            )
+            await conn.execute(
+                """
+                DELETE FROM shared_meetings WHERE meeting_id = ?
+                AND NOT EXISTS (SELECT 1 FROM meeting_shares WHERE meeting_id = ?)
+                """,
+                (meeting_id, meeting_id)
+            )
+            await conn.commit()
+        return removed
+
+    async def sync_comments(self, meeting_id: str, comments: List[Dict]) -> List[Dict]:
+        """Merges a client's comments into the meeting's by id and returns
+        all of them. A comment already stored is never replaced."""
+        async with self._get_connection() as conn:
+            for comment in comments:
+                comment_id = comment.get("id")
+                if not isinstance(comment_id, str) or not comment_id:
+                    continue
+                await conn.execute(
+                    """
+                    INSERT OR IGNORE INTO shared_comments (id, meeting_id, comment, created_at)
+                    VALUES (?, ?, ?, ?)
+                    """,
+                    (comment_id, meeting_id, json.dumps(comment),
+                     str(comment.get("created_at") or utc_now()))
+                )
+            await conn.commit()
+            cursor = await conn.execute(
+                "SELECT comment FROM shared_comments WHERE meeting_id = ? ORDER BY created_at, id",
+                (meeting_id,)
+            )
+            return [json.loads(row["comment"]) for row in await cursor.fetchall()]
+
+    async def create_link(self, meeting_id: str, owner: str, title: str, html: str,
+                          expires_at: datetime) -> Dict:
+        """Stores a rendered page under an unguessable id"""
+        link = {
+            "id": secrets.token_urlsafe(18),
+            "meeting_id": meeting_id,
+            "expires_at": expires_at.astimezone(timezone.utc).isoformat(),
+            "created_at": utc_now(),
+        }
+        async with self._get_connection() as conn:
+            await conn.execute(
+                """
+                INSERT INTO share_links (id, meeting_id, owner, title, html, expires_at, created_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?)
+                """,
+                (link["id"], meeting_id, owner, title, html, link["expires_at"], link["created_at"])
+            )
+            await conn.commit()
+        return link
+
+    async def list_links(self, meeting_id: str, owner: str) -> List[Dict]:
+        """The owner's links for a meeting that haven't expired"""
+        async with self._get_connection() as conn:
+            cursor = await conn.execute(
+                """
+                SELECT id, meeting_id, expires_at, created_at FROM share_links
+                WHERE meeting_id = ? AND owner = ? AND expires_at > ?
+                ORDER BY created_at
+                """,
+                (meeting_id, owner, utc_now())
+            )
+            return [dict(row) for row in await cursor.fetchall()]
+
+    async def revoke_link(self, link_id: str, owner: str) -> bool:
+        async with self._get_connection() as conn:
+            cursor = await conn.execute(
+                "DELETE FROM share_links WHERE id = ? AND owner = ?", (link_id, owner)
+            )
+            await conn.commit()
+            return cursor.rowcount > 0
+
+    async def link_page(self, link_id: str) -> Optional[str]:
+        """The page behind a link, or None once it expired or was revoked.
+        Expired links are removed on the way."""
+        async with self._get_connection() as conn:
+            now = utc_now()
+            await conn.execute("DELETE FROM share_links WHERE expires_at <= ?", (now,))
+            await conn.commit()
+            cursor = await conn.execute("SELECT html FROM share_links WHERE id = ?", (link_id,))
+            row = await cursor.fetchone()
+        return row["html"] if row else None
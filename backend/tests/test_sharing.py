@@ -0,0 +1,72 @@
+"""Access checks on shared meetings.
+
+Run from backend/: python -m unittest discover tests
+"""
+import asyncio
+import os
+import sys
+import tempfile
+import unittest
+
+APP_DIR = os.path.join(os.path.dirname(os.path.dirname(os.path.abspath(__file__))), "app")
+sys.path.insert(0, APP_DIR)
+
+_db_dir = tempfile.TemporaryDirectory()
+os.environ["DATABASE_PATH"] = os.path.join(_db_dir.name, "meeting_minutes.db")
+
+from fastapi import HTTPException  # noqa: E402
+
+import main  # noqa: E402
+from sharing import SharingStore, owner_of  # noqa: E402
+
+OWNER = "Bearer owner-token"
+TEAMMATE = "Bearer teammate-token"
+STRANGER = "Bearer stranger-token"
+
+
+class SyncCommentsAccessTest(unittest.TestCase):
+    def setUp(self):
+        self.db = tempfile.NamedTemporaryFile(suffix=".db", delete=False)
+        self.db.close()
+        main.sharing = SharingStore(self.db.name)
+        asyncio.run(main.sharing.share("meeting-1", owner_of("owner-token"), {"title": "Standup"},
+                                       ["teammate@example.com"], "read_only"))
+
+    def tearDown(self):
+        os.unlink(self.db.name)
+
+    def sync(self, authorization, email=None, comments=None):
+        request = main.SyncCommentsRequest(comments=comments or [])
+        return asyncio.run(main.sync_meeting_comments("meeting-1", request, authorization, email))
+
+    def assert_status(self, status, *args):
+        with self.assertRaises(HTTPException) as raised:
+            self.sync(*args)
+        self.assertEqual(raised.exception.status_code, status)
+
+    def test_owner_and_teammate_share_the_thread(self):
+        self.sync(OWNER, None, [{"id": "c1", "text": "From the owner"}])
+        comments = self.sync(TEAMMATE, "Teammate@example.com", [{"id": "c2", "text": "Reply"}])
+        self.assertEqual([c["id"] for c in comments], ["c1", "c2"])
+
+    def test_unrelated_user_is_forbidden(self):
+        self.sync(OWNER, None, [{"id": "c1", "text": "Private"}])
+        self.assert_status(403, STRANGER)
+        self.assert_status(403, STRANGER, "stranger@example.com", [{"id": "c9", "text": "Spam"}])
+        comments = self.sync(OWNER)
+        self.assertEqual([c["id"] for c in comments], ["c1"])
+
+    def test_a_teammate_email_belongs_to_the_first_token_naming_it(self):
+        self.sync(TEAMMATE, "teammate@example.com")
+        self.assert_status(403, STRANGER, "teammate@example.com")
+
+    def test_missing_token_or_unshared_meeting(self):
+        self.assert_status(401, None)
+        request = main.SyncCommentsRequest(comments=[])
+        with self.assertRaises(HTTPException) as raised:
+            asyncio.run(main.sync_meeting_comments("meeting-2", request, OWNER, None))
+        self.assertEqual(raised.exception.status_code, 404)
+
+
+if __name__ == "__main__":
+    unittest.main()
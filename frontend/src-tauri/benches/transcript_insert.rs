@@ -0,0 +1,94 @@
+//! Compares row-by-row transcript inserts with the batched repository path.
+//!
+//! Run with `cargo bench --bench transcript_insert`.
+
+use app_lib::api::TranscriptSegment;
+use app_lib::database::migrations::run_migrations;
+use app_lib::database::repositories::transcript::TranscriptsRepository;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions};
+use sqlx::{Connection, SqlitePool};
+use uuid::Uuid;
+
+fn segments(count: usize) -> Vec<TranscriptSegment> {
+    (0..count)
+        .map(|i| TranscriptSegment {
+            id: format!("seg-{}", i),
+            text: format!("Segment {} of a long imported meeting with some typical spoken text.", i),
+            timestamp: format!("00:{:02}:{:02}", (i / 60) % 60, i % 60),
+            audio_start_time: Some(i as f64 * 3.0),
+            audio_end_time: Some(i as f64 * 3.0 + 2.5),
+            duration: Some(2.5),
+            speaker: None,
+            uncertain: false,
+        })
+        .collect()
+}
+
+async fn open_pool(dir: &tempfile::TempDir) -> SqlitePool {
+    let options = SqliteConnectOptions::new()
+        .filename(dir.path().join("bench.sqlite"))
+        .create_if_missing(true)
+        .journal_mode(SqliteJournalMode::Wal);
+    let pool = SqlitePoolOptions::new()
+        .connect_with(options)
+        .await
+        .expect("open bench database");
+    run_migrations(&pool, None).await.expect("migrate bench database");
+    pool
+}
+
+/// The previous implementation: one INSERT per segment inside a transaction
+async fn insert_row_by_row(pool: &SqlitePool, segments: &[TranscriptSegment]) {
+    let meeting_id = format!("meeting-{}", Uuid::new_v4());
+    let mut conn = pool.acquire().await.unwrap();
+    let mut tx = conn.begin().await.unwrap();
+    sqlx::query("INSERT INTO meetings (id, title, created_at, updated_at) VALUES (?, 'bench', datetime('now'), datetime('now'))")
+        .bind(&meeting_id)
+        .execute(&mut *tx)
+        .await
+        .unwrap();
+    for segment in segments {
+        sqlx::query(
+            "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(format!("transcript-{}", Uuid::new_v4()))
+        .bind(&meeting_id)
+        .bind(&segment.text)
+        .bind(&segment.timestamp)
+        .bind(segment.audio_start_time)
+        .bind(segment.audio_end_time)
+        .bind(segment.duration)
+        .execute(&mut *tx)
+        .await
+        .unwrap();
+    }
+    tx.commit().await.unwrap();
+}
+
+fn bench_transcript_insert(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let pool = runtime.block_on(open_pool(&dir));
+
+    let mut group = c.benchmark_group("transcript_insert");
+    group.sample_size(10);
+    for count in [500usize, 5_000] {
+        let data = segments(count);
+        group.bench_with_input(BenchmarkId::new("row_by_row", count), &data, |b, data| {
+            b.to_async(&runtime).iter(|| insert_row_by_row(&pool, data));
+        });
+        group.bench_with_input(BenchmarkId::new("batched", count), &data, |b, data| {
+            b.to_async(&runtime).iter(|| async {
+                TranscriptsRepository::save_transcript(&pool, "bench", data, None)
+                    .await
+                    .unwrap();
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_transcript_insert);
+criterion_main!(benches);
@@ -1,5 +1,7 @@
 #[path = "build/ffmpeg.rs"]
 mod ffmpeg;
+#[path = "build/benchmark_clip.rs"]
+mod benchmark_clip;
 
 fn main() {
     // GPU Acceleration Detection and Build Guidance
@@ -18,6 +20,9 @@ fn main() {
     // Download and bundle FFmpeg binary at build-time
     ffmpeg::ensure_ffmpeg_binary();
 
+    // Speech clip for the transcription benchmark
+    benchmark_clip::ensure_benchmark_clip();
+
     tauri_build::build()
 }
 
@@ -0,0 +1,73 @@
+// ============================================================================
+// Benchmark Clip
+// ============================================================================
+// Fetches the speech clip used by the transcription benchmark into samples/,
+// where the bundler picks it up as a resource
+
+/// Public-domain recording (JFK's inaugural address, ~11s, 16kHz mono) from
+/// the whisper.cpp samples
+const BENCHMARK_CLIP_URL: &str =
+    "https://github.com/ggml-org/whisper.cpp/raw/master/samples/jfk.wav";
+
+/// Downloads samples/benchmark.wav unless it is already there. A failed
+/// download only disables the benchmark, so it warns instead of failing the build.
+pub fn ensure_benchmark_clip() {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .expect("CARGO_MANIFEST_DIR environment variable not set");
+    let clip_path = std::path::PathBuf::from(&manifest_dir)
+        .join("samples")
+        .join("benchmark.wav");
+    println!("cargo:rerun-if-changed={}", clip_path.display());
+
+    if clip_path.exists() {
+        if is_wav(&clip_path) {
+            return;
+        }
+        println!("cargo:warning=⚠️  samples/benchmark.wav is not a WAV file, re-downloading...");
+        let _ = std::fs::remove_file(&clip_path);
+    }
+
+    match download_clip(&clip_path) {
+        Ok(()) => println!("cargo:warning=✅ Benchmark clip downloaded to samples/benchmark.wav"),
+        Err(e) => println!(
+            "cargo:warning=⚠️  Failed to download the benchmark clip ({}); the transcription benchmark will need a clip chosen by the user",
+            e
+        ),
+    }
+}
+
+fn is_wav(path: &std::path::Path) -> bool {
+    use std::io::Read;
+    let mut header = [0u8; 12];
+    std::fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut header))
+        .map(|()| &header[..4] == b"RIFF" && &header[8..12] == b"WAVE")
+        .unwrap_or(false)
+}
+
+fn download_clip(clip_path: &std::path::Path) -> Result<(), String> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(60))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+    let response = client
+        .get(BENCHMARK_CLIP_URL)
+        .send()
+        .map_err(|e| format!("Failed to download: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP error: {}", response.status()));
+    }
+    let content = response
+        .bytes()
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+
+    // Written next to the target and renamed, so an interrupted build never
+    // leaves a truncated clip behind
+    let partial = clip_path.with_extension("wav.part");
+    std::fs::write(&partial, &content).map_err(|e| format!("Failed to write clip: {}", e))?;
+    if !is_wav(&partial) {
+        let _ = std::fs::remove_file(&partial);
+        return Err("Downloaded file is not a WAV file".to_string());
+    }
+    std::fs::rename(&partial, clip_path).map_err(|e| format!("Failed to save clip: {}", e))
+}
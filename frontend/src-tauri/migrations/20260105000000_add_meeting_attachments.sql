@@ -0,0 +1,17 @@
+-- Migration: Add meeting_attachments table
+-- Stores files (slides, screenshots, agendas) that users attach to a meeting.
+-- The file itself is copied into the meeting folder under attachments/,
+-- stored_path points at that copy.
+
+CREATE TABLE IF NOT EXISTS meeting_attachments (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    file_name TEXT NOT NULL,
+    stored_path TEXT NOT NULL,
+    mime_type TEXT,
+    size_bytes INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_attachments_meeting_id ON meeting_attachments(meeting_id);
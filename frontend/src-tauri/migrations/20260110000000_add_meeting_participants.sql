@@ -0,0 +1,19 @@
+-- Migration: Add meeting_participants table
+-- Participants can be entered manually or imported from an external source
+-- (e.g. calendar attendees). speaker_label links a participant to the
+-- diarization label used in transcripts (e.g. "Speaker 1", "mic").
+
+CREATE TABLE IF NOT EXISTS meeting_participants (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    email TEXT,
+    role TEXT,
+    speaker_label TEXT,
+    source TEXT NOT NULL DEFAULT 'manual',
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_participants_meeting_id ON meeting_participants(meeting_id);
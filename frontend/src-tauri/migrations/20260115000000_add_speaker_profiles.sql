@@ -0,0 +1,27 @@
+-- Migration: Add speaker naming and local voice profiles
+-- meeting_speakers maps a diarization label in one meeting to a display name
+-- and (optionally) the speaker's voice embedding for that meeting.
+-- voice_profiles keeps averaged embeddings for people the user chose to
+-- remember, so later meetings can be labelled automatically. Embeddings are
+-- stored as little-endian f32 blobs and never leave the machine.
+
+CREATE TABLE IF NOT EXISTS voice_profiles (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    embedding BLOB NOT NULL,
+    sample_count INTEGER NOT NULL DEFAULT 1,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS meeting_speakers (
+    meeting_id TEXT NOT NULL,
+    speaker_label TEXT NOT NULL,
+    display_name TEXT,
+    profile_id TEXT,
+    embedding BLOB,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (meeting_id, speaker_label),
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+    FOREIGN KEY (profile_id) REFERENCES voice_profiles(id) ON DELETE SET NULL
+);
@@ -0,0 +1,20 @@
+-- Migration: Add action_items table
+-- Action items are extracted from the "Action Items" section of generated
+-- summaries (source = 'summary') or added by the user (source = 'manual').
+-- due_date is kept as free text because LLM output is rarely a clean date.
+
+CREATE TABLE IF NOT EXISTS action_items (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    text TEXT NOT NULL,
+    owner TEXT,
+    due_date TEXT,
+    status TEXT NOT NULL DEFAULT 'open',
+    source TEXT NOT NULL DEFAULT 'summary',
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_action_items_meeting_id ON action_items(meeting_id);
+CREATE INDEX IF NOT EXISTS idx_action_items_status ON action_items(status);
@@ -0,0 +1,14 @@
+-- Migration: Issue tracker integration (Jira / Linear)
+-- integration_settings holds per-integration JSON config (credentials included),
+-- keyed by integration id (e.g. 'jira', 'linear').
+-- action_items gains the key/url of the issue created from it.
+
+CREATE TABLE IF NOT EXISTS integration_settings (
+    id TEXT PRIMARY KEY NOT NULL,
+    config TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+ALTER TABLE action_items ADD COLUMN external_tracker TEXT;
+ALTER TABLE action_items ADD COLUMN external_key TEXT;
+ALTER TABLE action_items ADD COLUMN external_url TEXT;
@@ -0,0 +1,6 @@
+-- Migration: SMTP settings for emailing summaries
+-- smtpConfig holds host/port/security/username/from as JSON (like customOpenAIConfig).
+-- The password is kept in its own column alongside the other API keys.
+
+ALTER TABLE settings ADD COLUMN smtpConfig TEXT;
+ALTER TABLE settings ADD COLUMN smtpPassword TEXT;
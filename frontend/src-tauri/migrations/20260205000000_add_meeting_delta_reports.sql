@@ -0,0 +1,18 @@
+-- Migration: Add meeting_delta_reports table
+-- A delta report compares a meeting with an earlier meeting in the same series
+-- (new decisions, action item progress, recurring topics). It is stored on the
+-- newer meeting; re-running the comparison replaces it.
+-- action_item_delta is JSON computed locally from the action_items table.
+
+CREATE TABLE IF NOT EXISTS meeting_delta_reports (
+    meeting_id TEXT PRIMARY KEY NOT NULL,
+    previous_meeting_id TEXT NOT NULL,
+    report TEXT NOT NULL,
+    action_item_delta TEXT NOT NULL,
+    provider TEXT,
+    model TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_delta_reports_previous ON meeting_delta_reports(previous_meeting_id);
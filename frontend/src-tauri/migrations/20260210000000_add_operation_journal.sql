@@ -0,0 +1,18 @@
+-- Migration: Add operation_journal table
+-- Destructive operations (meeting delete, segment delete, segment merge) store
+-- before-images of the affected rows here so they can be undone. before_image
+-- is JSON: a list of {table, key_column, keys, rows}. Entries only apply to the
+-- app session that wrote them (session_id) and older sessions are pruned.
+
+CREATE TABLE IF NOT EXISTS operation_journal (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id TEXT NOT NULL,
+    operation TEXT NOT NULL,
+    meeting_id TEXT,
+    description TEXT NOT NULL,
+    before_image TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    undone_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_operation_journal_session ON operation_journal(session_id, id);
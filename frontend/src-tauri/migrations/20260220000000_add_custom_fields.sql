@@ -0,0 +1,28 @@
+-- Migration: Add user-defined custom fields
+-- custom_field_definitions is the global list of fields (e.g. "Client",
+-- "Deal ID", "Billing code"); meeting_custom_fields holds the value a meeting
+-- has for a field. Values are stored as text in a canonical form per type:
+-- text/select as entered, number as a decimal, date as YYYY-MM-DD,
+-- boolean as "true"/"false". options is a JSON array of choices for select.
+
+CREATE TABLE IF NOT EXISTS custom_field_definitions (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    field_type TEXT NOT NULL CHECK (field_type IN ('text', 'number', 'date', 'boolean', 'select')),
+    options TEXT,
+    position INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS meeting_custom_fields (
+    meeting_id TEXT NOT NULL,
+    field_id TEXT NOT NULL,
+    value TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (meeting_id, field_id),
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+    FOREIGN KEY (field_id) REFERENCES custom_field_definitions(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_custom_fields_field ON meeting_custom_fields(field_id, value);
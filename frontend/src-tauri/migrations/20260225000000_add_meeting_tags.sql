@@ -0,0 +1,17 @@
+-- Migration: Add meeting tags and indexes for structured meeting queries
+-- Tags are free-form labels; a meeting has each tag at most once
+-- (case-insensitive).
+
+CREATE TABLE IF NOT EXISTS meeting_tags (
+    meeting_id TEXT NOT NULL,
+    tag TEXT NOT NULL COLLATE NOCASE,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (meeting_id, tag),
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_tags_tag ON meeting_tags(tag);
+
+-- Filters in api_query_meetings look up segments and speakers per meeting
+CREATE INDEX IF NOT EXISTS idx_transcripts_meeting_id ON transcripts(meeting_id);
+CREATE INDEX IF NOT EXISTS idx_meetings_created_at ON meetings(created_at);
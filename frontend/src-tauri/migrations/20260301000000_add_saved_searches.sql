@@ -0,0 +1,12 @@
+-- Migration: Add saved_searches table
+-- filter is the JSON MeetingFilter accepted by api_query_meetings. Relative
+-- date windows (within_days) are resolved each time the search is run.
+
+CREATE TABLE IF NOT EXISTS saved_searches (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    filter TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    last_run_at TEXT
+);
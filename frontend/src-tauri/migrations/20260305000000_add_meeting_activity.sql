@@ -0,0 +1,14 @@
+-- Migration: Add meeting_activity table
+-- Local UI state per meeting: when it was last opened and whether it is
+-- pinned. Rows are created lazily on first view or pin.
+
+CREATE TABLE IF NOT EXISTS meeting_activity (
+    meeting_id TEXT PRIMARY KEY NOT NULL,
+    pinned INTEGER NOT NULL DEFAULT 0,
+    pinned_at TEXT,
+    last_viewed_at TEXT,
+    view_count INTEGER NOT NULL DEFAULT 0,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_activity_last_viewed ON meeting_activity(last_viewed_at);
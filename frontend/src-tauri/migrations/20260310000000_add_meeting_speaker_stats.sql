@@ -0,0 +1,17 @@
+-- Migration: Add meeting_speaker_stats table
+-- Participation statistics per speaker, derived from transcript timing.
+-- source_fingerprint identifies the segments the row was computed from so
+-- stale rows are recomputed after transcripts change.
+
+CREATE TABLE IF NOT EXISTS meeting_speaker_stats (
+    meeting_id TEXT NOT NULL,
+    speaker_label TEXT NOT NULL,
+    talk_seconds REAL NOT NULL,
+    segment_count INTEGER NOT NULL,
+    interruptions INTEGER NOT NULL,
+    longest_monologue_seconds REAL NOT NULL,
+    source_fingerprint TEXT NOT NULL,
+    computed_at TEXT NOT NULL,
+    PRIMARY KEY (meeting_id, speaker_label),
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
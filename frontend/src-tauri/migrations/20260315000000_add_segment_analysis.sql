@@ -0,0 +1,20 @@
+-- Migration: Add segment_analysis table
+-- Sentiment and tone per transcript segment, produced by the local lexicon
+-- analyzer or the configured LLM. sentiment is positive/neutral/negative,
+-- score ranges from -1.0 (most negative) to 1.0, tone is a single lowercase
+-- word such as "frustrated" or "excited". Re-running replaces the rows.
+
+CREATE TABLE IF NOT EXISTS segment_analysis (
+    transcript_id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    sentiment TEXT NOT NULL CHECK (sentiment IN ('positive', 'neutral', 'negative')),
+    score REAL NOT NULL,
+    tone TEXT NOT NULL,
+    method TEXT NOT NULL,
+    model TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_segment_analysis_meeting ON segment_analysis(meeting_id);
+CREATE INDEX IF NOT EXISTS idx_segment_analysis_tone ON segment_analysis(tone, sentiment);
@@ -0,0 +1,22 @@
+-- Migration: Add transcript_entities table
+-- Named entities (people, organizations, products, dates) mentioned in each
+-- transcript segment, extracted by local rules or the configured LLM.
+-- normalized is the lowercase lookup key with company suffixes dropped, so
+-- "Acme Corp" and "Acme" share one key. Re-extracting a meeting replaces
+-- its rows.
+
+CREATE TABLE IF NOT EXISTS transcript_entities (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    meeting_id TEXT NOT NULL,
+    transcript_id TEXT NOT NULL,
+    kind TEXT NOT NULL CHECK (kind IN ('person', 'organization', 'product', 'date', 'other')),
+    name TEXT NOT NULL,
+    normalized TEXT NOT NULL,
+    method TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_transcript_entities_meeting ON transcript_entities(meeting_id);
+CREATE INDEX IF NOT EXISTS idx_transcript_entities_normalized ON transcript_entities(normalized, kind);
+CREATE INDEX IF NOT EXISTS idx_transcript_entities_transcript ON transcript_entities(transcript_id);
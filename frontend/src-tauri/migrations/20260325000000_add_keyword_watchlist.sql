@@ -0,0 +1,29 @@
+-- Migration: Add keyword watchlist and hits
+-- watch_keywords are words or phrases ("pricing", "contract", competitor
+-- names) matched case-insensitively against live transcript segments.
+-- keyword_hits records each match. A hit made during recording has no
+-- meeting yet; it keeps the recording folder and is attached to the meeting
+-- when the transcript is saved. segment_text follows transcript encryption.
+
+CREATE TABLE IF NOT EXISTS watch_keywords (
+    id TEXT PRIMARY KEY NOT NULL,
+    keyword TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    enabled INTEGER NOT NULL DEFAULT 1,
+    created_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS keyword_hits (
+    id TEXT PRIMARY KEY NOT NULL,
+    keyword_id TEXT NOT NULL,
+    keyword TEXT NOT NULL,
+    meeting_id TEXT,
+    folder_path TEXT,
+    segment_text TEXT NOT NULL,
+    sequence_id INTEGER,
+    audio_start_time REAL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_keyword_hits_meeting ON keyword_hits(meeting_id);
+CREATE INDEX IF NOT EXISTS idx_keyword_hits_folder ON keyword_hits(folder_path) WHERE meeting_id IS NULL;
@@ -0,0 +1,18 @@
+-- Migration: Add redaction_mappings table
+-- When transcript redaction runs in reversible mode, each placeholder written
+-- into a meeting's transcript ("[EMAIL_1]", "[NAME_2]") is mapped back to the
+-- original value here. original is always encrypted with the database
+-- encryption key; reversible redaction can't be turned on without it.
+
+CREATE TABLE IF NOT EXISTS redaction_mappings (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    placeholder TEXT NOT NULL,
+    kind TEXT NOT NULL CHECK (kind IN ('email', 'phone', 'credit_card', 'name')),
+    original TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    UNIQUE (meeting_id, placeholder),
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_redaction_mappings_meeting ON redaction_mappings(meeting_id);
@@ -0,0 +1,13 @@
+-- Migration: Add legal_holds table
+-- A meeting with a row here is under compliance (legal) hold: it can't be
+-- deleted, nor can its transcript segments or attachments, and retention
+-- purges must skip it. Releasing the hold deletes the row; both actions are
+-- recorded in the audit log.
+
+CREATE TABLE IF NOT EXISTS legal_holds (
+    meeting_id TEXT PRIMARY KEY NOT NULL,
+    reason TEXT NOT NULL,
+    placed_by TEXT NOT NULL,
+    placed_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
@@ -0,0 +1,20 @@
+-- Migration: Add segment_comments table
+-- Threaded review comments on transcript segments. A reply points at the
+-- comment it answers through parent_id; top-level comments have none. text
+-- is encrypted like transcript text when encryption at rest is on. Comments
+-- made by teammates arrive through sharing sync with their original ids.
+
+CREATE TABLE IF NOT EXISTS segment_comments (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    transcript_id TEXT NOT NULL,
+    parent_id TEXT,
+    author TEXT NOT NULL,
+    text TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_segment_comments_meeting ON segment_comments(meeting_id);
+CREATE INDEX IF NOT EXISTS idx_segment_comments_transcript ON segment_comments(transcript_id);
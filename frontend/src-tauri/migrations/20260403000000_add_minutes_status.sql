@@ -0,0 +1,17 @@
+-- Migration: Add minutes_status table
+-- Review state of a meeting's minutes: draft -> under_review -> approved.
+-- Meetings without a row are drafts. Approved minutes (summary, transcript
+-- and title) are locked against edits until they are returned to draft.
+-- Every transition is also recorded in the audit log.
+
+CREATE TABLE IF NOT EXISTS minutes_status (
+    meeting_id TEXT PRIMARY KEY NOT NULL,
+    status TEXT NOT NULL DEFAULT 'draft'
+        CHECK (status IN ('draft', 'under_review', 'approved')),
+    submitted_by TEXT,
+    submitted_at TEXT,
+    approved_by TEXT,
+    approved_at TEXT,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
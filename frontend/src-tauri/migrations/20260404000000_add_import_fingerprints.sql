@@ -0,0 +1,17 @@
+-- Migration: Add import_fingerprints table
+-- SHA-256 of every file imported as a meeting (recordings and transcript
+-- files), so importing the same file again can be flagged, skipped or
+-- linked to the meeting it already became.
+
+CREATE TABLE IF NOT EXISTS import_fingerprints (
+    meeting_id TEXT NOT NULL,
+    content_hash TEXT NOT NULL,
+    file_name TEXT,
+    file_size INTEGER NOT NULL,
+    source TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (meeting_id, content_hash),
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_import_fingerprints_hash ON import_fingerprints(content_hash);
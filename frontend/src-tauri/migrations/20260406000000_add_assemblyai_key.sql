@@ -0,0 +1,4 @@
+-- Migration: Add an AssemblyAI API key to the transcript settings
+-- AssemblyAI joins Deepgram and ElevenLabs as a cloud transcription provider.
+
+ALTER TABLE transcript_settings ADD COLUMN assemblyAiApiKey TEXT;
@@ -0,0 +1,5 @@
+-- Migration: Per-language transcription routing
+-- JSON array of {language, provider, model}; a meeting in a listed language
+-- is transcribed with that provider and model instead of the default one.
+
+ALTER TABLE transcript_settings ADD COLUMN languageRoutes TEXT;
@@ -0,0 +1,14 @@
+-- Migration: Add learned correction rules
+-- Each (wrong -> corrected) pair users make when editing a transcript is
+-- recorded here. Once the same correction has been made often enough it is
+-- applied to new transcriptions. Pairs are matched case-insensitively, so
+-- one row holds the latest correction for a phrase.
+
+CREATE TABLE IF NOT EXISTS correction_rules (
+    id TEXT PRIMARY KEY NOT NULL,
+    wrong TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    corrected TEXT NOT NULL,
+    occurrences INTEGER NOT NULL DEFAULT 1,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
@@ -0,0 +1,18 @@
+-- Migration: Add summary feedback
+-- Thumbs up/down and an optional comment on a generated summary. series_key
+-- is the meeting's title with dates and numbers removed ("Weekly sync"), so
+-- feedback on one meeting of a recurring series can steer the summaries of
+-- the next ones.
+
+CREATE TABLE IF NOT EXISTS summary_feedback (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    series_key TEXT NOT NULL,
+    helpful INTEGER NOT NULL,
+    comment TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_summary_feedback_meeting ON summary_feedback(meeting_id);
+CREATE INDEX IF NOT EXISTS idx_summary_feedback_series ON summary_feedback(series_key, created_at);
@@ -0,0 +1,5 @@
+-- Migration: Summary output language
+-- Language code summaries are written in, independent of the transcript's
+-- language. NULL (or 'auto') keeps the transcript's language.
+
+ALTER TABLE settings ADD COLUMN summaryLanguage TEXT;
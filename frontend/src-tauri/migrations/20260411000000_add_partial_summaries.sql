@@ -0,0 +1,20 @@
+-- Migration: Add partial summaries
+-- A named summary of part of a meeting ("Budget discussion"), covering the
+-- transcript segments between start_time and end_time (seconds from the start
+-- of the recording). A meeting can have any number of them next to its full
+-- summary.
+
+CREATE TABLE IF NOT EXISTS partial_summaries (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL,
+    content TEXT NOT NULL,
+    provider TEXT,
+    model TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_partial_summaries_meeting ON partial_summaries(meeting_id, start_time);
@@ -0,0 +1,19 @@
+-- Migration: Add digests
+-- A digest summarizes every meeting of a period (a day, a week or a custom
+-- range) into one brief: key decisions, all action items and upcoming
+-- commitments. meeting_ids is a JSON array of the meetings it covers.
+
+CREATE TABLE IF NOT EXISTS digests (
+    id TEXT PRIMARY KEY NOT NULL,
+    period TEXT NOT NULL,
+    period_start TEXT NOT NULL,
+    period_end TEXT NOT NULL,
+    title TEXT NOT NULL,
+    content TEXT NOT NULL,
+    meeting_ids TEXT NOT NULL,
+    provider TEXT,
+    model TEXT,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_digests_created ON digests(created_at);
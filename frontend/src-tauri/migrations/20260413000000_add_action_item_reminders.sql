@@ -0,0 +1,7 @@
+-- Migration: Action item due-date reminders
+-- reminded_on is the local date (YYYY-MM-DD) an item was last reminded of, so
+-- each item is reminded at most once a day. snoozed_until holds reminders
+-- back until that time.
+
+ALTER TABLE action_items ADD COLUMN reminded_on TEXT;
+ALTER TABLE action_items ADD COLUMN snoozed_until TEXT;
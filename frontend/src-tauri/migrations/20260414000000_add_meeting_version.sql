@@ -0,0 +1,13 @@
+-- Migration: Meeting versions for optimistic concurrency
+-- version goes up by one on every change to a meeting row, whoever makes it
+-- (an edit in another window, sync, a generated title), so a save carrying
+-- the version it was based on can tell when the row changed underneath it.
+
+ALTER TABLE meetings ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+
+CREATE TRIGGER IF NOT EXISTS meetings_bump_version
+AFTER UPDATE ON meetings
+WHEN NEW.version = OLD.version
+BEGIN
+    UPDATE meetings SET version = OLD.version + 1 WHERE id = NEW.id;
+END;
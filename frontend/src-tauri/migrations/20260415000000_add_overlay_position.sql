@@ -0,0 +1,6 @@
+-- Migration: Recorder overlay position
+-- Where the compact recorder overlay was last placed, in physical pixels, so
+-- it reopens in the same spot. NULL until the overlay is first moved.
+
+ALTER TABLE settings ADD COLUMN overlayX INTEGER;
+ALTER TABLE settings ADD COLUMN overlayY INTEGER;
@@ -0,0 +1,35 @@
+-- Migration: Add feed subscriptions
+-- RSS/Atom feeds (team podcasts, all-hands recordings) whose episodes are
+-- downloaded and imported as meetings tagged with the feed's title.
+-- Episodes are remembered by their guid so each is imported once. status is
+-- 'pending' (waiting to be imported), 'imported', 'failed' (gave up after
+-- repeated errors) or 'skipped' (already in the feed when it was subscribed).
+
+CREATE TABLE IF NOT EXISTS feed_subscriptions (
+    id TEXT PRIMARY KEY NOT NULL,
+    url TEXT NOT NULL UNIQUE,
+    title TEXT NOT NULL,
+    enabled INTEGER NOT NULL DEFAULT 1,
+    last_checked_at TEXT,
+    last_error TEXT,
+    created_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS feed_episodes (
+    id TEXT PRIMARY KEY NOT NULL,
+    feed_id TEXT NOT NULL,
+    guid TEXT NOT NULL,
+    title TEXT NOT NULL,
+    media_url TEXT NOT NULL,
+    published_at TEXT,
+    status TEXT NOT NULL DEFAULT 'pending',
+    meeting_id TEXT,
+    error TEXT,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    UNIQUE (feed_id, guid),
+    FOREIGN KEY (feed_id) REFERENCES feed_subscriptions(id) ON DELETE CASCADE,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_feed_episodes_status ON feed_episodes(status);
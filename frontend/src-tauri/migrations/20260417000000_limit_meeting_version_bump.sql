@@ -0,0 +1,10 @@
+-- Only edits a user can see bump the version; moving a meeting's folder
+-- (relocation, recordings root changes, reconcile) must not invalidate an
+-- open editor
+DROP TRIGGER IF EXISTS meetings_bump_version;
+CREATE TRIGGER meetings_bump_version
+AFTER UPDATE OF title, updated_at ON meetings
+WHEN NEW.version = OLD.version
+BEGIN
+    UPDATE meetings SET version = OLD.version + 1 WHERE id = NEW.id;
+END;
@@ -0,0 +1,7 @@
+-- Migration: Record where an imported recording was downloaded from
+-- A Zoom/Teams recording or a link imported again is recognized before it
+-- is downloaded, not only after its content was hashed
+
+ALTER TABLE import_fingerprints ADD COLUMN origin TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_import_fingerprints_origin ON import_fingerprints(origin);
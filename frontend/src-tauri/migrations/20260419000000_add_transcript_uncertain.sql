@@ -0,0 +1,6 @@
+-- Migration: Flag transcript segments that may be hallucinated
+-- Whisper segments the hallucination filter doubted (uncertain text, a
+-- repetition loop, text over audio judged to hold no speech) are kept but
+-- flagged, so the transcript view can mark them for review
+
+ALTER TABLE transcripts ADD COLUMN uncertain INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,144 @@
+use chrono::{Duration, Local, Utc};
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Runtime};
+
+use crate::{
+    audit::{self, AuditAction},
+    database::{
+        models::ActionItem,
+        repositories::action_item::{ActionItemStatus, ActionItemsRepository},
+    },
+    error::AppError,
+    notifications::reminders::{due_reminders, DueReminder, MAX_SNOOZE_MINUTES},
+    state::AppState,
+};
+
+/// Snooze length when none is given
+const DEFAULT_SNOOZE_MINUTES: u32 = 60;
+
+#[tauri::command]
+pub async fn api_list_action_items<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<ActionItem>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_list_action_items called for meeting_id: {}", meeting_id);
+
+    ActionItemsRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list action items for {}: {}", meeting_id, e);
+            format!("Failed to list action items: {}", e)
+        })
+}
+
+/// Marks an action item "open" or "done"
+#[tauri::command]
+pub async fn api_set_action_item_status<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    item_id: String,
+    status: String,
+) -> Result<serde_json::Value, AppError> {
+    crate::validation::item_id(&item_id)?;
+    let status: ActionItemStatus = status.parse().map_err(AppError::InvalidInput)?;
+    log_info!(
+        "api_set_action_item_status called for item_id: {}, status: {}",
+        item_id,
+        status.as_str()
+    );
+
+    let pool = state.db_manager.pool();
+    match ActionItemsRepository::update_status(pool, &item_id, status).await {
+        Ok(true) => {
+            audit::record(
+                pool,
+                AuditAction::ActionItemUpdate,
+                "action_item",
+                Some(&item_id),
+                serde_json::json!({ "status": status.as_str() }),
+            )
+            .await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "message": "Action item updated"
+            }))
+        }
+        Ok(false) => Err(AppError::NotFound(format!(
+            "Action item not found: {}",
+            item_id
+        ))),
+        Err(e) => {
+            log_error!("Failed to update action item {}: {}", item_id, e);
+            Err(AppError::from(e).context("Failed to update action item"))
+        }
+    }
+}
+
+/// Open action items due by tomorrow (including overdue ones) that aren't
+/// snoozed, most urgent first
+#[tauri::command]
+pub async fn api_list_due_action_items<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<DueReminder>, String> {
+    due_reminders(
+        state.db_manager.pool(),
+        Local::now().date_naive(),
+        Utc::now(),
+        false,
+    )
+    .await
+    .map_err(|e| {
+        log_error!("Failed to list due action items: {}", e);
+        e
+    })
+}
+
+/// Holds an action item's due-date reminder back for `minutes` (default 60)
+#[tauri::command]
+pub async fn api_snooze_action_item<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    item_id: String,
+    minutes: Option<u32>,
+) -> Result<serde_json::Value, String> {
+    crate::validation::item_id(&item_id)?;
+    let minutes = minutes.unwrap_or(DEFAULT_SNOOZE_MINUTES);
+    log_info!(
+        "api_snooze_action_item called for item_id: {}, minutes: {}",
+        item_id,
+        minutes
+    );
+    if minutes == 0 || minutes > MAX_SNOOZE_MINUTES {
+        return Err(format!(
+            "Snooze for between 1 and {} minutes",
+            MAX_SNOOZE_MINUTES
+        ));
+    }
+
+    let until = Utc::now() + Duration::minutes(minutes as i64);
+    match ActionItemsRepository::snooze(state.db_manager.pool(), &item_id, until).await {
+        Ok(true) => Ok(serde_json::json!({
+            "status": "success",
+            "message": "Reminder snoozed",
+            "snoozed_until": until
+        })),
+        Ok(false) => Err(format!("Action item not found: {}", item_id)),
+        Err(e) => {
+            log_error!("Failed to snooze action item {}: {}", item_id, e);
+            Err(format!("Failed to snooze action item: {}", e))
+        }
+    }
+}
+
+/// Marks an action item done from its reminder
+#[tauri::command]
+pub async fn api_complete_action_item<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    item_id: String,
+) -> Result<serde_json::Value, AppError> {
+    api_set_action_item_status(app, state, item_id, "done".to_string()).await
+}
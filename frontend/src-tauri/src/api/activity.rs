@@ -0,0 +1,87 @@
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Runtime};
+
+use crate::{
+    database::repositories::activity::{MeetingActivityRepository, PinnedMeeting, RecentMeeting},
+    state::AppState,
+};
+
+const DEFAULT_RECENT_LIMIT: i64 = 10;
+const MAX_RECENT_LIMIT: i64 = 100;
+
+/// Records that the user opened a meeting
+#[tauri::command]
+pub async fn api_record_meeting_view<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<(), String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    MeetingActivityRepository::record_view(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to record view of {}: {}", meeting_id, e);
+            format!("Failed to record meeting view: {}", e)
+        })
+}
+
+#[tauri::command]
+pub async fn api_get_recent_meetings<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    limit: Option<i64>,
+) -> Result<Vec<RecentMeeting>, String> {
+    log_info!("api_get_recent_meetings called with limit: {:?}", limit);
+
+    let limit = limit.unwrap_or(DEFAULT_RECENT_LIMIT).clamp(1, MAX_RECENT_LIMIT);
+    MeetingActivityRepository::recently_viewed(state.db_manager.pool(), limit)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to get recent meetings: {}", e);
+            format!("Failed to get recent meetings: {}", e)
+        })
+}
+
+/// Pins a meeting to the top of the meetings list, or unpins it
+#[tauri::command]
+pub async fn api_set_meeting_pinned<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    pinned: bool,
+) -> Result<serde_json::Value, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_set_meeting_pinned called for meeting_id: {}, pinned: {}",
+        meeting_id,
+        pinned
+    );
+
+    match MeetingActivityRepository::set_pinned(state.db_manager.pool(), &meeting_id, pinned).await
+    {
+        Ok(true) => Ok(serde_json::json!({
+            "status": "success",
+            "pinned": pinned
+        })),
+        Ok(false) => Err(format!("Meeting not found: {}", meeting_id)),
+        Err(e) => {
+            log_error!("Failed to update pin for {}: {}", meeting_id, e);
+            Err(format!("Failed to update pin: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn api_get_pinned_meetings<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<PinnedMeeting>, String> {
+    log_info!("api_get_pinned_meetings called");
+
+    MeetingActivityRepository::pinned_meetings(state.db_manager.pool())
+        .await
+        .map_err(|e| {
+            log_error!("Failed to get pinned meetings: {}", e);
+            format!("Failed to get pinned meetings: {}", e)
+        })
+}
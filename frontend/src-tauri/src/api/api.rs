@@ -5,20 +5,35 @@ use tauri::{AppHandle, Runtime};
 use tauri_plugin_store::StoreExt;
 
 use crate::{
+    api::debounce,
+    audio::{
+        corrections,
+        transcription::routing::{normalize_routes, LanguageRoute},
+    },
+    audit::{self, AuditAction},
     database::{
-        models::MeetingModel,
+        models::{JournalEntry, MeetingModel},
         repositories::{
-            meeting::MeetingsRepository, setting::SettingsRepository,
-            transcript::TranscriptsRepository,
+            activity::MeetingActivityRepository, custom_field::CustomFieldsRepository,
+            journal::JournalRepository, keyword_watch::KeywordWatchRepository,
+            meeting::MeetingsRepository,
+            setting::SettingsRepository, transcript::TranscriptsRepository,
         },
+        settings_cache::{config_changed, ConfigKind},
     },
+    error::AppError,
+    license,
+    locks::{folder_key, MeetingActivity},
     onboarding::load_onboarding_status,
     state::AppState,
     summary::CustomOpenAIConfig,
+    validation,
 };
 
 // Hardcoded server URL
-const APP_SERVER_URL: &str = "http://localhost:5167";
+pub(crate) const APP_SERVER_URL: &str = "http://localhost:5167";
+/// Store key of the team server address used for sharing
+pub(crate) const TEAM_SERVER_KEY: &str = "teamServerUrl";
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ApiResponse<T> {
@@ -31,6 +46,11 @@ pub struct ApiResponse<T> {
 pub struct Meeting {
     pub id: String,
     pub title: String,
+    /// Custom field values keyed by field name
+    #[serde(rename = "customFields", default)]
+    pub custom_fields: HashMap<String, String>,
+    #[serde(default)]
+    pub pinned: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -38,13 +58,19 @@ pub struct SearchRequest {
     pub query: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranscriptSearchResult {
     pub id: String,
     pub title: String,
     #[serde(rename = "matchContext")]
     pub match_context: String,
     pub timestamp: String,
+    /// Relevance of the match; higher is better
+    #[serde(default)]
+    pub score: f64,
+    /// [start, end) UTF-16 offsets of matched words within match_context
+    #[serde(rename = "matchOffsets", default)]
+    pub match_offsets: Vec<[usize; 2]>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -123,6 +149,8 @@ pub struct MeetingDetails {
     pub title: String,
     pub created_at: String,
     pub updated_at: String,
+    /// Passed back as `expected_version` when saving edits
+    pub version: i64,
     pub transcripts: Vec<MeetingTranscript>,
 }
 
@@ -138,6 +166,11 @@ pub struct MeetingTranscript {
     pub audio_end_time: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    /// Flagged as a possible hallucination
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub uncertain: bool,
 }
 
 /// Meeting metadata without transcripts (for pagination)
@@ -149,6 +182,8 @@ pub struct MeetingMetadata {
     pub updated_at: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub folder_path: Option<String>,
+    /// Passed back as `expected_version` when saving edits
+    pub version: i64,
 }
 
 /// Paginated transcripts response with total count
@@ -177,7 +212,7 @@ pub struct SaveTranscriptRequest {
     pub transcripts: Vec<TranscriptSegment>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranscriptSegment {
     pub id: String,
     pub text: String,
@@ -189,6 +224,12 @@ pub struct TranscriptSegment {
     pub audio_end_time: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<f64>,
+    /// Diarization label, e.g. "Speaker 1"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    /// Kept, but flagged as possibly not what was said (a likely hallucination)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub uncertain: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -202,6 +243,9 @@ pub struct Profile {
     pub created_at: String,
     pub updated_at: String,
     pub is_licensed: bool,
+    /// Signed token for offline validation, cached by the app
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offline_token: Option<String>,
 }
 
 // Helper function to get auth token from store (optional)
@@ -236,8 +280,19 @@ async fn get_server_address<R: Runtime>(_app: &AppHandle<R>) -> Result<String, S
     Ok(APP_SERVER_URL.to_string())
 }
 
+/// The team server meetings are shared through. Unlike the local backend
+/// it has to be reachable by teammates, so the user sets its address.
+pub(crate) fn team_server_address<R: Runtime>(app: &AppHandle<R>) -> Result<String, String> {
+    app.store("store.json")
+        .ok()
+        .and_then(|store| store.get(TEAM_SERVER_KEY))
+        .and_then(|value| value.as_str().map(|url| url.trim_end_matches('/').to_string()))
+        .filter(|url| !url.is_empty())
+        .ok_or_else(|| "Set the team server address in Settings to share meetings".to_string())
+}
+
 // Generic API call function with optional authentication
-async fn make_api_request<R: Runtime, T: for<'de> Deserialize<'de>>(
+pub(crate) async fn make_api_request<R: Runtime, T: for<'de> Deserialize<'de>>(
     app: &AppHandle<R>,
     endpoint: &str,
     method: &str,
@@ -245,9 +300,31 @@ async fn make_api_request<R: Runtime, T: for<'de> Deserialize<'de>>(
     additional_headers: Option<HashMap<String, String>>,
     auth_token: Option<String>, // Pass auth token from frontend
 ) -> Result<T, String> {
-    let client = reqwest::Client::new();
     let server_url = get_server_address(app).await?;
+    request_json(&server_url, endpoint, method, body, additional_headers, auth_token).await
+}
 
+/// `make_api_request` against the team server
+pub(crate) async fn make_team_request<R: Runtime, T: for<'de> Deserialize<'de>>(
+    app: &AppHandle<R>,
+    endpoint: &str,
+    method: &str,
+    body: Option<&str>,
+    auth_token: Option<String>,
+) -> Result<T, String> {
+    let server_url = team_server_address(app)?;
+    request_json(&server_url, endpoint, method, body, None, auth_token).await
+}
+
+async fn request_json<T: for<'de> Deserialize<'de>>(
+    server_url: &str,
+    endpoint: &str,
+    method: &str,
+    body: Option<&str>,
+    additional_headers: Option<HashMap<String, String>>,
+    auth_token: Option<String>,
+) -> Result<T, String> {
+    let client = reqwest::Client::new();
     let url = format!("{}{}", server_url, endpoint);
     log_info!("Making {} request to: {}", method, url);
 
@@ -317,6 +394,17 @@ async fn make_api_request<R: Runtime, T: for<'de> Deserialize<'de>>(
     })
 }
 
+/// The command error for a failed `make_api_request`, by how it failed
+fn api_request_error(error: String) -> AppError {
+    if license::is_unreachable(&error) {
+        AppError::Network(error)
+    } else if license::is_rejection(&error) {
+        AppError::Rejected(error)
+    } else {
+        AppError::Internal(error)
+    }
+}
+
 // API Commands for Tauri
 
 #[tauri::command]
@@ -324,7 +412,7 @@ pub async fn api_get_meetings<R: Runtime>(
     _app: AppHandle<R>,
     state: tauri::State<'_, AppState>,
     auth_token: Option<String>,
-) -> Result<Vec<Meeting>, String> {
+) -> Result<Vec<Meeting>, AppError> {
     log_info!(
         "api_get_meetings called with auth_token(native) : {}",
         auth_token.is_some()
@@ -337,18 +425,42 @@ pub async fn api_get_meetings<R: Runtime>(
         Ok(meeting_models) => {
             log_info!("Successfully got {} meetings", meeting_models.len());
 
-            let result: Vec<Meeting> = meeting_models
+            let mut custom_fields: HashMap<String, HashMap<String, String>> = HashMap::new();
+            match CustomFieldsRepository::list_all_values(pool).await {
+                Ok(values) => {
+                    for value in values {
+                        custom_fields
+                            .entry(value.meeting_id)
+                            .or_default()
+                            .insert(value.name, value.value);
+                    }
+                }
+                Err(e) => log_warn!("Failed to load custom fields for meetings list: {}", e),
+            }
+
+            let pinned = MeetingActivityRepository::pinned_ids(pool)
+                .await
+                .unwrap_or_else(|e| {
+                    log_warn!("Failed to load pinned meetings: {}", e);
+                    Default::default()
+                });
+
+            let mut result: Vec<Meeting> = meeting_models
                 .into_iter()
                 .map(|m| Meeting {
+                    custom_fields: custom_fields.remove(&m.id).unwrap_or_default(),
+                    pinned: pinned.contains(&m.id),
                     id: m.id,
                     title: m.title,
                 })
                 .collect();
+            // Pinned meetings first; the sort is stable so both groups stay newest first
+            result.sort_by_key(|m| !m.pinned);
             Ok(result)
         }
         Err(e) => {
             log_error!("Error getting meetings: {}", e);
-            Err(e.to_string())
+            Err(e.into())
         }
     }
 }
@@ -359,7 +471,7 @@ pub async fn api_search_transcripts<R: Runtime>(
     state: tauri::State<'_, AppState>,
     query: String,
     auth_token: Option<String>,
-) -> Result<Vec<TranscriptSearchResult>, String> {
+) -> Result<Vec<TranscriptSearchResult>, AppError> {
     log_info!(
         "api_search_transcripts called with query: '{}', auth_token: {}",
         query,
@@ -378,29 +490,66 @@ pub async fn api_search_transcripts<R: Runtime>(
         }
         Err(e) => {
             log_error!("Error searching transcripts for query '{}': {}", query, e);
-            Err(format!("Failed to search transcripts: {}", e))
+            Err(AppError::from(e).context("Failed to search transcripts"))
         }
     }
 }
 
+/// Validates the license with the server. When the server can't be reached,
+/// falls back to the cached offline license token until its grace period ends.
 #[tauri::command]
 pub async fn api_get_profile<R: Runtime>(
     app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
     email: String,
     license_key: String,
     auth_token: Option<String>,
-) -> Result<Profile, String> {
+) -> Result<Profile, AppError> {
     log_info!(
         "api_get_profile called for email: {}, auth_token: {}",
         email,
         auth_token.is_some()
     );
+    let pool = state.db_manager.pool();
 
-    let profile_request = ProfileRequest { email, license_key };
+    let profile_request = ProfileRequest {
+        email: email.clone(),
+        license_key: license_key.clone(),
+    };
     let body = serde_json::to_string(&profile_request).map_err(|e| e.to_string())?;
 
-    make_api_request::<R, Profile>(&app, "/get-profile", "POST", Some(&body), None, auth_token)
+    match make_api_request::<R, Profile>(&app, "/get-profile", "POST", Some(&body), None, auth_token)
         .await
+    {
+        Ok(mut profile) => {
+            if let Some(token) = profile.offline_token.take() {
+                if let Err(e) = license::store_token(pool, &token, &profile).await {
+                    log_warn!("Not caching offline license token: {}", e);
+                }
+            }
+            Ok(profile)
+        }
+        Err(e) if license::is_unreachable(&e) => {
+            match license::profile_offline(pool, &email, &license_key).await {
+                Ok(profile) => {
+                    log_info!("License server unreachable; using the offline license");
+                    Ok(profile)
+                }
+                Err(offline) => {
+                    log_warn!("Offline license not usable: {}", offline);
+                    Err(api_request_error(e))
+                }
+            }
+        }
+        Err(e) => {
+            if license::is_rejection(&e) {
+                if let Err(clear) = license::clear_cache(pool).await {
+                    log_warn!("Failed to clear offline license: {}", clear);
+                }
+            }
+            Err(api_request_error(e))
+        }
+    }
 }
 
 #[tauri::command]
@@ -409,7 +558,7 @@ pub async fn api_save_profile<R: Runtime>(
     id: String,
     email: String,
     auth_token: Option<String>,
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, AppError> {
     log_info!(
         "api_save_profile called for email: {}, auth_token: {}",
         email,
@@ -428,6 +577,7 @@ pub async fn api_save_profile<R: Runtime>(
         auth_token,
     )
     .await
+    .map_err(api_request_error)
 }
 
 #[tauri::command]
@@ -438,7 +588,7 @@ pub async fn api_update_profile<R: Runtime>(
     company: String,
     position: String,
     auth_token: Option<String>,
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, AppError> {
     log_info!(
         "api_update_profile called for email: {}, auth_token: {}",
         email,
@@ -462,6 +612,7 @@ pub async fn api_update_profile<R: Runtime>(
         auth_token,
     )
     .await
+    .map_err(api_request_error)
 }
 
 #[tauri::command]
@@ -469,11 +620,12 @@ pub async fn api_get_model_config<R: Runtime>(
     app: AppHandle<R>,
     state: tauri::State<'_, AppState>,
     _auth_token: Option<String>,
-) -> Result<Option<ModelConfig>, String> {
+) -> Result<Option<ModelConfig>, AppError> {
     log_info!("api_get_model_config called (native)");
     let pool = state.db_manager.pool();
+    let cache = &state.settings_cache;
 
-    match SettingsRepository::get_model_config(pool).await {
+    match cache.model_config(pool).await {
         Ok(Some(config)) => {
             log_info!(
                 "✅ Found model config in database: provider={}, model={}, whisperModel={}, ollamaEndpoint={:?}",
@@ -482,7 +634,7 @@ pub async fn api_get_model_config<R: Runtime>(
                 &config.whisper_model,
                 &config.ollama_endpoint
             );
-            match SettingsRepository::get_api_key(pool, &config.provider).await {
+            match cache.api_key(pool, &config.provider).await {
                 Ok(api_key) => {
                     log_info!("Successfully retrieved model config and API key.");
                     Ok(Some(ModelConfig {
@@ -499,7 +651,7 @@ pub async fn api_get_model_config<R: Runtime>(
                         &config.provider,
                         e
                     );
-                    Err(e.to_string())
+                    Err(e.into())
                 }
             }
         }
@@ -509,14 +661,14 @@ pub async fn api_get_model_config<R: Runtime>(
         }
         Err(e) => {
             log_error!("❌ Failed to get model config from database: {}", e);
-            Err(e.to_string())
+            Err(e.into())
         }
     }
 }
 
 #[tauri::command]
 pub async fn api_save_model_config<R: Runtime>(
-    _app: AppHandle<R>,
+    app: AppHandle<R>,
     state: tauri::State<'_, AppState>,
     provider: String,
     model: String,
@@ -524,7 +676,7 @@ pub async fn api_save_model_config<R: Runtime>(
     api_key: Option<String>,
     ollama_endpoint: Option<String>,
     _auth_token: Option<String>,
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, AppError> {
     log_info!(
         "💾 api_save_model_config called (native): provider='{}', model='{}', whisperModel='{}', ollamaEndpoint={:?}",
         &provider,
@@ -534,26 +686,29 @@ pub async fn api_save_model_config<R: Runtime>(
     );
     let pool = state.db_manager.pool();
 
-    if let Err(e) = SettingsRepository::save_model_config(
+    let result = SettingsRepository::save_model_config(
         pool,
         &provider,
         &model,
         &whisper_model,
         ollama_endpoint.as_deref(),
     )
-    .await
-    {
+    .await;
+    config_changed(&app, &state.settings_cache, ConfigKind::Model);
+    if let Err(e) = result {
         log_error!("❌ Failed to save model config to database: {}", e);
-        return Err(e.to_string());
+        return Err(e.into());
     }
 
     // Skip API key saving for custom-openai provider (it uses customOpenAIConfig JSON instead)
     if let Some(key) = api_key {
         if !key.is_empty() && provider != "custom-openai" {
             log_info!("🔑 API key provided, saving...");
-            if let Err(e) = SettingsRepository::save_api_key(pool, &provider, &key).await {
+            let result = SettingsRepository::save_api_key(pool, &provider, &key).await;
+            config_changed(&app, &state.settings_cache, ConfigKind::Model);
+            if let Err(e) = result {
                 log_error!("❌ Failed to save API key: {}", e);
-                return Err(e.to_string());
+                return Err(e.into());
             }
         }
     }
@@ -565,6 +720,14 @@ pub async fn api_save_model_config<R: Runtime>(
         log_warn!("Failed to initiate graceful sidecar shutdown: {}", e);
     }
 
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "model_config",
+        None,
+        serde_json::json!({ "provider": provider, "model": model }),
+    )
+    .await;
     log_info!("✅ Successfully saved model configuration to database");
     Ok(
         serde_json::json!({ "status": "success", "message": "Model configuration saved successfully" }),
@@ -577,12 +740,16 @@ pub async fn api_get_api_key<R: Runtime>(
     state: tauri::State<'_, AppState>,
     provider: String,
     _auth_token: Option<String>,
-) -> Result<String, String> {
+) -> Result<String, AppError> {
     log_info!(
         "api_get_api_key called (native) for provider '{}'",
         &provider
     );
-    match SettingsRepository::get_api_key(&state.db_manager.pool(), &provider).await {
+    match state
+        .settings_cache
+        .api_key(state.db_manager.pool(), &provider)
+        .await
+    {
         Ok(key) => {
             log_info!(
                 "Successfully retrieved API key for provider '{}'.",
@@ -592,7 +759,7 @@ pub async fn api_get_api_key<R: Runtime>(
         }
         Err(e) => {
             log_error!("Failed to get API key for provider '{}': {}", &provider, e);
-            Err(e.to_string())
+            Err(e.into())
         }
     }
 }
@@ -602,18 +769,19 @@ pub async fn api_get_transcript_config<R: Runtime>(
     _app: AppHandle<R>,
     state: tauri::State<'_, AppState>,
     _auth_token: Option<String>,
-) -> Result<Option<TranscriptConfig>, String> {
+) -> Result<Option<TranscriptConfig>, AppError> {
     log_info!("api_get_transcript_config called (native)");
     let pool = state.db_manager.pool();
+    let cache = &state.settings_cache;
 
-    match SettingsRepository::get_transcript_config(pool).await {
+    match cache.transcript_config(pool).await {
         Ok(Some(config)) => {
             log_info!(
                 "Found transcript config: provider={}, model={}",
                 &config.provider,
                 &config.model
             );
-            match SettingsRepository::get_transcript_api_key(pool, &config.provider).await {
+            match cache.transcript_api_key(pool, &config.provider).await {
                 Ok(api_key) => {
                     log_info!("Successfully retrieved transcript config and API key.");
                     Ok(Some(TranscriptConfig {
@@ -628,7 +796,7 @@ pub async fn api_get_transcript_config<R: Runtime>(
                         &config.provider,
                         e
                     );
-                    Err(e.to_string())
+                    Err(e.into())
                 }
             }
         }
@@ -642,42 +810,53 @@ pub async fn api_get_transcript_config<R: Runtime>(
         }
         Err(e) => {
             log_error!("Failed to get transcript config: {}", e);
-            Err(e.to_string())
+            Err(e.into())
         }
     }
 }
 
 #[tauri::command]
 pub async fn api_save_transcript_config<R: Runtime>(
-    _app: AppHandle<R>,
+    app: AppHandle<R>,
     state: tauri::State<'_, AppState>,
     provider: String,
     model: String,
     api_key: Option<String>,
     _auth_token: Option<String>,
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, AppError> {
     log_info!(
         "api_save_transcript_config called (native) for provider '{}'",
         &provider
     );
     let pool = state.db_manager.pool();
 
-    if let Err(e) = SettingsRepository::save_transcript_config(pool, &provider, &model).await {
+    let result = SettingsRepository::save_transcript_config(pool, &provider, &model).await;
+    config_changed(&app, &state.settings_cache, ConfigKind::Transcript);
+    if let Err(e) = result {
         log_error!("Failed to save transcript config: {}", e);
-        return Err(e.to_string());
+        return Err(e.into());
     }
 
     if let Some(key) = api_key {
         if !key.is_empty() {
             log_info!("API key provided, saving for transcript provider...");
-            if let Err(e) = SettingsRepository::save_transcript_api_key(pool, &provider, &key).await
-            {
+            let result = SettingsRepository::save_transcript_api_key(pool, &provider, &key).await;
+            config_changed(&app, &state.settings_cache, ConfigKind::Transcript);
+            if let Err(e) = result {
                 log_error!("Failed to save transcript API key: {}", e);
-                return Err(e.to_string());
+                return Err(e.into());
             }
         }
     }
 
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "transcript_config",
+        None,
+        serde_json::json!({ "provider": provider, "model": model }),
+    )
+    .await;
     log_info!("Successfully saved transcript configuration.");
     Ok(
         serde_json::json!({ "status": "success", "message": "Transcript configuration saved successfully" }),
@@ -690,12 +869,16 @@ pub async fn api_get_transcript_api_key<R: Runtime>(
     state: tauri::State<'_, AppState>,
     provider: String,
     _auth_token: Option<String>,
-) -> Result<String, String> {
+) -> Result<String, AppError> {
     log_info!(
         "api_get_transcript_api_key called (native) for provider '{}'",
         &provider
     );
-    match SettingsRepository::get_transcript_api_key(&state.db_manager.pool(), &provider).await {
+    match state
+        .settings_cache
+        .transcript_api_key(state.db_manager.pool(), &provider)
+        .await
+    {
         Ok(key) => {
             log_info!(
                 "Successfully retrieved transcript API key for provider '{}'.",
@@ -709,25 +892,134 @@ pub async fn api_get_transcript_api_key<R: Runtime>(
                 &provider,
                 e
             );
-            Err(e.to_string())
+            Err(e.into())
         }
     }
 }
 
 #[tauri::command]
-pub async fn api_delete_api_key<R: Runtime>(
+pub async fn api_get_transcript_language_routes<R: Runtime>(
     _app: AppHandle<R>,
     state: tauri::State<'_, AppState>,
+) -> Result<Vec<LanguageRoute>, AppError> {
+    log_info!("api_get_transcript_language_routes called (native)");
+    match state
+        .settings_cache
+        .transcript_config(state.db_manager.pool())
+        .await
+    {
+        Ok(setting) => Ok(setting
+            .map(|setting| setting.get_language_routes())
+            .unwrap_or_default()),
+        Err(e) => {
+            log_error!("Failed to get transcript language routes: {}", e);
+            Err(e.into())
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn api_save_transcript_language_routes<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    routes: Vec<LanguageRoute>,
+) -> Result<Vec<LanguageRoute>, AppError> {
+    log_info!(
+        "api_save_transcript_language_routes called (native) with {} routes",
+        routes.len()
+    );
+    let routes = normalize_routes(routes).map_err(AppError::InvalidInput)?;
+    let pool = state.db_manager.pool();
+    let routes_json = serde_json::to_string(&routes).map_err(|e| e.to_string())?;
+
+    let result = SettingsRepository::save_transcript_language_routes(pool, &routes_json).await;
+    config_changed(&app, &state.settings_cache, ConfigKind::Transcript);
+    if let Err(e) = result {
+        log_error!("Failed to save transcript language routes: {}", e);
+        return Err(e.into());
+    }
+
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "transcript_language_routes",
+        None,
+        serde_json::json!({ "routes": routes }),
+    )
+    .await;
+    Ok(routes)
+}
+
+#[tauri::command]
+pub async fn api_get_summary_language<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Option<String>, AppError> {
+    log_info!("api_get_summary_language called (native)");
+    SettingsRepository::get_summary_language(state.db_manager.pool())
+        .await
+        .map_err(|e| {
+            log_error!("Failed to get summary language: {}", e);
+            e.into()
+        })
+}
+
+/// Saves the language summaries are written in; None or "auto" keeps the
+/// transcript's language
+#[tauri::command]
+pub async fn api_save_summary_language<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    language: Option<String>,
+) -> Result<(), AppError> {
+    log_info!(
+        "api_save_summary_language called (native) with {:?}",
+        language
+    );
+    let language = language
+        .map(|language| language.trim().to_string())
+        .filter(|language| !language.is_empty() && language != "auto");
+    let pool = state.db_manager.pool();
+    if let Err(e) = SettingsRepository::save_summary_language(pool, language.as_deref()).await {
+        log_error!("Failed to save summary language: {}", e);
+        return Err(e.into());
+    }
+
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "summary_language",
+        None,
+        serde_json::json!({ "language": language }),
+    )
+    .await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn api_delete_api_key<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
     provider: String,
     _auth_token: Option<String>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     log_info!(
         "log_api_delete_api_key called (native) for provider '{}'",
         &provider
     );
-    match SettingsRepository::delete_api_key(&state.db_manager.pool(), &provider).await {
+    let result = SettingsRepository::delete_api_key(&state.db_manager.pool(), &provider).await;
+    config_changed(&app, &state.settings_cache, ConfigKind::Model);
+    match result {
         Ok(_) => {
             log_info!("Successfully deleted API key for provider '{}'.", &provider);
+            audit::record(
+                state.db_manager.pool(),
+                AuditAction::ConfigChange,
+                "api_key",
+                Some(&provider),
+                serde_json::json!({ "deleted": true }),
+            )
+            .await;
             Ok(())
         }
         Err(e) => {
@@ -736,7 +1028,7 @@ pub async fn api_delete_api_key<R: Runtime>(
                 &provider,
                 e
             );
-            Err(e.to_string())
+            Err(e.into())
         }
     }
 }
@@ -747,7 +1039,8 @@ pub async fn api_delete_meeting<R: Runtime>(
     state: tauri::State<'_, AppState>,
     meeting_id: String,
     auth_token: Option<String>,
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, AppError> {
+    validation::meeting_id(&meeting_id)?;
     log_info!(
         "api_delete_meeting called for meeting_id(native): {}, auth_token: {}",
         meeting_id,
@@ -759,6 +1052,14 @@ pub async fn api_delete_meeting<R: Runtime>(
     match MeetingsRepository::delete_meeting(pool, &meeting_id).await {
         Ok(true) => {
             log_info!("Successfully deleted meeting {}", meeting_id);
+            audit::record(
+                pool,
+                AuditAction::MeetingDelete,
+                "meeting",
+                Some(&meeting_id),
+                serde_json::Value::Null,
+            )
+            .await;
             Ok(serde_json::json!({
                 "status": "success",
                 "message": "Meeting deleted successfully"
@@ -766,14 +1067,307 @@ pub async fn api_delete_meeting<R: Runtime>(
         }
         Ok(false) => {
             log_warn!("Meeting not found or already deleted: {}", meeting_id);
-            Err(format!(
+            Err(AppError::NotFound(format!(
                 "Meeting not found or could not be deleted: {}",
                 meeting_id
-            ))
+            )))
         }
         Err(e) => {
             log_error!("Error deleting meeting {}: {}", meeting_id, e);
-            Err(format!("Failed to delete meeting: {}", e))
+            Err(AppError::from(e).context("Failed to delete meeting"))
+        }
+    }
+}
+
+fn copy_dir_all(src: &std::path::Path, dst: &std::path::Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(dst)?;
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        let target = dst.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_all(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Creates an editable copy of a meeting with its transcripts, notes,
+/// participants and speaker names. With `include_audio` the recording folder
+/// is copied as well; otherwise the copy has no folder.
+#[tauri::command]
+pub async fn api_duplicate_meeting<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    include_audio: Option<bool>,
+) -> Result<serde_json::Value, AppError> {
+    validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_duplicate_meeting called for meeting_id: {}, include_audio: {:?}",
+        meeting_id,
+        include_audio
+    );
+    let pool = state.db_manager.pool();
+    // Held until the copy exists, so the source can't change halfway through
+    let _lock = state
+        .meeting_locks
+        .try_acquire(meeting_id.as_str(), MeetingActivity::Duplication)?;
+
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, &meeting_id)
+        .await
+        .map_err(|e| AppError::from(e).context("Failed to load meeting"))?
+        .ok_or_else(|| AppError::NotFound(format!("Meeting not found: {}", meeting_id)))?;
+    let new_title = format!("{} (copy)", meeting.title);
+
+    let mut copied_folder = None;
+    if include_audio.unwrap_or(false) {
+        let source = meeting
+            .folder_path
+            .as_deref()
+            .map(std::path::PathBuf::from)
+            .filter(|p| p.is_dir())
+            .ok_or_else(|| {
+                AppError::NotFound("This meeting has no recording folder to copy".to_string())
+            })?;
+        let _folder_lock = state
+            .meeting_locks
+            .try_acquire(folder_key(&source), MeetingActivity::Duplication)?;
+        let base_name = source
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "meeting".to_string());
+        let parent = source.parent().unwrap_or(&source);
+        let target = (1..)
+            .map(|n| match n {
+                1 => parent.join(format!("{}_copy", base_name)),
+                n => parent.join(format!("{}_copy{}", base_name, n)),
+            })
+            .find(|p| !p.exists())
+            .expect("unbounded range always yields a free name");
+        let copy_target = target.clone();
+        tokio::task::spawn_blocking(move || {
+            copy_dir_all(&source, &copy_target).map_err(|e| {
+                let _ = std::fs::remove_dir_all(&copy_target);
+                e
+            })
+        })
+        .await
+        .map_err(|e| AppError::Internal(format!("Recording folder copy task failed: {}", e)))?
+        .map_err(|e| AppError::from(e).context("Failed to copy recording folder"))?;
+        copied_folder = Some(target);
+    }
+
+    let folder_path = copied_folder
+        .as_ref()
+        .map(|p| p.to_string_lossy().to_string());
+    let result =
+        MeetingsRepository::duplicate_meeting(pool, &meeting_id, &new_title, folder_path).await;
+    if !matches!(result, Ok(Some(_))) {
+        // Don't leave a copied folder behind without a meeting row
+        if let Some(folder) = &copied_folder {
+            let _ = std::fs::remove_dir_all(folder);
+        }
+    }
+
+    match result {
+        Ok(Some(new_id)) => {
+            log_info!("Duplicated meeting {} as {}", meeting_id, new_id);
+            audit::record(
+                pool,
+                AuditAction::MeetingDuplicate,
+                "meeting",
+                Some(&new_id),
+                serde_json::json!({ "source_meeting_id": meeting_id, "include_audio": copied_folder.is_some() }),
+            )
+            .await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "meeting_id": new_id,
+                "title": new_title,
+                "folder_path": copied_folder,
+            }))
+        }
+        Ok(None) => Err(AppError::NotFound(format!("Meeting not found: {}", meeting_id))),
+        Err(e) => {
+            log_error!("Failed to duplicate meeting {}: {}", meeting_id, e);
+            Err(AppError::from(e).context("Failed to duplicate meeting"))
+        }
+    }
+}
+
+/// Deletes a single transcript segment (undoable with `api_undo_last_operation`)
+#[tauri::command]
+pub async fn api_delete_transcript_segment<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    transcript_id: String,
+) -> Result<serde_json::Value, AppError> {
+    validation::id("transcript_id", &transcript_id)?;
+    log_info!("api_delete_transcript_segment called for {}", transcript_id);
+    let pool = state.db_manager.pool();
+    match TranscriptsRepository::delete_segment(pool, &transcript_id).await {
+        Ok(Some(meeting_id)) => {
+            audit::record(
+                pool,
+                AuditAction::SegmentDelete,
+                "transcript",
+                Some(&transcript_id),
+                serde_json::json!({ "meeting_id": meeting_id }),
+            )
+            .await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "meeting_id": meeting_id,
+            }))
+        }
+        Ok(None) => Err(AppError::NotFound(format!(
+            "Transcript segment not found: {}",
+            transcript_id
+        ))),
+        Err(e) => {
+            log_error!("Failed to delete transcript segment {}: {}", transcript_id, e);
+            Err(AppError::from(e).context("Failed to delete segment"))
+        }
+    }
+}
+
+/// Merges segments of one meeting into a single segment (undoable)
+#[tauri::command]
+pub async fn api_merge_transcript_segments<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    transcript_ids: Vec<String>,
+) -> Result<serde_json::Value, AppError> {
+    for transcript_id in &transcript_ids {
+        validation::id("transcript_id", transcript_id)?;
+    }
+    log_info!("api_merge_transcript_segments called for {:?}", transcript_ids);
+    let pool = state.db_manager.pool();
+    match TranscriptsRepository::merge_segments(pool, &transcript_ids).await {
+        Ok(merged_id) => {
+            audit::record(
+                pool,
+                AuditAction::SegmentMerge,
+                "transcript",
+                Some(&merged_id),
+                serde_json::json!({ "merged_ids": transcript_ids }),
+            )
+            .await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "transcript_id": merged_id,
+            }))
+        }
+        Err(e) => {
+            log_error!("Failed to merge transcript segments: {}", e);
+            Err(AppError::from(e).context("Failed to merge segments"))
+        }
+    }
+}
+
+/// Replaces the text of a transcript segment (undoable). The words changed
+/// are recorded so repeated corrections are applied to new transcriptions.
+#[tauri::command]
+pub async fn api_edit_transcript_segment<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    transcript_id: String,
+    text: String,
+) -> Result<serde_json::Value, AppError> {
+    validation::id("transcript_id", &transcript_id)?;
+    log_info!("api_edit_transcript_segment called for {}", transcript_id);
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(AppError::InvalidInput(
+            "Segment text is empty; delete the segment instead".to_string(),
+        ));
+    }
+    let pool = state.db_manager.pool();
+    let (_, segment) = TranscriptsRepository::get_segment(pool, &transcript_id)
+        .await
+        .map_err(|e| AppError::from(e).context("Failed to edit segment"))?
+        .ok_or_else(|| {
+            AppError::NotFound(format!("Transcript segment not found: {}", transcript_id))
+        })?;
+    if segment.text == text {
+        return Ok(serde_json::json!({ "status": "unchanged" }));
+    }
+
+    match TranscriptsRepository::replace_segment_text(
+        pool,
+        &transcript_id,
+        text,
+        false,
+        "edit_segment",
+        "Edit segment",
+        None,
+    )
+    .await
+    {
+        Ok(Some(meeting_id)) => {
+            audit::record(
+                pool,
+                AuditAction::SegmentEdit,
+                "transcript",
+                Some(&transcript_id),
+                serde_json::json!({ "meeting_id": meeting_id }),
+            )
+            .await;
+            corrections::learn(pool, &segment.text, text).await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "meeting_id": meeting_id,
+            }))
+        }
+        Ok(None) => Err(AppError::NotFound(format!(
+            "Transcript segment not found: {}",
+            transcript_id
+        ))),
+        Err(e) => {
+            log_error!("Failed to edit transcript segment {}: {}", transcript_id, e);
+            Err(AppError::from(e).context("Failed to edit segment"))
+        }
+    }
+}
+
+/// The operation that `api_undo_last_operation` would revert, if any
+#[tauri::command]
+pub async fn api_get_last_operation<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Option<JournalEntry>, AppError> {
+    JournalRepository::last_undoable(state.db_manager.pool())
+        .await
+        .map_err(|e| AppError::from(e).context("Failed to read operation journal"))
+}
+
+/// Reverts the most recent delete or merge made in this session
+#[tauri::command]
+pub async fn api_undo_last_operation<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<JournalEntry, AppError> {
+    log_info!("api_undo_last_operation called");
+    let pool = state.db_manager.pool();
+    match JournalRepository::undo_last(pool).await {
+        Ok(Some(entry)) => {
+            log_info!("Undid operation: {}", entry.description);
+            audit::record(
+                pool,
+                AuditAction::Undo,
+                "operation",
+                entry.meeting_id.as_deref(),
+                serde_json::json!({ "operation": entry.operation, "description": entry.description }),
+            )
+            .await;
+            Ok(entry)
+        }
+        Ok(None) => Err(AppError::NotFound("Nothing to undo".to_string())),
+        Err(e) => {
+            log_error!("Failed to undo last operation: {}", e);
+            Err(AppError::from(e).context("Failed to undo"))
         }
     }
 }
@@ -784,7 +1378,8 @@ pub async fn api_get_meeting<R: Runtime>(
     meeting_id: String,
     state: tauri::State<'_, AppState>,
     auth_token: Option<String>,
-) -> Result<MeetingDetails, String> {
+) -> Result<MeetingDetails, AppError> {
+    validation::meeting_id(&meeting_id)?;
     log_info!(
         "api_get_meeting called(native) for meeting_id: {}, auth_token: {}",
         meeting_id,
@@ -800,11 +1395,11 @@ pub async fn api_get_meeting<R: Runtime>(
         }
         Ok(None) => {
             log_warn!("Meeting not found: {}", meeting_id);
-            Err(format!("Meeting not found: {}", meeting_id))
+            Err(AppError::NotFound(format!("Meeting not found: {}", meeting_id)))
         }
         Err(e) => {
             log_error!("Error retrieving meeting {}: {}", meeting_id, e);
-            Err(format!("Failed to retrieve meeting: {}", e))
+            Err(AppError::from(e).context("Failed to retrieve meeting"))
         }
     }
 }
@@ -815,7 +1410,8 @@ pub async fn api_get_meeting_metadata<R: Runtime>(
     _app: AppHandle<R>,
     meeting_id: String,
     state: tauri::State<'_, AppState>,
-) -> Result<MeetingMetadata, String> {
+) -> Result<MeetingMetadata, AppError> {
+    validation::meeting_id(&meeting_id)?;
     log_info!("api_get_meeting_metadata called for meeting_id: {}", meeting_id);
 
     let pool = state.db_manager.pool();
@@ -823,21 +1419,26 @@ pub async fn api_get_meeting_metadata<R: Runtime>(
     match MeetingsRepository::get_meeting_metadata(pool, &meeting_id).await {
         Ok(Some(meeting)) => {
             log_info!("Successfully retrieved meeting metadata {}", meeting_id);
+            let version = MeetingsRepository::get_version(pool, &meeting_id)
+                .await
+                .map_err(|e| AppError::from(e).context("Failed to retrieve meeting metadata"))?
+                .unwrap_or(1);
             Ok(MeetingMetadata {
                 id: meeting.id,
                 title: meeting.title,
                 created_at: meeting.created_at.0.to_rfc3339(),
                 updated_at: meeting.updated_at.0.to_rfc3339(),
                 folder_path: meeting.folder_path,
+                version,
             })
         }
         Ok(None) => {
             log_warn!("Meeting not found: {}", meeting_id);
-            Err(format!("Meeting not found: {}", meeting_id))
+            Err(AppError::NotFound(format!("Meeting not found: {}", meeting_id)))
         }
         Err(e) => {
             log_error!("Error retrieving meeting metadata {}: {}", meeting_id, e);
-            Err(format!("Failed to retrieve meeting metadata: {}", e))
+            Err(AppError::from(e).context("Failed to retrieve meeting metadata"))
         }
     }
 }
@@ -850,7 +1451,9 @@ pub async fn api_get_meeting_transcripts<R: Runtime>(
     limit: i64,
     offset: i64,
     state: tauri::State<'_, AppState>,
-) -> Result<PaginatedTranscriptsResponse, String> {
+) -> Result<PaginatedTranscriptsResponse, AppError> {
+    validation::meeting_id(&meeting_id)?;
+    validation::page(limit, offset)?;
     log_info!(
         "api_get_meeting_transcripts called for meeting_id: {}, limit: {}, offset: {}",
         meeting_id,
@@ -879,6 +1482,8 @@ pub async fn api_get_meeting_transcripts<R: Runtime>(
                     audio_start_time: t.audio_start_time,
                     audio_end_time: t.audio_end_time,
                     duration: t.duration,
+                    speaker: t.speaker,
+                    uncertain: t.uncertain,
                 })
                 .collect::<Vec<_>>();
 
@@ -892,7 +1497,7 @@ pub async fn api_get_meeting_transcripts<R: Runtime>(
         }
         Err(e) => {
             log_error!("Error retrieving transcripts for meeting {}: {}", meeting_id, e);
-            Err(format!("Failed to retrieve transcripts: {}", e))
+            Err(AppError::from(e).context("Failed to retrieve transcripts"))
         }
     }
 }
@@ -903,39 +1508,75 @@ pub async fn api_save_meeting_title<R: Runtime>(
     state: tauri::State<'_, AppState>,
     meeting_id: String,
     title: String,
+    expected_version: Option<i64>,
     auth_token: Option<String>,
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, AppError> {
+    validation::meeting_id(&meeting_id)?;
+    let title = validation::title("title", &title)?;
     log_info!(
-        "api_save_meeting_title called for meeting_id: {}, auth_token: {}",
+        "api_save_meeting_title called for meeting_id: {}, expected_version: {:?}, auth_token: {}",
         meeting_id,
+        expected_version,
         auth_token.is_some()
     );
+    // Typing saves on every keystroke; only the last title of a burst is written
+    if !state
+        .save_debouncer
+        .settle(&format!("title:{}", meeting_id), debounce::TITLE_DEBOUNCE)
+        .await
+    {
+        log_debug!("Title save for {} superseded by a newer one", meeting_id);
+        return Ok(serde_json::json!({
+            "status": "coalesced",
+            "message": "Superseded by a newer title"
+        }));
+    }
     let pool = state.db_manager.pool();
-    match MeetingsRepository::update_meeting_title(pool, &meeting_id, &title).await {
-        Ok(true) => {
+    match MeetingsRepository::update_meeting_title(pool, &meeting_id, &title, expected_version).await
+    {
+        Ok(Some(version)) => {
             log_info!("Successfully saved meeting title");
-            Ok(serde_json::json!({"message": "Meeting title saved successfully"}))
+            audit::record(
+                pool,
+                AuditAction::MeetingRename,
+                "meeting",
+                Some(&meeting_id),
+                serde_json::json!({ "title": title }),
+            )
+            .await;
+            Ok(serde_json::json!({
+                "message": "Meeting title saved successfully",
+                "version": version
+            }))
         }
-        Ok(false) => {
+        Ok(None) => {
             log_error!("No meeting found with id {}", meeting_id);
-            Err(format!("No meeting found with id {}", meeting_id))
+            Err(AppError::NotFound(format!("No meeting found with id {}", meeting_id)))
         }
         Err(e) => {
             log_error!("Failed to update meeting {}", e);
-            Err(format!("Failed to update meeting: {}", e))
+            Err(AppError::from(e).context("Failed to update meeting"))
         }
     }
 }
 
 #[tauri::command]
 pub async fn api_save_transcript<R: Runtime>(
-    _app: AppHandle<R>,
+    app: AppHandle<R>,
     state: tauri::State<'_, AppState>,
     meeting_title: String,
     transcripts: Vec<serde_json::Value>,
     folder_path: Option<String>,
+    save_id: Option<String>,
     auth_token: Option<String>,
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, AppError> {
+    let meeting_title = validation::title("meeting_title", &meeting_title)?;
+    if let Some(folder) = folder_path.as_deref() {
+        validation::local_path("folder_path", folder)?;
+    }
+    if let Some(save_id) = save_id.as_deref() {
+        validation::id("save_id", save_id)?;
+    }
     log_info!(
         "api_save_transcript called for meeting: {}, transcripts: {}, folder_path: {:?}, auth_token: {}",
         meeting_title,
@@ -953,13 +1594,16 @@ pub async fn api_save_transcript<R: Runtime>(
     }
 
     // Convert serde_json::Value to TranscriptSegment
-    let transcripts_to_save: Vec<TranscriptSegment> = transcripts
+    let mut transcripts_to_save: Vec<TranscriptSegment> = transcripts
         .into_iter()
         .map(serde_json::from_value)
         .collect::<Result<Vec<_>, _>>()
         .map_err(|e| {
             log_error!("Failed to parse transcript segments: {}", e);
-            format!("Invalid transcript data format: {}. Please check the data structure.", e)
+            AppError::InvalidInput(format!(
+                "Invalid transcript data format: {}. Please check the data structure.",
+                e
+            ))
         })?;
 
     // Log parsed segments count and first segment details
@@ -971,37 +1615,93 @@ pub async fn api_save_transcript<R: Runtime>(
                    first_seg.duration);
     }
 
+    // Shutdown waits for this save before closing the database
+    let _operation = state
+        .shutdown
+        .begin_operation("save transcript")
+        .map_err(AppError::Unavailable)?;
     let pool = state.db_manager.pool();
 
-    // Now, call the repository with the correctly typed data.
-    match TranscriptsRepository::save_transcript(
-        pool,
-        &meeting_title,
-        &transcripts_to_save,
-        folder_path,
-    )
-    .await
-    {
-        Ok(meeting_id) => {
-            log_info!(
-                "Successfully saved transcript and created meeting with id: {}",
-                meeting_id
-            );
-            Ok(serde_json::json!({
-                "status": "success",
-                "message": "Transcript saved successfully",
-                "meeting_id": meeting_id
-            }))
+    // A repeated save of the same recording gets the meeting the first
+    // created. Recordings are told apart by folder or by the caller's save
+    // id, never by title; a save with neither is always written.
+    let save_key = folder_path
+        .as_deref()
+        .or(save_id.as_deref())
+        .map(|recording| format!("transcript:{}", recording));
+    let save = || async {
+        // Let enabled transcript processor plugins rewrite segment text before saving
+        match crate::plugins::commands::plugins_dir(&app) {
+            Ok(dir) => {
+                crate::plugins::process_transcript(pool, &dir, &mut transcripts_to_save).await
+            }
+            Err(e) => log_warn!("Skipping transcript plugins: {}", e),
         }
-        Err(e) => {
-            log_error!(
-                "Error saving transcript for meeting '{}': {}",
-                meeting_title,
-                e
-            );
-            Err(format!("Failed to save transcript: {}", e))
+
+        // Inserts go through the single transcript writer to avoid lock contention
+        let meeting_id = state
+            .db_manager
+            .save_transcript(
+                meeting_title.clone(),
+                transcripts_to_save,
+                folder_path.clone(),
+            )
+            .await
+            .map_err(|e| {
+                log_error!(
+                    "Error saving transcript for meeting '{}': {}",
+                    meeting_title,
+                    e
+                );
+                AppError::from(e).context("Failed to save transcript")
+            })?;
+        log_info!(
+            "Successfully saved transcript and created meeting with id: {}",
+            meeting_id
+        );
+        // Keyword hits flagged while recording belong to this meeting now
+        if let Some(folder) = folder_path.as_deref() {
+            if let Err(e) = KeywordWatchRepository::attach_hits(pool, folder, &meeting_id).await {
+                log_warn!("Failed to attach keyword hits to {}: {}", meeting_id, e);
+            }
         }
+        audit::record(
+            pool,
+            AuditAction::MeetingCreate,
+            "meeting",
+            Some(&meeting_id),
+            serde_json::json!({ "title": meeting_title }),
+        )
+        .await;
+        crate::hooks::fire(
+            pool.clone(),
+            crate::hooks::HookEvent::TranscriptSaved,
+            meeting_id.clone(),
+        );
+        Ok::<_, AppError>(meeting_id)
+    };
+    let (meeting_id, coalesced) = match save_key {
+        Some(key) => {
+            state
+                .save_debouncer
+                .once(&key, debounce::TRANSCRIPT_DEDUP_WINDOW, save)
+                .await?
+        }
+        None => (save().await?, false),
+    };
+    if coalesced {
+        log_info!(
+            "Transcript save repeated within {:?}; returning meeting {}",
+            debounce::TRANSCRIPT_DEDUP_WINDOW,
+            meeting_id
+        );
     }
+    Ok(serde_json::json!({
+        "status": "success",
+        "message": "Transcript saved successfully",
+        "meeting_id": meeting_id,
+        "coalesced": coalesced
+    }))
 }
 
 /// Opens the meeting's recording folder in the system file explorer
@@ -1010,7 +1710,8 @@ pub async fn open_meeting_folder<R: Runtime>(
     _app: AppHandle<R>,
     state: tauri::State<'_, AppState>,
     meeting_id: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
+    validation::meeting_id(&meeting_id)?;
     log_info!("open_meeting_folder called for meeting_id: {}", meeting_id);
 
     let pool = state.db_manager.pool();
@@ -1022,7 +1723,7 @@ pub async fn open_meeting_folder<R: Runtime>(
     .bind(&meeting_id)
     .fetch_optional(pool)
     .await
-    .map_err(|e| format!("Database error: {}", e))?;
+    .map_err(|e| AppError::from(e).context("Database error"))?;
 
     match meeting {
         Some(m) => {
@@ -1033,7 +1734,10 @@ pub async fn open_meeting_folder<R: Runtime>(
                 let path = std::path::Path::new(&folder_path);
                 if !path.exists() {
                     log_warn!("Folder path does not exist: {}", folder_path);
-                    return Err(format!("Recording folder not found: {}", folder_path));
+                    return Err(AppError::NotFound(format!(
+                        "Recording folder not found: {}",
+                        folder_path
+                    )));
                 }
 
                 // Open folder based on OS
@@ -1042,7 +1746,7 @@ pub async fn open_meeting_folder<R: Runtime>(
                     std::process::Command::new("open")
                         .arg(&folder_path)
                         .spawn()
-                        .map_err(|e| format!("Failed to open folder: {}", e))?;
+                        .map_err(|e| AppError::from(e).context("Failed to open folder"))?;
                 }
 
                 #[cfg(target_os = "windows")]
@@ -1050,7 +1754,7 @@ pub async fn open_meeting_folder<R: Runtime>(
                     std::process::Command::new("explorer")
                         .arg(&folder_path)
                         .spawn()
-                        .map_err(|e| format!("Failed to open folder: {}", e))?;
+                        .map_err(|e| AppError::from(e).context("Failed to open folder"))?;
                 }
 
                 #[cfg(target_os = "linux")]
@@ -1058,19 +1762,21 @@ pub async fn open_meeting_folder<R: Runtime>(
                     std::process::Command::new("xdg-open")
                         .arg(&folder_path)
                         .spawn()
-                        .map_err(|e| format!("Failed to open folder: {}", e))?;
+                        .map_err(|e| AppError::from(e).context("Failed to open folder"))?;
                 }
 
                 log_info!("Successfully opened folder: {}", folder_path);
                 Ok(())
             } else {
                 log_warn!("Meeting {} has no folder_path set", meeting_id);
-                Err("Recording folder path not available for this meeting".to_string())
+                Err(AppError::NotFound(
+                    "Recording folder path not available for this meeting".to_string(),
+                ))
             }
         }
         None => {
             log_warn!("Meeting not found: {}", meeting_id);
-            Err("Meeting not found".to_string())
+            Err(AppError::NotFound("Meeting not found".to_string()))
         }
     }
 }
@@ -1080,7 +1786,7 @@ pub async fn open_meeting_folder<R: Runtime>(
 pub async fn test_backend_connection<R: Runtime>(
     app: AppHandle<R>,
     auth_token: Option<String>,
-) -> Result<String, String> {
+) -> Result<String, AppError> {
     log_debug!("Testing backend connection...");
 
     let client = reqwest::Client::new();
@@ -1103,13 +1809,13 @@ pub async fn test_backend_connection<R: Runtime>(
         Err(e) => {
             let error_msg = format!("Failed to connect to backend: {}", e);
             log_debug!("{}", error_msg);
-            Err(error_msg)
+            Err(AppError::Network(error_msg))
         }
     }
 }
 
 #[tauri::command]
-pub async fn debug_backend_connection<R: Runtime>(app: AppHandle<R>) -> Result<String, String> {
+pub async fn debug_backend_connection<R: Runtime>(app: AppHandle<R>) -> Result<String, AppError> {
     log_debug!("=== DEBUG: Testing backend connection ===");
 
     // Test 1: Check server address from store
@@ -1120,7 +1826,7 @@ pub async fn debug_backend_connection<R: Runtime>(app: AppHandle<R>) -> Result<S
         }
         Err(e) => {
             log_error!("✗ Failed to get server URL: {}", e);
-            return Err(format!("Failed to get server URL: {}", e));
+            return Err(AppError::Internal(format!("Failed to get server URL: {}", e)));
         }
     };
 
@@ -1141,17 +1847,23 @@ pub async fn debug_backend_connection<R: Runtime>(app: AppHandle<R>) -> Result<S
         }
         Err(e) => {
             log_error!("✗ Backend connection failed: {}", e);
-            Err(format!("Backend connection failed: {}", e))
+            Err(AppError::from(e).context("Backend connection failed"))
         }
     }
 }
 
 #[tauri::command]
-pub async fn open_external_url(url: String) -> Result<(), String> {
+pub async fn open_external_url(url: String) -> Result<(), AppError> {
     use std::process::Command;
 
+    // Only web pages; anything else would be run by the shell's "open"
+    let url = validation::http_url("url", &url)?;
+
     let result = if cfg!(target_os = "windows") {
-        Command::new("cmd").args(&["/C", "start", &url]).output()
+        // Not `cmd /C start`: cmd would split the URL at '&' and run the rest
+        Command::new("rundll32")
+            .args(["url.dll,FileProtocolHandler", url.as_str()])
+            .output()
     } else if cfg!(target_os = "macos") {
         Command::new("open").arg(&url).output()
     } else {
@@ -1161,7 +1873,7 @@ pub async fn open_external_url(url: String) -> Result<(), String> {
 
     match result {
         Ok(_) => Ok(()),
-        Err(e) => Err(format!("Failed to open URL: {}", e)),
+        Err(e) => Err(AppError::from(e).context("Failed to open URL")),
     }
 }
 
@@ -1171,7 +1883,7 @@ pub async fn open_external_url(url: String) -> Result<(), String> {
 /// This configuration is stored as JSON and includes endpoint, apiKey, model, and optional parameters
 #[tauri::command]
 pub async fn api_save_custom_openai_config<R: Runtime>(
-    _app: AppHandle<R>,
+    app: AppHandle<R>,
     state: tauri::State<'_, AppState>,
     endpoint: String,
     api_key: Option<String>,
@@ -1179,40 +1891,32 @@ pub async fn api_save_custom_openai_config<R: Runtime>(
     max_tokens: Option<i32>,
     temperature: Option<f32>,
     top_p: Option<f32>,
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, AppError> {
+    // Validate required fields
+    let endpoint = validation::http_url("endpoint", &endpoint)?;
+    if model.trim().is_empty() {
+        return Err(AppError::InvalidInput("Model name is required".to_string()));
+    }
     log_info!(
         "api_save_custom_openai_config called: endpoint='{}', model='{}'",
         &endpoint,
         &model
     );
 
-    // Validate required fields
-    if endpoint.trim().is_empty() {
-        return Err("Endpoint URL is required".to_string());
-    }
-    if model.trim().is_empty() {
-        return Err("Model name is required".to_string());
-    }
-
-    // Validate endpoint URL format
-    if !endpoint.starts_with("http://") && !endpoint.starts_with("https://") {
-        return Err("Endpoint must start with http:// or https://".to_string());
-    }
-
     // Validate optional numeric parameters
     if let Some(temp) = temperature {
         if !(0.0..=2.0).contains(&temp) {
-            return Err("Temperature must be between 0.0 and 2.0".to_string());
+            return Err(AppError::InvalidInput("Temperature must be between 0.0 and 2.0".to_string()));
         }
     }
     if let Some(top) = top_p {
         if !(0.0..=1.0).contains(&top) {
-            return Err("Top P must be between 0.0 and 1.0".to_string());
+            return Err(AppError::InvalidInput("Top P must be between 0.0 and 1.0".to_string()));
         }
     }
     if let Some(tokens) = max_tokens {
         if tokens < 1 {
-            return Err("Max tokens must be at least 1".to_string());
+            return Err(AppError::InvalidInput("Max tokens must be at least 1".to_string()));
         }
     }
 
@@ -1227,9 +1931,19 @@ pub async fn api_save_custom_openai_config<R: Runtime>(
 
     let pool = state.db_manager.pool();
 
-    match SettingsRepository::save_custom_openai_config(pool, &config).await {
+    let result = SettingsRepository::save_custom_openai_config(pool, &config).await;
+    config_changed(&app, &state.settings_cache, ConfigKind::Model);
+    match result {
         Ok(()) => {
             log_info!("✅ Successfully saved custom OpenAI config for endpoint: {}", config.endpoint);
+            audit::record(
+                pool,
+                AuditAction::ConfigChange,
+                "custom_openai_config",
+                None,
+                serde_json::json!({ "endpoint": config.endpoint, "model": config.model }),
+            )
+            .await;
             Ok(serde_json::json!({
                 "status": "success",
                 "message": "Custom OpenAI configuration saved successfully"
@@ -1237,7 +1951,7 @@ pub async fn api_save_custom_openai_config<R: Runtime>(
         }
         Err(e) => {
             log_error!("❌ Failed to save custom OpenAI config: {}", e);
-            Err(format!("Failed to save custom OpenAI configuration: {}", e))
+            Err(AppError::from(e).context("Failed to save custom OpenAI configuration"))
         }
     }
 }
@@ -1247,7 +1961,7 @@ pub async fn api_save_custom_openai_config<R: Runtime>(
 pub async fn api_get_custom_openai_config<R: Runtime>(
     _app: AppHandle<R>,
     state: tauri::State<'_, AppState>,
-) -> Result<Option<CustomOpenAIConfig>, String> {
+) -> Result<Option<CustomOpenAIConfig>, AppError> {
     log_info!("api_get_custom_openai_config called");
 
     let pool = state.db_manager.pool();
@@ -1264,7 +1978,7 @@ pub async fn api_get_custom_openai_config<R: Runtime>(
         }
         Err(e) => {
             log_error!("❌ Failed to get custom OpenAI config: {}", e);
-            Err(format!("Failed to get custom OpenAI configuration: {}", e))
+            Err(AppError::from(e).context("Failed to get custom OpenAI configuration"))
         }
     }
 }
@@ -1277,18 +1991,15 @@ pub async fn api_test_custom_openai_connection<R: Runtime>(
     endpoint: String,
     api_key: Option<String>,
     model: String,
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, AppError> {
+    // Validate endpoint URL format
+    let endpoint = validation::http_url("endpoint", &endpoint)?;
     log_info!(
         "api_test_custom_openai_connection called: endpoint='{}', model='{}'",
         &endpoint,
         &model
     );
 
-    // Validate endpoint URL format
-    if !endpoint.starts_with("http://") && !endpoint.starts_with("https://") {
-        return Err("Endpoint must start with http:// or https://".to_string());
-    }
-
     // Build the URL - append /chat/completions to the base endpoint
     let url = format!("{}/chat/completions", endpoint.trim_end_matches('/'));
 
@@ -1307,7 +2018,7 @@ pub async fn api_test_custom_openai_connection<R: Runtime>(
     let client = reqwest::Client::builder()
         .timeout(std::time::Duration::from_secs(30))
         .build()
-        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+        .map_err(|e| AppError::Internal(format!("Failed to create HTTP client: {}", e)))?;
 
     let mut request = client
         .post(&url)
@@ -1358,26 +2069,26 @@ pub async fn api_test_custom_openai_connection<R: Runtime>(
 
                         // Response was 200 but doesn't match OpenAI format
                         log_warn!("⚠️ Endpoint returned 200 but response doesn't match OpenAI format: {}", response_text);
-                        Err("Endpoint is reachable but doesn't appear to be OpenAI-compatible. Response is missing 'choices' array or 'message.content' / 'message.reasoning_content' field.".to_string())
+                        Err(AppError::Network("Endpoint is reachable but doesn't appear to be OpenAI-compatible. Response is missing 'choices' array or 'message.content' / 'message.reasoning_content' field.".to_string()))
                     }
                     Err(e) => {
                         log_warn!("⚠️ Endpoint returned 200 but response is not valid JSON: {}", e);
-                        Err(format!("Endpoint is reachable but returned invalid JSON: {}. Response: {}", e, response_text))
+                        Err(AppError::Network(format!("Endpoint is reachable but returned invalid JSON: {}. Response: {}", e, response_text)))
                     }
                 }
             } else {
                 log_warn!("⚠️ Custom OpenAI connection test failed with status {}: {}", status, response_text);
-                Err(format!("Connection failed with status {}: {}", status, response_text))
+                Err(AppError::Network(format!("Connection failed with status {}: {}", status, response_text)))
             }
         }
         Err(e) => {
             log_error!("❌ Custom OpenAI connection test failed: {}", e);
             if e.is_timeout() {
-                Err("Connection timed out. Please check the endpoint URL.".to_string())
+                Err(AppError::Network("Connection timed out. Please check the endpoint URL.".to_string()))
             } else if e.is_connect() {
-                Err("Could not connect to endpoint. Please verify the URL is correct and the server is running.".to_string())
+                Err(AppError::Network("Could not connect to endpoint. Please verify the URL is correct and the server is running.".to_string()))
             } else {
-                Err(format!("Connection failed: {}", e))
+                Err(AppError::from(e).context("Connection failed"))
             }
         }
     }
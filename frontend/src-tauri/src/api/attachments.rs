@@ -0,0 +1,310 @@
+use log::{error as log_error, info as log_info, warn as log_warn};
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::{
+    audio::audio_processing::sanitize_filename,
+    audit::{self, AuditAction},
+    database::{
+        models::MeetingAttachment,
+        repositories::{
+            attachment::AttachmentsRepository, legal_hold::LegalHoldRepository,
+            meeting::MeetingsRepository,
+        },
+    },
+    state::AppState,
+    validation,
+};
+
+/// Subdirectory inside a meeting folder where attachments are copied
+const ATTACHMENTS_DIR: &str = "attachments";
+
+/// Resolve the directory attachments for a meeting are copied into.
+/// Uses the meeting's recording folder when available, otherwise falls back to
+/// app_data_dir/attachments/<meeting_id> (e.g. meetings saved without audio).
+async fn resolve_attachments_dir<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &AppState,
+    meeting_id: &str,
+) -> Result<PathBuf, String> {
+    let meeting = MeetingsRepository::get_meeting_metadata(state.db_manager.pool(), meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+
+    match meeting.folder_path {
+        Some(folder) if Path::new(&folder).exists() => Ok(PathBuf::from(folder).join(ATTACHMENTS_DIR)),
+        _ => {
+            let app_data_dir = app
+                .path()
+                .app_data_dir()
+                .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+            Ok(app_data_dir.join(ATTACHMENTS_DIR).join(sanitize_filename(meeting_id)))
+        }
+    }
+}
+
+/// Pick a destination path that doesn't overwrite an existing attachment
+/// e.g. agenda.pdf -> agenda (1).pdf -> agenda (2).pdf
+fn unique_destination(dir: &Path, file_name: &str) -> PathBuf {
+    let candidate = dir.join(file_name);
+    if !candidate.exists() {
+        return candidate;
+    }
+
+    let path = Path::new(file_name);
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| file_name.to_string());
+    let extension = path.extension().map(|e| e.to_string_lossy().to_string());
+
+    let mut counter = 1;
+    loop {
+        let name = match &extension {
+            Some(ext) => format!("{} ({}).{}", stem, counter, ext),
+            None => format!("{} ({})", stem, counter),
+        };
+        let candidate = dir.join(name);
+        if !candidate.exists() {
+            return candidate;
+        }
+        counter += 1;
+    }
+}
+
+/// Best-effort MIME type from the file extension (used by the UI to pick an icon/preview)
+fn guess_mime_type(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_string_lossy().to_lowercase();
+    let mime = match ext.as_str() {
+        "pdf" => "application/pdf",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "txt" => "text/plain",
+        "md" => "text/markdown",
+        "csv" => "text/csv",
+        "ppt" => "application/vnd.ms-powerpoint",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xls" => "application/vnd.ms-excel",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "key" => "application/vnd.apple.keynote",
+        _ => return None,
+    };
+    Some(mime)
+}
+
+/// Deletes the files of attachments deleted with their meeting in an earlier
+/// session. They are kept while the deletion can still be undone. Returns the
+/// number of files removed.
+pub async fn remove_expired_files(pool: &SqlitePool) -> usize {
+    let paths = match AttachmentsRepository::expired_files(pool).await {
+        Ok(paths) => paths,
+        Err(e) => {
+            log_warn!("Failed to look up attachments of deleted meetings: {}", e);
+            return 0;
+        }
+    };
+    let mut removed = 0;
+    for path in paths.iter().map(Path::new) {
+        match std::fs::remove_file(path) {
+            Ok(()) => {
+                removed += 1;
+                // Drops the attachments folder once its last file is gone
+                if let Some(dir) = path.parent() {
+                    let _ = std::fs::remove_dir(dir);
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => log_warn!("Failed to delete attachment file {}: {}", path.display(), e),
+        }
+    }
+    removed
+}
+
+/// Copies a file into the meeting's attachments folder and records it
+#[tauri::command]
+pub async fn api_add_attachment<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    source_path: String,
+) -> Result<MeetingAttachment, String> {
+    validation::meeting_id(&meeting_id)?;
+    let source = validation::existing_file("source_path", &source_path)?;
+    log_info!(
+        "api_add_attachment called for meeting_id: {}, source: {}",
+        meeting_id,
+        source_path
+    );
+
+    let file_name = source
+        .file_name()
+        .map(|n| sanitize_filename(&n.to_string_lossy()))
+        .filter(|n| !n.is_empty())
+        .ok_or_else(|| "Invalid attachment file name".to_string())?;
+
+    let attachments_dir = resolve_attachments_dir(&app, &state, &meeting_id).await?;
+    std::fs::create_dir_all(&attachments_dir)
+        .map_err(|e| format!("Failed to create attachments folder: {}", e))?;
+
+    let destination = unique_destination(&attachments_dir, &file_name);
+    let size_bytes = std::fs::copy(&source, &destination).map_err(|e| {
+        log_error!("Failed to copy attachment {}: {}", source_path, e);
+        format!("Failed to copy attachment: {}", e)
+    })?;
+
+    let stored_name = destination
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or(file_name);
+
+    match AttachmentsRepository::add_attachment(
+        state.db_manager.pool(),
+        &meeting_id,
+        &stored_name,
+        &destination.to_string_lossy(),
+        guess_mime_type(&destination),
+        size_bytes as i64,
+    )
+    .await
+    {
+        Ok(attachment) => {
+            log_info!("Attachment stored at {}", destination.display());
+            audit::record(
+                state.db_manager.pool(),
+                AuditAction::AttachmentChange,
+                "meeting",
+                Some(&meeting_id),
+                serde_json::json!({ "change": "add", "attachment_id": attachment.id, "file_name": stored_name }),
+            )
+            .await;
+            Ok(attachment)
+        }
+        Err(e) => {
+            // Don't leave an untracked copy behind
+            let _ = std::fs::remove_file(&destination);
+            log_error!("Failed to record attachment for meeting {}: {}", meeting_id, e);
+            Err(format!("Failed to add attachment: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn api_list_attachments<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<MeetingAttachment>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_list_attachments called for meeting_id: {}", meeting_id);
+
+    AttachmentsRepository::list_attachments(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list attachments for {}: {}", meeting_id, e);
+            format!("Failed to list attachments: {}", e)
+        })
+}
+
+/// Opens an attachment with the system default application
+#[tauri::command]
+pub async fn api_open_attachment<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    attachment_id: String,
+) -> Result<(), String> {
+    validation::attachment_id(&attachment_id)?;
+    log_info!("api_open_attachment called for attachment_id: {}", attachment_id);
+
+    let attachment = AttachmentsRepository::get_attachment(state.db_manager.pool(), &attachment_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| "Attachment not found".to_string())?;
+
+    let path = Path::new(&attachment.stored_path);
+    if !path.exists() {
+        log_warn!("Attachment file missing: {}", attachment.stored_path);
+        return Err(format!("Attachment file not found: {}", attachment.stored_path));
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("open")
+            .arg(&attachment.stored_path)
+            .spawn()
+            .map_err(|e| format!("Failed to open attachment: {}", e))?;
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        std::process::Command::new("explorer")
+            .arg(&attachment.stored_path)
+            .spawn()
+            .map_err(|e| format!("Failed to open attachment: {}", e))?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        std::process::Command::new("xdg-open")
+            .arg(&attachment.stored_path)
+            .spawn()
+            .map_err(|e| format!("Failed to open attachment: {}", e))?;
+    }
+
+    Ok(())
+}
+
+/// Removes an attachment record and deletes its copied file
+#[tauri::command]
+pub async fn api_remove_attachment<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    attachment_id: String,
+) -> Result<serde_json::Value, String> {
+    validation::attachment_id(&attachment_id)?;
+    log_info!("api_remove_attachment called for attachment_id: {}", attachment_id);
+    let pool = state.db_manager.pool();
+
+    let attachment = AttachmentsRepository::get_attachment(pool, &attachment_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| "Attachment not found".to_string())?;
+    LegalHoldRepository::ensure_not_held(pool, &attachment.meeting_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    match AttachmentsRepository::delete_attachment(pool, &attachment_id).await {
+        Ok(true) => {
+            if let Err(e) = std::fs::remove_file(&attachment.stored_path) {
+                // Row is gone either way; a missing file is not an error for the user
+                log_warn!(
+                    "Failed to delete attachment file {}: {}",
+                    attachment.stored_path,
+                    e
+                );
+            }
+            audit::record(
+                pool,
+                AuditAction::AttachmentChange,
+                "meeting",
+                Some(&attachment.meeting_id),
+                serde_json::json!({ "change": "remove", "attachment_id": attachment_id }),
+            )
+            .await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "message": "Attachment removed successfully"
+            }))
+        }
+        Ok(false) => Err("Attachment not found".to_string()),
+        Err(e) => {
+            log_error!("Failed to remove attachment {}: {}", attachment_id, e);
+            Err(format!("Failed to remove attachment: {}", e))
+        }
+    }
+}
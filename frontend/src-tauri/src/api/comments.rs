@@ -0,0 +1,152 @@
+use log::{error as log_error, info as log_info};
+use sqlx::Error as SqlxError;
+use tauri::{AppHandle, Runtime};
+
+use crate::{
+    audit::{self, AuditAction},
+    database::{models::SegmentComment, repositories::segment_comment::SegmentCommentsRepository},
+    state::AppState,
+};
+
+/// Adds a comment to a transcript segment, or a reply when `parent_id` is
+/// given. The author defaults to the signed-in OS user.
+#[tauri::command]
+pub async fn api_add_segment_comment<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    transcript_id: String,
+    text: String,
+    parent_id: Option<String>,
+    author: Option<String>,
+) -> Result<SegmentComment, String> {
+    crate::validation::id("transcript_id", &transcript_id)?;
+    if let Some(parent_id) = parent_id.as_deref() {
+        crate::validation::comment_id(parent_id)?;
+    }
+    log_info!(
+        "api_add_segment_comment called for transcript_id: {}",
+        transcript_id
+    );
+    let pool = state.db_manager.pool();
+    let author = author
+        .map(|a| a.trim().to_string())
+        .filter(|a| !a.is_empty())
+        .unwrap_or_else(audit::current_actor);
+
+    let comment =
+        SegmentCommentsRepository::add(pool, &transcript_id, parent_id.as_deref(), &author, &text)
+            .await
+            .map_err(|e| match e {
+                SqlxError::RowNotFound => format!("Segment {} not found", transcript_id),
+                e => {
+                    log_error!("Failed to add comment to {}: {}", transcript_id, e);
+                    format!("Failed to add comment: {}", e)
+                }
+            })?;
+    audit::record(
+        pool,
+        AuditAction::CommentChange,
+        "meeting",
+        Some(&comment.meeting_id),
+        serde_json::json!({
+            "change": "add",
+            "comment_id": comment.id,
+            "transcript_id": comment.transcript_id,
+        }),
+    )
+    .await;
+    Ok(comment)
+}
+
+/// All comments of a meeting, oldest first; the frontend threads them by
+/// `transcript_id` and `parent_id`
+#[tauri::command]
+pub async fn api_list_segment_comments<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<SegmentComment>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_list_segment_comments called for meeting_id: {}",
+        meeting_id
+    );
+    SegmentCommentsRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list comments of {}: {}", meeting_id, e);
+            format!("Failed to list comments: {}", e)
+        })
+}
+
+#[tauri::command]
+pub async fn api_update_segment_comment<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    comment_id: String,
+    text: String,
+) -> Result<SegmentComment, String> {
+    crate::validation::comment_id(&comment_id)?;
+    log_info!(
+        "api_update_segment_comment called for comment_id: {}",
+        comment_id
+    );
+    let pool = state.db_manager.pool();
+
+    match SegmentCommentsRepository::update_text(pool, &comment_id, &text).await {
+        Ok(Some(comment)) => {
+            audit::record(
+                pool,
+                AuditAction::CommentChange,
+                "meeting",
+                Some(&comment.meeting_id),
+                serde_json::json!({ "change": "edit", "comment_id": comment.id }),
+            )
+            .await;
+            Ok(comment)
+        }
+        Ok(None) => Err(format!("Comment {} not found", comment_id)),
+        Err(e) => {
+            log_error!("Failed to update comment {}: {}", comment_id, e);
+            Err(format!("Failed to update comment: {}", e))
+        }
+    }
+}
+
+/// Deletes a comment and its replies
+#[tauri::command]
+pub async fn api_delete_segment_comment<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    comment_id: String,
+) -> Result<serde_json::Value, String> {
+    crate::validation::comment_id(&comment_id)?;
+    log_info!(
+        "api_delete_segment_comment called for comment_id: {}",
+        comment_id
+    );
+    let pool = state.db_manager.pool();
+
+    let comment = SegmentCommentsRepository::get(pool, &comment_id)
+        .await
+        .map_err(|e| format!("Failed to load comment: {}", e))?
+        .ok_or_else(|| format!("Comment {} not found", comment_id))?;
+    let removed = SegmentCommentsRepository::delete(pool, &comment_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to delete comment {}: {}", comment_id, e);
+            format!("Failed to delete comment: {}", e)
+        })?;
+    audit::record(
+        pool,
+        AuditAction::CommentChange,
+        "meeting",
+        Some(&comment.meeting_id),
+        serde_json::json!({ "change": "delete", "comment_id": comment_id, "removed": removed }),
+    )
+    .await;
+    Ok(serde_json::json!({
+        "status": "success",
+        "message": format!("Deleted {} comments", removed)
+    }))
+}
@@ -0,0 +1,52 @@
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Runtime};
+
+use crate::{
+    audio::corrections,
+    database::repositories::correction::{CorrectionRule, CorrectionsRepository},
+    state::AppState,
+};
+
+/// Every recorded correction, most frequent first. Rules made fewer than
+/// `corrections::LEARN_AFTER` times are listed but not applied yet.
+#[tauri::command]
+pub async fn api_list_correction_rules<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<CorrectionRule>, String> {
+    log_info!("api_list_correction_rules called");
+
+    CorrectionsRepository::list_rules(state.db_manager.pool())
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list correction rules: {}", e);
+            format!("Failed to list correction rules: {}", e)
+        })
+}
+
+/// Forgets a learned correction; it is no longer applied to new transcripts
+#[tauri::command]
+pub async fn api_delete_correction_rule<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    rule_id: String,
+) -> Result<serde_json::Value, String> {
+    crate::validation::rule_id(&rule_id)?;
+    log_info!("api_delete_correction_rule called for: {}", rule_id);
+
+    let pool = state.db_manager.pool();
+    match CorrectionsRepository::delete_rule(pool, &rule_id).await {
+        Ok(true) => {
+            corrections::refresh_rules(pool).await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "message": "Correction removed"
+            }))
+        }
+        Ok(false) => Err(format!("Correction rule not found: {}", rule_id)),
+        Err(e) => {
+            log_error!("Failed to delete correction rule {}: {}", rule_id, e);
+            Err(format!("Failed to delete correction rule: {}", e))
+        }
+    }
+}
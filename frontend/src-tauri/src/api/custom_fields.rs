@@ -0,0 +1,199 @@
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Runtime};
+
+use crate::{
+    audit::{self, AuditAction},
+    database::{
+        models::{CustomFieldDefinition, MeetingCustomFieldValue},
+        repositories::custom_field::{CustomFieldInput, CustomFieldsRepository},
+    },
+    state::AppState,
+};
+
+#[tauri::command]
+pub async fn api_list_custom_fields<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<CustomFieldDefinition>, String> {
+    log_info!("api_list_custom_fields called");
+
+    CustomFieldsRepository::list_definitions(state.db_manager.pool())
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list custom fields: {}", e);
+            format!("Failed to list custom fields: {}", e)
+        })
+}
+
+/// Defines a new field available on every meeting
+#[tauri::command]
+pub async fn api_create_custom_field<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    field: CustomFieldInput,
+) -> Result<CustomFieldDefinition, String> {
+    log_info!("api_create_custom_field called for name: {}", field.name);
+
+    let pool = state.db_manager.pool();
+    let definition = CustomFieldsRepository::create_definition(pool, &field)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to create custom field {}: {}", field.name, e);
+            format!("Failed to create custom field: {}", e)
+        })?;
+
+    audit::record(
+        pool,
+        AuditAction::CustomFieldChange,
+        "custom_field",
+        Some(&definition.id),
+        serde_json::json!({
+            "change": "create",
+            "name": definition.name,
+            "fieldType": definition.field_type
+        }),
+    )
+    .await;
+    Ok(definition)
+}
+
+/// Renames, retypes or reorders a field. Existing values are converted to the
+/// new type; the update fails if any of them would become invalid.
+#[tauri::command]
+pub async fn api_update_custom_field<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    field_id: String,
+    field: CustomFieldInput,
+) -> Result<CustomFieldDefinition, String> {
+    crate::validation::field_id(&field_id)?;
+    log_info!("api_update_custom_field called for field_id: {}", field_id);
+
+    let pool = state.db_manager.pool();
+    let definition = CustomFieldsRepository::update_definition(pool, &field_id, &field)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to update custom field {}: {}", field_id, e);
+            format!("Failed to update custom field: {}", e)
+        })?
+        .ok_or_else(|| format!("Custom field not found: {}", field_id))?;
+
+    audit::record(
+        pool,
+        AuditAction::CustomFieldChange,
+        "custom_field",
+        Some(&field_id),
+        serde_json::json!({
+            "change": "update",
+            "name": definition.name,
+            "fieldType": definition.field_type
+        }),
+    )
+    .await;
+    Ok(definition)
+}
+
+/// Deletes a field and its value on every meeting
+#[tauri::command]
+pub async fn api_delete_custom_field<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    field_id: String,
+) -> Result<serde_json::Value, String> {
+    crate::validation::field_id(&field_id)?;
+    log_info!("api_delete_custom_field called for field_id: {}", field_id);
+
+    let pool = state.db_manager.pool();
+    match CustomFieldsRepository::delete_definition(pool, &field_id).await {
+        Ok(true) => {
+            audit::record(
+                pool,
+                AuditAction::CustomFieldChange,
+                "custom_field",
+                Some(&field_id),
+                serde_json::json!({ "change": "delete" }),
+            )
+            .await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "message": "Custom field deleted"
+            }))
+        }
+        Ok(false) => Err(format!("Custom field not found: {}", field_id)),
+        Err(e) => {
+            log_error!("Failed to delete custom field {}: {}", field_id, e);
+            Err(format!("Failed to delete custom field: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn api_get_meeting_custom_fields<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<MeetingCustomFieldValue>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_get_meeting_custom_fields called for meeting_id: {}", meeting_id);
+
+    CustomFieldsRepository::get_meeting_values(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to get custom fields for {}: {}", meeting_id, e);
+            format!("Failed to get custom fields: {}", e)
+        })
+}
+
+/// Sets a meeting's value for a field; a missing or empty value clears it
+#[tauri::command]
+pub async fn api_set_meeting_custom_field<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    field_id: String,
+    value: Option<String>,
+) -> Result<Option<MeetingCustomFieldValue>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    crate::validation::field_id(&field_id)?;
+    log_info!(
+        "api_set_meeting_custom_field called for meeting_id: {}, field_id: {}",
+        meeting_id,
+        field_id
+    );
+
+    let pool = state.db_manager.pool();
+    let meeting_exists: bool = sqlx::query("SELECT 1 FROM meetings WHERE id = ?")
+        .bind(&meeting_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("Failed to look up meeting: {}", e))?
+        .is_some();
+    if !meeting_exists {
+        return Err(format!("Meeting not found: {}", meeting_id));
+    }
+
+    let stored = CustomFieldsRepository::set_value(pool, &meeting_id, &field_id, value.as_deref())
+        .await
+        .map_err(|e| {
+            log_error!(
+                "Failed to set custom field {} for {}: {}",
+                field_id,
+                meeting_id,
+                e
+            );
+            format!("Failed to set custom field: {}", e)
+        })?;
+
+    audit::record(
+        pool,
+        AuditAction::CustomFieldChange,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({
+            "change": if stored.is_some() { "set" } else { "clear" },
+            "fieldId": field_id
+        }),
+    )
+    .await;
+    Ok(stored)
+}
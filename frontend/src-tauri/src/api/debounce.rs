@@ -0,0 +1,139 @@
+//! Coalesces rapid successive saves.
+//!
+//! The title field saves while the user types, and a double click on save
+//! sends the transcript twice. Title saves for a meeting wait
+//! [`TITLE_DEBOUNCE`]; a newer save of the same meeting within that window
+//! supersedes the older one, so only the last title is written. Transcript
+//! saves of the same recording run one at a time, and a repeat within
+//! [`TRANSCRIPT_DEDUP_WINDOW`] gets the meeting the first one created
+//! instead of creating another.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How long a title save waits for a newer one of the same meeting
+pub const TITLE_DEBOUNCE: Duration = Duration::from_millis(400);
+/// Repeats of a transcript save within this window are not written again
+pub const TRANSCRIPT_DEDUP_WINDOW: Duration = Duration::from_secs(10);
+
+type LastSave = Arc<tokio::sync::Mutex<Option<(Instant, String)>>>;
+
+#[derive(Default)]
+struct Inner {
+    next_generation: u64,
+    /// Generation of the newest pending save per key
+    pending: HashMap<String, u64>,
+    saved: HashMap<String, LastSave>,
+}
+
+/// Cheap to clone; all clones share state
+#[derive(Clone, Default)]
+pub struct SaveDebouncer {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl SaveDebouncer {
+    fn inner(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Registers a save of `key` and waits out `window`. True when no newer
+    /// save of `key` arrived meanwhile, so this one should be written.
+    pub async fn settle(&self, key: &str, window: Duration) -> bool {
+        let generation = {
+            let mut inner = self.inner();
+            inner.next_generation += 1;
+            let generation = inner.next_generation;
+            inner.pending.insert(key.to_string(), generation);
+            generation
+        };
+        tokio::time::sleep(window).await;
+        let mut inner = self.inner();
+        if inner.pending.get(key) == Some(&generation) {
+            inner.pending.remove(key);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Runs `save` for `key` unless a save of `key` finished within
+    /// `window`, in which case its id is returned instead. Saves of one key
+    /// run one at a time. The flag is true when the result was reused.
+    pub async fn once<F, Fut, E>(
+        &self,
+        key: &str,
+        window: Duration,
+        save: F,
+    ) -> Result<(String, bool), E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<String, E>>,
+    {
+        let slot = {
+            let mut inner = self.inner();
+            // Forget saves that can no longer be repeated
+            inner.saved.retain(|_, slot| {
+                slot.try_lock().map_or(true, |last| {
+                    last.as_ref().is_some_and(|(at, _)| at.elapsed() < window)
+                })
+            });
+            inner.saved.entry(key.to_string()).or_default().clone()
+        };
+        let mut last = slot.lock().await;
+        if let Some((at, id)) = last.as_ref() {
+            if at.elapsed() < window {
+                return Ok((id.clone(), true));
+            }
+        }
+        let id = save().await?;
+        *last = Some((Instant::now(), id.clone()));
+        Ok((id, false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn only_the_newest_save_is_written() {
+        let debouncer = SaveDebouncer::default();
+        let first = tokio::spawn({
+            let debouncer = debouncer.clone();
+            async move {
+                debouncer
+                    .settle("meeting-1", Duration::from_millis(50))
+                    .await
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let other = debouncer.settle("meeting-2", Duration::from_millis(50));
+        let second = debouncer.settle("meeting-1", Duration::from_millis(50));
+        let (other, second) = tokio::join!(other, second);
+        assert!(!first.await.unwrap());
+        assert!(second);
+        assert!(other);
+    }
+
+    #[tokio::test]
+    async fn repeated_saves_reuse_the_first_result() {
+        let debouncer = SaveDebouncer::default();
+        let window = Duration::from_secs(5);
+        let save = |id: &'static str| move || async move { Ok::<_, String>(id.to_string()) };
+        assert_eq!(
+            debouncer.once("/rec/a", window, save("meeting-1")).await,
+            Ok(("meeting-1".to_string(), false))
+        );
+        assert_eq!(
+            debouncer.once("/rec/a", window, save("meeting-2")).await,
+            Ok(("meeting-1".to_string(), true))
+        );
+        assert_eq!(
+            debouncer.once("/rec/b", window, save("meeting-3")).await,
+            Ok(("meeting-3".to_string(), false))
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use log::info as log_info;
+use tauri::{AppHandle, Runtime};
+
+use crate::audio::recording_preferences::load_recording_preferences;
+use crate::diagnostics::{self, DiagnosticsReport};
+use crate::state::AppState;
+
+/// Runs all health checks (database, models, disk space, microphone, backend,
+/// summary sidecar) and returns a report for the support screen
+#[tauri::command]
+pub async fn api_run_diagnostics<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<DiagnosticsReport, String> {
+    log_info!("api_run_diagnostics called");
+    let pool = state.db_manager.pool();
+    let recordings_root = load_recording_preferences(&app)
+        .await
+        .map(|prefs| prefs.save_folder)
+        .unwrap_or_else(|_| crate::audio::recording_preferences::get_default_recordings_folder());
+
+    let checks = vec![
+        diagnostics::timed("database", diagnostics::check_database(pool)).await,
+        diagnostics::timed("models", diagnostics::check_models(pool)).await,
+        diagnostics::timed(
+            "disk_space",
+            diagnostics::check_disk_space(&recordings_root),
+        )
+        .await,
+        diagnostics::timed("microphone", diagnostics::check_microphone()).await,
+        diagnostics::timed("backend", diagnostics::check_backend()).await,
+        diagnostics::timed("summary_sidecar", diagnostics::check_sidecar()).await,
+    ];
+    let report = DiagnosticsReport::new(app.package_info().version.to_string(), checks);
+    log_info!(
+        "Diagnostics finished: {:?} ({} checks)",
+        report.overall,
+        report.checks.len()
+    );
+    Ok(report)
+}
@@ -0,0 +1,108 @@
+use log::{error as log_error, info as log_info};
+use sqlx::Error as SqlxError;
+use tauri::{AppHandle, Runtime};
+
+use crate::{
+    audit::{self, AuditAction},
+    database::repositories::legal_hold::{LegalHold, LegalHoldRepository},
+    state::AppState,
+};
+
+/// Puts a meeting on legal hold. While held, the meeting, its transcript
+/// segments and its attachments can't be deleted.
+#[tauri::command]
+pub async fn api_place_legal_hold<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    reason: String,
+) -> Result<LegalHold, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_place_legal_hold called for meeting_id: {}", meeting_id);
+    let pool = state.db_manager.pool();
+
+    let hold = LegalHoldRepository::place(pool, &meeting_id, &reason, &audit::current_actor())
+        .await
+        .map_err(|e| match e {
+            SqlxError::RowNotFound => format!("Meeting {} not found", meeting_id),
+            e => {
+                log_error!("Failed to place legal hold on {}: {}", meeting_id, e);
+                format!("Failed to place legal hold: {}", e)
+            }
+        })?;
+    audit::record(
+        pool,
+        AuditAction::LegalHold,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({ "reason": hold.reason }),
+    )
+    .await;
+    Ok(hold)
+}
+
+#[tauri::command]
+pub async fn api_release_legal_hold<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<serde_json::Value, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_release_legal_hold called for meeting_id: {}",
+        meeting_id
+    );
+    let pool = state.db_manager.pool();
+
+    match LegalHoldRepository::release(pool, &meeting_id).await {
+        Ok(Some(hold)) => {
+            audit::record(
+                pool,
+                AuditAction::LegalHoldRelease,
+                "meeting",
+                Some(&meeting_id),
+                serde_json::json!({
+                    "reason": hold.reason,
+                    "placed_by": hold.placed_by,
+                    "placed_at": hold.placed_at,
+                }),
+            )
+            .await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "message": "Legal hold released"
+            }))
+        }
+        Ok(None) => Err("Meeting is not on legal hold".to_string()),
+        Err(e) => {
+            log_error!("Failed to release legal hold on {}: {}", meeting_id, e);
+            Err(format!("Failed to release legal hold: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn api_get_legal_hold<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Option<LegalHold>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    LegalHoldRepository::get(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load legal hold: {}", e))
+}
+
+#[tauri::command]
+pub async fn api_list_legal_holds<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<LegalHold>, String> {
+    log_info!("api_list_legal_holds called");
+    LegalHoldRepository::list(state.db_manager.pool())
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list legal holds: {}", e);
+            format!("Failed to list legal holds: {}", e)
+        })
+}
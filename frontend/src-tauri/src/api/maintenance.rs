@@ -0,0 +1,213 @@
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use crate::audio::recording_preferences::load_recording_preferences;
+use crate::audit::{self, AuditAction};
+use crate::database::maintenance::{
+    load_schedule, run_maintenance, save_schedule, MaintenanceOptions, MaintenanceReport,
+    MaintenanceSchedule,
+};
+use crate::database::migrations::{last_backup_at, schema_version, BACKUP_DIR_NAME};
+use crate::database::reconcile::{self, IntegrityReport, RepairAction, INTEGRITY_EVENT};
+use crate::database::repositories::legal_hold::LegalHoldRepository;
+use crate::locks;
+use crate::state::AppState;
+use crate::validation;
+
+/// Runs integrity check, orphan cleanup, ANALYZE and VACUUM and returns a report.
+/// Unreferenced recording folders are only deleted when `remove_orphan_folders` is set.
+#[tauri::command]
+pub async fn api_db_maintenance<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    remove_orphan_folders: Option<bool>,
+) -> Result<MaintenanceReport, String> {
+    log_info!(
+        "api_db_maintenance called (remove_orphan_folders: {:?})",
+        remove_orphan_folders
+    );
+    let pool = state.db_manager.pool();
+    let recordings_root = load_recording_preferences(&app)
+        .await
+        .map(|prefs| prefs.save_folder)
+        .ok();
+    let options = MaintenanceOptions {
+        remove_orphan_folders: remove_orphan_folders.unwrap_or(false),
+        vacuum: true,
+    };
+
+    let report = run_maintenance(pool, recordings_root.as_deref(), &options)
+        .await
+        .map_err(|e| {
+            log_error!("Database maintenance failed: {}", e);
+            format!("Database maintenance failed: {}", e)
+        })?;
+
+    // A manual run counts towards the schedule
+    let mut schedule = load_schedule(pool).await?;
+    schedule.last_run_at = Some(report.ran_at);
+    save_schedule(pool, &schedule).await?;
+    audit::record(
+        pool,
+        AuditAction::Maintenance,
+        "database",
+        None,
+        serde_json::to_value(&report).unwrap_or_default(),
+    )
+    .await;
+    Ok(report)
+}
+
+#[tauri::command]
+pub async fn api_get_maintenance_schedule<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<MaintenanceSchedule, String> {
+    log_info!("api_get_maintenance_schedule called");
+    load_schedule(state.db_manager.pool()).await
+}
+
+/// Enables or disables automatic maintenance every `interval_days` days
+#[tauri::command]
+pub async fn api_set_maintenance_schedule<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    enabled: bool,
+    interval_days: u32,
+) -> Result<MaintenanceSchedule, String> {
+    log_info!(
+        "api_set_maintenance_schedule called (enabled: {}, interval: {} days)",
+        enabled,
+        interval_days
+    );
+    if interval_days == 0 {
+        return Err("Maintenance interval must be at least one day".to_string());
+    }
+    let pool = state.db_manager.pool();
+    let mut schedule = load_schedule(pool).await?;
+    schedule.enabled = enabled;
+    schedule.interval_days = interval_days;
+    save_schedule(pool, &schedule).await?;
+    Ok(schedule)
+}
+
+/// Current and latest schema versions, pending steps and the last pre-migration backup
+#[tauri::command]
+pub async fn api_get_schema_version<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_get_schema_version called");
+    let version = schema_version(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to read schema version: {}", e))?;
+    let backup_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))?
+        .join(BACKUP_DIR_NAME);
+    Ok(serde_json::json!({
+        "current": version.current,
+        "latest": version.latest,
+        "applied": version.applied,
+        "pending": version.pending,
+        "upToDate": version.pending.is_empty(),
+        "backupDir": backup_dir,
+        "lastBackupAt": last_backup_at(&backup_dir),
+    }))
+}
+
+async fn scan_integrity<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &sqlx::SqlitePool,
+) -> Result<IntegrityReport, String> {
+    let recordings_root = load_recording_preferences(app)
+        .await
+        .map(|prefs| prefs.save_folder)
+        .ok();
+    reconcile::scan(pool, recordings_root.as_deref())
+        .await
+        .map_err(|e| format!("Integrity scan failed: {}", e))
+}
+
+/// Startup scan for meetings whose folder is gone and folders no meeting
+/// references; emits `integrity-issues` with the report when there are any
+pub async fn scan_integrity_at_startup<R: Runtime>(app: AppHandle<R>) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let pool = state.db_manager.pool().clone();
+    match scan_integrity(&app, &pool).await {
+        Ok(report) if report.has_issues() => {
+            log::warn!(
+                "Integrity scan: {} meetings with missing folders, {} orphaned folders",
+                report.missing_folders.len(),
+                report.orphan_folders.len()
+            );
+            let _ = app.emit(INTEGRITY_EVENT, &report);
+        }
+        Ok(_) => {}
+        Err(e) => log_error!("{}", e),
+    }
+}
+
+/// The latest integrity report (from startup, or a fresh scan with `refresh`)
+#[tauri::command]
+pub async fn api_get_integrity_report<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    refresh: Option<bool>,
+) -> Result<IntegrityReport, String> {
+    log_info!("api_get_integrity_report called (refresh: {:?})", refresh);
+    match reconcile::last_report() {
+        Some(report) if !refresh.unwrap_or(false) => Ok(report),
+        _ => scan_integrity(&app, state.db_manager.pool()).await,
+    }
+}
+
+/// Applies one repair from the integrity report: relink a meeting to a
+/// folder, detach its missing folder, or delete an orphaned folder
+#[tauri::command]
+pub async fn api_repair_integrity<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    action: RepairAction,
+) -> Result<IntegrityReport, String> {
+    if let RepairAction::Relink { folder_path, .. } | RepairAction::DeleteOrphan { folder_path } =
+        &action
+    {
+        validation::local_path("folder_path", folder_path)?;
+    }
+    if let RepairAction::Relink { meeting_id, .. } | RepairAction::Detach { meeting_id } = &action {
+        validation::meeting_id(meeting_id)?;
+    }
+    log_info!("api_repair_integrity called: {:?}", action);
+    let pool = state.db_manager.pool();
+    let meeting_id = match &action {
+        RepairAction::Relink { meeting_id, .. } | RepairAction::Detach { meeting_id } => {
+            LegalHoldRepository::ensure_not_held(pool, meeting_id)
+                .await
+                .map_err(|e| e.to_string())?;
+            locks::ensure_unlocked(meeting_id).map_err(|e| e.to_string())?;
+            Some(meeting_id.as_str())
+        }
+        RepairAction::DeleteOrphan { .. } => None,
+    };
+    reconcile::repair(pool, &action).await?;
+    let entity = match meeting_id {
+        Some(_) => "meeting",
+        None => "recording_folder",
+    };
+    audit::record(
+        pool,
+        AuditAction::Maintenance,
+        entity,
+        meeting_id,
+        serde_json::json!({
+            "operation": "integrity_repair",
+            "repair": format!("{:?}", action),
+        }),
+    )
+    .await;
+    reconcile::last_report().ok_or_else(|| "No integrity report".to_string())
+}
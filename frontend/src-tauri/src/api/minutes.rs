@@ -0,0 +1,69 @@
+use log::{error as log_error, info as log_info};
+use sqlx::Error as SqlxError;
+use tauri::{AppHandle, Runtime};
+
+use crate::{
+    audit::{self, AuditAction},
+    database::repositories::minutes_status::{
+        MinutesState, MinutesStatus, MinutesStatusRepository,
+    },
+    state::AppState,
+};
+
+#[tauri::command]
+pub async fn api_get_minutes_status<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<MinutesStatus, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    MinutesStatusRepository::get(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load minutes status: {}", e))
+}
+
+/// Moves a meeting's minutes through the review workflow: "under_review"
+/// submits a draft, "approved" approves it and locks editing, and "draft"
+/// requests changes or reopens approved minutes. The current user is
+/// recorded as submitter or approver.
+#[tauri::command]
+pub async fn api_set_minutes_status<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    status: MinutesState,
+) -> Result<MinutesStatus, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_set_minutes_status called for meeting_id: {}, status: {}",
+        meeting_id,
+        status.as_str()
+    );
+    let pool = state.db_manager.pool();
+    let previous = MinutesStatusRepository::get(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load minutes status: {}", e))?;
+
+    let updated =
+        MinutesStatusRepository::transition(pool, &meeting_id, status, &audit::current_actor())
+            .await
+            .map_err(|e| match e {
+                SqlxError::RowNotFound => format!("Meeting {} not found", meeting_id),
+                e => {
+                    log_error!("Failed to update minutes status of {}: {}", meeting_id, e);
+                    format!("Failed to update minutes status: {}", e)
+                }
+            })?;
+    audit::record(
+        pool,
+        AuditAction::MinutesStatusChange,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({
+            "from": previous.status,
+            "to": updated.status,
+        }),
+    )
+    .await;
+    Ok(updated)
+}
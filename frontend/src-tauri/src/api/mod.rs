@@ -1,5 +1,23 @@
+pub mod action_items;
+pub mod activity;
 pub mod api;
+pub mod attachments;
+pub mod comments;
+pub mod corrections;
 pub mod commands;
+pub mod custom_fields;
+pub mod debounce;
+pub mod diagnostics;
+pub mod legal_hold;
+pub mod maintenance;
+pub mod minutes;
+pub mod participants;
+pub mod search;
+pub mod sharing;
+pub mod speakers;
+pub mod stats;
+pub mod tags;
+pub mod watchlist;
 
 pub use api::*;
 // Don't re-export commands to avoid conflicts - lib.rs will import directly
@@ -0,0 +1,169 @@
+use log::{error as log_error, info as log_info, warn as log_warn};
+use tauri::{AppHandle, Runtime};
+
+use crate::{
+    audit::{self, AuditAction},
+    database::{
+        models::MeetingParticipant,
+        repositories::participant::{ParticipantInput, ParticipantsRepository},
+    },
+    state::AppState,
+};
+
+#[tauri::command]
+pub async fn api_list_participants<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<MeetingParticipant>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_list_participants called for meeting_id: {}", meeting_id);
+
+    ParticipantsRepository::list_participants(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list participants for {}: {}", meeting_id, e);
+            format!("Failed to list participants: {}", e)
+        })
+}
+
+#[tauri::command]
+pub async fn api_add_participant<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    participant: ParticipantInput,
+) -> Result<MeetingParticipant, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_add_participant called for meeting_id: {}", meeting_id);
+
+    let pool = state.db_manager.pool();
+    let added = ParticipantsRepository::add_participant(pool, &meeting_id, &participant, "manual")
+        .await
+        .map_err(|e| {
+            log_error!("Failed to add participant to {}: {}", meeting_id, e);
+            format!("Failed to add participant: {}", e)
+        })?;
+    audit::record(
+        pool,
+        AuditAction::ParticipantChange,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({ "change": "add", "participant_id": added.id }),
+    )
+    .await;
+    Ok(added)
+}
+
+#[tauri::command]
+pub async fn api_update_participant<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    participant_id: String,
+    participant: ParticipantInput,
+) -> Result<serde_json::Value, String> {
+    crate::validation::participant_id(&participant_id)?;
+    log_info!("api_update_participant called for participant_id: {}", participant_id);
+
+    let pool = state.db_manager.pool();
+    match ParticipantsRepository::update_participant(pool, &participant_id, &participant).await {
+        Ok(true) => {
+            audit::record(
+                pool,
+                AuditAction::ParticipantChange,
+                "participant",
+                Some(&participant_id),
+                serde_json::json!({ "change": "update" }),
+            )
+            .await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "message": "Participant updated successfully"
+            }))
+        }
+        Ok(false) => {
+            log_warn!("Participant not found: {}", participant_id);
+            Err(format!("Participant not found: {}", participant_id))
+        }
+        Err(e) => {
+            log_error!("Failed to update participant {}: {}", participant_id, e);
+            Err(format!("Failed to update participant: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn api_remove_participant<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    participant_id: String,
+) -> Result<serde_json::Value, String> {
+    crate::validation::participant_id(&participant_id)?;
+    log_info!("api_remove_participant called for participant_id: {}", participant_id);
+
+    let pool = state.db_manager.pool();
+    match ParticipantsRepository::delete_participant(pool, &participant_id).await {
+        Ok(true) => {
+            audit::record(
+                pool,
+                AuditAction::ParticipantChange,
+                "participant",
+                Some(&participant_id),
+                serde_json::json!({ "change": "remove" }),
+            )
+            .await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "message": "Participant removed successfully"
+            }))
+        }
+        Ok(false) => Err(format!("Participant not found: {}", participant_id)),
+        Err(e) => {
+            log_error!("Failed to remove participant {}: {}", participant_id, e);
+            Err(format!("Failed to remove participant: {}", e))
+        }
+    }
+}
+
+/// Bulk-merges participants from an external source such as calendar attendees.
+/// Safe to call repeatedly for the same meeting; existing entries are matched by email/name.
+#[tauri::command]
+pub async fn api_import_participants<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    participants: Vec<ParticipantInput>,
+    source: Option<String>,
+) -> Result<serde_json::Value, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    let source = source.unwrap_or_else(|| "calendar".to_string());
+    log_info!(
+        "api_import_participants called for meeting_id: {}, count: {}, source: {}",
+        meeting_id,
+        participants.len(),
+        source
+    );
+
+    let pool = state.db_manager.pool();
+    match ParticipantsRepository::merge_participants(pool, &meeting_id, &participants, &source).await {
+        Ok(inserted) => {
+            audit::record(
+                pool,
+                AuditAction::ParticipantChange,
+                "meeting",
+                Some(&meeting_id),
+                serde_json::json!({ "change": "import", "source": source, "inserted": inserted }),
+            )
+            .await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "inserted": inserted,
+                "total": participants.len()
+            }))
+        }
+        Err(e) => {
+            log_error!("Failed to import participants for {}: {}", meeting_id, e);
+            Err(format!("Failed to import participants: {}", e))
+        }
+    }
+}
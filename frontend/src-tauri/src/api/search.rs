@@ -0,0 +1,170 @@
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Runtime};
+
+use crate::{
+    audit::{self, AuditAction},
+    database::repositories::{
+        search::{
+            MeetingFilter, MeetingQueryResult, SavedSearch, SearchRanking, SearchRepository,
+            TranscriptSearchPage,
+        },
+        transcript::TranscriptsRepository,
+    },
+    state::AppState,
+};
+
+/// Largest page `api_search_transcripts_page` returns
+const MAX_SEARCH_PAGE_SIZE: usize = 200;
+
+/// Text search with pagination. `ranking` is "recency" (default) or "relevance";
+/// each result carries the offsets of matched words in its snippet.
+#[tauri::command]
+pub async fn api_search_transcripts_page<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    query: String,
+    limit: Option<usize>,
+    offset: Option<usize>,
+    ranking: Option<SearchRanking>,
+) -> Result<TranscriptSearchPage, String> {
+    log_info!(
+        "api_search_transcripts_page called with query: '{}', limit: {:?}, offset: {:?}, ranking: {:?}",
+        query,
+        limit,
+        offset,
+        ranking
+    );
+
+    TranscriptsRepository::search_transcripts_page(
+        state.db_manager.pool(),
+        &query,
+        ranking.unwrap_or_default(),
+        offset.unwrap_or(0),
+        limit.unwrap_or(50).clamp(1, MAX_SEARCH_PAGE_SIZE),
+    )
+    .await
+    .map_err(|e| {
+        log_error!("Error searching transcripts for query '{}': {}", query, e);
+        format!("Failed to search transcripts: {}", e)
+    })
+}
+
+/// Finds meetings matching a structured filter (text, date range, duration,
+/// tags, speakers, summary state and custom field values), newest first
+#[tauri::command]
+pub async fn api_query_meetings<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    filter: MeetingFilter,
+) -> Result<Vec<MeetingQueryResult>, String> {
+    log_info!("api_query_meetings called with filter: {:?}", filter);
+
+    let results = SearchRepository::query_meetings(state.db_manager.pool(), &filter)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to query meetings: {}", e);
+            format!("Failed to query meetings: {}", e)
+        })?;
+    log_info!("Meeting query matched {} meetings", results.len());
+    Ok(results)
+}
+
+#[tauri::command]
+pub async fn api_list_saved_searches<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<SavedSearch>, String> {
+    log_info!("api_list_saved_searches called");
+
+    SearchRepository::list_saved_searches(state.db_manager.pool())
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list saved searches: {}", e);
+            format!("Failed to list saved searches: {}", e)
+        })
+}
+
+/// Saves `filter` under `name`. Passing `search_id` replaces that search.
+#[tauri::command]
+pub async fn api_save_search<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    search_id: Option<String>,
+    name: String,
+    filter: MeetingFilter,
+) -> Result<SavedSearch, String> {
+    log_info!("api_save_search called for name: {}", name);
+
+    let pool = state.db_manager.pool();
+    let saved = SearchRepository::save_search(pool, search_id.as_deref(), &name, &filter)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to save search {}: {}", name, e);
+            format!("Failed to save search: {}", e)
+        })?;
+
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "saved_search",
+        Some(&saved.id),
+        serde_json::json!({
+            "change": if search_id.is_some() { "update" } else { "create" },
+            "name": saved.name
+        }),
+    )
+    .await;
+    Ok(saved)
+}
+
+#[tauri::command]
+pub async fn api_delete_saved_search<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    search_id: String,
+) -> Result<serde_json::Value, String> {
+    crate::validation::search_id(&search_id)?;
+    log_info!("api_delete_saved_search called for search_id: {}", search_id);
+
+    let pool = state.db_manager.pool();
+    match SearchRepository::delete_saved_search(pool, &search_id).await {
+        Ok(true) => {
+            audit::record(
+                pool,
+                AuditAction::ConfigChange,
+                "saved_search",
+                Some(&search_id),
+                serde_json::json!({ "change": "delete" }),
+            )
+            .await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "message": "Saved search deleted"
+            }))
+        }
+        Ok(false) => Err(format!("Saved search not found: {}", search_id)),
+        Err(e) => {
+            log_error!("Failed to delete saved search {}: {}", search_id, e);
+            Err(format!("Failed to delete saved search: {}", e))
+        }
+    }
+}
+
+/// Re-runs a saved search against the current data
+#[tauri::command]
+pub async fn api_run_saved_search<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    search_id: String,
+) -> Result<Vec<MeetingQueryResult>, String> {
+    crate::validation::search_id(&search_id)?;
+    log_info!("api_run_saved_search called for search_id: {}", search_id);
+
+    SearchRepository::run_saved_search(state.db_manager.pool(), &search_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to run saved search {}: {}", search_id, e);
+            format!("Failed to run saved search: {}", e)
+        })?
+        .ok_or_else(|| format!("Saved search not found: {}", search_id))
+}
@@ -0,0 +1,518 @@
+use chrono::{DateTime, Duration, Utc};
+use log::{error as log_error, info as log_info};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_store::StoreExt;
+
+use super::api::{make_team_request, team_server_address, TEAM_SERVER_KEY};
+use crate::{
+    audit::{self, AuditAction},
+    database::{models::SegmentComment, repositories::segment_comment::SegmentCommentsRepository},
+    export::{
+        data_archive::{collect_single_meeting, ArchiveMeeting},
+        html::render_share_page,
+    },
+    state::AppState,
+    validation,
+};
+
+/// Most teammates one share request may name
+const MAX_RECIPIENTS: usize = 50;
+/// Longest a guest link may stay valid, in hours (30 days)
+const MAX_LINK_EXPIRY_HOURS: u32 = 30 * 24;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SharePermission {
+    ReadOnly,
+    Edit,
+}
+
+/// A teammate's access to a meeting, as kept by the backend
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MeetingShare {
+    pub id: String,
+    pub meeting_id: String,
+    pub email: String,
+    pub permission: SharePermission,
+    #[serde(default)]
+    pub shared_by: Option<String>,
+    pub created_at: String,
+}
+
+/// A time-limited public link to a rendered, read-only copy of a meeting
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShareLink {
+    pub id: String,
+    pub meeting_id: String,
+    pub url: String,
+    pub expires_at: DateTime<Utc>,
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+struct CreateShareLinkRequest<'a> {
+    meeting_id: &'a str,
+    title: &'a str,
+    html: &'a str,
+    expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+struct ShareMeetingRequest<'a> {
+    recipients: &'a [String],
+    permission: SharePermission,
+    meeting: &'a ArchiveMeeting,
+}
+
+/// Sharing always needs a signed-in user; the backend checks the token owns the meeting
+fn require_auth(auth_token: Option<String>) -> Result<String, String> {
+    auth_token
+        .filter(|token| !token.trim().is_empty())
+        .ok_or_else(|| "Sign in to share meetings with your team".to_string())
+}
+
+/// The team server address, or None while sharing isn't set up
+#[tauri::command]
+pub async fn api_get_team_server<R: Runtime>(app: AppHandle<R>) -> Result<Option<String>, String> {
+    Ok(team_server_address(&app).ok())
+}
+
+/// Sets the server meetings are shared through; None turns sharing off
+#[tauri::command]
+pub async fn api_set_team_server<R: Runtime>(
+    app: AppHandle<R>,
+    url: Option<String>,
+) -> Result<Option<String>, String> {
+    let url = url
+        .filter(|url| !url.trim().is_empty())
+        .map(|url| validation::http_url("url", &url))
+        .transpose()?
+        .map(|url| url.trim_end_matches('/').to_string());
+    log_info!("api_set_team_server called");
+    let store = app
+        .store("store.json")
+        .map_err(|e| format!("Failed to open the settings store: {}", e))?;
+    match &url {
+        Some(url) => store.set(TEAM_SERVER_KEY, serde_json::json!(url)),
+        None => {
+            store.delete(TEAM_SERVER_KEY);
+        }
+    }
+    store
+        .save()
+        .map_err(|e| format!("Failed to save the team server: {}", e))?;
+    Ok(url)
+}
+
+/// Trimmed, lowercased and deduplicated; rejects anything that isn't an address
+fn normalize_recipients(emails: &[String]) -> Result<Vec<String>, String> {
+    let mut recipients: Vec<String> = Vec::new();
+    for email in emails {
+        let email = email.trim().to_lowercase();
+        if email.is_empty() {
+            continue;
+        }
+        let valid = match email.split_once('@') {
+            Some((local, domain)) => {
+                !local.is_empty()
+                    && domain.contains('.')
+                    && !domain.starts_with('.')
+                    && !domain.ends_with('.')
+                    && !email.contains(char::is_whitespace)
+                    && !domain.contains('@')
+            }
+            None => false,
+        };
+        if !valid {
+            return Err(format!("\"{}\" is not a valid email address", email));
+        }
+        if !recipients.contains(&email) {
+            recipients.push(email);
+        }
+    }
+    if recipients.is_empty() {
+        return Err("Add at least one teammate to share with".to_string());
+    }
+    if recipients.len() > MAX_RECIPIENTS {
+        return Err(format!(
+            "A meeting can be shared with at most {} teammates at once",
+            MAX_RECIPIENTS
+        ));
+    }
+    Ok(recipients)
+}
+
+/// Uploads the meeting (transcript, summary, notes, action items) and grants
+/// the teammates `permission`. Sharing again with the same teammate updates
+/// their permission.
+#[tauri::command]
+pub async fn api_share_meeting<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    emails: Vec<String>,
+    permission: SharePermission,
+    auth_token: Option<String>,
+) -> Result<Vec<MeetingShare>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_share_meeting called for meeting_id: {}, recipients: {}, permission: {:?}",
+        meeting_id,
+        emails.len(),
+        permission
+    );
+    let auth_token = require_auth(auth_token)?;
+    let recipients = normalize_recipients(&emails)?;
+    let (meeting, redacted) = meeting_for_upload(&state, &meeting_id).await?;
+
+    let body = serde_json::to_string(&ShareMeetingRequest {
+        recipients: &recipients,
+        permission,
+        meeting: &meeting,
+    })
+    .map_err(|e| e.to_string())?;
+    let shares = make_team_request::<R, Vec<MeetingShare>>(
+        &app,
+        &format!("/meetings/{}/shares", meeting_id),
+        "POST",
+        Some(&body),
+        Some(auth_token),
+    )
+    .await?;
+
+    audit::record(
+        state.db_manager.pool(),
+        AuditAction::MeetingShare,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({
+            "change": "share",
+            "recipients": recipients,
+            "permission": permission,
+            "redacted": redacted,
+        }),
+    )
+    .await;
+    Ok(shares)
+}
+
+#[tauri::command]
+pub async fn api_list_meeting_shares<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    auth_token: Option<String>,
+) -> Result<Vec<MeetingShare>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_list_meeting_shares called for meeting_id: {}",
+        meeting_id
+    );
+    let auth_token = require_auth(auth_token)?;
+    make_team_request::<R, Vec<MeetingShare>>(
+        &app,
+        &format!("/meetings/{}/shares", meeting_id),
+        "GET",
+        None,
+        Some(auth_token),
+    )
+    .await
+}
+
+/// Removes a teammate's access; their copy is deleted by the backend
+#[tauri::command]
+pub async fn api_revoke_meeting_share<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    share_id: String,
+    auth_token: Option<String>,
+) -> Result<serde_json::Value, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_revoke_meeting_share called for meeting_id: {}, share_id: {}",
+        meeting_id,
+        share_id
+    );
+    let auth_token = require_auth(auth_token)?;
+    let response = make_team_request::<R, serde_json::Value>(
+        &app,
+        &format!("/meetings/{}/shares/{}", meeting_id, share_id),
+        "DELETE",
+        None,
+        Some(auth_token),
+    )
+    .await?;
+
+    audit::record(
+        state.db_manager.pool(),
+        AuditAction::MeetingShare,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({ "change": "revoke", "share_id": share_id }),
+    )
+    .await;
+    Ok(response)
+}
+
+/// Loads a meeting for upload, without local paths or attachments and with
+/// transcripts redacted when redaction applies to exports. Returns whether
+/// it was redacted.
+async fn meeting_for_upload(
+    state: &AppState,
+    meeting_id: &str,
+) -> Result<(ArchiveMeeting, bool), String> {
+    let mut meeting = collect_single_meeting(state.db_manager.pool(), meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to load meeting {} for sharing: {}", meeting_id, e);
+            format!("Failed to load meeting: {}", e)
+        })?
+        .ok_or_else(|| format!("Meeting {} not found", meeting_id))?;
+    meeting.folder_path = None;
+    meeting.attachments.clear();
+    let redacted = crate::redaction::settings().redact_exports;
+    if redacted {
+        meeting.redact_transcripts();
+    }
+    Ok((meeting, redacted))
+}
+
+/// `[mm:ss] Speaker: text`, with the wall-clock timestamp for segments
+/// recorded before audio timing was stored
+fn transcript_lines(meeting: &ArchiveMeeting) -> Vec<String> {
+    meeting
+        .transcripts
+        .iter()
+        .filter(|t| !t.transcript.trim().is_empty())
+        .map(|t| {
+            let stamp = match t.audio_start_time {
+                Some(seconds) => {
+                    let total = seconds.max(0.0) as u64;
+                    format!("{:02}:{:02}", total / 60, total % 60)
+                }
+                None => t.timestamp.clone(),
+            };
+            match &t.speaker {
+                Some(speaker) => format!("[{}] {}: {}", stamp, speaker, t.transcript.trim()),
+                None => format!("[{}] {}", stamp, t.transcript.trim()),
+            }
+        })
+        .collect()
+}
+
+/// Uploads a read-only page with the meeting's summary (and, unless
+/// `include_transcript` is false, its transcript) and returns a public URL
+/// that stops working after `expiry` hours (at most 30 days). Anyone with the
+/// URL can read the page, so it is meant for external attendees.
+#[tauri::command]
+pub async fn api_create_share_link<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    expiry: u32,
+    include_transcript: Option<bool>,
+    auth_token: Option<String>,
+) -> Result<ShareLink, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_create_share_link called for meeting_id: {}, expiry: {}h",
+        meeting_id,
+        expiry
+    );
+    let auth_token = require_auth(auth_token)?;
+    if expiry == 0 || expiry > MAX_LINK_EXPIRY_HOURS {
+        return Err(format!(
+            "Link expiry must be between 1 and {} hours",
+            MAX_LINK_EXPIRY_HOURS
+        ));
+    }
+
+    let (meeting, redacted) = meeting_for_upload(&state, &meeting_id).await?;
+    let summary = meeting
+        .summary
+        .as_ref()
+        .and_then(|s| s.result.as_ref())
+        .and_then(|result| result.get("markdown"))
+        .and_then(|markdown| markdown.as_str())
+        .map(str::trim)
+        .filter(|markdown| !markdown.is_empty());
+    let transcript = if include_transcript.unwrap_or(true) {
+        transcript_lines(&meeting)
+    } else {
+        Vec::new()
+    };
+    if summary.is_none() && transcript.is_empty() {
+        return Err("This meeting has no summary or transcript to share yet".to_string());
+    }
+    let html = render_share_page(
+        &meeting.title,
+        &meeting.created_at.format("%Y-%m-%d %H:%M").to_string(),
+        summary,
+        &transcript,
+    );
+
+    let body = serde_json::to_string(&CreateShareLinkRequest {
+        meeting_id: &meeting_id,
+        title: &meeting.title,
+        html: &html,
+        expires_at: Utc::now() + Duration::hours(i64::from(expiry)),
+    })
+    .map_err(|e| e.to_string())?;
+    let link = make_team_request::<R, ShareLink>(
+        &app,
+        "/share-links",
+        "POST",
+        Some(&body),
+        Some(auth_token),
+    )
+    .await?;
+
+    audit::record(
+        state.db_manager.pool(),
+        AuditAction::MeetingShare,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({
+            "change": "create_link",
+            "link_id": link.id,
+            "expires_at": link.expires_at,
+            "transcript": !transcript.is_empty(),
+            "redacted": redacted,
+        }),
+    )
+    .await;
+    Ok(link)
+}
+
+#[tauri::command]
+pub async fn api_list_share_links<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    auth_token: Option<String>,
+) -> Result<Vec<ShareLink>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_list_share_links called for meeting_id: {}", meeting_id);
+    let auth_token = require_auth(auth_token)?;
+    make_team_request::<R, Vec<ShareLink>>(
+        &app,
+        &format!("/meetings/{}/share-links", meeting_id),
+        "GET",
+        None,
+        Some(auth_token),
+    )
+    .await
+}
+
+/// Disables a guest link before it expires
+#[tauri::command]
+pub async fn api_revoke_share_link<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    link_id: String,
+    auth_token: Option<String>,
+) -> Result<serde_json::Value, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_revoke_share_link called for link_id: {}", link_id);
+    let auth_token = require_auth(auth_token)?;
+    let response = make_team_request::<R, serde_json::Value>(
+        &app,
+        &format!("/share-links/{}", link_id),
+        "DELETE",
+        None,
+        Some(auth_token),
+    )
+    .await?;
+
+    audit::record(
+        state.db_manager.pool(),
+        AuditAction::MeetingShare,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({ "change": "revoke_link", "link_id": link_id }),
+    )
+    .await;
+    Ok(response)
+}
+
+/// Exchanges segment comments with the teammates a meeting is shared with.
+/// Local comments are uploaded (redacted like the shared meeting), and
+/// comments the backend has that aren't here yet are stored locally.
+/// Returns the meeting's comments after the sync.
+#[tauri::command]
+pub async fn api_sync_meeting_comments<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    auth_token: Option<String>,
+) -> Result<Vec<SegmentComment>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_sync_meeting_comments called for meeting_id: {}",
+        meeting_id
+    );
+    let auth_token = require_auth(auth_token)?;
+    let (meeting, _) = meeting_for_upload(&state, &meeting_id).await?;
+    let body = serde_json::to_string(&serde_json::json!({ "comments": meeting.comments }))
+        .map_err(|e| format!("Failed to serialize comments: {}", e))?;
+
+    let remote = make_team_request::<R, Vec<SegmentComment>>(
+        &app,
+        &format!("/meetings/{}/comments/sync", meeting_id),
+        "POST",
+        Some(&body),
+        Some(auth_token),
+    )
+    .await?;
+
+    let pool = state.db_manager.pool();
+    let mut conn = pool
+        .acquire()
+        .await
+        .map_err(|e| format!("Failed to store synced comments: {}", e))?;
+    let added = SegmentCommentsRepository::insert_synced(&mut conn, &meeting_id, &remote)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to store synced comments for {}: {}", meeting_id, e);
+            format!("Failed to store synced comments: {}", e)
+        })?;
+    if added > 0 {
+        audit::record(
+            pool,
+            AuditAction::CommentChange,
+            "meeting",
+            Some(&meeting_id),
+            serde_json::json!({ "change": "sync", "added": added }),
+        )
+        .await;
+    }
+
+    SegmentCommentsRepository::list_for_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to list comments: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_recipients() {
+        let emails = vec![
+            " Jane@Example.com ".to_string(),
+            "jane@example.com".to_string(),
+            String::new(),
+            "bob@team.example.org".to_string(),
+        ];
+        assert_eq!(
+            normalize_recipients(&emails).unwrap(),
+            vec!["jane@example.com", "bob@team.example.org"]
+        );
+        assert!(normalize_recipients(&["not-an-address".to_string()]).is_err());
+        assert!(normalize_recipients(&["a@localhost".to_string()]).is_err());
+        assert!(normalize_recipients(&["a b@example.com".to_string()]).is_err());
+        assert!(normalize_recipients(&[String::new()]).is_err());
+    }
+}
@@ -0,0 +1,138 @@
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Runtime};
+
+use crate::{
+    audio::speaker_embedding::DEFAULT_MATCH_THRESHOLD,
+    audit::{self, AuditAction},
+    database::{
+        models::{MeetingSpeaker, VoiceProfile},
+        repositories::speaker::SpeakersRepository,
+    },
+    state::AppState,
+};
+
+#[tauri::command]
+pub async fn api_list_meeting_speakers<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<MeetingSpeaker>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_list_meeting_speakers called for meeting_id: {}", meeting_id);
+
+    SpeakersRepository::list_meeting_speakers(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list speakers for {}: {}", meeting_id, e);
+            format!("Failed to list speakers: {}", e)
+        })
+}
+
+/// Renames a diarized speaker (e.g. "Speaker 1" -> "Alice").
+/// With `remember_voice`, the speaker's embedding is saved to a local voice profile.
+#[tauri::command]
+pub async fn api_rename_speaker<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    speaker_label: String,
+    name: String,
+    remember_voice: Option<bool>,
+) -> Result<serde_json::Value, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_rename_speaker called for meeting_id: {}, label: {}",
+        meeting_id,
+        speaker_label
+    );
+
+    let pool = state.db_manager.pool();
+    match SpeakersRepository::rename_speaker(
+        pool,
+        &meeting_id,
+        &speaker_label,
+        &name,
+        remember_voice.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(profile_id) => {
+            audit::record(
+                pool,
+                AuditAction::SpeakerRename,
+                "meeting",
+                Some(&meeting_id),
+                serde_json::json!({ "speaker_label": speaker_label, "name": name }),
+            )
+            .await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "message": "Speaker renamed successfully",
+                "profile_id": profile_id
+            }))
+        }
+        Err(e) => {
+            log_error!("Failed to rename speaker {} in {}: {}", speaker_label, meeting_id, e);
+            Err(format!("Failed to rename speaker: {}", e))
+        }
+    }
+}
+
+/// Auto-labels unnamed speakers in a meeting using saved voice profiles
+#[tauri::command]
+pub async fn api_apply_voice_profiles<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    threshold: Option<f32>,
+) -> Result<serde_json::Value, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_apply_voice_profiles called for meeting_id: {}", meeting_id);
+
+    let threshold = threshold.unwrap_or(DEFAULT_MATCH_THRESHOLD).clamp(0.0, 1.0);
+    match SpeakersRepository::apply_voice_profiles(state.db_manager.pool(), &meeting_id, threshold)
+        .await
+    {
+        Ok(labelled) => Ok(serde_json::json!({
+            "status": "success",
+            "labelled": labelled
+        })),
+        Err(e) => {
+            log_error!("Failed to apply voice profiles to {}: {}", meeting_id, e);
+            Err(format!("Failed to apply voice profiles: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn api_list_voice_profiles<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<VoiceProfile>, String> {
+    log_info!("api_list_voice_profiles called");
+
+    SpeakersRepository::list_voice_profiles(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to list voice profiles: {}", e))
+}
+
+#[tauri::command]
+pub async fn api_delete_voice_profile<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    profile_id: String,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_delete_voice_profile called for profile_id: {}", profile_id);
+
+    match SpeakersRepository::delete_voice_profile(state.db_manager.pool(), &profile_id).await {
+        Ok(true) => Ok(serde_json::json!({
+            "status": "success",
+            "message": "Voice profile deleted"
+        })),
+        Ok(false) => Err(format!("Voice profile not found: {}", profile_id)),
+        Err(e) => {
+            log_error!("Failed to delete voice profile {}: {}", profile_id, e);
+            Err(format!("Failed to delete voice profile: {}", e))
+        }
+    }
+}
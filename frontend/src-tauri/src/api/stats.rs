@@ -0,0 +1,35 @@
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Runtime};
+
+use crate::{
+    database::repositories::meeting_stats::{MeetingStats, MeetingStatsRepository},
+    state::AppState,
+};
+
+/// Per-speaker talk time, interruptions and longest monologue for a meeting.
+/// Cached stats are reused until the transcript changes; `refresh` forces a recompute.
+#[tauri::command]
+pub async fn api_get_meeting_stats<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    refresh: Option<bool>,
+) -> Result<MeetingStats, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_get_meeting_stats called for meeting_id: {}, refresh: {:?}",
+        meeting_id,
+        refresh
+    );
+
+    MeetingStatsRepository::get_stats(
+        state.db_manager.pool(),
+        &meeting_id,
+        refresh.unwrap_or(false),
+    )
+    .await
+    .map_err(|e| {
+        log_error!("Failed to get stats for {}: {}", meeting_id, e);
+        format!("Failed to get meeting stats: {}", e)
+    })
+}
@@ -0,0 +1,81 @@
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Runtime};
+
+use crate::{
+    audit::{self, AuditAction},
+    database::repositories::tag::{TagCount, TagsRepository},
+    state::AppState,
+};
+
+/// Every tag in use with its meeting count, most used first
+#[tauri::command]
+pub async fn api_list_tags<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<TagCount>, String> {
+    log_info!("api_list_tags called");
+
+    TagsRepository::list_tags(state.db_manager.pool())
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list tags: {}", e);
+            format!("Failed to list tags: {}", e)
+        })
+}
+
+#[tauri::command]
+pub async fn api_get_meeting_tags<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<String>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_get_meeting_tags called for meeting_id: {}", meeting_id);
+
+    TagsRepository::get_tags(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to get tags for {}: {}", meeting_id, e);
+            format!("Failed to get tags: {}", e)
+        })
+}
+
+/// Replaces a meeting's tags
+#[tauri::command]
+pub async fn api_set_meeting_tags<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    tags: Vec<String>,
+) -> Result<Vec<String>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_set_meeting_tags called for meeting_id: {}", meeting_id);
+
+    let pool = state.db_manager.pool();
+    let meeting_exists: bool = sqlx::query("SELECT 1 FROM meetings WHERE id = ?")
+        .bind(&meeting_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("Failed to look up meeting: {}", e))?
+        .is_some();
+    if !meeting_exists {
+        return Err(format!("Meeting not found: {}", meeting_id));
+    }
+
+    let stored = TagsRepository::set_tags(pool, &meeting_id, &tags)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to set tags for {}: {}", meeting_id, e);
+            format!("Failed to set tags: {}", e)
+        })?;
+
+    audit::record(
+        pool,
+        AuditAction::TagChange,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({ "tags": stored }),
+    )
+    .await;
+    Ok(stored)
+}
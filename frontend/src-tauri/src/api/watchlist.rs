@@ -0,0 +1,121 @@
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Runtime};
+
+use crate::{
+    audio::keyword_alerts,
+    database::repositories::keyword_watch::{KeywordHit, KeywordWatchRepository, WatchKeyword},
+    state::AppState,
+};
+
+#[tauri::command]
+pub async fn api_list_watch_keywords<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<WatchKeyword>, String> {
+    log_info!("api_list_watch_keywords called");
+
+    KeywordWatchRepository::list_keywords(state.db_manager.pool())
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list watch keywords: {}", e);
+            format!("Failed to list watch keywords: {}", e)
+        })
+}
+
+/// Adds a word or phrase to flag during live transcription
+#[tauri::command]
+pub async fn api_add_watch_keyword<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    keyword: String,
+) -> Result<WatchKeyword, String> {
+    log_info!("api_add_watch_keyword called for: {}", keyword);
+
+    let pool = state.db_manager.pool();
+    let watch = KeywordWatchRepository::add_keyword(pool, &keyword)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to add watch keyword {}: {}", keyword, e);
+            format!("Failed to add watch keyword: {}", e)
+        })?;
+    keyword_alerts::refresh_watchlist(pool).await;
+    Ok(watch)
+}
+
+#[tauri::command]
+pub async fn api_set_watch_keyword_enabled<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    keyword_id: String,
+    enabled: bool,
+) -> Result<serde_json::Value, String> {
+    log_info!(
+        "api_set_watch_keyword_enabled called for {}: {}",
+        keyword_id,
+        enabled
+    );
+
+    let pool = state.db_manager.pool();
+    match KeywordWatchRepository::set_enabled(pool, &keyword_id, enabled).await {
+        Ok(true) => {
+            keyword_alerts::refresh_watchlist(pool).await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "message": if enabled { "Keyword enabled" } else { "Keyword disabled" }
+            }))
+        }
+        Ok(false) => Err(format!("Watch keyword not found: {}", keyword_id)),
+        Err(e) => {
+            log_error!("Failed to update watch keyword {}: {}", keyword_id, e);
+            Err(format!("Failed to update watch keyword: {}", e))
+        }
+    }
+}
+
+/// Removes a keyword from the watchlist; hits already recorded are kept
+#[tauri::command]
+pub async fn api_delete_watch_keyword<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    keyword_id: String,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_delete_watch_keyword called for: {}", keyword_id);
+
+    let pool = state.db_manager.pool();
+    match KeywordWatchRepository::delete_keyword(pool, &keyword_id).await {
+        Ok(true) => {
+            keyword_alerts::refresh_watchlist(pool).await;
+            Ok(serde_json::json!({
+                "status": "success",
+                "message": "Keyword removed from watchlist"
+            }))
+        }
+        Ok(false) => Err(format!("Watch keyword not found: {}", keyword_id)),
+        Err(e) => {
+            log_error!("Failed to delete watch keyword {}: {}", keyword_id, e);
+            Err(format!("Failed to delete watch keyword: {}", e))
+        }
+    }
+}
+
+/// Keyword hits of a meeting, or the most recent hits when no meeting is given
+#[tauri::command]
+pub async fn api_list_keyword_hits<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: Option<String>,
+    limit: Option<i64>,
+) -> Result<Vec<KeywordHit>, String> {
+    meeting_id
+        .as_deref()
+        .map(crate::validation::meeting_id)
+        .transpose()?;
+    log_info!("api_list_keyword_hits called for meeting: {:?}", meeting_id);
+
+    KeywordWatchRepository::list_hits(state.db_manager.pool(), meeting_id.as_deref(), limit)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list keyword hits: {}", e);
+            format!("Failed to list keyword hits: {}", e)
+        })
+}
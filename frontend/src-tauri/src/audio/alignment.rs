@@ -0,0 +1,327 @@
+//! Re-syncs the timing of edited transcript segments to the recording.
+//!
+//! Heavy edits (merges, rewritten sentences, moved text) leave segments with
+//! the timing of the text they replaced. The stretch of audio they cover is
+//! transcribed again in short windows, every recognized word is placed in
+//! its window by its share of the window's characters, and the segments'
+//! words are matched to the recognized ones in order (a longest common
+//! subsequence). A segment then spans its first to its last matched word.
+//! Accuracy is about a second, which is what playback sync needs; segments
+//! with no matched word keep their timing.
+
+use log::{info, warn};
+use serde::Serialize;
+use std::path::Path;
+use tauri::{AppHandle, Runtime};
+
+use super::audio_processing::find_meeting_audio;
+use super::import::{decode_range_to_mono_16k, split_range, IMPORT_SAMPLE_RATE};
+use super::transcription::engine::get_or_init_transcription_engine;
+use super::transcription::priority::{engine_turn, Priority};
+use crate::api::TranscriptSegment;
+use crate::audit::{self, AuditAction};
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::locks::MeetingActivity;
+use crate::state::AppState;
+
+/// Length of the windows the audio is transcribed in; shorter windows place
+/// words more precisely but give the model less context
+const WINDOW_SECONDS: f64 = 4.0;
+/// Audio before and after the segments that is searched too, since their
+/// text may have drifted outside their old timing
+const PADDING_SECONDS: f64 = 10.0;
+/// Longest stretch realigned at once; the matching table grows with the
+/// square of the words in it
+const MAX_SPAN_SECONDS: f64 = 300.0;
+
+/// A recognized word and where it was heard
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimedToken {
+    pub text: String,
+    pub start: f64,
+    pub end: f64,
+}
+
+/// The outcome of a realignment
+#[derive(Debug, Serialize)]
+pub struct AlignmentReport {
+    /// Segments whose timing changed
+    pub updated: Vec<TranscriptSegment>,
+    /// Segments left as they were: no word of theirs was heard
+    pub unmatched: Vec<String>,
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c as u32,
+        0x3040..=0x30FF   // Hiragana, Katakana
+        | 0x3400..=0x4DBF // CJK Extension A
+        | 0x4E00..=0x9FFF // CJK Unified Ideographs
+        | 0xAC00..=0xD7AF // Hangul
+        | 0xF900..=0xFAFF)
+}
+
+/// Lowercased words without punctuation. Scripts written without spaces are
+/// split into single characters, so Japanese aligns character by character.
+pub fn tokenize(text: &str) -> Vec<String> {
+    // A diarization label isn't speech
+    let text = match text.split_once(": ") {
+        Some((label, rest)) if label.starts_with("Speaker ") => rest,
+        _ => text,
+    };
+    let mut tokens = Vec::new();
+    for word in text.split_whitespace() {
+        let mut current = String::new();
+        for c in word.chars() {
+            if is_cjk(c) {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                tokens.push(c.to_string());
+            } else if c.is_alphanumeric() {
+                current.extend(c.to_lowercase());
+            }
+        }
+        if !current.is_empty() {
+            tokens.push(current);
+        }
+    }
+    tokens
+}
+
+/// The words of a window's transcript, each given the part of the window
+/// matching its share of the characters
+pub fn spread_tokens(text: &str, start: f64, end: f64) -> Vec<TimedToken> {
+    let tokens = tokenize(text);
+    let total: usize = tokens.iter().map(|t| t.chars().count()).sum();
+    if total == 0 {
+        return Vec::new();
+    }
+    let per_char = (end - start).max(0.0) / total as f64;
+    let mut cursor = start;
+    tokens
+        .into_iter()
+        .map(|text| {
+            let token_start = cursor;
+            cursor += per_char * text.chars().count() as f64;
+            TimedToken {
+                text,
+                start: token_start,
+                end: cursor,
+            }
+        })
+        .collect()
+}
+
+/// New (start, end) of each segment, matching their words in order against
+/// the `heard` tokens; None for a segment with no matched word
+pub fn align(segments: &[Vec<String>], heard: &[TimedToken]) -> Vec<Option<(f64, f64)>> {
+    // Every segment token, tagged with its segment
+    let expected: Vec<(usize, &str)> = segments
+        .iter()
+        .enumerate()
+        .flat_map(|(i, tokens)| tokens.iter().map(move |t| (i, t.as_str())))
+        .collect();
+    let (n, m) = (expected.len(), heard.len());
+    let mut result = vec![None; segments.len()];
+    if n == 0 || m == 0 {
+        return result;
+    }
+
+    // lcs[i][j]: longest common subsequence of expected[i..] and heard[j..]
+    let width = m + 1;
+    let mut lcs = vec![0u32; (n + 1) * width];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i * width + j] = if expected[i].1 == heard[j].text {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if expected[i].1 == heard[j].text {
+            let (start, end) = (heard[j].start, heard[j].end);
+            let span = result[expected[i].0].get_or_insert((start, end));
+            span.0 = span.0.min(start);
+            span.1 = span.1.max(end);
+            i += 1;
+            j += 1;
+        } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    result
+}
+
+/// Re-syncs the timing of `segment_ids` (segments of one meeting, typically
+/// ones just edited) to the meeting's recording with the configured engine.
+/// The change is journaled, so it can be undone this session.
+#[tauri::command]
+pub async fn api_realign_segments<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    segment_ids: Vec<String>,
+) -> Result<AlignmentReport, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    let pool = state.db_manager.pool();
+    let mut segments = Vec::new();
+    for id in &segment_ids {
+        let (owner, segment) = TranscriptsRepository::get_segment(pool, id)
+            .await
+            .map_err(|e| format!("Database error: {}", e))?
+            .ok_or_else(|| format!("Transcript segment not found: {}", id))?;
+        if owner != meeting_id {
+            return Err(format!("Segment {} belongs to another meeting", id));
+        }
+        segments.push(segment);
+    }
+    segments.sort_by(|a, b| {
+        let start = |s: &TranscriptSegment| s.audio_start_time.unwrap_or(f64::MAX);
+        start(a).total_cmp(&start(b))
+    });
+
+    let starts = segments.iter().filter_map(|s| s.audio_start_time);
+    let ends = segments
+        .iter()
+        .filter_map(|s| s.audio_end_time.or(s.audio_start_time));
+    let (Some(first), Some(last)) = (starts.reduce(f64::min), ends.reduce(f64::max)) else {
+        return Err("None of these segments has audio timing to start from".to_string());
+    };
+    let span_start = (first - PADDING_SECONDS).max(0.0);
+    let span_end = last + PADDING_SECONDS;
+    if span_end - span_start > MAX_SPAN_SECONDS {
+        return Err(format!(
+            "These segments span more than {} minutes; realign fewer at once",
+            MAX_SPAN_SECONDS as u64 / 60
+        ));
+    }
+
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+    let lock = state
+        .meeting_locks
+        .try_acquire(meeting_id.as_str(), MeetingActivity::Alignment)
+        .map_err(|e| e.to_string())?;
+    let audio = meeting
+        .folder_path
+        .and_then(|folder| find_meeting_audio(Path::new(&folder)))
+        .ok_or_else(|| "This meeting has no recording".to_string())?;
+    let samples =
+        tokio::task::spawn_blocking(move || decode_range_to_mono_16k(&audio, span_start, span_end))
+            .await
+            .map_err(|e| format!("Decode task failed: {}", e))?
+            .map_err(|e| format!("Failed to decode the meeting audio: {}", e))?;
+
+    let engine = get_or_init_transcription_engine(&app).await?;
+    info!(
+        "Realigning {} segments of {} ({:.1}s-{:.1}s) with {}",
+        segments.len(),
+        meeting_id,
+        span_start,
+        span_end,
+        engine.provider_name()
+    );
+    let language = crate::get_language_preference_internal();
+    let rate = IMPORT_SAMPLE_RATE as f64;
+    let window = (WINDOW_SECONDS * rate) as usize;
+    let mut heard = Vec::new();
+    for (from, to) in split_range(0, samples.len(), window) {
+        let turn = engine_turn(Priority::Background).await;
+        let result = engine
+            .transcribe_stream(samples[from..to].to_vec(), language.clone())
+            .await;
+        drop(turn);
+        let text = result
+            .map_err(|e| format!("Transcription failed: {}", e))?
+            .text;
+        heard.extend(spread_tokens(
+            &text,
+            span_start + from as f64 / rate,
+            span_start + to as f64 / rate,
+        ));
+    }
+
+    let tokens: Vec<Vec<String>> = segments.iter().map(|s| tokenize(&s.text)).collect();
+    let spans = align(&tokens, &heard);
+    let mut timings = Vec::new();
+    let mut unmatched = Vec::new();
+    for (segment, span) in segments.iter_mut().zip(spans) {
+        match span {
+            Some((start, end)) => {
+                segment.audio_start_time = Some(start);
+                segment.audio_end_time = Some(end);
+                segment.duration = Some(end - start);
+                timings.push((segment.id.clone(), start, end));
+            }
+            None => unmatched.push(segment.id.clone()),
+        }
+    }
+    if timings.is_empty() {
+        warn!("Realignment matched no words in meeting {}", meeting_id);
+        return Err("None of the segments' words were heard in the recording".to_string());
+    }
+
+    TranscriptsRepository::update_segment_timings(pool, &meeting_id, &timings, Some(&lock))
+        .await
+        .map_err(|e| format!("Failed to update segment timing: {}", e))?;
+    audit::record(
+        pool,
+        AuditAction::SegmentRealign,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({
+            "segments": timings.len(),
+            "unmatched": unmatched.len(),
+            "provider": engine.provider_name(),
+        }),
+    )
+    .await;
+
+    Ok(AlignmentReport {
+        updated: segments
+            .into_iter()
+            .filter(|s| !unmatched.contains(&s.id))
+            .collect(),
+        unmatched,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenizes_words_and_cjk_characters() {
+        assert_eq!(
+            tokenize("Speaker 1: Let's ship, OK?"),
+            vec!["lets", "ship", "ok"]
+        );
+        assert_eq!(tokenize("明日 review"), vec!["明", "日", "review"]);
+    }
+
+    #[test]
+    fn edited_segments_follow_the_heard_words() {
+        // 32 characters over 4s: "the" starts at 10.25s, "we" at 12.5s
+        let heard = spread_tokens("so the budget is fine and we ship friday", 10.0, 14.0);
+        let segments = vec![
+            tokenize("The budget is fine."),
+            tokenize("We ship on Friday."),
+            tokenize("Completely unrelated"),
+        ];
+        let spans = align(&segments, &heard);
+        let (start, end) = spans[0].unwrap();
+        assert!((start - 10.25).abs() < 1e-9 && (end - 12.125).abs() < 1e-9);
+        let (start, end) = spans[1].unwrap();
+        assert!((start - 12.5).abs() < 1e-9 && (end - 14.0).abs() < 1e-9);
+        assert_eq!(spans[2], None);
+    }
+}
@@ -6,10 +6,11 @@ use realfft::RealFftPlanner;
 use rubato::{
     Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction,
 };
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use nnnoiseless::DenoiseState;
 
 use super::encode::encode_single_audio; // Correct path to encode module
+use super::folder_template::{unique_folder_name, FolderNameContext};
 
 /// Sanitize a filename to be safe for filesystem use
 pub fn sanitize_filename(name: &str) -> String {
@@ -24,22 +25,29 @@ pub fn sanitize_filename(name: &str) -> String {
         .to_string()
 }
 
-/// Create a meeting folder with timestamp and return the path
-/// Creates structure: base_path/MeetingName_YYYY-MM-DD_HH-MM/
+/// Create a meeting folder named by the folder name template and return the path
+/// Creates structure: base_path/<name from template>/ (MeetingName_YYYY-MM-DD_HH-MM/ by default)
 ///                    ├── .checkpoints/  (for incremental saves, optional)
 ///
 /// # Arguments
 /// * `base_path` - Base directory for meetings
 /// * `meeting_name` - Name of the meeting
+/// * `tags` - Tags the meeting will be given, for the template's `{tags}`
+/// * `template` - Folder name template (see `folder_template`)
 /// * `create_checkpoints_dir` - Whether to create .checkpoints/ subdirectory (only needed when auto_save is true)
 pub fn create_meeting_folder(
     base_path: &PathBuf,
     meeting_name: &str,
+    tags: &[String],
+    template: &str,
     create_checkpoints_dir: bool,
 ) -> Result<PathBuf> {
-    let timestamp = Utc::now().format("%Y-%m-%d_%H-%M").to_string();
-    let sanitized_name = sanitize_filename(meeting_name);
-    let folder_name = format!("{}_{}", sanitized_name, timestamp);
+    let context = FolderNameContext {
+        title: meeting_name,
+        tags,
+        now: Utc::now(),
+    };
+    let folder_name = unique_folder_name(base_path, template, &context);
     let meeting_folder = base_path.join(folder_name);
 
     // Create main meeting folder
@@ -57,6 +65,14 @@ pub fn create_meeting_folder(
     Ok(meeting_folder)
 }
 
+/// The recording inside a meeting folder (`audio.mp4`, or `audio.<ext>` for imports)
+pub fn find_meeting_audio(folder: &Path) -> Option<PathBuf> {
+    super::import::SUPPORTED_MEDIA_EXTENSIONS
+        .iter()
+        .map(|extension| folder.join(format!("audio.{}", extension)))
+        .find(|path| path.is_file())
+}
+
 pub fn normalize_v2(audio: &[f32]) -> Vec<f32> {
     let rms = (audio.iter().map(|&x| x * x).sum::<f32>() / audio.len() as f32).sqrt();
     let peak = audio
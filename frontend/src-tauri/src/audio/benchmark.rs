@@ -0,0 +1,321 @@
+//! Transcription benchmark: runs a short clip through every installed local
+//! model and reports how fast each one is on this machine and how much memory
+//! it takes, to help choose between Whisper sizes and Parakeet.
+//!
+//! The clip is `samples/benchmark.wav` from the app's resources unless the
+//! caller passes another file. Each model is loaded into its own engine (the
+//! one used for recordings is left alone) and dropped before the next.
+
+use log::{info, warn};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::import::{
+    decode_to_mono_16k, load_local_provider, transcribe_samples, IMPORT_SAMPLE_RATE,
+};
+use crate::jobs::JobHandle;
+use crate::parakeet_engine::{ModelStatus as ParakeetStatus, ParakeetEngine};
+use crate::state::AppState;
+use crate::whisper_engine::{ModelStatus as WhisperStatus, WhisperEngine};
+
+pub const BENCHMARK_CLIP: &str = "samples/benchmark.wav";
+/// Longer clips are cut, so a benchmark of every model stays in minutes
+const MAX_CLIP_SECONDS: usize = 60;
+const MEMORY_SAMPLE_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkResult {
+    /// "localWhisper" or "parakeet", as in the transcript settings
+    pub provider: String,
+    pub model: String,
+    pub load_seconds: f64,
+    pub transcribe_seconds: f64,
+    /// Transcription time divided by clip length; below 1.0 is faster than real time
+    pub real_time_factor: Option<f64>,
+    /// Resident memory the loaded model added to the app
+    pub model_memory_mb: Option<f64>,
+    /// Highest resident memory of the app while transcribing
+    pub peak_memory_mb: Option<f64>,
+    pub words: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkReport {
+    pub clip_path: String,
+    pub clip_seconds: f64,
+    /// Fastest first; models that failed last
+    pub results: Vec<BenchmarkResult>,
+}
+
+pub fn real_time_factor(transcribe_seconds: f64, clip_seconds: f64) -> Option<f64> {
+    (clip_seconds > 0.0).then(|| transcribe_seconds / clip_seconds)
+}
+
+/// Fastest first; results without a factor (failed runs) go last
+pub fn sort_results(results: &mut [BenchmarkResult]) {
+    results.sort_by(|a, b| match (a.real_time_factor, b.real_time_factor) {
+        (Some(a), Some(b)) => a.total_cmp(&b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+}
+
+/// Resident memory of this process in bytes
+fn resident_bytes() -> Option<u64> {
+    let pid = sysinfo::get_current_pid().ok()?;
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::new().with_memory(),
+    );
+    system.process(pid).map(|process| process.memory())
+}
+
+fn to_mb(bytes: u64) -> f64 {
+    bytes as f64 / (1024.0 * 1024.0)
+}
+
+/// (provider, model) for every downloaded local model
+async fn installed_models(models_dir: &Path) -> Vec<(&'static str, String)> {
+    let mut models = Vec::new();
+    match WhisperEngine::new_with_models_dir(Some(models_dir.to_path_buf())) {
+        Ok(engine) => match engine.discover_models().await {
+            Ok(found) => models.extend(
+                found
+                    .into_iter()
+                    .filter(|m| matches!(m.status, WhisperStatus::Available))
+                    .map(|m| ("localWhisper", m.name)),
+            ),
+            Err(e) => warn!("Failed to list Whisper models: {}", e),
+        },
+        Err(e) => warn!("Failed to open the Whisper models folder: {}", e),
+    }
+    match ParakeetEngine::new_with_models_dir(Some(models_dir.to_path_buf())) {
+        Ok(engine) => match engine.discover_models().await {
+            Ok(found) => models.extend(
+                found
+                    .into_iter()
+                    .filter(|m| matches!(m.status, ParakeetStatus::Available))
+                    .map(|m| ("parakeet", m.name)),
+            ),
+            Err(e) => warn!("Failed to list Parakeet models: {}", e),
+        },
+        Err(e) => warn!("Failed to open the Parakeet models folder: {}", e),
+    }
+    models
+}
+
+async fn benchmark_model(
+    job: &JobHandle,
+    models_dir: &Path,
+    provider: &str,
+    model: &str,
+    samples: &[f32],
+) -> BenchmarkResult {
+    let clip_seconds = samples.len() as f64 / IMPORT_SAMPLE_RATE as f64;
+    let mut result = BenchmarkResult {
+        provider: provider.to_string(),
+        model: model.to_string(),
+        load_seconds: 0.0,
+        transcribe_seconds: 0.0,
+        real_time_factor: None,
+        model_memory_mb: None,
+        peak_memory_mb: None,
+        words: 0,
+        error: None,
+    };
+
+    let before = resident_bytes();
+    let started = Instant::now();
+    let engine = match load_local_provider(models_dir.to_path_buf(), provider, model).await {
+        Ok(engine) => engine,
+        Err(e) => {
+            result.error = Some(e.to_string());
+            return result;
+        }
+    };
+    result.load_seconds = started.elapsed().as_secs_f64();
+    let loaded = resident_bytes();
+    result.model_memory_mb = before
+        .zip(loaded)
+        .map(|(before, loaded)| to_mb(loaded.saturating_sub(before)));
+
+    // Sample memory while the model works; the peak is what matters on
+    // machines that are short of RAM
+    let peak = Arc::new(AtomicU64::new(loaded.unwrap_or(0)));
+    let sampler = {
+        let peak = peak.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(MEMORY_SAMPLE_INTERVAL).await;
+                if let Some(bytes) = resident_bytes() {
+                    peak.fetch_max(bytes, Ordering::Relaxed);
+                }
+            }
+        })
+    };
+    let started = Instant::now();
+    let transcribed = transcribe_samples(
+        engine.as_ref(),
+        samples,
+        None,
+        &job.cancellation_token(),
+        |_, _| {},
+    )
+    .await;
+    result.transcribe_seconds = started.elapsed().as_secs_f64();
+    sampler.abort();
+    if let Some(bytes) = resident_bytes() {
+        peak.fetch_max(bytes, Ordering::Relaxed);
+    }
+    let peak = peak.load(Ordering::Relaxed);
+    result.peak_memory_mb = (peak > 0).then(|| to_mb(peak));
+
+    match transcribed {
+        Ok(segments) => {
+            result.real_time_factor = real_time_factor(result.transcribe_seconds, clip_seconds);
+            result.words = segments
+                .iter()
+                .map(|segment| segment.text.split_whitespace().count())
+                .sum();
+        }
+        Err(e) => result.error = Some(e.to_string()),
+    }
+    result
+}
+
+async fn run_benchmark<R: Runtime>(
+    app: &AppHandle<R>,
+    job: &JobHandle,
+    clip: PathBuf,
+) -> Result<BenchmarkReport, String> {
+    let models_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))?
+        .join("models");
+    let decode_path = clip.clone();
+    let mut samples = tokio::task::spawn_blocking(move || decode_to_mono_16k(&decode_path))
+        .await
+        .map_err(|e| format!("Decoding task failed: {}", e))?
+        .map_err(|e| format!("Failed to decode the benchmark clip: {}", e))?;
+    samples.truncate(MAX_CLIP_SECONDS * IMPORT_SAMPLE_RATE as usize);
+    let clip_seconds = samples.len() as f64 / IMPORT_SAMPLE_RATE as f64;
+    if clip_seconds < 1.0 {
+        return Err("The benchmark clip has no audio".to_string());
+    }
+
+    let models = installed_models(&models_dir).await;
+    if models.is_empty() {
+        return Err("No transcription models are downloaded yet".to_string());
+    }
+
+    let mut results = Vec::new();
+    for (index, (provider, model)) in models.iter().enumerate() {
+        job.checkpoint().await?;
+        job.progress(
+            index as f64 / models.len() as f64,
+            Some(format!("Benchmarking {} ({})", model, provider)),
+        )
+        .await;
+        let result = benchmark_model(job, &models_dir, provider, model, &samples).await;
+        if job.is_cancelled() {
+            return Err("Cancelled".to_string());
+        }
+        info!(
+            "Benchmark {} ({}): load {:.1}s, RTF {:?}, +{:?} MB",
+            model, provider, result.load_seconds, result.real_time_factor, result.model_memory_mb
+        );
+        results.push(result);
+    }
+    sort_results(&mut results);
+
+    Ok(BenchmarkReport {
+        clip_path: clip.to_string_lossy().to_string(),
+        clip_seconds,
+        results,
+    })
+}
+
+/// Runs the bundled sample clip (or `clip_path`) through each downloaded
+/// Whisper and Parakeet model and reports real-time factor and memory use.
+/// Runs as a job, so it can be followed and cancelled like an import.
+#[tauri::command]
+pub async fn api_benchmark_transcription<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    clip_path: Option<String>,
+) -> Result<BenchmarkReport, String> {
+    info!("api_benchmark_transcription called (clip: {:?})", clip_path);
+    if super::recording_commands::is_recording().await {
+        return Err("Stop the current recording before running a benchmark".to_string());
+    }
+    let clip = match clip_path {
+        Some(path) => PathBuf::from(path),
+        None => app
+            .path()
+            .resource_dir()
+            .map_err(|e| format!("Failed to resolve resource dir: {}", e))?
+            .join(BENCHMARK_CLIP),
+    };
+    if !clip.is_file() {
+        return Err(format!("Benchmark clip not found: {}", clip.display()));
+    }
+
+    let job = state
+        .jobs
+        .begin(&app, "benchmark", "Transcription benchmark", None)
+        .await?;
+    let report = run_benchmark(&app, &job, clip).await;
+    job.finish(
+        report
+            .as_ref()
+            .map(|report| serde_json::to_value(report).unwrap_or_default())
+            .map_err(String::clone),
+    )
+    .await;
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(model: &str, real_time_factor: Option<f64>) -> BenchmarkResult {
+        BenchmarkResult {
+            provider: "localWhisper".to_string(),
+            model: model.to_string(),
+            load_seconds: 0.0,
+            transcribe_seconds: 0.0,
+            real_time_factor,
+            model_memory_mb: None,
+            peak_memory_mb: None,
+            words: 0,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn results_sort_fastest_first_with_failures_last() {
+        let mut results = vec![
+            result("large-v3", Some(0.8)),
+            result("broken", None),
+            result("tiny", Some(0.05)),
+        ];
+        sort_results(&mut results);
+        let order: Vec<&str> = results.iter().map(|r| r.model.as_str()).collect();
+        assert_eq!(order, ["tiny", "large-v3", "broken"]);
+        assert_eq!(real_time_factor(15.0, 60.0), Some(0.25));
+        assert_eq!(real_time_factor(1.0, 0.0), None);
+    }
+}
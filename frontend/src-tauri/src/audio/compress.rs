@@ -0,0 +1,270 @@
+//! Re-encodes uncompressed meeting recordings (WAV/AIFF/FLAC) to Opus or AAC
+//! to reclaim disk space.
+//!
+//! The compressed file is written next to the original, its duration is
+//! checked against the original with FFmpeg, and only then does it replace
+//! the original. A failed or mismatched encode leaves the folder untouched.
+
+use log::{info, warn};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Runtime};
+
+use super::audio_processing::find_meeting_audio;
+use super::import::{ffmpeg_command, probe_media};
+use crate::audit::{self, AuditAction};
+use crate::database::repositories::{legal_hold::LegalHoldRepository, meeting::MeetingsRepository};
+use crate::locks::MeetingActivity;
+use crate::state::AppState;
+
+/// Source formats worth compressing; everything else is already lossy
+const UNCOMPRESSED_EXTENSIONS: [&str; 4] = ["wav", "aiff", "aif", "flac"];
+/// Allowed drift between the original and the re-encoded duration
+const DURATION_TOLERANCE_SECONDS: f64 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioCodec {
+    Opus,
+    Aac,
+}
+
+impl AudioCodec {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "opus" => Ok(Self::Opus),
+            "aac" => Ok(Self::Aac),
+            other => Err(format!("Unsupported codec '{}'; use opus or aac", other)),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Opus => "opus",
+            Self::Aac => "aac",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Opus => "opus",
+            Self::Aac => "m4a",
+        }
+    }
+
+    fn encoder(self) -> &'static str {
+        match self {
+            Self::Opus => "libopus",
+            Self::Aac => "aac",
+        }
+    }
+
+    /// Speech stays clear at these rates; AAC needs more than Opus for the same quality
+    fn default_bitrate_kbps(self) -> u32 {
+        match self {
+            Self::Opus => 32,
+            Self::Aac => 96,
+        }
+    }
+}
+
+pub fn validate_bitrate(kbps: u32) -> Result<u32, String> {
+    if !(16..=320).contains(&kbps) {
+        return Err("Bitrate must be between 16 and 320 kbps".to_string());
+    }
+    Ok(kbps)
+}
+
+pub fn is_uncompressed(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| UNCOMPRESSED_EXTENSIONS.contains(&e.to_lowercase().as_str()))
+}
+
+pub fn durations_match(original: f64, compressed: f64) -> bool {
+    (original - compressed).abs() <= DURATION_TOLERANCE_SECONDS.max(original * 0.005)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CompressionReport {
+    pub meeting_id: String,
+    pub original_file: String,
+    pub compressed_file: String,
+    pub original_bytes: u64,
+    pub compressed_bytes: u64,
+    pub saved_bytes: u64,
+    pub duration_seconds: Option<f64>,
+}
+
+/// Encodes `source` to `codec` next to it, verifies the duration and swaps
+/// the files. Returns the new path and its duration. Runs FFmpeg, so call it
+/// off the async runtime.
+pub fn compress_file(
+    source: &Path,
+    codec: AudioCodec,
+    bitrate_kbps: u32,
+) -> Result<(PathBuf, Option<f64>), String> {
+    let original = probe_media(source).map_err(|e| e.to_string())?;
+    let target = source.with_extension(codec.extension());
+    if target.exists() {
+        return Err(format!("{} already exists", target.display()));
+    }
+    let partial = source.with_extension(format!("compressing.{}", codec.extension()));
+
+    let output = ffmpeg_command()
+        .map_err(|e| e.to_string())?
+        .args(["-y", "-hide_banner", "-loglevel", "error", "-i"])
+        .arg(source)
+        .args(["-vn", "-c:a", codec.encoder(), "-b:a"])
+        .arg(format!("{}k", bitrate_kbps))
+        .arg(&partial)
+        .output()
+        .map_err(|e| format!("Failed to run FFmpeg: {}", e))?;
+    if !output.status.success() {
+        let _ = std::fs::remove_file(&partial);
+        return Err(format!(
+            "FFmpeg failed to encode the recording: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let compressed = probe_media(&partial).map_err(|e| {
+        let _ = std::fs::remove_file(&partial);
+        format!("The encoded file can't be read back: {}", e)
+    })?;
+    if let (Some(expected), Some(actual)) = (original.duration_seconds, compressed.duration_seconds)
+    {
+        if !durations_match(expected, actual) {
+            let _ = std::fs::remove_file(&partial);
+            return Err(format!(
+                "The encoded file is {:.1}s long but the original is {:.1}s; kept the original",
+                actual, expected
+            ));
+        }
+    }
+
+    std::fs::rename(&partial, &target).map_err(|e| {
+        let _ = std::fs::remove_file(&partial);
+        format!("Failed to move the encoded file into place: {}", e)
+    })?;
+    if let Err(e) = std::fs::remove_file(source) {
+        warn!(
+            "Failed to remove {} after compressing: {}",
+            source.display(),
+            e
+        );
+    }
+    Ok((target, compressed.duration_seconds))
+}
+
+/// Re-encodes a meeting's WAV (or AIFF/FLAC) recording to `codec` ("opus" or
+/// "aac") at `bitrate` kbps and reports the space saved
+#[tauri::command]
+pub async fn api_compress_meeting_audio<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    codec: String,
+    bitrate: Option<u32>,
+) -> Result<CompressionReport, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    info!(
+        "api_compress_meeting_audio called for {} ({}, {:?} kbps)",
+        meeting_id, codec, bitrate
+    );
+    let codec = AudioCodec::parse(&codec)?;
+    let bitrate = validate_bitrate(bitrate.unwrap_or(codec.default_bitrate_kbps()))?;
+
+    let pool = state.db_manager.pool();
+    LegalHoldRepository::ensure_not_held(pool, &meeting_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    // Held until the compressed file has replaced the original
+    let _lock = state
+        .meeting_locks
+        .try_acquire(meeting_id.as_str(), MeetingActivity::AudioCompression)
+        .map_err(|e| e.to_string())?;
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+    let source = meeting
+        .folder_path
+        .as_deref()
+        .and_then(|folder| find_meeting_audio(Path::new(folder)))
+        .ok_or_else(|| "This meeting has no recording".to_string())?;
+    if !is_uncompressed(&source) {
+        return Err(format!(
+            "{} is already compressed",
+            source.file_name().unwrap_or_default().to_string_lossy()
+        ));
+    }
+
+    let original_bytes = std::fs::metadata(&source)
+        .map_err(|e| format!("Failed to read {}: {}", source.display(), e))?
+        .len();
+    let original = source.clone();
+    let (target, duration_seconds) =
+        tokio::task::spawn_blocking(move || compress_file(&original, codec, bitrate))
+            .await
+            .map_err(|e| format!("Compression task failed: {}", e))??;
+    let compressed_bytes = std::fs::metadata(&target).map(|m| m.len()).unwrap_or(0);
+
+    let report = CompressionReport {
+        meeting_id: meeting_id.clone(),
+        original_file: source.to_string_lossy().to_string(),
+        compressed_file: target.to_string_lossy().to_string(),
+        original_bytes,
+        compressed_bytes,
+        saved_bytes: original_bytes.saturating_sub(compressed_bytes),
+        duration_seconds,
+    };
+    info!(
+        "Compressed {} to {} ({} -> {} bytes)",
+        report.original_file, report.compressed_file, original_bytes, compressed_bytes
+    );
+    audit::record(
+        pool,
+        AuditAction::Maintenance,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({
+            "operation": "compress_audio",
+            "codec": codec.as_str(),
+            "bitrate_kbps": bitrate,
+            "saved_bytes": report.saved_bytes,
+        }),
+    )
+    .await;
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_codecs_and_bitrates() {
+        assert_eq!(AudioCodec::parse(" Opus "), Ok(AudioCodec::Opus));
+        assert_eq!(AudioCodec::parse("aac"), Ok(AudioCodec::Aac));
+        assert!(AudioCodec::parse("mp3").is_err());
+        assert!(validate_bitrate(64).is_ok());
+        assert!(validate_bitrate(8).is_err());
+        assert!(validate_bitrate(512).is_err());
+    }
+
+    #[test]
+    fn only_uncompressed_recordings_are_candidates() {
+        assert!(is_uncompressed(Path::new("/m/audio.wav")));
+        assert!(is_uncompressed(Path::new("/m/audio.FLAC")));
+        assert!(!is_uncompressed(Path::new("/m/audio.mp4")));
+        assert!(!is_uncompressed(Path::new("/m/audio")));
+    }
+
+    #[test]
+    fn tolerates_small_duration_drift() {
+        assert!(durations_match(3600.0, 3601.5));
+        assert!(!durations_match(3600.0, 3620.0));
+        assert!(durations_match(10.0, 10.4));
+        assert!(!durations_match(10.0, 11.0));
+    }
+}
@@ -0,0 +1,267 @@
+// audio/corrections.rs
+//
+// Correction learning. When a transcript segment is edited, the old and new
+// text are diffed word by word (character by character for Japanese and
+// Chinese) and each replaced phrase is recorded as a (wrong -> corrected)
+// pair. A pair made LEARN_AFTER times becomes a rule applied to new live,
+// imported and re-transcribed text. Rules are cached in memory so applying
+// them never waits on the database.
+
+use crate::database::repositories::correction::{CorrectionRule, CorrectionsRepository};
+use log::{info, warn};
+use sqlx::SqlitePool;
+use std::ops::Range;
+use std::sync::RwLock;
+
+/// Times a correction must be made before it is applied automatically, so a
+/// one-off edit that only made sense in context isn't repeated everywhere
+pub const LEARN_AFTER: i64 = 2;
+/// Longest phrase (in words, or characters for CJK text) learned from an edit
+const MAX_RULE_TOKENS: usize = 6;
+/// Edits between longer texts are not diffed
+const MAX_DIFF_CELLS: usize = 250_000;
+
+static RULES: RwLock<Vec<CorrectionRule>> = RwLock::new(Vec::new());
+
+/// Reloads the learned rules into the cache, longest phrase first so a rule
+/// for "new york times" wins over one for "york"
+pub async fn refresh_rules(pool: &SqlitePool) {
+    match CorrectionsRepository::learned_rules(pool, LEARN_AFTER).await {
+        Ok(mut rules) => {
+            rules.sort_by_key(|rule| std::cmp::Reverse(rule.wrong.chars().count()));
+            info!("Loaded {} learned corrections", rules.len());
+            *RULES.write().unwrap_or_else(|e| e.into_inner()) = rules;
+        }
+        Err(e) => warn!("Failed to load learned corrections: {}", e),
+    }
+}
+
+/// Empties the cache, e.g. when encrypted data is locked
+pub fn clear_rules() {
+    RULES.write().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+/// Applies the cached rules to freshly transcribed text
+pub fn apply(text: &str) -> String {
+    let rules = RULES.read().unwrap_or_else(|e| e.into_inner());
+    rules.iter().fold(text.to_string(), |text, rule| {
+        replace_phrase(&text, &rule.wrong, &rule.corrected)
+    })
+}
+
+/// Records the corrections between a segment's old and edited text and
+/// refreshes the rules
+pub async fn learn(pool: &SqlitePool, old: &str, new: &str) {
+    let pairs = correction_pairs(old, new);
+    if pairs.is_empty() {
+        return;
+    }
+    for (wrong, corrected) in &pairs {
+        if let Err(e) = CorrectionsRepository::record_correction(pool, wrong, corrected).await {
+            warn!(
+                "Failed to record correction '{}' -> '{}': {}",
+                wrong, corrected, e
+            );
+        }
+    }
+    info!(
+        "Recorded {} corrections from a transcript edit",
+        pairs.len()
+    );
+    refresh_rules(pool).await;
+}
+
+/// Japanese kana and CJK ideographs, which are written without spaces
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}'
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{FF66}'..='\u{FF9F}')
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() && !is_cjk(c)
+}
+
+/// Byte ranges of the words in `text`; each CJK character is its own token
+/// and punctuation and spaces are skipped
+fn tokens(text: &str) -> Vec<Range<usize>> {
+    let mut tokens = Vec::new();
+    let mut word: Option<usize> = None;
+    for (i, c) in text.char_indices() {
+        if is_word_char(c) {
+            word.get_or_insert(i);
+            continue;
+        }
+        if let Some(start) = word.take() {
+            tokens.push(start..i);
+        }
+        if is_cjk(c) {
+            tokens.push(i..i + c.len_utf8());
+        }
+    }
+    if let Some(start) = word {
+        tokens.push(start..text.len());
+    }
+    tokens
+}
+
+/// Runs of tokens that differ between `old` and `new`, as index ranges into
+/// each, from a longest-common-subsequence alignment
+fn changed_runs(old: &[&str], new: &[&str]) -> Vec<(Range<usize>, Range<usize>)> {
+    let (n, m) = (old.len(), new.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut runs = Vec::new();
+    let mut run: Option<(usize, usize)> = None;
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && old[i] == new[j] {
+            if let Some((start_i, start_j)) = run.take() {
+                runs.push((start_i..i, start_j..j));
+            }
+            i += 1;
+            j += 1;
+        } else {
+            run.get_or_insert((i, j));
+            if j < m && (i == n || lcs[i][j + 1] >= lcs[i + 1][j]) {
+                j += 1;
+            } else {
+                i += 1;
+            }
+        }
+    }
+    if let Some((start_i, start_j)) = run {
+        runs.push((start_i..n, start_j..m));
+    }
+    runs
+}
+
+/// Whether `a` and `b` differ only in the case of their first letter, as
+/// when an edit starts a sentence; that says nothing about recognition
+fn is_recapitalization(a: &str, b: &str) -> bool {
+    a.to_lowercase() == b.to_lowercase() && a.chars().skip(1).eq(b.chars().skip(1))
+}
+
+/// The phrases replaced by an edit. Insertions and deletions aren't
+/// substitutions and are ignored, as are long rewrites and number changes.
+pub fn correction_pairs(old: &str, new: &str) -> Vec<(String, String)> {
+    let old_tokens = tokens(old);
+    let new_tokens = tokens(new);
+    if old_tokens.len() * new_tokens.len() > MAX_DIFF_CELLS {
+        return Vec::new();
+    }
+    let old_words: Vec<&str> = old_tokens.iter().map(|r| &old[r.clone()]).collect();
+    let new_words: Vec<&str> = new_tokens.iter().map(|r| &new[r.clone()]).collect();
+
+    changed_runs(&old_words, &new_words)
+        .into_iter()
+        .filter(|(o, n)| {
+            !o.is_empty()
+                && !n.is_empty()
+                && o.len() <= MAX_RULE_TOKENS
+                && n.len() <= MAX_RULE_TOKENS
+        })
+        .map(|(o, n)| {
+            (
+                old[old_tokens[o.start].start..old_tokens[o.end - 1].end].to_string(),
+                new[new_tokens[n.start].start..new_tokens[n.end - 1].end].to_string(),
+            )
+        })
+        .filter(|(wrong, corrected)| {
+            !is_recapitalization(wrong, corrected)
+                && wrong.chars().any(char::is_alphabetic)
+                && corrected.chars().any(char::is_alphabetic)
+        })
+        .collect()
+}
+
+fn same_letter(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// Replaces `wrong` with `corrected`, ignoring case. Latin phrases only match
+/// whole words; CJK text has no word boundaries to check.
+pub fn replace_phrase(text: &str, wrong: &str, corrected: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let pattern: Vec<char> = wrong.chars().collect();
+    let (Some(&first), Some(&last)) = (pattern.first(), pattern.last()) else {
+        return text.to_string();
+    };
+
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let end = i + pattern.len();
+        let matches = end <= chars.len()
+            && chars[i..end]
+                .iter()
+                .zip(&pattern)
+                .all(|(&a, &b)| same_letter(a, b))
+            && !(is_word_char(first) && i > 0 && is_word_char(chars[i - 1]))
+            && !(is_word_char(last) && end < chars.len() && is_word_char(chars[end]));
+        if matches {
+            out.push_str(corrected);
+            i = end;
+        } else {
+            out.push(chars[i]);
+            i += 1;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edits_yield_replaced_phrases() {
+        assert_eq!(
+            correction_pairs(
+                "We moved the release to cuber netties next week.",
+                "We moved the release to Kubernetes next week."
+            ),
+            vec![("cuber netties".to_string(), "Kubernetes".to_string())]
+        );
+        assert_eq!(
+            correction_pairs(
+                "来週の会議で規格を確認します",
+                "来週の会議で企画を確認します"
+            ),
+            vec![("規格".to_string(), "企画".to_string())]
+        );
+        // Sentence case, deletions and numbers aren't learned
+        assert!(correction_pairs("the plan is fine", "The plan is fine").is_empty());
+        assert!(correction_pairs("um the plan is fine", "the plan is fine").is_empty());
+        assert!(correction_pairs("it costs 15", "it costs 50").is_empty());
+    }
+
+    #[test]
+    fn rules_replace_whole_words_ignoring_case() {
+        assert_eq!(
+            replace_phrase(
+                "Cuber netties is up. cuber netties!",
+                "cuber netties",
+                "Kubernetes"
+            ),
+            "Kubernetes is up. Kubernetes!"
+        );
+        assert_eq!(replace_phrase("postgres", "gres", "x"), "postgres");
+        assert_eq!(
+            replace_phrase("新しい規格の話です", "規格", "企画"),
+            "新しい企画の話です"
+        );
+    }
+}
@@ -0,0 +1,873 @@
+//! In-app import of recordings made elsewhere (cloud meeting services,
+//! local files).
+//!
+//! The file is moved or copied into a new meeting folder under the recordings
+//! root, decoded and transcribed with the transcription engine selected in
+//! the app (the same VAD + per-window pipeline the CLI uses), and saved as a
+//! meeting. Integrations download the recording and hand it to
+//! [`import_recording`] together with the title and participants they know.
+//!
+//! Transcripts exported from other tools (.vtt, .srt, .json) are imported
+//! without audio by [`import_transcript`].
+//!
+//! Before importing, dropped files can be checked with
+//! [`validate_import_file`] so the UI can show what will and won't import.
+//!
+//! Every imported file is fingerprinted (SHA-256 of its bytes). Importing
+//! the same file again is reported, skipped or linked to the earlier meeting
+//! depending on the [`DuplicatePolicy`]. Downloads also record where they
+//! came from, so integrations can apply the policy with
+//! [`find_earlier_download`] before downloading the same recording again.
+
+use futures_util::StreamExt;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager, Runtime};
+use tokio_util::sync::CancellationToken;
+
+use super::audio_processing::create_meeting_folder;
+use super::corrections;
+use super::import::{
+    decode_head_to_mono_16k, decode_to_mono_16k, probe_media, salvage_to_mono_16k,
+    transcribe_samples, SalvageReport, IMPORT_SAMPLE_RATE, SUPPORTED_MEDIA_EXTENSIONS,
+};
+use super::recording_preferences::load_recording_preferences;
+use super::recordings_root::resolve_recordings_root;
+use super::speaker_embedding::{speaker_embeddings, DEFAULT_MATCH_THRESHOLD};
+use super::transcript_import::{is_transcript_file, read_transcript_file};
+use super::transcription::priority::{engine_turn, Priority};
+use super::transcription::routing::engine_for_samples;
+use super::transcription::validate_transcription_model_ready;
+use crate::api::TranscriptSegment;
+use crate::database::manager::DatabaseManager;
+use crate::database::repositories::{
+    import_fingerprint::{DuplicateImport, ImportFingerprintRepository},
+    participant::{ParticipantInput, ParticipantsRepository},
+    speaker::SpeakersRepository,
+    tag::TagsRepository,
+};
+use crate::error::AppError;
+use crate::events::{self, ImportProgress};
+use crate::validation;
+use crate::locks::{folder_key, MeetingActivity, MeetingLocks};
+use crate::state::AppState;
+
+/// A recording to import
+pub struct ImportRequest {
+    pub file: PathBuf,
+    pub title: String,
+    /// Move the file instead of copying it (for temporary downloads)
+    pub move_file: bool,
+    pub participants: Vec<ParticipantInput>,
+    /// Tags given to the meeting, also available to the folder name template
+    pub tags: Vec<String>,
+    /// Participant source recorded with the participants ("zoom", ...)
+    pub source: &'static str,
+    /// Where the file was downloaded from ("zoom:<file id>", "url:<link>"),
+    /// recorded for [`find_earlier_download`]
+    pub origin: Option<String>,
+    pub on_duplicate: DuplicatePolicy,
+    /// Stops the import between transcription windows; the half-built
+    /// meeting folder is removed
+    pub cancel: CancellationToken,
+}
+
+/// What to do when the file was imported before
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicatePolicy {
+    /// Import anyway and report the earlier meetings
+    #[default]
+    Warn,
+    /// Don't import; fail naming the earlier meeting
+    Skip,
+    /// Don't import; return the earlier meeting instead
+    LinkExisting,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportedMeeting {
+    pub meeting_id: String,
+    pub title: String,
+    /// None for transcripts imported without audio
+    pub folder_path: Option<String>,
+    pub segment_count: usize,
+    /// Set when the recording was damaged and only partly recovered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub salvage: Option<SalvageReport>,
+    /// Earlier meetings imported from the same file
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub duplicates: Vec<DuplicateImport>,
+    /// True when no new meeting was created because the file was already
+    /// imported as `meeting_id`
+    pub linked_existing: bool,
+}
+
+/// Identifies a file's content for duplicate detection
+#[derive(Debug, Clone)]
+pub struct FileFingerprint {
+    /// Hex SHA-256 of the file's bytes
+    pub hash: String,
+    pub size: u64,
+    pub file_name: Option<String>,
+}
+
+/// Hashes a file in chunks; call it off the async runtime
+pub fn fingerprint_file(path: &Path) -> std::io::Result<FileFingerprint> {
+    let mut file = std::fs::File::open(path)?;
+    let mut context = ring::digest::Context::new(&ring::digest::SHA256);
+    let mut buffer = vec![0u8; 64 * 1024];
+    let mut size = 0u64;
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        context.update(&buffer[..read]);
+        size += read as u64;
+    }
+    let hash = context
+        .finish()
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    Ok(FileFingerprint {
+        hash,
+        size,
+        file_name: path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string()),
+    })
+}
+
+/// Fingerprints `file` and looks up meetings imported from the same content
+pub async fn find_duplicates(
+    pool: &SqlitePool,
+    file: &Path,
+) -> Result<(FileFingerprint, Vec<DuplicateImport>), String> {
+    let path = file.to_path_buf();
+    let fingerprint = tokio::task::spawn_blocking(move || fingerprint_file(&path))
+        .await
+        .map_err(|e| format!("Fingerprinting task failed: {}", e))?
+        .map_err(|e| format!("Failed to read {}: {}", file.display(), e))?;
+    let duplicates = ImportFingerprintRepository::find_by_hash(pool, &fingerprint.hash)
+        .await
+        .map_err(|e| format!("Failed to check for earlier imports: {}", e))?;
+    Ok((fingerprint, duplicates))
+}
+
+/// Applies `policy` to the meetings already imported from `origin`, before
+/// the recording is downloaded. Returns the earlier meeting when the import
+/// should link to it; with [`DuplicatePolicy::Warn`] the download goes ahead
+/// and the duplicate is reported once its content was fingerprinted.
+pub async fn find_earlier_download(
+    pool: &SqlitePool,
+    origin: &str,
+    policy: DuplicatePolicy,
+) -> Result<Option<ImportedMeeting>, String> {
+    let duplicates = ImportFingerprintRepository::find_by_origin(pool, origin)
+        .await
+        .map_err(|e| format!("Failed to check for earlier imports: {}", e))?;
+    resolve_duplicate(policy, &duplicates)
+}
+
+/// Applies the duplicate policy. Returns the earlier meeting when the import
+/// should link to it instead of creating a new one.
+fn resolve_duplicate(
+    policy: DuplicatePolicy,
+    duplicates: &[DuplicateImport],
+) -> Result<Option<ImportedMeeting>, String> {
+    let Some(existing) = duplicates.first() else {
+        return Ok(None);
+    };
+    match policy {
+        DuplicatePolicy::Warn => Ok(None),
+        DuplicatePolicy::Skip => Err(format!("Already imported as '{}'; skipped", existing.title)),
+        DuplicatePolicy::LinkExisting => Ok(Some(ImportedMeeting {
+            meeting_id: existing.meeting_id.clone(),
+            title: existing.title.clone(),
+            folder_path: existing.folder_path.clone(),
+            segment_count: existing.segment_count.max(0) as usize,
+            salvage: None,
+            duplicates: duplicates.to_vec(),
+            linked_existing: true,
+        })),
+    }
+}
+
+/// Records that `meeting_id` was imported from the fingerprinted file; a
+/// failure is only logged since the meeting itself was saved
+pub async fn record_fingerprint(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    fingerprint: &FileFingerprint,
+    source: &str,
+    origin: Option<&str>,
+) {
+    if let Err(e) = ImportFingerprintRepository::record(
+        pool,
+        meeting_id,
+        &fingerprint.hash,
+        fingerprint.file_name.as_deref(),
+        fingerprint.size,
+        source,
+        origin,
+    )
+    .await
+    {
+        warn!(
+            "Failed to record the import fingerprint of {}: {}",
+            meeting_id, e
+        );
+    }
+}
+
+/// Stores a voice signature for each diarized speaker, then names the
+/// speakers whose voice matches a saved profile
+async fn record_speakers(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    samples: Vec<f32>,
+    segments: &[TranscriptSegment],
+) {
+    let labelled: Vec<TranscriptSegment> = segments
+        .iter()
+        .filter(|segment| segment.speaker.is_some())
+        .cloned()
+        .collect();
+    if labelled.is_empty() {
+        return;
+    }
+    let embeddings = match tokio::task::spawn_blocking(move || {
+        speaker_embeddings(&samples, IMPORT_SAMPLE_RATE, &labelled)
+    })
+    .await
+    {
+        Ok(embeddings) => embeddings,
+        Err(e) => {
+            warn!("Voice signature task failed for {}: {}", meeting_id, e);
+            return;
+        }
+    };
+    for (label, embedding) in &embeddings {
+        if let Err(e) =
+            SpeakersRepository::record_speaker_embedding(pool, meeting_id, label, embedding).await
+        {
+            warn!(
+                "Failed to store the voice of {} in {}: {}",
+                label, meeting_id, e
+            );
+            return;
+        }
+    }
+    match SpeakersRepository::apply_voice_profiles(pool, meeting_id, DEFAULT_MATCH_THRESHOLD).await
+    {
+        Ok(named) if named > 0 => info!(
+            "Named {} speakers of {} from voice profiles",
+            named, meeting_id
+        ),
+        Ok(_) => {}
+        Err(e) => warn!("Failed to match voice profiles for {}: {}", meeting_id, e),
+    }
+}
+
+/// Files checked at the same time by the batch validation; each check runs
+/// an FFmpeg process
+const VALIDATION_CONCURRENCY: usize = 4;
+
+/// Outcome of checking a file before import
+#[derive(Debug, Clone, Serialize)]
+pub struct FileValidation {
+    pub path: String,
+    pub file_name: String,
+    /// "audio" or "transcript" when the file can be imported
+    pub kind: Option<&'static str>,
+    pub valid: bool,
+    pub size_bytes: Option<u64>,
+    pub duration_seconds: Option<f64>,
+    /// Why the file can't be imported (unsupported, corrupt, no audio, ...)
+    pub reason: Option<String>,
+}
+
+/// How much audio the import preview transcribes
+const PREVIEW_SECONDS: f64 = 60.0;
+
+/// A quick look at a recording before committing to a full import
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportPreview {
+    pub file_name: String,
+    /// Text transcribed from the start of the recording
+    pub sample_text: String,
+    pub sample_seconds: f64,
+    /// Length of the whole recording, when FFmpeg reports it
+    pub duration_seconds: Option<f64>,
+    pub language: Option<String>,
+    /// "detected" from the audio or "configured" in the transcript settings
+    pub language_source: Option<&'static str>,
+    /// Expected transcription time for the whole recording, extrapolated from
+    /// the preview
+    pub estimated_import_seconds: Option<f64>,
+}
+
+/// Scales the time the sample took to the full recording
+fn estimate_import_seconds(
+    sample_seconds: f64,
+    elapsed_seconds: f64,
+    duration_seconds: Option<f64>,
+) -> Option<f64> {
+    let duration = duration_seconds?;
+    if sample_seconds <= 0.0 || duration <= 0.0 {
+        return None;
+    }
+    if duration <= sample_seconds {
+        return Some(elapsed_seconds);
+    }
+    Some(elapsed_seconds * duration / sample_seconds)
+}
+
+/// Moves or copies `file` into `folder` as `audio.<ext>`
+fn place_in_folder(file: &Path, folder: &Path, move_file: bool) -> std::io::Result<PathBuf> {
+    let extension = file
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("mp4")
+        .to_lowercase();
+    let target = folder.join(format!("audio.{}", extension));
+    if move_file && std::fs::rename(file, &target).is_ok() {
+        return Ok(target);
+    }
+    // Rename fails across volumes; fall back to copying
+    std::fs::copy(file, &target)?;
+    if move_file {
+        if let Err(e) = std::fs::remove_file(file) {
+            warn!("Failed to remove {} after import: {}", file.display(), e);
+        }
+    }
+    Ok(target)
+}
+
+/// Checks that a file can be imported: it exists and is not empty, has a
+/// supported extension, and FFmpeg can read it and finds an audio stream
+/// (transcripts are parsed instead). Runs FFmpeg, so call it off the async
+/// runtime.
+pub fn validate_import_file(path: &Path) -> FileValidation {
+    let mut result = FileValidation {
+        path: path.to_string_lossy().to_string(),
+        file_name: path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        kind: None,
+        valid: false,
+        size_bytes: None,
+        duration_seconds: None,
+        reason: None,
+    };
+    let invalid = |mut result: FileValidation, reason: String| {
+        result.reason = Some(reason);
+        result
+    };
+
+    let metadata = match std::fs::metadata(path) {
+        Ok(metadata) if metadata.is_file() => metadata,
+        Ok(_) => return invalid(result, "Not a file".to_string()),
+        Err(e) => return invalid(result, format!("Can't read the file: {}", e)),
+    };
+    result.size_bytes = Some(metadata.len());
+    if metadata.len() == 0 {
+        return invalid(result, "The file is empty".to_string());
+    }
+
+    if is_transcript_file(path) {
+        result.kind = Some("transcript");
+        return match read_transcript_file(path) {
+            Ok(parsed) => {
+                result.duration_seconds = parsed
+                    .segments
+                    .last()
+                    .and_then(|segment| segment.audio_end_time);
+                result.valid = true;
+                result
+            }
+            Err(e) => invalid(result, e.to_string()),
+        };
+    }
+
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+    if !SUPPORTED_MEDIA_EXTENSIONS.contains(&extension.as_str()) {
+        let reason = match extension.as_str() {
+            "" => "Unsupported file type".to_string(),
+            extension => format!("Unsupported file type .{}", extension),
+        };
+        return invalid(result, reason);
+    }
+
+    result.kind = Some("audio");
+    match probe_media(path) {
+        Ok(probe) if !probe.has_audio => invalid(result, "The file has no audio track".to_string()),
+        Ok(probe) => {
+            result.duration_seconds = probe.duration_seconds;
+            result.valid = true;
+            result
+        }
+        Err(e) => invalid(result, format!("Corrupt or unreadable: {}", e)),
+    }
+}
+
+async fn validate_in_background(path: String) -> FileValidation {
+    let checked = path.clone();
+    match tokio::task::spawn_blocking(move || validate_import_file(Path::new(&checked))).await {
+        Ok(result) => result,
+        Err(e) => FileValidation {
+            file_name: Path::new(&path)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            path,
+            kind: None,
+            valid: false,
+            size_bytes: None,
+            duration_seconds: None,
+            reason: Some(format!("Validation failed: {}", e)),
+        },
+    }
+}
+
+/// Checks a single file before import
+#[tauri::command]
+pub async fn validate_audio_file_command(path: String) -> Result<FileValidation, AppError> {
+    Ok(validate_in_background(path).await)
+}
+
+/// Checks dropped files before import, several at a time. Results are in
+/// the order of `paths`, one per file, so the UI can show a review table.
+#[tauri::command]
+pub async fn validate_audio_files_command(
+    paths: Vec<String>,
+) -> Result<Vec<FileValidation>, AppError> {
+    info!("Validating {} files for import", paths.len());
+    Ok(futures_util::stream::iter(paths)
+        .map(validate_in_background)
+        .buffered(VALIDATION_CONCURRENCY)
+        .collect()
+        .await)
+}
+
+/// Imports one recording as a new meeting
+pub async fn import_recording<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &SqlitePool,
+    request: ImportRequest,
+) -> Result<ImportedMeeting, String> {
+    validate_transcription_model_ready(app).await?;
+
+    let (fingerprint, duplicates) = find_duplicates(pool, &request.file).await?;
+    if let Some(existing) = resolve_duplicate(request.on_duplicate, &duplicates)? {
+        info!(
+            "{} was already imported as {}; linking to it",
+            request.file.display(),
+            existing.meeting_id
+        );
+        return Ok(existing);
+    }
+
+    let preferences = load_recording_preferences(app)
+        .await
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))?;
+    let recordings_root = resolve_recordings_root(app, &preferences.save_folder).await?;
+    let folder = create_meeting_folder(
+        &recordings_root,
+        &request.title,
+        &request.tags,
+        &preferences.folder_name_template,
+        false,
+    )
+    .map_err(|e| format!("Failed to create meeting folder: {}", e))?;
+    // Keeps maintenance from taking the folder for an orphan, and then the
+    // new meeting from being edited, until the import is done
+    let locks = MeetingLocks::global();
+    let _folder_lock = locks.acquire(folder_key(&folder), MeetingActivity::Import);
+    let audio_path = place_in_folder(&request.file, &folder, request.move_file)
+        .map_err(|e| format!("Failed to copy the recording: {}", e))?;
+
+    // Recordings cut off by a crash often fail a normal decode; recover what
+    // is there instead of refusing the file
+    let (samples, salvage) = tokio::task::spawn_blocking(move || {
+        decode_to_mono_16k(&audio_path)
+            .map(|samples| (samples, None))
+            .or_else(|e| {
+                warn!("{}; trying to recover the readable audio", e);
+                salvage_to_mono_16k(&audio_path).map(|(samples, report)| (samples, Some(report)))
+            })
+    })
+    .await
+    .map_err(|e| format!("Decoding task failed: {}", e))?
+    .map_err(|e| e.to_string())?;
+
+    let provider = engine_for_samples(app, &samples).await?;
+    let title = request.title.clone();
+    let transcribed = transcribe_samples(
+        provider.as_ref(),
+        &samples,
+        crate::get_language_preference_internal(),
+        &request.cancel,
+        |done, total| {
+            let _ = events::emit(
+                app,
+                &ImportProgress {
+                    title: title.clone(),
+                    done,
+                    total,
+                },
+            );
+        },
+    )
+    .await;
+    if request.cancel.is_cancelled() {
+        if let Err(e) = std::fs::remove_dir_all(&folder) {
+            warn!("Failed to remove {}: {}", folder.display(), e);
+        }
+        return Err("Cancelled".to_string());
+    }
+    let mut segments = transcribed.map_err(|e| format!("Transcription failed: {}", e))?;
+    corrections::refresh_rules(pool).await;
+    for segment in &mut segments {
+        segment.text = corrections::apply(&segment.text);
+    }
+
+    let folder_path = folder.to_string_lossy().to_string();
+    let state = app
+        .try_state::<AppState>()
+        .ok_or("The database is not ready")?;
+    let meeting_id = state
+        .db_manager
+        .save_transcript(
+            request.title.clone(),
+            segments.clone(),
+            Some(folder_path.clone()),
+        )
+        .await
+        .map_err(|e| format!("Failed to save the imported meeting: {}", e))?;
+    // Before the import lock, which edits like tagging respect
+    if !request.tags.is_empty() {
+        if let Err(e) = TagsRepository::set_tags(pool, &meeting_id, &request.tags).await {
+            warn!("Failed to tag {}: {}", meeting_id, e);
+        }
+    }
+    let _meeting_lock = locks.acquire(meeting_id.as_str(), MeetingActivity::Import);
+    record_fingerprint(
+        pool,
+        &meeting_id,
+        &fingerprint,
+        request.source,
+        request.origin.as_deref(),
+    )
+    .await;
+    record_speakers(pool, &meeting_id, samples, &segments).await;
+
+    if !request.participants.is_empty() {
+        if let Err(e) = ParticipantsRepository::merge_participants(
+            pool,
+            &meeting_id,
+            &request.participants,
+            request.source,
+        )
+        .await
+        {
+            warn!("Failed to add participants to {}: {}", meeting_id, e);
+        }
+    }
+
+    info!(
+        "Imported '{}' as meeting {} ({} segments)",
+        request.title,
+        meeting_id,
+        segments.len()
+    );
+    Ok(ImportedMeeting {
+        meeting_id,
+        title: request.title,
+        folder_path: Some(folder_path),
+        segment_count: segments.len(),
+        salvage,
+        duplicates,
+        linked_existing: false,
+    })
+}
+
+/// Imports a transcript file as a new meeting without audio. Speakers named
+/// in the file label their segments and become participants.
+pub async fn import_transcript(
+    db: &DatabaseManager,
+    file: &Path,
+    title: Option<String>,
+    on_duplicate: DuplicatePolicy,
+) -> Result<ImportedMeeting, String> {
+    if !is_transcript_file(file) {
+        return Err(format!(
+            "{} is not a transcript; use a .vtt, .srt or .json file",
+            file.display()
+        ));
+    }
+    let pool = db.pool();
+    let (fingerprint, duplicates) = find_duplicates(pool, file).await?;
+    if let Some(existing) = resolve_duplicate(on_duplicate, &duplicates)? {
+        return Ok(existing);
+    }
+    let path = file.to_path_buf();
+    let parsed = tokio::task::spawn_blocking(move || read_transcript_file(&path))
+        .await
+        .map_err(|e| format!("Transcript parsing task failed: {}", e))?
+        .map_err(|e| e.to_string())?;
+
+    let title = title
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .or_else(|| {
+            file.file_stem()
+                .map(|stem| stem.to_string_lossy().trim().to_string())
+                .filter(|stem| !stem.is_empty())
+        })
+        .unwrap_or_else(|| "Imported transcript".to_string());
+    let segment_count = parsed.segments.len();
+    let meeting_id = db
+        .save_transcript(title.clone(), parsed.segments, None)
+        .await
+        .map_err(|e| format!("Failed to save the imported transcript: {}", e))?;
+    record_fingerprint(pool, &meeting_id, &fingerprint, "transcript", None).await;
+
+    if !parsed.speakers.is_empty() {
+        let participants: Vec<ParticipantInput> = parsed
+            .speakers
+            .iter()
+            .map(|name| ParticipantInput {
+                name: name.clone(),
+                email: None,
+                role: None,
+                speaker_label: Some(name.clone()),
+            })
+            .collect();
+        if let Err(e) = ParticipantsRepository::merge_participants(
+            pool,
+            &meeting_id,
+            &participants,
+            "transcript",
+        )
+        .await
+        {
+            warn!("Failed to add participants to {}: {}", meeting_id, e);
+        }
+    }
+
+    info!(
+        "Imported transcript {} as meeting {} ({} segments)",
+        file.display(),
+        meeting_id,
+        segment_count
+    );
+    Ok(ImportedMeeting {
+        meeting_id,
+        title,
+        folder_path: None,
+        segment_count,
+        salvage: None,
+        duplicates,
+        linked_existing: false,
+    })
+}
+
+/// Imports a .vtt, .srt or .json transcript as a meeting. `title` defaults to
+/// the file name.
+#[tauri::command]
+pub async fn import_transcript_file<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    path: String,
+    title: Option<String>,
+    on_duplicate: Option<DuplicatePolicy>,
+) -> Result<ImportedMeeting, AppError> {
+    let path = validation::existing_file("path", &path)?;
+    let title = title
+        .filter(|title| !title.trim().is_empty())
+        .map(|title| validation::title("title", &title))
+        .transpose()?;
+    info!("import_transcript_file called for {}", path.display());
+    let imported = import_transcript(
+        &state.db_manager,
+        &path,
+        title,
+        on_duplicate.unwrap_or_default(),
+    )
+    .await?;
+    if !imported.linked_existing {
+        crate::audit::record(
+            state.db_manager.pool(),
+            crate::audit::AuditAction::Import,
+            "meeting",
+            Some(&imported.meeting_id),
+            serde_json::json!({ "source": "transcript_file" }),
+        )
+        .await;
+    }
+    Ok(imported)
+}
+
+/// Lists meetings already imported from the same file content
+#[tauri::command]
+pub async fn find_import_duplicates<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    path: String,
+) -> Result<Vec<DuplicateImport>, AppError> {
+    let path = validation::existing_file("path", &path)?;
+    let (_, duplicates) = find_duplicates(state.db_manager.pool(), &path).await?;
+    Ok(duplicates)
+}
+
+/// Transcribes the first minute of a recording and returns the text, the
+/// language and an estimate of how long the full import would take
+#[tauri::command]
+pub async fn preview_import<R: Runtime>(
+    app: AppHandle<R>,
+    path: String,
+) -> Result<ImportPreview, AppError> {
+    let file = validation::existing_file("path", &path)?;
+    info!("preview_import called for {}", file.display());
+    validate_transcription_model_ready(&app)
+        .await
+        .map_err(AppError::Rejected)?;
+
+    let probe_path = file.clone();
+    let (probe, samples) = tokio::task::spawn_blocking(move || {
+        let probe = probe_media(&probe_path)?;
+        let samples = decode_head_to_mono_16k(&probe_path, Some(PREVIEW_SECONDS))?;
+        Ok::<_, anyhow::Error>((probe, samples))
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Decoding task failed: {}", e)))?
+    .map_err(|e| AppError::InvalidInput(format!("Corrupt or unreadable: {}", e)))?;
+    let sample_seconds = samples.len() as f64 / IMPORT_SAMPLE_RATE as f64;
+
+    let provider = engine_for_samples(&app, &samples).await?;
+    let configured =
+        crate::get_language_preference_internal().filter(|language| !language.starts_with("auto"));
+    let (language, language_source) = match configured {
+        Some(language) => (Some(language), Some("configured")),
+        None => {
+            let turn = engine_turn(Priority::Background).await;
+            let detected = provider.detect_language(&samples).await;
+            drop(turn);
+            match detected {
+                Some(language) => (Some(language), Some("detected")),
+                None => (None, None),
+            }
+        }
+    };
+
+    let started = std::time::Instant::now();
+    let segments = transcribe_samples(
+        provider.as_ref(),
+        &samples,
+        language.clone(),
+        &CancellationToken::new(),
+        |_, _| {},
+    )
+    .await
+    .map_err(|e| AppError::Internal(format!("Transcription failed: {}", e)))?;
+    let elapsed = started.elapsed().as_secs_f64();
+
+    Ok(ImportPreview {
+        file_name: file
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        sample_text: corrections::apply(
+            &segments
+                .iter()
+                .map(|segment| segment.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+        sample_seconds,
+        duration_seconds: probe.duration_seconds,
+        language,
+        language_source,
+        estimated_import_seconds: estimate_import_seconds(
+            sample_seconds,
+            elapsed,
+            probe.duration_seconds,
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extrapolates_import_time() {
+        assert_eq!(
+            estimate_import_seconds(60.0, 6.0, Some(3600.0)),
+            Some(360.0)
+        );
+        // Short files are fully covered by the preview
+        assert_eq!(estimate_import_seconds(30.0, 4.0, Some(30.0)), Some(4.0));
+        assert_eq!(estimate_import_seconds(60.0, 6.0, None), None);
+        assert_eq!(estimate_import_seconds(0.0, 0.0, Some(10.0)), None);
+    }
+
+    fn earlier_import() -> DuplicateImport {
+        DuplicateImport {
+            meeting_id: "meeting-1".to_string(),
+            title: "Weekly sync".to_string(),
+            folder_path: Some("/recordings/weekly-sync".to_string()),
+            file_name: Some("weekly.m4a".to_string()),
+            source: "zoom".to_string(),
+            imported_at: chrono::Utc::now(),
+            segment_count: 12,
+        }
+    }
+
+    #[test]
+    fn new_files_import_under_every_policy() {
+        for policy in [
+            DuplicatePolicy::Warn,
+            DuplicatePolicy::Skip,
+            DuplicatePolicy::LinkExisting,
+        ] {
+            assert!(resolve_duplicate(policy, &[]).unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn duplicates_follow_the_policy() {
+        let duplicates = vec![earlier_import()];
+
+        assert!(resolve_duplicate(DuplicatePolicy::Warn, &duplicates)
+            .unwrap()
+            .is_none());
+
+        let skipped = resolve_duplicate(DuplicatePolicy::Skip, &duplicates).unwrap_err();
+        assert!(skipped.contains("Weekly sync"));
+
+        let linked = resolve_duplicate(DuplicatePolicy::LinkExisting, &duplicates)
+            .unwrap()
+            .unwrap();
+        assert!(linked.linked_existing);
+        assert_eq!(linked.meeting_id, "meeting-1");
+        assert_eq!(
+            linked.folder_path.as_deref(),
+            Some("/recordings/weekly-sync")
+        );
+        assert_eq!(linked.segment_count, 12);
+        assert_eq!(linked.duplicates.len(), 1);
+    }
+}
@@ -0,0 +1,272 @@
+//! Meeting folder names built from a user-configurable template.
+//!
+//! Placeholders:
+//! - `{title}` the meeting title, made filesystem safe
+//! - `{slug}` the title lowercased with runs of other characters turned into `-`
+//! - `{date}` `YYYY-MM-DD`, `{time}` `HH-MM`
+//! - `{tags}` the tags an import is given (a feed episode's feed), joined with
+//!   `-`. Recordings are tagged after their folder exists, so for them it is
+//!   empty and the separators around it are dropped.
+//! - `{counter}` 1, 2, ... picking the first number whose folder doesn't exist
+//!
+//! Without `{counter}`, a name that is already taken gets `_2`, `_3`, ...
+//! appended, so a template never reuses an existing folder.
+
+use chrono::{DateTime, Utc};
+use std::path::Path;
+
+use super::audio_processing::sanitize_filename;
+
+/// The naming scheme used before templates existed: `Title_2026-01-31_09-30`
+pub const DEFAULT_FOLDER_TEMPLATE: &str = "{title}_{date}_{time}";
+const PLACEHOLDERS: [&str; 6] = ["title", "slug", "date", "time", "tags", "counter"];
+/// Leaves room for the recordings root within common path length limits
+const MAX_FOLDER_NAME_CHARS: usize = 120;
+/// Device names Windows refuses as file names, with or without an extension
+const RESERVED_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// What a folder name can be built from
+pub struct FolderNameContext<'a> {
+    pub title: &'a str,
+    pub tags: &'a [String],
+    pub now: DateTime<Utc>,
+}
+
+enum Part<'a> {
+    Text(&'a str),
+    Placeholder(&'a str),
+}
+
+fn parse(template: &str) -> Result<Vec<Part<'_>>, String> {
+    let mut parts = Vec::new();
+    let mut rest = template;
+    while let Some(open) = rest.find(['{', '}']) {
+        if rest[open..].starts_with('}') {
+            return Err("Unmatched '}' in the folder name template".to_string());
+        }
+        let close = rest[open..]
+            .find('}')
+            .map(|i| open + i)
+            .ok_or_else(|| "Unclosed '{' in the folder name template".to_string())?;
+        let name = &rest[open + 1..close];
+        if !PLACEHOLDERS.contains(&name) {
+            return Err(format!(
+                "Unknown placeholder {{{}}}; use one of: {}",
+                name,
+                PLACEHOLDERS.map(|p| format!("{{{}}}", p)).join(", ")
+            ));
+        }
+        if open > 0 {
+            parts.push(Part::Text(&rest[..open]));
+        }
+        parts.push(Part::Placeholder(name));
+        rest = &rest[close + 1..];
+    }
+    if !rest.is_empty() {
+        parts.push(Part::Text(rest));
+    }
+    Ok(parts)
+}
+
+/// Checks that `template` only uses known placeholders, contains no path
+/// separators or characters folders can't have, and names each meeting
+/// differently (it must use the title, date or counter)
+pub fn validate_template(template: &str) -> Result<(), String> {
+    if template.trim().is_empty() {
+        return Err("The folder name template is empty".to_string());
+    }
+    let parts = parse(template)?;
+    for part in &parts {
+        if let Part::Text(text) = part {
+            let unsafe_char = text.chars().any(|c| {
+                matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|') || c.is_control()
+            });
+            if unsafe_char || text.contains("..") {
+                return Err(format!(
+                    "'{}' can't be part of a folder name; avoid / \\ : * ? \" < > | and '..'",
+                    text
+                ));
+            }
+        }
+    }
+    let distinguishes = parts.iter().any(|part| {
+        matches!(
+            part,
+            Part::Placeholder("title" | "slug" | "date" | "counter")
+        )
+    });
+    if !distinguishes {
+        return Err(
+            "The folder name template needs {title}, {slug}, {date} or {counter}".to_string(),
+        );
+    }
+    Ok(())
+}
+
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Makes a rendered name safe: no separators or reserved characters, no
+/// doubled or dangling separators left by empty placeholders, no trailing
+/// dots (Windows drops them), no reserved device names, bounded length
+fn tidy(name: &str) -> String {
+    let mut tidy = String::new();
+    for c in sanitize_filename(name).chars() {
+        let separator = matches!(c, '_' | '-' | ' ');
+        if separator && tidy.ends_with(['_', '-', ' ']) {
+            continue;
+        }
+        tidy.push(c);
+    }
+    let mut tidy: String = tidy
+        .trim_matches(['_', '-', ' ', '.'])
+        .chars()
+        .take(MAX_FOLDER_NAME_CHARS)
+        .collect();
+    tidy = tidy.trim_end_matches(['_', '-', ' ', '.']).to_string();
+
+    let stem = tidy.split('.').next().unwrap_or_default().to_uppercase();
+    if tidy.is_empty() {
+        tidy = "Meeting".to_string();
+    } else if RESERVED_NAMES.contains(&stem.as_str()) {
+        tidy = format!("Meeting_{}", tidy);
+    }
+    tidy
+}
+
+/// Renders `template` (already validated) with `counter` for `{counter}`
+pub fn render(template: &str, context: &FolderNameContext, counter: u32) -> String {
+    let parts = parse(template).unwrap_or_else(|_| vec![Part::Placeholder("title")]);
+    let mut name = String::new();
+    for part in parts {
+        match part {
+            Part::Text(text) => name.push_str(text),
+            Part::Placeholder("title") => name.push_str(&sanitize_filename(context.title)),
+            Part::Placeholder("slug") => name.push_str(&slugify(context.title)),
+            Part::Placeholder("date") => name.push_str(&context.now.format("%Y-%m-%d").to_string()),
+            Part::Placeholder("time") => name.push_str(&context.now.format("%H-%M").to_string()),
+            Part::Placeholder("tags") => name.push_str(
+                &context
+                    .tags
+                    .iter()
+                    .map(|tag| slugify(tag))
+                    .filter(|tag| !tag.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("-"),
+            ),
+            Part::Placeholder(_) => name.push_str(&counter.to_string()),
+        }
+    }
+    tidy(&name)
+}
+
+/// A folder name under `base` that doesn't exist yet. An invalid template
+/// falls back to [`DEFAULT_FOLDER_TEMPLATE`].
+pub fn unique_folder_name(base: &Path, template: &str, context: &FolderNameContext) -> String {
+    let template = match validate_template(template) {
+        Ok(()) => template,
+        Err(e) => {
+            log::warn!("{}; using the default folder name template", e);
+            DEFAULT_FOLDER_TEMPLATE
+        }
+    };
+    let has_counter = template.contains("{counter}");
+    let first = render(template, context, 1);
+    if !base.join(&first).exists() {
+        return first;
+    }
+    (2..)
+        .map(|n| {
+            if has_counter {
+                render(template, context, n)
+            } else {
+                format!("{}_{}", first, n)
+            }
+        })
+        .find(|name| !base.join(name).exists())
+        .unwrap_or(first)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn context<'a>(title: &'a str, tags: &'a [String]) -> FolderNameContext<'a> {
+        FolderNameContext {
+            title,
+            tags,
+            now: Utc.with_ymd_and_hms(2026, 3, 9, 14, 5, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn default_template_is_title_date_time() {
+        assert_eq!(
+            render(DEFAULT_FOLDER_TEMPLATE, &context("Team: Sync", &[]), 1),
+            "Team_Sync_2026-03-09_14-05"
+        );
+    }
+
+    #[test]
+    fn renders_every_placeholder() {
+        let tags = vec!["Client A".to_string(), "Q1".to_string()];
+        assert_eq!(
+            render(
+                "{date}_{slug}_{tags}_{counter}",
+                &context("Weekly Sync!", &tags),
+                3
+            ),
+            "2026-03-09_weekly-sync_client-a-q1_3"
+        );
+        // Empty placeholders don't leave doubled separators behind
+        assert_eq!(
+            render("{date}_{tags}_{slug}", &context("Retro", &[]), 1),
+            "2026-03-09_retro"
+        );
+        assert_eq!(render("{slug}", &context("???", &[]), 1), "Meeting");
+        assert_eq!(render("{title}", &context("con", &[]), 1), "Meeting_con");
+    }
+
+    #[test]
+    fn rejects_unsafe_or_constant_templates() {
+        assert!(validate_template("{date}_{title}").is_ok());
+        assert!(validate_template("{date} {title}").is_ok());
+        assert!(validate_template("").is_err());
+        assert!(validate_template("{date}/{title}").is_err());
+        assert!(validate_template("..{title}").is_err());
+        assert!(validate_template("{title").is_err());
+        assert!(validate_template("title}").is_err());
+        assert!(validate_template("{owner}_{date}").is_err());
+        assert!(validate_template("meeting_{time}").is_err());
+    }
+
+    #[test]
+    fn taken_names_get_a_suffix_or_the_next_counter() {
+        let base = std::env::temp_dir().join(format!("mm-folders-{}", std::process::id()));
+        std::fs::create_dir_all(base.join("Sync_2026-03-09")).unwrap();
+        std::fs::create_dir_all(base.join("2026-03-09_1")).unwrap();
+        let context = context("Sync", &[]);
+        assert_eq!(
+            unique_folder_name(&base, "{title}_{date}", &context),
+            "Sync_2026-03-09_2"
+        );
+        assert_eq!(
+            unique_folder_name(&base, "{date}_{counter}", &context),
+            "2026-03-09_2"
+        );
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+}
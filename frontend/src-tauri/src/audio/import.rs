@@ -0,0 +1,613 @@
+//! Audio file import: decode any FFmpeg-readable file, split it on speech with
+//! VAD and transcribe each segment with a local transcription provider.
+//!
+//! Used by the `meeting-minutes-cli` binary; has no Tauri dependencies.
+
+use anyhow::{anyhow, Result};
+use log::{debug, info, warn};
+use serde::Serialize;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use super::ffmpeg::find_ffmpeg_path;
+use super::mp4_recovery::{find_mdat, recover_adts};
+use super::transcription::priority::{engine_turn, Priority};
+use super::transcription::{ParakeetProvider, TranscriptionProvider, WhisperProvider};
+use super::vad::get_speech_chunks;
+use crate::api::TranscriptSegment;
+
+pub const IMPORT_SAMPLE_RATE: u32 = 16000;
+/// Whisper works on 30s windows; longer VAD segments are split before transcription
+const MAX_SEGMENT_SECONDS: usize = 30;
+/// Same redemption time the live pipeline uses, so segments bridge natural pauses
+const VAD_REDEMPTION_MS: u32 = 2000;
+
+pub const DEFAULT_PARAKEET_MODEL: &str = "parakeet-tdt-0.6b-v3-int8";
+
+/// Extensions offered for import; anything FFmpeg can demux works, these are
+/// the ones meeting tools and recorders produce
+pub const SUPPORTED_MEDIA_EXTENSIONS: [&str; 16] = [
+    "wav", "mp3", "m4a", "mp4", "aac", "flac", "ogg", "oga", "opus", "webm", "mkv", "mov", "wma",
+    "aiff", "aif", "avi",
+];
+
+/// Input flags that make FFmpeg skip damaged packets instead of stopping
+const SALVAGE_INPUT_ARGS: [&str; 4] = [
+    "-err_detect",
+    "ignore_err",
+    "-fflags",
+    "+discardcorrupt+genpts+igndts",
+];
+/// How far into an unreadable file to look for the first audio frame
+const SYNC_SEARCH_BYTES: u64 = 16 * 1024 * 1024;
+/// Consecutive well-formed frames required before trusting a sync point
+const SYNC_CONFIRM_FRAMES: usize = 3;
+/// How much of an unfinished MP4 is recovered, about six hours of a recording
+const MP4_RECOVERY_MAX_BYTES: u64 = 512 * 1024 * 1024;
+
+/// How much of a damaged file [`salvage_to_mono_16k`] could recover
+#[derive(Debug, Clone, Serialize)]
+pub struct SalvageReport {
+    pub recovered_seconds: f64,
+    /// Length the file's header claims, when it is readable
+    pub expected_seconds: Option<f64>,
+    /// Bytes skipped before the first valid audio frame
+    pub skipped_bytes: u64,
+    /// Damaged packets FFmpeg reported and skipped
+    pub decode_errors: usize,
+}
+
+/// What FFmpeg reports about a media file without decoding it
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaProbe {
+    pub duration_seconds: Option<f64>,
+    pub has_audio: bool,
+}
+
+/// Loads a local engine outside the Tauri app. `provider` uses the same names
+/// as the transcript settings ("parakeet" or "localWhisper"); models must
+/// already be downloaded into `models_dir` (the app's `models` directory).
+pub async fn load_local_provider(
+    models_dir: PathBuf,
+    provider: &str,
+    model: &str,
+) -> Result<Box<dyn TranscriptionProvider>> {
+    match provider {
+        "parakeet" => {
+            let engine = crate::parakeet_engine::ParakeetEngine::new_with_models_dir(Some(models_dir))?;
+            engine.discover_models().await?;
+            let provider = ParakeetProvider::with_model(Arc::new(engine), model);
+            provider.load().await?;
+            Ok(Box::new(provider))
+        }
+        "localWhisper" | "whisper" => {
+            let engine = crate::whisper_engine::WhisperEngine::new_with_models_dir(Some(models_dir))?;
+            engine.discover_models().await?;
+            let provider = WhisperProvider::with_model(Arc::new(engine), model);
+            provider.load().await?;
+            Ok(Box::new(provider))
+        }
+        other => Err(anyhow!(
+            "Provider '{}' is not supported for local transcription. Use 'parakeet' or 'localWhisper'.",
+            other
+        )),
+    }
+}
+
+pub fn ffmpeg_command() -> Result<Command> {
+    let ffmpeg_path = find_ffmpeg_path()
+        .ok_or_else(|| anyhow!("FFmpeg not found. Please install FFmpeg to import audio."))?;
+    let mut command = Command::new(ffmpeg_path);
+    command.stdin(Stdio::null());
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+    Ok(command)
+}
+
+/// Reads a media file's header with FFmpeg (no decoding) to check that it is
+/// readable and has an audio stream
+pub fn probe_media(path: &Path) -> Result<MediaProbe> {
+    if !path.is_file() {
+        return Err(anyhow!("Audio file not found: {}", path.display()));
+    }
+    let mut command = ffmpeg_command()?;
+    command
+        .arg("-hide_banner")
+        .arg("-i")
+        .arg(path)
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped());
+    // Without an output FFmpeg exits with an error after printing the input
+    // description, so the exit status says nothing here
+    let output = command
+        .output()
+        .map_err(|e| anyhow!("Failed to run FFmpeg: {}", e))?;
+    parse_probe_output(&String::from_utf8_lossy(&output.stderr)).map_err(|e| anyhow!(e))
+}
+
+/// Parses the input description FFmpeg prints for `ffmpeg -i <file>`
+pub fn parse_probe_output(stderr: &str) -> std::result::Result<MediaProbe, String> {
+    if !stderr.lines().any(|line| line.starts_with("Input #")) {
+        let reason = stderr
+            .lines()
+            .map(str::trim)
+            .rev()
+            .find(|line| !line.is_empty())
+            .unwrap_or("unreadable file");
+        // FFmpeg prefixes errors with the file path
+        let reason = reason.rsplit_once(": ").map(|(_, r)| r).unwrap_or(reason);
+        return Err(reason.to_string());
+    }
+
+    let duration_seconds = stderr.lines().find_map(|line| {
+        let value = line.trim().strip_prefix("Duration: ")?;
+        let value = value.split(',').next()?;
+        let mut seconds = 0.0;
+        for part in value.split(':') {
+            seconds = seconds * 60.0 + part.trim().parse::<f64>().ok()?;
+        }
+        Some(seconds)
+    });
+    let has_audio = stderr
+        .lines()
+        .any(|line| line.trim_start().starts_with("Stream #") && line.contains(": Audio:"));
+    Ok(MediaProbe {
+        duration_seconds,
+        has_audio,
+    })
+}
+
+/// Decodes an audio (or video) file to 16kHz mono f32 samples using FFmpeg
+pub fn decode_to_mono_16k(path: &Path) -> Result<Vec<f32>> {
+    decode_head_to_mono_16k(path, None)
+}
+
+/// Like [`decode_to_mono_16k`], but stops after `max_seconds` of audio
+pub fn decode_head_to_mono_16k(path: &Path, max_seconds: Option<f64>) -> Result<Vec<f32>> {
+    decode_checked(path, &[], max_seconds)
+}
+
+/// Decodes the `start..end` range (in seconds) of a file, e.g. the audio
+/// behind a single transcript segment
+pub fn decode_range_to_mono_16k(path: &Path, start: f64, end: f64) -> Result<Vec<f32>> {
+    if end <= start {
+        return Err(anyhow!("Empty audio range {:.3}s..{:.3}s", start, end));
+    }
+    // Seeking as an input option jumps straight to the range
+    let offset = format!("{:.3}", start.max(0.0));
+    decode_checked(path, &["-ss", &offset], Some(end - start.max(0.0)))
+}
+
+fn decode_checked(path: &Path, input_args: &[&str], max_seconds: Option<f64>) -> Result<Vec<f32>> {
+    if !path.is_file() {
+        return Err(anyhow!("Audio file not found: {}", path.display()));
+    }
+    let output = run_decode(path, input_args, max_seconds)?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let last_line = stderr.lines().last().unwrap_or("unknown error");
+        return Err(anyhow!("FFmpeg could not decode {}: {}", path.display(), last_line));
+    }
+
+    let samples = samples_from_f32le(&output.stdout);
+    if samples.is_empty() {
+        return Err(anyhow!("{} contains no audio", path.display()));
+    }
+    info!(
+        "Decoded {} ({:.1}s of audio)",
+        path.display(),
+        samples.len() as f64 / IMPORT_SAMPLE_RATE as f64
+    );
+    Ok(samples)
+}
+
+/// Decodes whatever audio a damaged file still holds, e.g. a recording cut
+/// off by a crash. Damaged packets are skipped; when the container header
+/// itself is unreadable, decoding restarts at the first run of valid MP3 or
+/// ADTS AAC frames, and the AAC frames of an unfinished MP4/M4A are
+/// recovered without its sample table.
+pub fn salvage_to_mono_16k(path: &Path) -> Result<(Vec<f32>, SalvageReport)> {
+    if !path.is_file() {
+        return Err(anyhow!("Audio file not found: {}", path.display()));
+    }
+    let expected_seconds = probe_media(path).ok().and_then(|p| p.duration_seconds);
+
+    let output = run_decode(path, &SALVAGE_INPUT_ARGS, None)?;
+    let mut samples = samples_from_f32le(&output.stdout);
+    let mut stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    let mut skipped_bytes = 0;
+    if samples.is_empty() {
+        let mut head = Vec::new();
+        std::fs::File::open(path)
+            .and_then(|file| file.take(SYNC_SEARCH_BYTES).read_to_end(&mut head))
+            .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+        if find_mdat(&head).is_some() {
+            if let Some((output, offset)) = recover_unfinished_mp4(path)? {
+                samples = samples_from_f32le(&output.stdout);
+                stderr = String::from_utf8_lossy(&output.stderr).to_string();
+                skipped_bytes = offset as u64;
+            }
+        } else if let Some((offset, format)) = find_frame_sync(&head) {
+            debug!("Found {} frames at byte {} of {}", format, offset, path.display());
+            let offset_arg = offset.to_string();
+            let mut args = vec!["-skip_initial_bytes", offset_arg.as_str(), "-f", format];
+            args.extend(SALVAGE_INPUT_ARGS);
+            let output = run_decode(path, &args, None)?;
+            samples = samples_from_f32le(&output.stdout);
+            stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            skipped_bytes = offset as u64;
+        }
+    }
+    if samples.is_empty() {
+        return Err(anyhow!("No audio could be recovered from {}", path.display()));
+    }
+
+    let report = SalvageReport {
+        recovered_seconds: samples.len() as f64 / IMPORT_SAMPLE_RATE as f64,
+        expected_seconds,
+        skipped_bytes,
+        decode_errors: count_decode_errors(&stderr),
+    };
+    warn!(
+        "Recovered {:.1}s of audio from damaged file {} ({} decode errors, {} bytes skipped)",
+        report.recovered_seconds,
+        path.display(),
+        report.decode_errors,
+        report.skipped_bytes
+    );
+    Ok((samples, report))
+}
+
+/// Decodes the AAC frames of an MP4/M4A that was never finalized. Also
+/// returns the number of bytes before the first frame.
+fn recover_unfinished_mp4(path: &Path) -> Result<Option<(Output, usize)>> {
+    let mut bytes = Vec::new();
+    std::fs::File::open(path)
+        .and_then(|file| file.take(MP4_RECOVERY_MAX_BYTES).read_to_end(&mut bytes))
+        .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+    let Some((adts, offset)) = recover_adts(&bytes) else {
+        return Ok(None);
+    };
+    drop(bytes);
+    debug!(
+        "Recovered {} bytes of AAC frames from unfinished MP4 {}",
+        adts.len(),
+        path.display()
+    );
+
+    let adts_path = std::env::temp_dir().join(format!("meetily-salvage-{}.aac", Uuid::new_v4()));
+    std::fs::write(&adts_path, &adts)
+        .map_err(|e| anyhow!("Failed to write {}: {}", adts_path.display(), e))?;
+    let mut args = vec!["-f", "aac"];
+    args.extend(SALVAGE_INPUT_ARGS);
+    let output = run_decode(&adts_path, &args, None);
+    let _ = std::fs::remove_file(&adts_path);
+    Ok(Some((output?, offset)))
+}
+
+/// FFmpeg writing `path` to stdout as 16kHz mono f32le PCM, for callers that
+/// read the audio as it is decoded
+pub fn decode_command(
+    path: &Path,
+    input_args: &[&str],
+    max_seconds: Option<f64>,
+) -> Result<Command> {
+    let mut command = ffmpeg_command()?;
+    command.args(input_args).arg("-i").arg(path);
+    if let Some(max_seconds) = max_seconds {
+        command.arg("-t").arg(format!("{:.3}", max_seconds));
+    }
+    command.args([
+        "-vn",
+        "-ac",
+        "1",
+        "-ar",
+        &IMPORT_SAMPLE_RATE.to_string(),
+        "-f",
+        "f32le",
+        "pipe:1",
+    ]);
+    Ok(command)
+}
+
+fn run_decode(path: &Path, input_args: &[&str], max_seconds: Option<f64>) -> Result<Output> {
+    let mut command = decode_command(path, input_args, max_seconds)?;
+    command.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+    debug!("FFmpeg decode command: {:?}", command);
+    command
+        .output()
+        .map_err(|e| anyhow!("Failed to run FFmpeg: {}", e))
+}
+
+/// Lines in FFmpeg's log that report a skipped or damaged packet
+fn count_decode_errors(stderr: &str) -> usize {
+    stderr
+        .lines()
+        .map(str::to_lowercase)
+        .filter(|line| {
+            line.contains("error") || line.contains("corrupt") || line.contains("invalid data")
+        })
+        .count()
+}
+
+/// Length of the ADTS (raw AAC) frame starting at `bytes`, if it is one
+fn adts_frame_length(bytes: &[u8]) -> Option<usize> {
+    if bytes.len() < 7 || bytes[0] != 0xFF || bytes[1] & 0xF6 != 0xF0 {
+        return None;
+    }
+    // Sampling frequency indexes 13-15 are reserved
+    if (bytes[2] >> 2) & 0x0F > 12 {
+        return None;
+    }
+    let length = ((bytes[3] as usize & 0x03) << 11)
+        | ((bytes[4] as usize) << 3)
+        | (bytes[5] as usize >> 5);
+    (length >= 7).then_some(length)
+}
+
+/// Length of the MPEG-1/2/2.5 Layer III (MP3) frame starting at `bytes`, if
+/// it is one
+fn mp3_frame_length(bytes: &[u8]) -> Option<usize> {
+    const MPEG1_KBPS: [u32; 15] = [
+        0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320,
+    ];
+    const MPEG2_KBPS: [u32; 15] = [
+        0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160,
+    ];
+    const SAMPLE_RATES: [u32; 3] = [44100, 48000, 32000];
+
+    if bytes.len() < 4 || bytes[0] != 0xFF || bytes[1] & 0xE0 != 0xE0 {
+        return None;
+    }
+    let version = (bytes[1] >> 3) & 0x03; // 0 = 2.5, 2 = 2, 3 = 1
+    let layer = (bytes[1] >> 1) & 0x03; // 1 = Layer III
+    let bitrate_index = (bytes[2] >> 4) as usize;
+    let rate_index = ((bytes[2] >> 2) & 0x03) as usize;
+    // Free-format (0) and bad (15) bitrates have no computable length
+    if version == 1 || layer != 1 || bitrate_index == 0 || bitrate_index == 15 || rate_index == 3
+    {
+        return None;
+    }
+    let padding = ((bytes[2] >> 1) & 0x01) as usize;
+    let (kbps, sample_rate, factor) = match version {
+        3 => (MPEG1_KBPS[bitrate_index], SAMPLE_RATES[rate_index], 144),
+        2 => (MPEG2_KBPS[bitrate_index], SAMPLE_RATES[rate_index] / 2, 72),
+        _ => (MPEG2_KBPS[bitrate_index], SAMPLE_RATES[rate_index] / 4, 72),
+    };
+    Some((factor * kbps * 1000 / sample_rate) as usize + padding)
+}
+
+/// Finds the first offset where several well-formed MP3 or ADTS frames
+/// follow each other, with the FFmpeg demuxer to read them
+pub fn find_frame_sync(bytes: &[u8]) -> Option<(usize, &'static str)> {
+    type FrameLength = fn(&[u8]) -> Option<usize>;
+    let formats: [(FrameLength, &'static str); 2] =
+        [(adts_frame_length, "aac"), (mp3_frame_length, "mp3")];
+    let confirmed = |start: usize, frame_length: FrameLength| {
+        let mut offset = start;
+        for _ in 0..SYNC_CONFIRM_FRAMES {
+            match frame_length(bytes.get(offset..)?) {
+                Some(length) => offset += length,
+                None => return None,
+            }
+        }
+        Some(())
+    };
+    (0..bytes.len())
+        .filter(|&offset| bytes[offset] == 0xFF)
+        .find_map(|offset| {
+            formats
+                .iter()
+                .find(|(frame_length, _)| confirmed(offset, *frame_length).is_some())
+                .map(|(_, format)| (offset, *format))
+        })
+}
+
+/// Converts raw little-endian f32 PCM to samples, ignoring a trailing partial sample
+pub fn samples_from_f32le(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+/// Splits `[start, end)` sample ranges longer than `max_len` into consecutive windows
+pub fn split_range(start: usize, end: usize, max_len: usize) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut cursor = start;
+    while cursor < end {
+        let window_end = (cursor + max_len).min(end);
+        ranges.push((cursor, window_end));
+        cursor = window_end;
+    }
+    ranges
+}
+
+/// Recording-relative offset as "HH:MM:SS", used for the transcript timestamp
+/// column (the live pipeline stores wall-clock time there)
+pub fn format_offset(seconds: f64) -> String {
+    let total = seconds.max(0.0) as u64;
+    format!("{:02}:{:02}:{:02}", total / 3600, (total / 60) % 60, total % 60)
+}
+
+/// Transcribes decoded 16kHz mono audio. `on_progress` is called with
+/// (segments done, segments total) after each segment. Stops with an error
+/// before the next window once `cancel` is cancelled. Windows run at background
+/// priority, so a live recording sharing the engine goes first. Providers
+/// that take whole recordings (cloud APIs) get the audio in one request.
+pub async fn transcribe_samples(
+    provider: &dyn TranscriptionProvider,
+    samples: &[f32],
+    language: Option<String>,
+    cancel: &CancellationToken,
+    mut on_progress: impl FnMut(usize, usize),
+) -> Result<Vec<TranscriptSegment>> {
+    if provider.capabilities().batch {
+        let segments = tokio::select! {
+            segments = provider.transcribe_batch(samples, language.clone()) => segments,
+            _ = cancel.cancelled() => return Err(anyhow!("Cancelled")),
+        };
+        let segments = segments.map_err(|e| anyhow!(e.to_string()))?;
+        on_progress(1, 1);
+        return Ok(segments);
+    }
+
+    let rate = IMPORT_SAMPLE_RATE as f64;
+    let speech = get_speech_chunks(samples, VAD_REDEMPTION_MS)?;
+
+    let windows: Vec<(usize, usize)> = speech
+        .iter()
+        .flat_map(|segment| {
+            let start = ((segment.start_timestamp_ms / 1000.0) * rate) as usize;
+            let end = (((segment.end_timestamp_ms / 1000.0) * rate) as usize).min(samples.len());
+            split_range(start.min(end), end, MAX_SEGMENT_SECONDS * IMPORT_SAMPLE_RATE as usize)
+        })
+        .collect();
+    info!(
+        "VAD found {} speech segments ({} transcription windows)",
+        speech.len(),
+        windows.len()
+    );
+
+    let mut segments = Vec::new();
+    for (index, (start, end)) in windows.iter().enumerate() {
+        if cancel.is_cancelled() {
+            return Err(anyhow!("Cancelled"));
+        }
+        let audio = samples[*start..*end].to_vec();
+        let turn = engine_turn(Priority::Background).await;
+        let transcribed = provider.transcribe_stream(audio, language.clone()).await;
+        drop(turn);
+        match transcribed {
+            Ok(result) if !result.text.is_empty() => {
+                let start_time = *start as f64 / rate;
+                let end_time = *end as f64 / rate;
+                segments.push(TranscriptSegment {
+                    id: format!("transcript-{}", Uuid::new_v4()),
+                    text: result.text,
+                    timestamp: format_offset(start_time),
+                    audio_start_time: Some(start_time),
+                    audio_end_time: Some(end_time),
+                    duration: Some(end_time - start_time),
+                    speaker: None,
+                    uncertain: result.uncertain,
+                });
+            }
+            Ok(_) => debug!("Window {} produced no text", index),
+            Err(e) => warn!("Skipping window {} ({:.1}s): {}", index, *start as f64 / rate, e),
+        }
+        on_progress(index + 1, windows.len());
+    }
+
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pcm_conversion() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&0.5f32.to_le_bytes());
+        bytes.extend_from_slice(&(-1.0f32).to_le_bytes());
+        bytes.push(0); // truncated trailing sample
+        assert_eq!(samples_from_f32le(&bytes), vec![0.5, -1.0]);
+    }
+
+    #[test]
+    fn long_ranges_are_split() {
+        assert_eq!(split_range(0, 10, 4), vec![(0, 4), (4, 8), (8, 10)]);
+        assert_eq!(split_range(5, 7, 4), vec![(5, 7)]);
+        assert!(split_range(3, 3, 4).is_empty());
+    }
+
+    #[test]
+    fn probe_output_is_parsed() {
+        let video = "Input #0, mov,mp4,m4a,3gp,3g2,mj2, from 'rec.mp4':\n  \
+                     Duration: 01:02:03.50, start: 0.000000, bitrate: 1205 kb/s\n  \
+                     Stream #0:0[0x1](und): Video: h264 (High)\n  \
+                     Stream #0:1[0x2](und): Audio: aac (LC), 48000 Hz, stereo\n\
+                     At least one output file must be specified\n";
+        assert_eq!(
+            parse_probe_output(video),
+            Ok(MediaProbe {
+                duration_seconds: Some(3723.5),
+                has_audio: true
+            })
+        );
+
+        let silent = "Input #0, matroska,webm, from 'screen.webm':\n  Duration: N/A\n  \
+                      Stream #0:0: Video: vp9\n";
+        let probe = parse_probe_output(silent).unwrap();
+        assert_eq!(probe.duration_seconds, None);
+        assert!(!probe.has_audio);
+
+        assert_eq!(
+            parse_probe_output("broken.m4a: moov atom not found\n"),
+            Err("moov atom not found".to_string())
+        );
+    }
+
+    fn adts_frame(length: usize) -> Vec<u8> {
+        let mut frame = vec![
+            0xFF,
+            0xF1,
+            0x50,
+            0x80 | ((length >> 11) & 0x03) as u8,
+            ((length >> 3) & 0xFF) as u8,
+            (((length & 0x07) << 5) as u8) | 0x1F,
+            0xFC,
+        ];
+        frame.resize(length, 0);
+        frame
+    }
+
+    #[test]
+    fn frame_sync_is_found_after_garbage() {
+        let mut bytes = vec![0x00, 0xFF, 0x13, 0x37, 0xFF, 0xF1];
+        for _ in 0..3 {
+            bytes.extend(adts_frame(20));
+        }
+        assert_eq!(find_frame_sync(&bytes), Some((6, "aac")));
+
+        // MPEG-1 Layer III, 128 kbps, 44.1 kHz: 417-byte frames
+        let mut mp3 = vec![0u8; 10];
+        for _ in 0..3 {
+            let mut frame = vec![0xFF, 0xFB, 0x90, 0x00];
+            frame.resize(417, 0);
+            mp3.extend(frame);
+        }
+        assert_eq!(mp3_frame_length(&mp3[10..]), Some(417));
+        assert_eq!(find_frame_sync(&mp3), Some((10, "mp3")));
+
+        // A lone header isn't enough
+        assert_eq!(find_frame_sync(&adts_frame(20)), None);
+    }
+
+    #[test]
+    fn decode_errors_are_counted() {
+        let stderr = "[mp3float @ 0x1] Header missing\n\
+                      [aac @ 0x2] Error while decoding stream #0:0: Invalid data found\n\
+                      [mov @ 0x3] stream 0, packet corrupt\n\
+                      size=  1024kB time=00:01:00.00\n";
+        assert_eq!(count_decode_errors(stderr), 2);
+    }
+
+    #[test]
+    fn offsets_are_formatted() {
+        assert_eq!(format_offset(0.0), "00:00:00");
+        assert_eq!(format_offset(3725.9), "01:02:05");
+        assert_eq!(format_offset(-1.0), "00:00:00");
+    }
+}
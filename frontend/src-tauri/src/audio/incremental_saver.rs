@@ -1,4 +1,6 @@
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use anyhow::{Result, anyhow};
 use log::{info, warn, error};
 use super::encode::encode_single_audio;
@@ -23,6 +25,7 @@ pub struct IncrementalAudioSaver {
     checkpoints_dir: PathBuf,
     meeting_folder: PathBuf,
     sample_rate: u32,
+    bytes_written: Arc<AtomicU64>,  // Encoded checkpoint bytes on disk, for live status
 }
 
 impl IncrementalAudioSaver {
@@ -46,6 +49,7 @@ impl IncrementalAudioSaver {
             checkpoints_dir,
             meeting_folder,
             sample_rate,
+            bytes_written: Arc::new(AtomicU64::new(0)),
         })
     }
 
@@ -102,6 +106,9 @@ impl IncrementalAudioSaver {
 
         let duration_seconds = audio_data.len() as f32 / self.sample_rate as f32;
         self.checkpoint_count += 1;
+        if let Ok(metadata) = std::fs::metadata(&checkpoint_path) {
+            self.bytes_written.fetch_add(metadata.len(), Ordering::Relaxed);
+        }
 
         info!("Saved checkpoint {}: {:.2}s of audio ({} samples)",
               self.checkpoint_count,
@@ -223,6 +230,11 @@ impl IncrementalAudioSaver {
     pub fn get_checkpoint_count(&self) -> u32 {
         self.checkpoint_count
     }
+
+    /// Shared counter of checkpoint bytes written, readable without locking the saver
+    pub fn bytes_written_counter(&self) -> Arc<AtomicU64> {
+        self.bytes_written.clone()
+    }
 }
 
 /// Audio recovery status for transcript recovery feature
@@ -0,0 +1,152 @@
+// audio/keyword_alerts.rs
+//
+// Live keyword alerting. Final transcript segments are matched against the
+// enabled watchlist while recording; each match emits a `keyword-alert` event
+// and is recorded in keyword_hits. The watchlist is cached in memory so
+// matching never waits on the database.
+
+use crate::database::repositories::keyword_watch::{KeywordWatchRepository, WatchKeyword};
+use crate::events;
+use crate::state::AppState;
+use log::{error, info, warn};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::transcription::TranscriptUpdate;
+
+pub const KEYWORD_ALERT_EVENT: &str = "keyword-alert";
+
+static WATCHLIST: RwLock<Vec<WatchKeyword>> = RwLock::new(Vec::new());
+
+#[derive(Debug, Clone, Serialize)]
+pub struct KeywordAlert {
+    pub keyword_id: String,
+    pub keyword: String,
+    pub text: String,
+    pub sequence_id: u64,
+    pub audio_start_time: f64,
+    pub timestamp: String,
+}
+
+/// Reloads the enabled keywords into the cache. Called when recording starts
+/// and whenever the watchlist changes.
+pub async fn refresh_watchlist(pool: &SqlitePool) {
+    match KeywordWatchRepository::list_keywords(pool).await {
+        Ok(keywords) => {
+            let enabled: Vec<_> = keywords.into_iter().filter(|k| k.enabled).collect();
+            info!("Keyword watchlist loaded with {} keywords", enabled.len());
+            *WATCHLIST.write().unwrap_or_else(|e| e.into_inner()) = enabled;
+        }
+        Err(e) => warn!("Failed to load keyword watchlist: {}", e),
+    }
+}
+
+/// Whether `keyword` occurs in `text` as whole words, ignoring case
+pub fn contains_keyword(text: &str, keyword: &str) -> bool {
+    let text = text.to_lowercase();
+    let keyword = keyword.to_lowercase();
+    if keyword.is_empty() {
+        return false;
+    }
+    let is_word_char = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric());
+    text.match_indices(&keyword).any(|(start, matched)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + matched.len()..].chars().next();
+        // Only require a boundary where the keyword itself starts/ends with a word character
+        (!is_word_char(keyword.chars().next()) || !is_word_char(before))
+            && (!is_word_char(keyword.chars().next_back()) || !is_word_char(after))
+    })
+}
+
+/// The watched keywords found in `text`
+pub fn matching_keywords<'a>(text: &str, keywords: &'a [WatchKeyword]) -> Vec<&'a WatchKeyword> {
+    keywords
+        .iter()
+        .filter(|k| contains_keyword(text, &k.keyword))
+        .collect()
+}
+
+/// Checks a transcript update against the watchlist, emitting an alert and
+/// recording a hit for every match. Partial results are skipped so a phrase
+/// is flagged once, when its segment is final.
+pub fn check_segment<R: Runtime>(
+    app: &AppHandle<R>,
+    update: &TranscriptUpdate,
+    folder_path: Option<String>,
+) {
+    if update.is_partial {
+        return;
+    }
+    let matches: Vec<WatchKeyword> = {
+        let watchlist = WATCHLIST.read().unwrap_or_else(|e| e.into_inner());
+        matching_keywords(&update.text, &watchlist)
+            .into_iter()
+            .cloned()
+            .collect()
+    };
+    if matches.is_empty() {
+        return;
+    }
+
+    for keyword in &matches {
+        info!(
+            "Keyword '{}' heard at {:.1}s",
+            keyword.keyword, update.audio_start_time
+        );
+        let alert = KeywordAlert {
+            keyword_id: keyword.id.clone(),
+            keyword: keyword.keyword.clone(),
+            text: update.text.clone(),
+            sequence_id: update.sequence_id,
+            audio_start_time: update.audio_start_time,
+            timestamp: update.timestamp.clone(),
+        };
+        if let Err(e) = events::emit(app, &alert) {
+            error!("Failed to emit keyword alert: {}", e);
+        }
+    }
+
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let pool = state.db_manager.pool().clone();
+    let update = update.clone();
+    // The transcript may be saved before this runs; record_hit then attaches
+    // the hit to the saved meeting itself
+    tauri::async_runtime::spawn(async move {
+        for keyword in &matches {
+            if let Err(e) = KeywordWatchRepository::record_hit(
+                &pool,
+                keyword,
+                folder_path.as_deref(),
+                &update.text,
+                update.sequence_id,
+                update.audio_start_time,
+            )
+            .await
+            {
+                warn!(
+                    "Failed to record hit for keyword '{}': {}",
+                    keyword.keyword, e
+                );
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_whole_words_ignoring_case() {
+        assert!(contains_keyword("Let's talk Pricing next.", "pricing"));
+        assert!(contains_keyword("the new CONTRACT terms", "contract terms"));
+        assert!(!contains_keyword("we are repricing it", "pricing"));
+        assert!(!contains_keyword("contractor", "contract"));
+        assert!(contains_keyword("is C++ allowed?", "c++"));
+        assert!(!contains_keyword("anything", ""));
+    }
+}
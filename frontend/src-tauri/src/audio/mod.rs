@@ -1,8 +1,18 @@
 // src/audio/mod.rs
 pub mod audio_processing;
+pub mod benchmark;
+pub mod folder_template;
 pub mod encode;
 pub mod ffmpeg;
 pub mod vad;
+pub mod speaker_embedding;
+pub mod import;
+pub mod mp4_recovery;
+pub mod file_import;
+pub mod url_import;
+pub mod transcript_import;
+pub mod keyword_alerts;
+pub mod corrections;
 
 // Modularized device management
 pub mod devices;
@@ -21,7 +31,10 @@ pub mod stream;
 pub mod recording_manager;
 pub mod recording_commands;
 pub mod recording_preferences;
+pub mod recordings_root;
 pub mod recording_saver;
+pub mod recording_status;
+pub mod power;
 pub mod incremental_saver;  // NEW: Incremental audio saving with checkpoints
 pub mod level_monitor;
 pub mod simple_level_monitor;
@@ -34,6 +47,12 @@ pub mod system_detector;
 pub mod system_audio_commands;
 pub mod device_monitor;  // NEW: Device disconnect/reconnect monitoring
 pub mod playback_monitor; // NEW: Playback device detection for BT warnings
+pub mod playback;
+pub mod time_stretch;
+pub mod waveform;
+pub mod compress;
+pub mod segment_rerun;
+pub mod alignment;
 
 // Transcription module (provider abstraction, engine management, worker pool)
 pub mod transcription;
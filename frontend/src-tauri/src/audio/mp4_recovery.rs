@@ -0,0 +1,312 @@
+//! Recovery of AAC audio from MP4/M4A files that were never finalized, e.g. a
+//! recording cut off by a crash. The sample table (`moov`) is written last,
+//! so such files hold raw AAC frames in `mdat` with nothing saying where one
+//! frame ends and the next begins, and FFmpeg can't open them.
+//!
+//! Frame boundaries are found by decoding: the AAC-LC decoder stops at a
+//! frame's end marker, so the shortest prefix it decodes with zeros appended
+//! is the frame. The frames are then wrapped in ADTS headers, which FFmpeg
+//! decodes like any .aac file. Only audio-only files (recordings, voice
+//! memos) are recovered; interleaved video ends the recovery.
+
+use symphonia::core::audio::Channels;
+use symphonia::core::codecs::{CodecParameters, Decoder, DecoderOptions, CODEC_TYPE_AAC};
+use symphonia::core::formats::Packet;
+use symphonia::default::codecs::AacDecoder;
+
+/// AAC frames don't carry their sample rate; 48 kHz is what the recorder and
+/// most devices write
+pub const ASSUMED_SAMPLE_RATE: u32 = 48000;
+/// ADTS sampling frequency index of 48 kHz
+const SAMPLE_RATE_INDEX: u8 = 3;
+/// An AAC-LC frame holds at most 6144 bits per channel
+const MAX_FRAME_BYTES: usize = 1536;
+/// Frames that must follow each other before a channel layout is trusted
+const CONFIRM_FRAMES: usize = 3;
+/// Element that opens each frame: a single channel (mono) or a channel pair
+const SINGLE_CHANNEL_ELEMENT: u8 = 0;
+const CHANNEL_PAIR_ELEMENT: u8 = 1;
+
+/// Byte range of the `mdat` payload. A box size of 0, or one reaching past
+/// the end of the file, means it was never closed and runs to the end.
+pub fn find_mdat(bytes: &[u8]) -> Option<std::ops::Range<usize>> {
+    let mut offset = 0usize;
+    while offset + 8 <= bytes.len() {
+        let size = u32::from_be_bytes(bytes[offset..offset + 4].try_into().ok()?) as u64;
+        let kind = &bytes[offset + 4..offset + 8];
+        let (header, size) = match size {
+            1 => {
+                let large = bytes.get(offset + 8..offset + 16)?;
+                (16, u64::from_be_bytes(large.try_into().ok()?))
+            }
+            size => (8, size),
+        };
+        let end = match usize::try_from(size) {
+            Ok(0) => bytes.len(),
+            Ok(size) if size >= header => offset.saturating_add(size).min(bytes.len()),
+            _ => return None,
+        };
+        if kind == b"mdat" {
+            return (offset + header < end).then_some(offset + header..end);
+        }
+        if end == bytes.len() {
+            return None;
+        }
+        offset = end;
+    }
+    None
+}
+
+/// A 7-byte ADTS header (AAC-LC, no CRC) for a frame of `payload_len` bytes
+pub fn adts_header(channels: u8, payload_len: usize) -> [u8; 7] {
+    let len = payload_len + 7;
+    [
+        0xFF,
+        0xF1,
+        (1 << 6) | (SAMPLE_RATE_INDEX << 2) | ((channels >> 2) & 0x01),
+        ((channels & 0x03) << 6) | ((len >> 11) & 0x03) as u8,
+        ((len >> 3) & 0xFF) as u8,
+        (((len & 0x07) << 5) as u8) | 0x1F,
+        0xFC,
+    ]
+}
+
+struct FrameSplitter {
+    decoder: AacDecoder,
+    first_element: u8,
+    scratch: Vec<u8>,
+}
+
+impl FrameSplitter {
+    fn new(channels: u8) -> Option<Self> {
+        let layout = match channels {
+            1 => Channels::FRONT_CENTRE,
+            _ => Channels::FRONT_LEFT | Channels::FRONT_RIGHT,
+        };
+        let mut params = CodecParameters::new();
+        params
+            .for_codec(CODEC_TYPE_AAC)
+            .with_sample_rate(ASSUMED_SAMPLE_RATE)
+            .with_channels(layout);
+        let decoder = AacDecoder::try_new(&params, &DecoderOptions::default()).ok()?;
+        Some(Self {
+            decoder,
+            first_element: match channels {
+                1 => SINGLE_CHANNEL_ELEMENT,
+                _ => CHANNEL_PAIR_ELEMENT,
+            },
+            scratch: Vec::with_capacity(MAX_FRAME_BYTES + 8),
+        })
+    }
+
+    /// Whether `bytes` holds a whole frame. The decoder ignores what follows
+    /// a frame's end marker; zeros after an unfinished frame read as one
+    /// more channel element, which the layout has no room for.
+    fn is_whole_frame(&mut self, bytes: &[u8]) -> bool {
+        self.scratch.clear();
+        self.scratch.extend_from_slice(bytes);
+        self.scratch.extend_from_slice(&[0; 8]);
+        self.decoder
+            .decode(&Packet::new_from_slice(0, 0, 0, &self.scratch))
+            .is_ok()
+    }
+
+    /// Length of the frame at the start of `bytes`
+    fn frame_length(&mut self, bytes: &[u8]) -> Option<usize> {
+        if bytes.first()? >> 5 != self.first_element {
+            return None;
+        }
+        let max = bytes.len().min(MAX_FRAME_BYTES);
+        if !self.is_whole_frame(&bytes[..max]) {
+            return None;
+        }
+        let (mut partial, mut whole) = (0, max);
+        while whole - partial > 1 {
+            let middle = (partial + whole) / 2;
+            if self.is_whole_frame(&bytes[..middle]) {
+                whole = middle;
+            } else {
+                partial = middle;
+            }
+        }
+        Some(whole)
+    }
+
+    /// Consecutive frames from the start of `data`, as byte ranges
+    fn frames(&mut self, data: &[u8], limit: usize) -> Vec<std::ops::Range<usize>> {
+        let mut frames = Vec::new();
+        let mut offset = 0;
+        while offset < data.len() && frames.len() < limit {
+            match self.frame_length(&data[offset..]) {
+                Some(length) => {
+                    frames.push(offset..offset + length);
+                    offset += length;
+                }
+                None => break,
+            }
+        }
+        frames
+    }
+}
+
+/// The audio of an unfinished MP4/M4A as an ADTS stream, with the number of
+/// bytes before the first frame. None when no AAC-LC frames are found.
+pub fn recover_adts(bytes: &[u8]) -> Option<(Vec<u8>, usize)> {
+    let mdat = find_mdat(bytes)?;
+    let data = &bytes[mdat.clone()];
+    let (channels, mut splitter) = [1u8, 2].into_iter().find_map(|channels| {
+        let mut splitter = FrameSplitter::new(channels)?;
+        let confirmed = splitter.frames(data, CONFIRM_FRAMES).len();
+        (confirmed == CONFIRM_FRAMES).then_some((channels, splitter))
+    })?;
+
+    let frames = splitter.frames(data, usize::MAX);
+    let mut adts = Vec::with_capacity(data.len() + frames.len() * 7);
+    for frame in &frames {
+        adts.extend_from_slice(&adts_header(channels, frame.len()));
+        adts.extend_from_slice(&data[frame.clone()]);
+    }
+    log::debug!(
+        "Recovered {} AAC frames ({} channels) from an unfinished MP4",
+        frames.len(),
+        channels
+    );
+    Some((adts, mdat.start))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::import::find_frame_sync;
+
+    /// Writes bit fields most significant bit first
+    #[derive(Default)]
+    struct Bits {
+        bytes: Vec<u8>,
+        used: usize,
+    }
+
+    impl Bits {
+        fn push(&mut self, value: u32, width: usize) -> &mut Self {
+            for bit in (0..width).rev() {
+                if self.used % 8 == 0 {
+                    self.bytes.push(0);
+                }
+                if (value >> bit) & 1 == 1 {
+                    *self.bytes.last_mut().unwrap() |= 0x80 >> (self.used % 8);
+                }
+                self.used += 1;
+            }
+            self
+        }
+    }
+
+    /// Global gain, then a long window with no scale factor bands: silence
+    fn silent_channel(bits: &mut Bits, gain: u32) {
+        bits.push(gain, 8)
+            .push(0, 1)
+            .push(0, 2)
+            .push(0, 1)
+            .push(0, 6)
+            .push(0, 1);
+        // No pulse, TNS or gain control data
+        bits.push(0, 3);
+    }
+
+    /// A silent AAC-LC frame, padded with a fill element of `fill` bytes
+    fn silent_frame(channels: u8, gain: u32, fill: u32) -> Vec<u8> {
+        let mut bits = Bits::default();
+        if channels == 1 {
+            bits.push(0, 3).push(0, 4);
+            silent_channel(&mut bits, gain);
+        } else {
+            bits.push(1, 3).push(0, 4).push(0, 1);
+            silent_channel(&mut bits, gain);
+            silent_channel(&mut bits, gain);
+        }
+        if fill > 0 {
+            bits.push(6, 3).push(fill, 4);
+            for _ in 0..fill {
+                bits.push(0xA5, 8);
+            }
+        }
+        bits.push(7, 3);
+        bits.bytes
+    }
+
+    fn unfinished_mp4(frames: &[Vec<u8>], tail: &[u8]) -> Vec<u8> {
+        let mut file = Vec::new();
+        file.extend_from_slice(&16u32.to_be_bytes());
+        file.extend_from_slice(b"ftypisom\0\0\x02\0");
+        file.extend_from_slice(&8u32.to_be_bytes());
+        file.extend_from_slice(b"free");
+        file.extend_from_slice(&0u32.to_be_bytes());
+        file.extend_from_slice(b"mdat");
+        for frame in frames {
+            file.extend_from_slice(frame);
+        }
+        file.extend_from_slice(tail);
+        file
+    }
+
+    /// Payloads of an ADTS stream
+    fn adts_payloads(mut adts: &[u8]) -> Vec<&[u8]> {
+        let mut payloads = Vec::new();
+        while adts.len() >= 7 {
+            let length = ((adts[3] as usize & 0x03) << 11)
+                | ((adts[4] as usize) << 3)
+                | (adts[5] as usize >> 5);
+            payloads.push(&adts[7..length]);
+            adts = &adts[length..];
+        }
+        payloads
+    }
+
+    #[test]
+    fn finds_the_media_data_box() {
+        let file = unfinished_mp4(&[vec![1, 2, 3]], &[]);
+        assert_eq!(find_mdat(&file), Some(32..35));
+
+        let mut closed = file[..24].to_vec();
+        closed.extend_from_slice(&10u32.to_be_bytes());
+        closed.extend_from_slice(b"mdat\x01\x02moov");
+        assert_eq!(find_mdat(&closed), Some(32..34));
+
+        assert_eq!(find_mdat(&file[..24]), None);
+        assert_eq!(find_mdat(b"not an mp4 file at all"), None);
+    }
+
+    #[test]
+    fn recovers_frames_of_an_unfinished_recording() {
+        let frames: Vec<Vec<u8>> = (0..12)
+            .map(|i| silent_frame(1, 90 + i, [0, 1, 2, 5, 14][i as usize % 5]))
+            .collect();
+        // The frame being written when the recording stopped is incomplete
+        let cut = &silent_frame(1, 100, 3)[..3];
+        let (adts, skipped) = recover_adts(&unfinished_mp4(&frames, cut)).unwrap();
+
+        assert_eq!(skipped, 32);
+        let payloads = adts_payloads(&adts);
+        assert_eq!(payloads.len(), frames.len());
+        for (payload, frame) in payloads.iter().zip(&frames) {
+            assert_eq!(*payload, frame.as_slice());
+        }
+        assert_eq!(adts[2] >> 6, 1, "AAC-LC");
+        assert_eq!(adts[3] >> 6, 1, "mono");
+        assert_eq!(find_frame_sync(&adts), Some((0, "aac")));
+    }
+
+    #[test]
+    fn recovers_stereo_frames() {
+        let frames: Vec<Vec<u8>> = (0..5).map(|i| silent_frame(2, 80 + i, i % 3)).collect();
+        let (adts, _) = recover_adts(&unfinished_mp4(&frames, &[])).unwrap();
+        assert_eq!(adts_payloads(&adts).len(), frames.len());
+        assert_eq!(adts[3] >> 6, 2, "stereo");
+    }
+
+    #[test]
+    fn other_data_is_not_taken_for_audio() {
+        let noise: Vec<u8> = (0..4096u32).map(|i| (i * 7919 % 251) as u8).collect();
+        assert!(recover_adts(&unfinished_mp4(&[], &noise)).is_none());
+    }
+}
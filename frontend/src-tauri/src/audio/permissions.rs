@@ -153,4 +153,48 @@ mod tests {
         let has_permission = check_screen_recording_permission();
         println!("Has Screen Recording permission: {}", has_permission);
     }
-}
\ No newline at end of file
+}
+/// Microphone access as reported by the OS
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MicrophonePermission {
+    Granted,
+    Denied,
+    NotDetermined,
+    /// The platform has no queryable permission state
+    Unknown,
+}
+
+/// Reads the AVFoundation authorization status for audio capture without
+/// prompting the user
+#[cfg(target_os = "macos")]
+pub fn check_microphone_permission() -> MicrophonePermission {
+    use objc::runtime::{Class, Object};
+    use objc::{msg_send, sel, sel_impl};
+
+    let (Some(capture_device), Some(ns_string)) =
+        (Class::get("AVCaptureDevice"), Class::get("NSString"))
+    else {
+        warn!("AVFoundation not available, cannot read microphone permission");
+        return MicrophonePermission::Unknown;
+    };
+    // AVMediaTypeAudio
+    let media_type = c"soun";
+    let status: i64 = unsafe {
+        let media_type: *mut Object =
+            msg_send![ns_string, stringWithUTF8String: media_type.as_ptr()];
+        msg_send![capture_device, authorizationStatusForMediaType: media_type]
+    };
+    match status {
+        0 => MicrophonePermission::NotDetermined,
+        // 1 = restricted (parental controls / MDM), 2 = denied
+        1 | 2 => MicrophonePermission::Denied,
+        3 => MicrophonePermission::Granted,
+        _ => MicrophonePermission::Unknown,
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn check_microphone_permission() -> MicrophonePermission {
+    MicrophonePermission::Unknown
+}
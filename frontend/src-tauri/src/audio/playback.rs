@@ -0,0 +1,508 @@
+//! Native playback of meeting recordings with variable speed.
+//!
+//! The recording is decoded to 16kHz mono by FFmpeg while it plays: a reader
+//! thread keeps about a minute decoded ahead of the play position, and audio
+//! well behind it is dropped, so long recordings start at once and don't sit
+//! in memory. A seek outside the decoded audio restarts FFmpeg at the new
+//! position. Speeds other than 1x go through [`TimeStretcher`] so voices keep
+//! their pitch. The cpal stream lives on its own thread because streams
+//! aren't `Send` on every platform; it stops when the [`Player`] is dropped.
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{FromSample, SampleFormat, SizedSample, StreamConfig};
+use log::{error, info, warn};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Stdio};
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::Duration;
+
+use super::import::{decode_command, probe_media, samples_from_f32le, IMPORT_SAMPLE_RATE};
+use super::time_stretch::{TimeStretcher, MAX_PLAYBACK_RATE, MIN_PLAYBACK_RATE};
+
+static PLAYER: Mutex<Option<Player>> = Mutex::new(None);
+
+/// How far the decoder may run ahead of the play position
+const DECODE_AHEAD: usize = 60 * IMPORT_SAMPLE_RATE as usize;
+/// Audio kept behind the play position, so short seeks back don't restart
+/// the decoder
+const KEEP_BEHIND: usize = 30 * IMPORT_SAMPLE_RATE as usize;
+/// Decoded audio the stretcher needs past its read position (a frame plus
+/// its search range at the fastest rate); less than this is played as
+/// silence until the decoder catches up
+const LOOKAHEAD: usize = IMPORT_SAMPLE_RATE as usize / 4;
+/// How often a decoder that is far enough ahead checks again
+const DECODE_WAIT: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlaybackStatus {
+    pub path: String,
+    pub playing: bool,
+    /// True once playback reached the end of the recording
+    pub ended: bool,
+    pub position_seconds: f64,
+    pub duration_seconds: f64,
+    pub rate: f32,
+}
+
+struct Player {
+    path: PathBuf,
+    state: Arc<Mutex<PlaybackState>>,
+    /// Dropping the sender ends the stream thread
+    _stop: mpsc::Sender<()>,
+}
+
+impl Player {
+    fn status(&self) -> PlaybackStatus {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        PlaybackStatus {
+            path: self.path.to_string_lossy().to_string(),
+            playing: !state.paused && !state.ended,
+            ended: state.ended,
+            position_seconds: state.heard_position() / IMPORT_SAMPLE_RATE as f64,
+            duration_seconds: state.duration_seconds(),
+            rate: state.rate,
+        }
+    }
+}
+
+/// Audio decoded by one FFmpeg run and not yet dropped
+struct DecodedAudio {
+    /// Position in the recording of `samples[0]`, in samples
+    start: usize,
+    samples: Vec<f32>,
+    /// Where playback is reading, so the decoder knows how far ahead it is
+    playing: usize,
+    finished: bool,
+    /// Tells the reader thread to stop FFmpeg
+    cancelled: bool,
+}
+
+impl DecodedAudio {
+    fn end(&self) -> usize {
+        self.start + self.samples.len()
+    }
+}
+
+/// FFmpeg decoding a recording from some position onwards. Dropping it stops
+/// the decode.
+struct Decoder {
+    audio: Arc<Mutex<DecodedAudio>>,
+}
+
+impl Decoder {
+    fn start(path: &Path, from: usize) -> Result<Self, String> {
+        let offset = format!("{:.3}", from as f64 / IMPORT_SAMPLE_RATE as f64);
+        let child = decode_command(path, &["-ss", &offset], None)
+            .map_err(|e| e.to_string())?
+            .stdout(Stdio::piped())
+            // Nothing reads FFmpeg's log, which would fill the pipe and stall it
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| format!("Failed to run FFmpeg: {}", e))?;
+        let audio = Arc::new(Mutex::new(DecodedAudio {
+            start: from,
+            samples: Vec::new(),
+            playing: from,
+            finished: false,
+            cancelled: false,
+        }));
+        let shared = audio.clone();
+        std::thread::Builder::new()
+            .name("meetily-playback-decode".to_string())
+            .spawn(move || read_decoded(child, shared))
+            .map_err(|e| format!("Failed to start the decoding thread: {}", e))?;
+        Ok(Self { audio })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, DecodedAudio> {
+        self.audio.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for Decoder {
+    fn drop(&mut self) {
+        self.lock().cancelled = true;
+    }
+}
+
+/// Moves FFmpeg's output into `audio`, pausing while it is far enough ahead
+/// of playback, until the recording ends or the decode is cancelled
+fn read_decoded(mut child: Child, audio: Arc<Mutex<DecodedAudio>>) {
+    let Some(mut stdout) = child.stdout.take() else {
+        return;
+    };
+    let mut buffer = vec![0u8; 64 * 1024];
+    // Bytes of a sample split across reads
+    let mut partial = Vec::new();
+    loop {
+        loop {
+            let audio = audio.lock().unwrap_or_else(|e| e.into_inner());
+            if audio.cancelled {
+                drop(audio);
+                let _ = child.kill();
+                let _ = child.wait();
+                return;
+            }
+            if audio.end() < audio.playing + DECODE_AHEAD {
+                break;
+            }
+            drop(audio);
+            std::thread::sleep(DECODE_WAIT);
+        }
+        let read = match stdout.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) => {
+                warn!("Failed to read the decoded audio: {}", e);
+                break;
+            }
+        };
+        partial.extend_from_slice(&buffer[..read]);
+        let whole = partial.len() / 4 * 4;
+        let samples = samples_from_f32le(&partial[..whole]);
+        partial.drain(..whole);
+        audio
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .samples
+            .extend(samples);
+    }
+    match child.wait() {
+        Ok(status) if !status.success() => {
+            error!("FFmpeg stopped decoding for playback: {}", status)
+        }
+        Err(e) => error!("Failed to wait for FFmpeg: {}", e),
+        Ok(_) => {}
+    }
+    audio.lock().unwrap_or_else(|e| e.into_inner()).finished = true;
+}
+
+struct PlaybackState {
+    path: PathBuf,
+    /// From the file's header; unknown for some damaged files
+    duration: Option<f64>,
+    decoder: Decoder,
+    /// Read position in the recording, in input samples
+    position: f64,
+    rate: f32,
+    paused: bool,
+    ended: bool,
+    stretcher: TimeStretcher,
+    /// Stretched 16kHz audio not yet played
+    pending: VecDeque<f32>,
+    // Linear resampling from 16kHz to the device rate
+    current: f32,
+    next: f32,
+    phase: f64,
+}
+
+impl PlaybackState {
+    fn new(
+        path: PathBuf,
+        duration: Option<f64>,
+        start_seconds: f64,
+        rate: f32,
+    ) -> Result<Self, String> {
+        let start = Self::clamp(duration, start_seconds);
+        Ok(Self {
+            decoder: Decoder::start(&path, start)?,
+            path,
+            duration,
+            position: start as f64,
+            rate,
+            paused: false,
+            ended: false,
+            stretcher: TimeStretcher::new(IMPORT_SAMPLE_RATE),
+            pending: VecDeque::new(),
+            current: 0.0,
+            next: 0.0,
+            phase: 1.0,
+        })
+    }
+
+    /// `seconds` as a sample position within the recording
+    fn clamp(duration: Option<f64>, seconds: f64) -> usize {
+        let seconds = match duration {
+            Some(duration) => seconds.min(duration),
+            None => seconds,
+        };
+        (seconds.max(0.0) * IMPORT_SAMPLE_RATE as f64) as usize
+    }
+
+    fn duration_seconds(&self) -> f64 {
+        self.duration.unwrap_or_else(|| {
+            let decoder = self.decoder.lock();
+            let known = if decoder.finished {
+                decoder.end() as f64
+            } else {
+                self.position.max(decoder.end() as f64)
+            };
+            known / IMPORT_SAMPLE_RATE as f64
+        })
+    }
+
+    fn seek(&mut self, seconds: f64) -> Result<(), String> {
+        let target = Self::clamp(self.duration, seconds);
+        let decoded = {
+            let decoder = self.decoder.lock();
+            decoder.start <= target && (target < decoder.end() || decoder.finished)
+        };
+        if !decoded {
+            self.decoder = Decoder::start(&self.path, target)?;
+        }
+        self.position = target as f64;
+        self.pending.clear();
+        self.stretcher.reset();
+        self.ended = false;
+        Ok(())
+    }
+
+    fn set_rate(&mut self, rate: f32) -> Result<(), String> {
+        let heard = self.heard_position();
+        self.rate = rate;
+        self.seek(heard / IMPORT_SAMPLE_RATE as f64)
+    }
+
+    /// Position of the audio coming out of the speakers, not of the stretcher
+    fn heard_position(&self) -> f64 {
+        (self.position - self.pending.len() as f64 * self.rate as f64).max(0.0)
+    }
+
+    /// Next sample at the device rate; `step` is 16kHz / device rate
+    fn next_output_sample(&mut self, step: f64) -> f32 {
+        if self.paused || self.ended {
+            return 0.0;
+        }
+        while self.phase >= 1.0 {
+            self.current = self.next;
+            self.next = self.pull();
+            self.phase -= 1.0;
+        }
+        let sample = self.current + (self.next - self.current) * self.phase as f32;
+        self.phase += step;
+        sample
+    }
+
+    fn pull(&mut self) -> f32 {
+        if self.pending.is_empty() {
+            self.refill();
+        }
+        self.pending.pop_front().unwrap_or(0.0)
+    }
+
+    /// Stretches the next hop of decoded audio into `pending`, leaving it
+    /// empty while the decoder is behind
+    fn refill(&mut self) {
+        let mut decoder = self.decoder.lock();
+        decoder.playing = self.position as usize;
+        if !decoder.finished && decoder.playing + LOOKAHEAD > decoder.end() {
+            return;
+        }
+        let mut relative = self.position - decoder.start as f64;
+        self.stretcher.process(
+            &decoder.samples,
+            &mut relative,
+            self.rate as f64,
+            &mut self.pending,
+        );
+        self.position = decoder.start as f64 + relative;
+        if self.pending.is_empty() {
+            self.ended = true;
+        }
+
+        let behind = (self.position as usize).saturating_sub(decoder.start);
+        if behind > 2 * KEEP_BEHIND {
+            let removed = behind - KEEP_BEHIND;
+            decoder.samples.drain(..removed);
+            decoder.start += removed;
+            self.stretcher.shift(removed);
+        }
+    }
+}
+
+fn validate_rate(rate: f32) -> Result<f32, String> {
+    if !(MIN_PLAYBACK_RATE..=MAX_PLAYBACK_RATE).contains(&rate) {
+        return Err(format!(
+            "Playback speed must be between {}x and {}x",
+            MIN_PLAYBACK_RATE, MAX_PLAYBACK_RATE
+        ));
+    }
+    Ok(rate)
+}
+
+fn build_stream<T>(
+    device: &cpal::Device,
+    config: &StreamConfig,
+    state: Arc<Mutex<PlaybackState>>,
+) -> Result<cpal::Stream, cpal::BuildStreamError>
+where
+    T: SizedSample + FromSample<f32>,
+{
+    let channels = config.channels.max(1) as usize;
+    let step = IMPORT_SAMPLE_RATE as f64 / config.sample_rate.0 as f64;
+    device.build_output_stream(
+        config,
+        move |data: &mut [T], _: &cpal::OutputCallbackInfo| {
+            let Ok(mut state) = state.lock() else {
+                data.fill(T::from_sample(0.0f32));
+                return;
+            };
+            for frame in data.chunks_mut(channels) {
+                frame.fill(T::from_sample(state.next_output_sample(step)));
+            }
+        },
+        |err| error!("Playback stream error: {}", err),
+        None,
+    )
+}
+
+fn open_output_stream(state: Arc<Mutex<PlaybackState>>) -> Result<cpal::Stream, String> {
+    let device = cpal::default_host()
+        .default_output_device()
+        .ok_or_else(|| "No audio output device found".to_string())?;
+    let supported = device
+        .default_output_config()
+        .map_err(|e| format!("Failed to get the output device config: {}", e))?;
+    let config = supported.config();
+    let stream = match supported.sample_format() {
+        SampleFormat::F32 => build_stream::<f32>(&device, &config, state),
+        SampleFormat::I16 => build_stream::<i16>(&device, &config, state),
+        SampleFormat::U16 => build_stream::<u16>(&device, &config, state),
+        SampleFormat::I32 => build_stream::<i32>(&device, &config, state),
+        format => return Err(format!("Unsupported output sample format: {:?}", format)),
+    }
+    .map_err(|e| format!("Failed to open the output device: {}", e))?;
+    stream
+        .play()
+        .map_err(|e| format!("Failed to start playback: {}", e))?;
+    Ok(stream)
+}
+
+/// Starts the output stream on its own thread; it runs until the returned
+/// sender is dropped
+fn spawn_output(state: Arc<Mutex<PlaybackState>>) -> Result<mpsc::Sender<()>, String> {
+    let (ready_tx, ready_rx) = mpsc::channel();
+    let (stop_tx, stop_rx) = mpsc::channel::<()>();
+    std::thread::Builder::new()
+        .name("meetily-playback".to_string())
+        .spawn(move || {
+            let stream = match open_output_stream(state) {
+                Ok(stream) => stream,
+                Err(e) => {
+                    let _ = ready_tx.send(Err(e));
+                    return;
+                }
+            };
+            let _ = ready_tx.send(Ok(()));
+            let _ = stop_rx.recv();
+            drop(stream);
+        })
+        .map_err(|e| format!("Failed to start the playback thread: {}", e))?;
+    ready_rx
+        .recv()
+        .map_err(|_| "The playback thread exited unexpectedly".to_string())??;
+    Ok(stop_tx)
+}
+
+fn with_player<T>(f: impl FnOnce(&Player) -> T) -> Result<T, String> {
+    let player = PLAYER.lock().unwrap_or_else(|e| e.into_inner());
+    player
+        .as_ref()
+        .map(f)
+        .ok_or_else(|| "Nothing is playing".to_string())
+}
+
+fn with_state(
+    f: impl FnOnce(&mut PlaybackState) -> Result<(), String>,
+) -> Result<PlaybackStatus, String> {
+    with_player(|player| {
+        f(&mut player.state.lock().unwrap_or_else(|e| e.into_inner()))?;
+        Ok(player.status())
+    })?
+}
+
+/// Plays a recording from `start_seconds` at `rate` (0.5x–3x, default 1x).
+/// Replaces anything already playing.
+#[tauri::command]
+pub async fn start_playback(
+    path: String,
+    start_seconds: Option<f64>,
+    rate: Option<f32>,
+) -> Result<PlaybackStatus, String> {
+    info!("start_playback called for {}", path);
+    let rate = validate_rate(rate.unwrap_or(1.0))?;
+    let path = PathBuf::from(path);
+
+    // Stop the current stream before opening another
+    PLAYER.lock().unwrap_or_else(|e| e.into_inner()).take();
+    let probed = path.clone();
+    let probe = tokio::task::spawn_blocking(move || probe_media(&probed))
+        .await
+        .map_err(|e| format!("Probing task failed: {}", e))?
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    if !probe.has_audio {
+        return Err(format!("{} has no audio", path.display()));
+    }
+
+    let state = Arc::new(Mutex::new(PlaybackState::new(
+        path.clone(),
+        probe.duration_seconds,
+        start_seconds.unwrap_or(0.0),
+        rate,
+    )?));
+    let stop = spawn_output(state.clone())?;
+    let player = Player {
+        path,
+        state,
+        _stop: stop,
+    };
+    let status = player.status();
+    *PLAYER.lock().unwrap_or_else(|e| e.into_inner()) = Some(player);
+    Ok(status)
+}
+
+#[tauri::command]
+pub async fn pause_playback() -> Result<PlaybackStatus, String> {
+    with_state(|state| {
+        state.paused = true;
+        Ok(())
+    })
+}
+
+/// Resumes playback; after the end of the recording it starts over
+#[tauri::command]
+pub async fn resume_playback() -> Result<PlaybackStatus, String> {
+    with_state(|state| {
+        if state.ended {
+            state.seek(0.0)?;
+        }
+        state.paused = false;
+        Ok(())
+    })
+}
+
+#[tauri::command]
+pub async fn seek_playback(seconds: f64) -> Result<PlaybackStatus, String> {
+    with_state(|state| state.seek(seconds))
+}
+
+/// Changes the speed of the current playback (0.5x–3x) without changing pitch
+#[tauri::command]
+pub async fn set_playback_rate(rate: f32) -> Result<PlaybackStatus, String> {
+    let rate = validate_rate(rate)?;
+    with_state(|state| state.set_rate(rate))
+}
+
+#[tauri::command]
+pub async fn stop_playback() -> Result<(), String> {
+    PLAYER.lock().unwrap_or_else(|e| e.into_inner()).take();
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_playback_status() -> Option<PlaybackStatus> {
+    with_player(Player::status).ok()
+}
@@ -0,0 +1,250 @@
+// audio/power.rs
+//
+// Keeps the machine awake while a recording or import is running, and
+// optionally silences notifications. Sleep prevention is reference counted:
+// the OS inhibitor is taken by the first `WakeGuard` and released when the
+// last one drops, so overlapping recording and import sessions share it.
+//
+// Platform mechanisms (no extra dependencies):
+// - macOS: a `caffeinate` child process tied to our pid
+// - Windows: SetThreadExecutionState held by a dedicated thread
+// - Linux: a `systemd-inhibit` child process
+//
+// Do-not-disturb has no public API on most platforms, so it is best effort:
+// GNOME notification banners on Linux, and the user's "Meetily Focus On" /
+// "Meetily Focus Off" Shortcuts on macOS. Windows is not supported.
+
+use log::{info, warn};
+use std::sync::Mutex;
+
+struct WakeState {
+    holders: usize,
+    inhibitor: Option<Inhibitor>,
+}
+
+static WAKE_STATE: Mutex<WakeState> = Mutex::new(WakeState {
+    holders: 0,
+    inhibitor: None,
+});
+
+/// Keeps the system (and display) from sleeping until dropped
+#[must_use = "sleep is only prevented while the guard is alive"]
+pub struct WakeGuard {
+    _private: (),
+}
+
+impl Drop for WakeGuard {
+    fn drop(&mut self) {
+        let mut state = WAKE_STATE.lock().unwrap_or_else(|e| e.into_inner());
+        state.holders = state.holders.saturating_sub(1);
+        if state.holders == 0 {
+            if let Some(inhibitor) = state.inhibitor.take() {
+                inhibitor.release();
+                info!("Sleep prevention released");
+            }
+        }
+    }
+}
+
+/// Prevents system and display sleep until the returned guard drops. Failure
+/// to inhibit is logged, never fatal: the session simply runs unprotected.
+pub fn prevent_sleep(reason: &str) -> WakeGuard {
+    let mut state = WAKE_STATE.lock().unwrap_or_else(|e| e.into_inner());
+    state.holders += 1;
+    if state.inhibitor.is_none() {
+        match Inhibitor::acquire(reason) {
+            Ok(inhibitor) => {
+                info!("Sleep prevention active: {}", reason);
+                state.inhibitor = Some(inhibitor);
+            }
+            Err(e) => warn!("Could not prevent system sleep: {}", e),
+        }
+    }
+    WakeGuard { _private: () }
+}
+
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+struct Inhibitor(std::process::Child);
+
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+impl Inhibitor {
+    fn acquire(reason: &str) -> Result<Self, String> {
+        #[cfg(target_os = "macos")]
+        let child = {
+            let _ = reason;
+            std::process::Command::new("caffeinate")
+                // display, idle, disk and system sleep; exits with our process
+                .args(["-dims", "-w", &std::process::id().to_string()])
+                .spawn()
+        };
+        #[cfg(target_os = "linux")]
+        let child = std::process::Command::new("systemd-inhibit")
+            .args([
+                "--what=idle:sleep",
+                "--who=Meetily",
+                &format!("--why={}", reason),
+                "--mode=block",
+                "sleep",
+                "infinity",
+            ])
+            .spawn();
+        child
+            .map(Inhibitor)
+            .map_err(|e| format!("failed to start inhibitor: {}", e))
+    }
+
+    fn release(mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+#[cfg(target_os = "windows")]
+struct Inhibitor(std::sync::mpsc::Sender<()>);
+
+#[cfg(target_os = "windows")]
+impl Inhibitor {
+    fn acquire(_reason: &str) -> Result<Self, String> {
+        const ES_CONTINUOUS: u32 = 0x8000_0000;
+        const ES_SYSTEM_REQUIRED: u32 = 0x0000_0001;
+        const ES_DISPLAY_REQUIRED: u32 = 0x0000_0002;
+
+        #[link(name = "kernel32")]
+        extern "system" {
+            fn SetThreadExecutionState(es_flags: u32) -> u32;
+        }
+
+        // The execution state belongs to the calling thread, so hold it on a
+        // thread of our own rather than a runtime worker
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+        let (ready_tx, ready_rx) = std::sync::mpsc::channel::<bool>();
+        std::thread::Builder::new()
+            .name("sleep-inhibitor".to_string())
+            .spawn(move || {
+                let previous = unsafe {
+                    SetThreadExecutionState(
+                        ES_CONTINUOUS | ES_SYSTEM_REQUIRED | ES_DISPLAY_REQUIRED,
+                    )
+                };
+                let _ = ready_tx.send(previous != 0);
+                // Returns once the sender is dropped
+                let _ = release_rx.recv();
+                unsafe {
+                    SetThreadExecutionState(ES_CONTINUOUS);
+                }
+            })
+            .map_err(|e| format!("failed to start inhibitor thread: {}", e))?;
+
+        match ready_rx.recv() {
+            Ok(true) => Ok(Inhibitor(release_tx)),
+            _ => Err("SetThreadExecutionState failed".to_string()),
+        }
+    }
+
+    fn release(self) {
+        drop(self.0);
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+struct Inhibitor;
+
+#[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+impl Inhibitor {
+    fn acquire(_reason: &str) -> Result<Self, String> {
+        Err("not supported on this platform".to_string())
+    }
+
+    fn release(self) {}
+}
+
+// GNOME's banner setting before we changed it, restored when DND ends
+#[cfg(target_os = "linux")]
+static PREVIOUS_BANNERS: Mutex<Option<String>> = Mutex::new(None);
+
+/// Best-effort OS do-not-disturb. Returns whether it was applied.
+pub fn set_do_not_disturb(enabled: bool) -> bool {
+    let applied = set_do_not_disturb_impl(enabled);
+    match (applied, enabled) {
+        (true, true) => info!("Do-not-disturb enabled for recording"),
+        (true, false) => info!("Do-not-disturb restored"),
+        (false, _) => warn!("Do-not-disturb is not available on this system"),
+    }
+    applied
+}
+
+#[cfg(target_os = "macos")]
+fn set_do_not_disturb_impl(enabled: bool) -> bool {
+    let shortcut = if enabled {
+        "Meetily Focus On"
+    } else {
+        "Meetily Focus Off"
+    };
+    std::process::Command::new("shortcuts")
+        .args(["run", shortcut])
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(target_os = "linux")]
+fn set_do_not_disturb_impl(enabled: bool) -> bool {
+    const SCHEMA: &str = "org.gnome.desktop.notifications";
+    const KEY: &str = "show-banners";
+    let mut previous = PREVIOUS_BANNERS.lock().unwrap_or_else(|e| e.into_inner());
+    let value = if enabled {
+        if previous.is_none() {
+            let current = std::process::Command::new("gsettings")
+                .args(["get", SCHEMA, KEY])
+                .output()
+                .ok()
+                .filter(|out| out.status.success())
+                .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string());
+            match current {
+                Some(current) => *previous = Some(current),
+                None => return false,
+            }
+        }
+        "false".to_string()
+    } else {
+        match previous.take() {
+            Some(value) => value,
+            // We never changed it
+            None => return true,
+        }
+    };
+    std::process::Command::new("gsettings")
+        .args(["set", SCHEMA, KEY, &value])
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+fn set_do_not_disturb_impl(_enabled: bool) -> bool {
+    false
+}
+
+/// Power settings held for the duration of one recording: sleep prevention
+/// and, when requested, do-not-disturb. Both are released on drop.
+pub struct RecordingPowerSession {
+    _wake: Option<WakeGuard>,
+    dnd_enabled: bool,
+}
+
+impl RecordingPowerSession {
+    pub fn begin(prevent_sleep_enabled: bool, do_not_disturb: bool) -> Self {
+        Self {
+            _wake: prevent_sleep_enabled.then(|| prevent_sleep("Recording a meeting")),
+            dnd_enabled: do_not_disturb && set_do_not_disturb(true),
+        }
+    }
+}
+
+impl Drop for RecordingPowerSession {
+    fn drop(&mut self) {
+        if self.dnd_enabled {
+            set_do_not_disturb(false);
+        }
+    }
+}
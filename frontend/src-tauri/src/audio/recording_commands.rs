@@ -10,9 +10,14 @@ use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc, Mutex,
 };
-use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tauri::{AppHandle, Manager, Runtime};
 use tokio::task::JoinHandle;
 
+use crate::events::{
+    self, RecordingError, RecordingPaused, RecordingResumed, RecordingShutdownProgress,
+    RecordingStarted, RecordingStopped, TranscriptionError,
+};
+
 use super::{
     parse_audio_device,
     default_input_device,   // Get default microphone
@@ -21,6 +26,8 @@ use super::{
     DeviceEvent,
     DeviceMonitorType
 };
+use super::recording_preferences::RecordingPreferences;
+use super::recordings_root::resolve_recordings_root;
 
 // Import transcription modules
 use super::transcription::{
@@ -41,6 +48,8 @@ static IS_RECORDING: AtomicBool = AtomicBool::new(false);
 // Global recording manager and transcription task to keep them alive during recording
 static RECORDING_MANAGER: Mutex<Option<RecordingManager>> = Mutex::new(None);
 static TRANSCRIPTION_TASK: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+// Sleep prevention / do-not-disturb held while recording
+static POWER_SESSION: Mutex<Option<super::power::RecordingPowerSession>> = Mutex::new(None);
 
 // Listener ID for proper cleanup - prevents microphone from staying active after recording stops
 static TRANSCRIPT_LISTENER_ID: Mutex<Option<tauri::EventId>> = Mutex::new(None);
@@ -94,11 +103,11 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
 
         // Emit error event for frontend - actionable: false to show toast instead of modal
         // (download progress is already shown in top-right toast)
-        let _ = app.emit("transcription-error", serde_json::json!({
-            "error": validation_error,
-            "userMessage": "Recording cannot start: Transcription model is still downloading. Please wait for the download to complete.",
-            "actionable": false
-        }));
+        let _ = events::emit(&app, &TranscriptionError {
+            error: validation_error.clone(),
+            user_message: "Recording cannot start: Transcription model is still downloading. Please wait for the download to complete.".to_string(),
+            actionable: false,
+        });
 
         return Err(validation_error);
     }
@@ -110,19 +119,21 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
     // Create new recording manager
     let mut manager = RecordingManager::new();
 
-    // Load recording preferences to get auto_save AND device preferences
-    let (auto_save, preferred_mic_name, preferred_system_name) =
-        match super::recording_preferences::load_recording_preferences(&app).await {
-            Ok(prefs) => {
-                info!("📋 Loaded recording preferences: auto_save={}, preferred_mic={:?}, preferred_system={:?}",
-                      prefs.auto_save, prefs.preferred_mic_device, prefs.preferred_system_device);
-                (prefs.auto_save, prefs.preferred_mic_device, prefs.preferred_system_device)
-            }
-            Err(e) => {
-                warn!("Failed to load recording preferences, using defaults: {}", e);
-                (true, None, None)
-            }
-        };
+    // Load recording preferences to get auto_save, device preferences and the meeting folder settings
+    let preferences = match super::recording_preferences::load_recording_preferences(&app).await {
+        Ok(prefs) => {
+            info!("📋 Loaded recording preferences: auto_save={}, preferred_mic={:?}, preferred_system={:?}",
+                  prefs.auto_save, prefs.preferred_mic_device, prefs.preferred_system_device);
+            prefs
+        }
+        Err(e) => {
+            warn!("Failed to load recording preferences, using defaults: {}", e);
+            RecordingPreferences::default()
+        }
+    };
+    let auto_save = preferences.auto_save;
+    let preferred_mic_name = preferences.preferred_mic_device.clone();
+    let preferred_system_name = preferences.preferred_system_device.clone();
 
     // ============================================================================
     // MICROPHONE DEVICE RESOLUTION: Preference → Default → Error
@@ -224,10 +235,14 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
     });
     manager.set_meeting_name(Some(effective_meeting_name));
 
+    // A network recordings root that can't be reached falls back to the local spool
+    let recordings_root = resolve_recordings_root(&app, &preferences.save_folder).await?;
+    manager.set_meeting_folder(recordings_root, preferences.folder_name_template);
+
     // Set up error callback
     let app_for_error = app.clone();
     manager.set_error_callback(move |error| {
-        let _ = app_for_error.emit("recording-error", error.user_message());
+        let _ = events::emit(&app_for_error, &RecordingError(error.user_message()));
     });
 
     // Start recording with resolved devices (replaces start_recording_with_defaults_and_auto_save call)
@@ -246,6 +261,15 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
     info!("🔍 Setting IS_RECORDING to true and resetting SPEECH_DETECTED_EMITTED");
     IS_RECORDING.store(true, Ordering::SeqCst);
     reset_speech_detected_flag(); // Reset for new recording session
+    super::recording_status::start_status_task(app.clone());
+    begin_power_session(&app).await;
+
+    // Load the keyword watchlist and learned corrections before the first
+    // segment arrives
+    if let Some(state) = app.try_state::<crate::state::AppState>() {
+        super::keyword_alerts::refresh_watchlist(state.db_manager.pool()).await;
+        super::corrections::refresh_rules(state.db_manager.pool()).await;
+    }
 
     // Start optimized parallel transcription task and store handle
     let task_handle = transcription::start_transcription_task(app.clone(), transcription_receiver);
@@ -259,6 +283,7 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
     // Store listener ID for cleanup during stop_recording to ensure microphone is released
     {
         use tauri::Listener;
+        let alert_app = app.clone();
         let listener_id = app.listen("transcript-update", move |event: tauri::Event| {
             // Parse the transcript update from the event payload
             if let Ok(update) = serde_json::from_str::<TranscriptUpdate>(event.payload()) {
@@ -272,14 +297,22 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
                     display_time: update.timestamp.clone(), // Use wall-clock timestamp for display
                     confidence: update.confidence,
                     sequence_id: update.sequence_id,
+                    uncertain: update.uncertain,
                 };
 
                 // Save to recording manager
+                let mut folder_path = None;
                 if let Ok(manager_guard) = RECORDING_MANAGER.lock() {
                     if let Some(manager) = manager_guard.as_ref() {
                         manager.add_transcript_segment(segment);
+                        folder_path = manager
+                            .get_meeting_folder()
+                            .map(|p| p.to_string_lossy().to_string());
                     }
                 }
+
+                // Flag watched keywords as they are heard
+                super::keyword_alerts::check_segment(&alert_app, &update, folder_path);
             }
         });
         let mut global_listener = TRANSCRIPT_LISTENER_ID.lock().unwrap();
@@ -288,11 +321,11 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
     }
 
     // Emit success event
-    app.emit("recording-started", serde_json::json!({
-        "message": "Recording started successfully with parallel processing",
-        "devices": ["Default Microphone", "Default System Audio"],
-        "workers": 3
-    })).map_err(|e| e.to_string())?;
+    events::emit(&app, &RecordingStarted {
+        message: "Recording started successfully with parallel processing".to_string(),
+        devices: vec!["Default Microphone".to_string(), "Default System Audio".to_string()],
+        workers: 3,
+    }).map_err(|e| e.to_string())?;
 
     // Update tray menu to reflect recording state
     crate::tray::update_tray_menu(&app);
@@ -337,11 +370,11 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
 
         // Emit error event for frontend - actionable: false to show toast instead of modal
         // (download progress is already shown in top-right toast)
-        let _ = app.emit("transcription-error", serde_json::json!({
-            "error": validation_error,
-            "userMessage": "Recording cannot start: Transcription model is still downloading. Please wait for the download to complete.",
-            "actionable": false
-        }));
+        let _ = events::emit(&app, &TranscriptionError {
+            error: validation_error.clone(),
+            user_message: "Recording cannot start: Transcription model is still downloading. Please wait for the download to complete.".to_string(),
+            actionable: false,
+        });
 
         return Err(validation_error);
     }
@@ -370,17 +403,18 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
     // Create new recording manager
     let mut manager = RecordingManager::new();
 
-    // Load recording preferences to check auto_save setting
-    let auto_save = match super::recording_preferences::load_recording_preferences(&app).await {
+    // Load recording preferences to check auto_save setting and the meeting folder settings
+    let preferences = match super::recording_preferences::load_recording_preferences(&app).await {
         Ok(prefs) => {
             info!("📋 Loaded recording preferences: auto_save={}", prefs.auto_save);
-            prefs.auto_save
+            prefs
         }
         Err(e) => {
             warn!("Failed to load recording preferences, defaulting to auto_save=true: {}", e);
-            true // Default to saving if preferences can't be loaded
+            RecordingPreferences::default() // Default to saving if preferences can't be loaded
         }
     };
+    let auto_save = preferences.auto_save;
 
     // Always ensure a meeting name is set so incremental saver initializes
     let effective_meeting_name = meeting_name.clone().unwrap_or_else(|| {
@@ -392,10 +426,14 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
     });
     manager.set_meeting_name(Some(effective_meeting_name));
 
+    // A network recordings root that can't be reached falls back to the local spool
+    let recordings_root = resolve_recordings_root(&app, &preferences.save_folder).await?;
+    manager.set_meeting_folder(recordings_root, preferences.folder_name_template);
+
     // Set up error callback
     let app_for_error = app.clone();
     manager.set_error_callback(move |error| {
-        let _ = app_for_error.emit("recording-error", error.user_message());
+        let _ = events::emit(&app_for_error, &RecordingError(error.user_message()));
     });
 
     // Start recording with specified devices and auto_save setting
@@ -414,6 +452,15 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
     info!("🔍 Setting IS_RECORDING to true and resetting SPEECH_DETECTED_EMITTED");
     IS_RECORDING.store(true, Ordering::SeqCst);
     reset_speech_detected_flag(); // Reset for new recording session
+    super::recording_status::start_status_task(app.clone());
+    begin_power_session(&app).await;
+
+    // Load the keyword watchlist and learned corrections before the first
+    // segment arrives
+    if let Some(state) = app.try_state::<crate::state::AppState>() {
+        super::keyword_alerts::refresh_watchlist(state.db_manager.pool()).await;
+        super::corrections::refresh_rules(state.db_manager.pool()).await;
+    }
 
     // Start optimized parallel transcription task and store handle
     let task_handle = transcription::start_transcription_task(app.clone(), transcription_receiver);
@@ -427,6 +474,7 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
     // Store listener ID for cleanup during stop_recording to ensure microphone is released
     {
         use tauri::Listener;
+        let alert_app = app.clone();
         let listener_id = app.listen("transcript-update", move |event: tauri::Event| {
             // Parse the transcript update from the event payload
             if let Ok(update) = serde_json::from_str::<TranscriptUpdate>(event.payload()) {
@@ -440,14 +488,22 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
                     display_time: update.timestamp.clone(), // Use wall-clock timestamp for display
                     confidence: update.confidence,
                     sequence_id: update.sequence_id,
+                    uncertain: update.uncertain,
                 };
 
                 // Save to recording manager
+                let mut folder_path = None;
                 if let Ok(manager_guard) = RECORDING_MANAGER.lock() {
                     if let Some(manager) = manager_guard.as_ref() {
                         manager.add_transcript_segment(segment);
+                        folder_path = manager
+                            .get_meeting_folder()
+                            .map(|p| p.to_string_lossy().to_string());
                     }
                 }
+
+                // Flag watched keywords as they are heard
+                super::keyword_alerts::check_segment(&alert_app, &update, folder_path);
             }
         });
         let mut global_listener = TRANSCRIPT_LISTENER_ID.lock().unwrap();
@@ -456,14 +512,14 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
     }
 
     // Emit success event
-    app.emit("recording-started", serde_json::json!({
-        "message": "Recording started with custom devices and parallel processing",
-        "devices": [
+    events::emit(&app, &RecordingStarted {
+        message: "Recording started with custom devices and parallel processing".to_string(),
+        devices: vec![
             mic_device_name.unwrap_or_else(|| "Default Microphone".to_string()),
-            system_device_name.unwrap_or_else(|| "Default System Audio".to_string())
+            system_device_name.unwrap_or_else(|| "Default System Audio".to_string()),
         ],
-        "workers": 3
-    })).map_err(|e| e.to_string())?;
+        workers: 3,
+    }).map_err(|e| e.to_string())?;
 
     // Update tray menu to reflect recording state
     crate::tray::update_tray_menu(&app);
@@ -489,13 +545,9 @@ pub async fn stop_recording<R: Runtime>(
     }
 
     // Emit shutdown progress to frontend
-    let _ = app.emit(
-        "recording-shutdown-progress",
-        serde_json::json!({
-            "stage": "stopping_audio",
-            "message": "Stopping audio capture...",
-            "progress": 20
-        }),
+    let _ = events::emit(
+        &app,
+        &RecordingShutdownProgress::new("stopping_audio", "Stopping audio capture...", 20),
     );
 
     // Step 1: Stop audio capture immediately (no more new chunks) with proper error handling
@@ -539,13 +591,9 @@ pub async fn stop_recording<R: Runtime>(
     }
 
     // Step 2: Signal transcription workers to finish processing ALL queued chunks
-    let _ = app.emit(
-        "recording-shutdown-progress",
-        serde_json::json!({
-            "stage": "processing_transcripts",
-            "message": "Processing remaining transcript chunks...",
-            "progress": 40
-        }),
+    let _ = events::emit(
+        &app,
+        &RecordingShutdownProgress::new("processing_transcripts", "Processing remaining transcript chunks...", 40),
     );
 
     // Wait for transcription task with enhanced progress monitoring (NO TIMEOUT - we must process all chunks)
@@ -567,15 +615,17 @@ pub async fn stop_recording<R: Runtime>(
 
                 // Emit periodic progress updates during shutdown
                 let elapsed = last_update.elapsed().as_secs();
-                let _ = progress_app.emit(
-                    "recording-shutdown-progress",
-                    serde_json::json!({
-                        "stage": "processing_transcripts",
-                        "message": format!("Processing transcripts... ({}s elapsed)", elapsed),
-                        "progress": 40,
-                        "detailed": true,
-                        "elapsed_seconds": elapsed
-                    }),
+                let _ = events::emit(
+                    &progress_app,
+                    &RecordingShutdownProgress {
+                        detailed: Some(true),
+                        elapsed_seconds: Some(elapsed),
+                        ..RecordingShutdownProgress::new(
+                            "processing_transcripts",
+                            format!("Processing transcripts... ({}s elapsed)", elapsed),
+                            40,
+                        )
+                    },
                 );
             }
         });
@@ -605,13 +655,9 @@ pub async fn stop_recording<R: Runtime>(
     }
 
     // Step 3: Now safely unload Whisper model after ALL chunks are processed
-    let _ = app.emit(
-        "recording-shutdown-progress",
-        serde_json::json!({
-            "stage": "unloading_model",
-            "message": "Unloading speech recognition model...",
-            "progress": 70
-        }),
+    let _ = events::emit(
+        &app,
+        &RecordingShutdownProgress::new("unloading_model", "Unloading speech recognition model...", 70),
     );
 
     info!("🧠 All transcript chunks processed. Now safely unloading transcription model...");
@@ -797,13 +843,9 @@ pub async fn stop_recording<R: Runtime>(
     }
 
     // Step 4: Finalize recording state and cleanup resources safely
-    let _ = app.emit(
-        "recording-shutdown-progress",
-        serde_json::json!({
-            "stage": "finalizing",
-            "message": "Finalizing recording and cleaning up resources...",
-            "progress": 90
-        }),
+    let _ = events::emit(
+        &app,
+        &RecordingShutdownProgress::new("finalizing", "Finalizing recording and cleaning up resources...", 90),
     );
 
     // Perform final cleanup with the manager if available
@@ -843,6 +885,7 @@ pub async fn stop_recording<R: Runtime>(
     // Set recording flag to false
     info!("🔍 Setting IS_RECORDING to false");
     IS_RECORDING.store(false, Ordering::SeqCst);
+    end_power_session();
 
     // Step 4.5: Prepare metadata for frontend (NO database save)
     // NOTE: We do NOT save to database here. The frontend will save after all transcripts are displayed.
@@ -863,23 +906,20 @@ pub async fn stop_recording<R: Runtime>(
     info!("ℹ️ Skipping database save in Rust - frontend will save after all transcripts received");
 
     // Step 5: Complete shutdown
-    let _ = app.emit(
-        "recording-shutdown-progress",
-        serde_json::json!({
-            "stage": "complete",
-            "message": "Recording stopped successfully",
-            "progress": 100
-        }),
+    let _ = events::emit(
+        &app,
+        &RecordingShutdownProgress::new("complete", "Recording stopped successfully", 100),
     );
 
     // Emit final stop event with folder_path and meeting_name for frontend to save
-    app.emit(
-        "recording-stopped",
-        serde_json::json!({
-            "message": "Recording stopped - frontend will save after all transcripts received",
-            "folder_path": folder_path_str,
-            "meeting_name": meeting_name_str
-        }),
+    events::emit(
+        &app,
+        &RecordingStopped {
+            message: "Recording stopped - frontend will save after all transcripts received"
+                .to_string(),
+            folder_path: folder_path_str,
+            meeting_name: meeting_name_str,
+        },
     )
     .map_err(|e| e.to_string())?;
 
@@ -895,6 +935,37 @@ pub async fn is_recording() -> bool {
     IS_RECORDING.load(Ordering::SeqCst)
 }
 
+/// Keeps the machine awake (and optionally silences notifications) for the
+/// recording, per the user's recording preferences
+async fn begin_power_session<R: Runtime>(app: &AppHandle<R>) {
+    let (prevent_sleep, do_not_disturb) =
+        match super::recording_preferences::load_recording_preferences(app).await {
+            Ok(prefs) => (prefs.prevent_sleep, prefs.do_not_disturb),
+            Err(_) => (true, false),
+        };
+    let session = super::power::RecordingPowerSession::begin(prevent_sleep, do_not_disturb);
+    *POWER_SESSION.lock().unwrap_or_else(|e| e.into_inner()) = Some(session);
+}
+
+fn end_power_session() {
+    POWER_SESSION.lock().unwrap_or_else(|e| e.into_inner()).take();
+}
+
+/// Live status of the current recording, or None when not recording
+pub fn current_recording_status() -> Option<super::recording_status::RecordingStatus> {
+    if !IS_RECORDING.load(Ordering::SeqCst) {
+        return None;
+    }
+    let manager_guard = RECORDING_MANAGER.lock().ok()?;
+    let manager = manager_guard.as_ref()?;
+    Some(super::recording_status::status(
+        manager.get_recording_duration().unwrap_or(0.0),
+        manager.get_active_recording_duration().unwrap_or(0.0),
+        manager.is_paused(),
+        manager.get_bytes_written(),
+    ))
+}
+
 /// Get recording statistics
 pub async fn get_transcription_status() -> TranscriptionStatus {
     TranscriptionStatus {
@@ -920,11 +991,11 @@ pub async fn pause_recording<R: Runtime>(app: AppHandle<R>) -> Result<(), String
         manager.pause_recording().map_err(|e| e.to_string())?;
 
         // Emit pause event to frontend
-        app.emit(
-            "recording-paused",
-            serde_json::json!({
-                "message": "Recording paused"
-            }),
+        events::emit(
+            &app,
+            &RecordingPaused {
+                message: "Recording paused".to_string(),
+            },
         )
         .map_err(|e| e.to_string())?;
 
@@ -954,11 +1025,11 @@ pub async fn resume_recording<R: Runtime>(app: AppHandle<R>) -> Result<(), Strin
         manager.resume_recording().map_err(|e| e.to_string())?;
 
         // Emit resume event to frontend
-        app.emit(
-            "recording-resumed",
-            serde_json::json!({
-                "message": "Recording resumed"
-            }),
+        events::emit(
+            &app,
+            &RecordingResumed {
+                message: "Recording resumed".to_string(),
+            },
         )
         .map_err(|e| e.to_string())?;
 
@@ -354,6 +354,11 @@ impl RecordingManager {
         self.recording_saver.get_stats()
     }
 
+    /// Audio bytes written to disk so far
+    pub fn get_bytes_written(&self) -> u64 {
+        self.recording_saver.get_bytes_written()
+    }
+
     /// Check if currently recording
     pub fn is_recording(&self) -> bool {
         self.state.is_recording()
@@ -434,6 +439,11 @@ impl RecordingManager {
         self.recording_saver.set_meeting_name(name);
     }
 
+    /// Set where the meeting folder is created and the template it is named with
+    pub fn set_meeting_folder(&mut self, recordings_root: std::path::PathBuf, template: String) {
+        self.recording_saver.set_meeting_folder(recordings_root, template);
+    }
+
     /// Add a structured transcript segment to be saved later
     pub fn add_transcript_segment(&self, segment: super::recording_saver::TranscriptSegment) {
         self.recording_saver.add_transcript_segment(segment);
@@ -1,9 +1,14 @@
 use log::{info, warn};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use tauri::{AppHandle, Runtime};
+use tauri::{AppHandle, Manager, Runtime};
 use tauri_plugin_store::StoreExt;
 
+use super::folder_template::{
+    unique_folder_name, validate_template, FolderNameContext, DEFAULT_FOLDER_TEMPLATE,
+};
+use crate::database::workspaces::{active_workspace, read_registry, store_path, write_registry};
+
 use anyhow::Result;
 #[cfg(target_os = "macos")]
 use log::error;
@@ -20,6 +25,23 @@ pub struct RecordingPreferences {
     pub preferred_mic_device: Option<String>,
     #[serde(default)]
     pub preferred_system_device: Option<String>,
+    /// Keep the system and display awake while recording
+    #[serde(default = "default_prevent_sleep")]
+    pub prevent_sleep: bool,
+    /// Silence OS notifications while recording (best effort, per platform)
+    #[serde(default)]
+    pub do_not_disturb: bool,
+    /// How meeting folders are named, e.g. "{date}_{slug}" (see `folder_template`)
+    #[serde(default = "default_folder_name_template")]
+    pub folder_name_template: String,
+    /// Load the transcription model in the background at startup so the
+    /// first recording doesn't wait for it
+    #[serde(default)]
+    pub preload_model: bool,
+    /// Unload the transcription model after this many minutes without use
+    /// (0 keeps it loaded); it is loaded again on the next recording
+    #[serde(default)]
+    pub model_idle_unload_minutes: u32,
     #[cfg(target_os = "macos")]
     #[serde(default)]
     pub system_audio_backend: Option<String>,
@@ -33,12 +55,25 @@ impl Default for RecordingPreferences {
             file_format: "mp4".to_string(),
             preferred_mic_device: None,
             preferred_system_device: None,
+            prevent_sleep: true,
+            do_not_disturb: false,
+            folder_name_template: default_folder_name_template(),
+            preload_model: false,
+            model_idle_unload_minutes: 0,
             #[cfg(target_os = "macos")]
             system_audio_backend: Some("coreaudio".to_string()),
         }
     }
 }
 
+fn default_prevent_sleep() -> bool {
+    true
+}
+
+fn default_folder_name_template() -> String {
+    DEFAULT_FOLDER_TEMPLATE.to_string()
+}
+
 /// Get the default recordings folder based on platform
 pub fn get_default_recordings_folder() -> PathBuf {
     #[cfg(target_os = "windows")]
@@ -92,21 +127,56 @@ pub fn generate_recording_filename(format: &str) -> String {
     format!("recording_{}.{}", timestamp, format)
 }
 
+const STORE_FILE: &str = "recording_preferences.json";
+
+/// The active workspace's store file and recordings root. A root set on the
+/// workspace (see `workspaces.json`) takes precedence over the store's
+/// `save_folder`.
+fn workspace_store<R: Runtime>(app: &AppHandle<R>) -> (String, Option<PathBuf>) {
+    let Ok(app_data_dir) = app.path().app_data_dir() else {
+        return (STORE_FILE.to_string(), None);
+    };
+    let workspace = active_workspace(&app_data_dir);
+    (store_path(&workspace, STORE_FILE), workspace.recordings_dir)
+}
+
+/// Moves the active workspace's own recordings root to `save_folder`, if it
+/// has one, so the settings page keeps changing where recordings go
+fn update_workspace_root<R: Runtime>(app: &AppHandle<R>, save_folder: &PathBuf) -> Result<()> {
+    let app_data_dir = app.path().app_data_dir()?;
+    let mut registry = read_registry(&app_data_dir);
+    let workspace = registry.active();
+    if workspace.recordings_dir.is_none() || workspace.recordings_dir.as_ref() == Some(save_folder)
+    {
+        return Ok(());
+    }
+    registry
+        .set_recordings_dir(&workspace.id, Some(save_folder.clone()))
+        .map_err(|e| anyhow::anyhow!(e))?;
+    write_registry(&app_data_dir, &registry)?;
+    Ok(())
+}
+
 /// Load recording preferences from store
 pub async fn load_recording_preferences<R: Runtime>(
     app: &AppHandle<R>,
 ) -> Result<RecordingPreferences> {
-    // Try to load from Tauri store
-    let store = match app.store("recording_preferences.json") {
+    // Try to load from the active workspace's Tauri store
+    let (store_file, workspace_root) = workspace_store(app);
+    let mut defaults = RecordingPreferences::default();
+    if let Some(dir) = &workspace_root {
+        defaults.save_folder = dir.clone();
+    }
+    let store = match app.store(store_file) {
         Ok(store) => store,
         Err(e) => {
             warn!("Failed to access store: {}, using defaults", e);
-            return Ok(RecordingPreferences::default());
+            return Ok(defaults);
         }
     };
 
     // Try to get the preferences from store
-    let prefs = if let Some(value) = store.get("preferences") {
+    let mut prefs = if let Some(value) = store.get("preferences") {
         match serde_json::from_value::<RecordingPreferences>(value.clone()) {
             Ok(mut p) => {
                 info!("Loaded recording preferences from store");
@@ -120,13 +190,16 @@ pub async fn load_recording_preferences<R: Runtime>(
             }
             Err(e) => {
                 warn!("Failed to deserialize preferences: {}, using defaults", e);
-                RecordingPreferences::default()
+                defaults
             }
         }
     } else {
         info!("No stored preferences found, using defaults");
-        RecordingPreferences::default()
+        defaults
     };
+    if let Some(dir) = workspace_root {
+        prefs.save_folder = dir;
+    }
 
     info!("Loaded recording preferences: save_folder={:?}, auto_save={}, format={}, mic={:?}, system={:?}",
           prefs.save_folder, prefs.auto_save, prefs.file_format,
@@ -142,10 +215,12 @@ pub async fn save_recording_preferences<R: Runtime>(
     info!("Saving recording preferences: save_folder={:?}, auto_save={}, format={}, mic={:?}, system={:?}",
           preferences.save_folder, preferences.auto_save, preferences.file_format,
           preferences.preferred_mic_device, preferences.preferred_system_device);
+    validate_template(&preferences.folder_name_template).map_err(|e| anyhow::anyhow!(e))?;
+    update_workspace_root(app, &preferences.save_folder)?;
 
     // Get or create store
     let store = app
-        .store("recording_preferences.json")
+        .store(workspace_store(app).0)
         .map_err(|e| anyhow::anyhow!("Failed to access store: {}", e))?;
 
     // Serialize preferences to JSON value
@@ -197,6 +272,31 @@ pub async fn set_recording_preferences<R: Runtime>(
         .map_err(|e| format!("Failed to save recording preferences: {}", e))
 }
 
+/// The folder name `template` would give a meeting called `title` with
+/// `tags` right now, or why the template can't be used
+#[tauri::command]
+pub async fn preview_folder_name<R: Runtime>(
+    app: AppHandle<R>,
+    template: String,
+    title: Option<String>,
+    tags: Option<Vec<String>>,
+) -> Result<String, String> {
+    validate_template(&template)?;
+    let preferences = load_recording_preferences(&app)
+        .await
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))?;
+    let context = FolderNameContext {
+        title: title.as_deref().unwrap_or("Weekly sync"),
+        tags: tags.as_deref().unwrap_or_default(),
+        now: chrono::Utc::now(),
+    };
+    Ok(unique_folder_name(
+        &preferences.save_folder,
+        &template,
+        &context,
+    ))
+}
+
 #[tauri::command]
 pub async fn get_default_recordings_folder_path() -> Result<String, String> {
     let path = get_default_recordings_folder();
@@ -384,4 +484,3 @@ pub async fn get_audio_backend_info() -> Result<Vec<BackendInfo>, String> {
         }])
     }
 }
-
@@ -1,15 +1,19 @@
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use tokio::sync::Mutex as AsyncMutex;
 use anyhow::Result;
 use log::{info, warn, error};
-use tauri::{AppHandle, Runtime, Emitter};
+use tauri::{AppHandle, Runtime};
 use tokio::sync::mpsc;
 use serde::{Serialize, Deserialize};
 use std::path::PathBuf;
 
 use super::recording_state::AudioChunk;
 use super::audio_processing::create_meeting_folder;
+use super::folder_template::DEFAULT_FOLDER_TEMPLATE;
 use super::incremental_saver::IncrementalAudioSaver;
+use crate::events::{self, RecordingSaved};
+use crate::locks::{folder_key, MeetingActivity, MeetingLock, MeetingLocks};
 
 /// Structured transcript segment for JSON export
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +26,9 @@ pub struct TranscriptSegment {
     pub display_time: String,   // Formatted time for display like "[02:15]"
     pub confidence: f32,
     pub sequence_id: u64,
+    /// Flagged as a possible hallucination; see `api::TranscriptSegment`
+    #[serde(default)]
+    pub uncertain: bool,
 }
 
 /// Meeting metadata structure
@@ -51,10 +58,15 @@ pub struct RecordingSaver {
     incremental_saver: Option<Arc<AsyncMutex<IncrementalAudioSaver>>>,
     meeting_folder: Option<PathBuf>,
     meeting_name: Option<String>,
+    recordings_root: Option<PathBuf>,
+    folder_name_template: String,
     metadata: Option<MeetingMetadata>,
     transcript_segments: Arc<Mutex<Vec<TranscriptSegment>>>,
     chunk_receiver: Option<mpsc::UnboundedReceiver<AudioChunk>>,
     is_saving: Arc<Mutex<bool>>,
+    bytes_written: Option<Arc<AtomicU64>>,
+    /// Keeps maintenance away from the folder while recording
+    folder_lock: Option<MeetingLock>,
 }
 
 impl RecordingSaver {
@@ -63,10 +75,14 @@ impl RecordingSaver {
             incremental_saver: None,
             meeting_folder: None,
             meeting_name: None,
+            recordings_root: None,
+            folder_name_template: DEFAULT_FOLDER_TEMPLATE.to_string(),
             metadata: None,
             transcript_segments: Arc::new(Mutex::new(Vec::new())),
             chunk_receiver: None,
             is_saving: Arc::new(Mutex::new(false)),
+            bytes_written: None,
+            folder_lock: None,
         }
     }
 
@@ -75,6 +91,12 @@ impl RecordingSaver {
         self.meeting_name = name;
     }
 
+    /// Set where the meeting folder is created and the template it is named with
+    pub fn set_meeting_folder(&mut self, recordings_root: PathBuf, template: String) {
+        self.recordings_root = Some(recordings_root);
+        self.folder_name_template = template;
+    }
+
     /// Set device information in metadata
     pub fn set_device_info(&mut self, mic_name: Option<String>, sys_name: Option<String>) {
         if let Some(ref mut metadata) = self.metadata {
@@ -129,6 +151,7 @@ impl RecordingSaver {
             display_time: "[00:00]".to_string(),
             confidence: 1.0,
             sequence_id: 0,
+            uncertain: false,
         };
         self.add_transcript_segment(segment);
     }
@@ -228,15 +251,26 @@ impl RecordingSaver {
     /// * `meeting_name` - Name of the meeting
     /// * `create_checkpoints` - Whether to create .checkpoints/ directory and IncrementalAudioSaver
     fn initialize_meeting_folder(&mut self, meeting_name: &str, create_checkpoints: bool) -> Result<()> {
-        // Load preferences to get base recordings folder
-        let base_folder = super::recording_preferences::get_default_recordings_folder();
+        // The recordings root from preferences (or the spool), else the platform default
+        let base_folder = self
+            .recordings_root
+            .clone()
+            .unwrap_or_else(super::recording_preferences::get_default_recordings_folder);
 
         // Create meeting folder structure (with or without .checkpoints/ subdirectory)
-        let meeting_folder = create_meeting_folder(&base_folder, meeting_name, create_checkpoints)?;
+        let meeting_folder = create_meeting_folder(
+            &base_folder,
+            meeting_name,
+            // A recording is tagged once it is saved
+            &[],
+            &self.folder_name_template,
+            create_checkpoints,
+        )?;
 
         // Only initialize incremental saver if checkpoints are needed (auto_save is true)
         if create_checkpoints {
             let incremental_saver = IncrementalAudioSaver::new(meeting_folder.clone(), 48000)?;
+            self.bytes_written = Some(incremental_saver.bytes_written_counter());
             self.incremental_saver = Some(Arc::new(AsyncMutex::new(incremental_saver)));
             info!("✅ Incremental audio saver initialized for meeting: {}", meeting_name);
         } else {
@@ -264,6 +298,9 @@ impl RecordingSaver {
         // Write initial metadata.json
         self.write_metadata(&meeting_folder, &metadata)?;
 
+        self.folder_lock = Some(
+            MeetingLocks::global().acquire(folder_key(&meeting_folder), MeetingActivity::Recording),
+        );
         self.meeting_folder = Some(meeting_folder);
         self.metadata = Some(metadata);
 
@@ -437,16 +474,16 @@ impl RecordingSaver {
         }
 
         // Emit save event with audio and transcript paths
-        let save_event = serde_json::json!({
-            "audio_file": final_audio_path.to_string_lossy(),
-            "transcript_file": self.meeting_folder.as_ref()
+        let save_event = RecordingSaved {
+            audio_file: final_audio_path.to_string_lossy().to_string(),
+            transcript_file: self.meeting_folder.as_ref()
                 .map(|f| f.join("transcripts.json").to_string_lossy().to_string()),
-            "meeting_name": self.meeting_name,
-            "meeting_folder": self.meeting_folder.as_ref()
-                .map(|f| f.to_string_lossy().to_string())
-        });
+            meeting_name: self.meeting_name.clone(),
+            meeting_folder: self.meeting_folder.as_ref()
+                .map(|f| f.to_string_lossy().to_string()),
+        };
 
-        if let Err(e) = app.emit("recording-saved", &save_event) {
+        if let Err(e) = events::emit(app, &save_event) {
             warn!("Failed to emit recording-saved event: {}", e);
         }
 
@@ -454,10 +491,19 @@ impl RecordingSaver {
         if let Ok(mut segments) = self.transcript_segments.lock() {
             segments.clear();
         }
+        self.folder_lock = None;
 
         Ok(Some(final_audio_path.to_string_lossy().to_string()))
     }
 
+    /// Audio bytes written to checkpoints so far (0 when audio is not saved)
+    pub fn get_bytes_written(&self) -> u64 {
+        self.bytes_written
+            .as_ref()
+            .map(|counter| counter.load(Ordering::Relaxed))
+            .unwrap_or(0)
+    }
+
     /// Get the meeting folder path (for passing to backend)
     pub fn get_meeting_folder(&self) -> Option<&PathBuf> {
         self.meeting_folder.as_ref()
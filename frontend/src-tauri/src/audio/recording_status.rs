@@ -0,0 +1,124 @@
+// audio/recording_status.rs
+//
+// Periodic `recording-status` events while recording, so the UI and tray can
+// show elapsed time, bytes written, segments transcribed and the active model
+// without polling. One emitter task runs per recording session; it stops when
+// recording stops or a newer session starts.
+
+use log::{info, warn};
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Runtime};
+
+use crate::events;
+
+pub const RECORDING_STATUS_EVENT: &str = "recording-status";
+const STATUS_INTERVAL: Duration = Duration::from_secs(3);
+
+// Bumped per session so a lingering emitter from the previous session exits
+static SESSION: AtomicU64 = AtomicU64::new(0);
+static SEGMENTS_TRANSCRIBED: AtomicU64 = AtomicU64::new(0);
+static CURRENT_MODEL: Mutex<Option<(String, String)>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingStatus {
+    /// Wall-clock seconds since recording started, pauses included
+    pub elapsed_seconds: f64,
+    /// Seconds actually recorded, pauses excluded
+    pub active_seconds: f64,
+    pub is_paused: bool,
+    /// Audio bytes written to disk so far (0 when audio saving is off)
+    pub bytes_written: u64,
+    /// Final transcript segments produced this session
+    pub segments_transcribed: u64,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+}
+
+/// Called by the transcription worker once its engine is ready
+pub fn set_current_model(provider: &str, model: &str) {
+    *CURRENT_MODEL.lock().unwrap_or_else(|e| e.into_inner()) =
+        Some((provider.to_string(), model.to_string()));
+}
+
+/// Called by the transcription worker for every final segment
+pub fn record_segment() {
+    SEGMENTS_TRANSCRIBED.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Builds the status for the current session from the recording manager's
+/// timing and byte counts
+pub fn status(
+    elapsed_seconds: f64,
+    active_seconds: f64,
+    is_paused: bool,
+    bytes_written: u64,
+) -> RecordingStatus {
+    let model = CURRENT_MODEL
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    RecordingStatus {
+        elapsed_seconds,
+        active_seconds,
+        is_paused,
+        bytes_written,
+        segments_transcribed: SEGMENTS_TRANSCRIBED.load(Ordering::Relaxed),
+        provider: model.as_ref().map(|(provider, _)| provider.clone()),
+        model: model.map(|(_, model)| model),
+    }
+}
+
+/// "1:02:03" or "12:34"
+pub fn format_elapsed(seconds: f64) -> String {
+    let total = seconds.max(0.0) as u64;
+    let (hours, minutes, secs) = (total / 3600, (total % 3600) / 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, secs)
+    } else {
+        format!("{}:{:02}", minutes, secs)
+    }
+}
+
+/// Resets the counters and starts emitting status for a new session
+pub fn start_status_task<R: Runtime>(app: AppHandle<R>) {
+    let session = SESSION.fetch_add(1, Ordering::SeqCst) + 1;
+    SEGMENTS_TRANSCRIBED.store(0, Ordering::Relaxed);
+    *CURRENT_MODEL.lock().unwrap_or_else(|e| e.into_inner()) = None;
+
+    tauri::async_runtime::spawn(async move {
+        info!("Recording status events started for session {}", session);
+        loop {
+            tokio::time::sleep(STATUS_INTERVAL).await;
+            if SESSION.load(Ordering::SeqCst) != session {
+                break;
+            }
+            let Some(status) = super::recording_commands::current_recording_status() else {
+                break;
+            };
+            if let Err(e) = events::emit(&app, &status) {
+                warn!("Failed to emit recording status: {}", e);
+            }
+            crate::tray::show_recording_duration(&app, status.elapsed_seconds, status.is_paused);
+        }
+        if SESSION.load(Ordering::SeqCst) == session {
+            crate::tray::set_tray_tooltip(&app, "Meetily");
+        }
+        info!("Recording status events stopped for session {}", session);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_elapsed_time() {
+        assert_eq!(format_elapsed(0.0), "0:00");
+        assert_eq!(format_elapsed(754.9), "12:34");
+        assert_eq!(format_elapsed(3723.0), "1:02:03");
+        assert_eq!(format_elapsed(-5.0), "0:00");
+    }
+}
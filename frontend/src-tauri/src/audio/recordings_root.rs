@@ -0,0 +1,431 @@
+//! The recordings root new meeting folders are created in, including roots on
+//! network drives (SMB/NFS shares, NAS mounts).
+//!
+//! Before recording or importing, the root is checked by writing a probe file
+//! with a timeout, since an unreachable network path can hang instead of
+//! failing. When the root can't be used, meeting folders go to a local spool
+//! directory in the app data folder (one per workspace) and an
+//! `recordings-root-unavailable` event is emitted. A background task moves
+//! spooled folders to the root once it is reachable again and updates the
+//! meetings that point at them.
+
+use log::{info, warn};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use super::recording_preferences::load_recording_preferences;
+use crate::database::relocation::copy_verified;
+use crate::database::workspaces::{active_workspace, workspace_dir};
+use crate::locks::{folder_key, MeetingActivity, MeetingLocks};
+use crate::state::AppState;
+
+pub const ROOT_UNAVAILABLE_EVENT: &str = "recordings-root-unavailable";
+pub const SPOOL_DIR_NAME: &str = "recording-spool";
+/// Network shares can stall for a long time before failing
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+const SPOOL_SYNC_INTERVAL: Duration = Duration::from_secs(10 * 60);
+/// Folders changed more recently than this may still be written to, or not
+/// yet saved as a meeting
+const SPOOL_MIN_AGE: Duration = Duration::from_secs(10 * 60);
+const NETWORK_FS_TYPES: [&str; 11] = [
+    "nfs",
+    "nfs4",
+    "cifs",
+    "smbfs",
+    "smb3",
+    "afpfs",
+    "webdav",
+    "davfs",
+    "fuse.sshfs",
+    "fuse.rclone",
+    "9p",
+];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RootStatus {
+    pub path: String,
+    pub available: bool,
+    /// The root looks like a network share
+    pub network: bool,
+    pub error: Option<String>,
+}
+
+/// File system type of the mount holding `path`, from `/proc/mounts` lines
+/// (`device mount-point type options ...`)
+pub fn mount_fs_type<'a>(path: &Path, mounts: &'a str) -> Option<&'a str> {
+    mounts
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let _device = fields.next()?;
+            // Spaces in mount points are written as \040
+            let mount_point = fields.next()?.replace("\\040", " ");
+            Some((mount_point, fields.next()?))
+        })
+        .filter(|(mount_point, _)| path.starts_with(mount_point))
+        .max_by_key(|(mount_point, _)| mount_point.len())
+        .map(|(_, fs_type)| fs_type)
+}
+
+/// File system type of the mount holding `path`, from BSD/macOS `mount`
+/// output (`device on mount-point (type, options...)`)
+pub fn bsd_mount_fs_type<'a>(path: &Path, mounts: &'a str) -> Option<&'a str> {
+    mounts
+        .lines()
+        .filter_map(|line| {
+            let (_, rest) = line.split_once(" on ")?;
+            let (mount_point, options) = rest.rsplit_once(" (")?;
+            let fs_type = options.split([',', ')']).next()?.trim();
+            Some((mount_point, fs_type))
+        })
+        .filter(|(mount_point, _)| path.starts_with(mount_point))
+        .max_by_key(|(mount_point, _)| mount_point.len())
+        .map(|(_, fs_type)| fs_type)
+}
+
+pub fn is_network_path(path: &Path) -> bool {
+    let text = path.to_string_lossy();
+    // UNC paths (\\server\share) and smb-style //server/share
+    if text.starts_with("\\\\") || text.starts_with("//") {
+        return true;
+    }
+    #[cfg(target_os = "linux")]
+    {
+        if let Ok(mounts) = std::fs::read_to_string("/proc/mounts") {
+            return mount_fs_type(path, &mounts).is_some_and(|t| NETWORK_FS_TYPES.contains(&t));
+        }
+    }
+    #[cfg(target_os = "macos")]
+    {
+        if let Ok(output) = std::process::Command::new("mount").output() {
+            let mounts = String::from_utf8_lossy(&output.stdout);
+            return bsd_mount_fs_type(path, &mounts).is_some_and(|t| NETWORK_FS_TYPES.contains(&t));
+        }
+    }
+    false
+}
+
+fn probe_writable(path: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(path)?;
+    let probe = path.join(format!(".meetily-probe-{}", std::process::id()));
+    std::fs::write(&probe, b"ok")?;
+    std::fs::remove_file(&probe)
+}
+
+/// Checks that `path` exists (creating it if needed) and can be written to
+/// within [`PROBE_TIMEOUT`]
+pub async fn check_root(path: &Path) -> RootStatus {
+    let owned = path.to_path_buf();
+    let network = tokio::task::spawn_blocking(move || is_network_path(&owned))
+        .await
+        .unwrap_or(false);
+    let owned = path.to_path_buf();
+    let probe = tokio::time::timeout(
+        PROBE_TIMEOUT,
+        tokio::task::spawn_blocking(move || probe_writable(&owned)),
+    )
+    .await;
+    let error = match probe {
+        Ok(Ok(Ok(()))) => None,
+        Ok(Ok(Err(e))) => Some(format!("Can't write to {}: {}", path.display(), e)),
+        Ok(Err(e)) => Some(format!("Check of {} failed: {}", path.display(), e)),
+        Err(_) => Some(format!(
+            "{} did not respond within {}s",
+            path.display(),
+            PROBE_TIMEOUT.as_secs()
+        )),
+    };
+    RootStatus {
+        path: path.to_string_lossy().to_string(),
+        available: error.is_none(),
+        network,
+        error,
+    }
+}
+
+/// The active workspace's spool directory
+pub fn spool_dir<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))?;
+    let workspace = active_workspace(&app_data_dir);
+    let base = if workspace.is_default() {
+        app_data_dir
+    } else {
+        workspace_dir(&app_data_dir, &workspace.id)
+    };
+    Ok(base.join(SPOOL_DIR_NAME))
+}
+
+/// `preferred` if it can be written to, else the spool directory
+pub async fn resolve_recordings_root<R: Runtime>(
+    app: &AppHandle<R>,
+    preferred: &Path,
+) -> Result<PathBuf, String> {
+    let status = check_root(preferred).await;
+    if status.available {
+        return Ok(preferred.to_path_buf());
+    }
+    let spool = spool_dir(app)?;
+    std::fs::create_dir_all(&spool).map_err(|e| {
+        format!(
+            "{}, and the local spool {} can't be created either: {}",
+            status
+                .error
+                .as_deref()
+                .unwrap_or("The recordings folder is unavailable"),
+            spool.display(),
+            e
+        )
+    })?;
+    warn!(
+        "{}; using the local spool {}",
+        status.error.as_deref().unwrap_or_default(),
+        spool.display()
+    );
+    let _ = app.emit(ROOT_UNAVAILABLE_EVENT, &status);
+    Ok(spool)
+}
+
+fn recently_modified(folder: &Path) -> bool {
+    let metadata = folder.join("metadata.json");
+    let marker = if metadata.is_file() {
+        metadata
+    } else {
+        folder.to_path_buf()
+    };
+    std::fs::metadata(marker)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .map(|age| age < SPOOL_MIN_AGE)
+        .unwrap_or(true)
+}
+
+/// metadata.json only changes at the start and end of a recording, so a long
+/// recording looks settled; its status tells it apart
+fn still_recording(folder: &Path) -> bool {
+    std::fs::read_to_string(folder.join("metadata.json"))
+        .ok()
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(&json).ok())
+        .is_some_and(|metadata| metadata["status"] == "recording")
+}
+
+/// Renames, or copies and deletes when `to` is on another device
+fn move_dir(from: &Path, to: &Path) -> Result<(), String> {
+    if std::fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    if let Err(e) = copy_verified(from, to) {
+        let _ = std::fs::remove_dir_all(to);
+        return Err(e);
+    }
+    std::fs::remove_dir_all(from)
+        .map_err(|e| format!("Copied but failed to remove {}: {}", from.display(), e))
+}
+
+/// Moves settled folders from `spool` to `root` and repoints their meetings.
+/// A folder or meeting another task holds is left for the next sync. Returns
+/// how many folders were moved.
+pub async fn sync_spool(pool: &SqlitePool, spool: &Path, root: &Path) -> Result<usize, String> {
+    let Ok(entries) = std::fs::read_dir(spool) else {
+        return Ok(0);
+    };
+    let recording = super::recording_commands::is_recording().await;
+    let folders: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir() && !recently_modified(path))
+        .filter(|path| !(recording && still_recording(path)))
+        .collect();
+
+    let locks = MeetingLocks::global();
+    let mut moved = 0;
+    for from in folders {
+        let _folder_lock = match locks.try_acquire(folder_key(&from), MeetingActivity::FolderMove) {
+            Ok(lock) => lock,
+            Err(busy) => {
+                log::debug!("Leaving {} in the spool: {}", from.display(), busy);
+                continue;
+            }
+        };
+        let meeting_id: Option<String> =
+            sqlx::query_scalar("SELECT id FROM meetings WHERE folder_path = ?")
+                .bind(from.to_string_lossy().to_string())
+                .fetch_optional(pool)
+                .await
+                .map_err(|e| {
+                    format!("Failed to look up the meeting of {}: {}", from.display(), e)
+                })?;
+        let _meeting_lock = match meeting_id
+            .map(|id| locks.try_acquire(id, MeetingActivity::FolderMove))
+            .transpose()
+        {
+            Ok(lock) => lock,
+            Err(busy) => {
+                log::debug!("Leaving {} in the spool: {}", from.display(), busy);
+                continue;
+            }
+        };
+
+        let name = from
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+        let to = (1..)
+            .map(|n| match n {
+                1 => root.join(&name),
+                n => root.join(format!("{}_{}", name, n)),
+            })
+            .find(|candidate| !candidate.exists())
+            .unwrap_or_else(|| root.join(&name));
+
+        let (source, target) = (from.clone(), to.clone());
+        tokio::task::spawn_blocking(move || move_dir(&source, &target))
+            .await
+            .map_err(|e| format!("Spool task failed: {}", e))??;
+        sqlx::query("UPDATE meetings SET folder_path = ? WHERE folder_path = ?")
+            .bind(to.to_string_lossy().to_string())
+            .bind(from.to_string_lossy().to_string())
+            .execute(pool)
+            .await
+            .map_err(|e| {
+                format!(
+                    "Moved {} but failed to update its meeting: {}",
+                    to.display(),
+                    e
+                )
+            })?;
+        info!(
+            "Moved spooled folder {} to {}",
+            from.display(),
+            to.display()
+        );
+        moved += 1;
+    }
+    Ok(moved)
+}
+
+async fn sync_spool_for<R: Runtime>(app: &AppHandle<R>) -> Result<usize, String> {
+    let state = app
+        .try_state::<AppState>()
+        .ok_or_else(|| "App state is not ready".to_string())?;
+    let spool = spool_dir(app)?;
+    if !spool.is_dir() {
+        return Ok(0);
+    }
+    let preferences = load_recording_preferences(app)
+        .await
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))?;
+    let status = check_root(&preferences.save_folder).await;
+    if !status.available {
+        return Err(status
+            .error
+            .unwrap_or_else(|| "The recordings folder is unavailable".to_string()));
+    }
+    sync_spool(state.db_manager.pool(), &spool, &preferences.save_folder).await
+}
+
+/// Moves spooled folders to the recordings root whenever it is reachable
+pub async fn run_spool_sync<R: Runtime>(app: AppHandle<R>) {
+    let mut interval = tokio::time::interval(SPOOL_SYNC_INTERVAL);
+    loop {
+        interval.tick().await;
+        match sync_spool_for(&app).await {
+            Ok(0) => {}
+            Ok(moved) => info!(
+                "Moved {} spooled meeting folders to the recordings root",
+                moved
+            ),
+            Err(e) => log::debug!("Spool sync skipped: {}", e),
+        }
+    }
+}
+
+/// Whether `path` (default: the configured recordings root) can be recorded to
+#[tauri::command]
+pub async fn check_recordings_root<R: Runtime>(
+    app: AppHandle<R>,
+    path: Option<String>,
+) -> Result<RootStatus, String> {
+    let path = match path {
+        Some(path) => PathBuf::from(path),
+        None => {
+            load_recording_preferences(&app)
+                .await
+                .map_err(|e| format!("Failed to load recording preferences: {}", e))?
+                .save_folder
+        }
+    };
+    Ok(check_root(&path).await)
+}
+
+/// Moves spooled meeting folders to the recordings root now, as a "sync"
+/// job; returns how many were moved
+#[tauri::command]
+pub async fn sync_recording_spool<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<usize, String> {
+    let job = state
+        .jobs
+        .begin(&app, "sync", "Move spooled recordings", None)
+        .await?;
+    let result = sync_spool_for(&app).await;
+    job.finish(
+        result
+            .clone()
+            .map(|moved| serde_json::json!({ "moved": moved })),
+    )
+    .await;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_innermost_mount() {
+        let mounts = "/dev/sda1 / ext4 rw 0 0\n\
+                      //nas/share /mnt/nas cifs rw 0 0\n\
+                      nas:/export /mnt/nas/nfs\\040dir nfs4 rw 0 0\n";
+        assert_eq!(mount_fs_type(Path::new("/home/me"), mounts), Some("ext4"));
+        assert_eq!(
+            mount_fs_type(Path::new("/mnt/nas/rec"), mounts),
+            Some("cifs")
+        );
+        assert_eq!(
+            mount_fs_type(Path::new("/mnt/nas/nfs dir/rec"), mounts),
+            Some("nfs4")
+        );
+        // Prefix matches are per path component
+        assert_eq!(mount_fs_type(Path::new("/mnt/nasty"), mounts), Some("ext4"));
+    }
+
+    #[test]
+    fn parses_bsd_mount_output() {
+        let mounts = "/dev/disk3s1s1 on / (apfs, sealed, local, read-only, journaled)\n\
+                      //me@nas/Recordings on /Volumes/Recordings (smbfs, nodev, nosuid, mounted by me)\n";
+        assert_eq!(
+            bsd_mount_fs_type(Path::new("/Volumes/Recordings/x"), mounts),
+            Some("smbfs")
+        );
+        assert_eq!(
+            bsd_mount_fs_type(Path::new("/Users/me"), mounts),
+            Some("apfs")
+        );
+    }
+
+    #[test]
+    fn unc_paths_are_network_paths() {
+        assert!(is_network_path(Path::new("\\\\nas\\share\\recordings")));
+        assert!(is_network_path(Path::new("//nas/share")));
+    }
+}
@@ -0,0 +1,197 @@
+//! Re-transcribes a single transcript segment from its slice of the meeting's
+//! recording. Fixing one garbled sentence with a bigger (or cloud) model this
+//! way costs a few seconds of audio instead of a whole re-run of the meeting.
+
+use log::{info, warn};
+use std::path::Path;
+use tauri::{AppHandle, Runtime};
+
+use super::audio_processing::find_meeting_audio;
+use super::corrections;
+use super::import::{decode_range_to_mono_16k, split_range, IMPORT_SAMPLE_RATE};
+use super::transcription::engine::{get_or_init_engine_with, get_or_init_transcription_engine};
+use super::transcription::priority::{engine_turn, Priority};
+use crate::api::TranscriptSegment;
+use crate::audit::{self, AuditAction};
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::jobs::JobHandle;
+use crate::locks::MeetingActivity;
+use crate::state::AppState;
+
+/// Longest window handed to the engine at once; Whisper works on 30s windows
+const WINDOW_SECONDS: usize = 30;
+
+/// The diarization label a segment starts with ("Speaker 2"), if any
+fn speaker_label(text: &str) -> Option<&str> {
+    let (label, _) = text.split_once(": ")?;
+    let number = label.strip_prefix("Speaker ")?;
+    (!number.is_empty() && number.chars().all(|c| c.is_ascii_digit())).then_some(label)
+}
+
+/// The re-transcribed text, keeping the old text's speaker label: a single
+/// segment is too short to diarize again
+pub fn relabel(old: &str, new: &str) -> String {
+    let new = new.trim();
+    match speaker_label(old) {
+        Some(label) if speaker_label(new).is_none() => format!("{}: {}", label, new),
+        _ => new.to_string(),
+    }
+}
+
+/// Re-transcribes one segment from its audio range and replaces only its
+/// text, as a "retranscribe" job. Uses `provider` (and `model`) when given,
+/// else the configured engine. Returns the updated segment.
+#[tauri::command]
+pub async fn api_retranscribe_segment<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    segment_id: String,
+    provider: Option<String>,
+    model: Option<String>,
+) -> Result<TranscriptSegment, String> {
+    let (meeting_id, segment) =
+        TranscriptsRepository::get_segment(state.db_manager.pool(), &segment_id)
+            .await
+            .map_err(|e| format!("Database error: {}", e))?
+            .ok_or_else(|| format!("Transcript segment not found: {}", segment_id))?;
+    let job = state
+        .jobs
+        .begin(
+            &app,
+            "retranscribe",
+            "Re-transcribe a segment",
+            Some(meeting_id.clone()),
+        )
+        .await?;
+    let result =
+        retranscribe_segment(&app, &state, &job, &meeting_id, segment, provider, model).await;
+    job.finish(match &result {
+        Ok(segment) => Ok(serde_json::json!({ "segment_id": segment.id })),
+        Err(e) => Err(e.clone()),
+    })
+    .await;
+    result
+}
+
+async fn retranscribe_segment<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &AppState,
+    job: &JobHandle,
+    meeting_id: &str,
+    mut segment: TranscriptSegment,
+    provider: Option<String>,
+    model: Option<String>,
+) -> Result<TranscriptSegment, String> {
+    let pool = state.db_manager.pool();
+    let segment_id = segment.id.clone();
+    let (Some(start), Some(end)) = (segment.audio_start_time, segment.audio_end_time) else {
+        return Err("This segment has no audio timing to re-transcribe".to_string());
+    };
+
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+    let lock = state
+        .meeting_locks
+        .try_acquire(meeting_id, MeetingActivity::Retranscription)
+        .map_err(|e| e.to_string())?;
+    let audio = meeting
+        .folder_path
+        .and_then(|folder| find_meeting_audio(Path::new(&folder)))
+        .ok_or_else(|| "This meeting has no recording".to_string())?;
+    let samples = tokio::task::spawn_blocking(move || decode_range_to_mono_16k(&audio, start, end))
+        .await
+        .map_err(|e| format!("Decode task failed: {}", e))?
+        .map_err(|e| format!("Failed to decode the segment's audio: {}", e))?;
+
+    let engine = match provider.as_deref().map(str::trim).filter(|p| !p.is_empty()) {
+        Some(provider) => get_or_init_engine_with(app, provider, model.as_deref()).await?,
+        None => get_or_init_transcription_engine(app).await?,
+    };
+    info!(
+        "Re-transcribing segment {} ({:.1}s-{:.1}s) with {}",
+        segment_id,
+        start,
+        end,
+        engine.provider_name()
+    );
+
+    let language = crate::get_language_preference_internal();
+    let window = WINDOW_SECONDS * IMPORT_SAMPLE_RATE as usize;
+    let mut texts = Vec::new();
+    let mut uncertain = false;
+    let windows = split_range(0, samples.len(), window);
+    let total = windows.len().max(1);
+    for (index, (from, to)) in windows.into_iter().enumerate() {
+        job.checkpoint().await?;
+        job.progress(index as f64 / total as f64, None).await;
+        let turn = engine_turn(Priority::Background).await;
+        let result = engine
+            .transcribe_stream(samples[from..to].to_vec(), language.clone())
+            .await;
+        drop(turn);
+        match result {
+            Ok(result) if !result.text.trim().is_empty() => {
+                uncertain |= result.uncertain;
+                texts.push(result.text);
+            }
+            Ok(_) => {}
+            Err(e) => return Err(format!("Re-transcription failed: {}", e)),
+        }
+    }
+    if texts.is_empty() {
+        warn!("Re-transcribing segment {} produced no text", segment_id);
+        return Err("No speech was recognized in this segment".to_string());
+    }
+    let text = corrections::apply(&relabel(&segment.text, &texts.join(" ")));
+
+    TranscriptsRepository::replace_segment_text(
+        pool,
+        &segment_id,
+        &text,
+        uncertain,
+        "retranscribe_segment",
+        "Re-transcribe segment",
+        Some(&lock),
+    )
+    .await
+    .map_err(|e| format!("Failed to update segment: {}", e))?
+    .ok_or_else(|| format!("Transcript segment not found: {}", segment_id))?;
+    audit::record(
+        pool,
+        AuditAction::SegmentRetranscribe,
+        "transcript",
+        Some(&segment_id),
+        serde_json::json!({
+            "meeting_id": meeting_id,
+            "provider": engine.provider_name(),
+            "model": engine.get_current_model().await,
+        }),
+    )
+    .await;
+
+    segment.text = text;
+    segment.uncertain = uncertain;
+    Ok(segment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn speaker_labels_survive_retranscription() {
+        assert_eq!(
+            relabel("Speaker 2: the bugdet is fine", " The budget is fine. "),
+            "Speaker 2: The budget is fine."
+        );
+        assert_eq!(
+            relabel("Speaker 2: old", "Speaker 1: new"),
+            "Speaker 1: new"
+        );
+        assert_eq!(relabel("Note: old", "new"), "new");
+        assert_eq!(relabel("old", "new"), "new");
+    }
+}
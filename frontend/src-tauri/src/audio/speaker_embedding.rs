@@ -0,0 +1,286 @@
+//! Helpers for speaker voice embeddings used by speaker naming / voice profiles.
+//!
+//! Embeddings are computed from the audio of each diarized speaker's segments
+//! as `Vec<f32>` and stored in SQLite as little-endian byte blobs.
+
+use crate::api::TranscriptSegment;
+use realfft::RealFftPlanner;
+
+/// Minimum cosine similarity for a meeting speaker to be auto-labelled with a profile
+pub const DEFAULT_MATCH_THRESHOLD: f32 = 0.75;
+
+const FRAME_LEN: usize = 400; // 25ms at 16kHz
+const FRAME_HOP: usize = 160; // 10ms at 16kHz
+const FFT_LEN: usize = 512;
+const BANDS: usize = 24;
+const MIN_HZ: f32 = 100.0;
+const MAX_HZ: f32 = 7000.0;
+/// Frames quieter than this are skipped as silence
+const SILENCE_RMS: f32 = 0.005;
+/// Below this much voiced audio a signature is too noisy to match on
+const MIN_VOICED_FRAMES: usize = 100;
+
+fn hz_to_mel(hz: f32) -> f32 {
+    2595.0 * (1.0 + hz / 700.0).log10()
+}
+
+fn mel_to_hz(mel: f32) -> f32 {
+    700.0 * (10f32.powf(mel / 2595.0) - 1.0)
+}
+
+/// A voice signature for mono audio: the mean and spread of log mel band
+/// energies over voiced frames, with overall loudness removed. None when
+/// there is too little voiced audio.
+pub fn voice_embedding(samples: &[f32], sample_rate: u32) -> Option<Vec<f32>> {
+    let mut planner = RealFftPlanner::<f32>::new();
+    let fft = planner.plan_fft_forward(FFT_LEN);
+    let mut input = fft.make_input_vec();
+    let mut spectrum = fft.make_output_vec();
+    let window: Vec<f32> = (0..FRAME_LEN)
+        .map(|i| {
+            0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (FRAME_LEN - 1) as f32).cos()
+        })
+        .collect();
+
+    let bin_hz = sample_rate as f32 / FFT_LEN as f32;
+    let (low, high) = (hz_to_mel(MIN_HZ), hz_to_mel(MAX_HZ));
+    let edges: Vec<usize> = (0..=BANDS + 1)
+        .map(|i| {
+            let hz = mel_to_hz(low + (high - low) * i as f32 / (BANDS + 1) as f32);
+            ((hz / bin_hz).round() as usize).min(FFT_LEN / 2)
+        })
+        .collect();
+
+    let mut sums = [0.0f64; BANDS];
+    let mut squares = [0.0f64; BANDS];
+    let mut voiced = 0usize;
+    let mut start = 0;
+    while start + FRAME_LEN <= samples.len() {
+        let frame = &samples[start..start + FRAME_LEN];
+        start += FRAME_HOP;
+        let rms = (frame.iter().map(|s| s * s).sum::<f32>() / FRAME_LEN as f32).sqrt();
+        if rms < SILENCE_RMS {
+            continue;
+        }
+        input.iter_mut().for_each(|v| *v = 0.0);
+        for (i, (s, w)) in frame.iter().zip(&window).enumerate() {
+            input[i] = s * w;
+        }
+        if fft.process(&mut input, &mut spectrum).is_err() {
+            continue;
+        }
+        let mut bands = [0.0f32; BANDS];
+        for (band, energy) in bands.iter_mut().enumerate() {
+            let (from, to) = (edges[band], edges[band + 2].max(edges[band] + 1));
+            let power: f32 = spectrum[from..to.min(spectrum.len())]
+                .iter()
+                .map(|c| c.norm_sqr())
+                .sum();
+            *energy = (power + 1e-10).ln();
+        }
+        // Loudness shifts every band alike; keep only the spectral shape
+        let mean = bands.iter().sum::<f32>() / BANDS as f32;
+        for (band, energy) in bands.iter().enumerate() {
+            let value = (energy - mean) as f64;
+            sums[band] += value;
+            squares[band] += value * value;
+        }
+        voiced += 1;
+    }
+    if voiced < MIN_VOICED_FRAMES {
+        return None;
+    }
+
+    let n = voiced as f64;
+    let means: Vec<f32> = sums.iter().map(|s| (s / n) as f32).collect();
+    let spreads = squares
+        .iter()
+        .zip(&sums)
+        .map(|(sq, s)| ((sq / n - (s / n).powi(2)).max(0.0).sqrt()) as f32);
+    Some(means.iter().copied().chain(spreads).collect())
+}
+
+/// One signature per labelled speaker, from the audio of their segments.
+/// Speakers with too little audio are left out.
+pub fn speaker_embeddings(
+    samples: &[f32],
+    sample_rate: u32,
+    segments: &[TranscriptSegment],
+) -> Vec<(String, Vec<f32>)> {
+    let mut audio: Vec<(String, Vec<f32>)> = Vec::new();
+    for segment in segments {
+        let (Some(speaker), Some(start), Some(end)) = (
+            segment.speaker.as_ref(),
+            segment.audio_start_time,
+            segment.audio_end_time,
+        ) else {
+            continue;
+        };
+        let from = ((start.max(0.0) * sample_rate as f64) as usize).min(samples.len());
+        let to = ((end.max(0.0) * sample_rate as f64) as usize).min(samples.len());
+        if from >= to {
+            continue;
+        }
+        match audio.iter_mut().find(|(label, _)| label == speaker) {
+            Some((_, collected)) => collected.extend_from_slice(&samples[from..to]),
+            None => audio.push((speaker.clone(), samples[from..to].to_vec())),
+        }
+    }
+    audio
+        .into_iter()
+        .filter_map(|(label, samples)| Some((label, voice_embedding(&samples, sample_rate)?)))
+        .collect()
+}
+
+pub fn embedding_to_bytes(embedding: &[f32]) -> Vec<u8> {
+    embedding.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+/// Returns None if the blob length is not a multiple of 4
+pub fn embedding_from_bytes(bytes: &[u8]) -> Option<Vec<f32>> {
+    if bytes.len() % 4 != 0 {
+        return None;
+    }
+    Some(
+        bytes
+            .chunks_exact(4)
+            .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .collect(),
+    )
+}
+
+/// Cosine similarity in [-1, 1]; 0.0 for mismatched or zero-length vectors
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let mut dot = 0.0f32;
+    let mut norm_a = 0.0f32;
+    let mut norm_b = 0.0f32;
+    for (x, y) in a.iter().zip(b.iter()) {
+        dot += x * y;
+        norm_a += x * x;
+        norm_b += y * y;
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a.sqrt() * norm_b.sqrt())
+}
+
+/// Folds a new sample into a running mean of `count` previous samples
+pub fn merge_embedding(current: &[f32], count: i64, sample: &[f32]) -> Vec<f32> {
+    if current.len() != sample.len() || count <= 0 {
+        return sample.to_vec();
+    }
+    let n = count as f32;
+    current
+        .iter()
+        .zip(sample.iter())
+        .map(|(c, s)| (c * n + s) / (n + 1.0))
+        .collect()
+}
+
+/// Picks the best candidate above `threshold`, returning its index and score
+pub fn best_match<'a, I>(embedding: &[f32], candidates: I, threshold: f32) -> Option<(usize, f32)>
+where
+    I: IntoIterator<Item = &'a [f32]>,
+{
+    candidates
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| (i, cosine_similarity(embedding, c)))
+        .filter(|(_, score)| *score >= threshold)
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_round_trip() {
+        let v = vec![0.5f32, -1.25, 3.0];
+        let bytes = embedding_to_bytes(&v);
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(embedding_from_bytes(&bytes), Some(v));
+        assert_eq!(embedding_from_bytes(&bytes[..5]), None);
+    }
+
+    #[test]
+    fn cosine_similarity_basics() {
+        assert!((cosine_similarity(&[1.0, 0.0], &[2.0, 0.0]) - 1.0).abs() < 1e-6);
+        assert!(cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]).abs() < 1e-6);
+        assert_eq!(cosine_similarity(&[1.0], &[1.0, 2.0]), 0.0);
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 1.0]), 0.0);
+    }
+
+    #[test]
+    fn merge_is_running_mean() {
+        let merged = merge_embedding(&[1.0, 1.0], 1, &[3.0, 5.0]);
+        assert_eq!(merged, vec![2.0, 3.0]);
+        // Dimension change replaces the profile
+        assert_eq!(merge_embedding(&[1.0], 4, &[2.0, 2.0]), vec![2.0, 2.0]);
+    }
+
+    #[test]
+    fn best_match_respects_threshold() {
+        let a = vec![1.0f32, 0.0];
+        let b = vec![0.7f32, 0.7];
+        let candidates = vec![a.as_slice(), b.as_slice()];
+        assert_eq!(best_match(&[0.9, 0.1], candidates.clone(), 0.9).map(|m| m.0), Some(0));
+        assert_eq!(best_match(&[0.0, 1.0], candidates, 0.9), None);
+    }
+
+    fn voice(pitch: f32, formant: f32, seconds: f32) -> Vec<f32> {
+        (0..(16000.0 * seconds) as usize)
+            .map(|i| {
+                let t = i as f32 / 16000.0;
+                0.2 * (2.0 * std::f32::consts::PI * pitch * t).sin()
+                    + 0.1 * (2.0 * std::f32::consts::PI * formant * t).sin()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn same_voice_matches_closer_than_another() {
+        let a = voice_embedding(&voice(120.0, 900.0, 2.0), 16000).unwrap();
+        let louder: Vec<f32> = voice(120.0, 900.0, 2.0).iter().map(|s| s * 2.0).collect();
+        let a_again = voice_embedding(&louder, 16000).unwrap();
+        let b = voice_embedding(&voice(240.0, 2500.0, 2.0), 16000).unwrap();
+        assert_eq!(a.len(), 2 * BANDS);
+        assert!(cosine_similarity(&a, &a_again) > 0.99);
+        assert!(cosine_similarity(&a, &b) < cosine_similarity(&a, &a_again));
+        // Silence and short clips give no signature
+        assert_eq!(voice_embedding(&vec![0.0; 32000], 16000), None);
+        assert_eq!(voice_embedding(&voice(120.0, 900.0, 0.5), 16000), None);
+    }
+
+    #[test]
+    fn embeddings_are_grouped_by_speaker() {
+        let mut samples = voice(120.0, 900.0, 2.0);
+        samples.extend(voice(240.0, 2500.0, 2.0));
+        let segment = |speaker: Option<&str>, start: f64, end: f64| TranscriptSegment {
+            id: "t".into(),
+            text: String::new(),
+            timestamp: String::new(),
+            audio_start_time: Some(start),
+            audio_end_time: Some(end),
+            duration: Some(end - start),
+            speaker: speaker.map(str::to_string),
+            uncertain: false,
+        };
+        let segments = [
+            segment(Some("Speaker 1"), 0.0, 1.0),
+            segment(Some("Speaker 2"), 2.0, 4.0),
+            segment(Some("Speaker 1"), 1.0, 2.0),
+            segment(None, 0.0, 4.0),
+            segment(Some("Speaker 3"), 3.9, 4.0),
+        ];
+        let embeddings = speaker_embeddings(&samples, 16000, &segments);
+        let labels: Vec<&str> = embeddings.iter().map(|(label, _)| label.as_str()).collect();
+        assert_eq!(labels, ["Speaker 1", "Speaker 2"]);
+        let whole = voice_embedding(&samples[..32000], 16000).unwrap();
+        assert!(cosine_similarity(&embeddings[0].1, &whole) > 0.999);
+    }
+}
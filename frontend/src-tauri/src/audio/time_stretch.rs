@@ -0,0 +1,192 @@
+//! Pitch-preserving time stretching (WSOLA) for variable-speed playback.
+//!
+//! Frames of 40ms are taken from the input at `rate` times the output hop and
+//! overlap-added with a Hann window. Each frame's start is nudged within a
+//! small search range to the offset that best continues the previous frame,
+//! so periodic speech lines up across frames instead of phasing. Voices keep
+//! their pitch at any rate, unlike resampling.
+
+use std::collections::VecDeque;
+
+pub const MIN_PLAYBACK_RATE: f32 = 0.5;
+pub const MAX_PLAYBACK_RATE: f32 = 3.0;
+
+/// Output hop; frames are twice as long and overlap by half
+const HOP_MS: u32 = 20;
+/// How far a frame may move from its nominal position to line up
+const SEARCH_MS: u32 = 10;
+
+pub struct TimeStretcher {
+    hop: usize,
+    search: usize,
+    window: Vec<f32>,
+    /// Windowed second half of the previous frame, added to the next one
+    tail: Vec<f32>,
+    /// Input offset the previous frame was taken from
+    previous: Option<usize>,
+}
+
+impl TimeStretcher {
+    pub fn new(sample_rate: u32) -> Self {
+        let hop = (sample_rate * HOP_MS / 1000).max(1) as usize;
+        let frame = hop * 2;
+        // Periodic Hann: halves shifted by `hop` sum to exactly 1
+        let window = (0..frame)
+            .map(|i| 0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / frame as f32).cos())
+            .collect();
+        Self {
+            hop,
+            search: (sample_rate * SEARCH_MS / 1000) as usize,
+            window,
+            tail: Vec::new(),
+            previous: None,
+        }
+    }
+
+    /// Forgets the previous frame; call after seeking or changing the rate
+    pub fn reset(&mut self) {
+        self.tail.clear();
+        self.previous = None;
+    }
+
+    /// Keeps the previous frame's offset valid after `removed` samples were
+    /// dropped from the front of the input
+    pub fn shift(&mut self, removed: usize) {
+        self.previous = self.previous.map(|previous| previous.saturating_sub(removed));
+    }
+
+    /// Appends the next hop of output, read from `input` at `position` (in
+    /// input samples), and advances `position` by `rate` hops. Returns false
+    /// once the input is exhausted.
+    pub fn process(
+        &mut self,
+        input: &[f32],
+        position: &mut f64,
+        rate: f64,
+        out: &mut VecDeque<f32>,
+    ) -> bool {
+        let nominal = position.max(0.0).round() as usize;
+        if nominal >= input.len() {
+            out.extend(self.tail.drain(..));
+            self.previous = None;
+            return false;
+        }
+
+        if (rate - 1.0).abs() < 1e-3 {
+            out.extend(self.tail.drain(..));
+            self.previous = None;
+            let end = (nominal + self.hop).min(input.len());
+            out.extend(&input[nominal..end]);
+            *position += self.hop as f64;
+            return true;
+        }
+
+        let start = match self.previous {
+            Some(previous) => self.best_start(input, previous + self.hop, nominal),
+            None => nominal,
+        };
+        let frame: Vec<f32> = self
+            .window
+            .iter()
+            .enumerate()
+            .map(|(i, w)| input.get(start + i).copied().unwrap_or(0.0) * w)
+            .collect();
+        let (head, tail) = frame.split_at(self.hop);
+        for (i, sample) in head.iter().enumerate() {
+            out.push_back(sample + self.tail.get(i).copied().unwrap_or(0.0));
+        }
+        self.tail = tail.to_vec();
+        self.previous = Some(start);
+        *position += self.hop as f64 * rate;
+        true
+    }
+
+    /// Finds the start near `nominal` whose first half best matches the
+    /// natural continuation of the previous frame (normalised correlation)
+    fn best_start(&self, input: &[f32], continuation: usize, nominal: usize) -> usize {
+        let frame = self.hop * 2;
+        if continuation + self.hop > input.len() {
+            return nominal;
+        }
+        let target = &input[continuation..continuation + self.hop];
+        let last = input.len().saturating_sub(frame);
+        let from = nominal.saturating_sub(self.search).min(last);
+        let to = (nominal + self.search).min(last);
+
+        let mut best = (nominal, f32::MIN);
+        for start in from..=to {
+            let candidate = &input[start..start + self.hop];
+            let (dot, energy) = candidate
+                .iter()
+                .zip(target)
+                .fold((0.0f32, 0.0f32), |(dot, energy), (c, t)| {
+                    (dot + c * t, energy + c * c)
+                });
+            let score = dot / (energy.sqrt() + 1e-6);
+            if score > best.1 {
+                best = (start, score);
+            }
+        }
+        best.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 16000;
+
+    fn sine(frequency: f32, seconds: f32) -> Vec<f32> {
+        (0..(RATE as f32 * seconds) as usize)
+            .map(|i| (2.0 * std::f32::consts::PI * frequency * i as f32 / RATE as f32).sin() * 0.5)
+            .collect()
+    }
+
+    fn stretch(input: &[f32], rate: f64) -> Vec<f32> {
+        let mut stretcher = TimeStretcher::new(RATE);
+        let mut position = 0.0;
+        let mut out = VecDeque::new();
+        while stretcher.process(input, &mut position, rate, &mut out) {}
+        out.into_iter().collect()
+    }
+
+    /// Dominant frequency from rising zero crossings, ignoring the edges
+    fn frequency(samples: &[f32]) -> f32 {
+        let middle = &samples[samples.len() / 10..samples.len() * 9 / 10];
+        let crossings = middle
+            .windows(2)
+            .filter(|w| w[0] < 0.0 && w[1] >= 0.0)
+            .count();
+        crossings as f32 * RATE as f32 / middle.len() as f32
+    }
+
+    #[test]
+    fn normal_speed_passes_audio_through() {
+        let input = sine(440.0, 1.0);
+        assert_eq!(stretch(&input, 1.0), input);
+    }
+
+    #[test]
+    fn changes_duration_but_not_pitch() {
+        let input = sine(440.0, 2.0);
+        for rate in [0.5, 1.5, 2.0, 3.0] {
+            let out = stretch(&input, rate);
+            let expected = input.len() as f64 / rate;
+            assert!(
+                (out.len() as f64 - expected).abs() < 2.0 * RATE as f64 * 0.02,
+                "rate {}: {} samples, expected about {}",
+                rate,
+                out.len(),
+                expected
+            );
+            let pitch = frequency(&out);
+            assert!(
+                (pitch - 440.0).abs() < 10.0,
+                "rate {}: pitch {}",
+                rate,
+                pitch
+            );
+        }
+    }
+}
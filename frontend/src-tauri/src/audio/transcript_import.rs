@@ -0,0 +1,430 @@
+//! Import of existing transcripts without audio: WebVTT, SubRip and the JSON
+//! exports of common meeting tools, turned into timed transcript segments.
+//!
+//! JSON exports are recognised by shape rather than by vendor: a list of
+//! entries (at the root or under `transcripts`, `entries`, `segments`, ...)
+//! that each carry text, a start time and optionally an end time and a
+//! speaker. That covers Otter (`transcripts` + `speakers`, offsets in
+//! milliseconds), Teams/Stream (`entries` with `speakerDisplayName` and
+//! `startOffset` strings) and Zoom/generic `segments` exports.
+//!
+//! Like `import`, this has no Tauri dependencies so the CLI can use it.
+
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use std::path::Path;
+use uuid::Uuid;
+
+use super::import::format_offset;
+use crate::api::TranscriptSegment;
+
+pub const TRANSCRIPT_EXTENSIONS: [&str; 3] = ["vtt", "srt", "json"];
+
+/// Keys that hold the entry list in JSON exports, in order of preference
+const JSON_LIST_KEYS: [&str; 7] = [
+    "transcripts",
+    "entries",
+    "segments",
+    "transcript",
+    "results",
+    "items",
+    "utterances",
+];
+const JSON_TEXT_KEYS: [&str; 4] = ["text", "transcript", "content", "caption"];
+const JSON_START_KEYS: [&str; 5] = ["start", "start_time", "startTime", "startOffset", "ts"];
+const JSON_END_KEYS: [&str; 4] = ["end", "end_time", "endTime", "endOffset"];
+const JSON_START_MS_KEYS: [&str; 3] = ["start_offset", "start_ms", "startMs"];
+const JSON_END_MS_KEYS: [&str; 3] = ["end_offset", "end_ms", "endMs"];
+const JSON_SPEAKER_KEYS: [&str; 5] = [
+    "speakerDisplayName",
+    "speaker_name",
+    "speakerName",
+    "speaker",
+    "user_name",
+];
+
+/// One timed line of a transcript file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cue {
+    pub start: f64,
+    pub end: Option<f64>,
+    pub speaker: Option<String>,
+    pub text: String,
+}
+
+/// A parsed transcript, ready to be saved as a meeting
+#[derive(Debug, Clone)]
+pub struct ParsedTranscript {
+    pub segments: Vec<TranscriptSegment>,
+    /// Distinct speaker names, in order of first appearance
+    pub speakers: Vec<String>,
+}
+
+pub fn is_transcript_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| TRANSCRIPT_EXTENSIONS.contains(&e.to_lowercase().as_str()))
+}
+
+/// Reads and parses a .vtt, .srt or .json transcript
+pub fn read_transcript_file(path: &Path) -> Result<ParsedTranscript> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+    let cues = parse_transcript(&extension, &content)?;
+    if cues.is_empty() {
+        return Err(anyhow!("{} contains no transcript lines", path.display()));
+    }
+    Ok(to_transcript(cues))
+}
+
+pub fn parse_transcript(extension: &str, content: &str) -> Result<Vec<Cue>> {
+    let content = content.trim_start_matches('\u{feff}');
+    match extension {
+        "vtt" | "srt" => Ok(parse_cue_blocks(content)),
+        "json" => parse_json(content),
+        other => Err(anyhow!(
+            "Unsupported transcript format '.{}'; use .vtt, .srt or .json",
+            other
+        )),
+    }
+}
+
+/// Parses "HH:MM:SS.mmm", "MM:SS.mmm" (WebVTT), "HH:MM:SS,mmm" (SubRip) and
+/// the 7-digit fractions Teams writes into seconds
+pub fn parse_timestamp(value: &str) -> Option<f64> {
+    let value = value.trim().replace(',', ".");
+    let parts: Vec<&str> = value.split(':').collect();
+    if parts.is_empty() || parts.len() > 3 {
+        return None;
+    }
+    let mut seconds = 0.0;
+    for (index, part) in parts.iter().enumerate() {
+        let last = index == parts.len() - 1;
+        if part.is_empty() || (!last && !part.chars().all(|c| c.is_ascii_digit())) {
+            return None;
+        }
+        let number: f64 = part.parse().ok()?;
+        if !number.is_finite() || number < 0.0 {
+            return None;
+        }
+        seconds = seconds * 60.0 + number;
+    }
+    Some(seconds)
+}
+
+/// WebVTT and SubRip share the cue layout: blank-line separated blocks with
+/// an optional identifier line, a `start --> end` timing line and text lines
+fn parse_cue_blocks(content: &str) -> Vec<Cue> {
+    let content = content.replace("\r\n", "\n").replace('\r', "\n");
+    let mut cues = Vec::new();
+    for block in content.split("\n\n") {
+        let lines: Vec<&str> = block.lines().collect();
+        let Some(timing_index) = lines.iter().position(|line| line.contains("-->")) else {
+            // Header, NOTE, STYLE and REGION blocks have no timing line
+            continue;
+        };
+        let mut times = lines[timing_index].split("-->");
+        let Some(start) = times.next().and_then(parse_timestamp) else {
+            continue;
+        };
+        // WebVTT cue settings (align:start ...) follow the end time
+        let end = times
+            .next()
+            .and_then(|rest| rest.split_whitespace().next())
+            .and_then(parse_timestamp);
+
+        let mut speaker = None;
+        let mut text_lines = Vec::new();
+        for line in &lines[timing_index + 1..] {
+            let (voice, text) = strip_cue_markup(line);
+            if speaker.is_none() {
+                speaker = voice;
+            }
+            if !text.is_empty() {
+                text_lines.push(text);
+            }
+        }
+        let text = text_lines.join(" ");
+        if !text.is_empty() {
+            cues.push(Cue {
+                start,
+                end,
+                speaker,
+                text,
+            });
+        }
+    }
+    cues
+}
+
+/// Removes markup from a cue line (`<i>`, `<c.yellow>`, inline timestamps,
+/// `{\an8}`) and returns the WebVTT voice (`<v Jane Doe>`) if there is one
+fn strip_cue_markup(line: &str) -> (Option<String>, String) {
+    let mut speaker = None;
+    let mut text = String::new();
+    let mut rest = line;
+    while let Some(open) = rest.find(['<', '{']) {
+        text.push_str(&rest[..open]);
+        let close = if rest[open..].starts_with('<') {
+            '>'
+        } else {
+            '}'
+        };
+        let Some(length) = rest[open..].find(close) else {
+            text.push_str(&rest[open..]);
+            rest = "";
+            break;
+        };
+        let tag = &rest[open + 1..open + length];
+        if let Some(voice) = tag.strip_prefix("v ").or_else(|| tag.strip_prefix("v.")) {
+            // `<v.loud Jane Doe>` carries classes before the name
+            let name = if tag.starts_with("v.") {
+                voice.split_once(' ').map(|(_, name)| name).unwrap_or("")
+            } else {
+                voice
+            };
+            if !name.trim().is_empty() {
+                speaker = Some(name.trim().to_string());
+            }
+        }
+        rest = &rest[open + length + 1..];
+    }
+    text.push_str(rest);
+    let text = decode_entities(&text);
+    (
+        speaker,
+        text.split_whitespace().collect::<Vec<_>>().join(" "),
+    )
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn parse_json(content: &str) -> Result<Vec<Cue>> {
+    let root: Value =
+        serde_json::from_str(content).map_err(|e| anyhow!("Invalid JSON transcript: {}", e))?;
+    let entries = match &root {
+        Value::Array(entries) => entries,
+        Value::Object(object) => JSON_LIST_KEYS
+            .iter()
+            .find_map(|key| object.get(*key).and_then(Value::as_array))
+            .ok_or_else(|| {
+                anyhow!("JSON transcript has no list of entries (expected e.g. \"segments\")")
+            })?,
+        _ => return Err(anyhow!("JSON transcript must be an object or a list")),
+    };
+
+    // Otter keeps speaker names in a separate list referenced by id
+    let speaker_names: Vec<(String, String)> = root
+        .get("speakers")
+        .and_then(Value::as_array)
+        .map(|speakers| {
+            speakers
+                .iter()
+                .filter_map(|s| {
+                    Some((
+                        value_key(s.get("id")?)?,
+                        s.get("name")?.as_str()?.to_string(),
+                    ))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut cues = Vec::new();
+    for entry in entries {
+        let Some(text) = first_str(entry, &JSON_TEXT_KEYS) else {
+            continue;
+        };
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        let Some(start) = json_time(entry, &JSON_START_KEYS, &JSON_START_MS_KEYS) else {
+            continue;
+        };
+        if text.is_empty() {
+            continue;
+        }
+        let speaker = first_str(entry, &JSON_SPEAKER_KEYS)
+            .map(str::to_string)
+            .or_else(|| {
+                let id = value_key(entry.get("speaker_id")?)?;
+                speaker_names
+                    .iter()
+                    .find(|(key, _)| *key == id)
+                    .map(|(_, name)| name.clone())
+            })
+            .filter(|s| !s.trim().is_empty());
+        cues.push(Cue {
+            start,
+            end: json_time(entry, &JSON_END_KEYS, &JSON_END_MS_KEYS),
+            speaker,
+            text,
+        });
+    }
+    cues.sort_by(|a, b| a.start.total_cmp(&b.start));
+    Ok(cues)
+}
+
+fn first_str<'a>(entry: &'a Value, keys: &[&str]) -> Option<&'a str> {
+    keys.iter()
+        .find_map(|key| entry.get(*key).and_then(Value::as_str))
+}
+
+/// Ids may be numbers or strings
+fn value_key(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Seconds from either a seconds field (number or timestamp string) or a
+/// milliseconds field
+fn json_time(entry: &Value, second_keys: &[&str], ms_keys: &[&str]) -> Option<f64> {
+    let seconds = second_keys.iter().find_map(|key| match entry.get(*key)? {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => parse_timestamp(s),
+        _ => None,
+    });
+    seconds
+        .or_else(|| {
+            ms_keys
+                .iter()
+                .find_map(|key| entry.get(*key)?.as_f64())
+                .map(|ms| ms / 1000.0)
+        })
+        .filter(|t| t.is_finite() && *t >= 0.0)
+}
+
+/// Turns cues into transcript segments. Cues without an end run until the
+/// next cue starts; speaker names label the segments.
+pub fn to_transcript(cues: Vec<Cue>) -> ParsedTranscript {
+    let mut speakers: Vec<String> = Vec::new();
+    let mut segments = Vec::with_capacity(cues.len());
+    for (index, cue) in cues.iter().enumerate() {
+        let next_start = cues.get(index + 1).map(|next| next.start);
+        let end = cue
+            .end
+            .filter(|end| *end >= cue.start)
+            .or(next_start)
+            .unwrap_or(cue.start)
+            .max(cue.start);
+        // A name spelled differently later on is the same speaker
+        let speaker = cue.speaker.as_ref().map(|speaker| {
+            match speakers.iter().find(|s| s.eq_ignore_ascii_case(speaker)) {
+                Some(known) => known.clone(),
+                None => {
+                    speakers.push(speaker.clone());
+                    speaker.clone()
+                }
+            }
+        });
+        segments.push(TranscriptSegment {
+            id: format!("transcript-{}", Uuid::new_v4()),
+            text: cue.text.clone(),
+            timestamp: format_offset(cue.start),
+            audio_start_time: Some(cue.start),
+            audio_end_time: Some(end),
+            duration: Some(end - cue.start),
+            speaker,
+            uncertain: false,
+        });
+    }
+    ParsedTranscript { segments, speakers }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_timestamps() {
+        assert_eq!(parse_timestamp("00:01:02.500"), Some(62.5));
+        assert_eq!(parse_timestamp("01:00:00,250"), Some(3600.25));
+        assert_eq!(parse_timestamp("02:03.000"), Some(123.0));
+        assert_eq!(parse_timestamp("00:00:01.2340000"), Some(1.234));
+        assert_eq!(parse_timestamp("1:2:x"), None);
+        assert_eq!(parse_timestamp(""), None);
+    }
+
+    #[test]
+    fn parses_webvtt_with_voices() {
+        let vtt = "\u{feff}WEBVTT\r\n\r\nNOTE exported by Teams\r\n\r\n\
+                   1a2b/3-0\r\n00:00:01.000 --> 00:00:04.500 align:start\r\n\
+                   <v Jane Doe>Hello <i>everyone</i>.</v>\r\n\r\n\
+                   00:05.000 --> 00:07.000\r\nNo voice &amp; no id\r\n";
+        let cues = parse_transcript("vtt", vtt).unwrap();
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].start, 1.0);
+        assert_eq!(cues[0].end, Some(4.5));
+        assert_eq!(cues[0].speaker.as_deref(), Some("Jane Doe"));
+        assert_eq!(cues[0].text, "Hello everyone.");
+        assert_eq!(cues[1].speaker, None);
+        assert_eq!(cues[1].text, "No voice & no id");
+    }
+
+    #[test]
+    fn parses_subrip() {
+        let srt = "1\n00:00:01,000 --> 00:00:02,000\n{\\an8}First line\nsecond line\n\n\
+                   2\n00:00:03,000 --> 00:00:04,000\n<b>Bold</b>\n";
+        let cues = parse_transcript("srt", srt).unwrap();
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].text, "First line second line");
+        assert_eq!(cues[1].start, 3.0);
+        assert_eq!(cues[1].text, "Bold");
+    }
+
+    #[test]
+    fn parses_json_exports() {
+        let otter = r#"{"speakers":[{"id":1,"name":"Ana"}],
+            "transcripts":[{"start_offset":2500,"end_offset":4000,"speaker_id":1,"transcript":"Hi"},
+                           {"start_offset":500,"end_offset":2000,"speaker_id":2,"transcript":"First"}]}"#;
+        let cues = parse_transcript("json", otter).unwrap();
+        assert_eq!(cues[0].text, "First");
+        assert_eq!(cues[1].start, 2.5);
+        assert_eq!(cues[1].speaker.as_deref(), Some("Ana"));
+
+        let teams = r#"{"entries":[{"text":"Morning","speakerDisplayName":"Bo",
+            "startOffset":"00:00:03.1000000","endOffset":"00:00:05.0000000"}]}"#;
+        let cues = parse_transcript("json", teams).unwrap();
+        assert_eq!(cues[0].start, 3.1);
+        assert_eq!(cues[0].end, Some(5.0));
+
+        let generic = r#"[{"start":1.5,"text":"a"},{"start":4,"text":"b"}]"#;
+        let parsed = to_transcript(parse_transcript("json", generic).unwrap());
+        // Without an end time a segment runs until the next one starts
+        assert_eq!(parsed.segments[0].audio_end_time, Some(4.0));
+        assert_eq!(parsed.segments[1].duration, Some(0.0));
+
+        assert!(parse_transcript("json", r#"{"foo":1}"#).is_err());
+    }
+
+    #[test]
+    fn keeps_and_collects_speakers() {
+        let cue = |speaker: Option<&str>, text: &str| Cue {
+            start: 0.0,
+            end: Some(1.0),
+            speaker: speaker.map(str::to_string),
+            text: text.to_string(),
+        };
+        let parsed = to_transcript(vec![
+            cue(Some("Jane"), "one"),
+            cue(None, "two"),
+            cue(Some("jane"), "three"),
+        ]);
+        assert_eq!(parsed.segments[0].text, "one");
+        assert_eq!(parsed.segments[0].speaker.as_deref(), Some("Jane"));
+        assert_eq!(parsed.segments[1].speaker, None);
+        assert_eq!(parsed.segments[2].speaker.as_deref(), Some("Jane"));
+        assert_eq!(parsed.speakers, vec!["Jane".to_string()]);
+    }
+}
@@ -0,0 +1,257 @@
+// audio/transcription/assemblyai_provider.rs
+//
+// AssemblyAI cloud transcription. Audio is uploaded, a transcript is
+// requested and polled until it completes, so live chunks take a few
+// seconds each; imports send the whole recording with speaker labels.
+
+use super::cloud::{language_hint, segments_from_words, wav_bytes, TimedWord};
+use super::provider::{
+    ProviderCapabilities, TranscriptResult, TranscriptionError, TranscriptionProvider,
+};
+use crate::api::TranscriptSegment;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::time::{Duration, Instant};
+
+const API_URL: &str = "https://api.assemblyai.com/v2";
+pub const DEFAULT_ASSEMBLYAI_MODEL: &str = "best";
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+const CHUNK_TIMEOUT: Duration = Duration::from_secs(60);
+/// Long recordings are transcribed at a fraction of real time
+const RECORDING_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Deserialize)]
+struct Upload {
+    upload_url: String,
+}
+
+#[derive(Deserialize)]
+struct Transcript {
+    id: String,
+    status: String,
+    #[serde(default)]
+    text: Option<String>,
+    #[serde(default)]
+    confidence: Option<f32>,
+    #[serde(default)]
+    words: Option<Vec<Word>>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Word {
+    text: String,
+    /// Milliseconds
+    start: u64,
+    end: u64,
+    /// "A", "B", ... when speaker labels were requested
+    #[serde(default)]
+    speaker: Option<String>,
+}
+
+fn failed(message: String) -> TranscriptionError {
+    TranscriptionError::EngineFailed(message)
+}
+
+/// "A" -> 0, "B" -> 1, ...
+fn speaker_index(label: &str) -> Option<u32> {
+    let mut chars = label.trim().chars();
+    match (chars.next(), chars.next()) {
+        (Some(letter), None) if letter.is_ascii_uppercase() => Some(letter as u32 - 'A' as u32),
+        _ => label.trim().parse().ok(),
+    }
+}
+
+pub struct AssemblyAiProvider {
+    api_key: String,
+    model: String,
+    http: reqwest::Client,
+}
+
+impl AssemblyAiProvider {
+    pub fn new(api_key: String, model: String) -> Self {
+        let model = match model.trim() {
+            "" => DEFAULT_ASSEMBLYAI_MODEL.to_string(),
+            model => model.to_string(),
+        };
+        Self {
+            api_key,
+            model,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    async fn check(response: reqwest::Response) -> Result<reqwest::Response, TranscriptionError> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+        let body = response.text().await.unwrap_or_default();
+        Err(failed(format!(
+            "AssemblyAI returned {}: {}",
+            status,
+            body.trim()
+        )))
+    }
+
+    /// Uploads `audio`, requests a transcript and waits for it. Every request
+    /// is bounded by what is left of `timeout`, so a stalled connection fails
+    /// like a transcript that never finishes.
+    async fn transcribe_audio(
+        &self,
+        audio: &[f32],
+        language: Option<String>,
+        speaker_labels: bool,
+        timeout: Duration,
+    ) -> Result<Transcript, TranscriptionError> {
+        let started = Instant::now();
+        let remaining = || timeout.saturating_sub(started.elapsed());
+        let upload: Upload = Self::check(
+            self.http
+                .post(format!("{}/upload", API_URL))
+                .header("authorization", &self.api_key)
+                .body(wav_bytes(audio))
+                .timeout(remaining())
+                .send()
+                .await
+                .map_err(|e| failed(format!("AssemblyAI upload failed: {}", e)))?,
+        )
+        .await?
+        .json()
+        .await
+        .map_err(|e| failed(format!("Unexpected AssemblyAI response: {}", e)))?;
+
+        let mut request = serde_json::json!({
+            "audio_url": upload.upload_url,
+            "speech_model": self.model,
+            "punctuate": true,
+            "format_text": true,
+            "speaker_labels": speaker_labels,
+        });
+        match language_hint(language) {
+            Some(language) => request["language_code"] = language.into(),
+            None => request["language_detection"] = true.into(),
+        }
+        let mut transcript: Transcript = Self::check(
+            self.http
+                .post(format!("{}/transcript", API_URL))
+                .header("authorization", &self.api_key)
+                .json(&request)
+                .timeout(remaining())
+                .send()
+                .await
+                .map_err(|e| failed(format!("AssemblyAI request failed: {}", e)))?,
+        )
+        .await?
+        .json()
+        .await
+        .map_err(|e| failed(format!("Unexpected AssemblyAI response: {}", e)))?;
+
+        loop {
+            match transcript.status.as_str() {
+                "completed" => return Ok(transcript),
+                "error" => {
+                    return Err(failed(format!(
+                        "AssemblyAI failed: {}",
+                        transcript.error.unwrap_or_default()
+                    )))
+                }
+                _ if started.elapsed() > timeout => {
+                    return Err(failed("Timed out waiting for AssemblyAI".to_string()))
+                }
+                _ => tokio::time::sleep(POLL_INTERVAL).await,
+            }
+            transcript = Self::check(
+                self.http
+                    .get(format!("{}/transcript/{}", API_URL, transcript.id))
+                    .header("authorization", &self.api_key)
+                    .timeout(remaining())
+                    .send()
+                    .await
+                    .map_err(|e| failed(format!("AssemblyAI request failed: {}", e)))?,
+            )
+            .await?
+            .json()
+            .await
+            .map_err(|e| failed(format!("Unexpected AssemblyAI response: {}", e)))?;
+        }
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for AssemblyAiProvider {
+    async fn transcribe_stream(
+        &self,
+        audio: Vec<f32>,
+        language: Option<String>,
+    ) -> std::result::Result<TranscriptResult, TranscriptionError> {
+        let transcript = self
+            .transcribe_audio(&audio, language, false, CHUNK_TIMEOUT)
+            .await?;
+        Ok(TranscriptResult {
+            text: transcript.text.unwrap_or_default().trim().to_string(),
+            confidence: transcript.confidence,
+            is_partial: false,
+            uncertain: false,
+        })
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            confidence: true,
+            language_hint: true,
+            batch: true,
+            diarization: true,
+            ..Default::default()
+        }
+    }
+
+    async fn is_model_loaded(&self) -> bool {
+        // Nothing to load locally
+        true
+    }
+
+    async fn get_current_model(&self) -> Option<String> {
+        Some(self.model.clone())
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "AssemblyAI"
+    }
+
+    async fn transcribe_batch(
+        &self,
+        audio: &[f32],
+        language: Option<String>,
+    ) -> std::result::Result<Vec<TranscriptSegment>, TranscriptionError> {
+        let transcript = self
+            .transcribe_audio(audio, language, true, RECORDING_TIMEOUT)
+            .await?;
+        let words: Vec<TimedWord> = transcript
+            .words
+            .unwrap_or_default()
+            .into_iter()
+            .map(|word| TimedWord {
+                text: word.text,
+                start: word.start as f64 / 1000.0,
+                end: word.end as f64 / 1000.0,
+                speaker: word.speaker.as_deref().and_then(speaker_index),
+            })
+            .collect();
+        Ok(segments_from_words(&words))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn speaker_letters_map_to_indexes() {
+        assert_eq!(speaker_index("A"), Some(0));
+        assert_eq!(speaker_index("C"), Some(2));
+        assert_eq!(speaker_index("3"), Some(3));
+        assert_eq!(speaker_index("AB"), None);
+    }
+}
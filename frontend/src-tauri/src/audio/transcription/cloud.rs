@@ -0,0 +1,214 @@
+// audio/transcription/cloud.rs
+//
+// Helpers shared by the cloud transcription providers: audio upload format,
+// language hints and turning timed words into transcript segments.
+
+use super::provider::TranscriptionProvider;
+use super::{AssemblyAiProvider, DeepgramProvider, ElevenLabsProvider};
+use crate::api::TranscriptSegment;
+use crate::audio::import::format_offset;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Sample rate of the audio the providers receive
+pub const CLOUD_SAMPLE_RATE: u32 = 16000;
+/// A pause this long starts a new segment
+const SEGMENT_GAP_SECONDS: f64 = 1.0;
+/// Segments end at the first sentence break past this length...
+const SEGMENT_SOFT_SECONDS: f64 = 12.0;
+/// ...and are cut here regardless
+const SEGMENT_MAX_SECONDS: f64 = 30.0;
+
+/// Transcript config providers that transcribe in the cloud
+const CLOUD_PROVIDERS: [(&str, &str); 3] = [
+    ("deepgram", "Deepgram"),
+    ("assemblyAi", "AssemblyAI"),
+    ("elevenLabs", "ElevenLabs"),
+];
+
+pub fn is_cloud_provider(provider: &str) -> bool {
+    CLOUD_PROVIDERS.iter().any(|(name, _)| *name == provider)
+}
+
+/// Builds the provider named in the transcript config
+pub fn cloud_provider(
+    provider: &str,
+    model: String,
+    api_key: Option<String>,
+) -> Result<Arc<dyn TranscriptionProvider>, String> {
+    let label = CLOUD_PROVIDERS
+        .iter()
+        .find(|(name, _)| *name == provider)
+        .map(|(_, label)| *label)
+        .ok_or_else(|| format!("'{}' is not a cloud transcription provider", provider))?;
+    let api_key = api_key
+        .filter(|key| !key.trim().is_empty())
+        .ok_or_else(|| format!("Add a {} API key in the transcription settings", label))?;
+    Ok(match provider {
+        "deepgram" => Arc::new(DeepgramProvider::new(api_key, model)),
+        "assemblyAi" => Arc::new(AssemblyAiProvider::new(api_key, model)),
+        _ => Arc::new(ElevenLabsProvider::new(api_key, model)),
+    })
+}
+
+/// 16kHz mono f32 samples as 16-bit PCM
+pub fn pcm16_bytes(samples: &[f32]) -> Vec<u8> {
+    samples
+        .iter()
+        .flat_map(|sample| ((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16).to_le_bytes())
+        .collect()
+}
+
+/// 16kHz mono f32 samples as a 16-bit PCM WAV file
+pub fn wav_bytes(samples: &[f32]) -> Vec<u8> {
+    let data = pcm16_bytes(samples);
+    let mut wav = Vec::with_capacity(44 + data.len());
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+    wav.extend_from_slice(&CLOUD_SAMPLE_RATE.to_le_bytes());
+    wav.extend_from_slice(&(CLOUD_SAMPLE_RATE * 2).to_le_bytes());
+    wav.extend_from_slice(&2u16.to_le_bytes());
+    wav.extend_from_slice(&16u16.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    wav.extend_from_slice(&data);
+    wav
+}
+
+/// The language setting as a provider hint; "auto" and "auto-translate"
+/// leave detection to the provider
+pub fn language_hint(language: Option<String>) -> Option<String> {
+    language
+        .map(|language| language.trim().to_string())
+        .filter(|language| !language.is_empty() && !language.starts_with("auto"))
+}
+
+/// A recognized word with its position in the recording (seconds)
+#[derive(Debug, Clone)]
+pub struct TimedWord {
+    pub text: String,
+    pub start: f64,
+    pub end: f64,
+    /// Diarization speaker index, from 0
+    pub speaker: Option<u32>,
+}
+
+/// Groups words into segments, breaking on speaker changes, pauses and
+/// sentence ends. With more than one speaker, each segment is labelled
+/// "Speaker N".
+pub fn segments_from_words(words: &[TimedWord]) -> Vec<TranscriptSegment> {
+    let mut speakers: Vec<u32> = words.iter().filter_map(|word| word.speaker).collect();
+    speakers.sort_unstable();
+    speakers.dedup();
+    let label_speakers = speakers.len() > 1;
+
+    let mut groups: Vec<Vec<&TimedWord>> = Vec::new();
+    for word in words.iter().filter(|word| !word.text.trim().is_empty()) {
+        let split = match groups
+            .last()
+            .and_then(|group| group.first().zip(group.last()))
+        {
+            Some((first, last)) => {
+                let length = word.end - first.start;
+                last.speaker != word.speaker
+                    || word.start - last.end >= SEGMENT_GAP_SECONDS
+                    || length > SEGMENT_MAX_SECONDS
+                    || (length > SEGMENT_SOFT_SECONDS && last.text.ends_with(['.', '?', '!']))
+            }
+            None => true,
+        };
+        if split {
+            groups.push(Vec::new());
+        }
+        if let Some(group) = groups.last_mut() {
+            group.push(word);
+        }
+    }
+
+    groups
+        .into_iter()
+        .filter_map(|group| {
+            let (first, last) = (group.first()?, group.last()?);
+            let text = group
+                .iter()
+                .map(|word| word.text.trim())
+                .collect::<Vec<_>>()
+                .join(" ");
+            let speaker = first
+                .speaker
+                .filter(|_| label_speakers)
+                .map(|speaker| format!("Speaker {}", speaker + 1));
+            Some(TranscriptSegment {
+                id: format!("transcript-{}", Uuid::new_v4()),
+                text,
+                timestamp: format_offset(first.start),
+                audio_start_time: Some(first.start),
+                audio_end_time: Some(last.end),
+                duration: Some(last.end - first.start),
+                speaker,
+                uncertain: false,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, start: f64, speaker: Option<u32>) -> TimedWord {
+        TimedWord {
+            text: text.to_string(),
+            start,
+            end: start + 0.4,
+            speaker,
+        }
+    }
+
+    #[test]
+    fn words_group_by_speaker_and_pause() {
+        let words = vec![
+            word("Hello", 0.0, Some(0)),
+            word("there.", 0.5, Some(0)),
+            word("Hi!", 1.0, Some(1)),
+            word("Next", 5.0, Some(1)),
+            word("topic.", 5.5, Some(1)),
+        ];
+        let segments = segments_from_words(&words);
+        let texts: Vec<&str> = segments.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, ["Hello there.", "Hi!", "Next topic."]);
+        let speakers: Vec<Option<&str>> = segments.iter().map(|s| s.speaker.as_deref()).collect();
+        assert_eq!(
+            speakers,
+            [Some("Speaker 1"), Some("Speaker 2"), Some("Speaker 2")]
+        );
+        assert_eq!(segments[2].audio_start_time, Some(5.0));
+        assert_eq!(segments[2].audio_end_time, Some(5.9));
+    }
+
+    #[test]
+    fn a_single_speaker_is_not_labelled() {
+        let words = vec![word("Just", 0.0, Some(0)), word("me", 0.5, Some(0))];
+        let segments = segments_from_words(&words);
+        assert_eq!(segments[0].text, "Just me");
+        assert_eq!(segments[0].speaker, None);
+        assert_eq!(language_hint(Some("auto-translate".to_string())), None);
+        assert_eq!(
+            language_hint(Some("ja".to_string())),
+            Some("ja".to_string())
+        );
+    }
+
+    #[test]
+    fn wav_header_describes_16k_mono_pcm() {
+        let wav = wav_bytes(&[0.0, 1.0, -1.0]);
+        assert_eq!(&wav[..4], b"RIFF");
+        assert_eq!(wav.len(), 44 + 6);
+        assert_eq!(u32::from_le_bytes(wav[24..28].try_into().unwrap()), 16000);
+        assert_eq!(i16::from_le_bytes([wav[46], wav[47]]), i16::MAX);
+    }
+}
@@ -0,0 +1,368 @@
+// audio/transcription/deepgram_provider.rs
+//
+// Deepgram cloud transcription. Live recording streams its speech chunks
+// over one websocket session (falling back to a plain request per chunk);
+// imports send the whole recording to the prerecorded API with word
+// timestamps and diarization.
+
+use super::cloud::{
+    language_hint, pcm16_bytes, segments_from_words, wav_bytes, TimedWord, CLOUD_SAMPLE_RATE,
+};
+use super::provider::{
+    ProviderCapabilities, TranscriptResult, TranscriptionError, TranscriptionProvider,
+};
+use crate::api::TranscriptSegment;
+use async_trait::async_trait;
+use futures_util::{SinkExt, StreamExt};
+use log::{info, warn};
+use serde::Deserialize;
+use std::time::{Duration, Instant};
+use tokio::net::TcpStream;
+use tokio::sync::Mutex;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+const LISTEN_URL: &str = "https://api.deepgram.com/v1/listen";
+const STREAM_URL: &str = "wss://api.deepgram.com/v1/listen";
+pub const DEFAULT_DEEPGRAM_MODEL: &str = "nova-3";
+/// Deepgram closes a stream after about 10 seconds without audio
+const STREAM_IDLE: Duration = Duration::from_secs(8);
+const STREAM_RESULT_TIMEOUT: Duration = Duration::from_secs(10);
+const CHUNK_TIMEOUT: Duration = Duration::from_secs(30);
+/// Uploading and transcribing a long recording takes a while
+const RECORDING_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+struct LiveStream {
+    socket: Socket,
+    last_used: Instant,
+}
+
+#[derive(Deserialize)]
+struct ListenResponse {
+    results: ListenResults,
+}
+
+#[derive(Deserialize)]
+struct ListenResults {
+    channels: Vec<Channel>,
+}
+
+#[derive(Deserialize)]
+struct Channel {
+    alternatives: Vec<Alternative>,
+    #[serde(default)]
+    detected_language: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Alternative {
+    transcript: String,
+    #[serde(default)]
+    confidence: Option<f32>,
+    #[serde(default)]
+    words: Vec<Word>,
+}
+
+#[derive(Deserialize)]
+struct Word {
+    word: String,
+    #[serde(default)]
+    punctuated_word: Option<String>,
+    start: f64,
+    end: f64,
+    #[serde(default)]
+    speaker: Option<u32>,
+}
+
+/// A message on the live stream; only "Results" messages are used
+#[derive(Deserialize)]
+struct StreamMessage {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    is_final: bool,
+    /// Set on the last result for audio flushed by a Finalize message
+    #[serde(default)]
+    from_finalize: bool,
+    #[serde(default)]
+    channel: Option<StreamChannel>,
+}
+
+#[derive(Deserialize)]
+struct StreamChannel {
+    alternatives: Vec<Alternative>,
+}
+
+fn failed(message: String) -> TranscriptionError {
+    TranscriptionError::EngineFailed(message)
+}
+
+pub struct DeepgramProvider {
+    api_key: String,
+    model: String,
+    http: reqwest::Client,
+    stream: Mutex<Option<LiveStream>>,
+}
+
+impl DeepgramProvider {
+    pub fn new(api_key: String, model: String) -> Self {
+        let model = match model.trim() {
+            "" => DEFAULT_DEEPGRAM_MODEL.to_string(),
+            model => model.to_string(),
+        };
+        Self {
+            api_key,
+            model,
+            http: reqwest::Client::new(),
+            stream: Mutex::new(None),
+        }
+    }
+
+    fn query(&self, language: Option<String>, detect: bool) -> Vec<(&'static str, String)> {
+        let mut query = vec![
+            ("model", self.model.clone()),
+            ("punctuate", "true".to_string()),
+            ("smart_format", "true".to_string()),
+        ];
+        match language_hint(language) {
+            Some(language) => query.push(("language", language)),
+            None if detect => query.push(("detect_language", "true".to_string())),
+            None => {}
+        }
+        query
+    }
+
+    /// Sends audio to the prerecorded API
+    async fn listen(
+        &self,
+        audio: &[f32],
+        query: &[(&'static str, String)],
+        timeout: Duration,
+    ) -> Result<Channel, TranscriptionError> {
+        let response = self
+            .http
+            .post(LISTEN_URL)
+            .query(query)
+            .header("Authorization", format!("Token {}", self.api_key))
+            .header("Content-Type", "audio/wav")
+            .timeout(timeout)
+            .body(wav_bytes(audio))
+            .send()
+            .await
+            .map_err(|e| failed(format!("Deepgram request failed: {}", e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(failed(format!(
+                "Deepgram returned {}: {}",
+                status,
+                body.trim()
+            )));
+        }
+        response
+            .json::<ListenResponse>()
+            .await
+            .map_err(|e| failed(format!("Unexpected Deepgram response: {}", e)))?
+            .results
+            .channels
+            .into_iter()
+            .next()
+            .ok_or_else(|| failed("Deepgram returned no channels".to_string()))
+    }
+
+    async fn connect(&self, language: Option<String>) -> Result<Socket, String> {
+        let mut url = reqwest::Url::parse(STREAM_URL).map_err(|e| e.to_string())?;
+        url.query_pairs_mut()
+            .extend_pairs(self.query(language, false))
+            .append_pair("encoding", "linear16")
+            .append_pair("sample_rate", &CLOUD_SAMPLE_RATE.to_string())
+            .append_pair("channels", "1");
+        let mut request = url
+            .as_str()
+            .into_client_request()
+            .map_err(|e| e.to_string())?;
+        let authorization =
+            HeaderValue::from_str(&format!("Token {}", self.api_key)).map_err(|e| e.to_string())?;
+        request.headers_mut().insert("Authorization", authorization);
+        let (socket, _) = tokio_tungstenite::connect_async(request)
+            .await
+            .map_err(|e| format!("Failed to open the Deepgram stream: {}", e))?;
+        info!("Opened a Deepgram stream ({})", self.model);
+        Ok(socket)
+    }
+
+    /// Streams one chunk and waits for its final transcript. The session is
+    /// kept for the next chunk unless it has gone quiet for too long.
+    async fn transcribe_streaming(
+        &self,
+        audio: &[f32],
+        language: Option<String>,
+    ) -> Result<TranscriptResult, String> {
+        let mut stream = self.stream.lock().await;
+        let mut live = match stream.take() {
+            Some(live) if live.last_used.elapsed() < STREAM_IDLE => live,
+            stale => {
+                if let Some(mut stale) = stale {
+                    let _ = stale.socket.close(None).await;
+                }
+                LiveStream {
+                    socket: self.connect(language).await?,
+                    last_used: Instant::now(),
+                }
+            }
+        };
+        let result = finalize_chunk(&mut live.socket, audio).await;
+        if result.is_ok() {
+            live.last_used = Instant::now();
+            *stream = Some(live);
+        }
+        result
+    }
+}
+
+/// Sends `audio` followed by a Finalize message and collects the final
+/// results up to the one answering the Finalize
+async fn finalize_chunk(socket: &mut Socket, audio: &[f32]) -> Result<TranscriptResult, String> {
+    socket
+        .send(Message::Binary(pcm16_bytes(audio)))
+        .await
+        .map_err(|e| e.to_string())?;
+    socket
+        .send(Message::Text(r#"{"type":"Finalize"}"#.to_string()))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let deadline = tokio::time::Instant::now() + STREAM_RESULT_TIMEOUT;
+    let mut texts = Vec::new();
+    let mut confidences = Vec::new();
+    loop {
+        let message = tokio::time::timeout_at(deadline, socket.next())
+            .await
+            .map_err(|_| "Timed out waiting for Deepgram".to_string())?
+            .ok_or_else(|| "Deepgram closed the stream".to_string())?
+            .map_err(|e| e.to_string())?;
+        let text = match message {
+            Message::Text(text) => text,
+            Message::Close(frame) => {
+                return Err(format!("Deepgram closed the stream: {:?}", frame))
+            }
+            _ => continue,
+        };
+        let Ok(message) = serde_json::from_str::<StreamMessage>(&text) else {
+            continue;
+        };
+        if message.kind != "Results" {
+            continue;
+        }
+        if message.is_final {
+            let alternative = message
+                .channel
+                .and_then(|channel| channel.alternatives.into_iter().next());
+            if let Some(alternative) = alternative {
+                if !alternative.transcript.trim().is_empty() {
+                    texts.push(alternative.transcript.trim().to_string());
+                    confidences.extend(alternative.confidence);
+                }
+            }
+        }
+        if message.from_finalize {
+            break;
+        }
+    }
+
+    let confidence = (!confidences.is_empty())
+        .then(|| confidences.iter().sum::<f32>() / confidences.len() as f32);
+    Ok(TranscriptResult {
+        text: texts.join(" "),
+        confidence,
+        is_partial: false,
+        uncertain: false,
+    })
+}
+
+#[async_trait]
+impl TranscriptionProvider for DeepgramProvider {
+    async fn transcribe_stream(
+        &self,
+        audio: Vec<f32>,
+        language: Option<String>,
+    ) -> std::result::Result<TranscriptResult, TranscriptionError> {
+        match self.transcribe_streaming(&audio, language.clone()).await {
+            Ok(result) => Ok(result),
+            Err(e) => {
+                warn!(
+                    "Deepgram stream failed ({}); sending the chunk as a request",
+                    e
+                );
+                let channel = self
+                    .listen(&audio, &self.query(language, false), CHUNK_TIMEOUT)
+                    .await?;
+                let alternative = channel.alternatives.into_iter().next();
+                Ok(TranscriptResult {
+                    text: alternative
+                        .as_ref()
+                        .map(|a| a.transcript.trim().to_string())
+                        .unwrap_or_default(),
+                    confidence: alternative.and_then(|a| a.confidence),
+                    is_partial: false,
+                    uncertain: false,
+                })
+            }
+        }
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            confidence: true,
+            language_hint: true,
+            batch: true,
+            diarization: true,
+            ..Default::default()
+        }
+    }
+
+    async fn is_model_loaded(&self) -> bool {
+        // Nothing to load locally
+        true
+    }
+
+    async fn get_current_model(&self) -> Option<String> {
+        Some(self.model.clone())
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "Deepgram"
+    }
+
+    async fn transcribe_batch(
+        &self,
+        audio: &[f32],
+        language: Option<String>,
+    ) -> std::result::Result<Vec<TranscriptSegment>, TranscriptionError> {
+        let mut query = self.query(language, true);
+        query.push(("diarize", "true".to_string()));
+        let channel = self.listen(audio, &query, RECORDING_TIMEOUT).await?;
+        if let Some(language) = &channel.detected_language {
+            info!("Deepgram detected language: {}", language);
+        }
+        let words: Vec<TimedWord> = channel
+            .alternatives
+            .into_iter()
+            .next()
+            .map(|alternative| alternative.words)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|word| TimedWord {
+                text: word.punctuated_word.unwrap_or(word.word),
+                start: word.start,
+                end: word.end,
+                speaker: word.speaker,
+            })
+            .collect();
+        Ok(segments_from_words(&words))
+    }
+}
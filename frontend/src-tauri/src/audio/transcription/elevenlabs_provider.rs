@@ -0,0 +1,183 @@
+// audio/transcription/elevenlabs_provider.rs
+//
+// ElevenLabs Scribe cloud transcription. Each chunk or recording is one
+// multipart request; imports ask for word timestamps and diarization.
+
+use super::cloud::{language_hint, segments_from_words, wav_bytes, TimedWord};
+use super::provider::{
+    ProviderCapabilities, TranscriptResult, TranscriptionError, TranscriptionProvider,
+};
+use crate::api::TranscriptSegment;
+use async_trait::async_trait;
+use log::info;
+use reqwest::multipart::{Form, Part};
+use serde::Deserialize;
+use std::time::Duration;
+
+const API_URL: &str = "https://api.elevenlabs.io/v1/speech-to-text";
+pub const DEFAULT_ELEVENLABS_MODEL: &str = "scribe_v1";
+const CHUNK_TIMEOUT: Duration = Duration::from_secs(30);
+const RECORDING_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+#[derive(Deserialize)]
+struct Transcript {
+    #[serde(default)]
+    language_code: Option<String>,
+    #[serde(default)]
+    text: String,
+    #[serde(default)]
+    words: Vec<Word>,
+}
+
+#[derive(Deserialize)]
+struct Word {
+    text: String,
+    #[serde(default)]
+    start: f64,
+    #[serde(default)]
+    end: f64,
+    /// "word", "spacing" or "audio_event"
+    #[serde(rename = "type", default)]
+    kind: String,
+    /// "speaker_0", "speaker_1", ...
+    #[serde(default)]
+    speaker_id: Option<String>,
+}
+
+fn failed(message: String) -> TranscriptionError {
+    TranscriptionError::EngineFailed(message)
+}
+
+fn speaker_index(speaker_id: &str) -> Option<u32> {
+    speaker_id.rsplit('_').next()?.parse().ok()
+}
+
+pub struct ElevenLabsProvider {
+    api_key: String,
+    model: String,
+    http: reqwest::Client,
+}
+
+impl ElevenLabsProvider {
+    pub fn new(api_key: String, model: String) -> Self {
+        let model = match model.trim() {
+            "" => DEFAULT_ELEVENLABS_MODEL.to_string(),
+            model => model.to_string(),
+        };
+        Self {
+            api_key,
+            model,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    async fn speech_to_text(
+        &self,
+        audio: &[f32],
+        language: Option<String>,
+        diarize: bool,
+        timeout: Duration,
+    ) -> Result<Transcript, TranscriptionError> {
+        let file = Part::bytes(wav_bytes(audio))
+            .file_name("audio.wav")
+            .mime_str("audio/wav")
+            .map_err(|e| failed(e.to_string()))?;
+        let mut form = Form::new()
+            .text("model_id", self.model.clone())
+            .text("diarize", diarize.to_string())
+            .text("timestamps_granularity", "word")
+            .part("file", file);
+        if let Some(language) = language_hint(language) {
+            form = form.text("language_code", language);
+        }
+
+        let response = self
+            .http
+            .post(API_URL)
+            .header("xi-api-key", &self.api_key)
+            .timeout(timeout)
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|e| failed(format!("ElevenLabs request failed: {}", e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(failed(format!(
+                "ElevenLabs returned {}: {}",
+                status,
+                body.trim()
+            )));
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| failed(format!("Unexpected ElevenLabs response: {}", e)))
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for ElevenLabsProvider {
+    async fn transcribe_stream(
+        &self,
+        audio: Vec<f32>,
+        language: Option<String>,
+    ) -> std::result::Result<TranscriptResult, TranscriptionError> {
+        let transcript = self
+            .speech_to_text(&audio, language, false, CHUNK_TIMEOUT)
+            .await?;
+        Ok(TranscriptResult {
+            text: transcript.text.trim().to_string(),
+            confidence: None,
+            is_partial: false,
+            uncertain: false,
+        })
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            language_hint: true,
+            batch: true,
+            diarization: true,
+            ..Default::default()
+        }
+    }
+
+    async fn is_model_loaded(&self) -> bool {
+        // Nothing to load locally
+        true
+    }
+
+    async fn get_current_model(&self) -> Option<String> {
+        Some(self.model.clone())
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "ElevenLabs Scribe"
+    }
+
+    async fn transcribe_batch(
+        &self,
+        audio: &[f32],
+        language: Option<String>,
+    ) -> std::result::Result<Vec<TranscriptSegment>, TranscriptionError> {
+        let transcript = self
+            .speech_to_text(audio, language, true, RECORDING_TIMEOUT)
+            .await?;
+        if let Some(language) = &transcript.language_code {
+            info!("ElevenLabs detected language: {}", language);
+        }
+        let words: Vec<TimedWord> = transcript
+            .words
+            .into_iter()
+            .filter(|word| word.kind == "word")
+            .map(|word| TimedWord {
+                speaker: word.speaker_id.as_deref().and_then(speaker_index),
+                text: word.text,
+                start: word.start,
+                end: word.end,
+            })
+            .collect();
+        Ok(segments_from_words(&words))
+    }
+}
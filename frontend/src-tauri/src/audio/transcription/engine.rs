@@ -2,50 +2,24 @@
 //
 // TranscriptionEngine enum and model initialization/validation logic.
 
+use super::priority::{engine_turn, Priority};
 use super::provider::TranscriptionProvider;
 use log::{info, warn};
 use std::sync::Arc;
 use tauri::{AppHandle, Manager, Runtime};
 
+// Serializes model loading so a background preload and a recording starting
+// at the same time don't both load the model
+pub(super) static MODEL_LOAD: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
 // ============================================================================
 // TRANSCRIPTION ENGINE ENUM
 // ============================================================================
 
-// Transcription engine abstraction to support multiple providers
-pub enum TranscriptionEngine {
-    Whisper(Arc<crate::whisper_engine::WhisperEngine>),  // Direct access (backward compat)
-    Parakeet(Arc<crate::parakeet_engine::ParakeetEngine>), // Direct access (backward compat)
-    Provider(Arc<dyn TranscriptionProvider>),  // Trait-based (preferred for new code)
-}
-
-impl TranscriptionEngine {
-    /// Check if the engine has a model loaded
-    pub async fn is_model_loaded(&self) -> bool {
-        match self {
-            Self::Whisper(engine) => engine.is_model_loaded().await,
-            Self::Parakeet(engine) => engine.is_model_loaded().await,
-            Self::Provider(provider) => provider.is_model_loaded().await,
-        }
-    }
-
-    /// Get the current model name
-    pub async fn get_current_model(&self) -> Option<String> {
-        match self {
-            Self::Whisper(engine) => engine.get_current_model().await,
-            Self::Parakeet(engine) => engine.get_current_model().await,
-            Self::Provider(provider) => provider.get_current_model().await,
-        }
-    }
-
-    /// Get the provider name for logging
-    pub fn provider_name(&self) -> &str {
-        match self {
-            Self::Whisper(_) => "Whisper (direct)",
-            Self::Parakeet(_) => "Parakeet (direct)",
-            Self::Provider(provider) => provider.provider_name(),
-        }
-    }
-}
+// The configured transcription provider. Every engine (Whisper, Parakeet,
+// cloud) sits behind the TranscriptionProvider trait, so the live and import
+// pipelines never need to know which one they were given.
+pub type TranscriptionEngine = Arc<dyn TranscriptionProvider>;
 
 // ============================================================================
 // MODEL VALIDATION AND INITIALIZATION
@@ -53,39 +27,32 @@ impl TranscriptionEngine {
 
 /// Validate that transcription models (Whisper or Parakeet) are ready before starting recording
 pub async fn validate_transcription_model_ready<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    let language = crate::get_language_preference_internal();
+    validate_for_language(app, language.as_deref()).await
+}
+
+/// Validates the provider and model routed for `language` (the default
+/// config when no language route matches)
+async fn validate_for_language<R: Runtime>(
+    app: &AppHandle<R>,
+    language: Option<&str>,
+) -> Result<(), String> {
+    let _loading = MODEL_LOAD.lock().await;
+
     // Check transcript configuration to determine which engine to validate
-    let config = match crate::api::api::api_get_transcript_config(
-        app.clone(),
-        app.clone().state(),
-        None,
-    )
-    .await
-    {
-        Ok(Some(config)) => {
-            info!(
-                "📝 Found transcript config - provider: {}, model: {}",
-                config.provider, config.model
-            );
-            config
-        }
-        Ok(None) => {
-            info!("📝 No transcript config found, defaulting to parakeet");
-            crate::api::api::TranscriptConfig {
-                provider: "parakeet".to_string(),
-                model: "parakeet-tdt-0.6b-v3-int8".to_string(),
-                api_key: None,
-            }
-        }
-        Err(e) => {
-            warn!("⚠️ Failed to get transcript config: {}, defaulting to parakeet", e);
-            crate::api::api::TranscriptConfig {
-                provider: "parakeet".to_string(),
-                model: "parakeet-tdt-0.6b-v3-int8".to_string(),
-                api_key: None,
-            }
-        }
-    };
+    let (config, routed) = transcript_config(app, language).await;
+
+    validate_config(app, &config).await?;
+    if routed {
+        load_routed_model(&config).await?;
+    }
+    Ok(())
+}
 
+async fn validate_config<R: Runtime>(
+    app: &AppHandle<R>,
+    config: &crate::api::api::TranscriptConfig,
+) -> Result<(), String> {
     // Validate based on provider
     match config.provider.as_str() {
         "localWhisper" => {
@@ -135,52 +102,78 @@ pub async fn validate_transcription_model_ready<R: Runtime>(app: &AppHandle<R>)
                 }
             }
         }
+        name if super::cloud::is_cloud_provider(name) => {
+            super::cloud::cloud_provider(name, config.model.clone(), config.api_key.clone())?;
+            info!("✅ {} API key is set", name);
+            Ok(())
+        }
         other => {
             warn!("❌ Unsupported transcription provider for local recording: {}", other);
             Err(format!(
-                "Provider '{}' is not supported for local transcription. Please select 'localWhisper' or 'parakeet'.",
+                "Provider '{}' is not supported for local transcription. Please select 'localWhisper', 'parakeet' or a cloud provider (deepgram, assemblyAi, elevenLabs).",
                 other
             ))
         }
     }
 }
 
+// ============================================================================
+// MODEL PRELOADING
+// ============================================================================
+
+/// Loads the configured model ahead of the first recording. Loading waits
+/// for (or is reused by) a recording that starts in the meantime.
+#[tauri::command]
+pub async fn preload_transcription_model<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    let started = std::time::Instant::now();
+    validate_transcription_model_ready(&app).await?;
+    info!("Transcription model ready after {:.1}s", started.elapsed().as_secs_f64());
+    Ok(())
+}
+
+/// Preloads the model in the background when the recording preferences ask for it
+pub async fn preload_at_startup<R: Runtime>(app: AppHandle<R>) {
+    let preload = crate::audio::recording_preferences::load_recording_preferences(&app)
+        .await
+        .map(|prefs| prefs.preload_model)
+        .unwrap_or(false);
+    if !preload {
+        return;
+    }
+    info!("Preloading the transcription model");
+    if let Err(e) = preload_transcription_model(app).await {
+        warn!("Model preload skipped: {}", e);
+    }
+}
+
 /// Get or initialize the appropriate transcription engine based on provider configuration
 pub async fn get_or_init_transcription_engine<R: Runtime>(
     app: &AppHandle<R>,
 ) -> Result<TranscriptionEngine, String> {
-    // Get provider configuration from API
-    let config = match crate::api::api::api_get_transcript_config(
-        app.clone(),
-        app.clone().state(),
-        None,
-    )
-    .await
-    {
-        Ok(Some(config)) => {
-            info!(
-                "📝 Transcript config - provider: {}, model: {}",
-                config.provider, config.model
-            );
-            config
-        }
-        Ok(None) => {
-            info!("📝 No transcript config found, defaulting to parakeet");
-            crate::api::api::TranscriptConfig {
-                provider: "parakeet".to_string(),
-                model: "parakeet-tdt-0.6b-v3-int8".to_string(),
-                api_key: None,
-            }
-        }
-        Err(e) => {
-            warn!("⚠️ Failed to get transcript config: {}, defaulting to parakeet", e);
-            crate::api::api::TranscriptConfig {
-                provider: "parakeet".to_string(),
-                model: "parakeet-tdt-0.6b-v3-int8".to_string(),
-                api_key: None,
-            }
+    let language = crate::get_language_preference_internal();
+    get_or_init_transcription_engine_for(app, language.as_deref()).await
+}
+
+/// Get or initialize the engine for a meeting in `language`, following the
+/// user's language routes
+pub async fn get_or_init_transcription_engine_for<R: Runtime>(
+    app: &AppHandle<R>,
+    language: Option<&str>,
+) -> Result<TranscriptionEngine, String> {
+    let (config, routed) = transcript_config(app, language).await;
+    info!(
+        "📝 Transcript config - provider: {}, model: {}",
+        config.provider, config.model
+    );
+    if routed {
+        // The shared local engines default to the saved model; load the
+        // routed one instead
+        let _loading = MODEL_LOAD.lock().await;
+        validate_config(app, &config).await?;
+        if let Some(provider) = load_routed_model(&config).await? {
+            return Ok(provider);
         }
-    };
+    }
 
     // Initialize the appropriate engine based on provider
     match config.provider.as_str() {
@@ -202,7 +195,10 @@ pub async fn get_or_init_transcription_engine<R: Runtime>(
                         let model_name = engine.get_current_model().await
                             .unwrap_or_else(|| "unknown".to_string());
                         info!("✅ Parakeet model '{}' already loaded", model_name);
-                        Ok(TranscriptionEngine::Parakeet(engine))
+                        Ok(Arc::new(super::ParakeetProvider::with_model(
+                            engine,
+                            &model_name,
+                        )))
                     } else {
                         Err("Parakeet engine initialized but no model loaded. This should not happen after validation.".to_string())
                     }
@@ -212,12 +208,152 @@ pub async fn get_or_init_transcription_engine<R: Runtime>(
                 }
             }
         }
+        name if super::cloud::is_cloud_provider(name) => {
+            let provider = super::cloud::cloud_provider(name, config.model, config.api_key)?;
+            info!(
+                "☁️ Using {} transcription ({})",
+                provider.provider_name(),
+                provider.get_current_model().await.unwrap_or_default()
+            );
+            Ok(provider)
+        }
         "localWhisper" | _ => {
             info!("🎤 Initializing Whisper transcription engine");
             let whisper_engine = get_or_init_whisper(app).await?;
-            Ok(TranscriptionEngine::Whisper(whisper_engine))
+            Ok(match whisper_engine.get_current_model().await {
+                Some(model) => Arc::new(super::WhisperProvider::with_model(whisper_engine, &model)),
+                None => Arc::new(super::WhisperProvider::new(whisper_engine)),
+            })
+        }
+    }
+}
+
+/// The engine for an explicitly chosen provider, e.g. to re-run one segment
+/// with a bigger model. Without a model, the saved one is used when the
+/// provider matches the saved config, else the provider's default.
+pub async fn get_or_init_engine_with<R: Runtime>(
+    app: &AppHandle<R>,
+    provider: &str,
+    model: Option<&str>,
+) -> Result<TranscriptionEngine, String> {
+    let (saved, _) = transcript_config(app, None).await;
+    let model = match model.map(str::trim).filter(|model| !model.is_empty()) {
+        Some(model) => model.to_string(),
+        None if saved.provider == provider => saved.model.clone(),
+        None if provider == "parakeet" => crate::audio::import::DEFAULT_PARAKEET_MODEL.to_string(),
+        None if provider == "localWhisper" => {
+            return Err("Choose the Whisper model to transcribe with".to_string())
+        }
+        None => String::new(),
+    };
+    let api_key = if saved.provider == provider {
+        saved.api_key
+    } else {
+        match app.try_state::<crate::state::AppState>() {
+            Some(state) => state
+                .settings_cache
+                .transcript_api_key(state.db_manager.pool(), provider)
+                .await
+                .map_err(|e| format!("Failed to read the {} API key: {}", provider, e))?,
+            None => None,
+        }
+    };
+    let config = crate::api::api::TranscriptConfig {
+        provider: provider.to_string(),
+        model,
+        api_key,
+    };
+    info!("📝 Using {} ({}) on request", config.provider, config.model);
+
+    let _loading = MODEL_LOAD.lock().await;
+    validate_config(app, &config).await?;
+    if let Some(provider) = load_routed_model(&config).await? {
+        return Ok(provider);
+    }
+    super::cloud::cloud_provider(&config.provider, config.model, config.api_key)
+}
+
+/// The saved transcript config (parakeet when there is none), with the
+/// language route for `language` applied. The flag is set when a route
+/// replaced the saved provider or model.
+async fn transcript_config<R: Runtime>(
+    app: &AppHandle<R>,
+    language: Option<&str>,
+) -> (crate::api::api::TranscriptConfig, bool) {
+    let config = match crate::api::api::api_get_transcript_config(
+        app.clone(),
+        app.clone().state(),
+        None,
+    )
+    .await
+    {
+        Ok(Some(config)) => config,
+        Ok(None) => {
+            info!("📝 No transcript config found, defaulting to parakeet");
+            crate::api::api::TranscriptConfig {
+                provider: "parakeet".to_string(),
+                model: "parakeet-tdt-0.6b-v3-int8".to_string(),
+                api_key: None,
+            }
         }
+        Err(e) => {
+            warn!("⚠️ Failed to get transcript config: {}, defaulting to parakeet", e);
+            crate::api::api::TranscriptConfig {
+                provider: "parakeet".to_string(),
+                model: "parakeet-tdt-0.6b-v3-int8".to_string(),
+                api_key: None,
+            }
+        }
+    };
+    super::routing::route_config(app, config, language).await
+}
+
+/// Loads a routed local model into the shared engine. Cloud providers have
+/// nothing to load (None). Callers hold `MODEL_LOAD`.
+///
+/// The load waits for a live turn so it never swaps the model under a chunk
+/// being transcribed. Every local provider is pinned to its model and swaps
+/// back under its own turn, so live transcription gets the saved model again
+/// on its next chunk.
+async fn load_routed_model(
+    config: &crate::api::api::TranscriptConfig,
+) -> Result<Option<TranscriptionEngine>, String> {
+    if config.model.trim().is_empty() {
+        return Ok(None);
     }
+    let provider: TranscriptionEngine = match config.provider.as_str() {
+        "localWhisper" => {
+            let engine = crate::whisper_engine::commands::WHISPER_ENGINE
+                .lock()
+                .unwrap()
+                .as_ref()
+                .cloned()
+                .ok_or("Whisper engine not initialized")?;
+            engine
+                .discover_models()
+                .await
+                .map_err(|e| format!("Failed to discover models: {}", e))?;
+            Arc::new(super::WhisperProvider::with_model(engine, &config.model))
+        }
+        "parakeet" => {
+            let engine = crate::parakeet_engine::commands::PARAKEET_ENGINE
+                .lock()
+                .unwrap()
+                .as_ref()
+                .cloned()
+                .ok_or("Parakeet engine not initialized")?;
+            engine
+                .discover_models()
+                .await
+                .map_err(|e| format!("Failed to discover Parakeet models: {}", e))?;
+            Arc::new(super::ParakeetProvider::with_model(engine, &config.model))
+        }
+        _ => return Ok(None),
+    };
+    let _turn = engine_turn(Priority::Live).await;
+    provider.load().await.map_err(|e| e.to_string())?;
+    info!("✅ Routed model '{}' is loaded", config.model);
+    Ok(Some(provider))
 }
 
 /// Get or initialize transcription engine using API configuration
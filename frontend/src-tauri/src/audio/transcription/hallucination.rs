@@ -0,0 +1,249 @@
+// audio/transcription/hallucination.rs
+//
+// Post-filter for Whisper segments. During silence and noise Whisper tends
+// to loop on a phrase or produce the sign-offs it saw in video subtitles
+// ("thanks for watching", "ご視聴ありがとうございました"). Each segment is
+// checked for those phrases, for repetition loops and for low-confidence
+// text over audio that is near-silent or that Whisper itself judged to hold
+// no speech; clear hallucinations are dropped before they reach the
+// transcript and doubtful ones are kept but flagged as uncertain.
+
+/// Confidence reported for flagged segments: the lowest the live pipeline
+/// still accepts, so they are kept but rank below everything else
+pub const FLAGGED_CONFIDENCE: f32 = 0.3;
+/// Mean token probability below which a segment is dropped outright...
+const DROP_TOKEN_PROBABILITY: f32 = 0.2;
+/// ...and below which it is doubtful
+const DOUBTFUL_TOKEN_PROBABILITY: f32 = 0.4;
+/// RMS under which the audio is treated as (near) silence
+const QUIET_RMS: f32 = 0.01;
+/// Whisper's no-speech probability above which a segment's audio is
+/// treated as silence
+const NO_SPEECH_PROBABILITY: f32 = 0.6;
+/// Longest repeated unit looked for, in characters
+const MAX_LOOP_UNIT: usize = 32;
+/// Share of a segment a loop may cover before the whole segment is dropped
+const LOOP_DOMINANCE: f32 = 0.8;
+
+/// Phrases Whisper produces from silence; compared without punctuation or
+/// spaces, in lowercase
+const KNOWN_PHRASES: [&str; 14] = [
+    "thankyouforwatching",
+    "thanksforwatching",
+    "thankyousomuchforwatching",
+    "pleasesubscribe",
+    "likeandsubscribe",
+    "subtitlesbytheamaraorgcommunity",
+    "seeyouinthenextvideo",
+    "ご視聴ありがとうございました",
+    "ご視聴ありがとうございます",
+    "最後までご視聴いただきありがとうございました",
+    "チャンネル登録よろしくお願いします",
+    "チャンネル登録お願いします",
+    "字幕視聴ありがとうございました",
+    "请不吝点赞订阅转发打赏支持明镜与点点栏目",
+];
+
+/// What the engine knows about a segment besides its text
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SegmentSignals {
+    /// Mean probability of the segment's text tokens
+    pub token_probability: Option<f32>,
+    /// Whisper's probability that the segment's audio holds no speech
+    pub no_speech_probability: Option<f32>,
+    /// RMS of the audio the segment came from
+    pub audio_rms: Option<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    /// The text to keep (repetition loops collapsed)
+    Keep(String),
+    /// Kept, but probably not what was said
+    Flag(String, &'static str),
+    Drop(&'static str),
+}
+
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn is_known_phrase(text: &str) -> bool {
+    let normalized = normalize(text);
+    !normalized.is_empty()
+        && KNOWN_PHRASES.iter().any(|phrase| {
+            // The phrase, possibly with a word or two around it
+            normalized.contains(phrase) && normalized.chars().count() <= phrase.chars().count() + 6
+        })
+}
+
+/// Collapses runs of a unit repeated back to back ("okay okay okay okay",
+/// "はいはいはいはい") to a single occurrence. Returns the collapsed text and
+/// how many characters the loops covered.
+fn collapse_loops(text: &str) -> (String, usize) {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::new();
+    let mut covered = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        let mut collapsed = false;
+        for unit in 1..=MAX_LOOP_UNIT.min((chars.len() - i) / 2) {
+            // Single characters repeat legitimately ("ーー", "!!")
+            let min_repeats = if unit == 1 { 8 } else { 4 };
+            let pattern = &chars[i..i + unit];
+            if pattern.iter().all(|c| c.is_whitespace()) {
+                continue;
+            }
+            let mut repeats = 1;
+            while i + (repeats + 1) * unit <= chars.len()
+                && &chars[i + repeats * unit..i + (repeats + 1) * unit] == pattern
+            {
+                repeats += 1;
+            }
+            if repeats >= min_repeats {
+                out.extend(pattern);
+                covered += repeats * unit;
+                i += repeats * unit;
+                collapsed = true;
+                break;
+            }
+        }
+        if !collapsed {
+            out.push(chars[i]);
+            i += 1;
+        }
+    }
+    (out, covered)
+}
+
+/// Checks one Whisper segment
+pub fn check(text: &str, signals: &SegmentSignals) -> Verdict {
+    let text = text.trim();
+    if normalize(text).is_empty() {
+        return Verdict::Drop("no words");
+    }
+    if is_known_phrase(text) {
+        return Verdict::Drop("known hallucination phrase");
+    }
+
+    let (collapsed, covered) = collapse_loops(text);
+    let loop_share = covered as f32 / text.chars().count() as f32;
+    if loop_share >= LOOP_DOMINANCE {
+        return Verdict::Drop("repetition loop");
+    }
+    let collapsed = collapsed.trim().to_string();
+    if is_known_phrase(&collapsed) {
+        return Verdict::Drop("known hallucination phrase");
+    }
+
+    let no_speech = signals
+        .no_speech_probability
+        .is_some_and(|p| p > NO_SPEECH_PROBABILITY);
+    let quiet = no_speech || signals.audio_rms.is_some_and(|rms| rms < QUIET_RMS);
+    match signals.token_probability {
+        Some(p) if p < DROP_TOKEN_PROBABILITY => Verdict::Drop("low token probability"),
+        Some(p) if p < DOUBTFUL_TOKEN_PROBABILITY && quiet => {
+            Verdict::Drop("uncertain text over silence")
+        }
+        Some(p) if p < DOUBTFUL_TOKEN_PROBABILITY => Verdict::Flag(collapsed, "uncertain text"),
+        _ if covered > 0 => Verdict::Flag(collapsed, "repetition loop"),
+        _ if no_speech => Verdict::Flag(collapsed, "text over no speech"),
+        _ => Verdict::Keep(collapsed),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn confident() -> SegmentSignals {
+        SegmentSignals {
+            token_probability: Some(0.9),
+            no_speech_probability: Some(0.05),
+            audio_rms: Some(0.1),
+        }
+    }
+
+    #[test]
+    fn known_phrases_are_dropped() {
+        for text in [
+            "Thanks for watching!",
+            "ご視聴ありがとうございました。",
+            " Thank you for watching. ",
+        ] {
+            assert!(
+                matches!(check(text, &confident()), Verdict::Drop(_)),
+                "{}",
+                text
+            );
+        }
+        assert_eq!(
+            check(
+                "We should thank everyone for watching the demo",
+                &confident()
+            ),
+            Verdict::Keep("We should thank everyone for watching the demo".to_string())
+        );
+    }
+
+    #[test]
+    fn repetition_loops_are_collapsed_or_dropped() {
+        assert_eq!(
+            check(
+                "ありがとう。ありがとう。ありがとう。ありがとう。ありがとう。",
+                &confident()
+            ),
+            Verdict::Drop("repetition loop")
+        );
+        assert_eq!(
+            check("The budget is fine. okay okay okay okay", &confident()),
+            Verdict::Flag("The budget is fine. okay".to_string(), "repetition loop")
+        );
+        assert_eq!(
+            check("ええ、そうですね", &confident()),
+            Verdict::Keep("ええ、そうですね".to_string())
+        );
+    }
+
+    #[test]
+    fn uncertain_text_over_silence_is_dropped() {
+        let quiet = SegmentSignals {
+            token_probability: Some(0.3),
+            no_speech_probability: None,
+            audio_rms: Some(0.001),
+        };
+        let loud = SegmentSignals {
+            audio_rms: Some(0.2),
+            ..quiet
+        };
+        assert!(matches!(check("Hello there", &quiet), Verdict::Drop(_)));
+        assert!(matches!(check("Hello there", &loud), Verdict::Flag(..)));
+        assert_eq!(
+            check("Hello there", &SegmentSignals::default()),
+            Verdict::Keep("Hello there".to_string())
+        );
+    }
+
+    #[test]
+    fn text_whisper_judged_to_be_no_speech_is_dropped_or_flagged() {
+        let no_speech = SegmentSignals {
+            token_probability: Some(0.3),
+            no_speech_probability: Some(0.9),
+            // Loud noise, so only the no-speech probability tells
+            audio_rms: Some(0.2),
+        };
+        assert!(matches!(check("Hello there", &no_speech), Verdict::Drop(_)));
+
+        let confident_text = SegmentSignals {
+            token_probability: Some(0.9),
+            ..no_speech
+        };
+        assert_eq!(
+            check("Hello there", &confident_text),
+            Verdict::Flag("Hello there".to_string(), "text over no speech")
+        );
+    }
+}
@@ -0,0 +1,104 @@
+// audio/transcription/idle.rs
+//
+// Unloads the Whisper/Parakeet model after a stretch without transcription
+// to give its memory back. The next recording or import loads it again
+// through validate_transcription_model_ready.
+
+use log::{info, warn};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Runtime};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+static LAST_USED: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Records model activity; called when a model is loaded or transcribes
+pub fn mark_used() {
+    *LAST_USED.lock().unwrap_or_else(|e| e.into_inner()) = Some(Instant::now());
+}
+
+fn idle_for() -> Option<Duration> {
+    LAST_USED
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .map(|used| used.elapsed())
+}
+
+/// Whether a model idle for `idle` should go; a timeout of 0 keeps it loaded
+pub fn should_unload(idle: Option<Duration>, timeout_minutes: u32) -> bool {
+    timeout_minutes > 0
+        && idle.is_some_and(|idle| idle >= Duration::from_secs(u64::from(timeout_minutes) * 60))
+}
+
+/// Unloads both engines' models; returns whether anything was loaded
+pub async fn unload_models() -> bool {
+    let whisper = crate::whisper_engine::commands::WHISPER_ENGINE
+        .lock()
+        .unwrap()
+        .as_ref()
+        .cloned();
+    let parakeet = crate::parakeet_engine::commands::PARAKEET_ENGINE
+        .lock()
+        .unwrap()
+        .as_ref()
+        .cloned();
+
+    let mut unloaded = false;
+    if let Some(engine) = whisper {
+        unloaded |= engine.unload_model().await;
+    }
+    if let Some(engine) = parakeet {
+        unloaded |= engine.unload_model().await;
+    }
+    unloaded
+}
+
+/// Checks once a minute whether the loaded model has been idle longer than
+/// the `model_idle_unload_minutes` recording preference
+pub async fn run_idle_unload<R: Runtime>(app: AppHandle<R>) {
+    loop {
+        tokio::time::sleep(CHECK_INTERVAL).await;
+        let timeout_minutes =
+            match crate::audio::recording_preferences::load_recording_preferences(&app).await {
+                Ok(prefs) => prefs.model_idle_unload_minutes,
+                Err(e) => {
+                    warn!("Idle unload: failed to load recording preferences: {}", e);
+                    continue;
+                }
+            };
+        if !should_unload(idle_for(), timeout_minutes)
+            || crate::audio::recording_commands::is_recording().await
+        {
+            continue;
+        }
+
+        // Hold the load lock so a recording starting now waits and reloads
+        let _loading = super::engine::MODEL_LOAD.lock().await;
+        if !should_unload(idle_for(), timeout_minutes) {
+            continue;
+        }
+        if unload_models().await {
+            info!(
+                "Unloaded the transcription model after {} idle minutes",
+                timeout_minutes
+            );
+        }
+        *LAST_USED.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unloads_only_after_the_timeout() {
+        let minutes = |m: u64| Some(Duration::from_secs(m * 60));
+        assert!(should_unload(minutes(15), 15));
+        assert!(!should_unload(minutes(14), 15));
+        assert!(!should_unload(minutes(600), 0));
+        // Never used since the last unload
+        assert!(!should_unload(None, 15));
+    }
+}
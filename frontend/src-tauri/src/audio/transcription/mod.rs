@@ -5,18 +5,32 @@
 pub mod provider;
 pub mod whisper_provider;
 pub mod parakeet_provider;
+pub mod cloud;
+pub mod deepgram_provider;
+pub mod assemblyai_provider;
+pub mod elevenlabs_provider;
 pub mod engine;
+pub mod hallucination;
+pub mod idle;
+pub mod priority;
+pub mod routing;
 pub mod worker;
 
 // Re-export commonly used types
-pub use provider::{TranscriptionError, TranscriptionProvider, TranscriptResult};
+pub use provider::{ProviderCapabilities, TranscriptionError, TranscriptionProvider, TranscriptResult};
 pub use whisper_provider::WhisperProvider;
 pub use parakeet_provider::ParakeetProvider;
+pub use deepgram_provider::DeepgramProvider;
+pub use assemblyai_provider::AssemblyAiProvider;
+pub use elevenlabs_provider::ElevenLabsProvider;
 pub use engine::{
     TranscriptionEngine,
     validate_transcription_model_ready,
     get_or_init_transcription_engine,
-    get_or_init_whisper
+    get_or_init_transcription_engine_for,
+    get_or_init_whisper,
+    preload_at_startup,
+    preload_transcription_model
 };
 pub use worker::{
     start_transcription_task,
@@ -2,7 +2,9 @@
 //
 // Parakeet transcription provider implementation.
 
-use super::provider::{TranscriptionError, TranscriptionProvider, TranscriptResult};
+use super::provider::{
+    ProviderCapabilities, TranscriptionError, TranscriptionProvider, TranscriptResult,
+};
 use async_trait::async_trait;
 use log::warn;
 use std::sync::Arc;
@@ -10,17 +12,57 @@ use std::sync::Arc;
 /// Parakeet transcription provider (wraps ParakeetEngine)
 pub struct ParakeetProvider {
     engine: Arc<crate::parakeet_engine::ParakeetEngine>,
+    /// Model `load` makes sure of; None uses whatever the engine has loaded
+    model: Option<String>,
 }
 
 impl ParakeetProvider {
     pub fn new(engine: Arc<crate::parakeet_engine::ParakeetEngine>) -> Self {
-        Self { engine }
+        Self {
+            engine,
+            model: None,
+        }
+    }
+
+    pub fn with_model(engine: Arc<crate::parakeet_engine::ParakeetEngine>, model: &str) -> Self {
+        Self {
+            engine,
+            model: Some(model.to_string()),
+        }
+    }
+
+    /// Swaps the engine to this provider's model if another provider left a
+    /// different one loaded. Callers hold the engine turn, so the swap never
+    /// happens under someone else's transcription.
+    async fn ensure_model(&self) -> std::result::Result<(), TranscriptionError> {
+        let Some(model) = &self.model else {
+            return Ok(());
+        };
+        if self.engine.get_current_model().await.as_deref() == Some(model.as_str()) {
+            return Ok(());
+        }
+        self.engine.load_model(model).await.map_err(|e| {
+            TranscriptionError::EngineFailed(format!(
+                "Failed to load Parakeet model '{}': {}",
+                model, e
+            ))
+        })
     }
 }
 
 #[async_trait]
 impl TranscriptionProvider for ParakeetProvider {
-    async fn transcribe(
+    async fn load(&self) -> std::result::Result<(), TranscriptionError> {
+        if self.model.is_some() {
+            self.ensure_model().await
+        } else if self.engine.is_model_loaded().await {
+            Ok(())
+        } else {
+            Err(TranscriptionError::ModelNotLoaded)
+        }
+    }
+
+    async fn transcribe_stream(
         &self,
         audio: Vec<f32>,
         language: Option<String>,
@@ -33,16 +75,25 @@ impl TranscriptionProvider for ParakeetProvider {
             );
         }
 
+        self.ensure_model().await?;
         match self.engine.transcribe_audio(audio).await {
             Ok(text) => Ok(TranscriptResult {
                 text: text.trim().to_string(),
                 confidence: None, // Parakeet doesn't provide confidence scores
                 is_partial: false, // Parakeet doesn't provide partial results
+                uncertain: false,
             }),
             Err(e) => Err(TranscriptionError::EngineFailed(e.to_string())),
         }
     }
 
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            local: true,
+            ..Default::default()
+        }
+    }
+
     async fn is_model_loaded(&self) -> bool {
         self.engine.is_model_loaded().await
     }
@@ -0,0 +1,87 @@
+// audio/transcription/priority.rs
+//
+// One shared transcription engine, taken in turns. A live recording and a
+// background import can transcribe at the same time without loading the
+// model twice: each call takes a turn on the engine, and background work
+// steps aside whenever a live chunk is waiting. A live chunk waits at most
+// for the background window already in progress.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::sync::{Mutex, MutexGuard, Notify};
+
+static ENGINE: Mutex<()> = Mutex::const_new(());
+static LIVE_WAITING: AtomicUsize = AtomicUsize::new(0);
+static LIVE_SERVED: Notify = Notify::const_new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    /// Chunks of the recording in progress
+    Live,
+    /// Imports and re-transcription
+    Background,
+}
+
+/// The engine is yours until this is dropped
+pub struct EngineTurn {
+    _guard: MutexGuard<'static, ()>,
+}
+
+pub async fn engine_turn(priority: Priority) -> EngineTurn {
+    match priority {
+        Priority::Live => {
+            LIVE_WAITING.fetch_add(1, Ordering::SeqCst);
+            let guard = ENGINE.lock().await;
+            if LIVE_WAITING.fetch_sub(1, Ordering::SeqCst) == 1 {
+                LIVE_SERVED.notify_waiters();
+            }
+            EngineTurn { _guard: guard }
+        }
+        Priority::Background => loop {
+            // Created before the check so a wakeup in between isn't lost
+            let served = LIVE_SERVED.notified();
+            if LIVE_WAITING.load(Ordering::SeqCst) > 0 {
+                served.await;
+                continue;
+            }
+            let guard = ENGINE.lock().await;
+            if LIVE_WAITING.load(Ordering::SeqCst) == 0 {
+                return EngineTurn { _guard: guard };
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn live_chunks_go_before_waiting_background_work() {
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let first = engine_turn(Priority::Background).await;
+
+        let background = {
+            let order = order.clone();
+            tokio::spawn(async move {
+                let _turn = engine_turn(Priority::Background).await;
+                order.lock().unwrap().push("background");
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let live = {
+            let order = order.clone();
+            tokio::spawn(async move {
+                let _turn = engine_turn(Priority::Live).await;
+                order.lock().unwrap().push("live");
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        drop(first);
+        background.await.unwrap();
+        live.await.unwrap();
+        assert_eq!(*order.lock().unwrap(), ["live", "background"]);
+    }
+}
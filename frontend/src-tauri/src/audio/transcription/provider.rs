@@ -3,6 +3,7 @@
 // Defines the unified TranscriptionProvider trait and common types for all
 // transcription engines (Whisper, Parakeet, future providers).
 
+use crate::api::TranscriptSegment;
 use async_trait::async_trait;
 
 // ============================================================================
@@ -43,12 +44,38 @@ pub struct TranscriptResult {
     pub text: String,
     pub confidence: Option<f32>, // None if provider doesn't support confidence scores
     pub is_partial: bool,
+    /// Kept, but flagged as possibly not what was said (a likely hallucination)
+    pub uncertain: bool,
 }
 
-/// Trait for transcription providers (Whisper, Parakeet, future providers)
+/// What a provider supports. The pipelines read this instead of checking
+/// which engine they were given.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProviderCapabilities {
+    /// Runs on this machine with a downloaded model
+    pub local: bool,
+    /// Streamed chunks come back with a confidence score
+    pub confidence: bool,
+    /// Honours a language hint
+    pub language_hint: bool,
+    /// Can identify the spoken language (`detect_language`)
+    pub language_detection: bool,
+    /// Transcribes a whole recording in one call (`transcribe_batch`)
+    pub batch: bool,
+    /// Batch transcripts label their speakers
+    pub diarization: bool,
+}
+
+/// Trait for transcription providers (Whisper, Parakeet, cloud providers)
 #[async_trait]
 pub trait TranscriptionProvider: Send + Sync {
-    /// Transcribe audio samples to text
+    /// Makes the provider ready to transcribe, loading its model if needed.
+    /// Cloud providers have nothing to load.
+    async fn load(&self) -> std::result::Result<(), TranscriptionError> {
+        Ok(())
+    }
+
+    /// Transcribe one chunk of a live recording (or one window of an import)
     ///
     /// # Arguments
     /// * `audio` - Audio samples (16kHz mono, f32 format)
@@ -56,12 +83,15 @@ pub trait TranscriptionProvider: Send + Sync {
     ///
     /// # Returns
     /// * `TranscriptResult` with text, optional confidence, and partial flag
-    async fn transcribe(
+    async fn transcribe_stream(
         &self,
         audio: Vec<f32>,
         language: Option<String>,
     ) -> std::result::Result<TranscriptResult, TranscriptionError>;
 
+    /// What this provider supports
+    fn capabilities(&self) -> ProviderCapabilities;
+
     /// Check if a model is currently loaded
     async fn is_model_loaded(&self) -> bool;
 
@@ -70,4 +100,25 @@ pub trait TranscriptionProvider: Send + Sync {
 
     /// Get the provider name (for logging/debugging)
     fn provider_name(&self) -> &'static str;
+
+    /// Detect the spoken language of audio samples (16kHz mono). None when
+    /// the provider can't identify languages.
+    async fn detect_language(&self, _audio: &[f32]) -> Option<String> {
+        None
+    }
+
+    /// Transcribes a whole recording (16kHz mono) in one call, with
+    /// timestamps and speaker labels where the provider has them. Only
+    /// called when `capabilities().batch` is set; other providers are fed
+    /// window by window through `transcribe_stream`.
+    async fn transcribe_batch(
+        &self,
+        _audio: &[f32],
+        _language: Option<String>,
+    ) -> std::result::Result<Vec<TranscriptSegment>, TranscriptionError> {
+        Err(TranscriptionError::EngineFailed(format!(
+            "{} does not transcribe whole recordings",
+            self.provider_name()
+        )))
+    }
 }
@@ -0,0 +1,210 @@
+// audio/transcription/routing.rs
+//
+// Per-language model routing. Users map languages to a provider and model
+// (e.g. Japanese -> Whisper large-v3, English -> Parakeet); a recording uses
+// the route for its selected language and an import the route for the
+// language detected in its first 30 seconds. Languages without a route use the
+// default transcript config.
+
+use super::engine::{get_or_init_transcription_engine_for, TranscriptionEngine};
+use super::priority::{engine_turn, Priority};
+use crate::api::api::TranscriptConfig;
+use crate::state::AppState;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, Runtime};
+
+/// How much of an import is used to detect its language
+const DETECTION_SECONDS: usize = 30;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LanguageRoute {
+    /// Language code as the language setting uses it ("ja", "en", ...)
+    pub language: String,
+    pub provider: String,
+    pub model: String,
+}
+
+/// "ja-JP" and "JA" both route as "ja"
+fn primary_language(code: &str) -> String {
+    code.trim()
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+/// The route for `language`; "auto" (and no language) has none
+pub fn route_for<'a>(
+    routes: &'a [LanguageRoute],
+    language: Option<&str>,
+) -> Option<&'a LanguageRoute> {
+    let language = primary_language(language?);
+    if language.is_empty() || language.starts_with("auto") {
+        return None;
+    }
+    routes
+        .iter()
+        .find(|route| primary_language(&route.language) == language)
+}
+
+/// Checks routes before they are saved: every route needs a language and a
+/// known provider, and a language may only be routed once (the last wins)
+pub fn normalize_routes(routes: Vec<LanguageRoute>) -> Result<Vec<LanguageRoute>, String> {
+    let mut normalized: Vec<LanguageRoute> = Vec::new();
+    for route in routes {
+        let language = primary_language(&route.language);
+        if language.is_empty() || language.starts_with("auto") {
+            return Err("Each language route needs a specific language".to_string());
+        }
+        let provider = route.provider.trim().to_string();
+        if !matches!(provider.as_str(), "localWhisper" | "parakeet")
+            && !super::cloud::is_cloud_provider(&provider)
+        {
+            return Err(format!(
+                "'{}' can't be used for language routing",
+                route.provider
+            ));
+        }
+        normalized.retain(|existing| existing.language != language);
+        normalized.push(LanguageRoute {
+            language,
+            provider,
+            model: route.model.trim().to_string(),
+        });
+    }
+    Ok(normalized)
+}
+
+/// Applies the route for `language` to the default config. Returns the
+/// config to use and whether a route picked it.
+pub async fn route_config<R: Runtime>(
+    app: &AppHandle<R>,
+    config: TranscriptConfig,
+    language: Option<&str>,
+) -> (TranscriptConfig, bool) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return (config, false);
+    };
+    let pool = state.db_manager.pool();
+    let routes = match state.settings_cache.transcript_config(pool).await {
+        Ok(Some(setting)) => setting.get_language_routes(),
+        Ok(None) => return (config, false),
+        Err(e) => {
+            warn!("Failed to read language routes: {}", e);
+            return (config, false);
+        }
+    };
+    let Some(route) = route_for(&routes, language) else {
+        return (config, false);
+    };
+    if route.provider == config.provider && route.model == config.model {
+        return (config, false);
+    }
+    let api_key = match state
+        .settings_cache
+        .transcript_api_key(pool, &route.provider)
+        .await
+    {
+        Ok(api_key) => api_key,
+        Err(e) => {
+            warn!("Failed to read the {} API key: {}", route.provider, e);
+            None
+        }
+    };
+    info!(
+        "🌐 Routing language '{}' to {} ({})",
+        route.language, route.provider, route.model
+    );
+    (
+        TranscriptConfig {
+            provider: route.provider.clone(),
+            model: route.model.clone(),
+            api_key,
+        },
+        true,
+    )
+}
+
+/// The engine for a decoded import (16kHz mono). A configured language picks
+/// its route directly; with "auto", the default engine listens to the opening
+/// of the recording and the detected language picks the route.
+pub async fn engine_for_samples<R: Runtime>(
+    app: &AppHandle<R>,
+    samples: &[f32],
+) -> Result<TranscriptionEngine, String> {
+    let configured =
+        crate::get_language_preference_internal().filter(|language| !language.starts_with("auto"));
+    if configured.is_some() {
+        return get_or_init_transcription_engine_for(app, configured.as_deref()).await;
+    }
+
+    let engine = get_or_init_transcription_engine_for(app, None).await?;
+    if !has_routes(app).await || !engine.capabilities().language_detection {
+        return Ok(engine);
+    }
+    let opening = &samples[..samples.len().min(DETECTION_SECONDS * 16000)];
+    let turn = engine_turn(Priority::Background).await;
+    let detected = engine.detect_language(opening).await;
+    drop(turn);
+    match detected {
+        Some(language) => {
+            info!("Detected language '{}' for routing", language);
+            get_or_init_transcription_engine_for(app, Some(&language)).await
+        }
+        None => Ok(engine),
+    }
+}
+
+async fn has_routes<R: Runtime>(app: &AppHandle<R>) -> bool {
+    let Some(state) = app.try_state::<AppState>() else {
+        return false;
+    };
+    match state
+        .settings_cache
+        .transcript_config(state.db_manager.pool())
+        .await
+    {
+        Ok(Some(setting)) => !setting.get_language_routes().is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(language: &str, provider: &str, model: &str) -> LanguageRoute {
+        LanguageRoute {
+            language: language.to_string(),
+            provider: provider.to_string(),
+            model: model.to_string(),
+        }
+    }
+
+    #[test]
+    fn routes_match_on_the_primary_language() {
+        let routes = vec![
+            route("ja", "localWhisper", "large-v3"),
+            route("en", "parakeet", "parakeet-tdt-0.6b-v3-int8"),
+        ];
+        assert_eq!(route_for(&routes, Some("ja-JP")).unwrap().model, "large-v3");
+        assert_eq!(route_for(&routes, Some("EN")).unwrap().provider, "parakeet");
+        assert!(route_for(&routes, Some("fr")).is_none());
+        assert!(route_for(&routes, Some("auto")).is_none());
+        assert!(route_for(&routes, None).is_none());
+    }
+
+    #[test]
+    fn saved_routes_are_normalized() {
+        let routes = normalize_routes(vec![
+            route(" JA ", "localWhisper", "medium"),
+            route("ja", "localWhisper", " large-v3 "),
+        ])
+        .unwrap();
+        assert_eq!(routes, vec![route("ja", "localWhisper", "large-v3")]);
+        assert!(normalize_routes(vec![route("auto", "parakeet", "")]).is_err());
+        assert!(normalize_routes(vec![route("en", "groq", "")]).is_err());
+    }
+}
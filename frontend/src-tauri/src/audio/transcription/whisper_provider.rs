@@ -2,42 +2,96 @@
 //
 // Whisper transcription provider implementation.
 
-use super::provider::{TranscriptionError, TranscriptionProvider, TranscriptResult};
+use super::provider::{
+    ProviderCapabilities, TranscriptionError, TranscriptionProvider, TranscriptResult,
+};
 use async_trait::async_trait;
 use std::sync::Arc;
 
 /// Whisper transcription provider (wraps WhisperEngine)
 pub struct WhisperProvider {
     engine: Arc<crate::whisper_engine::WhisperEngine>,
+    /// Model `load` makes sure of; None uses whatever the engine has loaded
+    model: Option<String>,
 }
 
 impl WhisperProvider {
     pub fn new(engine: Arc<crate::whisper_engine::WhisperEngine>) -> Self {
-        Self { engine }
+        Self {
+            engine,
+            model: None,
+        }
+    }
+
+    pub fn with_model(engine: Arc<crate::whisper_engine::WhisperEngine>, model: &str) -> Self {
+        Self {
+            engine,
+            model: Some(model.to_string()),
+        }
+    }
+
+    /// Swaps the engine to this provider's model if another provider left a
+    /// different one loaded. Callers hold the engine turn, so the swap never
+    /// happens under someone else's transcription.
+    async fn ensure_model(&self) -> std::result::Result<(), TranscriptionError> {
+        let Some(model) = &self.model else {
+            return Ok(());
+        };
+        if self.engine.get_current_model().await.as_deref() == Some(model.as_str()) {
+            return Ok(());
+        }
+        self.engine.load_model(model).await.map_err(|e| {
+            TranscriptionError::EngineFailed(format!(
+                "Failed to load Whisper model '{}': {}",
+                model, e
+            ))
+        })
     }
 }
 
 #[async_trait]
 impl TranscriptionProvider for WhisperProvider {
-    async fn transcribe(
+    async fn load(&self) -> std::result::Result<(), TranscriptionError> {
+        if self.model.is_some() {
+            self.ensure_model().await
+        } else if self.engine.is_model_loaded().await {
+            Ok(())
+        } else {
+            Err(TranscriptionError::ModelNotLoaded)
+        }
+    }
+
+    async fn transcribe_stream(
         &self,
         audio: Vec<f32>,
         language: Option<String>,
     ) -> std::result::Result<TranscriptResult, TranscriptionError> {
+        self.ensure_model().await?;
         match self
             .engine
             .transcribe_audio_with_confidence(audio, language)
             .await
         {
-            Ok((text, confidence, is_partial)) => Ok(TranscriptResult {
+            Ok((text, confidence, is_partial, uncertain)) => Ok(TranscriptResult {
                 text: text.trim().to_string(),
                 confidence: Some(confidence),
                 is_partial,
+                uncertain,
             }),
             Err(e) => Err(TranscriptionError::EngineFailed(e.to_string())),
         }
     }
 
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            local: true,
+            confidence: true,
+            language_hint: true,
+            language_detection: true,
+            ..Default::default()
+        }
+    }
+
     async fn is_model_loaded(&self) -> bool {
         self.engine.is_model_loaded().await
     }
@@ -49,4 +103,18 @@ impl TranscriptionProvider for WhisperProvider {
     fn provider_name(&self) -> &'static str {
         "Whisper"
     }
+
+    async fn detect_language(&self, audio: &[f32]) -> Option<String> {
+        if let Err(e) = self.ensure_model().await {
+            log::warn!("Whisper language detection skipped: {}", e);
+            return None;
+        }
+        match self.engine.detect_language(audio).await {
+            Ok(language) => Some(language),
+            Err(e) => {
+                log::warn!("Whisper language detection failed: {}", e);
+                None
+            }
+        }
+    }
 }
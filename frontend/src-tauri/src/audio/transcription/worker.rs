@@ -3,13 +3,19 @@
 // Parallel transcription worker pool and chunk processing logic.
 
 use super::engine::TranscriptionEngine;
-use super::provider::TranscriptionError;
+use super::provider::{TranscriptResult, TranscriptionError};
+use super::priority::{engine_turn, Priority};
 use crate::audio::AudioChunk;
 use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use tauri::{AppHandle, Emitter, Runtime};
+use tauri::{AppHandle, Runtime};
+
+use crate::events::{
+    self, SpeechDetected, TranscriptChunkLoss, TranscriptionProgress,
+    TranscriptionQueueComplete, TranscriptionWarning,
+};
 
 // Sequence counter for transcript updates
 static SEQUENCE_COUNTER: AtomicU64 = AtomicU64::new(0);
@@ -32,6 +38,9 @@ pub struct TranscriptUpdate {
     pub chunk_start_time: f64, // Legacy field, kept for compatibility
     pub is_partial: bool,
     pub confidence: f32,
+    /// Kept, but flagged as possibly not what was said (a likely hallucination)
+    #[serde(default)]
+    pub uncertain: bool,
     // NEW: Recording-relative timestamps for playback sync
     pub audio_start_time: f64, // Seconds from recording start (e.g., 125.3)
     pub audio_end_time: f64,   // Seconds from recording start (e.g., 128.6)
@@ -54,11 +63,11 @@ pub fn start_transcription_task<R: Runtime>(
             Ok(engine) => engine,
             Err(e) => {
                 error!("Failed to initialize transcription engine: {}", e);
-                let _ = app.emit("transcription-error", serde_json::json!({
-                    "error": e,
-                    "userMessage": "Recording failed: Unable to initialize speech recognition. Please check your model settings.",
-                    "actionable": true
-                }));
+                let _ = events::emit(&app, &events::TranscriptionError {
+                    error: e,
+                    user_message: "Recording failed: Unable to initialize speech recognition. Please check your model settings.".to_string(),
+                    actionable: true,
+                });
                 return;
             }
         };
@@ -78,11 +87,7 @@ pub fn start_transcription_task<R: Runtime>(
         // Spawn worker tasks
         let mut worker_handles = Vec::new();
         for worker_id in 0..NUM_WORKERS {
-            let engine_clone = match &transcription_engine {
-                TranscriptionEngine::Whisper(e) => TranscriptionEngine::Whisper(e.clone()),
-                TranscriptionEngine::Parakeet(e) => TranscriptionEngine::Parakeet(e.clone()),
-                TranscriptionEngine::Provider(p) => TranscriptionEngine::Provider(p.clone()),
-            };
+            let engine_clone = transcription_engine.clone();
             let app_clone = app.clone();
             let work_receiver_clone = work_receiver.clone();
             let chunks_completed_clone = chunks_completed.clone();
@@ -100,6 +105,7 @@ pub fn start_transcription_task<R: Runtime>(
                     .unwrap_or_else(|| "unknown".to_string());
 
                 let engine_name = engine_clone.provider_name();
+                crate::audio::recording_status::set_current_model(engine_name, &current_model);
 
                 if initial_model_loaded {
                     info!(
@@ -151,11 +157,17 @@ pub fn start_transcription_task<R: Runtime>(
                             )
                             .await
                             {
-                                Ok((transcript, confidence_opt, is_partial)) => {
+                                Ok(TranscriptResult {
+                                    text: transcript,
+                                    confidence: confidence_opt,
+                                    is_partial,
+                                    uncertain,
+                                }) => {
                                     // Provider-aware confidence threshold
-                                    let confidence_threshold = match &engine_clone {
-                                        TranscriptionEngine::Whisper(_) | TranscriptionEngine::Provider(_) => 0.3,
-                                        TranscriptionEngine::Parakeet(_) => 0.0, // Parakeet has no confidence, accept all
+                                    let confidence_threshold = if engine_clone.capabilities().confidence {
+                                        0.3
+                                    } else {
+                                        0.0 // No confidence scores (e.g. Parakeet), accept all
                                     };
 
                                     let confidence_str = match confidence_opt {
@@ -181,9 +193,9 @@ pub fn start_transcription_task<R: Runtime>(
 
                                         if !current_flag {
                                             SPEECH_DETECTED_EMITTED.store(true, Ordering::SeqCst);
-                                            match app_clone.emit("speech-detected", serde_json::json!({
-                                                "message": "Speech activity detected"
-                                            })) {
+                                            match events::emit(&app_clone, &SpeechDetected {
+                                                message: "Speech activity detected".to_string(),
+                                            }) {
                                                 Ok(_) => info!("🎤 ✅ First speech detected - successfully emitted speech-detected event"),
                                                 Err(e) => error!("🎤 ❌ Failed to emit speech-detected event: {}", e),
                                             }
@@ -206,21 +218,24 @@ pub fn start_transcription_task<R: Runtime>(
                                         // Emit transcript update with NEW recording-relative timestamps
 
                                         let update = TranscriptUpdate {
-                                            text: transcript,
+                                            text: crate::audio::corrections::apply(&transcript),
                                             timestamp: format_current_timestamp(), // Wall-clock for reference
                                             source: "Audio".to_string(),
                                             sequence_id,
                                             chunk_start_time: chunk_timestamp, // Legacy compatibility
                                             is_partial,
                                             confidence: confidence_opt.unwrap_or(0.85), // Default for providers without confidence
+                                            uncertain,
                                             // NEW: Recording-relative timestamps for sync
                                             audio_start_time,
                                             audio_end_time,
                                             duration: chunk_duration,
                                         };
 
-                                        if let Err(e) = app_clone.emit("transcript-update", &update)
-                                        {
+                                        if !update.is_partial {
+                                            crate::audio::recording_status::record_segment();
+                                        }
+                                        if let Err(e) = events::emit(&app_clone, &update) {
                                             error!(
                                                 "Worker {}: Failed to emit transcript update: {}",
                                                 worker_id, e
@@ -251,7 +266,7 @@ pub fn start_transcription_task<R: Runtime>(
                                         }
                                         _ => {
                                             warn!("Worker {}: Transcription failed: {}", worker_id, e);
-                                            let _ = app_clone.emit("transcription-warning", e.to_string());
+                                            let _ = events::emit(&app_clone, &TranscriptionWarning(e.to_string()));
                                         }
                                     }
                                 }
@@ -280,13 +295,13 @@ pub fn start_transcription_task<R: Runtime>(
                                 100
                             };
 
-                            let _ = app_clone.emit("transcription-progress", serde_json::json!({
-                                "worker_id": worker_id,
-                                "chunks_completed": completed,
-                                "chunks_queued": queued,
-                                "progress_percentage": progress_percentage,
-                                "message": format!("Worker {} processing... ({}/{})", worker_id, completed, queued)
-                            }));
+                            let _ = events::emit(&app_clone, &TranscriptionProgress {
+                                worker_id,
+                                chunks_completed: completed,
+                                chunks_queued: queued,
+                                progress_percentage,
+                                message: format!("Worker {} processing... ({}/{})", worker_id, completed, queued),
+                            });
                         }
                         None => {
                             // No more chunks available
@@ -344,10 +359,10 @@ pub fn start_transcription_task<R: Runtime>(
               total_chunks_queued, NUM_WORKERS);
 
         // Emit final chunk count to frontend
-        let _ = app.emit("transcription-queue-complete", serde_json::json!({
-            "total_chunks": total_chunks_queued,
-            "message": format!("{} chunks queued for processing - waiting for completion", total_chunks_queued)
-        }));
+        let _ = events::emit(&app, &TranscriptionQueueComplete {
+            total_chunks: total_chunks_queued,
+            message: format!("{} chunks queued for processing - waiting for completion", total_chunks_queued),
+        });
 
         // Wait for all workers to complete
         for (worker_id, handle) in worker_handles.into_iter().enumerate() {
@@ -386,14 +401,15 @@ pub fn start_transcription_task<R: Runtime>(
                 );
 
                 // Emit critical error event
-                let _ = app.emit(
-                    "transcript-chunk-loss-detected",
-                    serde_json::json!({
-                        "chunks_queued": final_queued,
-                        "chunks_completed": final_completed,
-                        "chunks_lost": final_queued - final_completed,
-                        "message": "Some transcript chunks may have been lost during shutdown"
-                    }),
+                let _ = events::emit(
+                    &app,
+                    &TranscriptChunkLoss {
+                        chunks_queued: final_queued,
+                        chunks_completed: final_completed,
+                        chunks_lost: final_queued - final_completed,
+                        message: "Some transcript chunks may have been lost during shutdown"
+                            .to_string(),
+                    },
                 );
                 break;
             }
@@ -406,10 +422,10 @@ pub fn start_transcription_task<R: Runtime>(
 /// Transcribe audio chunk using the appropriate provider (Whisper, Parakeet, or trait-based)
 /// Returns: (text, confidence Option, is_partial)
 async fn transcribe_chunk_with_provider<R: Runtime>(
-    engine: &TranscriptionEngine,
+    provider: &TranscriptionEngine,
     chunk: AudioChunk,
     app: &AppHandle<R>,
-) -> std::result::Result<(String, Option<f32>, bool), TranscriptionError> {
+) -> std::result::Result<TranscriptResult, TranscriptionError> {
     // Convert to 16kHz mono for transcription
     let transcription_data = if chunk.sample_rate != 16000 {
         crate::audio::audio_processing::resample_audio(&chunk.data, chunk.sample_rate, 16000)
@@ -442,132 +458,59 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
         energy
     );
 
-    // Transcribe using the appropriate engine (with improved error handling)
-    match engine {
-        TranscriptionEngine::Whisper(whisper_engine) => {
-            // Get language preference from global state
-            let language = crate::get_language_preference_internal();
-
-            match whisper_engine
-                .transcribe_audio_with_confidence(speech_samples, language)
-                .await
-            {
-                Ok((text, confidence, is_partial)) => {
-                    let cleaned_text = text.trim().to_string();
-                    if cleaned_text.is_empty() {
-                        return Ok((String::new(), Some(confidence), is_partial));
-                    }
-
-                    info!(
-                        "Whisper transcription complete for chunk {}: '{}' (confidence: {:.2}, partial: {})",
-                        chunk.chunk_id, cleaned_text, confidence, is_partial
-                    );
-
-                    Ok((cleaned_text, Some(confidence), is_partial))
-                }
-                Err(e) => {
-                    error!(
-                        "Whisper transcription failed for chunk {}: {}",
-                        chunk.chunk_id, e
-                    );
+    // Imports share the engine; live chunks take the next turn
+    let _turn = engine_turn(Priority::Live).await;
 
-                    let transcription_error = TranscriptionError::EngineFailed(e.to_string());
-                    let _ = app.emit(
-                        "transcription-error",
-                        &serde_json::json!({
-                            "error": transcription_error.to_string(),
-                            "userMessage": format!("Transcription failed: {}", transcription_error),
-                            "actionable": false
-                        }),
-                    );
+    // Transcribe with whichever provider is configured (with improved error handling)
+    let language = crate::get_language_preference_internal();
 
-                    Err(transcription_error)
-                }
+    match provider.transcribe_stream(speech_samples, language).await {
+        Ok(result) => {
+            let cleaned_text = result.text.trim().to_string();
+            if cleaned_text.is_empty() {
+                return Ok(TranscriptResult {
+                    text: String::new(),
+                    ..result
+                });
             }
-        }
-        TranscriptionEngine::Parakeet(parakeet_engine) => {
-            match parakeet_engine.transcribe_audio(speech_samples).await {
-                Ok(text) => {
-                    let cleaned_text = text.trim().to_string();
-                    if cleaned_text.is_empty() {
-                        return Ok((String::new(), None, false));
-                    }
 
-                    info!(
-                        "Parakeet transcription complete for chunk {}: '{}'",
-                        chunk.chunk_id, cleaned_text
-                    );
-
-                    // Parakeet doesn't provide confidence or partial results
-                    Ok((cleaned_text, None, false))
-                }
-                Err(e) => {
-                    error!(
-                        "Parakeet transcription failed for chunk {}: {}",
-                        chunk.chunk_id, e
-                    );
+            let confidence_str = match result.confidence {
+                Some(c) => format!("confidence: {:.2}", c),
+                None => "no confidence".to_string(),
+            };
 
-                    let transcription_error = TranscriptionError::EngineFailed(e.to_string());
-                    let _ = app.emit(
-                        "transcription-error",
-                        &serde_json::json!({
-                            "error": transcription_error.to_string(),
-                            "userMessage": format!("Transcription failed: {}", transcription_error),
-                            "actionable": false
-                        }),
-                    );
+            info!(
+                "{} transcription complete for chunk {}: '{}' ({}, partial: {})",
+                provider.provider_name(),
+                chunk.chunk_id,
+                cleaned_text,
+                confidence_str,
+                result.is_partial
+            );
 
-                    Err(transcription_error)
-                }
-            }
+            Ok(TranscriptResult {
+                text: cleaned_text,
+                ..result
+            })
         }
-        TranscriptionEngine::Provider(provider) => {
-            // NEW: Trait-based provider (clean, unified interface)
-            let language = crate::get_language_preference_internal();
-
-            match provider.transcribe(speech_samples, language).await {
-                Ok(result) => {
-                    let cleaned_text = result.text.trim().to_string();
-                    if cleaned_text.is_empty() {
-                        return Ok((String::new(), result.confidence, result.is_partial));
-                    }
-
-                    let confidence_str = match result.confidence {
-                        Some(c) => format!("confidence: {:.2}", c),
-                        None => "no confidence".to_string(),
-                    };
-
-                    info!(
-                        "{} transcription complete for chunk {}: '{}' ({}, partial: {})",
-                        provider.provider_name(),
-                        chunk.chunk_id,
-                        cleaned_text,
-                        confidence_str,
-                        result.is_partial
-                    );
-
-                    Ok((cleaned_text, result.confidence, result.is_partial))
-                }
-                Err(e) => {
-                    error!(
-                        "{} transcription failed for chunk {}: {}",
-                        provider.provider_name(),
-                        chunk.chunk_id,
-                        e
-                    );
+        Err(e) => {
+            error!(
+                "{} transcription failed for chunk {}: {}",
+                provider.provider_name(),
+                chunk.chunk_id,
+                e
+            );
 
-                    let _ = app.emit(
-                        "transcription-error",
-                        &serde_json::json!({
-                            "error": e.to_string(),
-                            "userMessage": format!("Transcription failed: {}", e),
-                            "actionable": false
-                        }),
-                    );
+            let _ = events::emit(
+                app,
+                &events::TranscriptionError {
+                    error: e.to_string(),
+                    user_message: format!("Transcription failed: {}", e),
+                    actionable: false,
+                },
+            );
 
-                    Err(e)
-                }
-            }
+            Err(e)
         }
     }
 }
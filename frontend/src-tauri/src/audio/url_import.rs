@@ -0,0 +1,646 @@
+//! Import of a recording from a link (a shared recording, a file on a web
+//! server).
+//!
+//! The file is downloaded to the temp folder and handed to
+//! [`import_recording`] like the recordings integrations download. The
+//! download is kept under a name derived from the link until it completes,
+//! so a dropped connection is resumed with a `Range` request, and importing
+//! the same link again after a failure continues where it stopped. `If-Range`
+//! with the server's ETag or Last-Modified makes sure the pieces belong to
+//! the same file; if it changed, the download starts over. A connection
+//! that stops sending for [`READ_TIMEOUT`] counts as dropped, and downloads
+//! left unfinished for [`STALE_DOWNLOAD_AGE`] are removed at startup.
+
+use log::{info, warn};
+use percent_encoding::percent_decode_str;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Runtime};
+use tokio::io::AsyncWriteExt;
+use tokio_util::sync::CancellationToken;
+use url::Url;
+
+use super::file_import::{
+    find_earlier_download, import_recording, DuplicatePolicy, ImportRequest, ImportedMeeting,
+};
+use super::import::SUPPORTED_MEDIA_EXTENSIONS;
+use crate::audit::{self, AuditAction};
+use crate::error::AppError;
+use crate::events::{self, UrlDownloadProgress};
+use crate::jobs::JobHandle;
+use crate::state::AppState;
+use crate::validation;
+
+/// Connections dropped more often than this fail the import
+const MAX_ATTEMPTS: u32 = 5;
+/// Progress is reported at most this often
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+/// A server that sends nothing for this long has dropped the connection
+const READ_TIMEOUT: Duration = Duration::from_secs(60);
+/// Partial downloads not continued for this long are given up on
+pub const STALE_DOWNLOAD_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+const DOWNLOAD_PREFIX: &str = "meetily-url-";
+
+/// What to do with a response to a (possibly ranged) download request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResumeDecision {
+    /// The body continues the partial file
+    Append,
+    /// The body is the whole file; the partial file is discarded
+    Replace,
+    /// The partial file is already the whole file
+    Complete,
+    /// The range was refused; discard the partial file and ask again
+    Restart,
+}
+
+/// Decides from the response status and `Content-Range` how a download with
+/// `existing` bytes already on disk continues
+pub fn resume_decision(
+    status: u16,
+    existing: u64,
+    content_range: Option<&str>,
+) -> Result<ResumeDecision, String> {
+    match status {
+        206 => Ok(match content_range.and_then(parse_content_range) {
+            Some((Some(start), _)) if start == existing => ResumeDecision::Append,
+            _ => ResumeDecision::Restart,
+        }),
+        200..=299 => Ok(ResumeDecision::Replace),
+        416 => Ok(match content_range.and_then(parse_content_range) {
+            Some((_, Some(total))) if existing > 0 && total == existing => ResumeDecision::Complete,
+            _ => ResumeDecision::Restart,
+        }),
+        _ => Err(format!("The server answered {}", status)),
+    }
+}
+
+/// `bytes 100-199/200` as (Some(100), Some(200)); `bytes */200` as
+/// (None, Some(200))
+fn parse_content_range(value: &str) -> Option<(Option<u64>, Option<u64>)> {
+    let (range, total) = value.trim().strip_prefix("bytes ")?.split_once('/')?;
+    let total = total.trim().parse().ok();
+    let start = match range.trim() {
+        "*" => None,
+        range => Some(range.split_once('-')?.0.parse().ok()?),
+    };
+    Some((start, total))
+}
+
+/// The file name the server suggests in `Content-Disposition`
+pub fn disposition_file_name(value: &str) -> Option<String> {
+    let mut plain = None;
+    for param in value.split(';').map(str::trim) {
+        let Some((key, raw)) = param.split_once('=') else {
+            continue;
+        };
+        match key.trim().to_ascii_lowercase().as_str() {
+            // RFC 5987: charset'language'percent-encoded-name
+            "filename*" => {
+                let encoded = raw.trim().splitn(3, '\'').nth(2)?;
+                let name = percent_decode_str(encoded).decode_utf8().ok()?;
+                return base_name(&name);
+            }
+            "filename" => plain = base_name(raw.trim().trim_matches('"')),
+            _ => {}
+        }
+    }
+    plain
+}
+
+/// The last component of a path, so a suggested name can't leave the folder
+fn base_name(name: &str) -> Option<String> {
+    let name = name.rsplit(['/', '\\']).next()?.trim();
+    (!name.is_empty() && name != "." && name != "..").then(|| name.to_string())
+}
+
+/// The file name at the end of the link's path
+pub fn url_file_name(url: &Url) -> Option<String> {
+    let last = url.path_segments()?.rfind(|s| !s.is_empty())?;
+    base_name(&percent_decode_str(last).decode_utf8_lossy())
+}
+
+/// The lowercase extension of `name` if it's a media file we import
+pub fn media_extension(name: &str) -> Option<String> {
+    let extension = Path::new(name).extension()?.to_str()?.to_lowercase();
+    SUPPORTED_MEDIA_EXTENSIONS
+        .contains(&extension.as_str())
+        .then_some(extension)
+}
+
+/// The extension for a `Content-Type`, for links that don't name the file
+pub fn extension_for_content_type(content_type: &str) -> Option<&'static str> {
+    let mime = content_type.split(';').next()?.trim().to_ascii_lowercase();
+    Some(match mime.as_str() {
+        "audio/mpeg" | "audio/mp3" => "mp3",
+        "audio/mp4" | "audio/x-m4a" | "audio/m4a" => "m4a",
+        "video/mp4" => "mp4",
+        "audio/wav" | "audio/x-wav" | "audio/wave" => "wav",
+        "audio/flac" | "audio/x-flac" => "flac",
+        "audio/ogg" => "ogg",
+        "audio/opus" => "opus",
+        "audio/aac" => "aac",
+        "audio/webm" | "video/webm" => "webm",
+        "video/x-matroska" => "mkv",
+        "video/quicktime" => "mov",
+        "audio/aiff" | "audio/x-aiff" => "aiff",
+        "video/x-msvideo" => "avi",
+        "audio/x-ms-wma" => "wma",
+        _ => return None,
+    })
+}
+
+/// The partial download of `url`: the same link always maps to the same file
+fn partial_path(url: &str) -> PathBuf {
+    let digest = ring::digest::digest(&ring::digest::SHA256, url.as_bytes());
+    let key: String = digest.as_ref()[..8]
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    std::env::temp_dir().join(format!("{}{}.part", DOWNLOAD_PREFIX, key))
+}
+
+/// Removes downloads in `dir` (partial files, their validators and files an
+/// interrupted import left behind) last written more than `max_age` ago.
+/// Returns how many were removed.
+pub fn remove_stale_downloads(dir: &Path, max_age: Duration) -> usize {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    let mut removed = 0;
+    for entry in entries.flatten() {
+        if !entry
+            .file_name()
+            .to_string_lossy()
+            .starts_with(DOWNLOAD_PREFIX)
+        {
+            continue;
+        }
+        let stale = entry
+            .metadata()
+            .ok()
+            .filter(|metadata| metadata.is_file())
+            .and_then(|metadata| metadata.modified().ok())
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age >= max_age);
+        if stale && std::fs::remove_file(entry.path()).is_ok() {
+            removed += 1;
+        }
+    }
+    removed
+}
+
+/// The ETag or Last-Modified the partial download was started with
+fn validator_path(partial: &Path) -> PathBuf {
+    partial.with_extension("part.validator")
+}
+
+fn header<'a>(
+    response: &'a reqwest::Response,
+    name: reqwest::header::HeaderName,
+) -> Option<&'a str> {
+    response.headers().get(name)?.to_str().ok()
+}
+
+/// What is known about the file once the download has started
+#[derive(Debug, Default)]
+struct RemoteFile {
+    file_name: Option<String>,
+    content_type: Option<String>,
+}
+
+/// One download attempt, continuing `partial` if it has bytes. Returns Ok
+/// when the file is complete; Err with `true` when another attempt may
+/// get further.
+async fn download_attempt<R: Runtime>(
+    app: &AppHandle<R>,
+    client: &reqwest::Client,
+    url: &str,
+    partial: &Path,
+    job: &JobHandle,
+    remote: &mut RemoteFile,
+) -> Result<(), (AppError, bool)> {
+    let network = |e: reqwest::Error| (AppError::Network(format!("Download failed: {}", e)), true);
+    let io = |e: std::io::Error| {
+        (
+            AppError::Io(format!("Failed to write the download: {}", e)),
+            false,
+        )
+    };
+    let existing = tokio::fs::metadata(partial)
+        .await
+        .map(|m| m.len())
+        .unwrap_or(0);
+    let validator = tokio::fs::read_to_string(validator_path(partial))
+        .await
+        .ok();
+
+    let mut request = client.get(url);
+    if existing > 0 {
+        request = request.header(reqwest::header::RANGE, format!("bytes={}-", existing));
+        if let Some(validator) = validator.as_deref() {
+            request = request.header(reqwest::header::IF_RANGE, validator.trim());
+        }
+    }
+    let timed_out = || {
+        (
+            AppError::Network(format!(
+                "The server sent nothing for {} seconds",
+                READ_TIMEOUT.as_secs()
+            )),
+            true,
+        )
+    };
+    let mut response = tokio::time::timeout(READ_TIMEOUT, request.send())
+        .await
+        .map_err(|_| timed_out())?
+        .map_err(network)?;
+    let status = response.status();
+    if status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err((
+            AppError::Network(format!("The server answered {}", status)),
+            true,
+        ));
+    }
+    let decision = resume_decision(
+        status.as_u16(),
+        existing,
+        header(&response, reqwest::header::CONTENT_RANGE),
+    )
+    .map_err(|e| (AppError::Network(e), false))?;
+
+    if let Some(name) =
+        header(&response, reqwest::header::CONTENT_DISPOSITION).and_then(disposition_file_name)
+    {
+        remote.file_name = Some(name);
+    }
+    if let Some(content_type) = header(&response, reqwest::header::CONTENT_TYPE) {
+        remote.content_type = Some(content_type.to_string());
+    }
+
+    let (mut out, mut downloaded, total) = match decision {
+        ResumeDecision::Complete => return Ok(()),
+        ResumeDecision::Restart => {
+            info!("{} refused to resume; starting over", url);
+            let _ = tokio::fs::remove_file(partial).await;
+            let _ = tokio::fs::remove_file(validator_path(partial)).await;
+            return Err((
+                AppError::Network("The download had to restart".to_string()),
+                true,
+            ));
+        }
+        ResumeDecision::Append => {
+            info!("Resuming the download of {} at {} bytes", url, existing);
+            let out = tokio::fs::OpenOptions::new()
+                .append(true)
+                .open(partial)
+                .await
+                .map_err(io)?;
+            let total = header(&response, reqwest::header::CONTENT_RANGE)
+                .and_then(parse_content_range)
+                .and_then(|(_, total)| total);
+            (out, existing, total)
+        }
+        ResumeDecision::Replace => {
+            // A web page means the link is a viewer, not the file itself
+            if remote
+                .content_type
+                .as_deref()
+                .is_some_and(|t| t.trim_start().starts_with("text/html"))
+            {
+                return Err((
+                    AppError::InvalidInput(
+                        "The link opens a web page, not a recording; use the file's direct download link"
+                            .to_string(),
+                    ),
+                    false,
+                ));
+            }
+            let strong_etag =
+                header(&response, reqwest::header::ETAG).filter(|etag| !etag.starts_with("W/"));
+            match strong_etag.or_else(|| header(&response, reqwest::header::LAST_MODIFIED)) {
+                Some(validator) => tokio::fs::write(validator_path(partial), validator)
+                    .await
+                    .map_err(io)?,
+                None => {
+                    let _ = tokio::fs::remove_file(validator_path(partial)).await;
+                }
+            }
+            let out = tokio::fs::File::create(partial).await.map_err(io)?;
+            (out, 0, response.content_length())
+        }
+    };
+
+    let mut reported = Instant::now();
+    let cancel = job.cancellation_token();
+    loop {
+        let chunk = tokio::select! {
+            chunk = tokio::time::timeout(READ_TIMEOUT, response.chunk()) => chunk,
+            _ = cancel.cancelled() => {
+                let _ = out.flush().await;
+                return Err((AppError::Rejected("Cancelled".to_string()), false));
+            }
+        };
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(_) => {
+                // What arrived is kept for the next attempt
+                let _ = out.flush().await;
+                return Err(timed_out());
+            }
+        };
+        let Some(chunk) = chunk.map_err(network)? else {
+            break;
+        };
+        out.write_all(&chunk).await.map_err(io)?;
+        downloaded += chunk.len() as u64;
+        if reported.elapsed() >= PROGRESS_INTERVAL {
+            reported = Instant::now();
+            let _ = events::emit(
+                app,
+                &UrlDownloadProgress {
+                    url: url.to_string(),
+                    downloaded,
+                    total,
+                },
+            );
+            if let Some(total) = total.filter(|total| *total > 0) {
+                // The download is the first half of the job, transcription
+                // the second
+                job.progress(
+                    downloaded as f64 / total as f64 * 0.5,
+                    Some(format!(
+                        "Downloading ({} of {} MB)",
+                        downloaded / 1_000_000,
+                        total / 1_000_000
+                    )),
+                )
+                .await;
+            }
+        }
+    }
+    out.flush().await.map_err(io)?;
+    if let Some(total) = total {
+        if downloaded < total {
+            return Err((
+                AppError::Network(format!(
+                    "The connection closed after {} of {} bytes",
+                    downloaded, total
+                )),
+                true,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Downloads `url` to the temp folder, resuming after dropped connections.
+/// Returns the finished file, named with a media extension.
+async fn download<R: Runtime>(
+    app: &AppHandle<R>,
+    url: &str,
+    job: &JobHandle,
+) -> Result<(PathBuf, RemoteFile), AppError> {
+    let client = reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(30))
+        .build()?;
+    let partial = partial_path(url);
+    let mut remote = RemoteFile::default();
+    let mut attempt = 1;
+    loop {
+        job.checkpoint().await.map_err(AppError::Rejected)?;
+        match download_attempt(app, &client, url, &partial, job, &mut remote).await {
+            Ok(()) => break,
+            Err((e, true)) if attempt < MAX_ATTEMPTS => {
+                warn!(
+                    "Download of {} interrupted (attempt {}): {}",
+                    url, attempt, e
+                );
+                tokio::time::sleep(Duration::from_secs(2 * attempt as u64)).await;
+                attempt += 1;
+            }
+            Err((e, _)) => return Err(e),
+        }
+    }
+
+    let parsed = Url::parse(url).map_err(|e| AppError::InvalidInput(e.to_string()))?;
+    let extension = remote
+        .file_name
+        .as_deref()
+        .and_then(media_extension)
+        .or_else(|| url_file_name(&parsed).as_deref().and_then(media_extension))
+        .or_else(|| {
+            remote
+                .content_type
+                .as_deref()
+                .and_then(extension_for_content_type)
+                .map(str::to_string)
+        })
+        // FFmpeg reads the content whatever the name; mp4 is the usual case
+        .unwrap_or_else(|| "mp4".to_string());
+    let file = partial.with_extension(extension);
+    tokio::fs::rename(&partial, &file).await?;
+    let _ = tokio::fs::remove_file(validator_path(&partial)).await;
+    Ok((file, remote))
+}
+
+/// The meeting title when none was given: the file's name without its
+/// extension, or the link's host
+fn default_title(url: &Url, remote: &RemoteFile) -> String {
+    remote
+        .file_name
+        .clone()
+        .or_else(|| url_file_name(url))
+        .and_then(|name| {
+            let stem = Path::new(&name)
+                .file_stem()?
+                .to_string_lossy()
+                .trim()
+                .to_string();
+            (!stem.is_empty()).then_some(stem)
+        })
+        .or_else(|| {
+            url.host_str()
+                .map(|host| format!("Recording from {}", host))
+        })
+        .unwrap_or_else(|| "Imported recording".to_string())
+}
+
+/// Downloads `url` and imports it with `tags`, reporting to `job`; the
+/// caller finishes the job and records the import
+pub(crate) async fn import_from_url<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &AppState,
+    url: &str,
+    title: Option<String>,
+    tags: Vec<String>,
+    on_duplicate: DuplicatePolicy,
+    job: &JobHandle,
+    cancel: CancellationToken,
+) -> Result<ImportedMeeting, AppError> {
+    let origin = format!("url:{}", url);
+    let pool = state.db_manager.pool();
+    if let Some(existing) = find_earlier_download(pool, &origin, on_duplicate).await? {
+        info!(
+            "{} was already imported as {}; linking to it",
+            url, existing.meeting_id
+        );
+        return Ok(existing);
+    }
+    let (file, remote) = download(app, url, job).await?;
+    let parsed = Url::parse(url).map_err(|e| AppError::InvalidInput(e.to_string()))?;
+    let title = match title {
+        Some(title) => title,
+        None => validation::title("title", &default_title(&parsed, &remote))
+            .unwrap_or_else(|_| "Imported recording".to_string()),
+    };
+    job.progress(0.5, Some("Transcribing".to_string())).await;
+
+    let imported = import_recording(
+        app,
+        pool,
+        ImportRequest {
+            file: file.clone(),
+            title,
+            move_file: true,
+            participants: Vec::new(),
+            tags,
+            source: "url",
+            origin: Some(origin),
+            on_duplicate,
+            cancel,
+        },
+    )
+    .await;
+    if file.exists() {
+        let _ = std::fs::remove_file(&file);
+    }
+    Ok(imported?)
+}
+
+/// Downloads the recording at `url` and imports it as a meeting, titled
+/// `title` or after the downloaded file. A download interrupted by a
+/// failure or cancellation continues when the same link is imported again.
+#[tauri::command]
+pub async fn api_import_from_url<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    url: String,
+    title: Option<String>,
+    on_duplicate: Option<DuplicatePolicy>,
+) -> Result<ImportedMeeting, AppError> {
+    let url = validation::http_url("url", &url)?;
+    let title = title
+        .filter(|title| !title.trim().is_empty())
+        .map(|title| validation::title("title", &title))
+        .transpose()?;
+    info!("api_import_from_url called for {}", url);
+
+    let job = state
+        .jobs
+        .begin(&app, "import", format!("Import {}", url), None)
+        .await?;
+    let cancel = job.cancellation_token();
+    let result = import_from_url(
+        &app,
+        &state,
+        &url,
+        title,
+        Vec::new(),
+        on_duplicate.unwrap_or_default(),
+        &job,
+        cancel,
+    )
+    .await;
+    job.finish(match &result {
+        Ok(meeting) => Ok(serde_json::json!({ "meeting_id": meeting.meeting_id })),
+        Err(e) => Err(e.to_string()),
+    })
+    .await;
+
+    let imported = result?;
+    if !imported.linked_existing {
+        audit::record(
+            state.db_manager.pool(),
+            AuditAction::Import,
+            "meeting",
+            Some(&imported.meeting_id),
+            serde_json::json!({ "source": "url", "url": url }),
+        )
+        .await;
+    }
+    Ok(imported)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decides_how_a_download_continues() {
+        use ResumeDecision::*;
+        assert_eq!(
+            resume_decision(206, 100, Some("bytes 100-199/200")),
+            Ok(Append)
+        );
+        assert_eq!(
+            resume_decision(206, 100, Some("bytes 0-199/200")),
+            Ok(Restart)
+        );
+        assert_eq!(resume_decision(200, 100, None), Ok(Replace));
+        assert_eq!(resume_decision(416, 200, Some("bytes */200")), Ok(Complete));
+        assert_eq!(resume_decision(416, 150, Some("bytes */200")), Ok(Restart));
+        assert!(resume_decision(404, 0, None).is_err());
+    }
+
+    #[test]
+    fn names_the_download() {
+        assert_eq!(
+            disposition_file_name("attachment; filename=\"Weekly sync.m4a\""),
+            Some("Weekly sync.m4a".to_string())
+        );
+        assert_eq!(
+            disposition_file_name(
+                "attachment; filename=x.mp3; filename*=UTF-8''%E4%BC%9A%E8%AD%B0.mp3"
+            ),
+            Some("会議.mp3".to_string())
+        );
+        assert_eq!(
+            disposition_file_name("attachment; filename=\"../../evil.wav\""),
+            Some("evil.wav".to_string())
+        );
+        let url = Url::parse("https://example.com/share/Team%20call.MP4?dl=1").unwrap();
+        assert_eq!(url_file_name(&url), Some("Team call.MP4".to_string()));
+        assert_eq!(media_extension("Team call.MP4"), Some("mp4".to_string()));
+        assert_eq!(media_extension("notes.pdf"), None);
+        assert_eq!(
+            extension_for_content_type("audio/mpeg; charset=binary"),
+            Some("mp3")
+        );
+        assert_eq!(
+            default_title(&url, &RemoteFile::default()),
+            "Team call".to_string()
+        );
+    }
+
+    #[test]
+    fn removes_only_stale_downloads() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in [
+            "meetily-url-0011.part",
+            "meetily-url-0011.part.validator",
+            "meetily-url-2233.mp4",
+            "other.part",
+        ] {
+            std::fs::write(dir.path().join(name), b"x").unwrap();
+        }
+
+        assert_eq!(remove_stale_downloads(dir.path(), STALE_DOWNLOAD_AGE), 0);
+        assert_eq!(remove_stale_downloads(dir.path(), Duration::ZERO), 3);
+        let left: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(left, vec!["other.part"]);
+    }
+}
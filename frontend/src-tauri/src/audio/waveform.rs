@@ -0,0 +1,165 @@
+//! Downsampled waveform peaks for drawing a meeting's waveform/scrubber.
+//!
+//! Peaks are computed from the decoded recording once and cached next to it
+//! as `waveform.json`. The cache remembers the recording's size and
+//! modification time and is rebuilt when either changes, or when a different
+//! resolution is asked for.
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+use tauri::{AppHandle, Runtime};
+
+use super::audio_processing::find_meeting_audio;
+use super::import::{decode_to_mono_16k, IMPORT_SAMPLE_RATE};
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::state::AppState;
+
+pub const WAVEFORM_FILE: &str = "waveform.json";
+const DEFAULT_PEAKS_PER_SECOND: u32 = 10;
+const MAX_PEAKS_PER_SECOND: u32 = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Waveform {
+    pub peaks_per_second: u32,
+    pub duration_seconds: f64,
+    /// Loudest sample in each bucket, scaled so the loudest bucket is 1.0
+    pub peaks: Vec<f32>,
+    /// Recording the peaks were computed from, to detect stale caches
+    pub source_size: u64,
+    pub source_modified: u64,
+}
+
+impl Waveform {
+    fn matches(&self, peaks_per_second: u32, size: u64, modified: u64) -> bool {
+        self.peaks_per_second == peaks_per_second
+            && self.source_size == size
+            && self.source_modified == modified
+    }
+}
+
+/// Maximum absolute sample per bucket of `samples_per_peak`, normalised to
+/// the loudest bucket and rounded to keep the cache small
+pub fn compute_peaks(samples: &[f32], samples_per_peak: usize) -> Vec<f32> {
+    let peaks: Vec<f32> = samples
+        .chunks(samples_per_peak.max(1))
+        .map(|chunk| chunk.iter().fold(0.0f32, |peak, s| peak.max(s.abs())))
+        .collect();
+    let loudest = peaks.iter().copied().fold(0.0f32, f32::max);
+    if loudest <= 0.0 {
+        return peaks;
+    }
+    peaks
+        .into_iter()
+        .map(|peak| (peak / loudest * 1000.0).round() / 1000.0)
+        .collect()
+}
+
+fn source_stamp(path: &Path) -> std::io::Result<(u64, u64)> {
+    let metadata = std::fs::metadata(path)?;
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+    Ok((metadata.len(), modified))
+}
+
+/// Returns the cached waveform for the recording in `folder`, computing and
+/// caching it if needed. Decodes the recording, so call it off the async
+/// runtime.
+pub fn load_or_compute(folder: &Path, peaks_per_second: u32) -> Result<Waveform, String> {
+    let audio =
+        find_meeting_audio(folder).ok_or_else(|| "This meeting has no recording".to_string())?;
+    let (size, modified) =
+        source_stamp(&audio).map_err(|e| format!("Failed to read {}: {}", audio.display(), e))?;
+
+    let cache_path = folder.join(WAVEFORM_FILE);
+    let cached = std::fs::read_to_string(&cache_path)
+        .ok()
+        .and_then(|json| serde_json::from_str::<Waveform>(&json).ok())
+        .filter(|waveform| waveform.matches(peaks_per_second, size, modified));
+    if let Some(waveform) = cached {
+        return Ok(waveform);
+    }
+
+    info!("Computing waveform for {}", audio.display());
+    let samples = decode_to_mono_16k(&audio)
+        .map_err(|e| format!("Failed to decode {}: {}", audio.display(), e))?;
+    let waveform = Waveform {
+        peaks_per_second,
+        duration_seconds: samples.len() as f64 / IMPORT_SAMPLE_RATE as f64,
+        peaks: compute_peaks(&samples, (IMPORT_SAMPLE_RATE / peaks_per_second) as usize),
+        source_size: size,
+        source_modified: modified,
+    };
+    match serde_json::to_string(&waveform) {
+        Ok(json) => {
+            if let Err(e) = std::fs::write(&cache_path, json) {
+                warn!(
+                    "Failed to cache the waveform in {}: {}",
+                    cache_path.display(),
+                    e
+                );
+            }
+        }
+        Err(e) => warn!("Failed to serialize the waveform: {}", e),
+    }
+    Ok(waveform)
+}
+
+/// Waveform peaks for a meeting's recording, `peaks_per_second` (1-100,
+/// default 10) buckets per second of audio
+#[tauri::command]
+pub async fn get_meeting_waveform<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    peaks_per_second: Option<u32>,
+) -> Result<Waveform, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    let peaks_per_second = peaks_per_second
+        .unwrap_or(DEFAULT_PEAKS_PER_SECOND)
+        .clamp(1, MAX_PEAKS_PER_SECOND);
+    let meeting = MeetingsRepository::get_meeting_metadata(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+    let folder = meeting
+        .folder_path
+        .ok_or_else(|| "This meeting has no recording".to_string())?;
+
+    tokio::task::spawn_blocking(move || load_or_compute(Path::new(&folder), peaks_per_second))
+        .await
+        .map_err(|e| format!("Waveform task failed: {}", e))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peaks_are_bucket_maxima_scaled_to_the_loudest() {
+        let samples = [0.1, -0.2, 0.05, 0.4, -0.8, 0.0, 0.2];
+        assert_eq!(compute_peaks(&samples, 3), vec![0.25, 1.0, 0.25]);
+        assert_eq!(compute_peaks(&[0.0; 4], 2), vec![0.0, 0.0]);
+        assert!(compute_peaks(&[], 2).is_empty());
+    }
+
+    #[test]
+    fn cache_is_stale_when_the_recording_or_resolution_changes() {
+        let waveform = Waveform {
+            peaks_per_second: 10,
+            duration_seconds: 1.0,
+            peaks: vec![1.0],
+            source_size: 100,
+            source_modified: 5,
+        };
+        assert!(waveform.matches(10, 100, 5));
+        assert!(!waveform.matches(20, 100, 5));
+        assert!(!waveform.matches(10, 101, 5));
+        assert!(!waveform.matches(10, 100, 6));
+    }
+}
@@ -0,0 +1,66 @@
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Runtime};
+
+use super::{record, render_csv, AuditAction};
+use crate::database::models::AuditLogEntry;
+use crate::database::repositories::audit::{AuditLogFilter, AuditRepository};
+use crate::state::AppState;
+
+#[tauri::command]
+pub async fn api_get_audit_log<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    filter: Option<AuditLogFilter>,
+) -> Result<Vec<AuditLogEntry>, String> {
+    log_info!("api_get_audit_log called with {:?}", filter);
+    let mut filter = filter.unwrap_or_default();
+    filter.limit = Some(filter.limit.unwrap_or(200).clamp(1, 1000));
+    AuditRepository::list(state.db_manager.pool(), &filter)
+        .await
+        .map_err(|e| format!("Failed to load audit log: {}", e))
+}
+
+/// Writes the (filtered) audit log to `path` as CSV or JSON ("csv" by default)
+#[tauri::command]
+pub async fn api_export_audit_log<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    path: String,
+    format: Option<String>,
+    filter: Option<AuditLogFilter>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_export_audit_log called, path: {}, format: {:?}", path, format);
+    let pool = state.db_manager.pool();
+    let mut filter = filter.unwrap_or_default();
+    filter.limit = None;
+    filter.offset = None;
+    let entries = AuditRepository::list(pool, &filter)
+        .await
+        .map_err(|e| format!("Failed to load audit log: {}", e))?;
+
+    let format = format.unwrap_or_else(|| "csv".to_string());
+    let content = match format.as_str() {
+        "csv" => render_csv(&entries),
+        "json" => serde_json::to_string_pretty(&entries)
+            .map_err(|e| format!("Failed to serialize audit log: {}", e))?,
+        other => return Err(format!("Unsupported audit log format '{}'", other)),
+    };
+    std::fs::write(&path, content).map_err(|e| {
+        log_error!("Failed to write audit log export {}: {}", path, e);
+        format!("Failed to write {}: {}", path, e)
+    })?;
+
+    record(
+        pool,
+        AuditAction::Export,
+        "audit_log",
+        None,
+        serde_json::json!({ "path": path, "format": format, "entries": entries.len() }),
+    )
+    .await;
+    Ok(serde_json::json!({
+        "status": "success",
+        "path": path,
+        "entries": entries.len(),
+    }))
+}
@@ -0,0 +1,204 @@
+/// Audit module - append-only log of who changed or exported what, and when
+///
+/// This module contains:
+/// - The list of audited actions
+/// - `record`, called by commands after a successful modification or export
+/// - CSV rendering for audit log exports
+/// - Tauri commands for frontend integration
+///
+/// Recording never fails the audited operation; a failed insert is logged.
+pub mod commands;
+
+use serde::Serialize;
+use sqlx::SqlitePool;
+
+use crate::database::models::AuditLogEntry;
+use crate::database::repositories::audit::AuditRepository;
+use crate::export::csv::escape_field;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum AuditAction {
+    #[serde(rename = "meeting.create")]
+    MeetingCreate,
+    #[serde(rename = "meeting.rename")]
+    MeetingRename,
+    #[serde(rename = "meeting.delete")]
+    MeetingDelete,
+    #[serde(rename = "meeting.duplicate")]
+    MeetingDuplicate,
+    #[serde(rename = "transcript.delete_segment")]
+    SegmentDelete,
+    #[serde(rename = "transcript.merge_segments")]
+    SegmentMerge,
+    #[serde(rename = "transcript.edit_segment")]
+    SegmentEdit,
+    #[serde(rename = "transcript.retranscribe_segment")]
+    SegmentRetranscribe,
+    #[serde(rename = "transcript.realign_segments")]
+    SegmentRealign,
+    #[serde(rename = "summary.edit")]
+    SummaryEdit,
+    #[serde(rename = "action_item.update")]
+    ActionItemUpdate,
+    #[serde(rename = "speaker.rename")]
+    SpeakerRename,
+    #[serde(rename = "participant.change")]
+    ParticipantChange,
+    #[serde(rename = "attachment.change")]
+    AttachmentChange,
+    #[serde(rename = "custom_field.change")]
+    CustomFieldChange,
+    #[serde(rename = "meeting.tags")]
+    TagChange,
+    #[serde(rename = "operation.undo")]
+    Undo,
+    #[serde(rename = "config.change")]
+    ConfigChange,
+    #[serde(rename = "data.export")]
+    Export,
+    #[serde(rename = "data.import")]
+    Import,
+    #[serde(rename = "database.maintenance")]
+    Maintenance,
+    #[serde(rename = "transcript.unredact")]
+    Unredact,
+    #[serde(rename = "meeting.legal_hold")]
+    LegalHold,
+    #[serde(rename = "meeting.legal_hold_release")]
+    LegalHoldRelease,
+    #[serde(rename = "meeting.share")]
+    MeetingShare,
+    #[serde(rename = "transcript.comment")]
+    CommentChange,
+    #[serde(rename = "meeting.minutes_status")]
+    MinutesStatusChange,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MeetingCreate => "meeting.create",
+            Self::MeetingRename => "meeting.rename",
+            Self::MeetingDelete => "meeting.delete",
+            Self::MeetingDuplicate => "meeting.duplicate",
+            Self::SegmentDelete => "transcript.delete_segment",
+            Self::SegmentMerge => "transcript.merge_segments",
+            Self::SegmentEdit => "transcript.edit_segment",
+            Self::SegmentRetranscribe => "transcript.retranscribe_segment",
+            Self::SegmentRealign => "transcript.realign_segments",
+            Self::SummaryEdit => "summary.edit",
+            Self::ActionItemUpdate => "action_item.update",
+            Self::SpeakerRename => "speaker.rename",
+            Self::ParticipantChange => "participant.change",
+            Self::AttachmentChange => "attachment.change",
+            Self::CustomFieldChange => "custom_field.change",
+            Self::TagChange => "meeting.tags",
+            Self::Undo => "operation.undo",
+            Self::ConfigChange => "config.change",
+            Self::Export => "data.export",
+            Self::Import => "data.import",
+            Self::Maintenance => "database.maintenance",
+            Self::Unredact => "transcript.unredact",
+            Self::LegalHold => "meeting.legal_hold",
+            Self::LegalHoldRelease => "meeting.legal_hold_release",
+            Self::MeetingShare => "meeting.share",
+            Self::CommentChange => "transcript.comment",
+            Self::MinutesStatusChange => "meeting.minutes_status",
+        }
+    }
+}
+
+/// The OS account running the app
+pub fn current_actor() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .ok()
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Appends an audit entry. `details` must not contain secrets such as API keys.
+pub async fn record(
+    pool: &SqlitePool,
+    action: AuditAction,
+    entity_type: &str,
+    entity_id: Option<&str>,
+    details: serde_json::Value,
+) {
+    let details = (!details.is_null()).then(|| details.to_string());
+    if let Err(e) = AuditRepository::append(
+        pool,
+        &current_actor(),
+        action.as_str(),
+        entity_type,
+        entity_id,
+        details.as_deref(),
+    )
+    .await
+    {
+        log::error!("Failed to write audit entry {}: {}", action.as_str(), e);
+    }
+}
+
+pub const CSV_HEADER: &str = "id,occurred_at,actor,action,entity_type,entity_id,details";
+
+pub fn render_csv(entries: &[AuditLogEntry]) -> String {
+    let mut out = String::from(CSV_HEADER);
+    out.push('\n');
+    for entry in entries {
+        let fields = [
+            entry.id.to_string(),
+            entry.occurred_at.0.to_rfc3339(),
+            entry.actor.clone(),
+            entry.action.clone(),
+            entry.entity_type.clone(),
+            entry.entity_id.clone().unwrap_or_default(),
+            entry.details.clone().unwrap_or_default(),
+        ];
+        out.push_str(
+            &fields
+                .iter()
+                .map(|f| escape_field(f))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::DateTimeUtc;
+    use chrono::TimeZone;
+
+    #[test]
+    fn action_names_match_serde() {
+        for action in [AuditAction::MeetingDelete, AuditAction::ConfigChange, AuditAction::Export] {
+            assert_eq!(
+                serde_json::to_value(action).unwrap(),
+                serde_json::Value::String(action.as_str().to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn csv_escapes_json_details() {
+        let entry = AuditLogEntry {
+            id: 7,
+            occurred_at: DateTimeUtc(chrono::Utc.with_ymd_and_hms(2026, 2, 1, 9, 30, 0).unwrap()),
+            actor: "alice".into(),
+            action: "meeting.delete".into(),
+            entity_type: "meeting".into(),
+            entity_id: Some("meeting-1".into()),
+            details: Some(r#"{"title":"Q1, planning"}"#.into()),
+        };
+        let csv = render_csv(&[entry]);
+        let line = csv.lines().nth(1).unwrap();
+        assert_eq!(
+            line,
+            r#"7,2026-02-01T09:30:00+00:00,alice,meeting.delete,meeting,meeting-1,"{""title"":""Q1, planning""}""#
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! MCP server for AI assistants (Claude Desktop, etc.).
+//!
+//! Add to the assistant's MCP configuration, e.g. for Claude Desktop:
+//!
+//! ```json
+//! { "mcpServers": { "meetily": { "command": "/path/to/meetily-mcp" } } }
+//! ```
+//!
+//! Usage: `meetily-mcp [--db <path>]`. Defaults to the desktop app's database.
+
+use std::path::PathBuf;
+
+use app_lib::database::manager::{default_db_path, DatabaseManager};
+
+fn parse_db_arg() -> Result<Option<PathBuf>, String> {
+    let mut args = std::env::args().skip(1);
+    let mut db = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--db" => {
+                let path = args.next().ok_or("--db requires a path")?;
+                db = Some(PathBuf::from(path));
+            }
+            "-h" | "--help" => {
+                eprintln!("Usage: meetily-mcp [--db <path>]");
+                std::process::exit(0);
+            }
+            other => return Err(format!("Unknown argument '{}'", other)),
+        }
+    }
+    Ok(db)
+}
+
+#[tokio::main]
+async fn main() {
+    // env_logger writes to stderr, keeping stdout clean for the protocol
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn")).init();
+
+    let db_path = match parse_db_arg() {
+        Ok(Some(path)) => path,
+        Ok(None) => match default_db_path() {
+            Some(path) => path,
+            None => {
+                eprintln!("Could not determine the app data directory; pass --db <path>");
+                std::process::exit(2);
+            }
+        },
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+
+    if !db_path.exists() {
+        eprintln!(
+            "Database not found at {}. Launch Meetily once or pass --db <path>",
+            db_path.display()
+        );
+        std::process::exit(1);
+    }
+
+    let db = match DatabaseManager::open_read_only(&db_path).await {
+        Ok(db) => db,
+        Err(e) => {
+            eprintln!("Failed to open {}: {}", db_path.display(), e);
+            std::process::exit(1);
+        }
+    };
+
+    log::info!("Serving MCP for {}", db_path.display());
+    if let Err(e) = app_lib::mcp::serve_stdio(db.pool().clone()).await {
+        eprintln!("MCP transport error: {}", e);
+        std::process::exit(1);
+    }
+}
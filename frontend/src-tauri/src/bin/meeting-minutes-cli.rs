@@ -0,0 +1,300 @@
+//! Headless companion to the desktop app: imports audio files, transcribes
+//! them with a local model and writes the result into the app database.
+//! Existing transcripts (.vtt, .srt, .json) are imported as they are,
+//! without audio or a model.
+//!
+//! ```text
+//! meeting-minutes-cli import <audio-file>... [--title <title>] [--provider parakeet|localWhisper]
+//!                     [--model <name>] [--language <code>] [--db <path>] [--models-dir <path>]
+//! meeting-minutes-cli list [--db <path>]
+//! ```
+//!
+//! Provider and model default to the transcript settings chosen in the app.
+//! Models must already be downloaded (via the app). Meeting ids are printed
+//! to stdout, one per imported file; progress goes to stderr.
+
+use std::path::{Path, PathBuf};
+
+use tokio_util::sync::CancellationToken;
+
+use app_lib::audio::file_import::{
+    find_duplicates, import_transcript, record_fingerprint, DuplicatePolicy,
+};
+use app_lib::audio::import::{
+    decode_to_mono_16k, load_local_provider, salvage_to_mono_16k, transcribe_samples,
+    DEFAULT_PARAKEET_MODEL,
+};
+use app_lib::audio::power::prevent_sleep;
+use app_lib::audio::transcript_import::is_transcript_file;
+use app_lib::audio::transcription::TranscriptionProvider;
+use app_lib::database::manager::{default_app_data_dir, DatabaseManager, DB_FILE_NAME};
+use app_lib::database::repositories::{meeting::MeetingsRepository, setting::SettingsRepository};
+
+const USAGE: &str = "Usage:
+  meeting-minutes-cli import <audio-file>... [--title <title>] [--provider parakeet|localWhisper]
+                      [--model <name>] [--language <code>] [--db <path>] [--models-dir <path>]
+  meeting-minutes-cli list [--db <path>]";
+
+#[derive(Debug, Default)]
+struct Options {
+    command: String,
+    files: Vec<PathBuf>,
+    title: Option<String>,
+    provider: Option<String>,
+    model: Option<String>,
+    language: Option<String>,
+    db: Option<PathBuf>,
+    models_dir: Option<PathBuf>,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut iter = args.iter();
+    let mut options = Options {
+        command: iter.next().cloned().ok_or("Missing command")?,
+        ..Default::default()
+    };
+    while let Some(arg) = iter.next() {
+        let mut value = |name: &str| {
+            iter.next()
+                .cloned()
+                .ok_or_else(|| format!("{} requires a value", name))
+        };
+        match arg.as_str() {
+            "--title" => options.title = Some(value("--title")?),
+            "--provider" => options.provider = Some(value("--provider")?),
+            "--model" => options.model = Some(value("--model")?),
+            "--language" => options.language = Some(value("--language")?),
+            "--db" => options.db = Some(PathBuf::from(value("--db")?)),
+            "--models-dir" => options.models_dir = Some(PathBuf::from(value("--models-dir")?)),
+            flag if flag.starts_with("--") => return Err(format!("Unknown option '{}'", flag)),
+            file => options.files.push(PathBuf::from(file)),
+        }
+    }
+    Ok(options)
+}
+
+fn fail(message: impl std::fmt::Display) -> ! {
+    eprintln!("error: {}", message);
+    std::process::exit(1);
+}
+
+fn default_title(path: &Path) -> String {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "Imported audio".to_string());
+    format!("{} (imported {})", stem, chrono::Local::now().format("%Y-%m-%d %H:%M"))
+}
+
+async fn open_database(options: &Options, app_data_dir: &Path) -> DatabaseManager {
+    let db_path = options
+        .db
+        .clone()
+        .unwrap_or_else(|| app_data_dir.join(DB_FILE_NAME));
+    let legacy_path = db_path.with_extension("db");
+    DatabaseManager::new(&db_path.to_string_lossy(), &legacy_path.to_string_lossy())
+        .await
+        .unwrap_or_else(|e| fail(format!("Failed to open {}: {}", db_path.display(), e)))
+}
+
+async fn run_list(db: &DatabaseManager) {
+    let meetings = MeetingsRepository::get_meetings(db.pool())
+        .await
+        .unwrap_or_else(|e| fail(format!("Failed to list meetings: {}", e)));
+    for meeting in meetings {
+        println!(
+            "{}\t{}\t{}",
+            meeting.id,
+            meeting.created_at.0.format("%Y-%m-%d %H:%M"),
+            meeting.title
+        );
+    }
+}
+
+async fn run_import(db: &DatabaseManager, options: &Options, app_data_dir: &Path) {
+    if options.files.is_empty() {
+        fail(format!("No audio files given\n{}", USAGE));
+    }
+    if options.title.is_some() && options.files.len() > 1 {
+        fail("--title can only be used when importing a single file");
+    }
+
+    // Fall back to the provider/model selected in the app
+    let saved = SettingsRepository::get_transcript_config(db.pool())
+        .await
+        .ok()
+        .flatten();
+    let provider = options
+        .provider
+        .clone()
+        .or_else(|| saved.as_ref().map(|s| s.provider.clone()))
+        .unwrap_or_else(|| "parakeet".to_string());
+    let model = options
+        .model
+        .clone()
+        .or_else(|| {
+            saved
+                .as_ref()
+                .filter(|s| s.provider == provider)
+                .map(|s| s.model.clone())
+        })
+        .unwrap_or_else(|| DEFAULT_PARAKEET_MODEL.to_string());
+
+    let models_dir = options
+        .models_dir
+        .clone()
+        .unwrap_or_else(|| app_data_dir.join("models"));
+    // Transcript files don't need a model
+    let engine: Option<Box<dyn TranscriptionProvider>> =
+        if options.files.iter().all(|file| is_transcript_file(file)) {
+            None
+        } else {
+            eprintln!("Loading {} model '{}'...", provider, model);
+            Some(
+                load_local_provider(models_dir, &provider, &model)
+                    .await
+                    .unwrap_or_else(|e| fail(e)),
+            )
+        };
+
+    // Long batches shouldn't be cut short by the machine going to sleep
+    let _awake = prevent_sleep("Importing audio");
+    let mut failures = 0;
+    for file in &options.files {
+        eprintln!("Importing {}", file.display());
+        let title = options.title.clone().unwrap_or_else(|| default_title(file));
+        if is_transcript_file(file) {
+            // Checks for earlier imports and records the fingerprint itself
+            match import_transcript(db, file, Some(title), DuplicatePolicy::Warn).await {
+                Ok(imported) => {
+                    if let Some(existing) = imported.duplicates.first() {
+                        eprintln!(
+                            "warning: {} was already imported as '{}' ({})",
+                            file.display(),
+                            existing.title,
+                            existing.meeting_id
+                        );
+                    }
+                    eprintln!(
+                        "  saved {} segments as '{}'",
+                        imported.segment_count, imported.title
+                    );
+                    println!("{}", imported.meeting_id);
+                }
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    failures += 1;
+                }
+            }
+            continue;
+        }
+
+        // Same duplicate detection as imports in the app
+        let (fingerprint, duplicates) = match find_duplicates(db.pool(), file).await {
+            Ok(found) => found,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                failures += 1;
+                continue;
+            }
+        };
+        if let Some(existing) = duplicates.first() {
+            eprintln!(
+                "warning: {} was already imported as '{}' ({})",
+                file.display(),
+                existing.title,
+                existing.meeting_id
+            );
+        }
+        let engine = engine
+            .as_ref()
+            .expect("the model is loaded whenever an audio file is given");
+
+        let samples = match decode_to_mono_16k(file) {
+            Ok(samples) => samples,
+            Err(e) => {
+                eprintln!("warning: {}; trying to recover the readable audio", e);
+                match salvage_to_mono_16k(file) {
+                    Ok((samples, report)) => {
+                        match report.expected_seconds {
+                            Some(expected) => eprintln!(
+                                "  recovered {:.0}s of {:.0}s",
+                                report.recovered_seconds, expected
+                            ),
+                            None => eprintln!("  recovered {:.0}s", report.recovered_seconds),
+                        }
+                        samples
+                    }
+                    Err(e) => {
+                        eprintln!("error: {}", e);
+                        failures += 1;
+                        continue;
+                    }
+                }
+            }
+        };
+
+        let segments = match transcribe_samples(
+            engine.as_ref(),
+            &samples,
+            options.language.clone(),
+            &CancellationToken::new(),
+            |done, total| eprint!("\r  transcribing {}/{}", done, total),
+        )
+        .await
+        {
+            Ok(segments) => segments,
+            Err(e) => {
+                eprintln!("\nerror: transcription failed for {}: {}", file.display(), e);
+                failures += 1;
+                continue;
+            }
+        };
+        eprintln!();
+
+        if segments.is_empty() {
+            eprintln!("warning: no speech detected in {}, skipping", file.display());
+            failures += 1;
+            continue;
+        }
+
+        let segment_count = segments.len();
+        match db.save_transcript(title.clone(), segments, None).await {
+            Ok(meeting_id) => {
+                record_fingerprint(db.pool(), &meeting_id, &fingerprint, "cli", None).await;
+                eprintln!("  saved {} segments as '{}'", segment_count, title);
+                println!("{}", meeting_id);
+            }
+            Err(e) => {
+                eprintln!("error: failed to save {}: {}", file.display(), e);
+                failures += 1;
+            }
+        }
+    }
+
+    if failures > 0 {
+        std::process::exit(1);
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn")).init();
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.is_empty() || matches!(args[0].as_str(), "-h" | "--help" | "help") {
+        eprintln!("{}", USAGE);
+        return;
+    }
+    let options = parse_args(&args).unwrap_or_else(|e| fail(format!("{}\n{}", e, USAGE)));
+
+    let app_data_dir = default_app_data_dir()
+        .unwrap_or_else(|| fail("Could not determine the app data directory"));
+    let db = open_database(&options, &app_data_dir).await;
+
+    match options.command.as_str() {
+        "import" => run_import(&db, &options, &app_data_dir).await,
+        "list" => run_list(&db).await,
+        other => fail(format!("Unknown command '{}'\n{}", other, USAGE)),
+    }
+}
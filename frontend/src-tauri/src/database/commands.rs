@@ -4,6 +4,10 @@ use std::path::PathBuf;
 use tauri::{AppHandle, Emitter, Manager};
 
 use super::manager::DatabaseManager;
+use super::relocation::{self, RecordingsMove, RelocationReport};
+use super::settings_cache::{config_changed, ConfigKind};
+use super::workspaces::{self, Workspace, WorkspaceRegistry};
+use crate::shutdown::ShutdownReason;
 use crate::state::AppState;
 
 #[derive(Serialize)]
@@ -161,7 +165,7 @@ pub async fn import_and_initialize_database(
         })?;
 
     // Update app state with the new manager
-    app.manage(AppState { db_manager });
+    app.manage(AppState::new(db_manager));
 
     info!("Legacy database imported and initialized successfully");
 
@@ -185,7 +189,7 @@ pub async fn initialize_fresh_database(app: AppHandle) -> Result<(), String> {
         })?;
 
     // Update app state with the new manager
-    app.manage(AppState { db_manager: db_manager.clone() });
+    app.manage(AppState::new(db_manager.clone()));
 
     // Set default model configuration for fresh installs
     let pool = db_manager.pool();
@@ -210,6 +214,11 @@ pub async fn initialize_fresh_database(app: AppHandle) -> Result<(), String> {
         error!("Failed to set default transcription model config: {}", e);
     }
 
+    if let Some(state) = app.try_state::<AppState>() {
+        config_changed(&app, &state.settings_cache, ConfigKind::Model);
+        config_changed(&app, &state.settings_cache, ConfigKind::Transcript);
+    }
+
     info!("Fresh database initialized successfully with default models");
 
     // Emit event to notify frontend that database is ready
@@ -227,7 +236,265 @@ pub async fn get_database_directory(app: AppHandle) -> Result<String, String> {
         .app_data_dir()
         .map_err(|e| format!("Failed to get app data dir: {}", e))?;
 
-    Ok(app_data_dir.to_string_lossy().to_string())
+    Ok(relocation::database_dir(&app_data_dir).to_string_lossy().to_string())
+}
+
+#[derive(Serialize)]
+pub struct DataLocations {
+    #[serde(rename = "databaseDir")]
+    pub database_dir: String,
+    #[serde(rename = "defaultDatabaseDir")]
+    pub default_database_dir: String,
+    #[serde(rename = "recordingsDir")]
+    pub recordings_dir: String,
+    #[serde(rename = "defaultRecordingsDir")]
+    pub default_recordings_dir: String,
+}
+
+/// Where the database and recordings currently live, and their defaults
+#[tauri::command]
+pub async fn get_data_locations(app: AppHandle) -> Result<DataLocations, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    let prefs = crate::audio::recording_preferences::load_recording_preferences(&app)
+        .await
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))?;
+
+    Ok(DataLocations {
+        database_dir: relocation::database_dir(&app_data_dir).to_string_lossy().to_string(),
+        default_database_dir: app_data_dir.to_string_lossy().to_string(),
+        recordings_dir: prefs.save_folder.to_string_lossy().to_string(),
+        default_recordings_dir: crate::audio::recording_preferences::get_default_recordings_folder()
+            .to_string_lossy()
+            .to_string(),
+    })
+}
+
+/// Moves the database to `target_dir`. With `move_recordings` the recordings
+/// root moves too, to `recordings_dir` or `<target_dir>/recordings`, and
+/// meetings stored under it are updated. Old files are kept unless
+/// `delete_old_recordings` is set; the old database is always kept.
+/// The app must be restarted afterwards.
+#[tauri::command]
+pub async fn relocate_data_directory(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    target_dir: String,
+    move_recordings: Option<bool>,
+    recordings_dir: Option<String>,
+    delete_old_recordings: Option<bool>,
+) -> Result<RelocationReport, String> {
+    info!(
+        "relocate_data_directory called, target: {}, move_recordings: {:?}",
+        target_dir, move_recordings
+    );
+    if crate::audio::recording_commands::is_recording().await {
+        return Err("Stop the current recording before moving data".to_string());
+    }
+    // Imports, duplicates and other meeting tasks would write to the old
+    // database or folders after they have been copied
+    state
+        .meeting_locks
+        .ensure_idle()
+        .map_err(|e| e.to_string())?;
+
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    let target_dir = PathBuf::from(target_dir);
+    let mut prefs = crate::audio::recording_preferences::load_recording_preferences(&app)
+        .await
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))?;
+    let recordings = move_recordings.unwrap_or(false).then(|| RecordingsMove {
+        from: prefs.save_folder.clone(),
+        to: recordings_dir
+            .map(PathBuf::from)
+            .unwrap_or_else(|| target_dir.join("recordings")),
+        delete_old: delete_old_recordings.unwrap_or(false),
+    });
+
+    let pool = state.db_manager.pool();
+    // Recorded before the copy, so the entry moves with the database
+    crate::audit::record(
+        pool,
+        crate::audit::AuditAction::ConfigChange,
+        "data_location",
+        None,
+        serde_json::json!({
+            "targetDir": target_dir.to_string_lossy(),
+            "recordingsDir": recordings.as_ref().map(|r| r.to.to_string_lossy().to_string()),
+            "previousRecordingsDir": recordings.as_ref().map(|r| r.from.to_string_lossy().to_string()),
+        }),
+    )
+    .await;
+    let report = relocation::relocate(pool, &app_data_dir, &target_dir, recordings.clone())
+        .await
+        .map_err(|e| {
+            error!("Failed to relocate data directory: {}", e);
+            e
+        })?;
+
+    if let Some(recordings) = recordings {
+        prefs.save_folder = recordings.to;
+        if let Err(e) =
+            crate::audio::recording_preferences::save_recording_preferences(&app, &prefs).await
+        {
+            // The moved database already points at the new folder
+            error!("Failed to update recordings folder preference: {}", e);
+        }
+    }
+    Ok(report)
+}
+
+/// Lists the workspaces and which one is active
+#[tauri::command]
+pub async fn list_workspaces(app: AppHandle) -> Result<WorkspaceRegistry, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    Ok(workspaces::read_registry(&app_data_dir))
+}
+
+/// Creates a workspace with its own database and settings, without switching
+/// to it. Recordings go to `recordings_dir`, or by default to a folder next to
+/// the default recordings folder (not inside it, where maintenance would take
+/// them for orphaned meeting folders).
+#[tauri::command]
+pub async fn create_workspace(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    name: String,
+    recordings_dir: Option<String>,
+) -> Result<Workspace, String> {
+    info!("create_workspace called: {}", name);
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+
+    let recordings_dir = recordings_dir
+        .filter(|dir| !dir.trim().is_empty())
+        .map(|dir| PathBuf::from(dir.trim()))
+        .unwrap_or_else(|| {
+            let default = crate::audio::recording_preferences::get_default_recordings_folder();
+            let folder = format!(
+                "{}-{}",
+                default.file_name().unwrap_or_default().to_string_lossy(),
+                crate::audio::audio_processing::sanitize_filename(name.trim())
+            );
+            default.with_file_name(folder)
+        });
+
+    let mut registry = workspaces::read_registry(&app_data_dir);
+    let workspace = registry.add(&name, Some(recordings_dir))?;
+    std::fs::create_dir_all(workspaces::workspace_dir(&app_data_dir, &workspace.id))
+        .map_err(|e| format!("Failed to create workspace directory: {}", e))?;
+    workspaces::write_registry(&app_data_dir, &registry)
+        .map_err(|e| format!("Failed to save workspaces: {}", e))?;
+
+    crate::audit::record(
+        state.db_manager.pool(),
+        crate::audit::AuditAction::ConfigChange,
+        "workspace",
+        Some(&workspace.id),
+        serde_json::json!({ "change": "create", "name": workspace.name }),
+    )
+    .await;
+    Ok(workspace)
+}
+
+/// Makes `workspace_id` the active workspace and relaunches the app, which
+/// re-initializes the app state from that workspace's database and settings.
+/// Refused while recording.
+#[tauri::command]
+pub async fn switch_workspace(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    workspace_id: String,
+) -> Result<(), String> {
+    info!("switch_workspace called: {}", workspace_id);
+    if crate::audio::recording_commands::is_recording().await {
+        return Err("Stop the current recording before switching workspaces".to_string());
+    }
+
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    let mut registry = workspaces::read_registry(&app_data_dir);
+    if registry.active == workspace_id {
+        return Ok(());
+    }
+    let previous = registry.active();
+    registry.set_active(&workspace_id)?;
+
+    // Recorded in the workspace being left; its database closes below
+    crate::audit::record(
+        state.db_manager.pool(),
+        crate::audit::AuditAction::ConfigChange,
+        "workspace",
+        Some(&workspace_id),
+        serde_json::json!({ "change": "switch", "from": previous.id }),
+    )
+    .await;
+    workspaces::write_registry(&app_data_dir, &registry)
+        .map_err(|e| format!("Failed to save workspaces: {}", e))?;
+
+    state
+        .shutdown
+        .shutdown(&app, &state, ShutdownReason::Restart)
+        .await;
+    app.restart()
+}
+
+/// Sets the recordings root of `workspace_id`; an empty or missing
+/// `recordings_dir` goes back to the folder in the workspace's recording
+/// settings. Network paths are allowed: the returned status says whether the
+/// folder is reachable now, and recordings go to a local spool while it isn't.
+#[tauri::command]
+pub async fn set_workspace_recordings_dir(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    workspace_id: String,
+    recordings_dir: Option<String>,
+) -> Result<serde_json::Value, String> {
+    info!(
+        "set_workspace_recordings_dir called: {} -> {:?}",
+        workspace_id, recordings_dir
+    );
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    let recordings_dir = recordings_dir
+        .filter(|dir| !dir.trim().is_empty())
+        .map(|dir| PathBuf::from(dir.trim()));
+
+    let mut registry = workspaces::read_registry(&app_data_dir);
+    let workspace = registry.set_recordings_dir(&workspace_id, recordings_dir)?;
+    workspaces::write_registry(&app_data_dir, &registry)
+        .map_err(|e| format!("Failed to save workspaces: {}", e))?;
+
+    // The registry is the only place the root is kept; the workspace's
+    // recording settings read it from there
+    let status = match &workspace.recordings_dir {
+        Some(root) => Some(crate::audio::recordings_root::check_root(root).await),
+        None => None,
+    };
+
+    crate::audit::record(
+        state.db_manager.pool(),
+        crate::audit::AuditAction::ConfigChange,
+        "workspace",
+        Some(&workspace.id),
+        serde_json::json!({ "change": "recordings_dir", "recordingsDir": workspace.recordings_dir }),
+    )
+    .await;
+    Ok(serde_json::json!({ "workspace": workspace, "status": status }))
 }
 
 /// Open the database folder in the system file explorer
@@ -236,6 +503,7 @@ pub async fn open_database_folder(app: AppHandle) -> Result<(), String> {
     let app_data_dir = app
         .path()
         .app_data_dir()
+        .map(|dir| relocation::database_dir(&dir))
         .map_err(|e| format!("Failed to get app data dir: {}", e))?;
 
     // Ensure directory exists before trying to open it
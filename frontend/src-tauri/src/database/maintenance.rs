@@ -0,0 +1,383 @@
+//! Database maintenance: integrity check, orphan cleanup, ANALYZE and VACUUM,
+//! plus an optional schedule that runs it automatically.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Error as SqlxError, Row, SqlitePool};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use crate::database::repositories::integration::IntegrationSettingsRepository;
+use crate::database::repositories::keyword_watch::KeywordWatchRepository;
+use crate::locks;
+
+pub const SCHEDULE_SETTINGS_ID: &str = "db_maintenance";
+
+/// Tables holding per-meeting rows, cleaned when their meeting no longer exists
+pub const MEETING_CHILD_TABLES: [&str; 21] = [
+    "transcripts",
+    "transcript_chunks",
+    "summary_processes",
+    "meeting_attachments",
+    "meeting_participants",
+    "meeting_speakers",
+    "action_items",
+    "meeting_delta_reports",
+    "meeting_custom_fields",
+    "meeting_tags",
+    "meeting_activity",
+    "meeting_speaker_stats",
+    "segment_analysis",
+    "transcript_entities",
+    "keyword_hits",
+    "segment_comments",
+    "redaction_mappings",
+    "partial_summaries",
+    "minutes_status",
+    "import_fingerprints",
+    "summary_feedback",
+];
+
+/// Folders touched more recently than this may belong to a recording that has
+/// not been saved yet, so they are never reported as orphans
+const ORPHAN_FOLDER_MIN_AGE_HOURS: u64 = 24;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MaintenanceOptions {
+    /// Delete recording folders that no meeting references (otherwise only reported)
+    #[serde(default, rename = "removeOrphanFolders")]
+    pub remove_orphan_folders: bool,
+    #[serde(default = "default_true")]
+    pub vacuum: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OrphanRows {
+    pub table: String,
+    pub removed: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MaintenanceReport {
+    #[serde(rename = "integrityOk")]
+    pub integrity_ok: bool,
+    /// Messages from PRAGMA integrity_check ("ok" when healthy)
+    pub integrity: Vec<String>,
+    #[serde(rename = "orphanRows")]
+    pub orphan_rows: Vec<OrphanRows>,
+    #[serde(rename = "orphanFolders")]
+    pub orphan_folders: Vec<String>,
+    #[serde(rename = "removedFolders")]
+    pub removed_folders: Vec<String>,
+    pub analyzed: bool,
+    pub vacuumed: bool,
+    #[serde(rename = "sizeBefore")]
+    pub size_before: i64,
+    #[serde(rename = "sizeAfter")]
+    pub size_after: i64,
+    #[serde(rename = "durationMs")]
+    pub duration_ms: u64,
+    #[serde(rename = "ranAt")]
+    pub ran_at: DateTime<Utc>,
+}
+
+async fn database_size(pool: &SqlitePool) -> Result<i64, SqlxError> {
+    let page_count: i64 = sqlx::query_scalar("PRAGMA page_count").fetch_one(pool).await?;
+    let page_size: i64 = sqlx::query_scalar("PRAGMA page_size").fetch_one(pool).await?;
+    Ok(page_count * page_size)
+}
+
+/// Recording folders under `root` that look like meeting folders (contain
+/// metadata.json), are not referenced by any meeting and are old enough
+pub fn find_orphan_folders(root: &Path, referenced: &HashSet<PathBuf>) -> Vec<PathBuf> {
+    let min_age = std::time::Duration::from_secs(ORPHAN_FOLDER_MIN_AGE_HOURS * 3600);
+    orphan_folders(root, referenced, min_age)
+}
+
+fn orphan_folders(
+    root: &Path,
+    referenced: &HashSet<PathBuf>,
+    min_age: std::time::Duration,
+) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let mut orphans: Vec<PathBuf> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_dir() && p.join("metadata.json").is_file())
+        .filter(|p| {
+            let canonical = p.canonicalize().unwrap_or_else(|_| p.clone());
+            !referenced.contains(&canonical)
+        })
+        // A recording or import in progress has no meeting row yet
+        .filter(|p| locks::ensure_unlocked(&locks::folder_key(p)).is_ok())
+        .filter(|p| {
+            std::fs::metadata(p)
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .map(|age| age >= min_age)
+                .unwrap_or(false)
+        })
+        .collect();
+    orphans.sort();
+    orphans
+}
+
+/// Runs a full maintenance pass. Cleanup and VACUUM are skipped when the
+/// integrity check fails, so a damaged database is never rewritten.
+pub async fn run_maintenance(
+    pool: &SqlitePool,
+    recordings_root: Option<&Path>,
+    options: &MaintenanceOptions,
+) -> Result<MaintenanceReport, SqlxError> {
+    let started = Instant::now();
+    let size_before = database_size(pool).await?;
+
+    let integrity: Vec<String> = sqlx::query("PRAGMA integrity_check")
+        .fetch_all(pool)
+        .await?
+        .iter()
+        .map(|row| row.get::<String, _>(0))
+        .collect();
+    let integrity_ok = integrity.len() == 1 && integrity[0] == "ok";
+
+    let mut report = MaintenanceReport {
+        integrity_ok,
+        integrity,
+        orphan_rows: Vec::new(),
+        orphan_folders: Vec::new(),
+        removed_folders: Vec::new(),
+        analyzed: false,
+        vacuumed: false,
+        size_before,
+        size_after: size_before,
+        duration_ms: 0,
+        ran_at: Utc::now(),
+    };
+
+    if !integrity_ok {
+        tracing::error!("Database integrity check failed: {:?}", report.integrity);
+        report.duration_ms = started.elapsed().as_millis() as u64;
+        return Ok(report);
+    }
+
+    for table in MEETING_CHILD_TABLES {
+        // Table names come from the constant list above, never from input
+        let removed = sqlx::query(&format!(
+            "DELETE FROM {} WHERE meeting_id NOT IN (SELECT id FROM meetings)",
+            table
+        ))
+        .execute(pool)
+        .await?
+        .rows_affected();
+        if removed > 0 {
+            tracing::info!("Removed {} orphaned rows from {}", removed, table);
+            report.orphan_rows.push(OrphanRows {
+                table: table.to_string(),
+                removed,
+            });
+        }
+    }
+
+    // Keyword hits of recordings that were never saved have no meeting to
+    // be cleaned with; the same grace period as orphan folders applies
+    let cutoff = Utc::now() - Duration::hours(ORPHAN_FOLDER_MIN_AGE_HOURS as i64);
+    let removed = KeywordWatchRepository::delete_unattached_hits(pool, cutoff).await?;
+    if removed > 0 {
+        tracing::info!("Removed {} keyword hits of unsaved recordings", removed);
+        match report
+            .orphan_rows
+            .iter_mut()
+            .find(|rows| rows.table == "keyword_hits")
+        {
+            Some(rows) => rows.removed += removed,
+            None => report.orphan_rows.push(OrphanRows {
+                table: "keyword_hits".to_string(),
+                removed,
+            }),
+        }
+    }
+
+    if let Some(root) = recordings_root {
+        let referenced: HashSet<PathBuf> =
+            sqlx::query_scalar::<_, String>("SELECT folder_path FROM meetings WHERE folder_path IS NOT NULL")
+                .fetch_all(pool)
+                .await?
+                .into_iter()
+                .map(|p| {
+                    let path = PathBuf::from(p);
+                    path.canonicalize().unwrap_or(path)
+                })
+                .collect();
+
+        for folder in find_orphan_folders(root, &referenced) {
+            let shown = folder.display().to_string();
+            if options.remove_orphan_folders {
+                match std::fs::remove_dir_all(&folder) {
+                    Ok(()) => {
+                        tracing::info!("Removed orphaned recording folder {}", shown);
+                        report.removed_folders.push(shown);
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to remove orphaned folder {}: {}", shown, e);
+                        report.orphan_folders.push(shown);
+                    }
+                }
+            } else {
+                report.orphan_folders.push(shown);
+            }
+        }
+    }
+
+    sqlx::query("ANALYZE").execute(pool).await?;
+    report.analyzed = true;
+
+    if options.vacuum {
+        sqlx::query("VACUUM").execute(pool).await?;
+        report.vacuumed = true;
+    }
+
+    report.size_after = database_size(pool).await?;
+    report.duration_ms = started.elapsed().as_millis() as u64;
+    tracing::info!(
+        "Database maintenance finished in {}ms ({} -> {} bytes)",
+        report.duration_ms,
+        report.size_before,
+        report.size_after
+    );
+    Ok(report)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaintenanceSchedule {
+    pub enabled: bool,
+    #[serde(rename = "intervalDays")]
+    pub interval_days: u32,
+    #[serde(rename = "lastRunAt")]
+    pub last_run_at: Option<DateTime<Utc>>,
+}
+
+impl Default for MaintenanceSchedule {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_days: 7,
+            last_run_at: None,
+        }
+    }
+}
+
+impl MaintenanceSchedule {
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        match self.last_run_at {
+            Some(last) => now - last >= Duration::days(self.interval_days.max(1) as i64),
+            None => true,
+        }
+    }
+}
+
+pub async fn load_schedule(pool: &SqlitePool) -> Result<MaintenanceSchedule, String> {
+    let raw = IntegrationSettingsRepository::get_config(pool, SCHEDULE_SETTINGS_ID)
+        .await
+        .map_err(|e| format!("Failed to load maintenance schedule: {}", e))?;
+    match raw {
+        Some(raw) => serde_json::from_str(&raw)
+            .map_err(|e| format!("Stored maintenance schedule is invalid: {}", e)),
+        None => Ok(MaintenanceSchedule::default()),
+    }
+}
+
+pub async fn save_schedule(pool: &SqlitePool, schedule: &MaintenanceSchedule) -> Result<(), String> {
+    let raw = serde_json::to_string(schedule)
+        .map_err(|e| format!("Failed to serialize maintenance schedule: {}", e))?;
+    IntegrationSettingsRepository::save_config(pool, SCHEDULE_SETTINGS_ID, &raw)
+        .await
+        .map_err(|e| format!("Failed to save maintenance schedule: {}", e))
+}
+
+/// Checks the schedule every few hours while the app runs and performs
+/// maintenance when due. Scheduled runs never delete folders.
+pub async fn run_scheduler(pool: SqlitePool) {
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(6 * 3600));
+    loop {
+        interval.tick().await;
+        let mut schedule = match load_schedule(&pool).await {
+            Ok(schedule) => schedule,
+            Err(e) => {
+                tracing::warn!("Skipping scheduled maintenance: {}", e);
+                continue;
+            }
+        };
+        if !schedule.is_due(Utc::now()) {
+            continue;
+        }
+
+        let options = MaintenanceOptions {
+            remove_orphan_folders: false,
+            vacuum: true,
+        };
+        match run_maintenance(&pool, None, &options).await {
+            Ok(report) => {
+                schedule.last_run_at = Some(report.ran_at);
+                if let Err(e) = save_schedule(&pool, &schedule).await {
+                    tracing::warn!("{}", e);
+                }
+            }
+            Err(e) => tracing::error!("Scheduled database maintenance failed: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schedule_due_logic() {
+        let now = Utc::now();
+        let mut schedule = MaintenanceSchedule::default();
+        assert!(!schedule.is_due(now));
+
+        schedule.enabled = true;
+        assert!(schedule.is_due(now));
+
+        schedule.last_run_at = Some(now - Duration::days(3));
+        assert!(!schedule.is_due(now));
+        schedule.last_run_at = Some(now - Duration::days(7));
+        assert!(schedule.is_due(now));
+    }
+
+    #[test]
+    fn fresh_or_referenced_folders_are_not_orphans() {
+        let root = std::env::temp_dir().join(format!("mm-maint-{}", std::process::id()));
+        let meeting = root.join("Standup_2026-01-01_10-00");
+        let other = root.join("not-a-meeting");
+        std::fs::create_dir_all(&meeting).unwrap();
+        std::fs::create_dir_all(&other).unwrap();
+        std::fs::write(meeting.join("metadata.json"), "{}").unwrap();
+
+        // Just created, so too recent to be reported
+        assert!(find_orphan_folders(&root, &HashSet::new()).is_empty());
+
+        // Old enough, it's an orphan until a meeting references it
+        let any_age = std::time::Duration::ZERO;
+        assert_eq!(
+            orphan_folders(&root, &HashSet::new(), any_age),
+            vec![meeting.clone()]
+        );
+        let referenced: HashSet<PathBuf> = [meeting.canonicalize().unwrap()].into_iter().collect();
+        assert!(orphan_folders(&root, &referenced, any_age).is_empty());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,11 +1,35 @@
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqliteSynchronous};
 use sqlx::{migrate::MigrateDatabase, Result, Sqlite, SqlitePool, Transaction};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 use tauri::Manager;
 
+use super::writer::TranscriptWriter;
+use crate::api::TranscriptSegment;
+
+/// Bundle identifier from tauri.conf.json; Tauri's app_data_dir is <data_dir>/<identifier>
+pub const APP_IDENTIFIER: &str = "com.meetily.ai";
+pub const DB_FILE_NAME: &str = "meeting_minutes.sqlite";
+/// How long a connection waits for another writer before failing with "database is locked"
+const BUSY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The desktop app's data directory, for tools that run without a Tauri
+/// AppHandle (CLI, MCP server)
+pub fn default_app_data_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join(APP_IDENTIFIER))
+}
+
+/// Database location used by the desktop app, following a relocation if any
+pub fn default_db_path() -> Option<PathBuf> {
+    default_app_data_dir().map(|dir| super::relocation::database_dir(&dir).join(DB_FILE_NAME))
+}
+
 #[derive(Clone)]
 pub struct DatabaseManager {
     pool: SqlitePool,
+    /// None when the database was opened read-only
+    transcript_writer: Option<TranscriptWriter>,
 }
 
 impl DatabaseManager {
@@ -30,11 +54,28 @@ impl DatabaseManager {
             }
         }
 
-        let pool = SqlitePool::connect(tauri_db_path).await?;
+        // WAL lets readers proceed while a write is in progress; NORMAL sync is
+        // durable in WAL mode and avoids an fsync per transcript insert
+        let options = SqliteConnectOptions::new()
+            .filename(tauri_db_path)
+            .journal_mode(SqliteJournalMode::Wal)
+            .synchronous(SqliteSynchronous::Normal)
+            .busy_timeout(BUSY_TIMEOUT);
+        let pool = SqlitePool::connect_with(options).await?;
 
-        sqlx::migrate!("./migrations").run(&pool).await?;
+        let backup_dir = Path::new(tauri_db_path)
+            .parent()
+            .map(|dir| dir.join(super::migrations::BACKUP_DIR_NAME));
+        super::migrations::run_migrations(&pool, backup_dir.as_deref()).await?;
+        crate::encryption::load_state(&pool).await?;
+        crate::redaction::load_state(&pool).await?;
+        crate::audio::corrections::refresh_rules(&pool).await;
 
-        Ok(DatabaseManager { pool })
+        let transcript_writer = Some(TranscriptWriter::spawn(pool.clone()));
+        Ok(DatabaseManager {
+            pool,
+            transcript_writer,
+        })
     }
 
     // NOTE: So for the first time users they needs to start the application
@@ -51,9 +92,24 @@ impl DatabaseManager {
             fs::create_dir_all(&app_data_dir).map_err(|e| sqlx::Error::Io(e))?;
         }
 
-        // Define database paths
-        let tauri_db_path = app_data_dir
-            .join("meeting_minutes.sqlite")
+        // Define database paths; the database may have been moved elsewhere
+        let workspace = super::workspaces::active_workspace(&app_data_dir);
+        log::info!("Opening workspace \"{}\"", workspace.name);
+        let db_dir = super::relocation::database_dir(&app_data_dir);
+        if workspace.is_default() && db_dir != app_data_dir && !db_dir.join(DB_FILE_NAME).exists()
+        {
+            // Don't silently start over with an empty database, e.g. when the
+            // drive holding it is not connected
+            return Err(sqlx::Error::Configuration(
+                format!(
+                    "Database not found in {}. Is the drive connected?",
+                    db_dir.display()
+                )
+                .into(),
+            ));
+        }
+        let tauri_db_path = db_dir
+            .join(DB_FILE_NAME)
             .to_string_lossy()
             .to_string();
         // Legacy backend DB path (for auto-migration if exists)
@@ -63,8 +119,8 @@ impl DatabaseManager {
             .to_string();
 
         // WAL file paths for defensive cleanup
-        let wal_path = app_data_dir.join("meeting_minutes.sqlite-wal");
-        let shm_path = app_data_dir.join("meeting_minutes.sqlite-shm");
+        let wal_path = db_dir.join("meeting_minutes.sqlite-wal");
+        let shm_path = db_dir.join("meeting_minutes.sqlite-shm");
 
         log::info!("Tauri DB path: {}", tauri_db_path);
         log::info!("Legacy backend DB path: {}", backend_db_path);
@@ -124,6 +180,14 @@ impl DatabaseManager {
             .app_data_dir()
             .expect("failed to get app data dir");
 
+        // A relocated database is never a first launch; if it is missing,
+        // opening it reports the problem instead of onboarding starting over.
+        // Neither is a new workspace, whose database is created on open.
+        if super::relocation::read_location(&app_data_dir).database_dir.is_some()
+            || !super::workspaces::active_workspace(&app_data_dir).is_default()
+        {
+            return Ok(false);
+        }
         let tauri_db_path = app_data_dir.join("meeting_minutes.sqlite");
 
         Ok(!tauri_db_path.exists())
@@ -157,10 +221,43 @@ impl DatabaseManager {
         Self::new_from_app_handle(app_handle).await
     }
 
+    /// Opens an existing database read-only, without creating it or running migrations.
+    /// Safe to use while the desktop app has the database open.
+    pub async fn open_read_only(db_path: &Path) -> Result<Self> {
+        let options = SqliteConnectOptions::new()
+            .filename(db_path)
+            .read_only(true)
+            .busy_timeout(BUSY_TIMEOUT);
+        let pool = SqlitePool::connect_with(options).await?;
+        Ok(DatabaseManager {
+            pool,
+            transcript_writer: None,
+        })
+    }
+
     pub fn pool(&self) -> &SqlitePool {
         &self.pool
     }
 
+    /// Serialized writer for transcript inserts
+    pub fn transcript_writer(&self) -> Result<&TranscriptWriter> {
+        self.transcript_writer
+            .as_ref()
+            .ok_or_else(|| sqlx::Error::Protocol("The database is open read-only".to_string()))
+    }
+
+    /// Creates a meeting with its transcript segments through the transcript writer
+    pub async fn save_transcript(
+        &self,
+        meeting_title: String,
+        segments: Vec<TranscriptSegment>,
+        folder_path: Option<String>,
+    ) -> Result<String> {
+        self.transcript_writer()?
+            .save_transcript(meeting_title, segments, folder_path)
+            .await
+    }
+
     pub async fn with_transaction<T, F, Fut>(&self, f: F) -> Result<T>
     where
         F: FnOnce(&mut Transaction<'_, Sqlite>) -> Fut,
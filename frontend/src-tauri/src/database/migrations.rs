@@ -0,0 +1,322 @@
+//! Schema migrations.
+//!
+//! SQL steps live in `migrations/` and are tracked by sqlx in `_sqlx_migrations`.
+//! Steps that need Rust (data rewrites, file moves) are listed in
+//! [`RUST_MIGRATIONS`] and tracked in `schema_migrations`. Both share one
+//! timestamp-based version space and run interleaved in version order, inside
+//! a transaction each. An existing database is backed up before any step runs.
+
+use chrono::{DateTime, Utc};
+use futures_util::future::BoxFuture;
+use serde::Serialize;
+use sqlx::migrate::{Migrate, MigrateError, Migrator};
+use sqlx::{Connection, Error as SqlxError, SqliteConnection, SqlitePool};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+pub const BACKUP_DIR_NAME: &str = "backups";
+/// Pre-migration backups kept on disk; older ones are pruned
+const MAX_BACKUPS: usize = 5;
+
+pub type RustStep = for<'c> fn(&'c mut SqliteConnection) -> BoxFuture<'c, Result<(), SqlxError>>;
+
+/// A migration step implemented in Rust
+pub struct RustMigration {
+    /// Same format as the SQL file prefixes, e.g. 20260301000000
+    pub version: i64,
+    pub description: &'static str,
+    pub run: RustStep,
+}
+
+/// Rust steps in version order. Add new entries at the end.
+pub static RUST_MIGRATIONS: &[RustMigration] = &[];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StepKind {
+    Sql,
+    Rust,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PendingStep {
+    pub version: i64,
+    pub description: String,
+    pub kind: StepKind,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemaVersion {
+    /// Highest applied step version, 0 for an empty database
+    pub current: i64,
+    /// Highest step version this build knows about
+    pub latest: i64,
+    pub applied: usize,
+    pub pending: Vec<PendingStep>,
+}
+
+/// Orders the not-yet-applied steps of both kinds by version
+pub fn pending_steps(
+    sql: &[(i64, String)],
+    rust: &[(i64, String)],
+    applied_sql: &HashSet<i64>,
+    applied_rust: &HashSet<i64>,
+) -> Vec<PendingStep> {
+    let mut steps: Vec<PendingStep> = sql
+        .iter()
+        .filter(|(version, _)| !applied_sql.contains(version))
+        .map(|(version, description)| PendingStep {
+            version: *version,
+            description: description.clone(),
+            kind: StepKind::Sql,
+        })
+        .chain(
+            rust.iter()
+                .filter(|(version, _)| !applied_rust.contains(version))
+                .map(|(version, description)| PendingStep {
+                    version: *version,
+                    description: description.clone(),
+                    kind: StepKind::Rust,
+                }),
+        )
+        .collect();
+    steps.sort_by_key(|step| step.version);
+    steps
+}
+
+fn sql_steps() -> Vec<(i64, String)> {
+    MIGRATOR
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration())
+        .map(|m| (m.version, m.description.to_string()))
+        .collect()
+}
+
+fn rust_steps() -> Vec<(i64, String)> {
+    RUST_MIGRATIONS
+        .iter()
+        .map(|m| (m.version, m.description.to_string()))
+        .collect()
+}
+
+async fn ensure_rust_migrations_table(conn: &mut SqliteConnection) -> Result<(), SqlxError> {
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS schema_migrations (
+            version INTEGER PRIMARY KEY,
+            description TEXT NOT NULL,
+            applied_at TEXT NOT NULL
+        )",
+    )
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+async fn applied_rust_versions(conn: &mut SqliteConnection) -> Result<HashSet<i64>, SqlxError> {
+    let versions: Vec<i64> = sqlx::query_scalar("SELECT version FROM schema_migrations")
+        .fetch_all(&mut *conn)
+        .await?;
+    Ok(versions.into_iter().collect())
+}
+
+/// Applied SQL versions, verifying that already-applied files were not edited
+async fn applied_sql_versions(conn: &mut SqliteConnection) -> Result<HashSet<i64>, SqlxError> {
+    if let Some(version) = conn.dirty_version().await? {
+        return Err(MigrateError::Dirty(version).into());
+    }
+    let applied: HashMap<i64, _> = conn
+        .list_applied_migrations()
+        .await?
+        .into_iter()
+        .map(|m| (m.version, m.checksum))
+        .collect();
+    for migration in MIGRATOR.iter() {
+        if let Some(checksum) = applied.get(&migration.version) {
+            if *checksum != migration.checksum {
+                return Err(MigrateError::VersionMismatch(migration.version).into());
+            }
+        }
+    }
+    Ok(applied.into_keys().collect())
+}
+
+/// Copies the database with `VACUUM INTO`, which is consistent even while
+/// other connections are open, then prunes old backups
+async fn backup_database(
+    pool: &SqlitePool,
+    backup_dir: &Path,
+    from_version: i64,
+) -> Result<PathBuf, SqlxError> {
+    std::fs::create_dir_all(backup_dir).map_err(SqlxError::Io)?;
+    let target = backup_dir.join(format!(
+        "meeting_minutes-v{}-{}.sqlite",
+        from_version,
+        Utc::now().format("%Y%m%d%H%M%S")
+    ));
+    sqlx::query("VACUUM INTO ?")
+        .bind(target.to_string_lossy().to_string())
+        .execute(pool)
+        .await?;
+    log::info!("Backed up database to {}", target.display());
+
+    if let Ok(entries) = std::fs::read_dir(backup_dir) {
+        let mut backups: Vec<PathBuf> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| {
+                p.file_name()
+                    .and_then(|n| n.to_str())
+                    .map(|n| n.starts_with("meeting_minutes-v") && n.ends_with(".sqlite"))
+                    .unwrap_or(false)
+            })
+            .collect();
+        backups.sort_by_key(|p| std::fs::metadata(p).and_then(|m| m.modified()).ok());
+        let excess = backups.len().saturating_sub(MAX_BACKUPS);
+        for old in backups.into_iter().take(excess) {
+            if let Err(e) = std::fs::remove_file(&old) {
+                log::warn!("Failed to prune old backup {}: {}", old.display(), e);
+            }
+        }
+    }
+    Ok(target)
+}
+
+/// Brings the schema up to date. When the database already has applied steps
+/// and anything is pending, a backup is written to `backup_dir` first.
+pub async fn run_migrations(pool: &SqlitePool, backup_dir: Option<&Path>) -> Result<(), SqlxError> {
+    let mut conn = pool.acquire().await?;
+    conn.ensure_migrations_table().await?;
+    ensure_rust_migrations_table(&mut conn).await?;
+
+    let applied_sql = applied_sql_versions(&mut conn).await?;
+    let applied_rust = applied_rust_versions(&mut conn).await?;
+    let pending = pending_steps(&sql_steps(), &rust_steps(), &applied_sql, &applied_rust);
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    let current = applied_sql.iter().chain(applied_rust.iter()).max().copied();
+    if let (Some(current), Some(dir)) = (current, backup_dir) {
+        // Refuse to migrate without a backup: a failed step must be recoverable
+        backup_database(pool, dir, current).await?;
+    }
+
+    conn.lock().await?;
+    let result = apply_steps(&mut conn, &pending).await;
+    conn.unlock().await?;
+    result
+}
+
+async fn apply_steps(conn: &mut SqliteConnection, pending: &[PendingStep]) -> Result<(), SqlxError> {
+    for step in pending {
+        log::info!("Applying {:?} migration {} ({})", step.kind, step.version, step.description);
+        match step.kind {
+            StepKind::Sql => {
+                let migration = MIGRATOR
+                    .iter()
+                    .find(|m| m.version == step.version && !m.migration_type.is_down_migration())
+                    .ok_or(MigrateError::VersionMissing(step.version))?;
+                conn.apply(migration).await?;
+            }
+            StepKind::Rust => {
+                let migration = RUST_MIGRATIONS
+                    .iter()
+                    .find(|m| m.version == step.version)
+                    .ok_or(MigrateError::VersionMissing(step.version))?;
+                let mut tx = conn.begin().await?;
+                (migration.run)(&mut *tx).await?;
+                sqlx::query(
+                    "INSERT INTO schema_migrations (version, description, applied_at) VALUES (?, ?, ?)",
+                )
+                .bind(migration.version)
+                .bind(migration.description)
+                .bind(Utc::now())
+                .execute(&mut *tx)
+                .await?;
+                tx.commit().await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+pub async fn schema_version(pool: &SqlitePool) -> Result<SchemaVersion, SqlxError> {
+    let mut conn = pool.acquire().await?;
+    conn.ensure_migrations_table().await?;
+    let applied_sql: HashSet<i64> = conn
+        .list_applied_migrations()
+        .await?
+        .into_iter()
+        .map(|m| m.version)
+        .collect();
+    ensure_rust_migrations_table(&mut conn).await?;
+    let applied_rust = applied_rust_versions(&mut conn).await?;
+
+    let sql = sql_steps();
+    let rust = rust_steps();
+    let latest = sql.iter().chain(rust.iter()).map(|(v, _)| *v).max().unwrap_or(0);
+    let current = applied_sql.iter().chain(applied_rust.iter()).max().copied().unwrap_or(0);
+    Ok(SchemaVersion {
+        current,
+        latest,
+        applied: applied_sql.len() + applied_rust.len(),
+        pending: pending_steps(&sql, &rust, &applied_sql, &applied_rust),
+    })
+}
+
+/// When the last pre-migration backup was written, if any
+pub fn last_backup_at(backup_dir: &Path) -> Option<DateTime<Utc>> {
+    std::fs::read_dir(backup_dir)
+        .ok()?
+        .flatten()
+        .filter_map(|e| e.metadata().and_then(|m| m.modified()).ok())
+        .max()
+        .map(DateTime::<Utc>::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn steps(list: &[(i64, &str)]) -> Vec<(i64, String)> {
+        list.iter().map(|(v, d)| (*v, d.to_string())).collect()
+    }
+
+    #[test]
+    fn pending_steps_interleave_by_version() {
+        let sql = steps(&[(1, "init"), (3, "add tags"), (5, "add versions")]);
+        let rust = steps(&[(2, "move files"), (4, "backfill tags")]);
+        let applied_sql = HashSet::from([1]);
+        let applied_rust = HashSet::new();
+
+        let pending = pending_steps(&sql, &rust, &applied_sql, &applied_rust);
+        let order: Vec<(i64, StepKind)> = pending.iter().map(|s| (s.version, s.kind)).collect();
+        assert_eq!(
+            order,
+            vec![
+                (2, StepKind::Rust),
+                (3, StepKind::Sql),
+                (4, StepKind::Rust),
+                (5, StepKind::Sql),
+            ]
+        );
+    }
+
+    #[test]
+    fn nothing_pending_when_all_applied() {
+        let sql = steps(&[(1, "init")]);
+        let rust = steps(&[(2, "move files")]);
+        let pending = pending_steps(&sql, &rust, &HashSet::from([1]), &HashSet::from([2]));
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn rust_migrations_are_ordered_and_unique() {
+        let versions: Vec<i64> = RUST_MIGRATIONS.iter().map(|m| m.version).collect();
+        assert!(versions.windows(2).all(|w| w[0] < w[1]));
+        let sql: HashSet<i64> = MIGRATOR.iter().map(|m| m.version).collect();
+        assert!(versions.iter().all(|v| !sql.contains(v)));
+    }
+}
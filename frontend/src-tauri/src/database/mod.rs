@@ -1,5 +1,14 @@
 pub mod commands;
+pub mod maintenance;
 pub mod manager;
+pub mod migrations;
 pub mod models;
+pub mod reconcile;
+pub mod relocation;
 pub mod repositories;
+pub mod settings_cache;
 pub mod setup;
+#[cfg(test)]
+pub mod test_support;
+pub mod workspaces;
+pub mod writer;
@@ -35,6 +35,13 @@ pub struct Transcript {
     pub audio_start_time: Option<f64>,
     pub audio_end_time: Option<f64>,
     pub duration: Option<f64>,
+    /// Diarization label, e.g. "Speaker 1"
+    #[sqlx(default)]
+    pub speaker: Option<String>,
+    /// Flagged as a possible hallucination
+    #[sqlx(default)]
+    #[serde(default)]
+    pub uncertain: bool,
 }
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
@@ -127,4 +134,184 @@ pub struct TranscriptSetting {
     #[sqlx(rename = "openaiApiKey")]
     #[serde(rename = "openaiApiKey")]
     pub openai_api_key: Option<String>,
+    #[sqlx(rename = "assemblyAiApiKey")]
+    #[serde(rename = "assemblyAiApiKey")]
+    pub assembly_ai_api_key: Option<String>,
+    #[sqlx(rename = "languageRoutes")]
+    #[serde(rename = "languageRoutes")]
+    pub language_routes: Option<String>,
+}
+
+impl TranscriptSetting {
+    /// Parse the per-language routes from JSON string
+    pub fn get_language_routes(&self) -> Vec<crate::audio::transcription::routing::LanguageRoute> {
+        self.language_routes
+            .as_ref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingAttachment {
+    pub id: String,
+    pub meeting_id: String,
+    pub file_name: String,
+    pub stored_path: String,
+    pub mime_type: Option<String>,
+    pub size_bytes: i64,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingParticipant {
+    pub id: String,
+    pub meeting_id: String,
+    pub name: String,
+    pub email: Option<String>,
+    pub role: Option<String>,
+    /// Diarization label this participant is mapped to (e.g. "Speaker 1")
+    pub speaker_label: Option<String>,
+    /// Where the participant came from: "manual" or "calendar"
+    pub source: String,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+/// A diarized speaker within one meeting and the name the user gave it
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingSpeaker {
+    pub meeting_id: String,
+    /// Label produced by diarization (e.g. "Speaker 1")
+    pub speaker_label: String,
+    pub display_name: Option<String>,
+    /// Voice profile this speaker was matched to or saved as
+    pub profile_id: Option<String>,
+    /// Little-endian f32 embedding; never sent to the frontend
+    #[serde(skip)]
+    pub embedding: Option<Vec<u8>>,
+    pub updated_at: DateTimeUtc,
+}
+
+/// Locally stored voice profile used to auto-label speakers in future meetings
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct VoiceProfile {
+    pub id: String,
+    pub name: String,
+    #[serde(skip)]
+    pub embedding: Vec<u8>,
+    /// Number of meeting embeddings averaged into this profile
+    pub sample_count: i64,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ActionItem {
+    pub id: String,
+    pub meeting_id: String,
+    pub text: String,
+    pub owner: Option<String>,
+    /// Free-form due date as written in the summary (e.g. "2026-02-01", "Friday")
+    pub due_date: Option<String>,
+    /// "open" or "done"
+    pub status: String,
+    /// "summary" (extracted) or "manual"
+    pub source: String,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+    /// Issue tracker the item was pushed to ("jira" / "linear")
+    pub external_tracker: Option<String>,
+    /// Issue key in that tracker (e.g. "PROJ-123", "ENG-42")
+    pub external_key: Option<String>,
+    pub external_url: Option<String>,
+    /// Local date (YYYY-MM-DD) of the last due-date reminder
+    pub reminded_on: Option<String>,
+    /// Due-date reminders are held back until then
+    pub snoozed_until: Option<DateTimeUtc>,
+}
+
+/// Comparison of a meeting with an earlier one, stored on the newer meeting
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingDeltaReport {
+    pub meeting_id: String,
+    pub previous_meeting_id: String,
+    /// Markdown produced by the summary model
+    pub report: String,
+    /// JSON-encoded `summary::delta::ActionItemDelta`
+    pub action_item_delta: String,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub created_at: DateTimeUtc,
+}
+
+/// Undoable operation recorded with before-images of the rows it changed
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub id: i64,
+    pub operation: String,
+    pub meeting_id: Option<String>,
+    pub description: String,
+    pub created_at: DateTimeUtc,
+}
+
+/// Append-only record of a modification, config change or export
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct AuditLogEntry {
+    pub id: i64,
+    pub occurred_at: DateTimeUtc,
+    pub actor: String,
+    pub action: String,
+    pub entity_type: String,
+    pub entity_id: Option<String>,
+    /// JSON object with action-specific context
+    pub details: Option<String>,
+}
+
+/// A user-defined field that meetings can be given a value for
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct CustomFieldDefinition {
+    pub id: String,
+    pub name: String,
+    /// "text", "number", "date", "boolean" or "select"
+    pub field_type: String,
+    /// JSON array of allowed values for "select" fields
+    pub options: Option<String>,
+    pub position: i64,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+/// A meeting's value for a custom field, joined with the field's name and type
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingCustomFieldValue {
+    pub meeting_id: String,
+    pub field_id: String,
+    pub name: String,
+    pub field_type: String,
+    pub value: String,
+}
+
+/// A named meeting filter; `filter` is the JSON of a `MeetingFilter`
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct SavedSearchModel {
+    pub id: String,
+    pub name: String,
+    pub filter: String,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+    pub last_run_at: Option<DateTime<Utc>>,
+}
+
+/// Review comment on a transcript segment; replies point at their parent
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct SegmentComment {
+    pub id: String,
+    pub meeting_id: String,
+    pub transcript_id: String,
+    pub parent_id: Option<String>,
+    pub author: String,
+    pub text: String,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
 }
@@ -0,0 +1,343 @@
+//! Cross-checks meetings against their recording folders: meetings whose
+//! folder is gone, and meeting folders under the recordings root that no
+//! meeting references.
+//!
+//! A scan runs at startup; its report is kept for `api_get_integrity_report`
+//! and announced with an `integrity-issues` event when something is off.
+//! Orphaned folders that still name their meeting in `metadata.json` (or
+//! share the missing folder's name, as after moving the recordings root) are
+//! offered as relink candidates.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Error as SqlxError, SqlitePool};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use super::maintenance::find_orphan_folders;
+use crate::locks;
+
+pub const INTEGRITY_EVENT: &str = "integrity-issues";
+
+static LAST_REPORT: Mutex<Option<IntegrityReport>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingFolder {
+    pub meeting_id: String,
+    pub title: String,
+    pub folder_path: String,
+    /// Orphaned folder that most likely holds this meeting's recording
+    pub candidate: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrphanFolder {
+    pub folder_path: String,
+    /// From the folder's metadata.json
+    pub meeting_id: Option<String>,
+    pub meeting_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityReport {
+    pub checked_at: DateTime<Utc>,
+    pub recordings_root: Option<String>,
+    pub missing_folders: Vec<MissingFolder>,
+    pub orphan_folders: Vec<OrphanFolder>,
+}
+
+impl IntegrityReport {
+    pub fn has_issues(&self) -> bool {
+        !self.missing_folders.is_empty() || !self.orphan_folders.is_empty()
+    }
+}
+
+/// Fixes offered for the issues in a report
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum RepairAction {
+    /// Points a meeting at a folder that exists
+    Relink {
+        #[serde(rename = "meetingId")]
+        meeting_id: String,
+        #[serde(rename = "folderPath")]
+        folder_path: String,
+    },
+    /// Keeps the meeting but forgets its missing folder
+    Detach {
+        #[serde(rename = "meetingId")]
+        meeting_id: String,
+    },
+    /// Deletes a folder that no meeting references
+    DeleteOrphan {
+        #[serde(rename = "folderPath")]
+        folder_path: String,
+    },
+}
+
+#[derive(Deserialize)]
+struct FolderMetadata {
+    meeting_id: Option<String>,
+    meeting_name: Option<String>,
+}
+
+fn read_orphan(path: &Path) -> OrphanFolder {
+    let metadata = std::fs::read_to_string(path.join("metadata.json"))
+        .ok()
+        .and_then(|json| serde_json::from_str::<FolderMetadata>(&json).ok());
+    OrphanFolder {
+        folder_path: path.to_string_lossy().to_string(),
+        meeting_id: metadata.as_ref().and_then(|m| m.meeting_id.clone()),
+        meeting_name: metadata.and_then(|m| m.meeting_name),
+    }
+}
+
+/// Suggests an orphan for each missing folder: first one whose metadata
+/// names the meeting, else one with the same folder name. Each orphan is
+/// suggested at most once.
+pub fn match_candidates(missing: &mut [MissingFolder], orphans: &[OrphanFolder]) {
+    let mut used = HashSet::new();
+    let folder_name = |path: &str| {
+        Path::new(path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+    };
+    for pass in 0..2 {
+        for entry in missing.iter_mut().filter(|m| m.candidate.is_none()) {
+            let found = orphans.iter().position(|orphan| {
+                !used.contains(&orphan.folder_path)
+                    && if pass == 0 {
+                        orphan.meeting_id.as_deref() == Some(entry.meeting_id.as_str())
+                    } else {
+                        folder_name(&orphan.folder_path).is_some()
+                            && folder_name(&orphan.folder_path) == folder_name(&entry.folder_path)
+                    }
+            });
+            if let Some(index) = found {
+                used.insert(orphans[index].folder_path.clone());
+                entry.candidate = Some(orphans[index].folder_path.clone());
+            }
+        }
+    }
+}
+
+/// (id, title, folder) of meetings that have a folder
+async fn referenced_folders(pool: &SqlitePool) -> Result<Vec<(String, String, String)>, SqlxError> {
+    sqlx::query_as::<_, (String, String, String)>(
+        "SELECT id, title, folder_path FROM meetings
+         WHERE folder_path IS NOT NULL AND folder_path != ''",
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// Scans the meetings and the recordings root and remembers the report
+pub async fn scan(
+    pool: &SqlitePool,
+    recordings_root: Option<&Path>,
+) -> Result<IntegrityReport, SqlxError> {
+    let meetings = referenced_folders(pool).await?;
+    let referenced: HashSet<PathBuf> = meetings
+        .iter()
+        .map(|(_, _, folder)| {
+            let path = PathBuf::from(folder);
+            path.canonicalize().unwrap_or(path)
+        })
+        .collect();
+
+    let mut missing_folders: Vec<MissingFolder> = meetings
+        .into_iter()
+        .filter(|(_, _, folder)| !Path::new(folder).is_dir())
+        .map(|(meeting_id, title, folder_path)| MissingFolder {
+            meeting_id,
+            title,
+            folder_path,
+            candidate: None,
+        })
+        .collect();
+    let orphan_folders: Vec<OrphanFolder> = recordings_root
+        .map(|root| find_orphan_folders(root, &referenced))
+        .unwrap_or_default()
+        .iter()
+        .map(|path| read_orphan(path))
+        .collect();
+    match_candidates(&mut missing_folders, &orphan_folders);
+
+    let report = IntegrityReport {
+        checked_at: Utc::now(),
+        recordings_root: recordings_root.map(|root| root.to_string_lossy().to_string()),
+        missing_folders,
+        orphan_folders,
+    };
+    *LAST_REPORT.lock().unwrap_or_else(|e| e.into_inner()) = Some(report.clone());
+    Ok(report)
+}
+
+pub fn last_report() -> Option<IntegrityReport> {
+    LAST_REPORT
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// Applies `action`. Relinking needs an existing folder no other meeting
+/// uses; deleting needs a folder the latest scan reported as orphaned.
+pub async fn repair(pool: &SqlitePool, action: &RepairAction) -> Result<(), String> {
+    match action {
+        RepairAction::Relink {
+            meeting_id,
+            folder_path,
+        } => {
+            let folder = Path::new(folder_path);
+            if !folder.is_dir() {
+                return Err(format!("{} is not a folder", folder_path));
+            }
+            let canonical = folder
+                .canonicalize()
+                .unwrap_or_else(|_| folder.to_path_buf());
+            let taken = referenced_folders(pool)
+                .await
+                .map_err(|e| format!("Database error: {}", e))?
+                .into_iter()
+                .any(|(id, _, other)| {
+                    &id != meeting_id
+                        && Path::new(&other)
+                            .canonicalize()
+                            .is_ok_and(|other| other == canonical)
+                });
+            if taken {
+                return Err(format!("{} belongs to another meeting", folder_path));
+            }
+            set_folder(pool, meeting_id, Some(folder_path)).await
+        }
+        RepairAction::Detach { meeting_id } => set_folder(pool, meeting_id, None).await,
+        RepairAction::DeleteOrphan { folder_path } => {
+            let reported = last_report().is_some_and(|report| {
+                report
+                    .orphan_folders
+                    .iter()
+                    .any(|orphan| &orphan.folder_path == folder_path)
+            });
+            if !reported {
+                return Err(format!(
+                    "{} is not an orphaned folder from the latest scan",
+                    folder_path
+                ));
+            }
+            locks::ensure_unlocked(&locks::folder_key(Path::new(folder_path)))
+                .map_err(|e| e.to_string())?;
+            std::fs::remove_dir_all(folder_path)
+                .map_err(|e| format!("Failed to delete {}: {}", folder_path, e))
+        }
+    }?;
+
+    // Keep the remembered report in step with the fix
+    if let Some(report) = LAST_REPORT
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_mut()
+    {
+        match action {
+            RepairAction::Relink {
+                meeting_id,
+                folder_path,
+            } => {
+                report
+                    .missing_folders
+                    .retain(|m| &m.meeting_id != meeting_id);
+                report
+                    .orphan_folders
+                    .retain(|o| &o.folder_path != folder_path);
+            }
+            RepairAction::Detach { meeting_id } => report
+                .missing_folders
+                .retain(|m| &m.meeting_id != meeting_id),
+            RepairAction::DeleteOrphan { folder_path } => report
+                .orphan_folders
+                .retain(|o| &o.folder_path != folder_path),
+        }
+    }
+    Ok(())
+}
+
+async fn set_folder(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    folder_path: Option<&str>,
+) -> Result<(), String> {
+    let updated = sqlx::query("UPDATE meetings SET folder_path = ? WHERE id = ?")
+        .bind(folder_path)
+        .bind(meeting_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .rows_affected();
+    if updated == 0 {
+        return Err(format!("Meeting not found: {}", meeting_id));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn missing(id: &str, folder: &str) -> MissingFolder {
+        MissingFolder {
+            meeting_id: id.to_string(),
+            title: id.to_string(),
+            folder_path: folder.to_string(),
+            candidate: None,
+        }
+    }
+
+    fn orphan(folder: &str, meeting_id: Option<&str>) -> OrphanFolder {
+        OrphanFolder {
+            folder_path: folder.to_string(),
+            meeting_id: meeting_id.map(str::to_string),
+            meeting_name: None,
+        }
+    }
+
+    #[test]
+    fn candidates_prefer_metadata_over_folder_names() {
+        let mut entries = vec![
+            missing("m1", "/old/Standup_2026-01-01"),
+            missing("m2", "/old/Retro_2026-01-02"),
+            missing("m3", "/old/Planning"),
+        ];
+        let orphans = vec![
+            orphan("/new/Standup_2026-01-01", Some("m2")),
+            orphan("/new/Standup_2026-01-01-copy", None),
+            orphan("/other/Standup_2026-01-01", None),
+        ];
+        match_candidates(&mut entries, &orphans);
+        assert_eq!(
+            entries[0].candidate.as_deref(),
+            Some("/other/Standup_2026-01-01")
+        );
+        assert_eq!(
+            entries[1].candidate.as_deref(),
+            Some("/new/Standup_2026-01-01")
+        );
+        assert_eq!(entries[2].candidate, None);
+    }
+
+    #[test]
+    fn repair_actions_deserialize_from_tagged_json() {
+        let action: RepairAction = serde_json::from_str(
+            r#"{"action": "relink", "meetingId": "m1", "folderPath": "/r/a"}"#,
+        )
+        .unwrap();
+        assert!(
+            matches!(action, RepairAction::Relink { ref meeting_id, .. } if meeting_id == "m1")
+        );
+        let action: RepairAction =
+            serde_json::from_str(r#"{"action": "delete_orphan", "folderPath": "/r/b"}"#).unwrap();
+        assert!(matches!(action, RepairAction::DeleteOrphan { .. }));
+    }
+}
@@ -0,0 +1,635 @@
+//! Moving the database and the default recordings folder to another location.
+//!
+//! The platform app data directory never moves; it keeps `data_location.json`
+//! pointing at the directory that holds the database. A move copies first,
+//! verifies the copy, and only then switches the pointer, so a failure at any
+//! step leaves the app on the old location. The old files are kept until the
+//! user removes them; the app must restart to open the moved database. Moving
+//! back to the default location sets the database kept there aside first.
+
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{Row, SqlitePool};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::manager::DB_FILE_NAME;
+use super::migrations::BACKUP_DIR_NAME;
+
+pub const LOCATION_FILE_NAME: &str = "data_location.json";
+
+/// Contents of the pointer file. No file means the default location.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DataLocation {
+    pub database_dir: Option<PathBuf>,
+}
+
+pub fn read_location(app_data_dir: &Path) -> DataLocation {
+    let path = app_data_dir.join(LOCATION_FILE_NAME);
+    match std::fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+            log::error!("Ignoring invalid {}: {}", path.display(), e);
+            DataLocation::default()
+        }),
+        Err(_) => DataLocation::default(),
+    }
+}
+
+/// Writes the pointer through a temporary file so a crash never leaves it half-written
+pub fn write_location(app_data_dir: &Path, location: &DataLocation) -> io::Result<()> {
+    let path = app_data_dir.join(LOCATION_FILE_NAME);
+    if location.database_dir.is_none() {
+        return match std::fs::remove_file(&path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        };
+    }
+    let tmp = app_data_dir.join(format!("{}.tmp", LOCATION_FILE_NAME));
+    let content = serde_json::to_string_pretty(location)?;
+    std::fs::write(&tmp, content)?;
+    std::fs::rename(&tmp, &path)
+}
+
+/// Directory holding the active workspace's database: for the default
+/// workspace the relocated one if set, else `app_data_dir`
+pub fn database_dir(app_data_dir: &Path) -> PathBuf {
+    let workspace = super::workspaces::active_workspace(app_data_dir);
+    if !workspace.is_default() {
+        return super::workspaces::workspace_dir(app_data_dir, &workspace.id);
+    }
+    read_location(app_data_dir)
+        .database_dir
+        .unwrap_or_else(|| app_data_dir.to_path_buf())
+}
+
+/// `path` moved from under `old_root` to under `new_root`, or None if it is not inside `old_root`
+pub fn rebase_path(path: &Path, old_root: &Path, new_root: &Path) -> Option<PathBuf> {
+    path.strip_prefix(old_root).ok().map(|rest| new_root.join(rest))
+}
+
+/// A target must be a different, non-nested absolute directory
+pub fn validate_target(current: &Path, target: &Path) -> Result<(), String> {
+    if !target.is_absolute() {
+        return Err(format!("{} is not an absolute path", target.display()));
+    }
+    if target == current {
+        return Err(format!("Data is already stored in {}", target.display()));
+    }
+    if target.starts_with(current) || current.starts_with(target) {
+        return Err(format!(
+            "{} and {} must not be inside each other",
+            target.display(),
+            current.display()
+        ));
+    }
+    Ok(())
+}
+
+fn is_empty_or_missing(dir: &Path) -> bool {
+    std::fs::read_dir(dir)
+        .map(|mut entries| entries.next().is_none())
+        .unwrap_or(true)
+}
+
+/// Renames the database an earlier move left in `dir` (with its WAL files),
+/// so the current one can be copied in its place. Returns the new name of
+/// the database, or None if `dir` has none.
+fn set_aside_database(dir: &Path) -> io::Result<Option<PathBuf>> {
+    let database = dir.join(DB_FILE_NAME);
+    if !database.exists() {
+        return Ok(None);
+    }
+    let suffix = format!("replaced-{}", chrono::Utc::now().format("%Y%m%d%H%M%S"));
+    let aside = dir.join(format!("{}.{}", DB_FILE_NAME, suffix));
+    std::fs::rename(&database, &aside)?;
+    for sidecar in ["-wal", "-shm"] {
+        let path = dir.join(format!("{}{}", DB_FILE_NAME, sidecar));
+        if path.exists() {
+            std::fs::rename(&path, dir.join(format!("{}{}.{}", DB_FILE_NAME, sidecar, suffix)))?;
+        }
+    }
+    Ok(Some(aside))
+}
+
+/// Puts a database set aside by [`set_aside_database`] back after a failed move
+fn restore_database(dir: &Path, aside: &Path) {
+    let suffix = aside
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_string())
+        .unwrap_or_default();
+    if let Err(e) = std::fs::rename(aside, dir.join(DB_FILE_NAME)) {
+        log::error!("Failed to restore {}: {}", aside.display(), e);
+        return;
+    }
+    for sidecar in ["-wal", "-shm"] {
+        let path = dir.join(format!("{}{}.{}", DB_FILE_NAME, sidecar, suffix));
+        if path.exists() {
+            let _ = std::fs::rename(&path, dir.join(format!("{}{}", DB_FILE_NAME, sidecar)));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct TreeStats {
+    pub files: u64,
+    pub bytes: u64,
+}
+
+fn tree_stats(dir: &Path) -> io::Result<TreeStats> {
+    let mut stats = TreeStats::default();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            let sub = tree_stats(&entry.path())?;
+            stats.files += sub.files;
+            stats.bytes += sub.bytes;
+        } else {
+            stats.files += 1;
+            stats.bytes += entry.metadata()?.len();
+        }
+    }
+    Ok(stats)
+}
+
+fn copy_tree(src: &Path, dst: &Path) -> io::Result<()> {
+    std::fs::create_dir_all(dst)?;
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        let target = dst.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_tree(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Copies a directory tree and checks that file count and total size match
+pub fn copy_verified(src: &Path, dst: &Path) -> Result<TreeStats, String> {
+    copy_tree(src, dst)
+        .map_err(|e| format!("Failed to copy {} to {}: {}", src.display(), dst.display(), e))?;
+    let expected = tree_stats(src).map_err(|e| format!("Failed to read {}: {}", src.display(), e))?;
+    let copied = tree_stats(dst).map_err(|e| format!("Failed to read {}: {}", dst.display(), e))?;
+    if expected != copied {
+        return Err(format!(
+            "Copy of {} is incomplete ({} of {} files, {} of {} bytes)",
+            src.display(),
+            copied.files,
+            expected.files,
+            copied.bytes,
+            expected.bytes
+        ));
+    }
+    Ok(copied)
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordingsMove {
+    pub from: PathBuf,
+    pub to: PathBuf,
+    pub delete_old: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RelocationReport {
+    #[serde(rename = "databaseDir")]
+    pub database_dir: String,
+    #[serde(rename = "previousDatabaseDir")]
+    pub previous_database_dir: String,
+    #[serde(rename = "recordingsDir")]
+    pub recordings_dir: Option<String>,
+    #[serde(rename = "previousRecordingsDir")]
+    pub previous_recordings_dir: Option<String>,
+    #[serde(rename = "recordingFiles")]
+    pub recording_files: u64,
+    /// Meetings whose folder_path was moved along with the recordings root
+    #[serde(rename = "meetingsRebased")]
+    pub meetings_rebased: usize,
+    #[serde(rename = "restartRequired")]
+    pub restart_required: bool,
+}
+
+async fn table_count(pool: &SqlitePool, table: &str) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", table))
+        .fetch_one(pool)
+        .await
+}
+
+/// Checks the copied database and points meeting folders at the moved recordings
+async fn verify_and_rebase(
+    source: &SqlitePool,
+    copy_path: &Path,
+    recordings: Option<&RecordingsMove>,
+) -> Result<usize, String> {
+    let copy = SqlitePool::connect_with(SqliteConnectOptions::new().filename(copy_path))
+        .await
+        .map_err(|e| format!("Failed to open copied database: {}", e))?;
+    let result = check_copy(source, &copy, recordings).await;
+    copy.close().await;
+    result
+}
+
+async fn check_copy(
+    source: &SqlitePool,
+    copy: &SqlitePool,
+    recordings: Option<&RecordingsMove>,
+) -> Result<usize, String> {
+    let db_error = |e: sqlx::Error| format!("Failed to verify copied database: {}", e);
+
+    let integrity: String = sqlx::query_scalar("PRAGMA integrity_check")
+        .fetch_one(copy)
+        .await
+        .map_err(db_error)?;
+    if integrity != "ok" {
+        return Err(format!("Copied database failed integrity check: {}", integrity));
+    }
+    for table in ["meetings", "transcripts"] {
+        let expected = table_count(source, table).await.map_err(db_error)?;
+        let copied = table_count(copy, table).await.map_err(db_error)?;
+        if expected != copied {
+            return Err(format!(
+                "Copied database has {} of {} rows in {}",
+                copied, expected, table
+            ));
+        }
+    }
+
+    let Some(recordings) = recordings else {
+        return Ok(0);
+    };
+    let mut transaction = copy.begin().await.map_err(db_error)?;
+    let rows = sqlx::query("SELECT id, folder_path FROM meetings WHERE folder_path IS NOT NULL")
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(db_error)?;
+    let mut rebased = 0;
+    for row in rows {
+        let id: String = row.get("id");
+        let folder: String = row.get("folder_path");
+        if let Some(moved) = rebase_path(Path::new(&folder), &recordings.from, &recordings.to) {
+            sqlx::query("UPDATE meetings SET folder_path = ? WHERE id = ?")
+                .bind(moved.to_string_lossy().to_string())
+                .bind(&id)
+                .execute(&mut *transaction)
+                .await
+                .map_err(db_error)?;
+            rebased += 1;
+        }
+    }
+    // Attachments are copied into their meeting folder and stored by full path
+    let attachments = sqlx::query("SELECT id, stored_path FROM meeting_attachments")
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(db_error)?;
+    for row in attachments {
+        let id: String = row.get("id");
+        let stored: String = row.get("stored_path");
+        if let Some(moved) = rebase_path(Path::new(&stored), &recordings.from, &recordings.to) {
+            sqlx::query("UPDATE meeting_attachments SET stored_path = ? WHERE id = ?")
+                .bind(moved.to_string_lossy().to_string())
+                .bind(&id)
+                .execute(&mut *transaction)
+                .await
+                .map_err(db_error)?;
+        }
+    }
+    transaction.commit().await.map_err(db_error)?;
+    Ok(rebased)
+}
+
+/// Copies the database (and optionally the recordings root) to `target_dir`,
+/// verifies both copies and switches the pointer. Writes made after the copy
+/// stay in the old database, so callers restart the app right away.
+///
+/// `target_dir` must be empty or missing, except for the default location
+/// (`app_data_dir`), which always holds the app's other files and still
+/// holds the database from before the data was first moved away.
+pub async fn relocate(
+    pool: &SqlitePool,
+    app_data_dir: &Path,
+    target_dir: &Path,
+    recordings: Option<RecordingsMove>,
+) -> Result<RelocationReport, String> {
+    if !super::workspaces::active_workspace(app_data_dir).is_default() {
+        return Err("Only the default workspace's data can be moved; switch to it first".to_string());
+    }
+    let current_dir = database_dir(app_data_dir);
+    validate_target(&current_dir, target_dir)?;
+    let target_db = target_dir.join(DB_FILE_NAME);
+    let to_default = target_dir == app_data_dir;
+    if !to_default && !is_empty_or_missing(target_dir) {
+        return Err(format!(
+            "{} is not empty; choose an empty folder",
+            target_dir.display()
+        ));
+    }
+    if let Some(recordings) = &recordings {
+        validate_target(&recordings.from, &recordings.to)?;
+        if !is_empty_or_missing(&recordings.to) {
+            return Err(format!("{} is not empty", recordings.to.display()));
+        }
+    }
+    std::fs::create_dir_all(target_dir)
+        .map_err(|e| format!("Cannot create {}: {}", target_dir.display(), e))?;
+
+    // 1. Recordings, so meeting folders exist before the database points at them
+    let recording_stats = match &recordings {
+        Some(r) if r.from.exists() => {
+            let (from, to) = (r.from.clone(), r.to.clone());
+            tokio::task::spawn_blocking(move || copy_verified(&from, &to))
+                .await
+                .map_err(|e| format!("Recordings copy task failed: {}", e))??
+        }
+        _ => TreeStats::default(),
+    };
+    // The database left behind by the earlier move is stale; keep it under
+    // another name until the move back has succeeded
+    let set_aside = if to_default {
+        match set_aside_database(target_dir) {
+            Ok(aside) => aside,
+            Err(e) => {
+                if let Some(r) = &recordings {
+                    let _ = std::fs::remove_dir_all(&r.to);
+                }
+                return Err(format!(
+                    "Failed to set aside the old database in {}: {}",
+                    target_dir.display(),
+                    e
+                ));
+            }
+        }
+    } else {
+        None
+    };
+    let cleanup = || {
+        if let Some(r) = &recordings {
+            if r.to.exists() {
+                let _ = std::fs::remove_dir_all(&r.to);
+            }
+        }
+        if let Some(aside) = &set_aside {
+            restore_database(target_dir, aside);
+        }
+    };
+
+    // 2. Database: VACUUM INTO gives a consistent copy while the pool is open
+    if let Err(e) = sqlx::query("VACUUM INTO ?")
+        .bind(target_db.to_string_lossy().to_string())
+        .execute(pool)
+        .await
+    {
+        cleanup();
+        return Err(format!("Failed to copy database: {}", e));
+    }
+    let rebased = match verify_and_rebase(pool, &target_db, recordings.as_ref()).await {
+        Ok(rebased) => rebased,
+        Err(e) => {
+            let _ = std::fs::remove_file(&target_db);
+            cleanup();
+            return Err(e);
+        }
+    };
+    let old_backups = current_dir.join(BACKUP_DIR_NAME);
+    if old_backups.exists() {
+        let new_backups = target_dir.join(BACKUP_DIR_NAME);
+        match tokio::task::spawn_blocking(move || copy_tree(&old_backups, &new_backups)).await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => log::warn!("Failed to copy migration backups: {}", e),
+            Err(e) => log::warn!("Migration backup copy task failed: {}", e),
+        }
+    }
+
+    // 3. Switch
+    let location = DataLocation {
+        database_dir: (target_dir != app_data_dir).then(|| target_dir.to_path_buf()),
+    };
+    if let Err(e) = write_location(app_data_dir, &location) {
+        let _ = std::fs::remove_file(&target_db);
+        cleanup();
+        return Err(format!("Failed to record the new data location: {}", e));
+    }
+    log::info!(
+        "Relocated database from {} to {}",
+        current_dir.display(),
+        target_dir.display()
+    );
+    if let Some(aside) = &set_aside {
+        log::info!("Kept the previous database at {}", aside.display());
+    }
+
+    if let Some(r) = recordings.as_ref().filter(|r| r.delete_old && r.from.exists()) {
+        let from = r.from.clone();
+        match tokio::task::spawn_blocking(move || std::fs::remove_dir_all(&from)).await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => {
+                log::warn!("Failed to remove old recordings folder {}: {}", r.from.display(), e)
+            }
+            Err(e) => log::warn!("Old recordings removal task failed: {}", e),
+        }
+    }
+
+    Ok(RelocationReport {
+        database_dir: target_dir.to_string_lossy().to_string(),
+        previous_database_dir: current_dir.to_string_lossy().to_string(),
+        recordings_dir: recordings.as_ref().map(|r| r.to.to_string_lossy().to_string()),
+        previous_recordings_dir: recordings.as_ref().map(|r| r.from.to_string_lossy().to_string()),
+        recording_files: recording_stats.files,
+        meetings_rebased: rebased,
+        restart_required: true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rebases_only_paths_inside_the_old_root() {
+        let old = Path::new("/data/recordings");
+        let new = Path::new("/mnt/ext/recordings");
+        assert_eq!(
+            rebase_path(Path::new("/data/recordings/Standup_2026"), old, new),
+            Some(PathBuf::from("/mnt/ext/recordings/Standup_2026"))
+        );
+        assert_eq!(rebase_path(Path::new("/data/recordings-old/x"), old, new), None);
+        assert_eq!(rebase_path(Path::new("/elsewhere/x"), old, new), None);
+    }
+
+    #[test]
+    fn rejects_nested_or_relative_targets() {
+        let current = Path::new("/data/app");
+        assert!(validate_target(current, Path::new("/mnt/ext/app")).is_ok());
+        assert!(validate_target(current, Path::new("/data/app")).is_err());
+        assert!(validate_target(current, Path::new("/data/app/sub")).is_err());
+        assert!(validate_target(current, Path::new("/data")).is_err());
+        assert!(validate_target(current, Path::new("relative/dir")).is_err());
+    }
+
+    #[test]
+    fn location_file_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(database_dir(dir.path()), dir.path());
+
+        let moved = DataLocation {
+            database_dir: Some(PathBuf::from("/mnt/ext/meetily")),
+        };
+        write_location(dir.path(), &moved).unwrap();
+        assert_eq!(read_location(dir.path()), moved);
+        assert_eq!(database_dir(dir.path()), PathBuf::from("/mnt/ext/meetily"));
+
+        write_location(dir.path(), &DataLocation::default()).unwrap();
+        assert!(!dir.path().join(LOCATION_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn verified_copy_matches_source() {
+        let src = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(src.path().join("meeting")).unwrap();
+        std::fs::write(src.path().join("meeting/audio.mp4"), b"abc").unwrap();
+        std::fs::write(src.path().join("notes.txt"), b"hello").unwrap();
+        let dst = tempfile::tempdir().unwrap();
+
+        let stats = copy_verified(src.path(), &dst.path().join("copy")).unwrap();
+        assert_eq!(stats, TreeStats { files: 2, bytes: 8 });
+    }
+
+    async fn open_database(path: &Path) -> SqlitePool {
+        let pool = SqlitePool::connect_with(
+            SqliteConnectOptions::new()
+                .filename(path)
+                .create_if_missing(true),
+        )
+        .await
+        .unwrap();
+        for statement in [
+            "CREATE TABLE IF NOT EXISTS meetings (id TEXT PRIMARY KEY, folder_path TEXT)",
+            "CREATE TABLE IF NOT EXISTS transcripts (id TEXT PRIMARY KEY, meeting_id TEXT)",
+            "CREATE TABLE IF NOT EXISTS meeting_attachments (id TEXT PRIMARY KEY, meeting_id TEXT, stored_path TEXT)",
+        ] {
+            sqlx::query(statement).execute(&pool).await.unwrap();
+        }
+        pool
+    }
+
+    async fn add_meeting(pool: &SqlitePool, id: &str) {
+        sqlx::query("INSERT INTO meetings (id) VALUES (?)")
+            .bind(id)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn moves_the_database_away_and_back() {
+        let app_data = tempfile::tempdir().unwrap();
+        let external = tempfile::tempdir().unwrap();
+        let moved_dir = external.path().join("meetily");
+
+        let pool = open_database(&app_data.path().join(DB_FILE_NAME)).await;
+        add_meeting(&pool, "before-move").await;
+        let report = relocate(&pool, app_data.path(), &moved_dir, None)
+            .await
+            .unwrap();
+        pool.close().await;
+        assert!(report.restart_required);
+        assert_eq!(database_dir(app_data.path()), moved_dir);
+
+        // After the restart the app works on the moved copy
+        let pool = open_database(&moved_dir.join(DB_FILE_NAME)).await;
+        add_meeting(&pool, "after-move").await;
+        relocate(&pool, app_data.path(), app_data.path(), None)
+            .await
+            .unwrap();
+        pool.close().await;
+        assert_eq!(database_dir(app_data.path()), app_data.path());
+        assert!(!app_data.path().join(LOCATION_FILE_NAME).exists());
+
+        let pool = open_database(&app_data.path().join(DB_FILE_NAME)).await;
+        assert_eq!(table_count(&pool, "meetings").await.unwrap(), 2);
+        pool.close().await;
+        // The stale database from before the first move is kept aside
+        let kept_aside = std::fs::read_dir(app_data.path())
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .any(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with(&format!("{}.replaced-", DB_FILE_NAME))
+            });
+        assert!(kept_aside);
+    }
+
+    #[tokio::test]
+    async fn moved_recordings_take_meeting_folders_and_attachments_along() {
+        let app_data = tempfile::tempdir().unwrap();
+        let external = tempfile::tempdir().unwrap();
+        let old_root = app_data.path().join("recordings");
+        let folder = old_root.join("Standup_2026-01-01_10-00");
+        std::fs::create_dir_all(folder.join("attachments")).unwrap();
+        std::fs::write(folder.join("attachments").join("agenda.pdf"), b"pdf").unwrap();
+
+        let pool = open_database(&app_data.path().join(DB_FILE_NAME)).await;
+        sqlx::query("INSERT INTO meetings (id, folder_path) VALUES (?, ?)")
+            .bind("m1")
+            .bind(folder.to_string_lossy().to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+        for (id, path) in [
+            ("a1", folder.join("attachments").join("agenda.pdf")),
+            ("a2", PathBuf::from("/elsewhere/attachments/notes.txt")),
+        ] {
+            sqlx::query("INSERT INTO meeting_attachments (id, meeting_id, stored_path) VALUES (?, ?, ?)")
+                .bind(id)
+                .bind("m1")
+                .bind(path.to_string_lossy().to_string())
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let new_root = external.path().join("recordings");
+        let moved_dir = external.path().join("meetily");
+        let recordings = RecordingsMove {
+            from: old_root.clone(),
+            to: new_root.clone(),
+            delete_old: false,
+        };
+        let report = relocate(&pool, app_data.path(), &moved_dir, Some(recordings))
+            .await
+            .unwrap();
+        pool.close().await;
+        assert_eq!(report.meetings_rebased, 1);
+
+        let pool = open_database(&moved_dir.join(DB_FILE_NAME)).await;
+        let stored: Vec<String> =
+            sqlx::query_scalar("SELECT stored_path FROM meeting_attachments ORDER BY id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        pool.close().await;
+        let moved = new_root
+            .join("Standup_2026-01-01_10-00")
+            .join("attachments")
+            .join("agenda.pdf");
+        assert_eq!(stored[0], moved.to_string_lossy());
+        assert!(moved.exists());
+        // Files outside the recordings root stay where they are
+        assert_eq!(stored[1], "/elsewhere/attachments/notes.txt");
+    }
+
+    #[tokio::test]
+    async fn refuses_a_non_empty_target() {
+        let app_data = tempfile::tempdir().unwrap();
+        let target = tempfile::tempdir().unwrap();
+        std::fs::write(target.path().join("notes.txt"), b"keep me").unwrap();
+
+        let pool = open_database(&app_data.path().join(DB_FILE_NAME)).await;
+        let result = relocate(&pool, app_data.path(), target.path(), None).await;
+        pool.close().await;
+        assert!(result.unwrap_err().contains("not empty"));
+        assert_eq!(database_dir(app_data.path()), app_data.path());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::database::models::ActionItem;
+use crate::summary::action_items::ExtractedActionItem;
+use chrono::{DateTime, Utc};
+use sqlx::{Connection, Error as SqlxError, SqlitePool};
+use std::str::FromStr;
+use tracing::info;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionItemStatus {
+    Open,
+    Done,
+}
+
+impl ActionItemStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Done => "done",
+        }
+    }
+}
+
+impl FromStr for ActionItemStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "open" => Ok(Self::Open),
+            "done" => Ok(Self::Done),
+            other => Err(format!(
+                "Unknown action item status '{}'. Use open or done",
+                other
+            )),
+        }
+    }
+}
+
+pub struct ActionItemsRepository;
+
+impl ActionItemsRepository {
+    pub async fn list_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<ActionItem>, SqlxError> {
+        sqlx::query_as::<_, ActionItem>(
+            "SELECT * FROM action_items WHERE meeting_id = ? ORDER BY created_at ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_action_item(
+        pool: &SqlitePool,
+        item_id: &str,
+    ) -> Result<Option<ActionItem>, SqlxError> {
+        sqlx::query_as::<_, ActionItem>("SELECT * FROM action_items WHERE id = ?")
+            .bind(item_id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Replaces the summary-extracted items of a meeting after (re)generation.
+    /// Manual items, items already marked done and items pushed to a tracker are kept.
+    pub async fn replace_extracted(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        items: &[ExtractedActionItem],
+    ) -> Result<usize, SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+        let now = Utc::now();
+
+        sqlx::query(
+            "DELETE FROM action_items
+             WHERE meeting_id = ? AND source = 'summary' AND status = 'open' AND external_key IS NULL",
+        )
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        for item in items {
+            sqlx::query(
+                "INSERT INTO action_items (id, meeting_id, text, owner, due_date, status, source, created_at, updated_at)
+                 VALUES (?, ?, ?, ?, ?, 'open', 'summary', ?, ?)",
+            )
+            .bind(format!("action-{}", Uuid::new_v4()))
+            .bind(meeting_id)
+            .bind(&item.text)
+            .bind(&item.owner)
+            .bind(&item.due_date)
+            .bind(now)
+            .bind(now)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+        info!(
+            "Stored {} extracted action items for meeting {}",
+            items.len(),
+            meeting_id
+        );
+        Ok(items.len())
+    }
+
+    pub async fn update_status(
+        pool: &SqlitePool,
+        item_id: &str,
+        status: ActionItemStatus,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query("UPDATE action_items SET status = ?, updated_at = ? WHERE id = ?")
+            .bind(status.as_str())
+            .bind(Utc::now())
+            .bind(item_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Open action items that have a due date, across all meetings
+    pub async fn list_open_with_due_date(pool: &SqlitePool) -> Result<Vec<ActionItem>, SqlxError> {
+        sqlx::query_as::<_, ActionItem>(
+            "SELECT * FROM action_items
+             WHERE status = 'open' AND due_date IS NOT NULL AND TRIM(due_date) != ''
+             ORDER BY created_at ASC",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Records that the items were reminded of on `date` (local YYYY-MM-DD)
+    pub async fn mark_reminded(
+        pool: &SqlitePool,
+        item_ids: &[String],
+        date: &str,
+    ) -> Result<(), SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+        for item_id in item_ids {
+            sqlx::query("UPDATE action_items SET reminded_on = ? WHERE id = ?")
+                .bind(date)
+                .bind(item_id)
+                .execute(&mut *transaction)
+                .await?;
+        }
+        transaction.commit().await
+    }
+
+    /// Holds the item's reminders back until `until`, after which it is
+    /// reminded of again even if that was already done today
+    pub async fn snooze(
+        pool: &SqlitePool,
+        item_id: &str,
+        until: DateTime<Utc>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query(
+            "UPDATE action_items SET snoozed_until = ?, reminded_on = NULL, updated_at = ? WHERE id = ?",
+        )
+        .bind(until)
+        .bind(Utc::now())
+        .bind(item_id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Records the issue created for an action item in an external tracker
+    pub async fn set_external_issue(
+        pool: &SqlitePool,
+        item_id: &str,
+        tracker: &str,
+        key: &str,
+        url: Option<&str>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query(
+            "UPDATE action_items
+             SET external_tracker = ?, external_key = ?, external_url = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(tracker)
+        .bind(key)
+        .bind(url)
+        .bind(Utc::now())
+        .bind(item_id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_known_statuses_parse() {
+        assert_eq!("open".parse(), Ok(ActionItemStatus::Open));
+        assert_eq!("done".parse(), Ok(ActionItemStatus::Done));
+        assert!("Done".parse::<ActionItemStatus>().is_err());
+        assert!("blocked".parse::<ActionItemStatus>().is_err());
+    }
+}
@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Error as SqlxError, SqlitePool};
+use std::collections::HashSet;
+
+/// A meeting from the recently viewed list
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct RecentMeeting {
+    pub id: String,
+    pub title: String,
+    pub last_viewed_at: DateTime<Utc>,
+    pub view_count: i64,
+    pub pinned: bool,
+}
+
+/// A pinned meeting, in pin order
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PinnedMeeting {
+    pub id: String,
+    pub title: String,
+    pub pinned_at: Option<DateTime<Utc>>,
+}
+
+pub struct MeetingActivityRepository;
+
+impl MeetingActivityRepository {
+    /// Records that a meeting was opened
+    pub async fn record_view(pool: &SqlitePool, meeting_id: &str) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO meeting_activity (meeting_id, last_viewed_at, view_count)
+             SELECT id, ?, 1 FROM meetings WHERE id = ?
+             ON CONFLICT(meeting_id) DO UPDATE SET
+                last_viewed_at = excluded.last_viewed_at,
+                view_count = view_count + 1",
+        )
+        .bind(Utc::now())
+        .bind(meeting_id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Most recently viewed meetings first
+    pub async fn recently_viewed(
+        pool: &SqlitePool,
+        limit: i64,
+    ) -> Result<Vec<RecentMeeting>, SqlxError> {
+        sqlx::query_as::<_, RecentMeeting>(
+            "SELECT m.id, m.title, a.last_viewed_at, a.view_count, a.pinned
+             FROM meeting_activity a
+             JOIN meetings m ON m.id = a.meeting_id
+             WHERE a.last_viewed_at IS NOT NULL
+             ORDER BY a.last_viewed_at DESC
+             LIMIT ?",
+        )
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Pins or unpins a meeting. Returns false if the meeting doesn't exist.
+    pub async fn set_pinned(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        pinned: bool,
+    ) -> Result<bool, SqlxError> {
+        let pinned_at = pinned.then(Utc::now);
+        let result = sqlx::query(
+            "INSERT INTO meeting_activity (meeting_id, pinned, pinned_at)
+             SELECT id, ?, ? FROM meetings WHERE id = ?
+             ON CONFLICT(meeting_id) DO UPDATE SET
+                pinned = excluded.pinned,
+                pinned_at = excluded.pinned_at",
+        )
+        .bind(pinned)
+        .bind(pinned_at)
+        .bind(meeting_id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Pinned meetings, most recently pinned first
+    pub async fn pinned_meetings(pool: &SqlitePool) -> Result<Vec<PinnedMeeting>, SqlxError> {
+        sqlx::query_as::<_, PinnedMeeting>(
+            "SELECT m.id, m.title, a.pinned_at
+             FROM meeting_activity a
+             JOIN meetings m ON m.id = a.meeting_id
+             WHERE a.pinned = 1
+             ORDER BY a.pinned_at DESC",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn pinned_ids(pool: &SqlitePool) -> Result<HashSet<String>, SqlxError> {
+        let ids: Vec<String> =
+            sqlx::query_scalar("SELECT meeting_id FROM meeting_activity WHERE pinned = 1")
+                .fetch_all(pool)
+                .await?;
+        Ok(ids.into_iter().collect())
+    }
+}
@@ -0,0 +1,190 @@
+use crate::database::models::MeetingAttachment;
+use crate::database::repositories::journal::JournalRepository;
+use chrono::Utc;
+use sqlx::{Error as SqlxError, SqlitePool};
+use std::collections::HashSet;
+use tracing::info;
+use uuid::Uuid;
+
+pub struct AttachmentsRepository;
+
+impl AttachmentsRepository {
+    /// Records an attachment that has already been copied into the meeting folder.
+    pub async fn add_attachment(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        file_name: &str,
+        stored_path: &str,
+        mime_type: Option<&str>,
+        size_bytes: i64,
+    ) -> Result<MeetingAttachment, SqlxError> {
+        if meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "meeting_id cannot be empty".to_string(),
+            ));
+        }
+
+        let attachment = MeetingAttachment {
+            id: format!("attachment-{}", Uuid::new_v4()),
+            meeting_id: meeting_id.to_string(),
+            file_name: file_name.to_string(),
+            stored_path: stored_path.to_string(),
+            mime_type: mime_type.map(|m| m.to_string()),
+            size_bytes,
+            created_at: crate::database::models::DateTimeUtc(Utc::now()),
+        };
+
+        sqlx::query(
+            "INSERT INTO meeting_attachments (id, meeting_id, file_name, stored_path, mime_type, size_bytes, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&attachment.id)
+        .bind(&attachment.meeting_id)
+        .bind(&attachment.file_name)
+        .bind(&attachment.stored_path)
+        .bind(&attachment.mime_type)
+        .bind(attachment.size_bytes)
+        .bind(attachment.created_at.0)
+        .execute(pool)
+        .await?;
+
+        info!(
+            "Added attachment {} ({}) to meeting {}",
+            attachment.id, attachment.file_name, meeting_id
+        );
+        Ok(attachment)
+    }
+
+    pub async fn list_attachments(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<MeetingAttachment>, SqlxError> {
+        sqlx::query_as::<_, MeetingAttachment>(
+            "SELECT * FROM meeting_attachments WHERE meeting_id = ? ORDER BY created_at ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_attachment(
+        pool: &SqlitePool,
+        attachment_id: &str,
+    ) -> Result<Option<MeetingAttachment>, SqlxError> {
+        sqlx::query_as::<_, MeetingAttachment>("SELECT * FROM meeting_attachments WHERE id = ?")
+            .bind(attachment_id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Removes the attachment row. The caller is responsible for deleting the file.
+    pub async fn delete_attachment(
+        pool: &SqlitePool,
+        attachment_id: &str,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query("DELETE FROM meeting_attachments WHERE id = ?")
+            .bind(attachment_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Files of attachments deleted with their meeting, once the deletion can
+    /// no longer be undone. Files another attachment still points at are left
+    /// out.
+    pub async fn expired_files(pool: &SqlitePool) -> Result<Vec<String>, SqlxError> {
+        let in_use: HashSet<String> =
+            sqlx::query_scalar("SELECT stored_path FROM meeting_attachments")
+                .fetch_all(pool)
+                .await?
+                .into_iter()
+                .collect();
+        let mut seen = HashSet::new();
+        Ok(JournalRepository::expired_rows(pool, "meeting_attachments")
+            .await?
+            .iter()
+            .filter_map(|row| row["stored_path"].as_str())
+            .filter(|path| !in_use.contains(*path) && seen.insert(path.to_string()))
+            .map(str::to_string)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::meeting::MeetingsRepository;
+    use crate::database::test_support::{insert_meeting, memory_pool};
+
+    #[tokio::test]
+    async fn files_expire_once_the_deletion_cannot_be_undone() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        AttachmentsRepository::add_attachment(&pool, "m1", "agenda.pdf", "/a/agenda.pdf", None, 1)
+            .await
+            .unwrap();
+        assert!(MeetingsRepository::delete_meeting(&pool, "m1")
+            .await
+            .unwrap());
+
+        // Undo could still bring the attachment back this session
+        assert!(AttachmentsRepository::expired_files(&pool)
+            .await
+            .unwrap()
+            .is_empty());
+
+        sqlx::query("UPDATE operation_journal SET session_id = 'earlier'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            AttachmentsRepository::expired_files(&pool).await.unwrap(),
+            ["/a/agenda.pdf"]
+        );
+
+        // Unless another attachment uses the file
+        insert_meeting(&pool, "m2").await;
+        AttachmentsRepository::add_attachment(&pool, "m2", "agenda.pdf", "/a/agenda.pdf", None, 1)
+            .await
+            .unwrap();
+        assert!(AttachmentsRepository::expired_files(&pool)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn undone_deletions_keep_their_files() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        let attachment = AttachmentsRepository::add_attachment(
+            &pool,
+            "m1",
+            "notes.txt",
+            "/a/notes.txt",
+            None,
+            1,
+        )
+        .await
+        .unwrap();
+        assert!(MeetingsRepository::delete_meeting(&pool, "m1")
+            .await
+            .unwrap());
+        JournalRepository::undo_last(&pool).await.unwrap().unwrap();
+        // Removing an attachment deletes its file itself
+        assert!(
+            AttachmentsRepository::delete_attachment(&pool, &attachment.id)
+                .await
+                .unwrap()
+        );
+
+        sqlx::query("UPDATE operation_journal SET session_id = 'earlier'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(AttachmentsRepository::expired_files(&pool)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,76 @@
+use crate::database::models::AuditLogEntry;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use sqlx::{Error as SqlxError, QueryBuilder, Sqlite, SqlitePool};
+
+/// Filters for reading the audit log; all fields are optional
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AuditLogFilter {
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub action: Option<String>,
+    #[serde(rename = "entityType")]
+    pub entity_type: Option<String>,
+    #[serde(rename = "entityId")]
+    pub entity_id: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+pub struct AuditRepository;
+
+impl AuditRepository {
+    pub async fn append(
+        pool: &SqlitePool,
+        actor: &str,
+        action: &str,
+        entity_type: &str,
+        entity_id: Option<&str>,
+        details: Option<&str>,
+    ) -> Result<i64, SqlxError> {
+        let result = sqlx::query(
+            "INSERT INTO audit_log (occurred_at, actor, action, entity_type, entity_id, details)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(Utc::now())
+        .bind(actor)
+        .bind(action)
+        .bind(entity_type)
+        .bind(entity_id)
+        .bind(details)
+        .execute(pool)
+        .await?;
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Newest entries first. Without a limit all matching entries are returned.
+    pub async fn list(
+        pool: &SqlitePool,
+        filter: &AuditLogFilter,
+    ) -> Result<Vec<AuditLogEntry>, SqlxError> {
+        let mut builder: QueryBuilder<Sqlite> =
+            QueryBuilder::new("SELECT * FROM audit_log WHERE 1 = 1");
+        if let Some(since) = filter.since {
+            builder.push(" AND occurred_at >= ").push_bind(since);
+        }
+        if let Some(until) = filter.until {
+            builder.push(" AND occurred_at <= ").push_bind(until);
+        }
+        if let Some(action) = &filter.action {
+            builder.push(" AND action = ").push_bind(action);
+        }
+        if let Some(entity_type) = &filter.entity_type {
+            builder.push(" AND entity_type = ").push_bind(entity_type);
+        }
+        if let Some(entity_id) = &filter.entity_id {
+            builder.push(" AND entity_id = ").push_bind(entity_id);
+        }
+        builder.push(" ORDER BY id DESC LIMIT ").push_bind(filter.limit.unwrap_or(-1));
+        builder.push(" OFFSET ").push_bind(filter.offset.unwrap_or(0).max(0));
+
+        builder
+            .build_query_as::<AuditLogEntry>()
+            .fetch_all(pool)
+            .await
+    }
+}
@@ -0,0 +1,209 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Error as SqlxError, SqlitePool};
+use uuid::Uuid;
+
+use crate::encryption;
+
+/// A correction learned from transcript edits
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CorrectionRule {
+    pub id: String,
+    pub wrong: String,
+    pub corrected: String,
+    /// How often users made this correction
+    pub occurrences: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub struct CorrectionsRepository;
+
+const SELECT_RULES: &str = "SELECT id, wrong, corrected, occurrences, created_at, updated_at
+     FROM correction_rules";
+
+/// Phrases come from transcript text, so they are sealed like it
+fn open_rule(mut rule: CorrectionRule) -> Result<CorrectionRule, SqlxError> {
+    rule.wrong = encryption::open(rule.wrong)?;
+    rule.corrected = encryption::open(rule.corrected)?;
+    Ok(rule)
+}
+
+fn same_phrase(a: &str, b: &str) -> bool {
+    a.to_lowercase() == b.to_lowercase()
+}
+
+impl CorrectionsRepository {
+    pub async fn list_rules(pool: &SqlitePool) -> Result<Vec<CorrectionRule>, SqlxError> {
+        let mut rules = sqlx::query_as::<_, CorrectionRule>(SELECT_RULES)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(open_rule)
+            .collect::<Result<Vec<_>, _>>()?;
+        rules.sort_by(|a, b| {
+            b.occurrences
+                .cmp(&a.occurrences)
+                .then_with(|| a.wrong.to_lowercase().cmp(&b.wrong.to_lowercase()))
+        });
+        Ok(rules)
+    }
+
+    /// Rules made at least `min_occurrences` times
+    pub async fn learned_rules(
+        pool: &SqlitePool,
+        min_occurrences: i64,
+    ) -> Result<Vec<CorrectionRule>, SqlxError> {
+        sqlx::query_as::<_, CorrectionRule>(&format!("{} WHERE occurrences >= ?", SELECT_RULES))
+            .bind(min_occurrences)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(open_rule)
+            .collect()
+    }
+
+    /// Counts one more (wrong -> corrected) edit. A different correction for
+    /// the same phrase starts counting again, and the reverse rule is
+    /// dropped: changing it back means it was wrong. Phrases match ignoring
+    /// case; sealed phrases can't be compared in SQL, so that happens here.
+    pub async fn record_correction(
+        pool: &SqlitePool,
+        wrong: &str,
+        corrected: &str,
+    ) -> Result<(), SqlxError> {
+        let now = Utc::now();
+        let mut transaction = pool.begin().await?;
+        let rules = sqlx::query_as::<_, CorrectionRule>(SELECT_RULES)
+            .fetch_all(&mut *transaction)
+            .await?
+            .into_iter()
+            .map(open_rule)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for rule in &rules {
+            if same_phrase(&rule.wrong, corrected) && same_phrase(&rule.corrected, wrong) {
+                sqlx::query("DELETE FROM correction_rules WHERE id = ?")
+                    .bind(&rule.id)
+                    .execute(&mut *transaction)
+                    .await?;
+            }
+        }
+
+        match rules.iter().find(|rule| same_phrase(&rule.wrong, wrong)) {
+            Some(rule) => {
+                let occurrences = if rule.corrected == corrected {
+                    rule.occurrences + 1
+                } else {
+                    1
+                };
+                sqlx::query(
+                    "UPDATE correction_rules SET corrected = ?, occurrences = ?, updated_at = ?
+                     WHERE id = ?",
+                )
+                .bind(encryption::seal(corrected)?)
+                .bind(occurrences)
+                .bind(now)
+                .bind(&rule.id)
+                .execute(&mut *transaction)
+                .await?;
+            }
+            None => {
+                sqlx::query(
+                    "INSERT INTO correction_rules (id, wrong, corrected, occurrences, created_at, updated_at)
+                     VALUES (?, ?, ?, 1, ?, ?)",
+                )
+                .bind(format!("correction-{}", Uuid::new_v4()))
+                .bind(encryption::seal(wrong)?)
+                .bind(encryption::seal(corrected)?)
+                .bind(now)
+                .bind(now)
+                .execute(&mut *transaction)
+                .await?;
+            }
+        }
+        transaction.commit().await
+    }
+
+    /// Returns false when the rule does not exist
+    pub async fn delete_rule(pool: &SqlitePool, id: &str) -> Result<bool, SqlxError> {
+        let result = sqlx::query("DELETE FROM correction_rules WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::memory_pool;
+
+    fn pairs(rules: &[CorrectionRule]) -> Vec<(&str, &str, i64)> {
+        rules
+            .iter()
+            .map(|r| (r.wrong.as_str(), r.corrected.as_str(), r.occurrences))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn repeated_corrections_are_learned() {
+        let pool = memory_pool().await;
+        CorrectionsRepository::record_correction(&pool, "cuber netties", "Kubernetes")
+            .await
+            .unwrap();
+        assert!(CorrectionsRepository::learned_rules(&pool, 2)
+            .await
+            .unwrap()
+            .is_empty());
+
+        CorrectionsRepository::record_correction(&pool, "Cuber Netties", "Kubernetes")
+            .await
+            .unwrap();
+        let learned = CorrectionsRepository::learned_rules(&pool, 2)
+            .await
+            .unwrap();
+        assert_eq!(pairs(&learned), vec![("cuber netties", "Kubernetes", 2)]);
+    }
+
+    #[tokio::test]
+    async fn a_new_correction_restarts_the_count_and_reversing_drops_the_rule() {
+        let pool = memory_pool().await;
+        for _ in 0..2 {
+            CorrectionsRepository::record_correction(&pool, "規格", "企画")
+                .await
+                .unwrap();
+        }
+        CorrectionsRepository::record_correction(&pool, "規格", "計画")
+            .await
+            .unwrap();
+        CorrectionsRepository::record_correction(&pool, "core", "Kor")
+            .await
+            .unwrap();
+        assert_eq!(
+            pairs(&CorrectionsRepository::list_rules(&pool).await.unwrap()),
+            vec![("core", "Kor", 1), ("規格", "計画", 1)]
+        );
+
+        CorrectionsRepository::record_correction(&pool, "kor", "Core")
+            .await
+            .unwrap();
+        let rules = CorrectionsRepository::list_rules(&pool).await.unwrap();
+        assert_eq!(pairs(&rules), vec![("kor", "Core", 1), ("規格", "計画", 1)]);
+
+        assert!(CorrectionsRepository::delete_rule(&pool, &rules[0].id)
+            .await
+            .unwrap());
+        assert!(!CorrectionsRepository::delete_rule(&pool, &rules[0].id)
+            .await
+            .unwrap());
+        assert_eq!(
+            CorrectionsRepository::list_rules(&pool)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,422 @@
+use crate::database::models::{CustomFieldDefinition, MeetingCustomFieldValue};
+use crate::locks;
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, Error as SqlxError, SqlitePool};
+use std::str::FromStr;
+use tracing::info;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CustomFieldType {
+    Text,
+    Number,
+    Date,
+    Boolean,
+    Select,
+}
+
+impl CustomFieldType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Number => "number",
+            Self::Date => "date",
+            Self::Boolean => "boolean",
+            Self::Select => "select",
+        }
+    }
+}
+
+impl FromStr for CustomFieldType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "number" => Ok(Self::Number),
+            "date" => Ok(Self::Date),
+            "boolean" => Ok(Self::Boolean),
+            "select" => Ok(Self::Select),
+            other => Err(format!(
+                "Unknown field type '{}'. Use text, number, date, boolean or select",
+                other
+            )),
+        }
+    }
+}
+
+/// Field definition supplied by the frontend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomFieldInput {
+    pub name: String,
+    pub field_type: String,
+    /// Allowed values, required for "select" fields
+    pub options: Option<Vec<String>>,
+    pub position: Option<i64>,
+}
+
+/// Checks a raw value against the field type and returns its stored form
+pub fn normalize_value(
+    field_type: CustomFieldType,
+    options: &[String],
+    raw: &str,
+) -> Result<String, String> {
+    let value = raw.trim();
+    if value.is_empty() {
+        return Err("Value cannot be empty".to_string());
+    }
+    match field_type {
+        CustomFieldType::Text => Ok(value.to_string()),
+        CustomFieldType::Number => {
+            let number: f64 = value
+                .parse()
+                .ok()
+                .filter(|n: &f64| n.is_finite())
+                .ok_or_else(|| format!("'{}' is not a number", value))?;
+            Ok(number.to_string())
+        }
+        CustomFieldType::Date => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .map_err(|_| format!("'{}' is not a date. Use YYYY-MM-DD", value)),
+        CustomFieldType::Boolean => match value.to_lowercase().as_str() {
+            "true" | "yes" | "1" => Ok("true".to_string()),
+            "false" | "no" | "0" => Ok("false".to_string()),
+            _ => Err(format!("'{}' is not true or false", value)),
+        },
+        CustomFieldType::Select => options
+            .iter()
+            .find(|option| option.eq_ignore_ascii_case(value))
+            .cloned()
+            .ok_or_else(|| format!("'{}' is not one of: {}", value, options.join(", "))),
+    }
+}
+
+fn parse_options(options: Option<&str>) -> Vec<String> {
+    options
+        .and_then(|o| serde_json::from_str(o).ok())
+        .unwrap_or_default()
+}
+
+/// Validates a definition and returns (name, type, JSON options)
+fn validate_input(input: &CustomFieldInput) -> Result<(String, CustomFieldType, Option<String>), String> {
+    let name = input.name.trim();
+    if name.is_empty() {
+        return Err("Field name cannot be empty".to_string());
+    }
+    let field_type: CustomFieldType = input.field_type.parse()?;
+    let options = match field_type {
+        CustomFieldType::Select => {
+            let mut options: Vec<String> = Vec::new();
+            for option in input.options.iter().flatten().map(|o| o.trim()) {
+                if !option.is_empty() && !options.iter().any(|o| o.eq_ignore_ascii_case(option)) {
+                    options.push(option.to_string());
+                }
+            }
+            if options.is_empty() {
+                return Err("Select fields need at least one option".to_string());
+            }
+            Some(serde_json::to_string(&options).map_err(|e| e.to_string())?)
+        }
+        _ => None,
+    };
+    Ok((name.to_string(), field_type, options))
+}
+
+/// A meeting whose custom field value matched a search
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct CustomFieldMatch {
+    pub meeting_id: String,
+    pub title: String,
+    pub field_name: String,
+    pub value: String,
+    pub created_at: String,
+}
+
+fn invalid(message: String) -> SqlxError {
+    SqlxError::Protocol(message)
+}
+
+pub struct CustomFieldsRepository;
+
+impl CustomFieldsRepository {
+    pub async fn list_definitions(pool: &SqlitePool) -> Result<Vec<CustomFieldDefinition>, SqlxError> {
+        sqlx::query_as::<_, CustomFieldDefinition>(
+            "SELECT * FROM custom_field_definitions ORDER BY position ASC, name ASC",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_definition(
+        pool: &SqlitePool,
+        field_id: &str,
+    ) -> Result<Option<CustomFieldDefinition>, SqlxError> {
+        sqlx::query_as::<_, CustomFieldDefinition>("SELECT * FROM custom_field_definitions WHERE id = ?")
+            .bind(field_id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    pub async fn create_definition(
+        pool: &SqlitePool,
+        input: &CustomFieldInput,
+    ) -> Result<CustomFieldDefinition, SqlxError> {
+        let (name, field_type, options) = validate_input(input).map_err(invalid)?;
+        let id = format!("field-{}", Uuid::new_v4());
+        let now = Utc::now();
+        let position = match input.position {
+            Some(position) => position,
+            None => sqlx::query_scalar::<_, i64>(
+                "SELECT COALESCE(MAX(position), -1) + 1 FROM custom_field_definitions",
+            )
+            .fetch_one(pool)
+            .await?,
+        };
+
+        sqlx::query(
+            "INSERT INTO custom_field_definitions (id, name, field_type, options, position, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&id)
+        .bind(&name)
+        .bind(field_type.as_str())
+        .bind(&options)
+        .bind(position)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await?;
+        info!("Created custom field {} ({})", name, field_type.as_str());
+
+        Self::get_definition(pool, &id)
+            .await?
+            .ok_or(SqlxError::RowNotFound)
+    }
+
+    /// Updates a definition. Existing values are converted to the new type;
+    /// the update is rejected if any of them would not be valid any more.
+    pub async fn update_definition(
+        pool: &SqlitePool,
+        field_id: &str,
+        input: &CustomFieldInput,
+    ) -> Result<Option<CustomFieldDefinition>, SqlxError> {
+        let (name, field_type, options) = validate_input(input).map_err(invalid)?;
+        let option_list = parse_options(options.as_deref());
+
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        let existing: Option<i64> =
+            sqlx::query_scalar("SELECT position FROM custom_field_definitions WHERE id = ?")
+                .bind(field_id)
+                .fetch_optional(&mut *transaction)
+                .await?;
+        let Some(position) = existing else {
+            transaction.rollback().await?;
+            return Ok(None);
+        };
+
+        let values: Vec<(String, String)> =
+            sqlx::query_as("SELECT meeting_id, value FROM meeting_custom_fields WHERE field_id = ?")
+                .bind(field_id)
+                .fetch_all(&mut *transaction)
+                .await?;
+        let now = Utc::now();
+        for (meeting_id, value) in values {
+            let converted = normalize_value(field_type, &option_list, &value).map_err(|e| {
+                invalid(format!(
+                    "Cannot change field '{}': meeting {} has value {}",
+                    name, meeting_id, e
+                ))
+            })?;
+            if converted != value {
+                sqlx::query(
+                    "UPDATE meeting_custom_fields SET value = ?, updated_at = ? WHERE meeting_id = ? AND field_id = ?",
+                )
+                .bind(&converted)
+                .bind(now)
+                .bind(&meeting_id)
+                .bind(field_id)
+                .execute(&mut *transaction)
+                .await?;
+            }
+        }
+
+        sqlx::query(
+            "UPDATE custom_field_definitions SET name = ?, field_type = ?, options = ?, position = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(&name)
+        .bind(field_type.as_str())
+        .bind(&options)
+        .bind(input.position.unwrap_or(position))
+        .bind(now)
+        .bind(field_id)
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+
+        Self::get_definition(pool, field_id).await
+    }
+
+    /// Deletes a definition and every meeting's value for it
+    pub async fn delete_definition(pool: &SqlitePool, field_id: &str) -> Result<bool, SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+        sqlx::query("DELETE FROM meeting_custom_fields WHERE field_id = ?")
+            .bind(field_id)
+            .execute(&mut *transaction)
+            .await?;
+        let result = sqlx::query("DELETE FROM custom_field_definitions WHERE id = ?")
+            .bind(field_id)
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn get_meeting_values(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<MeetingCustomFieldValue>, SqlxError> {
+        sqlx::query_as::<_, MeetingCustomFieldValue>(
+            "SELECT v.meeting_id, v.field_id, d.name, d.field_type, v.value
+             FROM meeting_custom_fields v
+             JOIN custom_field_definitions d ON d.id = v.field_id
+             WHERE v.meeting_id = ?
+             ORDER BY d.position ASC, d.name ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Every meeting's values, for the meetings list and exports
+    pub async fn list_all_values(pool: &SqlitePool) -> Result<Vec<MeetingCustomFieldValue>, SqlxError> {
+        sqlx::query_as::<_, MeetingCustomFieldValue>(
+            "SELECT v.meeting_id, v.field_id, d.name, d.field_type, v.value
+             FROM meeting_custom_fields v
+             JOIN custom_field_definitions d ON d.id = v.field_id
+             ORDER BY d.position ASC, d.name ASC",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Sets or, with an empty/None value, clears a meeting's value for a field.
+    /// Returns the stored value.
+    pub async fn set_value(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        field_id: &str,
+        value: Option<&str>,
+    ) -> Result<Option<MeetingCustomFieldValue>, SqlxError> {
+        locks::ensure_unlocked(meeting_id)?;
+        let definition = Self::get_definition(pool, field_id)
+            .await?
+            .ok_or_else(|| invalid(format!("Custom field not found: {}", field_id)))?;
+
+        let Some(raw) = value.filter(|v| !v.trim().is_empty()) else {
+            sqlx::query("DELETE FROM meeting_custom_fields WHERE meeting_id = ? AND field_id = ?")
+                .bind(meeting_id)
+                .bind(field_id)
+                .execute(pool)
+                .await?;
+            return Ok(None);
+        };
+
+        let field_type: CustomFieldType = definition.field_type.parse().map_err(invalid)?;
+        let normalized = normalize_value(
+            field_type,
+            &parse_options(definition.options.as_deref()),
+            raw,
+        )
+        .map_err(|e| invalid(format!("{}: {}", definition.name, e)))?;
+
+        sqlx::query(
+            "INSERT INTO meeting_custom_fields (meeting_id, field_id, value, updated_at)
+             VALUES (?, ?, ?, ?)
+             ON CONFLICT(meeting_id, field_id) DO UPDATE SET
+                value = excluded.value,
+                updated_at = excluded.updated_at",
+        )
+        .bind(meeting_id)
+        .bind(field_id)
+        .bind(&normalized)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+
+        Ok(Some(MeetingCustomFieldValue {
+            meeting_id: meeting_id.to_string(),
+            field_id: field_id.to_string(),
+            name: definition.name,
+            field_type: definition.field_type,
+            value: normalized,
+        }))
+    }
+
+    /// Meetings with a custom field value containing `query` (case-insensitive)
+    pub async fn search_values(
+        pool: &SqlitePool,
+        query: &str,
+    ) -> Result<Vec<CustomFieldMatch>, SqlxError> {
+        sqlx::query_as::<_, CustomFieldMatch>(
+            "SELECT m.id AS meeting_id, m.title, d.name AS field_name, v.value, m.created_at
+             FROM meeting_custom_fields v
+             JOIN custom_field_definitions d ON d.id = v.field_id
+             JOIN meetings m ON m.id = v.meeting_id
+             WHERE LOWER(v.value) LIKE ?
+             ORDER BY m.created_at DESC",
+        )
+        .bind(format!("%{}%", query.to_lowercase()))
+        .fetch_all(pool)
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_values_per_type() {
+        let none: &[String] = &[];
+        assert_eq!(normalize_value(CustomFieldType::Text, none, "  Acme  ").unwrap(), "Acme");
+        assert_eq!(normalize_value(CustomFieldType::Number, none, "42.50").unwrap(), "42.5");
+        assert_eq!(normalize_value(CustomFieldType::Number, none, "1e3").unwrap(), "1000");
+        assert!(normalize_value(CustomFieldType::Number, none, "NaN").is_err());
+        assert_eq!(normalize_value(CustomFieldType::Date, none, "2026-03-05").unwrap(), "2026-03-05");
+        assert!(normalize_value(CustomFieldType::Date, none, "05/03/2026").is_err());
+        assert_eq!(normalize_value(CustomFieldType::Boolean, none, "Yes").unwrap(), "true");
+        assert!(normalize_value(CustomFieldType::Boolean, none, "maybe").is_err());
+        assert!(normalize_value(CustomFieldType::Text, none, "   ").is_err());
+    }
+
+    #[test]
+    fn select_values_must_be_an_option() {
+        let options = vec!["Billable".to_string(), "Internal".to_string()];
+        assert_eq!(
+            normalize_value(CustomFieldType::Select, &options, "billable").unwrap(),
+            "Billable"
+        );
+        assert!(normalize_value(CustomFieldType::Select, &options, "Other").is_err());
+    }
+
+    #[test]
+    fn validates_definitions() {
+        let input = |field_type: &str, options: Option<Vec<&str>>| CustomFieldInput {
+            name: " Client ".to_string(),
+            field_type: field_type.to_string(),
+            options: options.map(|o| o.into_iter().map(String::from).collect()),
+            position: None,
+        };
+        let (name, field_type, options) = validate_input(&input("text", None)).unwrap();
+        assert_eq!((name.as_str(), field_type, options), ("Client", CustomFieldType::Text, None));
+        assert!(validate_input(&input("select", Some(vec![" ", ""]))).is_err());
+        assert!(validate_input(&input("currency", None)).is_err());
+        let (_, _, options) = validate_input(&input("select", Some(vec!["A", "a", "B"]))).unwrap();
+        assert_eq!(options.as_deref(), Some("[\"A\",\"B\"]"));
+    }
+}
@@ -0,0 +1,68 @@
+use crate::database::models::MeetingDeltaReport;
+use crate::encryption;
+use chrono::Utc;
+use sqlx::{Error as SqlxError, SqlitePool};
+use tracing::info;
+
+fn open_report(mut report: MeetingDeltaReport) -> Result<MeetingDeltaReport, SqlxError> {
+    report.report = encryption::open(std::mem::take(&mut report.report))?;
+    Ok(report)
+}
+
+pub struct DeltaReportsRepository;
+
+impl DeltaReportsRepository {
+    pub async fn get_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Option<MeetingDeltaReport>, SqlxError> {
+        sqlx::query_as::<_, MeetingDeltaReport>(
+            "SELECT * FROM meeting_delta_reports WHERE meeting_id = ?",
+        )
+        .bind(meeting_id)
+        .fetch_optional(pool)
+        .await?
+        .map(open_report)
+        .transpose()
+    }
+
+    /// Stores the report on the newer meeting, replacing any previous comparison
+    pub async fn save(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        previous_meeting_id: &str,
+        report: &str,
+        action_item_delta: &str,
+        provider: &str,
+        model: &str,
+    ) -> Result<MeetingDeltaReport, SqlxError> {
+        sqlx::query(
+            "INSERT INTO meeting_delta_reports (meeting_id, previous_meeting_id, report, action_item_delta, provider, model, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(meeting_id) DO UPDATE SET
+                previous_meeting_id = excluded.previous_meeting_id,
+                report = excluded.report,
+                action_item_delta = excluded.action_item_delta,
+                provider = excluded.provider,
+                model = excluded.model,
+                created_at = excluded.created_at",
+        )
+        .bind(meeting_id)
+        .bind(previous_meeting_id)
+        .bind(encryption::seal(report)?)
+        .bind(action_item_delta)
+        .bind(provider)
+        .bind(model)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+
+        info!(
+            "Saved delta report for meeting {} (compared with {})",
+            meeting_id, previous_meeting_id
+        );
+        Self::get_for_meeting(pool, meeting_id)
+            .await?
+            .ok_or(SqlxError::RowNotFound)
+    }
+}
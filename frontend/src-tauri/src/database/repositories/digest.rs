@@ -0,0 +1,106 @@
+use crate::encryption;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Error as SqlxError, SqlitePool};
+use uuid::Uuid;
+
+/// Brief covering every meeting of a period
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Digest {
+    pub id: String,
+    /// "daily", "weekly" or "custom"
+    pub period: String,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub title: String,
+    /// Markdown: the model's brief followed by the action items
+    pub content: String,
+    /// JSON array of the meeting ids covered
+    pub meeting_ids: String,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+fn open_digest(mut digest: Digest) -> Result<Digest, SqlxError> {
+    digest.content = encryption::open(std::mem::take(&mut digest.content))?;
+    Ok(digest)
+}
+
+pub struct DigestsRepository;
+
+impl DigestsRepository {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn save(
+        pool: &SqlitePool,
+        period: &str,
+        period_start: DateTime<Utc>,
+        period_end: DateTime<Utc>,
+        title: &str,
+        content: &str,
+        meeting_ids: &[String],
+        provider: &str,
+        model: &str,
+    ) -> Result<Digest, SqlxError> {
+        let meeting_ids = serde_json::to_string(meeting_ids)
+            .map_err(|e| SqlxError::Protocol(format!("Failed to serialize meeting ids: {}", e)))?;
+        let digest = Digest {
+            id: format!("digest-{}", Uuid::new_v4()),
+            period: period.to_string(),
+            period_start,
+            period_end,
+            title: title.to_string(),
+            content: content.to_string(),
+            meeting_ids,
+            provider: Some(provider.to_string()),
+            model: Some(model.to_string()),
+            created_at: Utc::now(),
+        };
+        sqlx::query(
+            "INSERT INTO digests (id, period, period_start, period_end, title, content, meeting_ids, provider, model, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&digest.id)
+        .bind(&digest.period)
+        .bind(digest.period_start)
+        .bind(digest.period_end)
+        .bind(&digest.title)
+        .bind(encryption::seal(&digest.content)?)
+        .bind(&digest.meeting_ids)
+        .bind(&digest.provider)
+        .bind(&digest.model)
+        .bind(digest.created_at)
+        .execute(pool)
+        .await?;
+        Ok(digest)
+    }
+
+    /// Newest first
+    pub async fn list(pool: &SqlitePool, limit: i64) -> Result<Vec<Digest>, SqlxError> {
+        sqlx::query_as::<_, Digest>("SELECT * FROM digests ORDER BY created_at DESC LIMIT ?")
+            .bind(limit)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(open_digest)
+            .collect()
+    }
+
+    pub async fn get(pool: &SqlitePool, id: &str) -> Result<Option<Digest>, SqlxError> {
+        sqlx::query_as::<_, Digest>("SELECT * FROM digests WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await?
+            .map(open_digest)
+            .transpose()
+    }
+
+    /// Returns false when the digest does not exist
+    pub async fn delete(pool: &SqlitePool, id: &str) -> Result<bool, SqlxError> {
+        let result = sqlx::query("DELETE FROM digests WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
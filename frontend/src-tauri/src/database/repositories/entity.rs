@@ -0,0 +1,179 @@
+use crate::summary::entities::{normalize_entity, ExtractedEntity};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, Error as SqlxError, QueryBuilder, Sqlite, SqlitePool};
+
+/// One entity across all meetings, for the entity browser
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct EntitySummary {
+    pub kind: String,
+    /// The most frequent spelling
+    pub name: String,
+    pub normalized: String,
+    pub mention_count: i64,
+    pub meeting_count: i64,
+}
+
+/// An entity as it appears in one meeting
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct MeetingEntity {
+    pub kind: String,
+    pub name: String,
+    pub normalized: String,
+    pub mention_count: i64,
+    /// Segment of the first mention, for jumping to it
+    pub first_transcript_id: String,
+}
+
+/// A meeting that mentions an entity
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct EntityMention {
+    pub meeting_id: String,
+    pub meeting_title: String,
+    pub created_at: String,
+    pub mention_count: i64,
+}
+
+const DEFAULT_ENTITY_LIMIT: i64 = 200;
+
+pub struct EntityRepository;
+
+impl EntityRepository {
+    /// Speaker names and participants of a meeting, which the local extractor
+    /// treats as people
+    pub async fn known_people(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<String>, SqlxError> {
+        sqlx::query_scalar(
+            "SELECT display_name FROM meeting_speakers WHERE meeting_id = ? AND display_name IS NOT NULL
+             UNION SELECT name FROM meeting_participants WHERE meeting_id = ?",
+        )
+        .bind(meeting_id)
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Replaces a meeting's entities with `entities` (transcript id, entity)
+    pub async fn save_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        entities: &[(String, ExtractedEntity)],
+        method: &str,
+    ) -> Result<(), SqlxError> {
+        let now = Utc::now();
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+        sqlx::query("DELETE FROM transcript_entities WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        for (transcript_id, entity) in entities {
+            sqlx::query(
+                "INSERT INTO transcript_entities (meeting_id, transcript_id, kind, name, normalized, method, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(meeting_id)
+            .bind(transcript_id)
+            .bind(entity.kind.as_str())
+            .bind(&entity.name)
+            .bind(&entity.normalized)
+            .bind(method)
+            .bind(now)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await
+    }
+
+    /// Entities across all meetings, most mentioned first. `query` matches the
+    /// start of any word of the normalized name.
+    pub async fn list_entities(
+        pool: &SqlitePool,
+        kind: Option<&str>,
+        query: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<EntitySummary>, SqlxError> {
+        let mut builder: QueryBuilder<Sqlite> = QueryBuilder::new(
+            "SELECT e.kind,
+                    (SELECT n.name FROM transcript_entities n WHERE n.kind = e.kind AND n.normalized = e.normalized
+                     GROUP BY n.name ORDER BY COUNT(*) DESC, n.name LIMIT 1) AS name,
+                    e.normalized, COUNT(*) AS mention_count, COUNT(DISTINCT e.meeting_id) AS meeting_count
+             FROM transcript_entities e WHERE 1 = 1",
+        );
+        if let Some(kind) = kind {
+            builder
+                .push(" AND e.kind = ")
+                .push_bind(kind.trim().to_lowercase());
+        }
+        if let Some(query) = query.map(normalize_entity).filter(|q| !q.is_empty()) {
+            let escaped = query
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+            builder
+                .push(" AND (e.normalized LIKE ")
+                .push_bind(format!("{}%", escaped))
+                .push(" ESCAPE '\\' OR e.normalized LIKE ")
+                .push_bind(format!("% {}%", escaped))
+                .push(" ESCAPE '\\')");
+        }
+        builder
+            .push(" GROUP BY e.kind, e.normalized ORDER BY mention_count DESC, e.normalized ASC LIMIT ")
+            .push_bind(limit.unwrap_or(DEFAULT_ENTITY_LIMIT).max(1));
+        builder
+            .build_query_as::<EntitySummary>()
+            .fetch_all(pool)
+            .await
+    }
+
+    /// A meeting's entities in order of first mention
+    pub async fn get_meeting_entities(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<MeetingEntity>, SqlxError> {
+        sqlx::query_as::<_, MeetingEntity>(
+            "SELECT e.kind, MIN(e.name) AS name, e.normalized, COUNT(*) AS mention_count,
+                    (SELECT f.transcript_id FROM transcript_entities f
+                     JOIN transcripts t ON t.id = f.transcript_id
+                     WHERE f.meeting_id = e.meeting_id AND f.kind = e.kind AND f.normalized = e.normalized
+                     ORDER BY t.audio_start_time IS NULL, t.audio_start_time, t.rowid LIMIT 1) AS first_transcript_id
+             FROM transcript_entities e WHERE e.meeting_id = ?
+             GROUP BY e.kind, e.normalized
+             ORDER BY MIN(e.id)",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Meetings mentioning `name` (any spelling that normalizes the same),
+    /// newest first
+    pub async fn meetings_mentioning(
+        pool: &SqlitePool,
+        name: &str,
+        kind: Option<&str>,
+    ) -> Result<Vec<EntityMention>, SqlxError> {
+        let normalized = normalize_entity(name);
+        if normalized.is_empty() {
+            return Err(SqlxError::Protocol("Entity name is empty".to_string()));
+        }
+        let mut builder: QueryBuilder<Sqlite> = QueryBuilder::new(
+            "SELECT m.id AS meeting_id, m.title AS meeting_title, m.created_at, COUNT(*) AS mention_count
+             FROM transcript_entities e JOIN meetings m ON m.id = e.meeting_id
+             WHERE e.normalized = ",
+        );
+        builder.push_bind(normalized);
+        if let Some(kind) = kind {
+            builder
+                .push(" AND e.kind = ")
+                .push_bind(kind.trim().to_lowercase());
+        }
+        builder.push(" GROUP BY m.id ORDER BY m.created_at DESC");
+        builder
+            .build_query_as::<EntityMention>()
+            .fetch_all(pool)
+            .await
+    }
+}
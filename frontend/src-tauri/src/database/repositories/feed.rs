@@ -0,0 +1,435 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, Error as SqlxError, SqlitePool};
+use uuid::Uuid;
+
+/// Imports of an episode that fail this often are given up on
+pub const MAX_EPISODE_ATTEMPTS: i64 = 3;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct FeedSubscription {
+    pub id: String,
+    pub url: String,
+    /// The feed's own title; imported episodes are tagged with it
+    pub title: String,
+    /// New episodes are imported automatically while enabled
+    pub enabled: bool,
+    pub last_checked_at: Option<DateTime<Utc>>,
+    /// Why the last check failed
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct FeedEpisode {
+    pub id: String,
+    pub feed_id: String,
+    pub guid: String,
+    pub title: String,
+    pub media_url: String,
+    pub published_at: Option<DateTime<Utc>>,
+    /// "pending", "imported", "failed" or "skipped"
+    pub status: String,
+    pub meeting_id: Option<String>,
+    pub error: Option<String>,
+    pub attempts: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+/// An episode as read from the feed
+#[derive(Debug, Clone, PartialEq)]
+pub struct EpisodeInput {
+    pub guid: String,
+    pub title: String,
+    pub media_url: String,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+const FEED_COLUMNS: &str = "id, url, title, enabled, last_checked_at, last_error, created_at";
+const EPISODE_COLUMNS: &str = "id, feed_id, guid, title, media_url, published_at, status, meeting_id, error, attempts, created_at";
+
+pub struct FeedsRepository;
+
+impl FeedsRepository {
+    pub async fn list_feeds(pool: &SqlitePool) -> Result<Vec<FeedSubscription>, SqlxError> {
+        sqlx::query_as::<_, FeedSubscription>(&format!(
+            "SELECT {} FROM feed_subscriptions ORDER BY title COLLATE NOCASE",
+            FEED_COLUMNS
+        ))
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_feed(
+        pool: &SqlitePool,
+        id: &str,
+    ) -> Result<Option<FeedSubscription>, SqlxError> {
+        sqlx::query_as::<_, FeedSubscription>(&format!(
+            "SELECT {} FROM feed_subscriptions WHERE id = ?",
+            FEED_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Subscribes to `url`. The episodes already in the feed are stored too:
+    /// the newest `backfill` of them to be imported, the rest as skipped.
+    pub async fn add_feed(
+        pool: &SqlitePool,
+        url: &str,
+        title: &str,
+        episodes: &[EpisodeInput],
+        backfill: usize,
+    ) -> Result<FeedSubscription, SqlxError> {
+        let exists: Option<String> =
+            sqlx::query_scalar("SELECT id FROM feed_subscriptions WHERE url = ?")
+                .bind(url)
+                .fetch_optional(pool)
+                .await?;
+        if exists.is_some() {
+            return Err(SqlxError::Protocol(format!(
+                "Already subscribed to {}",
+                url
+            )));
+        }
+
+        let feed = FeedSubscription {
+            id: format!("feed-{}", Uuid::new_v4()),
+            url: url.to_string(),
+            title: title.to_string(),
+            enabled: true,
+            last_checked_at: Some(Utc::now()),
+            last_error: None,
+            created_at: Utc::now(),
+        };
+        let mut newest_first: Vec<&EpisodeInput> = episodes.iter().collect();
+        newest_first.sort_by_key(|episode| std::cmp::Reverse(episode.published_at));
+
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+        sqlx::query(
+            "INSERT INTO feed_subscriptions (id, url, title, enabled, last_checked_at, created_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&feed.id)
+        .bind(&feed.url)
+        .bind(&feed.title)
+        .bind(feed.enabled)
+        .bind(feed.last_checked_at)
+        .bind(feed.created_at)
+        .execute(&mut *transaction)
+        .await?;
+        for (index, episode) in newest_first.into_iter().enumerate() {
+            let status = if index < backfill {
+                "pending"
+            } else {
+                "skipped"
+            };
+            insert_episode(&mut transaction, &feed.id, episode, status).await?;
+        }
+        transaction.commit().await?;
+        Ok(feed)
+    }
+
+    /// Returns false when the feed does not exist
+    pub async fn set_enabled(
+        pool: &SqlitePool,
+        id: &str,
+        enabled: bool,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query("UPDATE feed_subscriptions SET enabled = ? WHERE id = ?")
+            .bind(enabled)
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Unsubscribes. Meetings imported from the feed are kept.
+    pub async fn delete_feed(pool: &SqlitePool, id: &str) -> Result<bool, SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+        sqlx::query("DELETE FROM feed_episodes WHERE feed_id = ?")
+            .bind(id)
+            .execute(&mut *transaction)
+            .await?;
+        let result = sqlx::query("DELETE FROM feed_subscriptions WHERE id = ?")
+            .bind(id)
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Stores the outcome of a check; the title follows the feed's when given
+    pub async fn mark_checked(
+        pool: &SqlitePool,
+        id: &str,
+        title: Option<&str>,
+        error: Option<&str>,
+    ) -> Result<(), SqlxError> {
+        sqlx::query(
+            "UPDATE feed_subscriptions
+             SET last_checked_at = ?, last_error = ?, title = COALESCE(?, title)
+             WHERE id = ?",
+        )
+        .bind(Utc::now())
+        .bind(error)
+        .bind(title)
+        .bind(id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Stores the episodes not seen before as pending. Returns how many were new.
+    pub async fn add_new_episodes(
+        pool: &SqlitePool,
+        feed_id: &str,
+        episodes: &[EpisodeInput],
+    ) -> Result<u64, SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+        let mut added = 0;
+        for episode in episodes {
+            added += insert_episode(&mut transaction, feed_id, episode, "pending").await?;
+        }
+        transaction.commit().await?;
+        Ok(added)
+    }
+
+    /// A feed's episodes, newest first
+    pub async fn list_episodes(
+        pool: &SqlitePool,
+        feed_id: &str,
+    ) -> Result<Vec<FeedEpisode>, SqlxError> {
+        sqlx::query_as::<_, FeedEpisode>(&format!(
+            "SELECT {} FROM feed_episodes WHERE feed_id = ?
+             ORDER BY published_at DESC, created_at DESC",
+            EPISODE_COLUMNS
+        ))
+        .bind(feed_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Episodes waiting to be imported from enabled feeds, oldest first
+    pub async fn pending_episodes(pool: &SqlitePool) -> Result<Vec<FeedEpisode>, SqlxError> {
+        sqlx::query_as::<_, FeedEpisode>(
+            "SELECT e.id, e.feed_id, e.guid, e.title, e.media_url, e.published_at, e.status,
+                    e.meeting_id, e.error, e.attempts, e.created_at
+             FROM feed_episodes e JOIN feed_subscriptions f ON f.id = e.feed_id
+             WHERE e.status = 'pending' AND f.enabled = 1
+             ORDER BY e.published_at ASC, e.created_at ASC",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn mark_imported(
+        pool: &SqlitePool,
+        id: &str,
+        meeting_id: &str,
+    ) -> Result<(), SqlxError> {
+        sqlx::query(
+            "UPDATE feed_episodes SET status = 'imported', meeting_id = ?, error = NULL WHERE id = ?",
+        )
+        .bind(meeting_id)
+        .bind(id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Counts a failed import; the episode stays pending until it has
+    /// failed [`MAX_EPISODE_ATTEMPTS`] times
+    pub async fn mark_failed(pool: &SqlitePool, id: &str, error: &str) -> Result<(), SqlxError> {
+        sqlx::query(
+            "UPDATE feed_episodes
+             SET attempts = attempts + 1, error = ?,
+                 status = CASE WHEN attempts + 1 >= ? THEN 'failed' ELSE 'pending' END
+             WHERE id = ?",
+        )
+        .bind(error)
+        .bind(MAX_EPISODE_ATTEMPTS)
+        .bind(id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn skip_episode(pool: &SqlitePool, id: &str) -> Result<(), SqlxError> {
+        sqlx::query("UPDATE feed_episodes SET status = 'skipped' WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Queues a skipped or failed episode for import. Returns false when it
+    /// does not exist or was already imported.
+    pub async fn queue_episode(pool: &SqlitePool, id: &str) -> Result<bool, SqlxError> {
+        let result = sqlx::query(
+            "UPDATE feed_episodes SET status = 'pending', attempts = 0, error = NULL
+             WHERE id = ? AND status IN ('skipped', 'failed')",
+        )
+        .bind(id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// Inserts an episode unless the feed already has its guid
+async fn insert_episode(
+    conn: &mut sqlx::SqliteConnection,
+    feed_id: &str,
+    episode: &EpisodeInput,
+    status: &str,
+) -> Result<u64, SqlxError> {
+    let result = sqlx::query(
+        "INSERT OR IGNORE INTO feed_episodes
+             (id, feed_id, guid, title, media_url, published_at, status, created_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(format!("episode-{}", Uuid::new_v4()))
+    .bind(feed_id)
+    .bind(&episode.guid)
+    .bind(&episode.title)
+    .bind(&episode.media_url)
+    .bind(episode.published_at)
+    .bind(status)
+    .bind(Utc::now())
+    .execute(conn)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::{insert_meeting, memory_pool};
+    use chrono::TimeZone;
+
+    fn episode(guid: &str, day: u32) -> EpisodeInput {
+        EpisodeInput {
+            guid: guid.to_string(),
+            title: format!("Episode {}", guid),
+            media_url: format!("https://example.com/{}.mp3", guid),
+            published_at: Some(Utc.with_ymd_and_hms(2026, 10, day, 9, 0, 0).unwrap()),
+        }
+    }
+
+    async fn status_of(pool: &SqlitePool, feed_id: &str, guid: &str) -> FeedEpisode {
+        FeedsRepository::list_episodes(pool, feed_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|e| e.guid == guid)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn subscribing_backfills_only_the_newest_episodes() {
+        let pool = memory_pool().await;
+        let episodes = [episode("a", 1), episode("c", 3), episode("b", 2)];
+        let feed =
+            FeedsRepository::add_feed(&pool, "https://example.com/feed", "Radio", &episodes, 2)
+                .await
+                .unwrap();
+
+        assert_eq!(status_of(&pool, &feed.id, "c").await.status, "pending");
+        assert_eq!(status_of(&pool, &feed.id, "b").await.status, "pending");
+        assert_eq!(status_of(&pool, &feed.id, "a").await.status, "skipped");
+        let pending = FeedsRepository::pending_episodes(&pool).await.unwrap();
+        assert_eq!(
+            pending.iter().map(|e| e.guid.as_str()).collect::<Vec<_>>(),
+            vec!["b", "c"]
+        );
+        assert!(
+            FeedsRepository::add_feed(&pool, "https://example.com/feed", "Radio", &[], 0)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn episodes_already_seen_are_not_added_again() {
+        let pool = memory_pool().await;
+        let feed = FeedsRepository::add_feed(
+            &pool,
+            "https://example.com/feed",
+            "Radio",
+            &[episode("a", 1)],
+            0,
+        )
+        .await
+        .unwrap();
+
+        let added =
+            FeedsRepository::add_new_episodes(&pool, &feed.id, &[episode("a", 1), episode("b", 2)])
+                .await
+                .unwrap();
+        assert_eq!(added, 1);
+        assert_eq!(
+            FeedsRepository::list_episodes(&pool, &feed.id)
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+        // The skipped episode stays skipped
+        assert_eq!(status_of(&pool, &feed.id, "a").await.status, "skipped");
+    }
+
+    #[tokio::test]
+    async fn failing_episodes_are_given_up_on_and_can_be_queued_again() {
+        let pool = memory_pool().await;
+        let feed = FeedsRepository::add_feed(
+            &pool,
+            "https://example.com/feed",
+            "Radio",
+            &[episode("a", 1)],
+            1,
+        )
+        .await
+        .unwrap();
+        let id = status_of(&pool, &feed.id, "a").await.id;
+
+        for attempt in 1..=MAX_EPISODE_ATTEMPTS {
+            FeedsRepository::mark_failed(&pool, &id, "timeout")
+                .await
+                .unwrap();
+            let episode = status_of(&pool, &feed.id, "a").await;
+            assert_eq!(episode.attempts, attempt);
+            let expected = if attempt < MAX_EPISODE_ATTEMPTS {
+                "pending"
+            } else {
+                "failed"
+            };
+            assert_eq!(episode.status, expected);
+        }
+        assert!(FeedsRepository::pending_episodes(&pool)
+            .await
+            .unwrap()
+            .is_empty());
+
+        assert!(FeedsRepository::queue_episode(&pool, &id).await.unwrap());
+        let episode = status_of(&pool, &feed.id, "a").await;
+        assert_eq!(
+            (episode.status.as_str(), episode.attempts, episode.error),
+            ("pending", 0, None)
+        );
+        // Pending or imported episodes aren't queued
+        assert!(!FeedsRepository::queue_episode(&pool, &id).await.unwrap());
+        insert_meeting(&pool, "meeting-1").await;
+        FeedsRepository::mark_imported(&pool, &id, "meeting-1")
+            .await
+            .unwrap();
+        assert!(!FeedsRepository::queue_episode(&pool, &id).await.unwrap());
+        assert!(!FeedsRepository::queue_episode(&pool, "missing")
+            .await
+            .unwrap());
+    }
+}
@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{Error as SqlxError, SqlitePool};
+
+/// A meeting that was imported from a file with the same content
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct DuplicateImport {
+    pub meeting_id: String,
+    pub title: String,
+    pub folder_path: Option<String>,
+    pub file_name: Option<String>,
+    pub source: String,
+    pub imported_at: DateTime<Utc>,
+    pub segment_count: i64,
+}
+
+pub struct ImportFingerprintRepository;
+
+impl ImportFingerprintRepository {
+    pub async fn record(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        content_hash: &str,
+        file_name: Option<&str>,
+        file_size: u64,
+        source: &str,
+        origin: Option<&str>,
+    ) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT OR IGNORE INTO import_fingerprints (meeting_id, content_hash, file_name, file_size, source, origin, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(meeting_id)
+        .bind(content_hash)
+        .bind(file_name)
+        .bind(file_size as i64)
+        .bind(source)
+        .bind(origin)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Meetings already imported from a file with this hash, oldest first
+    pub async fn find_by_hash(
+        pool: &SqlitePool,
+        content_hash: &str,
+    ) -> Result<Vec<DuplicateImport>, SqlxError> {
+        sqlx::query_as::<_, DuplicateImport>(
+            "SELECT f.meeting_id, m.title, m.folder_path, f.file_name, f.source, f.created_at AS imported_at,
+                    (SELECT COUNT(*) FROM transcripts t WHERE t.meeting_id = f.meeting_id) AS segment_count
+             FROM import_fingerprints f
+             JOIN meetings m ON m.id = f.meeting_id
+             WHERE f.content_hash = ?
+             ORDER BY f.created_at ASC",
+        )
+        .bind(content_hash)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Meetings already imported from this download origin (a Zoom
+    /// recording file, a link), oldest first
+    pub async fn find_by_origin(
+        pool: &SqlitePool,
+        origin: &str,
+    ) -> Result<Vec<DuplicateImport>, SqlxError> {
+        sqlx::query_as::<_, DuplicateImport>(
+            "SELECT f.meeting_id, m.title, m.folder_path, f.file_name, f.source, f.created_at AS imported_at,
+                    (SELECT COUNT(*) FROM transcripts t WHERE t.meeting_id = f.meeting_id) AS segment_count
+             FROM import_fingerprints f
+             JOIN meetings m ON m.id = f.meeting_id
+             WHERE f.origin = ?
+             ORDER BY f.created_at ASC",
+        )
+        .bind(origin)
+        .fetch_all(pool)
+        .await
+    }
+}
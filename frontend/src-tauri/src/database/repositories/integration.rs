@@ -0,0 +1,52 @@
+use chrono::Utc;
+use sqlx::{Error as SqlxError, SqliteExecutor, SqlitePool};
+
+/// Stores JSON configuration for third-party integrations (issue trackers, email, ...)
+/// keyed by integration id. Configs may contain credentials; callers must redact
+/// them before returning anything to the frontend.
+pub struct IntegrationSettingsRepository;
+
+impl IntegrationSettingsRepository {
+    pub async fn get_config(
+        pool: &SqlitePool,
+        integration_id: &str,
+    ) -> Result<Option<String>, SqlxError> {
+        let row: Option<(String,)> =
+            sqlx::query_as("SELECT config FROM integration_settings WHERE id = ?")
+                .bind(integration_id)
+                .fetch_optional(pool)
+                .await?;
+        Ok(row.map(|(config,)| config))
+    }
+
+    pub async fn save_config<'e>(
+        executor: impl SqliteExecutor<'e>,
+        integration_id: &str,
+        config: &str,
+    ) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO integration_settings (id, config, updated_at)
+             VALUES (?, ?, ?)
+             ON CONFLICT(id) DO UPDATE SET
+                config = excluded.config,
+                updated_at = excluded.updated_at",
+        )
+        .bind(integration_id)
+        .bind(config)
+        .bind(Utc::now())
+        .execute(executor)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete_config<'e>(
+        executor: impl SqliteExecutor<'e>,
+        integration_id: &str,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query("DELETE FROM integration_settings WHERE id = ?")
+            .bind(integration_id)
+            .execute(executor)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
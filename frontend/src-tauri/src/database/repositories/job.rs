@@ -0,0 +1,219 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Error as SqlxError, SqlitePool};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Paused,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl JobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Running => "running",
+            Self::Paused => "paused",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        [
+            Self::Queued,
+            Self::Running,
+            Self::Paused,
+            Self::Completed,
+            Self::Failed,
+            Self::Cancelled,
+        ]
+        .into_iter()
+        .find(|status| status.as_str() == value)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Cancelled)
+    }
+}
+
+/// A background job; also the payload of the `job-progress` event
+#[derive(Debug, Clone, Serialize)]
+pub struct Job {
+    pub id: String,
+    /// "import", "retranscribe", "export", "sync", ...
+    pub kind: String,
+    pub title: String,
+    pub meeting_id: Option<String>,
+    pub status: JobStatus,
+    /// 0.0 to 1.0
+    pub progress: f64,
+    pub message: Option<String>,
+    pub error: Option<String>,
+    pub result: Option<serde_json::Value>,
+    pub created_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(sqlx::FromRow)]
+struct JobRow {
+    id: String,
+    kind: String,
+    title: String,
+    meeting_id: Option<String>,
+    status: String,
+    progress: f64,
+    message: Option<String>,
+    error: Option<String>,
+    result: Option<String>,
+    created_at: DateTime<Utc>,
+    started_at: Option<DateTime<Utc>>,
+    finished_at: Option<DateTime<Utc>>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<JobRow> for Job {
+    type Error = SqlxError;
+
+    fn try_from(row: JobRow) -> Result<Self, Self::Error> {
+        let status = JobStatus::parse(&row.status)
+            .ok_or_else(|| SqlxError::Protocol(format!("Unknown job status {}", row.status)))?;
+        Ok(Job {
+            id: row.id,
+            kind: row.kind,
+            title: row.title,
+            meeting_id: row.meeting_id,
+            status,
+            progress: row.progress,
+            message: row.message,
+            error: row.error,
+            result: row.result.and_then(|json| serde_json::from_str(&json).ok()),
+            created_at: row.created_at,
+            started_at: row.started_at,
+            finished_at: row.finished_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+const JOB_COLUMNS: &str = "id, kind, title, meeting_id, status, progress, message, error, result, \
+     created_at, started_at, finished_at, updated_at";
+
+pub struct JobsRepository;
+
+impl JobsRepository {
+    pub async fn insert(pool: &SqlitePool, job: &Job) -> Result<(), SqlxError> {
+        sqlx::query(&format!(
+            "INSERT INTO jobs ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            JOB_COLUMNS
+        ))
+        .bind(&job.id)
+        .bind(&job.kind)
+        .bind(&job.title)
+        .bind(&job.meeting_id)
+        .bind(job.status.as_str())
+        .bind(job.progress)
+        .bind(&job.message)
+        .bind(&job.error)
+        .bind(job.result.as_ref().map(|r| r.to_string()))
+        .bind(job.created_at)
+        .bind(job.started_at)
+        .bind(job.finished_at)
+        .bind(job.updated_at)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Saves the job's mutable fields
+    pub async fn update(pool: &SqlitePool, job: &Job) -> Result<(), SqlxError> {
+        sqlx::query(
+            "UPDATE jobs SET status = ?, progress = ?, message = ?, error = ?, result = ?,
+                 started_at = ?, finished_at = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(job.status.as_str())
+        .bind(job.progress)
+        .bind(&job.message)
+        .bind(&job.error)
+        .bind(job.result.as_ref().map(|r| r.to_string()))
+        .bind(job.started_at)
+        .bind(job.finished_at)
+        .bind(job.updated_at)
+        .bind(&job.id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get(pool: &SqlitePool, id: &str) -> Result<Option<Job>, SqlxError> {
+        sqlx::query_as::<_, JobRow>(&format!("SELECT {} FROM jobs WHERE id = ?", JOB_COLUMNS))
+            .bind(id)
+            .fetch_optional(pool)
+            .await?
+            .map(Job::try_from)
+            .transpose()
+    }
+
+    /// Newest first. Unfinished jobs are always included; `include_finished`
+    /// adds the history up to `limit` jobs.
+    pub async fn list(
+        pool: &SqlitePool,
+        include_finished: bool,
+        limit: i64,
+    ) -> Result<Vec<Job>, SqlxError> {
+        let filter = if include_finished {
+            ""
+        } else {
+            "WHERE status IN ('queued', 'running', 'paused')"
+        };
+        sqlx::query_as::<_, JobRow>(&format!(
+            "SELECT {} FROM jobs {} ORDER BY created_at DESC LIMIT ?",
+            JOB_COLUMNS, filter
+        ))
+        .bind(limit)
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(Job::try_from)
+        .collect()
+    }
+
+    /// Jobs left unfinished by the previous run can't resume; fail them
+    pub async fn fail_interrupted(pool: &SqlitePool) -> Result<u64, SqlxError> {
+        let now = Utc::now();
+        let result = sqlx::query(
+            "UPDATE jobs SET status = 'failed', error = 'Interrupted when the app closed',
+                 finished_at = ?, updated_at = ?
+             WHERE status IN ('queued', 'running', 'paused')",
+        )
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Removes finished jobs older than `before`
+    pub async fn prune_finished(
+        pool: &SqlitePool,
+        before: DateTime<Utc>,
+    ) -> Result<u64, SqlxError> {
+        let result = sqlx::query(
+            "DELETE FROM jobs
+             WHERE status IN ('completed', 'failed', 'cancelled') AND created_at < ?",
+        )
+        .bind(before)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,376 @@
+use crate::database::models::JournalEntry;
+use chrono::Utc;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, Error as SqlxError, Row, SqliteConnection, SqlitePool};
+use tracing::info;
+use uuid::Uuid;
+
+/// Identifies this app run; undo only applies to operations from the same session
+static SESSION_ID: Lazy<String> = Lazy::new(|| Uuid::new_v4().to_string());
+
+/// Undo history kept per session
+const MAX_ENTRIES_PER_SESSION: i64 = 50;
+
+/// Every row belonging to a meeting, as (table, key column). Parents come
+/// first: restore runs in this order and deletion in reverse.
+pub const MEETING_TABLES: [(&str, &str); 23] = [
+    ("meetings", "id"),
+    ("transcripts", "meeting_id"),
+    ("transcript_chunks", "meeting_id"),
+    ("summary_processes", "meeting_id"),
+    ("meeting_notes", "meeting_id"),
+    ("meeting_attachments", "meeting_id"),
+    ("meeting_participants", "meeting_id"),
+    ("meeting_speakers", "meeting_id"),
+    ("action_items", "meeting_id"),
+    ("meeting_delta_reports", "meeting_id"),
+    ("meeting_delta_reports", "previous_meeting_id"),
+    ("meeting_custom_fields", "meeting_id"),
+    ("meeting_tags", "meeting_id"),
+    ("meeting_activity", "meeting_id"),
+    ("segment_analysis", "meeting_id"),
+    ("transcript_entities", "meeting_id"),
+    ("keyword_hits", "meeting_id"),
+    ("segment_comments", "meeting_id"),
+    ("minutes_status", "meeting_id"),
+    ("import_fingerprints", "meeting_id"),
+    ("redaction_mappings", "meeting_id"),
+    ("partial_summaries", "meeting_id"),
+    ("summary_feedback", "meeting_id"),
+];
+
+/// Rows of one table as they were before an operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableImage {
+    pub table: String,
+    pub key_column: String,
+    pub keys: Vec<String>,
+    /// JSON array of row objects; BLOB columns are hex-encoded
+    pub rows: serde_json::Value,
+}
+
+impl TableImage {
+    pub fn row_count(&self) -> usize {
+        self.rows.as_array().map(Vec::len).unwrap_or(0)
+    }
+}
+
+struct Column {
+    name: String,
+    is_blob: bool,
+}
+
+fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn placeholders(count: usize) -> String {
+    vec!["?"; count].join(", ")
+}
+
+fn capture_sql(table: &str, key_column: &str, key_count: usize, columns: &[Column]) -> String {
+    let fields = columns
+        .iter()
+        .map(|c| {
+            let column = quote_ident(&c.name);
+            let value = if c.is_blob {
+                format!("CASE WHEN {0} IS NULL THEN NULL ELSE hex({0}) END", column)
+            } else {
+                column
+            };
+            format!("'{}', {}", c.name.replace('\'', "''"), value)
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "SELECT json_group_array(json_object({})) FROM {} WHERE {} IN ({})",
+        fields,
+        quote_ident(table),
+        quote_ident(key_column),
+        placeholders(key_count)
+    )
+}
+
+fn restore_sql(table: &str, columns: &[Column]) -> String {
+    let names = columns
+        .iter()
+        .map(|c| quote_ident(&c.name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let values = columns
+        .iter()
+        .map(|c| {
+            let path = format!("'$.{}'", quote_ident(&c.name).replace('\'', "''"));
+            if c.is_blob {
+                format!("unhex(json_extract(value, {}))", path)
+            } else {
+                format!("json_extract(value, {})", path)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "INSERT INTO {} ({}) SELECT {} FROM json_each(?)",
+        quote_ident(table),
+        names,
+        values
+    )
+}
+
+fn delete_sql(table: &str, key_column: &str, key_count: usize) -> String {
+    format!(
+        "DELETE FROM {} WHERE {} IN ({})",
+        quote_ident(table),
+        quote_ident(key_column),
+        placeholders(key_count)
+    )
+}
+
+/// Only tables listed in MEETING_TABLES can be captured or restored, so a
+/// journal entry can never write anywhere else
+async fn table_columns(
+    conn: &mut SqliteConnection,
+    table: &str,
+    key_column: &str,
+) -> Result<Vec<Column>, SqlxError> {
+    if !MEETING_TABLES.iter().any(|(t, _)| *t == table) {
+        return Err(SqlxError::Protocol(format!("Table {} is not journaled", table)));
+    }
+    let columns: Vec<Column> = sqlx::query(&format!("PRAGMA table_info({})", quote_ident(table)))
+        .fetch_all(&mut *conn)
+        .await?
+        .iter()
+        .map(|row| Column {
+            name: row.get("name"),
+            is_blob: row.get::<String, _>("type").eq_ignore_ascii_case("BLOB"),
+        })
+        .collect();
+    if !columns.iter().any(|c| c.name == key_column) {
+        return Err(SqlxError::Protocol(format!(
+            "Table {} has no column {}",
+            table, key_column
+        )));
+    }
+    Ok(columns)
+}
+
+pub struct JournalRepository;
+
+impl JournalRepository {
+    pub fn session_id() -> &'static str {
+        &SESSION_ID
+    }
+
+    /// Snapshots the rows of `table` whose `key_column` is one of `keys`
+    pub async fn capture(
+        conn: &mut SqliteConnection,
+        table: &str,
+        key_column: &str,
+        keys: &[String],
+    ) -> Result<TableImage, SqlxError> {
+        let columns = table_columns(conn, table, key_column).await?;
+        let rows = if keys.is_empty() {
+            "[]".to_string()
+        } else {
+            let sql = capture_sql(table, key_column, keys.len(), &columns);
+            let mut query = sqlx::query_scalar::<_, String>(&sql);
+            for key in keys {
+                query = query.bind(key);
+            }
+            query.fetch_one(&mut *conn).await?
+        };
+        Ok(TableImage {
+            table: table.to_string(),
+            key_column: key_column.to_string(),
+            keys: keys.to_vec(),
+            rows: serde_json::from_str(&rows)
+                .map_err(|e| SqlxError::Decode(Box::new(e)))?,
+        })
+    }
+
+    /// Snapshots every row belonging to a meeting
+    pub async fn capture_meeting(
+        conn: &mut SqliteConnection,
+        meeting_id: &str,
+    ) -> Result<Vec<TableImage>, SqlxError> {
+        let keys = vec![meeting_id.to_string()];
+        let mut images = Vec::with_capacity(MEETING_TABLES.len());
+        for (table, key_column) in MEETING_TABLES {
+            images.push(Self::capture(conn, table, key_column, &keys).await?);
+        }
+        Ok(images)
+    }
+
+    /// Records an undoable operation in the caller's transaction
+    pub async fn record(
+        conn: &mut SqliteConnection,
+        operation: &str,
+        meeting_id: Option<&str>,
+        description: &str,
+        images: &[TableImage],
+    ) -> Result<i64, SqlxError> {
+        let session_id = Self::session_id();
+        let before_image =
+            serde_json::to_string(images).map_err(|e| SqlxError::Protocol(e.to_string()))?;
+
+        // Before-images of earlier sessions can no longer be undone
+        sqlx::query("DELETE FROM operation_journal WHERE session_id != ?")
+            .bind(session_id)
+            .execute(&mut *conn)
+            .await?;
+
+        let id = sqlx::query(
+            "INSERT INTO operation_journal (session_id, operation, meeting_id, description, before_image, created_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(session_id)
+        .bind(operation)
+        .bind(meeting_id)
+        .bind(description)
+        .bind(before_image)
+        .bind(Utc::now())
+        .execute(&mut *conn)
+        .await?
+        .last_insert_rowid();
+
+        sqlx::query(
+            "DELETE FROM operation_journal WHERE session_id = ? AND id NOT IN (
+                SELECT id FROM operation_journal WHERE session_id = ? ORDER BY id DESC LIMIT ?
+             )",
+        )
+        .bind(session_id)
+        .bind(session_id)
+        .bind(MAX_ENTRIES_PER_SESSION)
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(id)
+    }
+
+    /// The operation `undo_last` would revert
+    pub async fn last_undoable(pool: &SqlitePool) -> Result<Option<JournalEntry>, SqlxError> {
+        sqlx::query_as::<_, JournalEntry>(
+            "SELECT id, operation, meeting_id, description, created_at FROM operation_journal
+             WHERE session_id = ? AND undone_at IS NULL ORDER BY id DESC LIMIT 1",
+        )
+        .bind(Self::session_id())
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Rows of `table` captured by operations of earlier sessions that were
+    /// not undone. Those operations can no longer be undone, so whatever the
+    /// rows pointed at outside the database can be cleaned up.
+    pub async fn expired_rows(
+        pool: &SqlitePool,
+        table: &str,
+    ) -> Result<Vec<serde_json::Value>, SqlxError> {
+        let rows: Vec<String> = sqlx::query_scalar(
+            "SELECT row.value FROM operation_journal,
+                    json_each(operation_journal.before_image) AS image,
+                    json_each(image.value, '$.rows') AS row
+             WHERE operation_journal.session_id != ? AND operation_journal.undone_at IS NULL
+               AND json_extract(image.value, '$.table') = ?",
+        )
+        .bind(Self::session_id())
+        .bind(table)
+        .fetch_all(pool)
+        .await?;
+        rows.iter()
+            .map(|row| serde_json::from_str(row).map_err(|e| SqlxError::Decode(Box::new(e))))
+            .collect()
+    }
+
+    /// Reverts the latest operation of this session by deleting the current
+    /// rows under each captured key and reinserting the before-images
+    pub async fn undo_last(pool: &SqlitePool) -> Result<Option<JournalEntry>, SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        let row: Option<(i64, String)> = sqlx::query_as(
+            "SELECT id, before_image FROM operation_journal
+             WHERE session_id = ? AND undone_at IS NULL ORDER BY id DESC LIMIT 1",
+        )
+        .bind(Self::session_id())
+        .fetch_optional(&mut *transaction)
+        .await?;
+        let Some((id, before_image)) = row else {
+            transaction.rollback().await?;
+            return Ok(None);
+        };
+        let images: Vec<TableImage> = serde_json::from_str(&before_image)
+            .map_err(|e| SqlxError::Decode(Box::new(e)))?;
+
+        for image in images.iter().rev().filter(|i| !i.keys.is_empty()) {
+            table_columns(&mut transaction, &image.table, &image.key_column).await?;
+            let sql = delete_sql(&image.table, &image.key_column, image.keys.len());
+            let mut query = sqlx::query(&sql);
+            for key in &image.keys {
+                query = query.bind(key);
+            }
+            query.execute(&mut *transaction).await?;
+        }
+        for image in images.iter().filter(|i| i.row_count() > 0) {
+            let columns = table_columns(&mut transaction, &image.table, &image.key_column).await?;
+            sqlx::query(&restore_sql(&image.table, &columns))
+                .bind(image.rows.to_string())
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        sqlx::query("UPDATE operation_journal SET undone_at = ? WHERE id = ?")
+            .bind(Utc::now())
+            .bind(id)
+            .execute(&mut *transaction)
+            .await?;
+        let entry = sqlx::query_as::<_, JournalEntry>(
+            "SELECT id, operation, meeting_id, description, created_at FROM operation_journal WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_one(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+
+        info!("Undid operation {} ({})", entry.id, entry.description);
+        Ok(Some(entry))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns() -> Vec<Column> {
+        vec![
+            Column { name: "meeting_id".into(), is_blob: false },
+            Column { name: "embedding".into(), is_blob: true },
+        ]
+    }
+
+    #[test]
+    fn capture_sql_hex_encodes_blobs() {
+        let sql = capture_sql("meeting_speakers", "meeting_id", 2, &columns());
+        assert_eq!(
+            sql,
+            "SELECT json_group_array(json_object('meeting_id', \"meeting_id\", 'embedding', \
+             CASE WHEN \"embedding\" IS NULL THEN NULL ELSE hex(\"embedding\") END)) \
+             FROM \"meeting_speakers\" WHERE \"meeting_id\" IN (?, ?)"
+        );
+    }
+
+    #[test]
+    fn restore_sql_reads_rows_from_json() {
+        let sql = restore_sql("meeting_speakers", &columns());
+        assert_eq!(
+            sql,
+            "INSERT INTO \"meeting_speakers\" (\"meeting_id\", \"embedding\") SELECT \
+             json_extract(value, '$.\"meeting_id\"'), unhex(json_extract(value, '$.\"embedding\"')) \
+             FROM json_each(?)"
+        );
+        assert_eq!(
+            delete_sql("transcripts", "id", 3),
+            "DELETE FROM \"transcripts\" WHERE \"id\" IN (?, ?, ?)"
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use crate::encryption;
+use crate::redaction;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Error as SqlxError, SqlitePool};
+use uuid::Uuid;
+
+const MAX_KEYWORD_LEN: usize = 100;
+const DEFAULT_HIT_LIMIT: i64 = 200;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct WatchKeyword {
+    pub id: String,
+    pub keyword: String,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A watched keyword heard in a segment
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct KeywordHit {
+    pub id: String,
+    pub keyword_id: String,
+    pub keyword: String,
+    /// None until the recording's transcript is saved
+    pub meeting_id: Option<String>,
+    pub segment_text: String,
+    pub sequence_id: Option<i64>,
+    pub audio_start_time: Option<f64>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Collapses whitespace and checks the length
+pub fn normalize_keyword(keyword: &str) -> Result<String, String> {
+    let keyword = keyword.split_whitespace().collect::<Vec<_>>().join(" ");
+    if keyword.is_empty() {
+        return Err("Keyword is empty".to_string());
+    }
+    if keyword.chars().count() > MAX_KEYWORD_LEN {
+        return Err(format!(
+            "Keyword is longer than {} characters",
+            MAX_KEYWORD_LEN
+        ));
+    }
+    Ok(keyword)
+}
+
+pub struct KeywordWatchRepository;
+
+impl KeywordWatchRepository {
+    pub async fn list_keywords(pool: &SqlitePool) -> Result<Vec<WatchKeyword>, SqlxError> {
+        sqlx::query_as::<_, WatchKeyword>(
+            "SELECT id, keyword, enabled, created_at FROM watch_keywords ORDER BY keyword COLLATE NOCASE",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn add_keyword(pool: &SqlitePool, keyword: &str) -> Result<WatchKeyword, SqlxError> {
+        let keyword = normalize_keyword(keyword).map_err(SqlxError::Protocol)?;
+        let exists: Option<String> =
+            sqlx::query_scalar("SELECT id FROM watch_keywords WHERE keyword = ? COLLATE NOCASE")
+                .bind(&keyword)
+                .fetch_optional(pool)
+                .await?;
+        if exists.is_some() {
+            return Err(SqlxError::Protocol(format!(
+                "'{}' is already on the watchlist",
+                keyword
+            )));
+        }
+
+        let watch = WatchKeyword {
+            id: format!("keyword-{}", Uuid::new_v4()),
+            keyword,
+            enabled: true,
+            created_at: Utc::now(),
+        };
+        sqlx::query(
+            "INSERT INTO watch_keywords (id, keyword, enabled, created_at) VALUES (?, ?, ?, ?)",
+        )
+        .bind(&watch.id)
+        .bind(&watch.keyword)
+        .bind(watch.enabled)
+        .bind(watch.created_at)
+        .execute(pool)
+        .await?;
+        Ok(watch)
+    }
+
+    /// Returns false when the keyword does not exist
+    pub async fn set_enabled(
+        pool: &SqlitePool,
+        id: &str,
+        enabled: bool,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query("UPDATE watch_keywords SET enabled = ? WHERE id = ?")
+            .bind(enabled)
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Removes a keyword. Its past hits are kept.
+    pub async fn delete_keyword(pool: &SqlitePool, id: &str) -> Result<bool, SqlxError> {
+        let result = sqlx::query("DELETE FROM watch_keywords WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Records a hit from a live recording, keyed by its recording folder.
+    /// A hit recorded after the recording was already saved as a meeting is
+    /// attached to it right away, so it can't miss [`Self::attach_hits`].
+    pub async fn record_hit(
+        pool: &SqlitePool,
+        keyword: &WatchKeyword,
+        folder_path: Option<&str>,
+        segment_text: &str,
+        sequence_id: u64,
+        audio_start_time: f64,
+    ) -> Result<KeywordHit, SqlxError> {
+        let mut hit = KeywordHit {
+            id: format!("hit-{}", Uuid::new_v4()),
+            keyword_id: keyword.id.clone(),
+            keyword: keyword.keyword.clone(),
+            meeting_id: None,
+            segment_text: segment_text.to_string(),
+            sequence_id: Some(sequence_id as i64),
+            audio_start_time: Some(audio_start_time),
+            created_at: Utc::now(),
+        };
+        // One statement, so the meeting can't be saved between the lookup and the insert
+        hit.meeting_id = sqlx::query_scalar(
+            "INSERT INTO keyword_hits (id, keyword_id, keyword, meeting_id, folder_path, segment_text, sequence_id, audio_start_time, created_at)
+             VALUES (?, ?, ?, (SELECT id FROM meetings WHERE folder_path = ? LIMIT 1), ?, ?, ?, ?, ?)
+             RETURNING meeting_id",
+        )
+        .bind(&hit.id)
+        .bind(&hit.keyword_id)
+        .bind(&hit.keyword)
+        .bind(folder_path)
+        .bind(folder_path)
+        .bind(encryption::seal(&redaction::redact_unattached(segment_text))?)
+        .bind(hit.sequence_id)
+        .bind(hit.audio_start_time)
+        .bind(hit.created_at)
+        .fetch_one(pool)
+        .await?;
+        Ok(hit)
+    }
+
+    /// Attaches the hits recorded into `folder_path` to the meeting saved from it
+    pub async fn attach_hits(
+        pool: &SqlitePool,
+        folder_path: &str,
+        meeting_id: &str,
+    ) -> Result<u64, SqlxError> {
+        let result = sqlx::query(
+            "UPDATE keyword_hits SET meeting_id = ? WHERE meeting_id IS NULL AND folder_path = ?",
+        )
+        .bind(meeting_id)
+        .bind(folder_path)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Removes hits recorded before `before` that never got a meeting: the
+    /// recording was discarded or never saved
+    pub async fn delete_unattached_hits(
+        pool: &SqlitePool,
+        before: DateTime<Utc>,
+    ) -> Result<u64, SqlxError> {
+        let result =
+            sqlx::query("DELETE FROM keyword_hits WHERE meeting_id IS NULL AND created_at < ?")
+                .bind(before)
+                .execute(pool)
+                .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Hits of one meeting in playback order, or the most recent hits overall
+    pub async fn list_hits(
+        pool: &SqlitePool,
+        meeting_id: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<KeywordHit>, SqlxError> {
+        let mut hits = match meeting_id {
+            Some(meeting_id) => {
+                sqlx::query_as::<_, KeywordHit>(
+                    "SELECT id, keyword_id, keyword, meeting_id, segment_text, sequence_id, audio_start_time, created_at
+                     FROM keyword_hits WHERE meeting_id = ?
+                     ORDER BY audio_start_time ASC, created_at ASC",
+                )
+                .bind(meeting_id)
+                .fetch_all(pool)
+                .await?
+            }
+            None => {
+                sqlx::query_as::<_, KeywordHit>(
+                    "SELECT id, keyword_id, keyword, meeting_id, segment_text, sequence_id, audio_start_time, created_at
+                     FROM keyword_hits ORDER BY created_at DESC LIMIT ?",
+                )
+                .bind(limit.unwrap_or(DEFAULT_HIT_LIMIT).max(1))
+                .fetch_all(pool)
+                .await?
+            }
+        };
+        for hit in &mut hits {
+            hit.segment_text = encryption::open(std::mem::take(&mut hit.segment_text))?;
+        }
+        Ok(hits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::{insert_meeting, memory_pool};
+
+    const FOLDER: &str = "/recordings/Standup_2026-01-01_10-00";
+
+    async fn watch(pool: &SqlitePool, keyword: &str) -> WatchKeyword {
+        KeywordWatchRepository::add_keyword(pool, keyword)
+            .await
+            .unwrap()
+    }
+
+    async fn save_meeting(pool: &SqlitePool, id: &str) {
+        insert_meeting(pool, id).await;
+        sqlx::query("UPDATE meetings SET folder_path = ? WHERE id = ?")
+            .bind(FOLDER)
+            .bind(id)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn hits_join_the_meeting_whether_recorded_before_or_after_the_save() {
+        let pool = memory_pool().await;
+        let keyword = watch(&pool, "pricing").await;
+
+        let early =
+            KeywordWatchRepository::record_hit(&pool, &keyword, Some(FOLDER), "pricing", 1, 2.0)
+                .await
+                .unwrap();
+        assert!(early.meeting_id.is_none());
+
+        save_meeting(&pool, "meeting-1").await;
+        let attached = KeywordWatchRepository::attach_hits(&pool, FOLDER, "meeting-1")
+            .await
+            .unwrap();
+        assert_eq!(attached, 1);
+
+        // Recorded by a task that ran after the transcript was saved
+        let late =
+            KeywordWatchRepository::record_hit(&pool, &keyword, Some(FOLDER), "pricing", 2, 5.0)
+                .await
+                .unwrap();
+        assert_eq!(late.meeting_id.as_deref(), Some("meeting-1"));
+
+        let hits = KeywordWatchRepository::list_hits(&pool, Some("meeting-1"), None)
+            .await
+            .unwrap();
+        assert_eq!(hits.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn unattached_hits_are_removed_once_stale() {
+        let pool = memory_pool().await;
+        let keyword = watch(&pool, "contract").await;
+        KeywordWatchRepository::record_hit(&pool, &keyword, Some(FOLDER), "contract", 1, 0.0)
+            .await
+            .unwrap();
+        KeywordWatchRepository::record_hit(&pool, &keyword, None, "contract", 2, 1.0)
+            .await
+            .unwrap();
+
+        let removed = KeywordWatchRepository::delete_unattached_hits(
+            &pool,
+            Utc::now() - chrono::Duration::hours(1),
+        )
+        .await
+        .unwrap();
+        assert_eq!(removed, 0);
+
+        save_meeting(&pool, "meeting-1").await;
+        KeywordWatchRepository::attach_hits(&pool, FOLDER, "meeting-1")
+            .await
+            .unwrap();
+        let removed = KeywordWatchRepository::delete_unattached_hits(
+            &pool,
+            Utc::now() + chrono::Duration::hours(1),
+        )
+        .await
+        .unwrap();
+        assert_eq!(removed, 1);
+        let hits = KeywordWatchRepository::list_hits(&pool, None, None)
+            .await
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].meeting_id.as_deref(), Some("meeting-1"));
+    }
+}
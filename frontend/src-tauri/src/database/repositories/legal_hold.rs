@@ -0,0 +1,225 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Error as SqlxError, SqliteExecutor, SqlitePool};
+
+const MAX_REASON_LEN: usize = 500;
+
+/// A compliance hold that keeps a meeting from being deleted or purged
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct LegalHold {
+    pub meeting_id: String,
+    pub reason: String,
+    pub placed_by: String,
+    pub placed_at: DateTime<Utc>,
+}
+
+pub struct LegalHoldRepository;
+
+impl LegalHoldRepository {
+    /// Places a hold; fails if the meeting doesn't exist or is already held
+    pub async fn place(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        reason: &str,
+        placed_by: &str,
+    ) -> Result<LegalHold, SqlxError> {
+        let reason = reason.trim();
+        if reason.is_empty() {
+            return Err(SqlxError::Protocol(
+                "A reason is required for a legal hold".to_string(),
+            ));
+        }
+        if reason.chars().count() > MAX_REASON_LEN {
+            return Err(SqlxError::Protocol(format!(
+                "Reason is longer than {} characters",
+                MAX_REASON_LEN
+            )));
+        }
+        let exists: Option<String> = sqlx::query_scalar("SELECT id FROM meetings WHERE id = ?")
+            .bind(meeting_id)
+            .fetch_optional(pool)
+            .await?;
+        if exists.is_none() {
+            return Err(SqlxError::RowNotFound);
+        }
+        if Self::get(pool, meeting_id).await?.is_some() {
+            return Err(SqlxError::Protocol(format!(
+                "Meeting {} is already on legal hold",
+                meeting_id
+            )));
+        }
+
+        let hold = LegalHold {
+            meeting_id: meeting_id.to_string(),
+            reason: reason.to_string(),
+            placed_by: placed_by.to_string(),
+            placed_at: Utc::now(),
+        };
+        sqlx::query(
+            "INSERT INTO legal_holds (meeting_id, reason, placed_by, placed_at) VALUES (?, ?, ?, ?)",
+        )
+        .bind(&hold.meeting_id)
+        .bind(&hold.reason)
+        .bind(&hold.placed_by)
+        .bind(hold.placed_at)
+        .execute(pool)
+        .await?;
+        Ok(hold)
+    }
+
+    /// Releases a hold; returns the released hold, or None if there was none
+    pub async fn release(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Option<LegalHold>, SqlxError> {
+        let hold = Self::get(pool, meeting_id).await?;
+        if hold.is_some() {
+            sqlx::query("DELETE FROM legal_holds WHERE meeting_id = ?")
+                .bind(meeting_id)
+                .execute(pool)
+                .await?;
+        }
+        Ok(hold)
+    }
+
+    pub async fn get<'e>(
+        executor: impl SqliteExecutor<'e>,
+        meeting_id: &str,
+    ) -> Result<Option<LegalHold>, SqlxError> {
+        sqlx::query_as::<_, LegalHold>(
+            "SELECT meeting_id, reason, placed_by, placed_at FROM legal_holds WHERE meeting_id = ?",
+        )
+        .bind(meeting_id)
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn list(pool: &SqlitePool) -> Result<Vec<LegalHold>, SqlxError> {
+        sqlx::query_as::<_, LegalHold>(
+            "SELECT meeting_id, reason, placed_by, placed_at FROM legal_holds ORDER BY placed_at DESC",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Fails when the meeting is on hold. Every path that deletes meeting
+    /// data (including retention purges) calls this first, inside its
+    /// transaction.
+    pub async fn ensure_not_held<'e>(
+        executor: impl SqliteExecutor<'e>,
+        meeting_id: &str,
+    ) -> Result<(), SqlxError> {
+        match Self::get(executor, meeting_id).await? {
+            Some(hold) => Err(SqlxError::Protocol(format!(
+                "Meeting is on legal hold ({}); release the hold before deleting its data",
+                hold.reason
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::meeting::MeetingsRepository;
+    use crate::database::repositories::transcript::TranscriptsRepository;
+    use crate::database::test_support::{insert_meeting, insert_transcript, memory_pool};
+
+    async fn segment_count(pool: &SqlitePool, meeting_id: &str) -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM transcripts WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn holds_need_a_reason_and_an_existing_meeting() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+
+        assert!(LegalHoldRepository::place(&pool, "m1", "  ", "alice")
+            .await
+            .is_err());
+        let long = "x".repeat(MAX_REASON_LEN + 1);
+        assert!(LegalHoldRepository::place(&pool, "m1", &long, "alice")
+            .await
+            .is_err());
+        assert!(matches!(
+            LegalHoldRepository::place(&pool, "missing", "Litigation", "alice").await,
+            Err(SqlxError::RowNotFound)
+        ));
+
+        let hold = LegalHoldRepository::place(&pool, "m1", " Litigation ", "alice")
+            .await
+            .unwrap();
+        assert_eq!(hold.reason, "Litigation");
+        assert!(LegalHoldRepository::place(&pool, "m1", "Again", "bob")
+            .await
+            .is_err());
+        assert_eq!(LegalHoldRepository::list(&pool).await.unwrap().len(), 1);
+
+        let released = LegalHoldRepository::release(&pool, "m1").await.unwrap();
+        assert_eq!(
+            released.map(|hold| hold.placed_by).as_deref(),
+            Some("alice")
+        );
+        assert!(LegalHoldRepository::release(&pool, "m1")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn held_meetings_cannot_be_deleted_until_released() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        insert_transcript(&pool, "m1", "t1").await;
+        LegalHoldRepository::place(&pool, "m1", "Litigation", "alice")
+            .await
+            .unwrap();
+
+        let error = MeetingsRepository::delete_meeting(&pool, "m1")
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("legal hold"));
+        assert_eq!(segment_count(&pool, "m1").await, 1);
+
+        LegalHoldRepository::release(&pool, "m1").await.unwrap();
+        assert!(MeetingsRepository::delete_meeting(&pool, "m1")
+            .await
+            .unwrap());
+        assert_eq!(segment_count(&pool, "m1").await, 0);
+    }
+
+    #[tokio::test]
+    async fn held_meetings_keep_their_segments() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        insert_transcript(&pool, "m1", "t1").await;
+        insert_transcript(&pool, "m1", "t2").await;
+        LegalHoldRepository::place(&pool, "m1", "Audit", "alice")
+            .await
+            .unwrap();
+
+        assert!(TranscriptsRepository::delete_segment(&pool, "t1")
+            .await
+            .is_err());
+        let ids = ["t1".to_string(), "t2".to_string()];
+        assert!(TranscriptsRepository::merge_segments(&pool, &ids)
+            .await
+            .is_err());
+        assert_eq!(segment_count(&pool, "m1").await, 2);
+
+        LegalHoldRepository::release(&pool, "m1").await.unwrap();
+        assert_eq!(
+            TranscriptsRepository::delete_segment(&pool, "t1")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("m1")
+        );
+        assert_eq!(segment_count(&pool, "m1").await, 1);
+    }
+}
@@ -1,8 +1,33 @@
 use crate::api::{MeetingDetails, MeetingTranscript};
 use crate::database::models::{MeetingModel, Transcript};
+use crate::database::repositories::journal::JournalRepository;
+use crate::database::repositories::legal_hold::LegalHoldRepository;
+use crate::database::repositories::minutes_status::MinutesStatusRepository;
+use crate::encryption;
+use crate::locks;
 use chrono::Utc;
-use sqlx::{Connection, Error as SqlxError, SqliteConnection, SqlitePool};
+use sqlx::{Connection, Error as SqlxError, SqliteConnection, SqliteExecutor, SqlitePool};
 use tracing::{error, info};
+use uuid::Uuid;
+
+/// Prefix of version conflict errors, so callers can tell them from other
+/// failures
+pub const CONFLICT_CODE: &str = "VERSION_CONFLICT";
+
+/// The meeting changed since the version a save was based on
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("{}: this meeting was changed elsewhere (version {} is now {}); reload it before saving", CONFLICT_CODE, .expected, .current)]
+pub struct VersionConflict {
+    pub meeting_id: String,
+    pub expected: i64,
+    pub current: i64,
+}
+
+impl From<VersionConflict> for SqlxError {
+    fn from(error: VersionConflict) -> Self {
+        SqlxError::Protocol(error.to_string())
+    }
+}
 
 pub struct MeetingsRepository;
 
@@ -15,6 +40,13 @@ impl MeetingsRepository {
         Ok(meetings)
     }
 
+    /// The most recently created meeting, if any
+    pub async fn get_latest_meeting_id(pool: &SqlitePool) -> Result<Option<String>, SqlxError> {
+        sqlx::query_scalar("SELECT id FROM meetings ORDER BY created_at DESC LIMIT 1")
+            .fetch_optional(pool)
+            .await
+    }
+
     pub async fn delete_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<bool, SqlxError> {
         if meeting_id.trim().is_empty() {
             return Err(SqlxError::Protocol(
@@ -22,9 +54,34 @@ impl MeetingsRepository {
             ));
         }
 
+        locks::ensure_unlocked(meeting_id)?;
+
         let mut conn = pool.acquire().await?;
         let mut transaction = conn.begin().await?;
 
+        if let Err(e) = LegalHoldRepository::ensure_not_held(&mut *transaction, meeting_id).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+
+        // Keep a before-image so the deletion can be undone this session
+        let images = JournalRepository::capture_meeting(&mut transaction, meeting_id).await?;
+        let title = images[0]
+            .rows
+            .get(0)
+            .and_then(|row| row["title"].as_str())
+            .map(str::to_string);
+        if let Some(title) = title {
+            JournalRepository::record(
+                &mut transaction,
+                "delete_meeting",
+                Some(meeting_id),
+                &format!("Delete meeting \"{}\"", title),
+                &images,
+            )
+            .await?;
+        }
+
         match delete_meeting_with_transaction(&mut transaction, meeting_id).await {
             Ok(success) => {
                 if success {
@@ -73,6 +130,10 @@ impl MeetingsRepository {
         }
 
         if let Some(meeting) = meeting {
+            let version = Self::get_version(&mut *transaction, meeting_id)
+                .await?
+                .unwrap_or(1);
+
             // Get all transcripts for this meeting
             let transcripts =
                 sqlx::query_as::<_, Transcript>("SELECT * FROM transcripts WHERE meeting_id = ?")
@@ -85,21 +146,26 @@ impl MeetingsRepository {
             // Convert Transcript to MeetingTranscript
             let meeting_transcripts = transcripts
                 .into_iter()
-                .map(|t| MeetingTranscript {
-                    id: t.id,
-                    text: t.transcript,
-                    timestamp: t.timestamp,
-                    audio_start_time: t.audio_start_time,
-                    audio_end_time: t.audio_end_time,
-                    duration: t.duration,
+                .map(|t| {
+                    Ok(MeetingTranscript {
+                        id: t.id,
+                        text: encryption::open(t.transcript)?,
+                        timestamp: t.timestamp,
+                        audio_start_time: t.audio_start_time,
+                        audio_end_time: t.audio_end_time,
+                        duration: t.duration,
+                        speaker: t.speaker,
+                        uncertain: t.uncertain,
+                    })
                 })
-                .collect::<Vec<_>>();
+                .collect::<Result<Vec<_>, SqlxError>>()?;
 
             Ok(Some(MeetingDetails {
                 id: meeting.id,
                 title: meeting.title,
                 created_at: meeting.created_at.0.to_rfc3339(),
                 updated_at: meeting.updated_at.0.to_rfc3339(),
+                version,
                 transcripts: meeting_transcripts,
             }))
         } else {
@@ -128,6 +194,39 @@ impl MeetingsRepository {
         Ok(meeting)
     }
 
+    /// The meeting's version, or None if it doesn't exist
+    pub async fn get_version<'e>(
+        executor: impl SqliteExecutor<'e>,
+        meeting_id: &str,
+    ) -> Result<Option<i64>, SqlxError> {
+        sqlx::query_scalar("SELECT version FROM meetings WHERE id = ?")
+            .bind(meeting_id)
+            .fetch_optional(executor)
+            .await
+    }
+
+    /// Fails with [`VersionConflict`] when `expected` is given and the
+    /// meeting has moved past it. Saves call this inside their transaction;
+    /// a missing meeting is left for the caller to report.
+    pub async fn ensure_version<'e>(
+        executor: impl SqliteExecutor<'e>,
+        meeting_id: &str,
+        expected: Option<i64>,
+    ) -> Result<(), SqlxError> {
+        let Some(expected) = expected else {
+            return Ok(());
+        };
+        match Self::get_version(executor, meeting_id).await? {
+            Some(current) if current != expected => Err(VersionConflict {
+                meeting_id: meeting_id.to_string(),
+                expected,
+                current,
+            }
+            .into()),
+            _ => Ok(()),
+        }
+    }
+
     /// Get meeting transcripts with pagination support
     pub async fn get_meeting_transcripts_paginated(
         pool: &SqlitePool,
@@ -150,7 +249,7 @@ impl MeetingsRepository {
         .await?;
 
         // Get paginated transcripts ordered by audio_start_time
-        let transcripts = sqlx::query_as::<_, Transcript>(
+        let mut transcripts = sqlx::query_as::<_, Transcript>(
             "SELECT * FROM transcripts
              WHERE meeting_id = ?
              ORDER BY audio_start_time ASC
@@ -161,23 +260,40 @@ impl MeetingsRepository {
         .bind(offset)
         .fetch_all(pool)
         .await?;
+        for t in &mut transcripts {
+            t.transcript = encryption::open(std::mem::take(&mut t.transcript))?;
+        }
 
         Ok((transcripts, total.0))
     }
 
+    /// Renames a meeting, failing with [`VersionConflict`] if it changed
+    /// since `expected_version`. Returns the new version, or None if the
+    /// meeting doesn't exist.
     pub async fn update_meeting_title(
         pool: &SqlitePool,
         meeting_id: &str,
         new_title: &str,
-    ) -> Result<bool, SqlxError> {
+        expected_version: Option<i64>,
+    ) -> Result<Option<i64>, SqlxError> {
         if meeting_id.trim().is_empty() {
             return Err(SqlxError::Protocol(
                 "meeting_id cannot be empty".to_string(),
             ));
         }
 
+        locks::ensure_unlocked(meeting_id)?;
+
         let mut conn = pool.acquire().await?;
         let mut transaction = conn.begin().await?;
+        if let Err(e) = MinutesStatusRepository::ensure_editable(&mut *transaction, meeting_id).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+        if let Err(e) = Self::ensure_version(&mut *transaction, meeting_id, expected_version).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
 
         let now = Utc::now().naive_utc();
 
@@ -190,10 +306,11 @@ impl MeetingsRepository {
                 .await?;
         if rows_affected.rows_affected() == 0 {
             transaction.rollback().await?;
-            return Ok(false);
+            return Ok(None);
         }
+        let version = Self::get_version(&mut *transaction, meeting_id).await?;
         transaction.commit().await?;
-        Ok(true)
+        Ok(version)
     }
 
     pub async fn update_meeting_name(
@@ -201,6 +318,7 @@ impl MeetingsRepository {
         meeting_id: &str,
         new_title: &str,
     ) -> Result<bool, SqlxError> {
+        locks::ensure_unlocked(meeting_id)?;
         let mut transaction = pool.begin().await?;
         let now = Utc::now();
 
@@ -228,6 +346,150 @@ impl MeetingsRepository {
         transaction.commit().await?;
         Ok(true)
     }
+
+    /// Copies a meeting with its transcripts, notes, participants, speaker
+    /// names and redaction mappings into a new meeting. Summaries, action items and attachments are
+    /// not copied. Returns the new meeting id, or None if the source is missing.
+    pub async fn duplicate_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        new_title: &str,
+        folder_path: Option<String>,
+    ) -> Result<Option<String>, SqlxError> {
+        let mut transaction = pool.begin().await?;
+
+        let exists: Option<(i64,)> = sqlx::query_as("SELECT 1 FROM meetings WHERE id = ?")
+            .bind(meeting_id)
+            .fetch_optional(&mut *transaction)
+            .await?;
+        if exists.is_none() {
+            transaction.rollback().await?;
+            return Ok(None);
+        }
+
+        let new_id = format!("meeting-{}", Uuid::new_v4());
+        let now = Utc::now();
+        sqlx::query(
+            "INSERT INTO meetings (id, title, created_at, updated_at, folder_path) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&new_id)
+        .bind(new_title)
+        .bind(now)
+        .bind(now)
+        .bind(&folder_path)
+        .execute(&mut *transaction)
+        .await?;
+
+        // Copy segments one by one so each gets a fresh id; rowid keeps the original order
+        let transcript_ids: Vec<String> =
+            sqlx::query_scalar("SELECT id FROM transcripts WHERE meeting_id = ? ORDER BY rowid")
+                .bind(meeting_id)
+                .fetch_all(&mut *transaction)
+                .await?;
+        for transcript_id in &transcript_ids {
+            sqlx::query(
+                "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, summary, action_items, key_points,
+                                          audio_start_time, audio_end_time, duration, speaker, uncertain)
+                 SELECT ?, ?, transcript, timestamp, summary, action_items, key_points,
+                        audio_start_time, audio_end_time, duration, speaker, uncertain
+                 FROM transcripts WHERE id = ?",
+            )
+            .bind(format!("transcript-{}", Uuid::new_v4()))
+            .bind(&new_id)
+            .bind(transcript_id)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        sqlx::query(
+            "INSERT INTO meeting_notes (meeting_id, notes_markdown, notes_json, created_at, updated_at)
+             SELECT ?, notes_markdown, notes_json, ?, ? FROM meeting_notes WHERE meeting_id = ?",
+        )
+        .bind(&new_id)
+        .bind(now)
+        .bind(now)
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        let participant_ids: Vec<String> =
+            sqlx::query_scalar("SELECT id FROM meeting_participants WHERE meeting_id = ? ORDER BY rowid")
+                .bind(meeting_id)
+                .fetch_all(&mut *transaction)
+                .await?;
+        for participant_id in &participant_ids {
+            sqlx::query(
+                "INSERT INTO meeting_participants (id, meeting_id, name, email, role, speaker_label, source, created_at, updated_at)
+                 SELECT ?, ?, name, email, role, speaker_label, source, ?, ? FROM meeting_participants WHERE id = ?",
+            )
+            .bind(format!("participant-{}", Uuid::new_v4()))
+            .bind(&new_id)
+            .bind(now)
+            .bind(now)
+            .bind(participant_id)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        sqlx::query(
+            "INSERT INTO meeting_speakers (meeting_id, speaker_label, display_name, profile_id, embedding, updated_at)
+             SELECT ?, speaker_label, display_name, profile_id, embedding, ? FROM meeting_speakers WHERE meeting_id = ?",
+        )
+        .bind(&new_id)
+        .bind(now)
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO meeting_custom_fields (meeting_id, field_id, value, updated_at)
+             SELECT ?, field_id, value, ? FROM meeting_custom_fields WHERE meeting_id = ?",
+        )
+        .bind(&new_id)
+        .bind(now)
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO meeting_tags (meeting_id, tag, created_at)
+             SELECT ?, tag, ? FROM meeting_tags WHERE meeting_id = ?",
+        )
+        .bind(&new_id)
+        .bind(now)
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        // The copied transcripts keep their placeholders, so the originals come
+        // along; they stay sealed and are copied as they are
+        let mapping_ids: Vec<String> =
+            sqlx::query_scalar("SELECT id FROM redaction_mappings WHERE meeting_id = ? ORDER BY rowid")
+                .bind(meeting_id)
+                .fetch_all(&mut *transaction)
+                .await?;
+        for mapping_id in &mapping_ids {
+            sqlx::query(
+                "INSERT INTO redaction_mappings (id, meeting_id, placeholder, kind, original, created_at)
+                 SELECT ?, ?, placeholder, kind, original, ? FROM redaction_mappings WHERE id = ?",
+            )
+            .bind(format!("redaction-{}", Uuid::new_v4()))
+            .bind(&new_id)
+            .bind(now)
+            .bind(mapping_id)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+        info!(
+            "Duplicated meeting {} as {} ({} transcript segments)",
+            meeting_id,
+            new_id,
+            transcript_ids.len()
+        );
+        Ok(Some(new_id))
+    }
 }
 
 async fn delete_meeting_with_transaction(
@@ -264,7 +526,99 @@ async fn delete_meeting_with_transaction(
         .execute(&mut *transaction)
         .await?;
 
-    // 4. Finally, delete the meeting
+    // 4. Delete attachment rows (their files are removed at a later launch,
+    // once the deletion can no longer be undone)
+    sqlx::query("DELETE FROM meeting_attachments WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 5. Delete participants
+    sqlx::query("DELETE FROM meeting_participants WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 6. Delete speaker names (voice profiles are global and kept)
+    sqlx::query("DELETE FROM meeting_speakers WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 7. Delete action items
+    sqlx::query("DELETE FROM action_items WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 8. Delete delta reports stored on this meeting or comparing against it
+    sqlx::query("DELETE FROM meeting_delta_reports WHERE meeting_id = ? OR previous_meeting_id = ?")
+        .bind(meeting_id)
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 9. Delete custom field values
+    sqlx::query("DELETE FROM meeting_custom_fields WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 10. Delete tags
+    sqlx::query("DELETE FROM meeting_tags WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 11. Delete view history and pin state
+    sqlx::query("DELETE FROM meeting_activity WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 12. Delete cached speaker stats
+    sqlx::query("DELETE FROM meeting_speaker_stats WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 13. Delete segment sentiment analysis
+    sqlx::query("DELETE FROM segment_analysis WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 14. Delete extracted entities
+    sqlx::query("DELETE FROM transcript_entities WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 15. Delete keyword watchlist hits
+    sqlx::query("DELETE FROM keyword_hits WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 16. Delete segment comments
+    sqlx::query("DELETE FROM segment_comments WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 17. Delete the minutes review status
+    sqlx::query("DELETE FROM minutes_status WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 18. Delete import fingerprints
+    sqlx::query("DELETE FROM import_fingerprints WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 19. Finally, delete the meeting
     let result = sqlx::query("DELETE FROM meetings WHERE id = ?")
         .bind(meeting_id)
         .execute(&mut *transaction)
@@ -272,3 +626,232 @@ async fn delete_meeting_with_transaction(
 
     Ok(result.rows_affected() > 0)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::{insert_meeting, insert_transcript, memory_pool};
+
+    #[tokio::test]
+    async fn ensure_version_rejects_stale_versions() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+
+        assert_eq!(MeetingsRepository::get_version(&pool, "m1").await.unwrap(), Some(1));
+        assert!(MeetingsRepository::ensure_version(&pool, "m1", None).await.is_ok());
+        assert!(MeetingsRepository::ensure_version(&pool, "m1", Some(1)).await.is_ok());
+        let error = MeetingsRepository::ensure_version(&pool, "m1", Some(0))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains(CONFLICT_CODE));
+        // A missing meeting is the caller's to report
+        assert!(MeetingsRepository::ensure_version(&pool, "missing", Some(1)).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn only_visible_edits_bump_the_version() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+
+        sqlx::query("UPDATE meetings SET folder_path = ? WHERE id = ?")
+            .bind("/elsewhere/m1")
+            .bind("m1")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(MeetingsRepository::get_version(&pool, "m1").await.unwrap(), Some(1));
+
+        sqlx::query("UPDATE meetings SET updated_at = ? WHERE id = ?")
+            .bind(Utc::now())
+            .bind("m1")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(MeetingsRepository::get_version(&pool, "m1").await.unwrap(), Some(2));
+    }
+
+    #[tokio::test]
+    async fn update_meeting_title_checks_the_version() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+
+        let version = MeetingsRepository::update_meeting_title(&pool, "m1", "Renamed", Some(1))
+            .await
+            .unwrap();
+        assert_eq!(version, Some(2));
+
+        let error = MeetingsRepository::update_meeting_title(&pool, "m1", "Stale", Some(1))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains(CONFLICT_CODE));
+        let meeting = MeetingsRepository::get_meeting_metadata(&pool, "m1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(meeting.title, "Renamed");
+
+        assert_eq!(
+            MeetingsRepository::update_meeting_title(&pool, "missing", "x", None)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn duplicate_meeting_copies_the_content_with_fresh_ids() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        insert_transcript(&pool, "m1", "t1").await;
+        insert_transcript(&pool, "m1", "t2").await;
+        let now = Utc::now();
+        sqlx::query(
+            "INSERT INTO meeting_notes (meeting_id, notes_markdown, created_at, updated_at) VALUES (?, ?, ?, ?)",
+        )
+        .bind("m1")
+        .bind("# Notes")
+        .bind(now)
+        .bind(now)
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO meeting_tags (meeting_id, tag, created_at) VALUES (?, ?, ?)")
+            .bind("m1")
+            .bind("weekly")
+            .bind(now)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let copy = MeetingsRepository::duplicate_meeting(&pool, "m1", "Copy", None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_ne!(copy, "m1");
+
+        let transcripts: Vec<(String, String)> = sqlx::query_as(
+            "SELECT id, transcript FROM transcripts WHERE meeting_id = ? ORDER BY rowid",
+        )
+        .bind(&copy)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        let texts: Vec<&str> = transcripts.iter().map(|(_, text)| text.as_str()).collect();
+        assert_eq!(texts, ["Segment t1", "Segment t2"]);
+        assert!(transcripts.iter().all(|(id, _)| id != "t1" && id != "t2"));
+
+        let notes: Option<String> =
+            sqlx::query_scalar("SELECT notes_markdown FROM meeting_notes WHERE meeting_id = ?")
+                .bind(&copy)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(notes.as_deref(), Some("# Notes"));
+        let tags: Vec<String> =
+            sqlx::query_scalar("SELECT tag FROM meeting_tags WHERE meeting_id = ?")
+                .bind(&copy)
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(tags, ["weekly"]);
+        let title: String = sqlx::query_scalar("SELECT title FROM meetings WHERE id = ?")
+            .bind(&copy)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(title, "Copy");
+
+        // The source is left as it was
+        let source_segments: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM transcripts WHERE meeting_id = ?")
+                .bind("m1")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(source_segments, 2);
+    }
+
+    #[tokio::test]
+    async fn duplicate_meeting_copies_redaction_mappings_sealed() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        let now = Utc::now();
+        for (id, placeholder, original) in [
+            ("r1", "[EMAIL_1]", "sealed-email"),
+            ("r2", "[NAME_1]", "sealed-name"),
+        ] {
+            sqlx::query(
+                "INSERT INTO redaction_mappings (id, meeting_id, placeholder, kind, original, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(id)
+            .bind("m1")
+            .bind(placeholder)
+            .bind(if id == "r1" { "email" } else { "name" })
+            .bind(original)
+            .bind(now)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        let copy = MeetingsRepository::duplicate_meeting(&pool, "m1", "Copy", None)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let mappings: Vec<(String, String, String, String)> = sqlx::query_as(
+            "SELECT id, placeholder, kind, original FROM redaction_mappings WHERE meeting_id = ? ORDER BY rowid",
+        )
+        .bind(&copy)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(mappings.len(), 2);
+        assert_eq!(
+            (
+                mappings[0].1.as_str(),
+                mappings[0].2.as_str(),
+                mappings[0].3.as_str()
+            ),
+            ("[EMAIL_1]", "email", "sealed-email")
+        );
+        assert_eq!(
+            (
+                mappings[1].1.as_str(),
+                mappings[1].2.as_str(),
+                mappings[1].3.as_str()
+            ),
+            ("[NAME_1]", "name", "sealed-name")
+        );
+        assert!(mappings.iter().all(|(id, ..)| id != "r1" && id != "r2"));
+
+        // Deleting the copy leaves the source's mappings alone
+        assert!(MeetingsRepository::delete_meeting(&pool, &copy)
+            .await
+            .unwrap());
+        let remaining: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM redaction_mappings WHERE meeting_id = ?")
+                .bind("m1")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(remaining, 2);
+    }
+
+    #[tokio::test]
+    async fn duplicate_meeting_of_a_missing_meeting_is_none() {
+        let pool = memory_pool().await;
+        assert_eq!(
+            MeetingsRepository::duplicate_meeting(&pool, "missing", "Copy", None)
+                .await
+                .unwrap(),
+            None
+        );
+        let meetings: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM meetings")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(meetings, 0);
+    }
+}
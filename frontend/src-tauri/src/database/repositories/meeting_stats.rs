@@ -0,0 +1,311 @@
+//! Per-speaker participation statistics.
+//!
+//! Computed from segment timing only: talk time, how often a speaker started
+//! while someone else still held the floor, and the longest stretch they
+//! spoke without anyone else speaking. Results are cached in
+//! `meeting_speaker_stats` and recomputed when the segments change.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, Error as SqlxError, SqlitePool};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// Label used for segments without a speaker
+pub const UNKNOWN_SPEAKER: &str = "Unknown";
+/// Same-speaker segments closer than this are one monologue
+const MONOLOGUE_GAP_SECONDS: f64 = 2.0;
+/// Overlaps shorter than this are treated as turn-taking, not interruptions
+const INTERRUPTION_TOLERANCE_SECONDS: f64 = 0.3;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimedSegment {
+    pub speaker: String,
+    pub start: f64,
+    pub end: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SpeakerStats {
+    pub speaker_label: String,
+    pub display_name: Option<String>,
+    pub talk_seconds: f64,
+    /// Share of all talk time, 0.0 to 1.0
+    pub talk_share: f64,
+    pub segment_count: i64,
+    /// Times this speaker started while another speaker was still talking
+    pub interruptions: i64,
+    pub longest_monologue_seconds: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeetingStats {
+    pub meeting_id: String,
+    pub total_talk_seconds: f64,
+    /// Speakers by talk time, most first
+    pub speakers: Vec<SpeakerStats>,
+    pub computed_at: DateTime<Utc>,
+}
+
+/// Identifies a set of segments. Only compared against values written by the
+/// same build, so a hasher change just causes one recompute.
+pub fn fingerprint(segments: &[TimedSegment]) -> String {
+    let mut hasher = DefaultHasher::new();
+    for segment in segments {
+        segment.speaker.hash(&mut hasher);
+        segment.start.to_bits().hash(&mut hasher);
+        segment.end.to_bits().hash(&mut hasher);
+    }
+    format!("{}:{:016x}", segments.len(), hasher.finish())
+}
+
+/// Computes stats for segments sorted by start time
+pub fn compute_stats(segments: &[TimedSegment]) -> Vec<SpeakerStats> {
+    let mut stats: HashMap<&str, SpeakerStats> = HashMap::new();
+    // Who holds the floor and until when
+    let mut floor: Option<(&str, f64)> = None;
+    // Current monologue: speaker, start, end
+    let mut monologue: Option<(&str, f64, f64)> = None;
+
+    for segment in segments {
+        let speaker = segment.speaker.as_str();
+        let entry = stats.entry(speaker).or_insert_with(|| SpeakerStats {
+            speaker_label: speaker.to_string(),
+            display_name: None,
+            talk_seconds: 0.0,
+            talk_share: 0.0,
+            segment_count: 0,
+            interruptions: 0,
+            longest_monologue_seconds: 0.0,
+        });
+        entry.talk_seconds += (segment.end - segment.start).max(0.0);
+        entry.segment_count += 1;
+
+        if let Some((holder, until)) = floor {
+            if holder != speaker && segment.start < until - INTERRUPTION_TOLERANCE_SECONDS {
+                entry.interruptions += 1;
+            }
+        }
+        match floor {
+            Some((_, until)) if segment.end <= until => {}
+            _ => floor = Some((speaker, segment.end)),
+        }
+
+        monologue = match monologue {
+            Some((current, start, end))
+                if current == speaker && segment.start - end <= MONOLOGUE_GAP_SECONDS =>
+            {
+                Some((current, start, end.max(segment.end)))
+            }
+            _ => Some((speaker, segment.start, segment.end)),
+        };
+        if let Some((_, start, end)) = monologue {
+            entry.longest_monologue_seconds = entry.longest_monologue_seconds.max(end - start);
+        }
+    }
+
+    let total: f64 = stats.values().map(|s| s.talk_seconds).sum();
+    let mut stats: Vec<SpeakerStats> = stats.into_values().collect();
+    for s in &mut stats {
+        s.talk_share = if total > 0.0 { s.talk_seconds / total } else { 0.0 };
+    }
+    stats.sort_by(|a, b| {
+        b.talk_seconds
+            .total_cmp(&a.talk_seconds)
+            .then_with(|| a.speaker_label.cmp(&b.speaker_label))
+    });
+    stats
+}
+
+#[derive(sqlx::FromRow)]
+struct StoredStats {
+    speaker_label: String,
+    talk_seconds: f64,
+    segment_count: i64,
+    interruptions: i64,
+    longest_monologue_seconds: f64,
+    source_fingerprint: String,
+    computed_at: DateTime<Utc>,
+}
+
+pub struct MeetingStatsRepository;
+
+impl MeetingStatsRepository {
+    async fn load_segments(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<TimedSegment>, SqlxError> {
+        let rows: Vec<(Option<String>, f64, f64)> = sqlx::query_as(
+            "SELECT speaker, audio_start_time, audio_end_time FROM transcripts
+             WHERE meeting_id = ? AND audio_start_time IS NOT NULL AND audio_end_time IS NOT NULL
+             ORDER BY audio_start_time ASC, audio_end_time ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(speaker, start, end)| TimedSegment {
+                speaker: speaker
+                    .filter(|s| !s.trim().is_empty())
+                    .unwrap_or_else(|| UNKNOWN_SPEAKER.to_string()),
+                start,
+                end,
+            })
+            .collect())
+    }
+
+    /// Returns cached stats, recomputing them when the transcript changed
+    /// since they were stored or when `refresh` is set
+    pub async fn get_stats(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        refresh: bool,
+    ) -> Result<MeetingStats, SqlxError> {
+        let segments = Self::load_segments(pool, meeting_id).await?;
+        let source_fingerprint = fingerprint(&segments);
+
+        let stored = sqlx::query_as::<_, StoredStats>(
+            "SELECT speaker_label, talk_seconds, segment_count, interruptions,
+                    longest_monologue_seconds, source_fingerprint, computed_at
+             FROM meeting_speaker_stats WHERE meeting_id = ?",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await?;
+
+        let is_fresh = !stored.is_empty()
+            && stored.iter().all(|s| s.source_fingerprint == source_fingerprint);
+        let (mut speakers, computed_at) = if is_fresh && !refresh {
+            let computed_at = stored[0].computed_at;
+            let total: f64 = stored.iter().map(|s| s.talk_seconds).sum();
+            let mut speakers: Vec<SpeakerStats> = stored
+                .into_iter()
+                .map(|s| SpeakerStats {
+                    talk_share: if total > 0.0 { s.talk_seconds / total } else { 0.0 },
+                    speaker_label: s.speaker_label,
+                    display_name: None,
+                    talk_seconds: s.talk_seconds,
+                    segment_count: s.segment_count,
+                    interruptions: s.interruptions,
+                    longest_monologue_seconds: s.longest_monologue_seconds,
+                })
+                .collect();
+            speakers.sort_by(|a, b| b.talk_seconds.total_cmp(&a.talk_seconds));
+            (speakers, computed_at)
+        } else {
+            let speakers = compute_stats(&segments);
+            let computed_at = Utc::now();
+            Self::store(pool, meeting_id, &speakers, &source_fingerprint, computed_at).await?;
+            (speakers, computed_at)
+        };
+
+        let names: HashMap<String, String> = sqlx::query_as::<_, (String, String)>(
+            "SELECT speaker_label, display_name FROM meeting_speakers
+             WHERE meeting_id = ? AND display_name IS NOT NULL",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .collect();
+        for speaker in &mut speakers {
+            speaker.display_name = names.get(&speaker.speaker_label).cloned();
+        }
+
+        Ok(MeetingStats {
+            meeting_id: meeting_id.to_string(),
+            total_talk_seconds: speakers.iter().map(|s| s.talk_seconds).sum(),
+            speakers,
+            computed_at,
+        })
+    }
+
+    async fn store(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        speakers: &[SpeakerStats],
+        source_fingerprint: &str,
+        computed_at: DateTime<Utc>,
+    ) -> Result<(), SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+        sqlx::query("DELETE FROM meeting_speaker_stats WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        for s in speakers {
+            sqlx::query(
+                "INSERT INTO meeting_speaker_stats (meeting_id, speaker_label, talk_seconds, segment_count,
+                    interruptions, longest_monologue_seconds, source_fingerprint, computed_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(meeting_id)
+            .bind(&s.speaker_label)
+            .bind(s.talk_seconds)
+            .bind(s.segment_count)
+            .bind(s.interruptions)
+            .bind(s.longest_monologue_seconds)
+            .bind(source_fingerprint)
+            .bind(computed_at)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seg(speaker: &str, start: f64, end: f64) -> TimedSegment {
+        TimedSegment {
+            speaker: speaker.to_string(),
+            start,
+            end,
+        }
+    }
+
+    #[test]
+    fn computes_talk_time_and_monologues() {
+        let stats = compute_stats(&[
+            seg("A", 0.0, 10.0),
+            seg("A", 11.0, 20.0),
+            seg("B", 20.5, 25.0),
+            seg("A", 30.0, 35.0),
+        ]);
+        assert_eq!(stats[0].speaker_label, "A");
+        assert_eq!(stats[0].talk_seconds, 24.0);
+        assert_eq!(stats[0].segment_count, 3);
+        assert_eq!(stats[0].longest_monologue_seconds, 20.0);
+        assert_eq!(stats[1].longest_monologue_seconds, 4.5);
+        assert!((stats[0].talk_share + stats[1].talk_share - 1.0).abs() < 1e-9);
+        assert_eq!(stats[0].interruptions + stats[1].interruptions, 0);
+    }
+
+    #[test]
+    fn counts_interruptions() {
+        let stats = compute_stats(&[
+            seg("A", 0.0, 10.0),
+            // Starts while A is still talking
+            seg("B", 5.0, 7.0),
+            // Small overlap at a turn change is not an interruption
+            seg("B", 9.8, 12.0),
+            seg("A", 11.0, 15.0),
+        ]);
+        let get = |label: &str| stats.iter().find(|s| s.speaker_label == label).unwrap();
+        assert_eq!(get("B").interruptions, 1);
+        assert_eq!(get("A").interruptions, 1);
+    }
+
+    #[test]
+    fn fingerprint_changes_with_segments() {
+        let a = vec![seg("A", 0.0, 1.0)];
+        let b = vec![seg("B", 0.0, 1.0)];
+        assert_eq!(fingerprint(&a), fingerprint(&a.clone()));
+        assert_ne!(fingerprint(&a), fingerprint(&b));
+        assert!(compute_stats(&[]).is_empty());
+    }
+}
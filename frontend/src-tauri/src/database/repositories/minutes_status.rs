@@ -0,0 +1,271 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Error as SqlxError, SqliteExecutor, SqlitePool};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MinutesState {
+    Draft,
+    UnderReview,
+    Approved,
+}
+
+impl MinutesState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::UnderReview => "under_review",
+            Self::Approved => "approved",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        [Self::Draft, Self::UnderReview, Self::Approved]
+            .into_iter()
+            .find(|state| state.as_str() == value)
+    }
+
+    /// Drafts go to review; a review ends in approval or back in draft when
+    /// changes are requested; approved minutes can only be reopened as a draft
+    pub fn can_become(&self, next: MinutesState) -> bool {
+        matches!(
+            (self, next),
+            (Self::Draft, Self::UnderReview)
+                | (Self::UnderReview, Self::Approved)
+                | (Self::UnderReview, Self::Draft)
+                | (Self::Approved, Self::Draft)
+        )
+    }
+}
+
+/// Review state of a meeting's minutes, with who submitted and approved them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinutesStatus {
+    pub meeting_id: String,
+    pub status: MinutesState,
+    pub submitted_by: Option<String>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub approved_by: Option<String>,
+    pub approved_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(sqlx::FromRow)]
+struct MinutesStatusRow {
+    meeting_id: String,
+    status: String,
+    submitted_by: Option<String>,
+    submitted_at: Option<DateTime<Utc>>,
+    approved_by: Option<String>,
+    approved_at: Option<DateTime<Utc>>,
+    updated_at: DateTime<Utc>,
+}
+
+impl MinutesStatus {
+    fn draft(meeting_id: &str) -> Self {
+        Self {
+            meeting_id: meeting_id.to_string(),
+            status: MinutesState::Draft,
+            submitted_by: None,
+            submitted_at: None,
+            approved_by: None,
+            approved_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+pub struct MinutesStatusRepository;
+
+impl MinutesStatusRepository {
+    /// The meeting's minutes status; meetings that were never submitted are drafts
+    pub async fn get<'e>(
+        executor: impl SqliteExecutor<'e>,
+        meeting_id: &str,
+    ) -> Result<MinutesStatus, SqlxError> {
+        let row = sqlx::query_as::<_, MinutesStatusRow>(
+            "SELECT meeting_id, status, submitted_by, submitted_at, approved_by, approved_at, updated_at
+             FROM minutes_status WHERE meeting_id = ?",
+        )
+        .bind(meeting_id)
+        .fetch_optional(executor)
+        .await?;
+        let Some(row) = row else {
+            return Ok(MinutesStatus::draft(meeting_id));
+        };
+        let status = MinutesState::parse(&row.status)
+            .ok_or_else(|| SqlxError::Protocol(format!("Unknown minutes status {}", row.status)))?;
+        Ok(MinutesStatus {
+            meeting_id: row.meeting_id,
+            status,
+            submitted_by: row.submitted_by,
+            submitted_at: row.submitted_at,
+            approved_by: row.approved_by,
+            approved_at: row.approved_at,
+            updated_at: Some(row.updated_at),
+        })
+    }
+
+    /// Moves the minutes to `next`, recording `actor` as the submitter or
+    /// approver. Returns RowNotFound if the meeting doesn't exist.
+    pub async fn transition(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        next: MinutesState,
+        actor: &str,
+    ) -> Result<MinutesStatus, SqlxError> {
+        let mut transaction = pool.begin().await?;
+
+        let exists: Option<String> = sqlx::query_scalar("SELECT id FROM meetings WHERE id = ?")
+            .bind(meeting_id)
+            .fetch_optional(&mut *transaction)
+            .await?;
+        if exists.is_none() {
+            transaction.rollback().await?;
+            return Err(SqlxError::RowNotFound);
+        }
+        let mut status = Self::get(&mut *transaction, meeting_id).await?;
+        if !status.status.can_become(next) {
+            transaction.rollback().await?;
+            return Err(SqlxError::Protocol(format!(
+                "Minutes can't go from {} to {}",
+                status.status.as_str(),
+                next.as_str()
+            )));
+        }
+
+        let now = Utc::now();
+        match next {
+            MinutesState::UnderReview => {
+                status.submitted_by = Some(actor.to_string());
+                status.submitted_at = Some(now);
+            }
+            MinutesState::Approved => {
+                status.approved_by = Some(actor.to_string());
+                status.approved_at = Some(now);
+            }
+            // Reopened minutes need a fresh review and approval
+            MinutesState::Draft => {
+                status.approved_by = None;
+                status.approved_at = None;
+            }
+        }
+        status.status = next;
+        status.updated_at = Some(now);
+
+        sqlx::query(
+            "INSERT INTO minutes_status (meeting_id, status, submitted_by, submitted_at, approved_by, approved_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(meeting_id) DO UPDATE SET
+                status = excluded.status,
+                submitted_by = excluded.submitted_by,
+                submitted_at = excluded.submitted_at,
+                approved_by = excluded.approved_by,
+                approved_at = excluded.approved_at,
+                updated_at = excluded.updated_at",
+        )
+        .bind(meeting_id)
+        .bind(next.as_str())
+        .bind(&status.submitted_by)
+        .bind(status.submitted_at)
+        .bind(&status.approved_by)
+        .bind(status.approved_at)
+        .bind(now)
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+        Ok(status)
+    }
+
+    /// Fails when the minutes are approved. Paths that edit the summary,
+    /// transcript or title call this first, inside their transaction.
+    pub async fn ensure_editable<'e>(
+        executor: impl SqliteExecutor<'e>,
+        meeting_id: &str,
+    ) -> Result<(), SqlxError> {
+        let status: Option<String> =
+            sqlx::query_scalar("SELECT status FROM minutes_status WHERE meeting_id = ?")
+                .bind(meeting_id)
+                .fetch_optional(executor)
+                .await?;
+        if status.as_deref() == Some(MinutesState::Approved.as_str()) {
+            return Err(SqlxError::Protocol(
+                "Minutes are approved; return them to draft before editing".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follows_the_review_workflow() {
+        use MinutesState::*;
+        assert!(Draft.can_become(UnderReview));
+        assert!(UnderReview.can_become(Approved));
+        assert!(UnderReview.can_become(Draft));
+        assert!(Approved.can_become(Draft));
+
+        assert!(!Draft.can_become(Approved));
+        assert!(!Approved.can_become(UnderReview));
+        assert!(!Draft.can_become(Draft));
+        assert_eq!(MinutesState::parse("under_review"), Some(UnderReview));
+        assert_eq!(MinutesState::parse("final"), None);
+    }
+
+    #[tokio::test]
+    async fn approved_minutes_are_locked_until_reopened() {
+        use crate::database::test_support::{insert_meeting, memory_pool};
+        use MinutesState::*;
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        MinutesStatusRepository::ensure_editable(&pool, "m1")
+            .await
+            .unwrap();
+
+        assert!(
+            MinutesStatusRepository::transition(&pool, "m1", Approved, "lead")
+                .await
+                .is_err()
+        );
+        let submitted = MinutesStatusRepository::transition(&pool, "m1", UnderReview, "author")
+            .await
+            .unwrap();
+        assert_eq!(submitted.submitted_by.as_deref(), Some("author"));
+        // Minutes under review can still be corrected
+        MinutesStatusRepository::ensure_editable(&pool, "m1")
+            .await
+            .unwrap();
+
+        let approved = MinutesStatusRepository::transition(&pool, "m1", Approved, "lead")
+            .await
+            .unwrap();
+        assert_eq!(approved.approved_by.as_deref(), Some("lead"));
+        assert!(MinutesStatusRepository::ensure_editable(&pool, "m1")
+            .await
+            .is_err());
+
+        let reopened = MinutesStatusRepository::transition(&pool, "m1", Draft, "lead")
+            .await
+            .unwrap();
+        assert_eq!(reopened.approved_by, None);
+        assert_eq!(
+            MinutesStatusRepository::get(&pool, "m1")
+                .await
+                .unwrap()
+                .status,
+            Draft
+        );
+        MinutesStatusRepository::ensure_editable(&pool, "m1")
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            MinutesStatusRepository::transition(&pool, "missing", UnderReview, "author").await,
+            Err(SqlxError::RowNotFound)
+        ));
+    }
+}
@@ -1,5 +1,32 @@
+pub mod action_item;
+pub mod activity;
+pub mod attachment;
+pub mod audit;
+pub mod correction;
+pub mod custom_field;
+pub mod delta_report;
+pub mod digest;
+pub mod entity;
+pub mod feed;
+pub mod import_fingerprint;
+pub mod integration;
+pub mod job;
+pub mod journal;
+pub mod keyword_watch;
+pub mod legal_hold;
 pub mod meeting;
+pub mod meeting_stats;
+pub mod minutes_status;
+pub mod partial_summary;
+pub mod participant;
+pub mod redaction;
+pub mod search;
+pub mod segment_analysis;
+pub mod segment_comment;
 pub mod setting;
+pub mod speaker;
 pub mod summary;
+pub mod summary_feedback;
+pub mod tag;
 pub mod transcript;
 pub mod transcript_chunk;
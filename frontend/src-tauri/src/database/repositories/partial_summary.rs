@@ -0,0 +1,227 @@
+use crate::database::repositories::{
+    journal::JournalRepository, legal_hold::LegalHoldRepository,
+    minutes_status::MinutesStatusRepository,
+};
+use crate::encryption;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, Error as SqlxError, SqlitePool};
+use uuid::Uuid;
+
+/// Summary of a time range of a meeting
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PartialSummary {
+    pub id: String,
+    pub meeting_id: String,
+    pub name: String,
+    /// Seconds from the start of the recording
+    pub start_time: f64,
+    pub end_time: f64,
+    /// Markdown produced by the summary model
+    pub content: String,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+pub struct PartialSummariesRepository;
+
+impl PartialSummariesRepository {
+    /// Stores a new partial summary. Refused once the minutes are approved.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn save(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        name: &str,
+        start_time: f64,
+        end_time: f64,
+        content: &str,
+        provider: &str,
+        model: &str,
+    ) -> Result<PartialSummary, SqlxError> {
+        let summary = PartialSummary {
+            id: format!("partial-{}", Uuid::new_v4()),
+            meeting_id: meeting_id.to_string(),
+            name: name.to_string(),
+            start_time,
+            end_time,
+            content: content.to_string(),
+            provider: Some(provider.to_string()),
+            model: Some(model.to_string()),
+            created_at: Utc::now(),
+        };
+        let mut transaction = pool.begin().await?;
+        if let Err(e) = MinutesStatusRepository::ensure_editable(&mut *transaction, meeting_id).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+        sqlx::query(
+            "INSERT INTO partial_summaries (id, meeting_id, name, start_time, end_time, content, provider, model, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&summary.id)
+        .bind(&summary.meeting_id)
+        .bind(&summary.name)
+        .bind(summary.start_time)
+        .bind(summary.end_time)
+        .bind(encryption::seal(&summary.content)?)
+        .bind(&summary.provider)
+        .bind(&summary.model)
+        .bind(summary.created_at)
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+        Ok(summary)
+    }
+
+    /// A meeting's partial summaries in the order of their ranges
+    pub async fn list_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<PartialSummary>, SqlxError> {
+        sqlx::query_as::<_, PartialSummary>(
+            "SELECT id, meeting_id, name, start_time, end_time, content, provider, model, created_at
+             FROM partial_summaries WHERE meeting_id = ? ORDER BY start_time, created_at",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|mut summary| {
+            summary.content = encryption::open(std::mem::take(&mut summary.content))?;
+            Ok(summary)
+        })
+        .collect()
+    }
+
+    /// Returns false when the summary does not exist. Undoable this session.
+    pub async fn delete(pool: &SqlitePool, id: &str) -> Result<bool, SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        let row: Option<(String, String)> =
+            sqlx::query_as("SELECT meeting_id, name FROM partial_summaries WHERE id = ?")
+                .bind(id)
+                .fetch_optional(&mut *transaction)
+                .await?;
+        let Some((meeting_id, name)) = row else {
+            transaction.rollback().await?;
+            return Ok(false);
+        };
+        if let Err(e) = LegalHoldRepository::ensure_not_held(&mut *transaction, &meeting_id).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+        if let Err(e) = MinutesStatusRepository::ensure_editable(&mut *transaction, &meeting_id).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+
+        let image = JournalRepository::capture(
+            &mut transaction,
+            "partial_summaries",
+            "id",
+            &[id.to_string()],
+        )
+        .await?;
+        JournalRepository::record(
+            &mut transaction,
+            "delete_partial_summary",
+            Some(&meeting_id),
+            &format!("Delete partial summary \"{}\"", name),
+            &[image],
+        )
+        .await?;
+
+        sqlx::query("DELETE FROM partial_summaries WHERE id = ?")
+            .bind(id)
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::minutes_status::MinutesState;
+    use crate::database::test_support::{insert_meeting, memory_pool};
+
+    async fn save(pool: &SqlitePool, meeting_id: &str) -> Result<PartialSummary, SqlxError> {
+        PartialSummariesRepository::save(
+            pool,
+            meeting_id,
+            "Budget",
+            60.0,
+            120.0,
+            "## Budget\n- Approved",
+            "ollama",
+            "llama3.2",
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn deleted_summaries_can_be_undone() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        let saved = save(&pool, "m1").await.unwrap();
+
+        let listed = PartialSummariesRepository::list_for_meeting(&pool, "m1")
+            .await
+            .unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].content, "## Budget\n- Approved");
+
+        assert!(PartialSummariesRepository::delete(&pool, &saved.id)
+            .await
+            .unwrap());
+        assert!(!PartialSummariesRepository::delete(&pool, &saved.id)
+            .await
+            .unwrap());
+        let entry = JournalRepository::last_undoable(&pool)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(entry.operation, "delete_partial_summary");
+        JournalRepository::undo_last(&pool).await.unwrap();
+        let restored = PartialSummariesRepository::list_for_meeting(&pool, "m1")
+            .await
+            .unwrap();
+        assert_eq!(restored.len(), 1);
+        assert_eq!(restored[0].id, saved.id);
+    }
+
+    #[tokio::test]
+    async fn held_or_approved_meetings_keep_their_summaries() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "held").await;
+        insert_meeting(&pool, "approved").await;
+        let held = save(&pool, "held").await.unwrap();
+        let approved = save(&pool, "approved").await.unwrap();
+        LegalHoldRepository::place(&pool, "held", "Litigation", "legal")
+            .await
+            .unwrap();
+        for next in [MinutesState::UnderReview, MinutesState::Approved] {
+            MinutesStatusRepository::transition(&pool, "approved", next, "reviewer")
+                .await
+                .unwrap();
+        }
+
+        assert!(matches!(
+            save(&pool, "approved").await,
+            Err(SqlxError::Protocol(_))
+        ));
+        for summary in [&held, &approved] {
+            assert!(matches!(
+                PartialSummariesRepository::delete(&pool, &summary.id).await,
+                Err(SqlxError::Protocol(_))
+            ));
+            let listed = PartialSummariesRepository::list_for_meeting(&pool, &summary.meeting_id)
+                .await
+                .unwrap();
+            assert_eq!(listed.len(), 1);
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use crate::database::models::MeetingParticipant;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, Error as SqlxError, SqlitePool};
+use tracing::info;
+use uuid::Uuid;
+
+/// Participant fields supplied by the frontend or an integration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParticipantInput {
+    pub name: String,
+    pub email: Option<String>,
+    pub role: Option<String>,
+    pub speaker_label: Option<String>,
+}
+
+pub struct ParticipantsRepository;
+
+impl ParticipantsRepository {
+    pub async fn list_participants(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<MeetingParticipant>, SqlxError> {
+        sqlx::query_as::<_, MeetingParticipant>(
+            "SELECT * FROM meeting_participants WHERE meeting_id = ? ORDER BY created_at ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_participant(
+        pool: &SqlitePool,
+        participant_id: &str,
+    ) -> Result<Option<MeetingParticipant>, SqlxError> {
+        sqlx::query_as::<_, MeetingParticipant>("SELECT * FROM meeting_participants WHERE id = ?")
+            .bind(participant_id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    pub async fn add_participant(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        input: &ParticipantInput,
+        source: &str,
+    ) -> Result<MeetingParticipant, SqlxError> {
+        if input.name.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "participant name cannot be empty".to_string(),
+            ));
+        }
+
+        let id = format!("participant-{}", Uuid::new_v4());
+        let now = Utc::now();
+
+        sqlx::query(
+            "INSERT INTO meeting_participants (id, meeting_id, name, email, role, speaker_label, source, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&id)
+        .bind(meeting_id)
+        .bind(input.name.trim())
+        .bind(&input.email)
+        .bind(&input.role)
+        .bind(&input.speaker_label)
+        .bind(source)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await?;
+
+        Self::get_participant(pool, &id)
+            .await?
+            .ok_or(SqlxError::RowNotFound)
+    }
+
+    pub async fn update_participant(
+        pool: &SqlitePool,
+        participant_id: &str,
+        input: &ParticipantInput,
+    ) -> Result<bool, SqlxError> {
+        if input.name.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "participant name cannot be empty".to_string(),
+            ));
+        }
+
+        let result = sqlx::query(
+            "UPDATE meeting_participants
+             SET name = ?, email = ?, role = ?, speaker_label = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(input.name.trim())
+        .bind(&input.email)
+        .bind(&input.role)
+        .bind(&input.speaker_label)
+        .bind(Utc::now())
+        .bind(participant_id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn delete_participant(
+        pool: &SqlitePool,
+        participant_id: &str,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query("DELETE FROM meeting_participants WHERE id = ?")
+            .bind(participant_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Merges participants from an external source (e.g. calendar attendees).
+    /// Existing participants are matched by email (case-insensitive), then by name,
+    /// so re-syncing the same event doesn't create duplicates or clobber manual edits
+    /// to role/speaker mapping.
+    pub async fn merge_participants(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        inputs: &[ParticipantInput],
+        source: &str,
+    ) -> Result<usize, SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+        let now = Utc::now();
+        let mut inserted = 0;
+
+        for input in inputs {
+            if input.name.trim().is_empty() {
+                continue;
+            }
+
+            let existing: Option<(String,)> = sqlx::query_as(
+                "SELECT id FROM meeting_participants
+                 WHERE meeting_id = ?
+                   AND ((? IS NOT NULL AND LOWER(email) = LOWER(?)) OR LOWER(name) = LOWER(?))
+                 LIMIT 1",
+            )
+            .bind(meeting_id)
+            .bind(&input.email)
+            .bind(&input.email)
+            .bind(input.name.trim())
+            .fetch_optional(&mut *transaction)
+            .await?;
+
+            if let Some((id,)) = existing {
+                // Only fill in blanks; keep whatever the user already set
+                sqlx::query(
+                    "UPDATE meeting_participants
+                     SET email = COALESCE(email, ?), role = COALESCE(role, ?), updated_at = ?
+                     WHERE id = ?",
+                )
+                .bind(&input.email)
+                .bind(&input.role)
+                .bind(now)
+                .bind(&id)
+                .execute(&mut *transaction)
+                .await?;
+            } else {
+                sqlx::query(
+                    "INSERT INTO meeting_participants (id, meeting_id, name, email, role, speaker_label, source, created_at, updated_at)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                )
+                .bind(format!("participant-{}", Uuid::new_v4()))
+                .bind(meeting_id)
+                .bind(input.name.trim())
+                .bind(&input.email)
+                .bind(&input.role)
+                .bind(&input.speaker_label)
+                .bind(source)
+                .bind(now)
+                .bind(now)
+                .execute(&mut *transaction)
+                .await?;
+                inserted += 1;
+            }
+        }
+
+        transaction.commit().await?;
+        info!(
+            "Merged {} participants ({} new) from '{}' into meeting {}",
+            inputs.len(),
+            inserted,
+            source,
+            meeting_id
+        );
+        Ok(inserted)
+    }
+
+    /// Looks up the participant mapped to a diarization speaker label
+    pub async fn find_by_speaker_label(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        speaker_label: &str,
+    ) -> Result<Option<MeetingParticipant>, SqlxError> {
+        sqlx::query_as::<_, MeetingParticipant>(
+            "SELECT * FROM meeting_participants WHERE meeting_id = ? AND speaker_label = ? LIMIT 1",
+        )
+        .bind(meeting_id)
+        .bind(speaker_label)
+        .fetch_optional(pool)
+        .await
+    }
+}
@@ -0,0 +1,83 @@
+use crate::encryption;
+use crate::redaction::detect::{MappingEntry, PiiKind};
+use chrono::Utc;
+use sqlx::{Error as SqlxError, Row, SqliteConnection, SqlitePool};
+use uuid::Uuid;
+
+/// Placeholder -> original value for reversibly redacted transcripts. The
+/// originals are always stored encrypted.
+pub struct RedactionMappingsRepository;
+
+impl RedactionMappingsRepository {
+    /// The meeting's mapping in placeholder order. Needs encrypted data to be
+    /// unlocked.
+    pub async fn list_for_meeting(
+        conn: &mut SqliteConnection,
+        meeting_id: &str,
+    ) -> Result<Vec<MappingEntry>, SqlxError> {
+        let rows = sqlx::query(
+            "SELECT placeholder, kind, original FROM redaction_mappings WHERE meeting_id = ? ORDER BY rowid",
+        )
+        .bind(meeting_id)
+        .fetch_all(&mut *conn)
+        .await?;
+        rows.into_iter()
+            .map(|row| {
+                let kind: String = row.get("kind");
+                Ok(MappingEntry {
+                    placeholder: row.get("placeholder"),
+                    kind: PiiKind::parse(&kind).ok_or_else(|| {
+                        SqlxError::Protocol(format!("Unknown redaction kind '{}'", kind))
+                    })?,
+                    original: encryption::open(row.get("original"))?,
+                })
+            })
+            .collect()
+    }
+
+    /// Stores new placeholders. Refuses to write originals in plaintext.
+    pub async fn insert(
+        conn: &mut SqliteConnection,
+        meeting_id: &str,
+        entries: &[MappingEntry],
+    ) -> Result<(), SqlxError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        if !encryption::is_enabled() {
+            return Err(SqlxError::Protocol(
+                "Reversible redaction requires encryption to be enabled".to_string(),
+            ));
+        }
+        let now = Utc::now();
+        for entry in entries {
+            sqlx::query(
+                "INSERT INTO redaction_mappings (id, meeting_id, placeholder, kind, original, created_at) VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(format!("redaction-{}", Uuid::new_v4()))
+            .bind(meeting_id)
+            .bind(&entry.placeholder)
+            .bind(entry.kind.as_str())
+            .bind(encryption::seal(&entry.original)?)
+            .bind(now)
+            .execute(&mut *conn)
+            .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn count(pool: &SqlitePool) -> Result<i64, SqlxError> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM redaction_mappings")
+            .fetch_one(pool)
+            .await
+    }
+
+    /// Drops every mapping, making existing redactions permanent. Returns the
+    /// number of mappings removed.
+    pub async fn delete_all(pool: &SqlitePool) -> Result<u64, SqlxError> {
+        let result = sqlx::query("DELETE FROM redaction_mappings")
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
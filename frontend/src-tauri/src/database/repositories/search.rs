@@ -0,0 +1,696 @@
+//! Structured meeting queries and transcript search ranking.
+//!
+//! A [`MeetingFilter`] is compiled into a single SELECT over `meetings` where
+//! every condition is an indexed `EXISTS` subquery or a comparison, so
+//! filters combine with AND and no rows are loaded just to be discarded.
+//!
+//! Text search results carry a relevance score and the ranges of matched words
+//! within their snippet. Ranges are UTF-16 code unit offsets so the frontend
+//! can slice JavaScript strings with them directly.
+
+use crate::api::TranscriptSearchResult;
+use crate::database::models::{DateTimeUtc, SavedSearchModel};
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::encryption;
+use crate::export::csv::parse_date_bound;
+use crate::summary::entities::normalize_entity;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Error as SqlxError, QueryBuilder, Sqlite, SqlitePool};
+use std::collections::{BTreeSet, HashMap};
+use uuid::Uuid;
+
+/// Every field is optional; an empty filter matches every meeting
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MeetingFilter {
+    /// Case-insensitive match on the title or transcript text
+    pub text: Option<String>,
+    /// Meeting date bounds, `YYYY-MM-DD` or RFC 3339 (inclusive)
+    pub from: Option<String>,
+    pub to: Option<String>,
+    /// Only meetings from the last N days, counted from when the query runs.
+    /// Lets a saved search mean "last 30 days" rather than fixed dates.
+    pub within_days: Option<u32>,
+    /// Recording length bounds, in seconds
+    pub min_duration_seconds: Option<f64>,
+    pub max_duration_seconds: Option<f64>,
+    /// Meetings must carry every listed tag
+    pub tags: Vec<String>,
+    /// Meetings must include at least one of these speakers, matched against
+    /// the speaker label or the name it was given
+    pub speakers: Vec<String>,
+    /// Only meetings with (true) or without (false) a completed summary
+    pub has_summary: Option<bool>,
+    /// Custom field values by field name, matched exactly (case-insensitive)
+    pub custom_fields: HashMap<String, String>,
+    /// Meetings with at least one analyzed segment of this sentiment
+    /// ("positive", "neutral", "negative") and/or tone (e.g. "frustrated")
+    pub sentiment: Option<String>,
+    pub tone: Option<String>,
+    /// Extracted entities the meeting must mention, e.g. "Acme Corp"
+    pub entities: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct MeetingQueryResult {
+    pub id: String,
+    pub title: String,
+    pub created_at: String,
+    pub duration_seconds: Option<f64>,
+    pub has_summary: bool,
+    #[sqlx(skip)]
+    pub tags: Vec<String>,
+}
+
+const DURATION_SQL: &str =
+    "(SELECT MAX(t.audio_end_time) FROM transcripts t WHERE t.meeting_id = m.id)";
+const HAS_SUMMARY_SQL: &str = "EXISTS (SELECT 1 FROM summary_processes s
+     WHERE s.meeting_id = m.id AND s.status = 'completed' AND s.result IS NOT NULL)";
+
+fn like_pattern(value: &str) -> String {
+    format!("%{}%", value.trim().to_lowercase())
+}
+
+/// Builds the query for `filter`. When transcripts are encrypted their text
+/// can't be matched in SQL, so the caller passes the ids of meetings whose
+/// decrypted text matched instead.
+fn build_query(
+    filter: &MeetingFilter,
+    text_matches: Option<&BTreeSet<String>>,
+) -> Result<QueryBuilder<'static, Sqlite>, String> {
+    let mut builder: QueryBuilder<Sqlite> = QueryBuilder::new(format!(
+        "SELECT m.id, m.title, m.created_at, {} AS duration_seconds, {} AS has_summary
+         FROM meetings m WHERE 1 = 1",
+        DURATION_SQL, HAS_SUMMARY_SQL
+    ));
+
+    if let Some(text) = filter.text.as_deref().filter(|t| !t.trim().is_empty()) {
+        builder
+            .push(" AND (LOWER(m.title) LIKE ")
+            .push_bind(like_pattern(text));
+        match text_matches {
+            Some(ids) => {
+                builder.push(" OR m.id IN (SELECT value FROM json_each(");
+                builder.push_bind(serde_json::to_string(ids).map_err(|e| e.to_string())?);
+                builder.push("))");
+            }
+            None => {
+                builder
+                    .push(
+                        " OR EXISTS (SELECT 1 FROM transcripts t WHERE t.meeting_id = m.id AND LOWER(t.transcript) LIKE ",
+                    )
+                    .push_bind(like_pattern(text))
+                    .push(")");
+            }
+        }
+        builder.push(")");
+    }
+
+    if let Some(from) = filter.from.as_deref() {
+        builder
+            .push(" AND m.created_at >= ")
+            .push_bind(parse_date_bound(from, false)?);
+    }
+    if let Some(to) = filter.to.as_deref() {
+        builder
+            .push(" AND m.created_at <= ")
+            .push_bind(parse_date_bound(to, true)?);
+    }
+    if let Some(days) = filter.within_days {
+        builder
+            .push(" AND m.created_at >= ")
+            .push_bind(Utc::now() - Duration::days(days.into()));
+    }
+
+    if let Some(min) = filter.min_duration_seconds {
+        builder
+            .push(format!(" AND COALESCE({}, 0) >= ", DURATION_SQL))
+            .push_bind(min);
+    }
+    if let Some(max) = filter.max_duration_seconds {
+        builder
+            .push(format!(" AND COALESCE({}, 0) <= ", DURATION_SQL))
+            .push_bind(max);
+    }
+
+    for tag in filter.tags.iter().filter(|t| !t.trim().is_empty()) {
+        builder
+            .push(" AND EXISTS (SELECT 1 FROM meeting_tags g WHERE g.meeting_id = m.id AND g.tag = ")
+            .push_bind(tag.trim().to_string())
+            .push(")");
+    }
+
+    let speakers: Vec<String> = filter
+        .speakers
+        .iter()
+        .map(|s| s.trim().to_lowercase())
+        .filter(|s| !s.is_empty())
+        .collect();
+    if !speakers.is_empty() {
+        let speakers = serde_json::to_string(&speakers).map_err(|e| e.to_string())?;
+        builder
+            .push(
+                " AND (EXISTS (SELECT 1 FROM transcripts t WHERE t.meeting_id = m.id
+                   AND LOWER(t.speaker) IN (SELECT value FROM json_each(",
+            )
+            .push_bind(speakers.clone())
+            .push(
+                ")))
+                 OR EXISTS (SELECT 1 FROM meeting_speakers sp WHERE sp.meeting_id = m.id
+                   AND (LOWER(sp.speaker_label) IN (SELECT value FROM json_each(",
+            )
+            .push_bind(speakers.clone())
+            .push(")) OR LOWER(sp.display_name) IN (SELECT value FROM json_each(")
+            .push_bind(speakers)
+            .push(")))))");
+    }
+
+    match filter.has_summary {
+        Some(true) => {
+            builder.push(format!(" AND {}", HAS_SUMMARY_SQL));
+        }
+        Some(false) => {
+            builder.push(format!(" AND NOT {}", HAS_SUMMARY_SQL));
+        }
+        None => {}
+    }
+
+    for (name, value) in &filter.custom_fields {
+        builder
+            .push(
+                " AND EXISTS (SELECT 1 FROM meeting_custom_fields v
+                   JOIN custom_field_definitions d ON d.id = v.field_id
+                   WHERE v.meeting_id = m.id AND d.name = ",
+            )
+            .push_bind(name.trim().to_string())
+            .push(" AND LOWER(v.value) = ")
+            .push_bind(value.trim().to_lowercase())
+            .push(")");
+    }
+
+    if filter.sentiment.is_some() || filter.tone.is_some() {
+        builder.push(" AND EXISTS (SELECT 1 FROM segment_analysis a WHERE a.meeting_id = m.id");
+        if let Some(sentiment) = &filter.sentiment {
+            builder
+                .push(" AND a.sentiment = ")
+                .push_bind(sentiment.trim().to_lowercase());
+        }
+        if let Some(tone) = &filter.tone {
+            builder.push(" AND a.tone = ").push_bind(tone.trim().to_lowercase());
+        }
+        builder.push(")");
+    }
+
+    for entity in filter.entities.iter().map(|e| normalize_entity(e)) {
+        if entity.is_empty() {
+            continue;
+        }
+        builder
+            .push(" AND EXISTS (SELECT 1 FROM transcript_entities e WHERE e.meeting_id = m.id AND e.normalized = ")
+            .push_bind(entity)
+            .push(")");
+    }
+
+    builder.push(" ORDER BY m.created_at DESC");
+    Ok(builder)
+}
+
+/// A saved search with its filter decoded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSearch {
+    pub id: String,
+    pub name: String,
+    pub filter: MeetingFilter,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+    pub last_run_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<SavedSearchModel> for SavedSearch {
+    type Error = SqlxError;
+
+    fn try_from(model: SavedSearchModel) -> Result<Self, Self::Error> {
+        let filter = serde_json::from_str(&model.filter).map_err(|e| {
+            SqlxError::Protocol(format!("Saved search '{}' has an invalid filter: {}", model.name, e))
+        })?;
+        Ok(Self {
+            id: model.id,
+            name: model.name,
+            filter,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+            last_run_at: model.last_run_at,
+        })
+    }
+}
+
+/// How text search results are ordered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchRanking {
+    /// Newest meetings first
+    #[default]
+    Recency,
+    /// Most matches first, newest first among equals
+    Relevance,
+}
+
+/// One page of text search results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptSearchPage {
+    pub results: Vec<TranscriptSearchResult>,
+    /// Matches across all pages
+    pub total: usize,
+    pub offset: usize,
+    pub limit: usize,
+    pub has_more: bool,
+}
+
+/// Characters of context kept on each side of the first match
+const CONTEXT_CHARS: usize = 100;
+
+/// Distinct lowercase words of a query
+pub fn query_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for term in query.split_whitespace().map(str::to_lowercase) {
+        if !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms
+}
+
+fn lower_chars(text: &str) -> Vec<char> {
+    // Lowercase per char so indexes line up with the original text
+    text.chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect()
+}
+
+/// Char indexes where `needle` starts in `haystack`, without overlaps
+fn find_all(haystack: &[char], needle: &[char]) -> Vec<usize> {
+    let mut found = Vec::new();
+    if needle.is_empty() || needle.len() > haystack.len() {
+        return found;
+    }
+    let mut i = 0;
+    while i + needle.len() <= haystack.len() {
+        if haystack[i..i + needle.len()] == *needle {
+            found.push(i);
+            i += needle.len();
+        } else {
+            i += 1;
+        }
+    }
+    found
+}
+
+/// Scores `text` against `query`: one point per occurrence of each word, plus
+/// a bonus when the whole phrase appears and when the title matches
+pub fn relevance_score(text: &str, title: &str, query: &str) -> f64 {
+    let text_chars = lower_chars(text);
+    let mut score: f64 = query_terms(query)
+        .iter()
+        .map(|term| find_all(&text_chars, &lower_chars(term)).len() as f64)
+        .sum();
+    let phrase = lower_chars(query.trim());
+    if !find_all(&text_chars, &phrase).is_empty() {
+        score += 2.0;
+    }
+    if !find_all(&lower_chars(title), &phrase).is_empty() {
+        score += 1.0;
+    }
+    score
+}
+
+/// A snippet around the first match of `query` in `text`, and the UTF-16
+/// ranges of every query word within that snippet
+pub fn match_context(text: &str, query: &str) -> (String, Vec<[usize; 2]>) {
+    let chars: Vec<char> = text.chars().collect();
+    let lower = lower_chars(text);
+    let terms: Vec<Vec<char>> = query_terms(query).iter().map(|t| lower_chars(t)).collect();
+
+    let phrase = lower_chars(query.trim());
+    let first = find_all(&lower, &phrase)
+        .first()
+        .map(|&i| (i, phrase.len()))
+        .or_else(|| {
+            terms
+                .iter()
+                .filter_map(|t| find_all(&lower, t).first().map(|&i| (i, t.len())))
+                .min()
+        });
+
+    let (start, end) = match first {
+        Some((index, len)) => (
+            index.saturating_sub(CONTEXT_CHARS),
+            (index + len + CONTEXT_CHARS).min(chars.len()),
+        ),
+        None => (0, chars.len().min(2 * CONTEXT_CHARS)),
+    };
+
+    let prefix = if start > 0 { "..." } else { "" };
+    let mut context: String = prefix.to_string();
+    context.extend(&chars[start..end]);
+    if end < chars.len() {
+        context.push_str("...");
+    }
+
+    // Char ranges within the window, merged where words overlap
+    let window = &lower[start..end];
+    let mut ranges: Vec<(usize, usize)> = terms
+        .iter()
+        .flat_map(|t| find_all(window, t).into_iter().map(move |i| (i, i + t.len())))
+        .collect();
+    ranges.sort_unstable();
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for (s, e) in ranges {
+        match merged.last_mut() {
+            Some(last) if s <= last.1 => last.1 = last.1.max(e),
+            _ => merged.push((s, e)),
+        }
+    }
+
+    let utf16_at = |char_index: usize| -> usize {
+        prefix.len() + chars[start..start + char_index].iter().map(|c| c.len_utf16()).sum::<usize>()
+    };
+    let offsets = merged
+        .into_iter()
+        .map(|(s, e)| [utf16_at(s), utf16_at(e)])
+        .collect();
+    (context, offsets)
+}
+
+/// Orders results. `created_at` is the meeting's creation time, used for
+/// recency and to break relevance ties.
+pub fn rank_results(
+    mut results: Vec<(String, TranscriptSearchResult)>,
+    ranking: SearchRanking,
+) -> Vec<TranscriptSearchResult> {
+    results.sort_by(|(a_created, a), (b_created, b)| {
+        let by_recency = b_created.cmp(a_created).then_with(|| a.timestamp.cmp(&b.timestamp));
+        match ranking {
+            SearchRanking::Recency => by_recency,
+            SearchRanking::Relevance => b.score.total_cmp(&a.score).then(by_recency),
+        }
+    });
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Cuts one page out of ranked results
+pub fn paginate(
+    results: Vec<TranscriptSearchResult>,
+    offset: usize,
+    limit: usize,
+) -> TranscriptSearchPage {
+    let total = results.len();
+    let page: Vec<TranscriptSearchResult> = results.into_iter().skip(offset).take(limit).collect();
+    TranscriptSearchPage {
+        has_more: offset + page.len() < total,
+        results: page,
+        total,
+        offset,
+        limit,
+    }
+}
+
+pub struct SearchRepository;
+
+impl SearchRepository {
+    /// Meetings matching every condition in `filter`, newest first
+    pub async fn query_meetings(
+        pool: &SqlitePool,
+        filter: &MeetingFilter,
+    ) -> Result<Vec<MeetingQueryResult>, SqlxError> {
+        let text_matches = match filter.text.as_deref() {
+            Some(text) if encryption::is_enabled() && !text.trim().is_empty() => Some(
+                TranscriptsRepository::search_transcripts(pool, text)
+                    .await?
+                    .into_iter()
+                    .map(|r| r.id)
+                    .collect::<BTreeSet<_>>(),
+            ),
+            _ => None,
+        };
+
+        let mut builder =
+            build_query(filter, text_matches.as_ref()).map_err(SqlxError::Protocol)?;
+        let mut meetings = builder
+            .build_query_as::<MeetingQueryResult>()
+            .fetch_all(pool)
+            .await?;
+
+        let mut tags: HashMap<String, Vec<String>> = HashMap::new();
+        let rows: Vec<(String, String)> =
+            sqlx::query_as("SELECT meeting_id, tag FROM meeting_tags ORDER BY tag ASC")
+                .fetch_all(pool)
+                .await?;
+        for (meeting_id, tag) in rows {
+            tags.entry(meeting_id).or_default().push(tag);
+        }
+        for meeting in &mut meetings {
+            meeting.tags = tags.remove(&meeting.id).unwrap_or_default();
+        }
+        Ok(meetings)
+    }
+
+    /// Saved searches, most recently used first
+    pub async fn list_saved_searches(pool: &SqlitePool) -> Result<Vec<SavedSearch>, SqlxError> {
+        sqlx::query_as::<_, SavedSearchModel>(
+            "SELECT * FROM saved_searches ORDER BY COALESCE(last_run_at, updated_at) DESC",
+        )
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(SavedSearch::try_from)
+        .collect()
+    }
+
+    pub async fn get_saved_search(
+        pool: &SqlitePool,
+        search_id: &str,
+    ) -> Result<Option<SavedSearch>, SqlxError> {
+        sqlx::query_as::<_, SavedSearchModel>("SELECT * FROM saved_searches WHERE id = ?")
+            .bind(search_id)
+            .fetch_optional(pool)
+            .await?
+            .map(SavedSearch::try_from)
+            .transpose()
+    }
+
+    /// Creates a saved search, or replaces the one with `search_id`. The filter
+    /// is validated first so a broken search can't be stored.
+    pub async fn save_search(
+        pool: &SqlitePool,
+        search_id: Option<&str>,
+        name: &str,
+        filter: &MeetingFilter,
+    ) -> Result<SavedSearch, SqlxError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(SqlxError::Protocol("Search name cannot be empty".to_string()));
+        }
+        build_query(filter, None).map_err(SqlxError::Protocol)?;
+        let filter_json = serde_json::to_string(filter)
+            .map_err(|e| SqlxError::Protocol(format!("Failed to serialize filter: {}", e)))?;
+        let now = Utc::now();
+
+        let id = match search_id {
+            Some(id) => {
+                let result = sqlx::query(
+                    "UPDATE saved_searches SET name = ?, filter = ?, updated_at = ? WHERE id = ?",
+                )
+                .bind(name)
+                .bind(&filter_json)
+                .bind(now)
+                .bind(id)
+                .execute(pool)
+                .await?;
+                if result.rows_affected() == 0 {
+                    return Err(SqlxError::RowNotFound);
+                }
+                id.to_string()
+            }
+            None => {
+                let id = format!("search-{}", Uuid::new_v4());
+                sqlx::query(
+                    "INSERT INTO saved_searches (id, name, filter, created_at, updated_at)
+                     VALUES (?, ?, ?, ?, ?)",
+                )
+                .bind(&id)
+                .bind(name)
+                .bind(&filter_json)
+                .bind(now)
+                .bind(now)
+                .execute(pool)
+                .await?;
+                id
+            }
+        };
+
+        Self::get_saved_search(pool, &id)
+            .await?
+            .ok_or(SqlxError::RowNotFound)
+    }
+
+    pub async fn delete_saved_search(pool: &SqlitePool, search_id: &str) -> Result<bool, SqlxError> {
+        let result = sqlx::query("DELETE FROM saved_searches WHERE id = ?")
+            .bind(search_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Runs a saved search and records when it was last used.
+    /// Returns None when the search doesn't exist.
+    pub async fn run_saved_search(
+        pool: &SqlitePool,
+        search_id: &str,
+    ) -> Result<Option<Vec<MeetingQueryResult>>, SqlxError> {
+        let Some(search) = Self::get_saved_search(pool, search_id).await? else {
+            return Ok(None);
+        };
+        let results = Self::query_meetings(pool, &search.filter).await?;
+        sqlx::query("UPDATE saved_searches SET last_run_at = ? WHERE id = ?")
+            .bind(Utc::now())
+            .bind(search_id)
+            .execute(pool)
+            .await?;
+        Ok(Some(results))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_filter_has_no_conditions() {
+        let sql = build_query(&MeetingFilter::default(), None).unwrap().into_sql();
+        assert!(sql.ends_with("WHERE 1 = 1 ORDER BY m.created_at DESC"));
+    }
+
+    #[test]
+    fn combines_conditions() {
+        let filter = MeetingFilter {
+            text: Some("budget".into()),
+            from: Some("2026-01-01".into()),
+            min_duration_seconds: Some(600.0),
+            tags: vec!["client".into(), "q1".into()],
+            speakers: vec!["Alice".into()],
+            has_summary: Some(false),
+            ..Default::default()
+        };
+        let sql = build_query(&filter, None).unwrap().into_sql();
+        assert!(sql.contains("LOWER(t.transcript) LIKE"));
+        assert!(sql.contains("m.created_at >="));
+        assert!(!sql.contains("m.created_at <="));
+        assert_eq!(sql.matches("FROM meeting_tags g").count(), 2);
+        assert!(sql.contains("LOWER(sp.display_name)"));
+        assert!(sql.contains("AND NOT EXISTS (SELECT 1 FROM summary_processes"));
+    }
+
+    #[test]
+    fn filters_by_segment_tone() {
+        let filter = MeetingFilter {
+            tone: Some(" Frustrated ".into()),
+            ..Default::default()
+        };
+        let sql = build_query(&filter, None).unwrap().into_sql();
+        assert!(sql.contains("EXISTS (SELECT 1 FROM segment_analysis a WHERE a.meeting_id = m.id AND a.tone = "));
+        assert!(!sql.contains("a.sentiment"));
+    }
+
+    #[test]
+    fn filters_by_entity_key() {
+        let filter = MeetingFilter {
+            entities: vec!["ACME Corp".into(), " ".into()],
+            ..Default::default()
+        };
+        let sql = build_query(&filter, None).unwrap().into_sql();
+        assert_eq!(sql.matches("FROM transcript_entities e").count(), 1);
+    }
+
+    #[test]
+    fn encrypted_text_uses_matched_ids() {
+        let filter = MeetingFilter {
+            text: Some("budget".into()),
+            ..Default::default()
+        };
+        let ids = BTreeSet::from(["meeting-1".to_string()]);
+        let sql = build_query(&filter, Some(&ids)).unwrap().into_sql();
+        assert!(sql.contains("m.id IN (SELECT value FROM json_each("));
+        assert!(!sql.contains("t.transcript"));
+    }
+
+    #[test]
+    fn relative_window_adds_a_date_bound() {
+        let filter = MeetingFilter {
+            within_days: Some(30),
+            ..Default::default()
+        };
+        let sql = build_query(&filter, None).unwrap().into_sql();
+        assert!(sql.contains("AND m.created_at >= "));
+    }
+
+    fn result(id: &str, score: f64) -> TranscriptSearchResult {
+        TranscriptSearchResult {
+            id: id.to_string(),
+            title: String::new(),
+            match_context: String::new(),
+            timestamp: String::new(),
+            score,
+            match_offsets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn context_offsets_mark_each_word() {
+        let (context, offsets) = match_context("The Budget review covers budget cuts", "budget cuts");
+        assert_eq!(context, "The Budget review covers budget cuts");
+        assert_eq!(offsets, vec![[4, 10], [25, 31], [32, 36]]);
+    }
+
+    #[test]
+    fn context_is_trimmed_on_char_boundaries() {
+        let text = format!("{}日本語 match here{}", "é".repeat(150), "x".repeat(150));
+        let (context, offsets) = match_context(&text, "match");
+        assert!(context.starts_with("...") && context.ends_with("..."));
+        let utf16: Vec<u16> = context.encode_utf16().collect();
+        let [s, e] = offsets[0];
+        assert_eq!(String::from_utf16(&utf16[s..e]).unwrap(), "match");
+    }
+
+    #[test]
+    fn scores_and_ranks() {
+        assert!(relevance_score("budget budget", "", "budget") > relevance_score("budget", "", "budget"));
+        assert_eq!(relevance_score("nothing", "Budget", "budget"), 1.0);
+
+        let results = vec![
+            ("2026-01-01".to_string(), result("old-strong", 5.0)),
+            ("2026-02-01".to_string(), result("new-weak", 1.0)),
+        ];
+        let ids = |r: Vec<TranscriptSearchResult>| r.into_iter().map(|r| r.id).collect::<Vec<_>>();
+        assert_eq!(ids(rank_results(results.clone(), SearchRanking::Recency)), ["new-weak", "old-strong"]);
+        assert_eq!(ids(rank_results(results, SearchRanking::Relevance)), ["old-strong", "new-weak"]);
+    }
+
+    #[test]
+    fn paginates() {
+        let results: Vec<_> = (0..5).map(|i| result(&i.to_string(), 0.0)).collect();
+        let page = paginate(results.clone(), 2, 2);
+        assert_eq!((page.total, page.results.len(), page.has_more), (5, 2, true));
+        assert!(!paginate(results, 4, 2).has_more);
+    }
+
+    #[test]
+    fn rejects_invalid_dates() {
+        let filter = MeetingFilter {
+            to: Some("31/01/2026".into()),
+            ..Default::default()
+        };
+        assert!(build_query(&filter, None).is_err());
+    }
+}
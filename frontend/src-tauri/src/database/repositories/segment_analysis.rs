@@ -0,0 +1,250 @@
+use crate::encryption;
+use crate::summary::sentiment::SegmentSentiment;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, Error as SqlxError, QueryBuilder, Sqlite, SqlitePool};
+
+/// A segment with its stored analysis
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AnalyzedSegment {
+    pub transcript_id: String,
+    pub meeting_id: String,
+    pub meeting_title: String,
+    pub text: String,
+    pub speaker: Option<String>,
+    pub audio_start_time: Option<f64>,
+    pub sentiment: String,
+    pub score: f64,
+    pub tone: String,
+    pub method: String,
+}
+
+/// Segment-level search over analysis results. Every field is optional.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SegmentAnalysisFilter {
+    pub meeting_id: Option<String>,
+    /// "positive", "neutral" or "negative"
+    pub sentiment: Option<String>,
+    pub tone: Option<String>,
+    /// Matched against the speaker label or the name it was given
+    pub speaker: Option<String>,
+    /// Only segments scoring at or below this, e.g. -0.5 for strongly negative
+    pub max_score: Option<f64>,
+    pub limit: Option<i64>,
+}
+
+/// Number of a meeting's segments with a given sentiment and tone
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ToneCount {
+    pub sentiment: String,
+    pub tone: String,
+    pub segment_count: i64,
+}
+
+/// A segment to analyze, with its text decrypted
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SegmentText {
+    pub id: String,
+    pub speaker: Option<String>,
+    pub transcript: String,
+}
+
+const DEFAULT_SEGMENT_LIMIT: i64 = 200;
+
+pub struct SegmentAnalysisRepository;
+
+impl SegmentAnalysisRepository {
+    /// A meeting's segments in playback order
+    pub async fn load_segments(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<SegmentText>, SqlxError> {
+        let mut segments = sqlx::query_as::<_, SegmentText>(
+            "SELECT id, speaker, transcript FROM transcripts WHERE meeting_id = ?
+             ORDER BY audio_start_time IS NULL, audio_start_time, rowid",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await?;
+        for segment in &mut segments {
+            segment.transcript = encryption::open(std::mem::take(&mut segment.transcript))?;
+        }
+        Ok(segments)
+    }
+
+    /// Replaces a meeting's analysis with `results` (transcript id, analysis).
+    /// A run that labeled nothing, e.g. because every model batch failed,
+    /// fails and keeps the earlier analysis.
+    pub async fn save_results(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        results: &[(String, SegmentSentiment)],
+        method: &str,
+        model: Option<&str>,
+    ) -> Result<(), SqlxError> {
+        if results.is_empty() {
+            return Err(SqlxError::Protocol(
+                "No segment was labeled; the earlier analysis is kept".to_string(),
+            ));
+        }
+        let now = Utc::now();
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+        sqlx::query("DELETE FROM segment_analysis WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        for (transcript_id, analysis) in results {
+            sqlx::query(
+                "INSERT INTO segment_analysis (transcript_id, meeting_id, sentiment, score, tone, method, model, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(transcript_id)
+            .bind(meeting_id)
+            .bind(analysis.sentiment.as_str())
+            .bind(analysis.score)
+            .bind(&analysis.tone)
+            .bind(method)
+            .bind(model)
+            .bind(now)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await
+    }
+
+    /// Segments matching `filter`, most negative first
+    pub async fn search(
+        pool: &SqlitePool,
+        filter: &SegmentAnalysisFilter,
+    ) -> Result<Vec<AnalyzedSegment>, SqlxError> {
+        let mut builder: QueryBuilder<Sqlite> = QueryBuilder::new(
+            "SELECT a.transcript_id, a.meeting_id, m.title AS meeting_title, t.transcript AS text,
+                    t.speaker, t.audio_start_time, a.sentiment, a.score, a.tone, a.method
+             FROM segment_analysis a
+             JOIN transcripts t ON t.id = a.transcript_id
+             JOIN meetings m ON m.id = a.meeting_id
+             WHERE 1 = 1",
+        );
+        if let Some(meeting_id) = &filter.meeting_id {
+            builder
+                .push(" AND a.meeting_id = ")
+                .push_bind(meeting_id.clone());
+        }
+        if let Some(sentiment) = &filter.sentiment {
+            builder
+                .push(" AND a.sentiment = ")
+                .push_bind(sentiment.trim().to_lowercase());
+        }
+        if let Some(tone) = &filter.tone {
+            builder
+                .push(" AND a.tone = ")
+                .push_bind(tone.trim().to_lowercase());
+        }
+        if let Some(max_score) = filter.max_score {
+            builder.push(" AND a.score <= ").push_bind(max_score);
+        }
+        if let Some(speaker) = filter.speaker.as_deref().filter(|s| !s.trim().is_empty()) {
+            let speaker = speaker.trim().to_lowercase();
+            builder
+                .push(" AND (LOWER(t.speaker) = ")
+                .push_bind(speaker.clone())
+                .push(
+                    " OR EXISTS (SELECT 1 FROM meeting_speakers sp WHERE sp.meeting_id = a.meeting_id
+                       AND sp.speaker_label = t.speaker AND LOWER(sp.display_name) = ",
+                )
+                .push_bind(speaker)
+                .push("))");
+        }
+        builder
+            .push(" ORDER BY a.score ASC, m.created_at DESC, t.audio_start_time ASC LIMIT ")
+            .push_bind(filter.limit.unwrap_or(DEFAULT_SEGMENT_LIMIT).max(1));
+
+        let mut segments = builder
+            .build_query_as::<AnalyzedSegment>()
+            .fetch_all(pool)
+            .await?;
+        for segment in &mut segments {
+            segment.text = encryption::open(std::mem::take(&mut segment.text))?;
+        }
+        Ok(segments)
+    }
+
+    /// Count of segments per (sentiment, tone) for a meeting
+    pub async fn summarize(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<ToneCount>, SqlxError> {
+        sqlx::query_as::<_, ToneCount>(
+            "SELECT sentiment, tone, COUNT(*) AS segment_count FROM segment_analysis
+             WHERE meeting_id = ? GROUP BY sentiment, tone ORDER BY segment_count DESC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::{insert_meeting, insert_transcript, memory_pool};
+    use crate::summary::sentiment::Sentiment;
+
+    fn label(sentiment: Sentiment, score: f64, tone: &str) -> SegmentSentiment {
+        SegmentSentiment {
+            sentiment,
+            score,
+            tone: tone.to_string(),
+        }
+    }
+
+    fn tones(summary: &[ToneCount]) -> Vec<(&str, &str, i64)> {
+        let mut tones: Vec<_> = summary
+            .iter()
+            .map(|t| (t.sentiment.as_str(), t.tone.as_str(), t.segment_count))
+            .collect();
+        tones.sort();
+        tones
+    }
+
+    #[tokio::test]
+    async fn failed_runs_keep_the_earlier_analysis() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        insert_transcript(&pool, "m1", "t1").await;
+        insert_transcript(&pool, "m1", "t2").await;
+
+        let results = [
+            (
+                "t1".to_string(),
+                label(Sentiment::Negative, -0.8, "frustrated"),
+            ),
+            ("t2".to_string(), label(Sentiment::Positive, 0.6, "excited")),
+        ];
+        SegmentAnalysisRepository::save_results(&pool, "m1", &results, "llm", Some("model"))
+            .await
+            .unwrap();
+
+        let failed = SegmentAnalysisRepository::save_results(&pool, "m1", &[], "llm", None).await;
+        assert!(matches!(failed, Err(SqlxError::Protocol(_))));
+        let summary = SegmentAnalysisRepository::summarize(&pool, "m1")
+            .await
+            .unwrap();
+        assert_eq!(
+            tones(&summary),
+            vec![("negative", "frustrated", 1), ("positive", "excited", 1)]
+        );
+
+        // A run that labels anything replaces the earlier one
+        let results = [("t1".to_string(), label(Sentiment::Neutral, 0.0, "calm"))];
+        SegmentAnalysisRepository::save_results(&pool, "m1", &results, "local", None)
+            .await
+            .unwrap();
+        let summary = SegmentAnalysisRepository::summarize(&pool, "m1")
+            .await
+            .unwrap();
+        assert_eq!(tones(&summary), vec![("neutral", "calm", 1)]);
+    }
+}
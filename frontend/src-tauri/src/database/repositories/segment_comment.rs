@@ -0,0 +1,377 @@
+use crate::database::models::{DateTimeUtc, SegmentComment};
+use crate::encryption;
+use crate::locks;
+use chrono::Utc;
+use sqlx::{Error as SqlxError, SqliteConnection, SqlitePool};
+use tracing::info;
+use uuid::Uuid;
+
+const MAX_TEXT_LEN: usize = 4000;
+
+fn validate_text(text: &str) -> Result<String, SqlxError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(SqlxError::Protocol(
+            "Comment text must not be empty".to_string(),
+        ));
+    }
+    if text.chars().count() > MAX_TEXT_LEN {
+        return Err(SqlxError::Protocol(format!(
+            "Comment is longer than {} characters",
+            MAX_TEXT_LEN
+        )));
+    }
+    Ok(text.to_string())
+}
+
+fn open_comment(mut comment: SegmentComment) -> Result<SegmentComment, SqlxError> {
+    comment.text = encryption::open(comment.text)?;
+    Ok(comment)
+}
+
+pub struct SegmentCommentsRepository;
+
+impl SegmentCommentsRepository {
+    /// Adds a comment to a segment. A reply must answer a comment on the same
+    /// segment.
+    pub async fn add(
+        pool: &SqlitePool,
+        transcript_id: &str,
+        parent_id: Option<&str>,
+        author: &str,
+        text: &str,
+    ) -> Result<SegmentComment, SqlxError> {
+        let text = validate_text(text)?;
+        let meeting_id: Option<String> =
+            sqlx::query_scalar("SELECT meeting_id FROM transcripts WHERE id = ?")
+                .bind(transcript_id)
+                .fetch_optional(pool)
+                .await?;
+        let Some(meeting_id) = meeting_id else {
+            return Err(SqlxError::RowNotFound);
+        };
+        locks::ensure_unlocked(&meeting_id)?;
+        if let Some(parent_id) = parent_id {
+            let on_segment = Self::get(pool, parent_id)
+                .await?
+                .is_some_and(|p| p.transcript_id == transcript_id);
+            if !on_segment {
+                return Err(SqlxError::Protocol(format!(
+                    "Comment {} is not on this segment",
+                    parent_id
+                )));
+            }
+        }
+
+        let now = Utc::now();
+        let comment = SegmentComment {
+            id: format!("comment-{}", Uuid::new_v4()),
+            meeting_id,
+            transcript_id: transcript_id.to_string(),
+            parent_id: parent_id.map(|p| p.to_string()),
+            author: author.to_string(),
+            text,
+            created_at: DateTimeUtc(now),
+            updated_at: DateTimeUtc(now),
+        };
+        sqlx::query(
+            "INSERT INTO segment_comments (id, meeting_id, transcript_id, parent_id, author, text, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&comment.id)
+        .bind(&comment.meeting_id)
+        .bind(&comment.transcript_id)
+        .bind(&comment.parent_id)
+        .bind(&comment.author)
+        .bind(encryption::seal(&comment.text)?)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await?;
+        Ok(comment)
+    }
+
+    /// Fails when a task holds the meeting the comment belongs to
+    async fn ensure_unlocked(pool: &SqlitePool, comment_id: &str) -> Result<(), SqlxError> {
+        let meeting_id: Option<String> =
+            sqlx::query_scalar("SELECT meeting_id FROM segment_comments WHERE id = ?")
+                .bind(comment_id)
+                .fetch_optional(pool)
+                .await?;
+        match meeting_id {
+            Some(meeting_id) => Ok(locks::ensure_unlocked(&meeting_id)?),
+            None => Ok(()),
+        }
+    }
+
+    pub async fn get(
+        pool: &SqlitePool,
+        comment_id: &str,
+    ) -> Result<Option<SegmentComment>, SqlxError> {
+        sqlx::query_as::<_, SegmentComment>("SELECT * FROM segment_comments WHERE id = ?")
+            .bind(comment_id)
+            .fetch_optional(pool)
+            .await?
+            .map(open_comment)
+            .transpose()
+    }
+
+    /// Comments of a meeting, oldest first; threads are rebuilt from parent_id
+    pub async fn list_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<SegmentComment>, SqlxError> {
+        sqlx::query_as::<_, SegmentComment>(
+            "SELECT * FROM segment_comments WHERE meeting_id = ? ORDER BY created_at ASC, rowid ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(open_comment)
+        .collect()
+    }
+
+    pub async fn update_text(
+        pool: &SqlitePool,
+        comment_id: &str,
+        text: &str,
+    ) -> Result<Option<SegmentComment>, SqlxError> {
+        let text = validate_text(text)?;
+        Self::ensure_unlocked(pool, comment_id).await?;
+        let result =
+            sqlx::query("UPDATE segment_comments SET text = ?, updated_at = ? WHERE id = ?")
+                .bind(encryption::seal(&text)?)
+                .bind(Utc::now())
+                .bind(comment_id)
+                .execute(pool)
+                .await?;
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+        Self::get(pool, comment_id).await
+    }
+
+    /// Deletes a comment together with all replies below it. Returns the
+    /// number of comments removed.
+    pub async fn delete(pool: &SqlitePool, comment_id: &str) -> Result<u64, SqlxError> {
+        Self::ensure_unlocked(pool, comment_id).await?;
+        let result = sqlx::query(
+            "WITH RECURSIVE thread(id) AS (
+                 SELECT id FROM segment_comments WHERE id = ?
+                 UNION SELECT c.id FROM segment_comments c JOIN thread t ON c.parent_id = t.id
+             )
+             DELETE FROM segment_comments WHERE id IN (SELECT id FROM thread)",
+        )
+        .bind(comment_id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Stores comments received from an import or a sharing sync. Existing
+    /// ids are kept as they are, and comments on segments that don't exist
+    /// in `meeting_id`, or replies to a comment on another segment, are
+    /// skipped. Returns the number of comments added.
+    pub async fn insert_synced(
+        conn: &mut SqliteConnection,
+        meeting_id: &str,
+        comments: &[SegmentComment],
+    ) -> Result<u64, SqlxError> {
+        // Parents before their replies
+        let mut ordered: Vec<&SegmentComment> = comments.iter().collect();
+        ordered.sort_by_key(|comment| comment.created_at.0);
+
+        let mut added = 0;
+        for comment in ordered {
+            if validate_text(&comment.text).is_err() {
+                continue;
+            }
+            let result = sqlx::query(
+                "INSERT OR IGNORE INTO segment_comments (id, meeting_id, transcript_id, parent_id, author, text, created_at, updated_at)
+                 SELECT ?, t.meeting_id, t.id, ?, ?, ?, ?, ? FROM transcripts t
+                 WHERE t.id = ? AND t.meeting_id = ?
+                   AND (? IS NULL OR EXISTS (
+                       SELECT 1 FROM segment_comments p
+                       WHERE p.id = ? AND p.meeting_id = t.meeting_id AND p.transcript_id = t.id
+                   ))",
+            )
+            .bind(&comment.id)
+            .bind(&comment.parent_id)
+            .bind(&comment.author)
+            .bind(encryption::seal(&comment.text)?)
+            .bind(comment.created_at.0)
+            .bind(comment.updated_at.0)
+            .bind(&comment.transcript_id)
+            .bind(meeting_id)
+            .bind(&comment.parent_id)
+            .bind(&comment.parent_id)
+            .execute(&mut *conn)
+            .await?;
+            added += result.rows_affected();
+        }
+        if added > 0 {
+            info!(
+                "Stored {} synced comments for meeting {}",
+                added, meeting_id
+            );
+        }
+        Ok(added)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::{insert_meeting, insert_transcript, memory_pool};
+
+    fn synced(
+        id: &str,
+        transcript_id: &str,
+        parent_id: Option<&str>,
+        minute: u32,
+    ) -> SegmentComment {
+        let at = DateTimeUtc(
+            chrono::NaiveDate::from_ymd_opt(2026, 1, 1)
+                .unwrap()
+                .and_hms_opt(10, minute, 0)
+                .unwrap()
+                .and_utc(),
+        );
+        SegmentComment {
+            id: id.to_string(),
+            meeting_id: "remote".to_string(),
+            transcript_id: transcript_id.to_string(),
+            parent_id: parent_id.map(str::to_string),
+            author: "Jane".to_string(),
+            text: format!("comment {}", id),
+            created_at: at.clone(),
+            updated_at: at,
+        }
+    }
+
+    #[tokio::test]
+    async fn replies_stay_on_their_segment_and_delete_with_the_thread() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        insert_transcript(&pool, "m1", "t1").await;
+        insert_transcript(&pool, "m1", "t2").await;
+
+        let root = SegmentCommentsRepository::add(&pool, "t1", None, "Jane", " Check this ")
+            .await
+            .unwrap();
+        assert_eq!(root.text, "Check this");
+        assert_eq!(root.meeting_id, "m1");
+        let reply = SegmentCommentsRepository::add(&pool, "t1", Some(&root.id), "Bob", "Done")
+            .await
+            .unwrap();
+        assert!(
+            SegmentCommentsRepository::add(&pool, "t2", Some(&root.id), "Bob", "Wrong")
+                .await
+                .is_err()
+        );
+        assert!(
+            SegmentCommentsRepository::add(&pool, "t1", None, "Bob", "  ")
+                .await
+                .is_err()
+        );
+        assert!(matches!(
+            SegmentCommentsRepository::add(&pool, "missing", None, "Bob", "x").await,
+            Err(SqlxError::RowNotFound)
+        ));
+
+        let edited = SegmentCommentsRepository::update_text(&pool, &reply.id, "Fixed")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(edited.text, "Fixed");
+
+        assert_eq!(
+            SegmentCommentsRepository::delete(&pool, &root.id)
+                .await
+                .unwrap(),
+            2
+        );
+        assert!(SegmentCommentsRepository::list_for_meeting(&pool, "m1")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn synced_comments_must_belong_to_the_meeting() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        insert_meeting(&pool, "m2").await;
+        insert_transcript(&pool, "m1", "t1").await;
+        insert_transcript(&pool, "m1", "t2").await;
+        insert_transcript(&pool, "m2", "other").await;
+        let foreign = SegmentCommentsRepository::add(&pool, "other", None, "Jane", "Elsewhere")
+            .await
+            .unwrap();
+
+        let comments = vec![
+            // A reply listed before its parent is still stored
+            synced("c2", "t1", Some("c1"), 5),
+            synced("c1", "t1", None, 1),
+            synced("c3", "t2", Some("c1"), 6),
+            synced("c4", "t1", Some(&foreign.id), 7),
+            synced("c5", "other", None, 8),
+            synced("c6", "missing", None, 9),
+        ];
+        let mut conn = pool.acquire().await.unwrap();
+        let added = SegmentCommentsRepository::insert_synced(&mut conn, "m1", &comments)
+            .await
+            .unwrap();
+        assert_eq!(added, 2);
+        // Syncing again adds nothing
+        let added = SegmentCommentsRepository::insert_synced(&mut conn, "m1", &comments)
+            .await
+            .unwrap();
+        assert_eq!(added, 0);
+        drop(conn);
+
+        let stored = SegmentCommentsRepository::list_for_meeting(&pool, "m1")
+            .await
+            .unwrap();
+        let ids: Vec<&str> = stored.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, ["c1", "c2"]);
+        assert!(stored.iter().all(|c| c.meeting_id == "m1"));
+    }
+
+    #[tokio::test]
+    async fn comments_wait_for_tasks_holding_the_meeting() {
+        use crate::locks::{MeetingActivity, MeetingLocks};
+
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "locked-meeting").await;
+        insert_transcript(&pool, "locked-meeting", "t1").await;
+        let comment = SegmentCommentsRepository::add(&pool, "t1", None, "Jane", "Before")
+            .await
+            .unwrap();
+
+        let lock =
+            MeetingLocks::global().acquire("locked-meeting", MeetingActivity::Retranscription);
+        assert!(
+            SegmentCommentsRepository::add(&pool, "t1", None, "Jane", "During")
+                .await
+                .is_err()
+        );
+        assert!(
+            SegmentCommentsRepository::update_text(&pool, &comment.id, "During")
+                .await
+                .is_err()
+        );
+        assert!(SegmentCommentsRepository::delete(&pool, &comment.id)
+            .await
+            .is_err());
+        drop(lock);
+
+        assert_eq!(
+            SegmentCommentsRepository::delete(&pool, &comment.id)
+                .await
+                .unwrap(),
+            1
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::database::models::{Setting, TranscriptSetting};
+use crate::integrations::email::SmtpConfig;
 use crate::summary::CustomOpenAIConfig;
 use sqlx::SqlitePool;
 
@@ -24,7 +25,7 @@ pub struct SaveTranscriptConfigRequest {
 
 pub struct SettingsRepository;
 
-// Transcript providers: localWhisper, deepgram, elevenLabs, groq, openai
+// Transcript providers: localWhisper, deepgram, elevenLabs, assemblyAi, groq, openai
 // Summary providers: openai, claude, ollama, groq, added openrouter
 // NOTE: Handle data exclusion in the higher layer as this is database abstraction layer(using SELECT *)
 
@@ -172,6 +173,25 @@ impl SettingsRepository {
         Ok(())
     }
 
+    pub async fn save_transcript_language_routes(
+        pool: &SqlitePool,
+        routes_json: &str,
+    ) -> std::result::Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO transcript_settings (id, provider, model, languageRoutes)
+            VALUES ('1', 'parakeet', 'parakeet-tdt-0.6b-v3-int8', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                languageRoutes = excluded.languageRoutes
+            "#,
+        )
+        .bind(routes_json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn save_transcript_api_key(
         pool: &SqlitePool,
         provider: &str,
@@ -182,6 +202,7 @@ impl SettingsRepository {
             "parakeet" => return Ok(()), // Parakeet doesn't need an API key, return early
             "deepgram" => "deepgramApiKey",
             "elevenLabs" => "elevenLabsApiKey",
+            "assemblyAi" => "assemblyAiApiKey",
             "groq" => "groqApiKey",
             "openai" => "openaiApiKey",
             _ => {
@@ -214,6 +235,7 @@ impl SettingsRepository {
             "parakeet" => return Ok(None), // Parakeet doesn't need an API key
             "deepgram" => "deepgramApiKey",
             "elevenLabs" => "elevenLabsApiKey",
+            "assemblyAi" => "assemblyAiApiKey",
             "groq" => "groqApiKey",
             "openai" => "openaiApiKey",
             _ => {
@@ -345,4 +367,139 @@ impl SettingsRepository {
 
         Ok(())
     }
+
+    // ===== SMTP CONFIG METHODS =====
+
+    /// Gets the SMTP configuration (without the password)
+    pub async fn get_smtp_config(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<SmtpConfig>, sqlx::Error> {
+        let config_json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT smtpConfig FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        match config_json.flatten() {
+            Some(json) => serde_json::from_str(&json).map(Some).map_err(|e| {
+                sqlx::Error::Protocol(format!("Invalid JSON in smtpConfig: {}", e).into())
+            }),
+            None => Ok(None),
+        }
+    }
+
+    /// Saves the SMTP configuration. `password` is only written when provided,
+    /// so the settings form can be re-saved without re-entering it.
+    pub async fn save_smtp_config(
+        pool: &SqlitePool,
+        config: &SmtpConfig,
+        password: Option<&str>,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let config_json = serde_json::to_string(config)
+            .map_err(|e| sqlx::Error::Protocol(
+                format!("Failed to serialize config to JSON: {}", e).into()
+            ))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, smtpConfig, smtpPassword)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1, $2)
+            ON CONFLICT(id) DO UPDATE SET
+                smtpConfig = excluded.smtpConfig,
+                smtpPassword = COALESCE(excluded.smtpPassword, smtpPassword)
+            "#,
+        )
+        .bind(config_json)
+        .bind(password)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_smtp_password(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<String>, sqlx::Error> {
+        let password: Option<Option<String>> =
+            sqlx::query_scalar("SELECT smtpPassword FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+        Ok(password.flatten())
+    }
+
+    pub async fn delete_smtp_config(pool: &SqlitePool) -> std::result::Result<(), sqlx::Error> {
+        sqlx::query("UPDATE settings SET smtpConfig = NULL, smtpPassword = NULL WHERE id = '1'")
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    // ===== SUMMARY LANGUAGE METHODS =====
+
+    /// Gets the language summaries are written in (None: the transcript's)
+    pub async fn get_summary_language(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<String>, sqlx::Error> {
+        let language: Option<Option<String>> =
+            sqlx::query_scalar("SELECT summaryLanguage FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+        Ok(language.flatten())
+    }
+
+    pub async fn save_summary_language(
+        pool: &SqlitePool,
+        language: Option<&str>,
+    ) -> std::result::Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, summaryLanguage)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                summaryLanguage = excluded.summaryLanguage
+            "#,
+        )
+        .bind(language)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // ===== OVERLAY POSITION METHODS =====
+
+    /// Where the recorder overlay was last placed (physical pixels)
+    pub async fn get_overlay_position(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<(i32, i32)>, sqlx::Error> {
+        let position: Option<(Option<i32>, Option<i32>)> =
+            sqlx::query_as("SELECT overlayX, overlayY FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+        Ok(match position {
+            Some((Some(x), Some(y))) => Some((x, y)),
+            _ => None,
+        })
+    }
+
+    pub async fn save_overlay_position(
+        pool: &SqlitePool,
+        x: i32,
+        y: i32,
+    ) -> std::result::Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, overlayX, overlayY)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1, $2)
+            ON CONFLICT(id) DO UPDATE SET
+                overlayX = excluded.overlayX,
+                overlayY = excluded.overlayY
+            "#,
+        )
+        .bind(x)
+        .bind(y)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
 }
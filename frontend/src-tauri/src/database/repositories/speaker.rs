@@ -0,0 +1,351 @@
+use crate::audio::speaker_embedding::{
+    best_match, embedding_from_bytes, embedding_to_bytes, merge_embedding,
+};
+use crate::database::models::{MeetingSpeaker, VoiceProfile};
+use crate::database::repositories::minutes_status::MinutesStatusRepository;
+use crate::locks;
+use chrono::Utc;
+use sqlx::{Connection, Error as SqlxError, SqlitePool};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+pub struct SpeakersRepository;
+
+impl SpeakersRepository {
+    pub async fn list_meeting_speakers(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<MeetingSpeaker>, SqlxError> {
+        sqlx::query_as::<_, MeetingSpeaker>(
+            "SELECT * FROM meeting_speakers WHERE meeting_id = ? ORDER BY speaker_label ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Stores the embedding diarization produced for a speaker in a meeting.
+    /// Called by the diarization pipeline; keeps any name already assigned.
+    pub async fn record_speaker_embedding(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        speaker_label: &str,
+        embedding: &[f32],
+    ) -> Result<(), SqlxError> {
+        if meeting_id.trim().is_empty() {
+            return Err(SqlxError::Protocol(
+                "meeting_id cannot be empty".to_string(),
+            ));
+        }
+
+        sqlx::query(
+            "INSERT INTO meeting_speakers (meeting_id, speaker_label, embedding, updated_at)
+             VALUES (?, ?, ?, ?)
+             ON CONFLICT(meeting_id, speaker_label) DO UPDATE SET
+                embedding = excluded.embedding,
+                updated_at = excluded.updated_at",
+        )
+        .bind(meeting_id)
+        .bind(speaker_label)
+        .bind(embedding_to_bytes(embedding))
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Assigns a display name to a speaker label in one meeting.
+    ///
+    /// When `remember_voice` is set and an embedding exists for the speaker, the
+    /// embedding is folded into the voice profile with the same name (created if
+    /// needed) so future meetings can be auto-labelled. Returns the profile id
+    /// when one was saved.
+    pub async fn rename_speaker(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        speaker_label: &str,
+        name: &str,
+        remember_voice: bool,
+    ) -> Result<Option<String>, SqlxError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(SqlxError::Protocol("speaker name cannot be empty".to_string()));
+        }
+        locks::ensure_unlocked(meeting_id)?;
+
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+        if let Err(e) =
+            MinutesStatusRepository::ensure_editable(&mut *transaction, meeting_id).await
+        {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+        let now = Utc::now();
+
+        sqlx::query(
+            "INSERT INTO meeting_speakers (meeting_id, speaker_label, display_name, updated_at)
+             VALUES (?, ?, ?, ?)
+             ON CONFLICT(meeting_id, speaker_label) DO UPDATE SET
+                display_name = excluded.display_name,
+                updated_at = excluded.updated_at",
+        )
+        .bind(meeting_id)
+        .bind(speaker_label)
+        .bind(name)
+        .bind(now)
+        .execute(&mut *transaction)
+        .await?;
+
+        // Keep the participant list in sync with the speaker mapping
+        sqlx::query(
+            "UPDATE meeting_participants SET speaker_label = ?, updated_at = ?
+             WHERE meeting_id = ? AND LOWER(name) = LOWER(?)",
+        )
+        .bind(speaker_label)
+        .bind(now)
+        .bind(meeting_id)
+        .bind(name)
+        .execute(&mut *transaction)
+        .await?;
+
+        let mut profile_id = None;
+        if remember_voice {
+            let embedding: Option<(Option<Vec<u8>>,)> = sqlx::query_as(
+                "SELECT embedding FROM meeting_speakers WHERE meeting_id = ? AND speaker_label = ?",
+            )
+            .bind(meeting_id)
+            .bind(speaker_label)
+            .fetch_optional(&mut *transaction)
+            .await?;
+
+            match embedding
+                .and_then(|(e,)| e)
+                .and_then(|bytes| embedding_from_bytes(&bytes))
+            {
+                Some(sample) if !sample.is_empty() => {
+                    let existing = sqlx::query_as::<_, VoiceProfile>(
+                        "SELECT * FROM voice_profiles WHERE LOWER(name) = LOWER(?) LIMIT 1",
+                    )
+                    .bind(name)
+                    .fetch_optional(&mut *transaction)
+                    .await?;
+
+                    let id = match existing {
+                        Some(profile) => {
+                            let current =
+                                embedding_from_bytes(&profile.embedding).unwrap_or_default();
+                            let merged = merge_embedding(&current, profile.sample_count, &sample);
+                            sqlx::query(
+                                "UPDATE voice_profiles
+                                 SET embedding = ?, sample_count = sample_count + 1, updated_at = ?
+                                 WHERE id = ?",
+                            )
+                            .bind(embedding_to_bytes(&merged))
+                            .bind(now)
+                            .bind(&profile.id)
+                            .execute(&mut *transaction)
+                            .await?;
+                            profile.id
+                        }
+                        None => {
+                            let id = format!("voice-{}", Uuid::new_v4());
+                            sqlx::query(
+                                "INSERT INTO voice_profiles (id, name, embedding, sample_count, created_at, updated_at)
+                                 VALUES (?, ?, ?, 1, ?, ?)",
+                            )
+                            .bind(&id)
+                            .bind(name)
+                            .bind(embedding_to_bytes(&sample))
+                            .bind(now)
+                            .bind(now)
+                            .execute(&mut *transaction)
+                            .await?;
+                            id
+                        }
+                    };
+
+                    sqlx::query(
+                        "UPDATE meeting_speakers SET profile_id = ? WHERE meeting_id = ? AND speaker_label = ?",
+                    )
+                    .bind(&id)
+                    .bind(meeting_id)
+                    .bind(speaker_label)
+                    .execute(&mut *transaction)
+                    .await?;
+                    profile_id = Some(id);
+                }
+                _ => {
+                    warn!(
+                        "No voice embedding for {} in meeting {}; name saved without a voice profile",
+                        speaker_label, meeting_id
+                    );
+                }
+            }
+        }
+
+        transaction.commit().await?;
+        info!(
+            "Renamed {} to '{}' in meeting {} (profile: {:?})",
+            speaker_label, name, meeting_id, profile_id
+        );
+        Ok(profile_id)
+    }
+
+    /// Labels unnamed speakers in a meeting by matching their embeddings against
+    /// saved voice profiles. Returns the number of speakers labelled.
+    pub async fn apply_voice_profiles(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        threshold: f32,
+    ) -> Result<usize, SqlxError> {
+        let profiles = Self::list_voice_profiles(pool).await?;
+        if profiles.is_empty() {
+            return Ok(0);
+        }
+        let profile_embeddings: Vec<Vec<f32>> = profiles
+            .iter()
+            .map(|p| embedding_from_bytes(&p.embedding).unwrap_or_default())
+            .collect();
+
+        let speakers = sqlx::query_as::<_, MeetingSpeaker>(
+            "SELECT * FROM meeting_speakers
+             WHERE meeting_id = ? AND display_name IS NULL AND embedding IS NOT NULL",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await?;
+
+        let mut labelled = 0;
+        for speaker in speakers {
+            let Some(embedding) = speaker.embedding.as_deref().and_then(embedding_from_bytes) else {
+                continue;
+            };
+            let candidates = profile_embeddings.iter().map(|e| e.as_slice());
+            if let Some((index, score)) = best_match(&embedding, candidates, threshold) {
+                let profile = &profiles[index];
+                sqlx::query(
+                    "UPDATE meeting_speakers SET display_name = ?, profile_id = ?, updated_at = ?
+                     WHERE meeting_id = ? AND speaker_label = ?",
+                )
+                .bind(&profile.name)
+                .bind(&profile.id)
+                .bind(Utc::now())
+                .bind(meeting_id)
+                .bind(&speaker.speaker_label)
+                .execute(pool)
+                .await?;
+                info!(
+                    "Auto-labelled {} as '{}' in meeting {} (similarity {:.2})",
+                    speaker.speaker_label, profile.name, meeting_id, score
+                );
+                labelled += 1;
+            }
+        }
+        Ok(labelled)
+    }
+
+    pub async fn list_voice_profiles(pool: &SqlitePool) -> Result<Vec<VoiceProfile>, SqlxError> {
+        sqlx::query_as::<_, VoiceProfile>("SELECT * FROM voice_profiles ORDER BY name ASC")
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Deletes a voice profile. Meeting speaker names are kept; only the link is cleared.
+    pub async fn delete_voice_profile(
+        pool: &SqlitePool,
+        profile_id: &str,
+    ) -> Result<bool, SqlxError> {
+        let mut transaction = pool.begin().await?;
+        sqlx::query("UPDATE meeting_speakers SET profile_id = NULL WHERE profile_id = ?")
+            .bind(profile_id)
+            .execute(&mut *transaction)
+            .await?;
+        let result = sqlx::query("DELETE FROM voice_profiles WHERE id = ?")
+            .bind(profile_id)
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::{insert_meeting, memory_pool};
+
+    #[tokio::test]
+    async fn saved_voices_name_speakers_in_later_meetings() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        insert_meeting(&pool, "m2").await;
+
+        SpeakersRepository::record_speaker_embedding(&pool, "m1", "Speaker 1", &[1.0, 0.0, 0.2])
+            .await
+            .unwrap();
+        let profile = SpeakersRepository::rename_speaker(&pool, "m1", "Speaker 1", "Alice", true)
+            .await
+            .unwrap();
+        assert!(profile.is_some());
+
+        SpeakersRepository::record_speaker_embedding(&pool, "m2", "Speaker 1", &[0.0, 1.0, 0.0])
+            .await
+            .unwrap();
+        SpeakersRepository::record_speaker_embedding(&pool, "m2", "Speaker 2", &[0.9, 0.1, 0.2])
+            .await
+            .unwrap();
+        let labelled = SpeakersRepository::apply_voice_profiles(&pool, "m2", 0.75)
+            .await
+            .unwrap();
+        assert_eq!(labelled, 1);
+
+        let speakers = SpeakersRepository::list_meeting_speakers(&pool, "m2")
+            .await
+            .unwrap();
+        let names: Vec<(&str, Option<&str>)> = speakers
+            .iter()
+            .map(|s| (s.speaker_label.as_str(), s.display_name.as_deref()))
+            .collect();
+        assert_eq!(names, [("Speaker 1", None), ("Speaker 2", Some("Alice"))]);
+        assert_eq!(speakers[1].profile_id, profile);
+
+        // A new embedding keeps the name already given
+        SpeakersRepository::record_speaker_embedding(&pool, "m2", "Speaker 2", &[1.0, 0.0, 0.0])
+            .await
+            .unwrap();
+        let speakers = SpeakersRepository::list_meeting_speakers(&pool, "m2")
+            .await
+            .unwrap();
+        assert_eq!(speakers[1].display_name.as_deref(), Some("Alice"));
+    }
+
+    #[tokio::test]
+    async fn approved_minutes_keep_their_speaker_names() {
+        use crate::database::repositories::minutes_status::MinutesState;
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        for next in [MinutesState::UnderReview, MinutesState::Approved] {
+            MinutesStatusRepository::transition(&pool, "m1", next, "reviewer")
+                .await
+                .unwrap();
+        }
+
+        assert!(
+            SpeakersRepository::rename_speaker(&pool, "m1", "Speaker 1", "Alice", false)
+                .await
+                .is_err()
+        );
+        assert!(SpeakersRepository::list_meeting_speakers(&pool, "m1")
+            .await
+            .unwrap()
+            .is_empty());
+
+        MinutesStatusRepository::transition(&pool, "m1", MinutesState::Draft, "reviewer")
+            .await
+            .unwrap();
+        SpeakersRepository::rename_speaker(&pool, "m1", "Speaker 1", "Alice", false)
+            .await
+            .unwrap();
+    }
+}
@@ -1,4 +1,9 @@
 use crate::database::models::SummaryProcess;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::minutes_status::MinutesStatusRepository;
+use crate::encryption;
+use crate::locks;
+use crate::summary::schema;
 use chrono::Utc;
 use serde_json::Value;
 use sqlx::SqlitePool;
@@ -6,6 +11,14 @@ use tracing::{error, info as log_info};
 
 pub struct SummaryProcessesRepository;
 
+/// Decrypts the results, upgrading ones saved in an older summary schema
+fn open_process(mut process: SummaryProcess) -> Result<SummaryProcess, sqlx::Error> {
+    let upgrade = |result: String| schema::upgrade_stored(&result).unwrap_or(result);
+    process.result = encryption::open_opt(process.result)?.map(upgrade);
+    process.result_backup = encryption::open_opt(process.result_backup)?.map(upgrade);
+    Ok(process)
+}
+
 impl SummaryProcessesRepository {
     /// Retrieves the current summary process state for a given meeting ID.
     pub async fn get_summary_data(
@@ -15,14 +28,22 @@ impl SummaryProcessesRepository {
         sqlx::query_as::<_, SummaryProcess>("SELECT * FROM summary_processes WHERE meeting_id = ?")
             .bind(meeting_id)
             .fetch_optional(pool)
-            .await
+            .await?
+            .map(open_process)
+            .transpose()
     }
 
+    /// Saves an edited summary, failing with a version conflict if the
+    /// meeting changed since `expected_version`. Returns the meeting's new
+    /// version, or None if the meeting is missing or the summary can't be
+    /// serialized.
     pub async fn update_meeting_summary(
         pool: &SqlitePool,
         meeting_id: &str,
         summary: &Value,
-    ) -> Result<bool, sqlx::Error> {
+        expected_version: Option<i64>,
+    ) -> Result<Option<i64>, sqlx::Error> {
+        locks::ensure_unlocked(meeting_id)?;
         let mut transaction = pool.begin().await?;
 
         let meeting_exists: bool = sqlx::query("SELECT 1 FROM meetings WHERE id = ?")
@@ -37,19 +58,30 @@ impl SummaryProcessesRepository {
                 meeting_id
             );
             transaction.rollback().await?;
-            return Ok(false);
+            return Ok(None);
+        }
+        if let Err(e) = MinutesStatusRepository::ensure_editable(&mut *transaction, meeting_id).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+        if let Err(e) =
+            MeetingsRepository::ensure_version(&mut *transaction, meeting_id, expected_version).await
+        {
+            transaction.rollback().await?;
+            return Err(e);
         }
 
         let result_json = serde_json::to_string(summary);
         if result_json.is_err() {
             error!("Can't convert the json to string for saving to Database");
             transaction.rollback().await?;
-            return Ok(false);
+            return Ok(None);
         }
         let now = Utc::now();
+        let result = encryption::seal(&result_json.unwrap())?;
 
         sqlx::query("UPDATE summary_processes SET result = ?, updated_at = ? WHERE meeting_id = ?")
-            .bind(&result_json.unwrap())
+            .bind(&result)
             .bind(now)
             .bind(meeting_id)
             .execute(&mut *transaction)
@@ -61,13 +93,46 @@ impl SummaryProcessesRepository {
             .execute(&mut *transaction)
             .await?;
 
+        let version = MeetingsRepository::get_version(&mut *transaction, meeting_id).await?;
         transaction.commit().await?;
 
         log_info!(
             "Successfully updated summary and timestamp for meeting_id: {}",
             meeting_id
         );
-        Ok(true)
+        Ok(version)
+    }
+
+    /// Rewrites results and backups saved in an older summary schema.
+    /// Returns how many summaries changed.
+    pub async fn upgrade_summary_schema(pool: &SqlitePool) -> Result<usize, sqlx::Error> {
+        let rows: Vec<(String, Option<String>, Option<String>)> = sqlx::query_as(
+            "SELECT meeting_id, result, result_backup FROM summary_processes
+             WHERE result IS NOT NULL OR result_backup IS NOT NULL",
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut upgraded = 0;
+        for (meeting_id, result, backup) in rows {
+            let result = encryption::open_opt(result)?;
+            let backup = encryption::open_opt(backup)?;
+            let new_result = result.as_deref().and_then(schema::upgrade_stored);
+            let new_backup = backup.as_deref().and_then(schema::upgrade_stored);
+            if new_result.is_none() && new_backup.is_none() {
+                continue;
+            }
+            sqlx::query(
+                "UPDATE summary_processes SET result = ?, result_backup = ? WHERE meeting_id = ?",
+            )
+            .bind(encryption::seal_opt(new_result.or(result).as_deref())?)
+            .bind(encryption::seal_opt(new_backup.or(backup).as_deref())?)
+            .bind(&meeting_id)
+            .execute(pool)
+            .await?;
+            upgraded += 1;
+        }
+        Ok(upgraded)
     }
 
     pub async fn get_summary_data_for_meeting(
@@ -79,7 +144,9 @@ impl SummaryProcessesRepository {
         )
         .bind(meeting_id)
         .fetch_optional(pool)
-        .await
+        .await?
+        .map(open_process)
+        .transpose()
     }
 
     pub async fn create_or_reset_process(
@@ -118,6 +185,8 @@ impl SummaryProcessesRepository {
         Ok(())
     }
 
+    /// Stores a generated summary. Fails, leaving the process as it was, when
+    /// the minutes were approved in the meantime.
     pub async fn update_process_completed(
         pool: &SqlitePool,
         meeting_id: &str,
@@ -128,7 +197,13 @@ impl SummaryProcessesRepository {
         let now = Utc::now();
         let result_str = serde_json::to_string(&result)
             .map_err(|e| sqlx::Error::Protocol(format!("Failed to serialize result: {}", e)))?;
+        let result_str = encryption::seal(&result_str)?;
 
+        let mut transaction = pool.begin().await?;
+        if let Err(e) = MinutesStatusRepository::ensure_editable(&mut *transaction, meeting_id).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
         sqlx::query(
             r#"
             UPDATE summary_processes
@@ -142,8 +217,9 @@ impl SummaryProcessesRepository {
         .bind(chunk_count)
         .bind(processing_time)
         .bind(meeting_id)
-        .execute(pool)
+        .execute(&mut *transaction)
         .await?;
+        transaction.commit().await?;
         log_info!(
             "Summary completed and backup cleared for meeting_id: {}",
             meeting_id
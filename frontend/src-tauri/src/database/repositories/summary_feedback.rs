@@ -0,0 +1,186 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Error as SqlxError, SqlitePool};
+use uuid::Uuid;
+
+/// A user's rating of a generated summary
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct SummaryFeedback {
+    pub id: String,
+    pub meeting_id: String,
+    pub series_key: String,
+    pub helpful: bool,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+pub struct SummaryFeedbackRepository;
+
+impl SummaryFeedbackRepository {
+    pub async fn add_feedback(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        series_key: &str,
+        helpful: bool,
+        comment: Option<String>,
+    ) -> Result<SummaryFeedback, SqlxError> {
+        let feedback = SummaryFeedback {
+            id: format!("feedback-{}", Uuid::new_v4()),
+            meeting_id: meeting_id.to_string(),
+            series_key: series_key.to_string(),
+            helpful,
+            comment,
+            created_at: Utc::now(),
+        };
+        sqlx::query(
+            "INSERT INTO summary_feedback (id, meeting_id, series_key, helpful, comment, created_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&feedback.id)
+        .bind(&feedback.meeting_id)
+        .bind(&feedback.series_key)
+        .bind(feedback.helpful)
+        .bind(&feedback.comment)
+        .bind(feedback.created_at)
+        .execute(pool)
+        .await?;
+        Ok(feedback)
+    }
+
+    pub async fn list_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<SummaryFeedback>, SqlxError> {
+        sqlx::query_as::<_, SummaryFeedback>(
+            "SELECT id, meeting_id, series_key, helpful, comment, created_at
+             FROM summary_feedback WHERE meeting_id = ? ORDER BY created_at DESC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// The newest feedback on meetings of a series, including `meeting_id`
+    /// itself (feedback on a summary that is being regenerated counts most)
+    pub async fn recent_for_series(
+        pool: &SqlitePool,
+        series_key: &str,
+        meeting_id: &str,
+        limit: i64,
+    ) -> Result<Vec<SummaryFeedback>, SqlxError> {
+        sqlx::query_as::<_, SummaryFeedback>(
+            "SELECT id, meeting_id, series_key, helpful, comment, created_at
+             FROM summary_feedback WHERE series_key = ? OR meeting_id = ?
+             ORDER BY created_at DESC LIMIT ?",
+        )
+        .bind(series_key)
+        .bind(meeting_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Returns false when the feedback does not exist
+    pub async fn delete_feedback(pool: &SqlitePool, id: &str) -> Result<bool, SqlxError> {
+        let result = sqlx::query("DELETE FROM summary_feedback WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::journal::JournalRepository;
+    use crate::database::test_support::{insert_meeting, memory_pool};
+
+    #[tokio::test]
+    async fn series_feedback_includes_the_meeting_itself() {
+        let pool = memory_pool().await;
+        for id in ["m1", "m2", "m3"] {
+            insert_meeting(&pool, id).await;
+        }
+        let first = SummaryFeedbackRepository::add_feedback(&pool, "m1", "weekly sync", true, None)
+            .await
+            .unwrap();
+        SummaryFeedbackRepository::add_feedback(
+            &pool,
+            "m2",
+            "m2",
+            false,
+            Some("Too long".to_string()),
+        )
+        .await
+        .unwrap();
+        SummaryFeedbackRepository::add_feedback(&pool, "m3", "standup", false, None)
+            .await
+            .unwrap();
+
+        let series = SummaryFeedbackRepository::recent_for_series(&pool, "weekly sync", "m2", 10)
+            .await
+            .unwrap();
+        let mut meetings: Vec<&str> = series.iter().map(|f| f.meeting_id.as_str()).collect();
+        meetings.sort();
+        assert_eq!(meetings, vec!["m1", "m2"]);
+
+        assert!(SummaryFeedbackRepository::delete_feedback(&pool, &first.id)
+            .await
+            .unwrap());
+        assert!(
+            !SummaryFeedbackRepository::delete_feedback(&pool, &first.id)
+                .await
+                .unwrap()
+        );
+        assert!(SummaryFeedbackRepository::list_for_meeting(&pool, "m1")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn undoing_a_meeting_deletion_restores_its_feedback() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        SummaryFeedbackRepository::add_feedback(
+            &pool,
+            "m1",
+            "weekly sync",
+            false,
+            Some("List decisions first".to_string()),
+        )
+        .await
+        .unwrap();
+
+        let mut transaction = pool.begin().await.unwrap();
+        let images = JournalRepository::capture_meeting(&mut transaction, "m1")
+            .await
+            .unwrap();
+        JournalRepository::record(
+            &mut transaction,
+            "delete_meeting",
+            Some("m1"),
+            "Delete",
+            &images,
+        )
+        .await
+        .unwrap();
+        sqlx::query("DELETE FROM meetings WHERE id = 'm1'")
+            .execute(&mut *transaction)
+            .await
+            .unwrap();
+        transaction.commit().await.unwrap();
+        assert!(SummaryFeedbackRepository::list_for_meeting(&pool, "m1")
+            .await
+            .unwrap()
+            .is_empty());
+
+        JournalRepository::undo_last(&pool).await.unwrap().unwrap();
+        let restored = SummaryFeedbackRepository::list_for_meeting(&pool, "m1")
+            .await
+            .unwrap();
+        assert_eq!(restored.len(), 1);
+        assert_eq!(restored[0].comment.as_deref(), Some("List decisions first"));
+    }
+}
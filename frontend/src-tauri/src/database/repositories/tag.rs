@@ -0,0 +1,146 @@
+use crate::locks;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, Error as SqlxError, SqlitePool};
+
+pub const MAX_TAG_LEN: usize = 64;
+
+/// A tag and how many meetings carry it
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TagCount {
+    pub tag: String,
+    pub meeting_count: i64,
+}
+
+/// Trims tags, collapses inner whitespace and drops empty or duplicate
+/// (case-insensitive) entries, keeping the first spelling
+pub fn normalize_tags(tags: &[String]) -> Result<Vec<String>, String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.split_whitespace().collect::<Vec<_>>().join(" ");
+        if tag.is_empty() {
+            continue;
+        }
+        if tag.chars().count() > MAX_TAG_LEN {
+            return Err(format!("Tag '{}' is longer than {} characters", tag, MAX_TAG_LEN));
+        }
+        if !normalized.iter().any(|t| t.eq_ignore_ascii_case(&tag)) {
+            normalized.push(tag);
+        }
+    }
+    Ok(normalized)
+}
+
+pub struct TagsRepository;
+
+impl TagsRepository {
+    pub async fn get_tags(pool: &SqlitePool, meeting_id: &str) -> Result<Vec<String>, SqlxError> {
+        sqlx::query_scalar("SELECT tag FROM meeting_tags WHERE meeting_id = ? ORDER BY tag ASC")
+            .bind(meeting_id)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Every tag in use, most used first
+    pub async fn list_tags(pool: &SqlitePool) -> Result<Vec<TagCount>, SqlxError> {
+        sqlx::query_as::<_, TagCount>(
+            "SELECT tag, COUNT(*) AS meeting_count FROM meeting_tags
+             GROUP BY tag COLLATE NOCASE
+             ORDER BY meeting_count DESC, tag ASC",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Replaces a meeting's tags. Returns the stored tags.
+    pub async fn set_tags(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        tags: &[String],
+    ) -> Result<Vec<String>, SqlxError> {
+        let tags = normalize_tags(tags).map_err(SqlxError::Protocol)?;
+        locks::ensure_unlocked(meeting_id)?;
+        let now = Utc::now();
+
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+        sqlx::query("DELETE FROM meeting_tags WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        for tag in &tags {
+            sqlx::query("INSERT INTO meeting_tags (meeting_id, tag, created_at) VALUES (?, ?, ?)")
+                .bind(meeting_id)
+                .bind(tag)
+                .bind(now)
+                .execute(&mut *transaction)
+                .await?;
+        }
+        transaction.commit().await?;
+
+        Ok(tags)
+    }
+
+    /// Adds one tag to a meeting's tags, if it doesn't carry it already in
+    /// any spelling
+    pub async fn add_tag(pool: &SqlitePool, meeting_id: &str, tag: &str) -> Result<(), SqlxError> {
+        let Some(tag) = normalize_tags(&[tag.to_string()])
+            .map_err(SqlxError::Protocol)?
+            .pop()
+        else {
+            return Ok(());
+        };
+        locks::ensure_unlocked(meeting_id)?;
+        sqlx::query(
+            "INSERT INTO meeting_tags (meeting_id, tag, created_at)
+             SELECT ?, ?, ?
+             WHERE NOT EXISTS (
+                 SELECT 1 FROM meeting_tags WHERE meeting_id = ? AND tag = ? COLLATE NOCASE
+             )",
+        )
+        .bind(meeting_id)
+        .bind(&tag)
+        .bind(Utc::now())
+        .bind(meeting_id)
+        .bind(&tag)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::{insert_meeting, memory_pool};
+
+    #[test]
+    fn normalizes_tags() {
+        let tags = vec![
+            "  client   review ".to_string(),
+            "Client Review".to_string(),
+            "".to_string(),
+            "q3".to_string(),
+        ];
+        assert_eq!(normalize_tags(&tags).unwrap(), vec!["client review", "q3"]);
+        assert!(normalize_tags(&["x".repeat(MAX_TAG_LEN + 1)]).is_err());
+    }
+
+    #[tokio::test]
+    async fn added_tags_match_existing_ones_case_insensitively() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        TagsRepository::set_tags(&pool, "m1", &["Team Radio".to_string()])
+            .await
+            .unwrap();
+
+        TagsRepository::add_tag(&pool, "m1", "team  radio")
+            .await
+            .unwrap();
+        TagsRepository::add_tag(&pool, "m1", "Q3").await.unwrap();
+        assert_eq!(
+            TagsRepository::get_tags(&pool, "m1").await.unwrap(),
+            vec!["Q3", "Team Radio"]
+        );
+    }
+}
@@ -1,12 +1,68 @@
 use crate::api::{TranscriptSearchResult, TranscriptSegment};
+use crate::database::repositories::custom_field::CustomFieldsRepository;
+use crate::database::repositories::journal::JournalRepository;
+use crate::database::repositories::legal_hold::LegalHoldRepository;
+use crate::database::repositories::minutes_status::MinutesStatusRepository;
+use crate::database::repositories::search::{
+    match_context, paginate, rank_results, relevance_score, SearchRanking, TranscriptSearchPage,
+};
+use crate::encryption;
+use crate::locks::{self, MeetingLock, MeetingLocks};
+use crate::redaction;
 use chrono::Utc;
-use sqlx::{Connection, Error as SqlxError, SqlitePool};
+use sqlx::{Connection, Error as SqlxError, QueryBuilder, Sqlite, SqliteConnection, SqlitePool};
 use tracing::{error, info};
 use uuid::Uuid;
 
+/// Rows per multi-row INSERT. Each row binds 8 parameters, keeping a statement
+/// under SQLite's historical limit of 999 bound parameters.
+const SEGMENTS_PER_STATEMENT: usize = 100;
+
 pub struct TranscriptsRepository;
 
 impl TranscriptsRepository {
+    /// Inserts segments with multi-row INSERT statements. Callers wrap this in
+    /// a transaction so the whole batch commits with a single sync. With
+    /// redaction on, the text is redacted first.
+    pub async fn insert_segments(
+        conn: &mut SqliteConnection,
+        meeting_id: &str,
+        segments: &[TranscriptSegment],
+    ) -> Result<(), SqlxError> {
+        for chunk in segments.chunks(SEGMENTS_PER_STATEMENT) {
+            let redacted = redaction::redact_for_storage(
+                &mut *conn,
+                meeting_id,
+                chunk.iter().map(|segment| segment.text.as_str()),
+            )
+            .await?;
+            let texts = chunk
+                .iter()
+                .enumerate()
+                .map(|(i, segment)| {
+                    let text = redacted.as_ref().map_or(&segment.text, |texts| &texts[i]);
+                    encryption::seal(text)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let mut builder: QueryBuilder<Sqlite> = QueryBuilder::new(
+                "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration, speaker, uncertain) ",
+            );
+            builder.push_values(chunk.iter().zip(texts), |mut row, (segment, text)| {
+                row.push_bind(format!("transcript-{}", Uuid::new_v4()))
+                    .push_bind(meeting_id)
+                    .push_bind(text)
+                    .push_bind(&segment.timestamp)
+                    .push_bind(segment.audio_start_time)
+                    .push_bind(segment.audio_end_time)
+                    .push_bind(segment.duration)
+                    .push_bind(&segment.speaker)
+                    .push_bind(segment.uncertain);
+            });
+            builder.build().execute(&mut *conn).await?;
+        }
+        Ok(())
+    }
+
     /// Saves a new meeting and its associated transcript segments.
     /// This function uses a transaction to ensure that either both the meeting
     /// and all its transcripts are saved, or none of them are.
@@ -43,31 +99,14 @@ impl TranscriptsRepository {
 
         info!("Successfully created meeting with id: {}", meeting_id);
 
-        // 2. Save each transcript segment with audio timing fields
-        for segment in transcripts {
-            let transcript_id = format!("transcript-{}", Uuid::new_v4());
-            let result = sqlx::query(
-                "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration)
-                 VALUES (?, ?, ?, ?, ?, ?, ?)"
-            )
-            .bind(&transcript_id)
-            .bind(&meeting_id)
-            .bind(&segment.text)
-            .bind(&segment.timestamp)
-            .bind(segment.audio_start_time)
-            .bind(segment.audio_end_time)
-            .bind(segment.duration)
-            .execute(&mut *transaction)
-            .await;
-
-            if let Err(e) = result {
-                error!(
-                    "Failed to save transcript segment for meeting {}: {}",
-                    meeting_id, e
-                );
-                transaction.rollback().await?;
-                return Err(e);
-            }
+        // 2. Save the transcript segments with audio timing fields
+        if let Err(e) = Self::insert_segments(&mut transaction, &meeting_id, transcripts).await {
+            error!(
+                "Failed to save transcript segments for meeting {}: {}",
+                meeting_id, e
+            );
+            transaction.rollback().await?;
+            return Err(e);
         }
 
         info!(
@@ -82,65 +121,760 @@ impl TranscriptsRepository {
         Ok(meeting_id)
     }
 
+    /// Deletes one transcript segment. Returns the meeting id it belonged to,
+    /// or None if the segment does not exist. Undoable this session.
+    pub async fn delete_segment(
+        pool: &SqlitePool,
+        transcript_id: &str,
+    ) -> Result<Option<String>, SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        let row: Option<(String, String)> =
+            sqlx::query_as("SELECT meeting_id, transcript FROM transcripts WHERE id = ?")
+                .bind(transcript_id)
+                .fetch_optional(&mut *transaction)
+                .await?;
+        let Some((meeting_id, text)) = row else {
+            transaction.rollback().await?;
+            return Ok(None);
+        };
+        if let Err(e) = locks::ensure_unlocked(&meeting_id) {
+            transaction.rollback().await?;
+            return Err(e.into());
+        }
+        if let Err(e) = LegalHoldRepository::ensure_not_held(&mut *transaction, &meeting_id).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+        if let Err(e) = MinutesStatusRepository::ensure_editable(&mut *transaction, &meeting_id).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+        let text = encryption::open(text)?;
+
+        let ids = [transcript_id.to_string()];
+        let image = JournalRepository::capture(&mut transaction, "transcripts", "id", &ids).await?;
+        let comments =
+            JournalRepository::capture(&mut transaction, "segment_comments", "transcript_id", &ids)
+                .await?;
+        JournalRepository::record(
+            &mut transaction,
+            "delete_segment",
+            Some(&meeting_id),
+            &format!("Delete segment \"{}\"", preview(&text)),
+            &[image, comments],
+        )
+        .await?;
+
+        sqlx::query("DELETE FROM transcripts WHERE id = ?")
+            .bind(transcript_id)
+            .execute(&mut *transaction)
+            .await?;
+        for table in ["segment_analysis", "transcript_entities", "segment_comments"] {
+            sqlx::query(&format!("DELETE FROM {} WHERE transcript_id = ?", table))
+                .bind(transcript_id)
+                .execute(&mut *transaction)
+                .await?;
+        }
+        transaction.commit().await?;
+        Ok(Some(meeting_id))
+    }
+
+    /// Merges segments of one meeting into the earliest of them, joining their
+    /// text and spanning their audio range. Returns the id of the merged
+    /// segment. Undoable this session.
+    pub async fn merge_segments(
+        pool: &SqlitePool,
+        transcript_ids: &[String],
+    ) -> Result<String, SqlxError> {
+        if transcript_ids.len() < 2 {
+            return Err(SqlxError::Protocol(
+                "At least two segments are needed to merge".to_string(),
+            ));
+        }
+
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        let mut segments = sqlx::query_as::<_, MergeSource>(
+            "SELECT id, meeting_id, transcript, audio_start_time, audio_end_time FROM transcripts
+             WHERE id IN (SELECT value FROM json_each(?))
+             ORDER BY audio_start_time IS NULL, audio_start_time, rowid",
+        )
+        .bind(serde_json::to_string(transcript_ids).unwrap_or_default())
+        .fetch_all(&mut *transaction)
+        .await?;
+        for segment in &mut segments {
+            segment.transcript = encryption::open(std::mem::take(&mut segment.transcript))?;
+        }
+
+        if segments.len() != transcript_ids.len() {
+            transaction.rollback().await?;
+            return Err(SqlxError::RowNotFound);
+        }
+        let meeting_id = segments[0].meeting_id.clone();
+        if segments.iter().any(|s| s.meeting_id != meeting_id) {
+            transaction.rollback().await?;
+            return Err(SqlxError::Protocol(
+                "Only segments of the same meeting can be merged".to_string(),
+            ));
+        }
+        if let Err(e) = locks::ensure_unlocked(&meeting_id) {
+            transaction.rollback().await?;
+            return Err(e.into());
+        }
+        if let Err(e) = LegalHoldRepository::ensure_not_held(&mut *transaction, &meeting_id).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+        if let Err(e) = MinutesStatusRepository::ensure_editable(&mut *transaction, &meeting_id).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+
+        let image =
+            JournalRepository::capture(&mut transaction, "transcripts", "id", transcript_ids)
+                .await?;
+        let comments = JournalRepository::capture(
+            &mut transaction,
+            "segment_comments",
+            "transcript_id",
+            transcript_ids,
+        )
+        .await?;
+        JournalRepository::record(
+            &mut transaction,
+            "merge_segments",
+            Some(&meeting_id),
+            &format!("Merge {} segments", segments.len()),
+            &[image, comments],
+        )
+        .await?;
+
+        let merged = merge(&segments);
+        let survivor = &segments[0].id;
+        sqlx::query(
+            "UPDATE transcripts SET transcript = ?, audio_start_time = ?, audio_end_time = ?, duration = ? WHERE id = ?",
+        )
+        .bind(encryption::seal(&merged.text)?)
+        .bind(merged.start)
+        .bind(merged.end)
+        .bind(merged.duration())
+        .bind(survivor)
+        .execute(&mut *transaction)
+        .await?;
+        for segment in &segments[1..] {
+            sqlx::query("DELETE FROM transcripts WHERE id = ?")
+                .bind(&segment.id)
+                .execute(&mut *transaction)
+                .await?;
+        }
+        // Comments stay with the merged segment
+        sqlx::query(
+            "UPDATE segment_comments SET transcript_id = ? WHERE transcript_id IN (SELECT value FROM json_each(?))",
+        )
+        .bind(survivor)
+        .bind(serde_json::to_string(transcript_ids).unwrap_or_default())
+        .execute(&mut *transaction)
+        .await?;
+        // The merged text needs a fresh analysis
+        let merged_ids = serde_json::to_string(transcript_ids).unwrap_or_default();
+        for table in ["segment_analysis", "transcript_entities"] {
+            sqlx::query(&format!(
+                "DELETE FROM {} WHERE transcript_id IN (SELECT value FROM json_each(?))",
+                table
+            ))
+            .bind(&merged_ids)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+        info!("Merged {} segments into {}", segments.len(), survivor);
+        Ok(survivor.clone())
+    }
+
+    /// One transcript segment and the id of its meeting
+    pub async fn get_segment(
+        pool: &SqlitePool,
+        transcript_id: &str,
+    ) -> Result<Option<(String, TranscriptSegment)>, SqlxError> {
+        type Row = (String, String, String, Option<f64>, Option<f64>, Option<f64>, Option<String>, bool);
+        let row: Option<Row> = sqlx::query_as(
+            "SELECT meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration, speaker, uncertain
+             FROM transcripts WHERE id = ?",
+        )
+        .bind(transcript_id)
+        .fetch_optional(pool)
+        .await?;
+        let Some((meeting_id, text, timestamp, audio_start_time, audio_end_time, duration, speaker, uncertain)) =
+            row
+        else {
+            return Ok(None);
+        };
+        Ok(Some((
+            meeting_id,
+            TranscriptSegment {
+                id: transcript_id.to_string(),
+                text: encryption::open(text)?,
+                timestamp,
+                audio_start_time,
+                audio_end_time,
+                duration,
+                speaker,
+                uncertain,
+            },
+        )))
+    }
+
+    /// Replaces the text of one segment (an edit, or re-transcribed audio),
+    /// keeping its timing and comments. `uncertain` flags the new text as a
+    /// possible hallucination; an edit passes false. `operation` and `label` describe the
+    /// change in the journal. A task writing its own result passes the
+    /// meeting lock it holds as `lock`. Returns the meeting id, or None if
+    /// the segment does not exist. Undoable this session.
+    pub async fn replace_segment_text(
+        pool: &SqlitePool,
+        transcript_id: &str,
+        text: &str,
+        uncertain: bool,
+        operation: &str,
+        label: &str,
+        lock: Option<&MeetingLock>,
+    ) -> Result<Option<String>, SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        let meeting_id: Option<String> =
+            sqlx::query_scalar("SELECT meeting_id FROM transcripts WHERE id = ?")
+                .bind(transcript_id)
+                .fetch_optional(&mut *transaction)
+                .await?;
+        let Some(meeting_id) = meeting_id else {
+            transaction.rollback().await?;
+            return Ok(None);
+        };
+        if let Err(e) = MeetingLocks::global().check(&meeting_id, lock) {
+            transaction.rollback().await?;
+            return Err(e.into());
+        }
+        if let Err(e) = LegalHoldRepository::ensure_not_held(&mut *transaction, &meeting_id).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+        if let Err(e) = MinutesStatusRepository::ensure_editable(&mut *transaction, &meeting_id).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+
+        let ids = [transcript_id.to_string()];
+        let image = JournalRepository::capture(&mut transaction, "transcripts", "id", &ids).await?;
+        JournalRepository::record(
+            &mut transaction,
+            operation,
+            Some(&meeting_id),
+            &format!("{} \"{}\"", label, preview(text)),
+            &[image],
+        )
+        .await?;
+
+        let redacted =
+            redaction::redact_for_storage(&mut transaction, &meeting_id, std::iter::once(text))
+                .await?;
+        let text = redacted
+            .as_ref()
+            .and_then(|texts| texts.first())
+            .map_or(text, String::as_str);
+        sqlx::query("UPDATE transcripts SET transcript = ?, uncertain = ? WHERE id = ?")
+            .bind(encryption::seal(text)?)
+            .bind(uncertain)
+            .bind(transcript_id)
+            .execute(&mut *transaction)
+            .await?;
+        // The new text needs a fresh analysis
+        for table in ["segment_analysis", "transcript_entities"] {
+            sqlx::query(&format!("DELETE FROM {} WHERE transcript_id = ?", table))
+                .bind(transcript_id)
+                .execute(&mut *transaction)
+                .await?;
+        }
+        transaction.commit().await?;
+        Ok(Some(meeting_id))
+    }
+
+    /// Sets the recording timing of segments of one meeting, as
+    /// (id, start, end) in seconds. A task writing its own result passes the
+    /// meeting lock it holds as `lock`. Returns how many segments changed.
+    /// Undoable this session.
+    pub async fn update_segment_timings(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        timings: &[(String, f64, f64)],
+        lock: Option<&MeetingLock>,
+    ) -> Result<u64, SqlxError> {
+        if timings.is_empty() {
+            return Ok(0);
+        }
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        if let Err(e) = MeetingLocks::global().check(meeting_id, lock) {
+            transaction.rollback().await?;
+            return Err(e.into());
+        }
+        if let Err(e) = LegalHoldRepository::ensure_not_held(&mut *transaction, meeting_id).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+        if let Err(e) = MinutesStatusRepository::ensure_editable(&mut *transaction, meeting_id).await {
+            transaction.rollback().await?;
+            return Err(e);
+        }
+
+        let ids: Vec<String> = timings.iter().map(|(id, _, _)| id.clone()).collect();
+        let image = JournalRepository::capture(&mut transaction, "transcripts", "id", &ids).await?;
+        JournalRepository::record(
+            &mut transaction,
+            "realign_segments",
+            Some(meeting_id),
+            &format!("Realign {} segments", timings.len()),
+            &[image],
+        )
+        .await?;
+
+        let mut updated = 0;
+        for (id, start, end) in timings {
+            updated += sqlx::query(
+                "UPDATE transcripts SET audio_start_time = ?, audio_end_time = ?, duration = ?
+                 WHERE id = ? AND meeting_id = ?",
+            )
+            .bind(start)
+            .bind(end)
+            .bind(end - start)
+            .bind(id)
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?
+            .rows_affected();
+        }
+        transaction.commit().await?;
+        info!("Realigned {} segments of meeting {}", updated, meeting_id);
+        Ok(updated)
+    }
+
     /// Searches for a query string within the transcripts.
-    /// It returns a list of matching transcripts with context.
+    /// It returns a list of matching transcripts with context, newest meetings first.
     pub async fn search_transcripts(
         pool: &SqlitePool,
         query: &str,
     ) -> Result<Vec<TranscriptSearchResult>, SqlxError> {
+        let matches = Self::collect_matches(pool, query).await?;
+        Ok(rank_results(matches, SearchRanking::Recency))
+    }
+
+    /// One page of search results in the requested order, with the total count
+    pub async fn search_transcripts_page(
+        pool: &SqlitePool,
+        query: &str,
+        ranking: SearchRanking,
+        offset: usize,
+        limit: usize,
+    ) -> Result<TranscriptSearchPage, SqlxError> {
+        let matches = Self::collect_matches(pool, query).await?;
+        Ok(paginate(rank_results(matches, ranking), offset, limit))
+    }
+
+    /// Every match paired with its meeting's creation time, unordered
+    async fn collect_matches(
+        pool: &SqlitePool,
+        query: &str,
+    ) -> Result<Vec<(String, TranscriptSearchResult)>, SqlxError> {
         if query.trim().is_empty() {
             return Ok(Vec::new());
         }
 
         let search_query = format!("%{}%", query.to_lowercase());
 
-        let rows = sqlx::query_as::<_, (String, String, String, String)>(
-            "SELECT m.id, m.title, t.transcript, t.timestamp
-             FROM meetings m
-             JOIN transcripts t ON m.id = t.meeting_id
-             WHERE LOWER(t.transcript) LIKE ?",
-        )
-        .bind(&search_query)
-        .fetch_all(pool)
-        .await?;
+        // Encrypted text can't be matched in SQL, so every segment is
+        // decrypted and matched here instead
+        let encrypted = encryption::is_enabled();
+        let rows = if encrypted {
+            sqlx::query_as::<_, SearchRow>(
+                "SELECT m.id, m.title, m.created_at, t.transcript, t.timestamp
+                 FROM meetings m
+                 JOIN transcripts t ON m.id = t.meeting_id",
+            )
+            .fetch_all(pool)
+            .await?
+        } else {
+            sqlx::query_as::<_, SearchRow>(
+                "SELECT m.id, m.title, m.created_at, t.transcript, t.timestamp
+                 FROM meetings m
+                 JOIN transcripts t ON m.id = t.meeting_id
+                 WHERE LOWER(t.transcript) LIKE ?",
+            )
+            .bind(&search_query)
+            .fetch_all(pool)
+            .await?
+        };
+
+        let query_lower = query.to_lowercase();
+        let mut results = Vec::new();
+        for row in rows {
+            let transcript = encryption::open(row.transcript)?;
+            if encrypted && !transcript.to_lowercase().contains(&query_lower) {
+                continue;
+            }
+            let (match_context, match_offsets) = match_context(&transcript, query);
+            results.push((
+                row.created_at,
+                TranscriptSearchResult {
+                    score: relevance_score(&transcript, &row.title, query),
+                    match_context,
+                    match_offsets,
+                    id: row.id,
+                    title: row.title,
+                    timestamp: row.timestamp,
+                },
+            ));
+        }
 
-        let results = rows
-            .into_iter()
-            .map(|(id, title, transcript, timestamp)| {
-                let match_context = Self::get_match_context(&transcript, query);
+        // Meetings whose custom field values match
+        for field in CustomFieldsRepository::search_values(pool, query).await? {
+            let text = format!("{}: {}", field.field_name, field.value);
+            let (match_context, match_offsets) = match_context(&text, query);
+            results.push((
+                field.created_at.clone(),
                 TranscriptSearchResult {
-                    id,
-                    title,
+                    score: relevance_score(&text, &field.title, query),
                     match_context,
-                    timestamp,
-                }
-            })
-            .collect();
+                    match_offsets,
+                    id: field.meeting_id,
+                    title: field.title,
+                    timestamp: field.created_at,
+                },
+            ));
+        }
 
         Ok(results)
     }
+}
 
-    /// Helper function to extract a snippet of text around the first match of a query.
-    fn get_match_context(transcript: &str, query: &str) -> String {
-        let transcript_lower = transcript.to_lowercase();
-        let query_lower = query.to_lowercase();
+#[derive(sqlx::FromRow)]
+struct SearchRow {
+    id: String,
+    title: String,
+    created_at: String,
+    transcript: String,
+    timestamp: String,
+}
 
-        match transcript_lower.find(&query_lower) {
-            Some(match_index) => {
-                let start_index = match_index.saturating_sub(100);
-                let end_index = (match_index + query.len() + 100).min(transcript.len());
-
-                let mut context = String::new();
-                if start_index > 0 {
-                    context.push_str("...");
-                }
-                context.push_str(&transcript[start_index..end_index]);
-                if end_index < transcript.len() {
-                    context.push_str("...");
-                }
-                context
-            }
-            None => transcript.chars().take(200).collect(), // Fallback to the start of the transcript
+#[derive(sqlx::FromRow)]
+struct MergeSource {
+    id: String,
+    meeting_id: String,
+    transcript: String,
+    audio_start_time: Option<f64>,
+    audio_end_time: Option<f64>,
+}
+
+struct Merged {
+    text: String,
+    start: Option<f64>,
+    end: Option<f64>,
+}
+
+impl Merged {
+    fn duration(&self) -> Option<f64> {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) => Some(end - start),
+            _ => None,
         }
     }
 }
+
+/// Joins segments given in playback order
+fn merge(segments: &[MergeSource]) -> Merged {
+    let text = segments
+        .iter()
+        .map(|s| s.transcript.trim())
+        .filter(|t| !t.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let start = segments
+        .iter()
+        .filter_map(|s| s.audio_start_time)
+        .reduce(f64::min);
+    let end = segments
+        .iter()
+        .filter_map(|s| s.audio_end_time)
+        .reduce(f64::max);
+    Merged { text, start, end }
+}
+
+fn preview(text: &str) -> String {
+    let mut preview: String = text.chars().take(40).collect();
+    if text.chars().count() > 40 {
+        preview.push_str("...");
+    }
+    preview
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::{insert_meeting, insert_transcript, memory_pool};
+
+    fn source(text: &str, start: Option<f64>, end: Option<f64>) -> MergeSource {
+        MergeSource {
+            id: text.to_string(),
+            meeting_id: "meeting-1".to_string(),
+            transcript: text.to_string(),
+            audio_start_time: start,
+            audio_end_time: end,
+        }
+    }
+
+    #[test]
+    fn merge_joins_text_and_spans_audio() {
+        let merged = merge(&[
+            source(" Hello there ", Some(1.5), Some(3.0)),
+            source("", Some(3.0), Some(3.2)),
+            source("general Kenobi", Some(3.2), Some(6.0)),
+        ]);
+        assert_eq!(merged.text, "Hello there general Kenobi");
+        assert_eq!(merged.start, Some(1.5));
+        assert_eq!(merged.end, Some(6.0));
+        assert_eq!(merged.duration(), Some(4.5));
+    }
+
+    #[test]
+    fn merge_without_timing_has_no_duration() {
+        let merged = merge(&[source("a", None, None), source("b", None, None)]);
+        assert_eq!(merged.duration(), None);
+        assert_eq!(preview(&"x".repeat(50)), format!("{}...", "x".repeat(40)));
+    }
+
+    async fn approve(pool: &SqlitePool, meeting_id: &str) {
+        use crate::database::repositories::minutes_status::MinutesState;
+        for next in [MinutesState::UnderReview, MinutesState::Approved] {
+            MinutesStatusRepository::transition(pool, meeting_id, next, "reviewer")
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn replaced_text_is_journaled_and_can_be_undone() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "m1").await;
+        insert_transcript(&pool, "m1", "t1").await;
+
+        let (meeting_id, segment) = TranscriptsRepository::get_segment(&pool, "t1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(meeting_id, "m1");
+        assert_eq!(segment.text, "Segment t1");
+        assert_eq!(segment.audio_start_time, Some(0.0));
+        assert!(TranscriptsRepository::get_segment(&pool, "missing")
+            .await
+            .unwrap()
+            .is_none());
+
+        let changed = TranscriptsRepository::replace_segment_text(
+            &pool,
+            "t1",
+            "Corrected text",
+            false,
+            "retranscribe_segment",
+            "Re-transcribe segment",
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(changed.as_deref(), Some("m1"));
+        let (_, segment) = TranscriptsRepository::get_segment(&pool, "t1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(segment.text, "Corrected text");
+
+        let entry = JournalRepository::last_undoable(&pool)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(entry.operation, "retranscribe_segment");
+        assert_eq!(entry.meeting_id.as_deref(), Some("m1"));
+        JournalRepository::undo_last(&pool).await.unwrap();
+        let (_, segment) = TranscriptsRepository::get_segment(&pool, "t1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(segment.text, "Segment t1");
+
+        assert!(TranscriptsRepository::replace_segment_text(
+            &pool,
+            "missing",
+            "x",
+            false,
+            "edit_segment",
+            "Edit segment",
+            None
+        )
+        .await
+        .unwrap()
+        .is_none());
+    }
+
+    #[tokio::test]
+    async fn held_or_approved_segments_are_not_replaced() {
+        let pool = memory_pool().await;
+        insert_meeting(&pool, "held").await;
+        insert_transcript(&pool, "held", "t-held").await;
+        insert_meeting(&pool, "approved").await;
+        insert_transcript(&pool, "approved", "t-approved").await;
+        LegalHoldRepository::place(&pool, "held", "Litigation", "legal")
+            .await
+            .unwrap();
+        approve(&pool, "approved").await;
+
+        for id in ["t-held", "t-approved"] {
+            let result = TranscriptsRepository::replace_segment_text(
+                &pool,
+                id,
+                "Changed",
+                false,
+                "edit_segment",
+                "Edit segment",
+                None,
+            )
+            .await;
+            assert!(matches!(result, Err(SqlxError::Protocol(_))), "{}", id);
+            let (_, segment) = TranscriptsRepository::get_segment(&pool, id)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(segment.text, format!("Segment {}", id));
+        }
+        assert!(JournalRepository::last_undoable(&pool)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn uncertain_segments_stay_flagged_until_edited() {
+        let pool = memory_pool().await;
+        let segment = TranscriptSegment {
+            id: "s1".to_string(),
+            text: "Thanks for the update".to_string(),
+            timestamp: "00:00:01".to_string(),
+            audio_start_time: Some(1.0),
+            audio_end_time: Some(2.5),
+            duration: Some(1.5),
+            speaker: None,
+            uncertain: true,
+        };
+        let meeting_id = TranscriptsRepository::save_transcript(&pool, "Standup", &[segment], None)
+            .await
+            .unwrap();
+        let id: String = sqlx::query_scalar("SELECT id FROM transcripts WHERE meeting_id = ?")
+            .bind(&meeting_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let (_, saved) = TranscriptsRepository::get_segment(&pool, &id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(saved.uncertain);
+
+        TranscriptsRepository::replace_segment_text(
+            &pool,
+            &id,
+            "Thanks for the update!",
+            false,
+            "edit_segment",
+            "Edit segment",
+            None,
+        )
+        .await
+        .unwrap();
+        let (_, edited) = TranscriptsRepository::get_segment(&pool, &id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!edited.uncertain);
+    }
+
+    #[tokio::test]
+    async fn realigned_timings_are_journaled_unless_held_or_approved() {
+        let pool = memory_pool().await;
+        for meeting_id in ["m1", "held", "approved"] {
+            insert_meeting(&pool, meeting_id).await;
+            insert_transcript(&pool, meeting_id, &format!("t-{}", meeting_id)).await;
+        }
+        LegalHoldRepository::place(&pool, "held", "Litigation", "legal")
+            .await
+            .unwrap();
+        approve(&pool, "approved").await;
+
+        for meeting_id in ["held", "approved"] {
+            let timings = [(format!("t-{}", meeting_id), 4.0, 6.5)];
+            let result =
+                TranscriptsRepository::update_segment_timings(&pool, meeting_id, &timings, None)
+                    .await;
+            assert!(
+                matches!(result, Err(SqlxError::Protocol(_))),
+                "{}",
+                meeting_id
+            );
+            let (_, segment) = TranscriptsRepository::get_segment(&pool, &timings[0].0)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(segment.audio_start_time, Some(0.0), "{}", meeting_id);
+        }
+        assert!(JournalRepository::last_undoable(&pool)
+            .await
+            .unwrap()
+            .is_none());
+
+        // Segments of other meetings are left alone
+        let timings = [
+            ("t-m1".to_string(), 4.0, 6.5),
+            ("t-held".to_string(), 1.0, 2.0),
+        ];
+        let updated = TranscriptsRepository::update_segment_timings(&pool, "m1", &timings, None)
+            .await
+            .unwrap();
+        assert_eq!(updated, 1);
+        let (_, segment) = TranscriptsRepository::get_segment(&pool, "t-m1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(segment.audio_start_time, Some(4.0));
+        assert_eq!(segment.audio_end_time, Some(6.5));
+        assert_eq!(segment.duration, Some(2.5));
+
+        let entry = JournalRepository::last_undoable(&pool)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(entry.operation, "realign_segments");
+        assert_eq!(entry.meeting_id.as_deref(), Some("m1"));
+        JournalRepository::undo_last(&pool).await.unwrap();
+        let (_, segment) = TranscriptsRepository::get_segment(&pool, "t-m1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(segment.audio_start_time, Some(0.0));
+        let (_, untouched) = TranscriptsRepository::get_segment(&pool, "t-held")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(untouched.audio_start_time, Some(0.0));
+    }
+}
@@ -21,6 +21,13 @@ impl TranscriptChunksRepository {
             meeting_id
         );
         let now = Utc::now();
+        let mut conn = pool.acquire().await?;
+        // Same placeholders as the meeting's stored segments
+        let redacted = crate::redaction::redact_for_storage(&mut conn, meeting_id, [text]).await?;
+        let text = match redacted {
+            Some(mut texts) => crate::encryption::seal(&texts.remove(0))?,
+            None => crate::encryption::seal(text)?,
+        };
         sqlx::query(
             r#"
             INSERT INTO transcript_chunks (meeting_id, transcript_text, model, model_name, chunk_size, overlap, created_at)
@@ -35,13 +42,13 @@ impl TranscriptChunksRepository {
             "#
         )
         .bind(meeting_id)
-        .bind(text)
+        .bind(&text)
         .bind(model)
         .bind(model_name)
         .bind(chunk_size)
         .bind(overlap)
         .bind(now)
-        .execute(pool)
+        .execute(&mut *conn)
         .await?;
 
         Ok(())
@@ -0,0 +1,188 @@
+//! In-memory cache for the model and transcript settings.
+//!
+//! The frontend queries config frequently (per segment while recording), so
+//! reads are served from memory after the first load. Every write path calls
+//! [`config_changed`], which drops the cached values, notifies in-process
+//! subscribers and emits `config-changed` to the frontend.
+
+use serde::Serialize;
+use sqlx::{Error as SqlxError, SqlitePool};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use tauri::{AppHandle, Emitter, Runtime};
+use tokio::sync::broadcast;
+
+use crate::database::models::{Setting, TranscriptSetting};
+use crate::database::repositories::setting::SettingsRepository;
+
+pub const CONFIG_CHANGED_EVENT: &str = "config-changed";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigKind {
+    Model,
+    Transcript,
+}
+
+#[derive(Default)]
+struct Entries {
+    /// Bumped on every invalidation so a read that raced with a write is not stored
+    generation: u64,
+    model: Option<Option<Setting>>,
+    transcript: Option<Option<TranscriptSetting>>,
+    model_keys: HashMap<String, Option<String>>,
+    transcript_keys: HashMap<String, Option<String>>,
+}
+
+pub struct SettingsCache {
+    entries: RwLock<Entries>,
+    changes: broadcast::Sender<ConfigKind>,
+}
+
+impl Default for SettingsCache {
+    fn default() -> Self {
+        let (changes, _) = broadcast::channel(16);
+        Self {
+            entries: RwLock::new(Entries::default()),
+            changes,
+        }
+    }
+}
+
+impl SettingsCache {
+    fn get<T>(&self, read: impl FnOnce(&Entries) -> Option<T>) -> Result<T, u64> {
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        read(&entries).ok_or(entries.generation)
+    }
+
+    fn store(&self, generation: u64, write: impl FnOnce(&mut Entries)) {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        if entries.generation == generation {
+            write(&mut entries);
+        }
+    }
+
+    pub async fn model_config(&self, pool: &SqlitePool) -> Result<Option<Setting>, SqlxError> {
+        let generation = match self.get(|e| e.model.clone()) {
+            Ok(cached) => return Ok(cached),
+            Err(generation) => generation,
+        };
+        let value = SettingsRepository::get_model_config(pool).await?;
+        self.store(generation, |e| e.model = Some(value.clone()));
+        Ok(value)
+    }
+
+    pub async fn api_key(&self, pool: &SqlitePool, provider: &str) -> Result<Option<String>, SqlxError> {
+        let generation = match self.get(|e| e.model_keys.get(provider).cloned()) {
+            Ok(cached) => return Ok(cached),
+            Err(generation) => generation,
+        };
+        let value = SettingsRepository::get_api_key(pool, provider).await?;
+        self.store(generation, |e| {
+            e.model_keys.insert(provider.to_string(), value.clone());
+        });
+        Ok(value)
+    }
+
+    pub async fn transcript_config(
+        &self,
+        pool: &SqlitePool,
+    ) -> Result<Option<TranscriptSetting>, SqlxError> {
+        let generation = match self.get(|e| e.transcript.clone()) {
+            Ok(cached) => return Ok(cached),
+            Err(generation) => generation,
+        };
+        let value = SettingsRepository::get_transcript_config(pool).await?;
+        self.store(generation, |e| e.transcript = Some(value.clone()));
+        Ok(value)
+    }
+
+    pub async fn transcript_api_key(
+        &self,
+        pool: &SqlitePool,
+        provider: &str,
+    ) -> Result<Option<String>, SqlxError> {
+        let generation = match self.get(|e| e.transcript_keys.get(provider).cloned()) {
+            Ok(cached) => return Ok(cached),
+            Err(generation) => generation,
+        };
+        let value = SettingsRepository::get_transcript_api_key(pool, provider).await?;
+        self.store(generation, |e| {
+            e.transcript_keys.insert(provider.to_string(), value.clone());
+        });
+        Ok(value)
+    }
+
+    /// Drops cached values of `kind` and notifies subscribers
+    pub fn invalidate(&self, kind: ConfigKind) {
+        {
+            let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+            entries.generation += 1;
+            match kind {
+                ConfigKind::Model => {
+                    entries.model = None;
+                    entries.model_keys.clear();
+                }
+                ConfigKind::Transcript => {
+                    entries.transcript = None;
+                    entries.transcript_keys.clear();
+                }
+            }
+        }
+        // No receivers is fine
+        let _ = self.changes.send(kind);
+    }
+
+    /// Receives the kind of every config change made through this cache
+    pub fn subscribe(&self) -> broadcast::Receiver<ConfigKind> {
+        self.changes.subscribe()
+    }
+}
+
+/// Invalidates the cache and tells the frontend that `kind` changed
+pub fn config_changed<R: Runtime>(app: &AppHandle<R>, cache: &SettingsCache, kind: ConfigKind) {
+    cache.invalidate(kind);
+    if let Err(e) = app.emit(CONFIG_CHANGED_EVENT, kind) {
+        log::warn!("Failed to emit {} event: {}", CONFIG_CHANGED_EVENT, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_racing_with_invalidation_is_not_stored() {
+        let cache = SettingsCache::default();
+        let mut changes = cache.subscribe();
+
+        let generation = cache.get(|e| e.model_keys.get("openai").cloned()).unwrap_err();
+        cache.invalidate(ConfigKind::Model);
+        cache.store(generation, |e| {
+            e.model_keys.insert("openai".into(), Some("stale".into()));
+        });
+        assert!(cache.get(|e| e.model_keys.get("openai").cloned()).is_err());
+        assert_eq!(changes.try_recv().unwrap(), ConfigKind::Model);
+
+        let generation = cache.get(|e| e.model_keys.get("openai").cloned()).unwrap_err();
+        cache.store(generation, |e| {
+            e.model_keys.insert("openai".into(), Some("fresh".into()));
+        });
+        assert_eq!(
+            cache.get(|e| e.model_keys.get("openai").cloned()),
+            Ok(Some("fresh".to_string()))
+        );
+    }
+
+    #[test]
+    fn invalidation_only_clears_its_kind() {
+        let cache = SettingsCache::default();
+        cache.store(0, |e| {
+            e.model_keys.insert("openai".into(), None);
+            e.transcript_keys.insert("deepgram".into(), None);
+        });
+        cache.invalidate(ConfigKind::Transcript);
+        assert!(cache.get(|e| e.model_keys.get("openai").cloned()).is_ok());
+        assert!(cache.get(|e| e.transcript_keys.get("deepgram").cloned()).is_err());
+    }
+}
@@ -30,8 +30,22 @@ pub async fn initialize_database_on_startup(app: &AppHandle) -> Result<(), Strin
             .await
             .map_err(|e| format!("Failed to initialize database manager: {}", e))?;
 
-        app.manage(AppState { db_manager });
+        app.manage(AppState::new(db_manager));
         info!("Database initialized successfully");
+
+        if crate::encryption::is_enabled() {
+            // Same delay as first-launch-detected: the unlock prompt needs its listener registered
+            let app_handle = app.clone();
+            tauri::async_runtime::spawn(async move {
+                tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+                if let Err(e) = app_handle.emit(
+                    crate::encryption::commands::ENCRYPTION_LOCKED_EVENT,
+                    crate::encryption::status(),
+                ) {
+                    log::warn!("Failed to emit encryption-locked event: {}", e);
+                }
+            });
+        }
     }
 
     Ok(())
@@ -0,0 +1,54 @@
+//! Helpers for repository tests
+
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::SqlitePool;
+
+/// A migrated in-memory database. One connection that never expires, so
+/// every query sees the same database.
+pub async fn memory_pool() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await
+        .expect("open in-memory database");
+    crate::database::migrations::run_migrations(&pool, None)
+        .await
+        .expect("migrate in-memory database");
+    pool
+}
+
+/// Inserts a bare meeting
+pub async fn insert_meeting(pool: &SqlitePool, id: &str) {
+    let now = chrono::Utc::now();
+    sqlx::query(
+        "INSERT INTO meetings (id, title, created_at, updated_at, folder_path) VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(format!("Meeting {}", id))
+    .bind(now)
+    .bind(now)
+    .bind(Option::<String>::None)
+    .execute(pool)
+    .await
+    .expect("insert meeting");
+}
+
+/// Inserts a transcript segment with plain text
+pub async fn insert_transcript(pool: &SqlitePool, meeting_id: &str, id: &str) {
+    sqlx::query(
+        "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration)
+         VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(meeting_id)
+    .bind(format!("Segment {}", id))
+    .bind("00:00:00")
+    .bind(0.0)
+    .bind(1.0)
+    .bind(1.0)
+    .execute(pool)
+    .await
+    .expect("insert transcript");
+}
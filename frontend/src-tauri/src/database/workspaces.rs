@@ -0,0 +1,254 @@
+//! Separate local workspaces ("Work", "Personal"), each with its own database,
+//! recordings root and settings.
+//!
+//! `workspaces.json` in the app data directory lists the workspaces and which
+//! one is active. The default workspace is the original layout: its database
+//! lives where `data_location.json` points and its stores sit in the app data
+//! directory. Every other workspace keeps its database and stores under
+//! `workspaces/<id>/`. The active workspace is read when the database is
+//! opened, so switching takes effect on the next launch; the switch command
+//! relaunches the app to re-initialize the app state.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+pub const REGISTRY_FILE_NAME: &str = "workspaces.json";
+pub const WORKSPACES_DIR_NAME: &str = "workspaces";
+pub const DEFAULT_WORKSPACE_ID: &str = "default";
+const DEFAULT_WORKSPACE_NAME: &str = "Default";
+const MAX_NAME_LEN: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Workspace {
+    pub id: String,
+    pub name: String,
+    /// Recordings root for new recordings; None means the platform default
+    #[serde(default)]
+    pub recordings_dir: Option<PathBuf>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Workspace {
+    pub fn is_default(&self) -> bool {
+        self.id == DEFAULT_WORKSPACE_ID
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceRegistry {
+    pub active: String,
+    pub workspaces: Vec<Workspace>,
+}
+
+impl Default for WorkspaceRegistry {
+    fn default() -> Self {
+        Self {
+            active: DEFAULT_WORKSPACE_ID.to_string(),
+            workspaces: vec![Workspace {
+                id: DEFAULT_WORKSPACE_ID.to_string(),
+                name: DEFAULT_WORKSPACE_NAME.to_string(),
+                recordings_dir: None,
+                created_at: DateTime::<Utc>::UNIX_EPOCH,
+            }],
+        }
+    }
+}
+
+impl WorkspaceRegistry {
+    pub fn get(&self, id: &str) -> Option<&Workspace> {
+        self.workspaces.iter().find(|w| w.id == id)
+    }
+
+    /// The active workspace; falls back to the default one if the active id
+    /// is unknown
+    pub fn active(&self) -> Workspace {
+        self.get(&self.active)
+            .or_else(|| self.get(DEFAULT_WORKSPACE_ID))
+            .cloned()
+            .unwrap_or_else(|| WorkspaceRegistry::default().workspaces.remove(0))
+    }
+
+    /// Adds a workspace; names must be unique, ignoring case
+    pub fn add(
+        &mut self,
+        name: &str,
+        recordings_dir: Option<PathBuf>,
+    ) -> Result<Workspace, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Workspace name must not be empty".to_string());
+        }
+        if name.chars().count() > MAX_NAME_LEN {
+            return Err(format!(
+                "Workspace name is longer than {} characters",
+                MAX_NAME_LEN
+            ));
+        }
+        if self
+            .workspaces
+            .iter()
+            .any(|w| w.name.to_lowercase() == name.to_lowercase())
+        {
+            return Err(format!("A workspace named \"{}\" already exists", name));
+        }
+        if let Some(dir) = &recordings_dir {
+            if !dir.is_absolute() {
+                return Err(format!("{} is not an absolute path", dir.display()));
+            }
+        }
+
+        let workspace = Workspace {
+            id: format!("workspace-{}", Uuid::new_v4()),
+            name: name.to_string(),
+            recordings_dir,
+            created_at: Utc::now(),
+        };
+        self.workspaces.push(workspace.clone());
+        Ok(workspace)
+    }
+
+    /// Sets or clears (None: platform default) a workspace's recordings root
+    pub fn set_recordings_dir(
+        &mut self,
+        id: &str,
+        recordings_dir: Option<PathBuf>,
+    ) -> Result<Workspace, String> {
+        if let Some(dir) = &recordings_dir {
+            if !dir.is_absolute() {
+                return Err(format!("{} is not an absolute path", dir.display()));
+            }
+        }
+        let workspace = self
+            .workspaces
+            .iter_mut()
+            .find(|w| w.id == id)
+            .ok_or_else(|| format!("Workspace {} not found", id))?;
+        workspace.recordings_dir = recordings_dir;
+        Ok(workspace.clone())
+    }
+
+    pub fn set_active(&mut self, id: &str) -> Result<(), String> {
+        if self.get(id).is_none() {
+            return Err(format!("Workspace {} not found", id));
+        }
+        self.active = id.to_string();
+        Ok(())
+    }
+}
+
+pub fn read_registry(app_data_dir: &Path) -> WorkspaceRegistry {
+    let path = app_data_dir.join(REGISTRY_FILE_NAME);
+    let mut registry: WorkspaceRegistry = match std::fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+            log::error!("Ignoring invalid {}: {}", path.display(), e);
+            WorkspaceRegistry::default()
+        }),
+        Err(_) => WorkspaceRegistry::default(),
+    };
+    // The default workspace always exists, even if the file was edited by hand
+    if registry.get(DEFAULT_WORKSPACE_ID).is_none() {
+        registry
+            .workspaces
+            .insert(0, WorkspaceRegistry::default().workspaces.remove(0));
+    }
+    registry
+}
+
+/// Writes the registry through a temporary file so a crash never leaves it half-written
+pub fn write_registry(app_data_dir: &Path, registry: &WorkspaceRegistry) -> io::Result<()> {
+    let path = app_data_dir.join(REGISTRY_FILE_NAME);
+    let tmp = app_data_dir.join(format!("{}.tmp", REGISTRY_FILE_NAME));
+    let content = serde_json::to_string_pretty(registry)?;
+    std::fs::write(&tmp, content)?;
+    std::fs::rename(&tmp, &path)
+}
+
+pub fn active_workspace(app_data_dir: &Path) -> Workspace {
+    read_registry(app_data_dir).active()
+}
+
+/// Directory of a non-default workspace's database and stores
+pub fn workspace_dir(app_data_dir: &Path, id: &str) -> PathBuf {
+    app_data_dir.join(WORKSPACES_DIR_NAME).join(id)
+}
+
+/// Store file name for the active workspace, relative to the app data directory
+/// as the store plugin expects
+pub fn store_path(workspace: &Workspace, file_name: &str) -> String {
+    if workspace.is_default() {
+        file_name.to_string()
+    } else {
+        format!("{}/{}/{}", WORKSPACES_DIR_NAME, workspace.id, file_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_and_activates_workspaces() {
+        let mut registry = WorkspaceRegistry::default();
+        assert!(registry.active().is_default());
+
+        let work = registry.add("  Work ", None).unwrap();
+        assert_eq!(work.name, "Work");
+        assert!(registry.add("work", None).is_err());
+        assert!(registry.add("", None).is_err());
+        assert!(registry
+            .add("Personal", Some(PathBuf::from("relative/recordings")))
+            .is_err());
+
+        registry.set_active(&work.id).unwrap();
+        assert_eq!(registry.active().id, work.id);
+        assert!(registry.set_active("workspace-missing").is_err());
+
+        // An unknown active id falls back to the default workspace
+        registry.active = "workspace-gone".to_string();
+        assert!(registry.active().is_default());
+    }
+
+    #[test]
+    fn recordings_dirs_are_per_workspace() {
+        let mut registry = WorkspaceRegistry::default();
+        let work = registry.add("Work", None).unwrap();
+        let nas = PathBuf::from(if cfg!(windows) {
+            "\\\\nas\\recordings"
+        } else {
+            "/mnt/nas/recordings"
+        });
+        let updated = registry
+            .set_recordings_dir(&work.id, Some(nas.clone()))
+            .unwrap();
+        assert_eq!(updated.recordings_dir, Some(nas));
+        assert_eq!(
+            registry.get(DEFAULT_WORKSPACE_ID).unwrap().recordings_dir,
+            None
+        );
+        assert!(registry
+            .set_recordings_dir(&work.id, Some(PathBuf::from("relative")))
+            .is_err());
+        assert!(registry
+            .set_recordings_dir("workspace-missing", None)
+            .is_err());
+    }
+
+    #[test]
+    fn stores_are_per_workspace() {
+        let mut registry = WorkspaceRegistry::default();
+        let work = registry.add("Work", None).unwrap();
+        assert_eq!(
+            store_path(&registry.active(), "recording_preferences.json"),
+            "recording_preferences.json"
+        );
+        assert_eq!(
+            store_path(&work, "recording_preferences.json"),
+            format!("workspaces/{}/recording_preferences.json", work.id)
+        );
+    }
+}
@@ -0,0 +1,161 @@
+//! Single writer for transcript inserts.
+//!
+//! All transcript writes go through one task fed by a channel, so inserts never
+//! compete with each other for the SQLite write lock. Writes that still hit a
+//! lock held by another connection (summaries, settings) are retried with
+//! backoff instead of being dropped.
+
+use sqlx::{Error as SqlxError, SqlitePool};
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::api::TranscriptSegment;
+use crate::database::repositories::transcript::TranscriptsRepository;
+
+const QUEUE_CAPACITY: usize = 256;
+const MAX_ATTEMPTS: u32 = 8;
+const INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+
+enum WriteRequest {
+    SaveTranscript {
+        meeting_title: String,
+        segments: Vec<TranscriptSegment>,
+        folder_path: Option<String>,
+        reply: oneshot::Sender<Result<String, SqlxError>>,
+    },
+    Flush {
+        reply: oneshot::Sender<()>,
+    },
+}
+
+/// Handle to the writer task; cheap to clone
+#[derive(Clone)]
+pub struct TranscriptWriter {
+    sender: mpsc::Sender<WriteRequest>,
+}
+
+/// SQLITE_BUSY (5) and SQLITE_LOCKED (6), including extended codes
+pub fn is_lock_code(code: &str) -> bool {
+    code.parse::<i32>()
+        .map(|c| matches!(c & 0xff, 5 | 6))
+        .unwrap_or(false)
+}
+
+fn is_lock_error(error: &SqlxError) -> bool {
+    match error {
+        SqlxError::Database(e) => {
+            e.code().map(|c| is_lock_code(&c)).unwrap_or(false)
+                || e.message().contains("database is locked")
+        }
+        _ => false,
+    }
+}
+
+/// Runs `op` until it succeeds, fails with a non-lock error, or runs out of attempts
+async fn with_retry<T, F, Fut>(mut op: F) -> Result<T, SqlxError>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, SqlxError>>,
+{
+    let mut backoff = INITIAL_BACKOFF;
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Err(e) if attempt < MAX_ATTEMPTS && is_lock_error(&e) => {
+                log::warn!(
+                    "Transcript write hit a locked database (attempt {}/{}), retrying in {:?}",
+                    attempt,
+                    MAX_ATTEMPTS,
+                    backoff
+                );
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(Duration::from_secs(2));
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+impl TranscriptWriter {
+    /// Spawns the writer task on the current Tokio runtime
+    pub fn spawn(pool: SqlitePool) -> Self {
+        let (sender, mut receiver) = mpsc::channel(QUEUE_CAPACITY);
+        tokio::spawn(async move {
+            while let Some(request) = receiver.recv().await {
+                match request {
+                    WriteRequest::SaveTranscript {
+                        meeting_title,
+                        segments,
+                        folder_path,
+                        reply,
+                    } => {
+                        let result = with_retry(|| {
+                            TranscriptsRepository::save_transcript(
+                                &pool,
+                                &meeting_title,
+                                &segments,
+                                folder_path.clone(),
+                            )
+                        })
+                        .await;
+                        let _ = reply.send(result);
+                    }
+                    WriteRequest::Flush { reply } => {
+                        let _ = reply.send(());
+                    }
+                }
+            }
+        });
+        Self { sender }
+    }
+
+    /// Creates a meeting with its transcript segments
+    pub async fn save_transcript(
+        &self,
+        meeting_title: String,
+        segments: Vec<TranscriptSegment>,
+        folder_path: Option<String>,
+    ) -> Result<String, SqlxError> {
+        let (reply, response) = oneshot::channel();
+        self.send(WriteRequest::SaveTranscript {
+            meeting_title,
+            segments,
+            folder_path,
+            reply,
+        })
+        .await?;
+        response.await.map_err(|_| SqlxError::WorkerCrashed)?
+    }
+
+    /// Waits until every write queued before this call has completed
+    pub async fn flush(&self) -> Result<(), SqlxError> {
+        let (reply, response) = oneshot::channel();
+        self.send(WriteRequest::Flush { reply }).await?;
+        response.await.map_err(|_| SqlxError::WorkerCrashed)
+    }
+
+    async fn send(&self, request: WriteRequest) -> Result<(), SqlxError> {
+        // Waits for queue space rather than dropping the write
+        self.sender
+            .send(request)
+            .await
+            .map_err(|_| SqlxError::WorkerCrashed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lock_codes_are_recognized() {
+        assert!(is_lock_code("5"));
+        assert!(is_lock_code("6"));
+        // SQLITE_BUSY_SNAPSHOT (517) and SQLITE_LOCKED_SHAREDCACHE (262)
+        assert!(is_lock_code("517"));
+        assert!(is_lock_code("262"));
+        assert!(!is_lock_code("19"));
+        assert!(!is_lock_code("not-a-code"));
+    }
+}
@@ -0,0 +1,195 @@
+// deep_link.rs
+//
+// meetingminutes:// links, so calendar entries and other apps can open a
+// meeting or start a recording:
+//
+//   meetingminutes://meeting/<meeting id>
+//   meetingminutes://record?title=<meeting title>
+//
+// Links arriving while the app runs come through the deep-link plugin (and
+// the single-instance plugin on Windows and Linux, where the OS starts a
+// second process). A link the app was launched with waits until the main
+// window has loaded, since handling it means navigating that window.
+//
+// Any page or app can open a link, so a record link only starts recording
+// after the user confirms it in a dialog.
+
+use log::{info, warn};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+use url::Url;
+
+use crate::audio::recording_commands::is_recording;
+use crate::tray::focus_main_window;
+use crate::validation;
+
+pub const SCHEME: &str = "meetingminutes";
+
+// Links received before the main window finished loading
+static PENDING: Mutex<Vec<DeepLink>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeepLink {
+    OpenMeeting { id: String },
+    Record { title: Option<String> },
+}
+
+impl DeepLink {
+    pub fn parse(link: &str) -> Result<Self, String> {
+        let url = Url::parse(link.trim()).map_err(|e| format!("not a URL: {}", e))?;
+        if url.scheme() != SCHEME {
+            return Err(format!("unsupported scheme '{}'", url.scheme()));
+        }
+        let segments: Vec<&str> = url
+            .path_segments()
+            .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default();
+
+        match (url.host_str(), segments.as_slice()) {
+            (Some("meeting"), [id]) => {
+                // Ids are plain ASCII, so a percent-encoded one is rejected
+                let id = validation::meeting_id(id)?;
+                Ok(DeepLink::OpenMeeting { id: id.to_string() })
+            }
+            (Some("record"), []) => {
+                let title = url
+                    .query_pairs()
+                    .find(|(key, _)| key == "title")
+                    .map(|(_, value)| value.into_owned())
+                    .filter(|value| !value.trim().is_empty())
+                    .map(|value| validation::title("title", &value))
+                    .transpose()?;
+                Ok(DeepLink::Record { title })
+            }
+            _ => Err(format!("unrecognized link '{}'", link)),
+        }
+    }
+}
+
+/// Asks the user whether to start the recording a link requested
+async fn confirm_recording<R: Runtime>(app: &AppHandle<R>, title: Option<&str>) -> bool {
+    let message = match title {
+        Some(title) => format!(
+            "A link asked to start recording \"{}\". Start recording now?",
+            title
+        ),
+        None => "A link asked to start a recording. Start recording now?".to_string(),
+    };
+    let (reply, confirmed) = tokio::sync::oneshot::channel();
+    app.dialog()
+        .message(message)
+        .title("Start recording?")
+        .kind(MessageDialogKind::Info)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            "Start recording".to_string(),
+            "Cancel".to_string(),
+        ))
+        .show(move |confirmed| {
+            let _ = reply.send(confirmed);
+        });
+    confirmed.await.unwrap_or(false)
+}
+
+async fn open<R: Runtime>(app: &AppHandle<R>, link: DeepLink) {
+    focus_main_window(app);
+    let Some(window) = app.get_webview_window("main") else {
+        warn!("Deep link: main window not found");
+        return;
+    };
+    match link {
+        DeepLink::OpenMeeting { id } => {
+            let url =
+                serde_json::to_string(&format!("/meeting-details?id={}", id)).unwrap_or_default();
+            let _ = window.eval(&format!("window.location.assign({})", url));
+        }
+        DeepLink::Record { title } => {
+            if is_recording().await {
+                info!("Deep link: already recording, not starting another");
+                return;
+            }
+            if !confirm_recording(app, title.as_deref()).await {
+                info!("Deep link: recording declined");
+                return;
+            }
+            if is_recording().await {
+                info!("Deep link: a recording started while confirming, not starting another");
+                return;
+            }
+            // Same auto-start flag the tray uses, plus the requested title
+            if let Some(title) = title {
+                let title = serde_json::to_string(&title).unwrap_or_default();
+                let _ = window.eval(&format!(
+                    "sessionStorage.setItem('autoStartRecordingTitle', {})",
+                    title
+                ));
+            }
+            let _ = window.eval("sessionStorage.setItem('autoStartRecording', 'true')");
+            let _ = window.eval("window.location.assign('/')");
+        }
+    }
+}
+
+/// Handles links received while the app runs
+pub fn handle_urls<R: Runtime>(app: &AppHandle<R>, urls: Vec<String>) {
+    for url in urls {
+        match DeepLink::parse(&url) {
+            Ok(link) => {
+                info!("Deep link: {:?}", link);
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move { open(&app, link).await });
+            }
+            Err(e) => warn!("Ignoring deep link: {}", e),
+        }
+    }
+}
+
+/// Keeps the links the app was launched with until the main window loads
+pub fn defer_launch_urls(urls: Vec<String>) {
+    let mut pending = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+    for url in urls {
+        match DeepLink::parse(&url) {
+            Ok(link) => pending.push(link),
+            Err(e) => warn!("Ignoring deep link: {}", e),
+        }
+    }
+}
+
+/// Handles the deferred launch links; called when the main window loads
+pub fn open_pending<R: Runtime>(app: &AppHandle<R>) {
+    let links = std::mem::take(&mut *PENDING.lock().unwrap_or_else(|e| e.into_inner()));
+    for link in links {
+        info!("Deep link from launch: {:?}", link);
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move { open(&app, link).await });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_meeting_and_record_links() {
+        assert_eq!(
+            DeepLink::parse("meetingminutes://meeting/meeting-1234"),
+            Ok(DeepLink::OpenMeeting {
+                id: "meeting-1234".to_string()
+            })
+        );
+        assert_eq!(
+            DeepLink::parse("meetingminutes://record?title=Weekly%20sync"),
+            Ok(DeepLink::Record {
+                title: Some("Weekly sync".to_string())
+            })
+        );
+        assert_eq!(
+            DeepLink::parse("meetingminutes://record/"),
+            Ok(DeepLink::Record { title: None })
+        );
+        assert!(DeepLink::parse("https://meeting/meeting-1234").is_err());
+        assert!(DeepLink::parse("meetingminutes://meeting/").is_err());
+        assert!(DeepLink::parse("meetingminutes://meeting/a/b").is_err());
+        assert!(DeepLink::parse("meetingminutes://delete/meeting-1234").is_err());
+    }
+}
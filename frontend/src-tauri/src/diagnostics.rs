@@ -0,0 +1,387 @@
+//! Health diagnostics for the support screen.
+//!
+//! Each check is independent and never fails the whole run: problems are
+//! reported as a check status with a short summary and structured details, so
+//! the report can be shown as-is and exported for support tickets.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{Row, SqlitePool};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::database::migrations::schema_version;
+use crate::database::repositories::setting::SettingsRepository;
+
+/// Free space below this is reported as a warning (roughly two hours of audio)
+const LOW_DISK_SPACE_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+const BACKEND_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Ok,
+    /// Not applicable here, e.g. an optional component that isn't running
+    Skipped,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticCheck {
+    pub name: String,
+    pub status: CheckStatus,
+    pub summary: String,
+    pub details: serde_json::Value,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticsReport {
+    pub app_version: String,
+    pub os: String,
+    pub arch: String,
+    /// Worst status across all checks
+    pub overall: CheckStatus,
+    pub checks: Vec<DiagnosticCheck>,
+    pub generated_at: DateTime<Utc>,
+}
+
+impl DiagnosticsReport {
+    pub fn new(app_version: String, checks: Vec<DiagnosticCheck>) -> Self {
+        Self {
+            app_version,
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            overall: overall_status(&checks),
+            checks,
+            generated_at: Utc::now(),
+        }
+    }
+}
+
+pub fn overall_status(checks: &[DiagnosticCheck]) -> CheckStatus {
+    checks
+        .iter()
+        .map(|c| c.status)
+        .filter(|s| *s != CheckStatus::Skipped)
+        .max()
+        .unwrap_or(CheckStatus::Ok)
+}
+
+/// Runs `check` and stamps it with its name and duration
+pub async fn timed<F>(name: &str, check: F) -> DiagnosticCheck
+where
+    F: std::future::Future<Output = (CheckStatus, String, serde_json::Value)>,
+{
+    let started = Instant::now();
+    let (status, summary, details) = check.await;
+    DiagnosticCheck {
+        name: name.to_string(),
+        status,
+        summary,
+        details,
+        duration_ms: started.elapsed().as_millis() as u64,
+    }
+}
+
+/// PRAGMA quick_check (the O(N) subset of integrity_check) plus schema state
+pub async fn check_database(pool: &SqlitePool) -> (CheckStatus, String, serde_json::Value) {
+    let messages: Vec<String> = match sqlx::query("PRAGMA quick_check").fetch_all(pool).await {
+        Ok(rows) => rows.iter().map(|row| row.get::<String, _>(0)).collect(),
+        Err(e) => {
+            return (
+                CheckStatus::Error,
+                format!("Database query failed: {}", e),
+                serde_json::Value::Null,
+            )
+        }
+    };
+    let healthy = messages.len() == 1 && messages[0] == "ok";
+    let schema = schema_version(pool).await.ok();
+    let pending = schema.as_ref().map(|s| s.pending.len()).unwrap_or(0);
+    let details = serde_json::json!({
+        "integrity": messages,
+        "schema": schema,
+    });
+
+    if !healthy {
+        (
+            CheckStatus::Error,
+            "Database integrity check failed".to_string(),
+            details,
+        )
+    } else if pending > 0 {
+        (
+            CheckStatus::Warning,
+            format!("{} database migrations are pending", pending),
+            details,
+        )
+    } else {
+        (CheckStatus::Ok, "Database is healthy".to_string(), details)
+    }
+}
+
+/// Local model files as seen by the Whisper and Parakeet engines (size and
+/// format validation), and whether the configured model is usable
+pub async fn check_models(pool: &SqlitePool) -> (CheckStatus, String, serde_json::Value) {
+    let whisper = crate::whisper_engine::commands::whisper_get_available_models().await;
+    let parakeet = crate::parakeet_engine::commands::parakeet_get_available_models().await;
+
+    let mut models = Vec::new();
+    let mut corrupted = Vec::new();
+    if let Ok(list) = &whisper {
+        for model in list {
+            let status = serde_json::to_value(&model.status).unwrap_or_default();
+            if matches!(
+                model.status,
+                crate::whisper_engine::ModelStatus::Corrupted { .. }
+            ) {
+                corrupted.push(format!("whisper/{}", model.name));
+            }
+            if !matches!(model.status, crate::whisper_engine::ModelStatus::Missing) {
+                models.push(serde_json::json!({
+                    "engine": "localWhisper",
+                    "name": model.name,
+                    "path": model.path,
+                    "status": status,
+                }));
+            }
+        }
+    }
+    if let Ok(list) = &parakeet {
+        for model in list {
+            let status = serde_json::to_value(&model.status).unwrap_or_default();
+            if matches!(
+                model.status,
+                crate::parakeet_engine::ModelStatus::Corrupted { .. }
+            ) {
+                corrupted.push(format!("parakeet/{}", model.name));
+            }
+            if !matches!(model.status, crate::parakeet_engine::ModelStatus::Missing) {
+                models.push(serde_json::json!({
+                    "engine": "parakeet",
+                    "name": model.name,
+                    "path": model.path,
+                    "status": status,
+                }));
+            }
+        }
+    }
+
+    // Is the configured transcription model actually there? Engines that
+    // aren't initialized yet can't be asked, so they don't count against it.
+    let configured = SettingsRepository::get_transcript_config(pool)
+        .await
+        .ok()
+        .flatten();
+    let configured_ready = configured.as_ref().map(|config| {
+        let available = |engine: &str| {
+            models.iter().any(|m| {
+                m["engine"] == engine
+                    && m["name"] == config.model.as_str()
+                    && m["status"] == "Available"
+            })
+        };
+        match config.provider.as_str() {
+            "localWhisper" if whisper.is_ok() => available("localWhisper"),
+            "parakeet" if parakeet.is_ok() => available("parakeet"),
+            // Cloud providers need no local model
+            _ => true,
+        }
+    });
+
+    let details = serde_json::json!({
+        "configured": configured.as_ref().map(|c| serde_json::json!({
+            "provider": c.provider,
+            "model": c.model,
+            "ready": configured_ready,
+        })),
+        "installed": models,
+        "corrupted": corrupted,
+        "errors": [whisper.err(), parakeet.err()].into_iter().flatten().collect::<Vec<_>>(),
+    });
+
+    if configured_ready == Some(false) {
+        let config = configured.as_ref().map(|c| c.model.as_str()).unwrap_or("");
+        (
+            CheckStatus::Error,
+            format!(
+                "Configured model '{}' is not downloaded or is damaged",
+                config
+            ),
+            details,
+        )
+    } else if !corrupted.is_empty() {
+        (
+            CheckStatus::Warning,
+            format!("{} model files appear corrupted", corrupted.len()),
+            details,
+        )
+    } else {
+        (
+            CheckStatus::Ok,
+            format!("{} local models installed", models.len()),
+            details,
+        )
+    }
+}
+
+/// Free space on the volume holding `path` (the recordings folder)
+pub async fn check_disk_space(path: &Path) -> (CheckStatus, String, serde_json::Value) {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    // The most specific mount point containing the path
+    let disk = disks
+        .list()
+        .iter()
+        .filter(|d| path.starts_with(d.mount_point()))
+        .max_by_key(|d| d.mount_point().as_os_str().len());
+    let Some(disk) = disk else {
+        return (
+            CheckStatus::Skipped,
+            "Could not determine the disk holding the recordings folder".to_string(),
+            serde_json::json!({ "path": path }),
+        );
+    };
+
+    let available = disk.available_space();
+    let details = serde_json::json!({
+        "path": path,
+        "mount_point": disk.mount_point(),
+        "available_bytes": available,
+        "total_bytes": disk.total_space(),
+    });
+    let available_gb = available as f64 / (1024.0 * 1024.0 * 1024.0);
+    if available < LOW_DISK_SPACE_BYTES {
+        (
+            CheckStatus::Warning,
+            format!("Only {:.1} GB free for recordings", available_gb),
+            details,
+        )
+    } else {
+        (
+            CheckStatus::Ok,
+            format!("{:.1} GB free for recordings", available_gb),
+            details,
+        )
+    }
+}
+
+/// OS microphone permission and presence of an input device
+pub async fn check_microphone() -> (CheckStatus, String, serde_json::Value) {
+    use crate::audio::permissions::{check_microphone_permission, MicrophonePermission};
+
+    let permission = check_microphone_permission();
+    let device = crate::audio::default_input_device();
+    let details = serde_json::json!({
+        "permission": permission,
+        "default_input": device.as_ref().ok().map(|d| d.name.clone()),
+        "device_error": device.as_ref().err().map(|e| e.to_string()),
+    });
+
+    match (permission, device.is_ok()) {
+        (MicrophonePermission::Denied, _) => (
+            CheckStatus::Error,
+            "Microphone access is denied in system settings".to_string(),
+            details,
+        ),
+        (_, false) => (
+            CheckStatus::Error,
+            "No microphone found".to_string(),
+            details,
+        ),
+        (MicrophonePermission::NotDetermined, true) => (
+            CheckStatus::Warning,
+            "Microphone access has not been granted yet".to_string(),
+            details,
+        ),
+        _ => (
+            CheckStatus::Ok,
+            "Microphone is available".to_string(),
+            details,
+        ),
+    }
+}
+
+/// The optional Python backend. Its absence is only a warning: recording,
+/// transcription and local summaries work without it.
+pub async fn check_backend() -> (CheckStatus, String, serde_json::Value) {
+    let url = crate::api::api::APP_SERVER_URL;
+    let client = match reqwest::Client::builder().timeout(BACKEND_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(e) => {
+            return (
+                CheckStatus::Warning,
+                format!("Could not create HTTP client: {}", e),
+                serde_json::json!({ "url": url }),
+            )
+        }
+    };
+    let started = Instant::now();
+    match client.get(url).send().await {
+        Ok(response) => (
+            CheckStatus::Ok,
+            "Backend is reachable".to_string(),
+            serde_json::json!({
+                "url": url,
+                "http_status": response.status().as_u16(),
+                "latency_ms": started.elapsed().as_millis() as u64,
+            }),
+        ),
+        Err(e) => (
+            CheckStatus::Warning,
+            "Backend is not reachable".to_string(),
+            serde_json::json!({ "url": url, "error": e.to_string() }),
+        ),
+    }
+}
+
+/// The built-in summary sidecar. It starts on demand, so not running is fine.
+pub async fn check_sidecar() -> (CheckStatus, String, serde_json::Value) {
+    if crate::summary::summary_engine::is_sidecar_healthy().await {
+        (
+            CheckStatus::Ok,
+            "Summary engine is running".to_string(),
+            serde_json::json!({ "running": true }),
+        )
+    } else {
+        (
+            CheckStatus::Skipped,
+            "Summary engine is not running (it starts when needed)".to_string(),
+            serde_json::json!({ "running": false }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(status: CheckStatus) -> DiagnosticCheck {
+        DiagnosticCheck {
+            name: "test".to_string(),
+            status,
+            summary: String::new(),
+            details: serde_json::Value::Null,
+            duration_ms: 0,
+        }
+    }
+
+    #[test]
+    fn overall_is_worst_non_skipped_status() {
+        assert_eq!(overall_status(&[]), CheckStatus::Ok);
+        assert_eq!(
+            overall_status(&[check(CheckStatus::Ok), check(CheckStatus::Skipped)]),
+            CheckStatus::Ok
+        );
+        assert_eq!(
+            overall_status(&[
+                check(CheckStatus::Warning),
+                check(CheckStatus::Error),
+                check(CheckStatus::Ok)
+            ]),
+            CheckStatus::Error
+        );
+    }
+}
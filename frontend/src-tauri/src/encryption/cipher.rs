@@ -0,0 +1,212 @@
+//! AES-256-GCM primitives and the passphrase KDF.
+//!
+//! Encrypted column values are text: `enc:v1:` followed by base64 of
+//! nonce || ciphertext || tag, so they fit the existing TEXT columns and can
+//! be told apart from plaintext written before encryption was enabled.
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use super::EncryptionError;
+
+pub const ENCRYPTED_PREFIX: &str = "enc:v1:";
+pub const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const SALT_LEN: usize = 16;
+
+pub const BUNDLE_FORMAT: &str = "meetily-encrypted-bundle";
+const BUNDLE_VERSION: u32 = 1;
+
+pub type SecretKey = Zeroizing<[u8; KEY_LEN]>;
+
+/// Argon2id cost parameters, stored next to the salt so they can be raised
+/// later without breaking existing databases and bundles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KdfParams {
+    /// Memory in KiB
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        // OWASP recommendation for Argon2id
+        Self {
+            memory_kib: 19 * 1024,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+pub fn new_salt() -> String {
+    let mut salt = [0u8; SALT_LEN];
+    rand::rngs::OsRng.fill_bytes(&mut salt);
+    STANDARD.encode(salt)
+}
+
+/// Derives the AES key from a passphrase and a base64 salt
+pub fn derive_key(passphrase: &str, salt: &str, params: &KdfParams) -> Result<SecretKey, EncryptionError> {
+    let salt = STANDARD
+        .decode(salt)
+        .map_err(|e| EncryptionError::Corrupt(format!("invalid salt: {}", e)))?;
+    let params = Params::new(
+        params.memory_kib,
+        params.iterations,
+        params.parallelism,
+        Some(KEY_LEN),
+    )
+    .map_err(|e| EncryptionError::Corrupt(format!("invalid KDF parameters: {}", e)))?;
+
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), &salt, key.as_mut())
+        .map_err(|e| EncryptionError::Corrupt(format!("key derivation failed: {}", e)))?;
+    Ok(key)
+}
+
+pub fn is_encrypted(value: &str) -> bool {
+    value.starts_with(ENCRYPTED_PREFIX)
+}
+
+pub fn encrypt(key: &[u8; KEY_LEN], plaintext: &str) -> Result<String, EncryptionError> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_bytes())
+        .map_err(|_| EncryptionError::Corrupt("encryption failed".to_string()))?;
+
+    let mut payload = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+    payload.extend_from_slice(&nonce);
+    payload.extend_from_slice(&ciphertext);
+    Ok(format!("{}{}", ENCRYPTED_PREFIX, STANDARD.encode(payload)))
+}
+
+/// Decrypts a value produced by [`encrypt`]. A wrong key and a tampered value
+/// both fail authentication and are reported as a wrong passphrase.
+pub fn decrypt(key: &[u8; KEY_LEN], value: &str) -> Result<String, EncryptionError> {
+    let encoded = value
+        .strip_prefix(ENCRYPTED_PREFIX)
+        .ok_or_else(|| EncryptionError::Corrupt("value is not encrypted".to_string()))?;
+    let payload = STANDARD
+        .decode(encoded)
+        .map_err(|e| EncryptionError::Corrupt(format!("invalid ciphertext encoding: {}", e)))?;
+    if payload.len() < NONCE_LEN {
+        return Err(EncryptionError::Corrupt("ciphertext is truncated".to_string()));
+    }
+
+    let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+    let plaintext = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key))
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| EncryptionError::WrongPassphrase)?;
+    String::from_utf8(plaintext)
+        .map_err(|_| EncryptionError::Corrupt("decrypted value is not UTF-8".to_string()))
+}
+
+/// A passphrase-protected export file
+#[derive(Debug, Serialize, Deserialize)]
+struct Bundle {
+    format: String,
+    version: u32,
+    kdf: KdfParams,
+    salt: String,
+    payload: String,
+}
+
+/// Encrypts `content` under a key derived from `passphrase` with a fresh salt
+pub fn seal_bundle(passphrase: &str, content: &str) -> Result<String, EncryptionError> {
+    let kdf = KdfParams::default();
+    let salt = new_salt();
+    let key = derive_key(passphrase, &salt, &kdf)?;
+    let bundle = Bundle {
+        format: BUNDLE_FORMAT.to_string(),
+        version: BUNDLE_VERSION,
+        kdf,
+        salt,
+        payload: encrypt(&key, content)?,
+    };
+    serde_json::to_string_pretty(&bundle).map_err(|e| EncryptionError::Corrupt(e.to_string()))
+}
+
+/// Whether `content` is a bundle written by [`seal_bundle`]
+pub fn is_bundle(content: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(content)
+        .ok()
+        .and_then(|v| v.get("format").and_then(|f| f.as_str()).map(|f| f == BUNDLE_FORMAT))
+        .unwrap_or(false)
+}
+
+pub fn open_bundle(passphrase: &str, content: &str) -> Result<String, EncryptionError> {
+    let bundle: Bundle = serde_json::from_str(content)
+        .map_err(|e| EncryptionError::Corrupt(format!("invalid bundle: {}", e)))?;
+    if bundle.format != BUNDLE_FORMAT || bundle.version > BUNDLE_VERSION {
+        return Err(EncryptionError::Corrupt(format!(
+            "unsupported bundle {} v{}",
+            bundle.format, bundle.version
+        )));
+    }
+    let key = derive_key(passphrase, &bundle.salt, &bundle.kdf)?;
+    decrypt(&key, &bundle.payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cheap parameters so tests don't spend seconds in Argon2
+    const TEST_KDF: KdfParams = KdfParams {
+        memory_kib: 64,
+        iterations: 1,
+        parallelism: 1,
+    };
+
+    #[test]
+    fn round_trips_and_rejects_wrong_key() {
+        let salt = new_salt();
+        let key = derive_key("correct horse", &salt, &TEST_KDF).unwrap();
+        let other = derive_key("battery staple", &salt, &TEST_KDF).unwrap();
+
+        let sealed = encrypt(&key, "Quarterly numbers are confidential").unwrap();
+        assert!(is_encrypted(&sealed));
+        assert!(!sealed.contains("Quarterly"));
+        assert_eq!(decrypt(&key, &sealed).unwrap(), "Quarterly numbers are confidential");
+        assert!(matches!(decrypt(&other, &sealed), Err(EncryptionError::WrongPassphrase)));
+    }
+
+    #[test]
+    fn same_plaintext_encrypts_differently() {
+        let key = derive_key("pass phrase", &new_salt(), &TEST_KDF).unwrap();
+        assert_ne!(encrypt(&key, "hello").unwrap(), encrypt(&key, "hello").unwrap());
+    }
+
+    #[test]
+    fn tampered_values_fail() {
+        let key = derive_key("pass phrase", &new_salt(), &TEST_KDF).unwrap();
+        let sealed = encrypt(&key, "hello").unwrap();
+        let mut payload = STANDARD.decode(&sealed[ENCRYPTED_PREFIX.len()..]).unwrap();
+        let last = payload.len() - 1;
+        payload[last] ^= 1;
+        let tampered = format!("{}{}", ENCRYPTED_PREFIX, STANDARD.encode(payload));
+        assert!(decrypt(&key, &tampered).is_err());
+        assert!(decrypt(&key, "enc:v1:AAAA").is_err());
+        assert!(decrypt(&key, "plain text").is_err());
+    }
+
+    #[test]
+    fn bundles_round_trip() {
+        let bundle = seal_bundle("export pass", "{\"meetings\":[]}").unwrap();
+        assert!(is_bundle(&bundle));
+        assert!(!is_bundle("{\"format\":\"meetily-archive\"}"));
+        assert_eq!(open_bundle("export pass", &bundle).unwrap(), "{\"meetings\":[]}");
+        assert!(matches!(
+            open_bundle("wrong pass", &bundle),
+            Err(EncryptionError::WrongPassphrase)
+        ));
+    }
+}
@@ -0,0 +1,113 @@
+use log::{error as log_error, info as log_info, warn as log_warn};
+use tauri::{AppHandle, Emitter, Runtime};
+
+use super::{disable, enable, lock, status, unlock, EncryptionStatus};
+use crate::audit::{self, AuditAction};
+use crate::state::AppState;
+
+/// Emitted with the new [`EncryptionStatus`] whenever it changes
+pub const ENCRYPTION_STATUS_EVENT: &str = "encryption-status-changed";
+/// Emitted on startup when the database is encrypted and needs the passphrase
+pub const ENCRYPTION_LOCKED_EVENT: &str = "encryption-locked";
+
+fn emit_status<R: Runtime>(app: &AppHandle<R>) -> EncryptionStatus {
+    let status = status();
+    if let Err(e) = app.emit(ENCRYPTION_STATUS_EVENT, status) {
+        log_warn!("Failed to emit {} event: {}", ENCRYPTION_STATUS_EVENT, e);
+    }
+    status
+}
+
+#[tauri::command]
+pub async fn api_get_encryption_status<R: Runtime>(
+    _app: AppHandle<R>,
+    _state: tauri::State<'_, AppState>,
+) -> Result<EncryptionStatus, String> {
+    Ok(status())
+}
+
+/// Encrypts all transcript and summary text with a key derived from `passphrase`.
+/// The passphrase cannot be recovered; losing it loses the encrypted text.
+#[tauri::command]
+pub async fn api_enable_encryption<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    passphrase: String,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_enable_encryption called");
+    let pool = state.db_manager.pool();
+    let encrypted = enable(pool, &passphrase).await.map_err(|e| {
+        log_error!("Failed to enable encryption: {}", e);
+        e.to_string()
+    })?;
+
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "encryption",
+        None,
+        serde_json::json!({ "change": "enable", "values": encrypted }),
+    )
+    .await;
+    emit_status(&app);
+    Ok(serde_json::json!({
+        "status": "success",
+        "encryptedValues": encrypted
+    }))
+}
+
+/// Unlocks encrypted data for this session
+#[tauri::command]
+pub async fn api_unlock_encryption<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    passphrase: String,
+) -> Result<EncryptionStatus, String> {
+    log_info!("api_unlock_encryption called");
+    let pool = state.db_manager.pool();
+    unlock(pool, &passphrase).await.map_err(|e| e.to_string())?;
+    // Learned corrections are sealed and couldn't be loaded while locked
+    crate::audio::corrections::refresh_rules(pool).await;
+    Ok(emit_status(&app))
+}
+
+/// Forgets the key until the next unlock
+#[tauri::command]
+pub async fn api_lock_encryption<R: Runtime>(
+    app: AppHandle<R>,
+    _state: tauri::State<'_, AppState>,
+) -> Result<EncryptionStatus, String> {
+    log_info!("api_lock_encryption called");
+    lock();
+    crate::audio::corrections::clear_rules();
+    Ok(emit_status(&app))
+}
+
+/// Decrypts all data and turns encryption off
+#[tauri::command]
+pub async fn api_disable_encryption<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    passphrase: String,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_disable_encryption called");
+    let pool = state.db_manager.pool();
+    let decrypted = disable(pool, &passphrase).await.map_err(|e| {
+        log_error!("Failed to disable encryption: {}", e);
+        e.to_string()
+    })?;
+
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "encryption",
+        None,
+        serde_json::json!({ "change": "disable", "values": decrypted }),
+    )
+    .await;
+    emit_status(&app);
+    Ok(serde_json::json!({
+        "status": "success",
+        "decryptedValues": decrypted
+    }))
+}
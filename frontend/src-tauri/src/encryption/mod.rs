@@ -0,0 +1,318 @@
+/// Encryption module - optional encryption at rest for transcript and summary text
+///
+/// This module contains:
+/// - AES-256-GCM and Argon2id primitives (`cipher`)
+/// - The in-memory key, set by unlocking with the passphrase
+/// - `seal`/`open`, used by repositories when writing and reading encrypted columns
+/// - Enabling/disabling, which rewrites every encrypted column in one transaction
+/// - Tauri commands for frontend integration
+///
+/// The passphrase and key are never stored. The database keeps only the salt,
+/// the KDF parameters and an encrypted verifier used to check the passphrase.
+/// While locked, reads of encrypted values and all writes to encrypted columns
+/// fail with [`EncryptionError::Locked`].
+pub mod cipher;
+pub mod commands;
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, Error as SqlxError, Row, SqliteConnection, SqlitePool};
+use std::sync::RwLock;
+
+use crate::database::repositories::integration::IntegrationSettingsRepository;
+use crate::database::repositories::redaction::RedactionMappingsRepository;
+use cipher::{KdfParams, SecretKey};
+
+pub const CONFIG_ID: &str = "encryption";
+pub const MIN_PASSPHRASE_LEN: usize = 8;
+const VERIFIER_PLAINTEXT: &str = "meetily-encryption-check";
+
+/// Columns holding transcript and summary text, as (table, column)
+pub const ENCRYPTED_COLUMNS: [(&str, &str); 11] = [
+    ("transcripts", "transcript"),
+    ("transcript_chunks", "transcript_text"),
+    ("summary_processes", "result"),
+    ("summary_processes", "result_backup"),
+    ("keyword_hits", "segment_text"),
+    ("segment_comments", "text"),
+    ("correction_rules", "wrong"),
+    ("correction_rules", "corrected"),
+    ("digests", "content"),
+    ("partial_summaries", "content"),
+    ("meeting_delta_reports", "report"),
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum EncryptionError {
+    #[error("Encrypted data is locked; unlock it with your passphrase first")]
+    Locked,
+    #[error("Wrong passphrase")]
+    WrongPassphrase,
+    #[error("Encryption is already enabled")]
+    AlreadyEnabled,
+    #[error("Encryption is not enabled")]
+    NotEnabled,
+    #[error("Passphrase must be at least {0} characters")]
+    WeakPassphrase(usize),
+    #[error("{0} reversible redactions depend on encryption; discard them before turning it off")]
+    RedactionMappingsExist(i64),
+    #[error("Encrypted data is corrupt: {0}")]
+    Corrupt(String),
+    #[error("Database error: {0}")]
+    Database(#[from] SqlxError),
+}
+
+impl From<EncryptionError> for SqlxError {
+    fn from(error: EncryptionError) -> Self {
+        match error {
+            EncryptionError::Database(e) => e,
+            other => SqlxError::Protocol(other.to_string()),
+        }
+    }
+}
+
+enum KeyState {
+    Disabled,
+    Locked,
+    Unlocked(SecretKey),
+}
+
+static KEY_STATE: Lazy<RwLock<KeyState>> = Lazy::new(|| RwLock::new(KeyState::Disabled));
+
+fn set_state(state: KeyState) {
+    *KEY_STATE.write().unwrap_or_else(|e| e.into_inner()) = state;
+}
+
+/// Stored under the "encryption" integration id; its presence means encryption is on
+#[derive(Debug, Serialize, Deserialize)]
+struct EncryptionConfig {
+    kdf: KdfParams,
+    salt: String,
+    verifier: String,
+    enabled_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct EncryptionStatus {
+    pub enabled: bool,
+    pub unlocked: bool,
+}
+
+pub fn status() -> EncryptionStatus {
+    match &*KEY_STATE.read().unwrap_or_else(|e| e.into_inner()) {
+        KeyState::Disabled => EncryptionStatus { enabled: false, unlocked: true },
+        KeyState::Locked => EncryptionStatus { enabled: true, unlocked: false },
+        KeyState::Unlocked(_) => EncryptionStatus { enabled: true, unlocked: true },
+    }
+}
+
+pub fn is_enabled() -> bool {
+    status().enabled
+}
+
+/// Prepares text for an encrypted column: encrypted when encryption is on,
+/// unchanged when it is off
+pub fn seal(plaintext: &str) -> Result<String, EncryptionError> {
+    match &*KEY_STATE.read().unwrap_or_else(|e| e.into_inner()) {
+        KeyState::Disabled => Ok(plaintext.to_string()),
+        KeyState::Locked => Err(EncryptionError::Locked),
+        KeyState::Unlocked(key) => cipher::encrypt(key, plaintext),
+    }
+}
+
+pub fn seal_opt(plaintext: Option<&str>) -> Result<Option<String>, EncryptionError> {
+    plaintext.map(seal).transpose()
+}
+
+/// Reads a value from an encrypted column. Plaintext written before encryption
+/// was enabled passes through.
+pub fn open(value: String) -> Result<String, EncryptionError> {
+    if !cipher::is_encrypted(&value) {
+        return Ok(value);
+    }
+    match &*KEY_STATE.read().unwrap_or_else(|e| e.into_inner()) {
+        KeyState::Unlocked(key) => cipher::decrypt(key, &value),
+        _ => Err(EncryptionError::Locked),
+    }
+}
+
+pub fn open_opt(value: Option<String>) -> Result<Option<String>, EncryptionError> {
+    value.map(open).transpose()
+}
+
+async fn load_config(pool: &SqlitePool) -> Result<Option<EncryptionConfig>, EncryptionError> {
+    let Some(raw) = IntegrationSettingsRepository::get_config(pool, CONFIG_ID).await? else {
+        return Ok(None);
+    };
+    serde_json::from_str(&raw)
+        .map(Some)
+        .map_err(|e| EncryptionError::Corrupt(format!("invalid encryption settings: {}", e)))
+}
+
+/// Derives the key and checks it against the stored verifier
+fn verified_key(config: &EncryptionConfig, passphrase: &str) -> Result<SecretKey, EncryptionError> {
+    let key = cipher::derive_key(passphrase, &config.salt, &config.kdf)?;
+    if cipher::decrypt(&key, &config.verifier)? != VERIFIER_PLAINTEXT {
+        return Err(EncryptionError::WrongPassphrase);
+    }
+    Ok(key)
+}
+
+/// Checks the passphrase without changing the lock state, for actions that
+/// need it re-entered while unlocked
+pub async fn verify_passphrase(pool: &SqlitePool, passphrase: &str) -> Result<(), EncryptionError> {
+    let config = load_config(pool).await?.ok_or(EncryptionError::NotEnabled)?;
+    verified_key(&config, passphrase).map(|_| ())
+}
+
+/// Sets the initial state for a freshly opened database: locked when
+/// encryption is enabled, disabled otherwise
+pub async fn load_state(pool: &SqlitePool) -> Result<(), SqlxError> {
+    let enabled = IntegrationSettingsRepository::get_config(pool, CONFIG_ID)
+        .await?
+        .is_some();
+    set_state(if enabled { KeyState::Locked } else { KeyState::Disabled });
+    if enabled {
+        log::info!("Encrypted database opened; waiting for unlock");
+    }
+    Ok(())
+}
+
+pub async fn unlock(pool: &SqlitePool, passphrase: &str) -> Result<(), EncryptionError> {
+    let config = load_config(pool).await?.ok_or(EncryptionError::NotEnabled)?;
+    let key = verified_key(&config, passphrase)?;
+    set_state(KeyState::Unlocked(key));
+    log::info!("Encrypted data unlocked");
+    Ok(())
+}
+
+/// Drops the key from memory
+pub fn lock() {
+    let mut state = KEY_STATE.write().unwrap_or_else(|e| e.into_inner());
+    if matches!(*state, KeyState::Unlocked(_)) {
+        *state = KeyState::Locked;
+        log::info!("Encrypted data locked");
+    }
+}
+
+/// Applies `transform` to every non-null encrypted column value, writing back
+/// the values it returns. Returns the number of values rewritten.
+async fn rewrite_columns<F>(conn: &mut SqliteConnection, transform: F) -> Result<usize, EncryptionError>
+where
+    F: Fn(&str) -> Result<Option<String>, EncryptionError>,
+{
+    let mut rewritten = 0;
+    for (table, column) in ENCRYPTED_COLUMNS {
+        let rows = sqlx::query(&format!(
+            "SELECT rowid, {column} FROM {table} WHERE {column} IS NOT NULL"
+        ))
+        .fetch_all(&mut *conn)
+        .await?;
+        for row in rows {
+            let rowid: i64 = row.get(0);
+            let value: String = row.get(1);
+            if let Some(new_value) = transform(&value)? {
+                sqlx::query(&format!("UPDATE {table} SET {column} = ? WHERE rowid = ?"))
+                    .bind(new_value)
+                    .bind(rowid)
+                    .execute(&mut *conn)
+                    .await?;
+                rewritten += 1;
+            }
+        }
+    }
+    Ok(rewritten)
+}
+
+/// Turns encryption on and encrypts all existing transcript and summary text.
+/// Returns the number of values encrypted.
+pub async fn enable(pool: &SqlitePool, passphrase: &str) -> Result<usize, EncryptionError> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        return Err(EncryptionError::WeakPassphrase(MIN_PASSPHRASE_LEN));
+    }
+    if load_config(pool).await?.is_some() {
+        return Err(EncryptionError::AlreadyEnabled);
+    }
+
+    let kdf = KdfParams::default();
+    let salt = cipher::new_salt();
+    let key = cipher::derive_key(passphrase, &salt, &kdf)?;
+    let config = EncryptionConfig {
+        kdf,
+        verifier: cipher::encrypt(&key, VERIFIER_PLAINTEXT)?,
+        salt,
+        enabled_at: Utc::now(),
+    };
+    let raw = serde_json::to_string(&config).map_err(|e| EncryptionError::Corrupt(e.to_string()))?;
+
+    let mut conn = pool.acquire().await?;
+    let mut transaction = conn.begin().await?;
+    // Writing the config first takes the write lock, so no transcript can be
+    // inserted between reading the plaintext and committing
+    IntegrationSettingsRepository::save_config(&mut *transaction, CONFIG_ID, &raw).await?;
+    let encrypted = rewrite_columns(&mut transaction, |value| {
+        if cipher::is_encrypted(value) {
+            Ok(None)
+        } else {
+            cipher::encrypt(&key, value).map(Some)
+        }
+    })
+    .await?;
+    // Undo before-images hold plaintext copies of deleted rows
+    sqlx::query("DELETE FROM operation_journal")
+        .execute(&mut *transaction)
+        .await?;
+
+    set_state(KeyState::Unlocked(key));
+    if let Err(e) = transaction.commit().await {
+        set_state(KeyState::Disabled);
+        return Err(e.into());
+    }
+    log::info!("Encryption enabled; encrypted {} values", encrypted);
+
+    // Plaintext can survive in free pages and the WAL until they are rewritten
+    if let Err(e) = sqlx::query("VACUUM").execute(pool).await {
+        log::warn!("VACUUM after enabling encryption failed: {}", e);
+    }
+    if let Err(e) = sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)").execute(pool).await {
+        log::warn!("WAL checkpoint after enabling encryption failed: {}", e);
+    }
+    Ok(encrypted)
+}
+
+/// Decrypts all encrypted values and turns encryption off. Requires the
+/// passphrase even when already unlocked. Returns the number of values decrypted.
+pub async fn disable(pool: &SqlitePool, passphrase: &str) -> Result<usize, EncryptionError> {
+    let config = load_config(pool).await?.ok_or(EncryptionError::NotEnabled)?;
+    let key = verified_key(&config, passphrase)?;
+    // The originals would otherwise end up in plaintext
+    let mappings = RedactionMappingsRepository::count(pool).await?;
+    if mappings > 0 {
+        return Err(EncryptionError::RedactionMappingsExist(mappings));
+    }
+
+    let mut conn = pool.acquire().await?;
+    let mut transaction = conn.begin().await?;
+    IntegrationSettingsRepository::delete_config(&mut *transaction, CONFIG_ID).await?;
+    let decrypted = rewrite_columns(&mut transaction, |value| {
+        if cipher::is_encrypted(value) {
+            cipher::decrypt(&key, value).map(Some)
+        } else {
+            Ok(None)
+        }
+    })
+    .await?;
+    // Before-images would restore ciphertext nothing can read any more
+    sqlx::query("DELETE FROM operation_journal")
+        .execute(&mut *transaction)
+        .await?;
+
+    set_state(KeyState::Disabled);
+    if let Err(e) = transaction.commit().await {
+        set_state(KeyState::Unlocked(key));
+        return Err(e.into());
+    }
+    log::info!("Encryption disabled; decrypted {} values", decrypted);
+    Ok(decrypted)
+}
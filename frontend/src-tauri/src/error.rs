@@ -0,0 +1,225 @@
+//! The error type of Tauri commands.
+//!
+//! A command failing with a bare string leaves the frontend to guess from
+//! the wording what went wrong. [`AppError`] serializes as
+//! `{ "code": "NOT_FOUND", "message": "...", "retryable": false }`: `code`
+//! is stable for handling, `message` is shown to the user, and `retryable`
+//! says whether the same call may succeed if tried again (a busy meeting, a
+//! database under load, an unreachable server).
+//!
+//! Commands not yet migrated still return `String`; helpers they share keep
+//! returning `String` too, and `?` turns those into [`AppError::Internal`].
+
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use sqlx::Error as SqlxError;
+
+use crate::database::repositories::meeting::CONFLICT_CODE;
+use crate::locks::{MeetingBusy, BUSY_CODE};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AppError {
+    /// The request itself is wrong: a missing field, a malformed value
+    #[error("{0}")]
+    InvalidInput(String),
+    #[error("{0}")]
+    NotFound(String),
+    /// A recording, import or re-transcription holds the meeting
+    #[error("{0}")]
+    Busy(String),
+    /// The row changed since the version the caller read; reload before
+    /// saving again
+    #[error("{0}")]
+    Conflict(String),
+    /// Not allowed in the current state: a legal hold, finalized minutes,
+    /// locked encryption
+    #[error("{0}")]
+    Rejected(String),
+    /// The database or app can't take the request right now
+    #[error("{0}")]
+    Unavailable(String),
+    #[error("{0}")]
+    Network(String),
+    #[error("{0}")]
+    Database(String),
+    #[error("{0}")]
+    Io(String),
+    #[error("{0}")]
+    Internal(String),
+}
+
+impl AppError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidInput(_) => "INVALID_INPUT",
+            Self::NotFound(_) => "NOT_FOUND",
+            Self::Busy(_) => BUSY_CODE,
+            Self::Conflict(_) => CONFLICT_CODE,
+            Self::Rejected(_) => "REJECTED",
+            Self::Unavailable(_) => "UNAVAILABLE",
+            Self::Network(_) => "NETWORK_ERROR",
+            Self::Database(_) => "DATABASE_ERROR",
+            Self::Io(_) => "IO_ERROR",
+            Self::Internal(_) => "INTERNAL_ERROR",
+        }
+    }
+
+    /// Whether trying the same call again later may succeed
+    pub fn retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::Busy(_) | Self::Unavailable(_) | Self::Network(_)
+        )
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            Self::InvalidInput(m)
+            | Self::NotFound(m)
+            | Self::Busy(m)
+            | Self::Conflict(m)
+            | Self::Rejected(m)
+            | Self::Unavailable(m)
+            | Self::Network(m)
+            | Self::Database(m)
+            | Self::Io(m)
+            | Self::Internal(m) => m,
+        }
+    }
+
+    /// Puts `context` in front of the message, keeping the code
+    pub fn context(self, context: &str) -> Self {
+        let wrap = |m: String| format!("{}: {}", context, m);
+        match self {
+            Self::InvalidInput(m) => Self::InvalidInput(wrap(m)),
+            Self::NotFound(m) => Self::NotFound(wrap(m)),
+            Self::Busy(m) => Self::Busy(wrap(m)),
+            Self::Conflict(m) => Self::Conflict(wrap(m)),
+            Self::Rejected(m) => Self::Rejected(wrap(m)),
+            Self::Unavailable(m) => Self::Unavailable(wrap(m)),
+            Self::Network(m) => Self::Network(wrap(m)),
+            Self::Database(m) => Self::Database(wrap(m)),
+            Self::Io(m) => Self::Io(wrap(m)),
+            Self::Internal(m) => Self::Internal(wrap(m)),
+        }
+    }
+}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut error = serializer.serialize_struct("AppError", 3)?;
+        error.serialize_field("code", self.code())?;
+        error.serialize_field("message", self.message())?;
+        error.serialize_field("retryable", &self.retryable())?;
+        error.end()
+    }
+}
+
+impl From<String> for AppError {
+    fn from(message: String) -> Self {
+        Self::Internal(message)
+    }
+}
+
+impl From<&str> for AppError {
+    fn from(message: &str) -> Self {
+        Self::Internal(message.to_string())
+    }
+}
+
+/// Lets commands still returning `String` use checks that fail with
+/// [`AppError`] through `?`
+impl From<AppError> for String {
+    fn from(error: AppError) -> Self {
+        error.to_string()
+    }
+}
+
+impl From<MeetingBusy> for AppError {
+    fn from(error: MeetingBusy) -> Self {
+        Self::from(SqlxError::from(error))
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error.to_string())
+    }
+}
+
+impl From<reqwest::Error> for AppError {
+    fn from(error: reqwest::Error) -> Self {
+        Self::Network(error.to_string())
+    }
+}
+
+/// Repository rule violations (legal holds, finalized minutes, busy
+/// meetings) arrive as `Protocol` errors carrying the user-facing message
+impl From<SqlxError> for AppError {
+    fn from(error: SqlxError) -> Self {
+        match error {
+            SqlxError::RowNotFound => Self::NotFound("Not found".to_string()),
+            SqlxError::PoolTimedOut | SqlxError::PoolClosed => {
+                Self::Unavailable("The database is busy; try again".to_string())
+            }
+            SqlxError::Protocol(message) => {
+                let rest = |code: &str| {
+                    message
+                        .strip_prefix(code)
+                        .and_then(|rest| rest.strip_prefix(": "))
+                        .map(str::to_string)
+                };
+                if let Some(rest) = rest(BUSY_CODE) {
+                    Self::Busy(rest)
+                } else if let Some(rest) = rest(CONFLICT_CODE) {
+                    Self::Conflict(rest)
+                } else {
+                    Self::Rejected(message)
+                }
+            }
+            SqlxError::Database(e) if e.message().contains("database is locked") => {
+                Self::Unavailable("The database is busy; try again".to_string())
+            }
+            other => Self::Database(other.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::meeting::VersionConflict;
+    use crate::locks::MeetingActivity;
+
+    #[test]
+    fn errors_serialize_with_code_and_retryability() {
+        let busy = AppError::from(MeetingBusy {
+            key: "meeting-1".to_string(),
+            activity: MeetingActivity::Recording,
+        })
+        .context("Failed to delete meeting");
+        assert_eq!(
+            serde_json::to_value(&busy).unwrap(),
+            serde_json::json!({
+                "code": "RESOURCE_BUSY",
+                "message": "Failed to delete meeting: this meeting is being recorded right now; try again when it has finished",
+                "retryable": true,
+            })
+        );
+
+        let held = AppError::from(SqlxError::Protocol("Meeting is under legal hold".into()));
+        assert_eq!(held.code(), "REJECTED");
+        assert!(!held.retryable());
+        assert_eq!(AppError::from(SqlxError::RowNotFound).code(), "NOT_FOUND");
+        let conflict = AppError::from(SqlxError::from(VersionConflict {
+            meeting_id: "meeting-1".to_string(),
+            expected: 3,
+            current: 4,
+        }));
+        assert_eq!(conflict.code(), "VERSION_CONFLICT");
+        assert!(!conflict.retryable());
+        assert!(conflict.message().starts_with("this meeting was changed elsewhere"));
+        assert_eq!(AppError::from("boom".to_string()).code(), "INTERNAL_ERROR");
+        let invalid = AppError::InvalidInput("meeting_id is not a valid id".to_string());
+        assert_eq!(String::from(invalid), "meeting_id is not a valid id");
+    }
+}
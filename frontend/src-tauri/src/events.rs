@@ -0,0 +1,402 @@
+//! Typed events sent to the frontend.
+//!
+//! Each event is a struct implementing [`AppEvent`], which fixes its name;
+//! [`emit`] sends it and keeps a copy in a short history, so a window that
+//! opens after an import finished or a recording failed can catch up with
+//! `api_replay_recent_events`. Payloads keep the shapes the frontend already
+//! listens for; [`EVENT_VERSION`] is recorded with each copy and goes up
+//! when one of them changes incompatibly.
+//!
+//! Progress events fire many times a second and are not kept, and neither
+//! are events that trigger work in the window receiving them (saving a
+//! stopped recording), so a replay can't run that work twice.
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Runtime};
+
+use crate::audio::keyword_alerts::{KeywordAlert, KEYWORD_ALERT_EVENT};
+use crate::audio::recording_status::{RecordingStatus, RECORDING_STATUS_EVENT};
+use crate::audio::transcription::TranscriptUpdate;
+use crate::database::repositories::digest::Digest;
+use crate::jobs::{Job, JOB_PROGRESS_EVENT};
+use crate::summary::digest::DIGEST_EVENT;
+
+pub const EVENT_VERSION: u32 = 1;
+
+/// How many events the history keeps
+const HISTORY_LEN: usize = 200;
+
+static HISTORY: Lazy<Mutex<EventHistory>> =
+    Lazy::new(|| Mutex::new(EventHistory::new(HISTORY_LEN)));
+
+pub trait AppEvent: Serialize {
+    const NAME: &'static str;
+    /// Whether windows opened later can replay it
+    const REPLAYABLE: bool = true;
+}
+
+/// A copy of an emitted event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventRecord {
+    /// Increases with every kept event; pass the last one seen as `since`
+    pub seq: u64,
+    pub name: &'static str,
+    pub version: u32,
+    pub emitted_at: DateTime<Utc>,
+    pub payload: Value,
+}
+
+struct EventHistory {
+    capacity: usize,
+    next_seq: u64,
+    events: VecDeque<EventRecord>,
+}
+
+impl EventHistory {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            next_seq: 1,
+            events: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    fn record<E: AppEvent>(&mut self, event: &E) {
+        let Ok(payload) = serde_json::to_value(event) else {
+            return;
+        };
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(EventRecord {
+            seq: self.next_seq,
+            name: E::NAME,
+            version: EVENT_VERSION,
+            emitted_at: Utc::now(),
+            payload,
+        });
+        self.next_seq += 1;
+    }
+
+    fn since(&self, since: u64, names: Option<&[String]>) -> Vec<EventRecord> {
+        self.events
+            .iter()
+            .filter(|record| record.seq > since)
+            .filter(|record| names.map_or(true, |names| names.iter().any(|n| n == record.name)))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Sends `event` to every window and keeps it for replay
+pub fn emit<R: Runtime, E: AppEvent>(app: &impl Emitter<R>, event: &E) -> tauri::Result<()> {
+    if E::REPLAYABLE {
+        HISTORY
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .record(event);
+    }
+    app.emit(E::NAME, event)
+}
+
+// Recording
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingStarted {
+    pub message: String,
+    pub devices: Vec<String>,
+    pub workers: u32,
+}
+
+impl AppEvent for RecordingStarted {
+    const NAME: &'static str = "recording-started";
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingPaused {
+    pub message: String,
+}
+
+impl AppEvent for RecordingPaused {
+    const NAME: &'static str = "recording-paused";
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingResumed {
+    pub message: String,
+}
+
+impl AppEvent for RecordingResumed {
+    const NAME: &'static str = "recording-resumed";
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingShutdownProgress {
+    pub stage: &'static str,
+    pub message: String,
+    pub progress: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detailed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elapsed_seconds: Option<u64>,
+}
+
+impl RecordingShutdownProgress {
+    pub fn new(stage: &'static str, message: impl Into<String>, progress: u8) -> Self {
+        Self {
+            stage,
+            message: message.into(),
+            progress,
+            detailed: None,
+            elapsed_seconds: None,
+        }
+    }
+}
+
+impl AppEvent for RecordingShutdownProgress {
+    const NAME: &'static str = "recording-shutdown-progress";
+    const REPLAYABLE: bool = false;
+}
+
+/// Recording stopped; the frontend saves the transcript on receiving it
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingStopped {
+    pub message: String,
+    pub folder_path: Option<String>,
+    pub meeting_name: Option<String>,
+}
+
+impl AppEvent for RecordingStopped {
+    const NAME: &'static str = "recording-stopped";
+    const REPLAYABLE: bool = false;
+}
+
+/// Sent by the tray once it has stopped a recording
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingStopComplete(pub bool);
+
+impl AppEvent for RecordingStopComplete {
+    const NAME: &'static str = "recording-stop-complete";
+    const REPLAYABLE: bool = false;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingSaved {
+    pub audio_file: String,
+    pub transcript_file: Option<String>,
+    pub meeting_name: Option<String>,
+    pub meeting_folder: Option<String>,
+}
+
+impl AppEvent for RecordingSaved {
+    const NAME: &'static str = "recording-saved";
+}
+
+/// The user-facing message of a failure during recording
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingError(pub String);
+
+impl AppEvent for RecordingError {
+    const NAME: &'static str = "recording-error";
+}
+
+/// The recorder overlay opened or closed
+#[derive(Debug, Clone, Serialize)]
+pub struct OverlayVisibility {
+    pub open: bool,
+}
+
+impl AppEvent for OverlayVisibility {
+    const NAME: &'static str = "overlay-visibility";
+}
+
+/// Elapsed and recorded time of the running recording, every few seconds
+impl AppEvent for RecordingStatus {
+    const NAME: &'static str = RECORDING_STATUS_EVENT;
+    const REPLAYABLE: bool = false;
+}
+
+/// A watched keyword was heard
+impl AppEvent for KeywordAlert {
+    const NAME: &'static str = KEYWORD_ALERT_EVENT;
+}
+
+// Transcription
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptionError {
+    pub error: String,
+    pub user_message: String,
+    /// Whether the frontend should offer a fix (a modal) rather than a toast
+    pub actionable: bool,
+}
+
+impl AppEvent for TranscriptionError {
+    const NAME: &'static str = "transcription-error";
+}
+
+/// First speech of a recording session
+#[derive(Debug, Clone, Serialize)]
+pub struct SpeechDetected {
+    pub message: String,
+}
+
+impl AppEvent for SpeechDetected {
+    const NAME: &'static str = "speech-detected";
+    const REPLAYABLE: bool = false;
+}
+
+/// A transcribed segment, partial or final
+impl AppEvent for TranscriptUpdate {
+    const NAME: &'static str = "transcript-update";
+    const REPLAYABLE: bool = false;
+}
+
+/// A chunk failed to transcribe; recording goes on
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptionWarning(pub String);
+
+impl AppEvent for TranscriptionWarning {
+    const NAME: &'static str = "transcription-warning";
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptionProgress {
+    pub worker_id: usize,
+    pub chunks_completed: u64,
+    pub chunks_queued: u64,
+    pub progress_percentage: u32,
+    pub message: String,
+}
+
+impl AppEvent for TranscriptionProgress {
+    const NAME: &'static str = "transcription-progress";
+    const REPLAYABLE: bool = false;
+}
+
+/// Recording input ended; the workers are finishing the queued chunks
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptionQueueComplete {
+    pub total_chunks: u64,
+    pub message: String,
+}
+
+impl AppEvent for TranscriptionQueueComplete {
+    const NAME: &'static str = "transcription-queue-complete";
+}
+
+/// Fewer chunks were transcribed than were queued when recording stopped
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptChunkLoss {
+    pub chunks_queued: u64,
+    pub chunks_completed: u64,
+    pub chunks_lost: u64,
+    pub message: String,
+}
+
+impl AppEvent for TranscriptChunkLoss {
+    const NAME: &'static str = "transcript-chunk-loss-detected";
+}
+
+// Import
+
+/// Progress of the transcription step of an import
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportProgress {
+    pub title: String,
+    pub done: usize,
+    pub total: usize,
+}
+
+impl AppEvent for ImportProgress {
+    const NAME: &'static str = "import-progress";
+    const REPLAYABLE: bool = false;
+}
+
+/// Progress of downloading a recording imported from a link
+#[derive(Debug, Clone, Serialize)]
+pub struct UrlDownloadProgress {
+    pub url: String,
+    pub downloaded: u64,
+    /// None when the server doesn't say how large the file is
+    pub total: Option<u64>,
+}
+
+impl AppEvent for UrlDownloadProgress {
+    const NAME: &'static str = "url-download-progress";
+    const REPLAYABLE: bool = false;
+}
+
+// Summary
+
+/// A summary generation finished; `error` is set when it failed
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SummaryFinished {
+    pub meeting_id: String,
+    pub title: String,
+    pub error: Option<String>,
+}
+
+impl AppEvent for SummaryFinished {
+    const NAME: &'static str = "summary-finished";
+}
+
+/// The digest schedule created a digest
+impl AppEvent for Digest {
+    const NAME: &'static str = DIGEST_EVENT;
+}
+
+// Jobs
+
+/// Every change of a background job; jobs are listed from the database
+impl AppEvent for Job {
+    const NAME: &'static str = JOB_PROGRESS_EVENT;
+    const REPLAYABLE: bool = false;
+}
+
+/// Events kept since `since` (a `seq`; all kept events when omitted),
+/// optionally only those named in `names`, oldest first
+#[tauri::command]
+pub async fn api_replay_recent_events<R: Runtime>(
+    _app: AppHandle<R>,
+    since: Option<u64>,
+    names: Option<Vec<String>>,
+) -> Result<Vec<EventRecord>, String> {
+    Ok(HISTORY
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .since(since.unwrap_or(0), names.as_deref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn history_keeps_the_latest_replayable_events() {
+        let mut history = EventHistory::new(2);
+        for message in ["one", "two", "three"] {
+            history.record(&RecordingPaused {
+                message: message.to_string(),
+            });
+        }
+        history.record(&RecordingError("Microphone disconnected".to_string()));
+
+        let all = history.since(0, None);
+        assert_eq!(all.iter().map(|r| r.seq).collect::<Vec<_>>(), vec![3, 4]);
+        assert_eq!(all[0].payload, serde_json::json!({ "message": "three" }));
+        assert_eq!(all[1].payload, serde_json::json!("Microphone disconnected"));
+
+        let errors = history.since(0, Some(&["recording-error".to_string()]));
+        assert_eq!(errors.len(), 1);
+        assert!(history.since(4, None).is_empty());
+    }
+}
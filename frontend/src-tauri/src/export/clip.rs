@@ -0,0 +1,169 @@
+//! Audio excerpts: cuts a time range out of a meeting recording with FFmpeg
+//! and renders the transcript segments inside it as SRT captions.
+
+use std::path::Path;
+
+use crate::api::MeetingTranscript;
+use crate::audio::import::ffmpeg_command;
+
+/// Formats a clip can be written as; FFmpeg picks the encoder from the extension
+pub const CLIP_EXTENSIONS: [&str; 6] = ["m4a", "mp3", "wav", "ogg", "opus", "flac"];
+/// Clips are meant for sharing a moment, not re-exporting the meeting
+pub const MAX_CLIP_SECONDS: f64 = 30.0 * 60.0;
+
+pub fn validate_range(start: f64, end: f64) -> Result<(), String> {
+    if !start.is_finite() || !end.is_finite() || start < 0.0 {
+        return Err("Clip start and end must be positive times in seconds".to_string());
+    }
+    if end <= start {
+        return Err("Clip end must be after its start".to_string());
+    }
+    if end - start > MAX_CLIP_SECONDS {
+        return Err(format!(
+            "Clips can be at most {} minutes long",
+            MAX_CLIP_SECONDS / 60.0
+        ));
+    }
+    Ok(())
+}
+
+pub fn validate_clip_path(path: &Path) -> Result<(), String> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase)
+        .unwrap_or_default();
+    if !CLIP_EXTENSIONS.contains(&extension.as_str()) {
+        return Err(format!(
+            "Unsupported clip format '{}'; use one of: {}",
+            extension,
+            CLIP_EXTENSIONS.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+/// Cuts `start..end` (seconds) of `source` into `destination`, audio only.
+/// Runs FFmpeg, so call it off the async runtime.
+pub fn cut_clip(source: &Path, destination: &Path, start: f64, end: f64) -> Result<(), String> {
+    let output = ffmpeg_command()
+        .map_err(|e| e.to_string())?
+        .args(["-y", "-hide_banner", "-loglevel", "error", "-ss"])
+        .arg(format!("{:.3}", start))
+        .arg("-i")
+        .arg(source)
+        .arg("-t")
+        .arg(format!("{:.3}", end - start))
+        .arg("-vn")
+        .arg(destination)
+        .output()
+        .map_err(|e| format!("Failed to run FFmpeg: {}", e))?;
+    if !output.status.success() {
+        let _ = std::fs::remove_file(destination);
+        return Err(format!(
+            "FFmpeg failed to cut the clip: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+/// `HH:MM:SS,mmm`
+fn srt_timestamp(seconds: f64) -> String {
+    let millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02},{:03}",
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        millis % 1000
+    )
+}
+
+/// SRT captions for the segments overlapping `start..end`, with times
+/// relative to the clip. Segments without recording timestamps are skipped.
+pub fn render_srt(segments: &[MeetingTranscript], start: f64, end: f64) -> String {
+    let mut cues: Vec<(f64, f64, &str)> = segments
+        .iter()
+        .filter_map(|segment| {
+            let segment_start = segment.audio_start_time?;
+            let segment_end = segment
+                .audio_end_time
+                .or_else(|| segment.duration.map(|d| segment_start + d))
+                .unwrap_or(segment_start);
+            let text = segment.text.trim();
+            (segment_end > start && segment_start < end && !text.is_empty()).then_some((
+                segment_start.max(start) - start,
+                segment_end.min(end) - start,
+                text,
+            ))
+        })
+        .collect();
+    cues.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    cues.iter()
+        .enumerate()
+        .map(|(i, (cue_start, cue_end, text))| {
+            format!(
+                "{}\n{} --> {}\n{}\n",
+                i + 1,
+                srt_timestamp(*cue_start),
+                srt_timestamp(*cue_end),
+                text
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(text: &str, start: Option<f64>, end: Option<f64>) -> MeetingTranscript {
+        MeetingTranscript {
+            id: text.to_string(),
+            text: text.to_string(),
+            timestamp: String::new(),
+            audio_start_time: start,
+            audio_end_time: end,
+            duration: None,
+            speaker: None,
+            uncertain: false,
+        }
+    }
+
+    #[test]
+    fn formats_srt_timestamps() {
+        assert_eq!(srt_timestamp(0.0), "00:00:00,000");
+        assert_eq!(srt_timestamp(3725.5), "01:02:05,500");
+    }
+
+    #[test]
+    fn renders_captions_relative_to_the_clip() {
+        let segments = vec![
+            segment("Later", Some(70.0), Some(75.0)),
+            segment("Before", Some(10.0), Some(20.0)),
+            segment("Straddles", Some(55.0), Some(65.0)),
+            segment("Inside", Some(66.0), Some(68.0)),
+            segment("Untimed", None, None),
+        ];
+        assert_eq!(
+            render_srt(&segments, 60.0, 72.0),
+            "1\n00:00:00,000 --> 00:00:05,000\nStraddles\n\n\
+             2\n00:00:06,000 --> 00:00:08,000\nInside\n\n\
+             3\n00:00:10,000 --> 00:00:12,000\nLater\n"
+        );
+    }
+
+    #[test]
+    fn validates_ranges_and_formats() {
+        assert!(validate_range(10.0, 20.0).is_ok());
+        assert!(validate_range(20.0, 10.0).is_err());
+        assert!(validate_range(-1.0, 10.0).is_err());
+        assert!(validate_range(0.0, MAX_CLIP_SECONDS + 1.0).is_err());
+        assert!(validate_clip_path(Path::new("/tmp/moment.MP3")).is_ok());
+        assert!(validate_clip_path(Path::new("/tmp/moment.txt")).is_err());
+        assert!(validate_clip_path(Path::new("/tmp/moment")).is_err());
+    }
+}
@@ -0,0 +1,273 @@
+//! Text of a meeting's summary, transcript or action items for the clipboard.
+//!
+//! Markdown keeps the summary as written; plain text drops the markup for
+//! chat and email clients that would show it literally.
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+
+use crate::api::MeetingTranscript;
+use crate::database::models::ActionItem;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClipboardContent {
+    Summary,
+    Transcript,
+    ActionItems,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClipboardFormat {
+    #[default]
+    Markdown,
+    Plain,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ClipboardOptions {
+    pub format: ClipboardFormat,
+    /// Start with the meeting title
+    pub include_title: bool,
+    /// Prefix transcript lines with their time in the recording
+    pub timestamps: bool,
+    /// Include action items already marked done
+    pub include_done: bool,
+    /// List marker in plain text
+    pub bullet: String,
+}
+
+impl Default for ClipboardOptions {
+    fn default() -> Self {
+        Self {
+            format: ClipboardFormat::Markdown,
+            include_title: true,
+            timestamps: true,
+            include_done: true,
+            bullet: "•".to_string(),
+        }
+    }
+}
+
+fn with_title(title: &str, body: &str, options: &ClipboardOptions) -> String {
+    if !options.include_title {
+        return body.to_string();
+    }
+    match options.format {
+        ClipboardFormat::Markdown => format!("# {}\n\n{}", title.trim(), body),
+        ClipboardFormat::Plain => format!("{}\n\n{}", title.trim(), body),
+    }
+}
+
+pub fn render_summary(title: &str, markdown: &str, options: &ClipboardOptions) -> String {
+    let body = match options.format {
+        ClipboardFormat::Markdown => markdown.trim().to_string(),
+        ClipboardFormat::Plain => markdown_to_plain(markdown, &options.bullet),
+    };
+    with_title(title, &body, options)
+}
+
+/// One line per non-empty segment; the stamp is the time into the recording,
+/// or the wall-clock time for segments recorded before that was stored
+pub fn transcript_lines(segments: &[MeetingTranscript], timestamps: bool) -> Vec<String> {
+    segments
+        .iter()
+        .filter(|segment| !segment.text.trim().is_empty())
+        .map(|segment| {
+            let text = segment.text.trim();
+            if !timestamps {
+                return text.to_string();
+            }
+            let stamp = match segment.audio_start_time {
+                Some(seconds) => {
+                    let total = seconds.max(0.0) as u64;
+                    if total >= 3600 {
+                        format!(
+                            "{}:{:02}:{:02}",
+                            total / 3600,
+                            total % 3600 / 60,
+                            total % 60
+                        )
+                    } else {
+                        format!("{:02}:{:02}", total / 60, total % 60)
+                    }
+                }
+                None => segment.timestamp.clone(),
+            };
+            format!("[{}] {}", stamp, text)
+        })
+        .collect()
+}
+
+pub fn render_transcript(
+    title: &str,
+    segments: &[MeetingTranscript],
+    options: &ClipboardOptions,
+) -> String {
+    let lines = transcript_lines(segments, options.timestamps);
+    with_title(title, &lines.join("\n"), options)
+}
+
+pub fn render_action_items(
+    title: &str,
+    items: &[ActionItem],
+    options: &ClipboardOptions,
+) -> String {
+    let lines: Vec<String> = items
+        .iter()
+        .filter(|item| options.include_done || item.status != "done")
+        .map(|item| {
+            let done = item.status == "done";
+            let mut line = match options.format {
+                ClipboardFormat::Markdown => {
+                    format!("- [{}] {}", if done { "x" } else { " " }, item.text.trim())
+                }
+                ClipboardFormat::Plain => format!("{} {}", options.bullet, item.text.trim()),
+            };
+            let details: Vec<String> = [
+                item.owner
+                    .as_deref()
+                    .map(|owner| format!("owner: {}", owner)),
+                item.due_date.as_deref().map(|due| format!("due: {}", due)),
+                (done && options.format == ClipboardFormat::Plain).then(|| "done".to_string()),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+            if !details.is_empty() {
+                line.push_str(&format!(" ({})", details.join(", ")));
+            }
+            line
+        })
+        .collect();
+    with_title(title, &lines.join("\n"), options)
+}
+
+/// Markdown as plain text: headings and paragraphs become lines separated by
+/// a blank line, list items get `bullet` (or their number), markup is dropped
+pub fn markdown_to_plain(markdown: &str, bullet: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let mut out = String::new();
+    // The next number of each open list; None for bulleted lists
+    let mut lists: Vec<Option<u64>> = Vec::new();
+    let end_block = |out: &mut String| {
+        let trimmed = out.trim_end_matches([' ', '\n']).len();
+        out.truncate(trimmed);
+        if !out.is_empty() {
+            out.push_str("\n\n");
+        }
+    };
+
+    for event in Parser::new_ext(markdown, options) {
+        match event {
+            Event::Start(Tag::List(first)) => {
+                if lists.is_empty() {
+                    end_block(&mut out);
+                }
+                lists.push(first);
+            }
+            Event::End(TagEnd::List(_)) => {
+                lists.pop();
+                if lists.is_empty() {
+                    end_block(&mut out);
+                }
+            }
+            Event::Start(Tag::Item) => {
+                if !out.is_empty() && !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str(&"  ".repeat(lists.len().saturating_sub(1)));
+                match lists.last_mut() {
+                    Some(Some(number)) => {
+                        out.push_str(&format!("{}. ", number));
+                        *number += 1;
+                    }
+                    _ => {
+                        out.push_str(bullet);
+                        out.push(' ');
+                    }
+                }
+            }
+            Event::TaskListMarker(done) => out.push_str(if done { "[x] " } else { "[ ] " }),
+            Event::Start(Tag::Paragraph)
+            | Event::Start(Tag::Heading { .. })
+            | Event::Start(Tag::CodeBlock(_))
+            | Event::Start(Tag::BlockQuote(_))
+            | Event::End(TagEnd::Heading(_))
+            | Event::End(TagEnd::Paragraph)
+            | Event::End(TagEnd::CodeBlock)
+            | Event::End(TagEnd::BlockQuote(_))
+                if lists.is_empty() =>
+            {
+                end_block(&mut out)
+            }
+            Event::End(TagEnd::TableCell) => out.push('\t'),
+            Event::End(TagEnd::TableRow) | Event::End(TagEnd::TableHead) => {
+                let trimmed = out.trim_end_matches('\t').len();
+                out.truncate(trimmed);
+                out.push('\n');
+            }
+            Event::Text(text) | Event::Code(text) => out.push_str(&text),
+            Event::SoftBreak | Event::HardBreak => out.push('\n'),
+            Event::Rule => {
+                end_block(&mut out);
+                out.push_str("---");
+            }
+            _ => {}
+        }
+    }
+    out.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_text_drops_markup() {
+        let markdown = "# Notes\n\nWe agreed on **Friday**.\n\n- Ship it\n  - QA first\n- [x] Book room\n\n1. One\n2. Two\n";
+        assert_eq!(
+            markdown_to_plain(markdown, "•"),
+            "Notes\n\nWe agreed on Friday.\n\n• Ship it\n  • QA first\n• [x] Book room\n\n1. One\n2. Two"
+        );
+    }
+
+    #[test]
+    fn transcript_lines_follow_the_options() {
+        let segment = |text: &str, start: Option<f64>| MeetingTranscript {
+            id: "t".into(),
+            text: text.into(),
+            timestamp: "10:00".into(),
+            audio_start_time: start,
+            audio_end_time: None,
+            duration: None,
+            speaker: None,
+            uncertain: false,
+        };
+        let segments = [
+            segment("Hello", Some(5.0)),
+            segment("  ", Some(6.0)),
+            segment("Later", Some(3725.0)),
+            segment("Old", None),
+        ];
+        assert_eq!(
+            render_transcript("Sync", &segments, &ClipboardOptions::default()),
+            "# Sync\n\n[00:05] Hello\n[1:02:05] Later\n[10:00] Old"
+        );
+        let options = ClipboardOptions {
+            format: ClipboardFormat::Plain,
+            timestamps: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            render_transcript("Sync", &segments, &options),
+            "Sync\n\nHello\nLater\nOld"
+        );
+    }
+}
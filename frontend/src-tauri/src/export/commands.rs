@@ -0,0 +1,673 @@
+use log::{error as log_error, info as log_info};
+use std::path::PathBuf;
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+use super::clip::{cut_clip, render_srt, validate_clip_path, validate_range};
+use super::clipboard::{
+    render_action_items as clipboard_action_items, render_summary, render_transcript,
+    transcript_lines, ClipboardContent, ClipboardOptions,
+};
+use super::csv::{
+    fetch_action_items, fetch_meetings, meeting_columns, parse_date_bound, render_action_items,
+    render_meetings, resolve_columns, ACTION_ITEM_COLUMNS,
+};
+use super::data_archive::{collect_archive, import_archive, parse_archive, ImportReport};
+use super::flashcards::{extract_flashcards, render_anki, render_csv as render_flashcards_csv};
+use super::html::render_minutes_document;
+use super::templates::{self as export_templates, ExportModel, ExportTemplate};
+use crate::audio::audio_processing::find_meeting_audio;
+use crate::audit::{self, AuditAction};
+use crate::database::repositories::action_item::{ActionItemStatus, ActionItemsRepository};
+use crate::database::repositories::custom_field::CustomFieldsRepository;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::participant::ParticipantsRepository;
+use crate::database::repositories::summary::SummaryProcessesRepository;
+use crate::database::settings_cache::{config_changed, ConfigKind};
+use crate::encryption::cipher::{is_bundle, open_bundle, seal_bundle};
+use crate::jobs::JobHandle;
+use crate::state::AppState;
+use crate::summary::schema;
+
+/// Writes every meeting, transcript, summary, note and non-secret setting to `path` as JSON.
+/// With a `passphrase` the archive is written as an encrypted bundle instead.
+/// Transcripts are redacted when the redaction settings ask for it.
+#[tauri::command]
+pub async fn api_export_all_data<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    path: String,
+    passphrase: Option<String>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_export_all_data called, path: {}", path);
+    let job = state
+        .jobs
+        .begin(&app, "export", "Export all data", None)
+        .await?;
+    let result = export_all_data(&app, &state, &job, &path, passphrase).await;
+    job.finish(result.clone()).await;
+    result
+}
+
+async fn export_all_data<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &AppState,
+    job: &JobHandle,
+    path: &str,
+    passphrase: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let app_version = app.package_info().version.to_string();
+    let mut archive = collect_archive(state.db_manager.pool(), &app_version)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to collect data for export: {}", e);
+            format!("Failed to export data: {}", e)
+        })?;
+    let redacted = crate::redaction::settings().redact_exports;
+    if redacted {
+        archive.redact_transcripts();
+    }
+
+    let json = serde_json::to_string_pretty(&archive)
+        .map_err(|e| format!("Failed to serialize export: {}", e))?;
+    let encrypted = passphrase.is_some();
+    let json = match passphrase {
+        Some(passphrase) if passphrase.is_empty() => {
+            return Err("Passphrase must not be empty".to_string())
+        }
+        Some(passphrase) => seal_bundle(&passphrase, &json).map_err(|e| e.to_string())?,
+        None => json,
+    };
+
+    // Last chance to stop before anything is written
+    job.checkpoint().await?;
+    let path = write_export_file(path, &json)?;
+
+    log_info!(
+        "Exported {} meetings to {}",
+        archive.meetings.len(),
+        path.display()
+    );
+    audit::record(
+        state.db_manager.pool(),
+        AuditAction::Export,
+        "data_archive",
+        None,
+        serde_json::json!({ "path": path, "meetings": archive.meetings.len(), "encrypted": encrypted, "redacted": redacted }),
+    )
+    .await;
+    Ok(serde_json::json!({
+        "status": "success",
+        "path": path.to_string_lossy(),
+        "meetings": archive.meetings.len(),
+        "encrypted": encrypted,
+        "redacted": redacted
+    }))
+}
+
+/// Imports an archive produced by `api_export_all_data`. Existing meetings are left untouched.
+/// Encrypted bundles need the `passphrase` they were exported with.
+#[tauri::command]
+pub async fn api_import_all_data<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    path: String,
+    import_settings: Option<bool>,
+    passphrase: Option<String>,
+) -> Result<ImportReport, String> {
+    log_info!("api_import_all_data called, path: {}", path);
+    let _operation = state.shutdown.begin_operation("import data")?;
+
+    let mut content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read archive file: {}", e))?;
+    if is_bundle(&content) {
+        let passphrase = passphrase
+            .ok_or_else(|| "This archive is encrypted; a passphrase is required".to_string())?;
+        content = open_bundle(&passphrase, &content).map_err(|e| e.to_string())?;
+    }
+    let archive = parse_archive(&content)?;
+
+    let import_settings = import_settings.unwrap_or(false);
+    let report = import_archive(state.db_manager.pool(), &archive, import_settings)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to import archive {}: {}", path, e);
+            format!("Failed to import data: {}", e)
+        })?;
+    if import_settings {
+        config_changed(&app, &state.settings_cache, ConfigKind::Model);
+        config_changed(&app, &state.settings_cache, ConfigKind::Transcript);
+    }
+    audit::record(
+        state.db_manager.pool(),
+        AuditAction::Import,
+        "data_archive",
+        None,
+        serde_json::json!({ "path": path, "import_settings": import_settings }),
+    )
+    .await;
+    Ok(report)
+}
+
+fn write_export_file(path: &str, content: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(path);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create export folder: {}", e))?;
+    }
+    std::fs::write(&path, content).map_err(|e| {
+        log_error!("Failed to write export file {}: {}", path.display(), e);
+        format!("Failed to write export file: {}", e)
+    })?;
+    Ok(path)
+}
+
+/// Exports the meetings index as CSV, including `custom:<name>` columns for custom fields.
+/// `from`/`to` accept `YYYY-MM-DD` or RFC 3339 and filter on the meeting date.
+#[tauri::command]
+pub async fn api_export_meetings_csv<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    path: String,
+    columns: Option<Vec<String>>,
+    from: Option<String>,
+    to: Option<String>,
+) -> Result<serde_json::Value, String> {
+    log_info!(
+        "api_export_meetings_csv called, path: {}, from: {:?}, to: {:?}",
+        path,
+        from,
+        to
+    );
+
+    let custom_field_names: Vec<String> =
+        CustomFieldsRepository::list_definitions(state.db_manager.pool())
+            .await
+            .map_err(|e| format!("Failed to load custom fields: {}", e))?
+            .into_iter()
+            .map(|field| field.name)
+            .collect();
+    let allowed = meeting_columns(&custom_field_names);
+    let allowed: Vec<&str> = allowed.iter().map(String::as_str).collect();
+    let columns = resolve_columns(columns, &allowed)?;
+    let from = from.as_deref().map(|d| parse_date_bound(d, false)).transpose()?;
+    let to = to.as_deref().map(|d| parse_date_bound(d, true)).transpose()?;
+
+    let rows = fetch_meetings(state.db_manager.pool(), from, to)
+        .await
+        .map_err(|e| format!("Failed to load meetings: {}", e))?;
+    let path = write_export_file(&path, &render_meetings(&columns, &rows))?;
+    audit::record(
+        state.db_manager.pool(),
+        AuditAction::Export,
+        "meetings_csv",
+        None,
+        serde_json::json!({ "path": path, "rows": rows.len() }),
+    )
+    .await;
+
+    Ok(serde_json::json!({
+        "status": "success",
+        "path": path.to_string_lossy(),
+        "rows": rows.len()
+    }))
+}
+
+/// Exports action items as CSV, optionally filtered by meeting date range and status ("open"/"done")
+#[tauri::command]
+pub async fn api_export_action_items_csv<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    path: String,
+    columns: Option<Vec<String>>,
+    from: Option<String>,
+    to: Option<String>,
+    status: Option<String>,
+) -> Result<serde_json::Value, String> {
+    log_info!(
+        "api_export_action_items_csv called, path: {}, from: {:?}, to: {:?}, status: {:?}",
+        path,
+        from,
+        to,
+        status
+    );
+
+    let columns = resolve_columns(columns, ACTION_ITEM_COLUMNS)?;
+    let from = from.as_deref().map(|d| parse_date_bound(d, false)).transpose()?;
+    let to = to.as_deref().map(|d| parse_date_bound(d, true)).transpose()?;
+    let status = status
+        .as_deref()
+        .map(str::parse::<ActionItemStatus>)
+        .transpose()?;
+
+    let rows = fetch_action_items(
+        state.db_manager.pool(),
+        from,
+        to,
+        status.as_ref().map(ActionItemStatus::as_str),
+    )
+    .await
+    .map_err(|e| format!("Failed to load action items: {}", e))?;
+    let path = write_export_file(&path, &render_action_items(&columns, &rows))?;
+    audit::record(
+        state.db_manager.pool(),
+        AuditAction::Export,
+        "action_items_csv",
+        None,
+        serde_json::json!({ "path": path, "rows": rows.len() }),
+    )
+    .await;
+
+    Ok(serde_json::json!({
+        "status": "success",
+        "path": path.to_string_lossy(),
+        "rows": rows.len()
+    }))
+}
+
+/// Cuts `start..end` (seconds into the recording) out of a meeting's audio into `path`
+/// (.m4a, .mp3, .wav, ...). With `captions` the transcript of that range is written next
+/// to the clip as an .srt file.
+#[tauri::command]
+pub async fn api_export_audio_clip<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    start: f64,
+    end: f64,
+    path: String,
+    captions: Option<bool>,
+) -> Result<serde_json::Value, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_export_audio_clip called for meeting {}, {:.1}s-{:.1}s, path: {}",
+        meeting_id,
+        start,
+        end,
+        path
+    );
+    validate_range(start, end)?;
+    let path = PathBuf::from(path);
+    validate_clip_path(&path)?;
+
+    let pool = state.db_manager.pool();
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+    let source = meeting
+        .folder_path
+        .as_deref()
+        .and_then(|folder| find_meeting_audio(std::path::Path::new(folder)))
+        .ok_or_else(|| "This meeting has no recording to cut a clip from".to_string())?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create export folder: {}", e))?;
+    }
+    let destination = path.clone();
+    tokio::task::spawn_blocking(move || cut_clip(&source, &destination, start, end))
+        .await
+        .map_err(|e| format!("Clip export task failed: {}", e))??;
+
+    let captions_path = if captions.unwrap_or(false) {
+        let mut transcripts = MeetingsRepository::get_meeting(pool, &meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load the transcript: {}", e))?
+            .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?
+            .transcripts;
+        if crate::redaction::settings().redact_exports {
+            for transcript in &mut transcripts {
+                transcript.text = crate::redaction::redact_for_export(&transcript.text);
+            }
+        }
+        let captions_path = path.with_extension("srt");
+        let srt = render_srt(&transcripts, start, end);
+        Some(write_export_file(&captions_path.to_string_lossy(), &srt)?)
+    } else {
+        None
+    };
+
+    audit::record(
+        pool,
+        AuditAction::Export,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({ "kind": "audio_clip", "path": path, "start": start, "end": end }),
+    )
+    .await;
+    Ok(serde_json::json!({
+        "status": "success",
+        "path": path.to_string_lossy(),
+        "captions_path": captions_path.map(|p| p.to_string_lossy().to_string()),
+        "duration": end - start
+    }))
+}
+
+/// The markdown of a meeting's saved summary, if it has one
+async fn summary_markdown(
+    pool: &sqlx::SqlitePool,
+    meeting_id: &str,
+) -> Result<Option<String>, String> {
+    Ok(SummaryProcessesRepository::get_summary_data(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load the summary: {}", e))?
+        .and_then(|process| process.result)
+        .and_then(|result| serde_json::from_str(&result).ok())
+        .and_then(|value| schema::normalize(value).ok())
+        .map(|summary| summary.markdown)
+        .filter(|markdown| !markdown.trim().is_empty()))
+}
+
+/// Copies a meeting's summary, transcript or action items to the clipboard, as markdown or
+/// plain text. Transcripts are redacted when the redaction settings ask for it.
+#[tauri::command]
+pub async fn api_copy_meeting_to_clipboard<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    content: ClipboardContent,
+    options: Option<ClipboardOptions>,
+) -> Result<serde_json::Value, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_copy_meeting_to_clipboard called for meeting {}, content: {:?}",
+        meeting_id,
+        content
+    );
+    let options = options.unwrap_or_default();
+    let pool = state.db_manager.pool();
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+
+    let text = match content {
+        ClipboardContent::Summary => {
+            let markdown = summary_markdown(pool, &meeting_id)
+                .await?
+                .ok_or_else(|| "This meeting has no summary yet".to_string())?;
+            render_summary(&meeting.title, &markdown, &options)
+        }
+        ClipboardContent::Transcript => {
+            let mut transcripts = MeetingsRepository::get_meeting(pool, &meeting_id)
+                .await
+                .map_err(|e| format!("Failed to load the transcript: {}", e))?
+                .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?
+                .transcripts;
+            if transcripts.is_empty() {
+                return Err("This meeting has no transcript".to_string());
+            }
+            if crate::redaction::settings().redact_exports {
+                for transcript in &mut transcripts {
+                    transcript.text = crate::redaction::redact_for_export(&transcript.text);
+                }
+            }
+            render_transcript(&meeting.title, &transcripts, &options)
+        }
+        ClipboardContent::ActionItems => {
+            let items = ActionItemsRepository::list_for_meeting(pool, &meeting_id)
+                .await
+                .map_err(|e| format!("Failed to load action items: {}", e))?;
+            if items.is_empty() {
+                return Err("This meeting has no action items".to_string());
+            }
+            clipboard_action_items(&meeting.title, &items, &options)
+        }
+    };
+
+    app.clipboard().write_text(text.clone()).map_err(|e| {
+        log_error!("Failed to write to the clipboard: {}", e);
+        format!("Failed to copy to the clipboard: {}", e)
+    })?;
+    audit::record(
+        pool,
+        AuditAction::Export,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({ "kind": "clipboard", "content": content }),
+    )
+    .await;
+    Ok(serde_json::json!({
+        "status": "success",
+        "characters": text.chars().count()
+    }))
+}
+
+/// Writes printable minutes of a meeting (attendees, summary and, unless `include_transcript`
+/// is false, the transcript) as a standalone `minutes.html` in the meeting folder, where
+/// `open_meeting_folder` shows it. Transcripts are redacted when the redaction settings ask
+/// for it.
+#[tauri::command]
+pub async fn api_export_minutes_html<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    include_transcript: Option<bool>,
+) -> Result<serde_json::Value, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_export_minutes_html called for meeting {}", meeting_id);
+    let pool = state.db_manager.pool();
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+    let folder = meeting
+        .folder_path
+        .clone()
+        .ok_or_else(|| "This meeting has no folder to write the minutes to".to_string())?;
+
+    let attendees: Vec<String> = ParticipantsRepository::list_participants(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load participants: {}", e))?
+        .into_iter()
+        .map(|participant| match participant.role {
+            Some(role) if !role.trim().is_empty() => {
+                format!("{} ({})", participant.name, role.trim())
+            }
+            _ => participant.name,
+        })
+        .collect();
+    let summary = summary_markdown(pool, &meeting_id).await?;
+    let transcript = if include_transcript.unwrap_or(true) {
+        let mut transcripts = MeetingsRepository::get_meeting(pool, &meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load the transcript: {}", e))?
+            .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?
+            .transcripts;
+        if crate::redaction::settings().redact_exports {
+            for transcript in &mut transcripts {
+                transcript.text = crate::redaction::redact_for_export(&transcript.text);
+            }
+        }
+        transcript_lines(&transcripts, true)
+    } else {
+        Vec::new()
+    };
+    if summary.is_none() && transcript.is_empty() {
+        return Err("This meeting has no summary or transcript to export yet".to_string());
+    }
+
+    let html = render_minutes_document(
+        &meeting.title,
+        &meeting.created_at.0.format("%Y-%m-%d %H:%M").to_string(),
+        &attendees,
+        summary.as_deref(),
+        &transcript,
+    );
+    let path = std::path::Path::new(&folder).join("minutes.html");
+    let path = write_export_file(&path.to_string_lossy(), &html)?;
+    audit::record(
+        pool,
+        AuditAction::Export,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({ "kind": "minutes_html", "path": path }),
+    )
+    .await;
+    Ok(serde_json::json!({
+        "status": "success",
+        "path": path.to_string_lossy()
+    }))
+}
+
+/// Templates in the export templates folder (created when missing), by the name of the file
+/// each renders
+#[tauri::command]
+pub async fn api_list_export_templates<R: Runtime>(
+    _app: AppHandle<R>,
+) -> Result<Vec<ExportTemplate>, String> {
+    let dir = export_templates::templates_dir()
+        .ok_or_else(|| "No data directory for export templates".to_string())?;
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create the export templates folder: {}", e))?;
+    Ok(export_templates::list_templates(&dir))
+}
+
+/// Renders the user template `template_name` (e.g. "minutes.md" for `minutes.md.tera`) with
+/// a meeting and writes the result to `path`, or under the template's name to the `exports`
+/// subfolder of the meeting folder, numbering the name rather than replacing an earlier
+/// export. Transcripts are redacted when the redaction settings ask for it.
+#[tauri::command]
+pub async fn api_export_with_template<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    template_name: String,
+    path: Option<String>,
+) -> Result<serde_json::Value, String> {
+    // Names are plain file names, so a template can't be read from elsewhere
+    crate::validation::id("template_name", &template_name)?;
+    log_info!(
+        "api_export_with_template called for meeting {}, template: {}",
+        meeting_id,
+        template_name
+    );
+    let dir = export_templates::templates_dir()
+        .ok_or_else(|| "No data directory for export templates".to_string())?;
+    let template_path = dir.join(format!(
+        "{}.{}",
+        template_name,
+        export_templates::TEMPLATE_EXTENSION
+    ));
+    let template = std::fs::read_to_string(&template_path)
+        .map_err(|_| format!("Export template not found: {}", template_name))?;
+
+    let pool = state.db_manager.pool();
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+    let participants = ParticipantsRepository::list_participants(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load participants: {}", e))?;
+    let action_items = ActionItemsRepository::list_for_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load action items: {}", e))?;
+    let summary = summary_markdown(pool, &meeting_id).await?;
+    let mut transcripts = MeetingsRepository::get_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load the transcript: {}", e))?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?
+        .transcripts;
+    if crate::redaction::settings().redact_exports {
+        for transcript in &mut transcripts {
+            transcript.text = crate::redaction::redact_for_export(&transcript.text);
+        }
+    }
+
+    let destination = path
+        .map(|path| crate::validation::local_path("path", &path))
+        .transpose()?;
+    let meeting_folder = meeting.folder_path.clone();
+    let model = ExportModel::new(
+        meeting.id,
+        meeting.title,
+        meeting.created_at.0,
+        meeting.folder_path,
+        participants,
+        summary,
+        &transcripts,
+        action_items,
+    );
+    let rendered = export_templates::render(&template_name, &template, &model)?;
+    let path = match destination {
+        Some(destination) => write_export_file(&destination.to_string_lossy(), &rendered)?,
+        None => {
+            let folder = meeting_folder.ok_or_else(|| {
+                "This meeting has no folder; choose where to save the export".to_string()
+            })?;
+            let dir = PathBuf::from(folder).join(export_templates::EXPORTS_DIR_NAME);
+            export_templates::write_new_output(&dir, &template_name, &rendered).map_err(|e| {
+                log_error!("Failed to write export into {}: {}", dir.display(), e);
+                format!("Failed to write export file: {}", e)
+            })?
+        }
+    };
+
+    audit::record(
+        pool,
+        AuditAction::Export,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({ "kind": "template", "template": template_name, "path": path }),
+    )
+    .await;
+    Ok(serde_json::json!({
+        "status": "success",
+        "path": path.to_string_lossy(),
+        "template": template_name
+    }))
+}
+
+/// Writes flashcards made from the key points and decisions of a meeting's summary to `path`,
+/// as an Anki text import (`format` "anki", the default) or as CSV (`format` "csv")
+#[tauri::command]
+pub async fn api_export_flashcards<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    path: String,
+    format: Option<String>,
+) -> Result<serde_json::Value, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_export_flashcards called for meeting {}, path: {}, format: {:?}",
+        meeting_id,
+        path,
+        format
+    );
+    let pool = state.db_manager.pool();
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+    let markdown = summary_markdown(pool, &meeting_id)
+        .await?
+        .ok_or_else(|| "This meeting has no summary yet".to_string())?;
+
+    let cards = extract_flashcards(&meeting.title, &markdown);
+    if cards.is_empty() {
+        return Err("The summary has no key points or decisions to make cards from".to_string());
+    }
+    let content = match format.as_deref().unwrap_or("anki") {
+        "anki" => render_anki(&meeting.title, &cards),
+        "csv" => render_flashcards_csv(&cards),
+        other => return Err(format!("Unknown flashcard format '{}' (use anki or csv)", other)),
+    };
+    let path = write_export_file(&path, &content)?;
+
+    audit::record(
+        pool,
+        AuditAction::Export,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({ "kind": "flashcards", "path": path, "cards": cards.len() }),
+    )
+    .await;
+    Ok(serde_json::json!({
+        "status": "success",
+        "path": path.to_string_lossy(),
+        "cards": cards.len()
+    }))
+}
@@ -0,0 +1,334 @@
+//! CSV export of the meetings index and action items.
+//!
+//! Columns are selectable by name; unknown names are rejected so a typo in a
+//! saved export preset doesn't silently produce an empty column. Custom fields
+//! are exported as `custom:<field name>` columns.
+
+use crate::database::repositories::custom_field::CustomFieldsRepository;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use sqlx::{Error as SqlxError, FromRow, SqlitePool};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct MeetingIndexRow {
+    pub id: String,
+    pub title: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub folder_path: Option<String>,
+    pub transcript_count: i64,
+    pub duration_seconds: Option<f64>,
+    pub summary_status: Option<String>,
+    pub action_item_count: i64,
+    /// Custom field values keyed by field name
+    #[sqlx(skip)]
+    pub custom_fields: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ActionItemRow {
+    pub id: String,
+    pub meeting_id: String,
+    pub meeting_title: String,
+    pub meeting_date: String,
+    pub text: String,
+    pub owner: Option<String>,
+    pub due_date: Option<String>,
+    pub status: String,
+    pub source: String,
+}
+
+pub const MEETING_COLUMNS: &[&str] = &[
+    "id",
+    "title",
+    "created_at",
+    "updated_at",
+    "folder_path",
+    "transcript_count",
+    "duration_seconds",
+    "summary_status",
+    "action_item_count",
+];
+
+pub const CUSTOM_COLUMN_PREFIX: &str = "custom:";
+
+/// Built-in meeting columns followed by one column per custom field
+pub fn meeting_columns(custom_field_names: &[String]) -> Vec<String> {
+    MEETING_COLUMNS
+        .iter()
+        .map(|c| c.to_string())
+        .chain(
+            custom_field_names
+                .iter()
+                .map(|name| format!("{}{}", CUSTOM_COLUMN_PREFIX, name)),
+        )
+        .collect()
+}
+
+pub const ACTION_ITEM_COLUMNS: &[&str] = &[
+    "id",
+    "meeting_id",
+    "meeting_title",
+    "meeting_date",
+    "text",
+    "owner",
+    "due_date",
+    "status",
+    "source",
+];
+
+impl MeetingIndexRow {
+    fn field(&self, column: &str) -> String {
+        match column {
+            "id" => self.id.clone(),
+            "title" => self.title.clone(),
+            "created_at" => self.created_at.clone(),
+            "updated_at" => self.updated_at.clone(),
+            "folder_path" => self.folder_path.clone().unwrap_or_default(),
+            "transcript_count" => self.transcript_count.to_string(),
+            "duration_seconds" => self
+                .duration_seconds
+                .map(|d| format!("{:.1}", d))
+                .unwrap_or_default(),
+            "summary_status" => self.summary_status.clone().unwrap_or_default(),
+            "action_item_count" => self.action_item_count.to_string(),
+            _ => column
+                .strip_prefix(CUSTOM_COLUMN_PREFIX)
+                .and_then(|name| self.custom_fields.get(name))
+                .cloned()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl ActionItemRow {
+    fn field(&self, column: &str) -> String {
+        match column {
+            "id" => self.id.clone(),
+            "meeting_id" => self.meeting_id.clone(),
+            "meeting_title" => self.meeting_title.clone(),
+            "meeting_date" => self.meeting_date.clone(),
+            "text" => self.text.clone(),
+            "owner" => self.owner.clone().unwrap_or_default(),
+            "due_date" => self.due_date.clone().unwrap_or_default(),
+            "status" => self.status.clone(),
+            "source" => self.source.clone(),
+            _ => String::new(),
+        }
+    }
+}
+
+/// Quotes a field when it contains a delimiter, quote or newline (RFC 4180)
+pub fn escape_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Validates a requested column list against the allowed set. `None` or an empty
+/// list selects every column.
+pub fn resolve_columns(
+    requested: Option<Vec<String>>,
+    allowed: &[&str],
+) -> Result<Vec<String>, String> {
+    match requested {
+        Some(columns) if !columns.is_empty() => {
+            for column in &columns {
+                if !allowed.contains(&column.as_str()) {
+                    return Err(format!(
+                        "Unknown column '{}'. Available: {}",
+                        column,
+                        allowed.join(", ")
+                    ));
+                }
+            }
+            Ok(columns)
+        }
+        _ => Ok(allowed.iter().map(|c| c.to_string()).collect()),
+    }
+}
+
+/// Parses a date filter given as `YYYY-MM-DD` or RFC 3339. Plain dates cover the
+/// whole day, so `to = 2026-01-31` includes meetings on the 31st.
+pub fn parse_date_bound(value: &str, end_of_day: bool) -> Result<DateTime<Utc>, String> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date '{}'. Use YYYY-MM-DD", value))?;
+    let time = if end_of_day {
+        NaiveTime::from_hms_milli_opt(23, 59, 59, 999).unwrap()
+    } else {
+        NaiveTime::MIN
+    };
+    Ok(date.and_time(time).and_utc())
+}
+
+fn render<T>(columns: &[String], rows: &[T], field: impl Fn(&T, &str) -> String) -> String {
+    let mut out = String::new();
+    out.push_str(
+        &columns
+            .iter()
+            .map(|c| escape_field(c))
+            .collect::<Vec<_>>()
+            .join(","),
+    );
+    out.push_str("\r\n");
+    for row in rows {
+        let line = columns
+            .iter()
+            .map(|c| escape_field(&field(row, c)))
+            .collect::<Vec<_>>()
+            .join(",");
+        out.push_str(&line);
+        out.push_str("\r\n");
+    }
+    out
+}
+
+pub fn render_meetings(columns: &[String], rows: &[MeetingIndexRow]) -> String {
+    render(columns, rows, |row, c| row.field(c))
+}
+
+pub fn render_action_items(columns: &[String], rows: &[ActionItemRow]) -> String {
+    render(columns, rows, |row, c| row.field(c))
+}
+
+pub async fn fetch_meetings(
+    pool: &SqlitePool,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) -> Result<Vec<MeetingIndexRow>, SqlxError> {
+    let mut rows = sqlx::query_as::<_, MeetingIndexRow>(
+        "SELECT m.id, m.title, m.created_at, m.updated_at, m.folder_path,
+                (SELECT COUNT(*) FROM transcripts t WHERE t.meeting_id = m.id) AS transcript_count,
+                (SELECT MAX(t.audio_end_time) FROM transcripts t WHERE t.meeting_id = m.id) AS duration_seconds,
+                (SELECT s.status FROM summary_processes s WHERE s.meeting_id = m.id) AS summary_status,
+                (SELECT COUNT(*) FROM action_items a WHERE a.meeting_id = m.id) AS action_item_count
+         FROM meetings m
+         WHERE (? IS NULL OR m.created_at >= ?)
+           AND (? IS NULL OR m.created_at <= ?)
+         ORDER BY m.created_at DESC",
+    )
+    .bind(from)
+    .bind(from)
+    .bind(to)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+
+    let mut custom_fields: HashMap<String, HashMap<String, String>> = HashMap::new();
+    for value in CustomFieldsRepository::list_all_values(pool).await? {
+        custom_fields
+            .entry(value.meeting_id)
+            .or_default()
+            .insert(value.name, value.value);
+    }
+    for row in &mut rows {
+        row.custom_fields = custom_fields.remove(&row.id).unwrap_or_default();
+    }
+    Ok(rows)
+}
+
+pub async fn fetch_action_items(
+    pool: &SqlitePool,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    status: Option<&str>,
+) -> Result<Vec<ActionItemRow>, SqlxError> {
+    sqlx::query_as::<_, ActionItemRow>(
+        "SELECT a.id, a.meeting_id, m.title AS meeting_title, m.created_at AS meeting_date,
+                a.text, a.owner, a.due_date, a.status, a.source
+         FROM action_items a
+         JOIN meetings m ON m.id = a.meeting_id
+         WHERE (? IS NULL OR m.created_at >= ?)
+           AND (? IS NULL OR m.created_at <= ?)
+           AND (? IS NULL OR a.status = ?)
+         ORDER BY m.created_at DESC, a.created_at ASC",
+    )
+    .bind(from)
+    .bind(from)
+    .bind(to)
+    .bind(to)
+    .bind(status)
+    .bind(status)
+    .fetch_all(pool)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_special_characters() {
+        assert_eq!(escape_field("plain"), "plain");
+        assert_eq!(escape_field("a,b"), "\"a,b\"");
+        assert_eq!(escape_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_field("line\nbreak"), "\"line\nbreak\"");
+    }
+
+    #[test]
+    fn resolves_columns() {
+        let all = resolve_columns(None, MEETING_COLUMNS).unwrap();
+        assert_eq!(all.len(), MEETING_COLUMNS.len());
+        let some = resolve_columns(Some(vec!["title".into()]), MEETING_COLUMNS).unwrap();
+        assert_eq!(some, vec!["title".to_string()]);
+        assert!(resolve_columns(Some(vec!["nope".into()]), MEETING_COLUMNS).is_err());
+    }
+
+    #[test]
+    fn date_bounds_cover_whole_day() {
+        let from = parse_date_bound("2026-01-31", false).unwrap();
+        let to = parse_date_bound("2026-01-31", true).unwrap();
+        assert_eq!(from.to_rfc3339(), "2026-01-31T00:00:00+00:00");
+        assert!(to > from && to.date_naive() == from.date_naive());
+        assert!(parse_date_bound("2026-01-31T10:00:00+02:00", false).is_ok());
+        assert!(parse_date_bound("31/01/2026", false).is_err());
+    }
+
+    #[test]
+    fn renders_selected_columns() {
+        let rows = vec![ActionItemRow {
+            id: "action-1".into(),
+            meeting_id: "meeting-1".into(),
+            meeting_title: "Weekly, sync".into(),
+            meeting_date: "2026-01-20".into(),
+            text: "Ship it".into(),
+            owner: None,
+            due_date: Some("Friday".into()),
+            status: "open".into(),
+            source: "summary".into(),
+        }];
+        let columns = vec!["meeting_title".to_string(), "owner".to_string(), "due_date".to_string()];
+        assert_eq!(
+            render_action_items(&columns, &rows),
+            "meeting_title,owner,due_date\r\n\"Weekly, sync\",,Friday\r\n"
+        );
+    }
+
+    #[test]
+    fn renders_custom_field_columns() {
+        let row = MeetingIndexRow {
+            id: "meeting-1".into(),
+            title: "Kickoff".into(),
+            created_at: "2026-01-20".into(),
+            updated_at: "2026-01-20".into(),
+            folder_path: None,
+            transcript_count: 0,
+            duration_seconds: None,
+            summary_status: None,
+            action_item_count: 0,
+            custom_fields: HashMap::from([("Client".to_string(), "Acme".to_string())]),
+        };
+        let columns = meeting_columns(&["Client".to_string(), "Project".to_string()]);
+        assert_eq!(columns.len(), MEETING_COLUMNS.len() + 2);
+        let selected = vec!["title".to_string(), "custom:Client".to_string(), "custom:Project".to_string()];
+        assert_eq!(
+            render_meetings(&selected, &[row]),
+            "title,custom:Client,custom:Project\r\nKickoff,Acme,\r\n"
+        );
+    }
+}
@@ -0,0 +1,623 @@
+//! Full data archive (export + import).
+//!
+//! The archive is a single JSON document:
+//!
+//! ```json
+//! {
+//!   "format": "meetily-archive",
+//!   "version": 1,
+//!   "exported_at": "2026-01-20T10:00:00Z",
+//!   "app_version": "0.2.0",
+//!   "settings": { "provider": "ollama", "model": "llama3.2", ... },
+//!   "custom_fields": [ { "id", "name", "field_type", "options", "position", ... } ],
+//!   "meetings": [
+//!     {
+//!       "id": "meeting-...", "title": "...", "created_at": "...", "updated_at": "...",
+//!       "folder_path": "/path/to/recording" | null,
+//!       "transcripts": [ { "id", "transcript", "timestamp", "audio_start_time",
+//!                          "audio_end_time", "duration", "speaker" } ],
+//!       "summary": { "status": "completed", "result": { "markdown": "..." } } | null,
+//!       "notes": { "markdown": "...", "json": "..." } | null,
+//!       "action_items": [ { "id", "text", "owner", "due_date", "status", "source", ... } ],
+//!       "participants": [ ... ],
+//!       "speakers": [ { "speaker_label", "display_name", "profile_id" } ],
+//!       "attachments": [ { "file_name", "stored_path", "mime_type", "size_bytes" } ],
+//!       "custom_fields": { "<field name>": "<value>" },
+//!       "tags": [ "..." ],
+//!       "comments": [ { "id", "transcript_id", "parent_id", "author", "text", ... } ],
+//!       "redaction_mappings": [ { "placeholder", "kind", "original" } ]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! API keys are never written. Attachments and audio are referenced by path only;
+//! voice embeddings are not exported. Redaction mappings are left out of
+//! redacted exports, and are only imported when encryption is enabled.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, Error as SqlxError, SqlitePool};
+use std::collections::HashMap;
+use tracing::{info, warn};
+
+use crate::database::models::{
+    ActionItem, CustomFieldDefinition, MeetingAttachment, MeetingModel, MeetingParticipant,
+    MeetingSpeaker, SegmentComment,
+};
+use crate::database::repositories::{
+    action_item::ActionItemsRepository, attachment::AttachmentsRepository,
+    custom_field::{CustomFieldInput, CustomFieldsRepository}, meeting::MeetingsRepository,
+    participant::ParticipantsRepository, redaction::RedactionMappingsRepository,
+    segment_comment::SegmentCommentsRepository,
+    setting::SettingsRepository, speaker::SpeakersRepository, tag::TagsRepository,
+};
+use crate::encryption;
+use crate::redaction::detect::MappingEntry;
+
+pub const ARCHIVE_FORMAT: &str = "meetily-archive";
+pub const ARCHIVE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataArchive {
+    pub format: String,
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub app_version: String,
+    pub settings: Option<ArchiveSettings>,
+    #[serde(default)]
+    pub custom_fields: Vec<CustomFieldDefinition>,
+    pub meetings: Vec<ArchiveMeeting>,
+}
+
+impl DataArchive {
+    /// Masks personal data in every transcript segment (see
+    /// [`crate::redaction`]). Summaries and notes are left as they are.
+    pub fn redact_transcripts(&mut self) {
+        for meeting in &mut self.meetings {
+            meeting.redact_transcripts();
+        }
+    }
+}
+
+/// Non-secret settings. API keys are deliberately left out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveSettings {
+    pub provider: String,
+    pub model: String,
+    pub whisper_model: String,
+    pub ollama_endpoint: Option<String>,
+    pub custom_openai_endpoint: Option<String>,
+    pub custom_openai_model: Option<String>,
+    pub transcript_provider: Option<String>,
+    pub transcript_model: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveMeeting {
+    pub id: String,
+    pub title: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub folder_path: Option<String>,
+    pub transcripts: Vec<ArchiveTranscript>,
+    pub summary: Option<ArchiveSummary>,
+    pub notes: Option<ArchiveNotes>,
+    #[serde(default)]
+    pub action_items: Vec<ActionItem>,
+    #[serde(default)]
+    pub participants: Vec<MeetingParticipant>,
+    #[serde(default)]
+    pub speakers: Vec<MeetingSpeaker>,
+    #[serde(default)]
+    pub attachments: Vec<MeetingAttachment>,
+    /// Custom field values keyed by field name
+    #[serde(default)]
+    pub custom_fields: HashMap<String, String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Review comments on the transcript segments
+    #[serde(default)]
+    pub comments: Vec<SegmentComment>,
+    /// Originals behind reversible redaction placeholders
+    #[serde(default)]
+    pub redaction_mappings: Vec<MappingEntry>,
+}
+
+impl ArchiveMeeting {
+    pub fn redact_transcripts(&mut self) {
+        for transcript in &mut self.transcripts {
+            transcript.transcript = crate::redaction::redact_for_export(&transcript.transcript);
+        }
+        for comment in &mut self.comments {
+            comment.text = crate::redaction::redact_for_export(&comment.text);
+        }
+        // The mapping would undo the redaction
+        self.redaction_mappings.clear();
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ArchiveTranscript {
+    pub id: String,
+    pub transcript: String,
+    pub timestamp: String,
+    pub audio_start_time: Option<f64>,
+    pub audio_end_time: Option<f64>,
+    pub duration: Option<f64>,
+    pub speaker: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveSummary {
+    pub status: String,
+    pub result: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveNotes {
+    pub markdown: Option<String>,
+    pub json: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportReport {
+    pub meetings_imported: usize,
+    pub meetings_skipped: usize,
+    pub transcripts_imported: usize,
+    pub settings_imported: bool,
+}
+
+/// Parses and validates an archive document
+pub fn parse_archive(json: &str) -> Result<DataArchive, String> {
+    let archive: DataArchive =
+        serde_json::from_str(json).map_err(|e| format!("Invalid archive file: {}", e))?;
+
+    if archive.format != ARCHIVE_FORMAT {
+        return Err(format!(
+            "Unsupported archive format '{}' (expected '{}')",
+            archive.format, ARCHIVE_FORMAT
+        ));
+    }
+    if archive.version > ARCHIVE_VERSION {
+        return Err(format!(
+            "Archive version {} is newer than supported version {}. Please update the app.",
+            archive.version, ARCHIVE_VERSION
+        ));
+    }
+    Ok(archive)
+}
+
+async fn collect_settings(pool: &SqlitePool) -> Result<Option<ArchiveSettings>, SqlxError> {
+    let Some(setting) = SettingsRepository::get_model_config(pool).await? else {
+        return Ok(None);
+    };
+    let transcript = SettingsRepository::get_transcript_config(pool).await?;
+    let custom = setting
+        .custom_openai_config
+        .as_deref()
+        .and_then(|c| serde_json::from_str::<crate::summary::CustomOpenAIConfig>(c).ok());
+
+    Ok(Some(ArchiveSettings {
+        provider: setting.provider,
+        model: setting.model,
+        whisper_model: setting.whisper_model,
+        ollama_endpoint: setting.ollama_endpoint,
+        custom_openai_endpoint: custom.as_ref().map(|c| c.endpoint.clone()),
+        custom_openai_model: custom.map(|c| c.model),
+        transcript_provider: transcript.as_ref().map(|t| t.provider.clone()),
+        transcript_model: transcript.map(|t| t.model),
+    }))
+}
+
+async fn collect_meeting(
+    pool: &SqlitePool,
+    meeting: MeetingModel,
+) -> Result<ArchiveMeeting, SqlxError> {
+    let mut transcripts = sqlx::query_as::<_, ArchiveTranscript>(
+        "SELECT id, transcript, timestamp, audio_start_time, audio_end_time, duration, speaker
+         FROM transcripts WHERE meeting_id = ? ORDER BY audio_start_time ASC, timestamp ASC",
+    )
+    .bind(&meeting.id)
+    .fetch_all(pool)
+    .await?;
+    for t in &mut transcripts {
+        t.transcript = encryption::open(std::mem::take(&mut t.transcript))?;
+    }
+
+    let summary: Option<(String, Option<String>)> =
+        sqlx::query_as("SELECT status, result FROM summary_processes WHERE meeting_id = ?")
+            .bind(&meeting.id)
+            .fetch_optional(pool)
+            .await?;
+    let summary = summary
+        .map(|(status, result)| Ok::<_, SqlxError>((status, encryption::open_opt(result)?)))
+        .transpose()?;
+
+    let notes: Option<(Option<String>, Option<String>)> = sqlx::query_as(
+        "SELECT notes_markdown, notes_json FROM meeting_notes WHERE meeting_id = ?",
+    )
+    .bind(&meeting.id)
+    .fetch_optional(pool)
+    .await?;
+
+    let mut conn = pool.acquire().await?;
+    let redaction_mappings =
+        RedactionMappingsRepository::list_for_meeting(&mut conn, &meeting.id).await?;
+    drop(conn);
+
+    Ok(ArchiveMeeting {
+        transcripts,
+        summary: summary.map(|(status, result)| ArchiveSummary {
+            status,
+            result: result.and_then(|r| serde_json::from_str(&r).ok()),
+        }),
+        notes: notes.map(|(markdown, json)| ArchiveNotes { markdown, json }),
+        action_items: ActionItemsRepository::list_for_meeting(pool, &meeting.id).await?,
+        participants: ParticipantsRepository::list_participants(pool, &meeting.id).await?,
+        speakers: SpeakersRepository::list_meeting_speakers(pool, &meeting.id).await?,
+        attachments: AttachmentsRepository::list_attachments(pool, &meeting.id).await?,
+        custom_fields: CustomFieldsRepository::get_meeting_values(pool, &meeting.id)
+            .await?
+            .into_iter()
+            .map(|v| (v.name, v.value))
+            .collect(),
+        tags: TagsRepository::get_tags(pool, &meeting.id).await?,
+        comments: SegmentCommentsRepository::list_for_meeting(pool, &meeting.id).await?,
+        redaction_mappings,
+        id: meeting.id,
+        title: meeting.title,
+        created_at: meeting.created_at.0,
+        updated_at: meeting.updated_at.0,
+        folder_path: meeting.folder_path,
+    })
+}
+
+/// One meeting in archive form, or None if it doesn't exist
+pub async fn collect_single_meeting(
+    pool: &SqlitePool,
+    meeting_id: &str,
+) -> Result<Option<ArchiveMeeting>, SqlxError> {
+    match MeetingsRepository::get_meeting_metadata(pool, meeting_id).await? {
+        Some(meeting) => Ok(Some(collect_meeting(pool, meeting).await?)),
+        None => Ok(None),
+    }
+}
+
+/// Reads every meeting and the non-secret settings into an archive
+pub async fn collect_archive(pool: &SqlitePool, app_version: &str) -> Result<DataArchive, SqlxError> {
+    let meetings = MeetingsRepository::get_meetings(pool).await?;
+    let mut archived = Vec::with_capacity(meetings.len());
+    for meeting in meetings {
+        archived.push(collect_meeting(pool, meeting).await?);
+    }
+
+    Ok(DataArchive {
+        format: ARCHIVE_FORMAT.to_string(),
+        version: ARCHIVE_VERSION,
+        exported_at: Utc::now(),
+        app_version: app_version.to_string(),
+        settings: collect_settings(pool).await?,
+        custom_fields: CustomFieldsRepository::list_definitions(pool).await?,
+        meetings: archived,
+    })
+}
+
+/// Creates archived custom fields that don't exist yet (matched by name) and
+/// returns field ids keyed by lowercase name
+async fn import_custom_fields(
+    pool: &SqlitePool,
+    definitions: &[CustomFieldDefinition],
+) -> Result<HashMap<String, String>, SqlxError> {
+    let mut field_ids: HashMap<String, String> = CustomFieldsRepository::list_definitions(pool)
+        .await?
+        .into_iter()
+        .map(|field| (field.name.to_lowercase(), field.id))
+        .collect();
+
+    for definition in definitions {
+        if field_ids.contains_key(&definition.name.to_lowercase()) {
+            continue;
+        }
+        let input = CustomFieldInput {
+            name: definition.name.clone(),
+            field_type: definition.field_type.clone(),
+            options: definition
+                .options
+                .as_deref()
+                .and_then(|o| serde_json::from_str(o).ok()),
+            position: None,
+        };
+        match CustomFieldsRepository::create_definition(pool, &input).await {
+            Ok(created) => {
+                field_ids.insert(created.name.to_lowercase(), created.id);
+            }
+            Err(e) => warn!("Skipping custom field '{}': {}", definition.name, e),
+        }
+    }
+    Ok(field_ids)
+}
+
+async fn import_meeting(
+    pool: &SqlitePool,
+    meeting: &ArchiveMeeting,
+    field_ids: &HashMap<String, String>,
+) -> Result<(), SqlxError> {
+    let mut conn = pool.acquire().await?;
+    let mut transaction = conn.begin().await?;
+
+    sqlx::query(
+        "INSERT INTO meetings (id, title, created_at, updated_at, folder_path) VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(&meeting.id)
+    .bind(&meeting.title)
+    .bind(meeting.created_at)
+    .bind(meeting.updated_at)
+    .bind(&meeting.folder_path)
+    .execute(&mut *transaction)
+    .await?;
+
+    for t in &meeting.transcripts {
+        sqlx::query(
+            "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration, speaker)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&t.id)
+        .bind(&meeting.id)
+        .bind(encryption::seal(&t.transcript)?)
+        .bind(&t.timestamp)
+        .bind(t.audio_start_time)
+        .bind(t.audio_end_time)
+        .bind(t.duration)
+        .bind(&t.speaker)
+        .execute(&mut *transaction)
+        .await?;
+    }
+
+    if let Some(summary) = &meeting.summary {
+        let result = summary.result.as_ref().map(|r| r.to_string());
+        let result = encryption::seal_opt(result.as_deref())?;
+        sqlx::query(
+            "INSERT INTO summary_processes (meeting_id, status, created_at, updated_at, result)
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&meeting.id)
+        .bind(&summary.status)
+        .bind(meeting.created_at)
+        .bind(meeting.updated_at)
+        .bind(result)
+        .execute(&mut *transaction)
+        .await?;
+    }
+
+    if let Some(notes) = &meeting.notes {
+        sqlx::query(
+            "INSERT INTO meeting_notes (meeting_id, notes_markdown, notes_json, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&meeting.id)
+        .bind(&notes.markdown)
+        .bind(&notes.json)
+        .bind(meeting.created_at)
+        .bind(meeting.updated_at)
+        .execute(&mut *transaction)
+        .await?;
+    }
+
+    for item in &meeting.action_items {
+        sqlx::query(
+            "INSERT INTO action_items (id, meeting_id, text, owner, due_date, status, source, created_at, updated_at, external_tracker, external_key, external_url)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&item.id)
+        .bind(&meeting.id)
+        .bind(&item.text)
+        .bind(&item.owner)
+        .bind(&item.due_date)
+        .bind(&item.status)
+        .bind(&item.source)
+        .bind(item.created_at.0)
+        .bind(item.updated_at.0)
+        .bind(&item.external_tracker)
+        .bind(&item.external_key)
+        .bind(&item.external_url)
+        .execute(&mut *transaction)
+        .await?;
+    }
+
+    for p in &meeting.participants {
+        sqlx::query(
+            "INSERT INTO meeting_participants (id, meeting_id, name, email, role, speaker_label, source, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&p.id)
+        .bind(&meeting.id)
+        .bind(&p.name)
+        .bind(&p.email)
+        .bind(&p.role)
+        .bind(&p.speaker_label)
+        .bind(&p.source)
+        .bind(p.created_at.0)
+        .bind(p.updated_at.0)
+        .execute(&mut *transaction)
+        .await?;
+    }
+
+    // Voice profiles are local to the exporting machine, so only the names come across
+    for s in &meeting.speakers {
+        sqlx::query(
+            "INSERT INTO meeting_speakers (meeting_id, speaker_label, display_name, updated_at)
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(&meeting.id)
+        .bind(&s.speaker_label)
+        .bind(&s.display_name)
+        .bind(s.updated_at.0)
+        .execute(&mut *transaction)
+        .await?;
+    }
+
+    for a in &meeting.attachments {
+        sqlx::query(
+            "INSERT INTO meeting_attachments (id, meeting_id, file_name, stored_path, mime_type, size_bytes, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&a.id)
+        .bind(&meeting.id)
+        .bind(&a.file_name)
+        .bind(&a.stored_path)
+        .bind(&a.mime_type)
+        .bind(a.size_bytes)
+        .bind(a.created_at.0)
+        .execute(&mut *transaction)
+        .await?;
+    }
+
+    for (name, value) in &meeting.custom_fields {
+        let Some(field_id) = field_ids.get(&name.to_lowercase()) else {
+            continue;
+        };
+        sqlx::query(
+            "INSERT INTO meeting_custom_fields (meeting_id, field_id, value, updated_at)
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(&meeting.id)
+        .bind(field_id)
+        .bind(value)
+        .bind(meeting.updated_at)
+        .execute(&mut *transaction)
+        .await?;
+    }
+
+    for tag in &meeting.tags {
+        sqlx::query(
+            "INSERT OR IGNORE INTO meeting_tags (meeting_id, tag, created_at) VALUES (?, ?, ?)",
+        )
+        .bind(&meeting.id)
+        .bind(tag)
+        .bind(meeting.updated_at)
+        .execute(&mut *transaction)
+        .await?;
+    }
+
+    SegmentCommentsRepository::insert_synced(&mut *transaction, &meeting.id, &meeting.comments)
+        .await?;
+
+    if !meeting.redaction_mappings.is_empty() {
+        if encryption::is_enabled() {
+            RedactionMappingsRepository::insert(
+                &mut *transaction,
+                &meeting.id,
+                &meeting.redaction_mappings,
+            )
+            .await?;
+        } else {
+            warn!(
+                "Skipping {} redaction mappings for meeting {}: encryption is not enabled",
+                meeting.redaction_mappings.len(),
+                meeting.id
+            );
+        }
+    }
+
+    transaction.commit().await
+}
+
+/// Imports an archive. Meetings whose id already exists are skipped, so
+/// re-importing the same file is harmless. Each meeting is imported atomically.
+pub async fn import_archive(
+    pool: &SqlitePool,
+    archive: &DataArchive,
+    import_settings: bool,
+) -> Result<ImportReport, SqlxError> {
+    let mut report = ImportReport::default();
+    let field_ids = import_custom_fields(pool, &archive.custom_fields).await?;
+
+    for meeting in &archive.meetings {
+        if MeetingsRepository::get_meeting_metadata(pool, &meeting.id)
+            .await?
+            .is_some()
+        {
+            report.meetings_skipped += 1;
+            continue;
+        }
+
+        match import_meeting(pool, meeting, &field_ids).await {
+            Ok(()) => {
+                report.meetings_imported += 1;
+                report.transcripts_imported += meeting.transcripts.len();
+            }
+            Err(e) => {
+                warn!("Failed to import meeting {}: {}", meeting.id, e);
+                report.meetings_skipped += 1;
+            }
+        }
+    }
+
+    if import_settings {
+        if let Some(settings) = &archive.settings {
+            SettingsRepository::save_model_config(
+                pool,
+                &settings.provider,
+                &settings.model,
+                &settings.whisper_model,
+                settings.ollama_endpoint.as_deref(),
+            )
+            .await?;
+            if let (Some(provider), Some(model)) =
+                (&settings.transcript_provider, &settings.transcript_model)
+            {
+                SettingsRepository::save_transcript_config(pool, provider, model).await?;
+            }
+            report.settings_imported = true;
+        }
+    }
+
+    info!(
+        "Archive import finished: {} imported, {} skipped, {} transcripts",
+        report.meetings_imported, report.meetings_skipped, report.transcripts_imported
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn archive_json(format: &str, version: u32) -> String {
+        serde_json::json!({
+            "format": format,
+            "version": version,
+            "exported_at": "2026-01-20T10:00:00Z",
+            "app_version": "0.2.0",
+            "settings": null,
+            "meetings": [{
+                "id": "meeting-1",
+                "title": "Standup",
+                "created_at": "2026-01-19T09:00:00Z",
+                "updated_at": "2026-01-19T09:30:00Z",
+                "folder_path": null,
+                "transcripts": [],
+                "summary": null,
+                "notes": null
+            }]
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn parses_valid_archive() {
+        let archive = parse_archive(&archive_json(ARCHIVE_FORMAT, 1)).unwrap();
+        assert_eq!(archive.meetings.len(), 1);
+        assert!(archive.meetings[0].participants.is_empty());
+        assert!(archive.custom_fields.is_empty());
+        assert!(archive.meetings[0].custom_fields.is_empty());
+    }
+
+    #[test]
+    fn rejects_foreign_or_newer_archives() {
+        assert!(parse_archive(&archive_json("something-else", 1)).is_err());
+        assert!(parse_archive(&archive_json(ARCHIVE_FORMAT, ARCHIVE_VERSION + 1)).is_err());
+        assert!(parse_archive("not json").is_err());
+    }
+}
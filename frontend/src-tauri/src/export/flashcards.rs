@@ -0,0 +1,226 @@
+//! Flashcards from the key points and decisions of a summary.
+//!
+//! Each bullet (or table row) under a "Key Points", "Decisions", "Takeaways"
+//! or similar heading becomes a card. A bullet shaped `**Term**: explanation`
+//! or `Term: explanation` asks for the term's explanation; any other bullet
+//! is the answer to "<section> — <meeting title>". Cards are written as an
+//! Anki text import (File > Import picks up the deck and columns from the
+//! header lines) or as plain CSV for other flashcard apps.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+
+use super::csv::escape_field;
+use super::html::escape_html;
+
+static CARD_HEADING: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^#{1,6}\s*\**\s*(?:key\s+(?:points?|decisions?|takeaways?|learnings?)|decisions?(?:\s+made)?|takeaways?|learnings?|highlights?|discussion\s+highlights|lessons?\s+learned|要点|決定事項|ポイント|学び)\b",
+    )
+    .unwrap()
+});
+
+static BULLET_PREFIX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\s*(?:[-*+]|\d+[.)])\s+(?:\[[ xX]\]\s+)?").unwrap());
+
+/// `**Term**: explanation`, `**Term** - explanation` or `Term: explanation`
+static TERM_DEFINITION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:\*\*([^*]{1,80}?)\*\*\s*[:\-–—]|([^:*]{1,60}?):)\s+(.+)$").unwrap()
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Flashcard {
+    pub front: String,
+    pub back: String,
+    /// "decision" or "key_point"
+    pub kind: &'static str,
+}
+
+fn strip_markup(text: &str) -> String {
+    text.replace("**", "").replace('`', "").trim().to_string()
+}
+
+fn heading_title(line: &str) -> String {
+    strip_markup(line.trim_start_matches('#'))
+}
+
+fn card(section: &str, meeting_title: &str, kind: &'static str, body: &str) -> Option<Flashcard> {
+    let body = body.trim();
+    if body.is_empty() {
+        return None;
+    }
+    let (front, back) = match TERM_DEFINITION.captures(body) {
+        Some(caps) => {
+            let term = caps
+                .get(1)
+                .or_else(|| caps.get(2))
+                .map_or("", |m| m.as_str());
+            (strip_markup(term), strip_markup(&caps[3]))
+        }
+        None => (
+            format!("{} — {}", section, meeting_title.trim()),
+            strip_markup(body),
+        ),
+    };
+    (!front.is_empty() && !back.is_empty()).then_some(Flashcard { front, back, kind })
+}
+
+/// Cards from the key point and decision sections of `markdown`
+pub fn extract_flashcards(meeting_title: &str, markdown: &str) -> Vec<Flashcard> {
+    let mut cards = Vec::new();
+    let mut section: Option<(String, &'static str)> = None;
+    let mut table_header_seen = false;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            section = CARD_HEADING.is_match(trimmed).then(|| {
+                let title = heading_title(trimmed);
+                let lowered = title.to_lowercase();
+                let kind = if lowered.contains("decision") || title.contains("決定") {
+                    "decision"
+                } else {
+                    "key_point"
+                };
+                (title, kind)
+            });
+            table_header_seen = false;
+            continue;
+        }
+        let Some((title, kind)) = &section else {
+            continue;
+        };
+
+        if trimmed.starts_with('|') {
+            let cells: Vec<String> = trimmed
+                .trim_matches('|')
+                .split('|')
+                .map(strip_markup)
+                .collect();
+            if cells
+                .iter()
+                .all(|c| c.chars().all(|ch| ch == '-' || ch == ':'))
+            {
+                continue;
+            }
+            if !table_header_seen {
+                table_header_seen = true;
+                continue;
+            }
+            let filled: Vec<&String> = cells.iter().filter(|c| !c.is_empty()).collect();
+            match filled.as_slice() {
+                [] => {}
+                [only] => cards.extend(card(title, meeting_title, kind, only)),
+                [first, rest @ ..] => cards.push(Flashcard {
+                    front: first.to_string(),
+                    back: rest
+                        .iter()
+                        .map(|c| c.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" — "),
+                    kind,
+                }),
+            }
+            continue;
+        }
+
+        if let Some(prefix) = BULLET_PREFIX.find(line) {
+            cards.extend(card(title, meeting_title, kind, &line[prefix.end()..]));
+        }
+    }
+    cards
+}
+
+fn tags(card: &Flashcard) -> String {
+    format!("meetily {}", card.kind)
+}
+
+/// An Anki text import into the deck `Meetily::<meeting title>`
+pub fn render_anki(meeting_title: &str, cards: &[Flashcard]) -> String {
+    // Tabs and newlines would end the field, and "::" nests decks
+    let field = |text: &str| escape_html(text).replace(['\t', '\n', '\r'], " ");
+    let deck = meeting_title
+        .replace("::", ":")
+        .replace(['\t', '\n', '\r'], " ");
+    let mut out = format!(
+        "#separator:tab\n#html:true\n#notetype:Basic\n#deck:Meetily::{}\n#tags column:3\n",
+        deck.trim()
+    );
+    for card in cards {
+        out.push_str(&format!(
+            "{}\t{}\t{}\n",
+            field(&card.front),
+            field(&card.back),
+            tags(card)
+        ));
+    }
+    out
+}
+
+pub fn render_csv(cards: &[Flashcard]) -> String {
+    let mut out = String::from("front,back,tags\n");
+    for card in cards {
+        out.push_str(&format!(
+            "{},{},{}\n",
+            escape_field(&card.front),
+            escape_field(&card.back),
+            escape_field(&tags(card))
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUMMARY: &str = "# Training\n\n## Key Points\n\
+        - **Backpressure**: slowing producers when consumers lag\n\
+        - Retries need jitter\n\n\
+        ## Key Decisions\n\
+        | Decision | Reason |\n| --- | --- |\n| Use Kafka | Existing ops knowledge |\n\n\
+        ## Action Items\n- Alice: write the doc\n";
+
+    #[test]
+    fn extracts_cards_from_key_points_and_decisions() {
+        let cards = extract_flashcards("Streaming 101", SUMMARY);
+        assert_eq!(
+            cards,
+            vec![
+                Flashcard {
+                    front: "Backpressure".into(),
+                    back: "slowing producers when consumers lag".into(),
+                    kind: "key_point",
+                },
+                Flashcard {
+                    front: "Key Points — Streaming 101".into(),
+                    back: "Retries need jitter".into(),
+                    kind: "key_point",
+                },
+                Flashcard {
+                    front: "Use Kafka".into(),
+                    back: "Existing ops knowledge".into(),
+                    kind: "decision",
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_anki_and_csv() {
+        let cards = vec![Flashcard {
+            front: "A <b>\tterm".into(),
+            back: "x, \"y\"".into(),
+            kind: "key_point",
+        }];
+        let anki = render_anki("Ops::Sync", &cards);
+        assert!(anki.starts_with("#separator:tab\n"));
+        assert!(anki.contains("#deck:Meetily::Ops:Sync\n"));
+        assert!(anki.ends_with("A &lt;b&gt; term\tx, &quot;y&quot;\tmeetily key_point\n"));
+        assert_eq!(
+            render_csv(&cards),
+            "front,back,tags\nA <b>\tterm,\"x, \"\"y\"\"\",meetily key_point\n"
+        );
+    }
+}
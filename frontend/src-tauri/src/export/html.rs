@@ -0,0 +1,205 @@
+//! Markdown -> HTML rendering for summaries (email bodies, printable exports).
+
+use pulldown_cmark::{html, Event, Options, Parser};
+
+/// Inline styles only: most mail clients strip <style> blocks
+const EMAIL_WRAPPER_STYLE: &str =
+    "font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, Helvetica, Arial, sans-serif; \
+     font-size: 14px; line-height: 1.5; color: #1f2933; max-width: 720px;";
+
+pub fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn gfm_options() -> Options {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+    options
+}
+
+/// Renders GitHub-flavoured markdown (tables, task lists, strikethrough) to an HTML fragment
+pub fn markdown_to_html(markdown: &str) -> String {
+    let parser = Parser::new_ext(markdown, gfm_options());
+    let mut out = String::new();
+    html::push_html(&mut out, parser);
+    out
+}
+
+/// Like [`markdown_to_html`], but raw HTML in the markdown is shown as text.
+/// For pages served to people outside the app.
+pub fn markdown_to_safe_html(markdown: &str) -> String {
+    let parser = Parser::new_ext(markdown, gfm_options()).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        other => other,
+    });
+    let mut out = String::new();
+    html::push_html(&mut out, parser);
+    out
+}
+
+/// Wraps a meeting summary into a complete HTML email body
+pub fn render_summary_email(meeting_title: &str, meeting_date: &str, summary_markdown: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>{title}</title></head>\
+         <body><div style=\"{style}\"><h1 style=\"font-size: 20px;\">{title}</h1>\
+         <p style=\"color: #616e7c;\">{date}</p>{body}</div></body></html>",
+        title = escape_html(meeting_title),
+        date = escape_html(meeting_date),
+        style = EMAIL_WRAPPER_STYLE,
+        body = markdown_to_html(summary_markdown),
+    )
+}
+
+/// Standalone read-only page for a guest share link: the summary, then the
+/// transcript lines. No scripts or external resources.
+pub fn render_share_page(
+    meeting_title: &str,
+    meeting_date: &str,
+    summary_markdown: Option<&str>,
+    transcript: &[String],
+) -> String {
+    let mut body = String::new();
+    if let Some(markdown) = summary_markdown {
+        body.push_str("<h2 style=\"font-size: 16px;\">Summary</h2>");
+        body.push_str(&markdown_to_safe_html(markdown));
+    }
+    if !transcript.is_empty() {
+        body.push_str("<h2 style=\"font-size: 16px;\">Transcript</h2>");
+        for line in transcript {
+            body.push_str("<p style=\"margin: 4px 0;\">");
+            body.push_str(&escape_html(line));
+            body.push_str("</p>");
+        }
+    }
+    format!(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\">\
+         <meta name=\"robots\" content=\"noindex\"><title>{title}</title></head>\
+         <body><div style=\"{style} margin: 24px auto;\"><h1 style=\"font-size: 20px;\">{title}</h1>\
+         <p style=\"color: #616e7c;\">{date}</p>{body}</div></body></html>",
+        title = escape_html(meeting_title),
+        date = escape_html(meeting_date),
+        style = EMAIL_WRAPPER_STYLE,
+        body = body,
+    )
+}
+
+/// Print stylesheet of the minutes document; a file opened in a browser can
+/// use a <style> block, unlike an email
+const MINUTES_STYLE: &str = "\
+body { font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, Helvetica, Arial, sans-serif; \
+font-size: 14px; line-height: 1.5; color: #1f2933; max-width: 760px; margin: 32px auto; padding: 0 24px; }
+h1 { font-size: 22px; margin-bottom: 4px; }
+h2 { font-size: 16px; border-bottom: 1px solid #d9e2ec; padding-bottom: 4px; margin-top: 28px; }
+.meta { color: #616e7c; margin-top: 0; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #d9e2ec; padding: 4px 8px; text-align: left; }
+ul.attendees { padding-left: 20px; }
+.transcript p { margin: 4px 0; }
+@page { margin: 18mm; }
+@media print { body { margin: 0; max-width: none; padding: 0; } h2 { break-after: avoid; } \
+.transcript { break-before: page; } }";
+
+/// Standalone, printable minutes of a meeting: attendees, the summary, then
+/// the transcript lines. No scripts or external resources.
+pub fn render_minutes_document(
+    meeting_title: &str,
+    meeting_date: &str,
+    attendees: &[String],
+    summary_markdown: Option<&str>,
+    transcript: &[String],
+) -> String {
+    let mut body = String::new();
+    if !attendees.is_empty() {
+        body.push_str("<h2>Attendees</h2><ul class=\"attendees\">");
+        for attendee in attendees {
+            body.push_str("<li>");
+            body.push_str(&escape_html(attendee));
+            body.push_str("</li>");
+        }
+        body.push_str("</ul>");
+    }
+    if let Some(markdown) = summary_markdown {
+        body.push_str("<h2>Summary</h2>");
+        body.push_str(&markdown_to_safe_html(markdown));
+    }
+    if !transcript.is_empty() {
+        body.push_str("<section class=\"transcript\"><h2>Transcript</h2>");
+        for line in transcript {
+            body.push_str("<p>");
+            body.push_str(&escape_html(line));
+            body.push_str("</p>");
+        }
+        body.push_str("</section>");
+    }
+    format!(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\">\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\
+         <title>{title}</title><style>{style}</style></head>\
+         <body><h1>{title}</h1><p class=\"meta\">{date}</p>{body}</body></html>",
+        title = escape_html(meeting_title),
+        date = escape_html(meeting_date),
+        style = MINUTES_STYLE,
+        body = body,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_title() {
+        let html = render_summary_email("Q&A <sync>", "2026-01-20", "Hello");
+        assert!(html.contains("Q&amp;A &lt;sync&gt;"));
+        assert!(html.contains("<p>Hello</p>"));
+    }
+
+    #[test]
+    fn share_page_escapes_transcript() {
+        let html = render_share_page(
+            "Sync",
+            "2026-01-20",
+            Some("**Decisions** <img src=x onerror=alert(1)>"),
+            &["[00:05] <script>alert(1)</script>".to_string()],
+        );
+        assert!(html.contains("<strong>Decisions</strong>"));
+        assert!(html.contains("[00:05] &lt;script&gt;"));
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("<img"));
+    }
+
+    #[test]
+    fn minutes_document_lists_attendees_and_skips_empty_sections() {
+        let html = render_minutes_document(
+            "Sync",
+            "2026-01-20 10:00",
+            &["Alice (PM)".to_string(), "<Bob>".to_string()],
+            None,
+            &["[00:05] Hello".to_string()],
+        );
+        assert!(html.contains("<li>Alice (PM)</li><li>&lt;Bob&gt;</li>"));
+        assert!(html.contains("<p>[00:05] Hello</p>"));
+        assert!(!html.contains("<h2>Summary</h2>"));
+        assert!(html.contains("@media print"));
+    }
+
+    #[test]
+    fn renders_tables() {
+        let html = markdown_to_html("| Owner | Task |\n| --- | --- |\n| Alice | Ship |\n");
+        assert!(html.contains("<table>"));
+        assert!(html.contains("<td>Alice</td>"));
+    }
+}
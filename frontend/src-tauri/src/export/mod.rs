@@ -0,0 +1,23 @@
+/// Export module - moving meeting data out of (and back into) the app
+///
+/// This module contains:
+/// - Full data archive: every meeting with transcripts, summaries, notes and
+///   participants plus non-secret settings, as a single documented JSON file
+/// - CSV exports of the meetings index and action items for spreadsheets
+/// - Markdown to HTML rendering for emails and printable minutes documents
+/// - Audio excerpts of a meeting recording with matching SRT captions
+/// - A meeting's summary, transcript or action items copied to the clipboard
+/// - Exports rendered from user templates in the export templates folder
+/// - Flashcards (Anki text import or CSV) from summary key points and decisions
+/// - Tauri commands for frontend integration
+
+pub mod clip;
+pub mod clipboard;
+pub mod commands;
+pub mod csv;
+pub mod data_archive;
+pub mod flashcards;
+pub mod html;
+pub mod templates;
+
+pub use data_archive::{DataArchive, ImportReport, ARCHIVE_FORMAT, ARCHIVE_VERSION};
@@ -0,0 +1,282 @@
+//! User export templates.
+//!
+//! Templates are Tera files in the export templates folder, named after the
+//! file they produce plus `.tera`: `minutes.md.tera` renders `minutes.md`.
+//! They see one meeting as [`ExportModel`]; output for `.html` files is
+//! HTML-escaped unless a value is marked `| safe`.
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use tera::{Context, Tera};
+
+use crate::api::MeetingTranscript;
+use crate::database::models::{ActionItem, MeetingParticipant};
+use crate::export::clipboard::transcript_lines;
+use crate::export::html::markdown_to_safe_html;
+
+pub const TEMPLATE_EXTENSION: &str = "tera";
+/// Subfolder of a meeting folder that exports without a chosen path go to
+pub const EXPORTS_DIR_NAME: &str = "exports";
+/// Numbered names tried before giving up on finding a free one
+const MAX_NAME_ATTEMPTS: u32 = 1000;
+
+/// The export templates folder:
+/// - macOS: ~/Library/Application Support/Meetily/export_templates/
+/// - Windows: %APPDATA%\Meetily\export_templates\
+/// - Linux: ~/.local/share/Meetily/export_templates/
+pub fn templates_dir() -> Option<PathBuf> {
+    let mut path = dirs::data_dir()?;
+    path.push("Meetily");
+    path.push("export_templates");
+    Some(path)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportTemplate {
+    /// Name of the rendered file, e.g. "minutes.md"
+    pub name: String,
+    pub path: String,
+}
+
+/// The name a template file renders to, or None if it isn't a template
+pub fn output_name(file_name: &str) -> Option<&str> {
+    file_name
+        .strip_suffix(TEMPLATE_EXTENSION)?
+        .strip_suffix('.')
+        .filter(|name| !name.is_empty() && !name.starts_with('.'))
+}
+
+/// Templates in `dir`, by name
+pub fn list_templates(dir: &Path) -> Vec<ExportTemplate> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut templates: Vec<ExportTemplate> = entries
+        .flatten()
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            Some(ExportTemplate {
+                name: output_name(&file_name)?.to_string(),
+                path: entry.path().to_string_lossy().to_string(),
+            })
+        })
+        .collect();
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    templates
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportAttendee {
+    pub name: String,
+    pub email: Option<String>,
+    pub role: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportSegment {
+    /// Seconds into the recording, when known
+    pub start: Option<f64>,
+    pub end: Option<f64>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportActionItem {
+    pub text: String,
+    pub owner: Option<String>,
+    pub due_date: Option<String>,
+    pub status: String,
+}
+
+/// What a template sees
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportModel {
+    pub id: String,
+    pub title: String,
+    /// "YYYY-MM-DD HH:MM"
+    pub date: String,
+    pub created_at: String,
+    pub folder_path: Option<String>,
+    pub attendees: Vec<ExportAttendee>,
+    /// Summary markdown, and the same rendered as HTML
+    pub summary: Option<String>,
+    pub summary_html: Option<String>,
+    pub transcript: Vec<ExportSegment>,
+    /// The transcript as `[mm:ss] text` lines
+    pub transcript_text: String,
+    pub action_items: Vec<ExportActionItem>,
+}
+
+impl ExportModel {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        title: String,
+        created_at: chrono::DateTime<chrono::Utc>,
+        folder_path: Option<String>,
+        participants: Vec<MeetingParticipant>,
+        summary: Option<String>,
+        segments: &[MeetingTranscript],
+        action_items: Vec<ActionItem>,
+    ) -> Self {
+        Self {
+            id,
+            title,
+            date: created_at.format("%Y-%m-%d %H:%M").to_string(),
+            created_at: created_at.to_rfc3339(),
+            folder_path,
+            attendees: participants
+                .into_iter()
+                .map(|p| ExportAttendee {
+                    name: p.name,
+                    email: p.email,
+                    role: p.role,
+                })
+                .collect(),
+            summary_html: summary.as_deref().map(markdown_to_safe_html),
+            summary,
+            transcript: segments
+                .iter()
+                .filter(|segment| !segment.text.trim().is_empty())
+                .map(|segment| ExportSegment {
+                    start: segment.audio_start_time,
+                    end: segment.audio_end_time,
+                    text: segment.text.trim().to_string(),
+                })
+                .collect(),
+            transcript_text: transcript_lines(segments, true).join("\n"),
+            action_items: action_items
+                .into_iter()
+                .map(|item| ExportActionItem {
+                    text: item.text,
+                    owner: item.owner,
+                    due_date: item.due_date,
+                    status: item.status,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Writes `content` to a new file `name` in `dir`, never replacing an
+/// existing file: when `name` is taken, "minutes-2.md", "minutes-3.md" and
+/// so on are tried. Returns the path written.
+pub fn write_new_output(dir: &Path, name: &str, content: &str) -> std::io::Result<PathBuf> {
+    use std::io::Write;
+
+    std::fs::create_dir_all(dir)?;
+    let (stem, extension) = match name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, Some(extension)),
+        _ => (name, None),
+    };
+    for n in 1..=MAX_NAME_ATTEMPTS {
+        let candidate = match (n, extension) {
+            (1, _) => name.to_string(),
+            (n, Some(extension)) => format!("{}-{}.{}", stem, n, extension),
+            (n, None) => format!("{}-{}", stem, n),
+        };
+        let path = dir.join(candidate);
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(mut file) => {
+                file.write_all(content.as_bytes())?;
+                return Ok(path);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Err(std::io::Error::new(
+        std::io::ErrorKind::AlreadyExists,
+        format!("No free file name for {} in {}", name, dir.display()),
+    ))
+}
+
+/// Renders `template` (named `name`, e.g. "minutes.md") with `model`
+pub fn render(name: &str, template: &str, model: &ExportModel) -> Result<String, String> {
+    let context =
+        Context::from_serialize(model).map_err(|e| format!("Invalid template data: {}", e))?;
+    let autoescape = name.ends_with(".html") || name.ends_with(".htm");
+    Tera::one_off(template, &context, autoescape).map_err(|e| {
+        // The cause says where in the template it went wrong
+        let cause = std::error::Error::source(&e)
+            .map(|cause| format!(": {}", cause))
+            .unwrap_or_default();
+        format!("Template '{}' failed to render{}", name, cause)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn template_names() {
+        assert_eq!(output_name("minutes.md.tera"), Some("minutes.md"));
+        assert_eq!(output_name("notes.tera"), Some("notes"));
+        assert_eq!(output_name(".tera"), None);
+        assert_eq!(output_name("minutes.md"), None);
+        assert_eq!(output_name("minutestera"), None);
+    }
+
+    #[test]
+    fn renders_the_meeting_model() {
+        let model = ExportModel::new(
+            "meeting-1".to_string(),
+            "Q&A sync".to_string(),
+            chrono::Utc.with_ymd_and_hms(2026, 1, 20, 10, 0, 0).unwrap(),
+            None,
+            Vec::new(),
+            Some("**Ship** it".to_string()),
+            &[MeetingTranscript {
+                id: "t".to_string(),
+                text: "Hello".to_string(),
+                timestamp: "10:00".to_string(),
+                audio_start_time: Some(5.0),
+                audio_end_time: None,
+                duration: None,
+                speaker: None,
+                uncertain: false,
+            }],
+            Vec::new(),
+        );
+        let markdown = "# {{ title }} ({{ date }})\n{{ summary }}\n{% for s in transcript %}{{ s.text }}{% endfor %}\n{{ transcript_text }}";
+        assert_eq!(
+            render("minutes.md", markdown, &model).unwrap(),
+            "# Q&A sync (2026-01-20 10:00)\n**Ship** it\nHello\n[00:05] Hello"
+        );
+        let html = render(
+            "minutes.html",
+            "<h1>{{ title }}</h1>{{ summary_html | safe }}",
+            &model,
+        )
+        .unwrap();
+        assert_eq!(
+            html,
+            "<h1>Q&amp;A sync</h1><p><strong>Ship</strong> it</p>\n"
+        );
+        assert!(render("broken.md", "{{ title", &model).is_err());
+    }
+
+    #[test]
+    fn outputs_never_replace_existing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let exports = dir.path().join(EXPORTS_DIR_NAME);
+        let first = write_new_output(&exports, "minutes.md", "one").unwrap();
+        let second = write_new_output(&exports, "minutes.md", "two").unwrap();
+        let bare = write_new_output(&exports, "notes", "three").unwrap();
+        let bare_again = write_new_output(&exports, "notes", "four").unwrap();
+
+        assert_eq!(first, exports.join("minutes.md"));
+        assert_eq!(second, exports.join("minutes-2.md"));
+        assert_eq!(bare_again, exports.join("notes-2"));
+        assert_eq!(std::fs::read_to_string(first).unwrap(), "one");
+        assert_eq!(std::fs::read_to_string(second).unwrap(), "two");
+        assert_eq!(std::fs::read_to_string(bare).unwrap(), "three");
+    }
+}
@@ -0,0 +1,56 @@
+use log::info as log_info;
+use tauri::{AppHandle, Runtime};
+
+use super::runner::{run_hook, HookOutcome};
+use super::{build_payload, load_hooks, save_hooks, HookConfig};
+use crate::state::AppState;
+
+#[tauri::command]
+pub async fn api_list_hooks<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<HookConfig>, String> {
+    log_info!("api_list_hooks called");
+    load_hooks(state.db_manager.pool()).await
+}
+
+/// Replaces the full hook list
+#[tauri::command]
+pub async fn api_save_hooks<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    hooks: Vec<HookConfig>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_save_hooks called with {} hooks", hooks.len());
+    save_hooks(state.db_manager.pool(), &hooks).await?;
+    Ok(serde_json::json!({
+        "status": "success",
+        "count": hooks.len(),
+    }))
+}
+
+/// Runs a hook immediately against an existing meeting and returns its output,
+/// regardless of whether the hook is enabled
+#[tauri::command]
+pub async fn api_test_hook<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    hook_id: String,
+    meeting_id: String,
+) -> Result<HookOutcome, String> {
+    crate::validation::hook_id(&hook_id)?;
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_test_hook called for hook {} with meeting {}",
+        hook_id,
+        meeting_id
+    );
+    let pool = state.db_manager.pool();
+    let hook = load_hooks(pool)
+        .await?
+        .into_iter()
+        .find(|h| h.id == hook_id)
+        .ok_or_else(|| format!("Hook '{}' not found", hook_id))?;
+    let payload = build_payload(pool, hook.event, &meeting_id).await?;
+    run_hook(&hook, &payload.to_string()).await
+}
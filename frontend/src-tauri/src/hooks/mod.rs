@@ -0,0 +1,199 @@
+/// Hooks module - user-configured scripts run after meeting events
+///
+/// This module contains:
+/// - Hook configuration (stored in `integration_settings` under the id "hooks")
+/// - Event payload assembly: meeting data serialized as JSON
+/// - The runner that executes hook commands with the payload on stdin (see `runner`)
+/// - Tauri commands for managing and test-running hooks
+///
+/// Hooks run in the background; failures and output are written to the log and
+/// never affect the operation that triggered them.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+
+use crate::database::repositories::{
+    action_item::ActionItemsRepository, integration::IntegrationSettingsRepository,
+    meeting::MeetingsRepository, summary::SummaryProcessesRepository,
+};
+
+pub mod commands;
+pub mod runner;
+
+pub const HOOKS_SETTINGS_ID: &str = "hooks";
+pub const DEFAULT_TIMEOUT_SECS: u64 = 60;
+pub const MAX_TIMEOUT_SECS: u64 = 600;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookEvent {
+    TranscriptSaved,
+    SummaryGenerated,
+}
+
+impl HookEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HookEvent::TranscriptSaved => "transcript_saved",
+            HookEvent::SummaryGenerated => "summary_generated",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HookConfig {
+    pub id: String,
+    pub name: String,
+    pub event: HookEvent,
+    /// Run through the platform shell (`sh -c` / `cmd /C`)
+    pub command: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(rename = "timeoutSecs", default = "default_timeout")]
+    pub timeout_secs: u64,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_timeout() -> u64 {
+    DEFAULT_TIMEOUT_SECS
+}
+
+impl HookConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        crate::validation::hook_id(&self.id)?;
+        if self.command.trim().is_empty() {
+            return Err(format!("Hook '{}' has no command", self.name));
+        }
+        if self.timeout_secs == 0 || self.timeout_secs > MAX_TIMEOUT_SECS {
+            return Err(format!(
+                "Hook '{}' timeout must be between 1 and {} seconds",
+                self.name, MAX_TIMEOUT_SECS
+            ));
+        }
+        Ok(())
+    }
+}
+
+pub async fn load_hooks(pool: &SqlitePool) -> Result<Vec<HookConfig>, String> {
+    let raw = IntegrationSettingsRepository::get_config(pool, HOOKS_SETTINGS_ID)
+        .await
+        .map_err(|e| format!("Failed to load hooks: {}", e))?;
+    match raw {
+        Some(raw) => {
+            serde_json::from_str(&raw).map_err(|e| format!("Stored hooks are invalid: {}", e))
+        }
+        None => Ok(Vec::new()),
+    }
+}
+
+pub async fn save_hooks(pool: &SqlitePool, hooks: &[HookConfig]) -> Result<(), String> {
+    for hook in hooks {
+        hook.validate()?;
+    }
+    let mut ids = std::collections::HashSet::new();
+    if let Some(dup) = hooks.iter().find(|h| !ids.insert(h.id.as_str())) {
+        return Err(format!("Duplicate hook id '{}'", dup.id));
+    }
+    let raw =
+        serde_json::to_string(hooks).map_err(|e| format!("Failed to serialize hooks: {}", e))?;
+    IntegrationSettingsRepository::save_config(pool, HOOKS_SETTINGS_ID, &raw)
+        .await
+        .map_err(|e| format!("Failed to save hooks: {}", e))
+}
+
+/// Builds the JSON passed to hooks on stdin
+pub async fn build_payload(
+    pool: &SqlitePool,
+    event: HookEvent,
+    meeting_id: &str,
+) -> Result<Value, String> {
+    let meeting = MeetingsRepository::get_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting '{}' not found", meeting_id))?;
+
+    let mut payload = json!({
+        "event": event.as_str(),
+        "meeting": meeting,
+    });
+
+    if event == HookEvent::SummaryGenerated {
+        let markdown = SummaryProcessesRepository::get_summary_data(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load summary: {}", e))?
+            .and_then(|p| p.result)
+            .and_then(|r| serde_json::from_str::<Value>(&r).ok())
+            .and_then(|v| v.get("markdown").cloned());
+        let action_items = ActionItemsRepository::list_for_meeting(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load action items: {}", e))?;
+        payload["summary"] = json!({ "markdown": markdown });
+        payload["action_items"] = json!(action_items);
+    }
+    Ok(payload)
+}
+
+/// Runs every enabled hook for `event` in the background
+pub fn fire(pool: SqlitePool, event: HookEvent, meeting_id: String) {
+    tokio::spawn(async move {
+        let hooks: Vec<HookConfig> = match load_hooks(&pool).await {
+            Ok(hooks) => hooks
+                .into_iter()
+                .filter(|h| h.enabled && h.event == event)
+                .collect(),
+            Err(e) => {
+                log::warn!("Skipping {} hooks: {}", event.as_str(), e);
+                return;
+            }
+        };
+        if hooks.is_empty() {
+            return;
+        }
+
+        let payload = match build_payload(&pool, event, &meeting_id).await {
+            Ok(payload) => payload.to_string(),
+            Err(e) => {
+                log::warn!("Skipping {} hooks for {}: {}", event.as_str(), meeting_id, e);
+                return;
+            }
+        };
+
+        for hook in hooks {
+            // Outcome (exit status, output) is logged by the runner
+            let _ = runner::run_hook(&hook, &payload).await;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_defaults_and_validation() {
+        let hook: HookConfig = serde_json::from_str(
+            r#"{"id":"h1","name":"Sync notes","event":"summary_generated","command":"./sync.sh"}"#,
+        )
+        .unwrap();
+        assert!(hook.enabled);
+        assert_eq!(hook.timeout_secs, DEFAULT_TIMEOUT_SECS);
+        assert_eq!(hook.event, HookEvent::SummaryGenerated);
+        assert!(hook.validate().is_ok());
+
+        let mut bad = hook.clone();
+        bad.command = "  ".into();
+        assert!(bad.validate().is_err());
+        bad = hook;
+        bad.timeout_secs = MAX_TIMEOUT_SECS + 1;
+        assert!(bad.validate().is_err());
+    }
+
+    #[test]
+    fn unknown_events_are_rejected() {
+        assert!(serde_json::from_str::<HookEvent>(r#""meeting_deleted""#).is_err());
+    }
+}
@@ -0,0 +1,179 @@
+//! Executes hook commands with the event payload on stdin.
+
+use serde::Serialize;
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use super::HookConfig;
+
+/// Captured output is truncated to this many bytes per stream
+const MAX_CAPTURED_OUTPUT: usize = 16 * 1024;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HookOutcome {
+    #[serde(rename = "exitCode")]
+    pub exit_code: Option<i32>,
+    #[serde(rename = "timedOut")]
+    pub timed_out: bool,
+    pub stdout: String,
+    pub stderr: String,
+    #[serde(rename = "durationMs")]
+    pub duration_ms: u64,
+}
+
+impl HookOutcome {
+    pub fn succeeded(&self) -> bool {
+        !self.timed_out && self.exit_code == Some(0)
+    }
+}
+
+fn shell_command(command: &str) -> Command {
+    #[cfg(target_os = "windows")]
+    {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        cmd.creation_flags(CREATE_NO_WINDOW);
+        cmd
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    }
+}
+
+/// Lossy UTF-8 with a byte cap, cut on a char boundary
+pub fn truncate_output(bytes: &[u8]) -> String {
+    let text = String::from_utf8_lossy(bytes);
+    if text.len() <= MAX_CAPTURED_OUTPUT {
+        return text.trim_end().to_string();
+    }
+    let mut end = MAX_CAPTURED_OUTPUT;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}... [truncated]", &text[..end])
+}
+
+/// Runs one hook to completion or timeout. The process is killed on timeout.
+pub async fn run_hook(hook: &HookConfig, payload: &str) -> Result<HookOutcome, String> {
+    let started = Instant::now();
+    let mut child = shell_command(&hook.command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| {
+            log::error!("Hook '{}' failed to start: {}", hook.name, e);
+            format!("Failed to start hook '{}': {}", hook.name, e)
+        })?;
+
+    // Written from a task so a hook that never reads a large payload can't
+    // block us past its timeout; the write ends when the child is killed
+    if let Some(mut stdin) = child.stdin.take() {
+        let name = hook.name.clone();
+        let payload = payload.to_string();
+        tokio::spawn(async move {
+            // Hooks that ignore stdin close the pipe early; that is not an error
+            if let Err(e) = stdin.write_all(payload.as_bytes()).await {
+                log::debug!("Hook '{}' did not read its payload: {}", name, e);
+            }
+        });
+    }
+
+    let result =
+        tokio::time::timeout(Duration::from_secs(hook.timeout_secs), child.wait_with_output()).await;
+    let duration_ms = started.elapsed().as_millis() as u64;
+
+    let outcome = match result {
+        Ok(Ok(output)) => HookOutcome {
+            exit_code: output.status.code(),
+            timed_out: false,
+            stdout: truncate_output(&output.stdout),
+            stderr: truncate_output(&output.stderr),
+            duration_ms,
+        },
+        Ok(Err(e)) => return Err(format!("Hook '{}' failed: {}", hook.name, e)),
+        // Dropping the wait future drops the child, which kills it (kill_on_drop)
+        Err(_) => HookOutcome {
+            exit_code: None,
+            timed_out: true,
+            stdout: String::new(),
+            stderr: String::new(),
+            duration_ms,
+        },
+    };
+
+    if outcome.succeeded() {
+        log::info!(
+            "Hook '{}' ({}) finished in {}ms",
+            hook.name,
+            hook.event.as_str(),
+            duration_ms
+        );
+    } else if outcome.timed_out {
+        log::warn!("Hook '{}' timed out after {}s and was killed", hook.name, hook.timeout_secs);
+    } else {
+        log::warn!("Hook '{}' exited with {:?}", hook.name, outcome.exit_code);
+    }
+    if !outcome.stdout.is_empty() {
+        log::info!("Hook '{}' stdout: {}", hook.name, outcome.stdout);
+    }
+    if !outcome.stderr.is_empty() {
+        log::warn!("Hook '{}' stderr: {}", hook.name, outcome.stderr);
+    }
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_is_truncated_on_char_boundary() {
+        assert_eq!(truncate_output(b"ok\n"), "ok");
+        let long = "é".repeat(MAX_CAPTURED_OUTPUT);
+        let truncated = truncate_output(long.as_bytes());
+        assert!(truncated.ends_with("... [truncated]"));
+        assert!(truncated.len() <= MAX_CAPTURED_OUTPUT + "... [truncated]".len());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn hook(command: &str, timeout_secs: u64) -> HookConfig {
+        HookConfig {
+            id: "hook-test".to_string(),
+            name: "test".to_string(),
+            event: super::super::HookEvent::TranscriptSaved,
+            command: command.to_string(),
+            enabled: true,
+            timeout_secs,
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn payload_is_passed_on_stdin() {
+        let outcome = run_hook(&hook("cat", 5), r#"{"event":"transcript_saved"}"#)
+            .await
+            .unwrap();
+        assert!(outcome.succeeded());
+        assert_eq!(outcome.stdout, r#"{"event":"transcript_saved"}"#);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn hook_that_ignores_a_large_payload_still_times_out() {
+        // Far more than a pipe buffer holds
+        let payload = "x".repeat(4 * 1024 * 1024);
+        let started = Instant::now();
+        let outcome = run_hook(&hook("sleep 30", 1), &payload).await.unwrap();
+        assert!(outcome.timed_out);
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+}
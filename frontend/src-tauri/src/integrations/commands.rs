@@ -0,0 +1,1242 @@
+use log::{error as log_error, info as log_info, warn as log_warn};
+use tauri::{AppHandle, Runtime};
+use tokio_util::sync::CancellationToken;
+
+use super::email::{build_mailto_url, parse_recipients, send_smtp, SmtpConfig};
+use super::feeds;
+use super::oauth;
+use super::trackers::{
+    create_jira_issue, create_linear_issue, issue_description, issue_title, normalized_due_date,
+    JiraConfig, LinearConfig, TrackerKind, REDACTED,
+};
+use super::teams::{self, TeamsConfig, TeamsRecording};
+use super::zoom::{self, ZoomConfig, ZoomRecording};
+use crate::audio::file_import::{
+    find_earlier_download, import_recording, DuplicatePolicy, ImportRequest, ImportedMeeting,
+};
+use crate::audit::{self, AuditAction};
+use crate::database::models::ActionItem;
+use crate::database::repositories::{
+    action_item::ActionItemsRepository,
+    feed::{FeedEpisode, FeedSubscription, FeedsRepository},
+    integration::IntegrationSettingsRepository,
+    meeting::MeetingsRepository,
+    setting::SettingsRepository,
+    summary::SummaryProcessesRepository,
+};
+use crate::export::html::render_summary_email;
+use crate::state::AppState;
+
+/// Validates a tracker config and keeps the stored secret when the frontend sends
+/// back the redacted placeholder.
+fn merge_tracker_config(
+    tracker: TrackerKind,
+    config: serde_json::Value,
+    existing: Option<&str>,
+) -> Result<String, String> {
+    let serialized = match tracker {
+        TrackerKind::Jira => {
+            let mut new: JiraConfig = serde_json::from_value(config)
+                .map_err(|e| format!("Invalid Jira configuration: {}", e))?;
+            if new.api_token == REDACTED {
+                let old: JiraConfig = existing
+                    .and_then(|c| serde_json::from_str(c).ok())
+                    .ok_or_else(|| "Jira API token is required".to_string())?;
+                new.api_token = old.api_token;
+            }
+            serde_json::to_string(&new)
+        }
+        TrackerKind::Linear => {
+            let mut new: LinearConfig = serde_json::from_value(config)
+                .map_err(|e| format!("Invalid Linear configuration: {}", e))?;
+            if new.api_key == REDACTED {
+                let old: LinearConfig = existing
+                    .and_then(|c| serde_json::from_str(c).ok())
+                    .ok_or_else(|| "Linear API key is required".to_string())?;
+                new.api_key = old.api_key;
+            }
+            serde_json::to_string(&new)
+        }
+    };
+    serialized.map_err(|e| format!("Failed to serialize configuration: {}", e))
+}
+
+#[tauri::command]
+pub async fn api_save_tracker_config<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    tracker: String,
+    config: serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_save_tracker_config called for tracker: {}", tracker);
+    let kind: TrackerKind = tracker.parse()?;
+    let pool = state.db_manager.pool();
+
+    let existing = IntegrationSettingsRepository::get_config(pool, kind.as_str())
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let serialized = merge_tracker_config(kind, config, existing.as_deref())?;
+
+    IntegrationSettingsRepository::save_config(pool, kind.as_str(), &serialized)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to save {} config: {}", kind.as_str(), e);
+            format!("Failed to save tracker configuration: {}", e)
+        })?;
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "integration",
+        Some(kind.as_str()),
+        serde_json::json!({ "change": "save" }),
+    )
+    .await;
+
+    Ok(serde_json::json!({
+        "status": "success",
+        "message": format!("{} configuration saved", kind.as_str())
+    }))
+}
+
+/// Returns the tracker configuration with credentials redacted, or null if not configured
+#[tauri::command]
+pub async fn api_get_tracker_config<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    tracker: String,
+) -> Result<Option<serde_json::Value>, String> {
+    log_info!("api_get_tracker_config called for tracker: {}", tracker);
+    let kind: TrackerKind = tracker.parse()?;
+
+    let Some(raw) = IntegrationSettingsRepository::get_config(state.db_manager.pool(), kind.as_str())
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+    else {
+        return Ok(None);
+    };
+
+    let redacted = match kind {
+        TrackerKind::Jira => serde_json::from_str::<JiraConfig>(&raw)
+            .map(|c| serde_json::to_value(c.redacted()))
+            .map_err(|e| format!("Stored Jira configuration is invalid: {}", e))?,
+        TrackerKind::Linear => serde_json::from_str::<LinearConfig>(&raw)
+            .map(|c| serde_json::to_value(c.redacted()))
+            .map_err(|e| format!("Stored Linear configuration is invalid: {}", e))?,
+    };
+    redacted
+        .map(Some)
+        .map_err(|e| format!("Failed to serialize configuration: {}", e))
+}
+
+#[tauri::command]
+pub async fn api_remove_tracker_config<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    tracker: String,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_remove_tracker_config called for tracker: {}", tracker);
+    let kind: TrackerKind = tracker.parse()?;
+
+    let pool = state.db_manager.pool();
+    IntegrationSettingsRepository::delete_config(pool, kind.as_str())
+        .await
+        .map_err(|e| format!("Failed to remove tracker configuration: {}", e))?;
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "integration",
+        Some(kind.as_str()),
+        serde_json::json!({ "change": "remove" }),
+    )
+    .await;
+
+    Ok(serde_json::json!({
+        "status": "success",
+        "message": format!("{} configuration removed", kind.as_str())
+    }))
+}
+
+/// Creates an issue in Jira or Linear from an action item and stores the issue key on it
+#[tauri::command]
+pub async fn api_push_action_item_to_tracker<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    item_id: String,
+    tracker: String,
+) -> Result<ActionItem, String> {
+    crate::validation::item_id(&item_id)?;
+    log_info!(
+        "api_push_action_item_to_tracker called for item_id: {}, tracker: {}",
+        item_id,
+        tracker
+    );
+    let kind: TrackerKind = tracker.parse()?;
+    let pool = state.db_manager.pool();
+
+    let item = ActionItemsRepository::get_action_item(pool, &item_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("Action item not found: {}", item_id))?;
+
+    if let Some(key) = &item.external_key {
+        log_warn!("Action item {} was already pushed as {}", item_id, key);
+        return Err(format!("Action item already linked to issue {}", key));
+    }
+
+    let meeting_title = MeetingsRepository::get_meeting_metadata(pool, &item.meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .map(|m| m.title)
+        .unwrap_or_default();
+
+    let raw_config = IntegrationSettingsRepository::get_config(pool, kind.as_str())
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("{} is not configured", kind.as_str()))?;
+
+    let title = issue_title(&item);
+    let description = issue_description(&item, &meeting_title);
+    let due_date = normalized_due_date(item.due_date.as_deref());
+    let client = reqwest::Client::new();
+
+    let created = match kind {
+        TrackerKind::Jira => {
+            let config: JiraConfig = serde_json::from_str(&raw_config)
+                .map_err(|e| format!("Stored Jira configuration is invalid: {}", e))?;
+            create_jira_issue(&client, &config, &title, &description, due_date.as_deref()).await
+        }
+        TrackerKind::Linear => {
+            let config: LinearConfig = serde_json::from_str(&raw_config)
+                .map_err(|e| format!("Stored Linear configuration is invalid: {}", e))?;
+            create_linear_issue(&client, &config, &title, &description, due_date.as_deref()).await
+        }
+    }
+    .map_err(|e| {
+        log_error!("Failed to create {} issue for {}: {}", kind.as_str(), item_id, e);
+        e
+    })?;
+
+    log_info!("Created {} issue {} for action item {}", kind.as_str(), created.key, item_id);
+
+    ActionItemsRepository::set_external_issue(
+        pool,
+        &item_id,
+        kind.as_str(),
+        &created.key,
+        created.url.as_deref(),
+    )
+    .await
+    .map_err(|e| {
+        // The issue exists remotely at this point; surface the key so it isn't lost
+        format!("Created issue {} but failed to save it: {}", created.key, e)
+    })?;
+
+    ActionItemsRepository::get_action_item(pool, &item_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("Action item not found: {}", item_id))
+}
+
+// ===== EMAIL SUMMARY COMMANDS =====
+
+#[tauri::command]
+pub async fn api_save_smtp_config<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    config: SmtpConfig,
+    password: Option<String>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_save_smtp_config called for host: {}", config.host);
+    config.validate()?;
+
+    let password = password.filter(|p| !p.is_empty() && p != REDACTED);
+    SettingsRepository::save_smtp_config(state.db_manager.pool(), &config, password.as_deref())
+        .await
+        .map_err(|e| {
+            log_error!("Failed to save SMTP config: {}", e);
+            format!("Failed to save SMTP settings: {}", e)
+        })?;
+    audit::record(
+        state.db_manager.pool(),
+        AuditAction::ConfigChange,
+        "integration",
+        Some("smtp"),
+        serde_json::json!({ "host": config.host, "password_changed": password.is_some() }),
+    )
+    .await;
+
+    Ok(serde_json::json!({
+        "status": "success",
+        "message": "SMTP settings saved"
+    }))
+}
+
+/// Returns the SMTP configuration and whether a password is stored (never the password itself)
+#[tauri::command]
+pub async fn api_get_smtp_config<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_get_smtp_config called");
+    let pool = state.db_manager.pool();
+
+    let config = SettingsRepository::get_smtp_config(pool)
+        .await
+        .map_err(|e| format!("Failed to load SMTP settings: {}", e))?;
+    let has_password = SettingsRepository::get_smtp_password(pool)
+        .await
+        .map_err(|e| format!("Failed to load SMTP settings: {}", e))?
+        .is_some();
+
+    Ok(serde_json::json!({
+        "config": config,
+        "hasPassword": has_password
+    }))
+}
+
+/// Emails a meeting's summary to `recipients`.
+///
+/// `method` is "smtp" or "mail_client"; when omitted SMTP is used if configured,
+/// otherwise the system mail client is opened with a pre-filled plain-text message.
+#[tauri::command]
+pub async fn api_email_summary<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    recipients: Vec<String>,
+    method: Option<String>,
+) -> Result<serde_json::Value, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_email_summary called for meeting_id: {}, recipients: {}",
+        meeting_id,
+        recipients.len()
+    );
+    let pool = state.db_manager.pool();
+    let mailboxes = parse_recipients(&recipients)?;
+
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?;
+
+    let markdown = SummaryProcessesRepository::get_summary_data(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .and_then(|p| p.result)
+        .and_then(|r| serde_json::from_str::<serde_json::Value>(&r).ok())
+        .and_then(|v| v.get("markdown").and_then(|m| m.as_str()).map(|m| m.to_string()))
+        .filter(|m| !m.trim().is_empty())
+        .ok_or_else(|| "This meeting has no summary to send yet".to_string())?;
+
+    let meeting_date = meeting.created_at.0.format("%Y-%m-%d %H:%M").to_string();
+    let subject = format!("Meeting summary: {}", meeting.title);
+
+    let smtp_config = SettingsRepository::get_smtp_config(pool)
+        .await
+        .map_err(|e| format!("Failed to load SMTP settings: {}", e))?;
+
+    let use_smtp = match method.as_deref() {
+        Some("smtp") => true,
+        Some("mail_client") => false,
+        Some(other) => return Err(format!("Unknown email method: {}", other)),
+        None => smtp_config.is_some(),
+    };
+
+    if use_smtp {
+        let config = smtp_config.ok_or_else(|| "SMTP is not configured".to_string())?;
+        let password = SettingsRepository::get_smtp_password(pool)
+            .await
+            .map_err(|e| format!("Failed to load SMTP settings: {}", e))?;
+        let html = render_summary_email(&meeting.title, &meeting_date, &markdown);
+
+        send_smtp(&config, password.as_deref(), &mailboxes, &subject, &markdown, &html)
+            .await
+            .map_err(|e| {
+                log_error!("Failed to email summary for {}: {}", meeting_id, e);
+                e
+            })?;
+
+        log_info!("Emailed summary for {} to {} recipients", meeting_id, mailboxes.len());
+        audit::record(
+            pool,
+            AuditAction::Export,
+            "meeting",
+            Some(&meeting_id),
+            serde_json::json!({ "via": "email", "recipients": mailboxes.len() }),
+        )
+        .await;
+        return Ok(serde_json::json!({
+            "status": "success",
+            "method": "smtp",
+            "recipients": mailboxes.len()
+        }));
+    }
+
+    let body = format!("{}\n{}\n\n{}", meeting.title, meeting_date, markdown);
+    let mailto = build_mailto_url(&recipients, &subject, &body);
+
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("open")
+            .arg(&mailto)
+            .spawn()
+            .map_err(|e| format!("Failed to open mail client: {}", e))?;
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        std::process::Command::new("explorer")
+            .arg(&mailto)
+            .spawn()
+            .map_err(|e| format!("Failed to open mail client: {}", e))?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        std::process::Command::new("xdg-open")
+            .arg(&mailto)
+            .spawn()
+            .map_err(|e| format!("Failed to open mail client: {}", e))?;
+    }
+
+    Ok(serde_json::json!({
+        "status": "success",
+        "method": "mail_client",
+        "recipients": mailboxes.len()
+    }))
+}
+
+// ===== ZOOM RECORDING IMPORT COMMANDS =====
+
+/// Saves the Zoom OAuth app credentials. The stored secret is kept when the
+/// frontend sends back the redacted placeholder; changing the client id
+/// disconnects the account.
+#[tauri::command]
+pub async fn api_save_zoom_config<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    client_id: String,
+    client_secret: String,
+    redirect_port: Option<u16>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_save_zoom_config called");
+    let pool = state.db_manager.pool();
+    let client_id = client_id.trim().to_string();
+    if client_id.is_empty() {
+        return Err("Zoom client ID is required".to_string());
+    }
+
+    let existing = zoom::load_config(pool).await?;
+    let client_secret = if client_secret == REDACTED {
+        existing
+            .as_ref()
+            .map(|c| c.client_secret.clone())
+            .ok_or_else(|| "Zoom client secret is required".to_string())?
+    } else {
+        client_secret.trim().to_string()
+    };
+    if client_secret.is_empty() {
+        return Err("Zoom client secret is required".to_string());
+    }
+    let tokens = existing
+        .filter(|c| c.client_id == client_id)
+        .and_then(|c| c.tokens);
+
+    let config = ZoomConfig {
+        client_id,
+        client_secret,
+        redirect_port: redirect_port.unwrap_or(zoom::DEFAULT_REDIRECT_PORT),
+        tokens,
+    };
+    zoom::save_config(pool, &config).await?;
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "integration",
+        Some(zoom::CONFIG_ID),
+        serde_json::json!({ "change": "save" }),
+    )
+    .await;
+
+    Ok(serde_json::json!({
+        "status": "success",
+        "message": "Zoom configuration saved"
+    }))
+}
+
+/// Returns the Zoom configuration with the secret redacted and whether an
+/// account is connected, or null if Zoom isn't configured
+#[tauri::command]
+pub async fn api_get_zoom_config<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Option<serde_json::Value>, String> {
+    log_info!("api_get_zoom_config called");
+    let Some(config) = zoom::load_config(state.db_manager.pool()).await? else {
+        return Ok(None);
+    };
+    Ok(Some(serde_json::json!({
+        "redirectUri": oauth::redirect_uri(config.redirect_port),
+        "connected": config.tokens.is_some(),
+        "config": config.redacted(),
+    })))
+}
+
+/// Connects a Zoom account: opens Zoom's consent page in the browser and
+/// waits for it to redirect back to the local callback
+#[tauri::command]
+pub async fn api_connect_zoom<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_connect_zoom called");
+    let pool = state.db_manager.pool();
+    let mut config = zoom::load_config(pool)
+        .await?
+        .ok_or_else(|| "Enter your Zoom app credentials first".to_string())?;
+
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", config.redirect_port))
+        .await
+        .map_err(|e| {
+            format!(
+                "Port {} is not available for the Zoom sign-in callback: {}",
+                config.redirect_port, e
+            )
+        })?;
+    let oauth_state = uuid::Uuid::new_v4().to_string();
+    crate::api::api::open_external_url(zoom::authorize_url(&config, &oauth_state)).await?;
+    let code = oauth::wait_for_code(listener, &oauth_state).await?;
+
+    let client = reqwest::Client::new();
+    config.tokens = Some(zoom::exchange_code(&client, &config, &code).await?);
+    zoom::save_config(pool, &config).await?;
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "integration",
+        Some(zoom::CONFIG_ID),
+        serde_json::json!({ "change": "connect" }),
+    )
+    .await;
+
+    Ok(serde_json::json!({
+        "status": "success",
+        "message": "Zoom account connected"
+    }))
+}
+
+/// Forgets the Zoom tokens; the app credentials stay configured
+#[tauri::command]
+pub async fn api_disconnect_zoom<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_disconnect_zoom called");
+    let pool = state.db_manager.pool();
+    if let Some(mut config) = zoom::load_config(pool).await? {
+        config.tokens = None;
+        zoom::save_config(pool, &config).await?;
+        audit::record(
+            pool,
+            AuditAction::ConfigChange,
+            "integration",
+            Some(zoom::CONFIG_ID),
+            serde_json::json!({ "change": "disconnect" }),
+        )
+        .await;
+    }
+    Ok(serde_json::json!({
+        "status": "success",
+        "message": "Zoom account disconnected"
+    }))
+}
+
+/// Lists cloud recordings between `from` and `to` (YYYY-MM-DD); defaults to
+/// the last 30 days
+#[tauri::command]
+pub async fn api_list_zoom_recordings<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    from: Option<String>,
+    to: Option<String>,
+) -> Result<Vec<ZoomRecording>, String> {
+    log_info!("api_list_zoom_recordings called from {:?} to {:?}", from, to);
+    let parse = |value: Option<String>, default: chrono::NaiveDate| match value {
+        Some(value) => chrono::NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+            .map_err(|_| format!("Invalid date {}; use YYYY-MM-DD", value)),
+        None => Ok(default),
+    };
+    let today = chrono::Utc::now().date_naive();
+    let to = parse(to, today)?;
+    let from = parse(from, to - chrono::Duration::days(30))?;
+    if from > to {
+        return Err("The start date is after the end date".to_string());
+    }
+
+    let client = reqwest::Client::new();
+    let token = zoom::access_token(&client, state.db_manager.pool()).await?;
+    let recordings = zoom::list_recordings(&client, &token, from, to).await?;
+    Ok(recordings
+        .into_iter()
+        .filter(|r| zoom::pick_media_file(&r.recording_files).is_some())
+        .collect())
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ZoomImportResult {
+    pub uuid: String,
+    pub topic: Option<String>,
+    pub meeting: Option<ImportedMeeting>,
+    pub error: Option<String>,
+}
+
+async fn import_zoom_recording<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &sqlx::SqlitePool,
+    client: &reqwest::Client,
+    uuid: &str,
+    on_duplicate: DuplicatePolicy,
+    cancel: &CancellationToken,
+) -> Result<(String, ImportedMeeting), String> {
+    let token = zoom::access_token(client, pool).await?;
+    let recording = zoom::get_recording(client, &token, uuid).await?;
+    let file = zoom::pick_media_file(&recording.recording_files)
+        .ok_or_else(|| "The recording has no audio or video file".to_string())?;
+    let title = match recording.topic.trim() {
+        "" => "Zoom meeting".to_string(),
+        topic => topic.to_string(),
+    };
+    let origin = format!(
+        "zoom:{}",
+        file.id.as_deref().unwrap_or(recording.uuid.as_str())
+    );
+    if let Some(existing) = find_earlier_download(pool, &origin, on_duplicate).await? {
+        log_info!(
+            "Zoom recording {} was already imported as {}; linking to it",
+            uuid,
+            existing.meeting_id
+        );
+        return Ok((title, existing));
+    }
+
+    let extension = file
+        .file_extension
+        .as_deref()
+        .unwrap_or(&file.file_type)
+        .to_lowercase();
+    let download_path =
+        std::env::temp_dir().join(format!("meetily-zoom-{}.{}", uuid::Uuid::new_v4(), extension));
+    let downloaded = tokio::select! {
+        result = zoom::download(client, &token, file, &download_path) => result,
+        _ = cancel.cancelled() => Err("Cancelled".to_string()),
+    };
+    if let Err(e) = downloaded {
+        let _ = std::fs::remove_file(&download_path);
+        return Err(e);
+    }
+
+    // Attendance needs the meeting:read scope; import without it if missing
+    let participants = match zoom::participants(client, &token, &recording.uuid).await {
+        Ok(participants) => participants,
+        Err(e) => {
+            log_warn!("Could not load Zoom participants for {}: {}", uuid, e);
+            Vec::new()
+        }
+    };
+
+    let imported = import_recording(
+        app,
+        pool,
+        ImportRequest {
+            file: download_path.clone(),
+            title: title.clone(),
+            move_file: true,
+            participants,
+            tags: Vec::new(),
+            source: "zoom",
+            origin: Some(origin),
+            on_duplicate,
+            cancel: cancel.clone(),
+        },
+    )
+    .await;
+    if download_path.exists() {
+        let _ = std::fs::remove_file(&download_path);
+    }
+    Ok((title, imported?))
+}
+
+/// Downloads the selected cloud recordings and imports each one as a
+/// meeting titled with its Zoom topic, with the Zoom attendees as
+/// participants. Recordings are imported one after another; a failure is
+/// reported for that recording and the rest continue.
+#[tauri::command]
+pub async fn api_import_zoom_recordings<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_uuids: Vec<String>,
+    on_duplicate: Option<DuplicatePolicy>,
+) -> Result<Vec<ZoomImportResult>, String> {
+    log_info!(
+        "api_import_zoom_recordings called for {} recordings",
+        meeting_uuids.len()
+    );
+    let pool = state.db_manager.pool();
+    let client = reqwest::Client::new();
+    let on_duplicate = on_duplicate.unwrap_or_default();
+    let total = meeting_uuids.len();
+    let job = state
+        .jobs
+        .begin(&app, "import", format!("Import {} Zoom recordings", total), None)
+        .await?;
+    let cancel = job.cancellation_token();
+
+    let mut results = Vec::with_capacity(total);
+    for (index, uuid) in meeting_uuids.into_iter().enumerate() {
+        if let Err(e) = job.checkpoint().await {
+            results.push(ZoomImportResult {
+                uuid,
+                topic: None,
+                meeting: None,
+                error: Some(e),
+            });
+            continue;
+        }
+        job.progress(
+            index as f64 / total as f64,
+            Some(format!("Importing recording {} of {}", index + 1, total)),
+        )
+        .await;
+        let result = match import_zoom_recording(&app, pool, &client, &uuid, on_duplicate, &cancel)
+            .await
+        {
+            Ok((title, meeting)) => {
+                if !meeting.linked_existing {
+                    audit::record(
+                        pool,
+                        AuditAction::Import,
+                        "meeting",
+                        Some(&meeting.meeting_id),
+                        serde_json::json!({ "source": "zoom", "zoom_meeting": uuid }),
+                    )
+                    .await;
+                }
+                ZoomImportResult {
+                    uuid,
+                    topic: Some(title),
+                    meeting: Some(meeting),
+                    error: None,
+                }
+            }
+            Err(e) => {
+                log_error!("Failed to import Zoom recording {}: {}", uuid, e);
+                ZoomImportResult {
+                    uuid,
+                    topic: None,
+                    meeting: None,
+                    error: Some(e),
+                }
+            }
+        };
+        results.push(result);
+    }
+    let imported = results.iter().filter(|r| r.meeting.is_some()).count();
+    job.finish(if job.is_cancelled() {
+        Err("Cancelled".to_string())
+    } else {
+        Ok(serde_json::json!({ "imported": imported, "failed": total - imported }))
+    })
+    .await;
+    Ok(results)
+}
+
+// ===== TEAMS RECORDING IMPORT COMMANDS =====
+
+/// Saves the Microsoft Entra app registration used for Teams recordings.
+/// Changing the client id or tenant disconnects the account.
+#[tauri::command]
+pub async fn api_save_teams_config<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    client_id: String,
+    tenant: Option<String>,
+    redirect_port: Option<u16>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_save_teams_config called");
+    let pool = state.db_manager.pool();
+    let client_id = client_id.trim().to_string();
+    if client_id.is_empty() {
+        return Err("Microsoft application (client) ID is required".to_string());
+    }
+    let tenant = tenant
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .unwrap_or_else(|| teams::DEFAULT_TENANT.to_string());
+
+    let tokens = teams::load_config(pool)
+        .await?
+        .filter(|c| c.client_id == client_id && c.tenant == tenant)
+        .and_then(|c| c.tokens);
+    let config = TeamsConfig {
+        client_id,
+        tenant,
+        redirect_port: redirect_port.unwrap_or(teams::DEFAULT_REDIRECT_PORT),
+        tokens,
+    };
+    teams::save_config(pool, &config).await?;
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "integration",
+        Some(teams::CONFIG_ID),
+        serde_json::json!({ "change": "save" }),
+    )
+    .await;
+
+    Ok(serde_json::json!({
+        "status": "success",
+        "message": "Microsoft Teams configuration saved"
+    }))
+}
+
+/// Returns the Teams configuration and whether an account is connected, or
+/// null if Teams isn't configured
+#[tauri::command]
+pub async fn api_get_teams_config<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Option<serde_json::Value>, String> {
+    log_info!("api_get_teams_config called");
+    let Some(config) = teams::load_config(state.db_manager.pool()).await? else {
+        return Ok(None);
+    };
+    Ok(Some(serde_json::json!({
+        "redirectUri": oauth::redirect_uri(config.redirect_port),
+        "connected": config.tokens.is_some(),
+        "config": config.redacted(),
+    })))
+}
+
+/// Connects a Microsoft account: opens the Microsoft sign-in page in the
+/// browser and waits for it to redirect back to the local callback
+#[tauri::command]
+pub async fn api_connect_teams<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_connect_teams called");
+    let pool = state.db_manager.pool();
+    let mut config = teams::load_config(pool)
+        .await?
+        .ok_or_else(|| "Enter your Microsoft application ID first".to_string())?;
+
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", config.redirect_port))
+        .await
+        .map_err(|e| {
+            format!(
+                "Port {} is not available for the Microsoft sign-in callback: {}",
+                config.redirect_port, e
+            )
+        })?;
+    let oauth_state = uuid::Uuid::new_v4().to_string();
+    let (verifier, challenge) = oauth::pkce_pair();
+    crate::api::api::open_external_url(teams::authorize_url(&config, &oauth_state, &challenge))
+        .await?;
+    let code = oauth::wait_for_code(listener, &oauth_state).await?;
+
+    let client = reqwest::Client::new();
+    config.tokens = Some(teams::exchange_code(&client, &config, &code, &verifier).await?);
+    teams::save_config(pool, &config).await?;
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "integration",
+        Some(teams::CONFIG_ID),
+        serde_json::json!({ "change": "connect" }),
+    )
+    .await;
+
+    Ok(serde_json::json!({
+        "status": "success",
+        "message": "Microsoft account connected"
+    }))
+}
+
+/// Forgets the Microsoft tokens; the app registration stays configured
+#[tauri::command]
+pub async fn api_disconnect_teams<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_disconnect_teams called");
+    let pool = state.db_manager.pool();
+    if let Some(mut config) = teams::load_config(pool).await? {
+        config.tokens = None;
+        teams::save_config(pool, &config).await?;
+        audit::record(
+            pool,
+            AuditAction::ConfigChange,
+            "integration",
+            Some(teams::CONFIG_ID),
+            serde_json::json!({ "change": "disconnect" }),
+        )
+        .await;
+    }
+    Ok(serde_json::json!({
+        "status": "success",
+        "message": "Microsoft account disconnected"
+    }))
+}
+
+/// Lists Teams meeting recordings in the user's OneDrive and shared with
+/// them, newest first
+#[tauri::command]
+pub async fn api_list_teams_recordings<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<TeamsRecording>, String> {
+    log_info!("api_list_teams_recordings called");
+    let client = reqwest::Client::new();
+    let token = teams::access_token(&client, state.db_manager.pool()).await?;
+    teams::list_recordings(&client, &token).await
+}
+
+/// Identifies a recording file in OneDrive or SharePoint
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamsRecordingRef {
+    pub drive_id: String,
+    pub item_id: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamsImportResult {
+    pub item_id: String,
+    pub subject: Option<String>,
+    pub meeting: Option<ImportedMeeting>,
+    pub error: Option<String>,
+}
+
+async fn import_teams_recording<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &sqlx::SqlitePool,
+    client: &reqwest::Client,
+    item: &TeamsRecordingRef,
+    on_duplicate: DuplicatePolicy,
+    cancel: &CancellationToken,
+) -> Result<(String, ImportedMeeting), String> {
+    let token = teams::access_token(client, pool).await?;
+    let recording = teams::get_recording(client, &token, &item.drive_id, &item.item_id).await?;
+    let origin = format!("teams:{}/{}", item.drive_id, item.item_id);
+    if let Some(existing) = find_earlier_download(pool, &origin, on_duplicate).await? {
+        log_info!(
+            "Teams recording {} was already imported as {}; linking to it",
+            recording.name,
+            existing.meeting_id
+        );
+        return Ok((existing.title.clone(), existing));
+    }
+
+    // The calendar event gives the real subject and the attendee list;
+    // without Calendars.Read (or a matching event) the file name has to do
+    let event = match recording.created_at {
+        Some(created_at) => {
+            let duration = recording
+                .duration_ms
+                .map(|ms| chrono::Duration::milliseconds(ms as i64));
+            match teams::find_event(client, &token, &recording.subject, created_at, duration)
+                .await
+            {
+                Ok(event) => event,
+                Err(e) => {
+                    log_warn!(
+                        "Could not look up the Teams meeting for {}: {}",
+                        recording.name,
+                        e
+                    );
+                    None
+                }
+            }
+        }
+        None => None,
+    };
+    let title = event
+        .as_ref()
+        .map(|e| e.subject.trim())
+        .filter(|s| !s.is_empty())
+        .unwrap_or(recording.subject.trim());
+    let title = match title {
+        "" => "Teams meeting".to_string(),
+        subject => subject.to_string(),
+    };
+    let participants = event
+        .as_ref()
+        .map(teams::event_participants)
+        .unwrap_or_default();
+
+    let download_path =
+        std::env::temp_dir().join(format!("meetily-teams-{}.mp4", uuid::Uuid::new_v4()));
+    let downloaded = tokio::select! {
+        result = teams::download(client, &token, &recording, &download_path) => result,
+        _ = cancel.cancelled() => Err("Cancelled".to_string()),
+    };
+    if let Err(e) = downloaded {
+        let _ = std::fs::remove_file(&download_path);
+        return Err(e);
+    }
+
+    let imported = import_recording(
+        app,
+        pool,
+        ImportRequest {
+            file: download_path.clone(),
+            title: title.clone(),
+            move_file: true,
+            participants,
+            tags: Vec::new(),
+            source: "teams",
+            origin: Some(origin),
+            on_duplicate,
+            cancel: cancel.clone(),
+        },
+    )
+    .await;
+    if download_path.exists() {
+        let _ = std::fs::remove_file(&download_path);
+    }
+    Ok((title, imported?))
+}
+
+/// Downloads the selected Teams recordings and imports each one as a
+/// meeting titled with the meeting subject, with the organizer and
+/// attendees as participants. Recordings are imported one after another; a
+/// failure is reported for that recording and the rest continue.
+#[tauri::command]
+pub async fn api_import_teams_recordings<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    items: Vec<TeamsRecordingRef>,
+    on_duplicate: Option<DuplicatePolicy>,
+) -> Result<Vec<TeamsImportResult>, String> {
+    log_info!(
+        "api_import_teams_recordings called for {} recordings",
+        items.len()
+    );
+    let pool = state.db_manager.pool();
+    let client = reqwest::Client::new();
+    let on_duplicate = on_duplicate.unwrap_or_default();
+    let total = items.len();
+    let job = state
+        .jobs
+        .begin(&app, "import", format!("Import {} Teams recordings", total), None)
+        .await?;
+    let cancel = job.cancellation_token();
+
+    let mut results = Vec::with_capacity(total);
+    for (index, item) in items.into_iter().enumerate() {
+        if let Err(e) = job.checkpoint().await {
+            results.push(TeamsImportResult {
+                item_id: item.item_id,
+                subject: None,
+                meeting: None,
+                error: Some(e),
+            });
+            continue;
+        }
+        job.progress(
+            index as f64 / total as f64,
+            Some(format!("Importing recording {} of {}", index + 1, total)),
+        )
+        .await;
+        let result = match import_teams_recording(&app, pool, &client, &item, on_duplicate, &cancel)
+            .await
+        {
+            Ok((title, meeting)) => {
+                if !meeting.linked_existing {
+                    audit::record(
+                        pool,
+                        AuditAction::Import,
+                        "meeting",
+                        Some(&meeting.meeting_id),
+                        serde_json::json!({ "source": "teams", "drive_item": item.item_id }),
+                    )
+                    .await;
+                }
+                TeamsImportResult {
+                    item_id: item.item_id,
+                    subject: Some(title),
+                    meeting: Some(meeting),
+                    error: None,
+                }
+            }
+            Err(e) => {
+                log_error!("Failed to import Teams recording {}: {}", item.item_id, e);
+                TeamsImportResult {
+                    item_id: item.item_id,
+                    subject: None,
+                    meeting: None,
+                    error: Some(e),
+                }
+            }
+        };
+        results.push(result);
+    }
+    let imported = results.iter().filter(|r| r.meeting.is_some()).count();
+    job.finish(if job.is_cancelled() {
+        Err("Cancelled".to_string())
+    } else {
+        Ok(serde_json::json!({ "imported": imported, "failed": total - imported }))
+    })
+    .await;
+    Ok(results)
+}
+
+// ===== FEED SUBSCRIPTION COMMANDS =====
+
+#[tauri::command]
+pub async fn api_list_feeds<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<FeedSubscription>, String> {
+    log_info!("api_list_feeds called");
+    FeedsRepository::list_feeds(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to list feeds: {}", e))
+}
+
+/// Subscribes to an RSS or Atom feed. Episodes already in the feed are not
+/// imported, except the newest `backfill` of them; new ones are imported as
+/// they appear.
+#[tauri::command]
+pub async fn api_subscribe_feed<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    url: String,
+    backfill: Option<u32>,
+) -> Result<FeedSubscription, String> {
+    let url = crate::validation::http_url("url", &url)?;
+    log_info!("api_subscribe_feed called for {}", url);
+    let parsed = feeds::fetch_feed(&reqwest::Client::new(), &url).await?;
+    if parsed.episodes.is_empty() && parsed.item_count > 0 {
+        return Err("The feed has no audio or video episodes".to_string());
+    }
+
+    let pool = state.db_manager.pool();
+    let backfill = backfill.unwrap_or(0) as usize;
+    let feed = FeedsRepository::add_feed(pool, &url, &parsed.title, &parsed.episodes, backfill)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to subscribe to {}: {}", url, e);
+            format!("Failed to subscribe: {}", e)
+        })?;
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "feed",
+        Some(&feed.id),
+        serde_json::json!({ "change": "subscribe", "url": url }),
+    )
+    .await;
+    if backfill > 0 {
+        feeds::spawn_imports(&app);
+    }
+    Ok(feed)
+}
+
+/// Pauses or resumes automatic import from a feed
+#[tauri::command]
+pub async fn api_set_feed_enabled<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    feed_id: String,
+    enabled: bool,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_set_feed_enabled called for {}: {}", feed_id, enabled);
+    match FeedsRepository::set_enabled(state.db_manager.pool(), &feed_id, enabled).await {
+        Ok(true) => Ok(serde_json::json!({
+            "status": "success",
+            "message": if enabled { "Feed enabled" } else { "Feed paused" }
+        })),
+        Ok(false) => Err(format!("Feed not found: {}", feed_id)),
+        Err(e) => Err(format!("Failed to update feed: {}", e)),
+    }
+}
+
+/// Unsubscribes from a feed; meetings imported from it are kept
+#[tauri::command]
+pub async fn api_unsubscribe_feed<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    feed_id: String,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_unsubscribe_feed called for {}", feed_id);
+    let pool = state.db_manager.pool();
+    match FeedsRepository::delete_feed(pool, &feed_id).await {
+        Ok(true) => {
+            audit::record(
+                pool,
+                AuditAction::ConfigChange,
+                "feed",
+                Some(&feed_id),
+                serde_json::json!({ "change": "unsubscribe" }),
+            )
+            .await;
+            Ok(serde_json::json!({ "status": "success", "message": "Unsubscribed" }))
+        }
+        Ok(false) => Err(format!("Feed not found: {}", feed_id)),
+        Err(e) => Err(format!("Failed to unsubscribe: {}", e)),
+    }
+}
+
+/// A feed's episodes, newest first, with their import status
+#[tauri::command]
+pub async fn api_list_feed_episodes<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    feed_id: String,
+) -> Result<Vec<FeedEpisode>, String> {
+    FeedsRepository::list_episodes(state.db_manager.pool(), &feed_id)
+        .await
+        .map_err(|e| format!("Failed to list episodes: {}", e))
+}
+
+/// Imports a skipped or failed episode
+#[tauri::command]
+pub async fn api_import_feed_episode<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    episode_id: String,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_import_feed_episode called for {}", episode_id);
+    match FeedsRepository::queue_episode(state.db_manager.pool(), &episode_id).await {
+        Ok(true) => {
+            feeds::spawn_imports(&app);
+            Ok(serde_json::json!({ "status": "success", "message": "Episode queued for import" }))
+        }
+        Ok(false) => Err(format!(
+            "Episode not found or already imported: {}",
+            episode_id
+        )),
+        Err(e) => Err(format!("Failed to queue episode: {}", e)),
+    }
+}
+
+/// Checks the feeds now instead of waiting for the next scheduled check, as
+/// a "sync" job
+#[tauri::command]
+pub async fn api_sync_feeds<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<feeds::FeedSyncReport, String> {
+    log_info!("api_sync_feeds called");
+    let job = state.jobs.begin(&app, "sync", "Check feeds", None).await?;
+    let result = feeds::sync_feeds(&app, &state).await;
+    job.finish(match &result {
+        Ok(report) => serde_json::to_value(report).map_err(|e| e.to_string()),
+        Err(e) => Err(e.clone()),
+    })
+    .await;
+    result
+}
@@ -0,0 +1,178 @@
+//! Email delivery of meeting summaries, via SMTP or the system mail client.
+
+use lettre::message::{Mailbox, MultiPart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use serde::{Deserialize, Serialize};
+
+/// SMTP connection settings (stored as JSON in settings.smtpConfig).
+/// The password is stored separately and never serialized with this struct.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    /// "tls" (implicit TLS, usually 465), "starttls" (usually 587) or "none"
+    pub security: String,
+    pub username: Option<String>,
+    #[serde(rename = "fromAddress")]
+    pub from_address: String,
+    #[serde(rename = "fromName")]
+    pub from_name: Option<String>,
+}
+
+impl SmtpConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.host.trim().is_empty() {
+            return Err("SMTP host is required".to_string());
+        }
+        if !matches!(self.security.as_str(), "tls" | "starttls" | "none") {
+            return Err(format!(
+                "Invalid SMTP security '{}'. Use tls, starttls or none",
+                self.security
+            ));
+        }
+        self.sender_mailbox().map(|_| ())
+    }
+
+    fn sender_mailbox(&self) -> Result<Mailbox, String> {
+        let address = match &self.from_name {
+            Some(name) if !name.trim().is_empty() => format!("{} <{}>", name.trim(), self.from_address),
+            _ => self.from_address.clone(),
+        };
+        address
+            .parse()
+            .map_err(|e| format!("Invalid sender address '{}': {}", self.from_address, e))
+    }
+}
+
+/// Parses and de-duplicates recipients, rejecting the whole list on the first invalid address
+pub fn parse_recipients(recipients: &[String]) -> Result<Vec<Mailbox>, String> {
+    let mut seen = std::collections::HashSet::new();
+    let mut mailboxes = Vec::new();
+    for recipient in recipients {
+        let trimmed = recipient.trim();
+        if trimmed.is_empty() || !seen.insert(trimmed.to_lowercase()) {
+            continue;
+        }
+        let mailbox: Mailbox = trimmed
+            .parse()
+            .map_err(|e| format!("Invalid recipient '{}': {}", trimmed, e))?;
+        mailboxes.push(mailbox);
+    }
+    if mailboxes.is_empty() {
+        return Err("At least one recipient is required".to_string());
+    }
+    Ok(mailboxes)
+}
+
+/// Sends a multipart (plain text + HTML) email over SMTP
+pub async fn send_smtp(
+    config: &SmtpConfig,
+    password: Option<&str>,
+    recipients: &[Mailbox],
+    subject: &str,
+    text_body: &str,
+    html_body: &str,
+) -> Result<(), String> {
+    let mut builder = Message::builder().from(config.sender_mailbox()?).subject(subject);
+    for recipient in recipients {
+        builder = builder.to(recipient.clone());
+    }
+    let message = builder
+        .multipart(MultiPart::alternative_plain_html(
+            text_body.to_string(),
+            html_body.to_string(),
+        ))
+        .map_err(|e| format!("Failed to build email: {}", e))?;
+
+    let transport = match config.security.as_str() {
+        "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host),
+        "starttls" => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host),
+        _ => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host)),
+    }
+    .map_err(|e| format!("Invalid SMTP host '{}': {}", config.host, e))?
+    .port(config.port);
+
+    let transport = match (&config.username, password) {
+        (Some(username), Some(password)) if !username.is_empty() => transport
+            .credentials(Credentials::new(username.clone(), password.to_string()))
+            .build(),
+        _ => transport.build(),
+    };
+
+    transport
+        .send(message)
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("SMTP send failed: {}", e))
+}
+
+/// Builds a mailto: URL for handing the summary to the system mail client.
+/// Mail clients only accept plain text bodies through mailto.
+pub fn build_mailto_url(recipients: &[String], subject: &str, body: &str) -> String {
+    let encode = |s: &str| {
+        url::form_urlencoded::byte_serialize(s.as_bytes())
+            .collect::<String>()
+            .replace('+', "%20")
+    };
+    let to = recipients
+        .iter()
+        .map(|r| r.trim())
+        .filter(|r| !r.is_empty())
+        .map(encode)
+        .collect::<Vec<_>>()
+        .join(",");
+    format!(
+        "mailto:{}?subject={}&body={}",
+        to,
+        encode(subject),
+        encode(body)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recipients_are_validated_and_deduplicated() {
+        let parsed = parse_recipients(&[
+            "alice@example.com".into(),
+            "ALICE@example.com".into(),
+            " bob@example.com ".into(),
+            "".into(),
+        ])
+        .unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert!(parse_recipients(&["not-an-address".into()]).is_err());
+        assert!(parse_recipients(&[]).is_err());
+    }
+
+    #[test]
+    fn mailto_is_percent_encoded() {
+        let url = build_mailto_url(
+            &["a@example.com".into(), "b@example.com".into()],
+            "Weekly sync",
+            "Line 1\nA & B",
+        );
+        assert_eq!(
+            url,
+            "mailto:a%40example.com,b%40example.com?subject=Weekly%20sync&body=Line%201%0AA%20%26%20B"
+        );
+    }
+
+    #[test]
+    fn config_validation() {
+        let mut config = SmtpConfig {
+            host: "smtp.example.com".into(),
+            port: 587,
+            security: "starttls".into(),
+            username: Some("me".into()),
+            from_address: "me@example.com".into(),
+            from_name: Some("Me".into()),
+        };
+        assert!(config.validate().is_ok());
+        config.security = "ssl".into();
+        assert!(config.validate().is_err());
+    }
+}
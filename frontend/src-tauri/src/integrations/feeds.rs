@@ -0,0 +1,551 @@
+//! Podcast and recording feeds (RSS 2.0, RSS 1.0 and Atom).
+//!
+//! A subscription is checked every [`CHECK_INTERVAL_SECS`] while the app
+//! runs. Episodes that weren't in the feed before are downloaded like a
+//! recording link ([`import_from_url`]) and imported as meetings titled after
+//! the episode and tagged with the feed's title, so team podcasts and
+//! all-hands recordings end up searchable next to the meetings. Episodes are
+//! imported one after another, oldest first, as background import jobs by a
+//! queue task of their own, so checks don't wait for long imports.
+
+use chrono::{DateTime, Utc};
+use log::{error, info, warn};
+use once_cell::sync::Lazy;
+use roxmltree::{Document, Node, ParsingOptions};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, Runtime};
+use url::Url;
+
+use crate::audio::file_import::DuplicatePolicy;
+use crate::audio::transcription::validate_transcription_model_ready;
+use crate::audio::url_import::{import_from_url, url_file_name};
+use crate::audit::{self, AuditAction};
+use crate::database::repositories::feed::{
+    EpisodeInput, FeedEpisode, FeedSubscription, FeedsRepository,
+};
+use crate::database::repositories::tag::{TagsRepository, MAX_TAG_LEN};
+use crate::state::AppState;
+use crate::validation::MAX_TITLE_CHARS;
+
+/// How often subscribed feeds are checked for new episodes
+pub const CHECK_INTERVAL_SECS: u64 = 30 * 60;
+/// The first check waits for startup to settle
+const FIRST_CHECK_DELAY_SECS: u64 = 120;
+
+const ATOM_NS: &str = "http://www.w3.org/2005/Atom";
+const RSS1_NS: &str = "http://purl.org/rss/1.0/";
+const MEDIA_RSS_NS: &str = "http://search.yahoo.com/mrss/";
+const DUBLIN_CORE_NS: &str = "http://purl.org/dc/elements/1.1/";
+
+// One check at a time, whether scheduled or asked for
+static SYNC: Lazy<tokio::sync::Mutex<()>> = Lazy::new(|| tokio::sync::Mutex::new(()));
+// One import queue; a request made while it runs is picked up by it
+static IMPORT_QUEUE: Lazy<tokio::sync::Mutex<()>> = Lazy::new(|| tokio::sync::Mutex::new(()));
+static IMPORT_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// A feed as read from its XML
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedFeed {
+    pub title: String,
+    /// Items with an audio or video file; other items (blog posts) are left out
+    pub episodes: Vec<EpisodeInput>,
+    /// Items in the feed, with or without a file
+    pub item_count: usize,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct FeedSyncReport {
+    /// Feeds checked
+    pub checked: usize,
+    pub new_episodes: u64,
+}
+
+#[derive(Debug, Default)]
+struct FeedImportReport {
+    imported: usize,
+    failed: usize,
+}
+
+/// Whitespace collapsed, control characters dropped, cut to `max` characters
+fn clean_text(text: &str, max: usize) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .filter(|c| !c.is_control())
+        .take(max)
+        .collect()
+}
+
+/// An element of the feed vocabulary itself, not of an extension such as
+/// iTunes (whose `itunes:title` would otherwise pass for the title)
+fn is_feed_element(node: &Node, name: &str) -> bool {
+    node.is_element()
+        && node.tag_name().name() == name
+        && matches!(
+            node.tag_name().namespace(),
+            None | Some(ATOM_NS) | Some(RSS1_NS)
+        )
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|c| is_feed_element(c, name))
+}
+
+fn child_text(node: Node, name: &str) -> Option<String> {
+    let text = clean_text(child(node, name)?.text()?, MAX_TITLE_CHARS);
+    (!text.is_empty()).then_some(text)
+}
+
+fn parse_date(text: &str) -> Option<DateTime<Utc>> {
+    let text = text.trim();
+    DateTime::parse_from_rfc2822(text)
+        .or_else(|_| DateTime::parse_from_rfc3339(text))
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+/// Whether a declared MIME type (if any) is audio or video
+fn is_media_type(mime: Option<&str>) -> bool {
+    let Some(mime) = mime.map(|mime| mime.trim().to_ascii_lowercase()) else {
+        return true;
+    };
+    mime.is_empty() || mime.starts_with("audio/") || mime.starts_with("video/")
+}
+
+/// The audio or video file of an item: its enclosure, an Atom enclosure
+/// link, or Media RSS content. Relative links resolve against `base`.
+fn media_url(item: Node, base: &Url) -> Option<String> {
+    let candidates = item.children().filter(Node::is_element).filter_map(|c| {
+        let tag = c.tag_name();
+        match (tag.namespace(), tag.name()) {
+            (None, "enclosure") => Some((c.attribute("url")?, c.attribute("type"))),
+            (Some(ATOM_NS), "link") if c.attribute("rel") == Some("enclosure") => {
+                Some((c.attribute("href")?, c.attribute("type")))
+            }
+            (Some(MEDIA_RSS_NS), "content") => {
+                let medium = c.attribute("medium");
+                if medium.is_some_and(|m| m != "audio" && m != "video") {
+                    return None;
+                }
+                Some((c.attribute("url")?, c.attribute("type")))
+            }
+            _ => None,
+        }
+    });
+    for (href, mime) in candidates {
+        if !is_media_type(mime) {
+            continue;
+        }
+        let Ok(url) = base.join(href.trim()) else {
+            continue;
+        };
+        if matches!(url.scheme(), "http" | "https") {
+            return Some(url.to_string());
+        }
+    }
+    None
+}
+
+fn parse_item(item: Node, base: &Url) -> Option<EpisodeInput> {
+    let media_url = media_url(item, base)?;
+    let published_at = ["pubDate", "published", "updated"]
+        .iter()
+        .find_map(|name| child_text(item, name))
+        .or_else(|| {
+            item.children()
+                .find(|c| {
+                    c.tag_name().namespace() == Some(DUBLIN_CORE_NS)
+                        && c.tag_name().name() == "date"
+                })
+                .and_then(|c| c.text().map(str::to_string))
+        })
+        .and_then(|text| parse_date(&text));
+    let guid = child_text(item, "guid")
+        .or_else(|| child_text(item, "id"))
+        .unwrap_or_else(|| media_url.clone());
+    let title = child_text(item, "title")
+        .or_else(|| {
+            let url = Url::parse(&media_url).ok()?;
+            let name = url_file_name(&url)?;
+            Some(clean_text(&name, MAX_TITLE_CHARS))
+        })
+        .unwrap_or_else(|| "Episode".to_string());
+    Some(EpisodeInput {
+        guid,
+        title,
+        media_url,
+        published_at,
+    })
+}
+
+/// Reads an RSS or Atom document fetched from `base`
+pub fn parse_feed(xml: &str, base: &Url) -> Result<ParsedFeed, String> {
+    // Old RSS 0.91 feeds carry a DOCTYPE
+    let options = ParsingOptions {
+        allow_dtd: true,
+        ..ParsingOptions::default()
+    };
+    let document = Document::parse_with_options(xml, options)
+        .map_err(|e| format!("The feed is not valid XML: {}", e))?;
+    let root = document.root_element();
+
+    let (channel, items): (Option<Node>, Vec<Node>) = match root.tag_name().name() {
+        "rss" => {
+            let channel =
+                child(root, "channel").ok_or_else(|| "The RSS feed has no channel".to_string())?;
+            (
+                Some(channel),
+                channel
+                    .children()
+                    .filter(|c| is_feed_element(c, "item"))
+                    .collect(),
+            )
+        }
+        // RSS 1.0 keeps its items next to the channel
+        "RDF" => (
+            child(root, "channel"),
+            root.children()
+                .filter(|c| is_feed_element(c, "item"))
+                .collect(),
+        ),
+        "feed" if root.tag_name().namespace() == Some(ATOM_NS) => (
+            Some(root),
+            root.children()
+                .filter(|c| is_feed_element(c, "entry"))
+                .collect(),
+        ),
+        _ => return Err("This is not an RSS or Atom feed".to_string()),
+    };
+
+    let title = channel
+        .and_then(|channel| child_text(channel, "title"))
+        .or_else(|| base.host_str().map(str::to_string))
+        .unwrap_or_else(|| "Feed".to_string());
+    Ok(ParsedFeed {
+        title,
+        episodes: items
+            .iter()
+            .filter_map(|item| parse_item(*item, base))
+            .collect(),
+        item_count: items.len(),
+    })
+}
+
+pub async fn fetch_feed(client: &reqwest::Client, url: &str) -> Result<ParsedFeed, String> {
+    let response = client
+        .get(url)
+        .timeout(Duration::from_secs(60))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch the feed: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("The feed server answered {}", response.status()));
+    }
+    // Relative links resolve against where redirects ended up
+    let base = response.url().clone();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read the feed: {}", e))?;
+    parse_feed(&body, &base)
+}
+
+/// The tag imported episodes get
+pub fn feed_tag(feed_title: &str) -> String {
+    clean_text(feed_title, MAX_TAG_LEN)
+}
+
+/// Checks one feed and stores its new episodes; returns how many were new
+async fn refresh_feed(
+    pool: &SqlitePool,
+    client: &reqwest::Client,
+    feed: &FeedSubscription,
+) -> Result<u64, String> {
+    let parsed = match fetch_feed(client, &feed.url).await {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            if let Err(db) = FeedsRepository::mark_checked(pool, &feed.id, None, Some(&e)).await {
+                warn!("Failed to store the check of {}: {}", feed.url, db);
+            }
+            return Err(e);
+        }
+    };
+    let added = FeedsRepository::add_new_episodes(pool, &feed.id, &parsed.episodes)
+        .await
+        .map_err(|e| format!("Failed to store episodes: {}", e))?;
+    FeedsRepository::mark_checked(pool, &feed.id, Some(&parsed.title), None)
+        .await
+        .map_err(|e| format!("Failed to store the check: {}", e))?;
+    Ok(added)
+}
+
+/// Imports one episode as a background import job
+async fn import_episode<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &AppState,
+    feed: &FeedSubscription,
+    episode: &FeedEpisode,
+) -> Result<String, String> {
+    let pool = state.db_manager.pool();
+    let job = state
+        .jobs
+        .begin(app, "import", format!("Import {}", episode.title), None)
+        .await?;
+    let result = import_from_url(
+        app,
+        state,
+        &episode.media_url,
+        Some(episode.title.clone()),
+        vec![feed_tag(&feed.title)],
+        // An episode whose file is in the archive already (a re-posted
+        // episode, or one imported by hand) isn't imported twice
+        DuplicatePolicy::LinkExisting,
+        &job,
+        job.cancellation_token(),
+    )
+    .await;
+    job.finish(match &result {
+        Ok(meeting) => Ok(serde_json::json!({ "meeting_id": meeting.meeting_id })),
+        Err(e) => Err(e.to_string()),
+    })
+    .await;
+    let imported = result.map_err(|e| e.to_string())?;
+
+    // New imports are tagged by the import; an episode already in the
+    // archive keeps its tags and gains the feed's
+    if imported.linked_existing {
+        if let Err(e) =
+            TagsRepository::add_tag(pool, &imported.meeting_id, &feed_tag(&feed.title)).await
+        {
+            warn!("Failed to tag {} with its feed: {}", imported.meeting_id, e);
+        }
+    } else {
+        audit::record(
+            pool,
+            AuditAction::Import,
+            "meeting",
+            Some(&imported.meeting_id),
+            serde_json::json!({ "source": "feed", "feed": feed.url, "url": episode.media_url }),
+        )
+        .await;
+    }
+    Ok(imported.meeting_id)
+}
+
+/// Checks the enabled feeds and queues their pending episodes for import.
+/// Fails when a check is already running.
+pub async fn sync_feeds<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &AppState,
+) -> Result<FeedSyncReport, String> {
+    let sync = SYNC
+        .try_lock()
+        .map_err(|_| "Feeds are already being checked".to_string())?;
+    let pool = state.db_manager.pool();
+    let client = reqwest::Client::new();
+    let mut report = FeedSyncReport::default();
+
+    let feeds = FeedsRepository::list_feeds(pool)
+        .await
+        .map_err(|e| format!("Failed to load feeds: {}", e))?;
+    for feed in feeds.iter().filter(|feed| feed.enabled) {
+        report.checked += 1;
+        match refresh_feed(pool, &client, feed).await {
+            Ok(added) => report.new_episodes += added,
+            Err(e) => warn!("Checking feed {} failed: {}", feed.url, e),
+        }
+    }
+    drop(sync);
+
+    spawn_imports(app);
+    Ok(report)
+}
+
+/// Imports the pending episodes, oldest first
+async fn import_pending<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &AppState,
+) -> Result<FeedImportReport, String> {
+    let pool = state.db_manager.pool();
+    let mut report = FeedImportReport::default();
+    let pending = FeedsRepository::pending_episodes(pool)
+        .await
+        .map_err(|e| format!("Failed to load pending episodes: {}", e))?;
+    if pending.is_empty() {
+        return Ok(report);
+    }
+    // Episodes wait for a transcription model instead of failing
+    if let Err(e) = validate_transcription_model_ready(app).await {
+        info!(
+            "{} feed episodes wait for a transcription model: {}",
+            pending.len(),
+            e
+        );
+        return Ok(report);
+    }
+    let feeds = FeedsRepository::list_feeds(pool)
+        .await
+        .map_err(|e| format!("Failed to load feeds: {}", e))?;
+    for episode in pending {
+        if state.shutdown.is_shutting_down() {
+            break;
+        }
+        let Some(feed) = feeds.iter().find(|feed| feed.id == episode.feed_id) else {
+            continue;
+        };
+        info!("Importing episode '{}' of {}", episode.title, feed.title);
+        match import_episode(app, state, feed, &episode).await {
+            Ok(meeting_id) => {
+                report.imported += 1;
+                if let Err(e) = FeedsRepository::mark_imported(pool, &episode.id, &meeting_id).await
+                {
+                    error!("Failed to mark episode {} imported: {}", episode.id, e);
+                }
+            }
+            // Left pending; it continues after the restart
+            Err(_) if state.shutdown.is_shutting_down() => break,
+            // Cancelled from the jobs list: not retried unless queued again
+            Err(e) if e == "Cancelled" => {
+                if let Err(db) = FeedsRepository::skip_episode(pool, &episode.id).await {
+                    error!("Failed to skip episode {}: {}", episode.id, db);
+                }
+            }
+            Err(e) => {
+                warn!("Importing episode {} failed: {}", episode.media_url, e);
+                report.failed += 1;
+                if let Err(db) = FeedsRepository::mark_failed(pool, &episode.id, &e).await {
+                    error!("Failed to record the failure of {}: {}", episode.id, db);
+                }
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Imports pending episodes in the background. While the queue runs, a
+/// request makes it look for pending episodes again once it is done.
+pub fn spawn_imports<R: Runtime>(app: &AppHandle<R>) {
+    IMPORT_REQUESTED.store(true, Ordering::SeqCst);
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let Some(state) = app.try_state::<AppState>() else {
+            return;
+        };
+        loop {
+            let Ok(queue) = IMPORT_QUEUE.try_lock() else {
+                return;
+            };
+            while IMPORT_REQUESTED.swap(false, Ordering::SeqCst) {
+                match import_pending(&app, &state).await {
+                    Ok(report) if report.imported > 0 || report.failed > 0 => {
+                        info!("Feed imports: {:?}", report)
+                    }
+                    Ok(_) => {}
+                    Err(e) => warn!("Feed imports stopped: {}", e),
+                }
+            }
+            drop(queue);
+            // A request made just before the queue let go
+            if !IMPORT_REQUESTED.load(Ordering::SeqCst) {
+                return;
+            }
+        }
+    });
+}
+
+fn log_report(result: Result<FeedSyncReport, String>) {
+    match result {
+        Ok(report) if report.new_episodes > 0 => info!("Feed check: {:?}", report),
+        Ok(_) => {}
+        Err(e) => warn!("Feed check skipped: {}", e),
+    }
+}
+
+/// Checks the feeds while the app runs
+pub async fn run_scheduler<R: Runtime>(app: AppHandle<R>) {
+    let start = tokio::time::Instant::now() + Duration::from_secs(FIRST_CHECK_DELAY_SECS);
+    let mut interval = tokio::time::interval_at(start, Duration::from_secs(CHECK_INTERVAL_SECS));
+    loop {
+        interval.tick().await;
+        let Some(state) = app.try_state::<AppState>() else {
+            continue;
+        };
+        if state.shutdown.is_shutting_down() {
+            return;
+        }
+        log_report(sync_feeds(&app, &state).await);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base() -> Url {
+        Url::parse("https://example.com/podcast/feed.xml").unwrap()
+    }
+
+    #[test]
+    fn parses_rss_episodes() {
+        let xml = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+  <channel>
+    <title>Team   Radio</title>
+    <itunes:title>Ignored</itunes:title>
+    <item>
+      <itunes:title>Not this one</itunes:title>
+      <title><![CDATA[Ep. 2 — Roadmap]]></title>
+      <guid isPermaLink="false">ep-2</guid>
+      <pubDate>Tue, 13 Oct 2026 09:00:00 +0200</pubDate>
+      <enclosure url="audio/ep2.mp3" type="audio/mpeg" length="1"/>
+    </item>
+    <item>
+      <title>Show notes only</title>
+      <enclosure url="https://example.com/cover.jpg" type="image/jpeg"/>
+    </item>
+  </channel>
+</rss>"#;
+        let feed = parse_feed(xml, &base()).unwrap();
+        assert_eq!(feed.title, "Team Radio");
+        assert_eq!(feed.item_count, 2);
+        assert_eq!(
+            feed.episodes,
+            vec![EpisodeInput {
+                guid: "ep-2".to_string(),
+                title: "Ep. 2 — Roadmap".to_string(),
+                media_url: "https://example.com/podcast/audio/ep2.mp3".to_string(),
+                published_at: Some(
+                    DateTime::parse_from_rfc3339("2026-10-13T07:00:00Z")
+                        .unwrap()
+                        .with_timezone(&Utc)
+                ),
+            }]
+        );
+    }
+
+    #[test]
+    fn parses_atom_entries() {
+        let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>All-hands</title>
+  <entry>
+    <id>urn:uuid:1</id>
+    <updated>2026-10-01T17:00:00Z</updated>
+    <link rel="alternate" href="https://example.com/post"/>
+    <link rel="enclosure" type="video/mp4" href="https://cdn.example.com/q3.mp4"/>
+  </entry>
+</feed>"#;
+        let feed = parse_feed(xml, &base()).unwrap();
+        assert_eq!(feed.title, "All-hands");
+        assert_eq!(feed.episodes.len(), 1);
+        let episode = &feed.episodes[0];
+        assert_eq!(episode.guid, "urn:uuid:1");
+        assert_eq!(episode.title, "q3.mp4");
+        assert_eq!(episode.media_url, "https://cdn.example.com/q3.mp4");
+        assert!(parse_feed("<html><body/></html>", &base()).is_err());
+        assert!(parse_feed("not xml", &base()).is_err());
+    }
+}
@@ -0,0 +1,24 @@
+/// Integrations module - connections to third-party services
+///
+/// This module contains:
+/// - Issue trackers (Jira, Linear) for turning action items into issues
+/// - Email delivery of summaries (SMTP or the system mail client)
+/// - Zoom cloud recordings, imported as meetings (OAuth via a loopback redirect)
+/// - Microsoft Teams recordings from OneDrive/SharePoint via Microsoft Graph,
+///   with subject, organizer and attendees from the calendar event
+/// - RSS/Atom feed subscriptions, whose new episodes (team podcasts, all-hands
+///   recordings) are imported as meetings tagged with the feed's title
+/// - Tauri commands for configuring integrations and pushing data to them
+///
+/// Integration configs (including credentials) are stored in the
+/// `integration_settings` table and are redacted before reaching the frontend.
+
+pub mod commands;
+pub mod email;
+pub mod feeds;
+pub mod oauth;
+pub mod teams;
+pub mod trackers;
+pub mod zoom;
+
+pub use trackers::{CreatedIssue, TrackerKind};
@@ -0,0 +1,226 @@
+//! OAuth authorization-code flow for desktop integrations.
+//!
+//! The provider redirects the browser to a loopback address
+//! (`http://localhost:<port>/oauth/callback`) where a one-shot listener picks
+//! up the authorization code. The `state` parameter ties the callback to the
+//! request that started it. Public clients that can't keep a secret add a
+//! PKCE challenge ([`pkce_pair`]). [`download`] streams a file the access
+//! token grants access to.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+pub const CALLBACK_PATH: &str = "/oauth/callback";
+/// How long the user has to finish signing in in the browser
+const AUTHORIZE_TIMEOUT: Duration = Duration::from_secs(300);
+/// Tokens are refreshed this long before they expire
+const REFRESH_MARGIN_SECS: i64 = 60;
+
+/// Tokens from an authorization-code grant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OAuthTokens {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl OAuthTokens {
+    pub fn needs_refresh(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at - ChronoDuration::seconds(REFRESH_MARGIN_SECS) <= now
+    }
+}
+
+/// Token endpoint response, as defined by RFC 6749
+#[derive(Debug, Deserialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub expires_in: Option<i64>,
+}
+
+impl TokenResponse {
+    /// Providers may omit a new refresh token on refresh; the previous one
+    /// stays valid then
+    pub fn into_tokens(self, previous_refresh: Option<String>) -> OAuthTokens {
+        OAuthTokens {
+            access_token: self.access_token,
+            refresh_token: self.refresh_token.or(previous_refresh),
+            expires_at: Utc::now() + ChronoDuration::seconds(self.expires_in.unwrap_or(3600)),
+        }
+    }
+}
+
+/// PKCE (RFC 7636) code verifier and its S256 challenge
+pub fn pkce_pair() -> (String, String) {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill(&mut bytes);
+    let verifier = URL_SAFE_NO_PAD.encode(bytes);
+    let challenge = pkce_challenge(&verifier);
+    (verifier, challenge)
+}
+
+fn pkce_challenge(verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(ring::digest::digest(
+        &ring::digest::SHA256,
+        verifier.as_bytes(),
+    ))
+}
+
+pub fn redirect_uri(port: u16) -> String {
+    format!("http://localhost:{}{}", port, CALLBACK_PATH)
+}
+
+/// Extracts the authorization code from the callback's request line
+/// (`GET /oauth/callback?code=...&state=... HTTP/1.1`)
+pub fn parse_callback(request_line: &str, expected_state: &str) -> Result<String, String> {
+    let target = request_line
+        .split_whitespace()
+        .nth(1)
+        .ok_or_else(|| "Malformed callback request".to_string())?;
+    let url = url::Url::parse(&format!("http://localhost{}", target))
+        .map_err(|e| format!("Malformed callback URL: {}", e))?;
+    if url.path() != CALLBACK_PATH {
+        return Err(format!("Unexpected callback path {}", url.path()));
+    }
+
+    let param = |name: &str| {
+        url.query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+    };
+    if let Some(error) = param("error") {
+        let description = param("error_description").unwrap_or_default();
+        return Err(
+            format!("Authorization was denied: {} {}", error, description)
+                .trim()
+                .to_string(),
+        );
+    }
+    if param("state").as_deref() != Some(expected_state) {
+        return Err("Authorization state mismatch; please try again".to_string());
+    }
+    param("code").ok_or_else(|| "Authorization callback has no code".to_string())
+}
+
+/// Waits for the browser to come back to the loopback listener and returns
+/// the authorization code. Requests for other paths (favicon) are ignored.
+pub async fn wait_for_code(listener: TcpListener, expected_state: &str) -> Result<String, String> {
+    let accept = async {
+        loop {
+            let (mut stream, _) = listener
+                .accept()
+                .await
+                .map_err(|e| format!("Failed to accept the sign-in callback: {}", e))?;
+            let mut buffer = vec![0u8; 8192];
+            let read = stream.read(&mut buffer).await.unwrap_or(0);
+            let request = String::from_utf8_lossy(&buffer[..read]);
+            let request_line = request.lines().next().unwrap_or_default();
+            if !request_line.contains(CALLBACK_PATH) {
+                let _ = stream
+                    .write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n")
+                    .await;
+                continue;
+            }
+
+            let result = parse_callback(request_line, expected_state);
+            let message = match &result {
+                Ok(_) => "Signed in. You can close this window and return to Meetily.",
+                Err(_) => "Sign-in failed. Return to Meetily for details.",
+            };
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                message.len(),
+                message
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+            return result;
+        }
+    };
+    tokio::time::timeout(AUTHORIZE_TIMEOUT, accept)
+        .await
+        .map_err(|_| "Timed out waiting for sign-in to finish in the browser".to_string())?
+}
+
+/// Streams `url` to `destination` with the access token. `provider` names
+/// the service in errors. Redirects to pre-signed storage URLs on another
+/// host drop the authorization header.
+pub async fn download(
+    client: &reqwest::Client,
+    token: &str,
+    url: &str,
+    destination: &Path,
+    provider: &str,
+) -> Result<u64, String> {
+    let mut response = client
+        .get(url)
+        .bearer_auth(token)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to download recording: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!(
+            "{} returned {} for the recording download",
+            provider,
+            response.status()
+        ));
+    }
+
+    let mut out = tokio::fs::File::create(destination)
+        .await
+        .map_err(|e| format!("Failed to create {}: {}", destination.display(), e))?;
+    let mut written = 0u64;
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| format!("Recording download interrupted: {}", e))?
+    {
+        out.write_all(&chunk)
+            .await
+            .map_err(|e| format!("Failed to write recording: {}", e))?;
+        written += chunk.len() as u64;
+    }
+    out.flush()
+        .await
+        .map_err(|e| format!("Failed to write recording: {}", e))?;
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_callbacks() {
+        assert_eq!(
+            parse_callback("GET /oauth/callback?code=abc%2F1&state=s1 HTTP/1.1", "s1"),
+            Ok("abc/1".to_string())
+        );
+        assert!(parse_callback("GET /oauth/callback?code=abc&state=other HTTP/1.1", "s1").is_err());
+        assert!(parse_callback("GET /oauth/callback?state=s1 HTTP/1.1", "s1").is_err());
+        assert!(parse_callback("GET /elsewhere?code=abc&state=s1 HTTP/1.1", "s1").is_err());
+        let denied = parse_callback(
+            "GET /oauth/callback?error=access_denied&state=s1 HTTP/1.1",
+            "s1",
+        )
+        .unwrap_err();
+        assert!(denied.contains("access_denied"));
+    }
+
+    #[test]
+    fn pkce_challenge_is_unpadded_base64url_sha256() {
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K9uhvm4yJCXp9tBDyhv9wyGHho"),
+            "n59NiyubnTS-pSn_gLIrM2Cr-SKoQ3SueeVscxgMwpk"
+        );
+        let (verifier, challenge) = pkce_pair();
+        assert_eq!(verifier.len(), 43);
+        assert_eq!(challenge, pkce_challenge(&verifier));
+    }
+}
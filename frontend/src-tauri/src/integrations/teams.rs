@@ -0,0 +1,729 @@
+//! Microsoft Teams meeting recordings stored in OneDrive / SharePoint, via
+//! Microsoft Graph: OAuth (public client with PKCE), listing recordings,
+//! downloading them and looking up the calendar event for subject,
+//! organizer and attendees.
+//!
+//! The user registers an Entra ID app as a public client (mobile and desktop)
+//! with the redirect URI `http://localhost:<redirect_port>/oauth/callback` and
+//! the delegated permissions `Files.Read.All` and `Calendars.Read`, then
+//! enters its client id (and the tenant, when it isn't multi-tenant).
+
+use chrono::{DateTime, Duration as ChronoDuration, NaiveDateTime, Utc};
+use log::info;
+use once_cell::sync::Lazy;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use regex::Regex;
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::path::Path;
+use std::time::Duration;
+
+use super::oauth::{OAuthTokens, TokenResponse};
+use crate::database::repositories::{
+    integration::IntegrationSettingsRepository, participant::ParticipantInput,
+};
+
+pub const CONFIG_ID: &str = "teams";
+pub const DEFAULT_REDIRECT_PORT: u16 = 47824;
+/// Work and school accounts of any tenant
+pub const DEFAULT_TENANT: &str = "organizations";
+const LOGIN_URL: &str = "https://login.microsoftonline.com";
+const GRAPH_URL: &str = "https://graph.microsoft.com/v1.0";
+const SCOPES: &str = "offline_access User.Read Files.Read.All Calendars.Read";
+const REQUEST_TIMEOUT_SECS: u64 = 30;
+/// Teams uploads the recording after the meeting ends, so the event started
+/// before the file was created
+const EVENT_LOOKBACK_HOURS: i64 = 12;
+const EVENT_LOOKAHEAD_HOURS: i64 = 1;
+/// An event with another subject only counts when it started while the
+/// recording could have been running, give or take this much
+const UNMATCHED_EVENT_SLACK_MINUTES: i64 = 30;
+/// Assumed recording length when Graph doesn't report one
+const DEFAULT_RECORDING_HOURS: i64 = 2;
+
+/// Characters left as they are in a Graph path segment (RFC 3986 unreserved)
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// `<subject>-<yyyymmdd>_<hhmmss>[-Meeting Recording]`
+static RECORDING_NAME: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(.*?)-(\d{8}_\d{6})(?:-.*)?$").expect("valid recording pattern"));
+
+/// Drive and item ids come from Graph responses and may contain `!` or
+/// other characters that need escaping in a URL path
+fn item_url(drive_id: &str, item_id: &str) -> String {
+    format!(
+        "{}/drives/{}/items/{}",
+        GRAPH_URL,
+        utf8_percent_encode(drive_id, PATH_SEGMENT),
+        utf8_percent_encode(item_id, PATH_SEGMENT)
+    )
+}
+
+fn default_redirect_port() -> u16 {
+    DEFAULT_REDIRECT_PORT
+}
+
+fn default_tenant() -> String {
+    DEFAULT_TENANT.to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamsConfig {
+    pub client_id: String,
+    /// Tenant id or domain; "organizations" for any work or school account
+    #[serde(default = "default_tenant")]
+    pub tenant: String,
+    #[serde(default = "default_redirect_port")]
+    pub redirect_port: u16,
+    /// Present once the user has connected their Microsoft account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tokens: Option<OAuthTokens>,
+}
+
+impl TeamsConfig {
+    /// Tokens never reach the frontend (there is no client secret)
+    pub fn redacted(mut self) -> Self {
+        self.tokens = None;
+        self
+    }
+
+    fn endpoint(&self, name: &str) -> String {
+        format!("{}/{}/oauth2/v2.0/{}", LOGIN_URL, self.tenant, name)
+    }
+}
+
+/// A recording file as listed to the user
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamsRecording {
+    pub drive_id: String,
+    pub item_id: String,
+    pub name: String,
+    /// Meeting subject taken from the file name
+    pub subject: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub size: Option<u64>,
+    pub duration_ms: Option<u64>,
+    pub web_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ParentReference {
+    #[serde(rename = "driveId")]
+    drive_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FileFacet {
+    #[serde(rename = "mimeType")]
+    mime_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VideoFacet {
+    duration: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DriveItem {
+    id: String,
+    #[serde(default)]
+    name: String,
+    size: Option<u64>,
+    created_date_time: Option<DateTime<Utc>>,
+    web_url: Option<String>,
+    parent_reference: Option<ParentReference>,
+    file: Option<FileFacet>,
+    video: Option<VideoFacet>,
+    /// Items shared with the user point at the owner's drive
+    remote_item: Option<Box<DriveItem>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Page<T> {
+    #[serde(default = "Vec::new")]
+    value: Vec<T>,
+    #[serde(rename = "@odata.nextLink")]
+    next_link: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmailAddress {
+    #[serde(default)]
+    pub name: String,
+    pub address: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Recipient {
+    pub email_address: EmailAddress,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attendee {
+    pub email_address: EmailAddress,
+    /// "required", "optional" or "resource" (rooms, equipment)
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventTime {
+    #[serde(rename = "dateTime")]
+    pub date_time: String,
+}
+
+impl EventTime {
+    /// calendarView returns UTC when no time zone preference is sent
+    fn parse(&self) -> Option<DateTime<Utc>> {
+        NaiveDateTime::parse_from_str(&self.date_time, "%Y-%m-%dT%H:%M:%S%.f")
+            .ok()
+            .map(|t| t.and_utc())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarEvent {
+    #[serde(default)]
+    pub subject: String,
+    pub organizer: Option<Recipient>,
+    #[serde(default)]
+    pub attendees: Vec<Attendee>,
+    pub start: EventTime,
+    #[serde(default)]
+    pub is_online_meeting: bool,
+}
+
+pub fn authorize_url(config: &TeamsConfig, state: &str, code_challenge: &str) -> String {
+    let mut url = url::Url::parse(&config.endpoint("authorize")).expect("valid authorize URL");
+    url.query_pairs_mut()
+        .append_pair("response_type", "code")
+        .append_pair("client_id", &config.client_id)
+        .append_pair(
+            "redirect_uri",
+            &super::oauth::redirect_uri(config.redirect_port),
+        )
+        .append_pair("scope", SCOPES)
+        .append_pair("state", state)
+        .append_pair("code_challenge", code_challenge)
+        .append_pair("code_challenge_method", "S256");
+    url.to_string()
+}
+
+async fn request_token(
+    client: &Client,
+    config: &TeamsConfig,
+    form: &[(&str, &str)],
+) -> Result<TokenResponse, String> {
+    let mut form = form.to_vec();
+    form.push(("client_id", &config.client_id));
+    form.push(("scope", SCOPES));
+    let response = client
+        .post(config.endpoint("token"))
+        .form(&form)
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Microsoft: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Microsoft sign-in failed ({}): {}", status, body));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Microsoft token response: {}", e))
+}
+
+pub async fn exchange_code(
+    client: &Client,
+    config: &TeamsConfig,
+    code: &str,
+    code_verifier: &str,
+) -> Result<OAuthTokens, String> {
+    let redirect_uri = super::oauth::redirect_uri(config.redirect_port);
+    let response = request_token(
+        client,
+        config,
+        &[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", &redirect_uri),
+            ("code_verifier", code_verifier),
+        ],
+    )
+    .await?;
+    Ok(response.into_tokens(None))
+}
+
+pub async fn load_config(pool: &SqlitePool) -> Result<Option<TeamsConfig>, String> {
+    let raw = IntegrationSettingsRepository::get_config(pool, CONFIG_ID)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    raw.map(|raw| {
+        serde_json::from_str(&raw)
+            .map_err(|e| format!("Stored Teams configuration is invalid: {}", e))
+    })
+    .transpose()
+}
+
+pub async fn save_config(pool: &SqlitePool, config: &TeamsConfig) -> Result<(), String> {
+    let raw = serde_json::to_string(config)
+        .map_err(|e| format!("Failed to serialize Teams configuration: {}", e))?;
+    IntegrationSettingsRepository::save_config(pool, CONFIG_ID, &raw)
+        .await
+        .map_err(|e| format!("Failed to save Teams configuration: {}", e))
+}
+
+/// A valid access token, refreshed (and stored) when it is about to expire
+pub async fn access_token(client: &Client, pool: &SqlitePool) -> Result<String, String> {
+    let mut config = load_config(pool)
+        .await?
+        .ok_or_else(|| "Microsoft Teams is not configured".to_string())?;
+    let tokens = config
+        .tokens
+        .clone()
+        .ok_or_else(|| "Connect your Microsoft account first".to_string())?;
+    if !tokens.needs_refresh(Utc::now()) {
+        return Ok(tokens.access_token);
+    }
+
+    let refresh_token = tokens.refresh_token.clone().ok_or_else(|| {
+        "Microsoft session expired; connect your Microsoft account again".to_string()
+    })?;
+    let refreshed = request_token(
+        client,
+        &config,
+        &[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", &refresh_token),
+        ],
+    )
+    .await
+    .map_err(|e| format!("{}; connect your Microsoft account again", e))?
+    .into_tokens(Some(refresh_token));
+    let access = refreshed.access_token.clone();
+    config.tokens = Some(refreshed);
+    save_config(pool, &config).await?;
+    info!("Refreshed Microsoft Graph access token");
+    Ok(access)
+}
+
+/// GETs a Graph URL; `Ok(None)` for 404 so callers can treat a missing
+/// folder as empty
+async fn get_json<T: for<'de> Deserialize<'de>>(
+    client: &Client,
+    token: &str,
+    url: &str,
+    query: &[(&str, String)],
+) -> Result<Option<T>, String> {
+    let response = client
+        .get(url)
+        .bearer_auth(token)
+        .query(query)
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Microsoft Graph: {}", e))?;
+    let status = response.status();
+    if status == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Microsoft Graph returned {}: {}", status, body));
+    }
+    response
+        .json()
+        .await
+        .map(Some)
+        .map_err(|e| format!("Failed to parse Microsoft Graph response: {}", e))
+}
+
+/// Every item of a paged collection
+async fn get_all<T: for<'de> Deserialize<'de>>(
+    client: &Client,
+    token: &str,
+    url: &str,
+    query: &[(&str, String)],
+) -> Result<Vec<T>, String> {
+    let mut items = Vec::new();
+    let Some(mut page) = get_json::<Page<T>>(client, token, url, query).await? else {
+        return Ok(items);
+    };
+    loop {
+        items.extend(page.value);
+        // The next link already carries the query
+        match page.next_link {
+            Some(next) => match get_json::<Page<T>>(client, token, &next, &[]).await? {
+                Some(next_page) => page = next_page,
+                None => break,
+            },
+            None => break,
+        }
+    }
+    Ok(items)
+}
+
+fn is_recording(item: &DriveItem) -> bool {
+    let video = item.video.is_some()
+        || item
+            .file
+            .as_ref()
+            .and_then(|f| f.mime_type.as_deref())
+            .is_some_and(|m| m.starts_with("video/"));
+    video && item.name.to_lowercase().ends_with(".mp4")
+}
+
+fn to_recording(item: DriveItem) -> Option<TeamsRecording> {
+    // Shared items describe the original in `remoteItem`
+    let item = match item.remote_item {
+        Some(remote) => DriveItem {
+            name: if remote.name.is_empty() {
+                item.name
+            } else {
+                remote.name
+            },
+            ..*remote
+        },
+        None => item,
+    };
+    if !is_recording(&item) {
+        return None;
+    }
+    let drive_id = item.parent_reference.as_ref()?.drive_id.clone()?;
+    let (subject, _) = parse_recording_name(&item.name);
+    Some(TeamsRecording {
+        drive_id,
+        item_id: item.id,
+        subject,
+        name: item.name,
+        created_at: item.created_date_time,
+        size: item.size,
+        duration_ms: item.video.and_then(|v| v.duration),
+        web_url: item.web_url,
+    })
+}
+
+/// Teams recordings the user can reach: the organizer's own `Recordings`
+/// folder in OneDrive, plus recordings shared with them (meetings others
+/// organized, channel meetings in SharePoint). Newest first.
+pub async fn list_recordings(client: &Client, token: &str) -> Result<Vec<TeamsRecording>, String> {
+    let own: Vec<DriveItem> = get_all(
+        client,
+        token,
+        &format!("{}/me/drive/root:/Recordings:/children", GRAPH_URL),
+        &[("$top", "200".to_string())],
+    )
+    .await?;
+    let shared: Vec<DriveItem> = get_all(
+        client,
+        token,
+        &format!("{}/me/drive/sharedWithMe", GRAPH_URL),
+        &[],
+    )
+    .await?;
+
+    let mut recordings: Vec<TeamsRecording> = Vec::new();
+    for recording in own.into_iter().chain(shared).filter_map(to_recording) {
+        let seen = recordings
+            .iter()
+            .any(|r| r.drive_id == recording.drive_id && r.item_id == recording.item_id);
+        if !seen {
+            recordings.push(recording);
+        }
+    }
+    recordings.sort_by_key(|r| std::cmp::Reverse(r.created_at));
+    Ok(recordings)
+}
+
+pub async fn get_recording(
+    client: &Client,
+    token: &str,
+    drive_id: &str,
+    item_id: &str,
+) -> Result<TeamsRecording, String> {
+    let item: DriveItem = get_json(client, token, &item_url(drive_id, item_id), &[])
+        .await?
+        .ok_or_else(|| "The recording no longer exists or isn't shared with you".to_string())?;
+    to_recording(item).ok_or_else(|| "The selected file is not a meeting recording".to_string())
+}
+
+/// Splits a Teams recording file name
+/// (`<subject>-<yyyymmdd>_<hhmmss>-Meeting Recording.mp4`) into the meeting
+/// subject and the recording start in the recorder's local time. Names that
+/// don't follow the pattern keep the whole stem as subject.
+pub fn parse_recording_name(name: &str) -> (String, Option<NaiveDateTime>) {
+    let stem = match name.rsplit_once('.') {
+        Some((stem, _)) => stem,
+        None => name,
+    };
+    if let Some(captures) = RECORDING_NAME.captures(stem) {
+        let subject = captures[1].trim().to_string();
+        let started = NaiveDateTime::parse_from_str(&captures[2], "%Y%m%d_%H%M%S").ok();
+        if !subject.is_empty() && started.is_some() {
+            return (subject, started);
+        }
+    }
+    (stem.trim().to_string(), None)
+}
+
+/// The calendar event a recording belongs to: among events that started
+/// within the lookup window before the file was created, the latest with the
+/// same subject. Otherwise the only online meeting that started while the
+/// recording of `duration` could have been running; an earlier meeting or a
+/// choice between several would attach the wrong attendees.
+pub fn match_event<'a>(
+    events: &'a [CalendarEvent],
+    subject: &str,
+    created_at: DateTime<Utc>,
+    duration: Option<ChronoDuration>,
+) -> Option<&'a CalendarEvent> {
+    let candidates: Vec<(&CalendarEvent, DateTime<Utc>)> = events
+        .iter()
+        .filter_map(|event| Some((event, event.start.parse()?)))
+        .filter(|(_, start)| *start <= created_at)
+        .collect();
+    let latest = |events: Vec<(&'a CalendarEvent, DateTime<Utc>)>| {
+        events
+            .into_iter()
+            .max_by_key(|(_, start)| *start)
+            .map(|(event, _)| event)
+    };
+
+    let same_subject: Vec<_> = candidates
+        .iter()
+        .copied()
+        .filter(|(event, _)| event.subject.trim().eq_ignore_ascii_case(subject.trim()))
+        .collect();
+    if !same_subject.is_empty() {
+        return latest(same_subject);
+    }
+
+    let earliest = created_at
+        - duration.unwrap_or_else(|| ChronoDuration::hours(DEFAULT_RECORDING_HOURS))
+        - ChronoDuration::minutes(UNMATCHED_EVENT_SLACK_MINUTES);
+    let mut during = candidates
+        .into_iter()
+        .filter(|(event, start)| event.is_online_meeting && *start >= earliest);
+    match (during.next(), during.next()) {
+        (Some((event, _)), None) => Some(event),
+        _ => None,
+    }
+}
+
+/// Looks up the calendar event for a recording created at `created_at`
+pub async fn find_event(
+    client: &Client,
+    token: &str,
+    subject: &str,
+    created_at: DateTime<Utc>,
+    duration: Option<ChronoDuration>,
+) -> Result<Option<CalendarEvent>, String> {
+    let window_start = created_at - ChronoDuration::hours(EVENT_LOOKBACK_HOURS);
+    let window_end = created_at + ChronoDuration::hours(EVENT_LOOKAHEAD_HOURS);
+    let events: Vec<CalendarEvent> = get_all(
+        client,
+        token,
+        &format!("{}/me/calendarView", GRAPH_URL),
+        &[
+            ("startDateTime", window_start.to_rfc3339()),
+            ("endDateTime", window_end.to_rfc3339()),
+            (
+                "$select",
+                "subject,organizer,attendees,start,isOnlineMeeting".to_string(),
+            ),
+            ("$top", "100".to_string()),
+        ],
+    )
+    .await?;
+    Ok(match_event(&events, subject, created_at, duration).cloned())
+}
+
+/// The organizer and the attendees of an event as participants; rooms and
+/// equipment are left out
+pub fn event_participants(event: &CalendarEvent) -> Vec<ParticipantInput> {
+    let organizer = event
+        .organizer
+        .iter()
+        .map(|o| (&o.email_address, Some("organizer")));
+    let attendees = event
+        .attendees
+        .iter()
+        .filter(|a| a.kind.as_deref() != Some("resource"))
+        .map(|a| (&a.email_address, None));
+
+    let mut people: Vec<ParticipantInput> = Vec::new();
+    for (address, role) in organizer.chain(attendees) {
+        let email = address
+            .address
+            .as_deref()
+            .map(|e| e.trim().to_lowercase())
+            .filter(|e| !e.is_empty());
+        let name = match address.name.trim() {
+            "" => match &email {
+                Some(email) => email.clone(),
+                None => continue,
+            },
+            name => name.to_string(),
+        };
+        let seen = people.iter().any(|p| match (&p.email, &email) {
+            (Some(a), Some(b)) => a == b,
+            _ => p.name.eq_ignore_ascii_case(&name),
+        });
+        if !seen {
+            people.push(ParticipantInput {
+                name,
+                email,
+                role: role.map(str::to_string),
+                speaker_label: None,
+            });
+        }
+    }
+    people
+}
+
+/// Streams a recording to `destination`; Graph redirects to a pre-signed URL
+pub async fn download(
+    client: &Client,
+    token: &str,
+    recording: &TeamsRecording,
+    destination: &Path,
+) -> Result<u64, String> {
+    let url = format!(
+        "{}/content",
+        item_url(&recording.drive_id, &recording.item_id)
+    );
+    super::oauth::download(client, token, &url, destination, "Microsoft Graph").await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(subject: &str, start: &str, online: bool) -> CalendarEvent {
+        CalendarEvent {
+            subject: subject.to_string(),
+            organizer: None,
+            attendees: Vec::new(),
+            start: EventTime {
+                date_time: start.to_string(),
+            },
+            is_online_meeting: online,
+        }
+    }
+
+    #[test]
+    fn parses_recording_names() {
+        let (subject, started) =
+            parse_recording_name("Weekly sync - Platform-20240115_100312-Meeting Recording.mp4");
+        assert_eq!(subject, "Weekly sync - Platform");
+        assert_eq!(
+            started.unwrap().format("%Y-%m-%d %H:%M:%S").to_string(),
+            "2024-01-15 10:03:12"
+        );
+        assert_eq!(
+            parse_recording_name("Recording.mp4"),
+            ("Recording".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn matches_events_by_subject_then_start() {
+        let created: DateTime<Utc> = "2024-01-15T11:05:00Z".parse().unwrap();
+        let events = vec![
+            event("Weekly sync", "2024-01-15T10:00:00.0000000", true),
+            event("1:1", "2024-01-15T10:30:00.0000000", true),
+            event("Lunch", "2024-01-15T10:45:00.0000000", false),
+            event("Later", "2024-01-15T11:30:00.0000000", true),
+        ];
+        let half_hour = Some(ChronoDuration::minutes(30));
+        assert_eq!(
+            match_event(&events, "weekly sync", created, half_hour)
+                .unwrap()
+                .subject,
+            "Weekly sync"
+        );
+        assert_eq!(
+            match_event(&events, "Renamed", created, half_hour)
+                .unwrap()
+                .subject,
+            "1:1"
+        );
+        assert!(match_event(&events[3..], "Later", created, half_hour).is_none());
+    }
+
+    #[test]
+    fn other_subjects_only_match_a_single_meeting_during_the_recording() {
+        let created: DateTime<Utc> = "2024-01-15T16:05:00Z".parse().unwrap();
+        let morning = vec![event("Standup", "2024-01-15T09:00:00", true)];
+        assert!(match_event(&morning, "Renamed", created, None).is_none());
+
+        let overlapping = vec![
+            event("Design review", "2024-01-15T15:00:00", true),
+            event("1:1", "2024-01-15T15:30:00", true),
+        ];
+        assert!(match_event(&overlapping, "Renamed", created, None).is_none());
+        assert_eq!(
+            match_event(
+                &overlapping,
+                "Renamed",
+                created,
+                Some(ChronoDuration::minutes(20))
+            )
+            .unwrap()
+            .subject,
+            "1:1"
+        );
+    }
+
+    #[test]
+    fn item_ids_are_escaped_in_urls() {
+        assert_eq!(
+            item_url("b!aZ_9-x", "01AB/CD?x=1"),
+            format!("{}/drives/b%21aZ_9-x/items/01AB%2FCD%3Fx%3D1", GRAPH_URL)
+        );
+    }
+
+    #[test]
+    fn maps_organizer_and_attendees() {
+        let address = |name: &str, email: &str| EmailAddress {
+            name: name.to_string(),
+            address: Some(email.to_string()),
+        };
+        let mut meeting = event("Sync", "2024-01-15T10:00:00", true);
+        meeting.organizer = Some(Recipient {
+            email_address: address("Ada", "Ada@Example.com"),
+        });
+        meeting.attendees = vec![
+            Attendee {
+                email_address: address("Ada Lovelace", "ada@example.com"),
+                kind: Some("required".to_string()),
+            },
+            Attendee {
+                email_address: address("Room 4", "room4@example.com"),
+                kind: Some("resource".to_string()),
+            },
+            Attendee {
+                email_address: address("", "bob@example.com"),
+                kind: Some("optional".to_string()),
+            },
+        ];
+        let people = event_participants(&meeting);
+        assert_eq!(people.len(), 2);
+        assert_eq!(people[0].role.as_deref(), Some("organizer"));
+        assert_eq!(people[0].email.as_deref(), Some("ada@example.com"));
+        assert_eq!(people[1].name, "bob@example.com");
+    }
+}
@@ -0,0 +1,283 @@
+//! Jira and Linear clients for creating issues from action items.
+
+use chrono::NaiveDate;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::time::Duration;
+
+use crate::database::models::ActionItem;
+
+const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
+const REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Placeholder returned instead of real credentials when configs are read back
+pub const REDACTED: &str = "********";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrackerKind {
+    Jira,
+    Linear,
+}
+
+impl TrackerKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrackerKind::Jira => "jira",
+            TrackerKind::Linear => "linear",
+        }
+    }
+}
+
+impl std::str::FromStr for TrackerKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "jira" => Ok(TrackerKind::Jira),
+            "linear" => Ok(TrackerKind::Linear),
+            other => Err(format!("Unsupported tracker: {}", other)),
+        }
+    }
+}
+
+/// Jira Cloud configuration (REST API v3, basic auth with an API token)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraConfig {
+    /// e.g. "https://your-team.atlassian.net"
+    #[serde(rename = "baseUrl")]
+    pub base_url: String,
+    pub email: String,
+    #[serde(rename = "apiToken")]
+    pub api_token: String,
+    #[serde(rename = "projectKey")]
+    pub project_key: String,
+    /// Defaults to "Task"
+    #[serde(rename = "issueType")]
+    pub issue_type: Option<String>,
+}
+
+/// Linear configuration (GraphQL API, personal API key)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinearConfig {
+    #[serde(rename = "apiKey")]
+    pub api_key: String,
+    #[serde(rename = "teamId")]
+    pub team_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedIssue {
+    pub key: String,
+    pub url: Option<String>,
+}
+
+impl JiraConfig {
+    pub fn redacted(mut self) -> Self {
+        if !self.api_token.is_empty() {
+            self.api_token = REDACTED.to_string();
+        }
+        self
+    }
+}
+
+impl LinearConfig {
+    pub fn redacted(mut self) -> Self {
+        if !self.api_key.is_empty() {
+            self.api_key = REDACTED.to_string();
+        }
+        self
+    }
+}
+
+/// Returns the due date only if it is an actual calendar date; free text like
+/// "next week" is left in the description instead.
+pub fn normalized_due_date(due: Option<&str>) -> Option<String> {
+    let due = due?.trim();
+    NaiveDate::parse_from_str(due, "%Y-%m-%d")
+        .ok()
+        .map(|d| d.format("%Y-%m-%d").to_string())
+}
+
+/// Issue titles are capped so long extracted sentences don't get rejected
+pub fn issue_title(item: &ActionItem) -> String {
+    const MAX_TITLE_CHARS: usize = 200;
+    let text = item.text.trim();
+    if text.chars().count() <= MAX_TITLE_CHARS {
+        text.to_string()
+    } else {
+        let truncated: String = text.chars().take(MAX_TITLE_CHARS - 1).collect();
+        format!("{}…", truncated)
+    }
+}
+
+pub fn issue_description(item: &ActionItem, meeting_title: &str) -> String {
+    let mut lines = vec![item.text.trim().to_string(), String::new()];
+    lines.push(format!("From meeting: {}", meeting_title));
+    if let Some(owner) = &item.owner {
+        lines.push(format!("Owner: {}", owner));
+    }
+    if let Some(due) = &item.due_date {
+        lines.push(format!("Due: {}", due));
+    }
+    lines.join("\n")
+}
+
+/// Wraps plain text into an Atlassian Document Format document (one paragraph per line)
+fn jira_adf(text: &str) -> serde_json::Value {
+    let paragraphs: Vec<serde_json::Value> = text
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                json!({ "type": "paragraph", "content": [] })
+            } else {
+                json!({ "type": "paragraph", "content": [{ "type": "text", "text": line }] })
+            }
+        })
+        .collect();
+    json!({ "type": "doc", "version": 1, "content": paragraphs })
+}
+
+pub async fn create_jira_issue(
+    client: &Client,
+    config: &JiraConfig,
+    title: &str,
+    description: &str,
+    due_date: Option<&str>,
+) -> Result<CreatedIssue, String> {
+    let base_url = config.base_url.trim_end_matches('/');
+    let mut fields = json!({
+        "project": { "key": config.project_key },
+        "summary": title,
+        "issuetype": { "name": config.issue_type.as_deref().unwrap_or("Task") },
+        "description": jira_adf(description),
+    });
+    if let Some(due) = due_date {
+        fields["duedate"] = json!(due);
+    }
+
+    let response = client
+        .post(format!("{}/rest/api/3/issue", base_url))
+        .basic_auth(&config.email, Some(&config.api_token))
+        .json(&json!({ "fields": fields }))
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Jira: {}", e))?;
+
+    let status = response.status();
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Jira response: {}", e))?;
+    if !status.is_success() {
+        return Err(format!("Jira returned {}: {}", status, body));
+    }
+
+    let key = body["key"]
+        .as_str()
+        .ok_or_else(|| "Jira response missing issue key".to_string())?;
+    Ok(CreatedIssue {
+        key: key.to_string(),
+        url: Some(format!("{}/browse/{}", base_url, key)),
+    })
+}
+
+pub async fn create_linear_issue(
+    client: &Client,
+    config: &LinearConfig,
+    title: &str,
+    description: &str,
+    due_date: Option<&str>,
+) -> Result<CreatedIssue, String> {
+    let mut input = json!({
+        "teamId": config.team_id,
+        "title": title,
+        "description": description,
+    });
+    if let Some(due) = due_date {
+        input["dueDate"] = json!(due);
+    }
+
+    let query = "mutation IssueCreate($input: IssueCreateInput!) { \
+                 issueCreate(input: $input) { success issue { identifier url } } }";
+
+    let response = client
+        .post(LINEAR_API_URL)
+        .header("Authorization", &config.api_key)
+        .json(&json!({ "query": query, "variables": { "input": input } }))
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Linear: {}", e))?;
+
+    let status = response.status();
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Linear response: {}", e))?;
+    if !status.is_success() || body.get("errors").is_some() {
+        return Err(format!("Linear returned {}: {}", status, body));
+    }
+
+    let issue = &body["data"]["issueCreate"]["issue"];
+    let key = issue["identifier"]
+        .as_str()
+        .ok_or_else(|| "Linear response missing issue identifier".to_string())?;
+    Ok(CreatedIssue {
+        key: key.to_string(),
+        url: issue["url"].as_str().map(|u| u.to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::DateTimeUtc;
+    use chrono::Utc;
+
+    fn item(text: &str, due: Option<&str>) -> ActionItem {
+        ActionItem {
+            id: "action-1".into(),
+            meeting_id: "meeting-1".into(),
+            text: text.into(),
+            owner: Some("Alice".into()),
+            due_date: due.map(|d| d.into()),
+            status: "open".into(),
+            source: "summary".into(),
+            created_at: DateTimeUtc(Utc::now()),
+            updated_at: DateTimeUtc(Utc::now()),
+            external_tracker: None,
+            external_key: None,
+            external_url: None,
+            reminded_on: None,
+            snoozed_until: None,
+        }
+    }
+
+    #[test]
+    fn only_real_dates_are_sent_as_due_dates() {
+        assert_eq!(normalized_due_date(Some("2026-02-01")), Some("2026-02-01".into()));
+        assert_eq!(normalized_due_date(Some("next Friday")), None);
+        assert_eq!(normalized_due_date(None), None);
+    }
+
+    #[test]
+    fn builds_title_and_description() {
+        let long = "x".repeat(300);
+        assert_eq!(issue_title(&item(&long, None)).chars().count(), 200);
+
+        let description = issue_description(&item("Send deck", Some("Friday")), "Weekly sync");
+        assert!(description.contains("From meeting: Weekly sync"));
+        assert!(description.contains("Owner: Alice"));
+        assert!(description.contains("Due: Friday"));
+    }
+
+    #[test]
+    fn parses_tracker_kind() {
+        assert_eq!("Jira".parse::<TrackerKind>(), Ok(TrackerKind::Jira));
+        assert_eq!("linear".parse::<TrackerKind>(), Ok(TrackerKind::Linear));
+        assert!("asana".parse::<TrackerKind>().is_err());
+    }
+}
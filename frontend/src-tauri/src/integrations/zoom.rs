@@ -0,0 +1,449 @@
+//! Zoom cloud recordings: OAuth (user-level app), listing recordings,
+//! downloading them and looking up who attended.
+//!
+//! The user registers a Zoom OAuth app with the redirect URL
+//! `http://localhost:<redirect_port>/oauth/callback` and the scopes
+//! `cloud_recording:read` and `meeting:read` (for participants), then enters
+//! its client id and secret in the app.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use log::info;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::path::Path;
+use std::time::Duration;
+
+use super::oauth::{OAuthTokens, TokenResponse};
+use super::trackers::REDACTED;
+use crate::database::repositories::{
+    integration::IntegrationSettingsRepository, participant::ParticipantInput,
+};
+
+pub const CONFIG_ID: &str = "zoom";
+pub const DEFAULT_REDIRECT_PORT: u16 = 47823;
+const AUTHORIZE_URL: &str = "https://zoom.us/oauth/authorize";
+const TOKEN_URL: &str = "https://zoom.us/oauth/token";
+const API_URL: &str = "https://api.zoom.us/v2";
+const REQUEST_TIMEOUT_SECS: u64 = 30;
+/// Zoom's largest page size for recordings and participants
+const PAGE_SIZE: u32 = 300;
+
+fn default_redirect_port() -> u16 {
+    DEFAULT_REDIRECT_PORT
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoomConfig {
+    pub client_id: String,
+    pub client_secret: String,
+    #[serde(default = "default_redirect_port")]
+    pub redirect_port: u16,
+    /// Present once the user has connected their Zoom account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tokens: Option<OAuthTokens>,
+}
+
+impl ZoomConfig {
+    /// Secret and tokens never reach the frontend
+    pub fn redacted(mut self) -> Self {
+        if !self.client_secret.is_empty() {
+            self.client_secret = REDACTED.to_string();
+        }
+        self.tokens = None;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZoomRecordingFile {
+    pub id: Option<String>,
+    /// "MP4", "M4A", "TRANSCRIPT", "CHAT", ...
+    #[serde(default)]
+    pub file_type: String,
+    #[serde(default)]
+    pub file_extension: Option<String>,
+    #[serde(default)]
+    pub file_size: Option<u64>,
+    #[serde(default)]
+    pub download_url: Option<String>,
+    /// "audio_only", "shared_screen_with_speaker_view", ...
+    #[serde(default)]
+    pub recording_type: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZoomRecording {
+    pub uuid: String,
+    pub id: Option<u64>,
+    #[serde(default)]
+    pub topic: String,
+    pub start_time: Option<DateTime<Utc>>,
+    /// Minutes
+    #[serde(default)]
+    pub duration: Option<u32>,
+    #[serde(default)]
+    pub recording_files: Vec<ZoomRecordingFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RecordingsPage {
+    #[serde(default)]
+    meetings: Vec<ZoomRecording>,
+    #[serde(default)]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ZoomParticipant {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    user_email: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ParticipantsPage {
+    #[serde(default)]
+    participants: Vec<ZoomParticipant>,
+    #[serde(default)]
+    next_page_token: Option<String>,
+}
+
+pub fn authorize_url(config: &ZoomConfig, state: &str) -> String {
+    let mut url = url::Url::parse(AUTHORIZE_URL).expect("valid Zoom authorize URL");
+    url.query_pairs_mut()
+        .append_pair("response_type", "code")
+        .append_pair("client_id", &config.client_id)
+        .append_pair(
+            "redirect_uri",
+            &super::oauth::redirect_uri(config.redirect_port),
+        )
+        .append_pair("state", state);
+    url.to_string()
+}
+
+async fn request_token(
+    client: &Client,
+    config: &ZoomConfig,
+    form: &[(&str, &str)],
+) -> Result<TokenResponse, String> {
+    let response = client
+        .post(TOKEN_URL)
+        .basic_auth(&config.client_id, Some(&config.client_secret))
+        .form(form)
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Zoom: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Zoom sign-in failed ({}): {}", status, body));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Zoom token response: {}", e))
+}
+
+pub async fn exchange_code(
+    client: &Client,
+    config: &ZoomConfig,
+    code: &str,
+) -> Result<OAuthTokens, String> {
+    let redirect_uri = super::oauth::redirect_uri(config.redirect_port);
+    let response = request_token(
+        client,
+        config,
+        &[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", &redirect_uri),
+        ],
+    )
+    .await?;
+    Ok(response.into_tokens(None))
+}
+
+pub async fn load_config(pool: &SqlitePool) -> Result<Option<ZoomConfig>, String> {
+    let raw = IntegrationSettingsRepository::get_config(pool, CONFIG_ID)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+    raw.map(|raw| {
+        serde_json::from_str(&raw)
+            .map_err(|e| format!("Stored Zoom configuration is invalid: {}", e))
+    })
+    .transpose()
+}
+
+pub async fn save_config(pool: &SqlitePool, config: &ZoomConfig) -> Result<(), String> {
+    let raw = serde_json::to_string(config)
+        .map_err(|e| format!("Failed to serialize Zoom configuration: {}", e))?;
+    IntegrationSettingsRepository::save_config(pool, CONFIG_ID, &raw)
+        .await
+        .map_err(|e| format!("Failed to save Zoom configuration: {}", e))
+}
+
+/// A valid access token, refreshed (and stored) when it is about to expire
+pub async fn access_token(client: &Client, pool: &SqlitePool) -> Result<String, String> {
+    let mut config = load_config(pool)
+        .await?
+        .ok_or_else(|| "Zoom is not configured".to_string())?;
+    let tokens = config
+        .tokens
+        .clone()
+        .ok_or_else(|| "Connect your Zoom account first".to_string())?;
+    if !tokens.needs_refresh(Utc::now()) {
+        return Ok(tokens.access_token);
+    }
+
+    let refresh_token = tokens
+        .refresh_token
+        .clone()
+        .ok_or_else(|| "Zoom session expired; connect your Zoom account again".to_string())?;
+    let refreshed = request_token(
+        client,
+        &config,
+        &[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", &refresh_token),
+        ],
+    )
+    .await
+    .map_err(|e| format!("{}; connect your Zoom account again", e))?
+    .into_tokens(Some(refresh_token));
+    let access = refreshed.access_token.clone();
+    config.tokens = Some(refreshed);
+    save_config(pool, &config).await?;
+    info!("Refreshed Zoom access token");
+    Ok(access)
+}
+
+async fn get_json<T: for<'de> Deserialize<'de>>(
+    client: &Client,
+    token: &str,
+    url: &str,
+    query: &[(&str, String)],
+) -> Result<T, String> {
+    let response = client
+        .get(url)
+        .bearer_auth(token)
+        .query(query)
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Zoom: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Zoom returned {}: {}", status, body));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Zoom response: {}", e))
+}
+
+/// Cloud recordings of the signed-in user that started between `from` and
+/// `to` (Zoom limits one query to a month)
+pub async fn list_recordings(
+    client: &Client,
+    token: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<ZoomRecording>, String> {
+    let mut recordings = Vec::new();
+    let mut next_page_token = String::new();
+    loop {
+        let page: RecordingsPage = get_json(
+            client,
+            token,
+            &format!("{}/users/me/recordings", API_URL),
+            &[
+                ("from", from.format("%Y-%m-%d").to_string()),
+                ("to", to.format("%Y-%m-%d").to_string()),
+                ("page_size", PAGE_SIZE.to_string()),
+                ("next_page_token", next_page_token.clone()),
+            ],
+        )
+        .await?;
+        recordings.extend(page.meetings);
+        match page.next_page_token.filter(|t| !t.is_empty()) {
+            Some(token) => next_page_token = token,
+            None => break,
+        }
+    }
+    Ok(recordings)
+}
+
+/// Meeting UUIDs that start with `/` or contain `//` must be encoded twice
+/// in API paths
+pub fn encode_meeting_uuid(uuid: &str) -> String {
+    let once = encode_component(uuid);
+    if uuid.starts_with('/') || uuid.contains("//") {
+        encode_component(&once)
+    } else {
+        once
+    }
+}
+
+fn encode_component(value: &str) -> String {
+    url::form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
+pub async fn get_recording(
+    client: &Client,
+    token: &str,
+    meeting_uuid: &str,
+) -> Result<ZoomRecording, String> {
+    get_json(
+        client,
+        token,
+        &format!(
+            "{}/meetings/{}/recordings",
+            API_URL,
+            encode_meeting_uuid(meeting_uuid)
+        ),
+        &[],
+    )
+    .await
+}
+
+/// The file to transcribe: audio-only when Zoom recorded it, otherwise the
+/// speaker-view video
+pub fn pick_media_file(files: &[ZoomRecordingFile]) -> Option<&ZoomRecordingFile> {
+    let usable = |f: &&ZoomRecordingFile| {
+        f.download_url.is_some() && f.status.as_deref().unwrap_or("completed") == "completed"
+    };
+    let rank = |f: &ZoomRecordingFile| match (f.file_type.as_str(), f.recording_type.as_deref()) {
+        ("M4A", _) => 0,
+        ("MP4", Some("active_speaker")) => 1,
+        ("MP4", Some("shared_screen_with_speaker_view")) => 2,
+        ("MP4", _) => 3,
+        _ => u8::MAX,
+    };
+    files
+        .iter()
+        .filter(usable)
+        .filter(|f| rank(f) != u8::MAX)
+        .min_by_key(|f| rank(f))
+}
+
+/// Streams a recording file to `destination`
+pub async fn download(
+    client: &Client,
+    token: &str,
+    file: &ZoomRecordingFile,
+    destination: &Path,
+) -> Result<u64, String> {
+    let url = file
+        .download_url
+        .as_deref()
+        .ok_or_else(|| "Recording has no download URL".to_string())?;
+    super::oauth::download(client, token, url, destination, "Zoom").await
+}
+
+/// People who joined the meeting, one entry per person (Zoom lists every
+/// rejoin separately)
+pub async fn participants(
+    client: &Client,
+    token: &str,
+    meeting_uuid: &str,
+) -> Result<Vec<ParticipantInput>, String> {
+    let mut people: Vec<ParticipantInput> = Vec::new();
+    let mut next_page_token = String::new();
+    loop {
+        let page: ParticipantsPage = get_json(
+            client,
+            token,
+            &format!(
+                "{}/past_meetings/{}/participants",
+                API_URL,
+                encode_meeting_uuid(meeting_uuid)
+            ),
+            &[
+                ("page_size", PAGE_SIZE.to_string()),
+                ("next_page_token", next_page_token.clone()),
+            ],
+        )
+        .await?;
+        for participant in page.participants {
+            let name = participant.name.trim().to_string();
+            let email = participant
+                .user_email
+                .map(|e| e.trim().to_lowercase())
+                .filter(|e| !e.is_empty());
+            if name.is_empty() {
+                continue;
+            }
+            let seen = people.iter().any(|p| match (&p.email, &email) {
+                (Some(a), Some(b)) => a == b,
+                _ => p.name.eq_ignore_ascii_case(&name),
+            });
+            if !seen {
+                people.push(ParticipantInput {
+                    name,
+                    email,
+                    role: None,
+                    speaker_label: None,
+                });
+            }
+        }
+        match page.next_page_token.filter(|t| !t.is_empty()) {
+            Some(token) => next_page_token = token,
+            None => break,
+        }
+    }
+    Ok(people)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(file_type: &str, recording_type: &str) -> ZoomRecordingFile {
+        ZoomRecordingFile {
+            id: None,
+            file_type: file_type.to_string(),
+            file_extension: None,
+            file_size: None,
+            download_url: Some(format!("https://zoom.us/rec/{}", recording_type)),
+            recording_type: Some(recording_type.to_string()),
+            status: Some("completed".to_string()),
+        }
+    }
+
+    #[test]
+    fn prefers_audio_only_files() {
+        let files = vec![
+            file("CHAT", "chat_file"),
+            file("MP4", "shared_screen_with_speaker_view"),
+            file("M4A", "audio_only"),
+        ];
+        assert_eq!(pick_media_file(&files).unwrap().file_type, "M4A");
+        assert_eq!(
+            pick_media_file(&files[..2])
+                .unwrap()
+                .recording_type
+                .as_deref(),
+            Some("shared_screen_with_speaker_view")
+        );
+        assert!(pick_media_file(&files[..1]).is_none());
+    }
+
+    #[test]
+    fn double_encodes_slashed_uuids() {
+        assert_eq!(
+            encode_meeting_uuid("aDYlohsHRtCd4ii1uC2+hA=="),
+            "aDYlohsHRtCd4ii1uC2%2BhA%3D%3D"
+        );
+        assert_eq!(
+            encode_meeting_uuid("/ajXp112QmuoKj4854875=="),
+            "%252FajXp112QmuoKj4854875%253D%253D"
+        );
+    }
+}
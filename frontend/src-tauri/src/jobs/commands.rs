@@ -0,0 +1,63 @@
+use log::info as log_info;
+use tauri::{AppHandle, Runtime};
+
+use super::Job;
+use crate::state::AppState;
+
+/// Jobs newest first. Queued, running and paused jobs are always listed;
+/// `include_finished` adds the recent history.
+#[tauri::command]
+pub async fn api_list_jobs<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    include_finished: Option<bool>,
+    limit: Option<i64>,
+) -> Result<Vec<Job>, String> {
+    state
+        .jobs
+        .list(
+            include_finished.unwrap_or(false),
+            limit.unwrap_or(100).clamp(1, 500),
+        )
+        .await
+}
+
+#[tauri::command]
+pub async fn api_get_job<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    job_id: String,
+) -> Result<Job, String> {
+    state.jobs.get(&job_id).await
+}
+
+/// Asks a job to stop; it reports `cancelled` once it has
+#[tauri::command]
+pub async fn api_cancel_job<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    job_id: String,
+) -> Result<Job, String> {
+    log_info!("api_cancel_job called for {}", job_id);
+    state.jobs.cancel(&job_id).await
+}
+
+#[tauri::command]
+pub async fn api_pause_job<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    job_id: String,
+) -> Result<Job, String> {
+    log_info!("api_pause_job called for {}", job_id);
+    state.jobs.pause(&job_id).await
+}
+
+#[tauri::command]
+pub async fn api_resume_job<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    job_id: String,
+) -> Result<Job, String> {
+    log_info!("api_resume_job called for {}", job_id);
+    state.jobs.resume(&job_id).await
+}
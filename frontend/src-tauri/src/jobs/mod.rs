@@ -0,0 +1,566 @@
+/// Jobs module - one place to track background work
+///
+/// This module contains:
+/// - `JobManager`, kept in `AppState`, which queues jobs and runs at most
+///   `MAX_RUNNING_JOBS` of them at a time
+/// - `JobHandle`, which a job uses to report progress, check for pause and
+///   cancellation, and finish
+/// - Tauri commands to list, cancel, pause and resume jobs
+///
+/// Jobs are stored in the `jobs` table, and every change is emitted as a
+/// `job-progress` event carrying the whole [`Job`], so the UI needs one
+/// listener for imports, re-transcriptions, exports and syncs alike.
+/// Pausing and cancelling are cooperative: a job stops at its next
+/// [`JobHandle::checkpoint`]. Jobs are also cancelled when the app shuts down.
+/// Imports and re-transcriptions that complete or fail are announced with a
+/// native notification while the app is in the background.
+pub mod commands;
+
+use chrono::{Duration as ChronoDuration, Utc};
+use log::warn;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Runtime};
+use tokio::sync::{watch, Semaphore};
+use tokio_util::sync::CancellationToken;
+
+use crate::database::repositories::job::JobsRepository;
+use crate::events;
+use crate::notifications::tasks::{notify_task_finished, TaskKind};
+pub use crate::database::repositories::job::{Job, JobStatus};
+
+pub const JOB_PROGRESS_EVENT: &str = "job-progress";
+/// Queued jobs that may run at the same time
+const MAX_RUNNING_JOBS: usize = 2;
+/// Progress is saved at most this often; every update is still emitted
+const SAVE_INTERVAL: Duration = Duration::from_secs(2);
+/// Finished jobs are kept this long
+const HISTORY_DAYS: i64 = 30;
+
+type Emit = Arc<dyn Fn(&Job) + Send + Sync>;
+
+struct Control {
+    job: Mutex<Job>,
+    cancel: CancellationToken,
+    paused: watch::Sender<bool>,
+    emit: Emit,
+    /// Called once with the finished job
+    announce: Emit,
+    last_saved: Mutex<Instant>,
+}
+
+impl Control {
+    fn snapshot(&self) -> Job {
+        self.job.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Applies `change`, emits the job and saves it. Progress-only updates
+    /// (`save == false`) are saved at most every `SAVE_INTERVAL`.
+    async fn update(&self, pool: &SqlitePool, save: bool, change: impl FnOnce(&mut Job)) -> Job {
+        let job = {
+            let mut job = self.job.lock().unwrap_or_else(|e| e.into_inner());
+            change(&mut job);
+            job.updated_at = Utc::now();
+            job.clone()
+        };
+        (self.emit)(&job);
+
+        let save = save || {
+            let mut last_saved = self.last_saved.lock().unwrap_or_else(|e| e.into_inner());
+            let due = last_saved.elapsed() >= SAVE_INTERVAL;
+            if due {
+                *last_saved = Instant::now();
+            }
+            due
+        };
+        if save {
+            if let Err(e) = JobsRepository::update(pool, &job).await {
+                warn!("Failed to save job {}: {}", job.id, e);
+            }
+        }
+        job
+    }
+}
+
+struct Inner {
+    pool: SqlitePool,
+    shutdown: CancellationToken,
+    slots: Arc<Semaphore>,
+    active: Mutex<HashMap<String, Arc<Control>>>,
+}
+
+/// Cheap to clone; all clones share state
+#[derive(Clone)]
+pub struct JobManager {
+    inner: Arc<Inner>,
+}
+
+impl JobManager {
+    /// `shutdown` cancels every job when the app quits
+    pub fn new(pool: SqlitePool, shutdown: CancellationToken) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                pool,
+                shutdown,
+                slots: Arc::new(Semaphore::new(MAX_RUNNING_JOBS)),
+                active: Mutex::new(HashMap::new()),
+            }),
+        }
+    }
+
+    fn control(&self, id: &str) -> Option<Arc<Control>> {
+        self.inner
+            .active
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(id)
+            .cloned()
+    }
+
+    async fn create<R: Runtime>(
+        &self,
+        app: &AppHandle<R>,
+        kind: &str,
+        title: String,
+        meeting_id: Option<String>,
+        status: JobStatus,
+    ) -> Result<JobHandle, String> {
+        let emit_app = app.clone();
+        let emit: Emit = Arc::new(move |job: &Job| {
+            let _ = events::emit(&emit_app, job);
+        });
+        let announce_app = app.clone();
+        let announce: Emit = Arc::new(move |job: &Job| {
+            let Some(kind) = TaskKind::from_job_kind(&job.kind) else {
+                return;
+            };
+            if job.status == JobStatus::Cancelled {
+                return;
+            }
+            let app = announce_app.clone();
+            let title = job.title.clone();
+            let error = job.error.clone();
+            tauri::async_runtime::spawn(async move {
+                notify_task_finished(&app, kind, &title, error.as_deref()).await;
+            });
+        });
+        self.create_with(kind, title, meeting_id, status, emit, announce)
+            .await
+    }
+
+    async fn create_with(
+        &self,
+        kind: &str,
+        title: String,
+        meeting_id: Option<String>,
+        status: JobStatus,
+        emit: Emit,
+        announce: Emit,
+    ) -> Result<JobHandle, String> {
+        let now = Utc::now();
+        let job = Job {
+            id: format!("job-{}", uuid::Uuid::new_v4()),
+            kind: kind.to_string(),
+            title,
+            meeting_id,
+            status,
+            progress: 0.0,
+            message: None,
+            error: None,
+            result: None,
+            created_at: now,
+            started_at: (status == JobStatus::Running).then_some(now),
+            finished_at: None,
+            updated_at: now,
+        };
+        JobsRepository::insert(&self.inner.pool, &job)
+            .await
+            .map_err(|e| format!("Failed to create the job: {}", e))?;
+
+        emit(&job);
+        let control = Arc::new(Control {
+            job: Mutex::new(job.clone()),
+            cancel: self.inner.shutdown.child_token(),
+            paused: watch::channel(false).0,
+            emit,
+            announce,
+            last_saved: Mutex::new(Instant::now()),
+        });
+        self.inner
+            .active
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(job.id, control.clone());
+        Ok(JobHandle {
+            manager: self.clone(),
+            control,
+        })
+    }
+
+    /// Tracks work the caller runs itself, such as a command that returns
+    /// its results directly. The job is running right away; the caller must
+    /// call [`JobHandle::finish`].
+    pub async fn begin<R: Runtime>(
+        &self,
+        app: &AppHandle<R>,
+        kind: &str,
+        title: impl Into<String>,
+        meeting_id: Option<String>,
+    ) -> Result<JobHandle, String> {
+        self.create(app, kind, title.into(), meeting_id, JobStatus::Running)
+            .await
+    }
+
+    /// Queues `work` to run in the background once a job slot is free and
+    /// returns the queued job. Its result is stored with the job.
+    pub async fn spawn<R, F, Fut>(
+        &self,
+        app: &AppHandle<R>,
+        kind: &str,
+        title: impl Into<String>,
+        meeting_id: Option<String>,
+        work: F,
+    ) -> Result<Job, String>
+    where
+        R: Runtime,
+        F: FnOnce(JobHandle) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<serde_json::Value, String>> + Send + 'static,
+    {
+        let handle = self
+            .create(app, kind, title.into(), meeting_id, JobStatus::Queued)
+            .await?;
+        let job = handle.snapshot();
+        let slots = self.inner.slots.clone();
+        tauri::async_runtime::spawn(async move {
+            let permit = tokio::select! {
+                permit = slots.acquire_owned() => permit.ok(),
+                _ = handle.control.cancel.cancelled() => None,
+            };
+            let Some(_permit) = permit else {
+                handle.finish(Err("Cancelled".to_string())).await;
+                return;
+            };
+            handle.start().await;
+            let result = work(handle.clone()).await;
+            handle.finish(result).await;
+        });
+        Ok(job)
+    }
+
+    /// Newest first; unfinished jobs show their latest (unsaved) progress
+    pub async fn list(&self, include_finished: bool, limit: i64) -> Result<Vec<Job>, String> {
+        let mut jobs = JobsRepository::list(&self.inner.pool, include_finished, limit)
+            .await
+            .map_err(|e| format!("Failed to load jobs: {}", e))?;
+        for job in &mut jobs {
+            if let Some(control) = self.control(&job.id) {
+                *job = control.snapshot();
+            }
+        }
+        Ok(jobs)
+    }
+
+    pub async fn get(&self, id: &str) -> Result<Job, String> {
+        if let Some(control) = self.control(id) {
+            return Ok(control.snapshot());
+        }
+        JobsRepository::get(&self.inner.pool, id)
+            .await
+            .map_err(|e| format!("Failed to load the job: {}", e))?
+            .ok_or_else(|| format!("Job not found: {}", id))
+    }
+
+    fn unfinished(&self, id: &str) -> Result<Arc<Control>, String> {
+        self.control(id)
+            .ok_or_else(|| format!("Job {} is not running", id))
+    }
+
+    /// Asks the job to stop; it finishes as cancelled at its next checkpoint
+    pub async fn cancel(&self, id: &str) -> Result<Job, String> {
+        let control = self.unfinished(id)?;
+        control.cancel.cancel();
+        Ok(control
+            .update(&self.inner.pool, false, |job| {
+                job.message = Some("Cancelling".to_string())
+            })
+            .await)
+    }
+
+    pub async fn pause(&self, id: &str) -> Result<Job, String> {
+        let control = self.unfinished(id)?;
+        if control.snapshot().status != JobStatus::Running {
+            return Err("Only running jobs can be paused".to_string());
+        }
+        control.paused.send_replace(true);
+        Ok(control
+            .update(&self.inner.pool, true, |job| job.status = JobStatus::Paused)
+            .await)
+    }
+
+    pub async fn resume(&self, id: &str) -> Result<Job, String> {
+        let control = self.unfinished(id)?;
+        if control.snapshot().status != JobStatus::Paused {
+            return Err("The job is not paused".to_string());
+        }
+        control.paused.send_replace(false);
+        Ok(control
+            .update(&self.inner.pool, true, |job| {
+                job.status = JobStatus::Running
+            })
+            .await)
+    }
+}
+
+/// A job's side of the manager; cheap to clone
+#[derive(Clone)]
+pub struct JobHandle {
+    manager: JobManager,
+    control: Arc<Control>,
+}
+
+impl JobHandle {
+    pub fn id(&self) -> String {
+        self.control.snapshot().id
+    }
+
+    pub fn snapshot(&self) -> Job {
+        self.control.snapshot()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.control.cancel.is_cancelled()
+    }
+
+    /// Cancelled when the job is cancelled or the app shuts down
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.control.cancel.clone()
+    }
+
+    /// Reports progress (0.0 to 1.0) and optionally what the job is doing
+    pub async fn progress(&self, fraction: f64, message: Option<String>) {
+        self.control
+            .update(&self.manager.inner.pool, false, |job| {
+                job.progress = fraction.clamp(0.0, 1.0);
+                if message.is_some() {
+                    job.message = message;
+                }
+            })
+            .await;
+    }
+
+    /// Waits while the job is paused. Fails once the job is cancelled, so
+    /// work can stop with `handle.checkpoint().await?`.
+    pub async fn checkpoint(&self) -> Result<(), String> {
+        let mut paused = self.control.paused.subscribe();
+        loop {
+            if self.is_cancelled() {
+                return Err("Cancelled".to_string());
+            }
+            if !*paused.borrow_and_update() {
+                return Ok(());
+            }
+            tokio::select! {
+                _ = paused.changed() => {}
+                _ = self.control.cancel.cancelled() => {}
+            }
+        }
+    }
+
+    async fn start(&self) {
+        self.control
+            .update(&self.manager.inner.pool, true, |job| {
+                job.status = JobStatus::Running;
+                job.started_at = Some(Utc::now());
+            })
+            .await;
+    }
+
+    /// Records the outcome. An error after cancellation counts as cancelled.
+    pub async fn finish(&self, result: Result<serde_json::Value, String>) {
+        let cancelled = self.is_cancelled();
+        let job = self
+            .control
+            .update(&self.manager.inner.pool, true, |job| {
+                job.finished_at = Some(Utc::now());
+                match result {
+                    Ok(value) => {
+                        job.status = JobStatus::Completed;
+                        job.progress = 1.0;
+                        job.result = Some(value);
+                    }
+                    Err(_) if cancelled => job.status = JobStatus::Cancelled,
+                    Err(e) => {
+                        job.status = JobStatus::Failed;
+                        job.error = Some(e);
+                    }
+                }
+            })
+            .await;
+        self.manager
+            .inner
+            .active
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&job.id);
+        (self.control.announce)(&job);
+    }
+}
+
+/// Fails jobs the previous run left unfinished and drops old history
+pub async fn recover_at_startup(pool: &SqlitePool) {
+    match JobsRepository::fail_interrupted(pool).await {
+        Ok(0) => {}
+        Ok(count) => log::info!("Marked {} interrupted jobs as failed", count),
+        Err(e) => warn!("Failed to clean up interrupted jobs: {}", e),
+    }
+    let cutoff = Utc::now() - ChronoDuration::days(HISTORY_DAYS);
+    if let Err(e) = JobsRepository::prune_finished(pool, cutoff).await {
+        warn!("Failed to prune the job history: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::test_support::memory_pool;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Recorder {
+        emitted: Arc<AtomicUsize>,
+        announced: Arc<Mutex<Vec<Job>>>,
+    }
+
+    /// Starts a running job like `begin`, counting what would be emitted
+    /// and announced
+    async fn begin(manager: &JobManager, kind: &str) -> (JobHandle, Recorder) {
+        let recorder = Recorder {
+            emitted: Arc::new(AtomicUsize::new(0)),
+            announced: Arc::new(Mutex::new(Vec::new())),
+        };
+        let emitted = recorder.emitted.clone();
+        let announced = recorder.announced.clone();
+        let handle = manager
+            .create_with(
+                kind,
+                "Import weekly sync".to_string(),
+                None,
+                JobStatus::Running,
+                Arc::new(move |_: &Job| {
+                    emitted.fetch_add(1, Ordering::SeqCst);
+                }),
+                Arc::new(move |job: &Job| announced.lock().unwrap().push(job.clone())),
+            )
+            .await
+            .unwrap();
+        (handle, recorder)
+    }
+
+    async fn stored(manager: &JobManager, id: &str) -> Job {
+        JobsRepository::get(&manager.inner.pool, id)
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn jobs_report_progress_and_finish() {
+        let manager = JobManager::new(memory_pool().await, CancellationToken::new());
+        let (job, recorder) = begin(&manager, "import").await;
+        let id = job.id();
+        assert_eq!(stored(&manager, &id).await.status, JobStatus::Running);
+
+        job.progress(1.5, Some("Transcribing".to_string())).await;
+        job.progress(0.25, None).await;
+        // Listed with the latest progress even before it is saved
+        let listed = manager.get(&id).await.unwrap();
+        assert_eq!(listed.progress, 0.25);
+        assert_eq!(listed.message.as_deref(), Some("Transcribing"));
+        assert_eq!(recorder.emitted.load(Ordering::SeqCst), 3);
+
+        job.finish(Ok(serde_json::json!({ "meeting_id": "m1" })))
+            .await;
+        let finished = stored(&manager, &id).await;
+        assert_eq!(finished.status, JobStatus::Completed);
+        assert_eq!(finished.progress, 1.0);
+        assert_eq!(
+            finished.result,
+            Some(serde_json::json!({ "meeting_id": "m1" }))
+        );
+        assert!(finished.finished_at.is_some());
+        assert_eq!(recorder.announced.lock().unwrap().len(), 1);
+        // Finished jobs can't be cancelled any more
+        assert!(manager.cancel(&id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn failures_are_recorded() {
+        let manager = JobManager::new(memory_pool().await, CancellationToken::new());
+        let (job, recorder) = begin(&manager, "retranscribe").await;
+        job.finish(Err("Decoding failed".to_string())).await;
+
+        let failed = stored(&manager, &job.id()).await;
+        assert_eq!(failed.status, JobStatus::Failed);
+        assert_eq!(failed.error.as_deref(), Some("Decoding failed"));
+        assert_eq!(
+            recorder.announced.lock().unwrap()[0].status,
+            JobStatus::Failed
+        );
+    }
+
+    #[tokio::test]
+    async fn cancelled_jobs_stop_at_their_next_checkpoint() {
+        let manager = JobManager::new(memory_pool().await, CancellationToken::new());
+        let (job, _) = begin(&manager, "import").await;
+        assert!(job.checkpoint().await.is_ok());
+
+        let cancelling = manager.cancel(&job.id()).await.unwrap();
+        assert_eq!(cancelling.message.as_deref(), Some("Cancelling"));
+        assert!(job.is_cancelled());
+        assert_eq!(job.checkpoint().await, Err("Cancelled".to_string()));
+
+        // The error the work stops with counts as a cancellation
+        job.finish(Err("Cancelled".to_string())).await;
+        assert_eq!(
+            stored(&manager, &job.id()).await.status,
+            JobStatus::Cancelled
+        );
+    }
+
+    #[tokio::test]
+    async fn paused_jobs_wait_until_resumed_or_cancelled() {
+        let manager = JobManager::new(memory_pool().await, CancellationToken::new());
+        let (job, _) = begin(&manager, "import").await;
+        let id = job.id();
+        assert!(manager.resume(&id).await.is_err());
+        assert_eq!(manager.pause(&id).await.unwrap().status, JobStatus::Paused);
+
+        let waiting = tokio::spawn({
+            let job = job.clone();
+            async move { job.checkpoint().await }
+        });
+        tokio::task::yield_now().await;
+        assert!(!waiting.is_finished());
+        manager.resume(&id).await.unwrap();
+        assert_eq!(waiting.await.unwrap(), Ok(()));
+
+        manager.pause(&id).await.unwrap();
+        let waiting = tokio::spawn({
+            let job = job.clone();
+            async move { job.checkpoint().await }
+        });
+        manager.cancel(&id).await.unwrap();
+        assert!(waiting.await.unwrap().is_err());
+    }
+
+    #[tokio::test]
+    async fn shutting_down_cancels_every_job() {
+        let shutdown = CancellationToken::new();
+        let manager = JobManager::new(memory_pool().await, shutdown.clone());
+        let (job, _) = begin(&manager, "export").await;
+        shutdown.cancel();
+        assert!(job.is_cancelled());
+    }
+}
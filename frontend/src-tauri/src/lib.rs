@@ -38,8 +38,22 @@ pub(crate) use perf_trace;
 pub mod analytics;
 pub mod api;
 pub mod audio;
+pub mod audit;
 pub mod console_utils;
 pub mod database;
+pub mod deep_link;
+pub mod diagnostics;
+pub mod encryption;
+pub mod error;
+pub mod events;
+pub mod export;
+pub mod hooks;
+pub mod integrations;
+pub mod jobs;
+pub mod license;
+pub mod locks;
+pub mod logging;
+pub mod mcp;
 pub mod notifications;
 pub mod ollama;
 pub mod onboarding;
@@ -47,11 +61,18 @@ pub mod openai;
 pub mod anthropic;
 pub mod groq;
 pub mod openrouter;
+pub mod overlay;
 pub mod parakeet_engine;
+pub mod plugins;
+pub mod redaction;
+pub mod server;
+pub mod shutdown;
 pub mod state;
 pub mod summary;
+pub mod telemetry;
 pub mod tray;
 pub mod utils;
+pub mod validation;
 pub mod whisper_engine;
 
 use audio::{list_audio_devices, AudioDevice, trigger_audio_permission};
@@ -397,12 +418,17 @@ pub fn get_language_preference_internal() -> Option<String> {
 }
 
 pub fn run() {
-    log::set_max_level(log::LevelFilter::Info);
-
     tauri::Builder::default()
+        // Must come first: a second launch hands its deep link to this
+        // instance, which receives it through on_open_url
+        .plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+            tray::focus_main_window(app);
+        }))
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_store::Builder::default().build())
         .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_clipboard_manager::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_process::init())
         .manage(whisper_engine::parallel_commands::ParallelProcessorState::new())
@@ -465,6 +491,15 @@ pub fn run() {
                 }
             });
 
+            // Load the transcription model ahead of the first recording if enabled
+            tauri::async_runtime::spawn(audio::transcription::preload_at_startup(
+                _app.handle().clone(),
+            ));
+            // Free the model's memory after the configured idle time
+            tauri::async_runtime::spawn(audio::transcription::idle::run_idle_unload(
+                _app.handle().clone(),
+            ));
+
             // Initialize ModelManager for summary engine (async, non-blocking)
             let app_handle_for_model_manager = _app.handle().clone();
             tauri::async_runtime::spawn(async move {
@@ -477,6 +512,9 @@ pub fn run() {
                 }
             });
 
+            // Restart the built-in AI sidecar if it crashes
+            summary::summary_engine::start_sidecar_supervisor(_app.handle());
+
             // Trigger system audio permission request on startup (similar to microphone permission)
             // #[cfg(target_os = "macos")]
             // {
@@ -493,6 +531,106 @@ pub fn run() {
             })
             .expect("Failed to initialize database");
 
+            // Apply the log filter saved in settings
+            if let Some(app_state) = _app.handle().try_state::<state::AppState>() {
+                let pool = app_state.db_manager.pool().clone();
+                tauri::async_runtime::block_on(logging::restore_filter(&pool));
+            }
+
+            // Start the local REST server if the user enabled it
+            if let Some(app_state) = _app.handle().try_state::<state::AppState>() {
+                let pool = app_state.db_manager.pool().clone();
+                tauri::async_runtime::spawn(async move {
+                    server::start_if_enabled(pool).await;
+                });
+            }
+
+            // Run scheduled database maintenance in the background
+            if let Some(app_state) = _app.handle().try_state::<state::AppState>() {
+                let pool = app_state.db_manager.pool().clone();
+                tauri::async_runtime::spawn(async move {
+                    database::maintenance::run_scheduler(pool).await;
+                });
+            }
+
+            // Generate scheduled meeting digests in the background
+            let handle = _app.handle().clone();
+            tauri::async_runtime::spawn(summary::digest::run_scheduler(handle));
+
+            // Remind of action items as their due dates come up
+            let handle = _app.handle().clone();
+            tauri::async_runtime::spawn(notifications::reminders::run_scheduler(handle));
+
+            // Import new episodes of subscribed feeds
+            let handle = _app.handle().clone();
+            tauri::async_runtime::spawn(integrations::feeds::run_scheduler(handle));
+
+            // Downloads of recording links abandoned long ago
+            tauri::async_runtime::spawn_blocking(|| {
+                let removed = audio::url_import::remove_stale_downloads(
+                    &std::env::temp_dir(),
+                    audio::url_import::STALE_DOWNLOAD_AGE,
+                );
+                if removed > 0 {
+                    log::info!("Removed {} abandoned link downloads", removed);
+                }
+            });
+
+            // Attachment files of meetings deleted in an earlier session
+            if let Some(app_state) = _app.handle().try_state::<state::AppState>() {
+                let pool = app_state.db_manager.pool().clone();
+                tauri::async_runtime::spawn(async move {
+                    let removed = api::attachments::remove_expired_files(&pool).await;
+                    if removed > 0 {
+                        log::info!("Removed {} attachment files of deleted meetings", removed);
+                    }
+                });
+            }
+
+            // Move meeting folders spooled while the recordings root was unreachable
+            let handle = _app.handle().clone();
+            tauri::async_runtime::spawn(audio::recordings_root::run_spool_sync(handle));
+
+            // Rewrite summaries saved by older versions in the current schema
+            if let Some(app_state) = _app.handle().try_state::<state::AppState>() {
+                let pool = app_state.db_manager.pool().clone();
+                tauri::async_runtime::spawn(summary::schema::upgrade_at_startup(pool));
+            }
+
+            // Check meetings against their recording folders
+            let handle = _app.handle().clone();
+            tauri::async_runtime::spawn(api::maintenance::scan_integrity_at_startup(handle));
+
+            // Jobs the previous run left unfinished can't resume
+            if let Some(app_state) = _app.handle().try_state::<state::AppState>() {
+                let pool = app_state.db_manager.pool().clone();
+                tauri::async_runtime::block_on(jobs::recover_at_startup(&pool));
+            }
+
+            // Send crash reports from earlier sessions if the user opted in
+            if let Some(app_state) = _app.handle().try_state::<state::AppState>() {
+                let pool = app_state.db_manager.pool().clone();
+                tauri::async_runtime::spawn(telemetry::submit_pending_if_enabled(pool));
+            }
+
+            // Open meetingminutes:// links; one the app was launched with
+            // waits for the main window to load
+            {
+                use tauri_plugin_deep_link::DeepLinkExt;
+                #[cfg(any(target_os = "linux", all(debug_assertions, windows)))]
+                if let Err(e) = _app.deep_link().register_all() {
+                    log::warn!("Failed to register the deep link scheme: {}", e);
+                }
+                if let Ok(Some(urls)) = _app.deep_link().get_current() {
+                    deep_link::defer_launch_urls(urls.iter().map(|u| u.to_string()).collect());
+                }
+                let handle = _app.handle().clone();
+                _app.deep_link().on_open_url(move |event| {
+                    let urls = event.urls().iter().map(|u| u.to_string()).collect();
+                    deep_link::handle_urls(&handle, urls);
+                });
+            }
+
             // Initialize bundled templates directory for dynamic template discovery
             log::info!("Initializing bundled templates directory...");
             if let Ok(resource_path) = _app.handle().path().resource_dir() {
@@ -505,6 +643,13 @@ pub fn run() {
 
             Ok(())
         })
+        .on_page_load(|webview, payload| {
+            if webview.label() == "main"
+                && matches!(payload.event(), tauri::webview::PageLoadEvent::Finished)
+            {
+                deep_link::open_pending(webview.app_handle());
+            }
+        })
         .invoke_handler(tauri::generate_handler![
             start_recording,
             stop_recording,
@@ -602,6 +747,26 @@ pub fn run() {
             audio::incremental_saver::recover_audio_from_checkpoints,
             audio::incremental_saver::cleanup_checkpoints,
             audio::incremental_saver::has_audio_checkpoints,
+            // Transcript file import (.vtt, .srt, .json)
+            audio::file_import::import_transcript_file,
+            audio::file_import::find_import_duplicates,
+            // Import file validation (drag-and-drop review)
+            audio::file_import::validate_audio_file_command,
+            audio::file_import::validate_audio_files_command,
+            audio::file_import::preview_import,
+            audio::url_import::api_import_from_url,
+            // Recording playback
+            audio::playback::start_playback,
+            audio::playback::pause_playback,
+            audio::playback::resume_playback,
+            audio::playback::seek_playback,
+            audio::playback::set_playback_rate,
+            audio::playback::stop_playback,
+            audio::playback::get_playback_status,
+            audio::waveform::get_meeting_waveform,
+            audio::compress::api_compress_meeting_audio,
+            audio::segment_rerun::api_retranscribe_segment,
+            audio::alignment::api_realign_segments,
             console_utils::show_console,
             console_utils::hide_console,
             console_utils::toggle_console,
@@ -617,6 +782,8 @@ pub fn run() {
             api::api_get_profile,
             api::api_save_profile,
             api::api_update_profile,
+            license::commands::api_get_offline_license_status,
+            license::commands::api_clear_offline_license,
             api::api_get_model_config,
             api::api_save_model_config,
             api::api_get_api_key,
@@ -624,8 +791,18 @@ pub fn run() {
             // api::api_save_auto_generate_setting,
             api::api_get_transcript_config,
             api::api_save_transcript_config,
+            api::api_get_transcript_language_routes,
+            api::api_save_transcript_language_routes,
+            api::api_get_summary_language,
+            api::api_save_summary_language,
             api::api_get_transcript_api_key,
             api::api_delete_meeting,
+            api::api_duplicate_meeting,
+            api::api_delete_transcript_segment,
+            api::api_merge_transcript_segments,
+            api::api_edit_transcript_segment,
+            api::api_get_last_operation,
+            api::api_undo_last_operation,
             api::api_get_meeting,
             api::api_get_meeting_metadata,
             api::api_get_meeting_transcripts,
@@ -639,11 +816,198 @@ pub fn run() {
             api::api_save_custom_openai_config,
             api::api_get_custom_openai_config,
             api::api_test_custom_openai_connection,
+            // Meeting attachment commands
+            api::attachments::api_add_attachment,
+            api::attachments::api_list_attachments,
+            api::attachments::api_open_attachment,
+            api::attachments::api_remove_attachment,
+            // Segment comment commands
+            api::comments::api_add_segment_comment,
+            api::comments::api_list_segment_comments,
+            api::comments::api_update_segment_comment,
+            api::comments::api_delete_segment_comment,
+            // Minutes approval commands
+            api::minutes::api_get_minutes_status,
+            api::minutes::api_set_minutes_status,
+            // Legal hold commands
+            api::legal_hold::api_place_legal_hold,
+            api::legal_hold::api_release_legal_hold,
+            api::legal_hold::api_get_legal_hold,
+            api::legal_hold::api_list_legal_holds,
+            // Team sharing commands
+            api::sharing::api_get_team_server,
+            api::sharing::api_set_team_server,
+            api::sharing::api_share_meeting,
+            api::sharing::api_list_meeting_shares,
+            api::sharing::api_revoke_meeting_share,
+            api::sharing::api_create_share_link,
+            api::sharing::api_list_share_links,
+            api::sharing::api_revoke_share_link,
+            api::sharing::api_sync_meeting_comments,
+            // Meeting participant commands
+            api::participants::api_list_participants,
+            api::participants::api_add_participant,
+            api::participants::api_update_participant,
+            api::participants::api_remove_participant,
+            api::participants::api_import_participants,
+            // Speaker naming and voice profile commands
+            api::speakers::api_list_meeting_speakers,
+            api::speakers::api_rename_speaker,
+            api::speakers::api_apply_voice_profiles,
+            api::speakers::api_list_voice_profiles,
+            api::speakers::api_delete_voice_profile,
+            // Full data export/import
+            export::commands::api_export_all_data,
+            export::commands::api_import_all_data,
+            export::commands::api_export_meetings_csv,
+            export::commands::api_export_audio_clip,
+            export::commands::api_export_action_items_csv,
+            export::commands::api_copy_meeting_to_clipboard,
+            export::commands::api_export_minutes_html,
+            export::commands::api_list_export_templates,
+            export::commands::api_export_with_template,
+            export::commands::api_export_flashcards,
+            // Action item commands
+            api::action_items::api_list_action_items,
+            api::action_items::api_set_action_item_status,
+            api::action_items::api_list_due_action_items,
+            api::action_items::api_snooze_action_item,
+            api::action_items::api_complete_action_item,
+            // Custom field commands
+            api::custom_fields::api_list_custom_fields,
+            api::custom_fields::api_create_custom_field,
+            api::custom_fields::api_update_custom_field,
+            api::custom_fields::api_delete_custom_field,
+            api::custom_fields::api_get_meeting_custom_fields,
+            api::custom_fields::api_set_meeting_custom_field,
+            // Meeting tag and structured query commands
+            api::tags::api_list_tags,
+            api::tags::api_get_meeting_tags,
+            api::tags::api_set_meeting_tags,
+            api::search::api_query_meetings,
+            api::search::api_search_transcripts_page,
+            // Meeting statistics commands
+            api::stats::api_get_meeting_stats,
+            // Recently viewed and pinned meeting commands
+            api::activity::api_record_meeting_view,
+            api::activity::api_get_recent_meetings,
+            api::activity::api_set_meeting_pinned,
+            api::activity::api_get_pinned_meetings,
+            // Keyword watchlist commands
+            api::watchlist::api_list_watch_keywords,
+            api::watchlist::api_add_watch_keyword,
+            api::watchlist::api_set_watch_keyword_enabled,
+            api::watchlist::api_delete_watch_keyword,
+            api::watchlist::api_list_keyword_hits,
+            // Learned correction commands
+            api::corrections::api_list_correction_rules,
+            api::corrections::api_delete_correction_rule,
+            // Saved search commands
+            api::search::api_list_saved_searches,
+            api::search::api_save_search,
+            api::search::api_delete_saved_search,
+            api::search::api_run_saved_search,
+            // Issue tracker integration commands
+            integrations::commands::api_save_tracker_config,
+            integrations::commands::api_get_tracker_config,
+            integrations::commands::api_remove_tracker_config,
+            integrations::commands::api_push_action_item_to_tracker,
+            // Email summary commands
+            integrations::commands::api_save_smtp_config,
+            integrations::commands::api_get_smtp_config,
+            integrations::commands::api_email_summary,
+            // Zoom recording import commands
+            integrations::commands::api_save_zoom_config,
+            integrations::commands::api_get_zoom_config,
+            integrations::commands::api_connect_zoom,
+            integrations::commands::api_disconnect_zoom,
+            integrations::commands::api_list_zoom_recordings,
+            integrations::commands::api_import_zoom_recordings,
+            // Teams recording import commands
+            integrations::commands::api_save_teams_config,
+            integrations::commands::api_get_teams_config,
+            integrations::commands::api_connect_teams,
+            integrations::commands::api_disconnect_teams,
+            integrations::commands::api_list_teams_recordings,
+            integrations::commands::api_import_teams_recordings,
+            integrations::commands::api_list_feeds,
+            integrations::commands::api_subscribe_feed,
+            integrations::commands::api_set_feed_enabled,
+            integrations::commands::api_unsubscribe_feed,
+            integrations::commands::api_list_feed_episodes,
+            integrations::commands::api_import_feed_episode,
+            integrations::commands::api_sync_feeds,
+            // Local REST server commands
+            server::commands::api_get_http_server_status,
+            server::commands::api_set_http_server_enabled,
+            server::commands::api_regenerate_http_server_token,
+            // Post-processing hook commands
+            hooks::commands::api_list_hooks,
+            hooks::commands::api_save_hooks,
+            hooks::commands::api_test_hook,
+            // WASM plugin commands
+            plugins::commands::api_list_plugins,
+            plugins::commands::api_enable_plugin,
+            plugins::commands::api_disable_plugin,
+            plugins::commands::api_export_with_plugin,
+            // Audit log commands
+            audit::commands::api_get_audit_log,
+            audit::commands::api_export_audit_log,
+            // Background job commands
+            jobs::commands::api_list_jobs,
+            locks::api_list_meeting_locks,
+            events::api_replay_recent_events,
+            overlay::api_open_overlay,
+            overlay::api_close_overlay,
+            overlay::api_is_overlay_open,
+            overlay::api_get_overlay_snapshot,
+            jobs::commands::api_get_job,
+            jobs::commands::api_cancel_job,
+            jobs::commands::api_pause_job,
+            jobs::commands::api_resume_job,
+            // Encryption at rest commands
+            encryption::commands::api_get_encryption_status,
+            encryption::commands::api_enable_encryption,
+            encryption::commands::api_unlock_encryption,
+            encryption::commands::api_lock_encryption,
+            encryption::commands::api_disable_encryption,
+            // Transcript redaction commands
+            redaction::commands::api_get_redaction_settings,
+            redaction::commands::api_save_redaction_settings,
+            redaction::commands::api_redact_text,
+            redaction::commands::api_unredact_meeting,
+            redaction::commands::api_discard_redaction_mappings,
+            // Database maintenance commands
+            api::maintenance::api_db_maintenance,
+            api::maintenance::api_get_maintenance_schedule,
+            api::maintenance::api_set_maintenance_schedule,
+            api::maintenance::api_get_schema_version,
+            api::maintenance::api_get_integrity_report,
+            api::maintenance::api_repair_integrity,
             // Summary commands
             summary::api_process_transcript,
             summary::api_get_summary,
             summary::api_save_meeting_summary,
             summary::api_cancel_summary,
+            summary::api_compare_meetings,
+            summary::api_get_delta_report,
+            summary::api_add_summary_feedback,
+            summary::api_list_summary_feedback,
+            summary::api_delete_summary_feedback,
+            summary::api_summarize_range,
+            summary::api_list_partial_summaries,
+            summary::api_delete_partial_summary,
+            summary::api_generate_digest,
+            summary::api_list_digests,
+            summary::api_delete_digest,
+            summary::api_get_digest_schedule,
+            summary::api_set_digest_schedule,
+            summary::api_analyze_meeting_sentiment,
+            summary::api_search_segment_sentiment,
+            summary::api_extract_meeting_entities,
+            summary::api_get_meeting_entities,
+            summary::api_list_entities,
+            summary::api_find_meetings_by_entity,
             // Template commands
             summary::api_list_templates,
             summary::api_get_template_details,
@@ -657,10 +1021,20 @@ pub fn run() {
             summary::summary_engine::builtin_ai_is_model_ready,
             summary::summary_engine::builtin_ai_get_available_summary_model,
             summary::summary_engine::builtin_ai_get_recommended_model,
+            summary::summary_engine::builtin_ai_sidecar_status,
+            summary::summary_engine::builtin_ai_sidecar_start,
+            summary::summary_engine::builtin_ai_sidecar_stop,
+            summary::summary_engine::builtin_ai_sidecar_restart,
+            summary::summary_engine::builtin_ai_switch_model,
             openrouter::get_openrouter_models,
             audio::recording_preferences::get_recording_preferences,
             audio::recording_preferences::set_recording_preferences,
             audio::recording_preferences::get_default_recordings_folder_path,
+            audio::recording_preferences::preview_folder_name,
+            audio::recordings_root::check_recordings_root,
+            audio::benchmark::api_benchmark_transcription,
+            audio::transcription::preload_transcription_model,
+            audio::recordings_root::sync_recording_spool,
             audio::recording_preferences::open_recordings_folder,
             audio::recording_preferences::select_recording_folder,
             audio::recording_preferences::get_available_audio_backends,
@@ -707,7 +1081,26 @@ pub fn run() {
             // Database and Models path commands
             database::commands::get_database_directory,
             database::commands::open_database_folder,
+            database::commands::get_data_locations,
+            database::commands::relocate_data_directory,
+            database::commands::list_workspaces,
+            database::commands::create_workspace,
+            database::commands::switch_workspace,
+            database::commands::set_workspace_recordings_dir,
             whisper_engine::commands::open_models_folder,
+            // Diagnostics commands
+            api::diagnostics::api_run_diagnostics,
+            logging::commands::api_export_logs,
+            logging::commands::api_get_log_filter,
+            logging::commands::api_set_log_filter,
+            // Telemetry commands
+            telemetry::commands::api_get_telemetry_settings,
+            telemetry::commands::api_save_telemetry_settings,
+            telemetry::commands::api_list_crash_reports,
+            telemetry::commands::api_submit_crash_reports,
+            // Shutdown commands
+            shutdown::api_prepare_for_shutdown,
+            shutdown::api_recover_from_failed_update,
             // Onboarding commands
             onboarding::get_onboarding_status,
             onboarding::save_onboarding_status_cmd,
@@ -719,29 +1112,32 @@ pub fn run() {
         ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
-        .run(|_app_handle, event| {
-            if let tauri::RunEvent::Exit = event {
+        .run(|_app_handle, event| match event {
+            // Finalize recordings and drain writes before the process exits
+            tauri::RunEvent::ExitRequested { api, .. } => {
+                shutdown::handle_exit_request(_app_handle, &api);
+            }
+            tauri::RunEvent::Exit => {
                 log::info!("Application exiting, cleaning up resources...");
                 tauri::async_runtime::block_on(async {
-                    // Clean up database connection and checkpoint WAL
+                    // Normally a no-op: the exit request already ran shutdown
                     if let Some(app_state) = _app_handle.try_state::<state::AppState>() {
-                        log::info!("Starting database cleanup...");
-                        if let Err(e) = app_state.db_manager.cleanup().await {
-                            log::error!("Failed to cleanup database: {}", e);
-                        } else {
-                            log::info!("Database cleanup completed successfully");
-                        }
+                        app_state
+                            .shutdown
+                            .shutdown(_app_handle, &app_state, shutdown::ShutdownReason::Exit)
+                            .await;
                     } else {
                         log::warn!("AppState not available for database cleanup (likely first launch)");
-                    }
 
-                    // Clean up sidecar
-                    log::info!("Cleaning up sidecar...");
-                    if let Err(e) = summary::summary_engine::force_shutdown_sidecar().await {
-                        log::error!("Failed to force shutdown sidecar: {}", e);
+                        // Clean up sidecar
+                        log::info!("Cleaning up sidecar...");
+                        if let Err(e) = summary::summary_engine::force_shutdown_sidecar().await {
+                            log::error!("Failed to force shutdown sidecar: {}", e);
+                        }
                     }
                 });
                 log::info!("Application cleanup complete");
             }
+            _ => {}
         });
 }
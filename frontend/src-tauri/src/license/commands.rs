@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use log::info as log_info;
+use serde::Serialize;
+use tauri::{AppHandle, Runtime};
+
+use super::{cached_status, clear_cache, LicenseError, OfflineStatus};
+use crate::state::AppState;
+
+#[derive(Debug, Serialize)]
+pub struct OfflineLicenseInfo {
+    pub cached: bool,
+    pub email: Option<String>,
+    pub issued_at: Option<DateTime<Utc>>,
+    /// None when there is no usable token
+    pub status: Option<OfflineStatus>,
+    /// Why the cached token can't be used, if it can't
+    pub error: Option<String>,
+}
+
+/// Reports the cached offline license, so the UI can warn before the grace
+/// period runs out
+#[tauri::command]
+pub async fn api_get_offline_license_status<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<OfflineLicenseInfo, String> {
+    let info = match cached_status(state.db_manager.pool()).await {
+        Ok((claims, status)) => OfflineLicenseInfo {
+            cached: true,
+            email: Some(claims.profile.email),
+            issued_at: Some(claims.issued_at),
+            status: Some(status),
+            error: None,
+        },
+        Err(LicenseError::NotCached) => OfflineLicenseInfo {
+            cached: false,
+            email: None,
+            issued_at: None,
+            status: None,
+            error: None,
+        },
+        Err(LicenseError::Database(e)) => {
+            return Err(format!("Failed to load offline license: {}", e))
+        }
+        Err(e) => OfflineLicenseInfo {
+            cached: true,
+            email: None,
+            issued_at: None,
+            status: None,
+            error: Some(e.to_string()),
+        },
+    };
+    Ok(info)
+}
+
+/// Forgets the cached offline license (e.g. when signing out)
+#[tauri::command]
+pub async fn api_clear_offline_license<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), String> {
+    log_info!("api_clear_offline_license called");
+    clear_cache(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to clear offline license: {}", e))
+}
@@ -0,0 +1,354 @@
+/// License module - offline validation of license keys
+///
+/// This module contains:
+/// - Signed offline license tokens issued by the profile server
+/// - Verification against the public key built into the app
+/// - The locally cached token and its expiry/grace window
+/// - Tauri commands for frontend integration
+///
+/// A token is `base64url(claims JSON).base64url(Ed25519 signature)`. When the
+/// server or the network is down, `api_get_profile` answers from the cached
+/// token until it expires plus the grace period. A license the server rejects
+/// outright (401/403) drops the cached token.
+pub mod commands;
+
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
+use ring::signature::{UnparsedPublicKey, ED25519};
+use serde::{Deserialize, Serialize};
+use sqlx::{Error as SqlxError, SqlitePool};
+
+use crate::api::Profile;
+use crate::database::repositories::integration::IntegrationSettingsRepository;
+
+pub const CONFIG_ID: &str = "license";
+
+/// Base64 Ed25519 public key of the license server, set at build time. Without
+/// it offline validation is off and every check needs the server.
+const PUBLIC_KEY: Option<&str> = option_env!("MEETILY_LICENSE_PUBLIC_KEY");
+
+const DEFAULT_GRACE_DAYS: u32 = 7;
+/// How far the clock may go back before the cache is distrusted
+const CLOCK_TOLERANCE_HOURS: i64 = 24;
+
+#[derive(Debug, thiserror::Error)]
+pub enum LicenseError {
+    #[error("Offline license validation is not available in this build")]
+    NoPublicKey,
+    #[error("No offline license is cached")]
+    NotCached,
+    #[error("Offline license token is malformed: {0}")]
+    Malformed(String),
+    #[error("Offline license token signature is invalid")]
+    BadSignature,
+    #[error("Offline license belongs to a different account or key")]
+    Mismatch,
+    #[error("Offline license expired on {0}; connect to the server to renew it")]
+    Expired(DateTime<Utc>),
+    #[error("System clock is earlier than the last license check; connect to the server")]
+    ClockRollback,
+    #[error("Database error: {0}")]
+    Database(#[from] SqlxError),
+}
+
+/// What the server signs: the profile it returned plus the validity window
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LicenseClaims {
+    pub profile: Profile,
+    pub issued_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    /// Days the token is still honoured after `expires_at`
+    #[serde(default = "default_grace_days")]
+    pub grace_days: u32,
+}
+
+fn default_grace_days() -> u32 {
+    DEFAULT_GRACE_DAYS
+}
+
+impl LicenseClaims {
+    pub fn grace_ends_at(&self) -> DateTime<Utc> {
+        self.expires_at + Duration::days(i64::from(self.grace_days))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum OfflineStatus {
+    /// Before expiry
+    Valid { expires_at: DateTime<Utc> },
+    /// Past expiry, still honoured
+    Grace { grace_ends_at: DateTime<Utc> },
+}
+
+fn decode_public_key(encoded: &str) -> Result<Vec<u8>, LicenseError> {
+    let key = STANDARD
+        .decode(encoded.trim())
+        .map_err(|_| LicenseError::NoPublicKey)?;
+    if key.len() != 32 {
+        return Err(LicenseError::NoPublicKey);
+    }
+    Ok(key)
+}
+
+/// Checks the signature and decodes the claims; does not look at the dates
+pub fn verify_token(token: &str, public_key: &[u8]) -> Result<LicenseClaims, LicenseError> {
+    let (payload, signature) = token
+        .trim()
+        .split_once('.')
+        .ok_or_else(|| LicenseError::Malformed("missing signature".to_string()))?;
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature)
+        .map_err(|e| LicenseError::Malformed(e.to_string()))?;
+    UnparsedPublicKey::new(&ED25519, public_key)
+        .verify(payload.as_bytes(), &signature)
+        .map_err(|_| LicenseError::BadSignature)?;
+    let claims = URL_SAFE_NO_PAD
+        .decode(payload)
+        .map_err(|e| LicenseError::Malformed(e.to_string()))?;
+    serde_json::from_slice(&claims).map_err(|e| LicenseError::Malformed(e.to_string()))
+}
+
+/// Where `now` falls in the token's window. `last_checked` is the latest time
+/// the license was validated, used to notice a clock turned back.
+pub fn evaluate(
+    claims: &LicenseClaims,
+    now: DateTime<Utc>,
+    last_checked: Option<DateTime<Utc>>,
+) -> Result<OfflineStatus, LicenseError> {
+    let tolerance = Duration::hours(CLOCK_TOLERANCE_HOURS);
+    if last_checked.is_some_and(|last| now + tolerance < last) || now + tolerance < claims.issued_at
+    {
+        return Err(LicenseError::ClockRollback);
+    }
+    if now < claims.expires_at {
+        Ok(OfflineStatus::Valid {
+            expires_at: claims.expires_at,
+        })
+    } else if now < claims.grace_ends_at() {
+        Ok(OfflineStatus::Grace {
+            grace_ends_at: claims.grace_ends_at(),
+        })
+    } else {
+        Err(LicenseError::Expired(claims.expires_at))
+    }
+}
+
+/// Stored under the "license" integration id
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedLicense {
+    token: String,
+    last_checked: DateTime<Utc>,
+}
+
+async fn load_cache(pool: &SqlitePool) -> Result<Option<CachedLicense>, LicenseError> {
+    let Some(raw) = IntegrationSettingsRepository::get_config(pool, CONFIG_ID).await? else {
+        return Ok(None);
+    };
+    match serde_json::from_str(&raw) {
+        Ok(cache) => Ok(Some(cache)),
+        Err(e) => {
+            log::warn!("Cached offline license is invalid, ignoring it: {}", e);
+            Ok(None)
+        }
+    }
+}
+
+async fn save_cache(pool: &SqlitePool, cache: &CachedLicense) -> Result<(), LicenseError> {
+    let raw = serde_json::to_string(cache)
+        .map_err(|e| LicenseError::Malformed(format!("failed to serialize: {}", e)))?;
+    IntegrationSettingsRepository::save_config(pool, CONFIG_ID, &raw).await?;
+    Ok(())
+}
+
+pub async fn clear_cache(pool: &SqlitePool) -> Result<(), LicenseError> {
+    IntegrationSettingsRepository::delete_config(pool, CONFIG_ID).await?;
+    Ok(())
+}
+
+fn public_key() -> Result<Vec<u8>, LicenseError> {
+    decode_public_key(PUBLIC_KEY.ok_or(LicenseError::NoPublicKey)?)
+}
+
+/// Verifies and caches a token the server returned with `profile`
+pub async fn store_token(
+    pool: &SqlitePool,
+    token: &str,
+    profile: &Profile,
+) -> Result<OfflineStatus, LicenseError> {
+    let claims = verify_token(token, &public_key()?)?;
+    if claims.profile.email != profile.email || claims.profile.license_key != profile.license_key {
+        return Err(LicenseError::Mismatch);
+    }
+    let now = Utc::now();
+    let status = evaluate(&claims, now, None)?;
+    save_cache(
+        pool,
+        &CachedLicense {
+            token: token.trim().to_string(),
+            last_checked: now,
+        },
+    )
+    .await?;
+    Ok(status)
+}
+
+/// The cached token's claims and status, without checking the account
+pub async fn cached_status(
+    pool: &SqlitePool,
+) -> Result<(LicenseClaims, OfflineStatus), LicenseError> {
+    let cache = load_cache(pool).await?.ok_or(LicenseError::NotCached)?;
+    let claims = verify_token(&cache.token, &public_key()?)?;
+    let status = evaluate(&claims, Utc::now(), Some(cache.last_checked))?;
+    Ok((claims, status))
+}
+
+/// Answers a profile request from the cached token when the server can't be
+/// reached
+pub async fn profile_offline(
+    pool: &SqlitePool,
+    email: &str,
+    license_key: &str,
+) -> Result<Profile, LicenseError> {
+    let cache = load_cache(pool).await?.ok_or(LicenseError::NotCached)?;
+    let claims = verify_token(&cache.token, &public_key()?)?;
+    if !claims.profile.email.eq_ignore_ascii_case(email.trim())
+        || claims.profile.license_key != license_key.trim()
+    {
+        return Err(LicenseError::Mismatch);
+    }
+    let now = Utc::now();
+    let status = evaluate(&claims, now, Some(cache.last_checked))?;
+    if let OfflineStatus::Grace { grace_ends_at } = &status {
+        log::warn!(
+            "Using offline license in its grace period (ends {})",
+            grace_ends_at
+        );
+    }
+    if now > cache.last_checked {
+        save_cache(
+            pool,
+            &CachedLicense {
+                token: cache.token,
+                last_checked: now,
+            },
+        )
+        .await?;
+    }
+    Ok(claims.profile)
+}
+
+/// Errors from `make_api_request` that mean the server couldn't answer, as
+/// opposed to answering no
+pub fn is_unreachable(error: &str) -> bool {
+    error.starts_with("Request failed") || error.starts_with("HTTP 5")
+}
+
+/// Errors that mean the server refused the license
+pub fn is_rejection(error: &str) -> bool {
+    error.starts_with("HTTP 401") || error.starts_with("HTTP 403")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    fn profile() -> Profile {
+        Profile {
+            id: "user-1".to_string(),
+            name: None,
+            email: "jane@example.com".to_string(),
+            license_key: "KEY-123".to_string(),
+            company: None,
+            position: None,
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            updated_at: "2026-01-01T00:00:00Z".to_string(),
+            is_licensed: true,
+            offline_token: None,
+        }
+    }
+
+    fn sign(key: &Ed25519KeyPair, claims: &LicenseClaims) -> String {
+        let payload = URL_SAFE_NO_PAD.encode(serde_json::to_vec(claims).unwrap());
+        let signature = key.sign(payload.as_bytes());
+        format!("{}.{}", payload, URL_SAFE_NO_PAD.encode(signature.as_ref()))
+    }
+
+    fn at(day: u32) -> DateTime<Utc> {
+        format!("2026-03-{:02}T00:00:00Z", day).parse().unwrap()
+    }
+
+    #[test]
+    fn verifies_signed_tokens_only() {
+        let key = Ed25519KeyPair::from_seed_unchecked(&[7; 32]).unwrap();
+        let other = Ed25519KeyPair::from_seed_unchecked(&[8; 32]).unwrap();
+        let claims = LicenseClaims {
+            profile: profile(),
+            issued_at: at(1),
+            expires_at: at(15),
+            grace_days: 7,
+        };
+        let token = sign(&key, &claims);
+        let public = key.public_key().as_ref();
+
+        let verified = verify_token(&token, public).unwrap();
+        assert_eq!(verified.profile.email, "jane@example.com");
+        assert!(matches!(
+            verify_token(&sign(&other, &claims), public),
+            Err(LicenseError::BadSignature)
+        ));
+
+        // Claims edited after signing
+        let (_, signature) = token.split_once('.').unwrap();
+        let mut forged = claims;
+        forged.expires_at = at(28);
+        let payload = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&forged).unwrap());
+        assert!(matches!(
+            verify_token(&format!("{}.{}", payload, signature), public),
+            Err(LicenseError::BadSignature)
+        ));
+        assert!(matches!(
+            verify_token("not-a-token", public),
+            Err(LicenseError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn honours_grace_window_and_detects_clock_rollback() {
+        let claims = LicenseClaims {
+            profile: profile(),
+            issued_at: at(1),
+            expires_at: at(10),
+            grace_days: 5,
+        };
+        assert_eq!(
+            evaluate(&claims, at(5), None).unwrap(),
+            OfflineStatus::Valid { expires_at: at(10) }
+        );
+        assert_eq!(
+            evaluate(&claims, at(12), Some(at(11))).unwrap(),
+            OfflineStatus::Grace {
+                grace_ends_at: at(15)
+            }
+        );
+        assert!(matches!(
+            evaluate(&claims, at(16), None),
+            Err(LicenseError::Expired(_))
+        ));
+        assert!(matches!(
+            evaluate(&claims, at(5), Some(at(12))),
+            Err(LicenseError::ClockRollback)
+        ));
+    }
+
+    #[test]
+    fn classifies_request_errors() {
+        assert!(is_unreachable("Request failed: error sending request"));
+        assert!(is_unreachable("HTTP 503 Service Unavailable: down"));
+        assert!(!is_unreachable("HTTP 403 Forbidden: revoked"));
+        assert!(is_rejection("HTTP 403 Forbidden: revoked"));
+        assert!(!is_rejection("HTTP 404 Not Found: missing"));
+    }
+}
@@ -0,0 +1,265 @@
+//! Locks on meetings with work in progress.
+//!
+//! A recording, an import, a segment re-transcription, an audio compression,
+//! a duplication or a spool folder move holds a lock on its meeting until it
+//! ends; before the meeting row exists (while recording or importing) the
+//! lock is on the recording folder instead. The repository refuses to delete
+//! or edit a locked meeting with [`MeetingBusy`], and maintenance leaves
+//! locked folders alone, so nothing is removed from under the task writing
+//! to it.
+//!
+//! Locks are shared, not exclusive: two re-transcriptions of the same
+//! meeting may run together. A lock is released when its guard is dropped,
+//! which also covers tasks that fail or are cancelled.
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use sqlx::Error as SqlxError;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Runtime};
+
+use crate::state::AppState;
+
+/// Prefix of busy errors, so callers can tell them from other failures
+pub const BUSY_CODE: &str = "RESOURCE_BUSY";
+
+/// The registry behind [`ensure_unlocked`]; `AppState` holds a handle to it
+static REGISTRY: Lazy<MeetingLocks> = Lazy::new(MeetingLocks::default);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeetingActivity {
+    Recording,
+    Import,
+    Retranscription,
+    AudioCompression,
+    Alignment,
+    FolderMove,
+    Duplication,
+}
+
+impl MeetingActivity {
+    fn describe(&self) -> &'static str {
+        match self {
+            Self::Recording => "is being recorded",
+            Self::Import => "is being imported",
+            Self::Retranscription => "is being re-transcribed",
+            Self::AudioCompression => "has its audio being compressed",
+            Self::Alignment => "is having its transcript timing realigned",
+            Self::FolderMove => "has its recording folder being moved",
+            Self::Duplication => "is being duplicated",
+        }
+    }
+}
+
+/// One held lock
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockInfo {
+    /// A meeting id, or the path of a recording folder
+    pub key: String,
+    pub activity: MeetingActivity,
+    pub since: DateTime<Utc>,
+}
+
+/// A meeting (or folder) can't be changed while a task holds it
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("{}: this meeting {} right now; try again when it has finished", BUSY_CODE, .activity.describe())]
+pub struct MeetingBusy {
+    pub key: String,
+    pub activity: MeetingActivity,
+}
+
+impl From<MeetingBusy> for SqlxError {
+    fn from(error: MeetingBusy) -> Self {
+        SqlxError::Protocol(error.to_string())
+    }
+}
+
+#[derive(Default)]
+struct Held {
+    next_id: u64,
+    locks: HashMap<u64, LockInfo>,
+}
+
+/// Cheap to clone; all clones share state
+#[derive(Clone, Default)]
+pub struct MeetingLocks {
+    held: Arc<Mutex<Held>>,
+}
+
+impl MeetingLocks {
+    /// The process-wide registry the repository checks
+    pub fn global() -> Self {
+        REGISTRY.clone()
+    }
+
+    fn held(&self) -> std::sync::MutexGuard<'_, Held> {
+        self.held.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Locks `key` for `activity` until the returned guard is dropped
+    pub fn acquire(&self, key: impl Into<String>, activity: MeetingActivity) -> MeetingLock {
+        self.insert(&mut self.held(), key.into(), activity)
+    }
+
+    /// Like [`acquire`](Self::acquire), but fails when another kind of task
+    /// already holds `key`
+    pub fn try_acquire(
+        &self,
+        key: impl Into<String>,
+        activity: MeetingActivity,
+    ) -> Result<MeetingLock, MeetingBusy> {
+        let key = key.into();
+        let mut held = self.held();
+        if let Some(info) = held
+            .locks
+            .values()
+            .find(|info| info.key == key && info.activity != activity)
+        {
+            return Err(MeetingBusy {
+                key: info.key.clone(),
+                activity: info.activity,
+            });
+        }
+        Ok(self.insert(&mut held, key, activity))
+    }
+
+    fn insert(&self, held: &mut Held, key: String, activity: MeetingActivity) -> MeetingLock {
+        held.next_id += 1;
+        let id = held.next_id;
+        held.locks.insert(
+            id,
+            LockInfo {
+                key,
+                activity,
+                since: Utc::now(),
+            },
+        );
+        MeetingLock {
+            locks: self.clone(),
+            id,
+        }
+    }
+
+    /// Fails when another task holds `key`. A task holding a lock passes
+    /// it as `holder`, so its own writes and those of tasks doing the same
+    /// work are let through.
+    pub fn check(&self, key: &str, holder: Option<&MeetingLock>) -> Result<(), MeetingBusy> {
+        let held = self.held();
+        let allowed = holder.and_then(|lock| held.locks.get(&lock.id).map(|info| info.activity));
+        match held
+            .locks
+            .values()
+            .find(|info| info.key == key && Some(info.activity) != allowed)
+        {
+            Some(info) => Err(MeetingBusy {
+                key: info.key.clone(),
+                activity: info.activity,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    pub fn is_locked(&self, key: &str) -> bool {
+        self.check(key, None).is_err()
+    }
+
+    /// Fails with the oldest held lock, for work that can't run alongside
+    /// any meeting task
+    pub fn ensure_idle(&self) -> Result<(), MeetingBusy> {
+        match self.list().into_iter().next() {
+            Some(info) => Err(MeetingBusy {
+                key: info.key,
+                activity: info.activity,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Held locks, oldest first
+    pub fn list(&self) -> Vec<LockInfo> {
+        let mut locks: Vec<LockInfo> = self.held().locks.values().cloned().collect();
+        locks.sort_by_key(|info| info.since);
+        locks
+    }
+}
+
+/// Releases its lock when dropped
+pub struct MeetingLock {
+    locks: MeetingLocks,
+    id: u64,
+}
+
+impl Drop for MeetingLock {
+    fn drop(&mut self) {
+        self.locks.held().locks.remove(&self.id);
+    }
+}
+
+/// The lock key of a recording folder; the same folder always gives the
+/// same key however its path was written
+pub fn folder_key(folder: &Path) -> String {
+    folder
+        .canonicalize()
+        .unwrap_or_else(|_| folder.to_path_buf())
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Fails when a task holds `key` (a meeting id or folder key)
+pub fn ensure_unlocked(key: &str) -> Result<(), MeetingBusy> {
+    REGISTRY.check(key, None)
+}
+
+/// The locks currently held, for showing why a meeting can't be edited
+#[tauri::command]
+pub async fn api_list_meeting_locks<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<LockInfo>, String> {
+    Ok(state.meeting_locks.list())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locks_block_other_work_until_dropped() {
+        let locks = MeetingLocks::default();
+        let rerun = locks.acquire("meeting-1", MeetingActivity::Retranscription);
+        let other = locks.acquire("meeting-1", MeetingActivity::Retranscription);
+
+        let busy = locks.check("meeting-1", None).unwrap_err();
+        assert_eq!(busy.activity, MeetingActivity::Retranscription);
+        assert!(busy.to_string().starts_with(BUSY_CODE));
+        assert!(locks.check("meeting-1", Some(&rerun)).is_ok());
+        assert!(locks.check("meeting-2", None).is_ok());
+
+        assert!(locks
+            .try_acquire("meeting-1", MeetingActivity::AudioCompression)
+            .is_err());
+        let compress = locks.acquire("meeting-1", MeetingActivity::AudioCompression);
+        assert!(locks.check("meeting-1", Some(&rerun)).is_err());
+        drop(compress);
+        drop(other);
+        drop(rerun);
+        assert!(!locks.is_locked("meeting-1"));
+        assert!(locks.list().is_empty());
+    }
+
+    #[test]
+    fn any_held_lock_keeps_the_registry_busy() {
+        let locks = MeetingLocks::default();
+        assert!(locks.ensure_idle().is_ok());
+        let import = locks.acquire("/recordings/Import_2026", MeetingActivity::Import);
+        let busy = locks.ensure_idle().unwrap_err();
+        assert_eq!(busy.activity, MeetingActivity::Import);
+        drop(import);
+        assert!(locks.ensure_idle().is_ok());
+    }
+}
@@ -0,0 +1,65 @@
+use log::{error as log_error, info as log_info};
+use std::path::PathBuf;
+use tauri::{AppHandle, Runtime};
+
+use super::export::{export_logs, LogExportReport, DEFAULT_EXPORT_DAYS};
+use super::filter::LogFilterConfig;
+use crate::state::AppState;
+
+/// Bundles the logs of the last `days` days (default 7) into a zip at `path`,
+/// with API keys, emails and transcript text redacted
+#[tauri::command]
+pub async fn api_export_logs<R: Runtime>(
+    app: AppHandle<R>,
+    path: String,
+    days: Option<u32>,
+) -> Result<LogExportReport, String> {
+    log_info!("api_export_logs called, path: {}", path);
+
+    let log_dir = super::log_dir().ok_or_else(|| "Log directory is unavailable".to_string())?;
+    let app_version = app.package_info().version.to_string();
+    let days = days.unwrap_or(DEFAULT_EXPORT_DAYS);
+    let dest = PathBuf::from(&path);
+
+    // Flush buffered output so the export includes the latest lines
+    log::logger().flush();
+    let report = tauri::async_runtime::spawn_blocking(move || {
+        export_logs(&log_dir, &dest, days, &app_version)
+    })
+    .await
+    .map_err(|e| format!("Log export task failed: {}", e))?
+    .map_err(|e| {
+        log_error!("Failed to export logs: {}", e);
+        e
+    })?;
+
+    log_info!(
+        "Exported {} log files ({} values redacted)",
+        report.files.len(),
+        report.redactions.total()
+    );
+    Ok(report)
+}
+
+/// The log level and per-module filters currently in effect
+#[tauri::command]
+pub async fn api_get_log_filter() -> Result<LogFilterConfig, String> {
+    Ok(super::current_filter())
+}
+
+/// Changes the log level and per-module filters (e.g. `whisper_engine` at
+/// `warn`) immediately and saves them for the next launch
+#[tauri::command]
+pub async fn api_set_log_filter(
+    state: tauri::State<'_, AppState>,
+    config: LogFilterConfig,
+) -> Result<LogFilterConfig, String> {
+    super::apply_filter(&config)?;
+    super::save_filter(state.db_manager.pool(), &config).await?;
+    log_info!(
+        "Log filter changed: level={}, {} module overrides",
+        config.level,
+        config.modules.len()
+    );
+    Ok(config)
+}
@@ -0,0 +1,126 @@
+//! Zip export of recent application logs for bug reports.
+//!
+//! Every line is passed through [`redact_line`] before it is written, so the
+//! archive never contains API keys, emails or transcript text. A
+//! `manifest.json` records what was included and how much was masked.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use zip::write::SimpleFileOptions;
+
+use super::redact::{redact_line, RedactionCounts};
+
+pub const DEFAULT_EXPORT_DAYS: u32 = 7;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedLogFile {
+    pub name: String,
+    pub lines: usize,
+    pub redactions: RedactionCounts,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogExportReport {
+    pub path: String,
+    pub app_version: String,
+    pub os: String,
+    pub files: Vec<ExportedLogFile>,
+    pub redactions: RedactionCounts,
+    pub since: DateTime<Utc>,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// Log files in `dir` modified at or after `since`, oldest first
+pub fn recent_log_files(dir: &Path, since: DateTime<Utc>) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<(std::time::SystemTime, PathBuf)> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "log"))
+        .filter_map(|p| {
+            let modified = std::fs::metadata(&p).and_then(|m| m.modified()).ok()?;
+            (DateTime::<Utc>::from(modified) >= since).then_some((modified, p))
+        })
+        .collect();
+    files.sort();
+    files.into_iter().map(|(_, p)| p).collect()
+}
+
+/// Writes the redacted logs from `log_dir` changed in the last `days` days to
+/// a zip at `dest`
+pub fn export_logs(
+    log_dir: &Path,
+    dest: &Path,
+    days: u32,
+    app_version: &str,
+) -> Result<LogExportReport, String> {
+    let since = Utc::now() - Duration::days(days.max(1) as i64);
+    let sources = recent_log_files(log_dir, since);
+    if sources.is_empty() {
+        return Err(format!(
+            "No log files from the last {} days in {}",
+            days,
+            log_dir.display()
+        ));
+    }
+
+    let file =
+        File::create(dest).map_err(|e| format!("Failed to create {}: {}", dest.display(), e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    let mut files = Vec::new();
+    let mut total = RedactionCounts::default();
+    for source in &sources {
+        let name = source
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let reader = File::open(source)
+            .map(BufReader::new)
+            .map_err(|e| format!("Failed to read {}: {}", source.display(), e))?;
+        zip.start_file(format!("logs/{}", name), options)
+            .map_err(|e| format!("Failed to write log archive: {}", e))?;
+
+        let mut entry = ExportedLogFile {
+            name,
+            lines: 0,
+            redactions: RedactionCounts::default(),
+        };
+        for line in reader.split(b'\n') {
+            let line = line.map_err(|e| format!("Failed to read {}: {}", source.display(), e))?;
+            // Lossy: a torn multi-byte sequence must not abort the export
+            let (redacted, counts) = redact_line(&String::from_utf8_lossy(&line));
+            zip.write_all(redacted.as_bytes())
+                .and_then(|_| zip.write_all(b"\n"))
+                .map_err(|e| format!("Failed to write log archive: {}", e))?;
+            entry.lines += 1;
+            entry.redactions.add(counts);
+        }
+        total.add(entry.redactions);
+        files.push(entry);
+    }
+
+    let report = LogExportReport {
+        path: dest.to_string_lossy().to_string(),
+        app_version: app_version.to_string(),
+        os: format!("{} {}", std::env::consts::OS, std::env::consts::ARCH),
+        files,
+        redactions: total,
+        since,
+        generated_at: Utc::now(),
+    };
+    let manifest = serde_json::to_vec_pretty(&report)
+        .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+    zip.start_file("manifest.json", options)
+        .and_then(|_| zip.write_all(&manifest).map_err(Into::into))
+        .map_err(|e| format!("Failed to write log archive: {}", e))?;
+    zip.finish()
+        .map_err(|e| format!("Failed to finish log archive: {}", e))?;
+    Ok(report)
+}
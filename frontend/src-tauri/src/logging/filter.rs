@@ -0,0 +1,173 @@
+//! Log level and per-module filters that can change while the app runs.
+//!
+//! Module names are matched against the record target with the crate prefix
+//! removed, so `whisper_engine` covers `app_lib::whisper_engine::*`. The most
+//! specific (longest) matching module wins; everything else uses the default
+//! level.
+
+use log::{Level, LevelFilter};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+const CRATE_PREFIX: &str = "app_lib::";
+
+/// Persisted form: levels are `off`, `error`, `warn`, `info`, `debug`, `trace`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogFilterConfig {
+    #[serde(default = "default_level")]
+    pub level: String,
+    /// Module path -> level
+    #[serde(default)]
+    pub modules: BTreeMap<String, String>,
+}
+
+fn default_level() -> String {
+    "info".to_string()
+}
+
+impl Default for LogFilterConfig {
+    fn default() -> Self {
+        Self {
+            level: default_level(),
+            modules: BTreeMap::new(),
+        }
+    }
+}
+
+impl LogFilterConfig {
+    /// Parses a RUST_LOG-style spec such as `info,whisper_engine=warn`
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((module, level)) => {
+                    config
+                        .modules
+                        .insert(module.trim().to_string(), level.trim().to_lowercase());
+                }
+                None if parse_level(directive).is_ok() => {
+                    config.level = directive.to_lowercase();
+                }
+                // A bare module name enables everything for it, as in RUST_LOG
+                None => {
+                    config
+                        .modules
+                        .insert(directive.to_string(), "trace".to_string());
+                }
+            }
+        }
+        LogFilter::from_config(&config)?;
+        Ok(config)
+    }
+}
+
+fn parse_level(level: &str) -> Result<LevelFilter, String> {
+    LevelFilter::from_str(level.trim()).map_err(|_| {
+        format!(
+            "Invalid log level '{}' (expected off, error, warn, info, debug or trace)",
+            level
+        )
+    })
+}
+
+fn normalize_module(module: &str) -> &str {
+    module.strip_prefix(CRATE_PREFIX).unwrap_or(module)
+}
+
+/// Compiled filter used on every log call
+#[derive(Debug, Clone)]
+pub struct LogFilter {
+    default: LevelFilter,
+    /// Longest module first, so the first match is the most specific
+    modules: Vec<(String, LevelFilter)>,
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        Self {
+            default: LevelFilter::Info,
+            modules: Vec::new(),
+        }
+    }
+}
+
+impl LogFilter {
+    pub fn from_config(config: &LogFilterConfig) -> Result<Self, String> {
+        let default = parse_level(&config.level)?;
+        let mut modules = Vec::with_capacity(config.modules.len());
+        for (module, level) in &config.modules {
+            let module = normalize_module(module.trim());
+            if module.is_empty() {
+                return Err("Module name must not be empty".to_string());
+            }
+            modules.push((module.to_string(), parse_level(level)?));
+        }
+        modules.sort_by_key(|(module, _)| std::cmp::Reverse(module.len()));
+        Ok(Self { default, modules })
+    }
+
+    pub fn level_for(&self, target: &str) -> LevelFilter {
+        let target = normalize_module(target);
+        self.modules
+            .iter()
+            .find(|(module, _)| {
+                target == module
+                    || (target.starts_with(module.as_str())
+                        && target[module.len()..].starts_with("::"))
+            })
+            .map(|(_, level)| *level)
+            .unwrap_or(self.default)
+    }
+
+    pub fn enabled(&self, target: &str, level: Level) -> bool {
+        level <= self.level_for(target)
+    }
+
+    /// The most verbose level any module allows, for `log::set_max_level`
+    pub fn max_level(&self) -> LevelFilter {
+        self.modules
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn most_specific_module_wins() {
+        let config = LogFilterConfig::from_spec(
+            "info,whisper_engine=warn,whisper_engine::parallel_processor=debug",
+        )
+        .unwrap();
+        let filter = LogFilter::from_config(&config).unwrap();
+
+        assert!(!filter.enabled("app_lib::whisper_engine::whisper_engine", Level::Info));
+        assert!(filter.enabled("app_lib::whisper_engine::whisper_engine", Level::Warn));
+        assert!(filter.enabled("app_lib::whisper_engine::parallel_processor", Level::Debug));
+        assert!(filter.enabled("app_lib::audio::pipeline", Level::Info));
+        assert!(!filter.enabled("app_lib::audio::pipeline", Level::Debug));
+        // Prefix of a different module name is not a match
+        assert_eq!(
+            filter.level_for("app_lib::whisper_engine_legacy"),
+            LevelFilter::Info
+        );
+        assert_eq!(filter.max_level(), LevelFilter::Debug);
+    }
+
+    #[test]
+    fn rejects_invalid_levels() {
+        // A bare word is a module name, as in RUST_LOG
+        assert!(LogFilterConfig::from_spec("loud").is_ok());
+        assert!(LogFilterConfig::from_spec("audio=loud").is_err());
+
+        let config = LogFilterConfig {
+            level: "verbose".to_string(),
+            modules: BTreeMap::new(),
+        };
+        assert!(LogFilter::from_config(&config).is_err());
+    }
+}
@@ -0,0 +1,246 @@
+/// Logging module - application log files and support log exports
+///
+/// This module contains:
+/// - A log file sink: everything logged to stderr is also appended to
+///   `<app data>/logs/meetily.log`, rotated by size
+/// - Redaction of API keys, tokens, emails and transcript text in every log
+///   record, and again in exported log lines
+/// - Zip export of recent logs (redacted) for attaching to bug reports
+/// - A log level and per-module filters that can be changed at runtime and
+///   are persisted in settings
+/// - Tauri commands for frontend integration
+
+pub mod commands;
+pub mod export;
+pub mod filter;
+pub mod redact;
+
+use log::Log;
+use once_cell::sync::Lazy;
+use sqlx::SqlitePool;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+
+use crate::database::repositories::integration::IntegrationSettingsRepository;
+
+pub use export::{export_logs, LogExportReport};
+pub use filter::{LogFilter, LogFilterConfig};
+pub use redact::{redact_line, RedactionCounts};
+
+pub const LOG_FILE_NAME: &str = "meetily.log";
+pub const LOG_SETTINGS_ID: &str = "logging";
+/// The active file is rotated once it grows past this
+const MAX_LOG_FILE_BYTES: u64 = 10 * 1024 * 1024;
+/// Rotated files kept next to the active one (meetily.1.log ... meetily.3.log)
+const ROTATED_FILES: usize = 3;
+
+/// Where the desktop app writes its log files
+pub fn log_dir() -> Option<PathBuf> {
+    crate::database::manager::default_app_data_dir().map(|dir| dir.join("logs"))
+}
+
+fn rotated_name(index: usize) -> String {
+    format!("meetily.{}.log", index)
+}
+
+/// Shifts meetily.log -> meetily.1.log -> ... dropping the oldest
+fn rotate(dir: &Path) {
+    let _ = std::fs::remove_file(dir.join(rotated_name(ROTATED_FILES)));
+    for index in (1..ROTATED_FILES).rev() {
+        let _ = std::fs::rename(
+            dir.join(rotated_name(index)),
+            dir.join(rotated_name(index + 1)),
+        );
+    }
+    let _ = std::fs::rename(dir.join(LOG_FILE_NAME), dir.join(rotated_name(1)));
+}
+
+struct LogFile {
+    dir: PathBuf,
+    file: File,
+    written: u64,
+}
+
+impl LogFile {
+    fn open(dir: &Path) -> std::io::Result<Self> {
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(LOG_FILE_NAME);
+        if std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0) >= MAX_LOG_FILE_BYTES {
+            rotate(dir);
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let written = file.metadata().map(|m| m.len()).unwrap_or(0);
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            file,
+            written,
+        })
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        if self.written >= MAX_LOG_FILE_BYTES {
+            rotate(&self.dir);
+            *self = Self::open(&self.dir.clone())?;
+        }
+        self.file.write_all(buf)?;
+        self.written += buf.len() as u64;
+        Ok(())
+    }
+}
+
+/// env_logger target that writes to stderr and, when available, the log file
+struct TeeWriter {
+    file: Option<Arc<Mutex<LogFile>>>,
+}
+
+impl Write for TeeWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let _ = std::io::stderr().write_all(buf);
+        crate::telemetry::push_breadcrumb(&String::from_utf8_lossy(buf));
+        if let Some(file) = &self.file {
+            if let Ok(mut file) = file.lock() {
+                // A full disk must not take logging (or the app) down with it
+                let _ = file.write_all(buf);
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        if let Some(file) = &self.file {
+            if let Ok(mut file) = file.lock() {
+                let _ = file.file.flush();
+            }
+        }
+        std::io::stderr().flush()
+    }
+}
+
+/// The active filter and the config it was built from
+static FILTER: Lazy<RwLock<(LogFilter, LogFilterConfig)>> =
+    Lazy::new(|| RwLock::new((LogFilter::default(), LogFilterConfig::default())));
+
+/// env_logger formats and writes; filtering happens here so it can change
+/// without rebuilding the logger, and messages are redacted before either
+struct DynamicLogger {
+    inner: env_logger::Logger,
+}
+
+impl Log for DynamicLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        FILTER
+            .read()
+            .map(|active| active.0.enabled(metadata.target(), metadata.level()))
+            .unwrap_or(true)
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        // Sanitize every message so no call site can leak keys, emails or
+        // transcript text into the log file
+        let message = record.args().to_string();
+        let (sanitized, counts) = redact_line(&message);
+        if counts.total() == 0 {
+            self.inner.log(record);
+            return;
+        }
+        self.inner.log(
+            &log::Record::builder()
+                .args(format_args!("{}", sanitized))
+                .metadata(record.metadata().clone())
+                .module_path(record.module_path())
+                .file(record.file())
+                .line(record.line())
+                .build(),
+        );
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+/// Switches to a new level/module filter immediately
+pub fn apply_filter(config: &LogFilterConfig) -> Result<(), String> {
+    let filter = LogFilter::from_config(config)?;
+    let max_level = filter.max_level();
+    *FILTER
+        .write()
+        .map_err(|_| "Log filter lock poisoned".to_string())? = (filter, config.clone());
+    log::set_max_level(max_level);
+    Ok(())
+}
+
+/// The filter currently in effect
+pub fn current_filter() -> LogFilterConfig {
+    FILTER
+        .read()
+        .map(|active| active.1.clone())
+        .unwrap_or_default()
+}
+
+pub async fn load_filter(pool: &SqlitePool) -> Result<Option<LogFilterConfig>, String> {
+    let raw = IntegrationSettingsRepository::get_config(pool, LOG_SETTINGS_ID)
+        .await
+        .map_err(|e| format!("Failed to load log settings: {}", e))?;
+    raw.map(|raw| {
+        serde_json::from_str(&raw).map_err(|e| format!("Stored log settings are invalid: {}", e))
+    })
+    .transpose()
+}
+
+pub async fn save_filter(pool: &SqlitePool, config: &LogFilterConfig) -> Result<(), String> {
+    let raw = serde_json::to_string(config)
+        .map_err(|e| format!("Failed to serialize log settings: {}", e))?;
+    IntegrationSettingsRepository::save_config(pool, LOG_SETTINGS_ID, &raw)
+        .await
+        .map_err(|e| format!("Failed to save log settings: {}", e))
+}
+
+/// Applies the filter saved in settings, if any. Until the database is open
+/// the RUST_LOG filter from startup is used.
+pub async fn restore_filter(pool: &SqlitePool) {
+    match load_filter(pool).await {
+        Ok(Some(config)) => match apply_filter(&config) {
+            Ok(()) => log::info!("Restored log filter: level={}", config.level),
+            Err(e) => log::warn!("Ignoring saved log filter: {}", e),
+        },
+        Ok(None) => {}
+        Err(e) => log::warn!("{}", e),
+    }
+}
+
+/// Installs the logger with output to stderr and the rotating log file,
+/// filtered by RUST_LOG until [`restore_filter`] runs. Falls back to stderr
+/// only if the file can't be opened.
+pub fn init() {
+    let file = log_dir().and_then(|dir| match LogFile::open(&dir) {
+        Ok(file) => Some(Arc::new(Mutex::new(file))),
+        Err(e) => {
+            eprintln!("Failed to open log file in {}: {}", dir.display(), e);
+            None
+        }
+    });
+    let inner = env_logger::Builder::new()
+        .filter_level(log::LevelFilter::Trace)
+        .format_timestamp_millis()
+        .target(env_logger::Target::Pipe(Box::new(TeeWriter { file })))
+        .build();
+
+    let config = match std::env::var("RUST_LOG") {
+        Ok(spec) => LogFilterConfig::from_spec(&spec).unwrap_or_else(|e| {
+            eprintln!("Ignoring RUST_LOG: {}", e);
+            LogFilterConfig::default()
+        }),
+        Err(_) => LogFilterConfig::default(),
+    };
+    if let Err(e) = log::set_boxed_logger(Box::new(DynamicLogger { inner })) {
+        eprintln!("Logger already initialized: {}", e);
+        return;
+    }
+    let _ = apply_filter(&config);
+}
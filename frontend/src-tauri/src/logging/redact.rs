@@ -0,0 +1,169 @@
+//! Redaction of secrets and personal data in log text.
+//!
+//! Applied to every log record before it is written, and again line by line
+//! when logs leave the machine (older files predate the sanitizer). Patterns
+//! are deliberately broad: a false positive costs a few characters of context,
+//! a miss leaks a key or a meeting's content.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+
+pub const REDACTED_SECRET: &str = "[REDACTED_SECRET]";
+pub const REDACTED_EMAIL: &str = "[REDACTED_EMAIL]";
+pub const REDACTED_TRANSCRIPT: &str = "[REDACTED_TEXT]";
+
+/// How many values of each kind were masked
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct RedactionCounts {
+    pub secrets: usize,
+    pub emails: usize,
+    pub transcript: usize,
+}
+
+impl RedactionCounts {
+    pub fn add(&mut self, other: RedactionCounts) {
+        self.secrets += other.secrets;
+        self.emails += other.emails;
+        self.transcript += other.transcript;
+    }
+
+    pub fn total(&self) -> usize {
+        self.secrets + self.emails + self.transcript
+    }
+}
+
+/// Provider key formats and bearer tokens that stand on their own
+static SECRET_TOKENS: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?x)
+        \bsk-[A-Za-z0-9_-]{16,}          # OpenAI, Anthropic (sk-ant-), OpenRouter (sk-or-)
+        | \bgsk_[A-Za-z0-9]{16,}         # Groq
+        | \bAIza[0-9A-Za-z_-]{30,}       # Google
+        | \bmm_[0-9a-f]{32,}             # local HTTP server token
+        | (?i:\bbearer\s+)[A-Za-z0-9._~+/=-]{8,}
+        ",
+    )
+    .expect("secret token pattern")
+});
+
+/// `api_key=...`, `"token": "..."`, `Authorization: ...` and friends
+static SECRET_FIELDS: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?i)((?:api[_-]?key|apikey|auth[_-]?token|access[_-]?token|token|secret|password|passphrase|authorization)["']?\s*[:=]\s*["']?)([^"'\s,;}]{4,})"#,
+    )
+    .expect("secret field pattern")
+});
+
+static EMAILS: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}")
+        .expect("email pattern")
+});
+
+/// Quoted values of text-carrying fields: `text='...'`, `"text": "..."`,
+/// `transcript: "..."`, `segment_text=...`. A value cut off by truncation
+/// runs to the end of the line.
+static TRANSCRIPT_FIELDS: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?i)(\b(?:text|transcript|segment_text|content|prompt)["']?\s*[:=]\s*)('(?:[^'\\]|\\.)*(?:'|$)|"(?:[^"\\]|\\.)*(?:"|$))"#,
+    )
+    .expect("transcript field pattern")
+});
+
+fn is_plain_value(value: &str) -> bool {
+    matches!(
+        value.to_ascii_lowercase().as_str(),
+        "true" | "false" | "none" | "null"
+    )
+}
+
+/// Masks secrets, emails and transcript text in one line of log output
+pub fn redact_line(line: &str) -> (String, RedactionCounts) {
+    let mut counts = RedactionCounts::default();
+
+    // Transcript first, so secrets/emails spoken in a meeting count as text
+    let line = TRANSCRIPT_FIELDS.replace_all(line, |caps: &regex::Captures| {
+        counts.transcript += 1;
+        let quote = &caps[2][..1];
+        format!("{}{}{}{}", &caps[1], quote, REDACTED_TRANSCRIPT, quote)
+    });
+    let line = SECRET_TOKENS.replace_all(&line, |_: &regex::Captures| {
+        counts.secrets += 1;
+        REDACTED_SECRET
+    });
+    let line = SECRET_FIELDS.replace_all(&line, |caps: &regex::Captures| {
+        if caps[2].starts_with('[') || is_plain_value(&caps[2]) {
+            // Already masked by an earlier pass, or a flag like `auth_token: true`
+            return caps[0].to_string();
+        }
+        counts.secrets += 1;
+        format!("{}{}", &caps[1], REDACTED_SECRET)
+    });
+    let line = EMAILS.replace_all(&line, |_: &regex::Captures| {
+        counts.emails += 1;
+        REDACTED_EMAIL
+    });
+    (line.into_owned(), counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_keys_and_tokens() {
+        let (line, counts) =
+            redact_line("Saving key sk-ant-REDACTED for provider claude");
+        assert_eq!(line, "Saving key [REDACTED_SECRET] for provider claude");
+        assert_eq!(counts.secrets, 1);
+
+        let (line, _) = redact_line(r#"headers: {"Authorization": "Bearer abc.def.ghi123"}"#);
+        assert!(!line.contains("abc.def.ghi123"));
+
+        let (line, _) = redact_line("config api_key=gsk_1234567890abcdefXYZ model=llama");
+        assert!(line.contains("api_key=[REDACTED_SECRET]"));
+        assert!(line.contains("model=llama"));
+    }
+
+    #[test]
+    fn masks_emails() {
+        let (line, counts) = redact_line("Profile loaded for jane.doe+work@example.co.jp");
+        assert_eq!(line, "Profile loaded for [REDACTED_EMAIL]");
+        assert_eq!(counts.emails, 1);
+    }
+
+    #[test]
+    fn masks_transcript_text_but_keeps_structure() {
+        let (line, counts) = redact_line(
+            "First parsed segment: text='we agreed on the budget', audio_start_time=Some(1.5)",
+        );
+        assert_eq!(
+            line,
+            "First parsed segment: text='[REDACTED_TEXT]', audio_start_time=Some(1.5)"
+        );
+        assert_eq!(counts.transcript, 1);
+
+        let (line, counts) = redact_line(r#"{"text": "call me at \"home\"", "sequence_id": 4}"#);
+        assert_eq!(line, r#"{"text": "[REDACTED_TEXT]", "sequence_id": 4}"#);
+        assert_eq!(counts.total(), 1);
+
+        // Response bodies are logged truncated, leaving the quote open
+        let (line, _) = redact_line(r#"Response body: {"segments":[{"text":"we agreed on the bud"#);
+        assert_eq!(
+            line,
+            r#"Response body: {"segments":[{"text":"[REDACTED_TEXT]""#
+        );
+    }
+
+    #[test]
+    fn leaves_ordinary_lines_alone() {
+        let line = "api_get_profile called, auth_token: true";
+        assert_eq!(redact_line(line).0, line);
+
+        let line = "Worker 2 started; chunks_queued=14 model=large-v3-turbo";
+        assert_eq!(
+            redact_line(line),
+            (line.to_string(), RedactionCounts::default())
+        );
+    }
+}
@@ -4,11 +4,13 @@
 )]
 
 use log;
-use env_logger;
 
 fn main() {
     std::env::set_var("RUST_LOG", "info");
-    env_logger::init();
+    // stderr plus the rotating log file used by log export
+    app_lib::logging::init();
+    // Local crash reports; submission is opt-in (telemetry settings)
+    app_lib::telemetry::install_panic_hook(env!("CARGO_PKG_VERSION"));
 
     // Async logger will be initialized lazily when first needed (after Tauri runtime starts)
     log::info!("Starting application...");
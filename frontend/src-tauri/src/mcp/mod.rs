@@ -0,0 +1,81 @@
+/// MCP server - exposes meetings to AI assistants over the Model Context Protocol
+///
+/// This module contains:
+/// - JSON-RPC 2.0 framing for the stdio transport (see `protocol`)
+/// - Read-only tools for listing, searching and reading meetings (see `tools`)
+///
+/// The server is started by the `meetily-mcp` binary, which assistants such as
+/// Claude Desktop launch as a subprocess. It opens the app database read-only,
+/// so it can run while the desktop app is recording.
+///
+/// stdout carries protocol messages only; all logging goes to stderr.
+
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+pub mod protocol;
+pub mod tools;
+
+use protocol::{error_response, success_response, INVALID_PARAMS, METHOD_NOT_FOUND};
+
+/// Handles one incoming message. Returns `None` for notifications.
+pub async fn handle_message(pool: &SqlitePool, line: &str) -> Option<Value> {
+    let request = match protocol::parse_request(line) {
+        Ok(request) => request,
+        Err(response) => return Some(response),
+    };
+    // Notifications (notifications/initialized, notifications/cancelled) need no reply
+    let id = request.id?;
+
+    let response = match request.method.as_str() {
+        "initialize" => success_response(id, protocol::initialize_result(env!("CARGO_PKG_VERSION"))),
+        "ping" => success_response(id, json!({})),
+        "tools/list" => success_response(id, json!({ "tools": tools::definitions() })),
+        "tools/call" => {
+            let Some(name) = request.params.get("name").and_then(Value::as_str) else {
+                return Some(error_response(id, INVALID_PARAMS, "Missing tool name"));
+            };
+            if !tools::TOOL_NAMES.contains(&name) {
+                return Some(error_response(id, INVALID_PARAMS, &format!("Unknown tool '{}'", name)));
+            }
+            let args = request
+                .params
+                .get("arguments")
+                .cloned()
+                .unwrap_or_else(|| json!({}));
+
+            let result = match tools::call(pool, name, &args).await {
+                Ok(text) => tools::text_result(text, false),
+                Err(message) => {
+                    log::warn!("MCP tool {} failed: {}", name, message);
+                    tools::text_result(message, true)
+                }
+            };
+            success_response(id, result)
+        }
+        method => error_response(id, METHOD_NOT_FOUND, &format!("Method not found: {}", method)),
+    };
+    Some(response)
+}
+
+/// Serves MCP over stdin/stdout until stdin is closed
+pub async fn serve_stdio(pool: SqlitePool) -> std::io::Result<()> {
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    let mut stdout = tokio::io::stdout();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = handle_message(&pool, &line).await {
+            let mut payload = response.to_string();
+            payload.push('\n');
+            stdout.write_all(payload.as_bytes()).await?;
+            stdout.flush().await?;
+        }
+    }
+
+    log::info!("MCP client disconnected, shutting down");
+    Ok(())
+}
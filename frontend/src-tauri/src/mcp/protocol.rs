@@ -0,0 +1,111 @@
+//! JSON-RPC 2.0 framing for the MCP stdio transport.
+//!
+//! Messages are newline-delimited JSON objects. Requests carry an `id` and get
+//! exactly one response; notifications have no `id` and never get a response.
+
+use serde_json::{json, Value};
+
+/// Latest MCP revision this server implements
+pub const PROTOCOL_VERSION: &str = "2024-11-05";
+
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const INTERNAL_ERROR: i64 = -32603;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    /// `None` for notifications
+    pub id: Option<Value>,
+    pub method: String,
+    pub params: Value,
+}
+
+/// Parses one line from the transport. On failure returns the error response
+/// to send back (with a null id, since the request id could not be read).
+pub fn parse_request(line: &str) -> Result<Request, Value> {
+    let message: Value = serde_json::from_str(line)
+        .map_err(|e| error_response(Value::Null, PARSE_ERROR, &format!("Parse error: {}", e)))?;
+
+    let id = message.get("id").cloned();
+    let invalid = |reason: &str| {
+        error_response(
+            id.clone().unwrap_or(Value::Null),
+            INVALID_REQUEST,
+            &format!("Invalid request: {}", reason),
+        )
+    };
+
+    if message.get("jsonrpc").and_then(Value::as_str) != Some("2.0") {
+        return Err(invalid("jsonrpc must be \"2.0\""));
+    }
+    let method = message
+        .get("method")
+        .and_then(Value::as_str)
+        .ok_or_else(|| invalid("missing method"))?
+        .to_string();
+
+    Ok(Request {
+        id,
+        method,
+        params: message.get("params").cloned().unwrap_or_else(|| json!({})),
+    })
+}
+
+pub fn success_response(id: Value, result: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+pub fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message }
+    })
+}
+
+/// Result for `initialize`. We only speak one protocol revision, so that is
+/// what we answer with regardless of what the client asked for.
+pub fn initialize_result(server_version: &str) -> Value {
+    json!({
+        "protocolVersion": PROTOCOL_VERSION,
+        "capabilities": { "tools": {} },
+        "serverInfo": { "name": "meetily", "version": server_version },
+        "instructions": "Read-only access to locally recorded Meetily meetings. \
+            Use search_meetings or list_meetings to find a meeting id, then \
+            get_transcript, get_summary or get_action_items for details."
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_requests_and_notifications() {
+        let request =
+            parse_request(r#"{"jsonrpc":"2.0","id":7,"method":"tools/list"}"#).unwrap();
+        assert_eq!(request.id, Some(json!(7)));
+        assert_eq!(request.method, "tools/list");
+        assert_eq!(request.params, json!({}));
+
+        let notification =
+            parse_request(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#).unwrap();
+        assert_eq!(notification.id, None);
+    }
+
+    #[test]
+    fn malformed_messages_produce_error_responses() {
+        let err = parse_request("{not json").unwrap_err();
+        assert_eq!(err["error"]["code"], json!(PARSE_ERROR));
+        assert_eq!(err["id"], Value::Null);
+
+        let err = parse_request(r#"{"jsonrpc":"1.0","id":"a","method":"ping"}"#).unwrap_err();
+        assert_eq!(err["error"]["code"], json!(INVALID_REQUEST));
+        assert_eq!(err["id"], json!("a"));
+
+        let err = parse_request(r#"{"jsonrpc":"2.0","id":1}"#).unwrap_err();
+        assert_eq!(err["error"]["code"], json!(INVALID_REQUEST));
+    }
+}
@@ -0,0 +1,332 @@
+//! MCP tools exposed to assistants. All tools are read-only.
+
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+
+use crate::database::models::Transcript;
+use crate::database::repositories::{
+    action_item::ActionItemsRepository, meeting::MeetingsRepository,
+    summary::SummaryProcessesRepository, transcript::TranscriptsRepository,
+};
+
+const DEFAULT_LIST_LIMIT: usize = 20;
+const MAX_LIST_LIMIT: usize = 200;
+/// Transcript segments returned per call; assistants page with `offset`
+const DEFAULT_TRANSCRIPT_PAGE: i64 = 500;
+const MAX_TRANSCRIPT_PAGE: i64 = 2000;
+
+pub const TOOL_NAMES: [&str; 5] = [
+    "list_meetings",
+    "search_meetings",
+    "get_transcript",
+    "get_summary",
+    "get_action_items",
+];
+
+/// Tool definitions for `tools/list`
+pub fn definitions() -> Value {
+    let meeting_id = json!({ "type": "string", "description": "Meeting id from list_meetings or search_meetings" });
+    json!([
+        {
+            "name": "list_meetings",
+            "description": "List recorded meetings, most recent first.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "limit": { "type": "integer", "description": "Maximum meetings to return (default 20)" }
+                }
+            }
+        },
+        {
+            "name": "search_meetings",
+            "description": "Full-text search across meeting transcripts. Returns matching meetings with a snippet of context.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string", "description": "Text to search for" },
+                    "limit": { "type": "integer", "description": "Maximum results to return (default 20)" }
+                },
+                "required": ["query"]
+            }
+        },
+        {
+            "name": "get_transcript",
+            "description": "Get the transcript of a meeting as timestamped lines. Long transcripts are paged; use offset to continue.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "meeting_id": meeting_id,
+                    "offset": { "type": "integer", "description": "Segment offset to start from (default 0)" },
+                    "limit": { "type": "integer", "description": "Maximum segments to return (default 500)" }
+                },
+                "required": ["meeting_id"]
+            }
+        },
+        {
+            "name": "get_summary",
+            "description": "Get the generated summary of a meeting as markdown.",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "meeting_id": meeting_id },
+                "required": ["meeting_id"]
+            }
+        },
+        {
+            "name": "get_action_items",
+            "description": "Get the action items of a meeting with owner, due date and status.",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "meeting_id": meeting_id },
+                "required": ["meeting_id"]
+            }
+        }
+    ])
+}
+
+/// `tools/call` result content
+pub fn text_result(text: impl Into<String>, is_error: bool) -> Value {
+    json!({
+        "content": [{ "type": "text", "text": text.into() }],
+        "isError": is_error,
+    })
+}
+
+fn required_str<'a>(args: &'a Value, name: &str) -> Result<&'a str, String> {
+    args.get(name)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| format!("Missing required argument '{}'", name))
+}
+
+fn optional_int(args: &Value, name: &str) -> Option<i64> {
+    args.get(name).and_then(Value::as_i64)
+}
+
+/// Runs a tool. `Err` is a tool execution error, reported to the assistant
+/// with `isError: true` rather than as a protocol error.
+pub async fn call(pool: &SqlitePool, name: &str, args: &Value) -> Result<String, String> {
+    match name {
+        "list_meetings" => list_meetings(pool, args).await,
+        "search_meetings" => search_meetings(pool, args).await,
+        "get_transcript" => get_transcript(pool, args).await,
+        "get_summary" => get_summary(pool, args).await,
+        "get_action_items" => get_action_items(pool, args).await,
+        _ => Err(format!("Unknown tool '{}'", name)),
+    }
+}
+
+fn list_limit(args: &Value) -> usize {
+    optional_int(args, "limit")
+        .map(|l| l.clamp(1, MAX_LIST_LIMIT as i64) as usize)
+        .unwrap_or(DEFAULT_LIST_LIMIT)
+}
+
+async fn list_meetings(pool: &SqlitePool, args: &Value) -> Result<String, String> {
+    let meetings = MeetingsRepository::get_meetings(pool)
+        .await
+        .map_err(|e| format!("Failed to list meetings: {}", e))?;
+    if meetings.is_empty() {
+        return Ok("No meetings recorded yet.".to_string());
+    }
+    let lines: Vec<String> = meetings
+        .iter()
+        .take(list_limit(args))
+        .map(|m| format!("- {} (id: {}, {})", m.title, m.id, m.created_at.0.format("%Y-%m-%d %H:%M UTC")))
+        .collect();
+    Ok(format!("{} meetings total.\n{}", meetings.len(), lines.join("\n")))
+}
+
+async fn search_meetings(pool: &SqlitePool, args: &Value) -> Result<String, String> {
+    let query = required_str(args, "query")?;
+    let results = TranscriptsRepository::search_transcripts(pool, query)
+        .await
+        .map_err(|e| format!("Search failed: {}", e))?;
+    if results.is_empty() {
+        return Ok(format!("No transcripts match '{}'.", query));
+    }
+    let lines: Vec<String> = results
+        .iter()
+        .take(list_limit(args))
+        .map(|r| format!("- {} (id: {}, {})\n  {}", r.title, r.id, r.timestamp, r.match_context))
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+async fn meeting_title(pool: &SqlitePool, meeting_id: &str) -> Result<String, String> {
+    MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .map(|m| m.title)
+        .ok_or_else(|| format!("Meeting '{}' not found", meeting_id))
+}
+
+async fn get_transcript(pool: &SqlitePool, args: &Value) -> Result<String, String> {
+    let meeting_id = required_str(args, "meeting_id")?;
+    let title = meeting_title(pool, meeting_id).await?;
+    let offset = optional_int(args, "offset").unwrap_or(0).max(0);
+    let limit = optional_int(args, "limit")
+        .unwrap_or(DEFAULT_TRANSCRIPT_PAGE)
+        .clamp(1, MAX_TRANSCRIPT_PAGE);
+
+    let (transcripts, total) =
+        MeetingsRepository::get_meeting_transcripts_paginated(pool, meeting_id, limit, offset)
+            .await
+            .map_err(|e| format!("Failed to load transcript: {}", e))?;
+
+    let mut text = format!("# {}\n", title);
+    if total == 0 {
+        text.push_str("\nThis meeting has no transcript.");
+        return Ok(text);
+    }
+    text.push('\n');
+    text.push_str(&format_transcript(&transcripts));
+
+    let end = offset + transcripts.len() as i64;
+    if end < total {
+        text.push_str(&format!(
+            "\n\n[Showing segments {}-{} of {}. Call again with offset {} for more.]",
+            offset + 1,
+            end,
+            total,
+            end
+        ));
+    }
+    Ok(text)
+}
+
+async fn get_summary(pool: &SqlitePool, args: &Value) -> Result<String, String> {
+    let meeting_id = required_str(args, "meeting_id")?;
+    let title = meeting_title(pool, meeting_id).await?;
+    let process = SummaryProcessesRepository::get_summary_data(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load summary: {}", e))?;
+
+    let markdown = process
+        .as_ref()
+        .and_then(|p| p.result.as_deref())
+        .and_then(summary_markdown);
+    match (markdown, process) {
+        (Some(markdown), _) => Ok(format!("# {}\n\n{}", title, markdown)),
+        (None, Some(p)) => Ok(format!("No summary available for '{}' (status: {}).", title, p.status)),
+        (None, None) => Ok(format!("No summary has been generated for '{}'.", title)),
+    }
+}
+
+async fn get_action_items(pool: &SqlitePool, args: &Value) -> Result<String, String> {
+    let meeting_id = required_str(args, "meeting_id")?;
+    let title = meeting_title(pool, meeting_id).await?;
+    let items = ActionItemsRepository::list_for_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load action items: {}", e))?;
+    if items.is_empty() {
+        return Ok(format!("No action items for '{}'.", title));
+    }
+    let lines: Vec<String> = items
+        .iter()
+        .map(|item| {
+            let mut line = format!(
+                "- [{}] {}",
+                if item.status == "done" { "x" } else { " " },
+                item.text
+            );
+            if let Some(owner) = &item.owner {
+                line.push_str(&format!(" (owner: {})", owner));
+            }
+            if let Some(due) = &item.due_date {
+                line.push_str(&format!(" (due: {})", due));
+            }
+            line
+        })
+        .collect();
+    Ok(format!("# {}\n\n{}", title, lines.join("\n")))
+}
+
+/// Extracts the markdown from a stored summary result (`{"markdown": ...}`)
+pub fn summary_markdown(result: &str) -> Option<String> {
+    serde_json::from_str::<Value>(result)
+        .ok()?
+        .get("markdown")?
+        .as_str()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+/// Renders segments as `[mm:ss] text`, falling back to the wall-clock
+/// timestamp for segments recorded before audio timing was stored
+pub fn format_transcript(transcripts: &[Transcript]) -> String {
+    transcripts
+        .iter()
+        .map(|t| {
+            let stamp = match t.audio_start_time {
+                Some(seconds) => {
+                    let total = seconds.max(0.0) as u64;
+                    format!("{:02}:{:02}", total / 60, total % 60)
+                }
+                None => t.timestamp.clone(),
+            };
+            format!("[{}] {}", stamp, t.transcript.trim())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(text: &str, start: Option<f64>) -> Transcript {
+        Transcript {
+            id: "t".into(),
+            meeting_id: "m".into(),
+            transcript: text.into(),
+            timestamp: "2026-01-01T10:00:00Z".into(),
+            summary: None,
+            action_items: None,
+            key_points: None,
+            audio_start_time: start,
+            audio_end_time: None,
+            duration: None,
+            speaker: None,
+            uncertain: false,
+        }
+    }
+
+    #[test]
+    fn every_tool_has_a_definition() {
+        let defs = definitions();
+        let names: Vec<&str> = defs
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|d| d["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, TOOL_NAMES);
+    }
+
+    #[test]
+    fn transcript_formatting() {
+        let text = format_transcript(&[segment(" Hello ", Some(75.4)), segment("Legacy", None)]);
+        assert_eq!(text, "[01:15] Hello\n[2026-01-01T10:00:00Z] Legacy");
+    }
+
+    #[test]
+    fn summary_markdown_extraction() {
+        assert_eq!(
+            summary_markdown(r##"{"markdown":"# Notes"}"##).as_deref(),
+            Some("# Notes")
+        );
+        assert_eq!(summary_markdown(r#"{"markdown":"  "}"#), None);
+        assert_eq!(summary_markdown("not json"), None);
+    }
+
+    #[test]
+    fn argument_validation() {
+        let args = json!({ "meeting_id": "  ", "limit": 5 });
+        assert!(required_str(&args, "meeting_id").is_err());
+        assert!(required_str(&args, "query").is_err());
+        assert_eq!(list_limit(&args), 5);
+        assert_eq!(list_limit(&json!({ "limit": 100000 })), MAX_LIST_LIMIT);
+    }
+}
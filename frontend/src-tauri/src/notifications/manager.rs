@@ -1,7 +1,8 @@
 use crate::notifications::{
     types::{Notification, NotificationType},
-    settings::{NotificationSettings, ConsentManager},
+    settings::{NotificationSettings, NotificationPreferences, ConsentManager},
     system::SystemNotificationHandler,
+    tasks::TaskKind,
 };
 use anyhow::Result;
 use log::{info as log_info, error as log_error, warn as log_warn};
@@ -172,6 +173,28 @@ impl<R: Runtime> NotificationManager<R> {
         self.show_notification(notification).await
     }
 
+    /// Show a reminder for action items that are due
+    pub async fn show_action_items_due(&self, count: usize, body: String) -> Result<()> {
+        let settings = self.settings.read().await;
+        if !settings.notification_preferences.show_action_item_reminders {
+            return Ok(());
+        }
+
+        let notification = Notification::action_items_due(count, body);
+        self.show_notification(notification).await
+    }
+
+    /// Show that a long-running task finished
+    pub async fn show_task_finished(&self, kind: TaskKind, title: String, body: String) -> Result<()> {
+        let settings = self.settings.read().await;
+        if !task_enabled(&settings.notification_preferences, kind) {
+            return Ok(());
+        }
+
+        let notification = Notification::task_finished(kind, title, body);
+        self.show_notification(notification).await
+    }
+
     /// Show a system error notification
     pub async fn show_system_error(&self, error: String) -> Result<()> {
         let settings = self.settings.read().await;
@@ -299,6 +322,8 @@ impl<R: Runtime> NotificationManager<R> {
             NotificationType::RecordingResumed => settings.notification_preferences.show_recording_resumed,
             NotificationType::TranscriptionComplete => settings.notification_preferences.show_transcription_complete,
             NotificationType::MeetingReminder(_) => settings.notification_preferences.show_meeting_reminders,
+            NotificationType::ActionItemsDue(_) => settings.notification_preferences.show_action_item_reminders,
+            NotificationType::TaskFinished(kind) => task_enabled(&settings.notification_preferences, *kind),
             NotificationType::SystemError(_) => settings.notification_preferences.show_system_errors,
             NotificationType::Test => true, // Always show test notifications
         }
@@ -329,6 +354,14 @@ impl<R: Runtime> NotificationManager<R> {
     }
 }
 
+fn task_enabled(preferences: &NotificationPreferences, kind: TaskKind) -> bool {
+    match kind {
+        TaskKind::Import => preferences.show_import_complete,
+        TaskKind::Retranscription => preferences.show_retranscription_complete,
+        TaskKind::Summary => preferences.show_summary_complete,
+    }
+}
+
 /// Notification system statistics
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct NotificationStats {
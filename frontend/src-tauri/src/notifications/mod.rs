@@ -4,6 +4,8 @@ pub mod system;
 pub mod settings;
 pub mod commands;
 pub mod manager;
+pub mod reminders;
+pub mod tasks;
 
 // Re-export main types for easy access
 pub use types::{
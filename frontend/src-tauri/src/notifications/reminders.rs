@@ -0,0 +1,276 @@
+//! Due-date reminders for action items.
+//!
+//! A scheduler looks at the open action items with a due date every few
+//! minutes. Items due tomorrow, today or overdue are reminded of once a day
+//! with an OS notification and a `reminders-due` event, unless snoozed. Only
+//! calendar dates count as due dates: "Friday" or "next week" were relative
+//! to the meeting, not to today.
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use log::{info, warn};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use crate::database::models::ActionItem;
+use crate::database::repositories::{
+    action_item::ActionItemsRepository, meeting::MeetingsRepository,
+};
+use crate::notifications::commands::NotificationManagerState;
+use crate::state::AppState;
+
+/// Event emitted with the reminders that became due
+pub const REMINDERS_EVENT: &str = "reminders-due";
+/// Items are reminded of this many days before they are due
+const LEAD_DAYS: i64 = 1;
+/// How often the scheduler checks for due items
+const CHECK_INTERVAL_SECS: u64 = 15 * 60;
+/// Longest snooze accepted, in minutes
+pub const MAX_SNOOZE_MINUTES: u32 = 30 * 24 * 60;
+
+/// An action item that is due, with where it came from
+#[derive(Debug, Clone, Serialize)]
+pub struct DueReminder {
+    pub item: ActionItem,
+    pub meeting_title: String,
+    pub due: NaiveDate,
+    /// Days until the due date; negative when overdue
+    pub days_left: i64,
+}
+
+/// The due date written on an action item, when it is a calendar date
+pub fn parse_due_date(due: &str) -> Option<NaiveDate> {
+    let due = due.trim();
+    ["%Y-%m-%d", "%Y/%m/%d", "%Y年%m月%d日"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(due, format).ok())
+}
+
+/// The due date of an item that needs a reminder on `today` at `now`: open,
+/// due by tomorrow, not reminded of today and not snoozed
+pub fn needs_reminder(
+    item: &ActionItem,
+    today: NaiveDate,
+    now: DateTime<Utc>,
+) -> Option<NaiveDate> {
+    if item.status != "open" {
+        return None;
+    }
+    if item
+        .snoozed_until
+        .as_ref()
+        .is_some_and(|until| until.0 > now)
+    {
+        return None;
+    }
+    let today_text = today.format("%Y-%m-%d").to_string();
+    if item.reminded_on.as_deref() == Some(today_text.as_str()) {
+        return None;
+    }
+    let due = parse_due_date(item.due_date.as_deref()?)?;
+    ((due - today).num_days() <= LEAD_DAYS).then_some(due)
+}
+
+fn due_phrase(days_left: i64) -> String {
+    match days_left {
+        1 => "is due tomorrow".to_string(),
+        0 => "is due today".to_string(),
+        -1 => "was due yesterday".to_string(),
+        d if d < 0 => format!("is {} days overdue", -d),
+        d => format!("is due in {} days", d),
+    }
+}
+
+/// Notification text for the reminders, most urgent first
+pub fn reminder_body(reminders: &[DueReminder]) -> String {
+    match reminders {
+        [] => String::new(),
+        [only] => format!(
+            "\"{}\" {} ({})",
+            only.item.text.trim(),
+            due_phrase(only.days_left),
+            only.meeting_title
+        ),
+        [first, rest @ ..] => format!(
+            "{} action items are due. \"{}\" {}, and {} more.",
+            reminders.len(),
+            first.item.text.trim(),
+            due_phrase(first.days_left),
+            rest.len()
+        ),
+    }
+}
+
+/// Open items with a due date, as reminders. With `check_reminded`, only the
+/// items `needs_reminder` picks; otherwise every item due by tomorrow that
+/// isn't snoozed.
+pub async fn due_reminders(
+    pool: &SqlitePool,
+    today: NaiveDate,
+    now: DateTime<Utc>,
+    check_reminded: bool,
+) -> Result<Vec<DueReminder>, String> {
+    let items = ActionItemsRepository::list_open_with_due_date(pool)
+        .await
+        .map_err(|e| format!("Failed to load action items: {}", e))?;
+    let mut titles: HashMap<String, String> = HashMap::new();
+    let mut reminders = Vec::new();
+    for mut item in items {
+        if !check_reminded {
+            item.reminded_on = None;
+        }
+        let Some(due) = needs_reminder(&item, today, now) else {
+            continue;
+        };
+        if !titles.contains_key(&item.meeting_id) {
+            let title = MeetingsRepository::get_meeting_metadata(pool, &item.meeting_id)
+                .await
+                .ok()
+                .flatten()
+                .map(|m| m.title)
+                .unwrap_or_default();
+            titles.insert(item.meeting_id.clone(), title);
+        }
+        reminders.push(DueReminder {
+            meeting_title: titles[&item.meeting_id].clone(),
+            days_left: (due - today).num_days(),
+            due,
+            item,
+        });
+    }
+    reminders.sort_by_key(|r| r.due);
+    Ok(reminders)
+}
+
+async fn notify<R: Runtime>(app: &AppHandle<R>, reminders: &[DueReminder]) {
+    let Some(manager_state) = app.try_state::<NotificationManagerState<R>>() else {
+        return;
+    };
+    let manager = manager_state.read().await;
+    match manager.as_ref() {
+        Some(manager) => {
+            if let Err(e) = manager
+                .show_action_items_due(reminders.len(), reminder_body(reminders))
+                .await
+            {
+                warn!("Failed to show action item reminder: {}", e);
+            }
+        }
+        None => info!("Notification manager not ready; reminder shown in the app only"),
+    }
+}
+
+/// Checks for due action items while the app runs
+pub async fn run_scheduler<R: Runtime>(app: AppHandle<R>) {
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(CHECK_INTERVAL_SECS));
+    loop {
+        interval.tick().await;
+        let Some(state) = app.try_state::<AppState>() else {
+            continue;
+        };
+        let pool = state.db_manager.pool();
+        let today = Local::now().date_naive();
+        let reminders = match due_reminders(pool, today, Utc::now(), true).await {
+            Ok(reminders) => reminders,
+            Err(e) => {
+                warn!("Skipping action item reminders: {}", e);
+                continue;
+            }
+        };
+        if reminders.is_empty() {
+            continue;
+        }
+
+        info!("{} action items are due", reminders.len());
+        if let Err(e) = app.emit(REMINDERS_EVENT, &reminders) {
+            warn!("Failed to emit {}: {}", REMINDERS_EVENT, e);
+        }
+        notify(&app, &reminders).await;
+
+        let ids: Vec<String> = reminders.iter().map(|r| r.item.id.clone()).collect();
+        let today_text = today.format("%Y-%m-%d").to_string();
+        if let Err(e) = ActionItemsRepository::mark_reminded(pool, &ids, &today_text).await {
+            warn!("Failed to record action item reminders: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::DateTimeUtc;
+    use chrono::Duration;
+
+    fn item(due: Option<&str>) -> ActionItem {
+        ActionItem {
+            id: "action-1".into(),
+            meeting_id: "meeting-1".into(),
+            text: "Send the budget".into(),
+            owner: None,
+            due_date: due.map(|d| d.into()),
+            status: "open".into(),
+            source: "summary".into(),
+            created_at: DateTimeUtc(Utc::now()),
+            updated_at: DateTimeUtc(Utc::now()),
+            external_tracker: None,
+            external_key: None,
+            external_url: None,
+            reminded_on: None,
+            snoozed_until: None,
+        }
+    }
+
+    #[test]
+    fn only_calendar_dates_are_due_dates() {
+        let date = NaiveDate::from_ymd_opt(2026, 4, 14);
+        assert_eq!(parse_due_date(" 2026-04-14 "), date);
+        assert_eq!(parse_due_date("2026/04/14"), date);
+        assert_eq!(parse_due_date("2026年4月14日"), date);
+        assert_eq!(parse_due_date("Friday"), None);
+        assert_eq!(parse_due_date("来週"), None);
+    }
+
+    #[test]
+    fn items_are_reminded_once_a_day_unless_snoozed() {
+        let today = NaiveDate::from_ymd_opt(2026, 4, 13).unwrap();
+        let now = Utc::now();
+        assert_eq!(needs_reminder(&item(Some("2026-04-20")), today, now), None);
+        assert!(needs_reminder(&item(Some("2026-04-14")), today, now).is_some());
+        assert!(needs_reminder(&item(Some("2026-04-01")), today, now).is_some());
+        assert_eq!(needs_reminder(&item(Some("Friday")), today, now), None);
+
+        let mut reminded = item(Some("2026-04-13"));
+        reminded.reminded_on = Some("2026-04-13".into());
+        assert_eq!(needs_reminder(&reminded, today, now), None);
+        reminded.reminded_on = Some("2026-04-12".into());
+        assert!(needs_reminder(&reminded, today, now).is_some());
+
+        let mut snoozed = item(Some("2026-04-13"));
+        snoozed.snoozed_until = Some(DateTimeUtc(now + Duration::hours(1)));
+        assert_eq!(needs_reminder(&snoozed, today, now), None);
+        assert!(needs_reminder(&snoozed, today, now + Duration::hours(2)).is_some());
+
+        let mut done = item(Some("2026-04-13"));
+        done.status = "done".into();
+        assert_eq!(needs_reminder(&done, today, now), None);
+    }
+
+    #[test]
+    fn reminder_text_names_the_most_urgent_item() {
+        let reminder = |days_left: i64| DueReminder {
+            item: item(Some("2026-04-13")),
+            meeting_title: "Weekly sync".into(),
+            due: NaiveDate::from_ymd_opt(2026, 4, 13).unwrap(),
+            days_left,
+        };
+        assert_eq!(
+            reminder_body(&[reminder(0)]),
+            "\"Send the budget\" is due today (Weekly sync)"
+        );
+        assert_eq!(
+            reminder_body(&[reminder(-3), reminder(1)]),
+            "2 action items are due. \"Send the budget\" is 3 days overdue, and 1 more."
+        );
+    }
+}
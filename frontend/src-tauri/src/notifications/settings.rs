@@ -60,6 +60,26 @@ pub struct NotificationPreferences {
 
     /// Minutes before meeting to show reminder (0 = disabled)
     pub meeting_reminder_minutes: Vec<u64>,
+
+    /// Show reminders for action items that are due or overdue
+    #[serde(default = "default_true")]
+    pub show_action_item_reminders: bool,
+
+    /// Show a notification when an import finishes while the app is in the background
+    #[serde(default = "default_true")]
+    pub show_import_complete: bool,
+
+    /// Show a notification when a re-transcription finishes while the app is in the background
+    #[serde(default = "default_true")]
+    pub show_retranscription_complete: bool,
+
+    /// Show a notification when a summary finishes while the app is in the background
+    #[serde(default = "default_true")]
+    pub show_summary_complete: bool,
+}
+
+fn default_true() -> bool {
+    true
 }
 
 impl Default for NotificationSettings {
@@ -89,6 +109,10 @@ impl Default for NotificationPreferences {
             show_meeting_reminders: true,
             show_system_errors: true,
             meeting_reminder_minutes: vec![15, 5], // 15 minutes and 5 minutes before
+            show_action_item_reminders: true,
+            show_import_complete: true,
+            show_retranscription_complete: true,
+            show_summary_complete: true,
         }
     }
 }
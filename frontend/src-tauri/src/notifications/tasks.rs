@@ -0,0 +1,123 @@
+//! Notifications for long-running tasks. When an import, a re-transcription
+//! or a summary finishes while no app window has focus, a native
+//! notification says so; each task type can be turned off in the
+//! notification preferences. Cancelled tasks are not announced.
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::notifications::commands::NotificationManagerState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskKind {
+    Import,
+    Retranscription,
+    Summary,
+}
+
+impl TaskKind {
+    /// The task type of a job kind, for the kinds that are announced. The job
+    /// manager announces its jobs through this: imports (files, links, Zoom,
+    /// Teams, feeds) and segment re-transcriptions (`segment_rerun`). Summaries
+    /// aren't jobs and announce themselves.
+    pub fn from_job_kind(kind: &str) -> Option<Self> {
+        match kind {
+            "import" => Some(Self::Import),
+            "retranscribe" => Some(Self::Retranscription),
+            _ => None,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Import => "Import",
+            Self::Retranscription => "Re-transcription",
+            Self::Summary => "Summary",
+        }
+    }
+}
+
+/// Notification text for a finished task; `error` is None when it succeeded
+pub fn task_message(kind: TaskKind, title: &str, error: Option<&str>) -> (String, String) {
+    let title = title.trim();
+    match error {
+        None => (
+            format!("{} finished", kind.label()),
+            if title.is_empty() {
+                format!("{} is ready", kind.label())
+            } else {
+                format!("\"{}\" is ready", title)
+            },
+        ),
+        Some(error) => (
+            format!("{} failed", kind.label()),
+            if title.is_empty() {
+                error.to_string()
+            } else {
+                format!("\"{}\": {}", title, error)
+            },
+        ),
+    }
+}
+
+/// Whether any of the app's windows has focus
+fn app_has_focus<R: Runtime>(app: &AppHandle<R>) -> bool {
+    app.webview_windows()
+        .values()
+        .any(|window| window.is_focused().unwrap_or(false))
+}
+
+/// Announces a finished task unless the app is in front, where the UI
+/// already shows the outcome
+pub async fn notify_task_finished<R: Runtime>(
+    app: &AppHandle<R>,
+    kind: TaskKind,
+    title: &str,
+    error: Option<&str>,
+) {
+    if app_has_focus(app) {
+        return;
+    }
+    let Some(manager_state) = app.try_state::<NotificationManagerState<R>>() else {
+        return;
+    };
+    let manager = manager_state.read().await;
+    let Some(manager) = manager.as_ref() else {
+        info!(
+            "Notification manager not ready; not announcing the finished {:?}",
+            kind
+        );
+        return;
+    };
+    let (heading, body) = task_message(kind, title, error);
+    if let Err(e) = manager.show_task_finished(kind, heading, body).await {
+        warn!("Failed to show the task notification: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn messages_name_the_task_and_its_outcome() {
+        assert_eq!(
+            task_message(TaskKind::Summary, "Weekly sync", None),
+            (
+                "Summary finished".to_string(),
+                "\"Weekly sync\" is ready".to_string()
+            )
+        );
+        assert_eq!(
+            task_message(TaskKind::Import, "", Some("Decoding failed")),
+            ("Import failed".to_string(), "Decoding failed".to_string())
+        );
+        assert_eq!(
+            TaskKind::from_job_kind("retranscribe"),
+            Some(TaskKind::Retranscription)
+        );
+        assert_eq!(TaskKind::from_job_kind("export"), None);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::notifications::tasks::TaskKind;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,6 +22,8 @@ pub enum NotificationType {
     RecordingResumed,
     TranscriptionComplete,
     MeetingReminder(u64), // Duration in minutes
+    ActionItemsDue(usize), // Number of items due
+    TaskFinished(TaskKind),
     SystemError(String),
     Test, // For testing notifications
 }
@@ -176,6 +179,18 @@ impl Notification {
             .with_timeout(NotificationTimeout::Seconds(10))
     }
 
+    pub fn action_items_due(count: usize, body: impl Into<String>) -> Self {
+        Notification::new("Meetily", body, NotificationType::ActionItemsDue(count))
+            .with_priority(NotificationPriority::Normal)
+            .with_timeout(NotificationTimeout::Seconds(10))
+    }
+
+    pub fn task_finished(kind: TaskKind, title: impl Into<String>, body: impl Into<String>) -> Self {
+        Notification::new(title, body, NotificationType::TaskFinished(kind))
+            .with_priority(NotificationPriority::Normal)
+            .with_timeout(NotificationTimeout::Seconds(5))
+    }
+
     pub fn system_error(error: impl Into<String>) -> Self {
         let error_string = error.into();
         Notification::new(
@@ -6,6 +6,7 @@ use anyhow::Result;
 
 use crate::state::AppState;
 use crate::database::repositories::setting::SettingsRepository;
+use crate::database::settings_cache::{config_changed, ConfigKind};
 
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -198,6 +199,8 @@ pub async fn complete_onboarding<R: Runtime>(
         return Err(format!("Failed to save transcription model config: {}", e));
     }
     info!("Saved transcription model config: provider=parakeet, model=parakeet-tdt-0.6b-v3-int8");
+    config_changed(&app, &state.settings_cache, ConfigKind::Model);
+    config_changed(&app, &state.settings_cache, ConfigKind::Transcript);
 
     // Step 2: Only NOW mark onboarding as complete (after DB operations succeed)
     let mut status = load_onboarding_status(&app)
@@ -0,0 +1,165 @@
+// overlay.rs
+//
+// Compact always-on-top recorder overlay: a second window showing elapsed
+// time and pause state while the main window is hidden or behind others. It
+// follows the recording through the `recording-status` events every window
+// receives, and reads a snapshot when it opens. Where it was last placed is
+// kept in settings and restored on the next open, unless that spot is no
+// longer on any screen.
+
+use log::{info, warn};
+use serde::Serialize;
+use std::sync::Mutex;
+use tauri::{
+    AppHandle, Manager, PhysicalPosition, Runtime, WebviewUrl, WebviewWindowBuilder, WindowEvent,
+};
+
+use crate::audio::recording_commands::{current_recording_status, is_recording};
+use crate::audio::recording_status::RecordingStatus;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::events::{self, OverlayVisibility};
+use crate::state::AppState;
+
+pub const OVERLAY_LABEL: &str = "overlay";
+const OVERLAY_WIDTH: f64 = 320.0;
+const OVERLAY_HEIGHT: f64 = 96.0;
+
+// Latest position while the overlay is dragged; saved when it closes
+static LAST_POSITION: Mutex<Option<(i32, i32)>> = Mutex::new(None);
+
+/// What the overlay shows when it opens, before the next status event
+#[derive(Debug, Clone, Serialize)]
+pub struct OverlaySnapshot {
+    pub is_recording: bool,
+    pub status: Option<RecordingStatus>,
+}
+
+/// Whether a window placed at `position` would show on one of the screens,
+/// given as (x, y, width, height)
+fn on_screen(position: (i32, i32), screens: &[(i32, i32, u32, u32)]) -> bool {
+    let (x, y) = position;
+    screens.iter().any(|&(sx, sy, width, height)| {
+        x >= sx && y >= sy && x < sx + width as i32 && y < sy + height as i32
+    })
+}
+
+/// The saved position, if it is still on a connected screen
+async fn restored_position<R: Runtime>(app: &AppHandle<R>) -> Option<(i32, i32)> {
+    let state = app.try_state::<AppState>()?;
+    let position = match SettingsRepository::get_overlay_position(state.db_manager.pool()).await {
+        Ok(position) => position?,
+        Err(e) => {
+            warn!("Failed to load the overlay position: {}", e);
+            return None;
+        }
+    };
+    let screens: Vec<(i32, i32, u32, u32)> = app
+        .available_monitors()
+        .unwrap_or_default()
+        .iter()
+        .map(|monitor| {
+            let (position, size) = (monitor.position(), monitor.size());
+            (position.x, position.y, size.width, size.height)
+        })
+        .collect();
+    on_screen(position, &screens).then_some(position)
+}
+
+async fn save_position<R: Runtime>(app: &AppHandle<R>) {
+    let Some((x, y)) = LAST_POSITION
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+    else {
+        return;
+    };
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    if let Err(e) = SettingsRepository::save_overlay_position(state.db_manager.pool(), x, y).await {
+        warn!("Failed to save the overlay position: {}", e);
+    }
+}
+
+/// Opens the recorder overlay, or brings it forward if already open
+#[tauri::command]
+pub async fn api_open_overlay<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window(OVERLAY_LABEL) {
+        window.show().map_err(|e| e.to_string())?;
+        return window.set_focus().map_err(|e| e.to_string());
+    }
+
+    let window = WebviewWindowBuilder::new(&app, OVERLAY_LABEL, WebviewUrl::App("overlay".into()))
+        .title("Meetily Recorder")
+        .inner_size(OVERLAY_WIDTH, OVERLAY_HEIGHT)
+        .resizable(false)
+        .decorations(false)
+        .always_on_top(true)
+        .skip_taskbar(true)
+        .visible_on_all_workspaces(true)
+        .build()
+        .map_err(|e| format!("Failed to open the overlay: {}", e))?;
+    if let Some((x, y)) = restored_position(&app).await {
+        if let Err(e) = window.set_position(PhysicalPosition::new(x, y)) {
+            warn!("Failed to restore the overlay position: {}", e);
+        }
+    }
+
+    let handle = app.clone();
+    window.on_window_event(move |event| match event {
+        WindowEvent::Moved(position) => {
+            *LAST_POSITION.lock().unwrap_or_else(|e| e.into_inner()) =
+                Some((position.x, position.y));
+        }
+        WindowEvent::Destroyed => {
+            let _ = events::emit(&handle, &OverlayVisibility { open: false });
+            let handle = handle.clone();
+            tauri::async_runtime::spawn(async move { save_position(&handle).await });
+        }
+        _ => {}
+    });
+
+    info!("Recorder overlay opened");
+    events::emit(&app, &OverlayVisibility { open: true }).map_err(|e| e.to_string())
+}
+
+/// Closes the recorder overlay; its position is saved for the next open
+#[tauri::command]
+pub async fn api_close_overlay<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    match app.get_webview_window(OVERLAY_LABEL) {
+        Some(window) => window.close().map_err(|e| e.to_string()),
+        None => Ok(()),
+    }
+}
+
+#[tauri::command]
+pub async fn api_is_overlay_open<R: Runtime>(app: AppHandle<R>) -> Result<bool, String> {
+    Ok(app.get_webview_window(OVERLAY_LABEL).is_some())
+}
+
+/// The recording state for the overlay to show until the next
+/// `recording-status` event
+#[tauri::command]
+pub async fn api_get_overlay_snapshot<R: Runtime>(
+    _app: AppHandle<R>,
+) -> Result<OverlaySnapshot, String> {
+    Ok(OverlaySnapshot {
+        is_recording: is_recording().await,
+        status: current_recording_status(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restores_only_positions_on_a_screen() {
+        let screens = [(0, 0, 1920, 1080), (1920, 0, 2560, 1440)];
+        assert!(on_screen((100, 100), &screens));
+        assert!(on_screen((3000, 1200), &screens));
+        assert!(!on_screen((3000, 1500), &screens));
+        assert!(!on_screen((-400, 10), &screens));
+        assert!(!on_screen((10, 10), &[]));
+    }
+}
@@ -374,6 +374,7 @@ impl ParakeetEngine {
 
     /// Load a Parakeet model
     pub async fn load_model(&self, model_name: &str) -> Result<()> {
+        crate::audio::transcription::idle::mark_used();
         let models = self.available_models.read().await;
         let model_info = models
             .get(model_name)
@@ -452,6 +453,7 @@ impl ParakeetEngine {
 
     /// Transcribe audio samples using the loaded Parakeet model
     pub async fn transcribe_audio(&self, audio_data: Vec<f32>) -> Result<String> {
+        crate::audio::transcription::idle::mark_used();
         let mut model_guard = self.current_model.write().await;
         let model = model_guard
             .as_mut()
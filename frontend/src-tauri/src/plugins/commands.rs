@@ -0,0 +1,166 @@
+use log::info as log_info;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::manifest::Capability;
+use super::{discover, load_states, run_exporter, save_states, PluginState, PLUGINS_DIR_NAME};
+use crate::database::repositories::{
+    action_item::ActionItemsRepository, meeting::MeetingsRepository,
+    summary::SummaryProcessesRepository,
+};
+use crate::audit::{self, AuditAction};
+use crate::state::AppState;
+
+pub fn plugins_dir<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join(PLUGINS_DIR_NAME))
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))
+}
+
+/// Lists installed plugins with their enable state and granted capabilities,
+/// plus any plugin directories that failed to load
+#[tauri::command]
+pub async fn api_list_plugins<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_list_plugins called");
+    let dir = plugins_dir(&app)?;
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create plugins directory: {}", e))?;
+
+    let states = load_states(state.db_manager.pool()).await?;
+    let (plugins, errors) = discover(&dir, &states);
+    Ok(serde_json::json!({
+        "pluginsDir": dir,
+        "plugins": plugins,
+        "errors": errors,
+    }))
+}
+
+/// Enables a plugin with the given capability grants. Grants must be a subset
+/// of what the manifest requests.
+#[tauri::command]
+pub async fn api_enable_plugin<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    plugin_id: String,
+    granted: Vec<Capability>,
+) -> Result<serde_json::Value, String> {
+    crate::validation::plugin_id(&plugin_id)?;
+    log_info!(
+        "api_enable_plugin called for {} with grants {:?}",
+        plugin_id,
+        granted
+    );
+    let pool = state.db_manager.pool();
+    let mut states = load_states(pool).await?;
+    let (plugins, _) = discover(&plugins_dir(&app)?, &states);
+    let plugin = plugins
+        .iter()
+        .find(|p| p.manifest.id == plugin_id)
+        .ok_or_else(|| format!("Plugin '{}' is not installed", plugin_id))?;
+
+    if let Some(extra) = granted
+        .iter()
+        .find(|c| !plugin.manifest.capabilities.contains(c))
+    {
+        return Err(format!(
+            "Plugin '{}' does not request capability {:?}",
+            plugin_id, extra
+        ));
+    }
+
+    states.insert(
+        plugin_id.clone(),
+        PluginState {
+            enabled: true,
+            granted,
+        },
+    );
+    save_states(pool, &states).await?;
+    Ok(serde_json::json!({ "status": "success", "plugin_id": plugin_id }))
+}
+
+/// Disables a plugin and revokes its grants
+#[tauri::command]
+pub async fn api_disable_plugin<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    plugin_id: String,
+) -> Result<serde_json::Value, String> {
+    crate::validation::plugin_id(&plugin_id)?;
+    log_info!("api_disable_plugin called for {}", plugin_id);
+    let pool = state.db_manager.pool();
+    let mut states = load_states(pool).await?;
+    states.insert(plugin_id.clone(), PluginState::default());
+    save_states(pool, &states).await?;
+    Ok(serde_json::json!({ "status": "success", "plugin_id": plugin_id }))
+}
+
+/// Exports a meeting through an exporter plugin and writes its output to `path`
+#[tauri::command]
+pub async fn api_export_with_plugin<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    plugin_id: String,
+    meeting_id: String,
+    path: String,
+) -> Result<serde_json::Value, String> {
+    crate::validation::plugin_id(&plugin_id)?;
+    crate::validation::meeting_id(&meeting_id)?;
+    let destination = crate::validation::output_file("path", &path)?;
+    log_info!(
+        "api_export_with_plugin called for meeting {} with plugin {}",
+        meeting_id,
+        plugin_id
+    );
+    let pool = state.db_manager.pool();
+    let states = load_states(pool).await?;
+    let (plugins, _) = discover(&plugins_dir(&app)?, &states);
+    let plugin = plugins
+        .into_iter()
+        .find(|p| p.manifest.id == plugin_id)
+        .ok_or_else(|| format!("Plugin '{}' is not installed", plugin_id))?;
+
+    let meeting = MeetingsRepository::get_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting '{}' not found", meeting_id))?;
+    let summary = SummaryProcessesRepository::get_summary_data(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load summary: {}", e))?
+        .and_then(|p| p.result)
+        .and_then(|r| serde_json::from_str::<serde_json::Value>(&r).ok())
+        .and_then(|v| v.get("markdown").cloned());
+    let action_items = ActionItemsRepository::list_for_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load action items: {}", e))?;
+
+    let payload = serde_json::json!({
+        "meeting": meeting,
+        "summary": { "markdown": summary },
+        "action_items": action_items,
+    });
+    let output = run_exporter(&plugin, payload).await?;
+
+    let bytes = output.len();
+    tokio::task::spawn_blocking(move || std::fs::write(&destination, output))
+        .await
+        .map_err(|e| format!("Export write task failed: {}", e))?
+        .map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    audit::record(
+        pool,
+        AuditAction::Export,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({ "plugin": plugin_id, "path": path }),
+    )
+    .await;
+    Ok(serde_json::json!({
+        "status": "success",
+        "path": path,
+        "bytes": bytes,
+    }))
+}
@@ -0,0 +1,166 @@
+//! Plugin manifest (`plugin.json`) parsing and validation.
+//!
+//! ```json
+//! {
+//!   "id": "filler-words",
+//!   "name": "Filler word remover",
+//!   "version": "1.0.0",
+//!   "description": "Removes um/uh from transcripts",
+//!   "wasm": "plugin.wasm",
+//!   "provides": ["transcript_processor"],
+//!   "capabilities": ["log"]
+//! }
+//! ```
+//!
+//! Exporters additionally declare `"exporter": { "label": "Obsidian note", "extension": "md" }`.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub const MANIFEST_FILE: &str = "plugin.json";
+
+/// Extension points a plugin implements. Each maps to a wasm export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginKind {
+    /// Export `process_transcript`: rewrites segment text before it is saved
+    TranscriptProcessor,
+    /// Export `export_meeting`: renders a meeting to a file
+    Exporter,
+}
+
+impl PluginKind {
+    pub fn export_name(&self) -> &'static str {
+        match self {
+            PluginKind::TranscriptProcessor => "process_transcript",
+            PluginKind::Exporter => "export_meeting",
+        }
+    }
+}
+
+/// Access a plugin must be granted by the user. Plugins have no filesystem,
+/// network or clock access at all; these only widen what the host hands them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Capability {
+    /// Host import `env.host_log(ptr, len)`, written to the app log
+    #[serde(rename = "log")]
+    Log,
+    /// Summary markdown is included in exporter input
+    #[serde(rename = "summary:read")]
+    ReadSummary,
+    /// Action items are included in exporter input
+    #[serde(rename = "action_items:read")]
+    ReadActionItems,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExporterInfo {
+    pub label: String,
+    pub extension: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginManifest {
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Module file, relative to the plugin directory
+    pub wasm: String,
+    pub provides: Vec<PluginKind>,
+    #[serde(default)]
+    pub capabilities: Vec<Capability>,
+    #[serde(default)]
+    pub exporter: Option<ExporterInfo>,
+}
+
+impl PluginManifest {
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let manifest: PluginManifest =
+            serde_json::from_str(raw).map_err(|e| format!("Invalid {}: {}", MANIFEST_FILE, e))?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    pub fn load(plugin_dir: &Path) -> Result<Self, String> {
+        let path = plugin_dir.join(MANIFEST_FILE);
+        let raw = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        Self::parse(&raw)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let id_ok = !self.id.is_empty()
+            && self
+                .id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+        if !id_ok {
+            return Err(format!(
+                "Plugin id '{}' must be non-empty lowercase letters, digits, '-' or '_'",
+                self.id
+            ));
+        }
+        // The module must live inside the plugin directory
+        let wasm = Path::new(&self.wasm);
+        if self.wasm.is_empty()
+            || wasm.is_absolute()
+            || wasm.components().any(|c| matches!(c, std::path::Component::ParentDir))
+        {
+            return Err(format!("Plugin '{}' has an invalid wasm path", self.id));
+        }
+        if self.provides.is_empty() {
+            return Err(format!("Plugin '{}' does not provide anything", self.id));
+        }
+        if self.provides.contains(&PluginKind::Exporter) {
+            match &self.exporter {
+                Some(info) if !info.extension.is_empty() && info.extension.chars().all(|c| c.is_ascii_alphanumeric()) => {}
+                _ => {
+                    return Err(format!(
+                        "Exporter plugin '{}' needs an \"exporter\" section with an alphanumeric extension",
+                        self.id
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROCESSOR: &str = r#"{
+        "id": "filler-words", "name": "Filler", "version": "1.0.0",
+        "wasm": "plugin.wasm", "provides": ["transcript_processor"], "capabilities": ["log"]
+    }"#;
+
+    #[test]
+    fn parses_valid_manifest() {
+        let manifest = PluginManifest::parse(PROCESSOR).unwrap();
+        assert_eq!(manifest.provides, vec![PluginKind::TranscriptProcessor]);
+        assert_eq!(manifest.capabilities, vec![Capability::Log]);
+    }
+
+    #[test]
+    fn rejects_unsafe_or_incomplete_manifests() {
+        let escape = PROCESSOR.replace("plugin.wasm", "../other/plugin.wasm");
+        assert!(PluginManifest::parse(&escape).is_err());
+
+        let bad_id = PROCESSOR.replace("filler-words", "Filler Words");
+        assert!(PluginManifest::parse(&bad_id).is_err());
+
+        let unknown_cap = PROCESSOR.replace("\"log\"", "\"network\"");
+        assert!(PluginManifest::parse(&unknown_cap).is_err());
+
+        let exporter = PROCESSOR.replace("transcript_processor", "exporter");
+        assert!(PluginManifest::parse(&exporter).is_err());
+        let exporter = exporter.replace(
+            "\"capabilities\"",
+            "\"exporter\": {\"label\": \"Notes\", \"extension\": \"md\"}, \"capabilities\"",
+        );
+        assert!(PluginManifest::parse(&exporter).is_ok());
+    }
+}
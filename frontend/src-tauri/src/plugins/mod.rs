@@ -0,0 +1,275 @@
+/// Plugins module - sandboxed WASM extensions
+///
+/// This module contains:
+/// - Manifest parsing for plugins in `<app data>/plugins/<id>/` (see `manifest`)
+/// - The wasmtime host and plugin ABI (see `runtime`)
+/// - Transcript post-processing and export through enabled plugins
+/// - Tauri commands for listing, enabling/disabling and running plugins
+///
+/// Plugins are disabled until the user enables them, and only receive the
+/// capabilities granted at that point. Enable state and grants are stored in
+/// `integration_settings` under the id "plugins".
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::api::TranscriptSegment;
+use crate::database::repositories::integration::IntegrationSettingsRepository;
+
+pub mod commands;
+pub mod manifest;
+pub mod runtime;
+
+use manifest::{Capability, PluginKind, PluginManifest};
+
+pub const PLUGINS_SETTINGS_ID: &str = "plugins";
+pub const PLUGINS_DIR_NAME: &str = "plugins";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PluginState {
+    pub enabled: bool,
+    pub granted: Vec<Capability>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InstalledPlugin {
+    #[serde(flatten)]
+    pub manifest: PluginManifest,
+    pub enabled: bool,
+    pub granted: Vec<Capability>,
+    #[serde(skip)]
+    pub dir: PathBuf,
+}
+
+impl InstalledPlugin {
+    fn granted_set(&self) -> HashSet<Capability> {
+        self.granted.iter().copied().collect()
+    }
+
+    fn wasm_path(&self) -> PathBuf {
+        self.dir.join(&self.manifest.wasm)
+    }
+
+    fn provides(&self, kind: PluginKind) -> bool {
+        self.manifest.provides.contains(&kind)
+    }
+}
+
+/// A plugin directory that could not be loaded, reported to the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct PluginLoadError {
+    pub dir: String,
+    pub error: String,
+}
+
+pub async fn load_states(pool: &SqlitePool) -> Result<HashMap<String, PluginState>, String> {
+    let raw = IntegrationSettingsRepository::get_config(pool, PLUGINS_SETTINGS_ID)
+        .await
+        .map_err(|e| format!("Failed to load plugin settings: {}", e))?;
+    match raw {
+        Some(raw) => serde_json::from_str(&raw)
+            .map_err(|e| format!("Stored plugin settings are invalid: {}", e)),
+        None => Ok(HashMap::new()),
+    }
+}
+
+pub async fn save_states(
+    pool: &SqlitePool,
+    states: &HashMap<String, PluginState>,
+) -> Result<(), String> {
+    let raw = serde_json::to_string(states)
+        .map_err(|e| format!("Failed to serialize plugin settings: {}", e))?;
+    IntegrationSettingsRepository::save_config(pool, PLUGINS_SETTINGS_ID, &raw)
+        .await
+        .map_err(|e| format!("Failed to save plugin settings: {}", e))
+}
+
+/// Scans the plugins directory. Grants are intersected with what the current
+/// manifest requests, so an updated plugin cannot inherit new capabilities.
+pub fn discover(
+    plugins_dir: &Path,
+    states: &HashMap<String, PluginState>,
+) -> (Vec<InstalledPlugin>, Vec<PluginLoadError>) {
+    let mut plugins = Vec::new();
+    let mut errors = Vec::new();
+    let Ok(entries) = std::fs::read_dir(plugins_dir) else {
+        return (plugins, errors);
+    };
+
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        if !dir.is_dir() {
+            continue;
+        }
+        match PluginManifest::load(&dir) {
+            Ok(manifest) if plugins.iter().any(|p: &InstalledPlugin| p.manifest.id == manifest.id) => {
+                errors.push(PluginLoadError {
+                    dir: dir.display().to_string(),
+                    error: format!("Duplicate plugin id '{}'", manifest.id),
+                });
+            }
+            Ok(manifest) => {
+                let state = states.get(&manifest.id).cloned().unwrap_or_default();
+                let granted = state
+                    .granted
+                    .into_iter()
+                    .filter(|c| manifest.capabilities.contains(c))
+                    .collect();
+                plugins.push(InstalledPlugin {
+                    enabled: state.enabled,
+                    granted,
+                    manifest,
+                    dir,
+                });
+            }
+            Err(error) => errors.push(PluginLoadError {
+                dir: dir.display().to_string(),
+                error,
+            }),
+        }
+    }
+    plugins.sort_by(|a, b| a.manifest.name.cmp(&b.manifest.name));
+    (plugins, errors)
+}
+
+async fn call(plugin: &InstalledPlugin, kind: PluginKind, input: String) -> Result<String, String> {
+    let id = plugin.manifest.id.clone();
+    let wasm_path = plugin.wasm_path();
+    let granted = plugin.granted_set();
+    tokio::task::spawn_blocking(move || runtime::call_plugin(&id, &wasm_path, kind, &granted, &input))
+        .await
+        .map_err(|e| format!("Plugin task failed: {}", e))?
+}
+
+/// Applies processor output: one text per input segment, in order
+pub fn apply_processed_text(
+    segments: &mut [TranscriptSegment],
+    output: &str,
+) -> Result<(), String> {
+    let parsed: Value =
+        serde_json::from_str(output).map_err(|e| format!("Invalid processor output: {}", e))?;
+    let texts = parsed
+        .get("segments")
+        .and_then(Value::as_array)
+        .ok_or("Processor output has no segments array")?;
+    if texts.len() != segments.len() {
+        return Err(format!(
+            "Processor returned {} segments for {} inputs",
+            texts.len(),
+            segments.len()
+        ));
+    }
+    let texts: Vec<&str> = texts
+        .iter()
+        .map(|s| s.get("text").and_then(Value::as_str))
+        .collect::<Option<_>>()
+        .ok_or("Every processed segment needs a text field")?;
+    for (segment, text) in segments.iter_mut().zip(texts) {
+        segment.text = text.to_string();
+    }
+    Ok(())
+}
+
+/// Runs enabled transcript processors in name order. A failing plugin is
+/// skipped and logged; the segments are left as the previous plugin produced them.
+pub async fn process_transcript(
+    pool: &SqlitePool,
+    plugins_dir: &Path,
+    segments: &mut [TranscriptSegment],
+) {
+    if segments.is_empty() {
+        return;
+    }
+    let states = match load_states(pool).await {
+        Ok(states) => states,
+        Err(e) => {
+            log::warn!("Skipping transcript plugins: {}", e);
+            return;
+        }
+    };
+    let (plugins, _) = discover(plugins_dir, &states);
+
+    for plugin in plugins
+        .iter()
+        .filter(|p| p.enabled && p.provides(PluginKind::TranscriptProcessor))
+    {
+        let input = json!({
+            "segments": segments.iter().map(|s| json!({
+                "text": s.text,
+                "start": s.audio_start_time,
+                "end": s.audio_end_time,
+            })).collect::<Vec<_>>()
+        })
+        .to_string();
+
+        let result = call(plugin, PluginKind::TranscriptProcessor, input)
+            .await
+            .and_then(|output| apply_processed_text(segments, &output));
+        match result {
+            Ok(()) => log::info!("Transcript processed by plugin '{}'", plugin.manifest.id),
+            Err(e) => log::warn!("Plugin '{}' failed, skipping: {}", plugin.manifest.id, e),
+        }
+    }
+}
+
+/// Runs an exporter plugin. `meeting` is the full meeting payload; fields the
+/// plugin has not been granted are removed before it is passed in.
+pub async fn run_exporter(plugin: &InstalledPlugin, mut meeting: Value) -> Result<String, String> {
+    if !plugin.enabled {
+        return Err(format!("Plugin '{}' is disabled", plugin.manifest.id));
+    }
+    if !plugin.provides(PluginKind::Exporter) {
+        return Err(format!("Plugin '{}' is not an exporter", plugin.manifest.id));
+    }
+    let granted = plugin.granted_set();
+    if let Some(obj) = meeting.as_object_mut() {
+        if !granted.contains(&Capability::ReadSummary) {
+            obj.remove("summary");
+        }
+        if !granted.contains(&Capability::ReadActionItems) {
+            obj.remove("action_items");
+        }
+    }
+    call(plugin, PluginKind::Exporter, meeting.to_string()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            id: "t".into(),
+            text: text.into(),
+            timestamp: "00:00:00".into(),
+            audio_start_time: None,
+            audio_end_time: None,
+            duration: None,
+            speaker: None,
+            uncertain: false,
+        }
+    }
+
+    #[test]
+    fn processor_output_replaces_text_in_order() {
+        let mut segments = vec![segment("um hello"), segment("uh bye")];
+        apply_processed_text(
+            &mut segments,
+            r#"{"segments":[{"text":"hello"},{"text":"bye"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(segments[0].text, "hello");
+        assert_eq!(segments[1].text, "bye");
+    }
+
+    #[test]
+    fn mismatched_processor_output_is_rejected_without_changes() {
+        let mut segments = vec![segment("a"), segment("b")];
+        assert!(apply_processed_text(&mut segments, r#"{"segments":[{"text":"x"}]}"#).is_err());
+        assert!(apply_processed_text(&mut segments, r#"{"segments":[{"text":"x"},{}]}"#).is_err());
+        assert_eq!(segments[0].text, "a");
+    }
+}
@@ -0,0 +1,229 @@
+//! wasmtime host for plugin modules.
+//!
+//! ABI: the module exports `memory` and `alloc(len: i32) -> i32`. Each
+//! extension point export takes `(ptr: i32, len: i32)` pointing at UTF-8 JSON
+//! input and returns an `i64` packing the output as `(ptr << 32) | len`.
+//! The only host import is `env.host_log(ptr, len)`, linked when the `log`
+//! capability is granted. Modules are not given WASI.
+
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+use std::path::Path;
+use wasmtime::{Caller, Config, Engine, Linker, Module, Store, StoreLimits, StoreLimitsBuilder};
+
+use super::manifest::{Capability, PluginKind};
+
+/// Roughly a few seconds of work; a plugin that runs out is aborted
+const FUEL_PER_CALL: u64 = 5_000_000_000;
+const MAX_MEMORY_BYTES: usize = 256 * 1024 * 1024;
+const MAX_OUTPUT_BYTES: usize = 64 * 1024 * 1024;
+
+static ENGINE: Lazy<Engine> = Lazy::new(|| {
+    let mut config = Config::new();
+    config.consume_fuel(true);
+    Engine::new(&config).expect("wasmtime engine configuration is valid")
+});
+
+struct HostState {
+    plugin_id: String,
+    limits: StoreLimits,
+}
+
+/// Checks every import against the granted capabilities, so a plugin that
+/// needs an ungranted capability fails with a clear message
+fn check_imports(module: &Module, granted: &HashSet<Capability>) -> Result<(), String> {
+    for import in module.imports() {
+        match (import.module(), import.name()) {
+            ("env", "host_log") if granted.contains(&Capability::Log) => {}
+            ("env", "host_log") => {
+                return Err("Plugin imports host_log but the 'log' capability is not granted".into())
+            }
+            (module, name) => {
+                return Err(format!("Plugin imports unsupported host function {}.{}", module, name))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Splits the packed `(ptr << 32) | len` return value
+pub fn unpack_ptr_len(packed: i64) -> (usize, usize) {
+    let packed = packed as u64;
+    ((packed >> 32) as usize, (packed & 0xffff_ffff) as usize)
+}
+
+/// Instantiates the module and calls the export for `kind` with `input`.
+/// Blocking; callers run this on a blocking thread.
+pub fn call_plugin(
+    plugin_id: &str,
+    wasm_path: &Path,
+    kind: PluginKind,
+    granted: &HashSet<Capability>,
+    input: &str,
+) -> Result<String, String> {
+    let module = Module::from_file(&ENGINE, wasm_path)
+        .map_err(|e| format!("Failed to load {}: {}", wasm_path.display(), e))?;
+    check_imports(&module, granted)?;
+
+    let mut store = Store::new(
+        &ENGINE,
+        HostState {
+            plugin_id: plugin_id.to_string(),
+            limits: StoreLimitsBuilder::new().memory_size(MAX_MEMORY_BYTES).build(),
+        },
+    );
+    store.limiter(|state| &mut state.limits);
+    store
+        .set_fuel(FUEL_PER_CALL)
+        .map_err(|e| format!("Failed to set fuel: {}", e))?;
+
+    let mut linker: Linker<HostState> = Linker::new(&ENGINE);
+    if granted.contains(&Capability::Log) {
+        linker
+            .func_wrap(
+                "env",
+                "host_log",
+                |mut caller: Caller<'_, HostState>, ptr: i32, len: i32| {
+                    let Some(memory) = caller.get_export("memory").and_then(|e| e.into_memory()) else {
+                        return;
+                    };
+                    let mut buf = vec![0u8; (len.max(0) as usize).min(4096)];
+                    if memory.read(&caller, ptr as usize, &mut buf).is_ok() {
+                        log::info!(
+                            "[plugin {}] {}",
+                            caller.data().plugin_id,
+                            String::from_utf8_lossy(&buf)
+                        );
+                    }
+                },
+            )
+            .map_err(|e| format!("Failed to link host_log: {}", e))?;
+    }
+
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .map_err(|e| format!("Failed to instantiate plugin: {}", e))?;
+    let memory = instance
+        .get_memory(&mut store, "memory")
+        .ok_or("Plugin does not export memory")?;
+    let alloc = instance
+        .get_typed_func::<i32, i32>(&mut store, "alloc")
+        .map_err(|e| format!("Plugin does not export alloc: {}", e))?;
+    let entry = instance
+        .get_typed_func::<(i32, i32), i64>(&mut store, kind.export_name())
+        .map_err(|e| format!("Plugin does not export {}: {}", kind.export_name(), e))?;
+
+    let bytes = input.as_bytes();
+    let len = i32::try_from(bytes.len()).map_err(|_| "Plugin input is too large".to_string())?;
+    let ptr = alloc
+        .call(&mut store, len)
+        .map_err(|e| format!("Plugin alloc failed: {}", e))?;
+    memory
+        .write(&mut store, ptr as usize, bytes)
+        .map_err(|e| format!("Plugin returned an invalid input buffer: {}", e))?;
+
+    let packed = entry
+        .call(&mut store, (ptr, len))
+        .map_err(|e| format!("Plugin {} failed: {}", kind.export_name(), e))?;
+    let (out_ptr, out_len) = unpack_ptr_len(packed);
+    if out_len > MAX_OUTPUT_BYTES {
+        return Err(format!("Plugin output exceeds {} bytes", MAX_OUTPUT_BYTES));
+    }
+    let mut out = vec![0u8; out_len];
+    memory
+        .read(&store, out_ptr, &mut out)
+        .map_err(|e| format!("Plugin returned an invalid output buffer: {}", e))?;
+    String::from_utf8(out).map_err(|_| "Plugin output is not valid UTF-8".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Echoes its input back, upper-casing ASCII letters
+    const UPPERCASE: &str = r#"
+        (module
+          (memory (export "memory") 1)
+          (func (export "alloc") (param i32) (result i32) i32.const 1024)
+          (func (export "process_transcript") (param $ptr i32) (param $len i32) (result i64)
+            (local $i i32) (local $c i32)
+            (block $done
+              (loop $next
+                (br_if $done (i32.ge_u (local.get $i) (local.get $len)))
+                (local.set $c (i32.load8_u (i32.add (local.get $ptr) (local.get $i))))
+                (if (i32.and (i32.ge_u (local.get $c) (i32.const 97))
+                             (i32.le_u (local.get $c) (i32.const 122)))
+                  (then (i32.store8 (i32.add (local.get $ptr) (local.get $i))
+                                    (i32.sub (local.get $c) (i32.const 32)))))
+                (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                (br $next)))
+            (i64.or (i64.shl (i64.extend_i32_u (local.get $ptr)) (i64.const 32))
+                    (i64.extend_i32_u (local.get $len)))))
+    "#;
+
+    fn write_module(dir: &tempfile::TempDir, name: &str, wat_source: &str) -> std::path::PathBuf {
+        let path = dir.path().join(name);
+        std::fs::write(&path, wat::parse_str(wat_source).unwrap()).unwrap();
+        path
+    }
+
+    #[test]
+    fn unpacks_pointer_and_length() {
+        let packed = ((1024u64 << 32) | 77) as i64;
+        assert_eq!(unpack_ptr_len(packed), (1024, 77));
+        // High bit set in the pointer must not sign-extend into the length
+        let packed = ((0x8000_0000u64 << 32) | 5) as i64;
+        assert_eq!(unpack_ptr_len(packed), (0x8000_0000, 5));
+    }
+
+    #[test]
+    fn calls_the_export_for_the_kind() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_module(&dir, "upper.wasm", UPPERCASE);
+        let output = call_plugin(
+            "upper",
+            &path,
+            PluginKind::TranscriptProcessor,
+            &HashSet::new(),
+            r#"{"text":"hello"}"#,
+        )
+        .unwrap();
+        assert_eq!(output, r#"{"TEXT":"HELLO"}"#);
+
+        let missing = call_plugin("upper", &path, PluginKind::Exporter, &HashSet::new(), "{}");
+        assert!(missing.unwrap_err().contains("export_meeting"));
+    }
+
+    #[test]
+    fn ungranted_imports_and_runaway_loops_fail() {
+        let dir = tempfile::tempdir().unwrap();
+        let logging = write_module(
+            &dir,
+            "log.wasm",
+            r#"(module (import "env" "host_log" (func (param i32 i32))))"#,
+        );
+        let err =
+            call_plugin("log", &logging, PluginKind::Exporter, &HashSet::new(), "{}").unwrap_err();
+        assert!(err.contains("'log' capability"));
+
+        let spinning = write_module(
+            &dir,
+            "spin.wasm",
+            r#"(module
+                 (memory (export "memory") 1)
+                 (func (export "alloc") (param i32) (result i32) i32.const 0)
+                 (func (export "export_meeting") (param i32 i32) (result i64)
+                   (loop $forever (br $forever))
+                   i64.const 0))"#,
+        );
+        let err = call_plugin(
+            "spin",
+            &spinning,
+            PluginKind::Exporter,
+            &HashSet::new(),
+            "{}",
+        )
+        .unwrap_err();
+        assert!(err.contains("export_meeting failed"));
+    }
+}
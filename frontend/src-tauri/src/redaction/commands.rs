@@ -0,0 +1,127 @@
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Runtime};
+
+use super::detect::unredact;
+use super::{redact_for_export, save_settings, settings, RedactionSettings};
+use crate::api::MeetingTranscript;
+use crate::audit::{self, AuditAction};
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::redaction::RedactionMappingsRepository;
+use crate::encryption;
+use crate::state::AppState;
+
+#[tauri::command]
+pub async fn api_get_redaction_settings() -> Result<RedactionSettings, String> {
+    Ok(settings())
+}
+
+/// Reversible mode requires encryption to be enabled
+#[tauri::command]
+pub async fn api_save_redaction_settings<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    settings: RedactionSettings,
+) -> Result<RedactionSettings, String> {
+    log_info!(
+        "api_save_redaction_settings called: enabled={}, reversible={}",
+        settings.enabled,
+        settings.reversible
+    );
+    let pool = state.db_manager.pool();
+    save_settings(pool, settings).await.map_err(|e| {
+        log_error!("Failed to save redaction settings: {}", e);
+        e
+    })?;
+    let saved = super::settings();
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "redaction",
+        None,
+        serde_json::json!({
+            "enabled": saved.enabled,
+            "redact_exports": saved.redact_exports,
+            "reversible": saved.reversible,
+            "kinds": saved.kinds,
+        }),
+    )
+    .await;
+    Ok(saved)
+}
+
+/// Masks `text` with the configured kinds, for previews and frontend exports
+#[tauri::command]
+pub async fn api_redact_text(text: String) -> Result<String, String> {
+    Ok(redact_for_export(&text))
+}
+
+/// Returns the meeting's transcript with reversibly redacted values restored.
+/// Stored text stays redacted. Requires the encryption passphrase even when
+/// already unlocked.
+#[tauri::command]
+pub async fn api_unredact_meeting<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    passphrase: String,
+) -> Result<Vec<MeetingTranscript>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_unredact_meeting called for meeting_id: {}", meeting_id);
+    let pool = state.db_manager.pool();
+    encryption::verify_passphrase(pool, &passphrase)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let meeting = MeetingsRepository::get_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting {} not found", meeting_id))?;
+    let mut conn = pool.acquire().await.map_err(|e| e.to_string())?;
+    let entries = RedactionMappingsRepository::list_for_meeting(&mut conn, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load redaction mapping: {}", e))?;
+
+    audit::record(
+        pool,
+        AuditAction::Unredact,
+        "meeting",
+        Some(&meeting_id),
+        serde_json::json!({ "placeholders": entries.len() }),
+    )
+    .await;
+    Ok(meeting
+        .transcripts
+        .into_iter()
+        .map(|mut transcript| {
+            transcript.text = unredact(&transcript.text, &entries);
+            transcript
+        })
+        .collect())
+}
+
+/// Deletes every redaction mapping so existing redactions become permanent
+/// (needed before encryption can be turned off). Requires the passphrase.
+#[tauri::command]
+pub async fn api_discard_redaction_mappings<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    passphrase: String,
+) -> Result<u64, String> {
+    log_info!("api_discard_redaction_mappings called");
+    let pool = state.db_manager.pool();
+    encryption::verify_passphrase(pool, &passphrase)
+        .await
+        .map_err(|e| e.to_string())?;
+    let removed = RedactionMappingsRepository::delete_all(pool)
+        .await
+        .map_err(|e| format!("Failed to discard redaction mappings: {}", e))?;
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "redaction",
+        None,
+        serde_json::json!({ "discarded_mappings": removed }),
+    )
+    .await;
+    Ok(removed)
+}
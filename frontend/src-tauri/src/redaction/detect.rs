@@ -0,0 +1,365 @@
+//! Detection and masking of personal data in transcript text.
+//!
+//! Emails, phone numbers and card numbers are found by pattern (cards must
+//! pass the Luhn check). Names come from the rule-based entity extractor, the
+//! user's list of names and Japanese honorifics (`田中さん`). Matches are
+//! replaced by placeholders such as `[EMAIL_1]`; a [`RedactionMap`] keeps the
+//! same value on the same placeholder throughout a meeting.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::summary::entities::{extract_local, EntityKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PiiKind {
+    Email,
+    Phone,
+    CreditCard,
+    Name,
+}
+
+impl PiiKind {
+    pub const ALL: [PiiKind; 4] = [Self::Email, Self::Phone, Self::CreditCard, Self::Name];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::Phone => "phone",
+            Self::CreditCard => "credit_card",
+            Self::Name => "name",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == value)
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Email => "EMAIL",
+            Self::Phone => "PHONE",
+            Self::CreditCard => "CARD",
+            Self::Name => "NAME",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PiiMatch {
+    pub kind: PiiKind,
+    /// Byte range in the text
+    pub start: usize,
+    pub end: usize,
+}
+
+static EMAILS: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}")
+        .expect("email pattern")
+});
+
+/// 13-19 digits, optionally grouped by spaces or dashes
+static CARDS: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b\d(?:[ -]?\d){12,18}\b").expect("card pattern"));
+
+/// Digit runs with the usual separators; the digit count is checked separately
+static PHONES: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?:\+|\(|\b)[\d(][\d().\- ]{6,}\d\b").expect("phone pattern"));
+
+/// Japanese names are marked by the honorific that follows them
+static HONORIFIC_NAMES: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"([\p{Han}\p{Katakana}ー]{1,6})(?:さん|様|さま|氏|くん|君|ちゃん)")
+        .expect("honorific pattern")
+});
+
+const MIN_PHONE_DIGITS: usize = 9;
+const MAX_PHONE_DIGITS: usize = 15;
+
+fn digits(text: &str) -> String {
+    text.chars().filter(char::is_ascii_digit).collect()
+}
+
+fn passes_luhn(number: &str) -> bool {
+    let mut sum = 0;
+    for (i, c) in number.chars().rev().enumerate() {
+        let Some(mut digit) = c.to_digit(10) else {
+            return false;
+        };
+        if i % 2 == 1 {
+            digit *= 2;
+            if digit > 9 {
+                digit -= 9;
+            }
+        }
+        sum += digit;
+    }
+    sum % 10 == 0
+}
+
+/// Byte ranges of `name` as a whole word, case-insensitive. Word boundaries
+/// only apply at Latin letters and digits; CJK names run into the next word.
+fn find_name(text: &str, name: &str, matches: &mut Vec<PiiMatch>) {
+    let name = name.trim();
+    if name.chars().count() < 2 {
+        return;
+    }
+    let boundary = |c: Option<char>| {
+        if c.is_some_and(|c| c.is_ascii_alphanumeric()) {
+            r"\b"
+        } else {
+            ""
+        }
+    };
+    let pattern = format!(
+        "(?i){}{}{}",
+        boundary(name.chars().next()),
+        regex::escape(name),
+        boundary(name.chars().last())
+    );
+    let Ok(pattern) = Regex::new(&pattern) else {
+        return;
+    };
+    matches.extend(pattern.find_iter(text).map(|m| PiiMatch {
+        kind: PiiKind::Name,
+        start: m.start(),
+        end: m.end(),
+    }));
+}
+
+/// Non-overlapping matches of the enabled `kinds`, in text order.
+/// `known_names` are always treated as names (participants, the user's list).
+pub fn detect(text: &str, kinds: &[PiiKind], known_names: &[String]) -> Vec<PiiMatch> {
+    let mut matches = Vec::new();
+    let enabled = |kind: PiiKind| kinds.contains(&kind);
+
+    if enabled(PiiKind::Email) {
+        matches.extend(EMAILS.find_iter(text).map(|m| PiiMatch {
+            kind: PiiKind::Email,
+            start: m.start(),
+            end: m.end(),
+        }));
+    }
+    if enabled(PiiKind::CreditCard) {
+        matches.extend(
+            CARDS
+                .find_iter(text)
+                .filter(|m| passes_luhn(&digits(m.as_str())))
+                .map(|m| PiiMatch {
+                    kind: PiiKind::CreditCard,
+                    start: m.start(),
+                    end: m.end(),
+                }),
+        );
+    }
+    if enabled(PiiKind::Phone) {
+        matches.extend(
+            PHONES
+                .find_iter(text)
+                .filter(|m| {
+                    let count = digits(m.as_str()).len();
+                    (MIN_PHONE_DIGITS..=MAX_PHONE_DIGITS).contains(&count)
+                })
+                .map(|m| PiiMatch {
+                    kind: PiiKind::Phone,
+                    start: m.start(),
+                    end: m.end(),
+                }),
+        );
+    }
+    if enabled(PiiKind::Name) {
+        for name in known_names {
+            find_name(text, name, &mut matches);
+        }
+        for entity in extract_local(text, known_names) {
+            if entity.kind == EntityKind::Person {
+                find_name(text, &entity.name, &mut matches);
+            }
+        }
+        for caps in HONORIFIC_NAMES.captures_iter(text) {
+            let name = caps.get(1).expect("name group");
+            matches.push(PiiMatch {
+                kind: PiiKind::Name,
+                start: name.start(),
+                end: name.end(),
+            });
+        }
+    }
+
+    // Earliest first, longest first at the same position; drop overlaps
+    matches.sort_by_key(|m| (m.start, std::cmp::Reverse(m.end)));
+    let mut kept: Vec<PiiMatch> = Vec::with_capacity(matches.len());
+    for m in matches {
+        if kept.last().is_some_and(|last| m.start < last.end) {
+            continue;
+        }
+        kept.push(m);
+    }
+    kept
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MappingEntry {
+    pub placeholder: String,
+    pub kind: PiiKind,
+    pub original: String,
+}
+
+/// Placeholder assignments for one meeting. A reversible map numbers the
+/// placeholders and remembers the originals; an irreversible one uses a bare
+/// `[EMAIL]` and remembers nothing.
+#[derive(Debug, Clone, Default)]
+pub struct RedactionMap {
+    reversible: bool,
+    entries: Vec<MappingEntry>,
+}
+
+/// Comparison key, so "555-123-4567" and "555 123 4567" share a placeholder
+fn match_key(kind: PiiKind, value: &str) -> String {
+    match kind {
+        PiiKind::Phone | PiiKind::CreditCard => digits(value),
+        PiiKind::Email | PiiKind::Name => value.to_lowercase(),
+    }
+}
+
+impl RedactionMap {
+    pub fn reversible(entries: Vec<MappingEntry>) -> Self {
+        Self {
+            reversible: true,
+            entries,
+        }
+    }
+
+    pub fn irreversible() -> Self {
+        Self::default()
+    }
+
+    pub fn entries(&self) -> &[MappingEntry] {
+        &self.entries
+    }
+
+    fn placeholder_for(&mut self, kind: PiiKind, original: &str) -> String {
+        if !self.reversible {
+            return format!("[{}]", kind.label());
+        }
+        let key = match_key(kind, original);
+        if let Some(entry) = self
+            .entries
+            .iter()
+            .find(|e| e.kind == kind && match_key(kind, &e.original) == key)
+        {
+            return entry.placeholder.clone();
+        }
+        let number = self.entries.iter().filter(|e| e.kind == kind).count() + 1;
+        let placeholder = format!("[{}_{}]", kind.label(), number);
+        self.entries.push(MappingEntry {
+            placeholder: placeholder.clone(),
+            kind,
+            original: original.to_string(),
+        });
+        placeholder
+    }
+
+    /// Masks the detected values in `text`; returns the text and how many
+    /// values were masked
+    pub fn redact(
+        &mut self,
+        text: &str,
+        kinds: &[PiiKind],
+        known_names: &[String],
+    ) -> (String, usize) {
+        let matches = detect(text, kinds, known_names);
+        if matches.is_empty() {
+            return (text.to_string(), 0);
+        }
+        let mut redacted = String::with_capacity(text.len());
+        let mut cursor = 0;
+        for m in &matches {
+            redacted.push_str(&text[cursor..m.start]);
+            redacted.push_str(&self.placeholder_for(m.kind, &text[m.start..m.end]));
+            cursor = m.end;
+        }
+        redacted.push_str(&text[cursor..]);
+        (redacted, matches.len())
+    }
+}
+
+/// Puts the original values back in place of their placeholders
+pub fn unredact(text: &str, entries: &[MappingEntry]) -> String {
+    let mut restored = text.to_string();
+    // [NAME_12] before [NAME_1]
+    let mut entries: Vec<&MappingEntry> = entries.iter().collect();
+    entries.sort_by_key(|e| std::cmp::Reverse(e.placeholder.len()));
+    for entry in entries {
+        restored = restored.replace(&entry.placeholder, &entry.original);
+    }
+    restored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds_found(text: &str) -> Vec<(PiiKind, String)> {
+        detect(text, &PiiKind::ALL, &[])
+            .into_iter()
+            .map(|m| (m.kind, text[m.start..m.end].to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn detects_contact_details_and_cards() {
+        assert_eq!(
+            kinds_found("Mail jane@example.com or call +1 (555) 123-4567 today"),
+            vec![
+                (PiiKind::Email, "jane@example.com".to_string()),
+                (PiiKind::Phone, "+1 (555) 123-4567".to_string()),
+            ]
+        );
+        assert_eq!(
+            kinds_found("The card is 4111 1111 1111 1111, expiring soon"),
+            vec![(PiiKind::CreditCard, "4111 1111 1111 1111".to_string())]
+        );
+        // Fails Luhn and is too long for a phone number: left alone
+        assert!(kinds_found("Order 4111 1111 1111 1112 shipped").is_empty());
+        // Dates and times are not phone numbers
+        assert!(kinds_found("We met on 2024-01-15 at 10:30").is_empty());
+    }
+
+    #[test]
+    fn detects_names() {
+        let names = vec!["Priya Raman".to_string(), "鈴木".to_string()];
+        let text = "Dr. Silberman said priya raman will follow up with 田中さん and 鈴木部長.";
+        let found: Vec<String> = detect(text, &[PiiKind::Name], &names)
+            .into_iter()
+            .map(|m| text[m.start..m.end].to_string())
+            .collect();
+        assert_eq!(found, vec!["Silberman", "priya raman", "田中", "鈴木"]);
+    }
+
+    #[test]
+    fn reversible_map_reuses_placeholders_and_round_trips() {
+        let mut map = RedactionMap::reversible(Vec::new());
+        let text = "Call 555-123-4567 or 555 123 4567, or mail bob@example.com";
+        let (redacted, count) = map.redact(text, &PiiKind::ALL, &[]);
+        assert_eq!(redacted, "Call [PHONE_1] or [PHONE_1], or mail [EMAIL_1]");
+        assert_eq!(count, 3);
+        assert_eq!(map.entries().len(), 2);
+
+        let (second, _) = map.redact("Reply to alice@example.com", &PiiKind::ALL, &[]);
+        assert_eq!(second, "Reply to [EMAIL_2]");
+        assert_eq!(
+            unredact(&second, map.entries()),
+            "Reply to alice@example.com"
+        );
+    }
+
+    #[test]
+    fn irreversible_map_keeps_nothing() {
+        let mut map = RedactionMap::irreversible();
+        let (redacted, _) = map.redact("mail bob@example.com", &[PiiKind::Email], &[]);
+        assert_eq!(redacted, "mail [EMAIL]");
+        assert!(map.entries().is_empty());
+    }
+}
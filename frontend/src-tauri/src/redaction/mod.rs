@@ -0,0 +1,195 @@
+/// Redaction module - optional masking of personal data in transcripts
+///
+/// This module contains:
+/// - Detection of emails, phone numbers, card numbers and names (`detect`)
+/// - The redaction settings and their in-memory copy used on the write path
+/// - Redaction of transcript text before it is stored, and of exports
+/// - Reversible mode: numbered placeholders whose originals are kept in
+///   `redaction_mappings`, encrypted with the database encryption key
+/// - Authorized un-redaction, which requires the encryption passphrase
+/// - Tauri commands for frontend integration
+///
+/// Without reversible mode the values are gone for good; placeholders are
+/// bare (`[EMAIL]`) and nothing is stored.
+pub mod commands;
+pub mod detect;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sqlx::{Error as SqlxError, SqliteConnection, SqlitePool};
+use std::sync::RwLock;
+
+use crate::database::repositories::integration::IntegrationSettingsRepository;
+use crate::database::repositories::redaction::RedactionMappingsRepository;
+use detect::{PiiKind, RedactionMap};
+
+pub const CONFIG_ID: &str = "redaction";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RedactionSettings {
+    /// Redact transcripts before they are stored
+    #[serde(default)]
+    pub enabled: bool,
+    /// Redact transcripts in data exports
+    #[serde(default)]
+    pub redact_exports: bool,
+    #[serde(default = "default_kinds")]
+    pub kinds: Vec<PiiKind>,
+    /// Keep an encrypted mapping so redactions can be undone by the owner
+    #[serde(default)]
+    pub reversible: bool,
+    /// Names to always mask (colleagues, clients), in addition to detected ones
+    #[serde(default)]
+    pub names: Vec<String>,
+}
+
+fn default_kinds() -> Vec<PiiKind> {
+    PiiKind::ALL.to_vec()
+}
+
+impl Default for RedactionSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            redact_exports: false,
+            kinds: default_kinds(),
+            reversible: false,
+            names: Vec::new(),
+        }
+    }
+}
+
+static SETTINGS: Lazy<RwLock<RedactionSettings>> =
+    Lazy::new(|| RwLock::new(RedactionSettings::default()));
+
+pub fn settings() -> RedactionSettings {
+    SETTINGS.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+fn set_settings(settings: RedactionSettings) {
+    *SETTINGS.write().unwrap_or_else(|e| e.into_inner()) = settings;
+}
+
+/// Loads the settings for a freshly opened database
+pub async fn load_state(pool: &SqlitePool) -> Result<(), SqlxError> {
+    let settings = match IntegrationSettingsRepository::get_config(pool, CONFIG_ID).await? {
+        Some(raw) => serde_json::from_str(&raw).unwrap_or_else(|e| {
+            log::warn!(
+                "Stored redaction settings are invalid, using defaults: {}",
+                e
+            );
+            RedactionSettings::default()
+        }),
+        None => RedactionSettings::default(),
+    };
+    if settings.enabled {
+        log::info!(
+            "Transcript redaction is on (reversible: {})",
+            settings.reversible
+        );
+    }
+    set_settings(settings);
+    Ok(())
+}
+
+pub async fn save_settings(pool: &SqlitePool, settings: RedactionSettings) -> Result<(), String> {
+    if settings.reversible && !crate::encryption::is_enabled() {
+        return Err(
+            "Reversible redaction keeps the original values encrypted; enable encryption first"
+                .to_string(),
+        );
+    }
+    let mut settings = settings;
+    settings.names = settings
+        .names
+        .iter()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect();
+    let mut kinds = Vec::with_capacity(settings.kinds.len());
+    for kind in settings.kinds {
+        if !kinds.contains(&kind) {
+            kinds.push(kind);
+        }
+    }
+    settings.kinds = kinds;
+
+    let raw = serde_json::to_string(&settings)
+        .map_err(|e| format!("Failed to serialize redaction settings: {}", e))?;
+    IntegrationSettingsRepository::save_config(pool, CONFIG_ID, &raw)
+        .await
+        .map_err(|e| format!("Failed to save redaction settings: {}", e))?;
+    set_settings(settings);
+    Ok(())
+}
+
+/// Reversible only while encryption is on; otherwise nothing could be stored
+/// safely and the redaction becomes permanent
+fn is_reversible(settings: &RedactionSettings) -> bool {
+    if settings.reversible && !crate::encryption::is_enabled() {
+        log::warn!("Encryption is off; redacting without keeping originals");
+        return false;
+    }
+    settings.reversible
+}
+
+/// Redacts texts about to be stored for `meeting_id`, recording any new
+/// placeholders on `conn`. Returns None when redaction is off.
+pub async fn redact_for_storage<'a>(
+    conn: &mut SqliteConnection,
+    meeting_id: &str,
+    texts: impl IntoIterator<Item = &'a str>,
+) -> Result<Option<Vec<String>>, SqlxError> {
+    let settings = settings();
+    if !settings.enabled {
+        return Ok(None);
+    }
+
+    let reversible = is_reversible(&settings);
+    let mut map = if reversible {
+        RedactionMap::reversible(
+            RedactionMappingsRepository::list_for_meeting(&mut *conn, meeting_id).await?,
+        )
+    } else {
+        RedactionMap::irreversible()
+    };
+    let known = map.entries().len();
+
+    let mut masked = 0;
+    let redacted = texts
+        .into_iter()
+        .map(|text| {
+            let (text, count) = map.redact(text, &settings.kinds, &settings.names);
+            masked += count;
+            text
+        })
+        .collect();
+    if reversible {
+        RedactionMappingsRepository::insert(&mut *conn, meeting_id, &map.entries()[known..])
+            .await?;
+    }
+    if masked > 0 {
+        log::info!("Redacted {} values in meeting {}", masked, meeting_id);
+    }
+    Ok(Some(redacted))
+}
+
+/// Redacts text that isn't tied to a meeting yet (keyword hits during a
+/// recording). Always irreversible.
+pub fn redact_unattached(text: &str) -> String {
+    let settings = settings();
+    if !settings.enabled {
+        return text.to_string();
+    }
+    RedactionMap::irreversible()
+        .redact(text, &settings.kinds, &settings.names)
+        .0
+}
+
+/// Redacts text for export with the configured kinds; irreversible
+pub fn redact_for_export(text: &str) -> String {
+    let settings = settings();
+    RedactionMap::irreversible()
+        .redact(text, &settings.kinds, &settings.names)
+        .0
+}
@@ -0,0 +1,78 @@
+use log::info as log_info;
+use tauri::{AppHandle, Runtime};
+
+use super::{generate_token, load_config, running_port, save_config, start_server, stop_server};
+use crate::state::AppState;
+
+async fn status_json(port: u16, token: &str, enabled: bool) -> serde_json::Value {
+    let running = running_port().await;
+    serde_json::json!({
+        "enabled": enabled,
+        "running": running.is_some(),
+        "port": running.unwrap_or(port),
+        "url": running.map(|p| format!("http://127.0.0.1:{}/api/v1", p)),
+        "token": token,
+    })
+}
+
+#[tauri::command]
+pub async fn api_get_http_server_status<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_get_http_server_status called");
+    let config = load_config(state.db_manager.pool()).await?;
+    Ok(status_json(config.port, &config.token, config.enabled).await)
+}
+
+/// Enables or disables the local REST server. The setting persists across restarts.
+#[tauri::command]
+pub async fn api_set_http_server_enabled<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    enabled: bool,
+    port: Option<u16>,
+) -> Result<serde_json::Value, String> {
+    log_info!(
+        "api_set_http_server_enabled called, enabled: {}, port: {:?}",
+        enabled,
+        port
+    );
+    let pool = state.db_manager.pool();
+    let mut config = load_config(pool).await?;
+    config.enabled = enabled;
+    if let Some(port) = port {
+        if port < 1024 {
+            return Err("Port must be 1024 or higher".to_string());
+        }
+        config.port = port;
+    }
+
+    if enabled {
+        start_server(pool.clone(), &config).await?;
+    } else {
+        stop_server().await;
+    }
+    save_config(pool, &config).await?;
+
+    Ok(status_json(config.port, &config.token, config.enabled).await)
+}
+
+/// Generates a new bearer token, invalidating the old one immediately
+#[tauri::command]
+pub async fn api_regenerate_http_server_token<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_regenerate_http_server_token called");
+    let pool = state.db_manager.pool();
+    let mut config = load_config(pool).await?;
+    config.token = generate_token();
+    save_config(pool, &config).await?;
+
+    if running_port().await.is_some() {
+        start_server(pool.clone(), &config).await?;
+    }
+
+    Ok(status_json(config.port, &config.token, config.enabled).await)
+}
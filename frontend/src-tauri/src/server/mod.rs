@@ -0,0 +1,137 @@
+/// Local REST server - read-only HTTP access to the meeting archive
+///
+/// This module contains:
+/// - An optional axum server bound to 127.0.0.1, protected by a generated bearer token
+/// - Read endpoints for meetings, transcripts, summaries and search (see `routes`)
+/// - Tauri commands for enabling/disabling the server and rotating the token
+///
+/// Configuration is stored in `integration_settings` under the id "http_server".
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::database::repositories::integration::IntegrationSettingsRepository;
+
+pub mod commands;
+pub mod routes;
+
+pub const SERVER_SETTINGS_ID: &str = "http_server";
+/// 5167 is taken by the Python backend
+pub const DEFAULT_PORT: u16 = 5180;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerConfig {
+    pub enabled: bool,
+    pub port: u16,
+    pub token: String,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: DEFAULT_PORT,
+            token: generate_token(),
+        }
+    }
+}
+
+struct RunningServer {
+    port: u16,
+    shutdown: CancellationToken,
+}
+
+static RUNNING_SERVER: Lazy<Mutex<Option<RunningServer>>> = Lazy::new(|| Mutex::new(None));
+
+/// 64 hex chars of randomness, prefixed so leaked tokens are easy to grep for
+pub fn generate_token() -> String {
+    format!(
+        "mm_{}{}",
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple()
+    )
+}
+
+pub async fn load_config(pool: &SqlitePool) -> Result<ServerConfig, String> {
+    let raw = IntegrationSettingsRepository::get_config(pool, SERVER_SETTINGS_ID)
+        .await
+        .map_err(|e| format!("Failed to load server settings: {}", e))?;
+    match raw {
+        Some(raw) => serde_json::from_str(&raw)
+            .map_err(|e| format!("Stored server settings are invalid: {}", e)),
+        None => {
+            // Persist the generated token so it stays stable until rotated
+            let config = ServerConfig::default();
+            save_config(pool, &config).await?;
+            Ok(config)
+        }
+    }
+}
+
+pub async fn save_config(pool: &SqlitePool, config: &ServerConfig) -> Result<(), String> {
+    let raw = serde_json::to_string(config)
+        .map_err(|e| format!("Failed to serialize server settings: {}", e))?;
+    IntegrationSettingsRepository::save_config(pool, SERVER_SETTINGS_ID, &raw)
+        .await
+        .map_err(|e| format!("Failed to save server settings: {}", e))
+}
+
+/// Returns the port the server is listening on, if running
+pub async fn running_port() -> Option<u16> {
+    RUNNING_SERVER.lock().await.as_ref().map(|s| s.port)
+}
+
+/// Starts (or restarts) the server with the given config. Only binds to localhost.
+pub async fn start_server(pool: SqlitePool, config: &ServerConfig) -> Result<u16, String> {
+    stop_server().await;
+
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", config.port))
+        .await
+        .map_err(|e| format!("Failed to bind 127.0.0.1:{}: {}", config.port, e))?;
+    let port = listener
+        .local_addr()
+        .map(|a| a.port())
+        .unwrap_or(config.port);
+
+    let shutdown = CancellationToken::new();
+    let app = routes::router(pool, config.token.clone());
+    let shutdown_signal = shutdown.clone();
+
+    tokio::spawn(async move {
+        let result = axum::serve(listener, app)
+            .with_graceful_shutdown(async move { shutdown_signal.cancelled().await })
+            .await;
+        match result {
+            Ok(()) => log::info!("Local REST server on port {} stopped", port),
+            Err(e) => log::error!("Local REST server on port {} failed: {}", port, e),
+        }
+    });
+
+    *RUNNING_SERVER.lock().await = Some(RunningServer { port, shutdown });
+    log::info!("Local REST server listening on http://127.0.0.1:{}", port);
+    Ok(port)
+}
+
+pub async fn stop_server() {
+    if let Some(server) = RUNNING_SERVER.lock().await.take() {
+        server.shutdown.cancel();
+        log::info!("Stopping local REST server on port {}", server.port);
+    }
+}
+
+/// Called once at startup: starts the server if the user enabled it previously
+pub async fn start_if_enabled(pool: SqlitePool) {
+    match load_config(&pool).await {
+        Ok(config) if config.enabled => {
+            if let Err(e) = start_server(pool, &config).await {
+                log::error!("Failed to start local REST server: {}", e);
+            }
+        }
+        Ok(_) => {}
+        Err(e) => log::warn!("Skipping local REST server startup: {}", e),
+    }
+}
@@ -0,0 +1,198 @@
+//! HTTP routes for the local REST server.
+//!
+//! All endpoints except `/api/v1/health` require `Authorization: Bearer <token>`.
+//!
+//! - `GET /api/v1/health`
+//! - `GET /api/v1/meetings`
+//! - `GET /api/v1/meetings/{id}` - metadata plus summary markdown
+//! - `GET /api/v1/meetings/{id}/transcripts?limit=&offset=`
+//! - `GET /api/v1/search?q=&limit=&offset=&ranking=recency|relevance`
+
+use axum::extract::{Path, Query, Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+
+use crate::database::repositories::{
+    meeting::MeetingsRepository, search::SearchRanking, summary::SummaryProcessesRepository,
+    transcript::TranscriptsRepository,
+};
+
+const MAX_PAGE_SIZE: i64 = 500;
+
+#[derive(Clone)]
+struct ServerState {
+    pool: SqlitePool,
+    token: Arc<str>,
+}
+
+type ApiResult = Result<Json<Value>, (StatusCode, Json<Value>)>;
+
+fn error(status: StatusCode, message: impl Into<String>) -> (StatusCode, Json<Value>) {
+    (status, Json(json!({ "error": message.into() })))
+}
+
+fn internal(e: impl std::fmt::Display) -> (StatusCode, Json<Value>) {
+    log::error!("Local REST server error: {}", e);
+    error(StatusCode::INTERNAL_SERVER_ERROR, "internal error")
+}
+
+/// Compares tokens without short-circuiting on the first differing byte
+pub fn tokens_match(provided: &str, expected: &str) -> bool {
+    if provided.len() != expected.len() {
+        return false;
+    }
+    provided
+        .bytes()
+        .zip(expected.bytes())
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+        == 0
+}
+
+async fn require_token(State(state): State<ServerState>, request: Request, next: Next) -> Response {
+    let authorized = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(|t| tokens_match(t.trim(), &state.token))
+        .unwrap_or(false);
+
+    if !authorized {
+        return error(StatusCode::UNAUTHORIZED, "missing or invalid bearer token").into_response();
+    }
+    next.run(request).await
+}
+
+pub fn router(pool: SqlitePool, token: String) -> Router {
+    let state = ServerState {
+        pool,
+        token: Arc::from(token),
+    };
+
+    let protected = Router::new()
+        .route("/meetings", get(list_meetings))
+        .route("/meetings/{id}", get(get_meeting))
+        .route("/meetings/{id}/transcripts", get(get_transcripts))
+        .route("/search", get(search))
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_token));
+
+    let api = Router::new()
+        .route("/health", get(health))
+        .merge(protected)
+        .with_state(state);
+
+    Router::new().nest("/api/v1", api)
+}
+
+async fn health() -> Json<Value> {
+    Json(json!({ "status": "ok" }))
+}
+
+async fn list_meetings(State(state): State<ServerState>) -> ApiResult {
+    let meetings = MeetingsRepository::get_meetings(&state.pool)
+        .await
+        .map_err(internal)?;
+    Ok(Json(json!({ "meetings": meetings })))
+}
+
+async fn get_meeting(State(state): State<ServerState>, Path(id): Path<String>) -> ApiResult {
+    let meeting = MeetingsRepository::get_meeting_metadata(&state.pool, &id)
+        .await
+        .map_err(internal)?
+        .ok_or_else(|| error(StatusCode::NOT_FOUND, "meeting not found"))?;
+
+    let summary = SummaryProcessesRepository::get_summary_data(&state.pool, &id)
+        .await
+        .map_err(internal)?;
+    let summary_markdown = summary
+        .as_ref()
+        .and_then(|p| p.result.as_deref())
+        .and_then(|r| serde_json::from_str::<Value>(r).ok())
+        .and_then(|v| v.get("markdown").cloned());
+
+    Ok(Json(json!({
+        "meeting": meeting,
+        "summary": {
+            "status": summary.as_ref().map(|p| p.status.clone()),
+            "markdown": summary_markdown,
+        }
+    })))
+}
+
+#[derive(Debug, Deserialize)]
+struct PageParams {
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+async fn get_transcripts(
+    State(state): State<ServerState>,
+    Path(id): Path<String>,
+    Query(params): Query<PageParams>,
+) -> ApiResult {
+    let limit = params.limit.unwrap_or(100).clamp(1, MAX_PAGE_SIZE);
+    let offset = params.offset.unwrap_or(0).max(0);
+
+    let (transcripts, total) =
+        MeetingsRepository::get_meeting_transcripts_paginated(&state.pool, &id, limit, offset)
+            .await
+            .map_err(internal)?;
+
+    Ok(Json(json!({
+        "transcripts": transcripts,
+        "total": total,
+        "limit": limit,
+        "offset": offset,
+    })))
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchParams {
+    q: String,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    ranking: Option<SearchRanking>,
+}
+
+async fn search(State(state): State<ServerState>, Query(params): Query<SearchParams>) -> ApiResult {
+    if params.q.trim().is_empty() {
+        return Err(error(StatusCode::BAD_REQUEST, "query parameter 'q' is required"));
+    }
+    let limit = params.limit.unwrap_or(100).clamp(1, MAX_PAGE_SIZE) as usize;
+    let offset = params.offset.unwrap_or(0).max(0) as usize;
+    let page = TranscriptsRepository::search_transcripts_page(
+        &state.pool,
+        params.q.trim(),
+        params.ranking.unwrap_or_default(),
+        offset,
+        limit,
+    )
+    .await
+    .map_err(internal)?;
+    Ok(Json(json!({
+        "results": page.results,
+        "total": page.total,
+        "limit": page.limit,
+        "offset": page.offset,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tokens_match;
+
+    #[test]
+    fn token_comparison() {
+        assert!(tokens_match("mm_abc", "mm_abc"));
+        assert!(!tokens_match("mm_abd", "mm_abc"));
+        assert!(!tokens_match("mm_ab", "mm_abc"));
+        assert!(!tokens_match("", "mm_abc"));
+    }
+}
@@ -0,0 +1,317 @@
+//! Graceful shutdown for app exit and updates.
+//!
+//! The coordinator lives in [`AppState`](crate::state::AppState). When the app
+//! quits (or the frontend is about to install an update and relaunch) it:
+//! 1. refuses new tracked operations and cancels long-running jobs such as imports
+//! 2. stops an active recording, which finalizes the audio file and writes the
+//!    remaining transcript segments to the recording folder
+//! 3. drains the transcript writer queue and waits for in-flight DB operations
+//! 4. stops the local HTTP server and the summary sidecar
+//! 5. checkpoints the WAL and closes the pool
+//!
+//! Each step is bounded by a timeout so a stuck component can't block exit.
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tokio::sync::{Mutex, Notify};
+use tokio_util::sync::CancellationToken;
+
+use crate::state::AppState;
+
+pub const SHUTDOWN_EVENT: &str = "app-shutting-down";
+
+const RECORDING_STOP_TIMEOUT: Duration = Duration::from_secs(120);
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShutdownReason {
+    Exit,
+    Update,
+    /// Relaunching into another workspace
+    Restart,
+}
+
+/// What the shutdown did, for logs and the update dialog
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ShutdownReport {
+    pub recording_stopped: bool,
+    /// Tracked operations still running when the drain timed out
+    pub abandoned_operations: usize,
+    pub database_closed: bool,
+}
+
+struct Inner {
+    shutting_down: AtomicBool,
+    in_flight: AtomicUsize,
+    idle: Notify,
+    cancel: CancellationToken,
+    /// Held for the whole run so concurrent callers wait for the first one
+    report: Mutex<Option<ShutdownReport>>,
+}
+
+/// Cheap to clone; all clones share state
+#[derive(Clone)]
+pub struct ShutdownCoordinator {
+    inner: Arc<Inner>,
+}
+
+/// Marks an operation that shutdown should wait for; released on drop
+pub struct OperationGuard {
+    inner: Arc<Inner>,
+}
+
+impl Drop for OperationGuard {
+    fn drop(&mut self) {
+        if self.inner.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.inner.idle.notify_waiters();
+        }
+    }
+}
+
+impl Default for ShutdownCoordinator {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                shutting_down: AtomicBool::new(false),
+                in_flight: AtomicUsize::new(0),
+                idle: Notify::new(),
+                cancel: CancellationToken::new(),
+                report: Mutex::new(None),
+            }),
+        }
+    }
+}
+
+impl ShutdownCoordinator {
+    pub fn is_shutting_down(&self) -> bool {
+        self.inner.shutting_down.load(Ordering::SeqCst)
+    }
+
+    /// Whether shutdown has already run to completion
+    pub fn is_complete(&self) -> bool {
+        self.inner
+            .report
+            .try_lock()
+            .map(|report| report.is_some())
+            .unwrap_or(false)
+    }
+
+    /// Tracks a DB write or other short operation that must finish before
+    /// the pool closes. Fails once shutdown has begun.
+    pub fn begin_operation(&self, name: &str) -> Result<OperationGuard, String> {
+        self.inner.in_flight.fetch_add(1, Ordering::SeqCst);
+        let guard = OperationGuard {
+            inner: self.inner.clone(),
+        };
+        if self.is_shutting_down() {
+            log::warn!("Rejected '{}': application is shutting down", name);
+            return Err("Application is shutting down".to_string());
+        }
+        Ok(guard)
+    }
+
+    /// Cancelled when shutdown begins. Long-running jobs (imports,
+    /// re-transcription) should stop cleanly when it fires.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.inner.cancel.child_token()
+    }
+
+    /// Runs the shutdown sequence once; later calls wait for and return the
+    /// first run's report
+    pub async fn shutdown<R: Runtime>(
+        &self,
+        app: &AppHandle<R>,
+        state: &AppState,
+        reason: ShutdownReason,
+    ) -> ShutdownReport {
+        let mut slot = self.inner.report.lock().await;
+        if let Some(report) = slot.as_ref() {
+            return report.clone();
+        }
+
+        log::info!("Shutdown started ({:?})", reason);
+        self.inner.shutting_down.store(true, Ordering::SeqCst);
+        self.inner.cancel.cancel();
+        let _ = app.emit(SHUTDOWN_EVENT, reason);
+
+        let mut report = ShutdownReport {
+            recording_stopped: finalize_recording(app).await,
+            ..Default::default()
+        };
+
+        // Anything queued by the recording stop (or still in flight) goes first
+        if let Ok(writer) = state.db_manager.transcript_writer() {
+            match tokio::time::timeout(DRAIN_TIMEOUT, writer.flush()).await {
+                Ok(Ok(())) => log::info!("Transcript writer drained"),
+                Ok(Err(e)) => log::warn!("Transcript writer unavailable during shutdown: {}", e),
+                Err(_) => log::warn!("Timed out draining the transcript writer"),
+            }
+        }
+        report.abandoned_operations = self.wait_for_operations().await;
+
+        crate::server::stop_server().await;
+        if let Err(e) = crate::summary::summary_engine::force_shutdown_sidecar().await {
+            log::error!("Failed to force shutdown sidecar: {}", e);
+        }
+
+        match state.db_manager.cleanup().await {
+            Ok(()) => report.database_closed = true,
+            Err(e) => log::error!("Failed to cleanup database: {}", e),
+        }
+
+        log::info!("Shutdown complete: {:?}", report);
+        *slot = Some(report.clone());
+        report
+    }
+
+    /// Waits for tracked operations to finish; returns how many were still
+    /// running at the timeout
+    async fn wait_for_operations(&self) -> usize {
+        let wait = async {
+            loop {
+                let idle = self.inner.idle.notified();
+                if self.inner.in_flight.load(Ordering::SeqCst) == 0 {
+                    return;
+                }
+                idle.await;
+            }
+        };
+        if tokio::time::timeout(DRAIN_TIMEOUT, wait).await.is_err() {
+            let remaining = self.inner.in_flight.load(Ordering::SeqCst);
+            log::warn!(
+                "Timed out waiting for {} in-flight operations; closing anyway",
+                remaining
+            );
+            return remaining;
+        }
+        0
+    }
+}
+
+/// Stops an active recording the same way the tray does, so the audio file is
+/// finalized and the transcript is kept in the recording folder, where
+/// transcript recovery finds it on the next launch.
+async fn finalize_recording<R: Runtime>(app: &AppHandle<R>) -> bool {
+    if !crate::audio::recording_commands::is_recording().await {
+        return false;
+    }
+    log::info!("Stopping active recording before shutdown");
+
+    let save_path = match app.path().app_data_dir() {
+        Ok(dir) => {
+            let timestamp = chrono::Local::now().format("%Y-%m-%dT%H-%M-%S");
+            dir.join(format!("recording-{}.wav", timestamp))
+        }
+        Err(e) => {
+            log::error!("Failed to get app data dir for recording stop: {}", e);
+            return false;
+        }
+    };
+    let stop = crate::audio::recording_commands::stop_recording(
+        app.clone(),
+        crate::audio::recording_commands::RecordingArgs {
+            save_path: save_path.to_string_lossy().to_string(),
+        },
+    );
+    match tokio::time::timeout(RECORDING_STOP_TIMEOUT, stop).await {
+        Ok(Ok(())) => {
+            log::info!("Recording finalized before shutdown");
+            true
+        }
+        Ok(Err(e)) => {
+            log::error!("Failed to stop recording during shutdown: {}", e);
+            false
+        }
+        Err(_) => {
+            log::error!("Timed out stopping recording during shutdown");
+            false
+        }
+    }
+}
+
+/// Runs shutdown ahead of an update install/relaunch, which would otherwise
+/// skip the exit handlers
+#[tauri::command]
+pub async fn api_prepare_for_shutdown<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    reason: Option<ShutdownReason>,
+) -> Result<ShutdownReport, String> {
+    log::info!("api_prepare_for_shutdown called: {:?}", reason);
+    Ok(state
+        .shutdown
+        .shutdown(&app, &state, reason.unwrap_or(ShutdownReason::Update))
+        .await)
+}
+
+/// Called when an update couldn't be installed after
+/// [`api_prepare_for_shutdown`]. Shutdown closed the database and cancelled
+/// the job manager for good, so the app restarts to open them again. Does
+/// nothing when no shutdown ran.
+#[tauri::command]
+pub async fn api_recover_from_failed_update<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), String> {
+    if !state.shutdown.is_shutting_down() {
+        return Ok(());
+    }
+    log::warn!("The update was not installed; restarting to reopen the database");
+    app.restart()
+}
+
+/// Used by the run loop on an exit request: false when shutdown still has to
+/// run first (or there is no app state to shut down)
+pub fn shutdown_pending<R: Runtime>(app: &AppHandle<R>) -> bool {
+    app.try_state::<AppState>()
+        .is_some_and(|state| !state.shutdown.is_complete())
+}
+
+/// Exit-request handler: defers the exit until shutdown has finished
+pub fn handle_exit_request<R: Runtime>(app: &AppHandle<R>, api: &tauri::ExitRequestApi) {
+    if !shutdown_pending(app) {
+        return;
+    }
+    api.prevent_exit();
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Some(state) = app.try_state::<AppState>() {
+            state
+                .shutdown
+                .shutdown(&app, &state, ShutdownReason::Exit)
+                .await;
+        }
+        app.exit(0);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn drains_operations_and_rejects_new_ones() {
+        let coordinator = ShutdownCoordinator::default();
+        let guard = coordinator.begin_operation("save").unwrap();
+        let token = coordinator.cancellation_token();
+
+        coordinator
+            .inner
+            .shutting_down
+            .store(true, Ordering::SeqCst);
+        coordinator.inner.cancel.cancel();
+        assert!(token.is_cancelled());
+        assert!(coordinator.begin_operation("late").is_err());
+
+        let release = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            drop(guard);
+        });
+        assert_eq!(coordinator.wait_for_operations().await, 0);
+        release.await.unwrap();
+    }
+}
@@ -1,5 +1,32 @@
 use crate::database::manager::DatabaseManager;
+use crate::database::settings_cache::SettingsCache;
+use crate::jobs::JobManager;
+use crate::api::debounce::SaveDebouncer;
+use crate::locks::MeetingLocks;
+use crate::shutdown::ShutdownCoordinator;
 
 pub struct AppState {
     pub db_manager: DatabaseManager,
+    pub settings_cache: SettingsCache,
+    pub shutdown: ShutdownCoordinator,
+    pub jobs: JobManager,
+    /// Meetings with a recording, import or re-transcription in progress
+    pub meeting_locks: MeetingLocks,
+    /// Coalesces title and transcript saves sent in quick succession
+    pub save_debouncer: SaveDebouncer,
+}
+
+impl AppState {
+    pub fn new(db_manager: DatabaseManager) -> Self {
+        let shutdown = ShutdownCoordinator::default();
+        let jobs = JobManager::new(db_manager.pool().clone(), shutdown.cancellation_token());
+        Self {
+            db_manager,
+            settings_cache: SettingsCache::default(),
+            shutdown,
+            jobs,
+            meeting_locks: MeetingLocks::global(),
+            save_debouncer: SaveDebouncer::default(),
+        }
+    }
 }
@@ -0,0 +1,184 @@
+//! Extraction of action items from generated summary markdown.
+//!
+//! Templates ask the model for an "Action Items" section, either as a markdown
+//! table (`| **Owner** | Task | Due | ... |`) or as a bullet list. Both shapes are
+//! parsed here so items can be stored and tracked outside the summary text.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+static ACTION_ITEMS_HEADING: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^#{1,6}\s*\**\s*action\s+items?\b").unwrap());
+
+static BULLET_PREFIX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\s*(?:[-*+]|\d+[.)])\s+(?:\[[ xX]\]\s+)?").unwrap());
+
+static BOLD_OWNER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\*\*([^*]+?)\*\*\s*[:\-–—]?\s*(.+)$").unwrap());
+
+static DUE_SUFFIX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\s*\((?:due|by|deadline)[:\s]+([^)]+)\)\s*$").unwrap());
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtractedActionItem {
+    pub text: String,
+    pub owner: Option<String>,
+    pub due_date: Option<String>,
+}
+
+fn clean_cell(cell: &str) -> String {
+    cell.trim().trim_matches('*').trim().to_string()
+}
+
+fn non_empty(value: String) -> Option<String> {
+    let lowered = value.to_lowercase();
+    if value.is_empty() || lowered == "n/a" || lowered == "tbd" || value == "-" {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+fn split_row(line: &str) -> Vec<String> {
+    line.trim()
+        .trim_start_matches('|')
+        .trim_end_matches('|')
+        .split('|')
+        .map(clean_cell)
+        .collect()
+}
+
+fn is_separator_row(cells: &[String]) -> bool {
+    cells
+        .iter()
+        .all(|c| !c.is_empty() && c.chars().all(|ch| ch == '-' || ch == ':'))
+}
+
+/// Column positions detected from a table header row
+struct TableColumns {
+    task: usize,
+    owner: Option<usize>,
+    due: Option<usize>,
+}
+
+impl TableColumns {
+    fn from_header(cells: &[String]) -> Self {
+        let find = |keys: &[&str]| {
+            cells.iter().position(|c| {
+                let c = c.to_lowercase();
+                keys.iter().any(|k| c.contains(k))
+            })
+        };
+        let owner = find(&["owner", "assignee", "who", "responsible"]);
+        let due = find(&["due", "deadline", "date"]);
+        let task = find(&["task", "action", "item", "description"])
+            .or_else(|| (0..cells.len()).find(|i| Some(*i) != owner && Some(*i) != due))
+            .unwrap_or(0);
+        Self { task, owner, due }
+    }
+}
+
+/// Parses the "Action Items" section of a summary. Returns an empty list when
+/// the section is missing.
+pub fn extract_action_items(markdown: &str) -> Vec<ExtractedActionItem> {
+    let mut items = Vec::new();
+    let mut in_section = false;
+    let mut columns: Option<TableColumns> = None;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with('#') {
+            in_section = ACTION_ITEMS_HEADING.is_match(trimmed);
+            columns = None;
+            continue;
+        }
+        if !in_section || trimmed.is_empty() {
+            continue;
+        }
+
+        if trimmed.starts_with('|') {
+            let cells = split_row(trimmed);
+            if is_separator_row(&cells) {
+                continue;
+            }
+            match &columns {
+                None => columns = Some(TableColumns::from_header(&cells)),
+                Some(cols) => {
+                    let get = |i: Option<usize>| i.and_then(|i| cells.get(i).cloned()).and_then(non_empty);
+                    if let Some(text) = get(Some(cols.task)) {
+                        items.push(ExtractedActionItem {
+                            text,
+                            owner: get(cols.owner),
+                            due_date: get(cols.due),
+                        });
+                    }
+                }
+            }
+            continue;
+        }
+
+        if let Some(prefix) = BULLET_PREFIX.find(line) {
+            let mut body = line[prefix.end()..].trim().to_string();
+
+            let mut due_date = None;
+            if let Some(caps) = DUE_SUFFIX.captures(&body) {
+                due_date = non_empty(caps[1].trim().to_string());
+                body = DUE_SUFFIX.replace(&body, "").to_string();
+            }
+
+            let (owner, text) = match BOLD_OWNER.captures(&body) {
+                Some(caps) => (non_empty(caps[1].trim().to_string()), caps[2].trim().to_string()),
+                None => (None, body.trim().to_string()),
+            };
+
+            if let Some(text) = non_empty(text) {
+                items.push(ExtractedActionItem {
+                    text,
+                    owner,
+                    due_date,
+                });
+            }
+        }
+    }
+
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_template_table() {
+        let md = "## Summary\nWe met.\n\n## Action Items\n\
+                  | **Owner** | Task | Due | Reference Transcript Segment | Segment Time stamp |\n\
+                  | --- | --- | --- | --- | --- |\n\
+                  | **Alice** | Send the deck | 2026-02-01 | \"I'll send it\" | 00:12:03 |\n\
+                  | Bob | Book venue | TBD | | |\n\n## Discussion Highlights\n- not an item\n";
+        let items = extract_action_items(md);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].owner.as_deref(), Some("Alice"));
+        assert_eq!(items[0].text, "Send the deck");
+        assert_eq!(items[0].due_date.as_deref(), Some("2026-02-01"));
+        assert_eq!(items[1].due_date, None);
+    }
+
+    #[test]
+    fn parses_bullets() {
+        let md = "# Action Items\n- **Carol**: Draft the RFC (due: Friday)\n- [ ] Update the wiki\n1. Follow up with legal\n";
+        let items = extract_action_items(md);
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].owner.as_deref(), Some("Carol"));
+        assert_eq!(items[0].text, "Draft the RFC");
+        assert_eq!(items[0].due_date.as_deref(), Some("Friday"));
+        assert_eq!(items[1].text, "Update the wiki");
+        assert_eq!(items[2].owner, None);
+    }
+
+    #[test]
+    fn missing_section_yields_nothing() {
+        assert!(extract_action_items("## Summary\n- point one\n").is_empty());
+    }
+}
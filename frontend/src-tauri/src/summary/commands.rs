@@ -1,11 +1,16 @@
 use crate::database::repositories::{
-    meeting::MeetingsRepository, summary::SummaryProcessesRepository,
-    transcript_chunk::TranscriptChunksRepository,
+    meeting::MeetingsRepository, minutes_status::MinutesStatusRepository,
+    summary::SummaryProcessesRepository, transcript_chunk::TranscriptChunksRepository,
 };
+use crate::error::AppError;
+use crate::events::{self, AppEvent, SummaryFinished};
+use crate::notifications::tasks::{notify_task_finished, TaskKind};
 use crate::state::AppState;
+use crate::summary::schema;
 use crate::summary::service::SummaryService;
 use log::{error as log_error, info as log_info, warn as log_warn};
 use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
 use tauri::{AppHandle, Runtime};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -26,40 +31,98 @@ pub struct ProcessTranscriptResponse {
     pub process_id: String,
 }
 
+/// Announces a finished summary generation from its stored outcome, as an
+/// event and a notification
+async fn announce_summary<R: Runtime>(app: &AppHandle<R>, pool: &SqlitePool, meeting_id: &str) {
+    let process = match SummaryProcessesRepository::get_summary_data(pool, meeting_id).await {
+        Ok(Some(process)) => process,
+        Ok(None) => return,
+        Err(e) => {
+            log_warn!("Failed to read the summary outcome for {}: {}", meeting_id, e);
+            return;
+        }
+    };
+    let error = match process.status.as_str() {
+        "completed" => None,
+        "failed" => Some(process.error.unwrap_or_else(|| "Unknown error".to_string())),
+        _ => return,
+    };
+    let title = MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .ok()
+        .flatten()
+        .map(|meeting| meeting.title)
+        .unwrap_or_default();
+    let finished = SummaryFinished {
+        meeting_id: meeting_id.to_string(),
+        title: title.clone(),
+        error: error.clone(),
+    };
+    if let Err(e) = events::emit(app, &finished) {
+        log_warn!("Failed to emit {}: {}", SummaryFinished::NAME, e);
+    }
+    notify_task_finished(app, TaskKind::Summary, &title, error.as_deref()).await;
+}
+
 /// Saves a meeting summary (Native SQLx implementation)
 ///
-/// Expected format: { "markdown": "...", "summary_json": [...BlockNote blocks...] }
+/// Expected format: { "markdown": "...", "summary_json": [...BlockNote blocks...] },
+/// checked against the summary schema; older formats are upgraded
 #[tauri::command]
 pub async fn api_save_meeting_summary<R: Runtime>(
     _app: AppHandle<R>,
     state: tauri::State<'_, AppState>,
     meeting_id: String,
     summary: serde_json::Value,
+    expected_version: Option<i64>,
     _auth_token: Option<String>,
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, AppError> {
+    crate::validation::meeting_id(&meeting_id)?;
     log_info!(
-        "api_save_meeting_summary (native) called for meeting_id: {}",
-        meeting_id
+        "api_save_meeting_summary (native) called for meeting_id: {}, expected_version: {:?}",
+        meeting_id,
+        expected_version
     );
+    let summary = schema::normalize(summary)
+        .map_err(|e| AppError::InvalidInput(e.to_string()))?
+        .to_value();
     let pool = state.db_manager.pool();
 
-    match SummaryProcessesRepository::update_meeting_summary(pool, &meeting_id, &summary).await {
-        Ok(true) => {
+    match SummaryProcessesRepository::update_meeting_summary(
+        pool,
+        &meeting_id,
+        &summary,
+        expected_version,
+    )
+    .await
+    {
+        Ok(Some(version)) => {
             log_info!("Summary saved successfully for meeting_id: {}", meeting_id);
+            crate::audit::record(
+                pool,
+                crate::audit::AuditAction::SummaryEdit,
+                "summary",
+                Some(&meeting_id),
+                serde_json::Value::Null,
+            )
+            .await;
             Ok(serde_json::json!({
-                "message": "Meeting summary saved successfully"
+                "message": "Meeting summary saved successfully",
+                "version": version
             }))
         }
-        Ok(false) => {
+        Ok(None) => {
             log_warn!(
                 "Meeting not found or invalid JSON for meeting_id: {}",
                 meeting_id
             );
-            Err("Meeting not found or can't convert the json".into())
+            Err(AppError::NotFound(
+                "Meeting not found or can't convert the json".to_string(),
+            ))
         }
         Err(e) => {
             log_error!("Failed to save meeting summary for {}: {}", meeting_id, e);
-            Err(e.to_string())
+            Err(AppError::from(e))
         }
     }
 }
@@ -74,6 +137,7 @@ pub async fn api_get_summary<R: Runtime>(
     meeting_id: String,
     _auth_token: Option<String>,
 ) -> Result<SummaryResponse, String> {
+    crate::validation::meeting_id(&meeting_id)?;
     log_info!(
         "api_get_summary (native) called for meeting_id: {}",
         meeting_id
@@ -162,7 +226,8 @@ pub async fn api_get_summary<R: Runtime>(
 
 /// Processes transcript and generates summary (Native SQLx implementation)
 ///
-/// Spawns a background task and returns immediately with process_id
+/// Spawns a background task and returns immediately with process_id.
+/// `summary_language` overrides the saved summary language for this run.
 #[tauri::command]
 pub async fn api_process_transcript<R: Runtime>(
     app: AppHandle<R>,
@@ -175,10 +240,16 @@ pub async fn api_process_transcript<R: Runtime>(
     _overlap: Option<i32>,
     custom_prompt: Option<String>,
     template_id: Option<String>,
+    summary_language: Option<String>,
     _auth_token: Option<String>,
 ) -> Result<ProcessTranscriptResponse, String> {
     use uuid::Uuid;
 
+    meeting_id
+        .as_deref()
+        .map(crate::validation::meeting_id)
+        .transpose()
+        .map_err(|e| e.to_string())?;
     let m_id = meeting_id.unwrap_or_else(|| format!("meeting-{}", Uuid::new_v4()));
     log_info!(
         "api_process_transcript (native) called for meeting_id: {}, model: {}",
@@ -190,6 +261,11 @@ pub async fn api_process_transcript<R: Runtime>(
     let final_prompt = custom_prompt.unwrap_or_else(|| "".to_string());
     let final_template_id = template_id.unwrap_or_else(|| "daily_standup".to_string());
 
+    // Approved minutes keep their summary until they are returned to draft
+    MinutesStatusRepository::ensure_editable(&pool, &m_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
     // Create or reset the process entry in the database
     SummaryProcessesRepository::create_or_reset_process(&pool, &m_id)
         .await
@@ -218,6 +294,8 @@ pub async fn api_process_transcript<R: Runtime>(
     // Spawn background task for actual processing
     let meeting_id_clone = m_id.clone();
     tauri::async_runtime::spawn(async move {
+        let notify_app = app.clone();
+        let notify_pool = pool.clone();
         SummaryService::process_transcript_background(
             app,
             pool,
@@ -227,8 +305,10 @@ pub async fn api_process_transcript<R: Runtime>(
             model_name,
             final_prompt,
             final_template_id,
+            summary_language,
         )
         .await;
+        announce_summary(&notify_app, &notify_pool, &meeting_id_clone).await;
     });
 
     log_info!("🚀 Background task spawned for meeting_id: {}", &m_id);
@@ -249,6 +329,7 @@ pub async fn api_cancel_summary<R: Runtime>(
     state: tauri::State<'_, AppState>,
     meeting_id: String,
 ) -> Result<serde_json::Value, String> {
+    crate::validation::meeting_id(&meeting_id)?;
     log_info!("api_cancel_summary called for meeting_id: {}", meeting_id);
 
     // Trigger cancellation via the service
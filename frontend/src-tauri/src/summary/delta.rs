@@ -0,0 +1,211 @@
+//! Delta reports: what changed between two meetings of a series.
+//!
+//! Action item progress is computed locally from the action_items table so it
+//! is exact; decisions and recurring topics come from the summary model.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::database::models::ActionItem;
+
+/// Meeting content sent to the model is capped per meeting
+const MAX_CONTENT_CHARS: usize = 24_000;
+/// Token overlap above which two action items are treated as the same task
+const SAME_ITEM_SIMILARITY: f64 = 0.6;
+
+pub const DELTA_SYSTEM_PROMPT: &str = "You compare two meetings from the same recurring series. \
+Report only what changed from the previous meeting to the current one. Be concise and factual, \
+use only information present in the input, and answer in markdown using exactly these sections: \
+## New Decisions, ## Action Item Progress, ## Recurring Topics, ## New Topics. \
+Write \"None\" under a section with nothing to report.";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionItemRef {
+    pub id: String,
+    pub text: String,
+    pub owner: Option<String>,
+    pub status: String,
+}
+
+impl From<&ActionItem> for ActionItemRef {
+    fn from(item: &ActionItem) -> Self {
+        Self {
+            id: item.id.clone(),
+            text: item.text.clone(),
+            owner: item.owner.clone(),
+            status: item.status.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ActionItemDelta {
+    /// Items from the previous meeting that are now done
+    pub resolved: Vec<ActionItemRef>,
+    /// Items from the previous meeting that are still open
+    pub still_open: Vec<ActionItemRef>,
+    /// Items raised in the current meeting that were not in the previous one
+    pub new: Vec<ActionItemRef>,
+}
+
+/// One side of the comparison
+pub struct MeetingContent<'a> {
+    pub title: &'a str,
+    pub date: &'a str,
+    /// Summary markdown, or transcript text when no summary exists
+    pub content: &'a str,
+}
+
+fn tokens(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.len() > 2)
+        .map(|w| w.to_lowercase())
+        .collect()
+}
+
+/// Jaccard similarity of the word sets of two action item texts
+pub fn item_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (tokens(a), tokens(b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f64 / a.union(&b).count() as f64
+}
+
+/// Classifies action items across the two meetings. An earlier item counts as
+/// resolved when it was marked done, or when the same task reappears as done.
+pub fn diff_action_items(previous: &[ActionItem], current: &[ActionItem]) -> ActionItemDelta {
+    let mut delta = ActionItemDelta::default();
+    let mut matched_current = HashSet::new();
+
+    for old in previous {
+        let matching = current
+            .iter()
+            .enumerate()
+            .filter(|(_, new)| item_similarity(&old.text, &new.text) >= SAME_ITEM_SIMILARITY)
+            .collect::<Vec<_>>();
+        matched_current.extend(matching.iter().map(|(i, _)| *i));
+
+        let done = old.status == "done" || matching.iter().any(|(_, new)| new.status == "done");
+        if done {
+            delta.resolved.push(old.into());
+        } else {
+            delta.still_open.push(old.into());
+        }
+    }
+
+    delta.new = current
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !matched_current.contains(i))
+        .map(|(_, item)| item.into())
+        .collect();
+    delta
+}
+
+fn truncate_chars(text: &str, max: usize) -> String {
+    match text.char_indices().nth(max) {
+        Some((idx, _)) => format!("{}\n[...truncated]", &text[..idx]),
+        None => text.to_string(),
+    }
+}
+
+fn render_items(items: &[ActionItemRef]) -> String {
+    if items.is_empty() {
+        return "- (none)".to_string();
+    }
+    items
+        .iter()
+        .map(|item| match &item.owner {
+            Some(owner) => format!("- {} (owner: {})", item.text, owner),
+            None => format!("- {}", item.text),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn build_delta_prompt(
+    previous: &MeetingContent,
+    current: &MeetingContent,
+    delta: &ActionItemDelta,
+) -> String {
+    format!(
+        "# Previous meeting: {} ({})\n\n{}\n\n# Current meeting: {} ({})\n\n{}\n\n\
+         # Action item status (computed)\n\n## Resolved since the previous meeting\n{}\n\n\
+         ## Still open from the previous meeting\n{}\n\n## New in the current meeting\n{}\n",
+        previous.title,
+        previous.date,
+        truncate_chars(previous.content, MAX_CONTENT_CHARS),
+        current.title,
+        current.date,
+        truncate_chars(current.content, MAX_CONTENT_CHARS),
+        render_items(&delta.resolved),
+        render_items(&delta.still_open),
+        render_items(&delta.new),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::DateTimeUtc;
+
+    fn item(id: &str, text: &str, status: &str) -> ActionItem {
+        ActionItem {
+            id: id.into(),
+            meeting_id: "m".into(),
+            text: text.into(),
+            owner: None,
+            due_date: None,
+            status: status.into(),
+            source: "summary".into(),
+            created_at: DateTimeUtc(chrono::Utc::now()),
+            updated_at: DateTimeUtc(chrono::Utc::now()),
+            external_tracker: None,
+            external_key: None,
+            external_url: None,
+            reminded_on: None,
+            snoozed_until: None,
+        }
+    }
+
+    #[test]
+    fn action_items_are_classified() {
+        let previous = vec![
+            item("p1", "Send the budget proposal to finance", "done"),
+            item("p2", "Update the onboarding docs", "open"),
+            item("p3", "Book venue for the offsite", "open"),
+        ];
+        let current = vec![
+            item("c1", "Book the venue for offsite", "done"),
+            item("c2", "Update onboarding docs", "open"),
+            item("c3", "Hire a contractor for QA", "open"),
+        ];
+        let delta = diff_action_items(&previous, &current);
+        let ids = |items: &[ActionItemRef]| items.iter().map(|i| i.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&delta.resolved), vec!["p1", "p3"]);
+        assert_eq!(ids(&delta.still_open), vec!["p2"]);
+        assert_eq!(ids(&delta.new), vec!["c3"]);
+    }
+
+    #[test]
+    fn similarity_ignores_case_and_short_words() {
+        assert_eq!(item_similarity("Ship the API", "ship THE api!"), 1.0);
+        assert_eq!(item_similarity("a b", "a b"), 0.0);
+        assert!(item_similarity("Write tests", "Deploy backend") < SAME_ITEM_SIMILARITY);
+    }
+
+    #[test]
+    fn prompt_contains_both_meetings_and_truncates() {
+        let long = "x".repeat(MAX_CONTENT_CHARS + 10);
+        let prompt = build_delta_prompt(
+            &MeetingContent { title: "Sync 1", date: "2026-01-01", content: &long },
+            &MeetingContent { title: "Sync 2", date: "2026-01-08", content: "short" },
+            &ActionItemDelta::default(),
+        );
+        assert!(prompt.contains("# Previous meeting: Sync 1 (2026-01-01)"));
+        assert!(prompt.contains("# Current meeting: Sync 2 (2026-01-08)"));
+        assert!(prompt.contains("[...truncated]"));
+        assert!(prompt.contains("- (none)"));
+    }
+}
@@ -0,0 +1,153 @@
+use crate::database::models::{MeetingDeltaReport, MeetingModel};
+use crate::database::repositories::{
+    action_item::ActionItemsRepository, delta_report::DeltaReportsRepository,
+    meeting::MeetingsRepository, summary::SummaryProcessesRepository,
+};
+use crate::state::AppState;
+use crate::summary::delta::{build_delta_prompt, diff_action_items, MeetingContent, DELTA_SYSTEM_PROMPT};
+use crate::summary::llm_connection::LlmConnection;
+use crate::summary::processor::clean_llm_markdown_output;
+use log::{error as log_error, info as log_info};
+use sqlx::SqlitePool;
+use tauri::{AppHandle, Manager, Runtime};
+
+/// Summary markdown when available, otherwise the transcript text
+async fn meeting_content(pool: &SqlitePool, meeting_id: &str) -> Result<String, String> {
+    let summary = SummaryProcessesRepository::get_summary_data(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load summary: {}", e))?
+        .and_then(|p| p.result)
+        .and_then(|r| serde_json::from_str::<serde_json::Value>(&r).ok())
+        .and_then(|v| v.get("markdown").and_then(|m| m.as_str()).map(str::to_string))
+        .filter(|m| !m.trim().is_empty());
+    if let Some(markdown) = summary {
+        return Ok(markdown);
+    }
+
+    let details = MeetingsRepository::get_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcript: {}", e))?
+        .ok_or_else(|| format!("Meeting '{}' not found", meeting_id))?;
+    let text = details
+        .transcripts
+        .iter()
+        .map(|t| t.text.trim())
+        .filter(|t| !t.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    if text.is_empty() {
+        return Err(format!("Meeting '{}' has no summary or transcript", details.title));
+    }
+    Ok(text)
+}
+
+async fn load_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<MeetingModel, String> {
+    MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting '{}' not found", meeting_id))
+}
+
+/// Compares two meetings and stores a delta report on the newer one.
+/// The order of the ids does not matter; creation time decides which is newer.
+/// Uses the summary model from settings unless `provider`/`model` are given.
+#[tauri::command]
+pub async fn api_compare_meetings<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    other_meeting_id: String,
+    provider: Option<String>,
+    model: Option<String>,
+) -> Result<MeetingDeltaReport, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_compare_meetings called for {} and {}",
+        meeting_id,
+        other_meeting_id
+    );
+    if meeting_id == other_meeting_id {
+        return Err("Choose two different meetings to compare".to_string());
+    }
+    let pool = state.db_manager.pool();
+
+    let first = load_meeting(pool, &meeting_id).await?;
+    let second = load_meeting(pool, &other_meeting_id).await?;
+    let (previous, current) = if first.created_at.0 <= second.created_at.0 {
+        (first, second)
+    } else {
+        (second, first)
+    };
+
+    let app_data_dir = app.path().app_data_dir().ok();
+    let connection = match (provider, model) {
+        (Some(provider), Some(model)) => {
+            LlmConnection::resolve(pool, &provider, &model, app_data_dir).await?
+        }
+        _ => LlmConnection::from_settings(pool, app_data_dir).await?,
+    };
+
+    let previous_items = ActionItemsRepository::list_for_meeting(pool, &previous.id)
+        .await
+        .map_err(|e| format!("Failed to load action items: {}", e))?;
+    let current_items = ActionItemsRepository::list_for_meeting(pool, &current.id)
+        .await
+        .map_err(|e| format!("Failed to load action items: {}", e))?;
+    let delta = diff_action_items(&previous_items, &current_items);
+
+    let previous_content = meeting_content(pool, &previous.id).await?;
+    let current_content = meeting_content(pool, &current.id).await?;
+    let previous_date = previous.created_at.0.format("%Y-%m-%d").to_string();
+    let current_date = current.created_at.0.format("%Y-%m-%d").to_string();
+    let prompt = build_delta_prompt(
+        &MeetingContent {
+            title: &previous.title,
+            date: &previous_date,
+            content: &previous_content,
+        },
+        &MeetingContent {
+            title: &current.title,
+            date: &current_date,
+            content: &current_content,
+        },
+        &delta,
+    );
+
+    let client = reqwest::Client::new();
+    let report = connection
+        .complete(&client, DELTA_SYSTEM_PROMPT, &prompt, None)
+        .await
+        .map_err(|e| {
+            log_error!("Delta report generation failed: {}", e);
+            format!("Failed to generate delta report: {}", e)
+        })?;
+    let report = clean_llm_markdown_output(&report);
+
+    let delta_json = serde_json::to_string(&delta)
+        .map_err(|e| format!("Failed to serialize action item delta: {}", e))?;
+    DeltaReportsRepository::save(
+        pool,
+        &current.id,
+        &previous.id,
+        &report,
+        &delta_json,
+        &connection.provider_name,
+        &connection.model_name,
+    )
+    .await
+    .map_err(|e| format!("Failed to save delta report: {}", e))
+}
+
+/// Returns the stored delta report for a meeting, if it has been compared
+#[tauri::command]
+pub async fn api_get_delta_report<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Option<MeetingDeltaReport>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_get_delta_report called for {}", meeting_id);
+    DeltaReportsRepository::get_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load delta report: {}", e))
+}
@@ -0,0 +1,676 @@
+//! Digests: one brief covering every meeting of a day or a week.
+//!
+//! The summary model writes the key decisions, upcoming commitments and
+//! highlights from each meeting's summary; the action items are listed
+//! verbatim from the database so none is dropped or reworded. Digests are
+//! generated on demand or by an optional schedule, stored, and can be sent by
+//! email (SMTP) or posted to a webhook.
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, TimeZone, Timelike, Utc};
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::database::models::ActionItem;
+use crate::database::repositories::{
+    action_item::ActionItemsRepository,
+    digest::{Digest, DigestsRepository},
+    integration::IntegrationSettingsRepository,
+    meeting::MeetingsRepository,
+    setting::SettingsRepository,
+    summary::SummaryProcessesRepository,
+};
+use crate::events;
+use crate::export::html::render_summary_email;
+use crate::integrations::email::{parse_recipients, send_smtp};
+use crate::state::AppState;
+use crate::summary::llm_connection::LlmConnection;
+use crate::summary::processor::{clean_llm_markdown_output, output_language_instruction};
+
+pub const SCHEDULE_SETTINGS_ID: &str = "digest_schedule";
+/// Event emitted with each digest the schedule creates
+pub const DIGEST_EVENT: &str = "digest-created";
+/// Each meeting's summary is shortened to this in the prompt
+const MAX_MEETING_CHARS: usize = 6000;
+/// How often the scheduler checks whether a digest is due
+const SCHEDULER_INTERVAL_SECS: u64 = 30 * 60;
+/// Wait after the first failed scheduled run; doubles with each further failure
+const RETRY_BACKOFF_MINUTES: i64 = 60;
+const MAX_RETRY_BACKOFF_MINUTES: i64 = 12 * 60;
+
+pub const DIGEST_SYSTEM_PROMPT: &str = "You write a digest covering several meetings held in one period. \
+You are given the summary of each meeting and the action items recorded in them. \
+Write markdown with exactly these sections: ## Key Decisions, ## Upcoming Commitments, ## Highlights. \
+Key Decisions lists the decisions made, naming the meeting each came from. \
+Upcoming Commitments lists deadlines, follow-up meetings and promised deliverables, with dates when known. \
+Highlights gives two or three sentences on what the period was about. \
+Do not list the action items themselves; they are appended separately. \
+Only use information from the meetings given. Write 'None' under a section with nothing to report.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DigestPeriod {
+    Daily,
+    Weekly,
+}
+
+impl DigestPeriod {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "daily" => Ok(Self::Daily),
+            "weekly" => Ok(Self::Weekly),
+            other => Err(format!("Unknown digest period: {}", other)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+        }
+    }
+}
+
+/// The local dates covered by the period ending at `today`, end exclusive:
+/// yesterday for a daily digest, the seven days before today for a weekly one
+pub fn period_dates(period: DigestPeriod, today: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let days = match period {
+        DigestPeriod::Daily => 1,
+        DigestPeriod::Weekly => 7,
+    };
+    (today - Duration::days(days), today)
+}
+
+/// Local midnight at the start of `date`, in UTC
+fn local_midnight(date: NaiveDate) -> DateTime<Utc> {
+    let midnight = date.and_hms_opt(0, 0, 0).unwrap_or_default();
+    match Local.from_local_datetime(&midnight).earliest() {
+        Some(local) => local.with_timezone(&Utc),
+        None => Utc.from_utc_datetime(&midnight),
+    }
+}
+
+/// The period ending at `today` as UTC instants, end exclusive
+pub fn period_bounds(period: DigestPeriod, today: NaiveDate) -> (DateTime<Utc>, DateTime<Utc>) {
+    let (start, end) = period_dates(period, today);
+    (local_midnight(start), local_midnight(end))
+}
+
+/// "Daily digest 2026-04-11", "Weekly digest 2026-04-04 – 2026-04-10"
+pub fn digest_title(period: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> String {
+    let first = start.with_timezone(&Local).date_naive();
+    let last = (end - Duration::seconds(1))
+        .with_timezone(&Local)
+        .date_naive();
+    let label = match period {
+        "daily" => "Daily digest",
+        "weekly" => "Weekly digest",
+        _ => "Digest",
+    };
+    if first >= last {
+        format!("{} {}", label, first)
+    } else {
+        format!("{} {} – {}", label, first, last)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DigestSchedule {
+    pub enabled: bool,
+    pub period: DigestPeriod,
+    /// Local hour (0-23) after which the digest is generated
+    pub hour: u32,
+    /// Day of a weekly digest, 0 = Monday
+    pub weekday: u32,
+    /// Email addresses the digest is sent to over SMTP
+    #[serde(default)]
+    pub recipients: Vec<String>,
+    /// URL the digest is POSTed to as JSON ({"text": ...})
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+    pub last_run_at: Option<DateTime<Utc>>,
+    /// Last scheduled run that failed, and how many failed in a row
+    #[serde(default)]
+    pub last_failed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub failed_attempts: u32,
+}
+
+impl Default for DigestSchedule {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            period: DigestPeriod::Daily,
+            hour: 8,
+            weekday: 0,
+            recipients: Vec::new(),
+            webhook_url: None,
+            last_run_at: None,
+            last_failed_at: None,
+            failed_attempts: 0,
+        }
+    }
+}
+
+impl DigestSchedule {
+    /// The local date of the latest scheduled run at or before `now`: today
+    /// or an earlier day once `hour` has passed, and only `weekday` for a
+    /// weekly digest
+    pub fn latest_slot<Tz: TimeZone>(&self, now: &DateTime<Tz>) -> NaiveDate {
+        let mut date = now.date_naive();
+        if now.hour() < self.hour {
+            date -= Duration::days(1);
+        }
+        if self.period == DigestPeriod::Weekly {
+            let back = (7 + date.weekday().num_days_from_monday() as i64 - self.weekday as i64) % 7;
+            date -= Duration::days(back);
+        }
+        date
+    }
+
+    /// Earliest time the next attempt may run after failed attempts
+    pub fn retry_at(&self) -> Option<DateTime<Utc>> {
+        let failed_at = self.last_failed_at?;
+        let doublings = self.failed_attempts.saturating_sub(1).min(10);
+        let minutes = (RETRY_BACKOFF_MINUTES << doublings).min(MAX_RETRY_BACKOFF_MINUTES);
+        Some(failed_at + Duration::minutes(minutes))
+    }
+
+    /// Due once per scheduled slot, from `hour` on. A slot missed while the
+    /// app was closed is caught up on the next check, but a new schedule only
+    /// starts with today's slot.
+    pub fn is_due<Tz: TimeZone>(&self, now: &DateTime<Tz>) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        if self
+            .retry_at()
+            .is_some_and(|retry| now.with_timezone(&Utc) < retry)
+        {
+            return false;
+        }
+        let slot = self.latest_slot(now);
+        match self.last_run_at {
+            Some(last) => {
+                let slot_start = slot.and_hms_opt(self.hour, 0, 0).unwrap_or_default();
+                last.with_timezone(&now.timezone()).naive_local() < slot_start
+            }
+            None => slot == now.date_naive(),
+        }
+    }
+
+    /// Marks the slot done and clears any failures
+    pub fn record_success(&mut self, at: DateTime<Utc>) {
+        self.last_run_at = Some(at);
+        self.last_failed_at = None;
+        self.failed_attempts = 0;
+    }
+
+    pub fn record_failure(&mut self, at: DateTime<Utc>) {
+        self.last_failed_at = Some(at);
+        self.failed_attempts = self.failed_attempts.saturating_add(1);
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.hour > 23 {
+            return Err("The hour must be between 0 and 23".to_string());
+        }
+        if self.weekday > 6 {
+            return Err("The weekday must be between 0 (Monday) and 6 (Sunday)".to_string());
+        }
+        if !self.recipients.is_empty() {
+            parse_recipients(&self.recipients)?;
+        }
+        if let Some(url) = &self.webhook_url {
+            if !url.starts_with("https://") && !url.starts_with("http://") {
+                return Err("The webhook URL must start with http:// or https://".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+pub async fn load_schedule(pool: &SqlitePool) -> Result<DigestSchedule, String> {
+    let raw = IntegrationSettingsRepository::get_config(pool, SCHEDULE_SETTINGS_ID)
+        .await
+        .map_err(|e| format!("Failed to load digest schedule: {}", e))?;
+    match raw {
+        Some(raw) => serde_json::from_str(&raw)
+            .map_err(|e| format!("Stored digest schedule is invalid: {}", e)),
+        None => Ok(DigestSchedule::default()),
+    }
+}
+
+pub async fn save_schedule(pool: &SqlitePool, schedule: &DigestSchedule) -> Result<(), String> {
+    let raw = serde_json::to_string(schedule)
+        .map_err(|e| format!("Failed to serialize digest schedule: {}", e))?;
+    IntegrationSettingsRepository::save_config(pool, SCHEDULE_SETTINGS_ID, &raw)
+        .await
+        .map_err(|e| format!("Failed to save digest schedule: {}", e))
+}
+
+/// A meeting as the digest prompt sees it
+pub struct DigestMeeting {
+    pub title: String,
+    pub date: String,
+    /// Summary markdown, if the meeting has been summarized
+    pub summary: Option<String>,
+}
+
+fn shorten(text: &str) -> String {
+    let mut short: String = text.chars().take(MAX_MEETING_CHARS).collect();
+    if text.chars().count() > MAX_MEETING_CHARS {
+        short.push_str("\n...");
+    }
+    short
+}
+
+/// The action items as a markdown checklist, open items first. `items` pairs
+/// each item with the title of its meeting.
+pub fn render_action_items(items: &[(&str, &ActionItem)]) -> String {
+    if items.is_empty() {
+        return "_No action items._".to_string();
+    }
+    let mut ordered: Vec<&(&str, &ActionItem)> = items.iter().collect();
+    ordered.sort_by_key(|(_, item)| item.status == "done");
+    ordered
+        .iter()
+        .map(|(meeting, item)| {
+            let mark = if item.status == "done" { "x" } else { " " };
+            let mut line = format!("- [{}] {}", mark, item.text.trim());
+            if let Some(owner) = item.owner.as_deref().filter(|o| !o.trim().is_empty()) {
+                line.push_str(&format!(" — {}", owner.trim()));
+            }
+            if let Some(due) = item.due_date.as_deref().filter(|d| !d.trim().is_empty()) {
+                line.push_str(&format!(" (due {})", due.trim()));
+            }
+            line.push_str(&format!(" · _{}_", meeting));
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn build_digest_prompt(title: &str, meetings: &[DigestMeeting], action_items: &str) -> String {
+    let mut prompt = format!("{}\n\n", title);
+    for meeting in meetings {
+        prompt.push_str(&format!("### {} ({})\n", meeting.title, meeting.date));
+        match &meeting.summary {
+            Some(summary) => prompt.push_str(&shorten(summary)),
+            None => prompt.push_str("_Not summarized yet._"),
+        }
+        prompt.push_str("\n\n");
+    }
+    prompt.push_str("### Action items\n");
+    prompt.push_str(action_items);
+    prompt
+}
+
+async fn summary_markdown(pool: &SqlitePool, meeting_id: &str) -> Option<String> {
+    match SummaryProcessesRepository::get_summary_data(pool, meeting_id).await {
+        Ok(process) => process
+            .and_then(|p| p.result)
+            .and_then(|r| serde_json::from_str::<serde_json::Value>(&r).ok())
+            .and_then(|v| {
+                v.get("markdown")
+                    .and_then(|m| m.as_str())
+                    .map(str::to_string)
+            })
+            .filter(|m| !m.trim().is_empty()),
+        Err(e) => {
+            warn!("Failed to load the summary of {}: {}", meeting_id, e);
+            None
+        }
+    }
+}
+
+/// Generates and stores a digest of the meetings created between `start` and
+/// `end`. Returns None when no meeting falls in the period.
+pub async fn generate_digest(
+    pool: &SqlitePool,
+    connection: &LlmConnection,
+    period: &str,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Option<Digest>, String> {
+    let mut meetings: Vec<_> = MeetingsRepository::get_meetings(pool)
+        .await
+        .map_err(|e| format!("Failed to load meetings: {}", e))?
+        .into_iter()
+        .filter(|m| m.created_at.0 >= start && m.created_at.0 < end)
+        .collect();
+    if meetings.is_empty() {
+        info!("No meetings between {} and {}; no digest", start, end);
+        return Ok(None);
+    }
+    meetings.sort_by_key(|m| m.created_at.0);
+
+    let mut digest_meetings = Vec::with_capacity(meetings.len());
+    let mut action_items = Vec::new();
+    for meeting in &meetings {
+        digest_meetings.push(DigestMeeting {
+            title: meeting.title.clone(),
+            date: meeting
+                .created_at
+                .0
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+            summary: summary_markdown(pool, &meeting.id).await,
+        });
+        let items = ActionItemsRepository::list_for_meeting(pool, &meeting.id)
+            .await
+            .map_err(|e| format!("Failed to load action items: {}", e))?;
+        action_items.extend(items.into_iter().map(|item| (meeting.title.as_str(), item)));
+    }
+    let item_refs: Vec<(&str, &ActionItem)> = action_items
+        .iter()
+        .map(|(title, item)| (*title, item))
+        .collect();
+    let action_list = render_action_items(&item_refs);
+
+    let title = digest_title(period, start, end);
+    let mut system_prompt = DIGEST_SYSTEM_PROMPT.to_string();
+    match SettingsRepository::get_summary_language(pool).await {
+        Ok(language) => {
+            if let Some(instruction) = output_language_instruction(language.as_deref()) {
+                system_prompt = format!("{} {}", system_prompt, instruction);
+            }
+        }
+        Err(e) => warn!("Failed to read the summary language: {}", e),
+    }
+    let prompt = build_digest_prompt(&title, &digest_meetings, &action_list);
+
+    let brief = connection
+        .complete(&reqwest::Client::new(), &system_prompt, &prompt, None)
+        .await
+        .map_err(|e| format!("Failed to generate the digest: {}", e))?;
+    let content = format!(
+        "{}\n\n## Action Items\n\n{}\n",
+        clean_llm_markdown_output(&brief).trim_end(),
+        action_list
+    );
+
+    let meeting_ids: Vec<String> = meetings.iter().map(|m| m.id.clone()).collect();
+    let digest = DigestsRepository::save(
+        pool,
+        period,
+        start,
+        end,
+        &title,
+        &content,
+        &meeting_ids,
+        &connection.provider_name,
+        &connection.model_name,
+    )
+    .await
+    .map_err(|e| format!("Failed to save digest: {}", e))?;
+    info!(
+        "Created digest {} covering {} meetings",
+        digest.id,
+        meeting_ids.len()
+    );
+    Ok(Some(digest))
+}
+
+/// Sends a digest to the schedule's recipients and webhook. Both are
+/// attempted; the errors of either are returned together.
+pub async fn deliver_digest(
+    pool: &SqlitePool,
+    digest: &Digest,
+    recipients: &[String],
+    webhook_url: Option<&str>,
+) -> Result<(), String> {
+    let mut errors = Vec::new();
+
+    if !recipients.is_empty() {
+        let sent = async {
+            let mailboxes = parse_recipients(recipients)?;
+            let config = SettingsRepository::get_smtp_config(pool)
+                .await
+                .map_err(|e| format!("Failed to load SMTP settings: {}", e))?
+                .ok_or_else(|| "SMTP is not configured".to_string())?;
+            let password = SettingsRepository::get_smtp_password(pool)
+                .await
+                .map_err(|e| format!("Failed to load SMTP settings: {}", e))?;
+            let date = digest
+                .created_at
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string();
+            let html = render_summary_email(&digest.title, &date, &digest.content);
+            send_smtp(
+                &config,
+                password.as_deref(),
+                &mailboxes,
+                &digest.title,
+                &digest.content,
+                &html,
+            )
+            .await
+        }
+        .await;
+        if let Err(e) = sent {
+            errors.push(format!("Email: {}", e));
+        }
+    }
+
+    if let Some(url) = webhook_url.filter(|u| !u.trim().is_empty()) {
+        let body = serde_json::json!({
+            "text": format!("*{}*\n\n{}", digest.title, digest.content),
+            "title": digest.title,
+            "digestId": digest.id,
+        });
+        let posted = reqwest::Client::new()
+            .post(url)
+            .timeout(std::time::Duration::from_secs(30))
+            .json(&body)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        if let Err(e) = posted {
+            errors.push(format!("Webhook: {}", e));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("; "))
+    }
+}
+
+/// Generates and delivers the digest the schedule is due for. A missed slot
+/// gets the digest of the period that ended at it.
+async fn run_scheduled(
+    pool: &SqlitePool,
+    app_data_dir: Option<PathBuf>,
+    schedule: &DigestSchedule,
+) -> Result<Option<Digest>, String> {
+    let (start, end) = period_bounds(schedule.period, schedule.latest_slot(&Local::now()));
+    let connection = LlmConnection::from_settings(pool, app_data_dir).await?;
+    let digest = generate_digest(pool, &connection, schedule.period.as_str(), start, end).await?;
+    if let Some(digest) = &digest {
+        if let Err(e) = deliver_digest(
+            pool,
+            digest,
+            &schedule.recipients,
+            schedule.webhook_url.as_deref(),
+        )
+        .await
+        {
+            warn!("Failed to deliver digest {}: {}", digest.id, e);
+        }
+    }
+    Ok(digest)
+}
+
+/// Checks the schedule while the app runs and generates the digest when due
+pub async fn run_scheduler<R: Runtime>(app: AppHandle<R>) {
+    let mut interval =
+        tokio::time::interval(std::time::Duration::from_secs(SCHEDULER_INTERVAL_SECS));
+    loop {
+        interval.tick().await;
+        let Some(state) = app.try_state::<AppState>() else {
+            continue;
+        };
+        let pool = state.db_manager.pool().clone();
+        let mut schedule = match load_schedule(&pool).await {
+            Ok(schedule) => schedule,
+            Err(e) => {
+                warn!("Skipping scheduled digest: {}", e);
+                continue;
+            }
+        };
+        if !schedule.is_due(&Local::now()) {
+            continue;
+        }
+
+        match run_scheduled(&pool, app.path().app_data_dir().ok(), &schedule).await {
+            Ok(digest) => {
+                if let Some(digest) = digest {
+                    if let Err(e) = events::emit(&app, &digest) {
+                        warn!("Failed to emit {}: {}", DIGEST_EVENT, e);
+                    }
+                }
+                schedule.record_success(Utc::now());
+            }
+            Err(e) => {
+                schedule.record_failure(Utc::now());
+                error!(
+                    "Scheduled digest failed (attempt {}), retrying after {:?}: {}",
+                    schedule.failed_attempts,
+                    schedule.retry_at(),
+                    e
+                );
+            }
+        }
+        if let Err(e) = save_schedule(&pool, &schedule).await {
+            warn!("{}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::DateTimeUtc;
+
+    #[test]
+    fn periods_end_before_today() {
+        let today = NaiveDate::from_ymd_opt(2026, 4, 13).unwrap();
+        assert_eq!(
+            period_dates(DigestPeriod::Daily, today),
+            (NaiveDate::from_ymd_opt(2026, 4, 12).unwrap(), today)
+        );
+        assert_eq!(
+            period_dates(DigestPeriod::Weekly, today),
+            (NaiveDate::from_ymd_opt(2026, 4, 6).unwrap(), today)
+        );
+    }
+
+    #[test]
+    fn schedule_runs_once_a_day_after_its_hour() {
+        // 2026-04-13 is a Monday
+        let at = |day: u32, hour: u32| Utc.with_ymd_and_hms(2026, 4, day, hour, 0, 0).unwrap();
+        let mut schedule = DigestSchedule {
+            enabled: true,
+            ..DigestSchedule::default()
+        };
+        assert!(!schedule.is_due(&at(13, 7)));
+        assert!(schedule.is_due(&at(13, 8)));
+
+        schedule.last_run_at = Some(at(13, 8));
+        assert!(!schedule.is_due(&at(13, 20)));
+        assert!(schedule.is_due(&at(14, 9)));
+
+        schedule.period = DigestPeriod::Weekly;
+        assert!(!schedule.is_due(&at(14, 9)));
+        assert!(schedule.is_due(&at(20, 9)));
+
+        schedule.enabled = false;
+        assert!(!schedule.is_due(&at(20, 9)));
+    }
+
+    #[test]
+    fn missed_slots_are_caught_up_but_not_backfilled() {
+        // 2026-04-13 is a Monday
+        let at = |day: u32, hour: u32| Utc.with_ymd_and_hms(2026, 4, day, hour, 0, 0).unwrap();
+        let mut schedule = DigestSchedule {
+            enabled: true,
+            period: DigestPeriod::Weekly,
+            ..DigestSchedule::default()
+        };
+        // A new weekly schedule waits for its first Monday
+        assert!(!schedule.is_due(&at(15, 10)));
+
+        // The app was closed on Monday the 13th; Wednesday catches up on it
+        schedule.last_run_at = Some(at(6, 8));
+        assert!(schedule.is_due(&at(15, 10)));
+        assert_eq!(
+            schedule.latest_slot(&at(15, 10)),
+            NaiveDate::from_ymd_opt(2026, 4, 13).unwrap()
+        );
+
+        schedule.period = DigestPeriod::Daily;
+        schedule.last_run_at = Some(at(10, 8));
+        assert!(schedule.is_due(&at(13, 7)));
+        assert_eq!(
+            schedule.latest_slot(&at(13, 7)),
+            NaiveDate::from_ymd_opt(2026, 4, 12).unwrap()
+        );
+    }
+
+    #[test]
+    fn failed_runs_back_off() {
+        let at =
+            |hour: u32, minute: u32| Utc.with_ymd_and_hms(2026, 4, 13, hour, minute, 0).unwrap();
+        let mut schedule = DigestSchedule {
+            enabled: true,
+            ..DigestSchedule::default()
+        };
+        schedule.record_failure(at(8, 0));
+        assert!(!schedule.is_due(&at(8, 30)));
+        assert!(schedule.is_due(&at(9, 0)));
+
+        schedule.record_failure(at(9, 0));
+        assert_eq!(schedule.failed_attempts, 2);
+        assert!(!schedule.is_due(&at(10, 30)));
+        assert!(schedule.is_due(&at(11, 0)));
+
+        schedule.record_success(at(11, 0));
+        assert_eq!(schedule.failed_attempts, 0);
+        assert!(schedule.retry_at().is_none());
+        assert!(!schedule.is_due(&at(12, 0)));
+    }
+
+    #[test]
+    fn action_items_are_listed_open_first() {
+        let item = |text: &str, status: &str, owner: Option<&str>, due: Option<&str>| ActionItem {
+            id: "action-1".to_string(),
+            meeting_id: "meeting-1".to_string(),
+            text: text.to_string(),
+            owner: owner.map(str::to_string),
+            due_date: due.map(str::to_string),
+            status: status.to_string(),
+            source: "summary".to_string(),
+            created_at: DateTimeUtc(Utc::now()),
+            updated_at: DateTimeUtc(Utc::now()),
+            external_tracker: None,
+            external_key: None,
+            external_url: None,
+            reminded_on: None,
+            snoozed_until: None,
+        };
+        let done = item("Book the venue", "done", None, None);
+        let open = item("Send the budget", "open", Some("Aiko"), Some("Friday"));
+        assert_eq!(
+            render_action_items(&[("Planning", &done), ("Weekly sync", &open)]),
+            "- [ ] Send the budget — Aiko (due Friday) · _Weekly sync_\n- [x] Book the venue · _Planning_"
+        );
+        assert_eq!(render_action_items(&[]), "_No action items._");
+    }
+}
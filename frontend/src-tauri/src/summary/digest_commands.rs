@@ -0,0 +1,170 @@
+use crate::audit::{self, AuditAction};
+use crate::database::repositories::digest::{Digest, DigestsRepository};
+use crate::state::AppState;
+use crate::summary::digest::{
+    deliver_digest, generate_digest, load_schedule, period_bounds, save_schedule, DigestPeriod,
+    DigestSchedule,
+};
+use crate::summary::llm_connection::LlmConnection;
+use chrono::{DateTime, Local, Utc};
+use log::{error as log_error, info as log_info, warn as log_warn};
+use tauri::{AppHandle, Manager, Runtime};
+
+const DEFAULT_DIGEST_LIMIT: i64 = 50;
+
+/// Generates a digest now. `start`/`end` select a custom range; otherwise the
+/// last `period` ("daily" or "weekly", default daily) ending today is used.
+/// With `deliver`, the digest is also sent to the schedule's recipients and
+/// webhook. Returns None when no meeting falls in the period.
+#[tauri::command]
+pub async fn api_generate_digest<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    period: Option<String>,
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+    deliver: Option<bool>,
+    provider: Option<String>,
+    model: Option<String>,
+) -> Result<Option<Digest>, String> {
+    log_info!(
+        "api_generate_digest called (period: {:?}, start: {:?}, end: {:?})",
+        period,
+        start,
+        end
+    );
+    let (label, start, end) = match (start, end) {
+        (Some(start), Some(end)) if start < end => ("custom".to_string(), start, end),
+        (Some(_), Some(_)) => return Err("The range must end after it starts".to_string()),
+        (None, None) => {
+            let period = DigestPeriod::parse(period.as_deref().unwrap_or("daily"))?;
+            let (start, end) = period_bounds(period, Local::now().date_naive());
+            (period.as_str().to_string(), start, end)
+        }
+        _ => return Err("Give both the start and the end of the range".to_string()),
+    };
+    let pool = state.db_manager.pool();
+
+    let app_data_dir = app.path().app_data_dir().ok();
+    let connection = match (provider, model) {
+        (Some(provider), Some(model)) => {
+            LlmConnection::resolve(pool, &provider, &model, app_data_dir).await?
+        }
+        _ => LlmConnection::from_settings(pool, app_data_dir).await?,
+    };
+
+    let digest = generate_digest(pool, &connection, &label, start, end)
+        .await
+        .map_err(|e| {
+            log_error!("Digest generation failed: {}", e);
+            e
+        })?;
+    if let (Some(digest), true) = (&digest, deliver.unwrap_or(false)) {
+        let schedule = load_schedule(pool).await?;
+        if schedule.recipients.is_empty() && schedule.webhook_url.is_none() {
+            log_warn!("Digest {} has nowhere to be delivered", digest.id);
+        } else {
+            deliver_digest(
+                pool,
+                digest,
+                &schedule.recipients,
+                schedule.webhook_url.as_deref(),
+            )
+            .await?;
+        }
+    }
+    Ok(digest)
+}
+
+/// Stored digests, newest first
+#[tauri::command]
+pub async fn api_list_digests<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    limit: Option<i64>,
+) -> Result<Vec<Digest>, String> {
+    DigestsRepository::list(
+        state.db_manager.pool(),
+        limit.unwrap_or(DEFAULT_DIGEST_LIMIT).max(1),
+    )
+    .await
+    .map_err(|e| {
+        log_error!("Failed to list digests: {}", e);
+        format!("Failed to list digests: {}", e)
+    })
+}
+
+#[tauri::command]
+pub async fn api_delete_digest<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    digest_id: String,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_delete_digest called for {}", digest_id);
+    match DigestsRepository::delete(state.db_manager.pool(), &digest_id).await {
+        Ok(true) => Ok(serde_json::json!({
+            "status": "success",
+            "message": "Digest deleted"
+        })),
+        Ok(false) => Err(format!("Digest not found: {}", digest_id)),
+        Err(e) => {
+            log_error!("Failed to delete digest {}: {}", digest_id, e);
+            Err(format!("Failed to delete digest: {}", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn api_get_digest_schedule<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<DigestSchedule, String> {
+    load_schedule(state.db_manager.pool()).await
+}
+
+/// Saves the digest schedule. The time of the last run is kept, so saving
+/// never makes a digest that already ran today run again.
+#[tauri::command]
+pub async fn api_set_digest_schedule<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    schedule: DigestSchedule,
+) -> Result<DigestSchedule, String> {
+    log_info!(
+        "api_set_digest_schedule called (enabled: {}, period: {})",
+        schedule.enabled,
+        schedule.period.as_str()
+    );
+    let pool = state.db_manager.pool();
+    let mut schedule = DigestSchedule {
+        recipients: schedule
+            .recipients
+            .iter()
+            .map(|r| r.trim().to_string())
+            .filter(|r| !r.is_empty())
+            .collect(),
+        webhook_url: schedule
+            .webhook_url
+            .map(|url| url.trim().to_string())
+            .filter(|url| !url.is_empty()),
+        ..schedule
+    };
+    schedule.validate()?;
+    schedule.last_run_at = load_schedule(pool).await?.last_run_at;
+    save_schedule(pool, &schedule).await?;
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "digest_schedule",
+        None,
+        serde_json::json!({
+            "enabled": schedule.enabled,
+            "period": schedule.period.as_str(),
+            "hour": schedule.hour,
+            "recipients": schedule.recipients.len(),
+            "webhook": schedule.webhook_url.is_some(),
+        }),
+    )
+    .await;
+    Ok(schedule)
+}
@@ -0,0 +1,445 @@
+//! Named entities mentioned in transcript segments.
+//!
+//! Two extractors produce the same [`ExtractedEntity`] list per segment:
+//! - `local`: capitalization and keyword rules, offline and instant. People
+//!   are recognized from titles, the meeting's speaker names and two- or
+//!   three-word capitalized names; companies from suffixes such as "Corp" or
+//!   "Inc"; products from version numbers and mixed-case words; dates from
+//!   weekday and month names, quarters and ISO dates. Other capitalized words
+//!   in the middle of a sentence are kept as `other`.
+//! - `llm`: the configured summary model, given segments in batches
+//!
+//! Every entity carries a normalized key (lowercase, company suffix dropped)
+//! so "Acme Corp", "ACME Corporation" and "Acme" are the same entity.
+
+use crate::summary::segment_labeling::json_array;
+use serde::{Deserialize, Serialize};
+
+pub const ENTITY_SYSTEM_PROMPT: &str = "You extract named entities from meeting transcript \
+segments. For every person, company or organization, product and date mentioned in a numbered \
+segment return one JSON object with the fields \"i\" (the segment number), \"kind\" (\"person\", \
+\"organization\", \"product\" or \"date\") and \"name\" (as written in the segment). Ignore the \
+speaker labels in brackets. Answer with a JSON array only, no prose.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EntityKind {
+    Person,
+    Organization,
+    Product,
+    Date,
+    Other,
+}
+
+impl EntityKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Person => "person",
+            Self::Organization => "organization",
+            Self::Product => "product",
+            Self::Date => "date",
+            Self::Other => "other",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "person" | "people" => Some(Self::Person),
+            "organization" | "organisation" | "company" => Some(Self::Organization),
+            "product" => Some(Self::Product),
+            "date" => Some(Self::Date),
+            "other" => Some(Self::Other),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtractedEntity {
+    pub kind: EntityKind,
+    /// As written in the transcript
+    pub name: String,
+    pub normalized: String,
+}
+
+impl ExtractedEntity {
+    fn new(kind: EntityKind, name: String) -> Option<Self> {
+        let normalized = normalize_entity(&name);
+        if normalized.is_empty() {
+            return None;
+        }
+        Some(Self {
+            kind,
+            name,
+            normalized,
+        })
+    }
+}
+
+const ORG_SUFFIXES: &[&str] = &[
+    "inc", "corp", "corporation", "co", "company", "ltd", "llc", "plc", "gmbh", "ag", "sa",
+    "group", "holdings", "labs", "technologies", "systems", "partners",
+];
+const TITLES: &[&str] = &["mr", "mrs", "ms", "dr", "prof"];
+const WEEKDAYS: &[&str] = &[
+    "monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday",
+];
+const MONTHS: &[&str] = &[
+    "january", "february", "march", "april", "may", "june", "july", "august", "september",
+    "october", "november", "december", "jan", "feb", "mar", "apr", "jun", "jul", "aug", "sep",
+    "sept", "oct", "nov", "dec",
+];
+/// Capitalized words that start sentences or fill speech, never entities
+const STOPWORDS: &[&str] = &[
+    "i", "i'm", "i'll", "i've", "i'd", "we", "we're", "we'll", "you", "they", "he", "she", "it",
+    "it's", "the", "a", "an", "and", "but", "or", "so", "yes", "no", "okay", "ok", "hi", "hello",
+    "hey", "thanks", "thank", "well", "yeah", "this", "that", "these", "those", "what", "when",
+    "where", "why", "how", "who", "if", "then", "also", "just", "let's", "let", "can", "could",
+    "would", "should", "will", "do", "does", "did", "is", "are", "was", "our", "my", "your",
+    "their", "there", "here", "sure", "right", "great", "good", "actually", "maybe", "please",
+    "now", "alright", "sorry", "um", "uh", "oh", "today", "tomorrow", "yesterday",
+];
+
+/// Lowercases, collapses whitespace, drops a trailing possessive and a
+/// trailing company suffix. Used for stored keys and for lookups.
+pub fn normalize_entity(name: &str) -> String {
+    let mut words: Vec<String> = name
+        .split_whitespace()
+        .map(|w| {
+            w.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|w| !w.is_empty())
+        .collect();
+    if let Some(last) = words.last_mut() {
+        if let Some(stripped) = last.strip_suffix("'s").or_else(|| last.strip_suffix("’s")) {
+            *last = stripped.to_string();
+        }
+    }
+    if words.len() > 1 && words.last().is_some_and(|w| ORG_SUFFIXES.contains(&w.as_str())) {
+        words.pop();
+    }
+    words.join(" ")
+}
+
+struct Word<'a> {
+    /// The word without surrounding punctuation
+    core: &'a str,
+    /// Punctuation after the word ends a name (comma, sentence end)
+    breaks: bool,
+    ends_sentence: bool,
+}
+
+fn tokenize(text: &str) -> Vec<Word<'_>> {
+    text.split_whitespace()
+        .filter_map(|raw| {
+            let core = raw.trim_matches(|c: char| !c.is_alphanumeric());
+            if core.is_empty() {
+                return None;
+            }
+            let trailing = &raw[raw.rfind(core).map(|i| i + core.len()).unwrap_or(raw.len())..];
+            let lower = core.to_lowercase();
+            let ends_sentence =
+                trailing.contains(['.', '!', '?']) && !TITLES.contains(&lower.as_str());
+            Some(Word {
+                core,
+                breaks: ends_sentence || trailing.contains([',', ';', ':', ')']),
+                ends_sentence,
+            })
+        })
+        .collect()
+}
+
+fn is_capitalized(word: &str) -> bool {
+    word.chars().next().is_some_and(|c| c.is_uppercase())
+}
+
+fn is_stopword(word: &str) -> bool {
+    STOPWORDS.contains(&word.to_lowercase().as_str())
+}
+
+fn day_number(word: &str) -> bool {
+    let digits = word.trim_end_matches(|c: char| c.is_alphabetic());
+    let suffix = &word[digits.len()..];
+    matches!(suffix.to_lowercase().as_str(), "" | "st" | "nd" | "rd" | "th")
+        && digits.parse::<u32>().is_ok_and(|d| (1..=31).contains(&d))
+}
+
+fn year(word: &str) -> bool {
+    word.len() == 4 && word.parse::<u32>().is_ok_and(|y| (1900..=2100).contains(&y))
+}
+
+fn iso_date(word: &str) -> bool {
+    let parts: Vec<&str> = word.split('-').collect();
+    parts.len() == 3
+        && year(parts[0])
+        && parts[1].len() == 2
+        && parts[2].len() == 2
+        && parts[1..].iter().all(|p| p.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Number of words forming a date starting at `i`, if any
+fn date_at(words: &[Word], i: usize) -> Option<usize> {
+    let word = words[i].core;
+    let lower = word.to_lowercase();
+    let next = |offset: usize| -> Option<&str> {
+        let k = i + offset;
+        (k < words.len() && !words[k - 1].ends_sentence).then(|| words[k].core)
+    };
+
+    if iso_date(word) {
+        return Some(1);
+    }
+    if word.len() == 2 && word.starts_with('Q') && matches!(&word[1..], "1" | "2" | "3" | "4") {
+        return Some(if next(1).is_some_and(year) { 2 } else { 1 });
+    }
+    if matches!(lower.as_str(), "next" | "this" | "last")
+        && next(1).is_some_and(|w| WEEKDAYS.contains(&w.to_lowercase().as_str()))
+    {
+        return Some(2);
+    }
+    if WEEKDAYS.contains(&lower.as_str()) {
+        return Some(1);
+    }
+    if is_capitalized(word) && MONTHS.contains(&lower.as_str()) {
+        // "March 5th, 2026", "March 2026"
+        let mut len = 1;
+        if next(len).is_some_and(day_number) {
+            len += 1;
+        }
+        if next(len).is_some_and(year) {
+            len += 1;
+        }
+        // "May" alone is usually the verb
+        if len > 1 || lower != "may" {
+            return Some(len);
+        }
+        return None;
+    }
+    if day_number(word) && !words[i].ends_sentence {
+        // "5 March", "5th of March 2026"
+        let mut len = 1;
+        if next(len).is_some_and(|w| w.eq_ignore_ascii_case("of")) {
+            len += 1;
+        }
+        if next(len).is_some_and(|w| is_capitalized(w) && MONTHS.contains(&w.to_lowercase().as_str())) {
+            len += 1;
+            if next(len).is_some_and(year) {
+                len += 1;
+            }
+            return Some(len);
+        }
+    }
+    None
+}
+
+/// "iPhone", "GitHub"
+fn is_mixed_case(word: &str) -> bool {
+    let chars: Vec<char> = word.chars().collect();
+    chars
+        .windows(2)
+        .any(|pair| pair[0].is_lowercase() && pair[1].is_uppercase())
+}
+
+fn is_product_word(word: &str) -> bool {
+    is_mixed_case(word) || word.chars().any(|c| c.is_ascii_digit())
+}
+
+fn is_version(word: &str) -> bool {
+    word.chars().next().is_some_and(|c| c.is_ascii_digit())
+        && word.chars().all(|c| c.is_ascii_digit() || c == '.')
+}
+
+/// Rule-based extraction for one segment. `known_people` are names already
+/// attached to the meeting (speaker names, participants), matched by full
+/// name or first name.
+pub fn extract_local(text: &str, known_people: &[String]) -> Vec<ExtractedEntity> {
+    let words = tokenize(text);
+    let known: Vec<String> = known_people.iter().map(|p| p.to_lowercase()).collect();
+    let is_known = |name: &str| {
+        let name = name.to_lowercase();
+        known
+            .iter()
+            .any(|k| *k == name || k.split_whitespace().next() == Some(name.as_str()))
+    };
+
+    let mut entities: Vec<ExtractedEntity> = Vec::new();
+    let mut push = |kind: EntityKind, name: String| {
+        if let Some(entity) = ExtractedEntity::new(kind, name) {
+            if !entities
+                .iter()
+                .any(|e| e.kind == entity.kind && e.normalized == entity.normalized)
+            {
+                entities.push(entity);
+            }
+        }
+    };
+
+    let mut i = 0;
+    while i < words.len() {
+        let sentence_start = i == 0 || words[i - 1].ends_sentence;
+        if let Some(len) = date_at(&words, i) {
+            let name: Vec<&str> = words[i..i + len].iter().map(|w| w.core).collect();
+            push(EntityKind::Date, name.join(" "));
+            i += len;
+            continue;
+        }
+        let word = words[i].core;
+        if !(is_capitalized(word) || is_mixed_case(word)) || is_stopword(word) {
+            i += 1;
+            continue;
+        }
+
+        let titled = TITLES.contains(&word.to_lowercase().as_str())
+            && i + 1 < words.len()
+            && !words[i].breaks
+            && is_capitalized(words[i + 1].core);
+        let start = if titled { i + 1 } else { i };
+        let mut end = start + 1;
+        while end < words.len()
+            && !words[end - 1].breaks
+            && is_capitalized(words[end].core)
+            && !is_stopword(words[end].core)
+            && date_at(&words, end).is_none()
+        {
+            end += 1;
+        }
+        // "Windows 11", "Figma 2"
+        let mut versioned = false;
+        if end < words.len() && !words[end - 1].breaks && is_version(words[end].core) {
+            end += 1;
+            versioned = true;
+        }
+
+        let run = &words[start..end];
+        let name = run.iter().map(|w| w.core).collect::<Vec<_>>().join(" ");
+        let last = run[run.len() - 1].core.to_lowercase();
+        let kind = if titled || is_known(&name) {
+            Some(EntityKind::Person)
+        } else if run.len() > 1 && ORG_SUFFIXES.contains(&last.as_str()) {
+            Some(EntityKind::Organization)
+        } else if versioned || run.iter().any(|w| is_product_word(w.core)) {
+            Some(EntityKind::Product)
+        } else if run.len() == 1 && sentence_start {
+            // A lone capitalized word opening a sentence says nothing
+            None
+        } else if (2..=3).contains(&run.len()) && run.iter().all(|w| w.core.chars().all(char::is_alphabetic)) {
+            Some(EntityKind::Person)
+        } else {
+            Some(EntityKind::Other)
+        };
+        if let Some(kind) = kind {
+            push(kind, name);
+        }
+        i = end;
+    }
+    entities
+}
+
+#[derive(Deserialize)]
+struct LlmEntity {
+    i: usize,
+    kind: String,
+    name: String,
+}
+
+/// Parses the model's JSON array into the entities of each segment.
+/// Entries with an unknown kind or segment number are dropped.
+pub fn parse_entity_response(
+    response: &str,
+    segment_count: usize,
+) -> Result<Vec<Vec<ExtractedEntity>>, String> {
+    let labels: Vec<LlmEntity> = serde_json::from_str(json_array(response)?)
+        .map_err(|e| format!("Model returned invalid JSON: {}", e))?;
+
+    let mut results: Vec<Vec<ExtractedEntity>> = vec![Vec::new(); segment_count];
+    for label in labels {
+        let (Some(kind), Some(slot)) = (EntityKind::parse(&label.kind), results.get_mut(label.i))
+        else {
+            continue;
+        };
+        let name = label.name.split_whitespace().collect::<Vec<_>>().join(" ");
+        if let Some(entity) = ExtractedEntity::new(kind, name) {
+            if !slot
+                .iter()
+                .any(|e| e.kind == entity.kind && e.normalized == entity.normalized)
+            {
+                slot.push(entity);
+            }
+        }
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found(entities: &[ExtractedEntity]) -> Vec<(EntityKind, &str)> {
+        entities.iter().map(|e| (e.kind, e.name.as_str())).collect()
+    }
+
+    #[test]
+    fn normalizes_names() {
+        assert_eq!(normalize_entity("  ACME   Corp. "), "acme");
+        assert_eq!(normalize_entity("Acme's"), "acme");
+        assert_eq!(normalize_entity("Corp"), "corp");
+        assert_eq!(normalize_entity("Sarah Connor"), "sarah connor");
+    }
+
+    #[test]
+    fn extracts_people_companies_and_products() {
+        let entities = extract_local(
+            "So we met with Sarah Connor from Acme Corp, and Dr. Silberman asked about Windows 11 and the iPhone rollout. Priya agreed.",
+            &["Priya Patel".to_string()],
+        );
+        assert_eq!(
+            found(&entities),
+            vec![
+                (EntityKind::Person, "Sarah Connor"),
+                (EntityKind::Organization, "Acme Corp"),
+                (EntityKind::Person, "Silberman"),
+                (EntityKind::Product, "Windows 11"),
+                (EntityKind::Product, "iPhone"),
+                (EntityKind::Person, "Priya"),
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_dates() {
+        let entities = extract_local(
+            "Can we ship by March 5th, or next Friday? The Q3 2026 review is on 2026-09-30. May I add 5 June too?",
+            &[],
+        );
+        assert_eq!(
+            found(&entities),
+            vec![
+                (EntityKind::Date, "March 5th"),
+                (EntityKind::Date, "next Friday"),
+                (EntityKind::Date, "Q3 2026"),
+                (EntityKind::Date, "2026-09-30"),
+                (EntityKind::Date, "5 June"),
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_sentence_openers_and_filler() {
+        assert!(extract_local("Okay. Thanks everyone. Budget looks fine.", &[]).is_empty());
+        assert_eq!(
+            found(&extract_local("we asked Globex about pricing", &[])),
+            vec![(EntityKind::Other, "Globex")]
+        );
+    }
+
+    #[test]
+    fn parses_model_entities() {
+        let response = "```json\n[{\"i\":0,\"kind\":\"company\",\"name\":\"Acme  Inc\"},\
+            {\"i\":0,\"kind\":\"organization\",\"name\":\"ACME\"},{\"i\":1,\"kind\":\"place\",\"name\":\"Paris\"},\
+            {\"i\":7,\"kind\":\"person\",\"name\":\"Bob\"}]\n```";
+        let parsed = parse_entity_response(response, 2).unwrap();
+        assert_eq!(found(&parsed[0]), vec![(EntityKind::Organization, "Acme Inc")]);
+        assert!(parsed[1].is_empty());
+    }
+}
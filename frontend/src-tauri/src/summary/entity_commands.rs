@@ -0,0 +1,187 @@
+use crate::database::repositories::entity::{
+    EntityMention, EntityRepository, EntitySummary, MeetingEntity,
+};
+use crate::database::repositories::segment_analysis::SegmentAnalysisRepository;
+use crate::state::AppState;
+use crate::summary::entities::{extract_local, parse_entity_response, ENTITY_SYSTEM_PROMPT};
+use crate::summary::llm_connection::LlmConnection;
+use crate::summary::segment_labeling::label_in_batches;
+use log::{error as log_error, info as log_info};
+use serde::Serialize;
+use tauri::{AppHandle, Manager, Runtime};
+
+#[derive(Debug, Serialize)]
+pub struct EntityRunReport {
+    pub meeting_id: String,
+    pub method: String,
+    pub segments: usize,
+    pub mentions: usize,
+    pub entities: Vec<MeetingEntity>,
+}
+
+/// Extracts named entities from every segment of a meeting, replacing any
+/// earlier extraction. `method` is "local" (default, offline rules) or "llm"
+/// (the summary model from settings unless `provider`/`model` are given).
+#[tauri::command]
+pub async fn api_extract_meeting_entities<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    method: Option<String>,
+    provider: Option<String>,
+    model: Option<String>,
+) -> Result<EntityRunReport, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    let method = method.unwrap_or_else(|| "local".to_string());
+    log_info!(
+        "api_extract_meeting_entities called for {} with method {}",
+        meeting_id,
+        method
+    );
+    let pool = state.db_manager.pool();
+
+    let segments: Vec<_> = SegmentAnalysisRepository::load_segments(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcript: {}", e))?
+        .into_iter()
+        .filter(|s| !s.transcript.trim().is_empty())
+        .collect();
+    if segments.is_empty() {
+        return Err(format!(
+            "Meeting '{}' has no transcript to analyze",
+            meeting_id
+        ));
+    }
+
+    let per_segment = match method.as_str() {
+        "local" => {
+            let known_people = EntityRepository::known_people(pool, &meeting_id)
+                .await
+                .map_err(|e| format!("Failed to load speaker names: {}", e))?;
+            segments
+                .iter()
+                .map(|s| extract_local(&s.transcript, &known_people))
+                .collect::<Vec<_>>()
+        }
+        "llm" => {
+            let app_data_dir = app.path().app_data_dir().ok();
+            let connection = match (provider, model) {
+                (Some(provider), Some(model)) => {
+                    LlmConnection::resolve(pool, &provider, &model, app_data_dir).await?
+                }
+                _ => LlmConnection::from_settings(pool, app_data_dir).await?,
+            };
+            let inputs: Vec<_> = segments
+                .iter()
+                .map(|s| (s.speaker.as_deref(), s.transcript.as_str()))
+                .collect();
+            label_in_batches(
+                &connection,
+                ENTITY_SYSTEM_PROMPT,
+                &inputs,
+                parse_entity_response,
+            )
+            .await
+            .map_err(|e| format!("Failed to extract entities: {}", e))?
+        }
+        other => {
+            return Err(format!(
+                "Unknown extraction method '{}'. Use 'local' or 'llm'",
+                other
+            ))
+        }
+    };
+
+    let mentions: Vec<_> = segments
+        .iter()
+        .zip(per_segment)
+        .flat_map(|(s, entities)| entities.into_iter().map(|e| (s.id.clone(), e)))
+        .collect();
+    EntityRepository::save_for_meeting(pool, &meeting_id, &mentions, &method)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to save entities for {}: {}", meeting_id, e);
+            format!("Failed to save entities: {}", e)
+        })?;
+    let entities = EntityRepository::get_meeting_entities(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load entities: {}", e))?;
+
+    log_info!(
+        "Extracted {} entities ({} mentions) from {}",
+        entities.len(),
+        mentions.len(),
+        meeting_id
+    );
+    Ok(EntityRunReport {
+        meeting_id,
+        method,
+        segments: segments.len(),
+        mentions: mentions.len(),
+        entities,
+    })
+}
+
+#[tauri::command]
+pub async fn api_get_meeting_entities<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<MeetingEntity>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_get_meeting_entities called for meeting_id: {}",
+        meeting_id
+    );
+    EntityRepository::get_meeting_entities(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to get entities for {}: {}", meeting_id, e);
+            format!("Failed to get entities: {}", e)
+        })
+}
+
+/// Entity browser: every extracted entity, most mentioned first, optionally
+/// narrowed by kind and a name prefix
+#[tauri::command]
+pub async fn api_list_entities<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    kind: Option<String>,
+    query: Option<String>,
+    limit: Option<i64>,
+) -> Result<Vec<EntitySummary>, String> {
+    log_info!(
+        "api_list_entities called with kind: {:?}, query: {:?}",
+        kind,
+        query
+    );
+    EntityRepository::list_entities(
+        state.db_manager.pool(),
+        kind.as_deref(),
+        query.as_deref(),
+        limit,
+    )
+    .await
+    .map_err(|e| {
+        log_error!("Failed to list entities: {}", e);
+        format!("Failed to list entities: {}", e)
+    })
+}
+
+/// All meetings mentioning an entity, e.g. "Acme Corp"
+#[tauri::command]
+pub async fn api_find_meetings_by_entity<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    name: String,
+    kind: Option<String>,
+) -> Result<Vec<EntityMention>, String> {
+    log_info!("api_find_meetings_by_entity called for: {}", name);
+    EntityRepository::meetings_mentioning(state.db_manager.pool(), &name, kind.as_deref())
+        .await
+        .map_err(|e| {
+            log_error!("Failed to find meetings mentioning {}: {}", name, e);
+            format!("Failed to find meetings: {}", e)
+        })
+}
@@ -0,0 +1,187 @@
+//! Summary feedback: thumbs up/down and comments on generated summaries.
+//!
+//! Meetings of a recurring series share a title once dates and numbers are
+//! removed ("Weekly sync 2026-03-04" -> "weekly sync"). The newest feedback
+//! on a series is added to the user context of the next summary prompt, so
+//! "too long" or "list decisions first" carries over to the following
+//! meetings.
+
+use log::warn;
+use sqlx::SqlitePool;
+
+use crate::database::repositories::{
+    meeting::MeetingsRepository,
+    summary_feedback::{SummaryFeedback, SummaryFeedbackRepository},
+};
+
+/// Feedback entries considered when steering a summary
+pub const SERIES_FEEDBACK_LIMIT: i64 = 10;
+/// Comments quoted in the prompt, newest first
+const MAX_QUOTED_COMMENTS: usize = 5;
+/// Longest comment accepted, in characters
+pub const MAX_COMMENT_CHARS: usize = 1000;
+/// Comments are shortened to this in the prompt
+const QUOTED_COMMENT_CHARS: usize = 300;
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+fn is_month(word: &str) -> bool {
+    word.chars().count() >= 3 && MONTHS.iter().any(|month| month.starts_with(word))
+}
+
+/// The series a meeting title belongs to: lowercased, without dates,
+/// numbers, ordinals and month names. None when nothing is left.
+pub fn series_key(title: &str) -> Option<String> {
+    let mut cleaned = String::new();
+    let mut chars = title.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_numeric() {
+            // The number and the date separators or units right after it
+            while let Some(&next) = chars.peek() {
+                if next.is_numeric() || matches!(next, '年' | '月' | '日' | '/' | '.' | '-' | ':')
+                {
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            cleaned.push(' ');
+        } else if c.is_alphanumeric() {
+            cleaned.extend(c.to_lowercase());
+        } else {
+            cleaned.push(' ');
+        }
+    }
+    let key = cleaned
+        .split_whitespace()
+        .filter(|word| !is_month(word) && !matches!(*word, "st" | "nd" | "rd" | "th"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!key.is_empty()).then_some(key)
+}
+
+/// The series key of a meeting; a title with no series falls back to the
+/// meeting itself
+pub async fn meeting_series_key(pool: &SqlitePool, meeting_id: &str) -> Result<String, String> {
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting '{}' not found", meeting_id))?;
+    Ok(series_key(&meeting.title).unwrap_or_else(|| meeting_id.to_string()))
+}
+
+fn shorten(comment: &str) -> String {
+    let mut short: String = comment.chars().take(QUOTED_COMMENT_CHARS).collect();
+    if comment.chars().count() > QUOTED_COMMENT_CHARS {
+        short.push_str("...");
+    }
+    short
+}
+
+/// Prompt text describing earlier feedback (newest first), or None without any
+pub fn feedback_guidance(feedback: &[SummaryFeedback]) -> Option<String> {
+    if feedback.is_empty() {
+        return None;
+    }
+    let helpful = feedback.iter().filter(|f| f.helpful).count();
+    let mut guidance = format!(
+        "Feedback on earlier summaries of this meeting series: {} of the last {} were rated helpful.",
+        helpful,
+        feedback.len()
+    );
+    let comments: Vec<String> = feedback
+        .iter()
+        .filter_map(|f| {
+            let comment = f.comment.as_deref()?.trim();
+            (!comment.is_empty()).then(|| {
+                let verdict = if f.helpful { "Liked" } else { "Disliked" };
+                format!("- {}: \"{}\"", verdict, shorten(comment))
+            })
+        })
+        .take(MAX_QUOTED_COMMENTS)
+        .collect();
+    if !comments.is_empty() {
+        guidance.push_str("\nReader comments, newest first:\n");
+        guidance.push_str(&comments.join("\n"));
+    }
+    guidance.push_str(
+        "\nFollow this feedback for the style, length and focus of the summary. It never changes which facts are reported.",
+    );
+    Some(guidance)
+}
+
+/// Appends the series feedback for `meeting_id` to the user's custom prompt.
+/// Failing to read feedback never blocks a summary.
+pub async fn steer_prompt(pool: &SqlitePool, meeting_id: &str, custom_prompt: String) -> String {
+    let feedback = match meeting_series_key(pool, meeting_id).await {
+        Ok(key) => SummaryFeedbackRepository::recent_for_series(
+            pool,
+            &key,
+            meeting_id,
+            SERIES_FEEDBACK_LIMIT,
+        )
+        .await
+        .map_err(|e| e.to_string()),
+        Err(e) => Err(e),
+    };
+    let guidance = match feedback {
+        Ok(feedback) => feedback_guidance(&feedback),
+        Err(e) => {
+            warn!("Summary feedback unavailable for {}: {}", meeting_id, e);
+            None
+        }
+    };
+    match guidance {
+        Some(guidance) if custom_prompt.trim().is_empty() => guidance,
+        Some(guidance) => format!("{}\n\n{}", custom_prompt.trim_end(), guidance),
+        None => custom_prompt,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn titles_of_a_series_share_a_key() {
+        let key = series_key("Weekly Sync 2026-03-04").unwrap();
+        assert_eq!(key, "weekly sync");
+        assert_eq!(series_key("Weekly sync – March 11th").unwrap(), key);
+        assert_eq!(series_key("週次定例（3月4日）").unwrap(), "週次定例");
+        assert!(series_key("2026/03/04").is_none());
+    }
+
+    #[test]
+    fn guidance_quotes_recent_comments() {
+        let feedback = |helpful: bool, comment: Option<&str>| SummaryFeedback {
+            id: "feedback-1".to_string(),
+            meeting_id: "meeting-1".to_string(),
+            series_key: "weekly sync".to_string(),
+            helpful,
+            comment: comment.map(str::to_string),
+            created_at: Utc::now(),
+        };
+        assert!(feedback_guidance(&[]).is_none());
+        let guidance = feedback_guidance(&[
+            feedback(false, Some("Too long, list decisions first")),
+            feedback(true, None),
+        ])
+        .unwrap();
+        assert!(guidance.contains("1 of the last 2 were rated helpful"));
+        assert!(guidance.contains("- Disliked: \"Too long, list decisions first\""));
+    }
+}
@@ -0,0 +1,80 @@
+use crate::database::repositories::summary_feedback::{SummaryFeedback, SummaryFeedbackRepository};
+use crate::state::AppState;
+use crate::summary::feedback::{meeting_series_key, MAX_COMMENT_CHARS};
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Runtime};
+
+/// Rates a meeting's summary. The feedback steers later summaries of the
+/// same meeting series.
+#[tauri::command]
+pub async fn api_add_summary_feedback<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    helpful: bool,
+    comment: Option<String>,
+) -> Result<SummaryFeedback, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_add_summary_feedback called for {} (helpful: {})",
+        meeting_id,
+        helpful
+    );
+    let comment = comment
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty());
+    if comment
+        .as_ref()
+        .is_some_and(|c| c.chars().count() > MAX_COMMENT_CHARS)
+    {
+        return Err(format!(
+            "Feedback is longer than {} characters",
+            MAX_COMMENT_CHARS
+        ));
+    }
+
+    let pool = state.db_manager.pool();
+    let series_key = meeting_series_key(pool, &meeting_id).await?;
+    SummaryFeedbackRepository::add_feedback(pool, &meeting_id, &series_key, helpful, comment)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to save summary feedback for {}: {}", meeting_id, e);
+            format!("Failed to save feedback: {}", e)
+        })
+}
+
+#[tauri::command]
+pub async fn api_list_summary_feedback<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<SummaryFeedback>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!("api_list_summary_feedback called for {}", meeting_id);
+    SummaryFeedbackRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list summary feedback for {}: {}", meeting_id, e);
+            format!("Failed to list feedback: {}", e)
+        })
+}
+
+#[tauri::command]
+pub async fn api_delete_summary_feedback<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    feedback_id: String,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_delete_summary_feedback called for {}", feedback_id);
+    match SummaryFeedbackRepository::delete_feedback(state.db_manager.pool(), &feedback_id).await {
+        Ok(true) => Ok(serde_json::json!({
+            "status": "success",
+            "message": "Feedback deleted"
+        })),
+        Ok(false) => Err(format!("Feedback not found: {}", feedback_id)),
+        Err(e) => {
+            log_error!("Failed to delete summary feedback {}: {}", feedback_id, e);
+            Err(format!("Failed to delete feedback: {}", e))
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::database::repositories::setting::SettingsRepository;
+use crate::summary::llm_client::{generate_summary, LLMProvider};
+use reqwest::Client;
+use sqlx::SqlitePool;
+use std::path::PathBuf;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+/// Everything needed to call the configured LLM: provider, model, credentials
+/// and provider-specific endpoint settings, resolved from the settings table
+#[derive(Debug, Clone)]
+pub struct LlmConnection {
+    pub provider: LLMProvider,
+    /// Provider id as stored in settings (e.g. "openai", "ollama")
+    pub provider_name: String,
+    pub model_name: String,
+    pub api_key: String,
+    pub ollama_endpoint: Option<String>,
+    pub custom_openai_endpoint: Option<String>,
+    pub max_tokens: Option<u32>,
+    pub temperature: Option<f32>,
+    pub top_p: Option<f32>,
+    pub app_data_dir: Option<PathBuf>,
+}
+
+impl LlmConnection {
+    /// Resolves the connection for an explicit provider/model pair
+    pub async fn resolve(
+        pool: &SqlitePool,
+        model_provider: &str,
+        model_name: &str,
+        app_data_dir: Option<PathBuf>,
+    ) -> Result<Self, String> {
+        let provider = LLMProvider::from_str(model_provider)?;
+
+        // Validate and setup api_key, Flexible for Ollama, BuiltInAI, and CustomOpenAI
+        let api_key = if provider == LLMProvider::Ollama
+            || provider == LLMProvider::BuiltInAI
+            || provider == LLMProvider::CustomOpenAI
+        {
+            // These providers don't require API keys from the standard database column
+            String::new()
+        } else {
+            match SettingsRepository::get_api_key(pool, model_provider).await {
+                Ok(Some(key)) if !key.is_empty() => key,
+                Ok(None) | Ok(Some(_)) => {
+                    return Err(format!("API key not found for {}", model_provider));
+                }
+                Err(e) => {
+                    return Err(format!(
+                        "Failed to retrieve API key for {}: {}",
+                        model_provider, e
+                    ));
+                }
+            }
+        };
+
+        // Get Ollama endpoint if provider is Ollama
+        let ollama_endpoint = if provider == LLMProvider::Ollama {
+            match SettingsRepository::get_model_config(pool).await {
+                Ok(Some(config)) => config.ollama_endpoint,
+                Ok(None) => None,
+                Err(e) => {
+                    info!("Failed to retrieve Ollama endpoint: {}, using default", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let mut connection = LlmConnection {
+            provider,
+            provider_name: model_provider.to_string(),
+            model_name: model_name.to_string(),
+            api_key,
+            ollama_endpoint,
+            custom_openai_endpoint: None,
+            max_tokens: None,
+            temperature: None,
+            top_p: None,
+            app_data_dir,
+        };
+
+        // CustomOpenAI uses its own endpoint and API key (if any)
+        if connection.provider == LLMProvider::CustomOpenAI {
+            match SettingsRepository::get_custom_openai_config(pool).await {
+                Ok(Some(config)) => {
+                    info!("✓ Using custom OpenAI endpoint: {}", config.endpoint);
+                    connection.custom_openai_endpoint = Some(config.endpoint);
+                    connection.api_key = config.api_key.unwrap_or_default();
+                    connection.max_tokens = config.max_tokens.map(|t| t as u32);
+                    connection.temperature = config.temperature;
+                    connection.top_p = config.top_p;
+                }
+                Ok(None) => {
+                    return Err(
+                        "Custom OpenAI provider selected but no configuration found".to_string()
+                    );
+                }
+                Err(e) => {
+                    return Err(format!("Failed to retrieve custom OpenAI config: {}", e));
+                }
+            }
+        }
+
+        Ok(connection)
+    }
+
+    /// Resolves the connection for the provider/model selected in settings
+    pub async fn from_settings(
+        pool: &SqlitePool,
+        app_data_dir: Option<PathBuf>,
+    ) -> Result<Self, String> {
+        let config = SettingsRepository::get_model_config(pool)
+            .await
+            .map_err(|e| format!("Failed to load model settings: {}", e))?
+            .ok_or("No summary model configured. Choose a model in settings first.")?;
+        Self::resolve(pool, &config.provider, &config.model, app_data_dir).await
+    }
+
+    /// Single prompt/response call (no chunking)
+    pub async fn complete(
+        &self,
+        client: &Client,
+        system_prompt: &str,
+        user_prompt: &str,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> Result<String, String> {
+        generate_summary(
+            client,
+            &self.provider,
+            &self.model_name,
+            &self.api_key,
+            system_prompt,
+            user_prompt,
+            self.ollama_endpoint.as_deref(),
+            self.custom_openai_endpoint.as_deref(),
+            self.max_tokens,
+            self.temperature,
+            self.top_p,
+            self.app_data_dir.as_ref(),
+            cancellation_token,
+        )
+        .await
+    }
+}
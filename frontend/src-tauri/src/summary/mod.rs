@@ -5,6 +5,12 @@
 /// - Processor for chunking transcripts and generating summaries
 /// - Service layer for orchestrating summary generation
 /// - Templates for structured meeting summary generation
+/// - Delta reports comparing two meetings of a series
+/// - Partial summaries of a selected time range of a meeting
+/// - Daily/weekly digests covering every meeting of a period
+/// - Summary feedback that steers later summaries of a series
+/// - The versioned schema stored summaries follow
+/// - Per-segment passes: sentiment/tone analysis and named entity extraction
 /// - Tauri commands for frontend integration
 
 use serde::{Deserialize, Serialize};
@@ -30,9 +36,25 @@ pub struct CustomOpenAIConfig {
     pub top_p: Option<f32>,
 }
 
+pub mod action_items;
 pub mod commands;
+pub mod delta;
+pub mod delta_commands;
+pub mod digest;
+pub mod digest_commands;
+pub mod entities;
+pub mod entity_commands;
+pub mod feedback;
+pub mod feedback_commands;
 pub mod llm_client;
+pub mod llm_connection;
 pub mod processor;
+pub mod range;
+pub mod range_commands;
+pub mod schema;
+pub mod segment_labeling;
+pub mod sentiment;
+pub mod sentiment_commands;
 pub mod service;
 pub mod summary_engine;
 pub mod template_commands;
@@ -51,8 +73,49 @@ pub use template_commands::{
     api_get_template_details, api_list_templates, api_validate_template,
 };
 
+// Re-export delta report commands
+pub use delta_commands::{
+    __cmd__api_compare_meetings, __cmd__api_get_delta_report, api_compare_meetings,
+    api_get_delta_report,
+};
+
+// Re-export partial (range) summary commands
+pub use range_commands::{
+    __cmd__api_delete_partial_summary, __cmd__api_list_partial_summaries,
+    __cmd__api_summarize_range, api_delete_partial_summary, api_list_partial_summaries,
+    api_summarize_range,
+};
+
+// Re-export digest commands
+pub use digest_commands::{
+    __cmd__api_delete_digest, __cmd__api_generate_digest, __cmd__api_get_digest_schedule,
+    __cmd__api_list_digests, __cmd__api_set_digest_schedule, api_delete_digest,
+    api_generate_digest, api_get_digest_schedule, api_list_digests, api_set_digest_schedule,
+};
+
+// Re-export summary feedback commands
+pub use feedback_commands::{
+    __cmd__api_add_summary_feedback, __cmd__api_delete_summary_feedback,
+    __cmd__api_list_summary_feedback, api_add_summary_feedback, api_delete_summary_feedback,
+    api_list_summary_feedback,
+};
+
+// Re-export sentiment analysis commands
+pub use sentiment_commands::{
+    __cmd__api_analyze_meeting_sentiment, __cmd__api_search_segment_sentiment,
+    api_analyze_meeting_sentiment, api_search_segment_sentiment,
+};
+
+// Re-export entity extraction commands
+pub use entity_commands::{
+    __cmd__api_extract_meeting_entities, __cmd__api_find_meetings_by_entity,
+    __cmd__api_get_meeting_entities, __cmd__api_list_entities, api_extract_meeting_entities,
+    api_find_meetings_by_entity, api_get_meeting_entities, api_list_entities,
+};
+
 // Re-export commonly used items
 pub use llm_client::LLMProvider;
+pub use llm_connection::LlmConnection;
 pub use processor::{
     chunk_text, clean_llm_markdown_output, extract_meeting_name_from_markdown,
     generate_meeting_summary, rough_token_count,
@@ -12,6 +12,40 @@ static THINKING_TAG_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"(?s)<think(?:ing)?>.*?</think(?:ing)?>").unwrap()
 });
 
+/// Names for the common summary output languages; other codes are given to
+/// the model as ISO codes
+const OUTPUT_LANGUAGES: [(&str, &str); 12] = [
+    ("ja", "Japanese"),
+    ("en", "English"),
+    ("zh", "Chinese"),
+    ("ko", "Korean"),
+    ("es", "Spanish"),
+    ("fr", "French"),
+    ("de", "German"),
+    ("it", "Italian"),
+    ("pt", "Portuguese"),
+    ("ru", "Russian"),
+    ("vi", "Vietnamese"),
+    ("th", "Thai"),
+];
+
+/// Prompt instruction to write the report in `language`, independent of the
+/// transcript's language. None, "" and "auto" keep the transcript's language.
+pub fn output_language_instruction(language: Option<&str>) -> Option<String> {
+    let code = language
+        .map(str::trim)
+        .filter(|code| !code.is_empty() && !code.starts_with("auto"))?;
+    let primary = code.split(['-', '_']).next().unwrap_or(code).to_lowercase();
+    let name = match OUTPUT_LANGUAGES.iter().find(|(known, _)| *known == primary) {
+        Some((_, name)) => name.to_string(),
+        None => format!("the language with ISO code '{}'", code),
+    };
+    Some(format!(
+        "Write the entire report in {}, including the section headings, whatever language the transcript is in. Keep names, product names and quotes as spoken.",
+        name
+    ))
+}
+
 /// Rough token count estimation using character count
 pub fn rough_token_count(s: &str) -> usize {
     let char_count = s.chars().count();
@@ -145,6 +179,7 @@ pub fn extract_meeting_name_from_markdown(markdown: &str) -> Option<String> {
 /// * `text` - Full transcript text to summarize
 /// * `custom_prompt` - Optional user-provided context
 /// * `template_id` - Template identifier (e.g., "daily_standup", "standard_meeting")
+/// * `output_language` - Language code to write the report in (None: the transcript's language)
 /// * `token_threshold` - Token limit for single-pass processing (default 4000)
 /// * `ollama_endpoint` - Optional custom Ollama endpoint
 /// * `custom_openai_endpoint` - Optional custom OpenAI-compatible endpoint
@@ -164,6 +199,7 @@ pub async fn generate_meeting_summary(
     text: &str,
     custom_prompt: &str,
     template_id: &str,
+    output_language: Option<&str>,
     token_threshold: usize,
     ollama_endpoint: Option<&str>,
     custom_openai_endpoint: Option<&str>,
@@ -333,6 +369,17 @@ pub async fn generate_meeting_summary(
 "#,
         section_instructions, clean_template_markdown
     );
+    let final_system_prompt = match output_language_instruction(output_language) {
+        Some(instruction) => {
+            info!("Writing the report in '{}'", output_language.unwrap_or_default());
+            format!(
+                "{}\n\n**OUTPUT LANGUAGE:** {}\n",
+                final_system_prompt.trim_end(),
+                instruction
+            )
+        }
+        None => final_system_prompt,
+    };
 
     let mut final_user_prompt = format!(
         r#"
@@ -380,3 +427,24 @@ pub async fn generate_meeting_summary(
     info!("Summary generation completed successfully");
     Ok((final_markdown, successful_chunk_count))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_language_is_named_in_the_prompt() {
+        assert!(output_language_instruction(Some("en"))
+            .unwrap()
+            .starts_with("Write the entire report in English"));
+        assert!(output_language_instruction(Some("ja-JP"))
+            .unwrap()
+            .contains("in Japanese"));
+        assert!(output_language_instruction(Some("nl"))
+            .unwrap()
+            .contains("ISO code 'nl'"));
+        assert!(output_language_instruction(Some("auto")).is_none());
+        assert!(output_language_instruction(Some(" ")).is_none());
+        assert!(output_language_instruction(None).is_none());
+    }
+}
@@ -0,0 +1,104 @@
+//! Summaries of part of a meeting ("just the budget discussion").
+//!
+//! The transcript segments overlapping the selected range are summarized on
+//! their own and stored as a named partial summary next to the meeting's
+//! full summary.
+
+use crate::api::api::MeetingTranscript;
+
+/// Longest transcript excerpt sent to the model; longer ranges should use the
+/// full (chunked) summary
+pub const MAX_RANGE_CHARS: usize = 60_000;
+/// Longest name accepted for a partial summary
+pub const MAX_NAME_CHARS: usize = 200;
+
+pub const RANGE_SYSTEM_PROMPT: &str = "You summarize an excerpt of a meeting transcript. \
+The excerpt is only part of the meeting, so do not describe the meeting as a whole. \
+Be concise and factual and use only information present in the excerpt. Answer in markdown \
+with these sections: ## Summary, ## Decisions, ## Action Items, ## Open Questions. \
+Write \"None\" under a section with nothing to report.";
+
+/// "mm:ss", or "h:mm:ss" from an hour on
+pub fn format_offset(seconds: f64) -> String {
+    let total = seconds.max(0.0).round() as u64;
+    let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+/// Name used when none is given: the range itself
+pub fn default_range_name(start: f64, end: f64) -> String {
+    format!("{} - {}", format_offset(start), format_offset(end))
+}
+
+/// The text of the segments overlapping `start..end`, one per line with
+/// their start offset. Segments without timing can't be placed and are
+/// skipped.
+pub fn range_transcript(segments: &[MeetingTranscript], start: f64, end: f64) -> String {
+    segments
+        .iter()
+        .filter_map(|segment| {
+            let from = segment.audio_start_time?;
+            let to = segment.audio_end_time.unwrap_or(from);
+            let text = segment.text.trim();
+            (from < end && to > start && !text.is_empty())
+                .then(|| format!("[{}] {}", format_offset(from), text))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The user prompt for a range of a meeting
+pub fn build_range_prompt(title: &str, name: &str, start: f64, end: f64, excerpt: &str) -> String {
+    format!(
+        "Meeting: {}\nExcerpt: {} ({})\n\n<transcript_excerpt>\n{}\n</transcript_excerpt>",
+        title,
+        name,
+        default_range_name(start, end),
+        excerpt
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(text: &str, start: Option<f64>, end: Option<f64>) -> MeetingTranscript {
+        MeetingTranscript {
+            id: "t".to_string(),
+            text: text.to_string(),
+            timestamp: String::new(),
+            audio_start_time: start,
+            audio_end_time: end,
+            duration: None,
+            speaker: None,
+            uncertain: false,
+        }
+    }
+
+    #[test]
+    fn segments_overlapping_the_range_are_kept() {
+        let segments = vec![
+            segment("Welcome everyone", Some(0.0), Some(10.0)),
+            segment("The budget is 5M", Some(58.0), Some(65.0)),
+            segment("Cut travel costs", Some(70.0), Some(80.0)),
+            segment("Untimed note", None, None),
+            segment("Next topic: hiring", Some(125.0), Some(130.0)),
+        ];
+        assert_eq!(
+            range_transcript(&segments, 60.0, 120.0),
+            "[00:58] The budget is 5M\n[01:10] Cut travel costs"
+        );
+        assert_eq!(range_transcript(&segments, 200.0, 300.0), "");
+    }
+
+    #[test]
+    fn offsets_are_formatted_as_clock_times() {
+        assert_eq!(format_offset(65.4), "01:05");
+        assert_eq!(format_offset(3725.0), "1:02:05");
+        assert_eq!(default_range_name(60.0, 150.0), "01:00 - 02:30");
+    }
+}
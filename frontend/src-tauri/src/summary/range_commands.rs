@@ -0,0 +1,154 @@
+use crate::database::repositories::{
+    meeting::MeetingsRepository,
+    partial_summary::{PartialSummariesRepository, PartialSummary},
+    setting::SettingsRepository,
+};
+use crate::state::AppState;
+use crate::summary::llm_connection::LlmConnection;
+use crate::summary::processor::{clean_llm_markdown_output, output_language_instruction};
+use crate::summary::range::{
+    build_range_prompt, default_range_name, range_transcript, MAX_NAME_CHARS, MAX_RANGE_CHARS,
+    RANGE_SYSTEM_PROMPT,
+};
+use log::{error as log_error, info as log_info, warn as log_warn};
+use tauri::{AppHandle, Manager, Runtime};
+
+/// Summarizes the part of a meeting between `start_time` and `end_time`
+/// (seconds from the start of the recording) and stores it as a partial
+/// summary called `name` (default: the range). Uses the summary model from
+/// settings unless `provider`/`model` are given.
+#[tauri::command]
+pub async fn api_summarize_range<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    start_time: f64,
+    end_time: f64,
+    name: Option<String>,
+    provider: Option<String>,
+    model: Option<String>,
+) -> Result<PartialSummary, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    log_info!(
+        "api_summarize_range called for {} ({:.1}s-{:.1}s)",
+        meeting_id,
+        start_time,
+        end_time
+    );
+    if !start_time.is_finite()
+        || !end_time.is_finite()
+        || start_time < 0.0
+        || end_time <= start_time
+    {
+        return Err("The range must end after it starts".to_string());
+    }
+    let name = name
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| default_range_name(start_time, end_time));
+    if name.chars().count() > MAX_NAME_CHARS {
+        return Err(format!(
+            "The name is longer than {} characters",
+            MAX_NAME_CHARS
+        ));
+    }
+    let pool = state.db_manager.pool();
+
+    let details = MeetingsRepository::get_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcript: {}", e))?
+        .ok_or_else(|| format!("Meeting '{}' not found", meeting_id))?;
+    let excerpt = range_transcript(&details.transcripts, start_time, end_time);
+    if excerpt.is_empty() {
+        return Err("No transcript falls within the selected range".to_string());
+    }
+    if excerpt.chars().count() > MAX_RANGE_CHARS {
+        return Err(
+            "The selected range is too long; summarize the whole meeting instead".to_string(),
+        );
+    }
+
+    let app_data_dir = app.path().app_data_dir().ok();
+    let connection = match (provider, model) {
+        (Some(provider), Some(model)) => {
+            LlmConnection::resolve(pool, &provider, &model, app_data_dir).await?
+        }
+        _ => LlmConnection::from_settings(pool, app_data_dir).await?,
+    };
+
+    let mut system_prompt = RANGE_SYSTEM_PROMPT.to_string();
+    match SettingsRepository::get_summary_language(pool).await {
+        Ok(language) => {
+            if let Some(instruction) = output_language_instruction(language.as_deref()) {
+                system_prompt = format!("{} {}", system_prompt, instruction);
+            }
+        }
+        Err(e) => log_warn!("Failed to read the summary language: {}", e),
+    }
+    let prompt = build_range_prompt(&details.title, &name, start_time, end_time, &excerpt);
+
+    let client = reqwest::Client::new();
+    let content = connection
+        .complete(&client, &system_prompt, &prompt, None)
+        .await
+        .map_err(|e| {
+            log_error!("Range summary generation failed: {}", e);
+            format!("Failed to summarize the range: {}", e)
+        })?;
+    let content = clean_llm_markdown_output(&content);
+
+    PartialSummariesRepository::save(
+        pool,
+        &meeting_id,
+        &name,
+        start_time,
+        end_time,
+        &content,
+        &connection.provider_name,
+        &connection.model_name,
+    )
+    .await
+    .map_err(|e| format!("Failed to save partial summary: {}", e))
+}
+
+#[tauri::command]
+pub async fn api_list_partial_summaries<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<PartialSummary>, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    PartialSummariesRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list partial summaries for {}: {}", meeting_id, e);
+            format!("Failed to list partial summaries: {}", e)
+        })
+}
+
+#[tauri::command]
+pub async fn api_delete_partial_summary<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    partial_summary_id: String,
+) -> Result<serde_json::Value, String> {
+    log_info!(
+        "api_delete_partial_summary called for {}",
+        partial_summary_id
+    );
+    match PartialSummariesRepository::delete(state.db_manager.pool(), &partial_summary_id).await {
+        Ok(true) => Ok(serde_json::json!({
+            "status": "success",
+            "message": "Partial summary deleted"
+        })),
+        Ok(false) => Err(format!("Partial summary not found: {}", partial_summary_id)),
+        Err(e) => {
+            log_error!(
+                "Failed to delete partial summary {}: {}",
+                partial_summary_id,
+                e
+            );
+            Err(format!("Failed to delete partial summary: {}", e))
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! The stored shape of a meeting summary.
+//!
+//! A summary result is saved as
+//! `{ "schema_version": 1, "markdown": "...", "summary_json": [...] }`:
+//! `markdown` is the summary text every export, digest and integration
+//! reads, and `summary_json` holds the BlockNote blocks of an edited
+//! summary. Saves are checked against this shape, and results written by
+//! older versions (a bare `{ "markdown" }`, the section format of the first
+//! editor, or plain text) are upgraded when read and rewritten at startup.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sqlx::SqlitePool;
+
+use crate::database::repositories::summary::SummaryProcessesRepository;
+
+pub const SUMMARY_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StoredSummary {
+    pub schema_version: u32,
+    pub markdown: String,
+    /// BlockNote blocks; present once the summary has been edited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary_json: Option<Vec<Value>>,
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum SummarySchemaError {
+    #[error(
+        "summary schema version {0} is newer than this app supports ({supported})",
+        supported = SUMMARY_SCHEMA_VERSION
+    )]
+    UnsupportedVersion(u64),
+    #[error("invalid summary: {0}")]
+    Invalid(String),
+}
+
+impl StoredSummary {
+    pub fn from_markdown(markdown: impl Into<String>) -> Self {
+        Self {
+            schema_version: SUMMARY_SCHEMA_VERSION,
+            markdown: markdown.into(),
+            summary_json: None,
+        }
+    }
+
+    pub fn to_value(&self) -> Value {
+        serde_json::to_value(self).unwrap_or(Value::Null)
+    }
+}
+
+fn invalid(message: impl Into<String>) -> SummarySchemaError {
+    SummarySchemaError::Invalid(message.into())
+}
+
+/// Checks a summary against the current schema, upgrading older shapes
+pub fn normalize(value: Value) -> Result<StoredSummary, SummarySchemaError> {
+    let object = match value {
+        Value::String(markdown) => return Ok(StoredSummary::from_markdown(markdown)),
+        Value::Object(object) => object,
+        _ => return Err(invalid("expected a JSON object")),
+    };
+
+    let summary = if let Some(version) = object.get("schema_version") {
+        let version = version
+            .as_u64()
+            .ok_or_else(|| invalid("schema_version must be a number"))?;
+        if version > u64::from(SUMMARY_SCHEMA_VERSION) {
+            return Err(SummarySchemaError::UnsupportedVersion(version));
+        }
+        serde_json::from_value::<StoredSummary>(Value::Object(object))
+            .map_err(|e| invalid(e.to_string()))?
+    } else if object.contains_key("markdown") || object.contains_key("summary_json") {
+        let markdown = match object.get("markdown") {
+            Some(Value::String(markdown)) => markdown.clone(),
+            None | Some(Value::Null) => String::new(),
+            Some(_) => return Err(invalid("markdown must be a string")),
+        };
+        let summary_json = match object.get("summary_json") {
+            Some(Value::Array(blocks)) => Some(blocks.clone()),
+            None | Some(Value::Null) => None,
+            Some(_) => return Err(invalid("summary_json must be an array")),
+        };
+        StoredSummary {
+            schema_version: SUMMARY_SCHEMA_VERSION,
+            markdown,
+            summary_json,
+        }
+    } else {
+        StoredSummary::from_markdown(legacy_sections_markdown(&object)?)
+    };
+
+    if let Some(blocks) = &summary.summary_json {
+        if !blocks
+            .iter()
+            .all(|block| block.get("type").is_some_and(Value::is_string))
+        {
+            return Err(invalid("every summary_json block needs a type"));
+        }
+    }
+    Ok(StoredSummary {
+        schema_version: SUMMARY_SCHEMA_VERSION,
+        ..summary
+    })
+}
+
+/// Markdown of the section format of the first editor, either
+/// `{ "MeetingName", "MeetingNotes": { "sections": [...] } }` or a map of
+/// section key to `{ "title", "blocks": [{ "type", "content" }] }`
+fn legacy_sections_markdown(object: &Map<String, Value>) -> Result<String, SummarySchemaError> {
+    let mut lines = Vec::new();
+    let sections: Vec<&Value> = match object.get("MeetingNotes") {
+        Some(notes) => {
+            if let Some(name) = object.get("MeetingName").and_then(Value::as_str) {
+                lines.push(format!("# {}", name.trim()));
+                lines.push(String::new());
+            }
+            notes
+                .get("sections")
+                .and_then(Value::as_array)
+                .ok_or_else(|| invalid("MeetingNotes.sections must be an array"))?
+                .iter()
+                .collect()
+        }
+        None if object.is_empty() => return Err(invalid("unrecognized summary format")),
+        None => object.values().collect(),
+    };
+
+    for section in sections {
+        let (Some(title), Some(blocks)) = (
+            section.get("title").and_then(Value::as_str),
+            section.get("blocks").and_then(Value::as_array),
+        ) else {
+            return Err(invalid("unrecognized summary format"));
+        };
+        lines.push(format!("## {}", title.trim()));
+        for block in blocks {
+            let content = block.get("content").and_then(Value::as_str).unwrap_or("");
+            let content = content.trim();
+            if content.is_empty() {
+                continue;
+            }
+            let kind = block.get("type").and_then(Value::as_str).unwrap_or("");
+            lines.push(if kind.contains("heading") {
+                format!("### {}", content)
+            } else if kind.contains("bullet") || kind.contains("list") {
+                format!("- {}", content)
+            } else {
+                content.to_string()
+            });
+        }
+        lines.push(String::new());
+    }
+    Ok(lines.join("\n").trim_end().to_string())
+}
+
+/// The stored result string in the current schema, or None when it is
+/// already current or can't be read as a summary
+pub fn upgrade_stored(result: &str) -> Option<String> {
+    let value: Value = serde_json::from_str(result).ok()?;
+    let summary = normalize(value.clone()).ok()?;
+    let upgraded = summary.to_value();
+    (upgraded != value).then(|| upgraded.to_string())
+}
+
+/// Rewrites summaries saved by older versions in the current schema
+pub async fn upgrade_at_startup(pool: SqlitePool) {
+    match SummaryProcessesRepository::upgrade_summary_schema(&pool).await {
+        Ok(0) => {}
+        Ok(count) => log::info!(
+            "Upgraded {} stored summaries to schema version {}",
+            count,
+            SUMMARY_SCHEMA_VERSION
+        ),
+        Err(e) => log::warn!("Failed to upgrade stored summaries: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn older_summaries_are_upgraded() {
+        assert_eq!(
+            normalize(json!({ "markdown": "# Notes" })).unwrap(),
+            StoredSummary::from_markdown("# Notes")
+        );
+        let legacy = json!({
+            "MeetingName": "Weekly sync",
+            "MeetingNotes": { "sections": [{
+                "title": "Decisions",
+                "blocks": [
+                    { "id": "1", "type": "bullet", "content": "Ship Friday", "color": "" },
+                    { "id": "2", "type": "text", "content": "  ", "color": "" }
+                ]
+            }]}
+        });
+        assert_eq!(
+            normalize(legacy).unwrap().markdown,
+            "# Weekly sync\n\n## Decisions\n- Ship Friday"
+        );
+        assert_eq!(
+            upgrade_stored(r#"{"schema_version":1,"markdown":"x"}"#),
+            None
+        );
+        let upgraded = upgrade_stored(r#"{"markdown":"x"}"#).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&upgraded).unwrap(),
+            json!({ "schema_version": 1, "markdown": "x" })
+        );
+    }
+
+    #[test]
+    fn malformed_summaries_are_rejected() {
+        assert!(normalize(json!([1, 2])).is_err());
+        assert!(normalize(json!({ "markdown": 3 })).is_err());
+        assert!(normalize(json!({ "markdown": "x", "summary_json": [{ "id": "1" }] })).is_err());
+        assert!(normalize(json!({ "schema_version": 1, "markdown": "x", "extra": true })).is_err());
+        assert_eq!(
+            normalize(json!({ "schema_version": 7, "markdown": "x" })),
+            Err(SummarySchemaError::UnsupportedVersion(7))
+        );
+        assert!(normalize(json!({ "title": "no blocks" })).is_err());
+    }
+}
@@ -0,0 +1,89 @@
+//! Shared plumbing for per-segment LLM passes (sentiment, entities).
+//!
+//! Segments are numbered and sent in batches; the model answers with a JSON
+//! array of objects carrying the segment number `i`. A batch whose answer
+//! cannot be parsed is skipped rather than failing the whole pass.
+
+use crate::summary::llm_connection::LlmConnection;
+use log::{error as log_error, warn as log_warn};
+
+/// Segments sent to the model per request
+pub const LLM_BATCH_SIZE: usize = 40;
+/// Characters of each segment sent to the model
+const MAX_SEGMENT_CHARS: usize = 600;
+
+/// Numbers segments for the model, trimming long ones
+pub fn build_segment_prompt(segments: &[(Option<&str>, &str)]) -> String {
+    let mut prompt = String::from("Segments:\n");
+    for (i, (speaker, text)) in segments.iter().enumerate() {
+        let text: String = text.chars().take(MAX_SEGMENT_CHARS).collect();
+        match speaker {
+            Some(speaker) => prompt.push_str(&format!("{}. [{}] {}\n", i, speaker, text.trim())),
+            None => prompt.push_str(&format!("{}. {}\n", i, text.trim())),
+        }
+    }
+    prompt
+}
+
+/// The outermost JSON array in a model response, ignoring code fences and prose
+pub fn json_array(response: &str) -> Result<&str, String> {
+    match (response.find('['), response.rfind(']')) {
+        (Some(start), Some(end)) if start < end => Ok(&response[start..=end]),
+        _ => Err("Model response contains no JSON array".to_string()),
+    }
+}
+
+/// Runs `segments` (speaker, text) through the model in batches and returns
+/// one label per segment. `parse` turns a response into labels for a batch of
+/// the given size; segments of batches it rejects get `T::default()`.
+pub async fn label_in_batches<T, F>(
+    connection: &LlmConnection,
+    system_prompt: &str,
+    segments: &[(Option<&str>, &str)],
+    parse: F,
+) -> Result<Vec<T>, String>
+where
+    T: Default,
+    F: Fn(&str, usize) -> Result<Vec<T>, String>,
+{
+    let client = reqwest::Client::new();
+    let mut labels = Vec::with_capacity(segments.len());
+    for batch in segments.chunks(LLM_BATCH_SIZE) {
+        let prompt = build_segment_prompt(batch);
+        let response = connection
+            .complete(&client, system_prompt, &prompt, None)
+            .await
+            .map_err(|e| {
+                log_error!("Segment labeling request failed: {}", e);
+                format!("Model request failed: {}", e)
+            })?;
+        let start = labels.len();
+        match parse(&response, batch.len()) {
+            Ok(batch_labels) => labels.extend(batch_labels.into_iter().take(batch.len())),
+            Err(e) => log_warn!("Skipping a batch of {} segments: {}", batch.len(), e),
+        }
+        labels.resize_with(start + batch.len(), T::default);
+    }
+    Ok(labels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prompt_numbers_segments() {
+        let prompt = build_segment_prompt(&[(Some("Alice"), " Hello "), (None, "Bye")]);
+        assert_eq!(prompt, "Segments:\n0. [Alice] Hello\n1. Bye\n");
+    }
+
+    #[test]
+    fn finds_json_array() {
+        assert_eq!(
+            json_array("```json\n[{\"i\":0}]\n```").unwrap(),
+            "[{\"i\":0}]"
+        );
+        assert!(json_array("no json").is_err());
+        assert!(json_array("] before [").is_err());
+    }
+}
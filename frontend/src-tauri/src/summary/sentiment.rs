@@ -0,0 +1,207 @@
+//! Sentiment and tone per transcript segment.
+//!
+//! Two analyzers produce the same [`SegmentSentiment`]:
+//! - `local`: a keyword lexicon, instant and offline, good enough to flag
+//!   clearly positive or negative moments
+//! - `llm`: the configured summary model, given segments in batches and asked
+//!   for a JSON array back
+
+use crate::summary::segment_labeling::json_array;
+use serde::{Deserialize, Serialize};
+
+pub const SENTIMENT_SYSTEM_PROMPT: &str = "You label the sentiment and tone of meeting transcript \
+segments. For every numbered segment return one JSON object with the fields \"i\" (the segment \
+number), \"sentiment\" (\"positive\", \"neutral\" or \"negative\"), \"score\" (from -1.0 to 1.0) and \
+\"tone\" (one lowercase word such as neutral, calm, excited, appreciative, concerned, confused, \
+frustrated, angry, uncertain). Answer with a JSON array only, no prose.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Sentiment {
+    Positive,
+    Neutral,
+    Negative,
+}
+
+impl Sentiment {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Positive => "positive",
+            Self::Neutral => "neutral",
+            Self::Negative => "negative",
+        }
+    }
+
+    pub fn from_score(score: f64) -> Self {
+        if score >= 0.25 {
+            Self::Positive
+        } else if score <= -0.25 {
+            Self::Negative
+        } else {
+            Self::Neutral
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "positive" => Some(Self::Positive),
+            "neutral" => Some(Self::Neutral),
+            "negative" => Some(Self::Negative),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentSentiment {
+    pub sentiment: Sentiment,
+    /// -1.0 (most negative) to 1.0
+    pub score: f64,
+    pub tone: String,
+}
+
+const POSITIVE_WORDS: &[&str] = &[
+    "great", "good", "excellent", "awesome", "love", "happy", "glad", "perfect", "nice",
+    "thanks", "thank", "agree", "excited", "amazing", "progress", "success", "well done",
+];
+const NEGATIVE_WORDS: &[&str] = &[
+    "bad", "problem", "issue", "wrong", "broken", "fail", "failed", "delay", "delayed", "blocked",
+    "worried", "concern", "frustrated", "frustrating", "annoying", "unacceptable", "disappointed",
+    "angry", "upset", "terrible", "again", "still not", "not happy",
+];
+/// Tone keywords, checked in order; the first tone with a hit wins
+const TONE_KEYWORDS: &[(&str, &[&str])] = &[
+    ("angry", &["unacceptable", "ridiculous", "angry", "furious"]),
+    ("frustrated", &["frustrat", "annoying", "still not", "again?", "how many times", "disappointed"]),
+    ("concerned", &["worried", "concern", "risk", "afraid", "nervous"]),
+    ("confused", &["confus", "don't understand", "not sure what", "unclear", "lost me"]),
+    ("appreciative", &["thank", "appreciate", "grateful"]),
+    ("excited", &["excited", "awesome", "amazing", "can't wait", "love"]),
+];
+
+fn count_hits(text: &str, words: &[&str]) -> usize {
+    words
+        .iter()
+        .filter(|w| {
+            // Whole-word match for single words, substring for phrases/stems
+            if w.contains(' ') || w.ends_with(['?', '\'']) {
+                text.contains(*w)
+            } else {
+                text.split(|c: char| !c.is_alphanumeric() && c != '\'')
+                    .any(|token| token == **w)
+            }
+        })
+        .count()
+}
+
+/// Lexicon-based analysis of one segment
+pub fn analyze_local(text: &str) -> SegmentSentiment {
+    let lower = text.to_lowercase();
+    let positive = count_hits(&lower, POSITIVE_WORDS) as f64;
+    let negative = count_hits(&lower, NEGATIVE_WORDS) as f64;
+    let score = if positive + negative > 0.0 {
+        (positive - negative) / (positive + negative).max(2.0)
+    } else {
+        0.0
+    };
+    let tone = TONE_KEYWORDS
+        .iter()
+        .find(|(_, keywords)| keywords.iter().any(|k| lower.contains(k)))
+        .map(|(tone, _)| tone.to_string())
+        .unwrap_or_else(|| "neutral".to_string());
+    SegmentSentiment {
+        sentiment: Sentiment::from_score(score),
+        score,
+        tone,
+    }
+}
+
+#[derive(Deserialize)]
+struct LlmLabel {
+    i: usize,
+    sentiment: String,
+    #[serde(default)]
+    score: Option<f64>,
+    #[serde(default)]
+    tone: Option<String>,
+}
+
+/// Parses the model's JSON array into one result per segment. Segments the
+/// model skipped or labelled invalidly are None.
+pub fn parse_sentiment_response(
+    response: &str,
+    segment_count: usize,
+) -> Result<Vec<Option<SegmentSentiment>>, String> {
+    let labels: Vec<LlmLabel> = serde_json::from_str(json_array(response)?)
+        .map_err(|e| format!("Model returned invalid JSON: {}", e))?;
+
+    let mut results = vec![None; segment_count];
+    for label in labels {
+        let Some(sentiment) = Sentiment::parse(&label.sentiment) else {
+            continue;
+        };
+        let Some(slot) = results.get_mut(label.i) else {
+            continue;
+        };
+        let score = label
+            .score
+            .filter(|s| s.is_finite())
+            .map(|s| s.clamp(-1.0, 1.0))
+            .unwrap_or(match sentiment {
+                Sentiment::Positive => 0.5,
+                Sentiment::Neutral => 0.0,
+                Sentiment::Negative => -0.5,
+            });
+        let tone = label
+            .tone
+            .as_deref()
+            .and_then(|t| t.split_whitespace().next())
+            .map(|t| t.trim_matches(|c: char| !c.is_alphabetic()).to_lowercase())
+            .filter(|t| !t.is_empty())
+            .unwrap_or_else(|| "neutral".to_string());
+        *slot = Some(SegmentSentiment {
+            sentiment,
+            score,
+            tone,
+        });
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_analysis_flags_clear_sentiment() {
+        let frustrated = analyze_local("This is still not fixed, it's frustrating and unacceptable.");
+        assert_eq!(frustrated.sentiment, Sentiment::Negative);
+        assert_eq!(frustrated.tone, "angry");
+
+        let happy = analyze_local("Great work everyone, thanks, I'm really excited about this.");
+        assert_eq!(happy.sentiment, Sentiment::Positive);
+        assert_eq!(happy.tone, "appreciative");
+
+        let neutral = analyze_local("The next sync is on Tuesday at ten.");
+        assert_eq!(neutral.sentiment, Sentiment::Neutral);
+        assert_eq!(neutral.tone, "neutral");
+    }
+
+    #[test]
+    fn single_words_match_whole_words_only() {
+        // "goods" and "badge" must not count as "good" and "bad"
+        assert_eq!(analyze_local("Ship the goods with the badge").score, 0.0);
+    }
+
+    #[test]
+    fn parses_model_labels() {
+        let response = "Here you go:\n```json\n[{\"i\":0,\"sentiment\":\"Negative\",\"score\":-3,\"tone\":\"Frustrated.\"},\
+            {\"i\":2,\"sentiment\":\"positive\"},{\"i\":9,\"sentiment\":\"positive\"},{\"i\":1,\"sentiment\":\"meh\"}]\n```";
+        let parsed = parse_sentiment_response(response, 3).unwrap();
+        let first = parsed[0].as_ref().unwrap();
+        assert_eq!((first.sentiment, first.score, first.tone.as_str()), (Sentiment::Negative, -1.0, "frustrated"));
+        assert!(parsed[1].is_none());
+        assert_eq!(parsed[2].as_ref().unwrap().tone, "neutral");
+        assert!(parse_sentiment_response("no json", 1).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use crate::database::repositories::segment_analysis::{
+    AnalyzedSegment, SegmentAnalysisFilter, SegmentAnalysisRepository, ToneCount,
+};
+use crate::state::AppState;
+use crate::summary::llm_connection::LlmConnection;
+use crate::summary::segment_labeling::label_in_batches;
+use crate::summary::sentiment::{
+    analyze_local, parse_sentiment_response, SegmentSentiment, SENTIMENT_SYSTEM_PROMPT,
+};
+use log::{error as log_error, info as log_info};
+use serde::Serialize;
+use tauri::{AppHandle, Manager, Runtime};
+
+#[derive(Debug, Serialize)]
+pub struct SentimentRunReport {
+    pub meeting_id: String,
+    pub method: String,
+    pub model: Option<String>,
+    pub analyzed: usize,
+    /// Segments that were empty or that the model did not label
+    pub skipped: usize,
+    pub summary: Vec<ToneCount>,
+}
+
+/// Labels every segment of a meeting with sentiment and tone, replacing any
+/// earlier analysis. `method` is "local" (default, offline lexicon) or "llm"
+/// (the summary model from settings unless `provider`/`model` are given).
+#[tauri::command]
+pub async fn api_analyze_meeting_sentiment<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    method: Option<String>,
+    provider: Option<String>,
+    model: Option<String>,
+) -> Result<SentimentRunReport, String> {
+    crate::validation::meeting_id(&meeting_id)?;
+    let method = method.unwrap_or_else(|| "local".to_string());
+    log_info!(
+        "api_analyze_meeting_sentiment called for {} with method {}",
+        meeting_id,
+        method
+    );
+    let pool = state.db_manager.pool();
+
+    let segments: Vec<_> = SegmentAnalysisRepository::load_segments(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcript: {}", e))?
+        .into_iter()
+        .filter(|s| !s.transcript.trim().is_empty())
+        .collect();
+    if segments.is_empty() {
+        return Err(format!(
+            "Meeting '{}' has no transcript to analyze",
+            meeting_id
+        ));
+    }
+
+    let (results, model_name): (Vec<(String, SegmentSentiment)>, Option<String>) =
+        match method.as_str() {
+            "local" => (
+                segments
+                    .iter()
+                    .map(|s| (s.id.clone(), analyze_local(&s.transcript)))
+                    .collect(),
+                None,
+            ),
+            "llm" => {
+                let app_data_dir = app.path().app_data_dir().ok();
+                let connection = match (provider, model) {
+                    (Some(provider), Some(model)) => {
+                        LlmConnection::resolve(pool, &provider, &model, app_data_dir).await?
+                    }
+                    _ => LlmConnection::from_settings(pool, app_data_dir).await?,
+                };
+                let inputs: Vec<_> = segments
+                    .iter()
+                    .map(|s| (s.speaker.as_deref(), s.transcript.as_str()))
+                    .collect();
+                let labels = label_in_batches(
+                    &connection,
+                    SENTIMENT_SYSTEM_PROMPT,
+                    &inputs,
+                    parse_sentiment_response,
+                )
+                .await
+                .map_err(|e| format!("Failed to analyze sentiment: {}", e))?;
+                let results = segments
+                    .iter()
+                    .zip(labels)
+                    .filter_map(|(s, label)| label.map(|l| (s.id.clone(), l)))
+                    .collect();
+                (results, Some(connection.model_name))
+            }
+            other => {
+                return Err(format!(
+                    "Unknown analysis method '{}'. Use 'local' or 'llm'",
+                    other
+                ))
+            }
+        };
+
+    if results.is_empty() {
+        return Err(
+            "The model labeled none of the segments; the earlier analysis was kept".to_string(),
+        );
+    }
+    SegmentAnalysisRepository::save_results(
+        pool,
+        &meeting_id,
+        &results,
+        &method,
+        model_name.as_deref(),
+    )
+    .await
+    .map_err(|e| format!("Failed to save analysis: {}", e))?;
+    let summary = SegmentAnalysisRepository::summarize(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to summarize analysis: {}", e))?;
+
+    log_info!(
+        "Analyzed {} of {} segments of {}",
+        results.len(),
+        segments.len(),
+        meeting_id
+    );
+    Ok(SentimentRunReport {
+        meeting_id,
+        method,
+        model: model_name,
+        analyzed: results.len(),
+        skipped: segments.len() - results.len(),
+        summary,
+    })
+}
+
+/// Finds analyzed segments by sentiment, tone and speaker, e.g. where the
+/// client sounded frustrated. Most negative first.
+#[tauri::command]
+pub async fn api_search_segment_sentiment<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    filter: SegmentAnalysisFilter,
+) -> Result<Vec<AnalyzedSegment>, String> {
+    log_info!(
+        "api_search_segment_sentiment called with filter: {:?}",
+        filter
+    );
+    SegmentAnalysisRepository::search(state.db_manager.pool(), &filter)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to search segment analysis: {}", e);
+            format!("Failed to search segments: {}", e)
+        })
+}
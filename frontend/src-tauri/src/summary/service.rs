@@ -1,8 +1,13 @@
 use crate::database::repositories::{
-    meeting::MeetingsRepository, setting::SettingsRepository, summary::SummaryProcessesRepository,
+    action_item::ActionItemsRepository, meeting::MeetingsRepository,
+    setting::SettingsRepository, summary::SummaryProcessesRepository,
 };
+use crate::summary::action_items::extract_action_items;
+use crate::summary::feedback::steer_prompt;
 use crate::summary::llm_client::LLMProvider;
+use crate::summary::llm_connection::LlmConnection;
 use crate::summary::processor::{extract_meeting_name_from_markdown, generate_meeting_summary};
+use crate::summary::schema::StoredSummary;
 use crate::ollama::metadata::ModelMetadataCache;
 use sqlx::SqlitePool;
 use std::collections::HashMap;
@@ -81,6 +86,7 @@ impl SummaryService {
         model_name: String,
         custom_prompt: String,
         template_id: String,
+        summary_language: Option<String>,
     ) {
         let start_time = Instant::now();
         info!(
@@ -91,83 +97,37 @@ impl SummaryService {
         // Register cancellation token for this meeting
         let cancellation_token = Self::register_cancellation_token(&meeting_id);
 
-        // Parse provider
-        let provider = match LLMProvider::from_str(&model_provider) {
-            Ok(p) => p,
+        // Resolve provider, credentials and endpoint settings
+        let connection = match LlmConnection::resolve(
+            &pool,
+            &model_provider,
+            &model_name,
+            _app.path().app_data_dir().ok(),
+        )
+        .await
+        {
+            Ok(connection) => connection,
             Err(e) => {
                 Self::update_process_failed(&pool, &meeting_id, &e).await;
                 return;
             }
         };
+        let provider = connection.provider.clone();
+        let ollama_endpoint = connection.ollama_endpoint.clone();
 
-        // Validate and setup api_key, Flexible for Ollama, BuiltInAI, and CustomOpenAI
-        let api_key = if provider == LLMProvider::Ollama || provider == LLMProvider::BuiltInAI || provider == LLMProvider::CustomOpenAI {
-            // These providers don't require API keys from the standard database column
-            String::new()
-        } else {
-            match SettingsRepository::get_api_key(&pool, &model_provider).await {
-                Ok(Some(key)) if !key.is_empty() => key,
-                Ok(None) | Ok(Some(_)) => {
-                    let err_msg = format!("API key not found for {}", &model_provider);
-                    Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
-                    return;
-                }
-                Err(e) => {
-                    let err_msg = format!("Failed to retrieve API key for {}: {}", &model_provider, e);
-                    Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
-                    return;
-                }
-            }
-        };
+        // Earlier feedback on this meeting series steers the style
+        let custom_prompt = steer_prompt(&pool, &meeting_id, custom_prompt).await;
 
-        // Get Ollama endpoint if provider is Ollama
-        let ollama_endpoint = if provider == LLMProvider::Ollama {
-            match SettingsRepository::get_model_config(&pool).await {
-                Ok(Some(config)) => config.ollama_endpoint,
-                Ok(None) => None,
-                Err(e) => {
-                    info!("Failed to retrieve Ollama endpoint: {}, using default", e);
+        // A per-request language wins over the saved one; neither means the
+        // transcript's language
+        let summary_language = match summary_language.filter(|l| !l.trim().is_empty()) {
+            Some(language) => Some(language),
+            None => SettingsRepository::get_summary_language(&pool)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("Failed to read the summary language: {}", e);
                     None
-                }
-            }
-        } else {
-            None
-        };
-
-        // Get CustomOpenAI config if provider is CustomOpenAI
-        let (custom_openai_endpoint, custom_openai_api_key, custom_openai_max_tokens, custom_openai_temperature, custom_openai_top_p) =
-            if provider == LLMProvider::CustomOpenAI {
-                match SettingsRepository::get_custom_openai_config(&pool).await {
-                    Ok(Some(config)) => {
-                        info!("✓ Using custom OpenAI endpoint: {}", config.endpoint);
-                        (
-                            Some(config.endpoint),
-                            config.api_key,
-                            config.max_tokens.map(|t| t as u32),
-                            config.temperature,
-                            config.top_p,
-                        )
-                    }
-                    Ok(None) => {
-                        let err_msg = "Custom OpenAI provider selected but no configuration found";
-                        Self::update_process_failed(&pool, &meeting_id, err_msg).await;
-                        return;
-                    }
-                    Err(e) => {
-                        let err_msg = format!("Failed to retrieve custom OpenAI config: {}", e);
-                        Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
-                        return;
-                    }
-                }
-            } else {
-                (None, None, None, None, None)
-            };
-
-        // For CustomOpenAI, use its API key (if any) instead of the empty string
-        let final_api_key = if provider == LLMProvider::CustomOpenAI {
-            custom_openai_api_key.unwrap_or_default()
-        } else {
-            api_key
+                }),
         };
 
         // Dynamically fetch context size based on provider and model
@@ -216,26 +176,24 @@ impl SummaryService {
             100000  // Effectively unlimited for single-pass processing
         };
 
-        // Get app data directory for BuiltInAI provider
-        let app_data_dir = _app.path().app_data_dir().ok();
-
         // Generate summary
         let client = reqwest::Client::new();
         let result = generate_meeting_summary(
             &client,
             &provider,
             &model_name,
-            &final_api_key,
+            &connection.api_key,
             &text,
             &custom_prompt,
             &template_id,
+            summary_language.as_deref(),
             token_threshold,
             ollama_endpoint.as_deref(),
-            custom_openai_endpoint.as_deref(),
-            custom_openai_max_tokens,
-            custom_openai_temperature,
-            custom_openai_top_p,
-            app_data_dir.as_ref(),
+            connection.custom_openai_endpoint.as_deref(),
+            connection.max_tokens,
+            connection.temperature,
+            connection.top_p,
+            connection.app_data_dir.as_ref(),
             Some(&cancellation_token),
         )
         .await;
@@ -271,7 +229,7 @@ impl SummaryService {
                             name, meeting_id
                         );
                         if let Err(e) =
-                            MeetingsRepository::update_meeting_title(&pool, &meeting_id, &name).await
+                            MeetingsRepository::update_meeting_title(&pool, &meeting_id, &name, None).await
                         {
                             error!("Failed to update meeting name for {}: {}", meeting_id, e);
                         }
@@ -295,10 +253,10 @@ impl SummaryService {
                     }
                 }
 
+                let action_items = extract_action_items(&final_markdown);
+
                 // Create result JSON with markdown only (summary_json will be added on first edit)
-                let result_json = serde_json::json!({
-                    "markdown": final_markdown,
-                });
+                let result_json = StoredSummary::from_markdown(final_markdown).to_value();
 
                 // Update database with completed status
                 if let Err(e) = SummaryProcessesRepository::update_process_completed(
@@ -310,15 +268,27 @@ impl SummaryService {
                 )
                 .await
                 {
-                    error!(
-                        "Failed to save completed process for {}: {}",
-                        meeting_id, e
-                    );
+                    // Minutes approved while the summary was generated keep
+                    // their approved summary
+                    Self::update_process_failed(
+                        &pool,
+                        &meeting_id,
+                        &format!("Failed to save the summary: {}", e),
+                    )
+                    .await;
                 } else {
                     info!(
                         "Summary saved successfully for meeting_id: {}",
                         meeting_id
                     );
+                    // Keep the action items table in sync with the new summary
+                    if let Err(e) =
+                        ActionItemsRepository::replace_extracted(&pool, &meeting_id, &action_items)
+                            .await
+                    {
+                        warn!("Failed to store action items for {}: {}", meeting_id, e);
+                    }
+                    crate::hooks::fire(pool.clone(), crate::hooks::HookEvent::SummaryGenerated, meeting_id.clone());
                 }
             }
             Err(e) => {
@@ -14,7 +14,7 @@ use tokio::sync::Mutex;
 use tokio_util::sync::CancellationToken;
 
 use super::models;
-use super::sidecar::SidecarManager;
+use super::sidecar::{SidecarManager, SidecarStatus};
 
 // ============================================================================
 // Request/Response Types
@@ -43,6 +43,36 @@ enum Response {
     Error { message: String },
 }
 
+/// Sidecar lifecycle changes, emitted to the frontend
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SidecarEvent {
+    Started,
+    Stopped,
+    /// The process exited on its own; `restart_in_secs` is None when no
+    /// more restarts will be attempted
+    Crashed {
+        exit_status: String,
+        restart_in_secs: Option<u64>,
+    },
+    Restarted { attempt: u32 },
+    RestartFailed { attempt: u32, error: String },
+    GaveUp { attempts: u32 },
+}
+
+// ============================================================================
+// Crash Supervision
+// ============================================================================
+
+/// How often the supervisor checks whether the process is still alive
+const SUPERVISE_INTERVAL_SECS: u64 = 2;
+/// Consecutive crashes restarted before giving up
+const MAX_RESTART_ATTEMPTS: u32 = 5;
+/// Longest wait before a restart
+const MAX_RESTART_DELAY_SECS: u64 = 60;
+/// A process up this long is stable again and resets the crash count
+const STABLE_UPTIME_SECS: u64 = 300;
+
 // ============================================================================
 // Global Sidecar Manager
 // ============================================================================
@@ -72,6 +102,17 @@ async fn get_sidecar_manager() -> Result<Arc<SidecarManager>> {
         .ok_or_else(|| anyhow!("Sidecar manager not initialized. Call init_sidecar_manager first."))
 }
 
+/// The global sidecar manager, created on first use
+async fn get_or_init_sidecar_manager(app_data_dir: &PathBuf) -> Result<Arc<SidecarManager>> {
+    let mut global_manager = SIDECAR_MANAGER.lock().await;
+    if global_manager.is_none() {
+        log::info!("Initializing sidecar manager");
+        let new_manager = SidecarManager::new(app_data_dir.clone())?;
+        *global_manager = Some(Arc::new(new_manager));
+    }
+    Ok(global_manager.clone().unwrap())
+}
+
 /// Get cached model path with read-through caching to avoid repeated filesystem I/O
 fn get_cached_model_path(app_data_dir: &PathBuf, model_name: &str) -> Result<PathBuf> {
     // Try read lock first (fast path for cache hits)
@@ -154,15 +195,7 @@ pub async fn generate_with_builtin(
     let formatted_prompt =
         models::format_prompt(&model_def.template, system_prompt, user_prompt)?;
     // Get or initialize sidecar manager
-    let manager = {
-        let mut global_manager = SIDECAR_MANAGER.lock().await;
-        if global_manager.is_none() {
-            log::info!("Initializing sidecar manager");
-            let new_manager = SidecarManager::new(app_data_dir.clone())?;
-            *global_manager = Some(Arc::new(new_manager));
-        }
-        global_manager.clone().unwrap()
-    };
+    let manager = get_or_init_sidecar_manager(app_data_dir).await?;
 
     // Ensure sidecar is running with this model
     manager.ensure_running(model_path.clone()).await?;
@@ -285,6 +318,162 @@ pub async fn is_sidecar_healthy() -> bool {
     }
 }
 
+/// Current state of the sidecar process
+pub async fn sidecar_status() -> SidecarStatus {
+    match get_sidecar_manager().await {
+        Ok(manager) => manager.status().await,
+        Err(_) => SidecarStatus::stopped(),
+    }
+}
+
+/// Starts the sidecar with `model_name`, or with the model it last ran
+pub async fn start_sidecar(app_data_dir: &PathBuf, model_name: Option<&str>) -> Result<()> {
+    let manager = get_or_init_sidecar_manager(app_data_dir).await?;
+    match model_name {
+        Some(model_name) => {
+            let model_path = get_cached_model_path(app_data_dir, model_name)?;
+            manager.ensure_running(model_path).await
+        }
+        None if manager.is_healthy() => Ok(()),
+        None => manager.restart().await,
+    }
+}
+
+/// Stops the sidecar. Refuses while a generation is running unless `force`.
+pub async fn stop_sidecar(force: bool) -> Result<()> {
+    let Ok(manager) = get_sidecar_manager().await else {
+        return Ok(());
+    };
+    ensure_idle(&manager, force)?;
+    manager.shutdown().await
+}
+
+/// Restarts the sidecar with the model it last ran
+pub async fn restart_sidecar(app_data_dir: &PathBuf, force: bool) -> Result<()> {
+    let manager = get_or_init_sidecar_manager(app_data_dir).await?;
+    ensure_idle(&manager, force)?;
+    manager.restart().await
+}
+
+/// Fails while the sidecar is generating, unless `force`
+pub async fn ensure_sidecar_idle(force: bool) -> Result<()> {
+    match get_sidecar_manager().await {
+        Ok(manager) => ensure_idle(&manager, force),
+        Err(_) => Ok(()),
+    }
+}
+
+/// Loads `model_name` into a running sidecar. A stopped sidecar picks up the
+/// configured model on its next generation, so it isn't started here.
+pub async fn switch_sidecar_model(app_data_dir: &PathBuf, model_name: &str, force: bool) -> Result<()> {
+    let model_path = get_cached_model_path(app_data_dir, model_name)?;
+    let Ok(manager) = get_sidecar_manager().await else {
+        return Ok(());
+    };
+    if !manager.is_running().await {
+        return Ok(());
+    }
+    ensure_idle(&manager, force)?;
+    log::info!("Switching sidecar to model {}", model_name);
+    manager.ensure_running(model_path).await
+}
+
+fn ensure_idle(manager: &SidecarManager, force: bool) -> Result<()> {
+    let active = manager.active_requests();
+    if active > 0 && !force {
+        return Err(anyhow!(
+            "{} generation(s) in progress; wait for them or force the operation",
+            active
+        ));
+    }
+    Ok(())
+}
+
+/// Wait before restart attempt `attempt` (0-based): 1s, 2s, 4s... up to
+/// MAX_RESTART_DELAY_SECS. None once MAX_RESTART_ATTEMPTS have been made.
+pub fn restart_delay(attempt: u32) -> Option<Duration> {
+    if attempt >= MAX_RESTART_ATTEMPTS {
+        return None;
+    }
+    let secs = 1u64
+        .checked_shl(attempt)
+        .unwrap_or(u64::MAX)
+        .min(MAX_RESTART_DELAY_SECS);
+    Some(Duration::from_secs(secs))
+}
+
+async fn is_current_manager(manager: &Arc<SidecarManager>) -> bool {
+    match get_sidecar_manager().await {
+        Ok(current) => Arc::ptr_eq(&current, manager),
+        Err(_) => false,
+    }
+}
+
+/// Watches the sidecar for crashes and restarts it with backoff. Runs for
+/// the life of the app; `emit` reports every lifecycle change.
+pub async fn supervise_sidecar<F>(emit: F)
+where
+    F: Fn(SidecarEvent) + Send + Sync + 'static,
+{
+    let mut interval = tokio::time::interval(Duration::from_secs(SUPERVISE_INTERVAL_SECS));
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    let mut attempt = 0;
+
+    loop {
+        interval.tick().await;
+        let Ok(manager) = get_sidecar_manager().await else {
+            continue;
+        };
+        if manager
+            .uptime_secs()
+            .await
+            .is_some_and(|uptime| uptime >= STABLE_UPTIME_SECS)
+        {
+            attempt = 0;
+        }
+        let Some(exit_status) = manager.take_unexpected_exit().await else {
+            continue;
+        };
+
+        emit(SidecarEvent::Crashed {
+            exit_status,
+            restart_in_secs: restart_delay(attempt).map(|delay| delay.as_secs()),
+        });
+        loop {
+            let Some(delay) = restart_delay(attempt) else {
+                log::error!("Sidecar crashed {} times in a row, not restarting", attempt);
+                emit(SidecarEvent::GaveUp { attempts: attempt });
+                break;
+            };
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+
+            // Started, stopped or replaced in the meantime
+            if !is_current_manager(&manager).await
+                || manager.is_healthy()
+                || manager.is_shutting_down()
+            {
+                break;
+            }
+            log::info!("Restarting sidecar (attempt {})", attempt);
+            match manager.restart().await {
+                Ok(()) => {
+                    manager.record_restart();
+                    emit(SidecarEvent::Restarted { attempt });
+                    break;
+                }
+                Err(e) => {
+                    log::warn!("Sidecar restart attempt {} failed: {}", attempt, e);
+                    emit(SidecarEvent::RestartFailed {
+                        attempt,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -327,6 +516,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_restart_backoff() {
+        let delays: Vec<u64> = (0..MAX_RESTART_ATTEMPTS)
+            .map(|attempt| restart_delay(attempt).unwrap().as_secs())
+            .collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 16]);
+        assert!(restart_delay(MAX_RESTART_ATTEMPTS).is_none());
+    }
+
     #[test]
     fn test_error_response_deserialization() {
         let json = r#"{"type":"error","message":"something went wrong"}"#;
@@ -6,7 +6,13 @@ use std::sync::Arc;
 use tauri::{AppHandle, Emitter, Manager, Runtime, State};
 use tokio::sync::Mutex;
 
-use super::model_manager::{DownloadProgress, ModelInfo, ModelManager};
+use super::client::{self, SidecarEvent};
+use super::model_manager::{DownloadProgress, ModelInfo, ModelManager, ModelStatus};
+use super::sidecar::SidecarStatus;
+use crate::audit::{self, AuditAction};
+use crate::database::repositories::setting::SettingsRepository;
+use crate::database::settings_cache::{config_changed, ConfigKind};
+use crate::state::AppState;
 
 // ============================================================================
 // Global State
@@ -313,6 +319,7 @@ pub async fn builtin_ai_get_available_summary_model<R: Runtime>(
         .filter(|m| matches!(m.status, crate::summary::summary_engine::model_manager::ModelStatus::Available))
         .max_by_key(|m| {
             match m.name.as_str() {
+                "gemma3:12b" => 3,
                 "gemma3:4b" => 2,
                 "gemma3:1b" => 1,
                 _ => 0,
@@ -391,3 +398,166 @@ fn get_system_ram_gb() -> Result<u64, String> {
 
     Ok(total_memory_gb)
 }
+
+// ============================================================================
+// Sidecar Lifecycle Commands
+// ============================================================================
+
+/// Event carrying `SidecarEvent`s to the frontend
+pub const SIDECAR_EVENT: &str = "builtin-ai-sidecar";
+
+fn emit_sidecar_event<R: Runtime>(app: &AppHandle<R>, event: SidecarEvent) {
+    if let Err(e) = app.emit(SIDECAR_EVENT, &event) {
+        log::warn!("Failed to emit sidecar event: {}", e);
+    }
+}
+
+/// Starts the crash supervisor for the app's lifetime
+pub fn start_sidecar_supervisor<R: Runtime>(app: &AppHandle<R>) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(client::supervise_sidecar(move |event| {
+        emit_sidecar_event(&app, event)
+    }));
+}
+
+fn app_data_dir<R: Runtime>(app: &AppHandle<R>) -> Result<std::path::PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))
+}
+
+/// Process, model and memory use of the llama sidecar
+#[tauri::command]
+pub async fn builtin_ai_sidecar_status() -> Result<SidecarStatus, String> {
+    Ok(client::sidecar_status().await)
+}
+
+/// Starts the sidecar and loads `model_name` (default: the last model)
+#[tauri::command]
+pub async fn builtin_ai_sidecar_start<R: Runtime>(
+    app: AppHandle<R>,
+    model_name: Option<String>,
+) -> Result<SidecarStatus, String> {
+    log::info!("Starting sidecar (model: {:?})", model_name);
+    client::start_sidecar(&app_data_dir(&app)?, model_name.as_deref())
+        .await
+        .map_err(|e| format!("Failed to start sidecar: {}", e))?;
+    emit_sidecar_event(&app, SidecarEvent::Started);
+    Ok(client::sidecar_status().await)
+}
+
+/// Stops the sidecar. `force` also interrupts running generations.
+#[tauri::command]
+pub async fn builtin_ai_sidecar_stop<R: Runtime>(
+    app: AppHandle<R>,
+    force: Option<bool>,
+) -> Result<SidecarStatus, String> {
+    log::info!("Stopping sidecar (force: {:?})", force);
+    client::stop_sidecar(force.unwrap_or(false))
+        .await
+        .map_err(|e| format!("Failed to stop sidecar: {}", e))?;
+    emit_sidecar_event(&app, SidecarEvent::Stopped);
+    Ok(client::sidecar_status().await)
+}
+
+/// Restarts the sidecar with its last model. `force` also interrupts
+/// running generations.
+#[tauri::command]
+pub async fn builtin_ai_sidecar_restart<R: Runtime>(
+    app: AppHandle<R>,
+    force: Option<bool>,
+) -> Result<SidecarStatus, String> {
+    log::info!("Restarting sidecar (force: {:?})", force);
+    client::restart_sidecar(&app_data_dir(&app)?, force.unwrap_or(false))
+        .await
+        .map_err(|e| format!("Failed to restart sidecar: {}", e))?;
+    emit_sidecar_event(&app, SidecarEvent::Started);
+    Ok(client::sidecar_status().await)
+}
+
+/// Makes `model_name` the built-in summary model and then loads it into the
+/// sidecar if one is running. `force` also interrupts running generations.
+#[tauri::command]
+pub async fn builtin_ai_switch_model<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    models: State<'_, ModelManagerState>,
+    model_name: String,
+    force: Option<bool>,
+) -> Result<SidecarStatus, String> {
+    log::info!("Switching built-in AI model to {}", model_name);
+    {
+        let manager_lock = models.0.lock().await;
+        if manager_lock.is_none() {
+            drop(manager_lock);
+            init_model_manager(&app)
+                .await
+                .map_err(|e| format!("Failed to initialize model manager: {}", e))?;
+        }
+    }
+    let manager = models
+        .0
+        .lock()
+        .await
+        .as_ref()
+        .ok_or_else(|| "Model manager not initialized".to_string())?
+        .clone();
+    match manager.get_model_info(&model_name).await {
+        Some(info) if info.status == ModelStatus::Available => {}
+        Some(_) => return Err(format!("Model '{}' is not downloaded", model_name)),
+        None => return Err(format!("Unknown model: {}", model_name)),
+    }
+
+    // Checked before the setting changes, so a refused switch changes nothing
+    let force = force.unwrap_or(false);
+    client::ensure_sidecar_idle(force)
+        .await
+        .map_err(|e| format!("Failed to switch model: {}", e))?;
+
+    let pool = state.db_manager.pool();
+    let current = SettingsRepository::get_model_config(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    let whisper_model = current
+        .as_ref()
+        .map_or("large-v3", |setting| setting.whisper_model.as_str());
+    let ollama_endpoint = current
+        .as_ref()
+        .and_then(|setting| setting.ollama_endpoint.as_deref());
+    let result = SettingsRepository::save_model_config(
+        pool,
+        "builtin-ai",
+        &model_name,
+        whisper_model,
+        ollama_endpoint,
+    )
+    .await;
+    config_changed(&app, &state.settings_cache, ConfigKind::Model);
+    result.map_err(|e| format!("Failed to save model config: {}", e))?;
+
+    let _ = app.emit(
+        "model-config-updated",
+        serde_json::json!({
+            "provider": "builtin-ai",
+            "model": model_name,
+            "whisperModel": whisper_model,
+            "apiKey": null,
+            "ollamaEndpoint": ollama_endpoint,
+        }),
+    );
+    audit::record(
+        pool,
+        AuditAction::ConfigChange,
+        "model_config",
+        None,
+        serde_json::json!({ "provider": "builtin-ai", "model": model_name }),
+    )
+    .await;
+
+    // The next generation loads the saved model even if this fails
+    client::switch_sidecar_model(&app_data_dir(&app)?, &model_name, force)
+        .await
+        .map_err(|e| format!("Failed to switch model: {}", e))?;
+    Ok(client::sidecar_status().await)
+}
+
@@ -93,6 +93,12 @@ pub struct ModelInfo {
     /// Size in MB
     pub size_mb: u64,
 
+    /// Memory needed to run on the CPU, in MB
+    pub ram_required_mb: u64,
+
+    /// GPU memory needed with every layer offloaded, in MB
+    pub vram_required_mb: u64,
+
     /// Context window size in tokens
     pub context_size: u32,
 
@@ -275,6 +281,8 @@ impl ModelManager {
                 status,
                 path: model_path,
                 size_mb: model_def.size_mb,
+                ram_required_mb: model_def.ram_required_mb,
+                vram_required_mb: model_def.vram_required_mb,
                 context_size: model_def.context_size,
                 description: model_def.description.clone(),
                 gguf_file: model_def.gguf_file.clone(),
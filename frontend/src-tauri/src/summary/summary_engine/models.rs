@@ -47,6 +47,12 @@ pub struct ModelDef {
     /// File size in MB
     pub size_mb: u64,
 
+    /// Memory needed to run on the CPU, in MB (weights plus context)
+    pub ram_required_mb: u64,
+
+    /// GPU memory needed with every layer offloaded, in MB
+    pub vram_required_mb: u64,
+
     /// Context window size in tokens (configurable per model!)
     /// This is used for chunking in processor.rs
     pub context_size: u32,
@@ -73,6 +79,8 @@ pub fn get_available_models() -> Vec<ModelDef> {
             template: "gemma3".to_string(),
             download_url: "https://meetily.towardsgeneralintelligence.com/models/gemma-3-1b-it-Q8_0.gguf".to_string(),
             size_mb: 1019,
+            ram_required_mb: 1500,
+            vram_required_mb: 1400,
             context_size: 32768, 
             layer_count: 26,     
             sampling: SamplingParams {
@@ -90,6 +98,8 @@ pub fn get_available_models() -> Vec<ModelDef> {
             template: "gemma3".to_string(),
             download_url: "https://meetily.towardsgeneralintelligence.com/models/gemma-3-4b-it-Q4_K_M.gguf".to_string(),
             size_mb: 2374,
+            ram_required_mb: 3500,
+            vram_required_mb: 3200,
             context_size: 32768, // Supports 128k, but 32k is good for local·
             layer_count: 35,
             sampling: SamplingParams {
@@ -100,6 +110,44 @@ pub fn get_available_models() -> Vec<ModelDef> {
             },
             description: "Balanced model. Great quality/speed trade-off. Requires ~3.5GB RAM.".to_string(),
         },
+        ModelDef {
+            name: "gemma3:12b".to_string(),
+            display_name: "Gemma 3 12B (Quality)".to_string(),
+            gguf_file: "gemma-3-12b-it-Q4_K_M.gguf".to_string(),
+            template: "gemma3".to_string(),
+            download_url: "https://huggingface.co/ggml-org/gemma-3-12b-it-GGUF/resolve/main/gemma-3-12b-it-Q4_K_M.gguf".to_string(),
+            size_mb: 6960,
+            ram_required_mb: 9500,
+            vram_required_mb: 8800,
+            context_size: 32768,
+            layer_count: 48,
+            sampling: SamplingParams {
+                temperature: 1.0,
+                top_k: 64,
+                top_p: 0.95,
+                stop_tokens: vec!["<end_of_turn>".to_string()],
+            },
+            description: "Best summaries of long meetings. Needs a GPU with ~9GB or ~10GB RAM.".to_string(),
+        },
+        ModelDef {
+            name: "qwen2.5:3b".to_string(),
+            display_name: "Qwen 2.5 3B (Multilingual)".to_string(),
+            gguf_file: "Qwen2.5-3B-Instruct-Q4_K_M.gguf".to_string(),
+            template: "chatml".to_string(),
+            download_url: "https://huggingface.co/bartowski/Qwen2.5-3B-Instruct-GGUF/resolve/main/Qwen2.5-3B-Instruct-Q4_K_M.gguf".to_string(),
+            size_mb: 1840,
+            ram_required_mb: 3000,
+            vram_required_mb: 2700,
+            context_size: 32768,
+            layer_count: 36,
+            sampling: SamplingParams {
+                temperature: 0.7,
+                top_k: 20,
+                top_p: 0.8,
+                stop_tokens: vec!["<|im_end|>".to_string()],
+            },
+            description: "Strong with Japanese and Chinese meetings. Requires ~3GB RAM.".to_string(),
+        },
     ]
 }
 
@@ -145,6 +193,15 @@ pub const GEMMA3_TEMPLATE: &str = "\
 <start_of_turn>model
 ";
 
+/// ChatML format (Qwen and most other instruction-tuned models)
+pub const CHATML_TEMPLATE: &str = "\
+<|im_start|>system
+{system_prompt}<|im_end|>
+<|im_start|>user
+{user_prompt}<|im_end|>
+<|im_start|>assistant
+";
+
 /// Format a prompt using the specified template
 ///
 /// # Arguments
@@ -161,6 +218,7 @@ pub fn format_prompt(
 ) -> Result<String> {
     let template = match template_name {
         "gemma3" => GEMMA3_TEMPLATE,
+        "chatml" => CHATML_TEMPLATE,
         _ => return Err(anyhow!("Unknown template: {}", template_name)),
     };
 
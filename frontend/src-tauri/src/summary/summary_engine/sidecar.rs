@@ -3,11 +3,12 @@
 
 use std::path::PathBuf;
 use std::process::Stdio;
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::process::{Child, ChildStdin, ChildStdout};
 use tokio::sync::{Mutex, RwLock};
@@ -52,6 +53,52 @@ pub struct SidecarManager {
 
     /// Idle timeout in seconds (configurable via env var)
     idle_timeout_secs: u64,
+
+    /// When the current process was spawned
+    started_at: Arc<RwLock<Option<Instant>>>,
+
+    /// Model of the last spawn, kept after shutdown for restarts
+    last_model_path: Arc<RwLock<Option<PathBuf>>>,
+
+    /// Automatic restarts after crashes
+    restart_count: Arc<AtomicU32>,
+
+    /// Exit status of the last crash
+    last_exit: Arc<RwLock<Option<String>>>,
+}
+
+/// Snapshot of the sidecar for the UI. The helper talks over stdin/stdout,
+/// so there is no port to report.
+#[derive(Debug, Clone, Serialize)]
+pub struct SidecarStatus {
+    /// "running", "unhealthy" or "stopped"
+    pub state: &'static str,
+    pub pid: Option<u32>,
+    pub model_path: Option<PathBuf>,
+    /// Resident memory of the helper process
+    pub memory_bytes: Option<u64>,
+    pub uptime_secs: Option<u64>,
+    pub active_requests: usize,
+    pub idle_timeout_secs: u64,
+    pub restart_count: u32,
+    pub last_exit: Option<String>,
+}
+
+impl SidecarStatus {
+    /// Status before the sidecar manager exists
+    pub fn stopped() -> Self {
+        Self {
+            state: "stopped",
+            pid: None,
+            model_path: None,
+            memory_bytes: None,
+            uptime_secs: None,
+            active_requests: 0,
+            idle_timeout_secs: models::DEFAULT_IDLE_TIMEOUT_SECS,
+            restart_count: 0,
+            last_exit: None,
+        }
+    }
 }
 
 /// RAII guard for tracking active requests
@@ -101,9 +148,33 @@ impl SidecarManager {
             helper_binary_path,
             current_model_path: Arc::new(RwLock::new(None)),
             idle_timeout_secs,
+            started_at: Arc::new(RwLock::new(None)),
+            last_model_path: Arc::new(RwLock::new(None)),
+            restart_count: Arc::new(AtomicU32::new(0)),
+            last_exit: Arc::new(RwLock::new(None)),
         })
     }
 
+    /// A handle sharing this manager's state, for background tasks
+    fn shared(&self) -> Self {
+        Self {
+            child_process: self.child_process.clone(),
+            stdin_writer: self.stdin_writer.clone(),
+            stdout_reader: self.stdout_reader.clone(),
+            last_activity: self.last_activity.clone(),
+            is_healthy: self.is_healthy.clone(),
+            should_shutdown: self.should_shutdown.clone(),
+            active_request_count: self.active_request_count.clone(),
+            helper_binary_path: self.helper_binary_path.clone(),
+            current_model_path: self.current_model_path.clone(),
+            idle_timeout_secs: self.idle_timeout_secs,
+            started_at: self.started_at.clone(),
+            last_model_path: self.last_model_path.clone(),
+            restart_count: self.restart_count.clone(),
+            last_exit: self.last_exit.clone(),
+        }
+    }
+
     /// Resolve the path to llama-helper binary
     fn resolve_helper_binary() -> Result<PathBuf> {
         // 1. Check environment variable (dev mode or manual override)
@@ -332,8 +403,10 @@ impl SidecarManager {
         // Update state
         {
             let mut current_model = self.current_model_path.write().await;
-            *current_model = Some(model_path);
+            *current_model = Some(model_path.clone());
         }
+        *self.last_model_path.write().await = Some(model_path);
+        *self.started_at.write().await = Some(Instant::now());
 
         self.is_healthy.store(true, Ordering::SeqCst);
         self.should_shutdown.store(false, Ordering::SeqCst);
@@ -529,6 +602,7 @@ impl SidecarManager {
             let mut current_model = self.current_model_path.write().await;
             *current_model = None;
         }
+        *self.started_at.write().await = None;
 
         self.is_healthy.store(false, Ordering::SeqCst);
 
@@ -536,6 +610,100 @@ impl SidecarManager {
         Ok(())
     }
 
+    /// Respawns the sidecar with the model it last ran
+    pub async fn restart(&self) -> Result<()> {
+        let model_path = self
+            .last_model_path
+            .read()
+            .await
+            .clone()
+            .ok_or_else(|| anyhow!("No model has been loaded yet"))?;
+        self.spawn(model_path).await
+    }
+
+    /// Whether a sidecar process exists (healthy or not)
+    pub async fn is_running(&self) -> bool {
+        self.child_process.lock().await.is_some()
+    }
+
+    /// Whether the sidecar was told to stop
+    pub fn is_shutting_down(&self) -> bool {
+        self.should_shutdown.load(Ordering::SeqCst)
+    }
+
+    /// Number of requests in flight
+    pub fn active_requests(&self) -> usize {
+        self.active_request_count.load(Ordering::SeqCst)
+    }
+
+    /// Detects a process that exited without being shut down. The dead
+    /// handles are cleared and the exit status returned; the model is kept
+    /// for `restart`.
+    pub async fn take_unexpected_exit(&self) -> Option<String> {
+        let status = {
+            let mut child_lock = self.child_process.lock().await;
+            let child = child_lock.as_mut()?;
+            let status = match child.try_wait() {
+                Ok(Some(status)) => status.to_string(),
+                Ok(None) => return None,
+                Err(e) => format!("unknown ({})", e),
+            };
+            if self.should_shutdown.load(Ordering::SeqCst) {
+                return None;
+            }
+            child_lock.take();
+            status
+        };
+
+        self.is_healthy.store(false, Ordering::SeqCst);
+        *self.stdin_writer.lock().await = None;
+        *self.stdout_reader.lock().await = None;
+        *self.current_model_path.write().await = None;
+        *self.started_at.write().await = None;
+        *self.last_exit.write().await = Some(status.clone());
+        log::error!("Sidecar exited unexpectedly: {}", status);
+        Some(status)
+    }
+
+    /// Counts an automatic restart
+    pub fn record_restart(&self) {
+        self.restart_count.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Seconds the current process has been up
+    pub async fn uptime_secs(&self) -> Option<u64> {
+        self.started_at
+            .read()
+            .await
+            .map(|started| started.elapsed().as_secs())
+    }
+
+    /// Current process, model and memory use
+    pub async fn status(&self) -> SidecarStatus {
+        let pid = self
+            .child_process
+            .lock()
+            .await
+            .as_ref()
+            .and_then(|child| child.id());
+        let state = match pid {
+            Some(_) if self.is_healthy() => "running",
+            Some(_) => "unhealthy",
+            None => "stopped",
+        };
+        SidecarStatus {
+            state,
+            pid,
+            model_path: self.current_model_path.read().await.clone(),
+            memory_bytes: pid.and_then(process_memory),
+            uptime_secs: self.uptime_secs().await,
+            active_requests: self.active_requests(),
+            idle_timeout_secs: self.idle_timeout_secs,
+            restart_count: self.restart_count.load(Ordering::SeqCst),
+            last_exit: self.last_exit.read().await.clone(),
+        }
+    }
+
     /// Check if sidecar is healthy
     pub fn is_healthy(&self) -> bool {
         self.is_healthy.load(Ordering::SeqCst)
@@ -555,18 +723,7 @@ impl SidecarManager {
 
     /// Start health check loop (runs in background)
     fn start_health_check_loop(&self) {
-        let manager = Self {
-            child_process: self.child_process.clone(),
-            stdin_writer: self.stdin_writer.clone(),
-            stdout_reader: self.stdout_reader.clone(),
-            last_activity: self.last_activity.clone(),
-            is_healthy: self.is_healthy.clone(),
-            should_shutdown: self.should_shutdown.clone(),
-            active_request_count: self.active_request_count.clone(),
-            helper_binary_path: self.helper_binary_path.clone(),
-            current_model_path: self.current_model_path.clone(),
-            idle_timeout_secs: self.idle_timeout_secs,
-        };
+        let manager = self.shared();
 
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(Duration::from_secs(30));
@@ -603,18 +760,7 @@ impl SidecarManager {
 
     /// Start idle check loop (runs in background)
     fn start_idle_check_loop(&self) {
-        let manager = Self {
-            child_process: self.child_process.clone(),
-            stdin_writer: self.stdin_writer.clone(),
-            stdout_reader: self.stdout_reader.clone(),
-            last_activity: self.last_activity.clone(),
-            is_healthy: self.is_healthy.clone(),
-            should_shutdown: self.should_shutdown.clone(),
-            active_request_count: self.active_request_count.clone(),
-            helper_binary_path: self.helper_binary_path.clone(),
-            current_model_path: self.current_model_path.clone(),
-            idle_timeout_secs: self.idle_timeout_secs,
-        };
+        let manager = self.shared();
 
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(Duration::from_secs(60));
@@ -658,6 +804,20 @@ impl SidecarManager {
     }
 }
 
+/// Resident memory of a process in bytes
+fn process_memory(pid: u32) -> Option<u64> {
+    use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+    let pid = Pid::from_u32(pid);
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::new().with_memory(),
+    );
+    system.process(pid).map(|process| process.memory())
+}
+
 impl Drop for SidecarManager {
     fn drop(&mut self) {
         // Set shutdown flag
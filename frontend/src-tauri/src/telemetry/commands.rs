@@ -0,0 +1,56 @@
+use log::{error as log_error, info as log_info};
+
+use super::crash::{self, CrashReport};
+use super::{load_settings, save_settings, submit_pending, TelemetrySettings};
+use crate::state::AppState;
+
+#[tauri::command]
+pub async fn api_get_telemetry_settings(
+    state: tauri::State<'_, AppState>,
+) -> Result<TelemetrySettings, String> {
+    load_settings(state.db_manager.pool()).await
+}
+
+/// Saves the opt-in choice. Turning submission on also sends any reports
+/// already waiting.
+#[tauri::command]
+pub async fn api_save_telemetry_settings(
+    state: tauri::State<'_, AppState>,
+    settings: TelemetrySettings,
+) -> Result<TelemetrySettings, String> {
+    log_info!(
+        "api_save_telemetry_settings called: crash_reports_enabled={}",
+        settings.crash_reports_enabled
+    );
+    let pool = state.db_manager.pool().clone();
+    save_settings(&pool, &settings).await?;
+    if settings.crash_reports_enabled {
+        tauri::async_runtime::spawn(super::submit_pending_if_enabled(pool));
+    }
+    Ok(settings)
+}
+
+/// Crash reports stored on this machine, newest first
+#[tauri::command]
+pub async fn api_list_crash_reports() -> Result<Vec<CrashReport>, String> {
+    Ok(crash::crash_dir()
+        .map(|dir| crash::list_reports(&dir))
+        .unwrap_or_default())
+}
+
+/// Sends pending reports now. Requires the opt-in setting.
+#[tauri::command]
+pub async fn api_submit_crash_reports(state: tauri::State<'_, AppState>) -> Result<usize, String> {
+    log_info!("api_submit_crash_reports called");
+    if !load_settings(state.db_manager.pool())
+        .await?
+        .crash_reports_enabled
+    {
+        return Err("Crash report submission is turned off".to_string());
+    }
+    let dir = crash::crash_dir().ok_or_else(|| "App data directory is unavailable".to_string())?;
+    submit_pending(&dir).await.map_err(|e| {
+        log_error!("Failed to submit crash reports: {}", e);
+        e
+    })
+}
@@ -0,0 +1,203 @@
+//! Local crash reports written by the panic hook.
+//!
+//! Reports are plain JSON files in `<app data>/crash_reports`, one per panic.
+//! They stay on this machine unless the user opts in to submission; the
+//! recent log lines they carry are redacted before they are written.
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::logging::redact_line;
+
+/// Log lines kept in memory for the next crash report
+const MAX_BREADCRUMBS: usize = 50;
+
+static BREADCRUMBS: Lazy<Mutex<VecDeque<String>>> =
+    Lazy::new(|| Mutex::new(VecDeque::with_capacity(MAX_BREADCRUMBS)));
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashReport {
+    pub id: String,
+    pub app_version: String,
+    pub os: String,
+    pub arch: String,
+    pub thread: String,
+    pub message: String,
+    pub location: Option<String>,
+    pub backtrace: String,
+    /// The last log lines before the panic, redacted
+    pub recent_log: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    /// Set once the report has been accepted by the backend
+    #[serde(default)]
+    pub submitted_at: Option<DateTime<Utc>>,
+}
+
+/// What leaves the machine: no thread names and no user names in paths
+#[derive(Debug, Clone, Serialize)]
+pub struct AnonymizedCrashReport {
+    pub id: String,
+    pub app_version: String,
+    pub os: String,
+    pub arch: String,
+    pub message: String,
+    pub location: Option<String>,
+    pub backtrace: String,
+    pub recent_log: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// `/Users/<name>`, `/home/<name>` and `C:\Users\<name>` (Windows user
+/// names may contain spaces)
+static HOME_DIRS: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(?:/Users/|/home/)[^/\s:]+|[A-Z]:\\Users\\[^\\\n]+").expect("home dir pattern")
+});
+
+/// Replaces user names in home directory paths with `~`
+pub fn strip_user_paths(text: &str) -> String {
+    HOME_DIRS.replace_all(text, "~").into_owned()
+}
+
+impl CrashReport {
+    pub fn anonymized(&self) -> AnonymizedCrashReport {
+        let clean = |text: &str| strip_user_paths(&redact_line(text).0);
+        AnonymizedCrashReport {
+            id: self.id.clone(),
+            app_version: self.app_version.clone(),
+            os: self.os.clone(),
+            arch: self.arch.clone(),
+            message: clean(&self.message),
+            location: self.location.as_deref().map(strip_user_paths),
+            backtrace: strip_user_paths(&self.backtrace),
+            recent_log: self.recent_log.iter().map(|line| clean(line)).collect(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// Called by the log sink for every formatted record
+pub fn push_breadcrumb(line: &str) {
+    // Never block logging on the crash buffer
+    let Ok(mut crumbs) = BREADCRUMBS.try_lock() else {
+        return;
+    };
+    for line in line.lines().filter(|l| !l.trim().is_empty()) {
+        if crumbs.len() == MAX_BREADCRUMBS {
+            crumbs.pop_front();
+        }
+        crumbs.push_back(line.to_string());
+    }
+}
+
+fn recent_breadcrumbs() -> Vec<String> {
+    BREADCRUMBS
+        .try_lock()
+        .map(|crumbs| crumbs.iter().map(|line| redact_line(line).0).collect())
+        .unwrap_or_default()
+}
+
+/// Where crash reports are stored
+pub fn crash_dir() -> Option<PathBuf> {
+    crate::database::manager::default_app_data_dir().map(|dir| dir.join("crash_reports"))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+fn report_path(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("crash-{}.json", id))
+}
+
+pub fn write_report(dir: &Path, report: &CrashReport) -> Result<PathBuf, String> {
+    std::fs::create_dir_all(dir)
+        .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let path = report_path(dir, &report.id);
+    let json = serde_json::to_vec_pretty(report)
+        .map_err(|e| format!("Failed to serialize crash report: {}", e))?;
+    std::fs::write(&path, json)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+/// Crash reports in `dir`, newest first. Unreadable files are skipped.
+pub fn list_reports(dir: &Path) -> Vec<CrashReport> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut reports: Vec<CrashReport> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|p| {
+            let raw = std::fs::read(&p).ok()?;
+            serde_json::from_slice(&raw).ok()
+        })
+        .collect();
+    reports.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    reports
+}
+
+/// Installs a panic hook that writes a crash report before the default hook
+/// runs. Safe to call once at startup, before the Tauri runtime exists.
+pub fn install_panic_hook(app_version: &'static str) {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let thread = std::thread::current();
+        let report = CrashReport {
+            id: uuid::Uuid::new_v4().to_string(),
+            app_version: app_version.to_string(),
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            thread: thread.name().unwrap_or("<unnamed>").to_string(),
+            message: panic_message(info.payload()),
+            location: info
+                .location()
+                .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column())),
+            backtrace: std::backtrace::Backtrace::force_capture().to_string(),
+            recent_log: recent_breadcrumbs(),
+            created_at: Utc::now(),
+            submitted_at: None,
+        };
+        match crash_dir().map(|dir| write_report(&dir, &report)) {
+            Some(Ok(path)) => eprintln!("Crash report written to {}", path.display()),
+            Some(Err(e)) => eprintln!("{}", e),
+            None => eprintln!("No app data directory for crash reports"),
+        }
+        previous(info);
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_user_names_from_paths() {
+        assert_eq!(
+            strip_user_paths("at /Users/jane/src/meetily/src/lib.rs:10"),
+            "at ~/src/meetily/src/lib.rs:10"
+        );
+        assert_eq!(strip_user_paths(r"C:\Users\Jane Doe\AppData"), r"~\AppData");
+        assert_eq!(
+            strip_user_paths("/home/bob/.local/share/meetily"),
+            "~/.local/share/meetily"
+        );
+        assert_eq!(
+            strip_user_paths("/rustc/abc/library/std/src/panicking.rs"),
+            "/rustc/abc/library/std/src/panicking.rs"
+        );
+    }
+}
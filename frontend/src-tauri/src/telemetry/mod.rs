@@ -0,0 +1,110 @@
+/// Telemetry module - local crash reports and opt-in submission
+///
+/// This module contains:
+/// - A panic hook that writes a crash report (stack trace, app version and the
+///   last log lines) to `<app data>/crash_reports`
+/// - The opt-in setting for sending anonymized reports to the backend server
+///   (off by default)
+/// - Submission of pending reports, with user names stripped from paths
+/// - Tauri commands for frontend integration
+
+pub mod commands;
+pub mod crash;
+
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::database::repositories::integration::IntegrationSettingsRepository;
+
+pub use crash::{install_panic_hook, push_breadcrumb, CrashReport};
+
+pub const TELEMETRY_SETTINGS_ID: &str = "telemetry";
+const SUBMIT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TelemetrySettings {
+    /// Send anonymized crash reports to the backend server
+    #[serde(default)]
+    pub crash_reports_enabled: bool,
+}
+
+pub async fn load_settings(pool: &SqlitePool) -> Result<TelemetrySettings, String> {
+    let raw = IntegrationSettingsRepository::get_config(pool, TELEMETRY_SETTINGS_ID)
+        .await
+        .map_err(|e| format!("Failed to load telemetry settings: {}", e))?;
+    match raw {
+        Some(raw) => serde_json::from_str(&raw)
+            .map_err(|e| format!("Stored telemetry settings are invalid: {}", e)),
+        None => Ok(TelemetrySettings::default()),
+    }
+}
+
+pub async fn save_settings(pool: &SqlitePool, settings: &TelemetrySettings) -> Result<(), String> {
+    let raw = serde_json::to_string(settings)
+        .map_err(|e| format!("Failed to serialize telemetry settings: {}", e))?;
+    IntegrationSettingsRepository::save_config(pool, TELEMETRY_SETTINGS_ID, &raw)
+        .await
+        .map_err(|e| format!("Failed to save telemetry settings: {}", e))
+}
+
+/// Sends every report in `dir` that hasn't been accepted yet; returns how many
+/// were sent. Stops at the first failure, leaving the rest for next time.
+pub async fn submit_pending(dir: &Path) -> Result<usize, String> {
+    let pending: Vec<CrashReport> = crash::list_reports(dir)
+        .into_iter()
+        .filter(|report| report.submitted_at.is_none())
+        .collect();
+    if pending.is_empty() {
+        return Ok(0);
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(SUBMIT_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+    let url = format!("{}/crash-reports", crate::api::api::APP_SERVER_URL);
+
+    let mut sent = 0;
+    for mut report in pending {
+        let response = client
+            .post(&url)
+            .json(&report.anonymized())
+            .send()
+            .await
+            .map_err(|e| format!("Failed to send crash report: {}", e))?;
+        if !response.status().is_success() {
+            return Err(format!(
+                "Backend rejected crash report {}: HTTP {}",
+                report.id,
+                response.status()
+            ));
+        }
+        report.submitted_at = Some(chrono::Utc::now());
+        crash::write_report(dir, &report)?;
+        sent += 1;
+    }
+    Ok(sent)
+}
+
+/// Sends reports left by earlier sessions if the user opted in. Runs once at
+/// startup; failures (e.g. the backend isn't running) are only logged.
+pub async fn submit_pending_if_enabled(pool: SqlitePool) {
+    match load_settings(&pool).await {
+        Ok(settings) if settings.crash_reports_enabled => {}
+        Ok(_) => return,
+        Err(e) => {
+            log::warn!("Skipping crash report submission: {}", e);
+            return;
+        }
+    }
+    let Some(dir) = crash::crash_dir() else {
+        return;
+    };
+    match submit_pending(&dir).await {
+        Ok(0) => {}
+        Ok(sent) => log::info!("Submitted {} crash reports", sent),
+        Err(e) => log::warn!("Crash reports not submitted: {}", e),
+    }
+}
@@ -1,10 +1,16 @@
+use std::sync::Mutex;
 use tauri::{
-    Emitter,
-    menu::{MenuBuilder, MenuItemBuilder, PredefinedMenuItem},
+    menu::{MenuBuilder, MenuItem, MenuItemBuilder, PredefinedMenuItem},
     tray::TrayIconBuilder,
     AppHandle, Manager, Runtime,
 };
 
+use crate::events::{self, RecordingStopComplete};
+
+/// Duration item of the current tray menu, kept so the elapsed time can be
+/// updated in place without rebuilding (and closing) an open menu
+struct TrayDurationItem<R: Runtime>(Mutex<Option<MenuItem<R>>>);
+
 #[derive(Debug, Clone)]
 pub enum RecordingState {
     Stopped,
@@ -17,6 +23,8 @@ pub enum RecordingState {
 }
 
 pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
+    app.manage(TrayDurationItem::<R>(Mutex::new(None)));
+
     // Start with default menu, will update with actual state after initialization
     // Pass can_record=true initially, will be updated by update_tray_menu immediately
     let menu = build_menu(app, RecordingState::Stopped, true)?;
@@ -41,6 +49,7 @@ fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, item_id: &str) {
         "resume_recording" => resume_recording_handler(app),
         "stop_recording" => stop_recording_handler(app),
         "open_window" => focus_main_window(app),
+        "open_last_meeting" => open_last_meeting_handler(app),
         "settings" => {
             focus_main_window(app);
             if let Some(window) = app.get_webview_window("main") {
@@ -91,7 +100,7 @@ fn toggle_recording_handler<R: Runtime>(app: &AppHandle<R>) {
 
                     // Trigger frontend post-processing via event (works from any page)
                     // (SQLite save, navigation, analytics)
-                    if let Err(e) = app_clone.emit("recording-stop-complete", true) {
+                    if let Err(e) = events::emit(&app_clone, &RecordingStopComplete(true)) {
                         log::error!("Tray toggle: Failed to emit recording-stop-complete event: {}", e);
                     }
                 }
@@ -187,7 +196,7 @@ fn stop_recording_handler<R: Runtime>(app: &AppHandle<R>) {
 
                 // Trigger frontend post-processing via event (works from any page)
                 // (SQLite save, navigation, analytics)
-                if let Err(e) = app_clone.emit("recording-stop-complete", true) {
+                if let Err(e) = events::emit(&app_clone, &RecordingStopComplete(true)) {
                     log::error!("Tray: Failed to emit recording-stop-complete event: {}", e);
                 }
             }
@@ -200,6 +209,37 @@ fn stop_recording_handler<R: Runtime>(app: &AppHandle<R>) {
     });
 }
 
+fn open_last_meeting_handler<R: Runtime>(app: &AppHandle<R>) {
+    focus_main_window(app);
+    let app_clone = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let Some(state) = app_clone.try_state::<crate::state::AppState>() else {
+            log::warn!("Tray: App state not ready, cannot open last meeting");
+            return;
+        };
+        let meeting_id = match crate::database::repositories::meeting::MeetingsRepository::get_latest_meeting_id(
+            state.db_manager.pool(),
+        )
+        .await
+        {
+            Ok(Some(id)) => id,
+            Ok(None) => {
+                log::info!("Tray: No meetings yet");
+                return;
+            }
+            Err(e) => {
+                log::error!("Tray: Failed to load last meeting: {}", e);
+                return;
+            }
+        };
+        if let Some(window) = app_clone.get_webview_window("main") {
+            let url = serde_json::to_string(&format!("/meeting-details?id={}", meeting_id))
+                .unwrap_or_default();
+            let _ = window.eval(&format!("window.location.assign({})", url));
+        }
+    });
+}
+
 fn check_updates_handler<R: Runtime>(app: &AppHandle<R>) {
     focus_main_window(app);
     if let Some(window) = app.get_webview_window("main") {
@@ -219,6 +259,47 @@ pub fn update_tray_menu<R: Runtime>(app: &AppHandle<R>) {
     });
 }
 
+/// Shows live recording info (e.g. elapsed time) on hover
+pub fn set_tray_tooltip<R: Runtime>(app: &AppHandle<R>, tooltip: &str) {
+    if let Some(tray) = app.tray_by_id("main-tray") {
+        if let Err(e) = tray.set_tooltip(Some(tooltip)) {
+            log::warn!("Tray: Failed to set tooltip: {}", e);
+        }
+    }
+}
+
+/// Updates the recording duration in the tray tooltip and menu
+pub fn show_recording_duration<R: Runtime>(app: &AppHandle<R>, elapsed_seconds: f64, is_paused: bool) {
+    let label = duration_label(elapsed_seconds, is_paused);
+    set_tray_tooltip(app, &format!("Meetily - {}", label));
+
+    let Some(slot) = app.try_state::<TrayDurationItem<R>>() else {
+        return;
+    };
+    let item = slot.0.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(item) = item.as_ref() {
+        if let Err(e) = item.set_text(&label) {
+            log::warn!("Tray: Failed to update duration: {}", e);
+        }
+    }
+}
+
+fn duration_label(elapsed_seconds: f64, is_paused: bool) -> String {
+    let elapsed = crate::audio::recording_status::format_elapsed(elapsed_seconds);
+    if is_paused {
+        format!("⏸ Paused at {}", elapsed)
+    } else {
+        format!("🔴 Recording {}", elapsed)
+    }
+}
+
+fn current_duration_label(is_paused: bool) -> String {
+    let elapsed = crate::audio::recording_commands::current_recording_status()
+        .map(|s| s.elapsed_seconds)
+        .unwrap_or(0.0);
+    duration_label(elapsed, is_paused)
+}
+
 pub fn set_tray_state<R: Runtime>(app: &AppHandle<R>, state: RecordingState) {
     log::info!("Tray: Setting intermediate state: {:?}", state);
     // During recording state transitions, we assume recording is allowed (we're already recording)
@@ -319,6 +400,7 @@ fn build_menu<R: Runtime>(
     can_record: bool, // True if recording is allowed (onboarding complete OR transcription model ready)
 ) -> tauri::Result<tauri::menu::Menu<R>> {
     let mut builder = MenuBuilder::new(app);
+    let mut duration_item = None;
 
     // If recording is not allowed (during onboarding, no transcription model), show disabled message
     if !can_record {
@@ -341,9 +423,14 @@ fn build_menu<R: Runtime>(
                 );
             }
             RecordingState::Recording => {
+                let item = MenuItemBuilder::with_id("recording_duration", current_duration_label(false))
+                    .enabled(false)
+                    .build(app)?;
                 builder = builder
+                    .item(&item)
                     .item(&MenuItemBuilder::with_id("pause_recording", "⏸ Pause Recording").build(app)?)
                     .item(&MenuItemBuilder::with_id("stop_recording", "⏹ Stop Recording").build(app)?);
+                duration_item = Some(item);
             }
             RecordingState::Pausing => {
                 builder = builder
@@ -355,12 +442,17 @@ fn build_menu<R: Runtime>(
                     .item(&MenuItemBuilder::with_id("stop_recording", "⏹ Stop Recording").build(app)?);
             }
             RecordingState::Paused => {
+                let item = MenuItemBuilder::with_id("recording_duration", current_duration_label(true))
+                    .enabled(false)
+                    .build(app)?;
                 builder = builder
+                    .item(&item)
                     .item(
                         &MenuItemBuilder::with_id("resume_recording", "▶ Resume Recording")
                             .build(app)?,
                     )
                     .item(&MenuItemBuilder::with_id("stop_recording", "⏹ Stop Recording").build(app)?);
+                duration_item = Some(item);
             }
             RecordingState::Resuming => {
                 builder = builder
@@ -381,9 +473,15 @@ fn build_menu<R: Runtime>(
         }
     }
 
+    // Only the Recording/Paused menus show a live duration
+    if let Some(slot) = app.try_state::<TrayDurationItem<R>>() {
+        *slot.0.lock().unwrap_or_else(|e| e.into_inner()) = duration_item;
+    }
+
     builder
         .item(&PredefinedMenuItem::separator(app)?)
         .item(&MenuItemBuilder::with_id("open_window", "Open Main Window").build(app)?)
+        .item(&MenuItemBuilder::with_id("open_last_meeting", "Open Last Meeting").build(app)?)
         .item(&MenuItemBuilder::with_id("settings", "Settings").build(app)?)
         .item(&MenuItemBuilder::with_id("check_updates", "Check for Updates").build(app)?)
         .item(&PredefinedMenuItem::separator(app)?)
@@ -391,7 +489,7 @@ fn build_menu<R: Runtime>(
         .build()
 }
 
-fn focus_main_window<R: Runtime>(app: &AppHandle<R>) {
+pub(crate) fn focus_main_window<R: Runtime>(app: &AppHandle<R>) {
     if let Some(window) = app.get_webview_window("main") {
         let _ = window.unminimize();
         let _ = window.show();
@@ -0,0 +1,242 @@
+//! Checks on command inputs before they reach SQL or the filesystem.
+//!
+//! Each check returns the cleaned value, or [`AppError::InvalidInput`] with
+//! a message naming the field, so malformed input from the frontend fails
+//! the same way in every command. Commands still returning `String` use
+//! them with a plain `?` as well, through `From<AppError> for String`.
+
+use std::path::{Component, Path, PathBuf};
+
+use crate::error::AppError;
+
+/// Longest meeting or document title accepted, in characters
+pub const MAX_TITLE_CHARS: usize = 500;
+/// Longest id accepted; generated ids ("meeting-<uuid>") are 44 characters
+const MAX_ID_CHARS: usize = 128;
+
+fn invalid(field: &str, problem: &str) -> AppError {
+    AppError::InvalidInput(format!("{} {}", field, problem))
+}
+
+/// A title: trimmed, not empty, no control characters and not overly long
+pub fn title(field: &str, value: &str) -> Result<String, AppError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(invalid(field, "is required"));
+    }
+    if value.chars().count() > MAX_TITLE_CHARS {
+        return Err(invalid(
+            field,
+            &format!("is longer than {} characters", MAX_TITLE_CHARS),
+        ));
+    }
+    if value.chars().any(char::is_control) {
+        return Err(invalid(field, "contains control characters"));
+    }
+    Ok(value.to_string())
+}
+
+/// An id of a stored row: letters, digits, '-', '_' and '.'
+pub fn id<'a>(field: &str, value: &'a str) -> Result<&'a str, AppError> {
+    if value.is_empty() {
+        return Err(invalid(field, "is required"));
+    }
+    if value.len() > MAX_ID_CHARS
+        || !value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        return Err(invalid(field, "is not a valid id"));
+    }
+    Ok(value)
+}
+
+/// A plugin id, with the characters manifests allow: lowercase letters,
+/// digits, '-' and '_'
+pub fn plugin_id(value: &str) -> Result<&str, AppError> {
+    if value.is_empty() {
+        return Err(invalid("plugin_id", "is required"));
+    }
+    if value.len() > MAX_ID_CHARS
+        || !value
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_'))
+    {
+        return Err(invalid("plugin_id", "is not a valid plugin id"));
+    }
+    Ok(value)
+}
+
+pub fn meeting_id(value: &str) -> Result<&str, AppError> {
+    id("meeting_id", value)
+}
+
+pub fn item_id(value: &str) -> Result<&str, AppError> {
+    id("item_id", value)
+}
+
+pub fn participant_id(value: &str) -> Result<&str, AppError> {
+    id("participant_id", value)
+}
+
+pub fn comment_id(value: &str) -> Result<&str, AppError> {
+    id("comment_id", value)
+}
+
+pub fn attachment_id(value: &str) -> Result<&str, AppError> {
+    id("attachment_id", value)
+}
+
+pub fn field_id(value: &str) -> Result<&str, AppError> {
+    id("field_id", value)
+}
+
+pub fn search_id(value: &str) -> Result<&str, AppError> {
+    id("search_id", value)
+}
+
+pub fn rule_id(value: &str) -> Result<&str, AppError> {
+    id("rule_id", value)
+}
+
+pub fn hook_id(value: &str) -> Result<&str, AppError> {
+    id("hook_id", value)
+}
+
+/// An absolute local path without ".." components, so input can't climb
+/// out of the folder it names
+pub fn local_path(field: &str, value: &str) -> Result<PathBuf, AppError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(invalid(field, "is required"));
+    }
+    if value.contains('\0') {
+        return Err(invalid(field, "contains a NUL character"));
+    }
+    let path = Path::new(value);
+    if !path.is_absolute() {
+        return Err(invalid(field, "must be an absolute path"));
+    }
+    if path.components().any(|c| c == Component::ParentDir) {
+        return Err(invalid(field, "must not contain '..'"));
+    }
+    Ok(path.to_path_buf())
+}
+
+/// A [`local_path`] naming a file that exists
+pub fn existing_file(field: &str, value: &str) -> Result<PathBuf, AppError> {
+    let path = local_path(field, value)?;
+    if !path.is_file() {
+        return Err(AppError::NotFound(format!(
+            "File not found: {}",
+            path.display()
+        )));
+    }
+    Ok(path)
+}
+
+/// A [`local_path`] to write a file to, in a folder that exists and can be
+/// written to
+pub fn output_file(field: &str, value: &str) -> Result<PathBuf, AppError> {
+    let path = local_path(field, value)?;
+    if path.is_dir() {
+        return Err(invalid(field, "is a folder, not a file"));
+    }
+    let parent = path
+        .parent()
+        .ok_or_else(|| invalid(field, "has no parent folder"))?;
+    match std::fs::metadata(parent) {
+        Ok(metadata) if metadata.is_dir() => {
+            if metadata.permissions().readonly() {
+                return Err(invalid(field, "is in a read-only folder"));
+            }
+            Ok(path)
+        }
+        _ => Err(AppError::NotFound(format!(
+            "Folder not found: {}",
+            parent.display()
+        ))),
+    }
+}
+
+/// An http(s) URL with a host, trimmed
+pub fn http_url(field: &str, value: &str) -> Result<String, AppError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(invalid(field, "is required"));
+    }
+    let url = url::Url::parse(value)
+        .map_err(|e| invalid(field, &format!("is not a valid URL: {}", e)))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(invalid(field, "must start with http:// or https://"));
+    }
+    if url.host_str().unwrap_or("").is_empty() {
+        return Err(invalid(field, "has no host"));
+    }
+    Ok(value.to_string())
+}
+
+/// Page bounds: neither may be negative
+pub fn page(limit: i64, offset: i64) -> Result<(i64, i64), AppError> {
+    if limit < 0 || offset < 0 {
+        return Err(AppError::InvalidInput(
+            "limit and offset must not be negative".to_string(),
+        ));
+    }
+    Ok((limit, offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn titles_and_ids_are_checked() {
+        assert_eq!(title("title", "  Weekly sync ").unwrap(), "Weekly sync");
+        assert!(title("title", "   ").is_err());
+        assert!(title("title", "a\u{0}b").is_err());
+        assert!(title("title", &"x".repeat(MAX_TITLE_CHARS + 1)).is_err());
+
+        assert!(meeting_id("meeting-3f2a9c1e-0b7d-4e7a-9c55-1d2e3f4a5b6c").is_ok());
+        assert!(meeting_id("").is_err());
+        assert!(meeting_id("1' OR '1'='1").is_err());
+        assert!(meeting_id("../meetings").is_err());
+
+        assert!(plugin_id("filler-words").is_ok());
+        assert!(plugin_id("Filler Words").is_err());
+        assert!(plugin_id("../plugins").is_err());
+    }
+
+    #[test]
+    fn output_files_need_an_existing_folder() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("export.md");
+        assert_eq!(output_file("path", &file.to_string_lossy()).unwrap(), file);
+        assert!(output_file("path", &dir.path().to_string_lossy()).is_err());
+        let missing = dir.path().join("missing").join("export.md");
+        let error = output_file("path", &missing.to_string_lossy()).unwrap_err();
+        assert_eq!(error.code(), "NOT_FOUND");
+        assert!(output_file("path", "export.md").is_err());
+    }
+
+    #[test]
+    fn paths_and_urls_are_checked() {
+        let root = if cfg!(windows) {
+            "C:\\recordings"
+        } else {
+            "/recordings"
+        };
+        assert!(local_path("folder_path", root).is_ok());
+        assert!(local_path("folder_path", "recordings/meeting").is_err());
+        assert!(local_path("folder_path", &format!("{}/../etc", root)).is_err());
+
+        assert_eq!(
+            http_url("endpoint", " https://api.example.com/v1 ").unwrap(),
+            "https://api.example.com/v1"
+        );
+        assert!(http_url("endpoint", "file:///etc/passwd").is_err());
+        assert!(http_url("endpoint", "not a url").is_err());
+        let error = http_url("endpoint", "ftp://example.com").unwrap_err();
+        assert_eq!(error.code(), "INVALID_INPUT");
+    }
+}
@@ -341,6 +341,7 @@ pub async fn whisper_transcribe_audio(audio_data: Vec<f32>) -> Result<String, St
         engine
             .transcribe_audio(audio_data, language)
             .await
+            .map(|(text, _uncertain)| text)
             .map_err(|e| format!("Transcription failed: {}", e))
     } else {
         Err("Whisper engine not initialized".to_string())
@@ -25,6 +25,9 @@ pub struct TranscriptionResult {
     pub model_used: String,
     pub start_time_ms: f64,
     pub confidence_score: Option<f32>,
+    /// Part of the text was flagged as a possible hallucination
+    #[serde(default)]
+    pub uncertain: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -347,7 +350,7 @@ impl ParallelProcessor {
         let transcription_future = engine.transcribe_audio(chunk.data.clone(), language);
         let timeout_duration = tokio::time::Duration::from_secs(120); // 2 minute timeout per chunk
 
-        let text = tokio::time::timeout(timeout_duration, transcription_future)
+        let (text, uncertain) = tokio::time::timeout(timeout_duration, transcription_future)
             .await
             .map_err(|_| anyhow!("Transcription timeout for chunk {}", chunk.id))?
             .map_err(|e| anyhow!("Transcription failed for chunk {}: {}", chunk.id, e))?;
@@ -361,6 +364,7 @@ impl ParallelProcessor {
             model_used: model_name.to_string(),
             start_time_ms: chunk.start_time_ms,
             confidence_score: None, // TODO: Add confidence scoring if available
+            uncertain,
         };
 
         debug!("Worker {} completed chunk {} in {}ms",
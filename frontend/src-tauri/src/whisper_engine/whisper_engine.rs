@@ -4,7 +4,8 @@ use std::path::{PathBuf};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use whisper_rs::{WhisperContext, WhisperContextParameters, FullParams, SamplingStrategy};
+use whisper_rs::{WhisperContext, WhisperContextParameters, WhisperState, FullParams, SamplingStrategy};
+use crate::audio::transcription::hallucination::{self, SegmentSignals, Verdict};
 use serde::{Serialize, Deserialize};
 use anyhow::{Result, anyhow};
 use reqwest::Client;
@@ -280,6 +281,7 @@ impl WhisperEngine {
     }
     
     pub async fn load_model(&self, model_name: &str) -> Result<()> {
+        crate::audio::transcription::idle::mark_used();
         let models = self.available_models.read().await;
         let model_info = models.get(model_name)
             .ok_or_else(|| anyhow!("Model {} not found", model_name))?;
@@ -421,6 +423,63 @@ impl WhisperEngine {
         final_text
     }
 
+    // Run a decoded segment through the hallucination filter. Returns the text
+    // to keep and whether it was flagged; None drops the segment.
+    fn filter_segment(ctx: &WhisperContext, state: &WhisperState, segment: i32, text: &str, audio: &[f32]) -> Option<(String, bool)> {
+        let signals = SegmentSignals {
+            token_probability: Self::segment_token_probability(ctx, state, segment),
+            no_speech_probability: state.full_get_segment_no_speech_prob(segment).ok(),
+            audio_rms: Some(Self::rms(Self::segment_audio(state, segment, audio))),
+        };
+        match hallucination::check(text, &signals) {
+            Verdict::Keep(text) => Some((text, false)),
+            Verdict::Flag(text, reason) => {
+                log::warn!("Flagged Whisper segment ({}): '{}'", reason, text);
+                Some((text, true))
+            }
+            Verdict::Drop(reason) => {
+                log::info!("Dropped hallucinated Whisper segment ({}): '{}'", reason, text.trim());
+                None
+            }
+        }
+    }
+
+    // Mean probability of a segment's text tokens (special tokens skipped)
+    fn segment_token_probability(ctx: &WhisperContext, state: &WhisperState, segment: i32) -> Option<f32> {
+        let eot = ctx.token_eot();
+        let tokens = state.full_n_tokens(segment).ok()?;
+        let probabilities: Vec<f32> = (0..tokens)
+            .filter(|&token| state.full_get_token_id(segment, token).is_ok_and(|id| id < eot))
+            .filter_map(|token| state.full_get_token_prob(segment, token).ok())
+            .collect();
+        if probabilities.is_empty() {
+            return None;
+        }
+        Some(probabilities.iter().sum::<f32>() / probabilities.len() as f32)
+    }
+
+    // The samples a segment was decoded from, by its t0/t1 (in 10ms units);
+    // the whole window when whisper reports no usable range
+    fn segment_audio<'a>(state: &WhisperState, segment: i32, audio: &'a [f32]) -> &'a [f32] {
+        const SAMPLES_PER_TICK: i64 = 16000 / 100;
+        let (Ok(t0), Ok(t1)) = (state.full_get_segment_t0(segment), state.full_get_segment_t1(segment)) else {
+            return audio;
+        };
+        let start = (t0.max(0) * SAMPLES_PER_TICK) as usize;
+        let end = ((t1.max(0) * SAMPLES_PER_TICK) as usize).min(audio.len());
+        if start >= end {
+            return audio;
+        }
+        &audio[start..end]
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        if samples.is_empty() {
+            return 0.0;
+        }
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
     // Check for obviously meaningless patterns
     fn is_meaningless_output(text: &str) -> bool {
         let text_lower = text.to_lowercase();
@@ -535,8 +594,11 @@ impl WhisperEngine {
         repeated_words as f32 / total_words
     }
     
-    /// Transcribe audio with streaming support for partial results and adaptive quality
-    pub async fn transcribe_audio_with_confidence(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<(String, f32, bool)> {
+    /// Transcribe audio with streaming support for partial results and adaptive quality.
+    /// Returns the text, its confidence, whether it is partial and whether the
+    /// hallucination filter flagged part of it as uncertain.
+    pub async fn transcribe_audio_with_confidence(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<(String, f32, bool, bool)> {
+        crate::audio::transcription::idle::mark_used();
         let ctx_lock = self.current_context.read().await;
         let ctx = ctx_lock.as_ref()
             .ok_or_else(|| anyhow!("No model loaded. Please load a model first."))?;
@@ -612,6 +674,7 @@ impl WhisperEngine {
             // Suppressor dropped here, stderr restored
         };
         let mut result = String::new();
+        let mut uncertain = false;
         let mut total_confidence = 0.0;
         let mut segment_count = 0;
 
@@ -621,6 +684,10 @@ impl WhisperEngine {
                 Ok(text) => text,
                 Err(_) => continue,
             };
+            let Some((segment_text, flagged)) = Self::filter_segment(ctx, &state, i, &segment_text, &audio_data) else {
+                continue;
+            };
+            uncertain |= flagged;
 
             // Calculate confidence based on segment length and duration (simplified approach)
             let segment_length = segment_text.len() as f32;
@@ -629,6 +696,12 @@ impl WhisperEngine {
             } else {
                 0.1
             };
+            // Flagged segments are kept at the bottom of the accepted range
+            let segment_confidence = if flagged {
+                segment_confidence.min(hallucination::FLAGGED_CONFIDENCE)
+            } else {
+                segment_confidence
+            };
             total_confidence += segment_confidence;
             segment_count += 1;
 
@@ -650,10 +723,31 @@ impl WhisperEngine {
             0.0
         };
 
-        Ok((cleaned_result, avg_confidence, is_partial))
+        Ok((cleaned_result, avg_confidence, is_partial, uncertain))
+    }
+
+    /// Detects the spoken language of 16kHz mono audio (whisper looks at the
+    /// first 30 seconds). Returns the language code, e.g. "en".
+    pub async fn detect_language(&self, audio_data: &[f32]) -> Result<String> {
+        let ctx_lock = self.current_context.read().await;
+        let ctx = ctx_lock.as_ref()
+            .ok_or_else(|| anyhow!("No model loaded. Please load a model first."))?;
+        let threads = std::thread::available_parallelism()
+            .map(|n| n.get().min(8))
+            .unwrap_or(4);
+
+        let mut state = ctx.create_state()?;
+        state.pcm_to_mel(audio_data, threads)?;
+        let (language_id, _probabilities) = state.lang_detect(0, threads)?;
+        whisper_rs::get_lang_str(language_id)
+            .map(|code| code.to_string())
+            .ok_or_else(|| anyhow!("Unknown language id {}", language_id))
     }
 
-    pub async fn transcribe_audio(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<String> {
+    /// Transcribes a whole window. Returns the text and whether the
+    /// hallucination filter flagged part of it as uncertain.
+    pub async fn transcribe_audio(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<(String, bool)> {
+        crate::audio::transcription::idle::mark_used();
         let ctx_lock = self.current_context.read().await;
         let ctx = ctx_lock.as_ref()
             .ok_or_else(|| anyhow!("No model loaded. Please load a model first."))?;
@@ -771,12 +865,17 @@ impl WhisperEngine {
             perf_debug!("Transcription #{} completed with {} segments ({:.1}s)", transcription_count, num_segments, duration_seconds);
         }
         let mut result = String::new();
+        let mut uncertain = false;
 
         for i in 0..num_segments {
             let segment_text = match state.full_get_segment_text_lossy(i) {
                 Ok(text) => text,
                 Err(_) => continue,
             };
+            let Some((segment_text, flagged)) = Self::filter_segment(ctx, &state, i, &segment_text, &audio_data) else {
+                continue;
+            };
+            uncertain |= flagged;
 
             let _start_time = state.full_get_segment_t0(i).unwrap_or(0);
             let _end_time = state.full_get_segment_t1(i).unwrap_or(0);
@@ -823,7 +922,7 @@ impl WhisperEngine {
             }
         }
 
-        Ok(cleaned_result)
+        Ok((cleaned_result, uncertain))
     }
     
     pub async fn get_models_directory(&self) -> PathBuf {
@@ -0,0 +1,138 @@
+//! Turning encryption on and off against a real database. Kept out of the
+//! unit tests: the key is process-wide, and other tests expect it off.
+
+use app_lib::database::migrations::run_migrations;
+use app_lib::database::repositories::delta_report::DeltaReportsRepository;
+use app_lib::database::repositories::digest::DigestsRepository;
+use app_lib::database::repositories::partial_summary::PartialSummariesRepository;
+use app_lib::encryption::{self, cipher};
+use chrono::Utc;
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::SqlitePool;
+
+const PASSPHRASE: &str = "correct horse battery";
+
+async fn memory_pool() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await
+        .expect("open in-memory database");
+    run_migrations(&pool, None)
+        .await
+        .expect("migrate in-memory database");
+    pool
+}
+
+async fn stored(pool: &SqlitePool, sql: &str, id: &str) -> String {
+    sqlx::query_scalar(sql)
+        .bind(id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+async fn partial_summary_content(pool: &SqlitePool, meeting_id: &str) -> String {
+    PartialSummariesRepository::list_for_meeting(pool, meeting_id)
+        .await
+        .unwrap()
+        .remove(0)
+        .content
+}
+
+async fn delta_report(pool: &SqlitePool, meeting_id: &str) -> String {
+    DeltaReportsRepository::get_for_meeting(pool, meeting_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .report
+}
+
+#[tokio::test]
+async fn enabling_and_disabling_rewrites_every_encrypted_column() {
+    let pool = memory_pool().await;
+    encryption::load_state(&pool).await.unwrap();
+
+    let digest = DigestsRepository::save(
+        &pool,
+        "weekly",
+        Utc::now(),
+        Utc::now(),
+        "Week 12",
+        "## Decisions\n- Ship on Friday",
+        &["m1".to_string()],
+        "ollama",
+        "llama3.2",
+    )
+    .await
+    .unwrap();
+    let digest_sql = "SELECT content FROM digests WHERE id = ?";
+
+    let now = Utc::now();
+    sqlx::query("INSERT INTO meetings (id, title, created_at, updated_at) VALUES (?, ?, ?, ?)")
+        .bind("m1")
+        .bind("Planning")
+        .bind(now)
+        .bind(now)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let partial = PartialSummariesRepository::save(
+        &pool,
+        "m1",
+        "Budget",
+        60.0,
+        120.0,
+        "## Budget\n- Approved",
+        "ollama",
+        "llama3.2",
+    )
+    .await
+    .unwrap();
+    let partial_sql = "SELECT content FROM partial_summaries WHERE id = ?";
+    let delta = DeltaReportsRepository::save(
+        &pool,
+        "m1",
+        "m0",
+        "## New decisions\n- Ship on Friday",
+        "{}",
+        "ollama",
+        "llama3.2",
+    )
+    .await
+    .unwrap();
+    let delta_sql = "SELECT report FROM meeting_delta_reports WHERE meeting_id = ?";
+
+    encryption::enable(&pool, PASSPHRASE).await.unwrap();
+    assert!(cipher::is_encrypted(
+        &stored(&pool, digest_sql, &digest.id).await
+    ));
+    assert!(cipher::is_encrypted(
+        &stored(&pool, partial_sql, &partial.id).await
+    ));
+    assert!(cipher::is_encrypted(&stored(&pool, delta_sql, "m1").await));
+    let read = DigestsRepository::get(&pool, &digest.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(read.content, digest.content);
+    assert_eq!(partial_summary_content(&pool, "m1").await, partial.content);
+    assert_eq!(delta_report(&pool, "m1").await, delta.report);
+
+    encryption::disable(&pool, PASSPHRASE).await.unwrap();
+    assert_eq!(stored(&pool, digest_sql, &digest.id).await, digest.content);
+    assert_eq!(
+        stored(&pool, partial_sql, &partial.id).await,
+        partial.content
+    );
+    assert_eq!(stored(&pool, delta_sql, "m1").await, delta.report);
+    let read = DigestsRepository::get(&pool, &digest.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(read.content, digest.content);
+    assert_eq!(partial_summary_content(&pool, "m1").await, partial.content);
+    assert_eq!(delta_report(&pool, "m1").await, delta.report);
+}
@@ -9,6 +9,7 @@ import AnalyticsProvider from '@/components/AnalyticsProvider'
 import { Toaster, toast } from 'sonner'
 import "sonner/dist/styles.css"
 import { useState, useEffect } from 'react'
+import { usePathname } from 'next/navigation'
 import { listen } from '@tauri-apps/api/event'
 import { invoke } from '@tauri-apps/api/core'
 import { TooltipProvider } from '@/components/ui/tooltip'
@@ -21,6 +22,7 @@ import { OnboardingFlow } from '@/components/onboarding'
 import { DownloadProgressToastProvider } from '@/components/shared/DownloadProgressToast'
 import { UpdateCheckProvider } from '@/components/UpdateCheckProvider'
 import { RecordingPostProcessingProvider } from '@/contexts/RecordingPostProcessingProvider'
+import { useEventReplay } from '@/hooks/useEventReplay'
 
 const sourceSans3 = Source_Sans_3({
   subsets: ['latin'],
@@ -89,6 +91,20 @@ export default function RootLayout({
     };
   }, [showOnboarding]);
 
+  // The recorder overlay is a separate window; it must not run the main
+  // window's providers (they save recordings and show onboarding)
+  const pathname = usePathname()
+  useEventReplay(pathname !== '/overlay')
+  if (pathname === '/overlay') {
+    return (
+      <html lang="ja">
+        <body className={`${sourceSans3.variable} font-sans antialiased`}>
+          {children}
+        </body>
+      </html>
+    )
+  }
+
   const handleOnboardingComplete = () => {
     console.log('[Layout] Onboarding completed, reloading app')
     setShowOnboarding(false)
@@ -15,6 +15,7 @@ interface MeetingDetailsResponse {
   title: string;
   created_at: string;
   updated_at: string;
+  version?: number;
   transcripts: Transcript[];
 }
 
@@ -43,6 +44,7 @@ function MeetingDetailsContent() {
     totalCount,
     loadedCount,
     loadMore,
+    refetchMetadata,
     error: transcriptError,
   } = usePaginatedTranscripts({ meetingId: meetingId || '' });
 
@@ -130,6 +132,7 @@ function MeetingDetailsContent() {
         title: metadata.title,
         created_at: metadata.created_at,
         updated_at: metadata.updated_at,
+        version: metadata.version,
         transcripts: transcripts, // Paginated transcripts from hook
       });
 
@@ -146,16 +149,15 @@ function MeetingDetailsContent() {
     }
   }, [transcriptError]);
 
-  // Extract fetchMeetingDetails for use in child components (now refetches via hook)
+  // Refetch metadata after the backend changed the meeting (e.g. a generated
+  // title), so the title and version used for the next save are current
   const fetchMeetingDetails = useCallback(async () => {
     if (!meetingId || meetingId === 'intro-call') {
       return;
     }
 
-    // The usePaginatedTranscripts hook automatically refetches when meetingId changes
-    // This function is kept for compatibility with onMeetingUpdated callback
-    console.log('fetchMeetingDetails called - pagination hook will handle refetch');
-  }, [meetingId]);
+    await refetchMetadata();
+  }, [meetingId, refetchMetadata]);
 
   // Reset states when meetingId changes (prevent race conditions)
   useEffect(() => {
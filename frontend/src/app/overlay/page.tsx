@@ -0,0 +1,111 @@
+'use client';
+
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { Pause, Play, X } from 'lucide-react';
+
+interface RecordingStatus {
+  elapsed_seconds: number;
+  /** Recorded time; the overlay shows this so pauses don't count */
+  active_seconds: number;
+  is_paused: boolean;
+}
+
+interface OverlaySnapshot {
+  is_recording: boolean;
+  status: RecordingStatus | null;
+}
+
+function formatElapsed(seconds: number): string {
+  const total = Math.max(0, Math.floor(seconds));
+  const hours = Math.floor(total / 3600);
+  const minutes = Math.floor((total % 3600) / 60);
+  const secs = String(total % 60).padStart(2, '0');
+  return hours > 0
+    ? `${hours}:${String(minutes).padStart(2, '0')}:${secs}`
+    : `${minutes}:${secs}`;
+}
+
+/** Compact always-on-top recorder, opened with `api_open_overlay` */
+export default function OverlayPage() {
+  const [isRecording, setIsRecording] = useState(false);
+  const [isPaused, setIsPaused] = useState(false);
+  const [elapsed, setElapsed] = useState(0);
+
+  useEffect(() => {
+    invoke<OverlaySnapshot>('api_get_overlay_snapshot')
+      .then((snapshot) => {
+        setIsRecording(snapshot.is_recording);
+        if (snapshot.status) {
+          setElapsed(snapshot.status.active_seconds);
+          setIsPaused(snapshot.status.is_paused);
+        }
+      })
+      .catch((error) => console.error('[Overlay] Failed to read recording state:', error));
+
+    const unlisteners = [
+      listen<RecordingStatus>('recording-status', ({ payload }) => {
+        setIsRecording(true);
+        setElapsed(payload.active_seconds);
+        setIsPaused(payload.is_paused);
+      }),
+      listen('recording-started', () => {
+        setIsRecording(true);
+        setIsPaused(false);
+        setElapsed(0);
+      }),
+      listen('recording-paused', () => setIsPaused(true)),
+      listen('recording-resumed', () => setIsPaused(false)),
+      listen('recording-stopped', () => setIsRecording(false)),
+    ];
+    return () => {
+      unlisteners.forEach((unlisten) => unlisten.then((fn) => fn()));
+    };
+  }, []);
+
+  // Status events arrive every few seconds; count in between
+  useEffect(() => {
+    if (!isRecording || isPaused) return;
+    const timer = setInterval(() => setElapsed((value) => value + 1), 1000);
+    return () => clearInterval(timer);
+  }, [isRecording, isPaused]);
+
+  const togglePause = () => {
+    invoke(isPaused ? 'resume_recording' : 'pause_recording').catch((error) =>
+      console.error('[Overlay] Failed to toggle pause:', error)
+    );
+  };
+
+  return (
+    <div
+      data-tauri-drag-region
+      className="flex h-screen select-none items-center gap-3 bg-gray-900 px-4 text-white"
+    >
+      <span
+        className={`h-3 w-3 rounded-full ${
+          isRecording ? (isPaused ? 'bg-yellow-400' : 'animate-pulse bg-red-500') : 'bg-gray-500'
+        }`}
+      />
+      <span data-tauri-drag-region className="flex-1 font-mono text-lg">
+        {isRecording ? formatElapsed(elapsed) : '録音していません'}
+      </span>
+      {isRecording && (
+        <button
+          onClick={togglePause}
+          className="rounded-full p-2 hover:bg-white/10"
+          title={isPaused ? '再開' : '一時停止'}
+        >
+          {isPaused ? <Play size={16} /> : <Pause size={16} />}
+        </button>
+      )}
+      <button
+        onClick={() => invoke('api_close_overlay')}
+        className="rounded-full p-2 hover:bg-white/10"
+        title="閉じる"
+      >
+        <X size={16} />
+      </button>
+    </div>
+  );
+}
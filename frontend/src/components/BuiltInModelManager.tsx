@@ -17,11 +17,15 @@ interface ModelInfo {
     progress?: number;
   };
   size_mb: number;
+  ram_required_mb: number;
+  vram_required_mb: number;
   context_size: number;
   description: string;
   gguf_file: string;
 }
 
+const formatGb = (mb: number) => `${(mb / 1024).toFixed(1)}GB`;
+
 interface DownloadProgressInfo {
   downloadedMb: number;
   totalMb: number;
@@ -352,7 +356,10 @@ export function BuiltInModelManager({ selectedModel, onModelSelect }: BuiltInMod
                       </p>
                     )}
                     <div className="text-xs text-gray-500">
-                      <span>{model.size_mb}MB • {model.context_size} tokens</span>
+                      <span>
+                        {model.size_mb}MB • {model.context_size} tokens • 必要メモリ {formatGb(model.ram_required_mb)}
+                        {' '}(GPU {formatGb(model.vram_required_mb)})
+                      </span>
                     </div>
                   </div>
                 </div>
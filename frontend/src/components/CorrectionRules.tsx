@@ -0,0 +1,77 @@
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { Trash2 } from 'lucide-react';
+import { toast } from 'sonner';
+import { Button } from './ui/button';
+import { Label } from './ui/label';
+import { errorMessage } from '@/lib/utils';
+
+export interface CorrectionRule {
+    id: string;
+    wrong: string;
+    corrected: string;
+    occurrences: number;
+    created_at: string;
+    updated_at: string;
+}
+
+// Matches corrections::LEARN_AFTER in the backend
+const LEARN_AFTER = 2;
+
+// Corrections learned from transcript edits. Rules made often enough are
+// applied to new transcriptions; deleting one stops that.
+export function CorrectionRules() {
+    const [rules, setRules] = useState<CorrectionRule[]>([]);
+
+    useEffect(() => {
+        invoke<CorrectionRule[]>('api_list_correction_rules')
+            .then(setRules)
+            .catch((err) => console.error('Failed to load correction rules:', err));
+    }, []);
+
+    const remove = async (rule: CorrectionRule) => {
+        try {
+            await invoke('api_delete_correction_rule', { ruleId: rule.id });
+            setRules(rules.filter((r) => r.id !== rule.id));
+            toast.success('学習した修正を削除しました');
+        } catch (err) {
+            toast.error('学習した修正を削除できませんでした', { description: errorMessage(err) });
+        }
+    };
+
+    return (
+        <div className="mt-6">
+            <Label className="block text-sm font-medium text-gray-700 mb-1">学習した修正</Label>
+            <p className="text-xs text-gray-500 mb-2">
+                文字起こしの編集から学習した置き換えです。同じ修正を{LEARN_AFTER}回以上行うと、新しい文字起こしに自動で適用されます。
+            </p>
+            {rules.length === 0 ? (
+                <p className="text-xs text-gray-400">まだ学習した修正はありません</p>
+            ) : (
+                <div className="space-y-1">
+                    {rules.map((rule) => (
+                        <div key={rule.id} className="flex items-center gap-2 text-sm">
+                            <span className="flex-1 truncate">
+                                <span className="text-gray-500 line-through">{rule.wrong}</span>
+                                {' → '}
+                                <span className="text-gray-800">{rule.corrected}</span>
+                            </span>
+                            <span className="text-xs text-gray-500 whitespace-nowrap">
+                                {rule.occurrences >= LEARN_AFTER ? '適用中' : `${rule.occurrences}回`}
+                            </span>
+                            <Button
+                                type="button"
+                                variant="ghost"
+                                size="icon"
+                                onClick={() => remove(rule)}
+                                title="削除"
+                            >
+                                <Trash2 className="h-4 w-4" />
+                            </Button>
+                        </div>
+                    ))}
+                </div>
+            )}
+        </div>
+    );
+}
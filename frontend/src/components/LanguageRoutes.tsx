@@ -0,0 +1,119 @@
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { Plus, Trash2 } from 'lucide-react';
+import { toast } from 'sonner';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from './ui/select';
+import { Input } from './ui/input';
+import { Button } from './ui/button';
+import { Label } from './ui/label';
+import { LANGUAGES } from './LanguageSelection';
+import { errorMessage } from '@/lib/utils';
+
+export interface LanguageRoute {
+    language: string;
+    provider: string;
+    model: string;
+}
+
+const ROUTE_PROVIDERS = [
+    { value: 'parakeet', label: 'Parakeet' },
+    { value: 'localWhisper', label: 'ローカル Whisper' },
+    { value: 'deepgram', label: 'Deepgram' },
+    { value: 'assemblyAi', label: 'AssemblyAI' },
+    { value: 'elevenLabs', label: 'ElevenLabs Scribe' },
+];
+
+const ROUTE_LANGUAGES = LANGUAGES.filter((language) => !language.code.startsWith('auto'));
+
+// Per-language provider/model overrides. Recordings use the route for the
+// selected language; imports use the route for the detected language.
+export function LanguageRoutes() {
+    const [routes, setRoutes] = useState<LanguageRoute[]>([]);
+    const [saving, setSaving] = useState(false);
+
+    useEffect(() => {
+        invoke<LanguageRoute[]>('api_get_transcript_language_routes')
+            .then(setRoutes)
+            .catch((err) => console.error('Failed to load language routes:', err));
+    }, []);
+
+    const update = (index: number, change: Partial<LanguageRoute>) => {
+        setRoutes(routes.map((route, i) => (i === index ? { ...route, ...change } : route)));
+    };
+
+    const save = async () => {
+        setSaving(true);
+        try {
+            const saved = await invoke<LanguageRoute[]>('api_save_transcript_language_routes', { routes });
+            setRoutes(saved);
+            toast.success('言語別ルーティングを保存しました');
+        } catch (err) {
+            toast.error('言語別ルーティングを保存できませんでした', { description: errorMessage(err) });
+        } finally {
+            setSaving(false);
+        }
+    };
+
+    return (
+        <div className="mt-6">
+            <Label className="block text-sm font-medium text-gray-700 mb-1">言語別のモデル</Label>
+            <p className="text-xs text-gray-500 mb-2">
+                会議の言語（選択した言語、またはインポート時に検出した言語）に応じて使うプロバイダーとモデルを切り替えます。
+            </p>
+            <div className="space-y-2">
+                {routes.map((route, index) => (
+                    <div key={index} className="flex items-center gap-2">
+                        <Select value={route.language} onValueChange={(language) => update(index, { language })}>
+                            <SelectTrigger className="w-36">
+                                <SelectValue placeholder="言語" />
+                            </SelectTrigger>
+                            <SelectContent>
+                                {ROUTE_LANGUAGES.map((language) => (
+                                    <SelectItem key={language.code} value={language.code}>{language.name}</SelectItem>
+                                ))}
+                            </SelectContent>
+                        </Select>
+                        <Select value={route.provider} onValueChange={(provider) => update(index, { provider })}>
+                            <SelectTrigger className="w-44">
+                                <SelectValue placeholder="プロバイダー" />
+                            </SelectTrigger>
+                            <SelectContent>
+                                {ROUTE_PROVIDERS.map((provider) => (
+                                    <SelectItem key={provider.value} value={provider.value}>{provider.label}</SelectItem>
+                                ))}
+                            </SelectContent>
+                        </Select>
+                        <Input
+                            className="flex-1"
+                            value={route.model}
+                            onChange={(e) => update(index, { model: e.target.value })}
+                            placeholder="モデル（例: large-v3）"
+                        />
+                        <Button
+                            type="button"
+                            variant="ghost"
+                            size="icon"
+                            onClick={() => setRoutes(routes.filter((_, i) => i !== index))}
+                            title="削除"
+                        >
+                            <Trash2 className="h-4 w-4" />
+                        </Button>
+                    </div>
+                ))}
+            </div>
+            <div className="flex gap-2 mt-2">
+                <Button
+                    type="button"
+                    variant="outline"
+                    size="sm"
+                    onClick={() => setRoutes([...routes, { language: 'ja', provider: 'localWhisper', model: '' }])}
+                >
+                    <Plus className="h-4 w-4 mr-1" /> 追加
+                </Button>
+                <Button type="button" size="sm" onClick={save} disabled={saving}>
+                    保存
+                </Button>
+            </div>
+        </div>
+    );
+}
@@ -10,7 +10,7 @@ export interface Language {
 }
 
 // ISO 639-1 language codes supported by Whisper
-const LANGUAGES: Language[] = [
+export const LANGUAGES: Language[] = [
   { code: 'auto', name: '自動検出（原語のまま）' },
   { code: 'auto-translate', name: '自動検出（英語に翻訳）' },
   { code: 'en', name: 'English' },
@@ -118,7 +118,7 @@ interface LanguageSelectionProps {
   selectedLanguage: string;
   onLanguageChange: (language: string) => void;
   disabled?: boolean;
-  provider?: 'localWhisper' | 'parakeet' | 'deepgram' | 'elevenLabs' | 'groq' | 'openai';
+  provider?: 'localWhisper' | 'parakeet' | 'deepgram' | 'assemblyAi' | 'elevenLabs' | 'groq' | 'openai';
 }
 
 export function LanguageSelection({
@@ -0,0 +1,72 @@
+"use client";
+
+import { useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { ThumbsDown, ThumbsUp } from 'lucide-react';
+import { toast } from 'sonner';
+import { Button } from '@/components/ui/button';
+import { ButtonGroup } from '@/components/ui/button-group';
+import { Popover, PopoverAnchor, PopoverContent } from '@/components/ui/popover';
+import { Textarea } from '@/components/ui/textarea';
+import { errorMessage } from '@/lib/utils';
+
+interface SummaryFeedbackButtonsProps {
+  meetingId: string;
+}
+
+// Thumbs up/down with an optional comment. Feedback steers later summaries
+// of the same meeting series.
+export function SummaryFeedbackButtons({ meetingId }: SummaryFeedbackButtonsProps) {
+  const [helpful, setHelpful] = useState<boolean | null>(null);
+  const [comment, setComment] = useState('');
+  const [sending, setSending] = useState(false);
+
+  const send = async () => {
+    if (helpful === null) return;
+    setSending(true);
+    try {
+      await invoke('api_add_summary_feedback', {
+        meetingId,
+        helpful,
+        comment: comment.trim() || null,
+      });
+      toast.success('フィードバックを送信しました', {
+        description: '次回以降の要約に反映されます',
+      });
+      setHelpful(null);
+      setComment('');
+    } catch (err) {
+      toast.error('フィードバックを送信できませんでした', { description: errorMessage(err) });
+    } finally {
+      setSending(false);
+    }
+  };
+
+  return (
+    <Popover open={helpful !== null} onOpenChange={(open) => !open && setHelpful(null)}>
+      <PopoverAnchor>
+        <ButtonGroup>
+          <Button variant="outline" size="sm" title="良い要約" onClick={() => setHelpful(true)}>
+            <ThumbsUp />
+          </Button>
+          <Button variant="outline" size="sm" title="改善が必要" onClick={() => setHelpful(false)}>
+            <ThumbsDown />
+          </Button>
+        </ButtonGroup>
+      </PopoverAnchor>
+      <PopoverContent className="w-80 space-y-2">
+        <Textarea
+          value={comment}
+          maxLength={1000}
+          onChange={(e) => setComment(e.target.value)}
+          placeholder={helpful ? '良かった点（任意）' : '改善してほしい点（任意）: 例「決定事項を先に」'}
+        />
+        <div className="flex justify-end">
+          <Button size="sm" onClick={send} disabled={sending}>
+            送信
+          </Button>
+        </div>
+      </PopoverContent>
+    </Popover>
+  );
+}
@@ -7,6 +7,7 @@ import { EmptyStateSummary } from '@/components/EmptyStateSummary';
 import { ModelConfig } from '@/components/ModelSettingsModal';
 import { SummaryGeneratorButtonGroup } from './SummaryGeneratorButtonGroup';
 import { SummaryUpdaterButtonGroup } from './SummaryUpdaterButtonGroup';
+import { SummaryFeedbackButtons } from './SummaryFeedbackButtons';
 import Analytics from '@/lib/analytics';
 import { RefObject } from 'react';
 
@@ -136,6 +137,11 @@ export function SummaryPanel({
                 hasSummary={!!aiSummary}
               />
             </div>
+
+            {/* Feedback that steers later summaries of the series */}
+            <div className="flex-shrink-0">
+              <SummaryFeedbackButtons meetingId={meeting.id} />
+            </div>
           </div>
         )}
       </div>
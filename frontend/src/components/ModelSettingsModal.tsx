@@ -27,7 +27,7 @@ import {
   CommandItem,
   CommandList,
 } from '@/components/ui/command';
-import { cn, isOllamaNotInstalledError } from '@/lib/utils';
+import { cn, errorMessage, isOllamaNotInstalledError } from '@/lib/utils';
 import { toast } from 'sonner';
 
 export interface ModelConfig {
@@ -682,8 +682,7 @@ export function ModelSettingsModal({
       });
       toast.success(result.message || '接続成功！');
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
-      toast.error(errorMsg);
+      toast.error(errorMessage(err));
     } finally {
       setIsTestingConnection(false);
     }
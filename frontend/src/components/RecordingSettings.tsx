@@ -12,6 +12,11 @@ export interface RecordingPreferences {
   file_format: string;
   preferred_mic_device: string | null;
   preferred_system_device: string | null;
+  prevent_sleep?: boolean;
+  do_not_disturb?: boolean;
+  folder_name_template?: string;
+  preload_model?: boolean;
+  model_idle_unload_minutes?: number;
 }
 
 interface RecordingSettingsProps {
@@ -29,6 +29,7 @@ import Info from '../Info';
 import { ComplianceNotification } from '../ComplianceNotification';
 import { Input } from '../ui/input';
 import { InputGroup, InputGroupAddon, InputGroupButton, InputGroupInput } from '../ui/input-group';
+import { errorMessage } from '@/lib/utils';
 
 interface SidebarItem {
   id: string;
@@ -345,7 +346,7 @@ const Sidebar: React.FC = () => {
     } catch (error) {
       console.error('Failed to delete meeting:', error);
       toast.error("会議の削除に失敗しました", {
-        description: error instanceof Error ? error.message : String(error)
+        description: errorMessage(error)
       });
     }
   };
@@ -407,7 +408,7 @@ const Sidebar: React.FC = () => {
     } catch (error) {
       console.error('Failed to update meeting title:', error);
       toast.error("会議タイトルの更新に失敗しました", {
-        description: error instanceof Error ? error.message : String(error)
+        description: errorMessage(error)
       });
     }
   };
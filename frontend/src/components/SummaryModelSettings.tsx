@@ -5,8 +5,12 @@ import { invoke } from '@tauri-apps/api/core';
 import { toast } from 'sonner';
 import { ModelConfig, ModelSettingsModal } from '@/components/ModelSettingsModal';
 import { Switch } from './ui/switch';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from './ui/select';
+import { LANGUAGES } from './LanguageSelection';
 import { useConfig } from '@/contexts/ConfigContext';
 
+const SUMMARY_LANGUAGES = LANGUAGES.filter((language) => !language.code.startsWith('auto'));
+
 interface SummaryModelSettingsProps {
   refetchTrigger?: number; // Change this to trigger refetch
 }
@@ -20,8 +24,31 @@ export function SummaryModelSettings({ refetchTrigger }: SummaryModelSettingsPro
     ollamaEndpoint: null
   });
 
+  const [summaryLanguage, setSummaryLanguage] = useState('auto');
+
   const { isAutoSummary, toggleIsAutoSummary } = useConfig();
 
+  useEffect(() => {
+    invoke<string | null>('api_get_summary_language')
+      .then((language) => setSummaryLanguage(language || 'auto'))
+      .catch((err) => console.error('Failed to load summary language:', err));
+  }, []);
+
+  const handleSummaryLanguageChange = async (language: string) => {
+    const previous = summaryLanguage;
+    setSummaryLanguage(language);
+    try {
+      await invoke('api_save_summary_language', {
+        language: language === 'auto' ? null : language,
+      });
+      toast.success('要約の言語を保存しました');
+    } catch (error) {
+      console.error('Error saving summary language:', error);
+      setSummaryLanguage(previous);
+      toast.error('要約の言語の保存に失敗しました');
+    }
+  };
+
   // Reusable fetch function
   const fetchModelConfig = useCallback(async () => {
     try {
@@ -133,6 +160,28 @@ export function SummaryModelSettings({ refetchTrigger }: SummaryModelSettingsPro
         </div>
       </div>
 
+      <div className="bg-white rounded-lg border border-gray-200 p-6 shadow-sm">
+        <div className="flex items-center justify-between gap-4">
+          <div>
+            <h3 className="text-lg font-semibold text-gray-900 mb-2">要約の言語</h3>
+            <p className="text-sm text-gray-600">文字起こしの言語に関係なく、指定した言語で要約を作成する</p>
+          </div>
+          <Select value={summaryLanguage} onValueChange={handleSummaryLanguageChange}>
+            <SelectTrigger className="w-48">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              <SelectItem value="auto">文字起こしと同じ</SelectItem>
+              {SUMMARY_LANGUAGES.map((language) => (
+                <SelectItem key={language.code} value={language.code}>
+                  {language.name}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        </div>
+      </div>
+
       <div className="bg-white rounded-lg border border-gray-200 p-6 shadow-sm">
         <h3 className="text-lg font-semibold mb-4">要約モデル設定</h3>
         <p className="text-sm text-gray-600 mb-6">
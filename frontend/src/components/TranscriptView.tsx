@@ -323,7 +323,10 @@ export const TranscriptView: React.FC<TranscriptViewProps> = ({ transcripts, isR
                     <p className="text-base text-gray-800 leading-relaxed" style={{ visibility: 'hidden' }}>
                       {sizerText}
                     </p>
-                    <p className="text-base text-gray-800 leading-relaxed absolute top-0 left-0">
+                    <p
+                      className={`text-base leading-relaxed absolute top-0 left-0 ${transcript.uncertain ? 'text-gray-400 italic' : 'text-gray-800'}`}
+                      title={transcript.uncertain ? '誤認識の可能性があります' : undefined}
+                    >
                       {displayText}
                     </p>
                   </div>
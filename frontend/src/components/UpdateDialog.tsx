@@ -12,6 +12,7 @@ import { Button } from './ui/button';
 import { updateService, UpdateInfo, UpdateProgress } from '@/services/updateService';
 import { check, Update } from '@tauri-apps/plugin-updater';
 import { relaunch } from '@tauri-apps/plugin-process';
+import { invoke } from '@tauri-apps/api/core';
 import { toast } from 'sonner';
 
 interface UpdateDialogProps {
@@ -86,7 +87,7 @@ export function UpdateDialog({ open, onOpenChange, updateInfo }: UpdateDialogPro
       let contentLength = 0;
 
       // Use the official Tauri updater API with progress callbacks
-      await updateToUse.downloadAndInstall((event) => {
+      await updateToUse.download((event) => {
         switch (event.event) {
           case 'Started':
             contentLength = event.data.contentLength || 0;
@@ -122,6 +123,18 @@ export function UpdateDialog({ open, onOpenChange, updateInfo }: UpdateDialogPro
         }
       });
 
+      // Finalize any recording and close the database before the installer
+      // replaces the app (on Windows it exits the process itself)
+      await invoke('api_prepare_for_shutdown', { reason: 'update' });
+      try {
+        await updateToUse.install();
+      } catch (err) {
+        // The database is closed by now; restarting opens it again
+        toast.error('アップデートをインストールできませんでした。アプリを再起動します');
+        await invoke('api_recover_from_failed_update');
+        throw err;
+      }
+
       console.log('[UpdateDialog] Update installed successfully');
       toast.success('アップデートのインストールが完了しました。アプリを再起動します...');
 
@@ -37,6 +37,10 @@ export interface NotificationSettings {
     show_meeting_reminders: boolean;
     show_system_errors: boolean;
     meeting_reminder_minutes: number[];
+    show_action_item_reminders?: boolean;
+    show_import_complete?: boolean;
+    show_retranscription_complete?: boolean;
+    show_summary_complete?: boolean;
   };
 }
 
@@ -311,6 +311,7 @@ export function TranscriptProvider({ children }: { children: ReactNode }) {
             chunk_start_time: update.chunk_start_time,
             is_partial: update.is_partial,
             confidence: update.confidence,
+            uncertain: update.uncertain,
             // NEW: Recording-relative timestamps for playback sync
             audio_start_time: update.audio_start_time,
             audio_end_time: update.audio_end_time,
@@ -380,6 +381,7 @@ export function TranscriptProvider({ children }: { children: ReactNode }) {
             chunk_start_time: segment.audio_start_time,
             is_partial: false, // History segments are always final
             confidence: segment.confidence,
+            uncertain: segment.uncertain,
             audio_start_time: segment.audio_start_time,
             audio_end_time: segment.audio_end_time,
             duration: segment.duration,
@@ -421,6 +423,7 @@ export function TranscriptProvider({ children }: { children: ReactNode }) {
       chunk_start_time: update.chunk_start_time,
       is_partial: update.is_partial,
       confidence: update.confidence,
+      uncertain: update.uncertain,
       audio_start_time: update.audio_start_time,
       audio_end_time: update.audio_end_time,
       duration: update.duration,
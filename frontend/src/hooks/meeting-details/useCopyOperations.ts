@@ -86,7 +86,7 @@ export function useCopyOperations({
     const header = `# 会議の文字起こし: ${meeting.id} - ${meetingTitle ?? meeting.title}\n\n`;
     const date = `## 日付: ${new Date(meeting.created_at).toLocaleDateString('ja-JP')}\n\n`;
     const fullTranscript = allTranscripts
-      .map(t => `${formatTime(t.audio_start_time, t.timestamp)} ${t.text}  `)
+      .map(t => `${formatTime(t.audio_start_time, t.timestamp)} ${t.speaker ? `${t.speaker}: ` : ''}${t.text}  `)
       .join('\n');
 
     await navigator.clipboard.writeText(header + date + fullTranscript);
@@ -4,6 +4,7 @@ import { BlockNoteSummaryViewRef } from '@/components/AISummary/BlockNoteSummary
 import { CurrentMeeting, useSidebar } from '@/components/Sidebar/SidebarProvider';
 import { invoke as invokeTauri } from '@tauri-apps/api/core';
 import { toast } from 'sonner';
+import { errorMessage, isAppError } from '@/lib/utils';
 
 interface UseMeetingDataProps {
   meeting: any;
@@ -26,6 +27,26 @@ export function useMeetingData({ meeting, summaryData, onMeetingUpdated }: UseMe
   // Ref for BlockNoteSummaryView
   const blockNoteSummaryRef = useRef<BlockNoteSummaryViewRef>(null);
 
+  // Version of the meeting these edits are based on; saves send it so the
+  // backend refuses to overwrite changes made elsewhere
+  const versionRef = useRef<number | undefined>(meeting.version);
+  useEffect(() => {
+    versionRef.current = meeting.version;
+  }, [meeting.id, meeting.version]);
+
+  const rememberVersion = (result: unknown) => {
+    const version = (result as { version?: number } | null)?.version;
+    if (typeof version === 'number') versionRef.current = version;
+  };
+
+  const reportConflict = (error: unknown) => {
+    if (isAppError(error) && error.code === 'VERSION_CONFLICT') {
+      toast.error('この会議は別の場所で変更されています', {
+        description: '再読み込みしてから保存してください',
+      });
+    }
+  };
+
   // Sidebar context
   const { setCurrentMeeting, setMeetings, meetings: sidebarMeetings } = useSidebar();
 
@@ -47,10 +68,12 @@ export function useMeetingData({ meeting, summaryData, onMeetingUpdated }: UseMe
 
   const handleSaveMeetingTitle = useCallback(async () => {
     try {
-      await invokeTauri('api_save_meeting_title', {
+      const result = await invokeTauri('api_save_meeting_title', {
         meetingId: meeting.id,
         title: meetingTitle,
+        expectedVersion: versionRef.current,
       });
+      rememberVersion(result);
 
       console.log('Save meeting title success');
       setIsTitleDirty(false);
@@ -64,11 +87,8 @@ export function useMeetingData({ meeting, summaryData, onMeetingUpdated }: UseMe
       return true;
     } catch (error) {
       console.error('Failed to save meeting title:', error);
-      if (error instanceof Error) {
-        setError(error.message);
-      } else {
-        setError('Failed to save meeting title: Unknown error');
-      }
+      reportConflict(error);
+      setError(errorMessage(error));
       return false;
     }
   }, [meeting.id, meetingTitle, sidebarMeetings, setMeetings, setCurrentMeeting]);
@@ -100,19 +120,18 @@ export function useMeetingData({ meeting, summaryData, onMeetingUpdated }: UseMe
         };
       }
 
-      await invokeTauri('api_save_meeting_summary', {
+      const result = await invokeTauri('api_save_meeting_summary', {
         meetingId: meeting.id,
         summary: formattedSummary,
+        expectedVersion: versionRef.current,
       });
+      rememberVersion(result);
 
       console.log('✅ Save meeting summary success');
     } catch (error) {
       console.error('❌ Failed to save meeting summary:', error);
-      if (error instanceof Error) {
-        setError(error.message);
-      } else {
-        setError('Failed to save meeting summary: Unknown error');
-      }
+      reportConflict(error);
+      setError(errorMessage(error));
     }
   }, [meeting.id, meetingTitle]);
 
@@ -135,7 +154,7 @@ export function useMeetingData({ meeting, summaryData, onMeetingUpdated }: UseMe
       toast.success("変更を保存しました");
     } catch (error) {
       console.error('Failed to save changes:', error);
-      toast.error("変更の保存に失敗しました", { description: String(error) });
+      toast.error("変更の保存に失敗しました", { description: errorMessage(error) });
     } finally {
       setIsSaving(false);
     }
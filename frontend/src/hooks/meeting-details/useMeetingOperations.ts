@@ -1,6 +1,7 @@
 import { useCallback } from 'react';
 import { invoke as invokeTauri } from '@tauri-apps/api/core';
 import { toast } from 'sonner';
+import { errorMessage } from '@/lib/utils';
 
 interface UseMeetingOperationsProps {
   meeting: any;
@@ -16,7 +17,7 @@ export function useMeetingOperations({
       await invokeTauri('open_meeting_folder', { meetingId: meeting.id });
     } catch (error) {
       console.error('Failed to open meeting folder:', error);
-      toast.error(error as string || '録音フォルダを開けませんでした');
+      toast.error('録音フォルダを開けませんでした', { description: errorMessage(error) });
     }
   }, [meeting.id]);
 
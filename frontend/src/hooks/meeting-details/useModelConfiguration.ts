@@ -3,6 +3,7 @@ import { ModelConfig } from '@/components/ModelSettingsModal';
 import { invoke as invokeTauri } from '@tauri-apps/api/core';
 import { toast } from 'sonner';
 import Analytics from '@/lib/analytics';
+import { errorMessage } from '@/lib/utils';
 
 interface UseModelConfigurationProps {
   serverAddress: string | null;
@@ -151,12 +152,8 @@ export function useModelConfiguration({ serverAddress }: UseModelConfigurationPr
       await Analytics.trackSettingsChanged('model_config', `${payload.provider}_${payload.model}`);
     } catch (error) {
       console.error('Failed to save model config:', error);
-      toast.error("要約設定の保存に失敗しました", { description: String(error) });
-      if (error instanceof Error) {
-        setError(error.message);
-      } else {
-        setError('Failed to save model config: Unknown error');
-      }
+      toast.error("要約設定の保存に失敗しました", { description: errorMessage(error) });
+      setError(errorMessage(error));
     }
   }, [modelConfig]);
 
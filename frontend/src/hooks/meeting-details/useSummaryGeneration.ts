@@ -62,10 +62,13 @@ export function useSummaryGeneration({
     transcriptText,
     customPrompt = '',
     isRegeneration = false,
+    summaryLanguage,
   }: {
     transcriptText: string;
     customPrompt?: string;
     isRegeneration?: boolean;
+    // Overrides the saved summary language for this run
+    summaryLanguage?: string;
   }) => {
     setSummaryStatus(isRegeneration ? 'regenerating' : 'processing');
     setSummaryError(null);
@@ -113,6 +116,7 @@ export function useSummaryGeneration({
         overlap: 1000,
         customPrompt: customPrompt,
         templateId: selectedTemplate,
+        summaryLanguage: summaryLanguage ?? null,
       }) as any;
 
       const process_id = result.process_id;
@@ -558,7 +562,7 @@ export function useSummaryGeneration({
     };
 
     const fullTranscript = allTranscripts
-      .map(t => `${formatTime(t.audio_start_time, t.timestamp)} ${t.text}`)
+      .map(t => `${formatTime(t.audio_start_time, t.timestamp)} ${t.speaker ? `${t.speaker}: ` : ''}${t.text}`)
       .join('\n');
 
     await processSummary({ transcriptText: fullTranscript, customPrompt });
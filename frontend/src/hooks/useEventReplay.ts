@@ -0,0 +1,84 @@
+import { useEffect } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { toast } from 'sonner';
+
+/** An event kept by the backend for windows that missed it */
+interface EventRecord {
+  seq: number;
+  name: string;
+  version: number;
+  emittedAt: string;
+  payload: unknown;
+}
+
+/** Events worth telling the user about after a reload */
+const REPLAYED_EVENTS = [
+  'recording-error',
+  'recording-saved',
+  'transcription-error',
+  'transcription-warning',
+  'summary-finished',
+  'keyword-alert',
+  'digest-created',
+];
+
+/** When the page was last left, so a reload only replays what it missed */
+const LEFT_AT_KEY = 'eventReplayLeftAt';
+
+/** A window opened for the first time catches up on this much history */
+const FIRST_LOAD_WINDOW_MS = 5 * 60 * 1000;
+
+function showReplayedEvent({ name, payload }: EventRecord) {
+  const data = (payload ?? {}) as Record<string, any>;
+  switch (name) {
+    case 'recording-error':
+      toast.error('録音エラー', { description: String(payload) });
+      break;
+    case 'recording-saved':
+      toast.success('録音を保存しました', { description: data.meeting_name ?? undefined });
+      break;
+    case 'transcription-error':
+      toast.error('文字起こしエラー', { description: data.userMessage ?? data.error });
+      break;
+    case 'transcription-warning':
+      toast.warning('一部の音声を文字起こしできませんでした', { description: String(payload) });
+      break;
+    case 'summary-finished':
+      if (data.error) {
+        toast.error('要約の生成に失敗しました', { description: `${data.title}: ${data.error}` });
+      } else {
+        toast.success('要約が完成しました', { description: data.title });
+      }
+      break;
+    case 'keyword-alert':
+      toast.info(`キーワード「${data.keyword}」が話されました`, { description: data.text });
+      break;
+    case 'digest-created':
+      toast.success('ダイジェストを作成しました', { description: data.title });
+      break;
+  }
+}
+
+/**
+ * Shows the events this window missed while it was loading or reloading
+ * (a finished summary, a recording error), from the backend's event history.
+ */
+export function useEventReplay(enabled: boolean) {
+  useEffect(() => {
+    if (!enabled) return;
+
+    const leftAt = Number(sessionStorage.getItem(LEFT_AT_KEY));
+    const after = leftAt > 0 ? leftAt : Date.now() - FIRST_LOAD_WINDOW_MS;
+    invoke<EventRecord[]>('api_replay_recent_events', { names: REPLAYED_EVENTS })
+      .then((records) => {
+        records
+          .filter((record) => Date.parse(record.emittedAt) > after)
+          .forEach(showReplayedEvent);
+      })
+      .catch((error) => console.error('[EventReplay] Failed to replay recent events:', error));
+
+    const remember = () => sessionStorage.setItem(LEFT_AT_KEY, String(Date.now()));
+    window.addEventListener('pagehide', remember);
+    return () => window.removeEventListener('pagehide', remember);
+  }, [enabled]);
+}
@@ -24,6 +24,7 @@ interface UsePaginatedTranscriptsReturn {
     // Actions
     loadMore: () => Promise<void>;
     reset: () => void;
+    refetchMetadata: () => Promise<MeetingMetadata | null>;
 }
 
 /**
@@ -36,6 +37,7 @@ function convertTranscriptsToSegments(transcripts: Transcript[]): TranscriptSegm
         endTime: t.audio_end_time,
         text: t.text,
         confidence: t.confidence,
+        speaker: t.speaker,
     }));
 }
 
@@ -195,5 +197,6 @@ export function usePaginatedTranscripts({
         error,
         loadMore,
         reset,
+        refetchMetadata: loadMetadata,
     };
 }
@@ -152,6 +152,9 @@ export function useRecordingStart(
           console.log('Auto-starting recording from navigation...');
           setIsAutoStarting(true);
           sessionStorage.removeItem('autoStartRecording'); // Clear the flag
+          // Set by meetingminutes://record?title=... links
+          const requestedTitle = sessionStorage.getItem('autoStartRecordingTitle');
+          sessionStorage.removeItem('autoStartRecordingTitle');
 
           // Check if Parakeet transcription model is ready before starting
           const parakeetReady = await checkParakeetReady();
@@ -178,8 +181,8 @@ export function useRecordingStart(
 
           // Start the actual backend recording
           try {
-            // Generate meeting title
-            const generatedMeetingTitle = generateMeetingTitle();
+            // Use the title from the link, or generate one
+            const generatedMeetingTitle = requestedTitle || generateMeetingTitle();
 
             // Set STARTING status before initiating backend recording
             setStatus(RecordingStatus.STARTING, 'Initializing recording...');
@@ -252,7 +252,8 @@ export function useRecordingStop(
           const responseData = await storageService.saveMeeting(
             savedMeetingName || meetingTitle || 'New Meeting',  // PREFER savedMeetingName (backend source)
             freshTranscripts,
-            folderPath
+            folderPath,
+            sessionStorage.getItem('indexeddb_current_meeting_id')
           );
 
           const meetingId = responseData.meeting_id;
@@ -5,6 +5,8 @@ export interface BuiltInModelInfo {
   status: BuiltInModelStatus;
   path: string;
   size_mb: number;
+  ram_required_mb: number;
+  vram_required_mb: number;
   context_size: number;
   description: string;
   gguf_file: string;
@@ -17,6 +19,30 @@ export type BuiltInModelStatus =
   | { type: 'corrupted', file_size: number, expected_min_size: number }
   | { type: 'error', Error: string };
 
+// State of the llama sidecar process
+export interface SidecarStatus {
+  state: 'running' | 'unhealthy' | 'stopped';
+  pid: number | null;
+  model_path: string | null;
+  memory_bytes: number | null;
+  uptime_secs: number | null;
+  active_requests: number;
+  idle_timeout_secs: number;
+  restart_count: number;
+  last_exit: string | null;
+}
+
+// Payload of the 'builtin-ai-sidecar' event
+export type SidecarEvent =
+  | { type: 'started' }
+  | { type: 'stopped' }
+  | { type: 'crashed', exit_status: string, restart_in_secs: number | null }
+  | { type: 'restarted', attempt: number }
+  | { type: 'restart_failed', attempt: number, error: string }
+  | { type: 'gave_up', attempts: number };
+
+export const SIDECAR_EVENT = 'builtin-ai-sidecar';
+
 // Helper functions for status handling
 export function isModelAvailable(status: BuiltInModelStatus): boolean {
   return status.type === 'available';
@@ -95,4 +121,25 @@ export class BuiltInAIAPI {
   static async getModelsDirectory(): Promise<string> {
     return await invoke('builtin_ai_get_models_directory');
   }
+
+  // Makes the model the built-in summary model and loads it into a running sidecar
+  static async switchModel(modelName: string, force: boolean = false): Promise<SidecarStatus> {
+    return await invoke('builtin_ai_switch_model', { modelName, force });
+  }
+
+  static async getSidecarStatus(): Promise<SidecarStatus> {
+    return await invoke('builtin_ai_sidecar_status');
+  }
+
+  static async startSidecar(modelName?: string): Promise<SidecarStatus> {
+    return await invoke('builtin_ai_sidecar_start', { modelName: modelName ?? null });
+  }
+
+  static async stopSidecar(force: boolean = false): Promise<SidecarStatus> {
+    return await invoke('builtin_ai_sidecar_stop', { force });
+  }
+
+  static async restartSidecar(force: boolean = false): Promise<SidecarStatus> {
+    return await invoke('builtin_ai_sidecar_restart', { force });
+  }
 }
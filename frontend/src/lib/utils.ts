@@ -30,3 +30,31 @@ export function isOllamaNotInstalledError(errorMessage: string): boolean {
 
   return patterns.some(pattern => lowerError.includes(pattern));
 }
+
+/**
+ * Error returned by Tauri commands that report a structured error
+ * (`code`, user-facing `message`, and whether retrying may help)
+ */
+export interface AppError {
+  code: string;
+  message: string;
+  retryable: boolean;
+}
+
+export function isAppError(error: unknown): error is AppError {
+  return (
+    typeof error === 'object' &&
+    error !== null &&
+    typeof (error as AppError).code === 'string' &&
+    typeof (error as AppError).message === 'string'
+  );
+}
+
+/**
+ * The message to show for an error thrown by `invoke`, whether the command
+ * returned a structured error, a plain string, or an Error was thrown
+ */
+export function errorMessage(error: unknown): string {
+  if (isAppError(error) || error instanceof Error) return error.message;
+  return String(error);
+}
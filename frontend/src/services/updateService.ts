@@ -8,6 +8,7 @@
 import { check, Update } from '@tauri-apps/plugin-updater';
 import { relaunch } from '@tauri-apps/plugin-process';
 import { getVersion } from '@tauri-apps/api/app';
+import { invoke } from '@tauri-apps/api/core';
 
 export interface UpdateInfo {
   available: boolean;
@@ -104,8 +105,18 @@ export class UpdateService {
         onProgress({ downloaded: 100, total: 100, percentage: 100 });
       }
 
+      // Finalize any recording and close the database before the installer
+      // replaces the app (on Windows it exits the process itself)
+      await invoke('api_prepare_for_shutdown', { reason: 'update' });
+
       // Install and relaunch
-      await update.install();
+      try {
+        await update.install();
+      } catch (error) {
+        // The database is closed by now; restarting opens it again
+        await invoke('api_recover_from_failed_update');
+        throw error;
+      }
       await relaunch();
     } catch (error) {
       console.error('Failed to download/install update:', error);
@@ -16,6 +16,8 @@ export interface Transcript {
   audio_start_time?: number; // Seconds from recording start (e.g., 125.3)
   audio_end_time?: number;   // Seconds from recording start (e.g., 128.6)
   duration?: number;          // Segment duration in seconds (e.g., 3.3)
+  speaker?: string;           // Diarization label (e.g., "Speaker 1")
+  uncertain?: boolean;        // Flagged as a possible hallucination
 }
 
 export interface TranscriptUpdate {
@@ -26,6 +28,7 @@ export interface TranscriptUpdate {
   chunk_start_time: number; // Legacy field
   is_partial: boolean;
   confidence: number;
+  uncertain?: boolean; // Flagged as a possible hallucination
   // NEW: Recording-relative timestamps for playback sync
   audio_start_time: number; // Seconds from recording start
   audio_end_time: number;   // Seconds from recording start
@@ -92,6 +95,7 @@ export interface MeetingMetadata {
   created_at: string;
   updated_at: string;
   folder_path?: string;
+  version: number;
 }
 
 export interface PaginatedTranscriptsResponse {
@@ -107,4 +111,5 @@ export interface TranscriptSegmentData {
   endTime?: number; // audio_end_time in seconds
   text: string;
   confidence?: number;
+  speaker?: string;
 }